@@ -40,7 +40,7 @@ pub async fn execute(
 ) -> anyhow::Result<()> {
     use tuitbot_core::config::Config;
     let config = Config::load(Some(config_path))?;
-    let pool = storage::init_db(&config.storage.db_path).await?;
+    let pool = storage::init_db_with_config(&config.storage.db_path, &config.storage).await?;
 
     let result = match cmd {
         AccountsSubcommand::List => list_accounts_cmd(&pool, &out).await,
@@ -8,12 +8,18 @@
 //!   --approve <ID>  Approve a specific item
 //!   --reject <ID>   Reject a specific item
 //!   --approve-all   Approve all pending items
+//!
+//! `--watch` keeps polling the queue and reviews new items as they arrive
+//! until Ctrl-C.
 
-use std::io::{self, BufRead, Write};
+use std::collections::HashSet;
+use std::io::{self, BufRead, IsTerminal, Write};
+use std::time::Duration;
 
 use serde::Serialize;
+use tokio::io::{AsyncBufReadExt, BufReader};
 use tuitbot_core::config::Config;
-use tuitbot_core::storage;
+use tuitbot_core::storage::{self, approval_queue::ApprovalItem, DbPool};
 
 use super::ApproveArgs;
 use crate::output::CliOutput;
@@ -61,10 +67,18 @@ pub async fn execute(config: &Config, args: ApproveArgs, out: CliOutput) -> anyh
     let action_count = args.list as u8
         + args.approve.is_some() as u8
         + args.reject.is_some() as u8
-        + args.approve_all as u8;
+        + args.approve_all as u8
+        + args.watch as u8;
     if action_count > 1 {
         anyhow::bail!(
-            "Conflicting flags: --list, --approve, --reject, and --approve-all are mutually exclusive."
+            "Conflicting flags: --list, --approve, --reject, --approve-all, and --watch are mutually exclusive."
+        );
+    }
+
+    if args.watch && (out.is_json() || out.quiet || !io::stdin().is_terminal()) {
+        anyhow::bail!(
+            "--watch needs an interactive terminal.\n\
+             Use `tuitbot approve --list` (or --output json) to read the queue from scripts."
         );
     }
 
@@ -81,7 +95,7 @@ pub async fn execute(config: &Config, args: ApproveArgs, out: CliOutput) -> anyh
         return Ok(());
     }
 
-    let pool = storage::init_db(&config.storage.db_path).await?;
+    let pool = storage::init_db_with_config(&config.storage.db_path, &config.storage).await?;
 
     // Handle non-interactive modes
     if args.list {
@@ -176,6 +190,12 @@ pub async fn execute(config: &Config, args: ApproveArgs, out: CliOutput) -> anyh
         return Ok(());
     }
 
+    if args.watch {
+        let result = watch(&pool, Duration::from_secs(args.interval.max(1))).await;
+        pool.close().await;
+        return result;
+    }
+
     // When --output json or --quiet is set without an explicit subcommand,
     // fall back to listing pending items (interactive mode needs a TTY).
     if out.is_json() {
@@ -215,30 +235,7 @@ pub async fn execute(config: &Config, args: ApproveArgs, out: CliOutput) -> anyh
     let mut rejected = 0u32;
 
     for (i, item) in pending.iter().enumerate() {
-        eprintln!("--- Item {}/{} ---", i + 1, pending.len());
-        eprintln!("  Type:    {}", item.action_type);
-        if !item.target_tweet_id.is_empty() {
-            eprintln!(
-                "  Reply to: {} (by {})",
-                item.target_tweet_id, item.target_author
-            );
-        }
-        if !item.topic.is_empty() {
-            eprintln!("  Topic:   {}", item.topic);
-        }
-        if !item.archetype.is_empty() {
-            eprintln!("  Style:   {}", item.archetype);
-        }
-        if item.score > 0.0 {
-            eprintln!("  Score:   {:.1}", item.score);
-        }
-        eprintln!("  Created: {}", item.created_at);
-        eprintln!();
-        eprintln!("  Content:");
-        for line in item.generated_content.lines() {
-            eprintln!("    {line}");
-        }
-        eprintln!();
+        print_item(item, &format!("--- Item {}/{} ---", i + 1, pending.len()));
         eprint!("  [y]es / [n]o / [s]kip / [q]uit > ");
         io::stderr().flush()?;
 
@@ -279,6 +276,126 @@ pub async fn execute(config: &Config, args: ApproveArgs, out: CliOutput) -> anyh
     Ok(())
 }
 
+/// Print one queued item for review.
+fn print_item(item: &ApprovalItem, heading: &str) {
+    eprintln!("{heading}");
+    eprintln!("  Type:    {}", item.action_type);
+    if !item.target_tweet_id.is_empty() {
+        eprintln!(
+            "  Reply to: {} (by {})",
+            item.target_tweet_id, item.target_author
+        );
+    }
+    if !item.topic.is_empty() {
+        eprintln!("  Topic:   {}", item.topic);
+    }
+    if !item.archetype.is_empty() {
+        eprintln!("  Style:   {}", item.archetype);
+    }
+    if item.score > 0.0 {
+        eprintln!("  Score:   {:.1}", item.score);
+    }
+    eprintln!("  Created: {}", item.created_at);
+    eprintln!();
+    eprintln!("  Content:");
+    for line in item.generated_content.lines() {
+        eprintln!("    {line}");
+    }
+    eprintln!();
+}
+
+/// Tracks which pending items `--watch` has already shown.
+#[derive(Debug, Default)]
+struct SeenItems {
+    ids: HashSet<i64>,
+}
+
+impl SeenItems {
+    /// IDs in `pending` not shown before, in queue order.
+    ///
+    /// Marks them as seen and forgets IDs that are no longer pending, so a
+    /// skipped item is not shown again while it waits in the queue.
+    fn take_new(&mut self, pending: &[i64]) -> Vec<i64> {
+        self.ids.retain(|id| pending.contains(id));
+        pending
+            .iter()
+            .copied()
+            .filter(|id| self.ids.insert(*id))
+            .collect()
+    }
+}
+
+/// `--watch`: poll the queue every `interval` and review new items inline.
+///
+/// Stops on Ctrl-C, `q`, or end of input.
+async fn watch(pool: &DbPool, interval: Duration) -> anyhow::Result<()> {
+    eprintln!(
+        "Watching the approval queue every {}s. Press Ctrl-C to stop.\n",
+        interval.as_secs()
+    );
+
+    let mut lines = BufReader::new(tokio::io::stdin()).lines();
+    let ctrl_c = tokio::signal::ctrl_c();
+    tokio::pin!(ctrl_c);
+
+    let mut seen = SeenItems::default();
+    let mut approved = 0u32;
+    let mut rejected = 0u32;
+
+    'watch: loop {
+        let pending = storage::approval_queue::get_pending(pool).await?;
+        let ids: Vec<i64> = pending.iter().map(|item| item.id).collect();
+        let new_ids = seen.take_new(&ids);
+
+        for item in pending.iter().filter(|item| new_ids.contains(&item.id)) {
+            print_item(item, &format!("--- New item #{} ---", item.id));
+            eprint!("  [y]es / [n]o / [s]kip / [q]uit > ");
+            io::stderr().flush()?;
+
+            let input = tokio::select! {
+                _ = &mut ctrl_c => break 'watch,
+                line = lines.next_line() => line?,
+            };
+            let Some(input) = input else {
+                break 'watch;
+            };
+
+            let status = match input.trim().to_lowercase().as_str() {
+                "y" | "yes" => "approved",
+                "n" | "no" => "rejected",
+                "q" | "quit" => break 'watch,
+                _ => {
+                    eprintln!("  -> Skipped\n");
+                    continue;
+                }
+            };
+
+            // The item may have been reviewed elsewhere while it was on screen.
+            let current = storage::approval_queue::get_by_id(pool, item.id).await?;
+            if !matches!(current, Some(c) if c.status == "pending") {
+                eprintln!("  -> Already reviewed elsewhere\n");
+                continue;
+            }
+            storage::approval_queue::update_status(pool, item.id, status).await?;
+            if status == "approved" {
+                approved += 1;
+                eprintln!("  -> Approved\n");
+            } else {
+                rejected += 1;
+                eprintln!("  -> Rejected\n");
+            }
+        }
+
+        tokio::select! {
+            _ = &mut ctrl_c => break,
+            _ = tokio::time::sleep(interval) => {}
+        }
+    }
+
+    eprintln!("\nStopped watching: {approved} approved, {rejected} rejected.");
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -412,6 +529,8 @@ mod tests {
             approve: Some(1),
             reject: None,
             approve_all: false,
+            watch: false,
+            interval: 5,
         };
         let action_count = args.list as u8
             + args.approve.is_some() as u8
@@ -427,6 +546,8 @@ mod tests {
             approve: None,
             reject: None,
             approve_all: false,
+            watch: false,
+            interval: 5,
         };
         let action_count = args.list as u8
             + args.approve.is_some() as u8
@@ -442,6 +563,8 @@ mod tests {
             approve: None,
             reject: None,
             approve_all: false,
+            watch: false,
+            interval: 5,
         };
         let action_count = args.list as u8
             + args.approve.is_some() as u8
@@ -457,6 +580,8 @@ mod tests {
             approve: None,
             reject: None,
             approve_all: false,
+            watch: false,
+            interval: 5,
         };
         let is_non_interactive =
             args.list || args.approve.is_some() || args.reject.is_some() || args.approve_all;
@@ -470,6 +595,8 @@ mod tests {
             approve: None,
             reject: None,
             approve_all: false,
+            watch: false,
+            interval: 5,
         };
         let is_non_interactive =
             args.list || args.approve.is_some() || args.reject.is_some() || args.approve_all;
@@ -506,6 +633,43 @@ mod tests {
         ));
     }
 
+    // ── Watch polling ─────────────────────────────────────────────────
+
+    #[test]
+    fn seen_items_reports_only_new_ids_in_queue_order() {
+        let mut seen = SeenItems::default();
+        assert_eq!(seen.take_new(&[3, 1, 2]), vec![3, 1, 2]);
+        // Nothing new on the next poll, even for items left pending.
+        assert!(seen.take_new(&[3, 1, 2]).is_empty());
+        assert_eq!(seen.take_new(&[1, 2, 4, 5]), vec![4, 5]);
+    }
+
+    #[test]
+    fn seen_items_forgets_reviewed_ids() {
+        let mut seen = SeenItems::default();
+        seen.take_new(&[1, 2]);
+        seen.take_new(&[2]);
+        assert_eq!(seen.ids, HashSet::from([2]));
+    }
+
+    #[test]
+    fn action_count_counts_watch() {
+        let args = ApproveArgs {
+            list: true,
+            approve: None,
+            reject: None,
+            approve_all: false,
+            watch: true,
+            interval: 5,
+        };
+        let action_count = args.list as u8
+            + args.approve.is_some() as u8
+            + args.reject.is_some() as u8
+            + args.approve_all as u8
+            + args.watch as u8;
+        assert!(action_count > 1);
+    }
+
     // ── Serialization round-trip ──────────────────────────────────────
 
     #[test]
@@ -1,23 +1,33 @@
 //! Implementation of the `tuitbot auth` command.
 //!
 //! Walks the user through the OAuth 2.0 PKCE authentication flow
-//! with the X API. Supports both manual code-entry and local
-//! callback server modes. Manual mode is the default and works
-//! on headless servers (VPS, SSH, OpenClaw).
+//! with the X API. Supports manual code-entry and local callback
+//! server modes. Manual mode is the default and works on headless
+//! servers (VPS, SSH, OpenClaw).
+//!
+//! The PKCE verifier and state are persisted to `~/.tuitbot/auth_state.json`
+//! when a session starts, so the callback can also be completed by a later
+//! `tuitbot auth --validate-callback-only <URL>` invocation.
 
 use std::io::Write;
 use tuitbot_core::config::Config;
 use tuitbot_core::startup::{
-    build_auth_url, build_redirect_uri, exchange_auth_code, extract_auth_code,
-    extract_callback_state, generate_pkce, save_tokens_to_file, token_file_path,
-    verify_credentials,
+    auth_state_file_path, build_auth_url, build_redirect_uri, clear_auth_state, exchange_auth_code,
+    extract_auth_code, extract_callback_state, generate_pkce, load_auth_state, save_auth_state,
+    token_store, verify_credentials, PendingAuthState, StoredTokens, AUTH_STATE_TTL_MINUTES,
 };
 
 /// Execute the `tuitbot auth` command.
 ///
 /// Determines the auth mode from the CLI flag or config, runs the
 /// appropriate PKCE flow, saves tokens, and verifies credentials.
-pub async fn execute(config: &Config, mode_override: Option<&str>) -> anyhow::Result<()> {
+/// With `validate_callback_only`, skips straight to completing the
+/// session persisted by an earlier `tuitbot auth`.
+pub async fn execute(
+    config: &Config,
+    mode_override: Option<&str>,
+    validate_callback_only: Option<&str>,
+) -> anyhow::Result<()> {
     // Short-circuit: scraper mode does not require X API auth.
     if config.x_api.provider_backend == "scraper" {
         eprintln!(
@@ -36,11 +46,16 @@ pub async fn execute(config: &Config, mode_override: Option<&str>) -> anyhow::Re
         );
     }
 
+    if let Some(callback) = validate_callback_only {
+        return complete_pending_auth(config, callback).await;
+    }
+
     // 2. Determine auth mode.
     let mode = mode_override.unwrap_or(&config.auth.mode);
+
     let redirect_uri = build_redirect_uri(&config.auth.callback_host, config.auth.callback_port);
 
-    // 3. Generate PKCE challenge.
+    // 3. Generate PKCE challenge and persist it for a later callback step.
     let pkce = generate_pkce();
     let auth_url = build_auth_url(
         &config.x_api.client_id,
@@ -48,24 +63,35 @@ pub async fn execute(config: &Config, mode_override: Option<&str>) -> anyhow::Re
         &pkce.state,
         &pkce.challenge,
     );
+    save_auth_state(
+        &auth_state_file_path(),
+        &PendingAuthState {
+            verifier: pkce.verifier.clone(),
+            state: pkce.state.clone(),
+            redirect_uri: redirect_uri.clone(),
+            created_at: chrono::Utc::now(),
+        },
+    )?;
 
     // 4. Run the auth flow based on mode.
+    let token_location = token_store(&config.auth).location();
     let code = match mode {
         "local_callback" => {
             if is_headless_environment() {
                 eprintln!("Headless environment detected — using manual authentication.\n");
-                run_manual_mode(&auth_url, &pkce.state)?
+                run_manual_mode(&auth_url, &pkce.state, &token_location)?
             } else {
                 run_callback_mode(
                     &auth_url,
                     &config.auth.callback_host,
                     config.auth.callback_port,
                     &pkce.state,
+                    &token_location,
                 )
                 .await?
             }
         }
-        "manual" => run_manual_mode(&auth_url, &pkce.state)?,
+        "manual" => run_manual_mode(&auth_url, &pkce.state, &token_location)?,
         other => {
             anyhow::bail!(
                 "Invalid auth mode: '{other}'. Must be 'manual' or 'local_callback'.\n\
@@ -74,46 +100,86 @@ pub async fn execute(config: &Config, mode_override: Option<&str>) -> anyhow::Re
         }
     };
 
+    finish_auth(config, &code, &redirect_uri, &pkce.verifier).await
+}
+
+/// Complete a session started by an earlier `tuitbot auth` invocation.
+///
+/// Loads the persisted PKCE verifier and state, validates the callback
+/// URL against them, and exchanges the code.
+async fn complete_pending_auth(config: &Config, callback: &str) -> anyhow::Result<()> {
+    let pending =
+        load_auth_state(&auth_state_file_path(), chrono::Utc::now())?.ok_or_else(|| {
+            anyhow::anyhow!(
+                "No pending auth session found.\n\
+             Sessions expire after {AUTH_STATE_TTL_MINUTES} minutes. \
+             Run `tuitbot auth` to start a new one."
+            )
+        })?;
+
+    validate_callback_state(callback.trim(), &pending.state)?;
+    let code = extract_auth_code(callback.trim());
+    if code.is_empty() {
+        anyhow::bail!("Callback URL is missing the 'code' parameter.");
+    }
+
+    finish_auth(config, &code, &pending.redirect_uri, &pending.verifier).await
+}
+
+/// Exchange the code, clear the pending session, then save and verify tokens.
+async fn finish_auth(
+    config: &Config,
+    code: &str,
+    redirect_uri: &str,
+    verifier: &str,
+) -> anyhow::Result<()> {
     // 5. Exchange the authorization code for tokens.
     eprintln!("\nExchanging authorization code for tokens...");
-    let tokens = exchange_auth_code(
-        &config.x_api.client_id,
-        &code,
-        &redirect_uri,
-        &pkce.verifier,
-    )
-    .await
-    .map_err(|e| {
-        let msg = e.to_string();
-        if msg.contains("401") || msg.contains("invalid_client") || msg.contains("invalid_grant") {
-            anyhow::anyhow!(
-                "X API rejected the authorization code (HTTP 401).\n\
+    let tokens = exchange_auth_code(&config.x_api.client_id, code, redirect_uri, verifier)
+        .await
+        .map_err(|e| {
+            let msg = e.to_string();
+            if msg.contains("401")
+                || msg.contains("invalid_client")
+                || msg.contains("invalid_grant")
+            {
+                anyhow::anyhow!(
+                    "X API rejected the authorization code (HTTP 401).\n\
                  \n\
                  This usually means the code was already used, it expired (codes are \
                  one-time-use and valid for ~30 seconds), or your client_id is wrong.\n\
                  Run `tuitbot auth` again to get a fresh code."
-            )
-        } else if msg.contains("connect") || msg.contains("timed out") || msg.contains("dns") {
-            anyhow::anyhow!(
-                "Cannot reach api.x.com to exchange the authorization code.\n\
+                )
+            } else if msg.contains("connect") || msg.contains("timed out") || msg.contains("dns") {
+                anyhow::anyhow!(
+                    "Cannot reach api.x.com to exchange the authorization code.\n\
                  \n\
                  Network error: {e}\n\
                  Check your internet connection and try again."
-            )
-        } else {
-            anyhow::anyhow!(
-                "Token exchange failed: {e}\n\
+                )
+            } else {
+                anyhow::anyhow!(
+                    "Token exchange failed: {e}\n\
                  \n\
                  Run `tuitbot auth` again. If this keeps failing, verify your \
                  client_id in config."
-            )
-        }
-    })?;
+                )
+            }
+        })?;
 
-    // 6. Save tokens to disk.
-    save_tokens_to_file(&tokens)?;
-    let token_path = token_file_path();
-    tracing::info!(path = %token_path.display(), "Tokens saved");
+    // The verifier is single-use; drop the pending session once exchanged.
+    clear_auth_state(&auth_state_file_path())?;
+
+    save_and_verify(config, &tokens).await
+}
+
+/// Persist freshly issued tokens and confirm they work.
+async fn save_and_verify(config: &Config, tokens: &StoredTokens) -> anyhow::Result<()> {
+    // 6. Save tokens to the configured store (auth.token_storage).
+    let store = token_store(&config.auth);
+    store.save(tokens)?;
+    let token_location = store.location();
+    tracing::info!(location = %token_location, "Tokens saved");
 
     // 7. Verify credentials.
     eprintln!("Verifying credentials...");
@@ -146,10 +212,7 @@ pub async fn execute(config: &Config, mode_override: Option<&str>) -> anyhow::Re
             }
         })?;
 
-    eprintln!(
-        "\nAuthenticated as @{username}. Tokens saved to {}",
-        token_path.display()
-    );
+    eprintln!("\nAuthenticated as @{username}. Tokens saved to {token_location}");
 
     Ok(())
 }
@@ -159,9 +222,11 @@ pub async fn execute(config: &Config, mode_override: Option<&str>) -> anyhow::Re
 /// Designed as the primary headless-friendly auth flow. Works from any
 /// terminal — local, SSH, VPS, or OpenClaw. The user opens the URL on
 /// any device with a browser, authorizes, then copies the code back.
-fn run_manual_mode(auth_url: &str, expected_state: &str) -> anyhow::Result<String> {
-    let token_path = token_file_path();
-
+fn run_manual_mode(
+    auth_url: &str,
+    expected_state: &str,
+    token_location: &str,
+) -> anyhow::Result<String> {
     eprintln!("=== X API Authentication ===\n");
     eprintln!("1. Open this URL in any browser (laptop, phone, etc.):\n");
     eprintln!("   {auth_url}\n");
@@ -171,7 +236,11 @@ fn run_manual_mode(auth_url: &str, expected_state: &str) -> anyhow::Result<Strin
          won't load — this is normal. Copy the ENTIRE URL from the address bar.\n   \
          It looks like: http://127.0.0.1:8080/callback?code=...&state=..."
     );
-    eprintln!("\nTokens will be saved to: {}\n", token_path.display());
+    eprintln!("\nTokens will be saved to: {token_location}\n");
+    eprintln!(
+        "To finish from another shell within {AUTH_STATE_TTL_MINUTES} minutes, run:\n   \
+         tuitbot auth --validate-callback-only '<callback URL>'\n"
+    );
     eprintln!("Paste the full callback URL (or just the code):");
 
     eprint!("> ");
@@ -191,7 +260,11 @@ fn run_manual_mode(auth_url: &str, expected_state: &str) -> anyhow::Result<Strin
 
     let code = extract_auth_code(trimmed);
     if code.is_empty() {
-        anyhow::bail!("No authorization code provided.");
+        anyhow::bail!(
+            "No authorization code provided.\n\
+             The auth session is saved; finish it with \
+             `tuitbot auth --validate-callback-only '<callback URL>'`."
+        );
     }
 
     Ok(code)
@@ -222,6 +295,7 @@ async fn run_callback_mode(
     host: &str,
     port: u16,
     expected_state: &str,
+    token_location: &str,
 ) -> anyhow::Result<String> {
     let addr = format!("{host}:{port}");
     let listener = tokio::net::TcpListener::bind(&addr).await.map_err(|e| {
@@ -243,7 +317,7 @@ async fn run_callback_mode(
              Falling back to manual authentication.\n"
         );
         drop(listener);
-        return run_manual_mode(auth_url, expected_state);
+        return run_manual_mode(auth_url, expected_state, token_location);
     }
 
     // Wait for the callback with a 120-second timeout.
@@ -0,0 +1,254 @@
+//! Implementation of `tuitbot auth status`.
+//!
+//! Reports whether stored OAuth tokens exist, whether they have expired,
+//! which scopes they grant, and how long until they expire. Never refreshes
+//! or exchanges tokens; `--live` additionally calls `GET /2/users/me` with
+//! the stored access token to report the authenticated username.
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use tuitbot_core::config::Config;
+use tuitbot_core::startup::{
+    token_store, verify_credentials, FileTokenStore, StoredTokens, TokenStore,
+};
+
+use crate::output::{write_stdout, CliOutput};
+
+/// Overall state of the stored tokens.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(super) enum TokenState {
+    /// No tokens stored.
+    Missing,
+    /// Stored tokens could not be read or parsed.
+    Unreadable,
+    /// Access token is past its expiry.
+    Expired,
+    /// Access token is present and not expired.
+    Valid,
+}
+
+impl TokenState {
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::Missing => "no tokens",
+            Self::Unreadable => "unreadable",
+            Self::Expired => "expired",
+            Self::Valid => "valid",
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(super) struct AuthStatus {
+    pub(super) state: TokenState,
+    pub(super) token_path: String,
+    pub(super) has_refresh_token: bool,
+    scopes: Vec<String>,
+    expires_at: Option<String>,
+    /// Seconds until the access token expires; negative once expired.
+    pub(super) expires_in_seconds: Option<i64>,
+    username: Option<String>,
+    error: Option<String>,
+}
+
+/// Execute the `tuitbot auth status` command.
+///
+/// Reads tokens from the store selected by `auth.token_storage`, falling
+/// back to the token file when the config cannot be loaded.
+pub async fn execute(config_path: &str, live: bool, out: CliOutput) -> anyhow::Result<()> {
+    let auth = Config::load(Some(config_path))
+        .map(|c| c.auth)
+        .unwrap_or_default();
+    let (mut status, tokens) = inspect_store(token_store(&auth).as_ref(), Utc::now());
+
+    if live {
+        match (&tokens, status.state) {
+            (Some(tokens), TokenState::Valid) => {
+                match verify_credentials(&tokens.access_token).await {
+                    Ok(username) => status.username = Some(username),
+                    Err(e) => status.error = Some(format!("live check failed: {e}")),
+                }
+            }
+            _ => out.info("Skipping live check: no valid access token."),
+        }
+    }
+
+    if out.is_json() {
+        return out.json(&status);
+    }
+    write_stdout(&format_text(&status))
+}
+
+/// Read the token file at `path` and classify it relative to `now`.
+pub(super) fn inspect(path: &Path, now: DateTime<Utc>) -> (AuthStatus, Option<StoredTokens>) {
+    inspect_store(&FileTokenStore::new(path.to_path_buf()), now)
+}
+
+/// Load tokens from `store` and classify them relative to `now`.
+pub(super) fn inspect_store(
+    store: &dyn TokenStore,
+    now: DateTime<Utc>,
+) -> (AuthStatus, Option<StoredTokens>) {
+    let mut status = AuthStatus {
+        state: TokenState::Missing,
+        token_path: store.location(),
+        has_refresh_token: false,
+        scopes: Vec::new(),
+        expires_at: None,
+        expires_in_seconds: None,
+        username: None,
+        error: None,
+    };
+
+    let tokens = match store.load() {
+        Ok(Some(t)) => t,
+        Ok(None) => return (status, None),
+        Err(e) => {
+            status.state = TokenState::Unreadable;
+            status.error = Some(e.to_string());
+            return (status, None);
+        }
+    };
+
+    status.has_refresh_token = tokens.refresh_token.is_some();
+    status.scopes = tokens.scopes.clone();
+    status.expires_at = tokens.expires_at.map(|t| t.to_rfc3339());
+    status.expires_in_seconds = tokens.expires_at.map(|t| (t - now).num_seconds());
+    status.state = match tokens.expires_at {
+        Some(expires) if now >= expires => TokenState::Expired,
+        _ => TokenState::Valid,
+    };
+    (status, Some(tokens))
+}
+
+fn format_text(status: &AuthStatus) -> String {
+    let mut lines = vec![
+        format!("Status:        {}", status.state.label()),
+        format!("Token file:    {}", status.token_path),
+    ];
+    if status.state == TokenState::Missing {
+        lines.push("Run `tuitbot auth` to authenticate.".to_string());
+        return lines.join("\n");
+    }
+    if let Some(err) = &status.error {
+        lines.push(format!("Error:         {err}"));
+    }
+    if matches!(status.state, TokenState::Valid | TokenState::Expired) {
+        let expiry = match (&status.expires_at, status.expires_in_seconds) {
+            (Some(at), Some(secs)) if secs >= 0 => format!("{at} (in {secs}s)"),
+            (Some(at), Some(secs)) => format!("{at} ({}s ago)", -secs),
+            _ => "unknown".to_string(),
+        };
+        lines.push(format!("Expires:       {expiry}"));
+        lines.push(format!(
+            "Refresh token: {}",
+            if status.has_refresh_token {
+                "yes"
+            } else {
+                "no"
+            }
+        ));
+        let scopes = if status.scopes.is_empty() {
+            "(not recorded)".to_string()
+        } else {
+            status.scopes.join(" ")
+        };
+        lines.push(format!("Scopes:        {scopes}"));
+    }
+    if let Some(username) = &status.username {
+        lines.push(format!("Username:      @{username}"));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Write `tokens` to `<home>/.tuitbot/tokens.json` and return the path.
+    fn write_tokens(home: &Path, tokens: &StoredTokens) -> PathBuf {
+        let dir = home.join(".tuitbot");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("tokens.json");
+        std::fs::write(&path, serde_json::to_string(tokens).unwrap()).unwrap();
+        path
+    }
+
+    fn tokens_expiring_at(expires_at: DateTime<Utc>) -> StoredTokens {
+        StoredTokens {
+            access_token: "access".to_string(),
+            refresh_token: Some("refresh".to_string()),
+            expires_at: Some(expires_at),
+            scopes: vec!["tweet.read".to_string(), "users.read".to_string()],
+        }
+    }
+
+    #[test]
+    fn missing_token_file() {
+        let home = tempfile::tempdir().unwrap();
+        let path = home.path().join(".tuitbot").join("tokens.json");
+
+        let (status, tokens) = inspect(&path, Utc::now());
+        assert_eq!(status.state, TokenState::Missing);
+        assert!(tokens.is_none());
+        assert!(status.expires_in_seconds.is_none());
+        assert!(format_text(&status).contains("tuitbot auth"));
+    }
+
+    #[test]
+    fn expired_tokens() {
+        let home = tempfile::tempdir().unwrap();
+        let now = Utc::now();
+        let path = write_tokens(
+            home.path(),
+            &tokens_expiring_at(now - chrono::Duration::seconds(120)),
+        );
+
+        let (status, _) = inspect(&path, now);
+        assert_eq!(status.state, TokenState::Expired);
+        assert_eq!(status.expires_in_seconds, Some(-120));
+        assert!(status.has_refresh_token);
+
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["state"], "expired");
+    }
+
+    #[test]
+    fn valid_tokens_report_scopes_and_expiry() {
+        let home = tempfile::tempdir().unwrap();
+        let now = Utc::now();
+        let path = write_tokens(
+            home.path(),
+            &tokens_expiring_at(now + chrono::Duration::seconds(3600)),
+        );
+
+        let (status, tokens) = inspect(&path, now);
+        assert_eq!(status.state, TokenState::Valid);
+        assert_eq!(status.expires_in_seconds, Some(3600));
+        assert_eq!(status.scopes, vec!["tweet.read", "users.read"]);
+        assert_eq!(tokens.unwrap().access_token, "access");
+
+        let json = serde_json::to_value(&status).unwrap();
+        assert_eq!(json["state"], "valid");
+        assert_eq!(json["scopes"][1], "users.read");
+        assert!(json["username"].is_null());
+    }
+
+    #[test]
+    fn corrupt_token_file_is_unreadable() {
+        let home = tempfile::tempdir().unwrap();
+        let dir = home.path().join(".tuitbot");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("tokens.json");
+        std::fs::write(&path, "not json").unwrap();
+
+        let (status, tokens) = inspect(&path, Utc::now());
+        assert_eq!(status.state, TokenState::Unreadable);
+        assert!(tokens.is_none());
+        assert!(status.error.unwrap().contains("parse"));
+    }
+}
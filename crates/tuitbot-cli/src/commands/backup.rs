@@ -2,6 +2,7 @@
 
 use std::path::PathBuf;
 
+use tuitbot_core::config::Config;
 use tuitbot_core::startup::{data_dir, resolve_db_path};
 use tuitbot_core::storage;
 
@@ -10,9 +11,9 @@ use crate::output::CliOutput;
 
 /// Execute the `tuitbot backup` command.
 pub async fn execute(args: BackupArgs, config_path: &str, out: CliOutput) -> anyhow::Result<()> {
-    if args.list && (args.prune.is_some() || args.output_dir.is_some()) {
+    if args.list && (args.prune.is_some() || args.output_dir.is_some() || args.incremental) {
         anyhow::bail!(
-            "--list is mutually exclusive with --prune and --output-dir.\n\
+            "--list is mutually exclusive with --prune, --output-dir, and --incremental.\n\
              Use --list alone to view backups."
         );
     }
@@ -46,7 +47,18 @@ pub async fn execute(args: BackupArgs, config_path: &str, out: CliOutput) -> any
     }
 
     // Open a read-only pool to the existing DB for VACUUM INTO.
-    let pool = storage::init_db(&db_path.to_string_lossy()).await?;
+    let storage_config = Config::load(Some(config_path))
+        .map(|c| c.storage)
+        .unwrap_or_default();
+    let pool = storage::init_db_with_config(&db_path.to_string_lossy(), &storage_config).await?;
+
+    if args.incremental {
+        out.info("Creating incremental backup...");
+        let result =
+            storage::incremental_backup::create_incremental_backup(&pool, &backup_dir).await;
+        pool.close().await;
+        return print_incremental(result?, out);
+    }
 
     out.info("Creating backup...");
     let result = storage::backup::create_backup(&pool, &backup_dir).await?;
@@ -69,6 +81,45 @@ pub async fn execute(args: BackupArgs, config_path: &str, out: CliOutput) -> any
     Ok(())
 }
 
+fn print_incremental(
+    result: storage::incremental_backup::IncrementalBackupResult,
+    out: CliOutput,
+) -> anyhow::Result<()> {
+    let manifest = &result.manifest;
+    if out.is_json() {
+        return out.json(&serde_json::json!({
+            "status": "success",
+            "path": result.backup.path.display().to_string(),
+            "size_bytes": result.backup.size_bytes,
+            "duration_ms": result.backup.duration_ms,
+            "manifest": manifest,
+            "new_chain_reason": result.new_chain_reason,
+        }));
+    }
+
+    match manifest.kind {
+        storage::incremental_backup::BackupKind::Full => {
+            if let Some(reason) = &result.new_chain_reason {
+                out.info(&format!("Starting a new backup chain: {reason}."));
+            }
+            out.info("Full base backup created successfully:");
+        }
+        storage::incremental_backup::BackupKind::Incremental => {
+            out.info("Incremental backup created successfully:");
+            out.info(&format!("  Base: {}", manifest.base_file));
+            out.info(&format!(
+                "  Changes: #{} to #{}",
+                manifest.from_seq + 1,
+                manifest.to_seq
+            ));
+        }
+    }
+    out.info(&format!("  Path: {}", result.backup.path.display()));
+    out.info(&format!("  Size: {} bytes", result.backup.size_bytes));
+    out.info(&format!("  Duration: {}ms", result.backup.duration_ms));
+    Ok(())
+}
+
 fn list_backups(data_dir: &std::path::Path, out: CliOutput) -> anyhow::Result<()> {
     let backup_dir = data_dir.join("backups");
     let backups = storage::backup::list_backups(&backup_dir);
@@ -140,6 +191,7 @@ mod tests {
             list: true,
             prune: Some(3),
             output_dir: None,
+            incremental: false,
         };
         assert!(args.list);
         assert!(args.prune.is_some());
@@ -151,6 +203,7 @@ mod tests {
             list: true,
             prune: None,
             output_dir: None,
+            incremental: false,
         };
         assert!(args.list);
         assert!(args.prune.is_none());
@@ -163,6 +216,7 @@ mod tests {
             list: false,
             prune: Some(5),
             output_dir: None,
+            incremental: false,
         };
         assert!(!args.list);
         assert_eq!(args.prune, Some(5));
@@ -174,6 +228,7 @@ mod tests {
             list: false,
             prune: None,
             output_dir: Some("/custom/backups".to_string()),
+            incremental: false,
         };
         assert_eq!(args.output_dir.as_deref(), Some("/custom/backups"));
     }
@@ -184,6 +239,7 @@ mod tests {
             list: true,
             prune: Some(3),
             output_dir: Some("/tmp/backups".to_string()),
+            incremental: false,
         };
         let debug = format!("{:?}", args);
         assert!(debug.contains("list: true"));
@@ -196,6 +252,7 @@ mod tests {
             list: false,
             prune: None,
             output_dir: None,
+            incremental: false,
         };
         // Default state: not list, not prune, no output_dir => create backup
         assert!(!args.list);
@@ -0,0 +1,179 @@
+//! Implementation of `tuitbot discover --once --score-only`.
+//!
+//! Runs one keyword search, scores the results, and prints a ranked list of
+//! candidates with their verdicts. Nothing is generated, posted, or written
+//! to the discovery queue, so it is a safe way to tune keywords and scoring.
+
+use serde::Serialize;
+use tuitbot_core::config::Config;
+use tuitbot_core::storage::{self, DbPool};
+use tuitbot_core::workflow::discover::{self, DiscoverInput};
+use tuitbot_core::workflow::ScoredCandidate;
+use tuitbot_core::x_api::XApiClient;
+
+use super::DiscoverArgs;
+use crate::deps::RuntimeDeps;
+use crate::output::{write_stdout, CliOutput};
+
+#[derive(Debug, Serialize)]
+struct DiscoverJson {
+    query: String,
+    threshold: f64,
+    would_reply: usize,
+    candidates: Vec<ScoredCandidate>,
+}
+
+/// Execute the `tuitbot discover` command.
+pub async fn execute(config: &Config, args: DiscoverArgs, out: CliOutput) -> anyhow::Result<()> {
+    if !args.score_only {
+        anyhow::bail!(
+            "discover: only `--once --score-only` is available; \
+             use `tuitbot tick --loops discovery` to run the reply pipeline"
+        );
+    }
+
+    let deps = RuntimeDeps::init(config, true).await?;
+    let report = score_once(&deps.pool, deps.dyn_client.as_ref(), config, args.limit).await;
+    deps.pool.close().await;
+    let report = report?;
+
+    if out.is_json() {
+        return out.json(&report);
+    }
+
+    out.info(&format!(
+        "Query: {}  (threshold {:.0}, {} of {} would get a reply)",
+        report.query,
+        report.threshold,
+        report.would_reply,
+        report.candidates.len()
+    ));
+    write_stdout(&format_table(&report.candidates))
+}
+
+/// Claim a search rate-limit slot, then search and score without persisting.
+async fn score_once(
+    pool: &DbPool,
+    x_client: &dyn XApiClient,
+    config: &Config,
+    limit: u32,
+) -> anyhow::Result<DiscoverJson> {
+    if !storage::rate_limits::check_and_increment_rate_limit(pool, "search").await? {
+        anyhow::bail!("Search rate limit reached; try again after the current window resets.");
+    }
+
+    let output = discover::preview(
+        pool,
+        x_client,
+        config,
+        DiscoverInput {
+            query: None,
+            min_score: None,
+            limit: Some(limit),
+            since_id: None,
+        },
+    )
+    .await?;
+
+    let would_reply = output
+        .candidates
+        .iter()
+        .filter(|c| (c.score_total as f64) >= output.threshold)
+        .count();
+
+    Ok(DiscoverJson {
+        query: output.query_used,
+        threshold: output.threshold,
+        would_reply,
+        candidates: output.candidates,
+    })
+}
+
+fn format_table(candidates: &[ScoredCandidate]) -> String {
+    if candidates.is_empty() {
+        return "No tweets found.".to_string();
+    }
+
+    let mut lines = vec![format!(
+        "{:>4}  {:>5}  {:<12}  {:<20}  {}",
+        "#", "SCORE", "VERDICT", "AUTHOR", "TEXT"
+    )];
+    for (i, c) in candidates.iter().enumerate() {
+        let text: String = c.text.replace('\n', " ").chars().take(60).collect();
+        lines.push(format!(
+            "{:>4}  {:>5.1}  {:<12}  {:<20}  {}",
+            i + 1,
+            c.score_total,
+            c.recommended_action,
+            format!("@{}", c.author_username),
+            text
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuitbot_core::storage::init_test_db;
+    use tuitbot_core::testing::{ConfigFixture, MockXClient, TweetFactory};
+
+    fn fixed_results() -> Vec<tuitbot_core::x_api::types::Tweet> {
+        vec![
+            TweetFactory::new()
+                .with_id("t1")
+                .with_text("Rust async runtimes compared: tokio vs smol")
+                .with_likes(120)
+                .build(),
+            TweetFactory::new()
+                .with_id("t2")
+                .with_text("Had a great lunch today")
+                .build(),
+        ]
+    }
+
+    #[tokio::test]
+    async fn score_once_ranks_fixed_results_without_posting() {
+        let pool = init_test_db().await.expect("init db");
+        let client = MockXClient::new();
+        client.set_search_tweets(fixed_results());
+        let config = ConfigFixture::default_config();
+
+        let report = score_once(&pool, &client, &config, 10)
+            .await
+            .expect("score");
+
+        assert_eq!(report.candidates.len(), 2);
+        assert!(report.candidates[0].score_total >= report.candidates[1].score_total);
+        assert_eq!(client.post_count(), 0);
+        assert!(storage::tweets::get_tweet_by_id(&pool, "t1")
+            .await
+            .expect("query")
+            .is_none());
+    }
+
+    #[tokio::test]
+    async fn score_once_respects_search_rate_limit() {
+        let pool = init_test_db().await.expect("init db");
+        sqlx::query(
+            "INSERT OR REPLACE INTO rate_limits \
+             (action_type, request_count, period_start, max_requests, period_seconds) \
+             VALUES ('search', 1, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'), 1, 900)",
+        )
+        .execute(&pool)
+        .await
+        .expect("seed limit");
+        let client = MockXClient::new();
+        client.set_search_tweets(fixed_results());
+
+        let err = score_once(&pool, &client, &ConfigFixture::default_config(), 10)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("rate limit"));
+    }
+
+    #[test]
+    fn format_table_handles_empty() {
+        assert_eq!(format_table(&[]), "No tweets found.");
+    }
+}
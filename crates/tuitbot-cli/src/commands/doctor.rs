@@ -1,164 +1,529 @@
 //! Implementation of the `tuitbot doctor` command.
 //!
-//! Runs a self-diagnosis checklist and prints a pass/fail report.
+//! Collects everything a support request usually asks for: config path,
+//! deployment mode, OAuth token state, database reachability and schema
+//! version, LLM provider reachability, X backend, free disk space, and the
+//! binary version. Read-only: the database is opened read-only and tokens
+//! are never refreshed. Secrets are reported only as present or absent.
+//!
 //! Exits 0 if all checks pass, 1 if any fail.
 
+use std::path::Path;
 use std::time::Duration;
 
-use tuitbot_core::config::Config;
-use tuitbot_core::startup::expand_tilde;
+use chrono::Utc;
+use serde::Serialize;
+use tuitbot_core::config::{Config, DeploymentMode};
+use tuitbot_core::llm::factory::create_provider;
+use tuitbot_core::safety::redact::scrub;
+use tuitbot_core::startup::{expand_tilde, token_file_path, token_store};
+use tuitbot_core::storage::health::read_schema_version;
+
+use super::auth_status::{self, AuthStatus, TokenState};
+use crate::output::{write_stdout, CliOutput};
 
+/// Free space below this on the database volume fails the disk check.
+const MIN_FREE_DISK_BYTES: u64 = 100 * 1024 * 1024;
+
+#[derive(Debug, Clone, Serialize)]
 struct Check {
+    label: &'static str,
     passed: bool,
     message: String,
 }
 
 impl Check {
-    fn pass(msg: impl Into<String>) -> Self {
+    fn pass(label: &'static str, msg: impl Into<String>) -> Self {
         Self {
+            label,
             passed: true,
             message: msg.into(),
         }
     }
 
-    fn fail(msg: impl Into<String>) -> Self {
+    fn fail(label: &'static str, msg: impl Into<String>) -> Self {
         Self {
+            label,
             passed: false,
             message: msg.into(),
         }
     }
+}
 
-    fn print(&self) {
-        let icon = if self.passed { '✓' } else { '✗' };
-        println!("  {} {}", icon, self.message);
-    }
+#[derive(Debug, Serialize)]
+struct DoctorReport {
+    version: &'static str,
+    config: ConfigSection,
+    /// Effective deployment mode; `None` when the config could not be loaded.
+    deployment_mode: Option<DeploymentMode>,
+    tokens: AuthStatus,
+    database: DatabaseSection,
+    llm: LlmSection,
+    x_api: XApiSection,
+    disk: DiskSection,
+    checks: Vec<Check>,
+}
+
+#[derive(Debug, Serialize)]
+struct ConfigSection {
+    path: String,
+    exists: bool,
+    valid: bool,
+    error: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct DatabaseSection {
+    path: Option<String>,
+    exists: bool,
+    reachable: bool,
+    /// Latest applied migration version.
+    schema_version: Option<i64>,
+    size_bytes: Option<u64>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct LlmSection {
+    provider: Option<String>,
+    model: Option<String>,
+    api_key_present: bool,
+    /// `None` until the provider has been probed.
+    reachable: Option<bool>,
+    error: Option<String>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct XApiSection {
+    /// `x_api` or `scraper`.
+    backend: Option<String>,
+    client_id_present: bool,
+    client_secret_present: bool,
+    /// Whether `api.x.com` answered; `None` until probed.
+    api_reachable: Option<bool>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct DiskSection {
+    /// Directory the free space was measured on.
+    path: Option<String>,
+    available_bytes: Option<u64>,
 }
 
 /// Execute `tuitbot doctor`.
 ///
-/// Loads and validates the config independently so we can report per-check
-/// failures instead of short-circuiting on the first error.
-pub async fn execute(config_path: &str) -> anyhow::Result<()> {
-    println!("tuitbot doctor");
-
-    let mut checks: Vec<Check> = Vec::new();
-
-    // ── 1. Config file exists and parses ─────────────────────────────────────
-    let config_opt = {
-        let expanded = expand_tilde(config_path);
-        let path_display = expanded.display().to_string();
-
-        match Config::load(Some(config_path)) {
-            Ok(cfg) => {
-                checks.push(Check::pass(format!(
-                    "Config file found and valid ({})",
-                    path_display
-                )));
-                Some(cfg)
-            }
-            Err(e) => {
-                checks.push(Check::fail(format!(
-                    "Config file invalid or missing ({path_display}): {e}"
-                )));
-                None
+/// Loads the config independently so per-check failures are reported
+/// instead of short-circuiting on the first error.
+pub async fn execute(config_path: &str, out: CliOutput) -> anyhow::Result<()> {
+    let (mut report, config) = collect(config_path, &token_file_path()).await;
+    if let Some(config) = &config {
+        probe_network(&mut report, config).await;
+    }
+    report.checks = evaluate(&report);
+
+    if out.is_json() {
+        out.json(&report)?;
+    } else {
+        write_stdout(&format_text(&report))?;
+    }
+
+    if report.checks.iter().all(|c| c.passed) {
+        Ok(())
+    } else {
+        std::process::exit(1);
+    }
+}
+
+/// Gather everything that can be read locally without touching the network.
+async fn collect(config_path: &str, token_path: &Path) -> (DoctorReport, Option<Config>) {
+    let expanded = expand_tilde(config_path);
+    let (config, error) = match Config::load(Some(config_path)) {
+        Ok(cfg) => (Some(cfg), None),
+        Err(e) => (None, Some(scrub(&e.to_string()))),
+    };
+
+    let mut report = DoctorReport {
+        version: env!("CARGO_PKG_VERSION"),
+        config: ConfigSection {
+            path: expanded.display().to_string(),
+            exists: expanded.is_file(),
+            valid: config.is_some(),
+            error,
+        },
+        deployment_mode: config.as_ref().map(|c| c.deployment_mode.clone()),
+        tokens: match &config {
+            Some(cfg) if cfg.auth.token_storage == "keychain" => {
+                auth_status::inspect_store(token_store(&cfg.auth).as_ref(), Utc::now()).0
             }
-        }
+            _ => auth_status::inspect(token_path, Utc::now()).0,
+        },
+        database: DatabaseSection::default(),
+        llm: LlmSection::default(),
+        x_api: XApiSection::default(),
+        disk: DiskSection::default(),
+        checks: Vec::new(),
     };
 
-    // ── 2. X API credentials ──────────────────────────────────────────────────
-    {
-        let present = config_opt
-            .as_ref()
-            .map(|cfg| {
-                !cfg.x_api.client_id.is_empty()
-                    || cfg
-                        .x_api
-                        .client_secret
-                        .as_deref()
-                        .is_some_and(|s| !s.is_empty())
-            })
-            .unwrap_or(false);
-
-        if present {
-            checks.push(Check::pass("X API credentials present"));
-        } else {
-            checks.push(Check::fail(
-                "X API credentials not configured (set x_api.client_id in config)",
-            ));
-        }
+    if let Some(cfg) = &config {
+        report.database = inspect_database(&cfg.storage.db_path).await;
+        report.disk = inspect_disk(&expand_tilde(cfg.storage.db_path.trim()));
+        report.llm = LlmSection {
+            provider: non_empty(&cfg.llm.provider),
+            model: non_empty(&cfg.llm.model),
+            api_key_present: cfg.llm.api_key.as_deref().is_some_and(|k| !k.is_empty()),
+            reachable: None,
+            error: None,
+        };
+        report.x_api = XApiSection {
+            backend: Some(backend_name(cfg).to_string()),
+            client_id_present: !cfg.x_api.client_id.is_empty(),
+            client_secret_present: cfg
+                .x_api
+                .client_secret
+                .as_deref()
+                .is_some_and(|s| !s.is_empty()),
+            api_reachable: None,
+        };
     }
 
-    // ── 3. LLM provider ──────────────────────────────────────────────────────
-    {
-        let configured = config_opt
-            .as_ref()
-            .map(|cfg| !cfg.llm.provider.is_empty())
-            .unwrap_or(false);
+    (report, config)
+}
 
-        if configured {
-            checks.push(Check::pass("LLM provider configured"));
-        } else {
-            checks.push(Check::fail(
-                "LLM provider not configured (set llm.provider in config)",
-            ));
+async fn inspect_database(db_path: &str) -> DatabaseSection {
+    let trimmed = db_path.trim();
+    if trimmed.is_empty() {
+        return DatabaseSection {
+            error: Some("storage.db_path is empty".to_string()),
+            ..Default::default()
+        };
+    }
+
+    let path = expand_tilde(trimmed);
+    let mut section = DatabaseSection {
+        path: Some(path.display().to_string()),
+        exists: path.is_file(),
+        ..Default::default()
+    };
+    if !section.exists {
+        return section;
+    }
+
+    section.size_bytes = std::fs::metadata(&path).ok().map(|m| m.len());
+    match read_schema_version(&path).await {
+        Ok(version) => {
+            section.reachable = true;
+            section.schema_version = Some(version);
         }
+        Err(e) => section.error = Some(e.to_string()),
     }
+    section
+}
 
-    // ── 4. Database path ──────────────────────────────────────────────────────
-    {
-        match config_opt.as_ref() {
-            Some(cfg) if !cfg.storage.db_path.is_empty() => {
-                let expanded = expand_tilde(&cfg.storage.db_path);
-                checks.push(Check::pass(format!(
-                    "Database path configured ({})",
-                    expanded.display()
-                )));
-            }
-            Some(_) => {
-                checks.push(Check::fail(
-                    "Database path not configured (set storage.db_path in config)",
-                ));
-            }
-            None => {
-                checks.push(Check::fail("Database path unknown (config not loaded)"));
+/// Free space on the volume holding `db_path`, measured on the nearest
+/// existing ancestor so a not-yet-created database still gets a reading.
+fn inspect_disk(db_path: &Path) -> DiskSection {
+    let Some(dir) = db_path.ancestors().skip(1).find(|p| p.is_dir()) else {
+        return DiskSection::default();
+    };
+    DiskSection {
+        path: Some(dir.display().to_string()),
+        available_bytes: fs2::available_space(dir).ok(),
+    }
+}
+
+/// Fill in the network-dependent fields: LLM health check and `api.x.com`.
+async fn probe_network(report: &mut DoctorReport, config: &Config) {
+    if report.llm.provider.is_some() {
+        match create_provider(&config.llm) {
+            Ok(provider) => match provider.health_check().await {
+                Ok(()) => report.llm.reachable = Some(true),
+                Err(e) => {
+                    report.llm.reachable = Some(false);
+                    report.llm.error = Some(scrub(&e.to_string()));
+                }
+            },
+            Err(e) => {
+                report.llm.reachable = Some(false);
+                report.llm.error = Some(scrub(&e.to_string()));
             }
         }
     }
 
-    // ── 5. Network: api.x.com ─────────────────────────────────────────────────
-    {
-        let client = reqwest::Client::builder()
+    if backend_name(config) == "x_api" {
+        let reachable = match reqwest::Client::builder()
             .timeout(Duration::from_secs(5))
-            .build();
-
-        let reachable = match client {
-            Ok(c) => c
-                .head("https://api.x.com")
-                .send()
-                .await
-                .map(|_| true)
-                .unwrap_or(false),
+            .build()
+        {
+            Ok(c) => c.head("https://api.x.com").send().await.is_ok(),
             Err(_) => false,
         };
+        report.x_api.api_reachable = Some(reachable);
+    }
+}
+
+/// Turn the collected report into pass/fail checks.
+fn evaluate(report: &DoctorReport) -> Vec<Check> {
+    let mut checks = Vec::new();
+
+    checks.push(match (&report.config.error, report.config.exists) {
+        (None, _) => Check::pass("Config", format!("valid ({})", report.config.path)),
+        (Some(e), true) => Check::fail("Config", format!("invalid: {e}")),
+        (Some(_), false) => Check::fail(
+            "Config",
+            format!("not found at {} (run `tuitbot init`)", report.config.path),
+        ),
+    });
+    if !report.config.valid {
+        return checks;
+    }
+
+    let scraper = report.x_api.backend.as_deref() == Some("scraper");
+    checks.push(if scraper || report.x_api.client_id_present {
+        Check::pass(
+            "X backend",
+            report.x_api.backend.clone().unwrap_or_default(),
+        )
+    } else {
+        Check::fail(
+            "X backend",
+            "x_api selected but x_api.client_id is not configured",
+        )
+    });
 
-        if reachable {
-            checks.push(Check::pass("Network: api.x.com reachable"));
-        } else {
-            checks.push(Check::fail(
-                "Network: api.x.com unreachable (check internet connection)",
-            ));
+    let tokens = &report.tokens;
+    checks.push(match tokens.state {
+        TokenState::Valid => Check::pass("Tokens", "valid"),
+        TokenState::Expired if tokens.has_refresh_token => {
+            Check::pass("Tokens", "expired, will refresh on next use")
         }
+        _ if scraper => Check::pass("Tokens", "not required for the scraper backend"),
+        TokenState::Missing => Check::fail("Tokens", "none stored (run `tuitbot auth`)"),
+        state => Check::fail("Tokens", state.label()),
+    });
+
+    let db = &report.database;
+    checks.push(match (&db.path, db.exists, db.reachable) {
+        (None, _, _) => Check::fail("Database", "storage.db_path is empty"),
+        (Some(path), false, _) => Check::pass("Database", format!("will be created at {path}")),
+        (Some(_), true, true) => Check::pass(
+            "Database",
+            format!("schema version {}", db.schema_version.unwrap_or(0)),
+        ),
+        (Some(_), true, false) => Check::fail(
+            "Database",
+            format!(
+                "unreachable: {}",
+                db.error.as_deref().unwrap_or("unknown error")
+            ),
+        ),
+    });
+
+    let llm = &report.llm;
+    checks.push(match (&llm.provider, llm.reachable) {
+        (None, _) => Check::fail("LLM", "llm.provider not configured"),
+        (Some(p), Some(false)) => Check::fail(
+            "LLM",
+            format!("{p}: {}", llm.error.as_deref().unwrap_or("unreachable")),
+        ),
+        (Some(p), Some(true)) => Check::pass("LLM", format!("{p}: reachable")),
+        (Some(p), None) => Check::pass("LLM", format!("{p}: configured (not probed)")),
+    });
+
+    if let Some(false) = report.x_api.api_reachable {
+        checks.push(Check::fail(
+            "Network",
+            "api.x.com unreachable (check internet connection)",
+        ));
     }
 
-    // ── Print results ─────────────────────────────────────────────────────────
-    for check in &checks {
-        check.print();
+    checks.push(match report.disk.available_bytes {
+        Some(free) if free >= MIN_FREE_DISK_BYTES => {
+            Check::pass("Disk", format!("{} free", format_bytes(free)))
+        }
+        Some(free) => Check::fail("Disk", format!("only {} free", format_bytes(free))),
+        None => Check::fail("Disk", "could not determine free space"),
+    });
+
+    checks
+}
+
+fn format_text(report: &DoctorReport) -> String {
+    let mut lines = vec![format!("tuitbot doctor (v{})", report.version)];
+    lines.push(format!("  Config:          {}", report.config.path));
+    if let Some(mode) = &report.deployment_mode {
+        lines.push(format!(
+            "  Deployment mode: {}",
+            serde_json::to_value(mode)
+                .ok()
+                .and_then(|v| v.as_str().map(str::to_string))
+                .unwrap_or_default()
+        ));
+    }
+    lines.push(format!("  Token file:      {}", report.tokens.token_path));
+    if let Some(path) = &report.database.path {
+        lines.push(format!("  Database:        {path}"));
+    }
+    lines.push(String::new());
+    for check in &report.checks {
+        let icon = if check.passed { '✓' } else { '✗' };
+        lines.push(format!("  {icon} {:<10} {}", check.label, check.message));
     }
+    lines.join("\n")
+}
 
-    let all_passed = checks.iter().all(|c| c.passed);
-    if all_passed {
-        Ok(())
+/// The selected X backend; an empty setting means the official API.
+fn backend_name(config: &Config) -> &str {
+    match config.x_api.provider_backend.as_str() {
+        "" => "x_api",
+        other => other,
+    }
+}
+
+fn non_empty(s: &str) -> Option<String> {
+    (!s.is_empty()).then(|| s.to_string())
+}
+
+fn format_bytes(bytes: u64) -> String {
+    let gib = bytes as f64 / (1024.0 * 1024.0 * 1024.0);
+    if gib >= 1.0 {
+        format!("{gib:.1} GiB")
     } else {
-        std::process::exit(1);
+        format!("{:.0} MiB", bytes as f64 / (1024.0 * 1024.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    /// Lay out `<home>/.tuitbot/` with a config, tokens, and a migrated DB.
+    async fn configured_home(home: &Path) -> (PathBuf, PathBuf) {
+        let dir = home.join(".tuitbot");
+        std::fs::create_dir_all(&dir).unwrap();
+        let db_path = dir.join("tuitbot.db");
+        let config_path = dir.join("config.toml");
+        std::fs::write(
+            &config_path,
+            format!(
+                r#"
+deployment_mode = "self_host"
+
+[x_api]
+client_id = "client-123"
+client_secret = "super-secret-value"
+
+[llm]
+provider = "openai"
+api_key = "sk-secret-key"
+model = "gpt-4o-mini"
+
+[storage]
+db_path = "{}"
+"#,
+                db_path.display()
+            ),
+        )
+        .unwrap();
+
+        let pool = tuitbot_core::storage::init_db(&db_path.to_string_lossy())
+            .await
+            .unwrap();
+        pool.close().await;
+
+        let token_path = dir.join("tokens.json");
+        std::fs::write(
+            &token_path,
+            serde_json::json!({
+                "access_token": "access-secret",
+                "refresh_token": "refresh-secret",
+                "expires_at": (Utc::now() + chrono::Duration::hours(1)).to_rfc3339(),
+                "scopes": ["tweet.read"],
+            })
+            .to_string(),
+        )
+        .unwrap();
+        (config_path, token_path)
+    }
+
+    async fn report_json(config_path: &Path, token_path: &Path) -> serde_json::Value {
+        let (mut report, _) = collect(&config_path.to_string_lossy(), token_path).await;
+        report.checks = evaluate(&report);
+        serde_json::to_value(&report).unwrap()
+    }
+
+    #[tokio::test]
+    async fn configured_home_reports_every_section() {
+        let home = tempfile::tempdir().unwrap();
+        let (config_path, token_path) = configured_home(home.path()).await;
+
+        let json = report_json(&config_path, &token_path).await;
+
+        assert_eq!(json["version"], env!("CARGO_PKG_VERSION"));
+        assert_eq!(json["config"]["exists"], true);
+        assert_eq!(json["config"]["valid"], true);
+        assert_eq!(json["deployment_mode"], "self_host");
+        assert_eq!(json["tokens"]["state"], "valid");
+        assert_eq!(json["database"]["exists"], true);
+        assert_eq!(json["database"]["reachable"], true);
+        assert!(json["database"]["schema_version"].as_i64().unwrap() > 0);
+        assert_eq!(json["llm"]["provider"], "openai");
+        assert_eq!(json["llm"]["api_key_present"], true);
+        assert!(json["llm"]["reachable"].is_null());
+        assert_eq!(json["x_api"]["backend"], "x_api");
+        assert_eq!(json["x_api"]["client_secret_present"], true);
+        assert!(json["disk"]["available_bytes"].is_u64());
+
+        let labels: Vec<&str> = json["checks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|c| c["label"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            labels,
+            vec!["Config", "X backend", "Tokens", "Database", "LLM", "Disk"]
+        );
+
+        let text = json.to_string();
+        for secret in [
+            "super-secret-value",
+            "sk-secret-key",
+            "access-secret",
+            "refresh-secret",
+        ] {
+            assert!(!text.contains(secret), "leaked {secret}");
+        }
+    }
+
+    #[tokio::test]
+    async fn bare_home_reports_missing_config_without_creating_files() {
+        let home = tempfile::tempdir().unwrap();
+        let config_path = home.path().join(".tuitbot").join("config.toml");
+        let token_path = home.path().join(".tuitbot").join("tokens.json");
+
+        let json = report_json(&config_path, &token_path).await;
+
+        assert_eq!(json["config"]["exists"], false);
+        assert_eq!(json["config"]["valid"], false);
+        assert!(json["config"]["error"].is_string());
+        assert!(json["deployment_mode"].is_null());
+        assert_eq!(json["tokens"]["state"], "missing");
+        assert!(json["database"]["path"].is_null());
+        assert!(json["llm"]["provider"].is_null());
+        assert!(json["x_api"]["backend"].is_null());
+
+        let checks = json["checks"].as_array().unwrap();
+        assert_eq!(checks.len(), 1);
+        assert_eq!(checks[0]["passed"], false);
+        assert!(!home.path().join(".tuitbot").exists());
+    }
+
+    #[test]
+    fn disk_is_measured_on_nearest_existing_ancestor() {
+        let dir = tempfile::tempdir().unwrap();
+        let disk = inspect_disk(&dir.path().join("not").join("yet").join("tuitbot.db"));
+        assert_eq!(disk.path, Some(dir.path().display().to_string()));
+        assert!(disk.available_bytes.is_some());
     }
 }
@@ -0,0 +1,93 @@
+//! Implementation of the global `--dump-effective-config` flag.
+//!
+//! Prints the configuration exactly as commands see it: built-in defaults,
+//! then `config.toml`, then `TUITBOT_*` environment overrides. Secrets are
+//! redacted. Output is TOML, or JSON with `--output json`.
+
+use anyhow::Context;
+use tuitbot_core::config::Config;
+
+use crate::output::{write_stdout, CliOutput};
+
+/// Load, resolve, and print the effective configuration.
+pub fn execute(config_path: &str, out: CliOutput) -> anyhow::Result<()> {
+    write_stdout(&render(config_path, out.is_json())?)
+}
+
+fn render(config_path: &str, json: bool) -> anyhow::Result<String> {
+    let config = Config::load(Some(config_path)).context("Failed to load configuration")?;
+    let redacted = config.redacted();
+    if json {
+        Ok(serde_json::to_string_pretty(&redacted)?)
+    } else {
+        toml::to_string_pretty(&redacted).context("Failed to serialize configuration as TOML")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::ffi::OsString;
+    use std::sync::{Mutex, OnceLock};
+
+    // Environment variables are process-global, so tests that mutate them must not run concurrently.
+    fn with_locked_env(test: impl FnOnce()) {
+        static ENV_LOCK: OnceLock<Mutex<()>> = OnceLock::new();
+        let _guard = ENV_LOCK
+            .get_or_init(|| Mutex::new(()))
+            .lock()
+            .expect("env lock poisoned");
+        test();
+    }
+
+    struct ScopedEnvVar {
+        key: &'static str,
+        previous: Option<OsString>,
+    }
+
+    impl ScopedEnvVar {
+        fn set(key: &'static str, value: &str) -> Self {
+            let previous = env::var_os(key);
+            env::set_var(key, value);
+            Self { key, previous }
+        }
+    }
+
+    impl Drop for ScopedEnvVar {
+        fn drop(&mut self) {
+            match self.previous.take() {
+                Some(previous) => env::set_var(self.key, previous),
+                None => env::remove_var(self.key),
+            }
+        }
+    }
+
+    #[test]
+    fn dump_reflects_env_overrides_and_masks_secrets() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join("config.toml");
+        std::fs::write(
+            &path,
+            "[llm]\nprovider = \"openai\"\nmodel = \"gpt-4o-mini\"\napi_key = \"sk-from-file-123456\"\n",
+        )
+        .expect("write config");
+        let path = path.to_str().expect("utf-8 path");
+
+        with_locked_env(|| {
+            let _model = ScopedEnvVar::set("TUITBOT_LLM__MODEL", "gpt-4o-from-env");
+            let toml_out = render(path, false).expect("render toml");
+            assert!(
+                toml_out.contains("model = \"gpt-4o-from-env\""),
+                "{toml_out}"
+            );
+            assert!(!toml_out.contains("sk-from-file-123456"));
+            assert!(toml_out.contains("api_key = \"***REDACTED***\""));
+
+            let json_out = render(path, true).expect("render json");
+            let json: serde_json::Value = serde_json::from_str(&json_out).expect("valid json");
+            assert_eq!(json["llm"]["model"], "gpt-4o-from-env");
+            assert_eq!(json["llm"]["api_key"], "***REDACTED***");
+        });
+    }
+}
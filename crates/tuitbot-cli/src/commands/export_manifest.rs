@@ -0,0 +1,213 @@
+//! Implementation of `tuitbot export-manifest`.
+//!
+//! Emits one machine-readable description of everything the binary exposes:
+//! MCP tools per profile, HTTP API routes, CLI subcommands, and config
+//! fields with their types and defaults. Intended for documentation and
+//! integration tests; `--output json` prints the full manifest.
+
+use serde::Serialize;
+use serde_json::Value;
+use tuitbot_core::config::settings_schema;
+use tuitbot_core::route_manifest::{api_routes, RouteEntry};
+use tuitbot_mcp::{generate_profile_manifest, Profile, ProfileManifest};
+
+use crate::output::{write_stdout, CliOutput};
+
+/// Every MCP runtime profile, in the order they are documented.
+const PROFILES: [Profile; 6] = [
+    Profile::Readonly,
+    Profile::ApiReadonly,
+    Profile::Write,
+    Profile::Admin,
+    Profile::UtilityReadonly,
+    Profile::UtilityWrite,
+];
+
+#[derive(Debug, Serialize)]
+struct Manifest {
+    tuitbot_version: String,
+    mcp_profiles: Vec<ProfileManifest>,
+    http_routes: Vec<RouteEntry>,
+    cli_commands: Vec<CliCommandEntry>,
+    config_fields: Vec<ConfigField>,
+}
+
+#[derive(Debug, Serialize)]
+struct CliCommandEntry {
+    /// Space-separated subcommand path, e.g. `mcp manifest`.
+    command: String,
+    about: Option<String>,
+    /// Long flags (`--name`) and positional argument names.
+    args: Vec<String>,
+    hidden: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct ConfigField {
+    /// Dotted TOML key, e.g. `limits.max_replies_per_day`.
+    key: String,
+    /// JSON type of the field: string, integer, number, boolean, array, or
+    /// object. Optional fields unset by default report their declared type.
+    #[serde(rename = "type")]
+    kind: String,
+    /// Default value; `null` when the field is optional and unset.
+    default: Value,
+}
+
+/// Execute the `tuitbot export-manifest` command.
+///
+/// `cli` is the root clap command, passed in so subcommands are read from
+/// the same definition the binary parses with.
+pub fn execute(cli: clap::Command, out: CliOutput) -> anyhow::Result<()> {
+    let manifest = build(cli)?;
+
+    if out.is_json() {
+        return out.json(&manifest);
+    }
+
+    let tools: usize = manifest.mcp_profiles.iter().map(|p| p.tool_count).sum();
+    write_stdout(&format!(
+        "tuitbot {}\n  MCP profiles:   {} ({} tools total)\n  HTTP routes:    {}\n  CLI commands:   {}\n  Config fields:  {}\n\nUse --output json for the full manifest.",
+        manifest.tuitbot_version,
+        manifest.mcp_profiles.len(),
+        tools,
+        manifest.http_routes.len(),
+        manifest.cli_commands.len(),
+        manifest.config_fields.len(),
+    ))
+}
+
+fn build(cli: clap::Command) -> anyhow::Result<Manifest> {
+    let mut cli_commands = Vec::new();
+    for sub in cli.get_subcommands() {
+        collect_commands(sub, "", &mut cli_commands);
+    }
+
+    let config_fields = settings_schema()
+        .into_iter()
+        .map(|f| ConfigField {
+            key: f.path,
+            kind: f.kind,
+            default: f.default.unwrap_or(Value::Null),
+        })
+        .collect();
+
+    Ok(Manifest {
+        tuitbot_version: env!("CARGO_PKG_VERSION").to_string(),
+        mcp_profiles: PROFILES
+            .into_iter()
+            .map(generate_profile_manifest)
+            .collect(),
+        http_routes: api_routes(),
+        cli_commands,
+        config_fields,
+    })
+}
+
+fn collect_commands(cmd: &clap::Command, parent: &str, out: &mut Vec<CliCommandEntry>) {
+    let command = if parent.is_empty() {
+        cmd.get_name().to_string()
+    } else {
+        format!("{parent} {}", cmd.get_name())
+    };
+    let args = cmd
+        .get_arguments()
+        .filter(|a| !a.is_global_set())
+        .map(|a| match a.get_long() {
+            Some(long) => format!("--{long}"),
+            None => a.get_id().to_string(),
+        })
+        .collect();
+    out.push(CliCommandEntry {
+        command: command.clone(),
+        about: cmd.get_about().map(|s| s.to_string()),
+        args,
+        hidden: cmd.is_hide_set(),
+    });
+    for sub in cmd.get_subcommands() {
+        collect_commands(sub, &command, out);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+
+    /// Golden check: well-known entries from each section must be present so
+    /// that renames or removals show up as a test failure.
+    #[test]
+    fn manifest_contains_key_tools_routes_commands_and_fields() {
+        let manifest = build(crate::Cli::command()).expect("build manifest");
+        let json = serde_json::to_value(&manifest).expect("serialize");
+
+        let profiles: Vec<&str> = json["mcp_profiles"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["profile"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            profiles,
+            vec![
+                "readonly",
+                "api-readonly",
+                "write",
+                "admin",
+                "utility-readonly",
+                "utility-write"
+            ]
+        );
+        let write_tools: Vec<&str> = manifest.mcp_profiles[2]
+            .tools
+            .iter()
+            .map(|t| t.name.as_str())
+            .collect();
+        for tool in ["get_tweet_by_id", "x_search_tweets", "x_post_tweet"] {
+            assert!(write_tools.contains(&tool), "missing MCP tool {tool}");
+        }
+
+        for (method, path) in [
+            ("GET", "/api/health"),
+            ("POST", "/api/auth/login"),
+            ("GET", "/api/settings"),
+            ("GET", "/api/ws"),
+        ] {
+            assert!(
+                manifest
+                    .http_routes
+                    .iter()
+                    .any(|r| r.method == method && r.path == path),
+                "missing route {method} {path}"
+            );
+        }
+
+        for command in ["run", "tick", "mcp manifest", "settings", "export-manifest"] {
+            assert!(
+                manifest.cli_commands.iter().any(|c| c.command == command),
+                "missing CLI command {command}"
+            );
+        }
+
+        let field = |key: &str| {
+            manifest
+                .config_fields
+                .iter()
+                .find(|f| f.key == key)
+                .unwrap_or_else(|| panic!("missing config field {key}"))
+        };
+        assert_eq!(field("limits.max_replies_per_day").kind, "integer");
+        assert_eq!(field("limits.max_replies_per_day").default, 5);
+        assert_eq!(field("limits.banned_phrases").kind, "array");
+        assert_eq!(field("llm.provider").kind, "string");
+        assert_eq!(field("approval_mode").kind, "boolean");
+
+        let unset: Vec<&str> = manifest
+            .config_fields
+            .iter()
+            .filter(|f| f.kind == "null")
+            .map(|f| f.key.as_str())
+            .collect();
+        assert!(unset.is_empty(), "untyped config fields: {unset:?}");
+    }
+}
@@ -11,6 +11,7 @@ mod display;
 mod helpers;
 mod prompts;
 mod render;
+mod replyguy;
 mod steps;
 mod wizard;
 
@@ -19,7 +20,7 @@ mod tests;
 
 use std::fs;
 use std::io::IsTerminal;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::time::Instant;
 
 use anyhow::{bail, Context, Result};
@@ -56,6 +57,7 @@ pub async fn execute(
     force: bool,
     non_interactive: bool,
     advanced: bool,
+    from_replyguy: Option<PathBuf>,
     out: CliOutput,
 ) -> Result<()> {
     if non_interactive && advanced {
@@ -85,6 +87,10 @@ pub async fn execute(
         return Ok(());
     }
 
+    if let Some(source) = from_replyguy {
+        return import_replyguy(&dir, &config_path, &source, out);
+    }
+
     if non_interactive {
         return write_template(&dir, &config_path, out);
     }
@@ -129,6 +135,41 @@ fn write_template(dir: &PathBuf, config_path: &PathBuf, out: CliOutput) -> Resul
     Ok(())
 }
 
+/// `--from-replyguy` path: convert a ReplyGuy config and write it.
+fn import_replyguy(dir: &Path, config_path: &Path, source: &Path, out: CliOutput) -> Result<()> {
+    let content = fs::read_to_string(source)
+        .with_context(|| format!("Failed to read {}", source.display()))?;
+    let imported = replyguy::convert(&content)?;
+
+    fs::create_dir_all(dir)?;
+    fs::write(config_path, &imported.toml)
+        .with_context(|| format!("Failed to write {}", config_path.display()))?;
+
+    if out.is_json() {
+        out.json(&serde_json::json!({
+            "status": "created",
+            "path": config_path.display().to_string(),
+            "source": source.display().to_string(),
+            "warnings": imported.warnings,
+        }))?;
+    } else {
+        out.info(&format!(
+            "Imported {} into {}",
+            source.display(),
+            config_path.display()
+        ));
+        for warning in &imported.warnings {
+            out.info(&format!("  warning: {warning}"));
+        }
+        out.info("\nNext steps:");
+        out.info("  1. tuitbot test    — validate configuration");
+        out.info("  2. tuitbot auth    — authenticate with X");
+        out.info("  3. tuitbot run     — start the agent");
+    }
+
+    Ok(())
+}
+
 /// Validate the LLM provider is reachable (non-blocking — continues on failure).
 async fn validate_llm(result: &WizardResult) {
     let llm_config = LlmConfig {
@@ -136,6 +177,7 @@ async fn validate_llm(result: &WizardResult) {
         api_key: result.llm_api_key.clone(),
         model: result.llm_model.clone(),
         base_url: result.llm_base_url.clone(),
+        ..Default::default()
     };
 
     let provider = match create_provider(&llm_config) {
@@ -266,7 +308,7 @@ async fn chain_post_config(config: &Config, config_path_str: &str) -> Result<()>
         return Ok(());
     }
 
-    if let Err(e) = auth::execute(config, None).await {
+    if let Err(e) = auth::execute(config, None, None).await {
         eprintln!("\nAuth failed: {e:#}");
         print_remaining_steps(&[
             "tuitbot auth           — retry authentication",
@@ -316,6 +358,8 @@ async fn chain_post_config(config: &Config, config_path_str: &str) -> Result<()>
         ignore_schedule: true,
         loops: Some(vec!["discovery".into(), "content".into()]),
         require_approval: false,
+        no_llm: false,
+        fail_on_no_op: false,
     };
 
     let text_out = CliOutput::new(false, super::OutputFormat::Text);
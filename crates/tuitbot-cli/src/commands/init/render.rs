@@ -174,6 +174,7 @@ max_threads_per_week = 1
 min_action_delay_seconds = 45
 max_action_delay_seconds = 180
 max_replies_per_author_per_day = 1
+max_replies_per_conversation_per_day = 2
 product_mention_ratio = 0.2
 banned_phrases = ["check out", "you should try", "I recommend", "link in bio"]
 
@@ -0,0 +1,226 @@
+//! `tuitbot init --from-replyguy` — import a ReplyGuy config.
+//!
+//! ReplyGuy configs use the same section layout as Tuitbot for the settings
+//! the two share. Keys in those sections are copied when Tuitbot accepts
+//! them (same name, compatible value); everything else is reported as a
+//! warning rather than silently dropped. Tuitbot-only sections are appended
+//! as commented placeholders showing their defaults.
+
+use anyhow::{Context, Result};
+use toml::{Table, Value};
+use tuitbot_core::config::Config;
+
+/// Sections ReplyGuy and Tuitbot have in common, in output order.
+const SHARED_SECTIONS: &[&str] = &[
+    "x_api",
+    "business",
+    "scoring",
+    "limits",
+    "intervals",
+    "llm",
+    "storage",
+];
+
+/// A converted config plus the ReplyGuy settings that could not be carried over.
+#[derive(Debug)]
+pub(super) struct Imported {
+    pub toml: String,
+    pub warnings: Vec<String>,
+}
+
+/// Convert ReplyGuy config TOML into a Tuitbot config file.
+pub(super) fn convert(source: &str) -> Result<Imported> {
+    let source: Table = toml::from_str(source).context("ReplyGuy config is not valid TOML")?;
+
+    let mut mapped = Table::new();
+    let mut warnings = Vec::new();
+
+    for (section, value) in &source {
+        if !SHARED_SECTIONS.contains(&section.as_str()) {
+            warnings.push(format!("[{section}] has no Tuitbot equivalent; skipped"));
+            continue;
+        }
+        let Some(fields) = value.as_table() else {
+            warnings.push(format!("{section} is not a table; skipped"));
+            continue;
+        };
+
+        let mut kept = Table::new();
+        for (key, value) in fields {
+            match accepts(section, key, value) {
+                Ok(()) => {
+                    kept.insert(key.clone(), value.clone());
+                }
+                Err(reason) => warnings.push(format!("{section}.{key} {reason}; skipped")),
+            }
+        }
+        if !kept.is_empty() {
+            mapped.insert(section.clone(), Value::Table(kept));
+        }
+    }
+
+    let toml = render(&mapped)?;
+    toml::from_str::<Config>(&toml).context("Converted config does not parse")?;
+
+    Ok(Imported { toml, warnings })
+}
+
+/// Check that Tuitbot knows `section.key` and accepts `value` for it.
+///
+/// Serde ignores unknown keys, so a key is only known if it survives a
+/// round trip through `Config`.
+fn accepts(section: &str, key: &str, value: &Value) -> Result<(), &'static str> {
+    let mut fields = Table::new();
+    fields.insert(key.to_string(), value.clone());
+    let mut probe = Table::new();
+    probe.insert(section.to_string(), Value::Table(fields));
+
+    let config: Config = Value::Table(probe)
+        .try_into()
+        .map_err(|_| "has a value Tuitbot does not accept")?;
+    let round_trip = Value::try_from(&config).map_err(|_| "could not be converted")?;
+    round_trip
+        .get(section)
+        .and_then(|s| s.get(key))
+        .map(|_| ())
+        .ok_or("has no Tuitbot equivalent")
+}
+
+/// Render the imported sections followed by commented Tuitbot-only defaults.
+fn render(mapped: &Table) -> Result<String> {
+    let defaults = Value::try_from(Config::default()).context("Failed to serialize defaults")?;
+    let defaults = defaults.as_table().cloned().unwrap_or_default();
+
+    let mut out = String::from(
+        "# Imported from a ReplyGuy config by `tuitbot init --from-replyguy`.\n\
+         # Review the imported values, then uncomment and adjust the\n\
+         # Tuitbot-only settings below as needed.\n\n",
+    );
+
+    // Top-level Tuitbot settings must precede every table to stay valid
+    // once uncommented.
+    let scalars: Table = defaults
+        .iter()
+        .filter(|(_, v)| !v.is_table())
+        .map(|(k, v)| (k.clone(), v.clone()))
+        .collect();
+    out.push_str(&commented(&toml::to_string(&scalars)?));
+    out.push('\n');
+
+    for section in SHARED_SECTIONS {
+        if let Some(value) = mapped.get(*section) {
+            out.push_str(&single_section(section, value)?);
+            out.push('\n');
+        }
+    }
+
+    for (section, value) in &defaults {
+        if value.is_table() && !SHARED_SECTIONS.contains(&section.as_str()) {
+            out.push_str(&commented(&single_section(section, value)?));
+            out.push('\n');
+        }
+    }
+
+    Ok(out)
+}
+
+fn single_section(name: &str, value: &Value) -> Result<String> {
+    let mut table = Table::new();
+    table.insert(name.to_string(), value.clone());
+    Ok(toml::to_string(&table)?)
+}
+
+fn commented(toml: &str) -> String {
+    toml.lines()
+        .map(|line| {
+            if line.is_empty() {
+                "\n".to_string()
+            } else {
+                format!("# {line}\n")
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+[x_api]
+client_id = "rg-client"
+client_secret = "rg-secret"
+bearer_token = "legacy"
+
+[business]
+product_name = "ReplyGuy"
+product_keywords = ["replies", "growth"]
+
+[scoring]
+threshold = 70
+
+[limits]
+max_replies_per_day = 15
+
+[intervals]
+mentions_check_seconds = 600
+
+[llm]
+provider = "openai"
+api_key = "sk-test"
+model = "gpt-4o-mini"
+
+[storage]
+db_path = "~/.replyguy/replyguy.db"
+
+[telemetry]
+enabled = true
+"#;
+
+    #[test]
+    fn converts_sample_into_valid_tuitbot_config() {
+        let imported = convert(SAMPLE).expect("convert");
+        let config: Config = toml::from_str(&imported.toml).expect("output parses");
+
+        assert_eq!(config.x_api.client_id, "rg-client");
+        assert_eq!(config.x_api.client_secret.as_deref(), Some("rg-secret"));
+        assert_eq!(config.business.product_name, "ReplyGuy");
+        assert_eq!(config.business.product_keywords, vec!["replies", "growth"]);
+        assert_eq!(config.scoring.threshold, 70);
+        assert_eq!(config.limits.max_replies_per_day, 15);
+        assert_eq!(config.intervals.mentions_check_seconds, 600);
+        assert_eq!(config.llm.api_key.as_deref(), Some("sk-test"));
+        assert_eq!(config.storage.db_path, "~/.replyguy/replyguy.db");
+    }
+
+    #[test]
+    fn warns_about_unmapped_fields() {
+        let mut imported = convert(SAMPLE).expect("convert");
+        imported.warnings.sort();
+        assert_eq!(
+            imported.warnings,
+            vec![
+                "[telemetry] has no Tuitbot equivalent; skipped",
+                "x_api.bearer_token has no Tuitbot equivalent; skipped",
+            ]
+        );
+    }
+
+    #[test]
+    fn invalid_values_are_skipped_with_a_warning() {
+        let imported = convert("[scoring]\nthreshold = \"high\"\n").expect("convert");
+        assert!(!imported.toml.contains("threshold = \"high\""));
+        assert_eq!(
+            imported.warnings,
+            vec!["scoring.threshold has a value Tuitbot does not accept; skipped"]
+        );
+    }
+
+    #[test]
+    fn tuitbot_only_sections_are_commented_placeholders() {
+        let imported = convert(SAMPLE).expect("convert");
+        assert!(imported.toml.contains("# [schedule]"));
+        assert!(!imported.toml.contains("\n[schedule]"));
+        assert!(imported.toml.contains("\n[business]"));
+    }
+}
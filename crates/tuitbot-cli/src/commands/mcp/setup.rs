@@ -93,7 +93,7 @@ pub async fn run_setup(out: crate::output::CliOutput) -> Result<()> {
         let config =
             Config::load(Some(&config_str)).context("Failed to load config after writing")?;
 
-        if let Err(e) = auth::execute(&config, None).await {
+        if let Err(e) = auth::execute(&config, None, None).await {
             eprintln!("\nAuth failed: {e:#}");
             eprintln!("{}", dim.apply_to("You can retry later with: tuitbot auth"));
             eprintln!();
@@ -0,0 +1,299 @@
+//! Implementation of `tuitbot mentions --once`.
+//!
+//! Fetches recent mentions, drops the ones already replied to, and prints the
+//! rest with a suggested reply archetype. Nothing is generated, posted, or
+//! written to the mention cursor, so it is a safe inbox view before enabling
+//! the mentions loop.
+
+use serde::Serialize;
+use tuitbot_core::automation::{LoopError, LoopTweet, MentionsFetcher};
+use tuitbot_core::config::Config;
+use tuitbot_core::content::ReplyArchetype;
+use tuitbot_core::safety::DedupChecker;
+use tuitbot_core::storage::{self, DbPool};
+
+use super::MentionsArgs;
+use crate::deps::RuntimeDeps;
+use crate::output::{write_stdout, CliOutput};
+
+#[derive(Debug, Serialize)]
+struct PendingMention {
+    id: String,
+    author_username: String,
+    text: String,
+    created_at: String,
+    /// Snake-case archetype name, as accepted by `archetype` workflow inputs.
+    suggested_archetype: String,
+}
+
+#[derive(Debug, Serialize)]
+struct MentionsJson {
+    fetched: usize,
+    already_replied: usize,
+    pending: Vec<PendingMention>,
+}
+
+/// Execute the `tuitbot mentions` command.
+pub async fn execute(config: &Config, args: MentionsArgs, out: CliOutput) -> anyhow::Result<()> {
+    if !args.once {
+        anyhow::bail!(
+            "mentions: only `--once` is available; \
+             use `tuitbot tick --loops mentions` to run the reply pipeline"
+        );
+    }
+
+    // Read-only preview: no LLM is needed.
+    let deps = RuntimeDeps::init_without_llm(config, true).await?;
+    if !deps.capabilities.mentions {
+        deps.pool.close().await;
+        anyhow::bail!(
+            "Mentions are not available in {} mode; an official X API connection is required.",
+            deps.tier
+        );
+    }
+    let report = preview_once(&deps.pool, deps.mentions_fetcher.as_ref(), args.limit).await;
+    deps.pool.close().await;
+    let report = report?;
+
+    if out.is_json() {
+        return out.json(&report);
+    }
+
+    out.info(&format!(
+        "{} mention(s) fetched, {} already replied to, {} pending",
+        report.fetched,
+        report.already_replied,
+        report.pending.len()
+    ));
+    write_stdout(&format_table(&report.pending))
+}
+
+/// Claim a mention-check rate-limit slot, then fetch and filter mentions.
+async fn preview_once(
+    pool: &DbPool,
+    fetcher: &dyn MentionsFetcher,
+    limit: u32,
+) -> anyhow::Result<MentionsJson> {
+    if !storage::rate_limits::check_and_increment_rate_limit(pool, "mention_check").await? {
+        anyhow::bail!(
+            "Mention check rate limit reached; try again after the current window resets."
+        );
+    }
+
+    let mentions = fetcher.get_mentions(None).await.map_err(|e| match e {
+        LoopError::RateLimited { retry_after } => anyhow::anyhow!(
+            "X API rate limit hit while fetching mentions.{}",
+            retry_after
+                .map(|s| format!(" Wait {s} seconds and try again."))
+                .unwrap_or_default()
+        ),
+        LoopError::AuthExpired => {
+            anyhow::anyhow!("X API token expired. Run `tuitbot auth` to re-authenticate.")
+        }
+        other => anyhow::anyhow!("Failed to fetch mentions: {other}"),
+    })?;
+
+    let dedup = DedupChecker::new(pool.clone());
+    let fetched = mentions.len();
+    let mut already_replied = 0;
+    let mut pending = Vec::new();
+    for mention in mentions {
+        if dedup.has_replied_to(&mention.id).await? {
+            already_replied += 1;
+            continue;
+        }
+        if pending.len() < limit as usize {
+            pending.push(to_pending(mention));
+        }
+    }
+
+    Ok(MentionsJson {
+        fetched,
+        already_replied,
+        pending,
+    })
+}
+
+fn to_pending(mention: LoopTweet) -> PendingMention {
+    let suggested_archetype = suggest_archetype(&mention.text).to_string();
+    PendingMention {
+        id: mention.id,
+        author_username: mention.author_username,
+        text: mention.text,
+        created_at: mention.created_at,
+        suggested_archetype,
+    }
+}
+
+/// Pick a reply archetype from simple cues in the mention text.
+///
+/// Questions get a concrete answer, pushback gets a respectful counterpoint,
+/// requests for experience get a personal story, and praise or statements
+/// get agreement that extends the point.
+fn suggest_archetype(text: &str) -> ReplyArchetype {
+    let lower = text.to_lowercase();
+    let has_any = |cues: &[&str]| cues.iter().any(|c| lower.contains(c));
+
+    if has_any(&[
+        "disagree",
+        "wrong",
+        "not true",
+        "doesn't work",
+        "don't think",
+        "overrated",
+    ]) {
+        ReplyArchetype::RespectfulDisagree
+    } else if has_any(&[
+        "have you tried",
+        "your experience",
+        "anyone tried",
+        "how did you",
+        "what was it like",
+    ]) {
+        ReplyArchetype::ShareExperience
+    } else if lower.contains('?') {
+        ReplyArchetype::AddData
+    } else if has_any(&["thanks", "thank you", "great", "love", "agree", "+1"]) {
+        ReplyArchetype::AgreeAndExpand
+    } else {
+        ReplyArchetype::AskQuestion
+    }
+}
+
+fn format_table(pending: &[PendingMention]) -> String {
+    if pending.is_empty() {
+        return "No unanswered mentions.".to_string();
+    }
+
+    let mut lines = vec![format!(
+        "{:>4}  {:<20}  {:<20}  {}",
+        "#", "AUTHOR", "SUGGESTED", "TEXT"
+    )];
+    for (i, m) in pending.iter().enumerate() {
+        let text: String = m.text.replace('\n', " ").chars().take(60).collect();
+        lines.push(format!(
+            "{:>4}  {:<20}  {:<20}  {}",
+            i + 1,
+            format!("@{}", m.author_username),
+            m.suggested_archetype,
+            text
+        ));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use tuitbot_core::automation::adapters::XApiMentionsAdapter;
+    use tuitbot_core::storage::init_test_db;
+    use tuitbot_core::storage::replies::{insert_reply, ReplySent};
+    use tuitbot_core::testing::{MockXClient, TweetFactory};
+
+    fn mentions_client() -> MockXClient {
+        let client = MockXClient::new();
+        client.set_search_tweets(vec![
+            TweetFactory::new()
+                .with_id("m1")
+                .with_text("@me is tokio really faster than smol?")
+                .build(),
+            TweetFactory::new()
+                .with_id("m2")
+                .with_text("@me thanks for the thread!")
+                .build(),
+            TweetFactory::new()
+                .with_id("m3")
+                .with_text("@me I disagree, async Rust is overrated")
+                .build(),
+        ]);
+        client
+    }
+
+    async fn mark_replied(pool: &DbPool, tweet_id: &str) {
+        insert_reply(
+            pool,
+            &ReplySent {
+                id: 0,
+                target_tweet_id: tweet_id.to_string(),
+                reply_tweet_id: Some("r1".to_string()),
+                reply_content: "already answered".to_string(),
+                llm_provider: None,
+                llm_model: None,
+                created_at: chrono::Utc::now().to_rfc3339(),
+                status: "sent".to_string(),
+                error_message: None,
+                conversation_id: None,
+            },
+        )
+        .await
+        .expect("insert reply");
+    }
+
+    #[tokio::test]
+    async fn preview_skips_already_answered_mentions() {
+        let pool = init_test_db().await.expect("init db");
+        mark_replied(&pool, "m2").await;
+        let client = mentions_client();
+        let fetcher = XApiMentionsAdapter::new(Arc::new(client.clone()), "me".to_string());
+
+        let report = preview_once(&pool, &fetcher, 20).await.expect("preview");
+
+        assert_eq!(report.fetched, 3);
+        assert_eq!(report.already_replied, 1);
+        let ids: Vec<&str> = report.pending.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, vec!["m1", "m3"]);
+        assert_eq!(report.pending[0].suggested_archetype, "add_data");
+        assert_eq!(report.pending[1].suggested_archetype, "respectful_disagree");
+        assert_eq!(client.post_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn preview_applies_limit_after_filtering() {
+        let pool = init_test_db().await.expect("init db");
+        mark_replied(&pool, "m1").await;
+        let fetcher = XApiMentionsAdapter::new(Arc::new(mentions_client()), "me".to_string());
+
+        let report = preview_once(&pool, &fetcher, 1).await.expect("preview");
+        assert_eq!(report.pending.len(), 1);
+        assert_eq!(report.pending[0].id, "m2");
+    }
+
+    #[tokio::test]
+    async fn preview_respects_mention_rate_limit() {
+        let pool = init_test_db().await.expect("init db");
+        sqlx::query(
+            "INSERT OR REPLACE INTO rate_limits \
+             (action_type, request_count, period_start, max_requests, period_seconds) \
+             VALUES ('mention_check', 1, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'), 1, 900)",
+        )
+        .execute(&pool)
+        .await
+        .expect("seed limit");
+        let fetcher = XApiMentionsAdapter::new(Arc::new(mentions_client()), "me".to_string());
+
+        let err = preview_once(&pool, &fetcher, 20).await.unwrap_err();
+        assert!(err.to_string().contains("rate limit"));
+    }
+
+    #[test]
+    fn suggest_archetype_cues() {
+        assert_eq!(
+            suggest_archetype("Have you tried axum?"),
+            ReplyArchetype::ShareExperience
+        );
+        assert_eq!(
+            suggest_archetype("Love this, totally agree"),
+            ReplyArchetype::AgreeAndExpand
+        );
+        assert_eq!(
+            suggest_archetype("Shipping v2 next week"),
+            ReplyArchetype::AskQuestion
+        );
+    }
+
+    #[test]
+    fn format_table_handles_empty() {
+        assert_eq!(format_table(&[]), "No unanswered mentions.");
+    }
+}
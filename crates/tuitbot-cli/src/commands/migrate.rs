@@ -0,0 +1,112 @@
+//! Implementation of the `tuitbot migrate` command.
+//!
+//! Applies any pending schema migrations to the configured database and
+//! reports the resulting schema version. Every other command already
+//! migrates on open; this one makes the step explicit, e.g. before a
+//! deploy or after restoring an old backup.
+
+use serde::Serialize;
+use tuitbot_core::config::Config;
+use tuitbot_core::storage;
+use tuitbot_core::storage::migrations::MigrationReport;
+
+use crate::output::{write_stdout, CliOutput};
+
+#[derive(Serialize)]
+struct MigrateOutput {
+    db_path: String,
+    #[serde(flatten)]
+    report: MigrationReport,
+}
+
+/// Execute the `tuitbot migrate` command.
+pub async fn execute(config: &Config, out: CliOutput) -> anyhow::Result<()> {
+    let report = storage::migrate_db(&config.storage.db_path, &config.storage).await?;
+    let output = MigrateOutput {
+        db_path: storage::expand_tilde(config.storage.db_path.trim()),
+        report,
+    };
+
+    if out.is_json() {
+        return out.json(&output);
+    }
+    if out.quiet {
+        return Ok(());
+    }
+    write_stdout(&format_text(&output))
+}
+
+fn format_text(output: &MigrateOutput) -> String {
+    let report = &output.report;
+    let mut lines = vec![format!("Database: {}", output.db_path)];
+    if report.applied.is_empty() {
+        lines.push(format!(
+            "Schema is up to date (version {}).",
+            report.to_version
+        ));
+        return lines.join("\n");
+    }
+    lines.push(format!(
+        "Applied {} migration(s): version {} -> {}",
+        report.applied.len(),
+        report.from_version,
+        report.to_version
+    ));
+    for m in &report.applied {
+        lines.push(format!("  {} {}", m.version, m.description));
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuitbot_core::storage::migrations::AppliedMigration;
+
+    #[test]
+    fn text_lists_applied_migrations() {
+        let output = MigrateOutput {
+            db_path: "/tmp/tuitbot.db".to_string(),
+            report: MigrationReport {
+                from_version: 1,
+                to_version: 3,
+                applied: vec![
+                    AppliedMigration {
+                        version: 2,
+                        description: "add accounts".to_string(),
+                    },
+                    AppliedMigration {
+                        version: 3,
+                        description: "add strategy".to_string(),
+                    },
+                ],
+            },
+        };
+        let text = format_text(&output);
+        assert!(text.contains("Applied 2 migration(s): version 1 -> 3"));
+        assert!(text.contains("  3 add strategy"));
+
+        let json = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["to_version"], 3);
+        assert_eq!(json["applied"][0]["description"], "add accounts");
+    }
+
+    #[tokio::test]
+    async fn migrate_db_reports_up_to_date_on_rerun() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("tuitbot.db").to_string_lossy().to_string();
+        let storage_config = tuitbot_core::config::StorageConfig::default();
+
+        let first = storage::migrate_db(&db_path, &storage_config)
+            .await
+            .unwrap();
+        assert_eq!(first.from_version, 0);
+        assert!(!first.applied.is_empty());
+
+        let second = storage::migrate_db(&db_path, &storage_config)
+            .await
+            .unwrap();
+        assert!(second.applied.is_empty());
+        assert_eq!(second.to_version, first.to_version);
+    }
+}
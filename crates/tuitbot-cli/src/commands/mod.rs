@@ -5,20 +5,31 @@
 pub mod accounts;
 pub mod approve;
 pub mod auth;
+pub mod auth_status;
 pub mod backup;
+pub mod discover;
 pub mod doctor;
+pub mod effective_config;
+pub mod export_manifest;
 pub mod init;
 pub mod mcp;
+pub mod mentions;
+pub mod migrate;
 pub mod restore;
 pub mod run;
+pub mod schedule;
+pub mod score;
 pub mod settings;
 pub mod stats;
 pub mod test;
+pub mod thread;
 pub mod tick;
 pub mod uninstall;
 pub mod update;
 pub mod upgrade;
 
+use std::path::PathBuf;
+
 use clap::Args;
 
 /// Output format for machine-readable output.
@@ -55,6 +66,10 @@ pub struct InitArgs {
     /// Run the full 8-step advanced setup wizard
     #[arg(long)]
     pub advanced: bool,
+
+    /// Import settings from an existing ReplyGuy config file
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["non_interactive", "advanced"])]
+    pub from_replyguy: Option<PathBuf>,
 }
 
 /// Arguments for the `run` subcommand.
@@ -63,6 +78,11 @@ pub struct RunArgs {
     /// Print periodic status summary (0 = disabled)
     #[arg(long, default_value = "0")]
     pub status_interval: u64,
+
+    /// Run each enabled loop exactly once (like `tick`), log a status
+    /// summary for the pass, and exit
+    #[arg(long, conflicts_with = "status_interval")]
+    pub once_per_loop: bool,
 }
 
 /// Arguments for the `auth` subcommand.
@@ -71,11 +91,38 @@ pub struct AuthArgs {
     /// Auth mode override
     #[arg(long, value_parser = ["manual", "local_callback"])]
     pub mode: Option<String>,
+
+    /// Complete a pending `tuitbot auth` session with this callback URL
+    #[arg(long, value_name = "CALLBACK_URL", conflicts_with = "mode")]
+    pub validate_callback_only: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<AuthSubcommand>,
+}
+
+/// Auth subcommands.
+#[derive(Debug, clap::Subcommand)]
+pub enum AuthSubcommand {
+    /// Show whether stored tokens exist, are expired, and which scopes they grant
+    Status {
+        /// Also call the X API to report the authenticated username
+        #[arg(long)]
+        live: bool,
+    },
 }
 
 /// Arguments for the `test` subcommand.
 #[derive(Debug, Args)]
-pub struct TestArgs;
+pub struct TestArgs {
+    /// Only validate config.toml (no network, no DB) and report via exit code
+    ///
+    /// Exit codes:
+    ///   0  configuration is valid
+    ///   2  configuration has validation errors
+    ///   3  config file not found or could not be parsed
+    #[arg(long, verbatim_doc_comment)]
+    pub config_check: bool,
+}
 
 /// Arguments for the `doctor` subcommand.
 #[derive(Debug, Args)]
@@ -88,6 +135,14 @@ pub struct DiscoverArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Run a single search pass and exit
+    #[arg(long)]
+    pub once: bool,
+
+    /// Print ranked candidates with verdicts; never generate or post
+    #[arg(long, requires = "once")]
+    pub score_only: bool,
+
     /// Maximum tweets to process
     #[arg(long, default_value = "50")]
     pub limit: u32,
@@ -100,6 +155,10 @@ pub struct MentionsArgs {
     #[arg(long)]
     pub dry_run: bool,
 
+    /// Fetch once and list unanswered mentions; never generate or post
+    #[arg(long)]
+    pub once: bool,
+
     /// Maximum mentions to process
     #[arg(long, default_value = "20")]
     pub limit: u32,
@@ -131,18 +190,73 @@ pub struct ThreadArgs {
     /// Number of tweets in thread
     #[arg(long)]
     pub count: Option<u32>,
+
+    /// Resume a partially posted thread by ID, posting only the remaining tweets
+    #[arg(long, value_name = "THREAD_ID")]
+    pub resume: Option<i64>,
+
+    /// Post a markdown draft as-is, one tweet per `---` or heading section (no LLM)
+    #[arg(
+        long,
+        value_name = "FILE",
+        conflicts_with_all = ["topic", "count", "resume"]
+    )]
+    pub from_markdown: Option<PathBuf>,
+}
+
+/// Arguments for the `schedule` subcommand.
+#[derive(Debug, Args)]
+pub struct ScheduleArgs {
+    /// Days to show, today included
+    #[arg(long, default_value = "7")]
+    pub days: u32,
+
+    /// Export the schedule instead of listing it (ics: iCalendar feed on stdout)
+    #[arg(long, value_name = "FORMAT", value_parser = ["ics"])]
+    pub export: Option<String>,
+
+    /// Write the export to this file instead of stdout
+    #[arg(long, value_name = "FILE", requires = "export")]
+    pub out: Option<PathBuf>,
 }
 
 /// Arguments for the `score` subcommand.
 #[derive(Debug, Args)]
 pub struct ScoreArgs {
     /// The X tweet ID to score
-    pub tweet_id: String,
+    #[arg(required_unless_present = "batch")]
+    pub tweet_id: Option<String>,
+
+    /// Score every record in a JSON array or CSV file (offline, no X API)
+    #[arg(long, value_name = "FILE", conflicts_with = "tweet_id")]
+    pub batch: Option<PathBuf>,
+
+    /// Write the batch results as JSON to this file
+    #[arg(long, value_name = "FILE", requires = "batch")]
+    pub out: Option<PathBuf>,
 }
 
 /// Arguments for the `stats` subcommand.
 #[derive(Debug, Args)]
-pub struct StatsArgs;
+pub struct StatsArgs {
+    /// Compare average engagement per content framework (reply archetype,
+    /// tweet format, thread structure) instead of the dashboard
+    #[arg(long)]
+    pub by_framework: bool,
+
+    /// Compare the current period with the one before it: day over day
+    /// (dod), week over week (wow), or 30 days over 30 days (mom)
+    #[arg(long, value_name = "PERIOD", value_parser = ["dod", "wow", "mom"], conflicts_with = "by_framework")]
+    pub compare: Option<String>,
+
+    /// Redraw the dashboard in place every --interval seconds until Ctrl-C
+    #[arg(long, conflicts_with_all = ["by_framework", "compare"])]
+    pub watch: bool,
+
+    /// Seconds between refreshes in --watch mode
+    #[arg(long, default_value = "30", requires = "watch")]
+    pub interval: u64,
+}
 
 /// Arguments for the `approve` subcommand.
 #[derive(Debug, Args)]
@@ -162,6 +276,14 @@ pub struct ApproveArgs {
     /// Approve all pending items
     #[arg(long)]
     pub approve_all: bool,
+
+    /// Keep polling the queue and review new items as they arrive
+    #[arg(long)]
+    pub watch: bool,
+
+    /// Seconds between polls in --watch mode
+    #[arg(long, default_value = "5", requires = "watch")]
+    pub interval: u64,
 }
 
 /// Arguments for the `settings` subcommand.
@@ -178,6 +300,26 @@ pub struct SettingsArgs {
     /// Jump directly to a specific category
     #[arg(value_name = "CATEGORY")]
     pub category: Option<String>,
+
+    #[command(subcommand)]
+    pub command: Option<SettingsSubcommand>,
+}
+
+/// Non-interactive settings subcommands.
+#[derive(Debug, clap::Subcommand)]
+pub enum SettingsSubcommand {
+    /// Set a single key in config.toml, preserving comments (e.g., `set scoring.threshold 70`)
+    Set {
+        /// Dotted key path (e.g., `scoring.threshold`, `business.product_keywords`)
+        key: String,
+        /// New value; list fields take comma-separated values
+        value: String,
+    },
+    /// Print the current value of a key
+    Get {
+        /// Dotted key path (e.g., `scoring.threshold`)
+        key: String,
+    },
 }
 
 /// Arguments for the `update` subcommand.
@@ -216,13 +358,23 @@ pub struct TickArgs {
     pub ignore_schedule: bool,
 
     /// Comma-separated loops to run (default: all enabled)
-    /// Options: discovery, mentions, content, thread, target, analytics
+    /// Options: discovery, mentions, content, thread, target, analytics, housekeeping
     #[arg(long, value_delimiter = ',')]
     pub loops: Option<Vec<String>>,
 
     /// Force approval mode on for this tick (queue posts for human review)
     #[arg(long)]
     pub require_approval: bool,
+
+    /// Run only loops that don't need an LLM (analytics, housekeeping); content-generating
+    /// loops are reported as skipped
+    #[arg(long)]
+    pub no_llm: bool,
+
+    /// Exit with code 3 when safety limits blocked every intended action
+    /// (a tick with nothing to do still exits 0)
+    #[arg(long)]
+    pub fail_on_no_op: bool,
 }
 
 /// Arguments for the `backup` subcommand.
@@ -239,8 +391,17 @@ pub struct BackupArgs {
     /// Keep N most recent backups, delete the rest
     #[arg(long)]
     pub prune: Option<usize>,
+
+    /// Back up only rows changed since the last backup in the chain
+    /// (starts a new chain with a full backup when needed)
+    #[arg(long)]
+    pub incremental: bool,
 }
 
+/// Arguments for the `migrate` subcommand.
+#[derive(Debug, Args)]
+pub struct MigrateArgs;
+
 /// Arguments for the `restore` subcommand.
 #[derive(Debug, Args)]
 pub struct RestoreArgs {
@@ -254,6 +415,11 @@ pub struct RestoreArgs {
     /// Validate backup without restoring
     #[arg(long)]
     pub validate_only: bool,
+
+    /// Check the backup's integrity, tables, and schema version read-only,
+    /// then exit without touching the database (non-zero on any problem)
+    #[arg(long, conflicts_with_all = ["force", "validate_only"])]
+    pub verify: bool,
 }
 
 /// Arguments for the `uninstall` subcommand.
@@ -362,6 +528,7 @@ mod tests {
             force: true,
             non_interactive: false,
             advanced: true,
+            from_replyguy: None,
         };
         let debug = format!("{:?}", args);
         assert!(debug.contains("force: true"));
@@ -375,6 +542,8 @@ mod tests {
             approve: Some(42),
             reject: None,
             approve_all: false,
+            watch: false,
+            interval: 5,
         };
         let debug = format!("{:?}", args);
         assert!(debug.contains("list: true"));
@@ -387,6 +556,7 @@ mod tests {
             show: true,
             set: Some("key=value".to_string()),
             category: None,
+            command: None,
         };
         let debug = format!("{:?}", args);
         assert!(debug.contains("show: true"));
@@ -399,6 +569,7 @@ mod tests {
             output_dir: Some("/tmp".to_string()),
             list: false,
             prune: Some(5),
+            incremental: false,
         };
         let debug = format!("{:?}", args);
         assert!(debug.contains("/tmp"));
@@ -412,6 +583,8 @@ mod tests {
             ignore_schedule: false,
             loops: Some(vec!["discovery".to_string(), "content".to_string()]),
             require_approval: false,
+            no_llm: false,
+            fail_on_no_op: false,
         };
         let debug = format!("{:?}", args);
         assert!(debug.contains("dry_run: true"));
@@ -445,6 +618,7 @@ mod tests {
             backup_path: "/tmp/backup.db".to_string(),
             force: true,
             validate_only: false,
+            verify: false,
         };
         let debug = format!("{:?}", args);
         assert!(debug.contains("/tmp/backup.db"));
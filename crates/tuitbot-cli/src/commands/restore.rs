@@ -1,7 +1,7 @@
 //! Implementation of the `tuitbot restore` command.
 
 use std::io::IsTerminal;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use tuitbot_core::startup::resolve_db_path;
 use tuitbot_core::storage;
@@ -29,6 +29,17 @@ pub async fn execute(args: RestoreArgs, config_path: &str, out: CliOutput) -> an
         anyhow::bail!("Cannot read backup file {}: {}", backup_path.display(), e);
     }
 
+    let manifest = storage::incremental_backup::read_manifest(&backup_path)?;
+    let incremental =
+        manifest.is_some_and(|m| m.kind == storage::incremental_backup::BackupKind::Incremental);
+
+    if args.verify {
+        return verify(&backup_path, incremental, out).await;
+    }
+    if incremental {
+        return restore_incremental(&args, &backup_path, config_path, out).await;
+    }
+
     // Validate.
     out.info(&format!("Validating backup: {}", backup_path.display()));
     let validation = storage::backup::validate_backup(&backup_path).await?;
@@ -48,6 +59,7 @@ pub async fn execute(args: RestoreArgs, config_path: &str, out: CliOutput) -> an
             out.json(&serde_json::json!({
                 "status": "valid",
                 "tables": validation.tables,
+                "schema_version": validation.schema_version,
                 "messages": validation.messages,
             }))?;
         } else {
@@ -58,6 +70,117 @@ pub async fn execute(args: RestoreArgs, config_path: &str, out: CliOutput) -> an
 
     // Validate db_path early — fail fast before asking for confirmation.
     let target = resolve_db_path(config_path)?;
+    if !confirm(&args, &target, &out)? {
+        return Ok(());
+    }
+
+    // Restore.
+    out.info("Restoring...");
+    storage::backup::restore_from_backup(&backup_path, &target).await?;
+
+    print_restored(&target, out)
+}
+
+/// Restore an incremental backup by replaying its chain onto the base.
+async fn restore_incremental(
+    args: &RestoreArgs,
+    backup_path: &Path,
+    config_path: &str,
+    out: CliOutput,
+) -> anyhow::Result<()> {
+    out.info(&format!(
+        "Resolving incremental chain: {}",
+        backup_path.display()
+    ));
+    let chain = storage::incremental_backup::resolve_chain(backup_path)?;
+
+    let validation = storage::backup::validate_backup(&chain[0]).await?;
+    for msg in &validation.messages {
+        out.info(&format!("  {msg}"));
+    }
+    if !validation.valid {
+        anyhow::bail!("Base backup validation failed. Aborting restore.");
+    }
+    for (i, path) in chain.iter().enumerate() {
+        let role = if i == 0 { "base" } else { "increment" };
+        out.info(&format!("  {role}: {}", path.display()));
+    }
+
+    if args.validate_only {
+        if out.is_json() {
+            out.json(&serde_json::json!({
+                "status": "valid",
+                "chain": chain.iter().map(|p| p.display().to_string()).collect::<Vec<_>>(),
+                "messages": validation.messages,
+            }))?;
+        } else {
+            out.info("\nValidation passed. Use without --validate-only to restore.");
+        }
+        return Ok(());
+    }
+
+    let target = resolve_db_path(config_path)?;
+    if !confirm(args, &target, &out)? {
+        return Ok(());
+    }
+
+    out.info(&format!("Restoring {} backup(s)...", chain.len()));
+    storage::incremental_backup::restore_incremental(backup_path, &target).await?;
+
+    print_restored(&target, out)
+}
+
+/// `--verify`: check a backup read-only and report, never restoring.
+///
+/// For an incremental backup the chain is resolved (which checks every
+/// manifest) and its base database is verified.
+async fn verify(backup_path: &Path, incremental: bool, out: CliOutput) -> anyhow::Result<()> {
+    let chain = if incremental {
+        storage::incremental_backup::resolve_chain(backup_path)?
+    } else {
+        vec![backup_path.to_path_buf()]
+    };
+
+    out.info(&format!("Verifying backup: {}", chain[0].display()));
+    let validation = storage::backup::validate_backup(&chain[0]).await?;
+    let latest = storage::migrations::latest_version();
+
+    if out.is_json() {
+        out.json(&serde_json::json!({
+            "status": if validation.valid { "valid" } else { "invalid" },
+            "path": chain[0].display().to_string(),
+            "chain_length": chain.len(),
+            "schema_version": validation.schema_version,
+            "binary_schema_version": latest,
+            "tables": validation.tables,
+            "messages": validation.messages,
+        }))?;
+    } else {
+        for msg in &validation.messages {
+            out.info(&format!("  {msg}"));
+        }
+        if !validation.tables.is_empty() {
+            out.info(&format!("  Tables: {}", validation.tables.join(", ")));
+        }
+        if let Some(version) = validation.schema_version {
+            out.info(&format!(
+                "  Schema version: {version} (this binary: {latest})"
+            ));
+        }
+        if incremental {
+            out.info(&format!("  Chain: {} backup(s)", chain.len()));
+        }
+    }
+
+    if !validation.valid {
+        anyhow::bail!("Backup verification failed: {}", backup_path.display());
+    }
+    out.info("\nVerification passed. Nothing was restored.");
+    Ok(())
+}
+
+/// Ask before replacing the database unless `--force` or non-interactive.
+fn confirm(args: &RestoreArgs, target: &Path, out: &CliOutput) -> anyhow::Result<bool> {
     if !args.force && std::io::stdin().is_terminal() {
         eprintln!("\nThis will replace the database at {}", target.display());
         eprintln!("A safety backup of the current database will be created first.");
@@ -66,14 +189,13 @@ pub async fn execute(args: RestoreArgs, config_path: &str, out: CliOutput) -> an
         std::io::stdin().read_line(&mut input)?;
         if !input.trim().eq_ignore_ascii_case("y") {
             out.info("Aborted.");
-            return Ok(());
+            return Ok(false);
         }
     }
+    Ok(true)
+}
 
-    // Restore.
-    out.info("Restoring...");
-    storage::backup::restore_from_backup(&backup_path, &target).await?;
-
+fn print_restored(target: &Path, out: CliOutput) -> anyhow::Result<()> {
     if out.is_json() {
         out.json(&serde_json::json!({
             "status": "restored",
@@ -86,3 +208,65 @@ pub async fn execute(args: RestoreArgs, config_path: &str, out: CliOutput) -> an
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::OutputFormat;
+    use tuitbot_core::config::StorageConfig;
+
+    fn verify_args(path: &Path) -> RestoreArgs {
+        RestoreArgs {
+            backup_path: path.display().to_string(),
+            force: false,
+            validate_only: false,
+            verify: true,
+        }
+    }
+
+    async fn backup_in(dir: &Path) -> PathBuf {
+        let pool = storage::init_db_with_config(
+            &dir.join("live.db").to_string_lossy(),
+            &StorageConfig::default(),
+        )
+        .await
+        .expect("init db");
+        let result = storage::backup::create_backup(&pool, &dir.join("backups"))
+            .await
+            .expect("backup");
+        pool.close().await;
+        result.path
+    }
+
+    #[tokio::test]
+    async fn verify_accepts_valid_backup_without_config() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let backup = backup_in(dir.path()).await;
+        let before = std::fs::read(&backup).expect("read backup");
+
+        let out = CliOutput::new(true, OutputFormat::Text);
+        // A missing config proves the live database is never resolved.
+        execute(verify_args(&backup), "/nonexistent/config.toml", out)
+            .await
+            .expect("valid backup verifies");
+
+        assert_eq!(std::fs::read(&backup).expect("reread"), before);
+    }
+
+    #[tokio::test]
+    async fn verify_fails_on_corrupted_backup() {
+        let dir = tempfile::tempdir().expect("temp dir");
+        let backup = backup_in(dir.path()).await;
+
+        let mut bytes = std::fs::read(&backup).expect("read backup");
+        let page_size = u16::from_be_bytes([bytes[16], bytes[17]]) as usize;
+        for b in bytes.iter_mut().skip(page_size) {
+            *b = 0xA5;
+        }
+        std::fs::write(&backup, &bytes).expect("corrupt backup");
+
+        let out = CliOutput::new(true, OutputFormat::Text);
+        let result = execute(verify_args(&backup), "/nonexistent/config.toml", out).await;
+        assert!(result.is_err(), "corrupted backup must fail verification");
+    }
+}
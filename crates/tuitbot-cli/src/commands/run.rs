@@ -3,19 +3,24 @@
 //! The main entry point for autonomous operation. Initializes all
 //! dependencies, detects API tier, creates adapter structs, spawns
 //! automation loops, and runs until a shutdown signal is received.
+//! With `--once-per-loop` it instead makes a single pass over the loops
+//! through the `tick` dispatcher and exits.
 
 use std::sync::Arc;
 use std::time::Duration;
 
 use tuitbot_core::automation::circuit_breaker::CircuitBreaker;
+use tuitbot_core::automation::loop_breaker::LoopBreaker;
 use tuitbot_core::automation::{
-    run_approval_poster, run_posting_queue_with_approval, run_token_refresh_loop,
-    scheduler_from_config, status_reporter::run_status_reporter, AnalyticsLoop, ContentLoop,
-    DiscoveryLoop, MentionsLoop, PostExecutor, Runtime, TargetLoop, ThreadLoop,
+    run_approval_poster, run_posting_queue_with_activity, run_token_refresh_loop,
+    scheduler_from_config, status_reporter::run_status_reporter_with_usage, ActivitySink,
+    AnalyticsLoop, ContentLoop, DiscoveryLoop, LogActivitySink, MentionsLoop, PostExecutor,
+    Runtime, StatusQuerier, TargetLoop, ThreadLoop,
 };
 use tuitbot_core::config::{Config, OperatingMode};
 use tuitbot_core::startup::format_startup_banner;
 
+use super::tick;
 use crate::deps::RuntimeDeps;
 
 /// Execute the `tuitbot run` command.
@@ -59,14 +64,22 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
         let executor = deps.post_executor.clone() as Arc<dyn PostExecutor>;
         let approval_queue = deps.approval_queue.clone();
         let cb = circuit_breaker.clone();
+        let random_seed = config.content.random_seed;
+        // Logged so the server can relay loop posts to its activity stream.
+        let activity: Arc<dyn ActivitySink> = Arc::new(LogActivitySink::new(
+            deps.pool.clone(),
+            deps.account_id.clone(),
+        ));
         async move {
-            run_posting_queue_with_approval(
+            run_posting_queue_with_activity(
                 post_rx,
                 executor,
                 approval_queue,
                 min_delay,
                 max_delay,
                 Some(cb),
+                Some(activity),
+                random_seed,
                 cancel,
             )
             .await;
@@ -86,38 +99,65 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
         let cancel = runtime.cancel_token();
         let pool = deps.pool.clone();
         let xc = deps.dyn_client.clone();
-        // CLI is single-account — always uses the default account.
+        // CLI is single-account — posts for the runtime's account.
         // Multi-account dispatch (server-driven) spawns one poster per account.
-        let account_id = tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID.to_string();
+        let account_id = deps.account_id.clone();
+        let loopback_cap = config
+            .content_sources
+            .loopback
+            .loop_back_max_writes_per_hour;
         runtime.spawn(
             "approval-poster",
-            run_approval_poster(pool, xc, account_id, min_delay, max_delay, cancel),
+            run_approval_poster(
+                pool,
+                xc,
+                account_id,
+                min_delay,
+                max_delay,
+                loopback_cap,
+                config.content.random_seed,
+                cancel,
+            ),
         );
     }
 
     let is_composer = config.mode == OperatingMode::Composer;
 
+    // Each loop pauses itself once its persisted error budget is spent.
+    let breaker = LoopBreaker::new(deps.pool.clone(), config.circuit_breaker.loops.clone());
+
     // --- Autopilot-only loops ---
     if !is_composer {
+        let (content_topics, topic_source) = config.business.content_topics();
+        tracing::info!(
+            source = %topic_source,
+            count = content_topics.len(),
+            "Content and thread loop topics resolved"
+        );
+
         // Content loop (all tiers)
         {
             let content_loop = ContentLoop::new(
                 deps.tweet_gen.clone(),
                 deps.content_safety.clone(),
                 deps.content_storage.clone(),
-                config.business.effective_industry_topics().to_vec(),
+                content_topics.clone(),
                 config.intervals.content_post_window_seconds,
                 false,
             )
             .with_topic_scorer(deps.topic_scorer.clone())
-            .with_thread_poster(deps.thread_poster.clone());
+            .with_thread_poster(deps.thread_poster.clone())
+            .with_min_topic_gap(config.content.min_topic_gap_hours)
+            .with_random_seed(config.content.random_seed)
+            .with_loop_breaker(breaker.clone());
 
             let cancel = runtime.cancel_token();
             let scheduler = scheduler_from_config(
                 config.intervals.content_post_window_seconds,
                 config.limits.min_action_delay_seconds,
                 config.limits.max_action_delay_seconds,
-            );
+            )
+            .with_seed(config.content.random_seed);
             let schedule = deps.active_schedule.clone();
             runtime.spawn("content-loop", async move {
                 content_loop.run(cancel, scheduler, schedule).await;
@@ -131,17 +171,20 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
                 deps.content_safety.clone(),
                 deps.content_storage.clone(),
                 deps.thread_poster.clone(),
-                config.business.effective_industry_topics().to_vec(),
+                content_topics,
                 config.intervals.thread_interval_seconds,
                 false,
-            );
+            )
+            .with_random_seed(config.content.random_seed)
+            .with_loop_breaker(breaker.clone());
 
             let cancel = runtime.cancel_token();
             let scheduler = scheduler_from_config(
                 config.intervals.thread_interval_seconds,
                 config.limits.min_action_delay_seconds,
                 config.limits.max_action_delay_seconds,
-            );
+            )
+            .with_seed(config.content.random_seed);
             let schedule = deps.active_schedule.clone();
             runtime.spawn("thread-loop", async move {
                 thread_loop.run(cancel, scheduler, schedule).await;
@@ -152,7 +195,7 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
     // --- Tier-gated loops ---
     if deps.capabilities.discovery {
         // Discovery loop: in composer mode, run with dry_run=true (read-only).
-        let discovery_loop = DiscoveryLoop::new(
+        let mut discovery_loop = DiscoveryLoop::new(
             deps.searcher.clone(),
             deps.scorer.clone(),
             deps.reply_gen.clone(),
@@ -162,7 +205,22 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
             deps.keywords.clone(),
             config.scoring.threshold as f32,
             is_composer, // dry_run in composer mode
-        );
+        )
+        .with_own_user_id(deps.own_user_id.clone())
+        .with_pagination(
+            config.intervals.discovery_max_pages as usize,
+            Some(config.limits.max_replies_per_day as usize),
+        )
+        .with_loop_breaker(breaker.clone());
+        if let Some(liker) = &deps.liker {
+            discovery_loop = discovery_loop.with_likes(liker.clone());
+        }
+        if let Some(fetcher) = &deps.conversation_fetcher {
+            discovery_loop = discovery_loop.with_conversation_context(
+                fetcher.clone(),
+                config.content.max_conversation_tweets as usize,
+            );
+        }
 
         let cancel = runtime.cancel_token();
         let scheduler = scheduler_from_config(
@@ -184,7 +242,9 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
             deps.safety.clone(),
             deps.post_sender.clone(),
             false,
-        );
+        )
+        .with_targets(config.targets.clone())
+        .with_loop_breaker(breaker.clone());
 
         let cancel = runtime.cancel_token();
         let scheduler = scheduler_from_config(
@@ -209,7 +269,8 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
             deps.target_storage.clone(),
             deps.post_sender.clone(),
             deps.target_loop_config.clone(),
-        );
+        )
+        .with_loop_breaker(breaker.clone());
 
         let cancel = runtime.cancel_token();
         let scheduler = scheduler_from_config(
@@ -229,7 +290,8 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
             deps.profile_adapter.clone(),
             deps.profile_adapter.clone(),
             deps.analytics_storage.clone(),
-        );
+        )
+        .with_loop_breaker(breaker);
 
         let cancel = runtime.cancel_token();
         let scheduler = scheduler_from_config(3600, 0, 0);
@@ -243,8 +305,10 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
         let scheduler = scheduler_from_config(effective_interval, 0, 0);
         let cancel = runtime.cancel_token();
         let status_querier = deps.status_querier.clone();
+        let session_usage = deps.session_usage.clone();
         runtime.spawn("status-reporter", async move {
-            run_status_reporter(status_querier, scheduler, cancel).await;
+            run_status_reporter_with_usage(status_querier, Some(session_usage), scheduler, cancel)
+                .await;
         });
     }
 
@@ -256,6 +320,45 @@ pub async fn execute(config: &Config, status_interval: u64) -> anyhow::Result<()
     // 5. Run until shutdown signal.
     runtime.run_until_shutdown().await;
 
+    tracing::info!("{}", deps.session_usage.snapshot().format_summary());
     tracing::info!("Shutdown complete.");
     Ok(())
 }
+
+/// Execute `tuitbot run --once-per-loop`.
+///
+/// Prints the usual startup banner, runs each enabled loop exactly once
+/// (respecting active hours), logs the same status and token usage summary
+/// the daemon logs, and exits. Fails if any loop failed. Holds the same
+/// process lock as `tuitbot tick`, so the two never run a pass concurrently.
+pub async fn execute_once_per_loop(config: &Config) -> anyhow::Result<()> {
+    let _lock = tick::acquire_process_lock()?;
+    let started = chrono::Utc::now();
+    let mut deps = RuntimeDeps::init(config, false).await?;
+
+    let banner = format_startup_banner(deps.tier, &deps.capabilities, 0);
+    eprintln!("{banner}");
+
+    let schedule_active = deps
+        .active_schedule
+        .as_ref()
+        .map_or(true, |s| s.is_active());
+    let failed = if schedule_active {
+        tick::run_each_loop_once(&mut deps, config).await
+    } else {
+        tracing::info!("Outside active hours; no loops run");
+        0
+    };
+
+    match deps.status_querier.query_action_counts_since(started).await {
+        Ok(counts) => tracing::info!("{}", counts.format_summary("pass")),
+        Err(e) => tracing::warn!(error = %e, "Failed to query action counts for status report"),
+    }
+    tracing::info!("{}", deps.session_usage.snapshot().format_summary());
+    deps.pool.close().await;
+
+    if failed > 0 {
+        anyhow::bail!("run failed: {failed} loop(s) failed");
+    }
+    Ok(())
+}
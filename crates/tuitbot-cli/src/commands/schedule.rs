@@ -0,0 +1,97 @@
+//! Implementation of the `tuitbot schedule` command.
+//!
+//! Lists the posting slots for the next few days, marking the ones already
+//! filled by scheduled content. `--export ics` renders the same preview as
+//! an iCalendar feed for calendar apps.
+
+use std::path::Path;
+
+use chrono::{Duration, Utc};
+use tuitbot_core::automation::schedule::{
+    build_schedule_preview, render_ics, ActiveSchedule, PreviewDay,
+};
+use tuitbot_core::config::Config;
+use tuitbot_core::storage;
+
+use super::ScheduleArgs;
+use crate::output::{self, CliOutput};
+
+/// Execute the `tuitbot schedule` command.
+pub async fn execute(config: &Config, args: ScheduleArgs, out: CliOutput) -> anyhow::Result<()> {
+    if args.days == 0 {
+        anyhow::bail!("--days must be at least 1");
+    }
+    let schedule = ActiveSchedule::from_config(&config.schedule).ok_or_else(|| {
+        anyhow::anyhow!("invalid schedule timezone '{}'", config.schedule.timezone)
+    })?;
+
+    let now = Utc::now();
+    let to = now + Duration::days(i64::from(args.days) + 1);
+    let pool = storage::init_db_with_config(&config.storage.db_path, &config.storage).await?;
+    let result = storage::scheduled_content::get_in_range(
+        &pool,
+        &now.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        &to.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+    )
+    .await;
+    pool.close().await;
+    let scheduled = result?;
+
+    let days = build_schedule_preview(&schedule, now, args.days, &scheduled);
+
+    if args.export.as_deref() == Some("ics") {
+        let ics = render_ics(&days, &scheduled, now);
+        return match &args.out {
+            Some(path) => write_file(path, &ics, out),
+            // `write_stdout` appends the final `\n`, completing the CRLF.
+            None => output::write_stdout(ics.strip_suffix('\n').unwrap_or(&ics)),
+        };
+    }
+
+    if out.is_json() {
+        out.json(&serde_json::json!({
+            "timezone": config.schedule.timezone,
+            "days": days,
+        }))?;
+    } else if !out.quiet {
+        eprintln!("Posting schedule ({})", config.schedule.timezone);
+        for line in preview_lines(&days) {
+            eprintln!("{line}");
+        }
+    }
+    Ok(())
+}
+
+fn write_file(path: &Path, ics: &str, out: CliOutput) -> anyhow::Result<()> {
+    std::fs::write(path, ics)
+        .map_err(|e| anyhow::anyhow!("Failed to write {}: {e}", path.display()))?;
+    out.info(&format!("Wrote calendar to {}", path.display()));
+    Ok(())
+}
+
+/// Text rendering of the preview: one line per day, one indented line per slot.
+fn preview_lines(days: &[PreviewDay]) -> Vec<String> {
+    let mut lines = Vec::new();
+    for day in days {
+        if !day.active {
+            lines.push(format!("{} {}  (inactive)", day.weekday, day.date));
+            continue;
+        }
+        lines.push(format!("{} {}", day.weekday, day.date));
+        for slot in &day.slots {
+            let status = match slot.scheduled_content_id {
+                Some(id) => format!("scheduled #{id}"),
+                None => "open".to_string(),
+            };
+            lines.push(format!(
+                "  {}  {:<6}  {status}",
+                slot.local_time,
+                slot.kind.as_str()
+            ));
+        }
+        for id in &day.unslotted_scheduled {
+            lines.push(format!("  --:--  off-slot  scheduled #{id}"));
+        }
+    }
+    lines
+}
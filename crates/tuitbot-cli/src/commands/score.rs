@@ -0,0 +1,435 @@
+//! Implementation of the `tuitbot score` command.
+//!
+//! `--batch <FILE>` scores every record in a JSON array or CSV file with the
+//! heuristic [`ScoringEngine`], fully offline. Rows that fail to parse are
+//! reported with their location and skipped; the rest of the batch is
+//! still scored.
+
+use std::path::Path;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tuitbot_core::config::Config;
+use tuitbot_core::scoring::{find_matched_keywords, ScoringEngine, SkipReason, TweetData};
+
+use super::ScoreArgs;
+use crate::output::CliOutput;
+
+/// One input row, shaped like [`TweetData`] plus an optional caller ID.
+#[derive(Debug, Clone, Deserialize)]
+struct BatchRecord {
+    #[serde(default)]
+    id: Option<String>,
+    text: String,
+    created_at: String,
+    #[serde(default)]
+    likes: u64,
+    #[serde(default)]
+    retweets: u64,
+    #[serde(default)]
+    replies: u64,
+    #[serde(default)]
+    author_username: String,
+    #[serde(default)]
+    author_followers: u64,
+    #[serde(default)]
+    has_media: bool,
+    #[serde(default)]
+    is_quote_tweet: bool,
+}
+
+impl BatchRecord {
+    fn validate(self) -> Result<Self, String> {
+        if self.text.trim().is_empty() {
+            return Err("text is empty".to_string());
+        }
+        if DateTime::parse_from_rfc3339(&self.created_at).is_err() {
+            return Err(format!(
+                "created_at '{}' is not an RFC 3339 timestamp",
+                self.created_at
+            ));
+        }
+        Ok(self)
+    }
+
+    fn into_tweet(self) -> (Option<String>, TweetData) {
+        (
+            self.id,
+            TweetData {
+                text: self.text,
+                created_at: self.created_at,
+                likes: self.likes,
+                retweets: self.retweets,
+                replies: self.replies,
+                author_username: self.author_username,
+                author_followers: self.author_followers,
+                has_media: self.has_media,
+                is_quote_tweet: self.is_quote_tweet,
+            },
+        )
+    }
+}
+
+/// Where a row came from: a CSV line or a JSON array element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RowLocation {
+    Line(usize),
+    Record(usize),
+}
+
+impl std::fmt::Display for RowLocation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RowLocation::Line(n) => write!(f, "line {n}"),
+            RowLocation::Record(n) => write!(f, "record {n}"),
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct ScoredRow {
+    location: String,
+    id: Option<String>,
+    author_username: String,
+    text: String,
+    total: f32,
+    keyword_relevance: f32,
+    follower: f32,
+    recency: f32,
+    engagement: f32,
+    reply_count: f32,
+    content_type: f32,
+    matched_keywords: Vec<String>,
+    verdict: &'static str,
+    skip_reason: Option<SkipReason>,
+}
+
+#[derive(Debug, Serialize)]
+struct RowError {
+    location: String,
+    error: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct BatchTotals {
+    rows: usize,
+    scored: usize,
+    failed: usize,
+    reply: usize,
+    skip: usize,
+    average_score: f32,
+}
+
+#[derive(Debug, Serialize)]
+struct BatchReport {
+    threshold: u32,
+    totals: BatchTotals,
+    results: Vec<ScoredRow>,
+    errors: Vec<RowError>,
+}
+
+/// Execute the `tuitbot score` command.
+pub async fn execute(config: &Config, args: ScoreArgs, out: CliOutput) -> anyhow::Result<()> {
+    let Some(batch) = args.batch else {
+        anyhow::bail!("score: scoring a tweet by ID is not yet available; use --batch <FILE>");
+    };
+
+    let contents = std::fs::read_to_string(&batch)
+        .map_err(|e| anyhow::anyhow!("Failed to read {}: {e}", batch.display()))?;
+    let rows = parse_batch(&batch, &contents)?;
+
+    let keywords: Vec<String> = config
+        .business
+        .product_keywords
+        .iter()
+        .chain(config.business.competitor_keywords.iter())
+        .cloned()
+        .collect();
+    let engine = ScoringEngine::new(config.scoring.clone(), keywords);
+    let report = score_rows(&engine, rows, Utc::now());
+
+    if let Some(path) = &args.out {
+        std::fs::write(path, serde_json::to_string_pretty(&report)?)
+            .map_err(|e| anyhow::anyhow!("Failed to write {}: {e}", path.display()))?;
+        out.info(&format!("Wrote results to {}", path.display()));
+    }
+
+    if out.is_json() {
+        if args.out.is_none() {
+            out.json(&report)?;
+        }
+    } else if !out.quiet {
+        print_report(&report);
+    }
+    Ok(())
+}
+
+type ParsedRow = (RowLocation, Result<BatchRecord, String>);
+
+/// Parse a batch file, picking CSV or JSON from the extension.
+///
+/// Only a file that is not a JSON array at all is a hard error; individual
+/// rows that fail come back as `Err` entries.
+fn parse_batch(path: &Path, contents: &str) -> anyhow::Result<Vec<ParsedRow>> {
+    let is_csv = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("csv"));
+    if is_csv {
+        Ok(parse_csv(contents))
+    } else {
+        parse_json(contents)
+    }
+}
+
+fn parse_json(contents: &str) -> anyhow::Result<Vec<ParsedRow>> {
+    let values: Vec<serde_json::Value> = serde_json::from_str(contents)
+        .map_err(|e| anyhow::anyhow!("Batch file is not a JSON array: {e}"))?;
+    Ok(values
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let record = serde_json::from_value::<BatchRecord>(value)
+                .map_err(|e| e.to_string())
+                .and_then(BatchRecord::validate);
+            (RowLocation::Record(i + 1), record)
+        })
+        .collect())
+}
+
+/// Parse CSV with a header row. Quoted fields may contain commas and `""`
+/// escapes but not line breaks. Unknown columns are ignored.
+fn parse_csv(contents: &str) -> Vec<ParsedRow> {
+    let mut lines = contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+    let Some((_, header)) = lines.next() else {
+        return Vec::new();
+    };
+    let headers: Vec<String> = split_csv_line(header)
+        .into_iter()
+        .map(|h| h.trim().to_ascii_lowercase())
+        .collect();
+
+    lines
+        .map(|(i, line)| {
+            let fields = split_csv_line(line);
+            let record = if fields.len() != headers.len() {
+                Err(format!(
+                    "expected {} fields, found {}",
+                    headers.len(),
+                    fields.len()
+                ))
+            } else {
+                record_from_csv(&headers, &fields).and_then(BatchRecord::validate)
+            };
+            (RowLocation::Line(i + 1), record)
+        })
+        .collect()
+}
+
+fn split_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if in_quotes && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => in_quotes = !in_quotes,
+            ',' if !in_quotes => fields.push(std::mem::take(&mut field)),
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+    fields
+}
+
+fn record_from_csv(headers: &[String], fields: &[String]) -> Result<BatchRecord, String> {
+    let get = |name: &str| {
+        headers
+            .iter()
+            .position(|h| h == name)
+            .map(|i| fields[i].trim())
+            .filter(|v| !v.is_empty())
+    };
+    let number = |name: &str| -> Result<u64, String> {
+        get(name).map_or(Ok(0), |v| {
+            v.parse()
+                .map_err(|_| format!("{name} '{v}' is not a whole number"))
+        })
+    };
+    let flag = |name: &str| -> Result<bool, String> {
+        match get(name).map(str::to_ascii_lowercase).as_deref() {
+            None | Some("false") | Some("0") => Ok(false),
+            Some("true") | Some("1") => Ok(true),
+            Some(v) => Err(format!("{name} '{v}' is not true/false")),
+        }
+    };
+
+    Ok(BatchRecord {
+        id: get("id").map(str::to_string),
+        text: get("text").ok_or("missing text")?.to_string(),
+        created_at: get("created_at").ok_or("missing created_at")?.to_string(),
+        likes: number("likes")?,
+        retweets: number("retweets")?,
+        replies: number("replies")?,
+        author_username: get("author_username").unwrap_or_default().to_string(),
+        author_followers: number("author_followers")?,
+        has_media: flag("has_media")?,
+        is_quote_tweet: flag("is_quote_tweet")?,
+    })
+}
+
+fn score_rows(engine: &ScoringEngine, rows: Vec<ParsedRow>, now: DateTime<Utc>) -> BatchReport {
+    let mut totals = BatchTotals {
+        rows: rows.len(),
+        ..BatchTotals::default()
+    };
+    let mut results = Vec::new();
+    let mut errors = Vec::new();
+    let mut score_sum = 0.0;
+
+    for (location, record) in rows {
+        let record = match record {
+            Ok(r) => r,
+            Err(error) => {
+                errors.push(RowError {
+                    location: location.to_string(),
+                    error,
+                });
+                continue;
+            }
+        };
+        let (id, tweet) = record.into_tweet();
+        let score = engine.score_tweet_at(&tweet, now);
+        score_sum += score.total;
+        if score.meets_threshold {
+            totals.reply += 1;
+        } else {
+            totals.skip += 1;
+        }
+        results.push(ScoredRow {
+            location: location.to_string(),
+            id,
+            matched_keywords: find_matched_keywords(&tweet.text, engine.keywords()),
+            author_username: tweet.author_username,
+            text: tweet.text,
+            total: score.total,
+            keyword_relevance: score.keyword_relevance,
+            follower: score.follower,
+            recency: score.recency,
+            engagement: score.engagement,
+            reply_count: score.reply_count,
+            content_type: score.content_type,
+            verdict: if score.meets_threshold {
+                "REPLY"
+            } else {
+                "SKIP"
+            },
+            skip_reason: score.skip_reason(engine.config()),
+        });
+    }
+
+    totals.scored = results.len();
+    totals.failed = errors.len();
+    if totals.scored > 0 {
+        totals.average_score = score_sum / totals.scored as f32;
+    }
+
+    BatchReport {
+        threshold: engine.config().threshold,
+        totals,
+        results,
+        errors,
+    }
+}
+
+fn print_report(report: &BatchReport) {
+    for row in &report.results {
+        let label = row.id.as_deref().unwrap_or(&row.location);
+        eprintln!(
+            "{label:<20} {:>5.0}/100  {:<5}  {}",
+            row.total,
+            row.verdict,
+            tuitbot_core::scoring::truncate_text(&row.text, 50)
+        );
+    }
+    for err in &report.errors {
+        eprintln!("{}: {}", err.location, err.error);
+    }
+    let t = &report.totals;
+    eprintln!(
+        "\nScored {} of {} rows ({} failed): {} REPLY, {} SKIP, average {:.1} (threshold {})",
+        t.scored, t.rows, t.failed, t.reply, t.skip, t.average_score, report.threshold
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tuitbot_core::config::ScoringConfig;
+
+    const CSV_FIXTURE: &str = include_str!("../../tests/fixtures/score_batch.csv");
+    const JSON_FIXTURE: &str = include_str!("../../tests/fixtures/score_batch.json");
+
+    fn engine() -> ScoringEngine {
+        ScoringEngine::new(ScoringConfig::default(), vec!["rust".to_string()])
+    }
+
+    fn now() -> DateTime<Utc> {
+        "2026-03-01T13:00:00Z".parse().unwrap()
+    }
+
+    #[test]
+    fn csv_batch_reports_malformed_row_by_line() {
+        let rows = parse_batch(Path::new("tweets.csv"), CSV_FIXTURE).unwrap();
+        let report = score_rows(&engine(), rows, now());
+
+        assert_eq!(report.totals.rows, 3);
+        assert_eq!(report.totals.scored, 2);
+        assert_eq!(report.totals.failed, 1);
+        assert_eq!(report.totals.reply + report.totals.skip, 2);
+        assert_eq!(report.errors[0].location, "line 3");
+        assert!(report.errors[0].error.contains("likes"));
+        assert_eq!(report.results[0].id.as_deref(), Some("t1"));
+        assert_eq!(report.results[0].matched_keywords, vec!["rust"]);
+        assert_eq!(
+            report.results[1].text,
+            "Shipping a CLI, with commas \"and quotes\""
+        );
+    }
+
+    #[test]
+    fn json_batch_reports_malformed_row_by_record() {
+        let rows = parse_batch(Path::new("tweets.json"), JSON_FIXTURE).unwrap();
+        let report = score_rows(&engine(), rows, now());
+
+        assert_eq!(report.totals.scored, 1);
+        assert_eq!(report.totals.failed, 1);
+        assert_eq!(report.errors[0].location, "record 2");
+        assert!(report.errors[0].error.contains("created_at"));
+        assert!(report.results[0].total > 0.0);
+    }
+
+    #[test]
+    fn json_batch_that_is_not_an_array_fails() {
+        assert!(parse_batch(Path::new("tweets.json"), "{\"text\": \"x\"}").is_err());
+    }
+
+    #[test]
+    fn csv_wrong_field_count_is_a_row_error() {
+        let rows = parse_csv("text,created_at\nhello\n");
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].0, RowLocation::Line(2));
+        assert!(rows[0]
+            .1
+            .as_ref()
+            .unwrap_err()
+            .contains("expected 2 fields"));
+    }
+}
@@ -0,0 +1,308 @@
+//! `tuitbot settings set|get` — non-interactive single-key editing.
+//!
+//! Unlike `--set`, which re-renders the whole file, `set` patches one key in
+//! place with `toml_edit` so comments and formatting survive. The value's type
+//! is taken from the current config schema, and the patched document must
+//! deserialize and validate before anything is written.
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use toml_edit::{Array, DocumentMut, Item, Table, Value};
+use tuitbot_core::config::Config;
+
+use super::helpers::{parse_bool, parse_csv};
+use super::render::validate_config;
+use crate::commands::OutputFormat;
+
+/// Set `key` to `value` in the config file at `config_path`, keeping a `.bak`.
+pub(super) fn set_key(config_path: &Path, key: &str, value: &str) -> Result<()> {
+    let content = fs::read_to_string(config_path)
+        .with_context(|| format!("Failed to read {}", config_path.display()))?;
+
+    let patched = patch_document(&content, key, value)?;
+
+    let backup_path = config_path.with_extension("toml.bak");
+    fs::copy(config_path, &backup_path)
+        .with_context(|| format!("Failed to create backup at {}", backup_path.display()))?;
+    fs::write(config_path, patched)
+        .with_context(|| format!("Failed to write config to {}", config_path.display()))?;
+
+    eprintln!("Set {key} = {value}");
+    eprintln!("Backup saved to {}", backup_path.display());
+    Ok(())
+}
+
+/// Print the current value of `key`.
+pub(super) fn get_key(config: &Config, key: &str, output: OutputFormat) -> Result<()> {
+    let segments = split_key(key)?;
+    let root = toml::Value::try_from(config).context("Failed to serialize configuration")?;
+    let Some(value) = lookup(&root, &segments) else {
+        bail!("Unknown setting: {key}");
+    };
+
+    if output.is_json() {
+        println!("{}", serde_json::to_string_pretty(value)?);
+    } else {
+        println!("{}", display_value(value));
+    }
+    Ok(())
+}
+
+/// Apply `key = value` to the TOML document and return the new file contents.
+///
+/// Fails without side effects if the key is unknown, the value does not fit
+/// the field's type, or the resulting config does not validate.
+pub(super) fn patch_document(content: &str, key: &str, value: &str) -> Result<String> {
+    let segments = split_key(key)?;
+
+    let current: Config = toml::from_str(content).context("Failed to parse config file")?;
+    let schema = toml::Value::try_from(&current).context("Failed to serialize configuration")?;
+
+    let candidates = match lookup(&schema, &segments) {
+        Some(existing) => vec![typed_value(existing, key, value)?],
+        None => inferred_values(value),
+    };
+
+    let mut last_err = None;
+    for new_value in candidates {
+        let mut doc: DocumentMut = content.parse().context("Failed to parse config file")?;
+        insert_value(doc.as_item_mut(), &segments, new_value, key)?;
+        let patched = doc.to_string();
+
+        let parsed: Config = match toml::from_str(&patched) {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                last_err = Some(anyhow::anyhow!("Invalid value for {key}: {}", e.message()));
+                continue;
+            }
+        };
+
+        // Serde ignores unknown keys, so confirm the key round-trips.
+        let round_trip = toml::Value::try_from(&parsed)?;
+        if lookup(&round_trip, &segments).is_none() {
+            bail!("Unknown setting: {key}");
+        }
+
+        validate_config(&parsed)?;
+        return Ok(patched);
+    }
+
+    Err(last_err.unwrap_or_else(|| anyhow::anyhow!("Invalid value for {key}: {value}")))
+}
+
+fn split_key(key: &str) -> Result<Vec<&str>> {
+    let segments: Vec<&str> = key.split('.').map(str::trim).collect();
+    if segments.iter().any(|s| s.is_empty()) {
+        bail!("Invalid key: {key:?} (expected a dotted path like scoring.threshold)");
+    }
+    Ok(segments)
+}
+
+fn lookup<'a>(root: &'a toml::Value, segments: &[&str]) -> Option<&'a toml::Value> {
+    segments.iter().try_fold(root, |node, seg| match node {
+        toml::Value::Table(t) => t.get(*seg),
+        toml::Value::Array(a) => seg.parse::<usize>().ok().and_then(|i| a.get(i)),
+        _ => None,
+    })
+}
+
+/// Convert `raw` to the TOML type of the existing value.
+fn typed_value(existing: &toml::Value, key: &str, raw: &str) -> Result<Value> {
+    Ok(match existing {
+        toml::Value::String(_) => Value::from(raw),
+        toml::Value::Integer(_) => raw
+            .trim()
+            .parse::<i64>()
+            .with_context(|| format!("{key} must be an integer"))?
+            .into(),
+        toml::Value::Float(_) => raw
+            .trim()
+            .parse::<f64>()
+            .with_context(|| format!("{key} must be a number"))?
+            .into(),
+        toml::Value::Boolean(_) => parse_bool(raw.trim())?.into(),
+        toml::Value::Array(items) => {
+            let mut array = Array::new();
+            for item in parse_csv(raw) {
+                match items.first() {
+                    Some(first @ (toml::Value::Integer(_) | toml::Value::Float(_))) => {
+                        array.push(typed_value(first, key, &item)?);
+                    }
+                    _ => array.push(item),
+                }
+            }
+            Value::Array(array)
+        }
+        toml::Value::Table(_) => {
+            bail!("{key} is a section; set one of its fields instead")
+        }
+        toml::Value::Datetime(_) => bail!("{key} cannot be set from the command line"),
+    })
+}
+
+/// Candidate encodings for a key that is currently unset (e.g. `Option` fields).
+fn inferred_values(raw: &str) -> Vec<Value> {
+    let trimmed = raw.trim();
+    let mut candidates = Vec::new();
+    if let Ok(b) = trimmed.parse::<bool>() {
+        candidates.push(b.into());
+    }
+    if let Ok(i) = trimmed.parse::<i64>() {
+        candidates.push(i.into());
+    } else if let Ok(f) = trimmed.parse::<f64>() {
+        candidates.push(f.into());
+    }
+    candidates.push(Value::from(raw));
+    if raw.contains(',') {
+        candidates.push(Value::Array(parse_csv(raw).into_iter().collect()));
+    }
+    candidates
+}
+
+/// Write `value` at `segments`, creating intermediate tables as needed.
+///
+/// Replacing an existing value keeps its surrounding whitespace and any
+/// trailing comment.
+fn insert_value(root: &mut Item, segments: &[&str], mut value: Value, key: &str) -> Result<()> {
+    let (leaf, parents) = segments.split_last().expect("split_key rejects empty keys");
+
+    let mut node = root;
+    for seg in parents {
+        if let Item::Table(table) = node {
+            if !table.contains_key(seg) {
+                let mut child = Table::new();
+                child.set_implicit(true);
+                table.insert(seg, Item::Table(child));
+            }
+        }
+        node = match seg.parse::<usize>() {
+            Ok(idx) => node.get_mut(idx),
+            Err(_) => node.get_mut(*seg),
+        }
+        .with_context(|| format!("Cannot set {key}: no such entry {seg:?}"))?;
+    }
+
+    let slot = match leaf.parse::<usize>() {
+        Ok(idx) => node.get_mut(idx),
+        Err(_) => node.get_mut(*leaf),
+    }
+    .with_context(|| format!("Cannot set {key}: no such entry {leaf:?}"))?;
+
+    if let Item::Value(old) = slot {
+        *value.decor_mut() = old.decor().clone();
+    }
+    *slot = Item::Value(value);
+    Ok(())
+}
+
+fn display_value(value: &toml::Value) -> String {
+    match value {
+        toml::Value::String(s) => s.clone(),
+        toml::Value::Array(items) if items.iter().all(|v| !v.is_table()) => items
+            .iter()
+            .map(display_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BASE: &str = r#"# Tuitbot config
+[business]
+product_name = "Tuitbot" # shown in replies
+product_description = "Growth assistant"
+product_keywords = ["rust", "cli"]
+industry_topics = ["devtools"]
+
+[x_api]
+client_id = "abc123"
+
+[scoring]
+# Minimum score to reply
+threshold = 60
+"#;
+
+    #[test]
+    fn set_scalar_preserves_comments() {
+        let out = patch_document(BASE, "scoring.threshold", "70").unwrap();
+        assert!(out.contains("threshold = 70"));
+        assert!(out.contains("# Minimum score to reply"));
+        assert!(out.contains("# shown in replies"));
+        let parsed: Config = toml::from_str(&out).unwrap();
+        assert_eq!(parsed.scoring.threshold, 70);
+    }
+
+    #[test]
+    fn set_list_from_csv() {
+        let out = patch_document(BASE, "business.product_keywords", "rust, tui, bots").unwrap();
+        let parsed: Config = toml::from_str(&out).unwrap();
+        assert_eq!(
+            parsed.business.product_keywords,
+            vec!["rust", "tui", "bots"]
+        );
+    }
+
+    #[test]
+    fn set_creates_missing_section() {
+        let out = patch_document(BASE, "limits.max_replies_per_day", "3").unwrap();
+        let parsed: Config = toml::from_str(&out).unwrap();
+        assert_eq!(parsed.limits.max_replies_per_day, 3);
+        assert!(out.contains("# Tuitbot config"));
+    }
+
+    #[test]
+    fn rejects_invalid_value() {
+        let err = patch_document(BASE, "scoring.threshold", "150").unwrap_err();
+        assert!(err.to_string().contains("validation failed"), "{err}");
+        let err = patch_document(BASE, "scoring.threshold", "high").unwrap_err();
+        assert!(err.to_string().contains("must be an integer"), "{err}");
+    }
+
+    #[test]
+    fn rejects_unknown_key() {
+        let err = patch_document(BASE, "scoring.not_a_field", "1").unwrap_err();
+        assert!(err.to_string().contains("Unknown setting"), "{err}");
+        assert!(patch_document(BASE, "scoring..threshold", "1").is_err());
+    }
+
+    #[test]
+    fn set_key_writes_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, BASE).unwrap();
+
+        set_key(&path, "scoring.threshold", "75").unwrap();
+
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .contains("threshold = 75"));
+        assert_eq!(
+            fs::read_to_string(path.with_extension("toml.bak")).unwrap(),
+            BASE
+        );
+    }
+
+    #[test]
+    fn invalid_set_leaves_file_untouched() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        fs::write(&path, BASE).unwrap();
+
+        assert!(set_key(&path, "scoring.threshold", "-5").is_err());
+        assert_eq!(fs::read_to_string(&path).unwrap(), BASE);
+        assert!(!path.with_extension("toml.bak").exists());
+    }
+
+    #[test]
+    fn display_joins_string_lists() {
+        let value = toml::Value::try_from(vec!["a", "b"]).unwrap();
+        assert_eq!(display_value(&value), "a, b");
+        assert_eq!(display_value(&toml::Value::Integer(5)), "5");
+    }
+}
@@ -4,7 +4,10 @@
 /// - `tuitbot settings`              — interactive category menu
 /// - `tuitbot settings --show`       — pretty-print current config
 /// - `tuitbot settings --set K=V`    — direct one-shot set
+/// - `tuitbot settings set K V`      — patch one key, preserving comments
+/// - `tuitbot settings get K`        — print one key's current value
 /// - `tuitbot settings <category>`   — jump to a specific category
+mod edit;
 mod enrich;
 mod helpers;
 mod interactive;
@@ -21,7 +24,7 @@ use std::path::PathBuf;
 use anyhow::{bail, Result};
 use tuitbot_core::config::Config;
 
-use super::{OutputFormat, SettingsArgs};
+use super::{OutputFormat, SettingsArgs, SettingsSubcommand};
 
 /// Entry point for the settings command.
 pub async fn execute(args: SettingsArgs, config_path: &str, output: OutputFormat) -> Result<()> {
@@ -40,6 +43,14 @@ pub async fn execute(args: SettingsArgs, config_path: &str, output: OutputFormat
         )
     })?;
 
+    match args.command {
+        Some(SettingsSubcommand::Set { key, value }) => {
+            return edit::set_key(&expanded, &key, &value);
+        }
+        Some(SettingsSubcommand::Get { key }) => return edit::get_key(&config, &key, output),
+        None => {}
+    }
+
     if args.show && args.set.is_some() {
         bail!(
             "--show and --set are mutually exclusive.\n\
@@ -4,7 +4,7 @@ use std::path::Path;
 use anyhow::{bail, Context, Result};
 use console::Style;
 use dialoguer::Confirm;
-use tuitbot_core::config::Config;
+use tuitbot_core::config::{Config, LlmBackoff, LlmConfig, LlmRetryConfig};
 
 use super::helpers::{escape_toml, format_toml_array, ChangeTracker};
 
@@ -93,6 +93,41 @@ pub(super) fn render_config(config: &Config) -> String {
         None => "# base_url = \"http://localhost:11434/v1\"".to_string(),
     };
 
+    // Only written when changed from the defaults, to keep the file short.
+    let retry = &config.llm.retry;
+    let ramp_section = match &config.limits.ramp {
+        Some(ramp) => format!(
+            "\n\n[limits.ramp]\nstart = {}\ntarget = {}\ndays = {}",
+            ramp.start, ramp.target, ramp.days
+        ),
+        None => String::new(),
+    };
+
+    let llm_defaults = LlmConfig::default();
+    let mut budget_lines = String::new();
+    if config.llm.max_tokens_cap != llm_defaults.max_tokens_cap {
+        budget_lines.push_str(&format!("\nmax_tokens_cap = {}", config.llm.max_tokens_cap));
+    }
+    if config.llm.session_token_budget != llm_defaults.session_token_budget {
+        budget_lines.push_str(&format!(
+            "\nsession_token_budget = {}",
+            config.llm.session_token_budget
+        ));
+    }
+
+    let retry_section = if *retry == LlmRetryConfig::default() {
+        String::new()
+    } else {
+        let backoff = match retry.backoff {
+            LlmBackoff::Exponential => "exponential",
+            LlmBackoff::Fixed => "fixed",
+        };
+        format!(
+            "\n\n[llm.retry]\nmax_attempts = {}\nbase_delay_ms = {}\nbackoff = \"{backoff}\"",
+            retry.max_attempts, retry.base_delay_ms,
+        )
+    };
+
     format!(
         r#"# =============================================================================
 # Tuitbot Configuration
@@ -117,6 +152,7 @@ client_id = "{client_id}"
 mode = "{auth_mode}"
 callback_host = "{callback_host}"
 callback_port = {callback_port}
+token_storage = "{token_storage}"
 
 # --- Business Profile ---
 # Describe your product so Tuitbot can find relevant conversations
@@ -157,6 +193,8 @@ recency_max = {recency_max:.1}
 engagement_rate_max = {engagement_rate_max:.1}
 reply_count_max = {reply_count_max:.1}
 content_type_max = {content_type_max:.1}
+recency_curve = "{recency_curve}"
+recency_half_life_minutes = {recency_half_life_minutes}
 
 # --- Safety Limits ---
 # Prevent aggressive posting that could trigger account restrictions.
@@ -167,14 +205,19 @@ max_threads_per_week = {max_threads_per_week}
 min_action_delay_seconds = {min_action_delay_seconds}
 max_action_delay_seconds = {max_action_delay_seconds}
 max_replies_per_author_per_day = {max_replies_per_author_per_day}
+max_replies_per_conversation_per_day = {max_replies_per_conversation_per_day}
+max_likes_per_day = {max_likes_per_day}
+max_retweets_per_day = {max_retweets_per_day}
+discovery_likes = {discovery_likes}
 product_mention_ratio = {product_mention_ratio}
-banned_phrases = {banned_phrases}
+banned_phrases = {banned_phrases}{ramp_section}
 
 # --- Automation Intervals ---
 # How often each loop runs. Shorter intervals use more API quota.
 [intervals]
 mentions_check_seconds = {mentions_check_seconds}
 discovery_search_seconds = {discovery_search_seconds}
+discovery_max_pages = {discovery_max_pages}
 content_post_window_seconds = {content_post_window_seconds}
 thread_interval_seconds = {thread_interval_seconds}
 
@@ -186,7 +229,7 @@ thread_interval_seconds = {thread_interval_seconds}
 provider = "{llm_provider}"
 {api_key_line}
 model = "{llm_model}"
-{base_url_line}
+{base_url_line}{budget_lines}{retry_section}
 
 # --- Data Storage ---
 [storage]
@@ -212,6 +255,7 @@ active_days = {active_days}
         auth_mode = escape_toml(&config.auth.mode),
         callback_host = escape_toml(&config.auth.callback_host),
         callback_port = config.auth.callback_port,
+        token_storage = escape_toml(&config.auth.token_storage),
         product_name = escape_toml(&config.business.product_name),
         product_description = escape_toml(&config.business.product_description),
         product_url_line = product_url_line,
@@ -232,16 +276,24 @@ active_days = {active_days}
         engagement_rate_max = config.scoring.engagement_rate_max,
         reply_count_max = config.scoring.reply_count_max,
         content_type_max = config.scoring.content_type_max,
+        recency_curve = config.scoring.recency_curve,
+        recency_half_life_minutes = config.scoring.recency_half_life_minutes,
         max_replies_per_day = config.limits.max_replies_per_day,
         max_tweets_per_day = config.limits.max_tweets_per_day,
         max_threads_per_week = config.limits.max_threads_per_week,
         min_action_delay_seconds = config.limits.min_action_delay_seconds,
         max_action_delay_seconds = config.limits.max_action_delay_seconds,
         max_replies_per_author_per_day = config.limits.max_replies_per_author_per_day,
+        max_replies_per_conversation_per_day = config.limits.max_replies_per_conversation_per_day,
+        max_likes_per_day = config.limits.max_likes_per_day,
+        max_retweets_per_day = config.limits.max_retweets_per_day,
+        discovery_likes = config.limits.discovery_likes,
         product_mention_ratio = config.limits.product_mention_ratio,
         banned_phrases = format_toml_array(&config.limits.banned_phrases),
+        ramp_section = ramp_section,
         mentions_check_seconds = config.intervals.mentions_check_seconds,
         discovery_search_seconds = config.intervals.discovery_search_seconds,
+        discovery_max_pages = config.intervals.discovery_max_pages,
         content_post_window_seconds = config.intervals.content_post_window_seconds,
         thread_interval_seconds = config.intervals.thread_interval_seconds,
         targets_section = targets_section,
@@ -249,6 +301,8 @@ active_days = {active_days}
         api_key_line = api_key_line,
         llm_model = escape_toml(&config.llm.model),
         base_url_line = base_url_line,
+        budget_lines = budget_lines,
+        retry_section = retry_section,
         db_path = escape_toml(&config.storage.db_path),
         retention_days = config.storage.retention_days,
         status_interval_seconds = config.logging.status_interval_seconds,
@@ -696,6 +696,13 @@ pub(super) fn set_direct(config: &mut Config, kv: &str, config_path: &Path) -> R
             "content_type_max",
             value,
         )?,
+        "scoring.recency_half_life_minutes" => set_u32(
+            &mut tracker,
+            &mut config.scoring.recency_half_life_minutes,
+            "scoring",
+            "recency_half_life_minutes",
+            value,
+        )?,
 
         // Limits
         "limits.max_replies_per_day" => set_u32(
@@ -740,6 +747,34 @@ pub(super) fn set_direct(config: &mut Config, kv: &str, config_path: &Path) -> R
             "max_replies_per_author_per_day",
             value,
         )?,
+        "limits.max_replies_per_conversation_per_day" => set_u32(
+            &mut tracker,
+            &mut config.limits.max_replies_per_conversation_per_day,
+            "limits",
+            "max_replies_per_conversation_per_day",
+            value,
+        )?,
+        "limits.max_likes_per_day" => set_u32(
+            &mut tracker,
+            &mut config.limits.max_likes_per_day,
+            "limits",
+            "max_likes_per_day",
+            value,
+        )?,
+        "limits.max_retweets_per_day" => set_u32(
+            &mut tracker,
+            &mut config.limits.max_retweets_per_day,
+            "limits",
+            "max_retweets_per_day",
+            value,
+        )?,
+        "limits.discovery_likes" => set_bool(
+            &mut tracker,
+            &mut config.limits.discovery_likes,
+            "limits",
+            "discovery_likes",
+            value,
+        )?,
         "limits.product_mention_ratio" => set_f32_fmt2(
             &mut tracker,
             &mut config.limits.product_mention_ratio,
@@ -771,6 +806,13 @@ pub(super) fn set_direct(config: &mut Config, kv: &str, config_path: &Path) -> R
             "discovery_search_seconds",
             value,
         )?,
+        "intervals.discovery_max_pages" => set_u32(
+            &mut tracker,
+            &mut config.intervals.discovery_max_pages,
+            "intervals",
+            "discovery_max_pages",
+            value,
+        )?,
         "intervals.content_post_window_seconds" => set_u64(
             &mut tracker,
             &mut config.intervals.content_post_window_seconds,
@@ -801,6 +843,13 @@ pub(super) fn set_direct(config: &mut Config, kv: &str, config_path: &Path) -> R
             "max_target_replies_per_day",
             value,
         )?,
+        "targets.recency_bonus_max" => set_f32(
+            &mut tracker,
+            &mut config.targets.recency_bonus_max,
+            "targets",
+            "recency_bonus_max",
+            value,
+        )?,
 
         // LLM
         "llm.provider" => set_string(
@@ -145,6 +145,23 @@ pub(super) fn show_config(config: &Config) {
         "  Replies/author/day:  {}",
         config.limits.max_replies_per_author_per_day
     );
+    eprintln!(
+        "  Replies/thread/day:  {}",
+        config.limits.max_replies_per_conversation_per_day
+    );
+    eprintln!(
+        "  Likes/day:           {}{}",
+        config.limits.max_likes_per_day,
+        if config.limits.discovery_likes {
+            " (discovery likes on)"
+        } else {
+            ""
+        }
+    );
+    eprintln!(
+        "  Retweets/day:        {}",
+        config.limits.max_retweets_per_day
+    );
     eprintln!(
         "  Product mention %:   {:.0}%",
         config.limits.product_mention_ratio * 100.0
@@ -170,8 +187,10 @@ pub(super) fn show_config(config: &Config) {
         config.scoring.follower_count_max
     );
     eprintln!(
-        "  Recency:             {:.0} pts max",
-        config.scoring.recency_max
+        "  Recency:             {:.0} pts max ({}, half-life {}m)",
+        config.scoring.recency_max,
+        config.scoring.recency_curve,
+        config.scoring.recency_half_life_minutes
     );
     eprintln!(
         "  Engagement rate:     {:.0} pts max",
@@ -283,18 +302,7 @@ pub(super) fn show_config(config: &Config) {
 
 /// Output configuration as JSON with secrets redacted.
 pub(super) fn show_config_json(config: &Config) -> Result<()> {
-    let mut config = config.clone();
-    config.llm.api_key = config
-        .llm
-        .api_key
-        .as_ref()
-        .map(|_| "***REDACTED***".to_string());
-    config.x_api.client_secret = config
-        .x_api
-        .client_secret
-        .as_ref()
-        .map(|_| "***REDACTED***".to_string());
-    write_stdout(&serde_json::to_string(&config)?)?;
+    write_stdout(&serde_json::to_string(&config.redacted())?)?;
     Ok(())
 }
 
@@ -120,6 +120,36 @@ fn render_config_roundtrip() {
     );
 }
 
+#[test]
+fn render_config_keeps_non_default_llm_retry() {
+    let mut config = Config::default();
+    config.llm.retry.max_attempts = 5;
+    config.llm.retry.backoff = tuitbot_core::config::LlmBackoff::Fixed;
+
+    let toml_str = render_config(&config);
+    let parsed: Config = toml::from_str(&toml_str).expect("rendered config should parse");
+    assert_eq!(parsed.llm.retry, config.llm.retry);
+
+    let default_toml = render_config(&Config::default());
+    assert!(!default_toml.contains("[llm.retry]"));
+}
+
+#[test]
+fn render_config_keeps_non_default_llm_budget() {
+    let mut config = Config::default();
+    config.llm.max_tokens_cap = 1024;
+    config.llm.session_token_budget = 200_000;
+
+    let toml_str = render_config(&config);
+    let parsed: Config = toml::from_str(&toml_str).expect("rendered config should parse");
+    assert_eq!(parsed.llm.max_tokens_cap, 1024);
+    assert_eq!(parsed.llm.session_token_budget, 200_000);
+
+    let default_toml = render_config(&Config::default());
+    assert!(!default_toml.contains("max_tokens_cap"));
+    assert!(!default_toml.contains("session_token_budget"));
+}
+
 #[test]
 fn render_config_with_all_fields() {
     let mut config = Config::default();
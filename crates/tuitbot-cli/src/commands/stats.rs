@@ -1,12 +1,21 @@
 //! Implementation of the `tuitbot stats` command.
 //!
 //! Displays analytics dashboard: follower trend, top-performing topics,
-//! engagement rates, and weekly volume.
+//! engagement rates, and weekly volume. `--compare` shows period-over-period
+//! deltas instead, and `--watch` redraws the dashboard in place on a timer.
+
+use std::future::Future;
+use std::io::{self, IsTerminal};
+use std::time::Duration;
 
 use serde::Serialize;
 use tuitbot_core::config::Config;
+use tuitbot_core::content::frameworks;
 use tuitbot_core::storage;
+use tuitbot_core::storage::analytics::{ComparePeriod, PeriodComparison, PeriodStats};
+use tuitbot_core::storage::content_frameworks::FrameworkPerformance;
 
+use super::StatsArgs;
 use crate::output::CliOutput;
 
 #[derive(Serialize)]
@@ -46,9 +55,55 @@ struct StatsOutput {
     content_measured: ContentMeasuredJson,
 }
 
+#[derive(Serialize)]
+struct FrameworkStatsOutput {
+    frameworks: Vec<FrameworkPerformance>,
+}
+
 /// Execute the `tuitbot stats` command.
-pub async fn execute(config: &Config, out: CliOutput) -> anyhow::Result<()> {
-    let pool = storage::init_db(&config.storage.db_path).await?;
+pub async fn execute(config: &Config, args: StatsArgs, out: CliOutput) -> anyhow::Result<()> {
+    if args.watch && (out.is_json() || out.quiet || !io::stderr().is_terminal()) {
+        anyhow::bail!(
+            "--watch needs an interactive terminal.\n\
+             Use `tuitbot stats --output json` to read analytics from scripts."
+        );
+    }
+
+    let pool = storage::init_db_with_config(&config.storage.db_path, &config.storage).await?;
+
+    if args.watch {
+        let result = watch(&pool, Duration::from_secs(args.interval.max(1))).await;
+        pool.close().await;
+        return result;
+    }
+
+    if args.by_framework {
+        let result = frameworks::performance_report(&pool).await;
+        pool.close().await;
+        let report = result?;
+        if out.is_json() {
+            out.json(&FrameworkStatsOutput { frameworks: report })?;
+        } else if !out.quiet {
+            print_framework_report(&report);
+        }
+        return Ok(());
+    }
+
+    if let Some(period) = args.compare.as_deref().and_then(ComparePeriod::parse) {
+        let result = storage::analytics::compare_periods(&pool, period, chrono::Utc::now()).await;
+        pool.close().await;
+        let comparison = result?;
+        if out.is_json() {
+            out.json(&comparison)?;
+        } else if !out.quiet {
+            eprintln!();
+            for line in compare_lines(&comparison) {
+                eprintln!("{line}");
+            }
+            eprintln!();
+        }
+        return Ok(());
+    }
 
     if out.is_json() {
         let result = collect_stats_json(&pool).await;
@@ -63,25 +118,80 @@ pub async fn execute(config: &Config, out: CliOutput) -> anyhow::Result<()> {
     out.info("");
 
     if !out.quiet {
-        // 1. Follower trend (7 days)
-        print_follower_trend(&pool).await;
+        print_dashboard(&pool).await;
+    }
+
+    pool.close().await;
+    Ok(())
+}
+
+async fn print_dashboard(pool: &storage::DbPool) {
+    // 1. Follower trend (7 days)
+    print_follower_trend(pool).await;
+
+    // 2. Top performing topics
+    print_top_topics(pool).await;
 
-        // 2. Top performing topics
-        print_top_topics(&pool).await;
+    // 3. Engagement rates
+    print_engagement_rates(pool).await;
 
-        // 3. Engagement rates
-        print_engagement_rates(&pool).await;
+    // 4. Performance counts
+    print_performance_counts(pool).await;
 
-        // 4. Performance counts
-        print_performance_counts(&pool).await;
+    eprintln!();
+}
 
+/// `--watch`: clear the terminal and redraw the dashboard every `interval`.
+///
+/// Stops on Ctrl-C.
+async fn watch(pool: &storage::DbPool, interval: Duration) -> anyhow::Result<()> {
+    let term = &console::Term::stderr();
+    let refreshes = refresh_until(interval, tokio::signal::ctrl_c(), || async move {
+        term.clear_screen()?;
+        eprintln!("=== Tuitbot Analytics ===");
+        eprintln!(
+            "Updated {} · refreshing every {}s · Ctrl-C to stop",
+            chrono::Local::now().format("%H:%M:%S"),
+            interval.as_secs()
+        );
         eprintln!();
-    }
+        print_dashboard(pool).await;
+        Ok(())
+    })
+    .await?;
 
-    pool.close().await;
+    eprintln!("\nStopped watching after {refreshes} refresh(es).");
     Ok(())
 }
 
+/// Call `render` immediately and then once per `interval` until `stop`
+/// resolves, returning how many times it ran.
+///
+/// A render that overruns the interval delays the next tick instead of
+/// triggering a burst of catch-up redraws.
+async fn refresh_until<S, F, Fut>(interval: Duration, stop: S, mut render: F) -> anyhow::Result<u32>
+where
+    S: Future,
+    F: FnMut() -> Fut,
+    Fut: Future<Output = anyhow::Result<()>>,
+{
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    tokio::pin!(stop);
+
+    let mut renders = 0u32;
+    loop {
+        tokio::select! {
+            _ = &mut stop => break,
+            _ = ticker.tick() => {
+                render().await?;
+                renders += 1;
+            }
+        }
+    }
+    Ok(renders)
+}
+
 async fn collect_stats_json(pool: &storage::DbPool) -> anyhow::Result<StatsOutput> {
     let snapshots = storage::analytics::get_follower_snapshots(pool, 7)
         .await
@@ -220,6 +330,97 @@ async fn print_engagement_rates(pool: &storage::DbPool) {
     eprintln!();
 }
 
+fn print_framework_report(report: &[FrameworkPerformance]) {
+    eprintln!();
+    eprintln!("--- Performance by Framework ---");
+    if report.is_empty() {
+        eprintln!("  No data yet. Frameworks are recorded when approved items are posted.");
+        eprintln!();
+        return;
+    }
+    for line in framework_lines(report) {
+        eprintln!("{line}");
+    }
+    eprintln!();
+}
+
+/// One line per framework, grouped under a header per kind.
+fn framework_lines(report: &[FrameworkPerformance]) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current_kind = "";
+    for row in report {
+        if row.kind != current_kind {
+            current_kind = &row.kind;
+            lines.push(format!("  [{current_kind}]"));
+        }
+        lines.push(format!(
+            "    {:<22} | Posted: {:>4} | Measured: {:>4} | Avg score: {:>5.1} | Avg likes: {:>6.1}",
+            row.framework, row.posted, row.measured, row.avg_performance, row.avg_likes,
+        ));
+    }
+    lines
+}
+
+/// Side-by-side table of the current and prior period with percentage deltas.
+fn compare_lines(cmp: &PeriodComparison) -> Vec<String> {
+    let label = match cmp.period {
+        ComparePeriod::Dod => "Day over Day",
+        ComparePeriod::Wow => "Week over Week",
+        ComparePeriod::Mom => "Month over Month",
+    };
+    let mut lines = vec![
+        format!("--- {label} ---"),
+        format!(
+            "  {:<18} | {:>9} | {:>9} | {:>8}",
+            "", "Current", "Prior", "Change"
+        ),
+    ];
+
+    let prev = cmp.previous.as_ref();
+    let mut row = |name: &str, value: fn(&PeriodStats) -> Option<String>, delta: Option<f64>| {
+        lines.push(format!(
+            "  {:<18} | {:>9} | {:>9} | {:>8}",
+            name,
+            value(&cmp.current).unwrap_or_else(|| "-".to_string()),
+            prev.map_or_else(
+                || "n/a".to_string(),
+                |p| value(p).unwrap_or_else(|| "-".to_string())
+            ),
+            delta.map_or_else(|| "-".to_string(), |d| format!("{d:+.1}%")),
+        ));
+    };
+    row(
+        "Replies sent",
+        |s| Some(s.replies_sent.to_string()),
+        cmp.deltas.replies_sent,
+    );
+    row(
+        "Tweets posted",
+        |s| Some(s.tweets_posted.to_string()),
+        cmp.deltas.tweets_posted,
+    );
+    row(
+        "Avg reply score",
+        |s| s.avg_reply_score.map(|v| format!("{v:.1}")),
+        cmp.deltas.avg_reply_score,
+    );
+    row(
+        "Avg tweet score",
+        |s| s.avg_tweet_score.map(|v| format!("{v:.1}")),
+        cmp.deltas.avg_tweet_score,
+    );
+    row(
+        "Follower change",
+        |s| s.follower_change.map(|v| format!("{v:+}")),
+        cmp.deltas.follower_change,
+    );
+
+    if prev.is_none() {
+        lines.push("  Not enough history for the prior period yet.".to_string());
+    }
+    lines
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -473,6 +674,97 @@ mod tests {
         assert_eq!(parsed["content_measured"]["replies"], 3);
         assert_eq!(parsed["content_measured"]["tweets"], 4);
     }
+
+    #[test]
+    fn compare_lines_show_deltas_and_missing_history() {
+        let stats = |replies: i64| PeriodStats {
+            replies_sent: replies,
+            follower_change: Some(replies),
+            ..Default::default()
+        };
+        let mut cmp = PeriodComparison {
+            period: ComparePeriod::Wow,
+            current: stats(6),
+            previous: Some(stats(4)),
+            deltas: tuitbot_core::storage::analytics::compute_deltas(&stats(6), &stats(4)),
+        };
+        let lines = compare_lines(&cmp);
+        assert_eq!(lines[0], "--- Week over Week ---");
+        assert!(lines[2].contains("Replies sent") && lines[2].contains("+50.0%"));
+        assert!(lines[4].contains("Avg reply score") && !lines[4].contains("n/a"));
+        assert!(lines[6].contains("+6") && lines[6].contains("+4"));
+        assert_eq!(lines.len(), 7);
+
+        cmp.previous = None;
+        cmp.deltas = Default::default();
+        let lines = compare_lines(&cmp);
+        assert!(lines[2].contains("n/a"));
+        assert!(lines.last().unwrap().contains("Not enough history"));
+    }
+
+    #[tokio::test]
+    async fn refresh_until_renders_on_each_tick_until_stopped() {
+        let (stop_tx, stop_rx) = tokio::sync::oneshot::channel::<()>();
+        let mut stop_tx = Some(stop_tx);
+        let mut calls = 0u32;
+        let interval = Duration::from_millis(20);
+        let started = std::time::Instant::now();
+
+        let renders = refresh_until(interval, stop_rx, || {
+            calls += 1;
+            if calls == 3 {
+                stop_tx.take().unwrap().send(()).unwrap();
+            }
+            async { Ok(()) }
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(renders, 3);
+        // First render is immediate; the next two each wait one interval.
+        assert!(started.elapsed() >= interval * 2);
+    }
+
+    #[tokio::test]
+    async fn refresh_until_stops_on_render_error() {
+        let mut calls = 0u32;
+        let err = refresh_until(
+            Duration::from_millis(1),
+            std::future::pending::<()>(),
+            || {
+                calls += 1;
+                async { Err(anyhow::anyhow!("db gone")) }
+            },
+        )
+        .await
+        .unwrap_err();
+        assert_eq!(err.to_string(), "db gone");
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn framework_lines_group_rows_by_kind() {
+        let row = |kind: &str, framework: &str| FrameworkPerformance {
+            kind: kind.to_string(),
+            framework: framework.to_string(),
+            posted: 2,
+            measured: 1,
+            avg_performance: 42.0,
+            avg_likes: 3.0,
+            avg_replies: 1.0,
+            avg_impressions: 100.0,
+        };
+        let lines = framework_lines(&[
+            row("archetype", "ask_question"),
+            row("format", "list"),
+            row("format", "tip"),
+        ]);
+        assert_eq!(lines.len(), 5);
+        assert_eq!(lines[0], "  [archetype]");
+        assert_eq!(lines[2], "  [format]");
+        assert!(lines[3].contains("list"));
+        assert!(lines[3].contains("42.0"));
+    }
 }
 
 async fn print_performance_counts(pool: &storage::DbPool) {
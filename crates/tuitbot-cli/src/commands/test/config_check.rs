@@ -0,0 +1,93 @@
+//! `tuitbot test --config-check` — offline configuration validation for CI.
+//!
+//! Loads and validates `config.toml` without touching the network or the
+//! database, and reports the outcome through a stable exit-code contract so
+//! pipelines can tell "config is wrong" apart from "connectivity is down".
+
+use serde::Serialize;
+use tuitbot_core::config::Config;
+use tuitbot_core::error::ConfigError;
+use tuitbot_core::startup::expand_tilde;
+
+use crate::output::CliOutput;
+
+/// Exit code: configuration is valid.
+pub const EXIT_VALID: i32 = 0;
+/// Exit code: configuration loaded but failed validation.
+pub const EXIT_INVALID: i32 = 2;
+/// Exit code: config file not found or could not be parsed.
+pub const EXIT_LOAD_FAILED: i32 = 3;
+
+#[derive(Debug, Serialize)]
+struct ConfigCheckOutput {
+    valid: bool,
+    exit_code: i32,
+    path: String,
+    errors: Vec<String>,
+    /// Non-fatal findings, e.g. style fields that contradict generator rules.
+    warnings: Vec<String>,
+}
+
+fn evaluate(config_path: &str) -> ConfigCheckOutput {
+    let path = expand_tilde(config_path).display().to_string();
+    let mut warnings = Vec::new();
+    let (exit_code, errors) = match Config::load(Some(config_path)) {
+        Ok(config) => {
+            warnings = config
+                .style_warnings()
+                .iter()
+                .map(ToString::to_string)
+                .collect();
+            match config.validate() {
+                Ok(()) => (EXIT_VALID, vec![]),
+                Err(errors) => (
+                    EXIT_INVALID,
+                    errors.iter().map(ToString::to_string).collect(),
+                ),
+            }
+        }
+        Err(e @ (ConfigError::FileNotFound { .. } | ConfigError::ParseError { .. })) => {
+            (EXIT_LOAD_FAILED, vec![e.to_string()])
+        }
+        // Env-var overrides that fail to apply are invalid values, not load failures.
+        Err(e) => (EXIT_INVALID, vec![e.to_string()]),
+    };
+    ConfigCheckOutput {
+        valid: exit_code == EXIT_VALID,
+        exit_code,
+        path,
+        errors,
+        warnings,
+    }
+}
+
+/// Execute `tuitbot test --config-check`.
+///
+/// Prints nothing on success under `--quiet`, including warnings. Exits the
+/// process with [`EXIT_INVALID`] or [`EXIT_LOAD_FAILED`] on failure; warnings
+/// never change the exit code.
+pub fn execute_config_check(config_path: &str, out: CliOutput) -> anyhow::Result<()> {
+    let result = evaluate(config_path);
+
+    if out.is_json() {
+        out.json(&result)?;
+    } else {
+        if !out.quiet {
+            for warning in &result.warnings {
+                eprintln!("Warning: {warning}");
+            }
+        }
+        if result.valid {
+            out.info(&format!("Configuration valid: {}", result.path));
+        } else {
+            for error in &result.errors {
+                eprintln!("Error: {error}");
+            }
+        }
+    }
+
+    if result.exit_code != EXIT_VALID {
+        std::process::exit(result.exit_code);
+    }
+    Ok(())
+}
@@ -3,15 +3,23 @@
 //! Validates configuration, credentials, and connectivity before
 //! running the agent. Each check runs independently -- a failure
 //! in one does not skip others.
+//!
+//! `--config-check` runs only the offline configuration validation
+//! (see [`config_check`]).
 
+mod config_check;
 #[cfg(test)]
 mod tests;
 
+pub use config_check::execute_config_check;
+
 use serde::Serialize;
 use tuitbot_core::config::Config;
 use tuitbot_core::error::LlmError;
 use tuitbot_core::llm::factory::create_provider;
-use tuitbot_core::startup::{expand_tilde, load_tokens_from_file, StartupError, StoredTokens};
+use tuitbot_core::safety::RateLimiter;
+use tuitbot_core::startup::{expand_tilde, load_tokens, StartupError, StoredTokens};
+use tuitbot_core::storage;
 
 use crate::output::CliOutput;
 
@@ -70,6 +78,8 @@ struct AuthDetails {
 struct TestOutput {
     passed: bool,
     checks: Vec<CheckResult>,
+    /// Non-fatal config findings; they never fail the run.
+    warnings: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     auth_details: Option<AuthDetails>,
 }
@@ -111,6 +121,7 @@ fn build_test_output(checks: Vec<CheckResult>, auth_details: Option<AuthDetails>
     TestOutput {
         passed,
         checks,
+        warnings: Vec::new(),
         auth_details,
     }
 }
@@ -121,6 +132,7 @@ fn build_test_output(checks: Vec<CheckResult>, auth_details: Option<AuthDetails>
 /// Does **not** call `process::exit` — callers decide what to do on failure.
 pub async fn run_checks(config: &Config, config_path: &str) -> bool {
     let mut results = collect_checks(config, config_path);
+    results.extend(check_rate_limits(config).await);
     results.push(check_llm_connectivity(config).await);
 
     // Print results.
@@ -130,6 +142,14 @@ pub async fn run_checks(config: &Config, config_path: &str) -> bool {
     }
     eprintln!();
 
+    let warnings = config.style_warnings();
+    for warning in &warnings {
+        eprintln!("Warning: {warning}");
+    }
+    if !warnings.is_empty() {
+        eprintln!();
+    }
+
     let all_passed = results.iter().all(|r| r.passed);
     if all_passed {
         eprintln!("All checks passed.");
@@ -171,11 +191,17 @@ pub async fn execute(config: &Config, config_path: &str, out: CliOutput) -> anyh
                 },
             }
         } else {
-            evaluate_auth(load_tokens_from_file())
+            evaluate_auth(load_tokens(&config.auth))
         };
         let mut checks = collect_checks_with_auth(config, config_path, auth.checks);
+        checks.extend(check_rate_limits(config).await);
         checks.push(check_llm_connectivity(config).await);
-        let output = build_test_output(checks, Some(auth.details));
+        let mut output = build_test_output(checks, Some(auth.details));
+        output.warnings = config
+            .style_warnings()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
         out.json(&output)?;
         if !output.passed {
             std::process::exit(1);
@@ -238,7 +264,7 @@ fn check_auth(config: &Config) -> Vec<CheckResult> {
             "skipped (Local No-Key Mode — scraper backend)",
         )];
     }
-    evaluate_auth(load_tokens_from_file()).checks
+    evaluate_auth(load_tokens(&config.auth)).checks
 }
 
 fn evaluate_auth(tokens_result: Result<StoredTokens, StartupError>) -> AuthEvaluation {
@@ -474,6 +500,38 @@ fn check_database(config: &Config) -> CheckResult {
     }
 }
 
+/// Report the remaining posting budget from an existing database.
+///
+/// Skipped (returns `None`) when the database has not been created yet.
+async fn check_rate_limits(config: &Config) -> Option<CheckResult> {
+    let db_path = expand_tilde(config.storage.db_path.trim());
+    if !db_path.is_file() {
+        return None;
+    }
+    let pool = match storage::init_db_with_config(&config.storage.db_path, &config.storage).await {
+        Ok(pool) => pool,
+        Err(e) => return Some(CheckResult::fail("Rate limits", format!("{e}"))),
+    };
+    let result = rate_limit_result(&RateLimiter::new(pool.clone())).await;
+    pool.close().await;
+    Some(result)
+}
+
+async fn rate_limit_result(limiter: &RateLimiter) -> CheckResult {
+    let mut parts = Vec::new();
+    for (label, action_type) in [
+        ("replies", "reply"),
+        ("tweets", "tweet"),
+        ("threads", "thread"),
+    ] {
+        match limiter.remaining(action_type).await {
+            Ok(n) => parts.push(format!("{n} {label}")),
+            Err(e) => return CheckResult::fail("Rate limits", format!("{e}")),
+        }
+    }
+    CheckResult::ok("Rate limits", format!("{} left", parts.join(", ")))
+}
+
 /// Check LLM connectivity by creating the provider and calling health_check.
 async fn check_llm_connectivity(config: &Config) -> CheckResult {
     let provider = match create_provider(&config.llm) {
@@ -486,12 +544,28 @@ async fn check_llm_connectivity(config: &Config) -> CheckResult {
         }
     };
 
-    match provider.health_check().await {
+    let start = std::time::Instant::now();
+    let result = provider.health_check().await;
+    llm_health_result(provider.name(), result, start.elapsed())
+}
+
+/// Format a health-check outcome with its latency.
+fn llm_health_result(
+    name: &str,
+    result: Result<(), LlmError>,
+    elapsed: std::time::Duration,
+) -> CheckResult {
+    let ms = elapsed.as_millis();
+    match result {
         Ok(()) => CheckResult::ok(
             "LLM connectivity",
-            format!("{}: reachable", provider.name()),
+            format!("{name}: reachable, model available ({ms}ms)"),
+        ),
+        Err(e @ LlmError::ModelUnavailable { .. }) => CheckResult::fail(
+            "LLM connectivity",
+            format!("{name}: reachable, but {e} ({ms}ms)"),
         ),
-        Err(e) => CheckResult::fail("LLM connectivity", format!("{}: {e}", provider.name())),
+        Err(e) => CheckResult::fail("LLM connectivity", format!("{name}: {e} ({ms}ms)")),
     }
 }
 
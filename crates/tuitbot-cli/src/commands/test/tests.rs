@@ -128,6 +128,7 @@ fn check_llm_connectivity_not_configured() {
         api_key: None,
         model: String::new(),
         base_url: None,
+        ..Default::default()
     };
     let result = check_llm_connectivity_sync(&config);
     assert!(!result.passed);
@@ -142,6 +143,7 @@ fn check_llm_connectivity_bad_provider_returns_fail() {
         api_key: None,
         model: String::new(),
         base_url: None,
+        ..Default::default()
     };
     let result = check_llm_connectivity_sync(&config);
     assert!(!result.passed);
@@ -149,6 +151,44 @@ fn check_llm_connectivity_bad_provider_returns_fail() {
     assert!(result.message.contains("not configured"));
 }
 
+#[test]
+fn llm_health_result_reports_latency_and_model() {
+    let result = llm_health_result("openai", Ok(()), std::time::Duration::from_millis(42));
+    assert!(result.passed);
+    assert_eq!(result.message, "openai: reachable, model available (42ms)");
+}
+
+#[test]
+fn llm_health_result_model_unavailable_fails() {
+    let result = llm_health_result(
+        "ollama",
+        Err(LlmError::ModelUnavailable {
+            model: "llama9".to_string(),
+        }),
+        std::time::Duration::from_millis(7),
+    );
+    assert!(!result.passed);
+    assert!(result
+        .message
+        .contains("reachable, but model 'llama9' is not available"));
+}
+
+#[test]
+fn llm_health_result_api_error_fails() {
+    let result = llm_health_result(
+        "anthropic",
+        Err(LlmError::Api {
+            status: 401,
+            message: "invalid x-api-key".to_string(),
+        }),
+        std::time::Duration::from_millis(3),
+    );
+    assert!(!result.passed);
+    assert!(result
+        .message
+        .starts_with("anthropic: LLM API error (status 401)"));
+}
+
 #[test]
 fn next_step_guidance_all_pass() {
     let checks = vec![
@@ -413,3 +453,29 @@ fn check_database_directory_path_fails() {
         result.message
     );
 }
+
+#[tokio::test]
+async fn rate_limit_result_reports_remaining_budget() {
+    let pool = tuitbot_core::storage::init_test_db().await.unwrap();
+    let mut config = tuitbot_core::config::Config::default();
+    config.limits.max_replies_per_day = 5;
+    config.limits.max_tweets_per_day = 2;
+    config.limits.max_threads_per_week = 1;
+    tuitbot_core::storage::rate_limits::init_rate_limits(&pool, &config.limits, &config.intervals)
+        .await
+        .unwrap();
+
+    let limiter = RateLimiter::new(pool);
+    limiter.record_reply().await.unwrap();
+
+    let result = rate_limit_result(&limiter).await;
+    assert!(result.passed);
+    assert_eq!(result.message, "4 replies, 2 tweets, 1 threads left");
+}
+
+#[tokio::test]
+async fn check_rate_limits_skipped_without_database() {
+    let mut config = tuitbot_core::config::Config::default();
+    config.storage.db_path = "/tmp/tuitbot-test-nonexistent-db-67890.sqlite".to_string();
+    assert!(check_rate_limits(&config).await.is_none());
+}
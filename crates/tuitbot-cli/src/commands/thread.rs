@@ -0,0 +1,205 @@
+//! Implementation of `tuitbot thread --resume <thread_id>` and
+//! `tuitbot thread --from-markdown <file>`.
+//!
+//! `--resume` finishes posting a thread that failed partway through, starting
+//! after the last tweet that made it to X. `--from-markdown` posts a
+//! hand-written draft as-is, or queues it when approval mode is on.
+
+use std::path::Path;
+
+use anyhow::Context;
+use serde::Serialize;
+use tuitbot_core::config::Config;
+use tuitbot_core::content::parse_markdown_thread;
+use tuitbot_core::storage;
+use tuitbot_core::workflow::thread_post::{self, PostThreadOutcome};
+use tuitbot_core::workflow::thread_resume;
+
+use crate::deps::RuntimeDeps;
+use crate::output::CliOutput;
+
+#[derive(Serialize)]
+struct ResumeJson {
+    thread_id: i64,
+    resumed_from: usize,
+    new_tweet_ids: Vec<String>,
+    thread_tweet_ids: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct MarkdownJson {
+    /// `dry_run`, `queued`, or `posted`.
+    status: &'static str,
+    tweets: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    approval_id: Option<i64>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    tweet_ids: Vec<String>,
+}
+
+/// Resume the partially posted thread `thread_id`.
+pub async fn execute_resume(config: &Config, thread_id: i64, out: CliOutput) -> anyhow::Result<()> {
+    let deps = RuntimeDeps::init(config, false).await?;
+
+    let result = thread_resume::execute(&deps.pool, deps.dyn_client.as_ref(), thread_id).await?;
+
+    if out.is_json() {
+        return out.json(&ResumeJson {
+            thread_id: result.thread_id,
+            resumed_from: result.resumed_from,
+            new_tweet_ids: result.new_tweet_ids,
+            thread_tweet_ids: result.thread_tweet_ids,
+        });
+    }
+
+    out.info(&format!(
+        "Resumed thread {thread_id} at tweet {}: posted {} remaining tweet(s).",
+        result.resumed_from + 1,
+        result.new_tweet_ids.len()
+    ));
+    if let Some(root) = result.thread_tweet_ids.first() {
+        out.info(&format!("Root tweet: {root}"));
+    }
+    Ok(())
+}
+
+/// Post (or queue, in approval mode) the markdown draft at `path` as a thread.
+pub async fn execute_from_markdown(
+    config: &Config,
+    path: &Path,
+    dry_run: bool,
+    out: CliOutput,
+) -> anyhow::Result<()> {
+    let tweets = read_markdown_thread(path)?;
+    let topic = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default();
+
+    if dry_run {
+        return report(out, "dry_run", tweets, None, Vec::new());
+    }
+
+    if config.effective_approval_mode() {
+        let pool = storage::init_db_with_config(&config.storage.db_path, &config.storage).await?;
+        let content = serde_json::to_string(&tweets)?;
+        let id = storage::approval_queue::enqueue(
+            &pool, "thread", "", "", &content, &topic, "", 0.0, "[]",
+        )
+        .await?;
+        pool.close().await;
+        return report(out, "queued", tweets, Some(id), Vec::new());
+    }
+
+    let deps = RuntimeDeps::init_without_llm(config, false).await?;
+    let outcome = thread_post::execute(
+        &deps.pool,
+        deps.dyn_client.as_ref(),
+        &deps.account_id,
+        &topic,
+        &tweets,
+    )
+    .await?;
+
+    match outcome {
+        PostThreadOutcome::Posted { tweet_ids, .. } => {
+            report(out, "posted", tweets, None, tweet_ids)
+        }
+        PostThreadOutcome::PartialFailure {
+            thread_id,
+            posted_ids,
+            failed_index,
+            total,
+            error,
+        } => anyhow::bail!(
+            "thread failed at tweet {}/{total}: {error}\n\
+             Posted {} tweet(s); finish with `tuitbot thread --resume {thread_id}`.",
+            failed_index + 1,
+            posted_ids.len()
+        ),
+        PostThreadOutcome::RateLimited { reason } => {
+            anyhow::bail!(
+                "thread limit reached (limits.max_threads_per_week): {reason}; not posting"
+            )
+        }
+    }
+}
+
+/// Read `path` and split it into tweets, rejecting over-length sections.
+fn read_markdown_thread(path: &Path) -> anyhow::Result<Vec<String>> {
+    let markdown = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path.display()))?;
+    let parts =
+        parse_markdown_thread(&markdown).map_err(|e| anyhow::anyhow!("{}: {e}", path.display()))?;
+    Ok(parts.into_iter().map(|p| p.text).collect())
+}
+
+fn report(
+    out: CliOutput,
+    status: &'static str,
+    tweets: Vec<String>,
+    approval_id: Option<i64>,
+    tweet_ids: Vec<String>,
+) -> anyhow::Result<()> {
+    if out.is_json() {
+        return out.json(&MarkdownJson {
+            status,
+            tweets,
+            approval_id,
+            tweet_ids,
+        });
+    }
+
+    for (i, tweet) in tweets.iter().enumerate() {
+        out.info(&format!("[{}/{}] {tweet}\n", i + 1, tweets.len()));
+    }
+    match status {
+        "queued" => out.info(&format!(
+            "Queued {}-tweet thread for approval (id {}).",
+            tweets.len(),
+            approval_id.unwrap_or_default()
+        )),
+        "posted" => out.info(&format!(
+            "Posted {}-tweet thread. Root tweet: {}",
+            tweets.len(),
+            tweet_ids.first().map(String::as_str).unwrap_or("")
+        )),
+        _ => out.info(&format!(
+            "Dry run: {} tweets, nothing posted.",
+            tweets.len()
+        )),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_draft(contents: &str) -> tempfile::NamedTempFile {
+        let mut file = tempfile::NamedTempFile::new().expect("temp file");
+        std::io::Write::write_all(&mut file, contents.as_bytes()).expect("write draft");
+        file
+    }
+
+    #[test]
+    fn markdown_file_splits_into_tweets() {
+        let file = write_draft("# Launch notes\n\nWe shipped it.\n\n---\n\nHere's what broke.\n");
+        let tweets = read_markdown_thread(file.path()).expect("valid draft");
+        assert_eq!(
+            tweets,
+            vec!["Launch notes\n\nWe shipped it.", "Here's what broke."]
+        );
+    }
+
+    #[test]
+    fn markdown_file_with_over_length_section_is_rejected_with_line() {
+        let long = "too long ".repeat(40);
+        let file = write_draft(&format!(
+            "Opening tweet.\n---\nSecond tweet.\n\n## Details\n{long}\n"
+        ));
+        let err = read_markdown_thread(file.path()).unwrap_err().to_string();
+        assert!(err.contains("tweet 3 (line 5)"), "{err}");
+        assert!(err.contains(&file.path().display().to_string()), "{err}");
+    }
+}
@@ -0,0 +1,275 @@
+//! Single-pass dispatch shared by `tuitbot tick` and
+//! `tuitbot run --once-per-loop`.
+
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio_util::sync::CancellationToken;
+
+use tuitbot_core::automation::loop_breaker::{LoopBreaker, LoopGate};
+use tuitbot_core::automation::{
+    run_posting_queue_with_activity, ActivitySink, LogActivitySink, PostExecutor,
+};
+use tuitbot_core::config::{Config, OperatingMode};
+use tuitbot_core::safety::denials::DenialLog;
+
+use super::filter::LoopFilter;
+use super::loops::{
+    run_analytics, run_content, run_discovery, run_housekeeping, run_mentions, run_target,
+    run_thread,
+};
+use super::{LoopErrorJson, LoopOutcome, LoopReport, LoopResults};
+use crate::deps::RuntimeDeps;
+
+/// Runs one loop by name; a single pass calls it once per loop.
+pub(super) trait LoopDispatch {
+    async fn run_loop(&mut self, name: &'static str) -> LoopOutcome;
+}
+
+/// Run every loop exactly once, in tick order. Denials are drained after
+/// each loop so every report only carries its own.
+pub(super) async fn dispatch_each_once(
+    dispatch: &mut impl LoopDispatch,
+    denials: &DenialLog,
+) -> LoopResults {
+    denials.take();
+    LoopResults {
+        analytics: LoopReport::drain(dispatch.run_loop("analytics").await, denials),
+        discovery: LoopReport::drain(dispatch.run_loop("discovery").await, denials),
+        mentions: LoopReport::drain(dispatch.run_loop("mentions").await, denials),
+        target: LoopReport::drain(dispatch.run_loop("target").await, denials),
+        content: LoopReport::drain(dispatch.run_loop("content").await, denials),
+        thread: LoopReport::drain(dispatch.run_loop("thread").await, denials),
+        housekeeping: LoopReport::drain(dispatch.run_loop("housekeeping").await, denials),
+    }
+}
+
+/// Dispatches to the per-loop runners in [`super::loops`], collecting loop errors.
+struct TickDispatch<'a> {
+    deps: &'a RuntimeDeps,
+    config: &'a Config,
+    filter: &'a LoopFilter,
+    breaker: LoopBreaker,
+    dry_run: bool,
+    errors: Vec<LoopErrorJson>,
+}
+
+impl LoopDispatch for TickDispatch<'_> {
+    async fn run_loop(&mut self, name: &'static str) -> LoopOutcome {
+        let Self {
+            deps,
+            config,
+            filter,
+            breaker,
+            dry_run,
+            errors,
+        } = self;
+        let (deps, config, filter) = (*deps, *config, *filter);
+
+        // Only analytics and housekeeping run in composer mode.
+        if config.mode == OperatingMode::Composer && !matches!(name, "analytics" | "housekeeping") {
+            return LoopOutcome::Skipped {
+                reason: "disabled in composer mode".to_string(),
+            };
+        }
+
+        match name {
+            "analytics" => {
+                run_guarded(
+                    breaker,
+                    filter,
+                    name,
+                    run_analytics(deps, filter, config, errors),
+                )
+                .await
+            }
+            "discovery" => {
+                run_guarded(
+                    breaker,
+                    filter,
+                    name,
+                    run_discovery(deps, filter, config, errors),
+                )
+                .await
+            }
+            "mentions" => {
+                run_guarded(
+                    breaker,
+                    filter,
+                    name,
+                    run_mentions(deps, filter, config, errors),
+                )
+                .await
+            }
+            "target" => {
+                run_guarded(
+                    breaker,
+                    filter,
+                    name,
+                    run_target(deps, filter, config, errors),
+                )
+                .await
+            }
+            "content" => {
+                run_guarded(
+                    breaker,
+                    filter,
+                    name,
+                    run_content(deps, filter, config, errors),
+                )
+                .await
+            }
+            "thread" => {
+                run_guarded(
+                    breaker,
+                    filter,
+                    name,
+                    run_thread(deps, filter, config, errors),
+                )
+                .await
+            }
+            "housekeeping" => {
+                run_guarded(
+                    breaker,
+                    filter,
+                    name,
+                    run_housekeeping(deps, filter, config, *dry_run, errors),
+                )
+                .await
+            }
+            other => LoopOutcome::Skipped {
+                reason: format!("unknown loop '{other}'"),
+            },
+        }
+    }
+}
+
+/// Run each loop in `filter` once, then drain the posting queue
+/// (30s timeout). Posts go out with no delay between them.
+pub(super) async fn run_single_pass(
+    deps: &mut RuntimeDeps,
+    config: &Config,
+    filter: &LoopFilter,
+    dry_run: bool,
+) -> (LoopResults, Vec<LoopErrorJson>) {
+    let cancel = CancellationToken::new();
+    let post_rx = deps.post_rx.take().expect("post_rx not yet consumed");
+    let queue_cancel = cancel.clone();
+    let queue_handle = tokio::spawn({
+        let executor = deps.post_executor.clone() as Arc<dyn PostExecutor>;
+        let approval_queue = deps.approval_queue.clone();
+        let activity: Arc<dyn ActivitySink> = Arc::new(LogActivitySink::new(
+            deps.pool.clone(),
+            deps.account_id.clone(),
+        ));
+        async move {
+            run_posting_queue_with_activity(
+                post_rx,
+                executor,
+                approval_queue,
+                Duration::ZERO,
+                Duration::ZERO,
+                None,
+                Some(activity),
+                None,
+                queue_cancel,
+            )
+            .await;
+        }
+    });
+
+    let deps: &RuntimeDeps = deps;
+    let mut dispatch = TickDispatch {
+        deps,
+        config,
+        filter,
+        breaker: LoopBreaker::new(deps.pool.clone(), config.circuit_breaker.loops.clone()),
+        dry_run,
+        errors: Vec::new(),
+    };
+    let loops = dispatch_each_once(&mut dispatch, &deps.denials).await;
+
+    cancel.cancel();
+    let _ = tokio::time::timeout(Duration::from_secs(30), queue_handle).await;
+
+    (loops, dispatch.errors)
+}
+
+/// Run every loop once for `tuitbot run --once-per-loop`, logging each
+/// outcome the way the daemon logs. Returns how many loops failed.
+pub(crate) async fn run_each_loop_once(deps: &mut RuntimeDeps, config: &Config) -> usize {
+    let (loops, errors) = run_single_pass(deps, config, &LoopFilter::all(), false).await;
+
+    for (name, report) in LoopFilter::VALID_NAMES.iter().zip(loops.reports()) {
+        match &report.outcome {
+            LoopOutcome::Completed { detail, actions } => {
+                tracing::info!(loop_name = name, actions, "Loop completed: {detail}");
+            }
+            LoopOutcome::Skipped { reason } => {
+                tracing::info!(loop_name = name, "Loop skipped: {reason}");
+            }
+            LoopOutcome::Failed { error } => {
+                tracing::warn!(loop_name = name, error = %error, "Loop failed");
+            }
+            LoopOutcome::CircuitOpen {
+                until,
+                consecutive_failures,
+            } => {
+                tracing::warn!(
+                    loop_name = name,
+                    until = %until,
+                    consecutive_failures,
+                    "Loop paused by its error budget"
+                );
+            }
+        }
+    }
+
+    errors.len()
+}
+
+/// Run one loop behind its persisted error budget.
+///
+/// A loop whose breaker is open is not run and reports `circuit_open`.
+/// Completed and failed runs update the budget; skips leave it untouched.
+/// Breaker storage errors are logged and never block the loop.
+async fn run_guarded(
+    breaker: &LoopBreaker,
+    filter: &LoopFilter,
+    name: &str,
+    run: impl Future<Output = LoopOutcome>,
+) -> LoopOutcome {
+    if filter.skip_reason(name).is_some() {
+        return run.await;
+    }
+
+    let now = chrono::Utc::now();
+    match breaker.gate(name, now).await {
+        Ok(LoopGate::Paused {
+            until,
+            consecutive_failures,
+        }) => {
+            return LoopOutcome::CircuitOpen {
+                until: until.to_rfc3339(),
+                consecutive_failures,
+            };
+        }
+        Ok(LoopGate::Run) => {}
+        Err(e) => tracing::warn!(loop_name = name, error = %e, "Failed to read loop breaker"),
+    }
+
+    let outcome = run.await;
+    let recorded = match &outcome {
+        LoopOutcome::Completed { .. } => breaker.record_success(name).await,
+        LoopOutcome::Failed { error } => breaker
+            .record_failure(name, error, chrono::Utc::now())
+            .await
+            .map(|_| ()),
+        LoopOutcome::Skipped { .. } | LoopOutcome::CircuitOpen { .. } => Ok(()),
+    };
+    if let Err(e) = recorded {
+        tracing::warn!(loop_name = name, error = %e, "Failed to update loop breaker");
+    }
+    outcome
+}
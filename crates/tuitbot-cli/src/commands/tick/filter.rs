@@ -0,0 +1,131 @@
+//! Loop selection for a single pass: `--loops` and `--no-llm`.
+
+use super::TickArgs;
+
+/// Which loops to run, resolved from CLI args + tier capabilities.
+#[derive(Debug)]
+pub(super) struct LoopFilter {
+    pub(super) analytics: bool,
+    pub(super) discovery: bool,
+    pub(super) mentions: bool,
+    pub(super) target: bool,
+    pub(super) content: bool,
+    pub(super) thread: bool,
+    /// Retention purge (`storage.retention_days`).
+    pub(super) housekeeping: bool,
+    /// False under `--no-llm`: loops that generate content are skipped.
+    pub(super) llm: bool,
+}
+
+impl LoopFilter {
+    pub(super) const VALID_NAMES: &'static [&'static str] = &[
+        "analytics",
+        "discovery",
+        "mentions",
+        "target",
+        "content",
+        "thread",
+        "housekeeping",
+    ];
+
+    /// Whether a loop generates content and therefore needs an LLM provider.
+    pub(super) fn requires_llm(name: &str) -> bool {
+        matches!(
+            name,
+            "discovery" | "mentions" | "target" | "content" | "thread"
+        )
+    }
+
+    pub(super) fn from_args(args: &TickArgs) -> Result<Self, anyhow::Error> {
+        let mut filter = Self::from_loops(args)?;
+        filter.llm = !args.no_llm;
+        Ok(filter)
+    }
+
+    fn from_loops(args: &TickArgs) -> Result<Self, anyhow::Error> {
+        match &args.loops {
+            Some(names) => {
+                let names: Vec<&str> = names
+                    .iter()
+                    .map(|n| n.trim())
+                    .filter(|n| !n.is_empty())
+                    .collect();
+                if names.is_empty() {
+                    anyhow::bail!(
+                        "--loops cannot be empty. Valid values: {}",
+                        Self::VALID_NAMES.join(", ")
+                    );
+                }
+                for name in &names {
+                    if !Self::VALID_NAMES.contains(name) {
+                        anyhow::bail!(
+                            "unknown loop '{}'; valid values: {}",
+                            name,
+                            Self::VALID_NAMES.join(", ")
+                        );
+                    }
+                }
+                if args.no_llm {
+                    let conflicting: Vec<&str> = names
+                        .iter()
+                        .copied()
+                        .filter(|n| Self::requires_llm(n))
+                        .collect();
+                    if !conflicting.is_empty() {
+                        anyhow::bail!(
+                            "--no-llm conflicts with --loops {}: these loops generate content \
+                             and need an LLM provider. Remove them from --loops or drop --no-llm.",
+                            conflicting.join(",")
+                        );
+                    }
+                }
+                Ok(Self {
+                    analytics: names.contains(&"analytics"),
+                    discovery: names.contains(&"discovery"),
+                    mentions: names.contains(&"mentions"),
+                    target: names.contains(&"target"),
+                    content: names.contains(&"content"),
+                    thread: names.contains(&"thread"),
+                    housekeeping: names.contains(&"housekeeping"),
+                    llm: true,
+                })
+            }
+            None => Ok(Self::all()),
+        }
+    }
+
+    /// Every loop selected, LLM enabled.
+    pub(super) fn all() -> Self {
+        Self {
+            analytics: true,
+            discovery: true,
+            mentions: true,
+            target: true,
+            content: true,
+            thread: true,
+            housekeeping: true,
+            llm: true,
+        }
+    }
+
+    /// Reason to skip `name` before any tier or config checks, if any.
+    pub(super) fn skip_reason(&self, name: &str) -> Option<&'static str> {
+        let selected = match name {
+            "analytics" => self.analytics,
+            "discovery" => self.discovery,
+            "mentions" => self.mentions,
+            "target" => self.target,
+            "content" => self.content,
+            "thread" => self.thread,
+            "housekeeping" => self.housekeeping,
+            _ => false,
+        };
+        if !selected {
+            return Some("not in --loops filter");
+        }
+        if !self.llm && Self::requires_llm(name) {
+            return Some("llm disabled");
+        }
+        None
+    }
+}
@@ -0,0 +1,483 @@
+//! Per-loop runners: each runs one loop iteration and reports its outcome.
+
+use std::sync::Arc;
+
+use tuitbot_core::automation::{
+    AnalyticsLoop, ContentLoop, DiscoveryLoop, MentionsLoop, TargetLoop, ThreadLoop,
+};
+use tuitbot_core::config::Config;
+use tuitbot_core::storage::retention::purge_expired_for;
+
+use super::filter::LoopFilter;
+use super::{LoopErrorJson, LoopOutcome};
+use crate::deps::RuntimeDeps;
+
+pub(super) async fn run_analytics(
+    deps: &RuntimeDeps,
+    filter: &LoopFilter,
+    config: &Config,
+    errors: &mut Vec<LoopErrorJson>,
+) -> LoopOutcome {
+    if let Some(reason) = filter.skip_reason("analytics") {
+        return LoopOutcome::Skipped {
+            reason: reason.to_string(),
+        };
+    }
+
+    if !deps.capabilities.mentions {
+        let reason = if config.x_api.provider_backend == "scraper" {
+            "analytics not supported in scraper mode".to_string()
+        } else {
+            "requires Basic/Pro tier".to_string()
+        };
+        return LoopOutcome::Skipped { reason };
+    }
+
+    let analytics_loop = AnalyticsLoop::new(
+        deps.profile_adapter.clone(),
+        deps.profile_adapter.clone(),
+        deps.analytics_storage.clone(),
+    );
+
+    match analytics_loop.run_iteration().await {
+        Ok(summary) => LoopOutcome::Completed {
+            detail: format!(
+                "followers={}, replies_measured={}, tweets_measured={}",
+                summary.follower_count, summary.replies_measured, summary.tweets_measured
+            ),
+            actions: 0,
+        },
+        Err(e) => {
+            let msg = e.to_string();
+            errors.push(LoopErrorJson {
+                loop_name: "analytics".to_string(),
+                error: msg.clone(),
+            });
+            LoopOutcome::Failed { error: msg }
+        }
+    }
+}
+
+pub(super) async fn run_discovery(
+    deps: &RuntimeDeps,
+    filter: &LoopFilter,
+    config: &Config,
+    errors: &mut Vec<LoopErrorJson>,
+) -> LoopOutcome {
+    if let Some(reason) = filter.skip_reason("discovery") {
+        return LoopOutcome::Skipped {
+            reason: reason.to_string(),
+        };
+    }
+
+    if !deps.capabilities.discovery {
+        let reason = if config.x_api.provider_backend == "scraper" {
+            "search not supported in scraper mode".to_string()
+        } else {
+            "requires Basic/Pro tier".to_string()
+        };
+        return LoopOutcome::Skipped { reason };
+    }
+
+    if deps.keywords.is_empty() {
+        return LoopOutcome::Skipped {
+            reason: "no keywords configured".to_string(),
+        };
+    }
+
+    let mut discovery_loop = DiscoveryLoop::new(
+        deps.searcher.clone(),
+        deps.scorer.clone(),
+        deps.reply_gen.clone(),
+        deps.safety.clone(),
+        deps.loop_storage.clone(),
+        deps.post_sender.clone(),
+        deps.keywords.clone(),
+        config.scoring.threshold as f32,
+        deps.target_loop_config.dry_run,
+    )
+    .with_own_user_id(deps.own_user_id.clone())
+    .with_pagination(
+        config.intervals.discovery_max_pages as usize,
+        Some(config.limits.max_replies_per_day as usize),
+    );
+    if let Some(liker) = &deps.liker {
+        discovery_loop = discovery_loop.with_likes(liker.clone());
+    }
+    if let Some(fetcher) = &deps.conversation_fetcher {
+        discovery_loop = discovery_loop.with_conversation_context(
+            fetcher.clone(),
+            config.content.max_conversation_tweets as usize,
+        );
+    }
+
+    match discovery_loop.run_once(None).await {
+        Ok((_results, summary)) => LoopOutcome::Completed {
+            detail: format!(
+                "found={}, qualifying={}, replied={}, skipped={}, failed={}",
+                summary.tweets_found,
+                summary.qualifying,
+                summary.replied,
+                summary.skipped,
+                summary.failed
+            ),
+            actions: summary.replied,
+        },
+        Err(e) => {
+            let msg = e.to_string();
+            errors.push(LoopErrorJson {
+                loop_name: "discovery".to_string(),
+                error: msg.clone(),
+            });
+            LoopOutcome::Failed { error: msg }
+        }
+    }
+}
+
+pub(super) async fn run_mentions(
+    deps: &RuntimeDeps,
+    filter: &LoopFilter,
+    config: &Config,
+    errors: &mut Vec<LoopErrorJson>,
+) -> LoopOutcome {
+    if let Some(reason) = filter.skip_reason("mentions") {
+        return LoopOutcome::Skipped {
+            reason: reason.to_string(),
+        };
+    }
+
+    if !deps.capabilities.mentions {
+        let reason = if config.x_api.provider_backend == "scraper" {
+            "mentions not supported in scraper mode".to_string()
+        } else {
+            "requires Basic/Pro tier".to_string()
+        };
+        return LoopOutcome::Skipped { reason };
+    }
+
+    let mentions_loop = MentionsLoop::new(
+        deps.mentions_fetcher.clone(),
+        deps.reply_gen.clone(),
+        deps.safety.clone(),
+        deps.post_sender.clone(),
+        deps.target_loop_config.dry_run,
+    )
+    .with_targets(config.targets.clone());
+
+    let storage: Arc<dyn tuitbot_core::automation::LoopStorage> = deps.loop_storage.clone();
+    match mentions_loop.run_once(None, None, &storage).await {
+        Ok((results, _since_id)) => {
+            let replied = results
+                .iter()
+                .filter(|r| matches!(r, tuitbot_core::automation::MentionResult::Replied { .. }))
+                .count();
+            let skipped = results
+                .iter()
+                .filter(|r| matches!(r, tuitbot_core::automation::MentionResult::Skipped { .. }))
+                .count();
+            let failed = results
+                .iter()
+                .filter(|r| matches!(r, tuitbot_core::automation::MentionResult::Failed { .. }))
+                .count();
+            LoopOutcome::Completed {
+                detail: format!(
+                    "total={}, replied={}, skipped={}, failed={}",
+                    results.len(),
+                    replied,
+                    skipped,
+                    failed
+                ),
+                actions: replied,
+            }
+        }
+        Err(e) => {
+            let msg = e.to_string();
+            errors.push(LoopErrorJson {
+                loop_name: "mentions".to_string(),
+                error: msg.clone(),
+            });
+            LoopOutcome::Failed { error: msg }
+        }
+    }
+}
+
+pub(super) async fn run_target(
+    deps: &RuntimeDeps,
+    filter: &LoopFilter,
+    config: &Config,
+    errors: &mut Vec<LoopErrorJson>,
+) -> LoopOutcome {
+    if let Some(reason) = filter.skip_reason("target") {
+        return LoopOutcome::Skipped {
+            reason: reason.to_string(),
+        };
+    }
+
+    if !deps.capabilities.mentions {
+        let reason = if config.x_api.provider_backend == "scraper" {
+            "target monitoring not supported in scraper mode".to_string()
+        } else {
+            "requires Basic/Pro tier".to_string()
+        };
+        return LoopOutcome::Skipped { reason };
+    }
+
+    if deps.target_loop_config.accounts.is_empty() {
+        return LoopOutcome::Skipped {
+            reason: "no target accounts configured".to_string(),
+        };
+    }
+
+    let target_loop = TargetLoop::new(
+        deps.target_adapter.clone(),
+        deps.target_adapter.clone(),
+        deps.reply_gen.clone(),
+        deps.safety.clone(),
+        deps.target_storage.clone(),
+        deps.post_sender.clone(),
+        deps.target_loop_config.clone(),
+    );
+
+    match target_loop.run_iteration().await {
+        Ok(results) => {
+            let replied = results
+                .iter()
+                .filter(|r| matches!(r, tuitbot_core::automation::TargetResult::Replied { .. }))
+                .count();
+            let skipped = results
+                .iter()
+                .filter(|r| matches!(r, tuitbot_core::automation::TargetResult::Skipped { .. }))
+                .count();
+            let failed = results
+                .iter()
+                .filter(|r| matches!(r, tuitbot_core::automation::TargetResult::Failed { .. }))
+                .count();
+            LoopOutcome::Completed {
+                detail: format!(
+                    "total={}, replied={}, skipped={}, failed={}",
+                    results.len(),
+                    replied,
+                    skipped,
+                    failed
+                ),
+                actions: replied,
+            }
+        }
+        Err(e) => {
+            let msg = e.to_string();
+            errors.push(LoopErrorJson {
+                loop_name: "target".to_string(),
+                error: msg.clone(),
+            });
+            LoopOutcome::Failed { error: msg }
+        }
+    }
+}
+
+pub(super) async fn run_content(
+    deps: &RuntimeDeps,
+    filter: &LoopFilter,
+    config: &Config,
+    errors: &mut Vec<LoopErrorJson>,
+) -> LoopOutcome {
+    if let Some(reason) = filter.skip_reason("content") {
+        return LoopOutcome::Skipped {
+            reason: reason.to_string(),
+        };
+    }
+
+    let (effective_topics, topic_source) = config.business.content_topics();
+    if effective_topics.is_empty() {
+        return LoopOutcome::Skipped {
+            reason: "no industry topics, content pillars, or product keywords configured"
+                .to_string(),
+        };
+    }
+    tracing::info!(source = %topic_source, count = effective_topics.len(), "Using topics");
+
+    let content_loop = ContentLoop::new(
+        deps.tweet_gen.clone(),
+        deps.content_safety.clone(),
+        deps.content_storage.clone(),
+        effective_topics,
+        config.intervals.content_post_window_seconds,
+        deps.target_loop_config.dry_run,
+    )
+    .with_topic_scorer(deps.topic_scorer.clone())
+    .with_thread_poster(deps.thread_poster.clone())
+    .with_min_topic_gap(config.content.min_topic_gap_hours)
+    .with_random_seed(config.content.random_seed);
+
+    match content_loop.run_once(None).await {
+        tuitbot_core::automation::ContentResult::Posted { topic, content } => {
+            LoopOutcome::Completed {
+                detail: format!("topic='{}', chars={}", topic, content.len()),
+                actions: 1,
+            }
+        }
+        tuitbot_core::automation::ContentResult::TooSoon {
+            elapsed_secs,
+            window_secs,
+        } => LoopOutcome::Skipped {
+            reason: format!(
+                "too soon since last tweet ({}s / {}s window)",
+                elapsed_secs, window_secs
+            ),
+        },
+        tuitbot_core::automation::ContentResult::RateLimited => LoopOutcome::Skipped {
+            reason: "daily tweet limit reached".to_string(),
+        },
+        tuitbot_core::automation::ContentResult::NoTopics => LoopOutcome::Skipped {
+            reason: "no topics configured".to_string(),
+        },
+        tuitbot_core::automation::ContentResult::Failed { error } => {
+            errors.push(LoopErrorJson {
+                loop_name: "content".to_string(),
+                error: error.clone(),
+            });
+            LoopOutcome::Failed { error }
+        }
+    }
+}
+
+pub(super) async fn run_thread(
+    deps: &RuntimeDeps,
+    filter: &LoopFilter,
+    config: &Config,
+    errors: &mut Vec<LoopErrorJson>,
+) -> LoopOutcome {
+    if let Some(reason) = filter.skip_reason("thread") {
+        return LoopOutcome::Skipped {
+            reason: reason.to_string(),
+        };
+    }
+
+    let (effective_topics, topic_source) = config.business.content_topics();
+    if effective_topics.is_empty() {
+        return LoopOutcome::Skipped {
+            reason: "no industry topics, content pillars, or product keywords configured"
+                .to_string(),
+        };
+    }
+    tracing::info!(source = %topic_source, count = effective_topics.len(), "Using topics");
+
+    let thread_loop = ThreadLoop::new(
+        deps.thread_gen.clone(),
+        deps.content_safety.clone(),
+        deps.content_storage.clone(),
+        deps.thread_poster.clone(),
+        effective_topics,
+        config.intervals.thread_interval_seconds,
+        deps.target_loop_config.dry_run,
+    )
+    .with_random_seed(config.content.random_seed);
+
+    match thread_loop.run_once(None, None).await {
+        tuitbot_core::automation::ThreadResult::Posted {
+            topic, tweet_count, ..
+        } => LoopOutcome::Completed {
+            detail: format!("topic='{}', tweets={}", topic, tweet_count),
+            actions: 1,
+        },
+        tuitbot_core::automation::ThreadResult::TooSoon {
+            elapsed_secs,
+            interval_secs,
+        } => LoopOutcome::Skipped {
+            reason: format!(
+                "too soon since last thread ({}s / {}s interval)",
+                elapsed_secs, interval_secs
+            ),
+        },
+        tuitbot_core::automation::ThreadResult::RateLimited => LoopOutcome::Skipped {
+            reason: "weekly thread limit reached".to_string(),
+        },
+        tuitbot_core::automation::ThreadResult::NoTopics => LoopOutcome::Skipped {
+            reason: "no topics configured".to_string(),
+        },
+        tuitbot_core::automation::ThreadResult::ValidationFailed { error } => {
+            errors.push(LoopErrorJson {
+                loop_name: "thread".to_string(),
+                error: error.clone(),
+            });
+            LoopOutcome::Failed { error }
+        }
+        tuitbot_core::automation::ThreadResult::PartialFailure {
+            tweets_posted,
+            total_tweets,
+            error,
+            ..
+        } => {
+            let detail = format!(
+                "partial: {}/{} tweets posted, error: {}",
+                tweets_posted, total_tweets, error
+            );
+            errors.push(LoopErrorJson {
+                loop_name: "thread".to_string(),
+                error: detail.clone(),
+            });
+            LoopOutcome::Failed { error: detail }
+        }
+        tuitbot_core::automation::ThreadResult::Failed { error } => {
+            errors.push(LoopErrorJson {
+                loop_name: "thread".to_string(),
+                error: error.clone(),
+            });
+            LoopOutcome::Failed { error }
+        }
+    }
+}
+
+pub(super) async fn run_housekeeping(
+    deps: &RuntimeDeps,
+    filter: &LoopFilter,
+    config: &Config,
+    dry_run: bool,
+    errors: &mut Vec<LoopErrorJson>,
+) -> LoopOutcome {
+    if let Some(reason) = filter.skip_reason("housekeeping") {
+        return LoopOutcome::Skipped {
+            reason: reason.to_string(),
+        };
+    }
+
+    if dry_run {
+        return LoopOutcome::Skipped {
+            reason: "dry run".to_string(),
+        };
+    }
+
+    if config.storage.retention_days == 0 {
+        return LoopOutcome::Skipped {
+            reason: "retention disabled (retention_days = 0)".to_string(),
+        };
+    }
+
+    match purge_expired_for(
+        &deps.pool,
+        &deps.account_id,
+        config.storage.retention_days,
+        config.storage.critical_retention_days,
+    )
+    .await
+    {
+        Ok(stats) => LoopOutcome::Completed {
+            detail: format!(
+                "purged={} (action_log={}, discovered_tweets={}, mcp_telemetry={}, replies={})",
+                stats.total_deleted,
+                stats.action_log_deleted,
+                stats.discovered_tweets_deleted,
+                stats.mcp_telemetry_deleted,
+                stats.replies_deleted
+            ),
+            actions: 0,
+        },
+        Err(e) => {
+            let msg = e.to_string();
+            errors.push(LoopErrorJson {
+                loop_name: "housekeeping".to_string(),
+                error: msg.clone(),
+            });
+            LoopOutcome::Failed { error: msg }
+        }
+    }
+}
@@ -0,0 +1,114 @@
+//! Human-readable tick summary and the profile enrichment tip.
+
+use tuitbot_core::config::Config;
+
+use super::{Activity, LoopOutcome, TickOutput};
+use crate::output::CliOutput;
+
+pub(super) fn print_output(output: &TickOutput, out: CliOutput) {
+    if out.is_json() {
+        let _ = out.json(output);
+    } else if !out.quiet {
+        print_text_output(output);
+    }
+}
+
+pub(super) fn print_text_output(output: &TickOutput) {
+    eprintln!(
+        "tuitbot tick  tier={}  schedule={}  dry_run={}  approval_mode={}  duration={}ms",
+        output.tier,
+        if output.schedule_active {
+            "active"
+        } else {
+            "inactive"
+        },
+        output.dry_run,
+        output.approval_mode,
+        output.duration_ms,
+    );
+    eprintln!();
+
+    let loop_entries = [
+        ("analytics", &output.loops.analytics),
+        ("discovery", &output.loops.discovery),
+        ("mentions", &output.loops.mentions),
+        ("target", &output.loops.target),
+        ("content", &output.loops.content),
+        ("thread", &output.loops.thread),
+        ("housekeeping", &output.loops.housekeeping),
+    ];
+
+    for (name, report) in &loop_entries {
+        let (status, detail) = match &report.outcome {
+            LoopOutcome::Completed { detail, .. } => ("OK", detail.clone()),
+            LoopOutcome::Skipped { reason } => ("SKIP", reason.clone()),
+            LoopOutcome::Failed { error } => ("FAIL", error.clone()),
+            LoopOutcome::CircuitOpen {
+                until,
+                consecutive_failures,
+            } => (
+                "PAUSED",
+                format!("{consecutive_failures} consecutive failures; paused until {until}"),
+            ),
+        };
+        eprintln!("  {:<12} {:<6} {}", name, status, detail);
+        for denial in &report.denials {
+            eprintln!(
+                "  {:<12} {:<6} {} x{}: {}",
+                "", "DENY", denial.reason, denial.count, denial.detail
+            );
+        }
+    }
+
+    if let Some(budget) = &output.remaining {
+        eprintln!();
+        eprintln!(
+            "Remaining: {} replies, {} tweets, {} threads",
+            budget.replies, budget.tweets, budget.threads
+        );
+    }
+
+    if !output.errors.is_empty() {
+        eprintln!();
+        eprintln!("Errors:");
+        for err in &output.errors {
+            eprintln!("  {}: {}", err.loop_name, err.error);
+        }
+    }
+
+    eprintln!();
+    eprintln!(
+        "Result: {}  activity={}",
+        if output.success { "success" } else { "failure" },
+        match output.activity {
+            Activity::Acted => "acted",
+            Activity::Idle => "idle",
+            Activity::Blocked => "blocked",
+        }
+    );
+
+    if output.dry_run && output.success {
+        eprintln!();
+        if let Some(tip) = &output.enrichment_tip {
+            eprintln!("Tip: {tip}");
+        } else {
+            eprintln!("Tip: Use `tuitbot settings` to fine-tune your configuration");
+        }
+    }
+}
+
+/// Compute a context-aware enrichment tip from profile completeness.
+pub(super) fn compute_enrichment_tip(config: &Config) -> Option<String> {
+    let completeness = config.profile_completeness();
+    if completeness.is_fully_enriched() {
+        return None;
+    }
+
+    completeness.next_incomplete().map(|stage| {
+        format!(
+            "Run `tuitbot settings enrich` to configure {} \u{2014} {}",
+            stage.label().to_lowercase(),
+            stage.description()
+        )
+    })
+}
@@ -1,3 +1,7 @@
+use std::time::Duration;
+
+use super::dispatch::{dispatch_each_once, LoopDispatch};
+use super::output::print_text_output;
 use super::*;
 use tuitbot_core::config::Config;
 
@@ -11,6 +15,8 @@ fn tick_args(loops: Option<Vec<&str>>) -> TickArgs {
         ignore_schedule: false,
         loops: loops.map(|v| v.into_iter().map(String::from).collect()),
         require_approval: false,
+        no_llm: false,
+        fail_on_no_op: false,
     }
 }
 
@@ -25,6 +31,7 @@ fn loop_filter_default_all_enabled() {
     assert!(filter.target);
     assert!(filter.content);
     assert!(filter.thread);
+    assert!(filter.housekeeping);
 }
 
 #[test]
@@ -60,6 +67,42 @@ fn loop_filter_unknown_name_errors() {
     );
 }
 
+#[test]
+fn loop_filter_no_llm_skips_generation_loops() {
+    let mut args = tick_args(None);
+    args.no_llm = true;
+    let f = LoopFilter::from_args(&args).unwrap();
+
+    assert_eq!(f.skip_reason("analytics"), None);
+    for name in ["discovery", "mentions", "target", "content", "thread"] {
+        assert_eq!(f.skip_reason(name), Some("llm disabled"), "{name}");
+    }
+}
+
+#[test]
+fn loop_filter_no_llm_with_analytics_only() {
+    let mut args = tick_args(Some(vec!["analytics"]));
+    args.no_llm = true;
+    let f = LoopFilter::from_args(&args).unwrap();
+    assert_eq!(f.skip_reason("analytics"), None);
+    assert_eq!(f.skip_reason("content"), Some("not in --loops filter"));
+}
+
+#[test]
+fn loop_filter_no_llm_conflicts_with_content_loops() {
+    let mut args = tick_args(Some(vec!["analytics", "content", "thread"]));
+    args.no_llm = true;
+    let err = LoopFilter::from_args(&args).unwrap_err().to_string();
+    assert!(err.contains("--no-llm conflicts with --loops content,thread"));
+}
+
+#[test]
+fn loop_filter_llm_enabled_by_default() {
+    let f = LoopFilter::from_args(&tick_args(None)).unwrap();
+    assert!(f.llm);
+    assert_eq!(f.skip_reason("content"), None);
+}
+
 #[test]
 fn loop_filter_single_loop() {
     let args = tick_args(Some(vec!["analytics"]));
@@ -148,28 +191,41 @@ fn sample_tick_output(enrichment_tip: Option<String>, errors: Vec<LoopErrorJson>
         dry_run: true,
         approval_mode: false,
         duration_ms: 42,
+        activity: Activity::Idle,
         loops: LoopResults {
             analytics: LoopOutcome::Completed {
                 detail: "ok".to_string(),
-            },
+                actions: 0,
+            }
+            .into(),
             discovery: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
             mentions: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
             target: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
             content: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
             thread: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
+            housekeeping: LoopOutcome::Skipped {
+                reason: "filtered".to_string(),
+            }
+            .into(),
         },
         errors,
         enrichment_tip,
+        remaining: None,
     }
 }
 
@@ -199,6 +255,7 @@ fn tick_output_json_includes_enrichment_tip() {
 fn loop_outcome_completed_json() {
     let outcome = LoopOutcome::Completed {
         detail: "followers=100".to_string(),
+        actions: 0,
     };
     let json = serde_json::to_value(&outcome).unwrap();
 
@@ -228,6 +285,19 @@ fn loop_outcome_failed_json() {
     assert_eq!(json["error"], "connection refused");
 }
 
+#[test]
+fn loop_outcome_circuit_open_json() {
+    let outcome = LoopOutcome::CircuitOpen {
+        until: "2026-03-29T12:30:00+00:00".to_string(),
+        consecutive_failures: 5,
+    };
+    let json = serde_json::to_value(&outcome).unwrap();
+
+    assert_eq!(json["status"], "circuit_open");
+    assert_eq!(json["until"], "2026-03-29T12:30:00+00:00");
+    assert_eq!(json["consecutive_failures"], 5);
+}
+
 #[test]
 fn tick_output_success_flag() {
     let output_ok = sample_tick_output(None, vec![]);
@@ -278,13 +348,32 @@ fn loop_filter_all_six_loops() {
 #[test]
 fn loop_filter_valid_names_constant() {
     // Verify the constant list contains all expected loop names.
-    assert_eq!(LoopFilter::VALID_NAMES.len(), 6);
+    assert_eq!(LoopFilter::VALID_NAMES.len(), 7);
     assert!(LoopFilter::VALID_NAMES.contains(&"analytics"));
     assert!(LoopFilter::VALID_NAMES.contains(&"discovery"));
     assert!(LoopFilter::VALID_NAMES.contains(&"mentions"));
     assert!(LoopFilter::VALID_NAMES.contains(&"target"));
     assert!(LoopFilter::VALID_NAMES.contains(&"content"));
     assert!(LoopFilter::VALID_NAMES.contains(&"thread"));
+    assert!(LoopFilter::VALID_NAMES.contains(&"housekeeping"));
+}
+
+#[test]
+fn loop_filter_housekeeping_only() {
+    let args = tick_args(Some(vec!["housekeeping"]));
+    let filter = LoopFilter::from_args(&args).unwrap();
+    assert!(filter.housekeeping);
+    assert!(!filter.analytics);
+    assert_eq!(filter.skip_reason("housekeeping"), None);
+    assert_eq!(filter.skip_reason("content"), Some("not in --loops filter"));
+}
+
+#[test]
+fn loop_filter_no_llm_allows_housekeeping() {
+    let mut args = tick_args(Some(vec!["analytics", "housekeeping"]));
+    args.no_llm = true;
+    let filter = LoopFilter::from_args(&args).unwrap();
+    assert_eq!(filter.skip_reason("housekeeping"), None);
 }
 
 #[test]
@@ -309,25 +398,36 @@ fn tick_output_all_failed_serializes() {
         dry_run: false,
         approval_mode: true,
         duration_ms: 1000,
+        activity: Activity::Idle,
         loops: LoopResults {
             analytics: LoopOutcome::Failed {
                 error: "auth error".to_string(),
-            },
+            }
+            .into(),
             discovery: LoopOutcome::Failed {
                 error: "timeout".to_string(),
-            },
+            }
+            .into(),
             mentions: LoopOutcome::Failed {
                 error: "rate limit".to_string(),
-            },
+            }
+            .into(),
             target: LoopOutcome::Failed {
                 error: "network".to_string(),
-            },
+            }
+            .into(),
             content: LoopOutcome::Failed {
                 error: "no topics".to_string(),
-            },
+            }
+            .into(),
             thread: LoopOutcome::Failed {
                 error: "validation".to_string(),
-            },
+            }
+            .into(),
+            housekeeping: LoopOutcome::Failed {
+                error: "validation".to_string(),
+            }
+            .into(),
         },
         errors: vec![
             LoopErrorJson {
@@ -340,6 +440,7 @@ fn tick_output_all_failed_serializes() {
             },
         ],
         enrichment_tip: None,
+        remaining: None,
     };
 
     let json = serde_json::to_value(&output).unwrap();
@@ -359,28 +460,40 @@ fn tick_output_schedule_inactive_serializes() {
         dry_run: false,
         approval_mode: false,
         duration_ms: 5,
+        activity: Activity::Idle,
         loops: LoopResults {
             analytics: LoopOutcome::Skipped {
                 reason: "outside active hours".to_string(),
-            },
+            }
+            .into(),
             discovery: LoopOutcome::Skipped {
                 reason: "outside active hours".to_string(),
-            },
+            }
+            .into(),
             mentions: LoopOutcome::Skipped {
                 reason: "outside active hours".to_string(),
-            },
+            }
+            .into(),
             target: LoopOutcome::Skipped {
                 reason: "outside active hours".to_string(),
-            },
+            }
+            .into(),
             content: LoopOutcome::Skipped {
                 reason: "outside active hours".to_string(),
-            },
+            }
+            .into(),
             thread: LoopOutcome::Skipped {
                 reason: "outside active hours".to_string(),
-            },
+            }
+            .into(),
+            housekeeping: LoopOutcome::Skipped {
+                reason: "outside active hours".to_string(),
+            }
+            .into(),
         },
         errors: vec![],
         enrichment_tip: None,
+        remaining: None,
     };
 
     let json = serde_json::to_value(&output).unwrap();
@@ -389,6 +502,58 @@ fn tick_output_schedule_inactive_serializes() {
     assert_eq!(json["loops"]["analytics"]["reason"], "outside active hours");
 }
 
+#[test]
+fn loop_report_includes_recorded_denials() {
+    use tuitbot_core::safety::DenialReason;
+
+    let log = DenialLog::new();
+    for current in [20, 21] {
+        log.record(DenialReason::RateLimited {
+            action_type: "reply".to_string(),
+            current,
+            max: 20,
+        });
+    }
+    log.record(DenialReason::BannedPhrase {
+        phrase: "check out".to_string(),
+    });
+
+    let mut output = sample_tick_output(None, vec![]);
+    output.loops.mentions = LoopReport::drain(
+        LoopOutcome::Completed {
+            detail: "replied=0, skipped=3, failed=0".to_string(),
+            actions: 0,
+        },
+        &log,
+    );
+    // The next loop starts from an empty log.
+    output.loops.target = LoopReport::drain(
+        LoopOutcome::Completed {
+            detail: "ok".to_string(),
+            actions: 0,
+        },
+        &log,
+    );
+
+    let json = serde_json::to_value(&output).unwrap();
+    let mentions = &json["loops"]["mentions"];
+    assert_eq!(mentions["status"], "completed");
+    let denials = mentions["denials"].as_array().unwrap();
+    assert_eq!(denials.len(), 2);
+    assert_eq!(denials[0]["reason"], "rate_limited");
+    assert_eq!(denials[0]["count"], 2);
+    assert!(denials[0]["detail"]
+        .as_str()
+        .unwrap()
+        .contains("Rate limited: reply"));
+    assert_eq!(denials[1]["reason"], "banned_phrase");
+    assert_eq!(denials[1]["count"], 1);
+    assert!(denials[1]["detail"].as_str().unwrap().contains("check out"));
+
+    assert_eq!(json["loops"]["target"]["denials"], serde_json::json!([]));
+    assert_eq!(json["loops"]["analytics"]["denials"], serde_json::json!([]));
+}
+
 #[test]
 fn loop_error_json_serialization() {
     let err = LoopErrorJson {
@@ -428,28 +593,41 @@ fn print_text_output_dry_run_success_shows_tip() {
         dry_run: true,
         approval_mode: false,
         duration_ms: 10,
+        activity: Activity::Idle,
         loops: LoopResults {
             analytics: LoopOutcome::Completed {
                 detail: "ok".to_string(),
-            },
+                actions: 0,
+            }
+            .into(),
             discovery: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
             mentions: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
             target: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
             content: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
             thread: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
+            housekeeping: LoopOutcome::Skipped {
+                reason: "filtered".to_string(),
+            }
+            .into(),
         },
         errors: vec![],
         enrichment_tip: Some("Run `tuitbot settings enrich`".to_string()),
+        remaining: None,
     };
     print_text_output(&output);
 }
@@ -464,28 +642,41 @@ fn print_text_output_dry_run_success_no_tip() {
         dry_run: true,
         approval_mode: false,
         duration_ms: 10,
+        activity: Activity::Idle,
         loops: LoopResults {
             analytics: LoopOutcome::Completed {
                 detail: "ok".to_string(),
-            },
+                actions: 0,
+            }
+            .into(),
             discovery: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
             mentions: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
             target: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
             content: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
             thread: LoopOutcome::Skipped {
                 reason: "filtered".to_string(),
-            },
+            }
+            .into(),
+            housekeeping: LoopOutcome::Skipped {
+                reason: "filtered".to_string(),
+            }
+            .into(),
         },
         errors: vec![],
         enrichment_tip: None,
+        remaining: None,
     };
     print_text_output(&output);
 }
@@ -513,3 +704,133 @@ fn enrichment_tip_with_targets_set() {
     let tip = compute_enrichment_tip(&config);
     assert!(tip.is_none());
 }
+
+// ============================================================================
+// --fail-on-no-op
+// ============================================================================
+
+fn completed(actions: usize) -> LoopOutcome {
+    LoopOutcome::Completed {
+        detail: "ok".to_string(),
+        actions,
+    }
+}
+
+fn denial_log(reasons: Vec<tuitbot_core::safety::DenialReason>) -> DenialLog {
+    let log = DenialLog::new();
+    for reason in reasons {
+        log.record(reason);
+    }
+    log
+}
+
+#[test]
+fn idle_tick_exits_zero_even_with_flag() {
+    use tuitbot_core::safety::DenialReason;
+
+    // Nothing new to reply to: only dedup denials.
+    let mut output = sample_tick_output(None, vec![]);
+    output.loops.mentions = LoopReport::drain(
+        completed(0),
+        &denial_log(vec![DenialReason::AlreadyReplied {
+            tweet_id: "1".to_string(),
+        }]),
+    );
+
+    assert_eq!(output.loops.activity(), Activity::Idle);
+    assert_eq!(no_op_exit_code(Activity::Idle, true), None);
+}
+
+#[test]
+fn blocked_tick_exits_non_zero_only_with_flag() {
+    use tuitbot_core::safety::DenialReason;
+
+    let mut output = sample_tick_output(None, vec![]);
+    output.loops.discovery = LoopReport::drain(
+        completed(0),
+        &denial_log(vec![DenialReason::RateLimited {
+            action_type: "reply".to_string(),
+            current: 20,
+            max: 20,
+        }]),
+    );
+
+    let activity = output.loops.activity();
+    output.activity = activity;
+    assert_eq!(activity, Activity::Blocked);
+    assert_eq!(no_op_exit_code(activity, true), Some(EXIT_BLOCKED));
+    assert_ne!(EXIT_BLOCKED, 1);
+    assert_eq!(no_op_exit_code(activity, false), None);
+
+    let json = serde_json::to_value(&output).unwrap();
+    assert_eq!(json["activity"], "blocked");
+}
+
+#[test]
+fn any_action_counts_as_acted_despite_denials() {
+    use tuitbot_core::safety::DenialReason;
+
+    let mut output = sample_tick_output(None, vec![]);
+    output.loops.discovery = LoopReport::drain(
+        completed(0),
+        &denial_log(vec![DenialReason::SimilarPhrasing]),
+    );
+    output.loops.mentions = completed(1).into();
+
+    assert_eq!(output.loops.activity(), Activity::Acted);
+    assert_eq!(no_op_exit_code(Activity::Acted, true), None);
+}
+
+#[test]
+fn open_circuit_counts_as_blocked() {
+    let mut output = sample_tick_output(None, vec![]);
+    output.loops.content = LoopOutcome::CircuitOpen {
+        until: "2026-01-01T00:00:00Z".to_string(),
+        consecutive_failures: 5,
+    }
+    .into();
+
+    assert_eq!(output.loops.activity(), Activity::Blocked);
+}
+
+// ============================================================================
+// Single-pass dispatch (tick and `run --once-per-loop`)
+// ============================================================================
+
+/// Records which loops ran; mentions is denied for similar phrasing.
+struct CountingDispatch {
+    denials: DenialLog,
+    calls: Vec<&'static str>,
+}
+
+impl LoopDispatch for CountingDispatch {
+    async fn run_loop(&mut self, name: &'static str) -> LoopOutcome {
+        self.calls.push(name);
+        if name == "mentions" {
+            self.denials
+                .record(tuitbot_core::safety::DenialReason::SimilarPhrasing);
+        }
+        completed(0)
+    }
+}
+
+#[tokio::test]
+async fn single_pass_runs_each_loop_exactly_once_and_returns() {
+    let denials = DenialLog::new();
+    let mut dispatch = CountingDispatch {
+        denials: denials.clone(),
+        calls: Vec::new(),
+    };
+
+    let loops = tokio::time::timeout(
+        Duration::from_secs(5),
+        dispatch_each_once(&mut dispatch, &denials),
+    )
+    .await
+    .expect("single pass finishes instead of looping");
+
+    assert_eq!(dispatch.calls, LoopFilter::VALID_NAMES);
+    assert_eq!(loops.mentions.denials.len(), 1);
+    assert!(loops.discovery.denials.is_empty());
+    assert!(loops.target.denials.is_empty());
+}
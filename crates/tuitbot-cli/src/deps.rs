@@ -12,19 +12,21 @@ use tuitbot_core::automation::adapters::{
     AnalyticsStorageAdapter, ApprovalQueueAdapter, ContentSafetyAdapter, ContentStorageAdapter,
     LlmReplyAdapter, LlmThreadAdapter, LlmTweetAdapter, PostSenderAdapter, SafetyAdapter,
     ScoringAdapter, StatusQuerierAdapter, StorageAdapter, TargetStorageAdapter, TopicScorerAdapter,
-    XApiMentionsAdapter, XApiPostExecutorAdapter, XApiProfileAdapter, XApiSearchAdapter,
-    XApiTargetAdapter, XApiThreadPosterAdapter,
+    XApiConversationAdapter, XApiLikeAdapter, XApiMentionsAdapter, XApiPostExecutorAdapter,
+    XApiProfileAdapter, XApiSearchAdapter, XApiTargetAdapter, XApiThreadPosterAdapter,
 };
 use tuitbot_core::automation::schedule::ActiveSchedule;
 use tuitbot_core::automation::{create_posting_queue, ApprovalQueue, PostAction, TargetLoopConfig};
 use tuitbot_core::config::Config;
-use tuitbot_core::content::ContentGenerator;
+use tuitbot_core::content::{ComplianceRules, ContentGenerator, FrameworkWeights, Temperatures};
 use tuitbot_core::error::XApiError;
-use tuitbot_core::llm::factory::create_provider;
-use tuitbot_core::safety::SafetyGuard;
+use tuitbot_core::llm::factory::{create_provider, DisabledProvider};
+use tuitbot_core::llm::SessionUsage;
+use tuitbot_core::safety::{DenialLog, RateLimiter, SafetyGuard};
 use tuitbot_core::scoring::ScoringEngine;
 use tuitbot_core::startup::{
-    expand_tilde, load_tokens_from_file, token_file_path, ApiTier, StartupError, TierCapabilities,
+    expand_tilde, load_tokens, token_file_path, token_store, ApiTier, StartupError,
+    TierCapabilities,
 };
 use tuitbot_core::storage;
 use tuitbot_core::x_api::auth::{TokenManager, Tokens};
@@ -340,11 +342,19 @@ mod tests {
     }
 }
 
+/// Account the CLI runtime acts for. The CLI drives a single account, so its
+/// rate limits, safety counters and rotation all live under this id.
+const RUNTIME_ACCOUNT_ID: &str = storage::accounts::DEFAULT_ACCOUNT_ID;
+
 /// All shared dependencies needed by the automation loops.
 pub struct RuntimeDeps {
     pub pool: sqlx::SqlitePool,
+    /// Account whose budgets and history the runtime reads and updates.
+    pub account_id: String,
     pub tier: ApiTier,
     pub capabilities: TierCapabilities,
+    /// Authenticated user's ID (empty in scraper mode).
+    pub own_user_id: String,
 
     // X API adapters
     pub searcher: Arc<XApiSearchAdapter>,
@@ -353,6 +363,10 @@ pub struct RuntimeDeps {
     pub profile_adapter: Arc<XApiProfileAdapter>,
     pub post_executor: Arc<XApiPostExecutorAdapter>,
     pub thread_poster: Arc<XApiThreadPosterAdapter>,
+    /// Set when `content.include_conversation_context` is on.
+    pub conversation_fetcher: Option<Arc<XApiConversationAdapter>>,
+    /// Set when `limits.discovery_likes` is on and the user ID is known.
+    pub liker: Option<Arc<XApiLikeAdapter>>,
 
     // Dynamic client (official or local mode)
     pub dyn_client: Arc<dyn XApiClient>,
@@ -361,11 +375,15 @@ pub struct RuntimeDeps {
     pub reply_gen: Arc<LlmReplyAdapter>,
     pub tweet_gen: Arc<LlmTweetAdapter>,
     pub thread_gen: Arc<LlmThreadAdapter>,
+    /// Token usage accumulated by the LLM adapters over this process.
+    pub session_usage: Arc<SessionUsage>,
 
     // Scoring / safety
     pub scorer: Arc<ScoringAdapter>,
     pub safety: Arc<SafetyAdapter>,
     pub content_safety: Arc<ContentSafetyAdapter>,
+    /// Safety denials recorded by `safety` and `content_safety`.
+    pub denials: DenialLog,
 
     // Storage adapters
     pub loop_storage: Arc<StorageAdapter>,
@@ -404,21 +422,70 @@ impl RuntimeDeps {
     /// loading, tier detection, and `get_me()`. Creates a `LocalModeXClient`
     /// instead of `XApiHttpClient`.
     pub async fn init(config: &Config, dry_run: bool) -> anyhow::Result<Self> {
+        Self::init_with_llm(config, dry_run, true).await
+    }
+
+    /// Initialize without an LLM provider (`tuitbot tick --no-llm`).
+    ///
+    /// Generation adapters are backed by [`DisabledProvider`], so only loops
+    /// that never generate content may be run with the result.
+    pub async fn init_without_llm(config: &Config, dry_run: bool) -> anyhow::Result<Self> {
+        Self::init_with_llm(config, dry_run, false).await
+    }
+
+    async fn init_with_llm(
+        config: &Config,
+        dry_run: bool,
+        llm_enabled: bool,
+    ) -> anyhow::Result<Self> {
         if config.x_api.provider_backend == "scraper" {
-            return Self::init_scraper_mode(config, dry_run).await;
+            return Self::init_scraper_mode(config, dry_run, llm_enabled).await;
         }
 
-        Self::init_official_mode(config, dry_run).await
+        Self::init_official_mode(config, dry_run, llm_enabled).await
+    }
+
+    /// Create the content generator from `config.llm`, or a disabled one.
+    fn content_generator(
+        config: &Config,
+        llm_enabled: bool,
+    ) -> anyhow::Result<Arc<ContentGenerator>> {
+        let provider = if llm_enabled {
+            create_provider(&config.llm)
+                .map_err(|e| anyhow::anyhow!("LLM provider creation failed: {e}"))?
+        } else {
+            Box::new(DisabledProvider)
+        };
+        let content_gen = Arc::new(
+            ContentGenerator::new(provider, config.business.clone())
+                .with_rag_sanitize(config.content.rag_sanitize)
+                .with_random_seed(config.content.random_seed)
+                .with_framework_weights(FrameworkWeights::from_config(&config.content))
+                .with_compliance(ComplianceRules::from_config(&config.content))
+                .with_temperatures(Temperatures::from_config(&config.content))
+                .with_retry(config.llm.retry.clone())
+                .with_dump_prompt_on_error(config.logging.dump_prompt_on_error),
+        );
+        if llm_enabled {
+            tracing::info!("LLM provider and content generator initialized");
+        } else {
+            tracing::info!("LLM disabled; content generation is unavailable");
+        }
+        Ok(content_gen)
     }
 
     /// Initialize in official X API mode (existing behavior).
-    async fn init_official_mode(config: &Config, dry_run: bool) -> anyhow::Result<Self> {
+    async fn init_official_mode(
+        config: &Config,
+        dry_run: bool,
+        llm_enabled: bool,
+    ) -> anyhow::Result<Self> {
         // 1. Validate database path.
         let db_path = expand_tilde(&config.storage.db_path);
         tracing::info!(path = %db_path.display(), "Database path configured");
 
         // 2. Load OAuth tokens and create token manager.
-        let stored = load_tokens_from_file().map_err(|e| match e {
+        let stored = load_tokens(&config.auth).map_err(|e| match e {
             StartupError::AuthRequired => anyhow::anyhow!(
                 "No X API credentials found.\n\
                  \n\
@@ -446,11 +513,14 @@ impl RuntimeDeps {
             scopes: stored.scopes.clone(),
         };
 
-        let token_manager = Arc::new(TokenManager::new(
-            auth_tokens,
-            config.x_api.client_id.clone(),
-            token_file_path(),
-        ));
+        let token_manager = Arc::new(
+            TokenManager::new(
+                auth_tokens,
+                config.x_api.client_id.clone(),
+                token_file_path(),
+            )
+            .with_store(token_store(&config.auth)),
+        );
 
         // Attempt refresh if token is expired or near expiry, instead of bailing.
         if let Err(e) = token_manager.refresh_if_needed().await {
@@ -475,7 +545,11 @@ impl RuntimeDeps {
         );
 
         // 3. Determine API tier by probing the search endpoint.
-        let x_client = XApiHttpClient::new(current_token);
+        let x_client = XApiHttpClient::new(current_token)
+            .with_timeouts(&config.x_api.http)
+            .with_cache_ttl(std::time::Duration::from_secs(
+                config.x_api.cache_ttl_seconds,
+            ));
         let detected = detect_tier(&x_client).await.map_err(|e| match e {
             XApiError::AuthExpired => anyhow::anyhow!(
                 "X API token is expired or invalid.\n\
@@ -495,7 +569,7 @@ impl RuntimeDeps {
                      calls were made recently. {wait}"
                 )
             }
-            XApiError::Network { source } => anyhow::anyhow!(
+            XApiError::Network { source } | XApiError::Timeout { source } => anyhow::anyhow!(
                 "Cannot reach api.x.com.\n\
                  \n\
                  A network error occurred while connecting to the X API: {source}\n\
@@ -517,7 +591,7 @@ impl RuntimeDeps {
         tracing::info!(tier = %tier, "{}", capabilities.format_status());
 
         // 4. Initialize database.
-        let pool = storage::init_db(&config.storage.db_path)
+        let pool = storage::init_db_with_config(&config.storage.db_path, &config.storage)
             .await
             .map_err(|e| anyhow::anyhow!("Database initialization failed: {e}"))?;
         tracing::info!("Database initialized");
@@ -537,10 +611,7 @@ impl RuntimeDeps {
             .map_err(|e| anyhow::anyhow!("Failed to persist API tier: {e}"))?;
 
         // 6. Create LLM provider and content generator.
-        let provider = create_provider(&config.llm)
-            .map_err(|e| anyhow::anyhow!("LLM provider creation failed: {e}"))?;
-        let content_gen = Arc::new(ContentGenerator::new(provider, config.business.clone()));
-        tracing::info!("LLM provider and content generator initialized");
+        let content_gen = Self::content_generator(config, llm_enabled)?;
 
         // 7. Create scoring engine and safety guard.
         let keywords: Vec<String> = config
@@ -550,8 +621,11 @@ impl RuntimeDeps {
             .chain(config.business.competitor_keywords.iter())
             .cloned()
             .collect();
-        let scoring_engine = Arc::new(ScoringEngine::new(config.scoring.clone(), keywords.clone()));
-        let safety_guard = Arc::new(SafetyGuard::new(pool.clone()));
+        let scoring_engine = Arc::new(
+            ScoringEngine::new(config.scoring.clone(), keywords.clone())
+                .with_targets(&config.targets),
+        );
+        let safety_guard = Arc::new(SafetyGuard::new_for(pool.clone(), RUNTIME_ACCOUNT_ID));
         tracing::info!("Scoring engine and safety guard initialized");
 
         // 8. Get own user ID.
@@ -573,7 +647,7 @@ impl RuntimeDeps {
                      The X API is throttling requests from your account. {wait}"
                 )
             }
-            XApiError::Network { source } => anyhow::anyhow!(
+            XApiError::Network { source } | XApiError::Timeout { source } => anyhow::anyhow!(
                 "Cannot reach api.x.com while fetching your profile.\n\
                  \n\
                  Network error: {source}\n\
@@ -617,7 +691,11 @@ impl RuntimeDeps {
     }
 
     /// Initialize in scraper mode — no OAuth tokens, no tier detection.
-    async fn init_scraper_mode(config: &Config, dry_run: bool) -> anyhow::Result<Self> {
+    async fn init_scraper_mode(
+        config: &Config,
+        dry_run: bool,
+        llm_enabled: bool,
+    ) -> anyhow::Result<Self> {
         tracing::info!("Starting in Local No-Key Mode (scraper backend)");
 
         // 1. Validate database path.
@@ -647,7 +725,7 @@ impl RuntimeDeps {
         tracing::info!(tier = %tier, "Scraper mode capabilities: discovery=false, search=false, mentions=false, posting={}", capabilities.posting);
 
         // 4. Initialize database.
-        let pool = storage::init_db(&config.storage.db_path)
+        let pool = storage::init_db_with_config(&config.storage.db_path, &config.storage)
             .await
             .map_err(|e| anyhow::anyhow!("Database initialization failed: {e}"))?;
         tracing::info!("Database initialized");
@@ -664,10 +742,7 @@ impl RuntimeDeps {
             .map_err(|e| anyhow::anyhow!("Failed to persist API tier: {e}"))?;
 
         // 6. Create LLM provider and content generator.
-        let provider = create_provider(&config.llm)
-            .map_err(|e| anyhow::anyhow!("LLM provider creation failed: {e}"))?;
-        let content_gen = Arc::new(ContentGenerator::new(provider, config.business.clone()));
-        tracing::info!("LLM provider and content generator initialized");
+        let content_gen = Self::content_generator(config, llm_enabled)?;
 
         // 7. Create scoring engine and safety guard.
         let keywords: Vec<String> = config
@@ -677,8 +752,11 @@ impl RuntimeDeps {
             .chain(config.business.competitor_keywords.iter())
             .cloned()
             .collect();
-        let scoring_engine = Arc::new(ScoringEngine::new(config.scoring.clone(), keywords.clone()));
-        let safety_guard = Arc::new(SafetyGuard::new(pool.clone()));
+        let scoring_engine = Arc::new(
+            ScoringEngine::new(config.scoring.clone(), keywords.clone())
+                .with_targets(&config.targets),
+        );
+        let safety_guard = Arc::new(SafetyGuard::new_for(pool.clone(), RUNTIME_ACCOUNT_ID));
         tracing::info!("Scoring engine and safety guard initialized");
 
         // 8. No get_me() — use empty user ID (mentions loop won't run).
@@ -730,9 +808,12 @@ impl RuntimeDeps {
         x_client: Option<Arc<XApiHttpClient>>,
         keywords: Vec<String>,
     ) -> Self {
+        let account_id = RUNTIME_ACCOUNT_ID;
         let searcher: Arc<XApiSearchAdapter> = Arc::new(XApiSearchAdapter::new(dyn_client.clone()));
-        let mentions_fetcher: Arc<XApiMentionsAdapter> =
-            Arc::new(XApiMentionsAdapter::new(dyn_client.clone(), own_user_id));
+        let mentions_fetcher: Arc<XApiMentionsAdapter> = Arc::new(XApiMentionsAdapter::new(
+            dyn_client.clone(),
+            own_user_id.clone(),
+        ));
         let target_adapter: Arc<XApiTargetAdapter> =
             Arc::new(XApiTargetAdapter::new(dyn_client.clone()));
         let profile_adapter: Arc<XApiProfileAdapter> =
@@ -741,29 +822,67 @@ impl RuntimeDeps {
             Arc::new(XApiPostExecutorAdapter::new(dyn_client.clone()));
         let thread_poster: Arc<XApiThreadPosterAdapter> =
             Arc::new(XApiThreadPosterAdapter::new(dyn_client.clone()));
-
-        let reply_gen: Arc<LlmReplyAdapter> =
-            Arc::new(LlmReplyAdapter::new(content_gen.clone(), pool.clone()));
-        let tweet_gen: Arc<LlmTweetAdapter> =
-            Arc::new(LlmTweetAdapter::new(content_gen.clone(), pool.clone()));
-        let thread_gen: Arc<LlmThreadAdapter> =
-            Arc::new(LlmThreadAdapter::new(content_gen.clone(), pool.clone()));
-
-        let scorer: Arc<ScoringAdapter> = Arc::new(ScoringAdapter::new(scoring_engine));
-        let safety: Arc<SafetyAdapter> =
-            Arc::new(SafetyAdapter::new(safety_guard.clone(), pool.clone()));
+        let conversation_fetcher = config
+            .content
+            .include_conversation_context
+            .then(|| Arc::new(XApiConversationAdapter::new(dyn_client.clone())));
+        let liker = (config.limits.discovery_likes && !own_user_id.is_empty()).then(|| {
+            Arc::new(XApiLikeAdapter::new(
+                dyn_client.clone(),
+                own_user_id.clone(),
+                RateLimiter::new_for(pool.clone(), account_id),
+            ))
+        });
+
+        let session_usage = Arc::new(SessionUsage::new());
+        let mut reply_gen = LlmReplyAdapter::new(content_gen.clone(), pool.clone())
+            .with_session_usage(session_usage.clone());
+        let mut tweet_gen = LlmTweetAdapter::new(content_gen.clone(), pool.clone())
+            .with_session_usage(session_usage.clone());
+        let mut thread_gen = LlmThreadAdapter::new(content_gen.clone(), pool.clone())
+            .with_session_usage(session_usage.clone());
+        if config.content.auto_rotate {
+            reply_gen = reply_gen.with_rotation(account_id);
+            tweet_gen = tweet_gen.with_rotation(account_id);
+            thread_gen = thread_gen.with_rotation(account_id);
+        }
+        let reply_gen: Arc<LlmReplyAdapter> = Arc::new(reply_gen);
+        if config
+            .content_sources
+            .sources
+            .iter()
+            .any(|source| source.is_enabled())
+        {
+            tweet_gen = tweet_gen.with_rag(account_id, config.content.rag_required);
+        }
+        let tweet_gen: Arc<LlmTweetAdapter> = Arc::new(tweet_gen);
+        let thread_gen: Arc<LlmThreadAdapter> = Arc::new(thread_gen);
+
+        let scorer: Arc<ScoringAdapter> =
+            Arc::new(ScoringAdapter::new(scoring_engine).with_reputation(pool.clone(), account_id));
+        let denials = DenialLog::new();
+        let safety: Arc<SafetyAdapter> = Arc::new(
+            SafetyAdapter::new(safety_guard.clone(), pool.clone())
+                .with_banned_phrases(config.limits.banned_phrases.clone())
+                .with_conversation_limit(config.limits.max_replies_per_conversation_per_day)
+                .with_denial_log(denials.clone()),
+        );
         let content_safety: Arc<ContentSafetyAdapter> =
-            Arc::new(ContentSafetyAdapter::new(safety_guard));
+            Arc::new(ContentSafetyAdapter::new(safety_guard).with_denial_log(denials.clone()));
 
         let loop_storage: Arc<StorageAdapter> = Arc::new(StorageAdapter::new(pool.clone()));
-        let content_storage: Arc<ContentStorageAdapter> =
-            Arc::new(ContentStorageAdapter::new(pool.clone(), post_tx.clone()));
+        let content_storage: Arc<ContentStorageAdapter> = Arc::new(ContentStorageAdapter::new_for(
+            pool.clone(),
+            account_id,
+            post_tx.clone(),
+        ));
         let target_storage: Arc<TargetStorageAdapter> =
             Arc::new(TargetStorageAdapter::new(pool.clone()));
         let analytics_storage: Arc<AnalyticsStorageAdapter> =
             Arc::new(AnalyticsStorageAdapter::new(pool.clone()));
         let topic_scorer: Arc<TopicScorerAdapter> = Arc::new(TopicScorerAdapter::new(pool.clone()));
-        let post_sender: Arc<PostSenderAdapter> = Arc::new(PostSenderAdapter::new(post_tx));
+        let post_sender: Arc<PostSenderAdapter> =
+            Arc::new(PostSenderAdapter::new(post_tx).with_framework_links(pool.clone()));
         let status_querier: Arc<StatusQuerierAdapter> =
             Arc::new(StatusQuerierAdapter::new(pool.clone()));
 
@@ -794,21 +913,27 @@ impl RuntimeDeps {
 
         Self {
             pool,
+            account_id: account_id.to_string(),
             tier,
             capabilities,
+            own_user_id,
             searcher,
             mentions_fetcher,
             target_adapter,
             profile_adapter,
             post_executor,
             thread_poster,
+            conversation_fetcher,
+            liker,
             dyn_client,
             reply_gen,
             tweet_gen,
             thread_gen,
+            session_usage,
             scorer,
             safety,
             content_safety,
+            denials,
             loop_storage,
             content_storage,
             target_storage,
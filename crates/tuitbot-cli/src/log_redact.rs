@@ -0,0 +1,106 @@
+/// Tracing writer that scrubs secrets from log lines before they reach stderr.
+///
+/// The fmt layer creates one writer per event, so buffering until drop lets
+/// `safety::redact::scrub` see each formatted line whole, even when it is
+/// written in several pieces.
+use std::io::{self, Write};
+
+use tracing_subscriber::fmt::MakeWriter;
+use tuitbot_core::safety::redact::scrub;
+
+/// `MakeWriter` wrapper that scrubs every event written through `inner`.
+pub struct ScrubbingMakeWriter<M> {
+    inner: M,
+}
+
+impl<M> ScrubbingMakeWriter<M> {
+    pub fn new(inner: M) -> Self {
+        Self { inner }
+    }
+}
+
+impl<'a, M: MakeWriter<'a>> MakeWriter<'a> for ScrubbingMakeWriter<M> {
+    type Writer = ScrubbingWriter<M::Writer>;
+
+    fn make_writer(&'a self) -> Self::Writer {
+        ScrubbingWriter {
+            inner: self.inner.make_writer(),
+            buf: Vec::new(),
+        }
+    }
+}
+
+/// Buffers one event and writes it, scrubbed, to `inner` on drop.
+pub struct ScrubbingWriter<W: Write> {
+    inner: W,
+    buf: Vec<u8>,
+}
+
+impl<W: Write> Write for ScrubbingWriter<W> {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        self.buf.extend_from_slice(bytes);
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl<W: Write> Drop for ScrubbingWriter<W> {
+    fn drop(&mut self) {
+        if self.buf.is_empty() {
+            return;
+        }
+        let line = scrub(&String::from_utf8_lossy(&self.buf));
+        let _ = self.inner.write_all(line.as_bytes());
+        let _ = self.inner.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Capture {
+        fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(bytes);
+            Ok(bytes.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn log_events_are_scrubbed() {
+        let capture = Capture::default();
+        let make = {
+            let capture = capture.clone();
+            move || capture.clone()
+        };
+        let subscriber = tracing_subscriber::fmt()
+            .with_writer(ScrubbingMakeWriter::new(make))
+            .with_ansi(false)
+            .finish();
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(
+                header = "Bearer abc.def.ghi",
+                "LLM call failed with key sk-ant-api03-abcdefgh1234"
+            );
+        });
+
+        let logged = String::from_utf8(capture.0.lock().unwrap().clone()).unwrap();
+        assert!(logged.contains("LLM call failed with key ***REDACTED***"));
+        assert!(logged.contains("Bearer ***REDACTED***"));
+        assert!(!logged.contains("abcdefgh1234"));
+        assert!(!logged.contains("abc.def.ghi"));
+    }
+}
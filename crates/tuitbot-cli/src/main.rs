@@ -4,19 +4,22 @@
 /// initializes logging, and dispatches to subcommand handlers.
 mod commands;
 mod deps;
+mod log_redact;
 pub mod output;
 
 use std::io::IsTerminal;
 
-use clap::Parser;
+use clap::{CommandFactory, Parser};
 use tracing_subscriber::EnvFilter;
 use tuitbot_core::config::Config;
+use tuitbot_core::safety::redact::scrub;
 
 /// Autonomous X growth assistant
 #[derive(Parser)]
 #[command(name = "tuitbot")]
 #[command(version)]
 #[command(about = "Autonomous X growth assistant")]
+#[command(arg_required_else_help = true)]
 #[command(after_help = "\
 Quick start:
   1. tuitbot init     — interactive setup wizard
@@ -45,8 +48,16 @@ struct Cli {
     #[arg(long, global = true, default_value = "text", value_parser = ["text", "json"])]
     output: String,
 
+    /// Print the fully-resolved config (defaults + file + env overrides), secrets redacted, and exit
+    #[arg(long, global = true)]
+    dump_effective_config: bool,
+
+    /// On a generation failure, log the full prompt and last raw LLM response (secrets redacted)
+    #[arg(long, global = true)]
+    dump_prompt_on_error: bool,
+
     #[command(subcommand)]
-    command: Commands,
+    command: Option<Commands>,
 }
 
 #[derive(clap::Subcommand)]
@@ -73,6 +84,8 @@ enum Commands {
     Thread(commands::ThreadArgs),
     /// Edit configuration interactively
     Settings(commands::SettingsArgs),
+    /// Show upcoming posting slots or export them as a calendar
+    Schedule(commands::ScheduleArgs),
     /// Score a specific tweet
     Score(commands::ScoreArgs),
     /// Show analytics dashboard
@@ -92,8 +105,12 @@ enum Commands {
     Backup(commands::BackupArgs),
     /// Restore database from a backup
     Restore(commands::RestoreArgs),
+    /// Apply pending database migrations and report the schema version
+    Migrate(commands::MigrateArgs),
     /// Remove Tuitbot data and binaries from this machine
     Uninstall(commands::UninstallArgs),
+    /// Describe MCP tools, HTTP routes, CLI commands, and config fields
+    ExportManifest,
 }
 
 #[tokio::main]
@@ -118,10 +135,10 @@ async fn main() {
         }
         Err(e) => {
             if wants_json {
-                let json = serde_json::json!({ "error": format!("{e:#}") });
+                let json = serde_json::json!({ "error": scrub(&format!("{e:#}")) });
                 let _ = output::write_stdout(&json.to_string());
             } else {
-                eprintln!("Error: {e:#}");
+                eprintln!("Error: {}", scrub(&format!("{e:#}")));
             }
             std::process::exit(1);
         }
@@ -137,6 +154,8 @@ async fn run() -> anyhow::Result<()> {
     // - Default: warn level, compact format with timestamps.
     // - Verbose (-v): debug level, includes module paths.
     // - Quiet (-q): error level, minimal format.
+    // - --dump-prompt-on-error: also debug for the content generator, so the
+    //   prompt dump is visible without -v.
     let suppress_logs = cli.quiet || commands::OutputFormat::from_str(&cli.output).is_json();
     let filter = if suppress_logs {
         // --quiet and --output json always suppress logs, even if RUST_LOG is set.
@@ -146,13 +165,15 @@ async fn run() -> anyhow::Result<()> {
         EnvFilter::from_default_env()
     } else if cli.verbose {
         EnvFilter::new("tuitbot=debug,tuitbot_core=debug,info")
+    } else if cli.dump_prompt_on_error {
+        EnvFilter::new("tuitbot=info,tuitbot_core=info,tuitbot_core::content::generator=debug,warn")
     } else {
         EnvFilter::new("tuitbot=info,tuitbot_core=info,warn")
     };
 
     tracing_subscriber::fmt()
         .with_env_filter(filter)
-        .with_writer(std::io::stderr)
+        .with_writer(log_redact::ScrubbingMakeWriter::new(std::io::stderr))
         .with_target(cli.verbose)
         .compact()
         .init();
@@ -160,12 +181,33 @@ async fn run() -> anyhow::Result<()> {
     let output_format = commands::OutputFormat::from_str(&cli.output);
     let out = output::CliOutput::new(cli.quiet, output_format);
 
+    // `--dump-effective-config` resolves the config on its own and exits
+    // before any command runs, whichever subcommand was given.
+    if cli.dump_effective_config {
+        return commands::effective_config::execute(&cli.config, out);
+    }
+    let Some(command) = cli.command else {
+        Cli::command()
+            .error(
+                clap::error::ErrorKind::MissingSubcommand,
+                "a subcommand is required",
+            )
+            .exit();
+    };
+
     // Handle `init`, `update`, `upgrade`, and `settings` before general config
     // loading (they manage their own config lifecycle).
-    if let Commands::Init(args) = cli.command {
-        return commands::init::execute(args.force, args.non_interactive, args.advanced, out).await;
+    if let Commands::Init(args) = command {
+        return commands::init::execute(
+            args.force,
+            args.non_interactive,
+            args.advanced,
+            args.from_replyguy,
+            out,
+        )
+        .await;
     }
-    if let Commands::Update(args) = cli.command {
+    if let Commands::Update(args) = command {
         return commands::update::execute(
             args.non_interactive,
             args.check,
@@ -175,22 +217,25 @@ async fn run() -> anyhow::Result<()> {
         )
         .await;
     }
-    if let Commands::Upgrade(args) = cli.command {
+    if let Commands::Upgrade(args) = command {
         return commands::upgrade::execute(args.non_interactive, &cli.config).await;
     }
-    if let Commands::Settings(args) = cli.command {
+    if let Commands::Settings(args) = command {
         return commands::settings::execute(args, &cli.config, output_format).await;
     }
-    if let Commands::Backup(args) = cli.command {
+    if let Commands::Backup(args) = command {
         return commands::backup::execute(args, &cli.config, out).await;
     }
-    if let Commands::Restore(args) = cli.command {
+    if let Commands::Restore(args) = command {
         return commands::restore::execute(args, &cli.config, out).await;
     }
-    if let Commands::Uninstall(args) = cli.command {
+    if let Commands::Uninstall(args) = command {
         return commands::uninstall::execute(args.force, args.data_only, out);
     }
-    if let Commands::Mcp(ref args) = cli.command {
+    if let Commands::ExportManifest = command {
+        return commands::export_manifest::execute(Cli::command(), out);
+    }
+    if let Commands::Mcp(ref args) = command {
         return match &args.command {
             commands::McpSubcommand::Manifest { ref profile } => {
                 commands::mcp::print_manifest(profile)
@@ -201,15 +246,27 @@ async fn run() -> anyhow::Result<()> {
             commands::McpSubcommand::Setup => commands::mcp::execute_setup(out).await,
         };
     }
-    if let Commands::Doctor(_) = cli.command {
-        return commands::doctor::execute(&cli.config).await;
+    if let Commands::Auth(commands::AuthArgs {
+        command: Some(commands::AuthSubcommand::Status { live }),
+        ..
+    }) = command
+    {
+        return commands::auth_status::execute(&cli.config, live, out).await;
     }
-    if let Commands::Accounts(args) = cli.command {
+    if let Commands::Doctor(_) = command {
+        return commands::doctor::execute(&cli.config, out).await;
+    }
+    if let Commands::Accounts(args) = command {
         return commands::accounts::execute(args.command, &cli.config, out).await;
     }
+    if let Commands::Test(ref args) = command {
+        if args.config_check {
+            return commands::test::execute_config_check(&cli.config, out);
+        }
+    }
 
     // Load configuration.
-    let config = match Config::load(Some(&cli.config)) {
+    let mut config = match Config::load(Some(&cli.config)) {
         Ok(c) => c,
         Err(e) => {
             // If the default config path doesn't exist and we're in an
@@ -227,7 +284,7 @@ async fn run() -> anyhow::Result<()> {
                     .unwrap_or(false);
 
                 if run_init {
-                    return commands::init::execute(false, false, false, out).await;
+                    return commands::init::execute(false, false, false, None, out).await;
                 }
             }
 
@@ -237,18 +294,23 @@ async fn run() -> anyhow::Result<()> {
             ));
         }
     };
+    if cli.dump_prompt_on_error {
+        config.logging.dump_prompt_on_error = true;
+    }
+    tuitbot_core::safety::redact::register_patterns(&config.logging.redact_patterns)
+        .map_err(|e| anyhow::anyhow!("invalid logging.redact_patterns: {e}"))?;
 
     // Validate db_path early for all commands except Test (which shows its own diagnostics).
-    if !matches!(&cli.command, Commands::Test(_)) {
+    if !matches!(&command, Commands::Test(_)) {
         tuitbot_core::startup::validate_db_path(&config.storage.db_path)?;
     }
 
     // Check for config upgrade opportunity before `run`
-    if matches!(&cli.command, Commands::Run(_)) && std::io::stdin().is_terminal() {
+    if matches!(&command, Commands::Run(_)) && std::io::stdin().is_terminal() {
         commands::update::check_before_run(&cli.config).await?;
     }
 
-    match cli.command {
+    match command {
         Commands::Init(_)
         | Commands::Update(_)
         | Commands::Upgrade(_)
@@ -256,13 +318,18 @@ async fn run() -> anyhow::Result<()> {
         | Commands::Backup(_)
         | Commands::Restore(_)
         | Commands::Uninstall(_)
+        | Commands::ExportManifest
         | Commands::Mcp(_)
         | Commands::Doctor(_)
         | Commands::Accounts(_) => {
             unreachable!()
         }
         Commands::Run(args) => {
-            commands::run::execute(&config, args.status_interval).await?;
+            if args.once_per_loop {
+                commands::run::execute_once_per_loop(&config).await?;
+            } else {
+                commands::run::execute(&config, args.status_interval).await?;
+            }
         }
         Commands::Tick(args) => {
             let mut config = config;
@@ -272,32 +339,49 @@ async fn run() -> anyhow::Result<()> {
             commands::tick::execute(&config, args, out).await?;
         }
         Commands::Auth(args) => {
-            commands::auth::execute(&config, args.mode.as_deref()).await?;
+            commands::auth::execute(
+                &config,
+                args.mode.as_deref(),
+                args.validate_callback_only.as_deref(),
+            )
+            .await?;
         }
         Commands::Test(_args) => {
             commands::test::execute(&config, &cli.config, out).await?;
         }
-        Commands::Discover(_args) => {
-            anyhow::bail!("discover: not yet available (requires WP08 merge)");
+        Commands::Discover(args) => {
+            commands::discover::execute(&config, args, out).await?;
         }
-        Commands::Mentions(_args) => {
-            anyhow::bail!("mentions: not yet available (requires WP08 merge)");
+        Commands::Mentions(args) => {
+            commands::mentions::execute(&config, args, out).await?;
         }
         Commands::Post(_args) => {
             anyhow::bail!("post: not yet available (requires WP09 merge)");
         }
-        Commands::Thread(_args) => {
-            anyhow::bail!("thread: not yet available (requires WP09 merge)");
+        Commands::Thread(args) => {
+            if let Some(path) = &args.from_markdown {
+                commands::thread::execute_from_markdown(&config, path, args.dry_run, out).await?;
+            } else if let Some(thread_id) = args.resume {
+                commands::thread::execute_resume(&config, thread_id, out).await?;
+            } else {
+                anyhow::bail!("thread: not yet available (requires WP09 merge)");
+            }
         }
-        Commands::Score(_args) => {
-            anyhow::bail!("score: not yet available (requires WP06 merge)");
+        Commands::Schedule(args) => {
+            commands::schedule::execute(&config, args, out).await?;
         }
-        Commands::Stats(_args) => {
-            commands::stats::execute(&config, out).await?;
+        Commands::Score(args) => {
+            commands::score::execute(&config, args, out).await?;
+        }
+        Commands::Stats(args) => {
+            commands::stats::execute(&config, args, out).await?;
         }
         Commands::Approve(args) => {
             commands::approve::execute(&config, args, out).await?;
         }
+        Commands::Migrate(_) => {
+            commands::migrate::execute(&config, out).await?;
+        }
     }
 
     Ok(())
@@ -38,6 +38,7 @@ impl CliOutput {
     /// Print an error message to stderr. In JSON mode, emits a JSON error
     /// envelope to stdout instead.
     pub fn error(&self, msg: &str) -> anyhow::Result<()> {
+        let msg = tuitbot_core::safety::redact::scrub(msg);
         if self.is_json() {
             let json = serde_json::json!({ "error": msg });
             write_stdout(&json.to_string())
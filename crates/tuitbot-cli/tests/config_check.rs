@@ -0,0 +1,99 @@
+//! Exit-code contract for `tuitbot test --config-check`.
+
+use std::path::Path;
+use std::process::{Command, Output};
+
+const VALID_CONFIG: &str = r#"
+[business]
+product_name = "Tuitbot"
+product_description = "Growth assistant for developers"
+product_keywords = ["rust"]
+industry_topics = ["devtools"]
+
+[x_api]
+client_id = "abc123"
+"#;
+
+fn config_check(config_path: &Path, extra: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_tuitbot"))
+        .arg("--config")
+        .arg(config_path)
+        .args(extra)
+        .args(["test", "--config-check"])
+        .output()
+        .expect("run tuitbot")
+}
+
+#[test]
+fn valid_config_exits_zero_and_is_silent_with_quiet() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    std::fs::write(&path, VALID_CONFIG).unwrap();
+
+    let output = config_check(&path, &["--quiet"]);
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stdout.is_empty());
+    assert!(output.stderr.is_empty());
+}
+
+#[test]
+fn validation_errors_exit_two() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    std::fs::write(&path, "[scoring]\nthreshold = 150\n").unwrap();
+
+    let output = config_check(&path, &[]);
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("scoring.threshold"), "{stderr}");
+}
+
+#[test]
+fn conflicting_style_warns_but_exits_zero() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    let config = VALID_CONFIG.replace(
+        "industry_topics = [\"devtools\"]",
+        "industry_topics = [\"devtools\"]\ncontent_style = \"Always add hashtags\"",
+    );
+    std::fs::write(&path, config).unwrap();
+
+    let output = config_check(&path, &[]);
+    assert_eq!(output.status.code(), Some(0));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Warning: business.content_style"),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn missing_file_exits_three() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = config_check(&dir.path().join("absent.toml"), &[]);
+    assert_eq!(output.status.code(), Some(3));
+}
+
+#[test]
+fn parse_error_exits_three() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    std::fs::write(&path, "[business\nproduct_name = ").unwrap();
+
+    let output = config_check(&path, &[]);
+    assert_eq!(output.status.code(), Some(3));
+}
+
+#[test]
+fn json_output_reports_exit_code() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("config.toml");
+    std::fs::write(&path, "[scoring]\nthreshold = 150\n").unwrap();
+
+    let output = config_check(&path, &["--output", "json"]);
+    assert_eq!(output.status.code(), Some(2));
+    let json: serde_json::Value = serde_json::from_slice(&output.stdout).expect("json stdout");
+    assert_eq!(json["valid"], false);
+    assert_eq!(json["exit_code"], 2);
+    assert!(!json["errors"].as_array().unwrap().is_empty());
+}
@@ -13,7 +13,7 @@ use chrono::{Duration, Utc};
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 
 use tuitbot_core::{
-    config::ScoringConfig,
+    config::{RecencyCurve, ScoringConfig},
     scoring::{
         signals::{
             content_type_score, engagement_rate, follower_score, keyword_relevance,
@@ -95,7 +95,15 @@ fn bench_recency_score(c: &mut Criterion) {
     for &age in ages_min {
         let ts = (now - Duration::minutes(age)).to_rfc3339_opts(chrono::SecondsFormat::Secs, true);
         group.bench_with_input(BenchmarkId::new("age_min", age), &ts, |b, ts| {
-            b.iter(|| recency_score_at(black_box(ts.as_str()), black_box(10.0), now));
+            b.iter(|| {
+                recency_score_at(
+                    black_box(ts.as_str()),
+                    black_box(10.0),
+                    RecencyCurve::Brackets,
+                    60,
+                    now,
+                )
+            });
         });
     }
     group.finish();
@@ -0,0 +1,14 @@
+-- Per-author reply outcomes, used to down-weight authors whose threads never
+-- engage with our replies. Rows are recomputed from replies_sent,
+-- discovered_tweets and reply_performance whenever a reply is measured.
+
+CREATE TABLE IF NOT EXISTS author_reputation (
+    account_id TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    author_id TEXT NOT NULL,
+    author_username TEXT NOT NULL DEFAULT '',
+    replies_sent INTEGER NOT NULL DEFAULT 0,
+    replies_measured INTEGER NOT NULL DEFAULT 0,
+    avg_performance REAL NOT NULL DEFAULT 0.0,
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    PRIMARY KEY (account_id, author_id)
+);
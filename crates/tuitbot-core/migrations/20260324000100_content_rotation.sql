@@ -0,0 +1,14 @@
+-- Selection history for content::rotation. Each row records one archetype,
+-- tweet format, or thread structure handed to the generator so the next
+-- pick can favor the least-recently-used variant.
+
+CREATE TABLE IF NOT EXISTS content_rotation (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    kind TEXT NOT NULL,
+    variant TEXT NOT NULL,
+    used_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_content_rotation_account_kind
+    ON content_rotation (account_id, kind, variant, id);
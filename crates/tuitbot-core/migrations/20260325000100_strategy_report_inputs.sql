@@ -0,0 +1,6 @@
+-- Snapshot of the inputs a strategy report was computed from (scoring
+-- config, business profile, week metrics, target accounts), serialized as
+-- JSON so a past recommendation can be reproduced. NULL for reports
+-- computed before snapshots were recorded.
+
+ALTER TABLE strategy_reports ADD COLUMN inputs_json TEXT;
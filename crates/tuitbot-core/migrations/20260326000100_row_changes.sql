@@ -0,0 +1,15 @@
+-- Change log for incremental backups.
+--
+-- Rows are written by per-table triggers that
+-- `storage::incremental_backup::enable_change_tracking` installs when the
+-- first full base backup of an incremental chain is taken. Databases that
+-- never use incremental backups have no triggers and this table stays empty.
+CREATE TABLE IF NOT EXISTS row_changes (
+    seq INTEGER PRIMARY KEY AUTOINCREMENT,
+    table_name TEXT NOT NULL,
+    row_id INTEGER NOT NULL,
+    op TEXT NOT NULL,
+    changed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_row_changes_table_seq ON row_changes(table_name, seq);
@@ -0,0 +1,8 @@
+-- Conversation (root tweet) ID of the thread each reply was posted into, so
+-- `limits.max_replies_per_conversation_per_day` can be enforced. NULL when
+-- the target tweet's conversation ID was not available.
+
+ALTER TABLE replies_sent ADD COLUMN conversation_id TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_replies_sent_conversation
+    ON replies_sent(conversation_id, created_at);
@@ -0,0 +1,16 @@
+-- Framework (reply archetype, tweet format, or thread structure) used for
+-- each posted item, so engagement captured later in tweet_performance /
+-- reply_performance can be compared across frameworks.
+
+CREATE TABLE IF NOT EXISTS content_frameworks (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    account_id TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    tweet_id TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    framework TEXT NOT NULL,
+    posted_at TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE (account_id, tweet_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_content_frameworks_account_kind
+    ON content_frameworks (account_id, kind, framework);
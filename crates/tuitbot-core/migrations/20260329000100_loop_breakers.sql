@@ -0,0 +1,14 @@
+-- Per-loop error budget. A loop that keeps failing is paused until
+-- open_until; state survives across `tuitbot tick` invocations.
+
+CREATE TABLE IF NOT EXISTS loop_breakers (
+    account_id TEXT NOT NULL DEFAULT '00000000-0000-0000-0000-000000000000',
+    loop_name TEXT NOT NULL,
+    consecutive_failures INTEGER NOT NULL DEFAULT 0,
+    window_started_at TEXT,
+    open_until TEXT,
+    trips INTEGER NOT NULL DEFAULT 0,
+    last_error TEXT,
+    updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+    PRIMARY KEY (account_id, loop_name)
+);
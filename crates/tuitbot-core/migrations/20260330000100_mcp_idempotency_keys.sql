@@ -0,0 +1,13 @@
+-- Fingerprints of recent MCP mutation calls, so a retried call is still
+-- recognised after the MCP server restarts. `response` holds the result of
+-- the original call once it succeeds.
+
+CREATE TABLE IF NOT EXISTS mcp_idempotency_keys (
+    key TEXT PRIMARY KEY,
+    tool_name TEXT NOT NULL,
+    response TEXT,
+    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_mcp_idempotency_keys_created
+    ON mcp_idempotency_keys(created_at);
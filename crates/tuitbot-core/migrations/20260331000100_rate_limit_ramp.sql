@@ -0,0 +1,11 @@
+-- Soft-launch ramp for posting limits (`limits.ramp`).
+--
+-- `ramp_start`/`ramp_target` are fractions of `max_requests`, reached over
+-- `ramp_days` days from `ramp_anchor`, the account's first recorded activity.
+-- The anchor is stored rather than derived from action_log so retention
+-- cleanup cannot restart the ramp. NULL ramp columns mean no ramp.
+
+ALTER TABLE rate_limits ADD COLUMN ramp_start REAL;
+ALTER TABLE rate_limits ADD COLUMN ramp_target REAL;
+ALTER TABLE rate_limits ADD COLUMN ramp_days INTEGER;
+ALTER TABLE rate_limits ADD COLUMN ramp_anchor TEXT;
@@ -0,0 +1,351 @@
+//! Live activity events for posting actions.
+//!
+//! The posting paths emit an [`ActivityEvent`] whenever a reply, tweet, or
+//! thread tweet is posted, denied, or fails. Events go to an [`ActivitySink`]
+//! supplied by the host (e.g. the server's WebSocket broadcast), which keeps
+//! core free of any transport dependency.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::automation::loop_helpers::LoopError;
+use crate::error::XApiError;
+use crate::storage::{action_log, DbPool};
+
+/// What kind of content the action posted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityKind {
+    Reply,
+    Tweet,
+    Thread,
+}
+
+impl fmt::Display for ActivityKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Reply => write!(f, "reply"),
+            Self::Tweet => write!(f, "tweet"),
+            Self::Thread => write!(f, "thread"),
+        }
+    }
+}
+
+/// Outcome of a posting action.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityStatus {
+    /// The content was published.
+    Posted,
+    /// The platform or a policy refused the action.
+    Denied,
+    /// The action failed for another reason (network, server error).
+    Failed,
+}
+
+impl From<&XApiError> for ActivityStatus {
+    /// Refusals by the platform or a policy are denials; anything else failed.
+    fn from(e: &XApiError) -> Self {
+        match e {
+            XApiError::Forbidden { .. }
+            | XApiError::AccountRestricted { .. }
+            | XApiError::ScopeInsufficient { .. }
+            | XApiError::ScraperMutationBlocked { .. }
+            | XApiError::FeatureRequiresAuth { .. } => Self::Denied,
+            _ => Self::Failed,
+        }
+    }
+}
+
+impl From<&LoopError> for ActivityStatus {
+    fn from(e: &LoopError) -> Self {
+        match e {
+            LoopError::Denied(_) => Self::Denied,
+            _ => Self::Failed,
+        }
+    }
+}
+
+impl fmt::Display for ActivityStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Posted => write!(f, "posted"),
+            Self::Denied => write!(f, "denied"),
+            Self::Failed => write!(f, "failed"),
+        }
+    }
+}
+
+/// A single posting action outcome.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ActivityEvent {
+    pub kind: ActivityKind,
+    /// Tweet being replied to, or the posted tweet ID for originals.
+    /// Empty when an original tweet failed before an ID was assigned.
+    pub target: String,
+    pub status: ActivityStatus,
+}
+
+impl ActivityEvent {
+    /// Build an event from a posting result.
+    pub fn from_result<E>(kind: ActivityKind, target: &str, result: &Result<String, E>) -> Self
+    where
+        for<'e> ActivityStatus: From<&'e E>,
+    {
+        let (target, status) = match result {
+            Ok(id) if target.is_empty() => (id.clone(), ActivityStatus::Posted),
+            Ok(_) => (target.to_string(), ActivityStatus::Posted),
+            Err(e) => (target.to_string(), ActivityStatus::from(e)),
+        };
+        Self {
+            kind,
+            target,
+            status,
+        }
+    }
+}
+
+/// Receiver for activity events.
+///
+/// Implementations must not block: emission happens inline on the posting path.
+pub trait ActivitySink: Send + Sync {
+    /// Publish one activity event.
+    fn emit(&self, event: ActivityEvent);
+}
+
+/// Action log type under which [`LogActivitySink`] records events.
+pub const ACTIVITY_ACTION_TYPE: &str = "post_activity";
+
+/// [`ActivitySink`] that records events in the action log.
+///
+/// Runtimes started from the CLI run outside the server process, so they
+/// cannot reach its broadcast channel; the server relays these rows to the
+/// live activity stream instead.
+pub struct LogActivitySink {
+    pool: DbPool,
+    account_id: String,
+}
+
+impl LogActivitySink {
+    pub fn new(pool: DbPool, account_id: impl Into<String>) -> Self {
+        Self {
+            pool,
+            account_id: account_id.into(),
+        }
+    }
+}
+
+impl ActivitySink for LogActivitySink {
+    fn emit(&self, event: ActivityEvent) {
+        let pool = self.pool.clone();
+        let account_id = self.account_id.clone();
+        // The write happens off the posting path; a lost row only drops a feed entry.
+        tokio::spawn(async move {
+            let metadata = match serde_json::to_string(&event) {
+                Ok(json) => json,
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to serialize activity event");
+                    return;
+                }
+            };
+            let message = format!("{} {}", event.kind, event.status);
+            if let Err(e) = action_log::log_action_for(
+                &pool,
+                &account_id,
+                ACTIVITY_ACTION_TYPE,
+                &event.status.to_string(),
+                Some(&message),
+                Some(&metadata),
+            )
+            .await
+            {
+                tracing::warn!(error = %e, "Failed to record activity event");
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_from_typed_errors_classifies_denials() {
+        let forbidden = XApiError::Forbidden {
+            message: "tier".to_string(),
+        };
+        assert_eq!(ActivityStatus::from(&forbidden), ActivityStatus::Denied);
+        let blocked = XApiError::ScraperMutationBlocked {
+            message: "post_tweet".to_string(),
+        };
+        assert_eq!(ActivityStatus::from(&blocked), ActivityStatus::Denied);
+        let server = XApiError::ApiError {
+            status: 503,
+            message: "forbidden words in body".to_string(),
+        };
+        assert_eq!(ActivityStatus::from(&server), ActivityStatus::Failed);
+
+        let denied = LoopError::Denied("403".to_string());
+        assert_eq!(ActivityStatus::from(&denied), ActivityStatus::Denied);
+        let network = LoopError::NetworkError("connection reset".to_string());
+        assert_eq!(ActivityStatus::from(&network), ActivityStatus::Failed);
+    }
+
+    #[test]
+    fn event_from_result_uses_posted_id_for_originals() {
+        let ok: Result<String, LoopError> = Ok("999".to_string());
+        let event = ActivityEvent::from_result(ActivityKind::Tweet, "", &ok);
+        assert_eq!(event.target, "999");
+        assert_eq!(event.status, ActivityStatus::Posted);
+
+        let reply = ActivityEvent::from_result(ActivityKind::Reply, "123", &ok);
+        assert_eq!(reply.target, "123");
+
+        let err = Err(LoopError::RateLimited { retry_after: None });
+        let failed = ActivityEvent::from_result(ActivityKind::Thread, "55", &err);
+        assert_eq!(failed.status, ActivityStatus::Failed);
+    }
+
+    #[test]
+    fn kinds_and_statuses_display_snake_case() {
+        assert_eq!(ActivityKind::Thread.to_string(), "thread");
+        assert_eq!(ActivityStatus::Denied.to_string(), "denied");
+        let json = serde_json::to_string(&ActivityKind::Reply).unwrap();
+        assert_eq!(json, "\"reply\"");
+    }
+
+    // ── Posting queue integration ───────────────────────────────────
+
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use tokio::sync::oneshot;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::automation::{create_posting_queue, run_posting_queue_with_activity};
+    use crate::automation::{PostAction, PostExecutor};
+
+    struct RecordingSink(Mutex<Vec<ActivityEvent>>);
+
+    impl ActivitySink for RecordingSink {
+        fn emit(&self, event: ActivityEvent) {
+            self.0.lock().expect("lock poisoned").push(event);
+        }
+    }
+
+    struct DenyingExecutor;
+
+    #[async_trait::async_trait]
+    impl PostExecutor for DenyingExecutor {
+        async fn execute_reply(
+            &self,
+            _tweet_id: &str,
+            _content: &str,
+            _media_ids: &[String],
+        ) -> Result<String, LoopError> {
+            Err(LoopError::Denied("403 Forbidden".to_string()))
+        }
+
+        async fn execute_tweet(
+            &self,
+            _content: &str,
+            _media_ids: &[String],
+        ) -> Result<String, LoopError> {
+            Ok("posted-1".to_string())
+        }
+    }
+
+    #[tokio::test]
+    async fn posting_queue_emits_activity_events() {
+        let sink = Arc::new(RecordingSink(Mutex::new(Vec::new())));
+        let (tx, rx) = create_posting_queue();
+        let cancel = CancellationToken::new();
+
+        let handle = tokio::spawn(run_posting_queue_with_activity(
+            rx,
+            Arc::new(DenyingExecutor),
+            None,
+            Duration::ZERO,
+            Duration::ZERO,
+            None,
+            Some(sink.clone() as Arc<dyn ActivitySink>),
+            None,
+            cancel.clone(),
+        ));
+
+        let (tweet_tx, tweet_rx) = oneshot::channel();
+        tx.send(PostAction::Tweet {
+            content: "hello".to_string(),
+            media_ids: vec![],
+            framework: None,
+            result_tx: Some(tweet_tx),
+        })
+        .await
+        .expect("send");
+        let (reply_tx, reply_rx) = oneshot::channel();
+        tx.send(PostAction::Reply {
+            tweet_id: "t1".to_string(),
+            content: "hi".to_string(),
+            media_ids: vec![],
+            framework: None,
+            result_tx: Some(reply_tx),
+        })
+        .await
+        .expect("send");
+        tweet_rx.await.expect("recv").expect("tweet posted");
+        reply_rx.await.expect("recv").expect_err("reply denied");
+
+        cancel.cancel();
+        handle.await.expect("join");
+
+        let events = sink.0.lock().expect("lock poisoned").clone();
+        assert_eq!(
+            events,
+            vec![
+                ActivityEvent {
+                    kind: ActivityKind::Tweet,
+                    target: "posted-1".to_string(),
+                    status: ActivityStatus::Posted,
+                },
+                ActivityEvent {
+                    kind: ActivityKind::Reply,
+                    target: "t1".to_string(),
+                    status: ActivityStatus::Denied,
+                },
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn log_sink_records_events_in_action_log() {
+        let pool = crate::storage::init_test_db().await.expect("init db");
+        let sink = LogActivitySink::new(pool.clone(), "acct-1");
+        sink.emit(ActivityEvent {
+            kind: ActivityKind::Reply,
+            target: "t1".to_string(),
+            status: ActivityStatus::Denied,
+        });
+
+        let rows = tokio::time::timeout(Duration::from_secs(1), async {
+            loop {
+                let rows = action_log::get_actions_after_id(&pool, 0, ACTIVITY_ACTION_TYPE)
+                    .await
+                    .expect("query");
+                if !rows.is_empty() {
+                    break rows;
+                }
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("row within timeout");
+
+        assert_eq!(rows[0].account_id, "acct-1");
+        assert_eq!(rows[0].entry.status, "denied");
+        let logged: ActivityEvent =
+            serde_json::from_str(rows[0].entry.metadata.as_deref().expect("metadata"))
+                .expect("event json");
+        assert_eq!(logged.target, "t1");
+    }
+}
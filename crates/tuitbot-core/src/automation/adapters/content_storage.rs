@@ -0,0 +1,344 @@
+//! Content storage adapter: tweet and thread persistence for the content loops.
+
+use chrono::{DateTime, Utc};
+use tokio::sync::mpsc;
+
+use super::super::loop_helpers::{ContentLoopError, ContentStorage};
+use super::super::posting_queue::PostAction;
+use super::helpers::{parse_datetime, sqlx_to_content_error};
+use crate::content::{frameworks, FrameworkPick};
+use crate::storage::{self, DbPool};
+
+/// Adapts `DbPool` + posting queue to the `ContentStorage` port trait.
+pub struct ContentStorageAdapter {
+    pool: DbPool,
+    /// Account whose rate-limit counters and action log this adapter updates.
+    account_id: String,
+    post_tx: mpsc::Sender<PostAction>,
+}
+
+impl ContentStorageAdapter {
+    /// Create an adapter for the default account.
+    pub fn new(pool: DbPool, post_tx: mpsc::Sender<PostAction>) -> Self {
+        Self::new_for(pool, storage::accounts::DEFAULT_ACCOUNT_ID, post_tx)
+    }
+
+    /// Create an adapter whose counters and action log are scoped to `account_id`.
+    pub fn new_for(
+        pool: DbPool,
+        account_id: impl Into<String>,
+        post_tx: mpsc::Sender<PostAction>,
+    ) -> Self {
+        Self {
+            pool,
+            account_id: account_id.into(),
+            post_tx,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ContentStorage for ContentStorageAdapter {
+    async fn last_tweet_time(&self) -> Result<Option<DateTime<Utc>>, ContentLoopError> {
+        let time_str = storage::threads::get_last_original_tweet_time(&self.pool)
+            .await
+            .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+        Ok(time_str.and_then(|s| parse_datetime(&s)))
+    }
+
+    async fn todays_tweet_times(&self) -> Result<Vec<DateTime<Utc>>, ContentLoopError> {
+        let time_strs = storage::threads::get_todays_tweet_times(&self.pool)
+            .await
+            .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+        Ok(time_strs.iter().filter_map(|s| parse_datetime(s)).collect())
+    }
+
+    async fn last_thread_time(&self) -> Result<Option<DateTime<Utc>>, ContentLoopError> {
+        let time_str = storage::threads::get_last_thread_time(&self.pool)
+            .await
+            .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+        Ok(time_str.and_then(|s| parse_datetime(&s)))
+    }
+
+    async fn next_topic(
+        &self,
+        topics: &[String],
+        min_gap: chrono::Duration,
+    ) -> Result<Option<String>, ContentLoopError> {
+        crate::content::topic_selector::next_topic(&self.pool, topics, min_gap)
+            .await
+            .map_err(|e| ContentLoopError::StorageError(e.to_string()))
+    }
+
+    async fn post_tweet(&self, topic: &str, content: &str) -> Result<(), ContentLoopError> {
+        self.post_tweet_with_framework(topic, content, None).await
+    }
+
+    async fn post_tweet_with_framework(
+        &self,
+        topic: &str,
+        content: &str,
+        framework: Option<&FrameworkPick>,
+    ) -> Result<(), ContentLoopError> {
+        // Send to the posting queue and await result.
+        let (result_tx, result_rx) = tokio::sync::oneshot::channel();
+        self.post_tx
+            .send(PostAction::Tweet {
+                content: content.to_string(),
+                media_ids: vec![],
+                framework: framework.cloned(),
+                result_tx: Some(result_tx),
+            })
+            .await
+            .map_err(|e| ContentLoopError::PostFailed(e.to_string()))?;
+
+        let tweet_id = result_rx
+            .await
+            .map_err(|e| ContentLoopError::PostFailed(e.to_string()))?
+            .map_err(ContentLoopError::PostFailed)?;
+
+        if let Some(framework) = framework {
+            frameworks::link_posted_for(&self.pool, &self.account_id, &tweet_id, framework).await;
+        }
+
+        // Record in the database.
+        let original = storage::threads::OriginalTweet {
+            id: 0,
+            tweet_id: Some(tweet_id),
+            content: content.to_string(),
+            topic: Some(topic.to_string()),
+            llm_provider: None,
+            created_at: Utc::now().to_rfc3339(),
+            status: "sent".to_string(),
+            error_message: None,
+        };
+        storage::threads::insert_original_tweet(&self.pool, &original)
+            .await
+            .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+
+        // Increment rate limit.
+        storage::rate_limits::increment_rate_limit_for(&self.pool, &self.account_id, "tweet")
+            .await
+            .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn create_thread(
+        &self,
+        topic: &str,
+        tweet_count: usize,
+    ) -> Result<String, ContentLoopError> {
+        let thread = storage::threads::Thread {
+            id: 0,
+            topic: topic.to_string(),
+            tweet_count: tweet_count as i64,
+            root_tweet_id: None,
+            created_at: Utc::now().to_rfc3339(),
+            status: "pending".to_string(),
+        };
+        let id = storage::threads::insert_thread(&self.pool, &thread)
+            .await
+            .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+        Ok(id.to_string())
+    }
+
+    async fn update_thread_status(
+        &self,
+        thread_id: &str,
+        status: &str,
+        tweet_count: usize,
+        root_tweet_id: Option<&str>,
+    ) -> Result<(), ContentLoopError> {
+        let id: i64 = thread_id
+            .parse()
+            .map_err(|_| ContentLoopError::StorageError("invalid thread_id".to_string()))?;
+
+        sqlx::query(
+            "UPDATE threads SET status = ?1, tweet_count = ?2, root_tweet_id = ?3 WHERE id = ?4",
+        )
+        .bind(status)
+        .bind(tweet_count as i64)
+        .bind(root_tweet_id)
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(sqlx_to_content_error)?;
+
+        // If the thread was fully posted, increment the rate limit.
+        if status == "sent" {
+            storage::rate_limits::increment_rate_limit_for(&self.pool, &self.account_id, "thread")
+                .await
+                .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    async fn store_thread_tweet(
+        &self,
+        thread_id: &str,
+        position: usize,
+        tweet_id: &str,
+        content: &str,
+    ) -> Result<(), ContentLoopError> {
+        let tid: i64 = thread_id
+            .parse()
+            .map_err(|_| ContentLoopError::StorageError("invalid thread_id".to_string()))?;
+
+        sqlx::query(
+            "INSERT INTO thread_tweets (thread_id, position, tweet_id, content, created_at)
+             VALUES (?1, ?2, ?3, ?4, datetime('now'))",
+        )
+        .bind(tid)
+        .bind(position as i64)
+        .bind(tweet_id)
+        .bind(content)
+        .execute(&self.pool)
+        .await
+        .map_err(sqlx_to_content_error)?;
+        Ok(())
+    }
+
+    async fn link_thread_framework(&self, root_tweet_id: &str, framework: &FrameworkPick) {
+        frameworks::link_posted_for(&self.pool, &self.account_id, root_tweet_id, framework).await;
+    }
+
+    async fn log_action(
+        &self,
+        action_type: &str,
+        status: &str,
+        message: &str,
+    ) -> Result<(), ContentLoopError> {
+        storage::action_log::log_action_for(
+            &self.pool,
+            &self.account_id,
+            action_type,
+            status,
+            Some(message),
+            None,
+        )
+        .await
+        .map_err(|e| ContentLoopError::StorageError(e.to_string()))
+    }
+
+    async fn next_scheduled_item(&self) -> Result<Option<(i64, String, String)>, ContentLoopError> {
+        let items = storage::scheduled_content::get_due_items(&self.pool)
+            .await
+            .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+
+        Ok(items
+            .into_iter()
+            .next()
+            .map(|item| (item.id, item.content_type, item.content)))
+    }
+
+    async fn mark_scheduled_posted(
+        &self,
+        id: i64,
+        tweet_id: Option<&str>,
+    ) -> Result<(), ContentLoopError> {
+        storage::scheduled_content::update_status(&self.pool, id, "posted", tweet_id)
+            .await
+            .map_err(|e| ContentLoopError::StorageError(e.to_string()))
+    }
+
+    async fn mark_failed_permanent(
+        &self,
+        thread_id: &str,
+        error: &str,
+    ) -> Result<(), ContentLoopError> {
+        let id: i64 = thread_id
+            .parse()
+            .map_err(|_| ContentLoopError::StorageError("invalid thread_id".to_string()))?;
+
+        // Update thread status to failed
+        sqlx::query(
+            "UPDATE threads SET status = ?1, failure_kind = ?2, last_error = ?3, failed_at = datetime('now') WHERE id = ?4",
+        )
+        .bind("failed")
+        .bind("permanent")
+        .bind(error)
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+
+        // Fetch thread details for approval queue entry
+        // Concatenate all thread tweets into a single content string for the approval queue
+        let row: (String, u32) =
+            sqlx::query_as("SELECT topic, retry_count FROM threads WHERE id = ?1")
+                .bind(id)
+                .fetch_one(&self.pool)
+                .await
+                .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+
+        let (topic, retry_count) = row;
+
+        // Fetch all tweets in the thread and concatenate them
+        let tweets: Vec<(String,)> = sqlx::query_as(
+            "SELECT content FROM thread_tweets WHERE thread_id = ?1 ORDER BY position",
+        )
+        .bind(id)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+
+        let content = if tweets.is_empty() {
+            format!("Failed thread id={}", id)
+        } else {
+            tweets
+                .iter()
+                .map(|t| t.0.as_str())
+                .collect::<Vec<_>>()
+                .join("\n---\n")
+        };
+
+        // Build metadata JSON for the approval queue entry
+        let metadata = format!(
+            "Failed thread id={}, retries={}, error: {}",
+            id, retry_count, error
+        );
+
+        // Insert into approval_queue with status="pending" for human review
+        sqlx::query(
+            "INSERT INTO approval_queue (action_type, generated_content, topic, status, reason) VALUES (?1, ?2, ?3, ?4, ?5)",
+        )
+        .bind("failed_post_recovery")
+        .bind(content)
+        .bind(topic)
+        .bind("pending")
+        .bind(metadata)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    async fn increment_retry(&self, thread_id: &str, error: &str) -> Result<u32, ContentLoopError> {
+        let id: i64 = thread_id
+            .parse()
+            .map_err(|_| ContentLoopError::StorageError("invalid thread_id".to_string()))?;
+
+        // Increment retry_count and update failure metadata
+        sqlx::query(
+            "UPDATE threads SET retry_count = retry_count + 1, failure_kind = ?1, last_error = ?2, failed_at = datetime('now') WHERE id = ?3",
+        )
+        .bind("transient")
+        .bind(error)
+        .bind(id)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+
+        // Fetch updated retry_count
+        let row: (i64,) = sqlx::query_as("SELECT retry_count FROM threads WHERE id = ?1")
+            .bind(id)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| ContentLoopError::StorageError(e.to_string()))?;
+
+        Ok(row.0 as u32)
+    }
+}
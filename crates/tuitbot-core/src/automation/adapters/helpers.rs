@@ -27,6 +27,7 @@ pub(super) fn search_response_to_loop_tweets(response: SearchResponse) -> Vec<Lo
         .into_iter()
         .map(|tweet| {
             let user = users.get(tweet.author_id.as_str());
+            let in_reply_to_id = tweet.replied_to_id().map(String::from);
             LoopTweet {
                 id: tweet.id,
                 text: tweet.text,
@@ -37,6 +38,9 @@ pub(super) fn search_response_to_loop_tweets(response: SearchResponse) -> Vec<Lo
                 likes: tweet.public_metrics.like_count,
                 retweets: tweet.public_metrics.retweet_count,
                 replies: tweet.public_metrics.reply_count,
+                in_reply_to_id,
+                conversation_id: tweet.conversation_id,
+                author_protected: user.and_then(|u| u.protected),
             }
         })
         .collect()
@@ -49,9 +53,13 @@ pub(super) fn toolkit_to_loop_error(e: ToolkitError) -> LoopError {
             XApiError::RateLimited { retry_after } => LoopError::RateLimited { retry_after },
             XApiError::AuthExpired => LoopError::AuthExpired,
             XApiError::Network { source } => LoopError::NetworkError(source.to_string()),
-            XApiError::ScraperMutationBlocked { .. }
-            | XApiError::ScraperTransportUnavailable { .. }
-            | XApiError::FeatureRequiresAuth { .. } => LoopError::Other(xe.to_string()),
+            XApiError::Timeout { .. } => LoopError::NetworkError(xe.to_string()),
+            XApiError::Forbidden { .. }
+            | XApiError::AccountRestricted { .. }
+            | XApiError::ScopeInsufficient { .. }
+            | XApiError::ScraperMutationBlocked { .. }
+            | XApiError::FeatureRequiresAuth { .. } => LoopError::Denied(xe.to_string()),
+            XApiError::ScraperTransportUnavailable { .. } => LoopError::Other(xe.to_string()),
             other => LoopError::Other(other.to_string()),
         },
         other => LoopError::Other(other.to_string()),
@@ -69,6 +77,7 @@ pub(super) fn toolkit_to_content_error(e: ToolkitError) -> ContentLoopError {
                     .unwrap_or_default()
             )),
             XApiError::Network { source } => ContentLoopError::NetworkError(source.to_string()),
+            XApiError::Timeout { .. } => ContentLoopError::NetworkError(xe.to_string()),
             other => ContentLoopError::PostFailed(other.to_string()),
         },
         other => ContentLoopError::PostFailed(other.to_string()),
@@ -197,6 +206,7 @@ mod tests {
                     ..Default::default()
                 },
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: Some(Includes {
                 users: vec![User {
@@ -212,6 +222,7 @@ mod tests {
                         following_count: 500,
                         tweet_count: 200,
                     },
+                    protected: None,
                 }],
             }),
             meta: SearchMeta {
@@ -229,6 +240,31 @@ mod tests {
         assert_eq!(tweets[0].retweets, 2);
     }
 
+    #[test]
+    fn search_response_to_loop_tweets_reads_protected_flag() {
+        let response: SearchResponse = serde_json::from_value(serde_json::json!({
+            "data": [
+                {"id": "t1", "text": "a", "author_id": "locked"},
+                {"id": "t2", "text": "b", "author_id": "open"},
+                {"id": "t3", "text": "c", "author_id": "unknown"},
+                {"id": "t4", "text": "d", "author_id": "missing"}
+            ],
+            "includes": {"users": [
+                {"id": "locked", "username": "locked", "name": "L", "protected": true},
+                {"id": "open", "username": "open", "name": "O", "protected": false},
+                {"id": "unknown", "username": "unknown", "name": "U"}
+            ]},
+            "meta": {"result_count": 4}
+        }))
+        .expect("valid payload");
+
+        let protected: Vec<Option<bool>> = search_response_to_loop_tweets(response)
+            .iter()
+            .map(|t| t.author_protected)
+            .collect();
+        assert_eq!(protected, vec![Some(true), Some(false), None, None]);
+    }
+
     #[test]
     fn search_response_to_loop_tweets_without_includes() {
         use crate::x_api::types::*;
@@ -241,6 +277,7 @@ mod tests {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -277,6 +314,14 @@ mod tests {
         assert!(matches!(err, LoopError::AuthExpired));
     }
 
+    #[test]
+    fn toolkit_to_loop_error_forbidden_is_denied() {
+        let err = toolkit_to_loop_error(ToolkitError::XApi(crate::error::XApiError::Forbidden {
+            message: "tier".into(),
+        }));
+        assert!(matches!(err, LoopError::Denied(_)));
+    }
+
     #[test]
     fn toolkit_to_loop_error_other() {
         let err = toolkit_to_loop_error(ToolkitError::XApi(crate::error::XApiError::ApiError {
@@ -1,24 +1,44 @@
 //! LLM adapter implementations.
 
-use std::sync::Arc;
+use std::future::Future;
+use std::sync::{Arc, Mutex};
 
 use super::super::loop_helpers::{
-    ContentLoopError, LoopError, ReplyGenerator, ReplyOutput, TweetGenerator,
+    ContentLoopError, LoopError, ReplyGenerator, ReplyOutput, TweetGenerator, TweetOutput,
 };
-use super::super::thread_loop::ThreadGenerator;
+use super::super::thread_loop::{ThreadGenerator, ThreadOutput};
 use super::helpers::{llm_to_content_error, llm_to_loop_error};
-use crate::content::ContentGenerator;
+use crate::content::{
+    rotation, ContentGenerator, ConversationTweet, FrameworkPick, GenerationOutput, ReplyArchetype,
+    ThreadStructure, TweetFormat,
+};
+use crate::context::winning_dna;
+use crate::error::StorageError;
+use crate::llm::SessionUsage;
 use crate::storage::DbPool;
 
-/// Record LLM usage to the database (fire-and-forget).
+/// Record LLM usage to the database (fire-and-forget) and, when present,
+/// to the session accumulator.
 pub(super) async fn record_llm_usage(
     pool: &DbPool,
+    session_usage: Option<&SessionUsage>,
     generation_type: &str,
     provider: &str,
     model: &str,
     input_tokens: u32,
     output_tokens: u32,
 ) {
+    if let Some(session_usage) = session_usage {
+        session_usage.record(
+            provider,
+            model,
+            &crate::llm::TokenUsage {
+                input_tokens,
+                output_tokens,
+            },
+        );
+    }
+
     let pricing = crate::llm::pricing::lookup(provider, model);
     let cost = pricing.compute_cost(input_tokens, output_tokens);
     if let Err(e) = crate::storage::llm_usage::insert_llm_usage(
@@ -36,101 +56,106 @@ pub(super) async fn record_llm_usage(
     }
 }
 
-/// Adapts `ContentGenerator` to the `ReplyGenerator` port trait.
-pub struct LlmReplyAdapter {
-    generator: Arc<ContentGenerator>,
-    pool: DbPool,
-}
-
-impl LlmReplyAdapter {
-    pub fn new(generator: Arc<ContentGenerator>, pool: DbPool) -> Self {
-        Self { generator, pool }
+/// Await the rotation's pick when the adapter rotates (`content.auto_rotate`),
+/// otherwise or on a storage error fall back to the weighted `random` pick.
+async fn rotated_or<T>(
+    rotated: Option<impl Future<Output = Result<T, StorageError>>>,
+    random: impl FnOnce() -> T,
+) -> T {
+    if let Some(pick) = rotated {
+        match pick.await {
+            Ok(pick) => return pick,
+            Err(e) => tracing::warn!(error = %e, "Framework rotation failed, picking at random"),
+        }
     }
+    random()
 }
 
-#[async_trait::async_trait]
-impl ReplyGenerator for LlmReplyAdapter {
-    async fn generate_reply(
-        &self,
-        tweet_text: &str,
-        author: &str,
-        mention_product: bool,
-    ) -> Result<String, LoopError> {
-        let output = self
-            .generator
-            .generate_reply(tweet_text, author, mention_product)
-            .await
-            .map_err(llm_to_loop_error)?;
-        record_llm_usage(
-            &self.pool,
-            "reply",
-            &output.provider,
-            &output.model,
-            output.usage.input_tokens,
-            output.usage.output_tokens,
-        )
-        .await;
-        Ok(output.text)
-    }
+pub(super) async fn next_archetype(
+    generator: &ContentGenerator,
+    pool: &DbPool,
+    rotation: Option<&str>,
+) -> ReplyArchetype {
+    let weights = generator.framework_weights();
+    rotated_or(
+        rotation.map(|account_id| rotation::next_archetype_for(pool, account_id, weights)),
+        || generator.pick_archetype(),
+    )
+    .await
 }
 
-/// Vault-aware reply adapter that injects pre-built RAG context into replies.
-///
-/// The RAG prompt is built once at construction time (by the server/CLI wiring
-/// layer) and reused for every reply, avoiding per-tweet DB queries.
-pub struct VaultAwareLlmReplyAdapter {
+/// Adapts `ContentGenerator` to the `ReplyGenerator` port trait.
+pub struct LlmReplyAdapter {
     generator: Arc<ContentGenerator>,
     pool: DbPool,
-    /// Pre-built RAG prompt block to inject into every reply.
-    rag_prompt: Option<String>,
-    /// Pre-built vault citations corresponding to the RAG prompt.
-    vault_citations: Vec<crate::context::retrieval::VaultCitation>,
+    session_usage: Option<Arc<SessionUsage>>,
+    /// Account whose rotation history picks archetypes (`content.auto_rotate`).
+    rotation: Option<String>,
 }
 
-impl VaultAwareLlmReplyAdapter {
-    pub fn new(
-        generator: Arc<ContentGenerator>,
-        pool: DbPool,
-        rag_prompt: Option<String>,
-        vault_citations: Vec<crate::context::retrieval::VaultCitation>,
-    ) -> Self {
+impl LlmReplyAdapter {
+    pub fn new(generator: Arc<ContentGenerator>, pool: DbPool) -> Self {
         Self {
             generator,
             pool,
-            rag_prompt,
-            vault_citations,
+            session_usage: None,
+            rotation: None,
         }
     }
-}
 
-#[async_trait::async_trait]
-impl ReplyGenerator for VaultAwareLlmReplyAdapter {
-    async fn generate_reply(
+    /// Also accumulate usage into a session-level total.
+    pub fn with_session_usage(mut self, session_usage: Arc<SessionUsage>) -> Self {
+        self.session_usage = Some(session_usage);
+        self
+    }
+
+    /// Cycle archetypes through `account_id`'s rotation (`content.auto_rotate`)
+    /// instead of drawing them at random.
+    pub fn with_rotation(mut self, account_id: impl Into<String>) -> Self {
+        self.rotation = Some(account_id.into());
+        self
+    }
+
+    async fn generate(
         &self,
         tweet_text: &str,
         author: &str,
         mention_product: bool,
-    ) -> Result<String, LoopError> {
+        conversation: &[ConversationTweet],
+    ) -> Result<ReplyOutput, LoopError> {
+        let archetype = next_archetype(&self.generator, &self.pool, self.rotation.as_deref()).await;
         let output = self
             .generator
             .generate_reply_with_context(
                 tweet_text,
                 author,
                 mention_product,
+                Some(archetype),
                 None,
-                self.rag_prompt.as_deref(),
+                conversation,
             )
             .await
             .map_err(llm_to_loop_error)?;
-        record_llm_usage(
-            &self.pool,
-            "reply",
-            &output.provider,
-            &output.model,
-            output.usage.input_tokens,
-            output.usage.output_tokens,
-        )
-        .await;
+        record_reply_usage(&self.pool, self.session_usage.as_deref(), &output).await;
+        Ok(ReplyOutput {
+            text: output.text,
+            vault_citations: vec![],
+            framework: Some(FrameworkPick::archetype(archetype)),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ReplyGenerator for LlmReplyAdapter {
+    async fn generate_reply(
+        &self,
+        tweet_text: &str,
+        author: &str,
+        mention_product: bool,
+    ) -> Result<String, LoopError> {
+        let output = self
+            .generate(tweet_text, author, mention_product, &[])
+            .await?;
         Ok(output.text)
     }
 
@@ -140,38 +165,176 @@ impl ReplyGenerator for VaultAwareLlmReplyAdapter {
         author: &str,
         mention_product: bool,
     ) -> Result<ReplyOutput, LoopError> {
-        let text = self
-            .generate_reply(tweet_text, author, mention_product)
-            .await?;
-        Ok(ReplyOutput {
-            text,
-            vault_citations: self.vault_citations.clone(),
-        })
+        self.generate(tweet_text, author, mention_product, &[])
+            .await
+    }
+
+    async fn generate_reply_in_conversation(
+        &self,
+        tweet_text: &str,
+        author: &str,
+        mention_product: bool,
+        conversation: &[ConversationTweet],
+    ) -> Result<ReplyOutput, LoopError> {
+        self.generate(tweet_text, author, mention_product, conversation)
+            .await
     }
 }
 
+pub(super) async fn record_reply_usage(
+    pool: &DbPool,
+    session_usage: Option<&SessionUsage>,
+    output: &GenerationOutput,
+) {
+    record_llm_usage(
+        pool,
+        session_usage,
+        "reply",
+        &output.provider,
+        &output.model,
+        output.usage.input_tokens,
+        output.usage.output_tokens,
+    )
+    .await;
+}
+
+/// How many recently used formats the tweet adapter skips, so consecutive
+/// tweets vary their structure.
+const RECENT_FORMATS: usize = 3;
+
 /// Adapts `ContentGenerator` to the `TweetGenerator` port trait.
 pub struct LlmTweetAdapter {
     generator: Arc<ContentGenerator>,
     pool: DbPool,
+    session_usage: Option<Arc<SessionUsage>>,
+    /// Retrieve per-topic RAG context when set.
+    rag: Option<TweetRag>,
+    /// Formats of the last few tweets, most recent last.
+    recent_formats: Mutex<Vec<TweetFormat>>,
+    /// Account whose rotation history picks formats (`content.auto_rotate`).
+    rotation: Option<String>,
+}
+
+/// Per-topic RAG retrieval settings for [`LlmTweetAdapter`].
+struct TweetRag {
+    account_id: String,
+    /// Fail the tweet instead of generating without context.
+    required: bool,
 }
 
 impl LlmTweetAdapter {
     pub fn new(generator: Arc<ContentGenerator>, pool: DbPool) -> Self {
-        Self { generator, pool }
+        Self {
+            generator,
+            pool,
+            session_usage: None,
+            rag: None,
+            recent_formats: Mutex::new(Vec::new()),
+            rotation: None,
+        }
+    }
+
+    /// Also accumulate usage into a session-level total.
+    pub fn with_session_usage(mut self, session_usage: Arc<SessionUsage>) -> Self {
+        self.session_usage = Some(session_usage);
+        self
+    }
+
+    /// Retrieve RAG context for each topic from `account_id`'s content.
+    ///
+    /// When no context is available, the tweet is generated without it
+    /// unless `required` (`content.rag_required`) is set.
+    pub fn with_rag(mut self, account_id: impl Into<String>, required: bool) -> Self {
+        self.rag = Some(TweetRag {
+            account_id: account_id.into(),
+            required,
+        });
+        self
+    }
+
+    /// Cycle formats through `account_id`'s rotation (`content.auto_rotate`)
+    /// instead of drawing them at random.
+    pub fn with_rotation(mut self, account_id: impl Into<String>) -> Self {
+        self.rotation = Some(account_id.into());
+        self
+    }
+
+    /// Next tweet format: from the rotation when set, otherwise a weighted
+    /// random pick that skips the most recent ones.
+    async fn next_format(&self) -> TweetFormat {
+        let weights = self.generator.framework_weights();
+        let rotated = self
+            .rotation
+            .as_deref()
+            .map(|account_id| rotation::next_format_for(&self.pool, account_id, weights));
+        rotated_or(rotated, || self.pick_recent_format()).await
+    }
+
+    fn pick_recent_format(&self) -> TweetFormat {
+        let mut recent = self
+            .recent_formats
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        let format = self.generator.pick_format(&recent);
+        recent.push(format);
+        if recent.len() > RECENT_FORMATS {
+            recent.remove(0);
+        }
+        format
+    }
+
+    /// RAG prompt block for `topic`, or `None` when generation should
+    /// proceed without context.
+    async fn rag_context(&self, topic: &str) -> Result<Option<String>, ContentLoopError> {
+        let Some(rag) = &self.rag else {
+            return Ok(None);
+        };
+
+        let reason = match winning_dna::build_draft_context(
+            &self.pool,
+            &rag.account_id,
+            &[topic.to_string()],
+            winning_dna::MAX_ANCESTORS,
+            winning_dna::RECENCY_HALF_LIFE_DAYS,
+        )
+        .await
+        {
+            Ok(ctx) if !ctx.prompt_block.is_empty() => return Ok(Some(ctx.prompt_block)),
+            Ok(_) => "content sources returned no context".to_string(),
+            Err(e) => format!("context retrieval failed: {e}"),
+        };
+
+        if rag.required {
+            return Err(ContentLoopError::Other(format!(
+                "RAG context required but unavailable for topic '{topic}': {reason}"
+            )));
+        }
+        tracing::warn!(topic = %topic, reason = %reason, "No RAG context, generating without it");
+        Ok(None)
     }
 }
 
 #[async_trait::async_trait]
 impl TweetGenerator for LlmTweetAdapter {
     async fn generate_tweet(&self, topic: &str) -> Result<String, ContentLoopError> {
+        let output = self.generate_tweet_with_framework(topic).await?;
+        Ok(output.text)
+    }
+
+    async fn generate_tweet_with_framework(
+        &self,
+        topic: &str,
+    ) -> Result<TweetOutput, ContentLoopError> {
+        let rag_context = self.rag_context(topic).await?;
+        let format = self.next_format().await;
         let output = self
             .generator
-            .generate_tweet(topic)
+            .generate_tweet_with_context(topic, Some(format), rag_context.as_deref())
             .await
             .map_err(llm_to_content_error)?;
         record_llm_usage(
             &self.pool,
+            self.session_usage.as_deref(),
             "tweet",
             &output.provider,
             &output.model,
@@ -179,7 +342,10 @@ impl TweetGenerator for LlmTweetAdapter {
             output.usage.output_tokens,
         )
         .await;
-        Ok(output.text)
+        Ok(TweetOutput {
+            text: output.text,
+            framework: Some(FrameworkPick::format(format)),
+        })
     }
 }
 
@@ -187,11 +353,43 @@ impl TweetGenerator for LlmTweetAdapter {
 pub struct LlmThreadAdapter {
     generator: Arc<ContentGenerator>,
     pool: DbPool,
+    session_usage: Option<Arc<SessionUsage>>,
+    /// Account whose rotation history picks structures (`content.auto_rotate`).
+    rotation: Option<String>,
 }
 
 impl LlmThreadAdapter {
     pub fn new(generator: Arc<ContentGenerator>, pool: DbPool) -> Self {
-        Self { generator, pool }
+        Self {
+            generator,
+            pool,
+            session_usage: None,
+            rotation: None,
+        }
+    }
+
+    /// Also accumulate usage into a session-level total.
+    pub fn with_session_usage(mut self, session_usage: Arc<SessionUsage>) -> Self {
+        self.session_usage = Some(session_usage);
+        self
+    }
+
+    /// Cycle structures through `account_id`'s rotation (`content.auto_rotate`)
+    /// instead of drawing them at random.
+    pub fn with_rotation(mut self, account_id: impl Into<String>) -> Self {
+        self.rotation = Some(account_id.into());
+        self
+    }
+
+    /// Next thread structure: from the rotation when set, otherwise a
+    /// weighted random pick.
+    async fn next_structure(&self) -> ThreadStructure {
+        let weights = self.generator.framework_weights();
+        let rotated = self
+            .rotation
+            .as_deref()
+            .map(|account_id| rotation::next_structure_for(&self.pool, account_id, weights));
+        rotated_or(rotated, || self.generator.pick_structure()).await
     }
 }
 
@@ -200,15 +398,26 @@ impl ThreadGenerator for LlmThreadAdapter {
     async fn generate_thread(
         &self,
         topic: &str,
-        _count: Option<usize>,
+        count: Option<usize>,
     ) -> Result<Vec<String>, ContentLoopError> {
+        let output = self.generate_thread_with_framework(topic, count).await?;
+        Ok(output.tweets)
+    }
+
+    async fn generate_thread_with_framework(
+        &self,
+        topic: &str,
+        _count: Option<usize>,
+    ) -> Result<ThreadOutput, ContentLoopError> {
+        let structure = self.next_structure().await;
         let output = self
             .generator
-            .generate_thread(topic)
+            .generate_thread_with_structure(topic, Some(structure))
             .await
             .map_err(llm_to_content_error)?;
         record_llm_usage(
             &self.pool,
+            self.session_usage.as_deref(),
             "thread",
             &output.provider,
             &output.model,
@@ -216,6 +425,9 @@ impl ThreadGenerator for LlmThreadAdapter {
             output.usage.output_tokens,
         )
         .await;
-        Ok(output.tweets)
+        Ok(ThreadOutput {
+            tweets: output.tweets,
+            framework: Some(FrameworkPick::structure(structure)),
+        })
     }
 }
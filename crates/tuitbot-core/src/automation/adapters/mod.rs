@@ -5,6 +5,7 @@
 //! and implements the port traits defined in [`loop_helpers`], [`analytics_loop`],
 //! [`target_loop`], [`thread_loop`], [`posting_queue`], and [`status_reporter`].
 
+mod content_storage;
 mod helpers;
 mod llm;
 mod queue;
@@ -12,19 +13,26 @@ mod safety;
 mod scoring;
 mod status;
 mod storage;
+mod vault_reply;
 mod x_api;
 
 #[cfg(test)]
 mod tests;
 #[cfg(test)]
+mod tests_llm;
+#[cfg(test)]
 mod tests_safety;
 #[cfg(test)]
+mod tests_scoring;
+#[cfg(test)]
 mod tests_storage;
 
+pub use content_storage::*;
 pub use llm::*;
 pub use queue::*;
 pub use safety::*;
 pub use scoring::*;
 pub use status::*;
 pub use storage::*;
+pub use vault_reply::*;
 pub use x_api::*;
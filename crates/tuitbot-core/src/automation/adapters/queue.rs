@@ -4,38 +4,64 @@ use tokio::sync::mpsc;
 
 use super::super::loop_helpers::{LoopError, PostSender};
 use super::super::posting_queue::{ApprovalQueue, PostAction};
+use crate::content::{frameworks, FrameworkPick};
 use crate::storage::{self, DbPool};
 
 /// Adapts `mpsc::Sender<PostAction>` to the `PostSender` port trait.
 pub struct PostSenderAdapter {
     tx: mpsc::Sender<PostAction>,
+    /// Where posted replies are linked to the archetype they were generated with.
+    framework_pool: Option<DbPool>,
 }
 
 impl PostSenderAdapter {
     pub fn new(tx: mpsc::Sender<PostAction>) -> Self {
-        Self { tx }
+        Self {
+            tx,
+            framework_pool: None,
+        }
+    }
+
+    /// Link each posted reply to the framework it was generated with.
+    pub fn with_framework_links(mut self, pool: DbPool) -> Self {
+        self.framework_pool = Some(pool);
+        self
     }
 }
 
 #[async_trait::async_trait]
 impl PostSender for PostSenderAdapter {
     async fn send_reply(&self, tweet_id: &str, content: &str) -> Result<(), LoopError> {
+        self.send_reply_with_framework(tweet_id, content, None)
+            .await
+    }
+
+    async fn send_reply_with_framework(
+        &self,
+        tweet_id: &str,
+        content: &str,
+        framework: Option<&FrameworkPick>,
+    ) -> Result<(), LoopError> {
         let (result_tx, result_rx) = tokio::sync::oneshot::channel();
         self.tx
             .send(PostAction::Reply {
                 tweet_id: tweet_id.to_string(),
                 content: content.to_string(),
                 media_ids: vec![],
+                framework: framework.cloned(),
                 result_tx: Some(result_tx),
             })
             .await
             .map_err(|e| LoopError::Other(format!("posting queue send failed: {e}")))?;
 
-        result_rx
+        let reply_id = result_rx
             .await
             .map_err(|e| LoopError::Other(format!("posting queue result recv failed: {e}")))?
             .map_err(|e| LoopError::Other(format!("post action failed: {e}")))?;
 
+        if let (Some(pool), Some(framework)) = (&self.framework_pool, framework) {
+            frameworks::link_posted(pool, &reply_id, framework).await;
+        }
         Ok(())
     }
 }
@@ -51,6 +77,13 @@ impl ApprovalQueueAdapter {
     }
 }
 
+/// Value for the queue's `archetype` column: the framework name, or empty
+/// when the item was generated without one. The approval poster links the
+/// item to it once it is posted.
+fn framework_column(framework: Option<&FrameworkPick>) -> &str {
+    framework.map_or("", |pick| pick.framework.as_str())
+}
+
 #[async_trait::async_trait]
 impl ApprovalQueue for ApprovalQueueAdapter {
     async fn queue_reply(
@@ -58,6 +91,22 @@ impl ApprovalQueue for ApprovalQueueAdapter {
         tweet_id: &str,
         content: &str,
         media_paths: &[String],
+    ) -> Result<i64, String> {
+        self.queue_reply_with_framework(tweet_id, content, media_paths, None)
+            .await
+    }
+
+    async fn queue_tweet(&self, content: &str, media_paths: &[String]) -> Result<i64, String> {
+        self.queue_tweet_with_framework(content, media_paths, None)
+            .await
+    }
+
+    async fn queue_reply_with_framework(
+        &self,
+        tweet_id: &str,
+        content: &str,
+        media_paths: &[String],
+        framework: Option<&FrameworkPick>,
     ) -> Result<i64, String> {
         let media_json = serde_json::to_string(media_paths).unwrap_or_else(|_| "[]".to_string());
         storage::approval_queue::enqueue(
@@ -66,8 +115,8 @@ impl ApprovalQueue for ApprovalQueueAdapter {
             tweet_id,
             "", // target_author not available here
             content,
-            "",  // topic
-            "",  // archetype
+            "", // topic
+            framework_column(framework),
             0.0, // score
             &media_json,
         )
@@ -75,7 +124,12 @@ impl ApprovalQueue for ApprovalQueueAdapter {
         .map_err(|e| e.to_string())
     }
 
-    async fn queue_tweet(&self, content: &str, media_paths: &[String]) -> Result<i64, String> {
+    async fn queue_tweet_with_framework(
+        &self,
+        content: &str,
+        media_paths: &[String],
+        framework: Option<&FrameworkPick>,
+    ) -> Result<i64, String> {
         let media_json = serde_json::to_string(media_paths).unwrap_or_else(|_| "[]".to_string());
         storage::approval_queue::enqueue(
             &self.pool,
@@ -83,8 +137,8 @@ impl ApprovalQueue for ApprovalQueueAdapter {
             "", // no target tweet
             "", // no target author
             content,
-            "",  // topic
-            "",  // archetype
+            "", // topic
+            framework_column(framework),
             0.0, // score
             &media_json,
         )
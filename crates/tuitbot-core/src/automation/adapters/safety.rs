@@ -6,18 +6,51 @@ use chrono::Utc;
 
 use super::super::loop_helpers::{ContentSafety, LoopError, SafetyChecker};
 use super::helpers::storage_to_loop_error;
-use crate::safety::SafetyGuard;
+use crate::safety::{DenialLog, DenialReason, SafetyGuard};
 use crate::storage::{self, DbPool};
 
 /// Adapts `SafetyGuard` to the `SafetyChecker` port trait.
 pub struct SafetyAdapter {
     guard: Arc<SafetyGuard>,
     pool: DbPool,
+    banned_phrases: Vec<String>,
+    max_replies_per_conversation: Option<u32>,
+    denials: Option<DenialLog>,
 }
 
 impl SafetyAdapter {
     pub fn new(guard: Arc<SafetyGuard>, pool: DbPool) -> Self {
-        Self { guard, pool }
+        Self {
+            guard,
+            pool,
+            banned_phrases: Vec::new(),
+            max_replies_per_conversation: None,
+            denials: None,
+        }
+    }
+
+    /// Allow at most `max_per_day` replies into any one conversation.
+    pub fn with_conversation_limit(mut self, max_per_day: u32) -> Self {
+        self.max_replies_per_conversation = Some(max_per_day);
+        self
+    }
+
+    /// Reject generated replies containing any of these phrases.
+    pub fn with_banned_phrases(mut self, banned_phrases: Vec<String>) -> Self {
+        self.banned_phrases = banned_phrases;
+        self
+    }
+
+    /// Record every denial into `denials`.
+    pub fn with_denial_log(mut self, denials: DenialLog) -> Self {
+        self.denials = Some(denials);
+        self
+    }
+
+    fn deny(&self, reason: DenialReason) {
+        if let Some(denials) = &self.denials {
+            denials.record(reason);
+        }
     }
 }
 
@@ -28,6 +61,7 @@ impl SafetyChecker for SafetyAdapter {
             Ok(Ok(())) => true,
             Ok(Err(reason)) => {
                 tracing::debug!(reason = %reason, "Safety check denied reply");
+                self.deny(reason);
                 false
             }
             Err(e) => {
@@ -39,7 +73,13 @@ impl SafetyChecker for SafetyAdapter {
 
     async fn has_replied_to(&self, tweet_id: &str) -> bool {
         match self.guard.dedup_checker().has_replied_to(tweet_id).await {
-            Ok(replied) => replied,
+            Ok(true) => {
+                self.deny(DenialReason::AlreadyReplied {
+                    tweet_id: tweet_id.to_string(),
+                });
+                true
+            }
+            Ok(false) => false,
             Err(e) => {
                 tracing::warn!(error = %e, "Dedup check error, assuming already replied");
                 true
@@ -47,7 +87,34 @@ impl SafetyChecker for SafetyAdapter {
         }
     }
 
-    async fn record_reply(&self, tweet_id: &str, reply_content: &str) -> Result<(), LoopError> {
+    async fn allows_conversation(&self, conversation_id: Option<&str>) -> bool {
+        let Some(max) = self.max_replies_per_conversation else {
+            return true;
+        };
+        match self
+            .guard
+            .check_conversation_limit(conversation_id, max)
+            .await
+        {
+            Ok(Ok(())) => true,
+            Ok(Err(reason)) => {
+                tracing::debug!(reason = %reason, "Safety check denied conversation reply");
+                self.deny(reason);
+                false
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Conversation limit check error, denying reply");
+                false
+            }
+        }
+    }
+
+    async fn record_reply(
+        &self,
+        tweet_id: &str,
+        conversation_id: Option<&str>,
+        reply_content: &str,
+    ) -> Result<(), LoopError> {
         // Insert a reply record for dedup tracking.
         let reply = storage::replies::ReplySent {
             id: 0,
@@ -59,6 +126,7 @@ impl SafetyChecker for SafetyAdapter {
             created_at: Utc::now().to_rfc3339(),
             status: "pending".to_string(),
             error_message: None,
+            conversation_id: conversation_id.map(String::from),
         };
         storage::replies::insert_reply(&self.pool, &reply)
             .await
@@ -72,16 +140,58 @@ impl SafetyChecker for SafetyAdapter {
 
         Ok(())
     }
+
+    async fn allows_reply_text(&self, reply_text: &str) -> bool {
+        match SafetyGuard::check_banned_phrases(reply_text, &self.banned_phrases) {
+            Ok(()) => true,
+            Err(reason) => {
+                tracing::debug!(reason = %reason, "Safety check denied reply text");
+                self.deny(reason);
+                false
+            }
+        }
+    }
+
+    async fn acquire_search(&self) -> bool {
+        match self
+            .guard
+            .rate_limiter()
+            .acquire_posting_permit("search")
+            .await
+        {
+            Ok(allowed) => allowed,
+            Err(e) => {
+                tracing::warn!(error = %e, "Search rate limit check error, skipping search");
+                false
+            }
+        }
+    }
 }
 
 /// Adapts `SafetyGuard` to the `ContentSafety` port trait.
 pub struct ContentSafetyAdapter {
     guard: Arc<SafetyGuard>,
+    denials: Option<DenialLog>,
 }
 
 impl ContentSafetyAdapter {
     pub fn new(guard: Arc<SafetyGuard>) -> Self {
-        Self { guard }
+        Self {
+            guard,
+            denials: None,
+        }
+    }
+
+    /// Record every denial into `denials`.
+    pub fn with_denial_log(mut self, denials: DenialLog) -> Self {
+        self.denials = Some(denials);
+        self
+    }
+
+    fn deny(&self, reason: DenialReason) {
+        if let Some(denials) = &self.denials {
+            denials.record(reason);
+        }
     }
 }
 
@@ -92,6 +202,7 @@ impl ContentSafety for ContentSafetyAdapter {
             Ok(Ok(())) => true,
             Ok(Err(reason)) => {
                 tracing::debug!(reason = %reason, "Safety check denied tweet");
+                self.deny(reason);
                 false
             }
             Err(e) => {
@@ -106,6 +217,7 @@ impl ContentSafety for ContentSafetyAdapter {
             Ok(Ok(())) => true,
             Ok(Err(reason)) => {
                 tracing::debug!(reason = %reason, "Safety check denied thread");
+                self.deny(reason);
                 false
             }
             Err(e) => {
@@ -2,22 +2,38 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
+
 use super::super::loop_helpers::{LoopTweet, ScoreResult, TweetScorer};
-use crate::scoring::{self, ScoringEngine, TweetData};
+use crate::scoring::{self, reputation, ScoringEngine, TweetData};
+use crate::storage::DbPool;
 
 /// Adapts `ScoringEngine` to the `TweetScorer` port trait.
 pub struct ScoringAdapter {
     engine: Arc<ScoringEngine>,
+    /// Pool and account whose author reputation is folded into scores.
+    reputation: Option<(DbPool, String)>,
 }
 
 impl ScoringAdapter {
     pub fn new(engine: Arc<ScoringEngine>) -> Self {
-        Self { engine }
+        Self {
+            engine,
+            reputation: None,
+        }
+    }
+
+    /// Adjust scores by each author's reply history for `account_id`.
+    /// Without it, tweets are scored on the heuristic signals alone.
+    pub fn with_reputation(mut self, pool: DbPool, account_id: impl Into<String>) -> Self {
+        self.reputation = Some((pool, account_id.into()));
+        self
     }
 }
 
+#[async_trait::async_trait]
 impl TweetScorer for ScoringAdapter {
-    fn score(&self, tweet: &LoopTweet) -> ScoreResult {
+    async fn score(&self, tweet: &LoopTweet) -> ScoreResult {
         let data = TweetData {
             text: tweet.text.clone(),
             created_at: tweet.created_at.clone(),
@@ -30,7 +46,15 @@ impl TweetScorer for ScoringAdapter {
             is_quote_tweet: false,
         };
 
-        let score = self.engine.score_tweet(&data);
+        let author_reputation = match &self.reputation {
+            Some((pool, account_id)) => {
+                reputation::author_reputation_for(pool, account_id, &tweet.author_id).await
+            }
+            None => 0.0,
+        };
+        let score =
+            self.engine
+                .score_tweet_at_with_reputation(&data, Utc::now(), author_reputation);
         let matched_keywords = scoring::find_matched_keywords(&tweet.text, self.engine.keywords());
 
         ScoreResult {
@@ -2,7 +2,10 @@ use std::sync::Arc;
 
 use super::*;
 use crate::automation::analytics_loop::{EngagementFetcher, ProfileFetcher};
-use crate::automation::loop_helpers::{LoopError, MentionsFetcher, ThreadPoster, TweetSearcher};
+use crate::automation::loop_helpers::{
+    ConversationFetcher, LoopError, LoopTweet, MentionsFetcher, ThreadPoster, TweetLiker,
+    TweetSearcher,
+};
 use crate::automation::posting_queue::PostExecutor;
 use crate::automation::target_loop::{TargetTweetFetcher, TargetUserManager};
 use crate::x_api::types::*;
@@ -29,6 +32,7 @@ impl XApiClient for MockXApiClient {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -54,6 +58,7 @@ impl XApiClient for MockXApiClient {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -84,13 +89,26 @@ impl XApiClient for MockXApiClient {
     }
 
     async fn get_tweet(&self, id: &str) -> Result<Tweet, crate::error::XApiError> {
+        // "c2" replies to "c1", which replies to the root "c0".
+        let parent = match id {
+            "c2" => Some("c1"),
+            "c1" => Some("c0"),
+            _ => None,
+        };
         Ok(Tweet {
             id: id.into(),
-            text: "tweet text".into(),
+            text: format!("text of {id}"),
             author_id: "a1".into(),
             created_at: String::new(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            referenced_tweets: parent
+                .map(|p| ReferencedTweet {
+                    kind: "replied_to".into(),
+                    id: p.into(),
+                })
+                .into_iter()
+                .collect(),
         })
     }
 
@@ -104,6 +122,7 @@ impl XApiClient for MockXApiClient {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -135,8 +154,13 @@ impl XApiClient for MockXApiClient {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
+
+    async fn like_tweet(&self, _: &str, _: &str) -> Result<bool, crate::error::XApiError> {
+        Ok(true)
+    }
 }
 
 fn mock_client() -> Arc<dyn XApiClient> {
@@ -164,6 +188,50 @@ async fn mentions_adapter_routes_through_toolkit() {
     assert_eq!(tweets[0].id, "m1");
 }
 
+// --- ConversationFetcher (routes through toolkit::read::get_tweet) ---
+
+fn reply_tweet(author_id: &str, in_reply_to_id: &str) -> LoopTweet {
+    LoopTweet {
+        id: "c3".into(),
+        text: "reply".into(),
+        author_id: author_id.into(),
+        author_username: "replier".into(),
+        author_followers: 0,
+        created_at: String::new(),
+        likes: 0,
+        retweets: 0,
+        replies: 0,
+        in_reply_to_id: Some(in_reply_to_id.into()),
+        conversation_id: None,
+        author_protected: None,
+    }
+}
+
+#[tokio::test]
+async fn conversation_adapter_walks_ancestors_oldest_first() {
+    let adapter = XApiConversationAdapter::new(mock_client());
+    let ancestors = adapter
+        .fetch_ancestors(&reply_tweet("a1", "c2"), 5)
+        .await
+        .unwrap();
+    let texts: Vec<_> = ancestors.iter().map(|t| t.text.as_str()).collect();
+    assert_eq!(texts, vec!["text of c0", "text of c1", "text of c2"]);
+    assert!(ancestors.iter().all(|t| t.author == "replier"));
+}
+
+#[tokio::test]
+async fn conversation_adapter_respects_cap() {
+    let adapter = XApiConversationAdapter::new(mock_client());
+    let ancestors = adapter
+        .fetch_ancestors(&reply_tweet("other", "c2"), 2)
+        .await
+        .unwrap();
+    let texts: Vec<_> = ancestors.iter().map(|t| t.text.as_str()).collect();
+    assert_eq!(texts, vec!["text of c1", "text of c2"]);
+    // The mock has no batch user lookup, so other authors stay unnamed.
+    assert!(ancestors.iter().all(|t| t.author == "unknown"));
+}
+
 // --- TargetTweetFetcher (routes through toolkit::read::get_user_tweets) ---
 
 #[tokio::test]
@@ -234,6 +302,42 @@ async fn thread_poster_reply_routes_through_toolkit() {
     assert_eq!(id, "rt1");
 }
 
+// --- TweetLiker (routes through toolkit::engage, capped by the `like` limit) ---
+
+async fn like_adapter(max_likes_per_day: u32) -> XApiLikeAdapter {
+    let pool = crate::storage::init_test_db().await.expect("init db");
+    let limits = crate::config::LimitsConfig {
+        max_likes_per_day,
+        ..Default::default()
+    };
+    crate::storage::rate_limits::init_rate_limits(
+        &pool,
+        &limits,
+        &crate::config::IntervalsConfig::default(),
+    )
+    .await
+    .expect("init limits");
+    XApiLikeAdapter::new(
+        mock_client(),
+        "me".into(),
+        crate::safety::RateLimiter::new(pool),
+    )
+}
+
+#[tokio::test]
+async fn like_adapter_stops_at_daily_cap() {
+    let adapter = like_adapter(1).await;
+    let tweet = reply_tweet("a1", "c2");
+    assert!(adapter.like_tweet(&tweet).await.unwrap());
+    assert!(!adapter.like_tweet(&tweet).await.unwrap());
+}
+
+#[tokio::test]
+async fn like_adapter_skips_own_tweets() {
+    let adapter = like_adapter(5).await;
+    assert!(!adapter.like_tweet(&reply_tweet("me", "c2")).await.unwrap());
+}
+
 // --- Error mapping: ToolkitError → LoopError ---
 
 #[tokio::test]
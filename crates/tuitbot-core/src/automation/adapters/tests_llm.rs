@@ -0,0 +1,398 @@
+use std::sync::{Arc, Mutex};
+
+use super::*;
+use crate::automation::thread_loop::ThreadGenerator;
+use crate::automation::{ReplyGenerator, TweetGenerator};
+use crate::content::frameworks::{FrameworkWeights, ReplyArchetype, ThreadStructure, TweetFormat};
+use crate::content::ContentGenerator;
+use crate::error::LlmError;
+use crate::llm::{GenerationParams, LlmProvider, LlmResponse, SessionUsage, TokenUsage};
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::init_test_db;
+
+// ============================================================================
+// LLM adapters — session usage accumulation
+// ============================================================================
+
+/// Provider that always returns the same text with fixed token usage.
+struct FixedUsageProvider {
+    text: String,
+}
+
+#[async_trait::async_trait]
+impl LlmProvider for FixedUsageProvider {
+    fn name(&self) -> &str {
+        "openai"
+    }
+
+    async fn complete(
+        &self,
+        _system: &str,
+        _user_message: &str,
+        _params: &GenerationParams,
+    ) -> Result<LlmResponse, LlmError> {
+        Ok(LlmResponse {
+            text: self.text.clone(),
+            usage: TokenUsage {
+                input_tokens: 1000,
+                output_tokens: 200,
+            },
+            model: "gpt-4o-mini".to_string(),
+        })
+    }
+
+    async fn health_check(&self) -> Result<(), LlmError> {
+        Ok(())
+    }
+}
+
+fn fixed_generator(text: &str) -> Arc<ContentGenerator> {
+    Arc::new(ContentGenerator::new(
+        Box::new(FixedUsageProvider {
+            text: text.to_string(),
+        }),
+        crate::config::BusinessProfile::default(),
+    ))
+}
+
+#[tokio::test]
+async fn tweet_adapter_generates_without_rag_when_sources_are_empty() {
+    let pool = init_test_db().await.expect("init db");
+    let tweets = LlmTweetAdapter::new(fixed_generator("Ship small, ship often."), pool)
+        .with_rag(crate::storage::accounts::DEFAULT_ACCOUNT_ID, false);
+
+    let text = tweets.generate_tweet("rust").await.expect("tweet");
+    assert_eq!(text, "Ship small, ship often.");
+}
+
+#[tokio::test]
+async fn tweet_adapter_fails_when_rag_required_and_sources_are_empty() {
+    let pool = init_test_db().await.expect("init db");
+    let tweets = LlmTweetAdapter::new(fixed_generator("Ship small, ship often."), pool.clone())
+        .with_rag(crate::storage::accounts::DEFAULT_ACCOUNT_ID, true);
+
+    let err = tweets.generate_tweet("rust").await.unwrap_err();
+    assert!(
+        err.to_string().contains("RAG context required"),
+        "got: {err}"
+    );
+
+    // No LLM call was made.
+    let rows: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM llm_usage")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(rows.0, 0);
+}
+
+#[tokio::test]
+async fn llm_adapters_accumulate_session_usage() {
+    let pool = init_test_db().await.expect("init db");
+    let session = Arc::new(SessionUsage::new());
+
+    let tweets = LlmTweetAdapter::new(fixed_generator("Ship small, ship often."), pool.clone())
+        .with_session_usage(session.clone());
+    let replies = LlmReplyAdapter::new(fixed_generator("Agreed, tests first."), pool.clone())
+        .with_session_usage(session.clone());
+    let thread_text = ["One", "Two", "Three", "Four", "Five"].join("\n---\n");
+    let threads = LlmThreadAdapter::new(fixed_generator(&thread_text), pool.clone())
+        .with_session_usage(session.clone());
+
+    tweets.generate_tweet("rust").await.expect("tweet");
+    tweets.generate_tweet("testing").await.expect("tweet");
+    replies
+        .generate_reply("Should I write tests?", "dev", false)
+        .await
+        .expect("reply");
+    threads.generate_thread("ci", None).await.expect("thread");
+
+    let totals = session.snapshot();
+    assert_eq!(totals.generations, 4);
+    assert_eq!(totals.input_tokens, 4000);
+    assert_eq!(totals.output_tokens, 800);
+    assert_eq!(totals.priced_generations, 4);
+    // gpt-4o-mini: 4000 * $0.15/M + 800 * $0.60/M
+    assert!((totals.estimated_cost - 0.00108).abs() < 1e-9);
+
+    // Database recording is unaffected.
+    let rows: (i64,) = sqlx::query_as("SELECT COUNT(*) FROM llm_usage")
+        .fetch_one(&pool)
+        .await
+        .unwrap();
+    assert_eq!(rows.0, 4);
+}
+
+// ============================================================================
+// LLM adapters — framework picks
+// ============================================================================
+
+/// Provider that records every system prompt it is sent.
+struct RecordingProvider {
+    text: String,
+    prompts: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait::async_trait]
+impl LlmProvider for RecordingProvider {
+    fn name(&self) -> &str {
+        "openai"
+    }
+
+    async fn complete(
+        &self,
+        system: &str,
+        _user_message: &str,
+        _params: &GenerationParams,
+    ) -> Result<LlmResponse, LlmError> {
+        self.prompts.lock().unwrap().push(system.to_string());
+        Ok(LlmResponse {
+            text: self.text.clone(),
+            usage: TokenUsage::default(),
+            model: "gpt-4o-mini".to_string(),
+        })
+    }
+
+    async fn health_check(&self) -> Result<(), LlmError> {
+        Ok(())
+    }
+}
+
+fn recording_generator(
+    text: &str,
+    seed: Option<u64>,
+) -> (Arc<ContentGenerator>, Arc<Mutex<Vec<String>>>) {
+    weighted_recording_generator(text, seed, &[])
+}
+
+fn weighted_recording_generator(
+    text: &str,
+    seed: Option<u64>,
+    weights: &[(&str, f64)],
+) -> (Arc<ContentGenerator>, Arc<Mutex<Vec<String>>>) {
+    let prompts = Arc::new(Mutex::new(Vec::new()));
+    let weights = weights
+        .iter()
+        .map(|(name, weight)| (name.to_string(), *weight))
+        .collect();
+    let generator = ContentGenerator::new(
+        Box::new(RecordingProvider {
+            text: text.to_string(),
+            prompts: prompts.clone(),
+        }),
+        crate::config::BusinessProfile::default(),
+    )
+    .with_random_seed(seed)
+    .with_framework_weights(FrameworkWeights::new(weights));
+    (Arc::new(generator), prompts)
+}
+
+#[tokio::test]
+async fn reply_adapter_prompts_with_a_picked_archetype() {
+    let pool = init_test_db().await.expect("init db");
+    let (generator, prompts) = recording_generator("Agreed, tests first.", None);
+    let replies = LlmReplyAdapter::new(generator, pool);
+
+    replies
+        .generate_reply("Should I write tests?", "dev", false)
+        .await
+        .expect("reply");
+
+    let prompt = prompts.lock().unwrap()[0].clone();
+    assert!(
+        ReplyArchetype::ALL
+            .iter()
+            .any(|a| prompt.contains(a.prompt_fragment())),
+        "no archetype in prompt: {prompt}"
+    );
+}
+
+#[tokio::test]
+async fn reply_adapter_archetypes_follow_the_seed() {
+    let pool = init_test_db().await.expect("init db");
+    let (first, first_prompts) = recording_generator("Agreed.", Some(7));
+    let (second, second_prompts) = recording_generator("Agreed.", Some(7));
+    let first = LlmReplyAdapter::new(first, pool.clone());
+    let second = LlmReplyAdapter::new(second, pool);
+
+    for _ in 0..5 {
+        first.generate_reply("tweet", "dev", false).await.unwrap();
+        second.generate_reply("tweet", "dev", false).await.unwrap();
+    }
+
+    assert_eq!(
+        *first_prompts.lock().unwrap(),
+        *second_prompts.lock().unwrap()
+    );
+}
+
+#[tokio::test]
+async fn tweet_adapter_does_not_repeat_recent_formats() {
+    let pool = init_test_db().await.expect("init db");
+    let (generator, prompts) = recording_generator("Ship small.", Some(3));
+    let tweets = LlmTweetAdapter::new(generator, pool);
+
+    for _ in 0..8 {
+        tweets.generate_tweet("rust").await.expect("tweet");
+    }
+
+    let formats: Vec<TweetFormat> = prompts
+        .lock()
+        .unwrap()
+        .iter()
+        .map(|prompt| {
+            *TweetFormat::ALL
+                .iter()
+                .find(|f| prompt.contains(f.prompt_fragment()))
+                .expect("format in prompt")
+        })
+        .collect();
+    for window in formats.windows(2) {
+        assert_ne!(window[0], window[1], "formats: {formats:?}");
+    }
+}
+
+#[tokio::test]
+async fn thread_adapter_prompts_with_a_picked_structure() {
+    let pool = init_test_db().await.expect("init db");
+    let thread_text = ["One", "Two", "Three", "Four", "Five"].join("\n---\n");
+    let (generator, prompts) = recording_generator(&thread_text, None);
+    let threads = LlmThreadAdapter::new(generator, pool);
+
+    threads.generate_thread("ci", None).await.expect("thread");
+
+    let prompt = prompts.lock().unwrap()[0].clone();
+    assert!(
+        ThreadStructure::ALL
+            .iter()
+            .any(|s| prompt.contains(s.prompt_fragment())),
+        "no structure in prompt: {prompt}"
+    );
+}
+
+#[tokio::test]
+async fn reply_adapter_never_picks_a_zero_weight_archetype() {
+    let pool = init_test_db().await.expect("init db");
+    let (generator, prompts) = weighted_recording_generator(
+        "Agreed.",
+        Some(11),
+        &[
+            ("ask_question", 0.0),
+            ("agree_and_expand", 1.0),
+            ("add_data", 1.0),
+        ],
+    );
+    let replies = LlmReplyAdapter::new(generator, pool);
+
+    for _ in 0..30 {
+        replies.generate_reply("tweet", "dev", false).await.unwrap();
+    }
+
+    let asked = ReplyArchetype::AskQuestion.prompt_fragment();
+    assert!(prompts.lock().unwrap().iter().all(|p| !p.contains(asked)));
+}
+
+#[tokio::test]
+async fn thread_adapter_never_picks_a_zero_weight_structure() {
+    let pool = init_test_db().await.expect("init db");
+    let thread_text = ["One", "Two", "Three", "Four", "Five"].join("\n---\n");
+    let (generator, prompts) = weighted_recording_generator(
+        &thread_text,
+        Some(11),
+        &[("mistakes", 0.0), ("framework", 1.0), ("analysis", 1.0)],
+    );
+    let threads = LlmThreadAdapter::new(generator, pool);
+
+    for _ in 0..30 {
+        threads.generate_thread("ci", None).await.expect("thread");
+    }
+
+    let mistakes = ThreadStructure::Mistakes.prompt_fragment();
+    assert!(prompts
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|p| !p.contains(mistakes)));
+}
+
+#[tokio::test]
+async fn rotating_reply_adapter_cycles_every_archetype() {
+    let pool = init_test_db().await.expect("init db");
+    let (generator, prompts) = recording_generator("Agreed.", Some(5));
+    let replies = LlmReplyAdapter::new(generator, pool.clone()).with_rotation(DEFAULT_ACCOUNT_ID);
+
+    for _ in 0..ReplyArchetype::ALL.len() {
+        replies.generate_reply("tweet", "dev", false).await.unwrap();
+    }
+
+    let prompts = prompts.lock().unwrap();
+    for archetype in ReplyArchetype::ALL {
+        let uses = prompts
+            .iter()
+            .filter(|p| p.contains(archetype.prompt_fragment()))
+            .count();
+        assert_eq!(uses, 1, "{archetype} used {uses} times");
+    }
+}
+
+#[tokio::test]
+async fn rotating_adapters_record_picks_for_their_account() {
+    use crate::content::frameworks::{KIND_FORMAT, KIND_STRUCTURE};
+    use crate::storage::content_rotation;
+
+    let pool = init_test_db().await.expect("init db");
+    let thread_text = ["One", "Two", "Three", "Four", "Five"].join("\n---\n");
+    let (tweet_gen, _) = recording_generator("Ship small.", None);
+    let (thread_gen, _) = recording_generator(&thread_text, None);
+    let tweets = LlmTweetAdapter::new(tweet_gen, pool.clone()).with_rotation("acct-2");
+    let threads = LlmThreadAdapter::new(thread_gen, pool.clone()).with_rotation("acct-2");
+
+    tweets.generate_tweet("rust").await.expect("tweet");
+    threads.generate_thread("ci", None).await.expect("thread");
+
+    for kind in [KIND_FORMAT, KIND_STRUCTURE] {
+        let counts = content_rotation::use_counts_for(&pool, "acct-2", kind)
+            .await
+            .unwrap();
+        assert_eq!(counts.values().sum::<i64>(), 1, "{kind}: {counts:?}");
+        assert!(
+            content_rotation::use_counts_for(&pool, DEFAULT_ACCOUNT_ID, kind)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+    }
+}
+
+#[tokio::test]
+async fn adapters_return_each_framework_with_the_output() {
+    use crate::content::frameworks::{KIND_ARCHETYPE, KIND_FORMAT, KIND_STRUCTURE};
+
+    let pool = init_test_db().await.expect("init db");
+    let thread_text = ["One", "Two", "Three", "Four", "Five"].join("\n---\n");
+    let (reply_gen, _) = recording_generator("Agreed!", None);
+    let (tweet_gen, _) = recording_generator("Ship small.", None);
+    let (thread_gen, _) = recording_generator(&thread_text, None);
+    let replies = LlmReplyAdapter::new(reply_gen, pool.clone());
+    let tweets = LlmTweetAdapter::new(tweet_gen, pool.clone());
+    let threads = LlmThreadAdapter::new(thread_gen, pool);
+
+    let reply = replies
+        .generate_reply_with_rag("hi", "alice", false)
+        .await
+        .expect("reply");
+    let tweet = tweets
+        .generate_tweet_with_framework("rust")
+        .await
+        .expect("tweet");
+    let thread = threads
+        .generate_thread_with_framework("ci", None)
+        .await
+        .expect("thread");
+
+    assert_eq!(reply.text, "Agreed!");
+    assert_eq!(reply.framework.map(|pick| pick.kind), Some(KIND_ARCHETYPE));
+    assert_eq!(tweet.text, "Ship small.");
+    assert_eq!(tweet.framework.map(|pick| pick.kind), Some(KIND_FORMAT));
+    assert_eq!(thread.tweets.len(), 5);
+    assert_eq!(thread.framework.map(|pick| pick.kind), Some(KIND_STRUCTURE));
+}
@@ -3,7 +3,7 @@ use std::sync::Arc;
 use super::*;
 use crate::automation::loop_helpers::{ContentSafety, SafetyChecker};
 use crate::config::{IntervalsConfig, LimitsConfig};
-use crate::safety::SafetyGuard;
+use crate::safety::{DenialLog, DenialReason, SafetyGuard};
 use crate::storage::{init_test_db, rate_limits, DbPool};
 
 fn test_limits() -> LimitsConfig {
@@ -14,8 +14,13 @@ fn test_limits() -> LimitsConfig {
         min_action_delay_seconds: 30,
         max_action_delay_seconds: 120,
         max_replies_per_author_per_day: 1,
+        max_replies_per_conversation_per_day: 2,
+        max_likes_per_day: 20,
+        max_retweets_per_day: 5,
+        discovery_likes: false,
         banned_phrases: vec![],
         product_mention_ratio: 0.2,
+        ramp: None,
     }
 }
 
@@ -23,6 +28,7 @@ fn test_intervals() -> IntervalsConfig {
     IntervalsConfig {
         mentions_check_seconds: 300,
         discovery_search_seconds: 600,
+        discovery_max_pages: 1,
         content_post_window_seconds: 14400,
         thread_interval_seconds: 604800,
     }
@@ -49,6 +55,20 @@ async fn safety_can_reply_under_limit() {
     assert!(adapter.can_reply().await);
 }
 
+#[tokio::test]
+async fn safety_acquire_search_stops_at_limit() {
+    let (pool, guard) = setup().await;
+    sqlx::query("UPDATE rate_limits SET max_requests = 2 WHERE action_type = 'search'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let adapter = SafetyAdapter::new(guard, pool);
+
+    assert!(adapter.acquire_search().await);
+    assert!(adapter.acquire_search().await);
+    assert!(!adapter.acquire_search().await);
+}
+
 #[tokio::test]
 async fn safety_has_replied_to_false() {
     let (pool, guard) = setup().await;
@@ -63,7 +83,7 @@ async fn safety_has_replied_to_true() {
     let adapter = SafetyAdapter::new(guard, pool);
 
     adapter
-        .record_reply("tweet_42", "Great insight!")
+        .record_reply("tweet_42", None, "Great insight!")
         .await
         .unwrap();
 
@@ -75,7 +95,7 @@ async fn safety_record_reply_inserts() {
     let (pool, guard) = setup().await;
     let adapter = SafetyAdapter::new(guard, pool);
 
-    let result = adapter.record_reply("tweet_7", "Nice thread!").await;
+    let result = adapter.record_reply("tweet_7", None, "Nice thread!").await;
     assert!(result.is_ok());
 
     // Verify it was recorded by checking dedup.
@@ -101,3 +121,94 @@ async fn content_safety_can_post_thread() {
 
     assert!(adapter.can_post_thread().await);
 }
+
+// ============================================================================
+// Denial recording
+// ============================================================================
+
+#[tokio::test]
+async fn safety_records_rate_limit_denial() {
+    let (pool, guard) = setup().await;
+    let denials = DenialLog::new();
+    let adapter = SafetyAdapter::new(guard, pool).with_denial_log(denials.clone());
+
+    for i in 0..3 {
+        adapter
+            .record_reply(&format!("tweet_{i}"), None, "Nice!")
+            .await
+            .unwrap();
+    }
+
+    assert!(!adapter.can_reply().await);
+    let recorded = denials.take();
+    assert_eq!(recorded.len(), 1);
+    assert_eq!(recorded[0].kind(), "rate_limited");
+}
+
+#[tokio::test]
+async fn safety_records_banned_phrase_denial() {
+    let (pool, guard) = setup().await;
+    let denials = DenialLog::new();
+    let adapter = SafetyAdapter::new(guard, pool)
+        .with_banned_phrases(vec!["check out".to_string()])
+        .with_denial_log(denials.clone());
+
+    assert!(adapter.allows_reply_text("Solid benchmark numbers").await);
+    assert!(
+        !adapter
+            .allows_reply_text("You should Check Out my tool")
+            .await
+    );
+    assert_eq!(
+        denials.take(),
+        vec![DenialReason::BannedPhrase {
+            phrase: "check out".to_string()
+        }]
+    );
+}
+
+#[tokio::test]
+async fn safety_records_already_replied_denial() {
+    let (pool, guard) = setup().await;
+    let denials = DenialLog::new();
+    let adapter = SafetyAdapter::new(guard, pool).with_denial_log(denials.clone());
+
+    adapter
+        .record_reply("tweet_1", None, "Nice!")
+        .await
+        .unwrap();
+    assert!(adapter.has_replied_to("tweet_1").await);
+    assert_eq!(denials.take()[0].kind(), "already_replied");
+}
+
+#[tokio::test]
+async fn safety_refuses_second_reply_in_same_conversation() {
+    let (pool, guard) = setup().await;
+    let denials = DenialLog::new();
+    let adapter = SafetyAdapter::new(guard, pool)
+        .with_conversation_limit(1)
+        .with_denial_log(denials.clone());
+
+    assert!(adapter.allows_conversation(Some("conv_1")).await);
+    adapter
+        .record_reply("tweet_1", Some("conv_1"), "Nice!")
+        .await
+        .unwrap();
+
+    assert!(!adapter.allows_conversation(Some("conv_1")).await);
+    assert!(adapter.allows_conversation(Some("conv_2")).await);
+    assert!(adapter.allows_conversation(None).await);
+    assert_eq!(denials.take(), vec![DenialReason::ConversationLimitReached]);
+}
+
+#[tokio::test]
+async fn safety_without_conversation_limit_allows_everything() {
+    let (pool, guard) = setup().await;
+    let adapter = SafetyAdapter::new(guard, pool);
+
+    adapter
+        .record_reply("tweet_1", Some("conv_1"), "Nice!")
+        .await
+        .unwrap();
+    assert!(adapter.allows_conversation(Some("conv_1")).await);
+}
@@ -0,0 +1,48 @@
+use std::sync::Arc;
+
+use super::*;
+use crate::automation::loop_helpers::{LoopTweet, TweetScorer};
+use crate::config::ScoringConfig;
+use crate::scoring::ScoringEngine;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::author_reputation::seed_author_history;
+use crate::storage::init_test_db;
+
+fn tweet_by(author_id: &str) -> LoopTweet {
+    LoopTweet {
+        id: format!("{author_id}-new"),
+        text: "Shipping a rust release today".to_string(),
+        author_id: author_id.to_string(),
+        author_username: author_id.to_string(),
+        author_followers: 2_000,
+        created_at: chrono::Utc::now().to_rfc3339(),
+        likes: 4,
+        retweets: 1,
+        replies: 1,
+        in_reply_to_id: None,
+        conversation_id: None,
+        author_protected: None,
+    }
+}
+
+#[tokio::test]
+async fn scoring_adapter_folds_in_author_reputation() {
+    let pool = init_test_db().await.expect("init db");
+    seed_author_history(&pool, "dud", &[0.0, 0.0, 0.0]).await;
+    seed_author_history(&pool, "star", &[20.0, 20.0, 20.0]).await;
+
+    let engine = Arc::new(ScoringEngine::new(
+        ScoringConfig::default(),
+        vec!["rust".to_string()],
+    ));
+    let adapter = ScoringAdapter::new(engine).with_reputation(pool, DEFAULT_ACCOUNT_ID);
+
+    let dud = adapter.score(&tweet_by("dud")).await;
+    let star = adapter.score(&tweet_by("star")).await;
+    assert!(
+        star.total > dud.total,
+        "star {} should outscore dud {}",
+        star.total,
+        dud.total
+    );
+}
@@ -3,6 +3,7 @@ use crate::automation::analytics_loop::AnalyticsStorage;
 use crate::automation::loop_helpers::{ContentStorage, LoopStorage, LoopTweet, TopicScorer};
 use crate::automation::posting_queue::PostAction;
 use crate::automation::target_loop::TargetStorage;
+use crate::content::FrameworkPick;
 use crate::storage::init_test_db;
 use tokio::sync::mpsc;
 
@@ -21,6 +22,9 @@ fn sample_loop_tweet() -> LoopTweet {
         likes: 5,
         retweets: 1,
         replies: 0,
+        in_reply_to_id: None,
+        conversation_id: None,
+        author_protected: None,
     }
 }
 
@@ -388,3 +392,143 @@ async fn content_storage_mark_failed_permanent_creates_approval_queue_entry() {
         "reason should contain retry count"
     );
 }
+
+// ============================================================================
+// Framework links — posting adapters
+// ============================================================================
+
+async fn linked_frameworks(pool: &crate::storage::DbPool) -> Vec<(String, String, String)> {
+    sqlx::query_as("SELECT tweet_id, kind, framework FROM content_frameworks ORDER BY tweet_id")
+        .fetch_all(pool)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn content_storage_links_posted_tweets_and_thread_roots() {
+    use crate::content::{ThreadStructure, TweetFormat};
+
+    let pool = init_test_db().await.expect("init db");
+    let (tx, mut rx) = test_post_channel();
+    let adapter = ContentStorageAdapter::new(pool.clone(), tx);
+    let tip = FrameworkPick::format(TweetFormat::Tip);
+
+    let queued = tokio::spawn(async move {
+        let mut frameworks = Vec::new();
+        for id in ["t1", "t4"] {
+            if let Some(PostAction::Tweet {
+                framework,
+                result_tx,
+                ..
+            }) = rx.recv().await
+            {
+                frameworks.push(framework);
+                let _ = result_tx.unwrap().send(Ok(id.to_string()));
+            }
+        }
+        frameworks
+    });
+    adapter
+        .post_tweet_with_framework("rust", "Ship small.", Some(&tip))
+        .await
+        .unwrap();
+    adapter.post_tweet("rust", "No framework.").await.unwrap();
+    assert_eq!(queued.await.unwrap(), vec![Some(tip), None]);
+
+    let thread_id = adapter.create_thread("ci", 2).await.unwrap();
+    adapter
+        .store_thread_tweet(&thread_id, 0, "t2", "Root")
+        .await
+        .unwrap();
+    adapter
+        .link_thread_framework("t2", &FrameworkPick::structure(ThreadStructure::Mistakes))
+        .await;
+
+    assert_eq!(
+        linked_frameworks(&pool).await,
+        vec![
+            ("t1".to_string(), "format".to_string(), "tip".to_string()),
+            (
+                "t2".to_string(),
+                "structure".to_string(),
+                "mistakes".to_string()
+            ),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn approval_queue_adapter_stores_the_items_framework() {
+    use crate::automation::posting_queue::ApprovalQueue;
+    use crate::content::ReplyArchetype;
+
+    let pool = init_test_db().await.expect("init db");
+    let queue = ApprovalQueueAdapter::new(pool.clone());
+    let pick = FrameworkPick::archetype(ReplyArchetype::AgreeAndExpand);
+
+    let framed = queue
+        .queue_reply_with_framework("t1", "Agreed!", &[], Some(&pick))
+        .await
+        .unwrap();
+    let plain = queue.queue_tweet("Unrelated", &[]).await.unwrap();
+
+    let archetype = |id| {
+        let pool = pool.clone();
+        async move {
+            crate::storage::approval_queue::get_by_id(&pool, id)
+                .await
+                .unwrap()
+                .unwrap()
+                .archetype
+        }
+    };
+    assert_eq!(archetype(framed).await, "agree_and_expand");
+    assert_eq!(archetype(plain).await, "");
+}
+
+#[tokio::test]
+async fn post_sender_links_replies_but_not_queued_items() {
+    use crate::automation::loop_helpers::PostSender;
+    use crate::content::ReplyArchetype;
+
+    let pool = init_test_db().await.expect("init db");
+    let (tx, mut rx) = test_post_channel();
+    let sender = PostSenderAdapter::new(tx).with_framework_links(pool.clone());
+    let agree = FrameworkPick::archetype(ReplyArchetype::AgreeAndExpand);
+    let ask = FrameworkPick::archetype(ReplyArchetype::AskQuestion);
+
+    let queued = tokio::spawn(async move {
+        let mut frameworks = Vec::new();
+        for id in ["r1", "queued:7"] {
+            if let Some(PostAction::Reply {
+                framework,
+                result_tx,
+                ..
+            }) = rx.recv().await
+            {
+                frameworks.push(framework);
+                let _ = result_tx.unwrap().send(Ok(id.to_string()));
+            }
+        }
+        frameworks
+    });
+    sender
+        .send_reply_with_framework("t1", "Agreed!", Some(&agree))
+        .await
+        .unwrap();
+    sender
+        .send_reply_with_framework("t2", "Queued!", Some(&ask))
+        .await
+        .unwrap();
+
+    // The queued item keeps its framework on the action for the approval queue.
+    assert_eq!(queued.await.unwrap(), vec![Some(agree), Some(ask)]);
+    assert_eq!(
+        linked_frameworks(&pool).await,
+        vec![(
+            "r1".to_string(),
+            "archetype".to_string(),
+            "agree_and_expand".to_string()
+        )]
+    );
+}
@@ -0,0 +1,123 @@
+//! Vault-aware LLM reply adapter.
+
+use std::sync::Arc;
+
+use super::super::loop_helpers::{LoopError, ReplyGenerator, ReplyOutput};
+use super::helpers::llm_to_loop_error;
+use super::llm::{next_archetype, record_reply_usage};
+use crate::content::{ContentGenerator, ConversationTweet, FrameworkPick};
+use crate::llm::SessionUsage;
+use crate::storage::DbPool;
+
+/// Vault-aware reply adapter that injects pre-built RAG context into replies.
+///
+/// The RAG prompt is built once at construction time (by the server/CLI wiring
+/// layer) and reused for every reply, avoiding per-tweet DB queries.
+pub struct VaultAwareLlmReplyAdapter {
+    generator: Arc<ContentGenerator>,
+    pool: DbPool,
+    session_usage: Option<Arc<SessionUsage>>,
+    /// Account whose rotation history picks archetypes (`content.auto_rotate`).
+    rotation: Option<String>,
+    /// Pre-built RAG prompt block to inject into every reply.
+    rag_prompt: Option<String>,
+    /// Pre-built vault citations corresponding to the RAG prompt.
+    vault_citations: Vec<crate::context::retrieval::VaultCitation>,
+}
+
+impl VaultAwareLlmReplyAdapter {
+    pub fn new(
+        generator: Arc<ContentGenerator>,
+        pool: DbPool,
+        rag_prompt: Option<String>,
+        vault_citations: Vec<crate::context::retrieval::VaultCitation>,
+    ) -> Self {
+        Self {
+            generator,
+            pool,
+            session_usage: None,
+            rotation: None,
+            rag_prompt,
+            vault_citations,
+        }
+    }
+
+    /// Also accumulate usage into a session-level total.
+    pub fn with_session_usage(mut self, session_usage: Arc<SessionUsage>) -> Self {
+        self.session_usage = Some(session_usage);
+        self
+    }
+
+    /// Cycle archetypes through `account_id`'s rotation (`content.auto_rotate`)
+    /// instead of drawing them at random.
+    pub fn with_rotation(mut self, account_id: impl Into<String>) -> Self {
+        self.rotation = Some(account_id.into());
+        self
+    }
+}
+
+impl VaultAwareLlmReplyAdapter {
+    async fn generate(
+        &self,
+        tweet_text: &str,
+        author: &str,
+        mention_product: bool,
+        conversation: &[ConversationTweet],
+    ) -> Result<ReplyOutput, LoopError> {
+        let archetype = next_archetype(&self.generator, &self.pool, self.rotation.as_deref()).await;
+        let output = self
+            .generator
+            .generate_reply_with_context(
+                tweet_text,
+                author,
+                mention_product,
+                Some(archetype),
+                self.rag_prompt.as_deref(),
+                conversation,
+            )
+            .await
+            .map_err(llm_to_loop_error)?;
+        record_reply_usage(&self.pool, self.session_usage.as_deref(), &output).await;
+        Ok(ReplyOutput {
+            text: output.text,
+            vault_citations: self.vault_citations.clone(),
+            framework: Some(FrameworkPick::archetype(archetype)),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl ReplyGenerator for VaultAwareLlmReplyAdapter {
+    async fn generate_reply(
+        &self,
+        tweet_text: &str,
+        author: &str,
+        mention_product: bool,
+    ) -> Result<String, LoopError> {
+        let output = self
+            .generate(tweet_text, author, mention_product, &[])
+            .await?;
+        Ok(output.text)
+    }
+
+    async fn generate_reply_with_rag(
+        &self,
+        tweet_text: &str,
+        author: &str,
+        mention_product: bool,
+    ) -> Result<ReplyOutput, LoopError> {
+        self.generate(tweet_text, author, mention_product, &[])
+            .await
+    }
+
+    async fn generate_reply_in_conversation(
+        &self,
+        tweet_text: &str,
+        author: &str,
+        mention_product: bool,
+        conversation: &[ConversationTweet],
+    ) -> Result<ReplyOutput, LoopError> {
+        self.generate(tweet_text, author, mention_product, conversation)
+            .await
+    }
+}
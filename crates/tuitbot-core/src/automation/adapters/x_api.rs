@@ -4,14 +4,17 @@ use std::sync::Arc;
 
 use super::super::analytics_loop::{AnalyticsError, EngagementFetcher, ProfileFetcher};
 use super::super::loop_helpers::{
-    ContentLoopError, LoopError, LoopTweet, MentionsFetcher, ThreadPoster, TweetSearcher,
+    ContentLoopError, ConversationFetcher, LoopError, LoopTweet, MentionsFetcher, ThreadPoster,
+    TweetLiker, TweetSearcher,
 };
 use super::super::posting_queue::PostExecutor;
 use super::super::target_loop::{TargetTweetFetcher, TargetUserManager};
 use super::helpers::{
-    search_response_to_loop_tweets, toolkit_to_analytics_error, toolkit_to_content_error,
-    toolkit_to_loop_error,
+    search_response_to_loop_tweets, storage_to_loop_error, toolkit_to_analytics_error,
+    toolkit_to_content_error, toolkit_to_loop_error,
 };
+use crate::content::ConversationTweet;
+use crate::safety::{is_self_reply, RateLimiter};
 use crate::x_api::XApiClient;
 
 /// Adapts `XApiClient` to the `TweetSearcher` port trait via toolkit.
@@ -28,10 +31,20 @@ impl XApiSearchAdapter {
 #[async_trait::async_trait]
 impl TweetSearcher for XApiSearchAdapter {
     async fn search_tweets(&self, query: &str) -> Result<Vec<LoopTweet>, LoopError> {
-        let response = crate::toolkit::read::search_tweets(&*self.client, query, 20, None, None)
-            .await
-            .map_err(toolkit_to_loop_error)?;
-        Ok(search_response_to_loop_tweets(response))
+        Ok(self.search_tweets_page(query, None).await?.0)
+    }
+
+    async fn search_tweets_page(
+        &self,
+        query: &str,
+        pagination_token: Option<&str>,
+    ) -> Result<(Vec<LoopTweet>, Option<String>), LoopError> {
+        let response =
+            crate::toolkit::read::search_tweets(&*self.client, query, 20, None, pagination_token)
+                .await
+                .map_err(toolkit_to_loop_error)?;
+        let next_token = response.meta.next_token.clone();
+        Ok((search_response_to_loop_tweets(response), next_token))
     }
 }
 
@@ -61,6 +74,82 @@ impl MentionsFetcher for XApiMentionsAdapter {
     }
 }
 
+/// Adapts `XApiClient` to the `ConversationFetcher` port trait via toolkit.
+///
+/// Walks `replied_to` references one tweet at a time, then resolves the
+/// ancestor authors' usernames in a single batch lookup.
+pub struct XApiConversationAdapter {
+    client: Arc<dyn XApiClient>,
+}
+
+impl XApiConversationAdapter {
+    pub fn new(client: Arc<dyn XApiClient>) -> Self {
+        Self { client }
+    }
+}
+
+#[async_trait::async_trait]
+impl ConversationFetcher for XApiConversationAdapter {
+    async fn fetch_ancestors(
+        &self,
+        tweet: &LoopTweet,
+        max: usize,
+    ) -> Result<Vec<ConversationTweet>, LoopError> {
+        let mut ancestors = Vec::new();
+        let mut next_id = tweet.in_reply_to_id.clone();
+        while let Some(id) = next_id.take() {
+            if ancestors.len() >= max {
+                break;
+            }
+            let parent = match crate::toolkit::read::get_tweet(&*self.client, &id).await {
+                Ok(parent) => parent,
+                // A deleted or protected ancestor ends the walk; keep what we have.
+                Err(e) if !ancestors.is_empty() => {
+                    tracing::debug!(tweet_id = %id, error = %e, "Stopped conversation walk");
+                    break;
+                }
+                Err(e) => return Err(toolkit_to_loop_error(e)),
+            };
+            next_id = parent.replied_to_id().map(String::from);
+            ancestors.push(parent);
+        }
+
+        let unknown_ids: Vec<&str> = ancestors
+            .iter()
+            .map(|t| t.author_id.as_str())
+            .filter(|id| *id != tweet.author_id)
+            .collect();
+        let users = if unknown_ids.is_empty() {
+            Vec::new()
+        } else {
+            crate::toolkit::read::get_users_by_ids(&*self.client, &unknown_ids)
+                .await
+                .map(|r| r.data)
+                .unwrap_or_default()
+        };
+
+        Ok(ancestors
+            .into_iter()
+            .rev()
+            .map(|t| {
+                let author = if t.author_id == tweet.author_id {
+                    tweet.author_username.clone()
+                } else {
+                    users
+                        .iter()
+                        .find(|u| u.id == t.author_id)
+                        .map(|u| u.username.clone())
+                        .unwrap_or_else(|| "unknown".to_string())
+                };
+                ConversationTweet {
+                    author,
+                    text: t.text,
+                }
+            })
+            .collect())
+    }
+}
+
 /// Adapts `XApiClient` to `TargetTweetFetcher` and `TargetUserManager` via toolkit.
 pub struct XApiTargetAdapter {
     client: Arc<dyn XApiClient>,
@@ -92,6 +181,44 @@ impl TargetUserManager for XApiTargetAdapter {
     }
 }
 
+/// Adapts `XApiClient` to the `TweetLiker` port trait via toolkit, claiming
+/// a slot of the daily `like` rate limit for every like.
+pub struct XApiLikeAdapter {
+    client: Arc<dyn XApiClient>,
+    own_user_id: String,
+    limiter: RateLimiter,
+}
+
+impl XApiLikeAdapter {
+    pub fn new(client: Arc<dyn XApiClient>, own_user_id: String, limiter: RateLimiter) -> Self {
+        Self {
+            client,
+            own_user_id,
+            limiter,
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl TweetLiker for XApiLikeAdapter {
+    async fn like_tweet(&self, tweet: &LoopTweet) -> Result<bool, LoopError> {
+        if is_self_reply(&tweet.author_id, &self.own_user_id) {
+            return Ok(false);
+        }
+        if !self
+            .limiter
+            .acquire_posting_permit("like")
+            .await
+            .map_err(storage_to_loop_error)?
+        {
+            return Ok(false);
+        }
+        crate::toolkit::engage::like_tweet(&*self.client, &self.own_user_id, &tweet.id)
+            .await
+            .map_err(toolkit_to_loop_error)
+    }
+}
+
 /// Adapts `XApiClient` to `ProfileFetcher` and `EngagementFetcher` via toolkit.
 pub struct XApiProfileAdapter {
     client: Arc<dyn XApiClient>,
@@ -155,7 +282,7 @@ impl PostExecutor for XApiPostExecutorAdapter {
         tweet_id: &str,
         content: &str,
         media_ids: &[String],
-    ) -> Result<String, String> {
+    ) -> Result<String, LoopError> {
         let media = if media_ids.is_empty() {
             None
         } else {
@@ -164,10 +291,14 @@ impl PostExecutor for XApiPostExecutorAdapter {
         crate::toolkit::write::reply_to_tweet(&*self.client, content, tweet_id, media)
             .await
             .map(|posted| posted.id)
-            .map_err(|e| e.to_string())
+            .map_err(toolkit_to_loop_error)
     }
 
-    async fn execute_tweet(&self, content: &str, media_ids: &[String]) -> Result<String, String> {
+    async fn execute_tweet(
+        &self,
+        content: &str,
+        media_ids: &[String],
+    ) -> Result<String, LoopError> {
         let media = if media_ids.is_empty() {
             None
         } else {
@@ -176,7 +307,7 @@ impl PostExecutor for XApiPostExecutorAdapter {
         crate::toolkit::write::post_tweet(&*self.client, content, media)
             .await
             .map(|posted| posted.id)
-            .map_err(|e| e.to_string())
+            .map_err(toolkit_to_loop_error)
     }
 }
 
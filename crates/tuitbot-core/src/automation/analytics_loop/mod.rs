@@ -17,6 +17,7 @@ pub use collector::{
 };
 pub use reporter::{compute_performance_score, AnalyticsSummary};
 
+use super::loop_breaker::{breaker_gate, record_iteration, LoopBreaker};
 use super::loop_helpers::ConsecutiveErrorTracker;
 use super::scheduler::LoopScheduler;
 use std::sync::Arc;
@@ -28,6 +29,8 @@ pub struct AnalyticsLoop {
     profile_fetcher: Arc<dyn ProfileFetcher>,
     engagement_fetcher: Arc<dyn EngagementFetcher>,
     storage: Arc<dyn AnalyticsStorage>,
+    /// Error budget checked between iterations of `run`.
+    breaker: Option<LoopBreaker>,
 }
 
 impl AnalyticsLoop {
@@ -41,9 +44,16 @@ impl AnalyticsLoop {
             profile_fetcher,
             engagement_fetcher,
             storage,
+            breaker: None,
         }
     }
 
+    /// Pause the loop while its persisted error budget is spent.
+    pub fn with_loop_breaker(mut self, breaker: LoopBreaker) -> Self {
+        self.breaker = Some(breaker);
+        self
+    }
+
     /// Run the continuous analytics loop until cancellation.
     pub async fn run(&self, cancel: CancellationToken, scheduler: LoopScheduler) {
         tracing::info!("Analytics loop started");
@@ -55,9 +65,14 @@ impl AnalyticsLoop {
                 break;
             }
 
+            if !breaker_gate(&self.breaker, "analytics", &cancel).await {
+                break;
+            }
+
             match self.run_iteration().await {
                 Ok(summary) => {
                     error_tracker.record_success();
+                    record_iteration(&self.breaker, "analytics", Ok(())).await;
                     tracing::info!(
                         followers = summary.follower_count,
                         replies_measured = summary.replies_measured,
@@ -66,6 +81,7 @@ impl AnalyticsLoop {
                     );
                 }
                 Err(e) => {
+                    record_iteration(&self.breaker, "analytics", Err(&e.to_string())).await;
                     let should_pause = error_tracker.record_error();
                     tracing::warn!(error = %e, "Analytics iteration failed");
 
@@ -15,6 +15,7 @@ use std::time::Duration;
 
 use tokio_util::sync::CancellationToken;
 
+use crate::automation::watchtower::loopback::LoopBackThrottle;
 use crate::storage::{self, DbPool};
 use crate::x_api::XApiClient;
 
@@ -24,15 +25,23 @@ use crate::x_api::XApiClient;
 /// and posts them to X using the provided `x_client` (which must be
 /// constructed with that account's credentials).
 ///
-/// Uses randomized delay between `min_delay` and `max_delay` to appear human-like.
+/// Uses randomized delay between `min_delay` and `max_delay` to appear human-like;
+/// `random_seed` (`content.random_seed`) makes those delays repeat across runs.
+/// Loop-back writes to source notes are capped at `loopback_max_writes_per_hour`
+/// per source (0 = unlimited).
+#[allow(clippy::too_many_arguments)]
 pub async fn run_approval_poster(
     pool: DbPool,
     x_client: Arc<dyn XApiClient>,
     account_id: String,
     min_delay: Duration,
     max_delay: Duration,
+    loopback_max_writes_per_hour: u32,
+    random_seed: Option<u64>,
     cancel: CancellationToken,
 ) {
+    let loopback_throttle = LoopBackThrottle::new(loopback_max_writes_per_hour);
+    let mut rng = queue::pacing_rng(random_seed);
     tracing::info!(account_id = %account_id, "Approval poster loop started");
 
     // Poll interval when no items are found.
@@ -83,6 +92,7 @@ pub async fn run_approval_poster(
                 if item.action_type == "thread" {
                     match poster::post_thread_and_persist(
                         &pool,
+                        &loopback_throttle,
                         &*x_client,
                         &account_id,
                         &item,
@@ -107,6 +117,9 @@ pub async fn run_approval_poster(
                                 tracing::warn!(id = item.id, error = %e, "Failed to mark thread as posted");
                             }
 
+                            queue::record_framework(&pool, &account_id, &item, &root_tweet_id)
+                                .await;
+
                             let _ = storage::action_log::log_action_for(
                                 &pool,
                                 &account_id,
@@ -180,12 +193,16 @@ pub async fn run_approval_poster(
                                 );
                             }
 
-                            // Propagate vault provenance to original_tweets record.
+                            // Record the tweet, its topic and vault provenance.
                             queue::propagate_provenance(&pool, &account_id, &item, &tweet_id).await;
 
+                            // Link the post to its framework for performance tracking.
+                            queue::record_framework(&pool, &account_id, &item, &tweet_id).await;
+
                             // Write loop-back metadata to source notes.
                             queue::execute_loopback_for_provenance(
                                 &pool,
+                                &loopback_throttle,
                                 &account_id,
                                 &item,
                                 &tweet_id,
@@ -230,7 +247,7 @@ pub async fn run_approval_poster(
                 }
 
                 // Jittered delay between posts.
-                let delay = queue::randomized_delay(min_delay, max_delay);
+                let delay = queue::randomized_delay(&mut rng, min_delay, max_delay);
                 if !delay.is_zero() {
                     tokio::time::sleep(delay).await;
                 }
@@ -3,6 +3,7 @@
 //! Handles routing by action type (tweet / reply / thread),
 //! media upload, and full thread reply-chain posting with persistence.
 
+use crate::automation::watchtower::loopback::LoopBackThrottle;
 use crate::content::deserialize_blocks_from_content;
 use crate::storage::{self, DbPool};
 use crate::x_api::XApiClient;
@@ -102,6 +103,7 @@ pub fn parse_thread_content(content: &str) -> Result<Vec<String>, String> {
 /// Returns the root tweet ID on success.
 pub(super) async fn post_thread_and_persist(
     pool: &DbPool,
+    loopback_throttle: &LoopBackThrottle,
     x_client: &dyn XApiClient,
     account_id: &str,
     item: &storage::approval_queue::ApprovalItem,
@@ -110,51 +112,44 @@ pub(super) async fn post_thread_and_persist(
     let tweet_texts = parse_thread_content(&item.generated_content)?;
 
     // Post as reply chain: root standalone, children reply to previous.
-    let mut posted_ids: Vec<String> = Vec::with_capacity(tweet_texts.len());
-    let mut posted_contents: Vec<String> = Vec::with_capacity(tweet_texts.len());
-
-    for (i, text) in tweet_texts.iter().enumerate() {
-        let result = if i == 0 {
-            post_tweet(x_client, text, media_ids).await
-        } else {
-            post_reply(x_client, &posted_ids[i - 1], text, &[]).await
-        };
-
-        match result {
-            Ok(tweet_id) => {
-                posted_ids.push(tweet_id);
-                posted_contents.push(text.clone());
-            }
-            Err(e) => {
+    let posted_ids =
+        match crate::workflow::thread_post::post_reply_chain(x_client, &tweet_texts, media_ids)
+            .await
+        {
+            Ok(ids) => ids,
+            Err(failure) => {
                 // Partial failure: persist what we posted so far.
-                if !posted_ids.is_empty() {
+                if !failure.posted_ids.is_empty() {
+                    let posted_contents = &tweet_texts[..failure.posted_ids.len()];
                     persist_and_propagate_thread(
                         pool,
+                        loopback_throttle,
                         account_id,
                         item,
-                        &posted_ids,
-                        &posted_contents,
+                        &failure.posted_ids,
+                        posted_contents,
                         "partial",
                     )
                     .await;
                 }
                 return Err(format!(
-                    "Thread failed at tweet {}/{}: {e}. {} tweet(s) posted.",
-                    i + 1,
+                    "Thread failed at tweet {}/{}: {}. {} tweet(s) posted.",
+                    failure.failed_index + 1,
                     tweet_texts.len(),
-                    posted_ids.len()
+                    failure.error,
+                    failure.posted_ids.len()
                 ));
             }
-        }
-    }
+        };
 
     // Full success: persist all records.
     persist_and_propagate_thread(
         pool,
+        loopback_throttle,
         account_id,
         item,
         &posted_ids,
-        &posted_contents,
+        &tweet_texts,
         "sent",
     )
     .await;
@@ -165,6 +160,7 @@ pub(super) async fn post_thread_and_persist(
 /// Persist thread records and propagate provenance + loopback.
 pub(super) async fn persist_and_propagate_thread(
     pool: &DbPool,
+    loopback_throttle: &LoopBackThrottle,
     account_id: &str,
     item: &storage::approval_queue::ApprovalItem,
     posted_ids: &[String],
@@ -223,6 +219,7 @@ pub(super) async fn persist_and_propagate_thread(
             let child_ids: Vec<String> = posted_ids.iter().skip(1).cloned().collect();
             super::queue::execute_loopback_for_thread(
                 pool,
+                loopback_throttle,
                 account_id,
                 item,
                 root_tweet_id,
@@ -5,21 +5,32 @@
 
 use std::collections::HashSet;
 
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::automation::watchtower::loopback::LoopBackThrottle;
 use crate::storage::{self, DbPool};
 
-/// Compute a randomized delay between `min` and `max`.
+/// Compute a randomized delay between `min` and `max`, drawn from `rng`.
 pub(super) fn randomized_delay(
+    rng: &mut impl Rng,
     min: std::time::Duration,
     max: std::time::Duration,
 ) -> std::time::Duration {
-    use rand::Rng;
-
     if min >= max || (min.is_zero() && max.is_zero()) {
         return min;
     }
     let min_ms = min.as_millis() as u64;
     let max_ms = max.as_millis() as u64;
-    std::time::Duration::from_millis(rand::rng().random_range(min_ms..=max_ms))
+    std::time::Duration::from_millis(rng.random_range(min_ms..=max_ms))
+}
+
+/// RNG for the delay between posts, seeded by `content.random_seed`.
+pub(super) fn pacing_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_rng(&mut rand::rng()),
+    }
 }
 
 /// Write loop-back metadata to source notes referenced by provenance links.
@@ -30,6 +41,7 @@ pub(super) fn randomized_delay(
 /// `account_id` must match the account that owns the approval item.
 pub(super) async fn execute_loopback_for_provenance(
     pool: &DbPool,
+    throttle: &LoopBackThrottle,
     account_id: &str,
     item: &storage::approval_queue::ApprovalItem,
     tweet_id: &str,
@@ -54,8 +66,15 @@ pub(super) async fn execute_loopback_for_provenance(
     for link in &links {
         if let Some(node_id) = link.node_id {
             if seen.insert(node_id) {
-                let result =
-                    loopback::execute_loopback(pool, node_id, tweet_id, &url, content_type).await;
+                let result = loopback::execute_loopback(
+                    pool,
+                    throttle,
+                    node_id,
+                    tweet_id,
+                    &url,
+                    content_type,
+                )
+                .await;
                 match &result {
                     loopback::LoopBackResult::Written => {
                         tracing::info!(
@@ -76,6 +95,9 @@ pub(super) async fn execute_loopback_for_provenance(
                     loopback::LoopBackResult::FileNotFound => {
                         tracing::debug!(node_id, "Loopback: file not found on disk");
                     }
+                    loopback::LoopBackResult::Throttled => {
+                        tracing::debug!(node_id, tweet_id, "Loopback: hourly write cap reached");
+                    }
                 }
             }
         }
@@ -85,6 +107,7 @@ pub(super) async fn execute_loopback_for_provenance(
 /// Write loop-back metadata to source notes for a thread.
 pub(super) async fn execute_loopback_for_thread(
     pool: &DbPool,
+    throttle: &LoopBackThrottle,
     account_id: &str,
     item: &storage::approval_queue::ApprovalItem,
     root_tweet_id: &str,
@@ -115,6 +138,7 @@ pub(super) async fn execute_loopback_for_thread(
             if seen.insert(node_id) {
                 let result = loopback::execute_loopback_thread(
                     pool,
+                    throttle,
                     node_id,
                     root_tweet_id,
                     &url,
@@ -137,11 +161,13 @@ pub(super) async fn execute_loopback_for_thread(
     }
 }
 
-/// Propagate vault provenance from the approval queue item to original_tweets.
+/// Record a posted item in original_tweets, with its topic and vault provenance.
 ///
-/// If the approval item has a `source_node_id`, inserts an `original_tweets`
-/// record with that node ID set, and copies provenance links from the
-/// `approval_queue` entity to the new `original_tweet` entity.
+/// Approved tweets always get an `original_tweets` record so their topic is
+/// joined to engagement in topic analytics. Other items get one only when
+/// they have a `source_node_id` or `source_seed_id`. The node ID is set on
+/// the record, and provenance links are copied from the `approval_queue`
+/// entity to the new `original_tweet` entity.
 ///
 /// `account_id` must match the account that owns the approval item.
 pub(super) async fn propagate_provenance(
@@ -150,8 +176,7 @@ pub(super) async fn propagate_provenance(
     item: &storage::approval_queue::ApprovalItem,
     tweet_id: &str,
 ) {
-    // Insert an original_tweets record for provenance tracking.
-    if item.source_node_id.is_some() || item.source_seed_id.is_some() {
+    if should_record_original(item) {
         let tweet = storage::threads::OriginalTweet {
             id: 0,
             tweet_id: Some(tweet_id.to_string()),
@@ -198,3 +223,35 @@ pub(super) async fn propagate_provenance(
         }
     }
 }
+
+/// Whether a posted approval item needs an `original_tweets` record.
+pub(super) fn should_record_original(item: &storage::approval_queue::ApprovalItem) -> bool {
+    item.action_type == "tweet" || item.source_node_id.is_some() || item.source_seed_id.is_some()
+}
+
+/// Link a posted item to the content framework recorded on it (the queue's
+/// `archetype` column), for `content::frameworks::performance_report`.
+///
+/// Items queued without a framework are skipped. Failures are logged only.
+pub(super) async fn record_framework(
+    pool: &DbPool,
+    account_id: &str,
+    item: &storage::approval_queue::ApprovalItem,
+    tweet_id: &str,
+) {
+    if item.archetype.is_empty() {
+        return;
+    }
+    let kind = crate::content::frameworks::kind_for_action(&item.action_type);
+    if let Err(e) = storage::content_frameworks::record_framework_for(
+        pool,
+        account_id,
+        tweet_id,
+        kind,
+        &item.archetype,
+    )
+    .await
+    {
+        tracing::warn!(id = item.id, error = %e, "Failed to record content framework");
+    }
+}
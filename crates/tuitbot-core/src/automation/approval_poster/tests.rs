@@ -7,19 +7,27 @@ mod tests {
 
     #[test]
     fn delay_returns_min_when_min_equals_max() {
-        let d = queue::randomized_delay(Duration::from_secs(5), Duration::from_secs(5));
+        let d = queue::randomized_delay(
+            &mut rand::rng(),
+            Duration::from_secs(5),
+            Duration::from_secs(5),
+        );
         assert_eq!(d, Duration::from_secs(5));
     }
 
     #[test]
     fn delay_returns_min_when_min_greater_than_max() {
-        let d = queue::randomized_delay(Duration::from_secs(10), Duration::from_secs(5));
+        let d = queue::randomized_delay(
+            &mut rand::rng(),
+            Duration::from_secs(10),
+            Duration::from_secs(5),
+        );
         assert_eq!(d, Duration::from_secs(10));
     }
 
     #[test]
     fn delay_returns_zero_when_both_zero() {
-        let d = queue::randomized_delay(Duration::ZERO, Duration::ZERO);
+        let d = queue::randomized_delay(&mut rand::rng(), Duration::ZERO, Duration::ZERO);
         assert_eq!(d, Duration::ZERO);
     }
 
@@ -28,7 +36,7 @@ mod tests {
         let min = Duration::from_millis(100);
         let max = Duration::from_millis(500);
         for _ in 0..50 {
-            let d = queue::randomized_delay(min, max);
+            let d = queue::randomized_delay(&mut rand::rng(), min, max);
             assert!(d >= min, "delay {d:?} should be >= {min:?}");
             assert!(d <= max, "delay {d:?} should be <= {max:?}");
         }
@@ -39,7 +47,7 @@ mod tests {
         let min = Duration::ZERO;
         let max = Duration::from_millis(100);
         for _ in 0..20 {
-            let d = queue::randomized_delay(min, max);
+            let d = queue::randomized_delay(&mut rand::rng(), min, max);
             assert!(d <= max);
         }
     }
@@ -49,7 +57,7 @@ mod tests {
         let min = Duration::from_millis(50);
         let max = Duration::from_millis(51);
         for _ in 0..20 {
-            let d = queue::randomized_delay(min, max);
+            let d = queue::randomized_delay(&mut rand::rng(), min, max);
             assert!(d >= min && d <= max);
         }
     }
@@ -194,6 +202,75 @@ mod tests {
         assert!(should_propagate);
     }
 
+    #[tokio::test]
+    async fn approved_tweets_are_recorded_with_their_topic() {
+        use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+        use crate::storage::approval_queue::{enqueue, get_by_id};
+
+        let pool = crate::storage::init_test_db().await.expect("init db");
+        let tweet_id = enqueue(&pool, "tweet", "", "", "Tip", "rust", "", 0.0, "[]")
+            .await
+            .unwrap();
+        let reply_id = enqueue(&pool, "reply", "t1", "@a", "Nice", "rust", "", 0.0, "[]")
+            .await
+            .unwrap();
+
+        for (id, posted) in [(tweet_id, "posted_tweet"), (reply_id, "posted_reply")] {
+            let item = get_by_id(&pool, id).await.unwrap().unwrap();
+            queue::propagate_provenance(&pool, DEFAULT_ACCOUNT_ID, &item, posted).await;
+        }
+
+        let rows: Vec<(String, Option<String>)> =
+            sqlx::query_as("SELECT tweet_id, topic FROM original_tweets")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            rows,
+            vec![("posted_tweet".to_string(), Some("rust".to_string()))],
+            "replies without provenance are not original tweets"
+        );
+    }
+
+    // ── record_framework ─────────────────────────────────────────
+
+    #[tokio::test]
+    async fn record_framework_links_posted_items_with_a_framework() {
+        use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+        use crate::storage::approval_queue::{enqueue, get_by_id};
+
+        let pool = crate::storage::init_test_db().await.expect("init db");
+        let reply_id = enqueue(
+            &pool,
+            "reply",
+            "t1",
+            "@a",
+            "Nice",
+            "rust",
+            "ask_question",
+            80.0,
+            "[]",
+        )
+        .await
+        .unwrap();
+        let tweet_id = enqueue(&pool, "tweet", "", "", "Tip", "rust", "", 0.0, "[]")
+            .await
+            .unwrap();
+
+        for (id, posted) in [(reply_id, "posted_reply"), (tweet_id, "posted_tweet")] {
+            let item = get_by_id(&pool, id).await.unwrap().unwrap();
+            queue::record_framework(&pool, DEFAULT_ACCOUNT_ID, &item, posted).await;
+        }
+
+        let report = crate::content::frameworks::performance_report(&pool)
+            .await
+            .unwrap();
+        assert_eq!(report.len(), 1, "items without a framework are skipped");
+        assert_eq!(report[0].kind, "archetype");
+        assert_eq!(report[0].framework, "ask_question");
+        assert_eq!(report[0].posted, 1);
+    }
+
     // ── topic to Option conversion ───────────────────────────────
 
     #[test]
@@ -234,7 +311,7 @@ mod tests {
         let min = Duration::from_secs(60);
         let max = Duration::from_secs(300);
         for _ in 0..20 {
-            let d = queue::randomized_delay(min, max);
+            let d = queue::randomized_delay(&mut rand::rng(), min, max);
             assert!(d >= min);
             assert!(d <= max);
         }
@@ -245,7 +322,7 @@ mod tests {
         let min = Duration::from_millis(1);
         let max = Duration::from_millis(10);
         for _ in 0..20 {
-            let d = queue::randomized_delay(min, max);
+            let d = queue::randomized_delay(&mut rand::rng(), min, max);
             assert!(d >= min);
             assert!(d <= max);
         }
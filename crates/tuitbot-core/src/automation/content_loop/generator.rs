@@ -12,8 +12,8 @@ impl ContentLoop {
         tracing::info!(topic = %topic, "Generating tweet on topic");
 
         // Generate tweet
-        let content = match self.generator.generate_tweet(topic).await {
-            Ok(text) => text,
+        let (content, framework) = match self.generator.generate_tweet_with_framework(topic).await {
+            Ok(output) => (output.text, output.framework),
             Err(e) => {
                 return ContentResult::Failed {
                     error: format!("Generation failed: {e}"),
@@ -22,7 +22,7 @@ impl ContentLoop {
         };
 
         // Validate length (280 char limit, URL-aware)
-        let content = if crate::content::length::tweet_weighted_len(&content)
+        let (content, framework) = if crate::content::length::tweet_weighted_len(&content)
             > crate::content::length::MAX_TWEET_CHARS
         {
             // Retry once with explicit shorter instruction
@@ -32,29 +32,33 @@ impl ContentLoop {
             );
 
             let shorter_topic = format!("{topic} (IMPORTANT: keep under 280 characters)");
-            match self.generator.generate_tweet(&shorter_topic).await {
-                Ok(text)
-                    if crate::content::length::tweet_weighted_len(&text)
+            match self
+                .generator
+                .generate_tweet_with_framework(&shorter_topic)
+                .await
+            {
+                Ok(retry)
+                    if crate::content::length::tweet_weighted_len(&retry.text)
                         <= crate::content::length::MAX_TWEET_CHARS =>
                 {
-                    text
+                    (retry.text, retry.framework)
                 }
-                Ok(text) => {
+                Ok(retry) => {
                     // Truncate at word boundary
                     tracing::warn!(
-                        chars = text.len(),
+                        chars = retry.text.len(),
                         "Retry still too long, truncating at word boundary"
                     );
-                    truncate_at_word_boundary(&text, 280)
+                    (truncate_at_word_boundary(&retry.text, 280), retry.framework)
                 }
                 Err(e) => {
                     // Use original but truncated
                     tracing::warn!(error = %e, "Retry generation failed, truncating original");
-                    truncate_at_word_boundary(&content, 280)
+                    (truncate_at_word_boundary(&content, 280), framework)
                 }
             }
         } else {
-            content
+            (content, framework)
         };
 
         if self.dry_run {
@@ -74,7 +78,11 @@ impl ContentLoop {
                 )
                 .await;
         } else {
-            if let Err(e) = self.storage.post_tweet(topic, &content).await {
+            if let Err(e) = self
+                .storage
+                .post_tweet_with_framework(topic, &content, framework.as_ref())
+                .await
+            {
                 tracing::error!(error = %e, "Failed to post tweet");
                 let _ = self
                     .storage
@@ -109,11 +117,18 @@ impl ContentLoop {
     ///
     /// Falls back to uniform random selection if no scorer is set or
     /// if the scorer returns no data.
+    ///
+    /// With a minimum topic gap configured, the storage's gap-aware
+    /// selector is asked first.
     pub(super) async fn pick_topic_epsilon_greedy(
         &self,
         recent_topics: &mut Vec<String>,
         rng: &mut impl rand::Rng,
     ) -> String {
+        if let Some(topic) = self.pick_topic_outside_gap().await {
+            return topic;
+        }
+
         if let Some(scorer) = &self.topic_scorer {
             let roll: f64 = rng.random();
             if roll < EXPLOIT_RATIO {
@@ -142,6 +157,28 @@ impl ContentLoop {
     }
 }
 
+impl ContentLoop {
+    /// Ask storage for the least recently used topic outside the minimum
+    /// topic gap. `None` when no gap is configured or selection failed.
+    pub(super) async fn pick_topic_outside_gap(&self) -> Option<String> {
+        if self.min_topic_gap_hours == 0 {
+            return None;
+        }
+        let gap = chrono::Duration::hours(self.min_topic_gap_hours as i64);
+        match self.storage.next_topic(&self.topics, gap).await {
+            Ok(Some(topic)) => {
+                tracing::debug!(topic = %topic, "Picked least recently used topic outside gap");
+                Some(topic)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                tracing::warn!(error = %e, "Topic gap selection failed, falling back");
+                None
+            }
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Free functions
 // ---------------------------------------------------------------------------
@@ -279,6 +316,39 @@ mod tests {
         assert!(recent.is_empty());
     }
 
+    #[tokio::test]
+    async fn min_topic_gap_uses_storage_selection() {
+        let storage = Arc::new(MockStorage::new(None));
+        *storage.next_topic.lock().unwrap() = Some("Open source".to_string());
+        let scorer = Arc::new(MockTopicScorer {
+            top_topics: vec!["Rust".to_string()],
+        });
+
+        let content = ContentLoop::new(
+            Arc::new(MockGenerator {
+                response: "tweet".to_string(),
+            }),
+            Arc::new(MockSafety {
+                can_tweet: true,
+                can_thread: true,
+            }),
+            storage,
+            make_topics(),
+            14400,
+            false,
+        )
+        .with_topic_scorer(scorer)
+        .with_min_topic_gap(12);
+
+        let mut recent = Vec::new();
+        let mut rng = FirstCallRng::low_roll();
+
+        let topic = content
+            .pick_topic_epsilon_greedy(&mut recent, &mut rng)
+            .await;
+        assert_eq!(topic, "Open source");
+    }
+
     #[tokio::test]
     async fn epsilon_greedy_exploits_top_topic() {
         let storage = Arc::new(MockStorage::new(None));
@@ -19,9 +19,12 @@ mod scheduler;
 #[cfg(test)]
 mod tests_guardrails; // Task 3.5: safety guardrails + publisher tests
 
+use super::loop_breaker::LoopBreaker;
 use super::loop_helpers::{
     ContentSafety, ContentStorage, ThreadPoster, TopicScorer, TweetGenerator,
 };
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use std::sync::Arc;
 
 /// Fraction of the time to exploit top-performing topics (vs. explore random ones).
@@ -35,8 +38,14 @@ pub struct ContentLoop {
     pub(super) topic_scorer: Option<Arc<dyn TopicScorer>>,
     pub(super) thread_poster: Option<Arc<dyn ThreadPoster>>,
     pub(super) topics: Vec<String>,
+    /// Minimum hours between tweets on the same topic (0 = no gap).
+    pub(super) min_topic_gap_hours: u64,
+    /// Seed for topic picks and slot jitter (`content.random_seed`).
+    pub(super) random_seed: Option<u64>,
     pub(super) post_window_secs: u64,
     pub(super) dry_run: bool,
+    /// Error budget checked between iterations of `run`.
+    pub(super) breaker: Option<LoopBreaker>,
 }
 
 /// Result of a content generation attempt.
@@ -71,11 +80,29 @@ impl ContentLoop {
             topic_scorer: None,
             thread_poster: None,
             topics,
+            min_topic_gap_hours: 0,
+            random_seed: None,
             post_window_secs,
             dry_run,
+            breaker: None,
         }
     }
 
+    /// Keep at least `hours` between tweets on the same topic.
+    ///
+    /// When non-zero, topics come from `content::topic_selector` (least
+    /// recently used first) instead of epsilon-greedy selection.
+    pub fn with_min_topic_gap(mut self, hours: u64) -> Self {
+        self.min_topic_gap_hours = hours;
+        self
+    }
+
+    /// Seed the loop's RNG so topic picks and slot jitter are reproducible.
+    pub fn with_random_seed(mut self, seed: Option<u64>) -> Self {
+        self.random_seed = seed;
+        self
+    }
+
     /// Set a topic scorer for epsilon-greedy topic selection.
     ///
     /// When set, 80% of the time the loop picks from top-performing topics
@@ -90,6 +117,20 @@ impl ContentLoop {
         self.thread_poster = Some(poster);
         self
     }
+
+    /// Pause the loop while its persisted error budget is spent.
+    pub fn with_loop_breaker(mut self, breaker: LoopBreaker) -> Self {
+        self.breaker = Some(breaker);
+        self
+    }
+
+    /// RNG for topic picks and slot jitter, seeded by `content.random_seed`.
+    pub(super) fn rng(&self) -> StdRng {
+        match self.random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(&mut rand::rng()),
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -168,6 +209,7 @@ pub(super) mod test_mocks {
 
     pub struct MockStorage {
         pub last_tweet: Mutex<Option<chrono::DateTime<chrono::Utc>>>,
+        pub next_topic: Mutex<Option<String>>,
         pub posted_tweets: Mutex<Vec<(String, String)>>,
         pub actions: Mutex<Vec<(String, String, String)>>,
     }
@@ -176,6 +218,7 @@ pub(super) mod test_mocks {
         pub fn new(last_tweet: Option<chrono::DateTime<chrono::Utc>>) -> Self {
             Self {
                 last_tweet: Mutex::new(last_tweet),
+                next_topic: Mutex::new(None),
                 posted_tweets: Mutex::new(Vec::new()),
                 actions: Mutex::new(Vec::new()),
             }
@@ -210,6 +253,14 @@ pub(super) mod test_mocks {
             Ok(Vec::new())
         }
 
+        async fn next_topic(
+            &self,
+            _topics: &[String],
+            _min_gap: chrono::Duration,
+        ) -> Result<Option<String>, ContentLoopError> {
+            Ok(self.next_topic.lock().expect("lock").clone())
+        }
+
         async fn post_tweet(&self, topic: &str, content: &str) -> Result<(), ContentLoopError> {
             self.posted_tweets
                 .lock()
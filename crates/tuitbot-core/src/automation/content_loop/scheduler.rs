@@ -3,11 +3,11 @@
 //! Implements the `run`, `run_once`, `run_iteration`, `run_slot_iteration`,
 //! and `log_content_result` methods on [`ContentLoop`].
 
-use super::super::schedule::{apply_slot_jitter, schedule_gate, ActiveSchedule};
+use super::super::loop_breaker::{breaker_gate, record_iteration};
+use super::super::schedule::{apply_slot_jitter_with, schedule_gate, ActiveSchedule};
 use super::super::scheduler::LoopScheduler;
 use super::{ContentLoop, ContentResult};
 use rand::seq::IndexedRandom;
-use rand::SeedableRng;
 use std::sync::Arc;
 use tokio_util::sync::CancellationToken;
 
@@ -40,7 +40,7 @@ impl ContentLoop {
             .max(min_recent)
             .min(self.topics.len());
         let mut recent_topics: Vec<String> = Vec::with_capacity(max_recent);
-        let mut rng = rand::rngs::StdRng::from_rng(&mut rand::rng());
+        let mut rng = self.rng();
 
         loop {
             if cancel.is_cancelled() {
@@ -51,6 +51,10 @@ impl ContentLoop {
                 break;
             }
 
+            if !breaker_gate(&self.breaker, "content", &cancel).await {
+                break;
+            }
+
             if slot_mode {
                 // Slot-based scheduling: post at preferred times
                 let sched = schedule.as_ref().expect("slot_mode requires schedule");
@@ -66,7 +70,7 @@ impl ContentLoop {
 
                 match sched.next_unused_slot(&today_posts) {
                     Some((wait, slot)) => {
-                        let jittered_wait = apply_slot_jitter(wait);
+                        let jittered_wait = apply_slot_jitter_with(wait, &mut rng);
                         tracing::info!(
                             slot = %slot.format(),
                             wait_secs = jittered_wait.as_secs(),
@@ -87,6 +91,7 @@ impl ContentLoop {
                             .run_slot_iteration(&mut recent_topics, max_recent, &mut rng)
                             .await;
                         self.log_content_result(&result);
+                        self.record_content_result(&result).await;
                     }
                     None => {
                         // All slots used today — sleep until next active day
@@ -114,6 +119,7 @@ impl ContentLoop {
                     .run_iteration(&mut recent_topics, max_recent, &mut rng)
                     .await;
                 self.log_content_result(&result);
+                self.record_content_result(&result).await;
 
                 tokio::select! {
                     _ = cancel.cancelled() => break,
@@ -125,6 +131,16 @@ impl ContentLoop {
         tracing::info!("Content loop stopped");
     }
 
+    /// Spend or reset the error budget; skipped iterations leave it untouched.
+    async fn record_content_result(&self, result: &ContentResult) {
+        let outcome = match result {
+            ContentResult::Posted { .. } => Ok(()),
+            ContentResult::Failed { error } => Err(error.as_str()),
+            _ => return,
+        };
+        record_iteration(&self.breaker, "content", outcome).await;
+    }
+
     /// Log the result of a content iteration.
     pub(super) fn log_content_result(&self, result: &ContentResult) {
         match result {
@@ -193,8 +209,9 @@ impl ContentLoop {
 
     /// Run a single content generation (for CLI `tuitbot post` command).
     ///
-    /// If `topic` is provided, uses that topic. Otherwise picks a random
-    /// topic from the configured list.
+    /// If `topic` is provided, uses that topic. Otherwise picks the least
+    /// recently used topic outside the minimum topic gap, or a random topic
+    /// from the configured list when no gap is set.
     pub async fn run_once(&self, topic: Option<&str>) -> ContentResult {
         let chosen_topic = match topic {
             Some(t) => t.to_string(),
@@ -202,11 +219,16 @@ impl ContentLoop {
                 if self.topics.is_empty() {
                     return ContentResult::NoTopics;
                 }
-                let mut rng = rand::rng();
-                self.topics
-                    .choose(&mut rng)
-                    .expect("topics is non-empty")
-                    .clone()
+                match self.pick_topic_outside_gap().await {
+                    Some(topic) => topic,
+                    None => {
+                        let mut rng = self.rng();
+                        self.topics
+                            .choose(&mut rng)
+                            .expect("topics is non-empty")
+                            .clone()
+                    }
+                }
             }
         };
 
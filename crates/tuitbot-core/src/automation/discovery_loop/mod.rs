@@ -3,14 +3,18 @@
 //! Searches X using configured keywords, scores each tweet with the
 //! scoring engine, filters by threshold, generates replies for
 //! qualifying tweets, and posts them through the posting queue.
+//! Qualifying tweets can also be liked (`limits.discovery_likes`).
 //! Rotates keywords across iterations to distribute API usage.
 
+use super::loop_breaker::{breaker_gate, record_iteration, LoopBreaker};
 use super::loop_helpers::{
-    ConsecutiveErrorTracker, LoopError, LoopStorage, LoopTweet, PostSender, ReplyGenerator,
-    SafetyChecker, TweetScorer, TweetSearcher,
+    ConsecutiveErrorTracker, ConversationFetcher, LoopError, LoopStorage, LoopTweet, PostSender,
+    ReplyGenerator, SafetyChecker, TweetLiker, TweetScorer, TweetSearcher,
 };
 use super::schedule::{schedule_gate, ActiveSchedule};
 use super::scheduler::LoopScheduler;
+use crate::content::ConversationTweet;
+use crate::safety::SafetyGuard;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -26,6 +30,18 @@ pub struct DiscoveryLoop {
     keywords: Vec<String>,
     threshold: f32,
     dry_run: bool,
+    /// Authenticated user's ID, used to skip the bot's own tweets.
+    own_user_id: Option<String>,
+    /// Fetches parent tweets of replies, with the maximum to fetch.
+    conversation: Option<(Arc<dyn ConversationFetcher>, usize)>,
+    /// Search result pages fetched per keyword.
+    max_pages: usize,
+    /// Stop paging once this many new tweets meet the threshold.
+    enough_candidates: Option<usize>,
+    /// Likes qualifying tweets when set.
+    liker: Option<Arc<dyn TweetLiker>>,
+    /// Error budget checked between iterations of `run`.
+    breaker: Option<LoopBreaker>,
 }
 
 /// Result of processing a single discovered tweet.
@@ -85,9 +101,58 @@ impl DiscoveryLoop {
             keywords,
             threshold,
             dry_run,
+            own_user_id: None,
+            conversation: None,
+            max_pages: 1,
+            enough_candidates: None,
+            liker: None,
+            breaker: None,
         }
     }
 
+    /// Set the authenticated user's ID so the loop never replies to itself.
+    ///
+    /// An empty ID (unknown user, e.g. scraper mode) disables the check.
+    pub fn with_own_user_id(mut self, own_user_id: impl Into<String>) -> Self {
+        let own_user_id = own_user_id.into();
+        self.own_user_id = (!own_user_id.is_empty()).then_some(own_user_id);
+        self
+    }
+
+    /// Give replies the conversation they join: when a candidate is itself a
+    /// reply, up to `max` ancestors are fetched and passed to the generator.
+    pub fn with_conversation_context(
+        mut self,
+        fetcher: Arc<dyn ConversationFetcher>,
+        max: usize,
+    ) -> Self {
+        self.conversation = Some((fetcher, max));
+        self
+    }
+
+    /// Follow search pagination for up to `max_pages` pages per keyword.
+    ///
+    /// Paging stops early once `enough_candidates` new tweets meet the
+    /// threshold, or when the search rate limit is spent.
+    pub fn with_pagination(mut self, max_pages: usize, enough_candidates: Option<usize>) -> Self {
+        self.max_pages = max_pages.max(1);
+        self.enough_candidates = enough_candidates;
+        self
+    }
+
+    /// Like every tweet that meets the threshold, whether or not it gets a
+    /// reply. The liker enforces the daily like cap; dry runs like nothing.
+    pub fn with_likes(mut self, liker: Arc<dyn TweetLiker>) -> Self {
+        self.liker = Some(liker);
+        self
+    }
+
+    /// Pause the loop while its persisted error budget is spent.
+    pub fn with_loop_breaker(mut self, breaker: LoopBreaker) -> Self {
+        self.breaker = Some(breaker);
+        self
+    }
+
     /// Run the continuous discovery loop until cancellation.
     ///
     /// Rotates through keywords across iterations to distribute API usage.
@@ -122,6 +187,10 @@ impl DiscoveryLoop {
                 break;
             }
 
+            if !breaker_gate(&self.breaker, "discovery", &cancel).await {
+                break;
+            }
+
             // Select next keyword (round-robin)
             let keyword = &self.keywords[keyword_index % self.keywords.len()];
             keyword_index += 1;
@@ -129,6 +198,7 @@ impl DiscoveryLoop {
             match self.search_and_process(keyword, None).await {
                 Ok((_results, summary)) => {
                     error_tracker.record_success();
+                    record_iteration(&self.breaker, "discovery", Ok(())).await;
                     if summary.tweets_found > 0 {
                         tracing::info!(
                             keyword = %keyword,
@@ -140,6 +210,7 @@ impl DiscoveryLoop {
                     }
                 }
                 Err(e) => {
+                    record_iteration(&self.breaker, "discovery", Err(&e.to_string())).await;
                     let should_pause = error_tracker.record_error();
                     tracing::warn!(
                         keyword = %keyword,
@@ -239,7 +310,7 @@ impl DiscoveryLoop {
         limit: Option<usize>,
     ) -> Result<(Vec<DiscoveryResult>, DiscoverySummary), LoopError> {
         tracing::info!(keyword = %keyword, "Searching keyword");
-        let tweets = self.searcher.search_tweets(keyword).await?;
+        let tweets = self.search_pages(keyword, limit).await?;
 
         let mut summary = DiscoverySummary {
             tweets_found: tweets.len(),
@@ -278,6 +349,71 @@ impl DiscoveryLoop {
         Ok((results, summary))
     }
 
+    /// Fetch search results for `keyword`, following pagination.
+    ///
+    /// Every page claims a search request first; if the first one is denied
+    /// the search fails as rate limited. A failed later page ends paging but
+    /// keeps the tweets already fetched.
+    async fn search_pages(
+        &self,
+        keyword: &str,
+        limit: Option<usize>,
+    ) -> Result<Vec<LoopTweet>, LoopError> {
+        if !self.safety.acquire_search().await {
+            return Err(LoopError::RateLimited { retry_after: None });
+        }
+        let (mut tweets, mut next_token) = self.searcher.search_tweets_page(keyword, None).await?;
+        let mut pages = 1;
+
+        while let Some(token) = next_token.take() {
+            if pages >= self.max_pages || limit.is_some_and(|n| tweets.len() >= n) {
+                break;
+            }
+            if let Some(enough) = self.enough_candidates {
+                let candidates = self.count_candidates(&tweets).await;
+                if candidates >= enough {
+                    tracing::debug!(keyword = %keyword, pages, candidates, "Enough candidates, stopping pagination");
+                    break;
+                }
+            }
+            if !self.safety.acquire_search().await {
+                tracing::debug!(keyword = %keyword, pages, "Search rate limit reached, stopping pagination");
+                break;
+            }
+
+            match self
+                .searcher
+                .search_tweets_page(keyword, Some(&token))
+                .await
+            {
+                Ok((page, token)) => {
+                    tweets.extend(page);
+                    next_token = token;
+                    pages += 1;
+                }
+                Err(e) => {
+                    tracing::warn!(keyword = %keyword, page = pages + 1, error = %e, "Search page failed, using results so far");
+                    break;
+                }
+            }
+        }
+
+        Ok(tweets)
+    }
+
+    /// Count tweets not yet discovered that meet the score threshold.
+    async fn count_candidates(&self, tweets: &[LoopTweet]) -> usize {
+        let mut count = 0;
+        for tweet in tweets {
+            if self.scorer.score(tweet).await.meets_threshold
+                && !self.storage.tweet_exists(&tweet.id).await.unwrap_or(false)
+            {
+                count += 1;
+            }
+        }
+        count
+    }
+
     /// Process a single discovered tweet: dedup, score, generate reply, post.
     pub(crate) async fn process_tweet(&self, tweet: &LoopTweet, keyword: &str) -> DiscoveryResult {
         // Check if already discovered (dedup)
@@ -297,7 +433,7 @@ impl DiscoveryLoop {
         }
 
         // Score the tweet
-        let score_result = self.scorer.score(tweet);
+        let score_result = self.scorer.score(tweet).await;
 
         // Store discovered tweet (even if below threshold, useful for analytics)
         if let Err(e) = self
@@ -323,6 +459,26 @@ impl DiscoveryLoop {
         }
 
         // Safety checks
+        if let Err(reason) =
+            SafetyGuard::check_protected_author(&tweet.author_id, tweet.author_protected)
+        {
+            return DiscoveryResult::Skipped {
+                tweet_id: tweet.id.clone(),
+                reason: reason.to_string(),
+            };
+        }
+
+        if let Some(own_user_id) = &self.own_user_id {
+            if let Err(reason) = SafetyGuard::check_self_reply(&tweet.author_id, own_user_id) {
+                return DiscoveryResult::Skipped {
+                    tweet_id: tweet.id.clone(),
+                    reason: reason.to_string(),
+                };
+            }
+        }
+
+        self.like(tweet).await;
+
         if self.safety.has_replied_to(&tweet.id).await {
             return DiscoveryResult::Skipped {
                 tweet_id: tweet.id.clone(),
@@ -337,10 +493,28 @@ impl DiscoveryLoop {
             };
         }
 
-        // Generate reply with vault context (product mention always on for discovery)
+        if !self
+            .safety
+            .allows_conversation(tweet.conversation_id.as_deref())
+            .await
+        {
+            return DiscoveryResult::Skipped {
+                tweet_id: tweet.id.clone(),
+                reason: "conversation limit reached".to_string(),
+            };
+        }
+
+        // Generate reply with vault and conversation context
+        // (product mention always on for discovery)
+        let conversation = self.fetch_conversation(tweet).await;
         let reply_output = match self
             .generator
-            .generate_reply_with_rag(&tweet.text, &tweet.author_username, true)
+            .generate_reply_in_conversation(
+                &tweet.text,
+                &tweet.author_username,
+                true,
+                &conversation,
+            )
             .await
         {
             Ok(output) => output,
@@ -358,6 +532,13 @@ impl DiscoveryLoop {
         };
         let reply_text = reply_output.text;
 
+        if !self.safety.allows_reply_text(&reply_text).await {
+            return DiscoveryResult::Skipped {
+                tweet_id: tweet.id.clone(),
+                reason: "banned phrase".to_string(),
+            };
+        }
+
         tracing::info!(
             author = %tweet.author_username,
             score = format!("{:.0}", score_result.total),
@@ -388,7 +569,11 @@ impl DiscoveryLoop {
                 )
                 .await;
         } else {
-            if let Err(e) = self.poster.send_reply(&tweet.id, &reply_text).await {
+            if let Err(e) = self
+                .poster
+                .send_reply_with_framework(&tweet.id, &reply_text, reply_output.framework.as_ref())
+                .await
+            {
                 tracing::error!(tweet_id = %tweet.id, error = %e, "Failed to send reply");
                 return DiscoveryResult::Failed {
                     tweet_id: tweet.id.clone(),
@@ -396,7 +581,11 @@ impl DiscoveryLoop {
                 };
             }
 
-            if let Err(e) = self.safety.record_reply(&tweet.id, &reply_text).await {
+            if let Err(e) = self
+                .safety
+                .record_reply(&tweet.id, tweet.conversation_id.as_deref(), &reply_text)
+                .await
+            {
                 tracing::warn!(tweet_id = %tweet.id, error = %e, "Failed to record reply");
             }
 
@@ -422,6 +611,58 @@ impl DiscoveryLoop {
             reply_text,
         }
     }
+
+    /// Like `tweet` when likes are enabled. Best-effort: failures are
+    /// logged and never stop the reply.
+    async fn like(&self, tweet: &LoopTweet) {
+        let Some(liker) = &self.liker else {
+            return;
+        };
+        if self.dry_run {
+            return;
+        }
+        match liker.like_tweet(tweet).await {
+            Ok(true) => {
+                let _ = self
+                    .storage
+                    .log_action(
+                        "discovery_like",
+                        "success",
+                        &format!("Liked tweet {} by @{}", tweet.id, tweet.author_username),
+                    )
+                    .await;
+            }
+            Ok(false) => {
+                tracing::debug!(tweet_id = %tweet.id, "Like skipped (cap reached or own tweet)");
+            }
+            Err(e) => {
+                tracing::warn!(tweet_id = %tweet.id, error = %e, "Failed to like tweet");
+            }
+        }
+    }
+
+    /// Fetch the ancestors of `tweet` when conversation context is enabled.
+    ///
+    /// Failures are logged and yield no context rather than failing the reply.
+    async fn fetch_conversation(&self, tweet: &LoopTweet) -> Vec<ConversationTweet> {
+        let Some((fetcher, max)) = &self.conversation else {
+            return Vec::new();
+        };
+        if tweet.in_reply_to_id.is_none() {
+            return Vec::new();
+        }
+        match fetcher.fetch_ancestors(tweet, *max).await {
+            Ok(ancestors) => ancestors,
+            Err(e) => {
+                tracing::warn!(
+                    tweet_id = %tweet.id,
+                    error = %e,
+                    "Failed to fetch conversation context, replying without it"
+                );
+                Vec::new()
+            }
+        }
+    }
 }
 
 /// Truncate a string for display.
@@ -58,6 +58,49 @@ async fn search_and_process_dry_run() {
     assert_eq!(poster.sent_count(), 0);
 }
 
+#[tokio::test]
+async fn search_and_process_skips_own_tweets() {
+    let tweets = vec![test_tweet("100", "me"), test_tweet("101", "alice")];
+    let (discovery, poster, _) = build_loop(tweets, 85.0, true, false);
+    let discovery = discovery.with_own_user_id("uid_me");
+
+    let (results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+
+    assert_eq!(summary.replied, 1);
+    assert_eq!(summary.skipped, 1);
+    assert!(matches!(
+        &results[0],
+        DiscoveryResult::Skipped { tweet_id, reason }
+            if tweet_id == "100" && reason == "Cannot reply to own tweets"
+    ));
+    assert_eq!(poster.sent_count(), 1);
+}
+
+#[tokio::test]
+async fn search_and_process_skips_protected_authors() {
+    let with_flag = |id: &str, author: &str, flag: Option<bool>| LoopTweet {
+        author_protected: flag,
+        ..test_tweet(id, author)
+    };
+    let tweets = vec![
+        with_flag("100", "locked", Some(true)),
+        with_flag("101", "open", Some(false)),
+        with_flag("102", "unknown", None),
+    ];
+    let (discovery, poster, _) = build_loop(tweets, 85.0, true, false);
+
+    let (results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+
+    assert_eq!(summary.replied, 2);
+    assert_eq!(summary.skipped, 1);
+    assert!(matches!(
+        &results[0],
+        DiscoveryResult::Skipped { tweet_id, reason }
+            if tweet_id == "100" && reason == "Author's tweets are protected"
+    ));
+    assert_eq!(poster.sent_count(), 2);
+}
+
 #[tokio::test]
 async fn search_and_process_skips_existing() {
     let tweets = vec![test_tweet("100", "alice")];
@@ -193,3 +236,133 @@ async fn run_once_all_keywords_fail_returns_error() {
     let result = discovery.run_once(None).await;
     assert!(result.is_err());
 }
+
+// --- Conversation context ---
+
+/// Generator that records the conversation it was given.
+struct ConversationGenerator {
+    seen: Mutex<Vec<ConversationTweet>>,
+}
+
+#[async_trait::async_trait]
+impl ReplyGenerator for ConversationGenerator {
+    async fn generate_reply(
+        &self,
+        _tweet_text: &str,
+        _author: &str,
+        _mention_product: bool,
+    ) -> Result<String, LoopError> {
+        Ok("Reply".to_string())
+    }
+
+    async fn generate_reply_in_conversation(
+        &self,
+        _tweet_text: &str,
+        _author: &str,
+        _mention_product: bool,
+        conversation: &[ConversationTweet],
+    ) -> Result<crate::automation::loop_helpers::ReplyOutput, LoopError> {
+        *self.seen.lock().expect("lock") = conversation.to_vec();
+        Ok(crate::automation::loop_helpers::ReplyOutput {
+            text: "Reply".to_string(),
+            vault_citations: vec![],
+        })
+    }
+}
+
+struct MockConversation {
+    fail: bool,
+    requested_max: Mutex<Option<usize>>,
+}
+
+#[async_trait::async_trait]
+impl ConversationFetcher for MockConversation {
+    async fn fetch_ancestors(
+        &self,
+        _tweet: &LoopTweet,
+        max: usize,
+    ) -> Result<Vec<ConversationTweet>, LoopError> {
+        *self.requested_max.lock().expect("lock") = Some(max);
+        if self.fail {
+            return Err(LoopError::Other("gone".to_string()));
+        }
+        Ok(vec![ConversationTweet {
+            author: "root".to_string(),
+            text: "Original question".to_string(),
+        }])
+    }
+}
+
+fn conversation_loop(
+    fail: bool,
+) -> (
+    DiscoveryLoop,
+    Arc<ConversationGenerator>,
+    Arc<MockConversation>,
+    Arc<MockPoster>,
+) {
+    let generator = Arc::new(ConversationGenerator {
+        seen: Mutex::new(Vec::new()),
+    });
+    let fetcher = Arc::new(MockConversation {
+        fail,
+        requested_max: Mutex::new(None),
+    });
+    let poster = Arc::new(MockPoster::new());
+    let discovery = DiscoveryLoop::new(
+        Arc::new(MockSearcher { results: vec![] }),
+        Arc::new(MockScorer {
+            score: 85.0,
+            meets_threshold: true,
+        }),
+        generator.clone(),
+        Arc::new(MockSafety::new(true)),
+        Arc::new(MockStorage::new()),
+        poster.clone(),
+        vec!["rust".to_string()],
+        70.0,
+        false,
+    )
+    .with_conversation_context(fetcher.clone(), 2);
+    (discovery, generator, fetcher, poster)
+}
+
+#[tokio::test]
+async fn conversation_context_passed_to_generator_for_replies() {
+    let (discovery, generator, fetcher, _) = conversation_loop(false);
+    let mut tweet = test_tweet("200", "alice");
+    tweet.in_reply_to_id = Some("199".to_string());
+
+    let result = discovery.process_tweet(&tweet, "rust").await;
+
+    assert!(matches!(result, DiscoveryResult::Replied { .. }));
+    assert_eq!(*fetcher.requested_max.lock().expect("lock"), Some(2));
+    let seen = generator.seen.lock().expect("lock");
+    assert_eq!(seen.len(), 1);
+    assert_eq!(seen[0].text, "Original question");
+}
+
+#[tokio::test]
+async fn conversation_context_skipped_for_top_level_tweets() {
+    let (discovery, generator, fetcher, _) = conversation_loop(false);
+
+    discovery
+        .process_tweet(&test_tweet("201", "alice"), "rust")
+        .await;
+
+    assert!(fetcher.requested_max.lock().expect("lock").is_none());
+    assert!(generator.seen.lock().expect("lock").is_empty());
+}
+
+#[tokio::test]
+async fn conversation_fetch_failure_still_replies() {
+    let (discovery, generator, _, poster) = conversation_loop(true);
+    let mut tweet = test_tweet("202", "alice");
+    tweet.in_reply_to_id = Some("199".to_string());
+
+    let result = discovery.process_tweet(&tweet, "rust").await;
+
+    assert!(matches!(result, DiscoveryResult::Replied { .. }));
+    assert!(generator.seen.lock().expect("lock").is_empty());
+    assert_eq!(poster.sent_count(), 1);
+}
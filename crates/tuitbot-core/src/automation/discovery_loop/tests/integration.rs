@@ -197,3 +197,53 @@ async fn process_tweet_post_failure_returns_failed() {
     assert_eq!(summary.failed, 1);
     assert!(matches!(results[0], DiscoveryResult::Failed { .. }));
 }
+
+#[tokio::test]
+async fn second_reply_in_same_conversation_is_refused() {
+    use crate::automation::adapters::SafetyAdapter;
+    use crate::config::{IntervalsConfig, LimitsConfig};
+    use crate::safety::SafetyGuard;
+    use crate::storage::{init_test_db, rate_limits};
+
+    let pool = init_test_db().await.expect("init db");
+    rate_limits::init_rate_limits(&pool, &LimitsConfig::default(), &IntervalsConfig::default())
+        .await
+        .expect("init rate limits");
+    let safety = SafetyAdapter::new(Arc::new(SafetyGuard::new(pool.clone())), pool)
+        .with_conversation_limit(1);
+
+    let mut first = test_tweet("200", "alice");
+    first.conversation_id = Some("conv_1".to_string());
+    let mut second = test_tweet("201", "bob");
+    second.conversation_id = Some("conv_1".to_string());
+
+    let poster = Arc::new(MockPoster::new());
+    let discovery = DiscoveryLoop::new(
+        Arc::new(MockSearcher {
+            results: vec![first, second],
+        }),
+        Arc::new(MockScorer {
+            score: 90.0,
+            meets_threshold: true,
+        }),
+        Arc::new(MockGenerator {
+            reply: "Great insight!".to_string(),
+        }),
+        Arc::new(safety),
+        Arc::new(MockStorage::new()),
+        poster.clone(),
+        vec!["rust".to_string()],
+        70.0,
+        false,
+    );
+
+    let (results, summary) = discovery.search_and_process("rust", None).await.unwrap();
+
+    assert_eq!(summary.replied, 1);
+    assert_eq!(poster.sent_count(), 1);
+    assert!(matches!(
+        &results[1],
+        DiscoveryResult::Skipped { tweet_id, reason }
+            if tweet_id == "201" && reason == "conversation limit reached"
+    ));
+}
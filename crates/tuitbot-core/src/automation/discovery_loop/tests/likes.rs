@@ -0,0 +1,79 @@
+//! Liking qualifying tweets (`limits.discovery_likes`).
+
+use super::*;
+
+/// Likes up to `cap` tweets, then reports the cap as spent.
+struct MockLiker {
+    cap: usize,
+    liked: Mutex<Vec<String>>,
+}
+
+impl MockLiker {
+    fn new(cap: usize) -> Self {
+        Self {
+            cap,
+            liked: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn liked(&self) -> Vec<String> {
+        self.liked.lock().expect("lock").clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl TweetLiker for MockLiker {
+    async fn like_tweet(&self, tweet: &LoopTweet) -> Result<bool, LoopError> {
+        let mut liked = self.liked.lock().expect("lock");
+        if liked.len() >= self.cap {
+            return Ok(false);
+        }
+        liked.push(tweet.id.clone());
+        Ok(true)
+    }
+}
+
+#[tokio::test]
+async fn likes_qualifying_tweets_up_to_cap() {
+    let tweets = vec![
+        test_tweet("100", "alice"),
+        test_tweet("101", "bob"),
+        test_tweet("102", "carol"),
+    ];
+    let (discovery, poster, storage) = build_loop(tweets, 85.0, true, false);
+    let liker = Arc::new(MockLiker::new(2));
+    let discovery = discovery.with_likes(liker.clone());
+
+    let (_, summary) = discovery.search_and_process("rust", None).await.unwrap();
+
+    assert_eq!(liker.liked(), vec!["100", "101"]);
+    // Hitting the like cap does not stop replies.
+    assert_eq!(summary.replied, 3);
+    assert_eq!(poster.sent_count(), 3);
+    let actions = storage.actions.lock().expect("lock");
+    assert_eq!(
+        actions.iter().filter(|a| a.0 == "discovery_like").count(),
+        2
+    );
+}
+
+#[tokio::test]
+async fn does_not_like_below_threshold_own_or_dry_run() {
+    let liker = Arc::new(MockLiker::new(10));
+
+    let (discovery, _, _) = build_loop(vec![test_tweet("100", "alice")], 40.0, false, false);
+    let discovery = discovery.with_likes(liker.clone());
+    discovery.search_and_process("rust", None).await.unwrap();
+
+    let (discovery, _, _) = build_loop(vec![test_tweet("101", "me")], 85.0, true, false);
+    let discovery = discovery
+        .with_own_user_id("uid_me")
+        .with_likes(liker.clone());
+    discovery.search_and_process("rust", None).await.unwrap();
+
+    let (discovery, _, _) = build_loop(vec![test_tweet("102", "alice")], 85.0, true, true);
+    let discovery = discovery.with_likes(liker.clone());
+    discovery.search_and_process("rust", None).await.unwrap();
+
+    assert!(liker.liked().is_empty());
+}
@@ -2,8 +2,9 @@
 
 use super::super::*;
 use super::truncate;
-use crate::automation::loop_helpers::LoopError;
+use crate::automation::loop_helpers::{ConversationFetcher, LoopError};
 use crate::automation::ScoreResult;
+use crate::content::ConversationTweet;
 use std::sync::Mutex;
 
 // --- Mock implementations ---
@@ -35,8 +36,9 @@ struct MockScorer {
     meets_threshold: bool,
 }
 
+#[async_trait::async_trait]
 impl TweetScorer for MockScorer {
-    fn score(&self, _tweet: &LoopTweet) -> ScoreResult {
+    async fn score(&self, _tweet: &LoopTweet) -> ScoreResult {
         ScoreResult {
             total: self.score,
             meets_threshold: self.meets_threshold,
@@ -86,7 +88,12 @@ impl SafetyChecker for MockSafety {
             .expect("lock")
             .contains(&tweet_id.to_string())
     }
-    async fn record_reply(&self, tweet_id: &str, _content: &str) -> Result<(), LoopError> {
+    async fn record_reply(
+        &self,
+        tweet_id: &str,
+        _conversation_id: Option<&str>,
+        _content: &str,
+    ) -> Result<(), LoopError> {
         self.replied_ids
             .lock()
             .expect("lock")
@@ -187,6 +194,9 @@ fn test_tweet(id: &str, author: &str) -> LoopTweet {
         likes: 20,
         retweets: 5,
         replies: 3,
+        in_reply_to_id: None,
+        conversation_id: None,
+        author_protected: None,
     }
 }
 
@@ -221,3 +231,5 @@ fn build_loop(
 
 mod core_tests;
 mod integration;
+mod likes;
+mod pagination;
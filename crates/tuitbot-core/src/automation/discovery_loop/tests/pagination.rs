@@ -0,0 +1,168 @@
+//! Search pagination: page cap, early stop, and the search rate limit.
+
+use super::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Serves `pages` in order, handing out `p<index>` pagination tokens.
+struct PagedSearcher {
+    pages: Vec<Vec<LoopTweet>>,
+    tokens_seen: Mutex<Vec<Option<String>>>,
+}
+
+impl PagedSearcher {
+    fn new(pages: usize, per_page: usize) -> Self {
+        let pages = (0..pages)
+            .map(|p| {
+                (0..per_page)
+                    .map(|i| test_tweet(&format!("{p}-{i}"), "alice"))
+                    .collect()
+            })
+            .collect();
+        Self {
+            pages,
+            tokens_seen: Mutex::new(Vec::new()),
+        }
+    }
+
+    fn requests(&self) -> Vec<Option<String>> {
+        self.tokens_seen.lock().expect("lock").clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl TweetSearcher for PagedSearcher {
+    async fn search_tweets(&self, query: &str) -> Result<Vec<LoopTweet>, LoopError> {
+        Ok(self.search_tweets_page(query, None).await?.0)
+    }
+
+    async fn search_tweets_page(
+        &self,
+        _query: &str,
+        pagination_token: Option<&str>,
+    ) -> Result<(Vec<LoopTweet>, Option<String>), LoopError> {
+        self.tokens_seen
+            .lock()
+            .expect("lock")
+            .push(pagination_token.map(String::from));
+        let index = pagination_token
+            .and_then(|t| t.strip_prefix('p'))
+            .map_or(0, |n| n.parse().expect("page token"));
+        let next = (index + 1 < self.pages.len()).then(|| format!("p{}", index + 1));
+        Ok((self.pages[index].clone(), next))
+    }
+}
+
+/// Allows replies and at most `searches` search requests.
+struct SearchBudgetSafety {
+    searches: AtomicUsize,
+}
+
+#[async_trait::async_trait]
+impl SafetyChecker for SearchBudgetSafety {
+    async fn can_reply(&self) -> bool {
+        true
+    }
+    async fn has_replied_to(&self, _tweet_id: &str) -> bool {
+        false
+    }
+    async fn record_reply(
+        &self,
+        _tweet_id: &str,
+        _conversation_id: Option<&str>,
+        _content: &str,
+    ) -> Result<(), LoopError> {
+        Ok(())
+    }
+    async fn acquire_search(&self) -> bool {
+        self.searches
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |n| n.checked_sub(1))
+            .is_ok()
+    }
+}
+
+fn paged_loop(
+    searcher: Arc<PagedSearcher>,
+    meets_threshold: bool,
+    search_budget: usize,
+) -> DiscoveryLoop {
+    DiscoveryLoop::new(
+        searcher,
+        Arc::new(MockScorer {
+            score: if meets_threshold { 90.0 } else { 10.0 },
+            meets_threshold,
+        }),
+        Arc::new(MockGenerator {
+            reply: "Great insight!".to_string(),
+        }),
+        Arc::new(SearchBudgetSafety {
+            searches: AtomicUsize::new(search_budget),
+        }),
+        Arc::new(MockStorage::new()),
+        Arc::new(MockPoster::new()),
+        vec!["rust".to_string()],
+        70.0,
+        true,
+    )
+}
+
+#[tokio::test]
+async fn single_page_by_default() {
+    let searcher = Arc::new(PagedSearcher::new(3, 2));
+    let discovery = paged_loop(searcher.clone(), false, 100);
+
+    let (_, summary) = discovery.search_and_process("rust", None).await.unwrap();
+    assert_eq!(summary.tweets_found, 2);
+    assert_eq!(searcher.requests(), vec![None]);
+}
+
+#[tokio::test]
+async fn pagination_stops_at_page_cap() {
+    let searcher = Arc::new(PagedSearcher::new(5, 2));
+    let discovery = paged_loop(searcher.clone(), false, 100).with_pagination(3, Some(1));
+
+    let (_, summary) = discovery.search_and_process("rust", None).await.unwrap();
+    assert_eq!(summary.tweets_found, 6);
+    assert_eq!(
+        searcher.requests(),
+        vec![None, Some("p1".to_string()), Some("p2".to_string())]
+    );
+}
+
+#[tokio::test]
+async fn pagination_stops_when_candidates_suffice() {
+    let searcher = Arc::new(PagedSearcher::new(5, 2));
+    let discovery = paged_loop(searcher.clone(), true, 100).with_pagination(5, Some(3));
+
+    let (_, summary) = discovery.search_and_process("rust", None).await.unwrap();
+    // Page 1 holds 2 candidates, page 2 brings it to 4 >= 3.
+    assert_eq!(summary.tweets_found, 4);
+    assert_eq!(searcher.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn pagination_stops_at_last_page() {
+    let searcher = Arc::new(PagedSearcher::new(2, 2));
+    let discovery = paged_loop(searcher.clone(), false, 100).with_pagination(10, None);
+
+    let (_, summary) = discovery.search_and_process("rust", None).await.unwrap();
+    assert_eq!(summary.tweets_found, 4);
+    assert_eq!(searcher.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn pagination_respects_search_rate_limit() {
+    let searcher = Arc::new(PagedSearcher::new(5, 2));
+    let discovery = paged_loop(searcher.clone(), false, 2).with_pagination(5, None);
+
+    let (_, summary) = discovery.search_and_process("rust", None).await.unwrap();
+    assert_eq!(summary.tweets_found, 4);
+    assert_eq!(searcher.requests().len(), 2);
+
+    // Budget spent: the next search fails as rate limited without a request.
+    let err = discovery
+        .search_and_process("rust", None)
+        .await
+        .unwrap_err();
+    assert!(matches!(err, LoopError::RateLimited { .. }));
+    assert_eq!(searcher.requests().len(), 2);
+}
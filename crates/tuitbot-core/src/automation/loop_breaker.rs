@@ -0,0 +1,390 @@
+//! Per-loop error budgets.
+//!
+//! Where [`CircuitBreaker`](super::circuit_breaker::CircuitBreaker) guards
+//! the shared mutation path in memory, a loop breaker pauses a single
+//! automation loop that keeps failing (bad keywords, persistent API errors)
+//! and persists its state in `loop_breakers`, so one-shot `tuitbot tick`
+//! runs honour it as well as long-lived runtimes.
+//!
+//! Closed → Open after `failure_threshold` consecutive failures within
+//! `window_seconds`. Once the cooldown expires the breaker is HalfOpen and
+//! the next run is a probe: success closes it, failure re-opens it with a
+//! doubled cooldown (capped at `max_cooldown_seconds`).
+//!
+//! Long-running loops hold a breaker via `with_loop_breaker` and call
+//! [`breaker_gate`] before each iteration and [`record_iteration`] after it.
+
+use chrono::{DateTime, Duration, Utc};
+use tokio_util::sync::CancellationToken;
+
+use super::circuit_breaker::BreakerState;
+use crate::config::LoopBreakerConfig;
+use crate::error::StorageError;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::loop_breakers::{self, LoopBreakerRow};
+use crate::storage::DbPool;
+
+/// Whether a loop may run now.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LoopGate {
+    /// Run the loop (breaker closed or half-open).
+    Run,
+    /// Skip the loop until `until`.
+    Paused {
+        until: DateTime<Utc>,
+        consecutive_failures: u32,
+    },
+}
+
+/// Persistent error budgets for the loops of one account.
+#[derive(Clone)]
+pub struct LoopBreaker {
+    pool: DbPool,
+    account_id: String,
+    config: LoopBreakerConfig,
+}
+
+impl LoopBreaker {
+    /// Create a breaker for the default account.
+    pub fn new(pool: DbPool, config: LoopBreakerConfig) -> Self {
+        Self {
+            pool,
+            account_id: DEFAULT_ACCOUNT_ID.to_string(),
+            config,
+        }
+    }
+
+    /// Scope the breaker to a specific account.
+    pub fn with_account_id(mut self, account_id: impl Into<String>) -> Self {
+        self.account_id = account_id.into();
+        self
+    }
+
+    /// Whether `loop_name` may run at `now`.
+    pub async fn gate(
+        &self,
+        loop_name: &str,
+        now: DateTime<Utc>,
+    ) -> Result<LoopGate, StorageError> {
+        Ok(gate(&self.load(loop_name).await?, now))
+    }
+
+    /// Record a successful run, resetting the budget.
+    pub async fn record_success(&self, loop_name: &str) -> Result<(), StorageError> {
+        let mut row = self.load(loop_name).await?;
+        if row == self.empty_row(loop_name) {
+            return Ok(());
+        }
+        if state(&row, Utc::now()) != BreakerState::Closed {
+            tracing::info!(loop_name, "Loop breaker closed — loop resumed");
+        }
+        reset(&mut row);
+        loop_breakers::save_loop_breaker(&self.pool, &row).await
+    }
+
+    /// Record a failed run. Returns the gate for the next run.
+    pub async fn record_failure(
+        &self,
+        loop_name: &str,
+        error: &str,
+        now: DateTime<Utc>,
+    ) -> Result<LoopGate, StorageError> {
+        let mut row = self.load(loop_name).await?;
+        if record_failure(&mut row, &self.config, error, now) {
+            tracing::warn!(
+                loop_name,
+                consecutive_failures = row.consecutive_failures,
+                open_until = row.open_until.as_deref().unwrap_or_default(),
+                error,
+                "Loop breaker opened — loop paused"
+            );
+        }
+        loop_breakers::save_loop_breaker(&self.pool, &row).await?;
+        Ok(gate(&row, now))
+    }
+
+    async fn load(&self, loop_name: &str) -> Result<LoopBreakerRow, StorageError> {
+        Ok(
+            loop_breakers::get_loop_breaker_for(&self.pool, &self.account_id, loop_name)
+                .await?
+                .unwrap_or_else(|| self.empty_row(loop_name)),
+        )
+    }
+
+    fn empty_row(&self, loop_name: &str) -> LoopBreakerRow {
+        LoopBreakerRow {
+            account_id: self.account_id.clone(),
+            loop_name: loop_name.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+/// Async gate that sleeps while `loop_name`'s breaker is open.
+///
+/// Returns `true` if the loop should continue, `false` if cancelled.
+/// If `breaker` is `None`, always returns `true` immediately. Storage errors
+/// are logged and never block the loop.
+pub async fn breaker_gate(
+    breaker: &Option<LoopBreaker>,
+    loop_name: &str,
+    cancel: &CancellationToken,
+) -> bool {
+    let Some(breaker) = breaker else {
+        return true;
+    };
+
+    loop {
+        let now = Utc::now();
+        let until = match breaker.gate(loop_name, now).await {
+            Ok(LoopGate::Run) => return true,
+            Ok(LoopGate::Paused { until, .. }) => until,
+            Err(e) => {
+                tracing::warn!(loop_name, error = %e, "Failed to read loop breaker");
+                return true;
+            }
+        };
+
+        let wait = (until - now).to_std().unwrap_or_default();
+        tracing::info!(
+            loop_name,
+            wait_secs = wait.as_secs(),
+            "Loop paused by its error budget, sleeping until the cooldown ends"
+        );
+        tokio::select! {
+            _ = cancel.cancelled() => return false,
+            _ = tokio::time::sleep(wait) => {},
+        }
+    }
+}
+
+/// Record the outcome of one iteration of a long-running loop: `Ok` resets
+/// the budget, `Err` spends it. Does nothing when `breaker` is `None`.
+pub async fn record_iteration(
+    breaker: &Option<LoopBreaker>,
+    loop_name: &str,
+    outcome: Result<(), &str>,
+) {
+    let Some(breaker) = breaker else {
+        return;
+    };
+
+    let recorded = match outcome {
+        Ok(()) => breaker.record_success(loop_name).await,
+        Err(error) => breaker
+            .record_failure(loop_name, error, Utc::now())
+            .await
+            .map(|_| ()),
+    };
+    if let Err(e) = recorded {
+        tracing::warn!(loop_name, error = %e, "Failed to update loop breaker");
+    }
+}
+
+/// Breaker state of `row` at `now`.
+pub fn state(row: &LoopBreakerRow, now: DateTime<Utc>) -> BreakerState {
+    match parse(row.open_until.as_deref()) {
+        Some(until) if now < until => BreakerState::Open,
+        Some(_) => BreakerState::HalfOpen,
+        None => BreakerState::Closed,
+    }
+}
+
+fn gate(row: &LoopBreakerRow, now: DateTime<Utc>) -> LoopGate {
+    match parse(row.open_until.as_deref()) {
+        Some(until) if now < until => LoopGate::Paused {
+            until,
+            consecutive_failures: row.consecutive_failures as u32,
+        },
+        _ => LoopGate::Run,
+    }
+}
+
+/// Apply a failure to `row`. Returns `true` when this failure opened the breaker.
+fn record_failure(
+    row: &mut LoopBreakerRow,
+    config: &LoopBreakerConfig,
+    error: &str,
+    now: DateTime<Utc>,
+) -> bool {
+    row.last_error = Some(error.to_string());
+    let probe_failed = state(row, now) == BreakerState::HalfOpen;
+
+    let window_expired = match parse(row.window_started_at.as_deref()) {
+        Some(start) => now - start > seconds(config.window_seconds),
+        None => true,
+    };
+    if window_expired && !probe_failed {
+        row.consecutive_failures = 0;
+        row.window_started_at = Some(now.to_rfc3339());
+    }
+    row.consecutive_failures += 1;
+
+    if !probe_failed && row.consecutive_failures < i64::from(config.failure_threshold.max(1)) {
+        return false;
+    }
+
+    row.trips += 1;
+    let doublings = (row.trips - 1).clamp(0, 20) as u32;
+    let cooldown = config
+        .cooldown_seconds
+        .saturating_mul(1u64 << doublings)
+        .min(config.max_cooldown_seconds.max(config.cooldown_seconds));
+    row.open_until = Some((now + seconds(cooldown)).to_rfc3339());
+    true
+}
+
+fn reset(row: &mut LoopBreakerRow) {
+    row.consecutive_failures = 0;
+    row.window_started_at = None;
+    row.open_until = None;
+    row.trips = 0;
+    row.last_error = None;
+}
+
+fn parse(ts: Option<&str>) -> Option<DateTime<Utc>> {
+    ts.and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+        .map(|t| t.with_timezone(&Utc))
+}
+
+fn seconds(secs: u64) -> Duration {
+    Duration::seconds(i64::try_from(secs).unwrap_or(i64::MAX / 1000))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    fn config() -> LoopBreakerConfig {
+        LoopBreakerConfig {
+            failure_threshold: 3,
+            window_seconds: 3600,
+            cooldown_seconds: 600,
+            max_cooldown_seconds: 1500,
+        }
+    }
+
+    fn at(minutes: i64) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339("2026-03-29T12:00:00Z")
+            .unwrap()
+            .with_timezone(&Utc)
+            + Duration::minutes(minutes)
+    }
+
+    #[test]
+    fn trips_after_threshold_consecutive_failures() {
+        let mut row = LoopBreakerRow::default();
+        assert!(!record_failure(&mut row, &config(), "boom", at(0)));
+        assert!(!record_failure(&mut row, &config(), "boom", at(1)));
+        assert!(record_failure(&mut row, &config(), "boom", at(2)));
+
+        assert_eq!(state(&row, at(3)), BreakerState::Open);
+        assert_eq!(
+            gate(&row, at(3)),
+            LoopGate::Paused {
+                until: at(12),
+                consecutive_failures: 3
+            }
+        );
+        assert_eq!(state(&row, at(12)), BreakerState::HalfOpen);
+        assert_eq!(gate(&row, at(12)), LoopGate::Run);
+    }
+
+    #[test]
+    fn failures_outside_the_window_do_not_accumulate() {
+        let mut row = LoopBreakerRow::default();
+        record_failure(&mut row, &config(), "boom", at(0));
+        record_failure(&mut row, &config(), "boom", at(1));
+        assert!(!record_failure(&mut row, &config(), "boom", at(90)));
+        assert_eq!(row.consecutive_failures, 1);
+        assert_eq!(state(&row, at(91)), BreakerState::Closed);
+    }
+
+    #[test]
+    fn failed_probe_reopens_with_doubled_capped_cooldown() {
+        let mut row = LoopBreakerRow::default();
+        for m in 0..3 {
+            record_failure(&mut row, &config(), "boom", at(m));
+        }
+        // Probe after the 10-minute cooldown fails: 20 minutes.
+        assert!(record_failure(&mut row, &config(), "boom", at(12)));
+        assert_eq!(
+            gate(&row, at(13)),
+            LoopGate::Paused {
+                until: at(32),
+                consecutive_failures: 4
+            }
+        );
+        // Next probe fails: 40 minutes, capped at 25.
+        assert!(record_failure(&mut row, &config(), "boom", at(32)));
+        assert_eq!(row.open_until, Some(at(57).to_rfc3339()));
+    }
+
+    #[tokio::test]
+    async fn persisted_breaker_trips_and_resets_on_success() {
+        let pool = init_test_db().await.expect("init db");
+        let breaker = LoopBreaker::new(pool.clone(), config());
+
+        for m in 0..2 {
+            assert_eq!(
+                breaker
+                    .record_failure("discovery", "bad keywords", at(m))
+                    .await
+                    .unwrap(),
+                LoopGate::Run
+            );
+        }
+        let gate = breaker
+            .record_failure("discovery", "bad keywords", at(2))
+            .await
+            .unwrap();
+        assert!(matches!(gate, LoopGate::Paused { .. }));
+        assert!(matches!(
+            breaker.gate("discovery", at(5)).await.unwrap(),
+            LoopGate::Paused { .. }
+        ));
+        // Other loops are unaffected.
+        assert_eq!(
+            breaker.gate("mentions", at(5)).await.unwrap(),
+            LoopGate::Run
+        );
+
+        breaker.record_success("discovery").await.unwrap();
+        assert_eq!(
+            breaker.gate("discovery", at(5)).await.unwrap(),
+            LoopGate::Run
+        );
+        let row = loop_breakers::get_loop_breaker(&pool, "discovery")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!((row.consecutive_failures, row.trips), (0, 0));
+    }
+
+    #[tokio::test]
+    async fn run_loop_helpers_pause_and_resume() {
+        let pool = init_test_db().await.expect("init db");
+        let breaker = Some(LoopBreaker::new(
+            pool.clone(),
+            LoopBreakerConfig {
+                failure_threshold: 2,
+                cooldown_seconds: 3600,
+                ..config()
+            },
+        ));
+        let cancel = CancellationToken::new();
+
+        assert!(breaker_gate(&None, "discovery", &cancel).await);
+        record_iteration(&breaker, "discovery", Err("bad keywords")).await;
+        assert!(breaker_gate(&breaker, "discovery", &cancel).await);
+        record_iteration(&breaker, "discovery", Err("bad keywords")).await;
+
+        // Open: the gate sleeps until cancelled.
+        cancel.cancel();
+        assert!(!breaker_gate(&breaker, "discovery", &cancel).await);
+
+        record_iteration(&breaker, "discovery", Ok(())).await;
+        assert!(breaker_gate(&breaker, "discovery", &CancellationToken::new()).await);
+    }
+}
@@ -8,6 +8,8 @@
 use std::fmt;
 use std::time::Duration;
 
+use crate::content::{ConversationTweet, FrameworkPick};
+
 // ============================================================================
 // WP08 types: Mentions + Discovery loops
 // ============================================================================
@@ -28,6 +30,8 @@ pub struct LoopTweet {
     pub author_username: String,
     /// Author's follower count.
     pub author_followers: u64,
+    /// Whether the author's tweets are protected; `None` when unknown.
+    pub author_protected: Option<bool>,
     /// ISO-8601 creation timestamp.
     pub created_at: String,
     /// Number of likes.
@@ -36,6 +40,10 @@ pub struct LoopTweet {
     pub retweets: u64,
     /// Number of replies.
     pub replies: u64,
+    /// ID of the tweet this one replies to, if it is a reply.
+    pub in_reply_to_id: Option<String>,
+    /// ID of the conversation (thread root) this tweet belongs to, if known.
+    pub conversation_id: Option<String>,
 }
 
 /// Result of scoring a tweet for reply-worthiness.
@@ -68,6 +76,9 @@ pub enum LoopError {
     NetworkError(String),
     /// Database/storage error.
     StorageError(String),
+    /// The platform or a policy refused the action (forbidden, restricted,
+    /// scraper mutations disabled).
+    Denied(String),
     /// Any other error.
     Other(String),
 }
@@ -83,7 +94,7 @@ impl fmt::Display for LoopError {
             LoopError::LlmFailure(msg) => write!(f, "LLM failure: {msg}"),
             LoopError::NetworkError(msg) => write!(f, "network error: {msg}"),
             LoopError::StorageError(msg) => write!(f, "storage error: {msg}"),
-            LoopError::Other(msg) => write!(f, "{msg}"),
+            LoopError::Denied(msg) | LoopError::Other(msg) => write!(f, "{msg}"),
         }
     }
 }
@@ -137,6 +148,30 @@ pub trait MentionsFetcher: Send + Sync {
 pub trait TweetSearcher: Send + Sync {
     /// Search for tweets matching the query.
     async fn search_tweets(&self, query: &str) -> Result<Vec<LoopTweet>, LoopError>;
+
+    /// Fetch one page of results, continuing from `pagination_token`.
+    /// Returns the tweets and the token for the next page, if any.
+    ///
+    /// Defaults to a single unpaginated `search_tweets` call.
+    async fn search_tweets_page(
+        &self,
+        query: &str,
+        _pagination_token: Option<&str>,
+    ) -> Result<(Vec<LoopTweet>, Option<String>), LoopError> {
+        Ok((self.search_tweets(query).await?, None))
+    }
+}
+
+/// Port for fetching the tweets a reply sits under.
+#[async_trait::async_trait]
+pub trait ConversationFetcher: Send + Sync {
+    /// Fetch up to `max` ancestors of `tweet` (parent first, walking toward
+    /// the root), returned oldest first.
+    async fn fetch_ancestors(
+        &self,
+        tweet: &LoopTweet,
+        max: usize,
+    ) -> Result<Vec<ConversationTweet>, LoopError>;
 }
 
 /// Output from reply generation, carrying both the text and optional vault citations.
@@ -146,6 +181,8 @@ pub struct ReplyOutput {
     pub text: String,
     /// Vault citations used to ground the reply (empty when no vault context).
     pub vault_citations: Vec<crate::context::retrieval::VaultCitation>,
+    /// Archetype that shaped the reply, when the generator picked one.
+    pub framework: Option<FrameworkPick>,
 }
 
 /// Port for generating reply content via LLM.
@@ -178,8 +215,25 @@ pub trait ReplyGenerator: Send + Sync {
         Ok(ReplyOutput {
             text,
             vault_citations: vec![],
+            framework: None,
         })
     }
+
+    /// Generate a reply that sees the conversation the tweet belongs to.
+    ///
+    /// `conversation` holds the tweet's ancestors, oldest first. Default
+    /// implementation ignores it and delegates to `generate_reply_with_rag`.
+    async fn generate_reply_in_conversation(
+        &self,
+        tweet_text: &str,
+        author: &str,
+        mention_product: bool,
+        conversation: &[ConversationTweet],
+    ) -> Result<ReplyOutput, LoopError> {
+        let _ = conversation;
+        self.generate_reply_with_rag(tweet_text, author, mention_product)
+            .await
+    }
 }
 
 /// Port for safety checks (rate limits and dedup).
@@ -191,14 +245,42 @@ pub trait SafetyChecker: Send + Sync {
     /// Check if we've already replied to this tweet.
     async fn has_replied_to(&self, tweet_id: &str) -> bool;
 
+    /// Check whether another reply into this conversation is allowed today.
+    ///
+    /// Allows everything unless the implementation enforces a limit.
+    async fn allows_conversation(&self, _conversation_id: Option<&str>) -> bool {
+        true
+    }
+
     /// Record a reply for dedup and rate limit tracking.
-    async fn record_reply(&self, tweet_id: &str, reply_content: &str) -> Result<(), LoopError>;
+    async fn record_reply(
+        &self,
+        tweet_id: &str,
+        conversation_id: Option<&str>,
+        reply_content: &str,
+    ) -> Result<(), LoopError>;
+
+    /// Check a generated reply against content rules (banned phrases).
+    ///
+    /// Allows everything unless the implementation enforces a policy.
+    async fn allows_reply_text(&self, _reply_text: &str) -> bool {
+        true
+    }
+
+    /// Claim one search request against the search rate limit.
+    ///
+    /// Returns false once the budget is spent. Allows everything unless
+    /// the implementation enforces a limit.
+    async fn acquire_search(&self) -> bool {
+        true
+    }
 }
 
 /// Port for scoring tweets.
+#[async_trait::async_trait]
 pub trait TweetScorer: Send + Sync {
     /// Score a tweet for reply-worthiness.
-    fn score(&self, tweet: &LoopTweet) -> ScoreResult;
+    async fn score(&self, tweet: &LoopTweet) -> ScoreResult;
 }
 
 /// Port for persisting loop state (since_id, discovered tweets, action log).
@@ -235,6 +317,28 @@ pub trait LoopStorage: Send + Sync {
 pub trait PostSender: Send + Sync {
     /// Send a reply to a tweet through the posting queue.
     async fn send_reply(&self, tweet_id: &str, content: &str) -> Result<(), LoopError>;
+
+    /// Send a reply generated with `framework`, so the posted reply (or its
+    /// approval-queue item) is linked to it.
+    ///
+    /// Default implementation ignores the framework and delegates to `send_reply`.
+    async fn send_reply_with_framework(
+        &self,
+        tweet_id: &str,
+        content: &str,
+        framework: Option<&FrameworkPick>,
+    ) -> Result<(), LoopError> {
+        let _ = framework;
+        self.send_reply(tweet_id, content).await
+    }
+}
+
+/// Port for liking tweets the discovery loop finds.
+#[async_trait::async_trait]
+pub trait TweetLiker: Send + Sync {
+    /// Like `tweet`. Returns false when it was not liked because the daily
+    /// like cap is spent or the tweet is the account's own.
+    async fn like_tweet(&self, tweet: &LoopTweet) -> Result<bool, LoopError>;
 }
 
 // ============================================================================
@@ -248,11 +352,34 @@ pub trait TopicScorer: Send + Sync {
     async fn get_top_topics(&self, limit: u32) -> Result<Vec<String>, ContentLoopError>;
 }
 
+/// Output from tweet generation, carrying the text and the format behind it.
+#[derive(Debug, Clone)]
+pub struct TweetOutput {
+    /// The generated tweet text.
+    pub text: String,
+    /// Format that shaped the tweet, when the generator picked one.
+    pub framework: Option<FrameworkPick>,
+}
+
 /// Generates individual tweets on a given topic.
 #[async_trait::async_trait]
 pub trait TweetGenerator: Send + Sync {
     /// Generate an educational tweet on the given topic.
     async fn generate_tweet(&self, topic: &str) -> Result<String, ContentLoopError>;
+
+    /// Generate a tweet along with the framework that shaped it.
+    ///
+    /// Default implementation delegates to `generate_tweet` with no framework.
+    async fn generate_tweet_with_framework(
+        &self,
+        topic: &str,
+    ) -> Result<TweetOutput, ContentLoopError> {
+        let text = self.generate_tweet(topic).await?;
+        Ok(TweetOutput {
+            text,
+            framework: None,
+        })
+    }
 }
 
 /// Checks safety limits for content posting.
@@ -285,6 +412,20 @@ pub trait ContentStorage: Send + Sync {
     /// Post a tweet (sends to posting queue and records in DB).
     async fn post_tweet(&self, topic: &str, content: &str) -> Result<(), ContentLoopError>;
 
+    /// Post a tweet generated with `framework`, so the posted tweet (or its
+    /// approval-queue item) is linked to it.
+    ///
+    /// Default implementation ignores the framework and delegates to `post_tweet`.
+    async fn post_tweet_with_framework(
+        &self,
+        topic: &str,
+        content: &str,
+        framework: Option<&FrameworkPick>,
+    ) -> Result<(), ContentLoopError> {
+        let _ = framework;
+        self.post_tweet(topic, content).await
+    }
+
     /// Create a thread record in the database. Returns the thread ID.
     async fn create_thread(
         &self,
@@ -310,6 +451,12 @@ pub trait ContentStorage: Send + Sync {
         content: &str,
     ) -> Result<(), ContentLoopError>;
 
+    /// Link a posted thread's root tweet to the structure that shaped the
+    /// thread. Default implementation does nothing.
+    async fn link_thread_framework(&self, root_tweet_id: &str, framework: &FrameworkPick) {
+        let _ = (root_tweet_id, framework);
+    }
+
     /// Log an action to the audit trail.
     async fn log_action(
         &self,
@@ -326,6 +473,19 @@ pub trait ContentStorage: Send + Sync {
         Ok(None)
     }
 
+    /// Pick the next topic, skipping topics posted within `min_gap`.
+    ///
+    /// Returns `None` when the implementation does not track topic usage,
+    /// in which case the caller falls back to its own selection.
+    async fn next_topic(
+        &self,
+        topics: &[String],
+        min_gap: chrono::Duration,
+    ) -> Result<Option<String>, ContentLoopError> {
+        let _ = (topics, min_gap);
+        Ok(None)
+    }
+
     /// Mark a scheduled content item as posted.
     async fn mark_scheduled_posted(
         &self,
@@ -557,6 +717,9 @@ mod tests {
             likes: 10,
             retweets: 2,
             replies: 1,
+            in_reply_to_id: None,
+            conversation_id: None,
+            author_protected: None,
         };
         let debug = format!("{tweet:?}");
         assert!(debug.contains("123"));
@@ -567,6 +730,7 @@ mod tests {
         let output = ReplyOutput {
             text: "Great point!".to_string(),
             vault_citations: vec![],
+            framework: None,
         };
         assert_eq!(output.text, "Great point!");
         assert!(output.vault_citations.is_empty());
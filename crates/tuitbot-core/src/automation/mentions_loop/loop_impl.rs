@@ -1,6 +1,7 @@
 //! Main poll-fetch-filter logic for the mentions loop.
 
 use super::{MentionResult, MentionsLoop};
+use crate::automation::loop_breaker::{breaker_gate, record_iteration};
 use crate::automation::loop_helpers::{ConsecutiveErrorTracker, LoopError, LoopStorage};
 use crate::automation::schedule::{schedule_gate, ActiveSchedule};
 use crate::automation::scheduler::LoopScheduler;
@@ -44,9 +45,14 @@ impl MentionsLoop {
                 break;
             }
 
+            if !breaker_gate(&self.breaker, "mentions", &cancel).await {
+                break;
+            }
+
             match self.run_once(since_id.as_deref(), None, &storage).await {
                 Ok((results, new_since_id)) => {
                     error_tracker.record_success();
+                    record_iteration(&self.breaker, "mentions", Ok(())).await;
 
                     if let Some(ref new_id) = new_since_id {
                         since_id = Some(new_id.clone());
@@ -68,6 +74,7 @@ impl MentionsLoop {
                     }
                 }
                 Err(e) => {
+                    record_iteration(&self.breaker, "mentions", Err(&e.to_string())).await;
                     let should_pause = error_tracker.record_error();
                     tracing::warn!(
                         error = %e,
@@ -134,6 +141,8 @@ impl MentionsLoop {
 
         tracing::info!(count = mentions.len(), "Found new mentions");
 
+        let mentions = super::prioritize(mentions, &self.targets);
+
         let mut results = Vec::new();
         let mut max_id: Option<String> = None;
 
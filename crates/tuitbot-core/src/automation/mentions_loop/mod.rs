@@ -3,15 +3,28 @@
 //! Fetches new @-mentions from X API, generates contextual replies
 //! via LLM, and posts them through the posting queue. Persists
 //! `since_id` to survive restarts and avoid reprocessing.
+//!
+//! Each batch is processed highest-priority mentioner first (see
+//! [`prioritize`]), so when the reply rate limit runs out it is the
+//! low-value mentions that get skipped.
 
 mod loop_impl;
 mod responder;
 #[cfg(test)]
 mod tests;
 
-use super::loop_helpers::{MentionsFetcher, PostSender, ReplyGenerator, SafetyChecker};
+use super::loop_breaker::LoopBreaker;
+use super::loop_helpers::{LoopTweet, MentionsFetcher, PostSender, ReplyGenerator, SafetyChecker};
+use crate::config::TargetsConfig;
+use chrono::DateTime;
+use std::cmp::Ordering;
 use std::sync::Arc;
 
+/// Priority bonus for mentions from a configured target account.
+///
+/// Larger than any follower-magnitude score, so targets always go first.
+const TARGET_PRIORITY_BONUS: u32 = 100;
+
 /// Mentions loop that monitors and replies to @-mentions.
 pub struct MentionsLoop {
     pub(crate) fetcher: Arc<dyn MentionsFetcher>,
@@ -19,6 +32,9 @@ pub struct MentionsLoop {
     pub(crate) safety: Arc<dyn SafetyChecker>,
     pub(crate) poster: Arc<dyn PostSender>,
     pub(crate) dry_run: bool,
+    pub(crate) targets: TargetsConfig,
+    /// Error budget checked between iterations of `run`.
+    pub(crate) breaker: Option<LoopBreaker>,
 }
 
 /// Result of processing a single mention.
@@ -51,8 +67,61 @@ impl MentionsLoop {
             safety,
             poster,
             dry_run,
+            targets: TargetsConfig::default(),
+            breaker: None,
         }
     }
+
+    /// Set the target accounts whose mentions are answered first.
+    pub fn with_targets(mut self, targets: TargetsConfig) -> Self {
+        self.targets = targets;
+        self
+    }
+
+    /// Pause the loop while its persisted error budget is spent.
+    pub fn with_loop_breaker(mut self, breaker: LoopBreaker) -> Self {
+        self.breaker = Some(breaker);
+        self
+    }
+}
+
+/// Priority of a mentioner: a bonus for configured target accounts plus
+/// the order of magnitude of their follower count.
+pub fn mentioner_priority(mention: &LoopTweet, targets: &TargetsConfig) -> u32 {
+    let is_target = targets.accounts.iter().any(|account| {
+        account
+            .trim_start_matches('@')
+            .eq_ignore_ascii_case(&mention.author_username)
+    });
+    let magnitude = mention.author_followers.checked_ilog10().unwrap_or(0);
+    if is_target {
+        TARGET_PRIORITY_BONUS + magnitude
+    } else {
+        magnitude
+    }
+}
+
+/// Order mentions highest-priority mentioner first.
+///
+/// Equal priorities go newest first; mentions whose timestamps can't be
+/// compared keep their arrival order.
+pub fn prioritize(mut mentions: Vec<LoopTweet>, targets: &TargetsConfig) -> Vec<LoopTweet> {
+    mentions.sort_by(|a, b| {
+        mentioner_priority(b, targets)
+            .cmp(&mentioner_priority(a, targets))
+            .then_with(|| newer_first(a, b))
+    });
+    mentions
+}
+
+fn newer_first(a: &LoopTweet, b: &LoopTweet) -> Ordering {
+    match (
+        DateTime::parse_from_rfc3339(&a.created_at),
+        DateTime::parse_from_rfc3339(&b.created_at),
+    ) {
+        (Ok(a), Ok(b)) => b.cmp(&a),
+        _ => Ordering::Equal,
+    }
 }
 
 /// Update max_id tracking. Tweet IDs are numeric strings; higher = newer.
@@ -2,6 +2,7 @@
 
 use super::{truncate, MentionResult, MentionsLoop};
 use crate::automation::loop_helpers::{LoopStorage, LoopTweet};
+use crate::safety::SafetyGuard;
 use std::sync::Arc;
 
 impl MentionsLoop {
@@ -11,6 +12,16 @@ impl MentionsLoop {
         mention: &LoopTweet,
         storage: &Arc<dyn LoopStorage>,
     ) -> MentionResult {
+        if let Err(reason) =
+            SafetyGuard::check_protected_author(&mention.author_id, mention.author_protected)
+        {
+            tracing::debug!(tweet_id = %mention.id, "Mention author is protected, skipping");
+            return MentionResult::Skipped {
+                tweet_id: mention.id.clone(),
+                reason: reason.to_string(),
+            };
+        }
+
         // Check if already replied
         if self.safety.has_replied_to(&mention.id).await {
             tracing::debug!(tweet_id = %mention.id, "Already replied to mention, skipping");
@@ -29,6 +40,18 @@ impl MentionsLoop {
             };
         }
 
+        if !self
+            .safety
+            .allows_conversation(mention.conversation_id.as_deref())
+            .await
+        {
+            tracing::debug!(tweet_id = %mention.id, "Conversation reply limit reached, skipping");
+            return MentionResult::Skipped {
+                tweet_id: mention.id.clone(),
+                reason: "conversation limit reached".to_string(),
+            };
+        }
+
         // Generate reply with vault context (always mention product for direct mentions)
         let reply_output = match self
             .generator
@@ -50,6 +73,13 @@ impl MentionsLoop {
         };
         let reply_text = reply_output.text;
 
+        if !self.safety.allows_reply_text(&reply_text).await {
+            return MentionResult::Skipped {
+                tweet_id: mention.id.clone(),
+                reason: "banned phrase".to_string(),
+            };
+        }
+
         tracing::info!(
             author = %mention.author_username,
             "Replied to mention from @{}",
@@ -65,7 +95,15 @@ impl MentionsLoop {
             );
         } else {
             // Send to posting queue
-            if let Err(e) = self.poster.send_reply(&mention.id, &reply_text).await {
+            if let Err(e) = self
+                .poster
+                .send_reply_with_framework(
+                    &mention.id,
+                    &reply_text,
+                    reply_output.framework.as_ref(),
+                )
+                .await
+            {
                 tracing::error!(
                     tweet_id = %mention.id,
                     error = %e,
@@ -78,7 +116,11 @@ impl MentionsLoop {
             }
 
             // Record the reply
-            if let Err(e) = self.safety.record_reply(&mention.id, &reply_text).await {
+            if let Err(e) = self
+                .safety
+                .record_reply(&mention.id, mention.conversation_id.as_deref(), &reply_text)
+                .await
+            {
                 tracing::warn!(
                     tweet_id = %mention.id,
                     error = %e,
@@ -2,6 +2,7 @@
 
 use super::*;
 use crate::automation::loop_helpers::{LoopError, LoopStorage, LoopTweet};
+use crate::config::TargetsConfig;
 use std::sync::Arc;
 use std::sync::Mutex;
 
@@ -75,7 +76,12 @@ impl crate::automation::loop_helpers::SafetyChecker for MockSafety {
             .contains(&tweet_id.to_string())
     }
 
-    async fn record_reply(&self, tweet_id: &str, _content: &str) -> Result<(), LoopError> {
+    async fn record_reply(
+        &self,
+        tweet_id: &str,
+        _conversation_id: Option<&str>,
+        _content: &str,
+    ) -> Result<(), LoopError> {
         self.replied_ids
             .lock()
             .expect("lock")
@@ -178,6 +184,9 @@ fn test_tweet(id: &str, author: &str) -> LoopTweet {
         likes: 10,
         retweets: 2,
         replies: 1,
+        in_reply_to_id: None,
+        conversation_id: None,
+        author_protected: None,
     }
 }
 
@@ -259,7 +268,10 @@ async fn run_once_respects_limit() {
 async fn run_once_skips_already_replied() {
     let safety = Arc::new(MockSafety::new(true));
     // Pre-mark tweet "100" as replied
-    safety.record_reply("100", "already replied").await.unwrap();
+    safety
+        .record_reply("100", None, "already replied")
+        .await
+        .unwrap();
 
     let poster = Arc::new(MockPoster::new());
     let mentions_loop = MentionsLoop::new(
@@ -282,6 +294,41 @@ async fn run_once_skips_already_replied() {
     assert_eq!(poster.sent_count(), 1);
 }
 
+#[tokio::test]
+async fn run_once_skips_protected_authors_but_not_unknown() {
+    let protected = |id: &str, author: &str, flag: Option<bool>| LoopTweet {
+        author_protected: flag,
+        ..test_tweet(id, author)
+    };
+    let poster = Arc::new(MockPoster::new());
+    let mentions_loop = MentionsLoop::new(
+        Arc::new(MockFetcher {
+            mentions: vec![
+                protected("100", "locked", Some(true)),
+                protected("101", "open", Some(false)),
+                protected("102", "unknown", None),
+            ],
+        }),
+        Arc::new(MockGenerator {
+            reply_prefix: "Hi".to_string(),
+        }),
+        Arc::new(MockSafety::new(true)),
+        poster.clone(),
+        false,
+    );
+    let storage: Arc<dyn LoopStorage> = Arc::new(MockStorage::new());
+
+    let (results, _) = mentions_loop.run_once(None, None, &storage).await.unwrap();
+    assert_eq!(results.len(), 3);
+    assert!(matches!(
+        &results[0],
+        MentionResult::Skipped { reason, .. } if reason.contains("protected")
+    ));
+    assert!(matches!(&results[1], MentionResult::Replied { .. }));
+    assert!(matches!(&results[2], MentionResult::Replied { .. }));
+    assert_eq!(poster.sent_count(), 2);
+}
+
 #[tokio::test]
 async fn run_once_skips_when_rate_limited() {
     let poster = Arc::new(MockPoster::new());
@@ -590,3 +637,147 @@ fn mention_result_debug() {
     let debug = format!("{result:?}");
     assert!(debug.contains("Failed"));
 }
+
+// --- Prioritization ---
+
+fn mention_from(id: &str, author: &str, followers: u64, created_at: &str) -> LoopTweet {
+    LoopTweet {
+        author_followers: followers,
+        created_at: created_at.to_string(),
+        ..test_tweet(id, author)
+    }
+}
+
+fn targets(accounts: &[&str]) -> TargetsConfig {
+    TargetsConfig {
+        accounts: accounts.iter().map(|a| a.to_string()).collect(),
+        ..TargetsConfig::default()
+    }
+}
+
+#[test]
+fn prioritize_puts_target_and_large_account_ahead_of_small() {
+    let mentions = vec![
+        mention_from("1", "small", 120, "2026-01-01T12:00:00Z"),
+        mention_from("2", "big", 250_000, "2026-01-01T09:00:00Z"),
+        mention_from("3", "TargetFriend", 40, "2026-01-01T08:00:00Z"),
+    ];
+
+    let ordered = prioritize(mentions, &targets(&["@targetfriend"]));
+    let ids: Vec<&str> = ordered.iter().map(|m| m.id.as_str()).collect();
+    assert_eq!(ids, vec!["3", "2", "1"]);
+}
+
+#[test]
+fn prioritize_breaks_ties_by_recency() {
+    let mentions = vec![
+        mention_from("1", "older", 1_500, "2026-01-01T08:00:00Z"),
+        mention_from("2", "newer", 1_900, "2026-01-01T10:00:00Z"),
+        mention_from("3", "undated", 1_200, "not a date"),
+    ];
+
+    let ordered = prioritize(mentions, &TargetsConfig::default());
+    let ids: Vec<&str> = ordered.iter().map(|m| m.id.as_str()).collect();
+    assert_eq!(ids[..2], ["2", "1"]);
+}
+
+#[test]
+fn mentioner_priority_scales_with_follower_magnitude() {
+    let none = TargetsConfig::default();
+    assert_eq!(mentioner_priority(&mention_from("1", "a", 0, ""), &none), 0);
+    assert_eq!(
+        mentioner_priority(&mention_from("1", "a", 999, ""), &none),
+        2
+    );
+    assert_eq!(
+        mentioner_priority(&mention_from("1", "a", 1_000_000, ""), &none),
+        6
+    );
+    assert!(
+        mentioner_priority(&mention_from("1", "a", 5, ""), &targets(&["a"]))
+            > mentioner_priority(&mention_from("2", "b", u64::MAX, ""), &none)
+    );
+}
+
+#[tokio::test]
+async fn run_once_replies_to_highest_priority_first() {
+    let poster = Arc::new(MockPoster::new());
+    let mentions_loop = MentionsLoop::new(
+        Arc::new(MockFetcher {
+            mentions: vec![
+                mention_from("10", "small", 50, "2026-01-01T12:00:00Z"),
+                mention_from("11", "big", 90_000, "2026-01-01T11:00:00Z"),
+            ],
+        }),
+        Arc::new(MockGenerator {
+            reply_prefix: "Hi".to_string(),
+        }),
+        Arc::new(MockSafety::new(true)),
+        poster.clone(),
+        false,
+    );
+    let storage: Arc<dyn LoopStorage> = Arc::new(MockStorage::new());
+
+    // With a limit of one, only the large account gets a reply.
+    let (results, since_id) = mentions_loop
+        .run_once(None, Some(1), &storage)
+        .await
+        .unwrap();
+    assert_eq!(results.len(), 1);
+    assert!(matches!(
+        &results[0],
+        MentionResult::Replied { tweet_id, .. } if tweet_id == "11"
+    ));
+    assert_eq!(since_id, Some("11".to_string()));
+    assert_eq!(poster.sent_count(), 1);
+}
+
+struct BannedWordSafety;
+
+#[async_trait::async_trait]
+impl crate::automation::loop_helpers::SafetyChecker for BannedWordSafety {
+    async fn can_reply(&self) -> bool {
+        true
+    }
+
+    async fn has_replied_to(&self, _tweet_id: &str) -> bool {
+        false
+    }
+
+    async fn record_reply(
+        &self,
+        _tweet_id: &str,
+        _conversation_id: Option<&str>,
+        _content: &str,
+    ) -> Result<(), LoopError> {
+        Ok(())
+    }
+
+    async fn allows_reply_text(&self, reply_text: &str) -> bool {
+        !reply_text.contains("Buy")
+    }
+}
+
+#[tokio::test]
+async fn run_once_skips_reply_with_banned_text() {
+    let poster = Arc::new(MockPoster::new());
+    let mentions_loop = MentionsLoop::new(
+        Arc::new(MockFetcher {
+            mentions: vec![test_tweet("1", "alice")],
+        }),
+        Arc::new(MockGenerator {
+            reply_prefix: "Buy".to_string(),
+        }),
+        Arc::new(BannedWordSafety),
+        poster.clone(),
+        false,
+    );
+    let storage: Arc<dyn LoopStorage> = Arc::new(MockStorage::new());
+
+    let (results, _) = mentions_loop.run_once(None, None, &storage).await.unwrap();
+    assert!(matches!(
+        &results[0],
+        MentionResult::Skipped { reason, .. } if reason == "banned phrase"
+    ));
+    assert_eq!(poster.sent_count(), 0);
+}
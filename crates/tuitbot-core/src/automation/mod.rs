@@ -7,19 +7,23 @@
 //! Submodules:
 //! - [`scheduler`]: Loop scheduler with configurable interval and jitter.
 //! - [`posting_queue`]: Serialized posting queue for concurrent loops.
+//! - [`activity`]: Live posting activity events and the sink trait.
 //! - [`status_reporter`]: Periodic action count summaries.
+//! - [`loop_breaker`]: Persisted per-loop error budgets that pause failing loops.
 //! - [`loop_helpers`]: Shared types, traits, and error handling for loops.
 //! - [`mentions_loop`]: Monitors @-mentions and generates replies.
 //! - [`discovery_loop`]: Searches tweets by keyword, scores, and replies.
 //! - [`content_loop`]: Generates and posts educational tweets.
 //! - [`thread_loop`]: Generates and posts multi-tweet threads.
 
+pub mod activity;
 pub mod adapters;
 pub mod analytics_loop;
 pub mod approval_poster;
 pub mod circuit_breaker;
 pub mod content_loop;
 pub mod discovery_loop;
+pub mod loop_breaker;
 pub mod loop_helpers;
 pub mod mentions_loop;
 pub mod posting_queue;
@@ -33,6 +37,10 @@ pub mod target_loop;
 pub mod thread_loop;
 pub mod watchtower;
 
+pub use activity::{
+    ActivityEvent, ActivityKind, ActivitySink, ActivityStatus, LogActivitySink,
+    ACTIVITY_ACTION_TYPE,
+};
 pub use analytics_loop::{
     AnalyticsError, AnalyticsLoop, AnalyticsStorage, AnalyticsSummary, EngagementFetcher,
     ProfileFetcher, ProfileMetrics, TweetMetrics,
@@ -43,12 +51,12 @@ pub use discovery_loop::{DiscoveryLoop, DiscoveryResult, DiscoverySummary};
 pub use loop_helpers::{
     ConsecutiveErrorTracker, ContentLoopError, ContentSafety, ContentStorage, LoopError,
     LoopStorage, LoopTweet, MentionsFetcher, PostSender, ReplyGenerator, SafetyChecker,
-    ScoreResult, ThreadPoster, TopicScorer, TweetGenerator, TweetScorer, TweetSearcher,
+    ScoreResult, ThreadPoster, TopicScorer, TweetGenerator, TweetLiker, TweetScorer, TweetSearcher,
 };
 pub use mentions_loop::{MentionResult, MentionsLoop};
 pub use posting_queue::{
-    create_posting_queue, run_posting_queue, run_posting_queue_with_approval, ApprovalQueue,
-    PostAction, PostExecutor, QUEUE_CAPACITY,
+    create_posting_queue, run_posting_queue, run_posting_queue_with_activity,
+    run_posting_queue_with_approval, ApprovalQueue, PostAction, PostExecutor, QUEUE_CAPACITY,
 };
 pub use schedule::{schedule_gate, ActiveSchedule};
 pub use scheduler::{scheduler_from_config, LoopScheduler};
@@ -58,8 +66,8 @@ pub use target_loop::{
     TargetLoop, TargetLoopConfig, TargetResult, TargetStorage, TargetTweetFetcher,
     TargetUserManager,
 };
-pub use thread_loop::{ThreadGenerator, ThreadLoop, ThreadResult};
-pub use watchtower::{IngestSummary, WatchtowerError, WatchtowerLoop};
+pub use thread_loop::{ThreadGenerator, ThreadLoop, ThreadOutput, ThreadResult};
+pub use watchtower::{FileLimits, IngestSummary, WatchtowerError, WatchtowerLoop};
 
 use std::future::Future;
 use std::sync::Arc;
@@ -1,10 +1,12 @@
 use std::sync::Arc;
 use std::time::Duration;
 
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use tokio::sync::mpsc;
 use tokio_util::sync::CancellationToken;
 
+use crate::automation::activity::{ActivityEvent, ActivityKind, ActivitySink};
 use crate::automation::circuit_breaker::CircuitBreaker;
 use crate::automation::posting_queue::queue::{ApprovalQueue, PostAction, PostExecutor};
 
@@ -31,15 +33,48 @@ pub async fn run_posting_queue(
 /// If a `circuit_breaker` is provided, mutations are gated: the queue blocks
 /// while the breaker is Open, and errors/successes are recorded.
 pub async fn run_posting_queue_with_approval(
+    receiver: mpsc::Receiver<PostAction>,
+    executor: Arc<dyn PostExecutor>,
+    approval_queue: Option<Arc<dyn ApprovalQueue>>,
+    min_delay: Duration,
+    max_delay: Duration,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    cancel: CancellationToken,
+) {
+    run_posting_queue_with_activity(
+        receiver,
+        executor,
+        approval_queue,
+        min_delay,
+        max_delay,
+        circuit_breaker,
+        None,
+        None,
+        cancel,
+    )
+    .await;
+}
+
+/// Run the posting queue consumer loop, reporting outcomes to an activity sink.
+///
+/// Behaves like [`run_posting_queue_with_approval`]; additionally, every
+/// directly executed action emits an [`ActivityEvent`] to `activity`.
+/// `random_seed` (`content.random_seed`) makes the delays between posts
+/// repeat across runs.
+#[allow(clippy::too_many_arguments)]
+pub async fn run_posting_queue_with_activity(
     mut receiver: mpsc::Receiver<PostAction>,
     executor: Arc<dyn PostExecutor>,
     approval_queue: Option<Arc<dyn ApprovalQueue>>,
     min_delay: Duration,
     max_delay: Duration,
     circuit_breaker: Option<Arc<CircuitBreaker>>,
+    activity: Option<Arc<dyn ActivitySink>>,
+    random_seed: Option<u64>,
     cancel: CancellationToken,
 ) {
     tracing::info!("Posting queue consumer started");
+    let mut rng = pacing_rng(random_seed);
 
     loop {
         let action = tokio::select! {
@@ -72,7 +107,7 @@ pub async fn run_posting_queue_with_approval(
             }
         }
 
-        let result = execute_or_queue(action, &executor, &approval_queue).await;
+        let result = execute_or_queue(action, &executor, &approval_queue, &activity).await;
 
         // Record result in circuit breaker.
         if approval_queue.is_none() {
@@ -89,7 +124,7 @@ pub async fn run_posting_queue_with_approval(
             }
         }
 
-        let delay = randomized_delay(min_delay, max_delay);
+        let delay = randomized_delay(&mut rng, min_delay, max_delay);
         if !delay.is_zero() {
             tokio::time::sleep(delay).await;
         }
@@ -98,7 +133,7 @@ pub async fn run_posting_queue_with_approval(
     // Drain remaining actions after cancellation or channel close.
     let mut drained = 0u32;
     while let Ok(action) = receiver.try_recv() {
-        execute_or_queue(action, &executor, &approval_queue).await;
+        execute_or_queue(action, &executor, &approval_queue, &activity).await;
         drained += 1;
     }
 
@@ -134,12 +169,13 @@ async fn execute_or_queue(
     action: PostAction,
     executor: &Arc<dyn PostExecutor>,
     approval_queue: &Option<Arc<dyn ApprovalQueue>>,
+    activity: &Option<Arc<dyn ActivitySink>>,
 ) -> PostResult {
     if let Some(queue) = approval_queue {
         queue_for_approval(action, queue).await;
         PostResult::Queued
     } else {
-        execute_and_respond(action, executor).await
+        execute_and_respond(action, executor, activity).await
     }
 }
 
@@ -150,11 +186,12 @@ async fn queue_for_approval(action: PostAction, queue: &Arc<dyn ApprovalQueue>)
             tweet_id,
             content,
             media_ids: _,
+            framework,
             result_tx,
         } => {
             tracing::info!(tweet_id = %tweet_id, "Queuing reply for approval");
             let r = queue
-                .queue_reply(&tweet_id, &content, &[])
+                .queue_reply_with_framework(&tweet_id, &content, &[], framework.as_ref())
                 .await
                 .map(|id| format!("queued:{id}"));
             (r, result_tx)
@@ -162,11 +199,12 @@ async fn queue_for_approval(action: PostAction, queue: &Arc<dyn ApprovalQueue>)
         PostAction::Tweet {
             content,
             media_ids: _,
+            framework,
             result_tx,
         } => {
             tracing::info!("Queuing tweet for approval");
             let r = queue
-                .queue_tweet(&content, &[])
+                .queue_tweet_with_framework(&content, &[], framework.as_ref())
                 .await
                 .map(|id| format!("queued:{id}"));
             (r, result_tx)
@@ -197,28 +235,34 @@ async fn queue_for_approval(action: PostAction, queue: &Arc<dyn ApprovalQueue>)
 }
 
 /// Execute a single post action and send the result back via oneshot.
-async fn execute_and_respond(action: PostAction, executor: &Arc<dyn PostExecutor>) -> PostResult {
-    let (result, result_tx) = match action {
+async fn execute_and_respond(
+    action: PostAction,
+    executor: &Arc<dyn PostExecutor>,
+    activity: &Option<Arc<dyn ActivitySink>>,
+) -> PostResult {
+    let (result, result_tx, kind, target) = match action {
         PostAction::Reply {
             tweet_id,
             content,
             media_ids,
+            framework: _,
             result_tx,
         } => {
             tracing::debug!(tweet_id = %tweet_id, "Executing reply action");
             let r = executor
                 .execute_reply(&tweet_id, &content, &media_ids)
                 .await;
-            (r, result_tx)
+            (r, result_tx, ActivityKind::Reply, tweet_id)
         }
         PostAction::Tweet {
             content,
             media_ids,
+            framework: _,
             result_tx,
         } => {
             tracing::debug!("Executing tweet action");
             let r = executor.execute_tweet(&content, &media_ids).await;
-            (r, result_tx)
+            (r, result_tx, ActivityKind::Tweet, String::new())
         }
         PostAction::ThreadTweet {
             content,
@@ -230,7 +274,7 @@ async fn execute_and_respond(action: PostAction, executor: &Arc<dyn PostExecutor
             let r = executor
                 .execute_reply(&in_reply_to, &content, &media_ids)
                 .await;
-            (r, result_tx)
+            (r, result_tx, ActivityKind::Thread, in_reply_to)
         }
     };
 
@@ -241,24 +285,36 @@ async fn execute_and_respond(action: PostAction, executor: &Arc<dyn PostExecutor
         }
         Err(e) => {
             tracing::warn!(error = %e, "Post action failed");
-            PostResult::Error(e.clone())
+            PostResult::Error(e.to_string())
         }
     };
 
+    if let Some(sink) = activity {
+        sink.emit(ActivityEvent::from_result(kind, &target, &result));
+    }
+
     if let Some(tx) = result_tx {
         // Ignore send error (receiver may have been dropped).
-        let _ = tx.send(result);
+        let _ = tx.send(result.map_err(|e| e.to_string()));
     }
 
     post_result
 }
 
-/// Compute a randomized delay between `min` and `max`.
-pub fn randomized_delay(min: Duration, max: Duration) -> Duration {
+/// Compute a randomized delay between `min` and `max`, drawn from `rng`.
+pub fn randomized_delay(rng: &mut impl Rng, min: Duration, max: Duration) -> Duration {
     if min >= max || min.is_zero() && max.is_zero() {
         return min;
     }
     let min_ms = min.as_millis() as u64;
     let max_ms = max.as_millis() as u64;
-    Duration::from_millis(rand::rng().random_range(min_ms..=max_ms))
+    Duration::from_millis(rng.random_range(min_ms..=max_ms))
+}
+
+/// RNG for the delay between posts, seeded by `content.random_seed`.
+fn pacing_rng(seed: Option<u64>) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_rng(&mut rand::rng()),
+    }
 }
@@ -5,7 +5,9 @@
 //! globally. A single consumer task processes actions sequentially with
 //! configurable delays between posts.
 
-pub use dispatch::{run_posting_queue, run_posting_queue_with_approval};
+pub use dispatch::{
+    run_posting_queue, run_posting_queue_with_activity, run_posting_queue_with_approval,
+};
 pub use queue::{create_posting_queue, ApprovalQueue, PostAction, PostExecutor, QUEUE_CAPACITY};
 
 mod dispatch;
@@ -7,6 +7,9 @@
 
 use tokio::sync::{mpsc, oneshot};
 
+use crate::automation::loop_helpers::LoopError;
+use crate::content::FrameworkPick;
+
 /// Default bounded channel capacity for the posting queue.
 pub const QUEUE_CAPACITY: usize = 100;
 
@@ -23,6 +26,8 @@ pub enum PostAction {
         content: String,
         /// Media IDs to attach (already uploaded to X API).
         media_ids: Vec<String>,
+        /// Archetype that shaped the reply, kept on the approval-queue item.
+        framework: Option<FrameworkPick>,
         /// Optional channel to receive the result (posted tweet ID or error).
         result_tx: Option<oneshot::Sender<Result<String, String>>>,
     },
@@ -32,6 +37,8 @@ pub enum PostAction {
         content: String,
         /// Media IDs to attach (already uploaded to X API).
         media_ids: Vec<String>,
+        /// Format that shaped the tweet, kept on the approval-queue item.
+        framework: Option<FrameworkPick>,
         /// Optional channel to receive the result.
         result_tx: Option<oneshot::Sender<Result<String, String>>>,
     },
@@ -96,10 +103,11 @@ pub trait PostExecutor: Send + Sync {
         tweet_id: &str,
         content: &str,
         media_ids: &[String],
-    ) -> Result<String, String>;
+    ) -> Result<String, LoopError>;
 
     /// Post a new original tweet. Returns the posted tweet ID.
-    async fn execute_tweet(&self, content: &str, media_ids: &[String]) -> Result<String, String>;
+    async fn execute_tweet(&self, content: &str, media_ids: &[String])
+        -> Result<String, LoopError>;
 }
 
 /// Create a bounded posting queue channel.
@@ -123,4 +131,29 @@ pub trait ApprovalQueue: Send + Sync {
 
     /// Queue a tweet for human review. Returns the queue item ID.
     async fn queue_tweet(&self, content: &str, media_paths: &[String]) -> Result<i64, String>;
+
+    /// Queue a reply generated with `framework`, keeping the framework on the
+    /// queue item. Default implementation ignores it and delegates to `queue_reply`.
+    async fn queue_reply_with_framework(
+        &self,
+        tweet_id: &str,
+        content: &str,
+        media_paths: &[String],
+        framework: Option<&FrameworkPick>,
+    ) -> Result<i64, String> {
+        let _ = framework;
+        self.queue_reply(tweet_id, content, media_paths).await
+    }
+
+    /// Queue a tweet generated with `framework`, keeping the framework on the
+    /// queue item. Default implementation ignores it and delegates to `queue_tweet`.
+    async fn queue_tweet_with_framework(
+        &self,
+        content: &str,
+        media_paths: &[String],
+        framework: Option<&FrameworkPick>,
+    ) -> Result<i64, String> {
+        let _ = framework;
+        self.queue_tweet(content, media_paths).await
+    }
 }
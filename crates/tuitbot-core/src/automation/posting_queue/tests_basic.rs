@@ -2,6 +2,7 @@
 mod tests_basic {
     use super::super::dispatch::{is_rate_limit_error, randomized_delay};
     use super::super::*;
+    use crate::automation::LoopError;
     use std::sync::{Arc, Mutex};
     use std::time::Duration;
     use tokio::sync::oneshot;
@@ -44,13 +45,13 @@ mod tests_basic {
             tweet_id: &str,
             content: &str,
             _media_ids: &[String],
-        ) -> Result<String, String> {
+        ) -> Result<String, LoopError> {
             self.calls
                 .lock()
                 .expect("lock poisoned")
                 .push(("reply".to_string(), format!("{tweet_id}:{content}")));
             if self.fail {
-                Err("mock error".to_string())
+                Err(LoopError::Other("mock error".to_string()))
             } else {
                 Ok("reply-id-123".to_string())
             }
@@ -60,13 +61,13 @@ mod tests_basic {
             &self,
             content: &str,
             _media_ids: &[String],
-        ) -> Result<String, String> {
+        ) -> Result<String, LoopError> {
             self.calls
                 .lock()
                 .expect("lock poisoned")
                 .push(("tweet".to_string(), content.to_string()));
             if self.fail {
-                Err("mock error".to_string())
+                Err(LoopError::Other("mock error".to_string()))
             } else {
                 Ok("tweet-id-456".to_string())
             }
@@ -90,6 +91,7 @@ mod tests_basic {
             tweet_id: "t1".to_string(),
             content: "hello".to_string(),
             media_ids: vec![],
+            framework: None,
             result_tx: Some(result_tx),
         })
         .await
@@ -119,6 +121,7 @@ mod tests_basic {
         tx.send(PostAction::Tweet {
             content: "my tweet".to_string(),
             media_ids: vec![],
+            framework: None,
             result_tx: Some(result_tx),
         })
         .await
@@ -179,6 +182,7 @@ mod tests_basic {
         tx.send(PostAction::Tweet {
             content: "fire and forget".to_string(),
             media_ids: vec![],
+            framework: None,
             result_tx: None,
         })
         .await
@@ -208,6 +212,7 @@ mod tests_basic {
         tx.send(PostAction::Tweet {
             content: "will fail".to_string(),
             media_ids: vec![],
+            framework: None,
             result_tx: Some(result_tx),
         })
         .await
@@ -248,6 +253,7 @@ mod tests_basic {
         tx.send(PostAction::Tweet {
             content: "queued1".to_string(),
             media_ids: vec![],
+            framework: None,
             result_tx: None,
         })
         .await
@@ -255,6 +261,7 @@ mod tests_basic {
         tx.send(PostAction::Tweet {
             content: "queued2".to_string(),
             media_ids: vec![],
+            framework: None,
             result_tx: None,
         })
         .await
@@ -290,6 +297,7 @@ mod tests_basic {
             tx.send(PostAction::Tweet {
                 content: format!("tweet-{i}"),
                 media_ids: vec![],
+                framework: None,
                 result_tx: None,
             })
             .await
@@ -316,6 +324,7 @@ mod tests_basic {
             tweet_id: "123".to_string(),
             content: "hello world".to_string(),
             media_ids: vec![],
+            framework: None,
             result_tx: None,
         };
         let debug = format!("{action:?}");
@@ -395,6 +404,7 @@ mod tests_basic {
             tweet_id: "t1".to_string(),
             content: "hello".to_string(),
             media_ids: vec![],
+            framework: None,
             result_tx: Some(result_tx),
         })
         .await
@@ -428,19 +438,27 @@ mod tests_basic {
 
     #[test]
     fn randomized_delay_returns_min_when_equal() {
-        let d = randomized_delay(Duration::from_millis(100), Duration::from_millis(100));
+        let d = randomized_delay(
+            &mut rand::rng(),
+            Duration::from_millis(100),
+            Duration::from_millis(100),
+        );
         assert_eq!(d, Duration::from_millis(100));
     }
 
     #[test]
     fn randomized_delay_returns_min_when_min_greater() {
-        let d = randomized_delay(Duration::from_millis(200), Duration::from_millis(100));
+        let d = randomized_delay(
+            &mut rand::rng(),
+            Duration::from_millis(200),
+            Duration::from_millis(100),
+        );
         assert_eq!(d, Duration::from_millis(200));
     }
 
     #[test]
     fn randomized_delay_returns_zero_when_both_zero() {
-        let d = randomized_delay(Duration::ZERO, Duration::ZERO);
+        let d = randomized_delay(&mut rand::rng(), Duration::ZERO, Duration::ZERO);
         assert_eq!(d, Duration::ZERO);
     }
 
@@ -449,7 +467,7 @@ mod tests_basic {
         let min = Duration::from_millis(50);
         let max = Duration::from_millis(150);
         for _ in 0..20 {
-            let d = randomized_delay(min, max);
+            let d = randomized_delay(&mut rand::rng(), min, max);
             assert!(
                 d >= min && d <= max,
                 "delay {:?} not in [{:?}, {:?}]",
@@ -465,6 +483,7 @@ mod tests_basic {
         let action = PostAction::Tweet {
             content: "hello world".to_string(),
             media_ids: vec!["m1".to_string()],
+            framework: None,
             result_tx: None,
         };
         let debug = format!("{action:?}");
@@ -478,7 +497,7 @@ mod tests_basic {
         let max = Duration::from_millis(1000);
 
         for _ in 0..50 {
-            let d = randomized_delay(min, max);
+            let d = randomized_delay(&mut rand::rng(), min, max);
             assert!(d >= min && d <= max);
         }
     }
@@ -489,7 +508,7 @@ mod tests_basic {
         let max = Duration::from_millis(100);
 
         for _ in 0..50 {
-            let d = randomized_delay(min, max);
+            let d = randomized_delay(&mut rand::rng(), min, max);
             assert!(d >= min && d <= max);
         }
     }
@@ -2,6 +2,8 @@
 mod tests_dispatch {
     use super::super::dispatch::{is_rate_limit_error, randomized_delay};
     use super::super::*;
+    use crate::automation::LoopError;
+    use crate::content::{FrameworkPick, ReplyArchetype};
     use std::sync::{Arc, Mutex};
     use std::time::Duration;
     use tokio::sync::oneshot;
@@ -10,12 +12,15 @@ mod tests_dispatch {
     /// Mock approval queue that records all queued actions.
     struct MockApprovalQueue {
         items: Mutex<Vec<(String, String)>>,
+        /// Framework handed over with each reply or tweet.
+        frameworks: Mutex<Vec<Option<FrameworkPick>>>,
     }
 
     impl MockApprovalQueue {
         fn new() -> Self {
             Self {
                 items: Mutex::new(Vec::new()),
+                frameworks: Mutex::new(Vec::new()),
             }
         }
 
@@ -46,6 +51,33 @@ mod tests_dispatch {
                 .push(("tweet".to_string(), content.to_string()));
             Ok(43)
         }
+
+        async fn queue_reply_with_framework(
+            &self,
+            tweet_id: &str,
+            content: &str,
+            media_paths: &[String],
+            framework: Option<&FrameworkPick>,
+        ) -> Result<i64, String> {
+            self.frameworks
+                .lock()
+                .expect("lock poisoned")
+                .push(framework.cloned());
+            self.queue_reply(tweet_id, content, media_paths).await
+        }
+
+        async fn queue_tweet_with_framework(
+            &self,
+            content: &str,
+            media_paths: &[String],
+            framework: Option<&FrameworkPick>,
+        ) -> Result<i64, String> {
+            self.frameworks
+                .lock()
+                .expect("lock poisoned")
+                .push(framework.cloned());
+            self.queue_tweet(content, media_paths).await
+        }
     }
 
     /// Mock executor for approval mode tests.
@@ -72,7 +104,7 @@ mod tests_dispatch {
             tweet_id: &str,
             content: &str,
             _media_ids: &[String],
-        ) -> Result<String, String> {
+        ) -> Result<String, LoopError> {
             self.calls
                 .lock()
                 .expect("lock poisoned")
@@ -84,7 +116,7 @@ mod tests_dispatch {
             &self,
             content: &str,
             _media_ids: &[String],
-        ) -> Result<String, String> {
+        ) -> Result<String, LoopError> {
             self.calls
                 .lock()
                 .expect("lock poisoned")
@@ -121,6 +153,7 @@ mod tests_dispatch {
             tweet_id: "t123".to_string(),
             content: "needs approval".to_string(),
             media_ids: vec![],
+            framework: None,
             result_tx: Some(result_tx),
         })
         .await
@@ -137,6 +170,50 @@ mod tests_dispatch {
         handle.await.expect("join");
     }
 
+    #[tokio::test]
+    async fn approval_mode_hands_the_framework_to_the_queue() {
+        let executor = Arc::new(MockExecutor::new());
+        let approval = Arc::new(MockApprovalQueue::new());
+        let (tx, rx) = create_posting_queue();
+        let cancel = CancellationToken::new();
+
+        let cancel_clone = cancel.clone();
+        let approval_clone = approval.clone();
+        let handle = tokio::spawn(async move {
+            run_posting_queue_with_approval(
+                rx,
+                executor,
+                Some(approval_clone),
+                Duration::ZERO,
+                Duration::ZERO,
+                None,
+                cancel_clone,
+            )
+            .await;
+        });
+
+        let pick = FrameworkPick::archetype(ReplyArchetype::AskQuestion);
+        let (result_tx, result_rx) = oneshot::channel::<Result<String, String>>();
+        tx.send(PostAction::Reply {
+            tweet_id: "t123".to_string(),
+            content: "needs approval".to_string(),
+            media_ids: vec![],
+            framework: Some(pick.clone()),
+            result_tx: Some(result_tx),
+        })
+        .await
+        .expect("send");
+        result_rx.await.expect("recv").expect("queued");
+
+        assert_eq!(
+            *approval.frameworks.lock().expect("lock poisoned"),
+            vec![Some(pick)]
+        );
+
+        cancel.cancel();
+        handle.await.expect("join");
+    }
+
     #[tokio::test]
     async fn approval_mode_queues_tweets() {
         let executor = Arc::new(MockExecutor::new());
@@ -163,6 +240,7 @@ mod tests_dispatch {
         tx.send(PostAction::Tweet {
             content: "my tweet".to_string(),
             media_ids: vec![],
+            framework: None,
             result_tx: None,
         })
         .await
@@ -261,7 +339,7 @@ mod tests_dispatch {
     fn randomized_delay_zero_min_nonzero_max() {
         let min = Duration::ZERO;
         let max = Duration::from_millis(100);
-        let d = randomized_delay(min, max);
+        let d = randomized_delay(&mut rand::rng(), min, max);
         assert!(d <= max);
     }
 
@@ -307,16 +385,16 @@ mod tests_dispatch {
                 _tweet_id: &str,
                 _content: &str,
                 _media_ids: &[String],
-            ) -> Result<String, String> {
-                Err("API error".to_string())
+            ) -> Result<String, LoopError> {
+                Err(LoopError::Other("API error".to_string()))
             }
 
             async fn execute_tweet(
                 &self,
                 _content: &str,
                 _media_ids: &[String],
-            ) -> Result<String, String> {
-                Err("API error".to_string())
+            ) -> Result<String, LoopError> {
+                Err(LoopError::Other("API error".to_string()))
             }
         }
 
@@ -335,6 +413,7 @@ mod tests_dispatch {
             tweet_id: "t1".to_string(),
             content: "will fail".to_string(),
             media_ids: vec![],
+            framework: None,
             result_tx: Some(result_tx),
         })
         .await
@@ -359,16 +438,16 @@ mod tests_dispatch {
                 _tweet_id: &str,
                 _content: &str,
                 _media_ids: &[String],
-            ) -> Result<String, String> {
-                Err("API error".to_string())
+            ) -> Result<String, LoopError> {
+                Err(LoopError::Other("API error".to_string()))
             }
 
             async fn execute_tweet(
                 &self,
                 _content: &str,
                 _media_ids: &[String],
-            ) -> Result<String, String> {
-                Err("API error".to_string())
+            ) -> Result<String, LoopError> {
+                Err(LoopError::Other("API error".to_string()))
             }
         }
 
@@ -411,11 +490,11 @@ mod tests_dispatch {
                 _tweet_id: &str,
                 _content: &str,
                 media_ids: &[String],
-            ) -> Result<String, String> {
+            ) -> Result<String, LoopError> {
                 if media_ids.len() == 2 {
                     Ok("reply-with-media".to_string())
                 } else {
-                    Err("unexpected media count".to_string())
+                    Err(LoopError::Other("unexpected media count".to_string()))
                 }
             }
 
@@ -423,7 +502,7 @@ mod tests_dispatch {
                 &self,
                 _content: &str,
                 _media_ids: &[String],
-            ) -> Result<String, String> {
+            ) -> Result<String, LoopError> {
                 Ok("tweet-id".to_string())
             }
         }
@@ -443,6 +522,7 @@ mod tests_dispatch {
             tweet_id: "t1".to_string(),
             content: "reply".to_string(),
             media_ids: vec!["m1".to_string(), "m2".to_string()],
+            framework: None,
             result_tx: Some(result_tx),
         })
         .await
@@ -461,7 +541,7 @@ mod tests_dispatch {
         let max = Duration::from_millis(100);
 
         for _ in 0..100 {
-            let d = randomized_delay(min, max);
+            let d = randomized_delay(&mut rand::rng(), min, max);
             assert!(d >= min);
             assert!(d <= max);
         }
@@ -471,7 +551,7 @@ mod tests_dispatch {
     fn randomized_delay_returns_min_when_equal() {
         let min = Duration::from_millis(50);
         let max = Duration::from_millis(50);
-        let d = randomized_delay(min, max);
+        let d = randomized_delay(&mut rand::rng(), min, max);
         assert_eq!(d, min);
     }
 
@@ -479,7 +559,7 @@ mod tests_dispatch {
     fn randomized_delay_returns_zero_when_both_zero() {
         let min = Duration::ZERO;
         let max = Duration::ZERO;
-        let d = randomized_delay(min, max);
+        let d = randomized_delay(&mut rand::rng(), min, max);
         assert_eq!(d, Duration::ZERO);
     }
 
@@ -487,7 +567,23 @@ mod tests_dispatch {
     fn randomized_delay_returns_min_when_min_greater() {
         let min = Duration::from_millis(100);
         let max = Duration::from_millis(50);
-        let d = randomized_delay(min, max);
+        let d = randomized_delay(&mut rand::rng(), min, max);
         assert_eq!(d, min);
     }
+
+    #[test]
+    fn randomized_delay_repeats_with_same_seed() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let min = Duration::from_millis(10);
+        let max = Duration::from_millis(10_000);
+        let draw = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            (0..5)
+                .map(|_| randomized_delay(&mut rng, min, max))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(draw(42), draw(42));
+    }
 }
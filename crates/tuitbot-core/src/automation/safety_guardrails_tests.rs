@@ -321,6 +321,7 @@ mod tests {
             created_at: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
             status: "sent".to_string(),
             error_message: None,
+            conversation_id: None,
         };
         crate::storage::replies::insert_reply(&pool, &reply)
             .await
@@ -356,6 +357,7 @@ mod tests {
             created_at: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
             status: "sent".to_string(),
             error_message: None,
+            conversation_id: None,
         };
         crate::storage::replies::insert_reply(&pool, &reply)
             .await
@@ -0,0 +1,153 @@
+//! iCalendar (RFC 5545) export of the schedule preview.
+//!
+//! Each posting slot becomes a `VEVENT`; scheduled items that do not line
+//! up with a slot get an event of their own. Times are written in UTC so
+//! calendar apps convert them to the viewer's timezone.
+
+use chrono::{DateTime, Utc};
+
+use crate::storage::scheduled_content::ScheduledContent;
+
+use super::preview::{parse_utc, PreviewDay};
+
+/// Length of each event. Slots are instants; this just gives them a
+/// visible block in calendar apps.
+const EVENT_DURATION: &str = "PT15M";
+
+/// Maximum characters of content carried in an event description.
+const PREVIEW_CHARS: usize = 280;
+
+/// Maximum octets per content line before folding (RFC 5545 §3.1).
+const MAX_LINE_OCTETS: usize = 75;
+
+/// Render the preview as an iCalendar feed.
+///
+/// `scheduled` supplies the content previews for filled slots and
+/// unslotted items; `now` stamps every event (`DTSTAMP`).
+pub fn render_ics(
+    days: &[PreviewDay],
+    scheduled: &[ScheduledContent],
+    now: DateTime<Utc>,
+) -> String {
+    let stamp = now.format("%Y%m%dT%H%M%SZ").to_string();
+    let find = |id: i64| scheduled.iter().find(|item| item.id == id);
+
+    let mut lines: Vec<String> = vec![
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Tuitbot//Posting Schedule//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        "X-WR-CALNAME:Tuitbot posting schedule".to_string(),
+    ];
+
+    for day in days {
+        for slot in &day.slots {
+            let Some(at) = parse_utc(&slot.at) else {
+                continue;
+            };
+            let start = at.format("%Y%m%dT%H%M%SZ").to_string();
+            let kind = slot.kind.as_str();
+            let item = slot.scheduled_content_id.and_then(find);
+            let summary = if slot.filled {
+                format!("Tuitbot {kind} (scheduled)")
+            } else {
+                format!("Tuitbot {kind} slot")
+            };
+            push_event(
+                &mut lines,
+                &format!("slot-{start}-{kind}@tuitbot"),
+                &stamp,
+                &start,
+                &summary,
+                kind,
+                item,
+            );
+        }
+
+        for id in &day.unslotted_scheduled {
+            let Some(item) = find(*id) else {
+                continue;
+            };
+            let Some(at) = item.scheduled_for.as_deref().and_then(parse_utc) else {
+                continue;
+            };
+            let kind = item.content_type.as_str();
+            push_event(
+                &mut lines,
+                &format!("scheduled-{id}@tuitbot"),
+                &stamp,
+                &at.format("%Y%m%dT%H%M%SZ").to_string(),
+                &format!("Tuitbot {kind} (scheduled)"),
+                kind,
+                Some(item),
+            );
+        }
+    }
+
+    lines.push("END:VCALENDAR".to_string());
+
+    let mut out = String::new();
+    for line in lines {
+        out.push_str(&fold_line(&line));
+    }
+    out
+}
+
+fn push_event(
+    lines: &mut Vec<String>,
+    uid: &str,
+    stamp: &str,
+    start: &str,
+    summary: &str,
+    kind: &str,
+    item: Option<&ScheduledContent>,
+) {
+    lines.push("BEGIN:VEVENT".to_string());
+    lines.push(format!("UID:{uid}"));
+    lines.push(format!("DTSTAMP:{stamp}"));
+    lines.push(format!("DTSTART:{start}"));
+    lines.push(format!("DURATION:{EVENT_DURATION}"));
+    lines.push(format!("SUMMARY:{}", escape_text(summary)));
+    lines.push(format!("CATEGORIES:{}", escape_text(kind)));
+    if let Some(item) = item {
+        let preview: String = item.content.chars().take(PREVIEW_CHARS).collect();
+        lines.push(format!("DESCRIPTION:{}", escape_text(&preview)));
+    }
+    lines.push("END:VEVENT".to_string());
+}
+
+/// Escape a TEXT value (RFC 5545 §3.3.11).
+fn escape_text(raw: &str) -> String {
+    let mut out = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            ';' => out.push_str("\\;"),
+            ',' => out.push_str("\\,"),
+            '\n' => out.push_str("\\n"),
+            '\r' => {}
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
+/// Fold a content line at 75 octets without splitting a UTF-8 character,
+/// terminating every physical line with CRLF.
+fn fold_line(line: &str) -> String {
+    let mut out = String::with_capacity(line.len() + 8);
+    let mut width = 0;
+    for c in line.chars() {
+        let len = c.len_utf8();
+        if width + len > MAX_LINE_OCTETS {
+            out.push_str("\r\n ");
+            // The leading space counts toward the continuation line.
+            width = 1;
+        }
+        out.push(c);
+        width += len;
+    }
+    out.push_str("\r\n");
+    out
+}
@@ -8,13 +8,19 @@
 //! - [`recurrence`]: `PostingSlot`, `apply_slot_jitter`, jitter constant.
 //! - [`planner`]: `ActiveSchedule` construction, slot resolution, active-window logic.
 //! - [`executor`]: `schedule_gate` async gate function.
+//! - [`preview`]: day-by-day slot preview cross-referenced with scheduled content.
+//! - [`ics`]: iCalendar export of the preview.
 
 mod executor;
+mod ics;
 mod planner;
+mod preview;
 mod recurrence;
 #[cfg(test)]
 mod tests;
 
 pub use executor::schedule_gate;
-pub use planner::{ActiveSchedule, AUTO_PREFERRED_TIMES};
-pub use recurrence::{apply_slot_jitter, PostingSlot};
+pub use ics::render_ics;
+pub use planner::{ActiveSchedule, ResolvedSlot, SlotKind, AUTO_PREFERRED_TIMES};
+pub use preview::{build_schedule_preview, PreviewDay, PreviewSlot};
+pub use recurrence::{apply_slot_jitter, apply_slot_jitter_with, PostingSlot};
@@ -1,7 +1,7 @@
 //! Schedule planning: `ActiveSchedule` construction, slot resolution,
 //! active-window checks, and "time until active" computation.
 
-use chrono::{DateTime, Datelike, Timelike, Utc};
+use chrono::{DateTime, Datelike, TimeZone, Timelike, Utc};
 use chrono_tz::Tz;
 use std::collections::HashMap;
 use std::time::Duration;
@@ -27,6 +27,33 @@ pub(super) fn parse_weekday(s: &str) -> Option<chrono::Weekday> {
     }
 }
 
+/// What a resolved slot is reserved for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SlotKind {
+    Tweet,
+    Thread,
+}
+
+impl SlotKind {
+    /// The content type this slot is reserved for (`tweet` or `thread`).
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Tweet => "tweet",
+            Self::Thread => "thread",
+        }
+    }
+}
+
+/// A concrete posting time resolved from the schedule.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedSlot {
+    pub at: DateTime<Utc>,
+    pub kind: SlotKind,
+    /// The configured HH:MM the slot came from (in the schedule timezone).
+    pub slot: PostingSlot,
+}
+
 /// Parsed active schedule with timezone, hours, weekday filtering, and preferred posting times.
 #[derive(Debug, Clone)]
 pub struct ActiveSchedule {
@@ -124,8 +151,11 @@ impl ActiveSchedule {
     /// Otherwise use the base `preferred_times`.
     pub fn slots_for_today(&self) -> Vec<PostingSlot> {
         let now = Utc::now().with_timezone(&self.tz);
-        let weekday = now.weekday();
+        self.slots_for_weekday(now.weekday())
+    }
 
+    /// Get the tweet posting slots for `weekday`, resolving per-day overrides.
+    pub fn slots_for_weekday(&self, weekday: chrono::Weekday) -> Vec<PostingSlot> {
         if let Some(override_slots) = self.preferred_times_override.get(&weekday) {
             override_slots.clone()
         } else {
@@ -133,6 +163,63 @@ impl ActiveSchedule {
         }
     }
 
+    /// The schedule's timezone.
+    pub fn timezone(&self) -> Tz {
+        self.tz
+    }
+
+    /// Whether posting is allowed on `weekday` (all days when none are configured).
+    pub fn is_active_day(&self, weekday: chrono::Weekday) -> bool {
+        self.active_weekdays.is_empty() || self.active_weekdays.contains(&weekday)
+    }
+
+    /// Resolve concrete tweet and thread slots after `now` over the next
+    /// `days` calendar days in the schedule timezone, today included.
+    ///
+    /// Applies per-day overrides and skips inactive days and slots outside
+    /// active hours, since the loops never post there. Slots that fall in a
+    /// DST gap are skipped. Sorted by time.
+    pub fn next_slots(&self, now: DateTime<Utc>, days: u32) -> Vec<ResolvedSlot> {
+        let today = now.with_timezone(&self.tz).date_naive();
+        let mut resolved = Vec::new();
+
+        for offset in 0..days {
+            let Some(date) = today.checked_add_days(chrono::Days::new(offset.into())) else {
+                break;
+            };
+            let weekday = date.weekday();
+            if !self.is_active_day(weekday) {
+                continue;
+            }
+
+            let mut candidates: Vec<(PostingSlot, SlotKind)> = self
+                .slots_for_weekday(weekday)
+                .into_iter()
+                .map(|slot| (slot, SlotKind::Tweet))
+                .collect();
+            if self.thread_preferred_day == Some(weekday) {
+                candidates.push((self.thread_preferred_time.clone(), SlotKind::Thread));
+            }
+
+            for (slot, kind) in candidates {
+                if !self.in_active_hours(slot.hour) {
+                    continue;
+                }
+                let local = date.and_time(slot.to_naive_time());
+                let Some(at) = self.tz.from_local_datetime(&local).earliest() else {
+                    continue;
+                };
+                let at = at.with_timezone(&Utc);
+                if at > now {
+                    resolved.push(ResolvedSlot { at, kind, slot });
+                }
+            }
+        }
+
+        resolved.sort_by_key(|s| s.at);
+        resolved
+    }
+
     /// Find the next unused slot for today.
     ///
     /// Compares today's slots against `today_post_times` (actual post times from DB).
@@ -225,7 +312,13 @@ impl ActiveSchedule {
             return false;
         }
 
-        // Check hours — handle wrapping (e.g. 22-06)
+        self.in_active_hours(hour)
+    }
+
+    /// Whether `hour` falls within the active window.
+    ///
+    /// Handles wrapping ranges (e.g. start=22, end=6).
+    fn in_active_hours(&self, hour: u8) -> bool {
         if self.start_hour <= self.end_hour {
             // Normal range: 8-22 means hours 8..22
             hour >= self.start_hour && hour < self.end_hour
@@ -0,0 +1,118 @@
+//! Schedule preview: lays out upcoming posting slots day by day and
+//! cross-references them with scheduled content.
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::Serialize;
+
+use crate::storage::scheduled_content::ScheduledContent;
+
+use super::planner::{ActiveSchedule, SlotKind};
+
+/// A scheduled item within this many minutes of a slot fills it, matching
+/// the window the content loop uses to mark a slot as used.
+const SLOT_MATCH_MINUTES: i64 = 30;
+
+/// One day of the schedule preview, in the schedule timezone.
+#[derive(Debug, Serialize)]
+pub struct PreviewDay {
+    /// Local date, `YYYY-MM-DD`.
+    pub date: String,
+    /// Weekday abbreviation (`Mon`..`Sun`).
+    pub weekday: String,
+    /// Whether posting is enabled on this weekday (`schedule.active_days`).
+    pub active: bool,
+    pub slots: Vec<PreviewSlot>,
+    /// Scheduled items on this day that do not line up with any slot.
+    pub unslotted_scheduled: Vec<i64>,
+}
+
+/// A planned posting slot and whether content is already lined up for it.
+#[derive(Debug, Serialize)]
+pub struct PreviewSlot {
+    /// UTC time of the slot.
+    pub at: String,
+    /// Slot time in the schedule timezone (`HH:MM`).
+    pub local_time: String,
+    pub kind: SlotKind,
+    pub filled: bool,
+    /// The scheduled content item filling the slot, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scheduled_content_id: Option<i64>,
+}
+
+/// Lay out the next `days` local days with their slots, filling each slot
+/// with the nearest unused scheduled item of the same type.
+pub fn build_schedule_preview(
+    schedule: &ActiveSchedule,
+    now: DateTime<Utc>,
+    days: u32,
+    scheduled: &[ScheduledContent],
+) -> Vec<PreviewDay> {
+    let tz = schedule.timezone();
+    let today = now.with_timezone(&tz).date_naive();
+
+    let mut pending: Vec<(i64, &str, DateTime<Utc>)> = scheduled
+        .iter()
+        .filter(|item| item.status == "scheduled")
+        .filter_map(|item| {
+            let at = parse_utc(item.scheduled_for.as_deref()?)?;
+            Some((item.id, item.content_type.as_str(), at))
+        })
+        .collect();
+
+    let mut preview: Vec<PreviewDay> = (0..days)
+        .filter_map(|offset| today.checked_add_days(chrono::Days::new(offset.into())))
+        .map(|date| PreviewDay {
+            date: date.format("%Y-%m-%d").to_string(),
+            weekday: date.format("%a").to_string(),
+            active: schedule.is_active_day(chrono::Datelike::weekday(&date)),
+            slots: Vec::new(),
+            unslotted_scheduled: Vec::new(),
+        })
+        .collect();
+
+    for slot in schedule.next_slots(now, days) {
+        let kind = slot.kind.as_str();
+        let nearest = pending
+            .iter()
+            .enumerate()
+            .filter(|(_, (_, t, at))| {
+                *t == kind && (*at - slot.at).num_minutes().abs() <= SLOT_MATCH_MINUTES
+            })
+            .min_by_key(|(_, (_, _, at))| (*at - slot.at).num_seconds().abs())
+            .map(|(i, _)| i);
+        let filled_by = nearest.map(|i| pending.remove(i).0);
+
+        let date = slot.at.with_timezone(&tz).format("%Y-%m-%d").to_string();
+        if let Some(day) = preview.iter_mut().find(|d| d.date == date) {
+            day.slots.push(PreviewSlot {
+                at: slot.at.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+                local_time: slot.slot.format(),
+                kind: slot.kind,
+                filled: filled_by.is_some(),
+                scheduled_content_id: filled_by,
+            });
+        }
+    }
+
+    for (id, _, at) in pending {
+        let date = at.with_timezone(&tz).format("%Y-%m-%d").to_string();
+        if let Some(day) = preview.iter_mut().find(|d| d.date == date) {
+            day.unslotted_scheduled.push(id);
+        }
+    }
+
+    preview
+}
+
+/// Parse a stored `scheduled_for` value (UTC, with or without `Z`).
+pub(super) fn parse_utc(raw: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(raw)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(raw, "%Y-%m-%dT%H:%M:%S")
+                .ok()
+                .map(|n| n.and_utc())
+        })
+}
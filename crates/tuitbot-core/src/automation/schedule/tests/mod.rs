@@ -1,6 +1,7 @@
 mod active_schedule;
 mod jitter;
 mod posting_slot;
+mod preview_and_ics;
 mod slots_and_gate;
 
 use crate::config::ScheduleConfig;
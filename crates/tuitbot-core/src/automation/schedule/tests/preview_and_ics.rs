@@ -0,0 +1,198 @@
+//! Tests for `build_schedule_preview` and the iCalendar export.
+
+use chrono::{DateTime, TimeZone, Utc};
+
+use crate::automation::schedule::{build_schedule_preview, render_ics, ActiveSchedule, SlotKind};
+use crate::config::ScheduleConfig;
+use crate::storage::scheduled_content::ScheduledContent;
+
+fn scheduled(id: i64, content_type: &str, at: &str) -> ScheduledContent {
+    ScheduledContent {
+        id,
+        content_type: content_type.to_string(),
+        content: "hello".to_string(),
+        scheduled_for: Some(at.to_string()),
+        status: "scheduled".to_string(),
+        posted_tweet_id: None,
+        created_at: "2026-03-01T00:00:00Z".to_string(),
+        updated_at: "2026-03-01T00:00:00Z".to_string(),
+        qa_report: "{}".to_string(),
+        qa_hard_flags: "[]".to_string(),
+        qa_soft_flags: "[]".to_string(),
+        qa_recommendations: "[]".to_string(),
+        qa_score: 0.0,
+        title: None,
+        notes: None,
+        archived_at: None,
+        source: "manual".to_string(),
+    }
+}
+
+fn week_schedule() -> ActiveSchedule {
+    let config = ScheduleConfig {
+        timezone: "UTC".to_string(),
+        active_hours_start: 8,
+        active_hours_end: 22,
+        active_days: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]
+            .iter()
+            .map(|d| d.to_string())
+            .collect(),
+        preferred_times: vec!["09:00".to_string(), "17:00".to_string()],
+        preferred_times_override: [("Sat".to_string(), vec!["11:00".to_string()])]
+            .into_iter()
+            .collect(),
+        thread_preferred_day: Some("Wed".to_string()),
+        thread_preferred_time: "10:00".to_string(),
+    };
+    ActiveSchedule::from_config(&config).unwrap()
+}
+
+/// Monday 2026-03-09 12:00 UTC.
+fn monday_noon() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 9, 12, 0, 0).unwrap()
+}
+
+fn week_items() -> Vec<ScheduledContent> {
+    vec![
+        scheduled(1, "tweet", "2026-03-10T09:10:00Z"),
+        scheduled(2, "thread", "2026-03-11T10:00:00Z"),
+        scheduled(3, "tweet", "2026-03-12T14:00:00Z"),
+    ]
+}
+
+/// Unfold an iCalendar stream into logical lines, checking the physical
+/// framing (CRLF endings, 75-octet limit) on the way.
+fn unfold(ics: &str) -> Vec<String> {
+    assert!(ics.ends_with("\r\n"), "feed must end with CRLF");
+    let mut lines: Vec<String> = Vec::new();
+    for physical in ics.split_terminator("\r\n") {
+        assert!(!physical.contains('\n'), "bare LF in {physical:?}");
+        assert!(physical.len() <= 75, "line over 75 octets: {physical:?}");
+        match physical.strip_prefix(' ') {
+            Some(rest) => lines.last_mut().expect("continuation first").push_str(rest),
+            None => lines.push(physical.to_string()),
+        }
+    }
+    lines
+}
+
+#[test]
+fn preview_lays_out_week_and_fills_matching_slots() {
+    let days = build_schedule_preview(&week_schedule(), monday_noon(), 7, &week_items());
+
+    let layout: Vec<(&str, bool, Vec<&str>)> = days
+        .iter()
+        .map(|d| {
+            (
+                d.weekday.as_str(),
+                d.active,
+                d.slots.iter().map(|s| s.local_time.as_str()).collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        layout,
+        vec![
+            ("Mon", true, vec!["17:00"]),
+            ("Tue", true, vec!["09:00", "17:00"]),
+            ("Wed", true, vec!["09:00", "10:00", "17:00"]),
+            ("Thu", true, vec!["09:00", "17:00"]),
+            ("Fri", true, vec!["09:00", "17:00"]),
+            ("Sat", true, vec!["11:00"]),
+            ("Sun", false, vec![]),
+        ]
+    );
+
+    let tue = &days[1].slots[0];
+    assert!(tue.filled);
+    assert_eq!(tue.scheduled_content_id, Some(1));
+    assert_eq!(tue.at, "2026-03-10T09:00:00Z");
+
+    let wed_thread = &days[2].slots[1];
+    assert_eq!(wed_thread.kind, SlotKind::Thread);
+    assert_eq!(wed_thread.scheduled_content_id, Some(2));
+    assert!(!days[2].slots[0].filled);
+
+    assert_eq!(days[3].unslotted_scheduled, vec![3]);
+    assert!(days[3].slots.iter().all(|s| !s.filled));
+}
+
+#[test]
+fn preview_respects_day_count() {
+    let days = build_schedule_preview(&week_schedule(), monday_noon(), 3, &week_items());
+    assert_eq!(days.len(), 3);
+    assert_eq!(days[2].weekday, "Wed");
+    assert!(days.iter().all(|d| d.unslotted_scheduled.is_empty()));
+}
+
+#[test]
+fn ics_export_parses_with_one_event_per_slot() {
+    let mut items = week_items();
+    items[0].content = "Ship it; then, measure\nwhat \\ matters".to_string();
+    items[1].content = "x".repeat(400);
+    let days = build_schedule_preview(&week_schedule(), monday_noon(), 7, &items);
+
+    let ics = render_ics(&days, &items, monday_noon());
+    let lines = unfold(&ics);
+
+    assert_eq!(lines.first().map(String::as_str), Some("BEGIN:VCALENDAR"));
+    assert_eq!(lines.last().map(String::as_str), Some("END:VCALENDAR"));
+    assert!(lines.iter().any(|l| l == "VERSION:2.0"));
+    assert!(lines.iter().any(|l| l.starts_with("PRODID:")));
+
+    // Every content line is NAME:value and components nest properly.
+    let mut depth: Vec<&str> = Vec::new();
+    for line in &lines {
+        let (name, value) = line.split_once(':').expect("content line without ':'");
+        assert!(!name.is_empty());
+        match name {
+            "BEGIN" => depth.push(value),
+            "END" => assert_eq!(depth.pop(), Some(value)),
+            _ => assert!(!depth.is_empty(), "property outside a component: {line}"),
+        }
+    }
+    assert!(depth.is_empty());
+
+    // 11 slots over the week, plus Thursday's unslotted tweet.
+    let events: Vec<&[String]> = lines.split(|l| l == "BEGIN:VEVENT").skip(1).collect();
+    assert_eq!(events.len(), 12);
+    for event in &events {
+        for prop in ["UID:", "DTSTAMP:20260309T120000Z", "DTSTART:", "SUMMARY:"] {
+            assert!(
+                event.iter().any(|l| l.starts_with(prop)),
+                "event missing {prop}: {event:?}"
+            );
+        }
+    }
+
+    let tue = events
+        .iter()
+        .find(|e| e.iter().any(|l| l == "DTSTART:20260310T090000Z"))
+        .unwrap();
+    assert!(tue.iter().any(|l| l == "SUMMARY:Tuitbot tweet (scheduled)"));
+    assert!(tue
+        .iter()
+        .any(|l| l == r"DESCRIPTION:Ship it\; then\, measure\nwhat \\ matters"));
+
+    let wed_thread = events
+        .iter()
+        .find(|e| e.iter().any(|l| l == "CATEGORIES:thread"))
+        .unwrap();
+    let description = wed_thread
+        .iter()
+        .find_map(|l| l.strip_prefix("DESCRIPTION:"))
+        .unwrap();
+    assert_eq!(description.len(), 280);
+
+    let empty = events
+        .iter()
+        .find(|e| e.iter().any(|l| l == "DTSTART:20260309T170000Z"))
+        .unwrap();
+    assert!(empty.iter().any(|l| l == "SUMMARY:Tuitbot tweet slot"));
+    assert!(!empty.iter().any(|l| l.starts_with("DESCRIPTION:")));
+
+    assert!(events
+        .iter()
+        .any(|e| e.iter().any(|l| l == "UID:scheduled-3@tuitbot")
+            && e.iter().any(|l| l == "DTSTART:20260312T140000Z")));
+}
@@ -495,3 +495,82 @@ async fn schedule_gate_cancelled_returns_false() {
 
     assert!(!schedule_gate(&Some(schedule), &cancel).await);
 }
+
+// -----------------------------------------------------------------------
+// next_slots
+// -----------------------------------------------------------------------
+
+#[test]
+fn next_slots_resolves_week_with_overrides_and_inactive_days() {
+    use crate::automation::schedule::SlotKind;
+    use chrono::TimeZone;
+
+    let mut config = default_schedule_config();
+    config.active_days = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat"]
+        .iter()
+        .map(|d| d.to_string())
+        .collect();
+    config.preferred_times = vec![
+        "07:00".to_string(), // before active hours, never used
+        "09:00".to_string(),
+        "12:00".to_string(),
+        "18:00".to_string(),
+    ];
+    config
+        .preferred_times_override
+        .insert("Sat".to_string(), vec![]);
+    config
+        .preferred_times_override
+        .insert("Sun".to_string(), vec!["11:00".to_string()]);
+    config.thread_preferred_day = Some("Wed".to_string());
+    config.thread_preferred_time = "10:00".to_string();
+    let schedule = ActiveSchedule::from_config(&config).unwrap();
+
+    // Monday 2026-03-09 10:00 UTC.
+    let now = Utc.with_ymd_and_hms(2026, 3, 9, 10, 0, 0).unwrap();
+    let slots = schedule.next_slots(now, 7);
+
+    let per_day = |day: u32| slots.iter().filter(|s| s.at.day() == day).count();
+    assert_eq!(per_day(9), 2, "Mon: 09:00 already passed");
+    assert_eq!(per_day(10), 3);
+    assert_eq!(per_day(11), 4, "Wed: three tweets plus the thread");
+    assert_eq!(per_day(14), 0, "Sat override is empty");
+    assert_eq!(per_day(15), 0, "Sun is not an active day");
+    assert_eq!(slots.len(), 15);
+
+    assert_eq!(
+        slots[0].at,
+        Utc.with_ymd_and_hms(2026, 3, 9, 12, 0, 0).unwrap()
+    );
+    let thread: Vec<_> = slots
+        .iter()
+        .filter(|s| s.kind == SlotKind::Thread)
+        .collect();
+    assert_eq!(thread.len(), 1);
+    assert_eq!(
+        thread[0].at,
+        Utc.with_ymd_and_hms(2026, 3, 11, 10, 0, 0).unwrap()
+    );
+    assert!(slots.windows(2).all(|w| w[0].at <= w[1].at));
+}
+
+#[test]
+fn next_slots_converts_from_schedule_timezone() {
+    use chrono::TimeZone;
+
+    let mut config = default_schedule_config();
+    config.timezone = "America/New_York".to_string();
+    config.preferred_times = vec!["09:00".to_string()];
+    let schedule = ActiveSchedule::from_config(&config).unwrap();
+
+    // 2026-03-09 00:00 UTC is still Sunday evening in New York (EDT, UTC-4).
+    let now = Utc.with_ymd_and_hms(2026, 3, 9, 0, 0, 0).unwrap();
+    let slots = schedule.next_slots(now, 1);
+
+    assert!(slots.is_empty(), "Sunday's 09:00 has already passed");
+    let slots = schedule.next_slots(now, 2);
+    assert_eq!(
+        slots[0].at,
+        Utc.with_ymd_and_hms(2026, 3, 9, 13, 0, 0).unwrap()
+    );
+}
@@ -5,7 +5,9 @@
 //! range (from `[limits]` config). The jitter prevents predictable
 //! patterns, making the agent's behavior appear more natural.
 
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 /// A scheduler that paces automation loop iterations with jitter.
@@ -17,6 +19,8 @@ pub struct LoopScheduler {
     interval: Duration,
     min_delay: Duration,
     max_delay: Duration,
+    /// Seeded jitter source; `None` draws from the thread RNG.
+    rng: Option<Arc<Mutex<StdRng>>>,
 }
 
 impl LoopScheduler {
@@ -39,9 +43,17 @@ impl LoopScheduler {
             interval,
             min_delay: actual_min,
             max_delay: actual_max,
+            rng: None,
         }
     }
 
+    /// Draw jitter from an RNG seeded with `seed` (`content.random_seed`)
+    /// so delays repeat across runs. `None` keeps the thread RNG.
+    pub fn with_seed(mut self, seed: Option<u64>) -> Self {
+        self.rng = seed.map(|s| Arc::new(Mutex::new(StdRng::seed_from_u64(s))));
+        self
+    }
+
     /// Compute the next sleep duration: `interval + random_jitter`.
     ///
     /// The jitter is drawn uniformly from `[min_delay, max_delay]`.
@@ -52,7 +64,14 @@ impl LoopScheduler {
         } else {
             let min_ms = self.min_delay.as_millis() as u64;
             let max_ms = self.max_delay.as_millis() as u64;
-            Duration::from_millis(rand::rng().random_range(min_ms..=max_ms))
+            let ms = match &self.rng {
+                Some(rng) => rng
+                    .lock()
+                    .unwrap_or_else(|poisoned| poisoned.into_inner())
+                    .random_range(min_ms..=max_ms),
+                None => rand::rng().random_range(min_ms..=max_ms),
+            };
+            Duration::from_millis(ms)
         };
 
         self.interval + jitter
@@ -196,6 +215,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn same_seed_gives_same_delays() {
+        let make = || {
+            LoopScheduler::new(
+                Duration::from_secs(60),
+                Duration::from_secs(30),
+                Duration::from_secs(120),
+            )
+            .with_seed(Some(42))
+        };
+        let (a, b) = (make(), make());
+        for _ in 0..10 {
+            assert_eq!(a.next_delay(), b.next_delay());
+        }
+    }
+
     #[test]
     fn scheduler_clone() {
         let scheduler = LoopScheduler::new(
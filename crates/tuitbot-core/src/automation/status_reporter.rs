@@ -5,6 +5,7 @@
 //! what the agent has been doing without requiring verbose logging.
 
 use super::scheduler::LoopScheduler;
+use crate::llm::SessionUsage;
 use chrono::{DateTime, Utc};
 use tokio_util::sync::CancellationToken;
 
@@ -63,6 +64,17 @@ pub async fn run_status_reporter(
     querier: std::sync::Arc<dyn StatusQuerier>,
     scheduler: LoopScheduler,
     cancel: CancellationToken,
+) {
+    run_status_reporter_with_usage(querier, None, scheduler, cancel).await;
+}
+
+/// Run the periodic status reporter, also logging cumulative LLM token
+/// usage (and estimated cost, when priced) from `session_usage`.
+pub async fn run_status_reporter_with_usage(
+    querier: std::sync::Arc<dyn StatusQuerier>,
+    session_usage: Option<std::sync::Arc<SessionUsage>>,
+    scheduler: LoopScheduler,
+    cancel: CancellationToken,
 ) {
     tracing::info!("Status reporter started");
 
@@ -95,6 +107,10 @@ pub async fn run_status_reporter(
             }
         }
 
+        if let Some(usage) = &session_usage {
+            tracing::info!("{}", usage.snapshot().format_summary());
+        }
+
         last_report = now;
     }
 
@@ -274,6 +290,32 @@ mod tests {
         handle.await.expect("join");
     }
 
+    #[tokio::test]
+    async fn reporter_with_usage_runs_one_cycle() {
+        let querier = Arc::new(MockQuerier::with_activity());
+        let usage = Arc::new(SessionUsage::new());
+        usage.record(
+            "openai",
+            "gpt-4o-mini",
+            &crate::llm::TokenUsage {
+                input_tokens: 100,
+                output_tokens: 20,
+            },
+        );
+        let scheduler =
+            LoopScheduler::new(Duration::from_millis(10), Duration::ZERO, Duration::ZERO);
+        let cancel = CancellationToken::new();
+
+        let cancel_clone = cancel.clone();
+        let handle = tokio::spawn(async move {
+            run_status_reporter_with_usage(querier, Some(usage), scheduler, cancel_clone).await;
+        });
+
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        cancel.cancel();
+        handle.await.expect("join");
+    }
+
     #[tokio::test]
     async fn reporter_handles_query_error() {
         let querier = Arc::new(FailingQuerier);
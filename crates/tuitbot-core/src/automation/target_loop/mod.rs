@@ -6,6 +6,7 @@
 //! from keyword-based discovery to enable genuine engagement with specific
 //! people.
 
+use super::loop_breaker::{breaker_gate, record_iteration, LoopBreaker};
 use super::loop_helpers::{
     ConsecutiveErrorTracker, LoopError, LoopTweet, PostSender, ReplyGenerator, SafetyChecker,
 };
@@ -124,6 +125,8 @@ pub struct TargetLoop {
     storage: Arc<dyn TargetStorage>,
     poster: Arc<dyn PostSender>,
     config: TargetLoopConfig,
+    /// Error budget checked between iterations of `run`.
+    breaker: Option<LoopBreaker>,
 }
 
 impl TargetLoop {
@@ -146,9 +149,16 @@ impl TargetLoop {
             storage,
             poster,
             config,
+            breaker: None,
         }
     }
 
+    /// Pause the loop while its persisted error budget is spent.
+    pub fn with_loop_breaker(mut self, breaker: LoopBreaker) -> Self {
+        self.breaker = Some(breaker);
+        self
+    }
+
     /// Run the continuous target monitoring loop until cancellation.
     pub async fn run(
         &self,
@@ -180,9 +190,14 @@ impl TargetLoop {
                 break;
             }
 
+            if !breaker_gate(&self.breaker, "target", &cancel).await {
+                break;
+            }
+
             match self.run_iteration().await {
                 Ok(results) => {
                     error_tracker.record_success();
+                    record_iteration(&self.breaker, "target", Ok(())).await;
                     let replied = results
                         .iter()
                         .filter(|r| matches!(r, TargetResult::Replied { .. }))
@@ -201,6 +216,7 @@ impl TargetLoop {
                     }
                 }
                 Err(e) => {
+                    record_iteration(&self.breaker, "target", Err(&e.to_string())).await;
                     let should_pause = error_tracker.record_error();
                     tracing::warn!(
                         error = %e,
@@ -378,6 +394,17 @@ impl TargetLoop {
             };
         }
 
+        if !self
+            .safety
+            .allows_conversation(tweet.conversation_id.as_deref())
+            .await
+        {
+            return TargetResult::Skipped {
+                tweet_id: tweet.id.clone(),
+                reason: "conversation limit reached".to_string(),
+            };
+        }
+
         // Generate reply with vault context (no product mention — genuine engagement)
         let reply_output = match self
             .generator
@@ -394,6 +421,13 @@ impl TargetLoop {
         };
         let reply_text = reply_output.text;
 
+        if !self.safety.allows_reply_text(&reply_text).await {
+            return TargetResult::Skipped {
+                tweet_id: tweet.id.clone(),
+                reason: "banned phrase".to_string(),
+            };
+        }
+
         tracing::info!(
             username = %username,
             "Replied to target @{}",
@@ -417,14 +451,22 @@ impl TargetLoop {
                 )
                 .await;
         } else {
-            if let Err(e) = self.poster.send_reply(&tweet.id, &reply_text).await {
+            if let Err(e) = self
+                .poster
+                .send_reply_with_framework(&tweet.id, &reply_text, reply_output.framework.as_ref())
+                .await
+            {
                 return TargetResult::Failed {
                     tweet_id: tweet.id.clone(),
                     error: e.to_string(),
                 };
             }
 
-            if let Err(e) = self.safety.record_reply(&tweet.id, &reply_text).await {
+            if let Err(e) = self
+                .safety
+                .record_reply(&tweet.id, tweet.conversation_id.as_deref(), &reply_text)
+                .await
+            {
                 tracing::warn!(tweet_id = %tweet.id, error = %e, "Failed to record reply");
             }
 
@@ -219,7 +219,10 @@ async fn skips_when_already_replied() {
     let poster = Arc::new(MockPoster::new());
     let safety = Arc::new(MockSafety::new(true));
     // Pre-mark tw1 as replied
-    safety.record_reply("tw1", "already replied").await.unwrap();
+    safety
+        .record_reply("tw1", None, "already replied")
+        .await
+        .unwrap();
 
     let user_mgr = Arc::new(MockUserManager {
         users: vec![(
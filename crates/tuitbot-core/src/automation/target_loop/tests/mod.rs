@@ -75,7 +75,12 @@ impl SafetyChecker for MockSafety {
             .expect("lock")
             .contains(&tweet_id.to_string())
     }
-    async fn record_reply(&self, tweet_id: &str, _content: &str) -> Result<(), LoopError> {
+    async fn record_reply(
+        &self,
+        tweet_id: &str,
+        _conversation_id: Option<&str>,
+        _content: &str,
+    ) -> Result<(), LoopError> {
         self.replied_ids
             .lock()
             .expect("lock")
@@ -183,6 +188,9 @@ fn test_tweet(id: &str, author: &str) -> LoopTweet {
         likes: 10,
         retweets: 2,
         replies: 1,
+        in_reply_to_id: None,
+        conversation_id: None,
+        author_protected: None,
     }
 }
 
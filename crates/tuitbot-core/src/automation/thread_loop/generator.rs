@@ -4,7 +4,8 @@
 //! `post_reply_chain` on [`ThreadLoop`].
 
 use super::super::loop_helpers::ContentLoopError;
-use super::{ThreadLoop, ThreadResult};
+use super::{ThreadLoop, ThreadOutput, ThreadResult};
+use crate::content::FrameworkPick;
 use std::time::Duration;
 
 impl ThreadLoop {
@@ -17,10 +18,11 @@ impl ThreadLoop {
         tracing::info!(topic = %topic, "Generating thread on topic");
 
         // Generate with retries for length validation
-        let tweets = match self.generate_with_validation(topic, count).await {
-            Ok(tweets) => tweets,
-            Err(result) => return result,
-        };
+        let ThreadOutput { tweets, framework } =
+            match self.generate_with_validation(topic, count).await {
+                Ok(output) => output,
+                Err(result) => return result,
+            };
 
         let tweet_count = tweets.len();
 
@@ -66,7 +68,9 @@ impl ThreadLoop {
         };
 
         // Post tweets as reply chain
-        let result = self.post_reply_chain(&thread_id, &tweets, topic).await;
+        let result = self
+            .post_reply_chain(&thread_id, &tweets, topic, framework.as_ref())
+            .await;
 
         // Log action
         let (status, message) = match &result {
@@ -98,7 +102,7 @@ impl ThreadLoop {
         &self,
         topic: &str,
         count: Option<usize>,
-    ) -> Result<Vec<String>, ThreadResult> {
+    ) -> Result<ThreadOutput, ThreadResult> {
         let max_retries = 3;
 
         for attempt in 0..max_retries {
@@ -108,12 +112,12 @@ impl ThreadLoop {
                 format!("{topic} (IMPORTANT: each tweet MUST be under 280 characters)")
             };
 
-            let tweets = match self
+            let output = match self
                 .generator
-                .generate_thread(&effective_topic, count)
+                .generate_thread_with_framework(&effective_topic, count)
                 .await
             {
-                Ok(t) => t,
+                Ok(output) => output,
                 Err(e) => {
                     return Err(ThreadResult::Failed {
                         error: format!("Generation failed: {e}"),
@@ -122,12 +126,13 @@ impl ThreadLoop {
             };
 
             // Validate all tweets are <= 280 chars (URL-aware)
+            let tweets = &output.tweets;
             let all_valid = tweets.iter().all(|t| {
                 crate::content::length::tweet_weighted_len(t)
                     <= crate::content::length::MAX_TWEET_CHARS
             });
             if all_valid {
-                return Ok(tweets);
+                return Ok(output);
             }
 
             let over_limit: Vec<usize> = tweets
@@ -154,8 +159,20 @@ impl ThreadLoop {
         })
     }
 
+    /// Store tweets from `from` onward with an empty tweet ID so the thread
+    /// can be resumed later (`tuitbot thread --resume`).
+    async fn store_unposted_tail(&self, thread_id: &str, tweets: &[String], from: usize) {
+        for (pos, content) in tweets.iter().enumerate().skip(from) {
+            let _ = self
+                .storage
+                .store_thread_tweet(thread_id, pos, "", content)
+                .await;
+        }
+    }
+
     /// Post tweets as a reply chain. First tweet is standalone,
-    /// each subsequent tweet replies to the previous one.
+    /// each subsequent tweet replies to the previous one. The root tweet is
+    /// linked to `framework`, the structure that shaped the thread.
     ///
     /// On transient error (429, 5xx, timeout), retries up to 3 times with exponential backoff.
     /// On permanent error (401, validation), marks thread as failed and stops.
@@ -164,6 +181,7 @@ impl ThreadLoop {
         thread_id: &str,
         tweets: &[String],
         topic: &str,
+        framework: Option<&FrameworkPick>,
     ) -> ThreadResult {
         use super::super::loop_helpers::{is_transient_error, thread_retry_backoff};
 
@@ -209,6 +227,7 @@ impl ThreadLoop {
                                 .storage
                                 .mark_failed_permanent(thread_id, &last_error)
                                 .await;
+                            self.store_unposted_tail(thread_id, tweets, i).await;
                             return ThreadResult::PartialFailure {
                                 topic: topic.to_string(),
                                 tweets_posted: i,
@@ -266,6 +285,11 @@ impl ThreadLoop {
                             .storage
                             .update_thread_status(thread_id, "posting", i + 1, Some(&new_tweet_id))
                             .await;
+                        if let Some(framework) = framework {
+                            self.storage
+                                .link_thread_framework(&new_tweet_id, framework)
+                                .await;
+                        }
                     }
 
                     let _ = self
@@ -297,6 +321,7 @@ impl ThreadLoop {
                         .storage
                         .update_thread_status(thread_id, "partial", i, root_tweet_id.as_deref())
                         .await;
+                    self.store_unposted_tail(thread_id, tweets, i).await;
 
                     return ThreadResult::PartialFailure {
                         topic: topic.to_string(),
@@ -460,7 +485,8 @@ mod tests {
             }
             other => panic!("Expected PartialFailure, got {other:?}"),
         }
-        assert_eq!(storage.thread_tweet_count(), 2);
+        // Unposted tweets are stored with an empty ID for resumption.
+        assert_eq!(storage.thread_tweet_count(), 5);
         assert_eq!(poster.posted_count(), 2);
     }
 
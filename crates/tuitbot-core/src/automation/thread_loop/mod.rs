@@ -17,7 +17,11 @@ mod planner;
 #[cfg(test)]
 mod tests_guardrails; // Task 3.5: safety guardrails + thread semantics
 
+use super::loop_breaker::LoopBreaker;
 use super::loop_helpers::{ContentLoopError, ContentSafety, ContentStorage, ThreadPoster};
+use crate::content::FrameworkPick;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
 use std::sync::Arc;
 
 /// Thread loop that generates and posts educational threads.
@@ -28,7 +32,11 @@ pub struct ThreadLoop {
     pub(super) poster: Arc<dyn ThreadPoster>,
     pub(super) topics: Vec<String>,
     pub(super) thread_interval_secs: u64,
+    /// Seed for topic picks (`content.random_seed`).
+    pub(super) random_seed: Option<u64>,
     pub(super) dry_run: bool,
+    /// Error budget checked between iterations of `run`.
+    pub(super) breaker: Option<LoopBreaker>,
 }
 
 /// Trait for generating multi-tweet threads.
@@ -43,6 +51,30 @@ pub trait ThreadGenerator: Send + Sync {
         topic: &str,
         count: Option<usize>,
     ) -> Result<Vec<String>, ContentLoopError>;
+
+    /// Generate a thread along with the structure that shaped it.
+    ///
+    /// Default implementation delegates to `generate_thread` with no framework.
+    async fn generate_thread_with_framework(
+        &self,
+        topic: &str,
+        count: Option<usize>,
+    ) -> Result<ThreadOutput, ContentLoopError> {
+        let tweets = self.generate_thread(topic, count).await?;
+        Ok(ThreadOutput {
+            tweets,
+            framework: None,
+        })
+    }
+}
+
+/// Output from thread generation, carrying the tweets and the structure behind them.
+#[derive(Debug, Clone)]
+pub struct ThreadOutput {
+    /// The generated tweets, in posting order.
+    pub tweets: Vec<String>,
+    /// Structure that shaped the thread, when the generator picked one.
+    pub framework: Option<FrameworkPick>,
 }
 
 /// Result of a thread generation/posting attempt.
@@ -95,7 +127,29 @@ impl ThreadLoop {
             poster,
             topics,
             thread_interval_secs,
+            random_seed: None,
             dry_run,
+            breaker: None,
+        }
+    }
+
+    /// Seed the loop's RNG so topic picks are reproducible.
+    pub fn with_random_seed(mut self, seed: Option<u64>) -> Self {
+        self.random_seed = seed;
+        self
+    }
+
+    /// Pause the loop while its persisted error budget is spent.
+    pub fn with_loop_breaker(mut self, breaker: LoopBreaker) -> Self {
+        self.breaker = Some(breaker);
+        self
+    }
+
+    /// RNG for topic picks, seeded by `content.random_seed`.
+    pub(super) fn rng(&self) -> StdRng {
+        match self.random_seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_rng(&mut rand::rng()),
         }
     }
 }
@@ -3,11 +3,11 @@
 //! Implements `run`, `run_once`, `run_iteration`, and `log_thread_result`
 //! on [`ThreadLoop`].
 
+use super::super::loop_breaker::{breaker_gate, record_iteration};
 use super::super::schedule::{apply_slot_jitter, schedule_gate, ActiveSchedule};
 use super::super::scheduler::LoopScheduler;
 use super::{ThreadLoop, ThreadResult};
 use rand::seq::IndexedRandom;
-use rand::SeedableRng;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio_util::sync::CancellationToken;
@@ -43,7 +43,7 @@ impl ThreadLoop {
             .max(min_recent)
             .min(self.topics.len());
         let mut recent_topics: Vec<String> = Vec::with_capacity(max_recent);
-        let mut rng = rand::rngs::StdRng::from_rng(&mut rand::rng());
+        let mut rng = self.rng();
 
         loop {
             if cancel.is_cancelled() {
@@ -54,6 +54,10 @@ impl ThreadLoop {
                 break;
             }
 
+            if !breaker_gate(&self.breaker, "thread", &cancel).await {
+                break;
+            }
+
             if slot_mode {
                 let sched = schedule.as_ref().expect("slot_mode requires schedule");
 
@@ -90,6 +94,7 @@ impl ThreadLoop {
                         }
 
                         Self::log_thread_result(&result, self.dry_run);
+                        self.record_thread_result(&result).await;
                     }
                     None => {
                         tracing::warn!("Thread slot mode: no next slot found, sleeping 1 hour");
@@ -104,6 +109,7 @@ impl ThreadLoop {
                     .run_iteration(&mut recent_topics, max_recent, &mut rng)
                     .await;
                 Self::log_thread_result(&result, self.dry_run);
+                self.record_thread_result(&result).await;
 
                 tokio::select! {
                     _ = cancel.cancelled() => break,
@@ -115,6 +121,20 @@ impl ThreadLoop {
         tracing::info!("Thread loop stopped");
     }
 
+    /// Spend or reset the error budget; skipped iterations leave it untouched.
+    async fn record_thread_result(&self, result: &ThreadResult) {
+        let outcome = match result {
+            ThreadResult::Posted { .. } => Ok(()),
+            ThreadResult::PartialFailure { error, .. }
+            | ThreadResult::ValidationFailed { error }
+            | ThreadResult::Failed { error } => Err(error.as_str()),
+            ThreadResult::TooSoon { .. } | ThreadResult::RateLimited | ThreadResult::NoTopics => {
+                return
+            }
+        };
+        record_iteration(&self.breaker, "thread", outcome).await;
+    }
+
     /// Log the result of a thread iteration.
     pub(super) fn log_thread_result(result: &ThreadResult, dry_run: bool) {
         match result {
@@ -170,7 +190,7 @@ impl ThreadLoop {
                 if self.topics.is_empty() {
                     return ThreadResult::NoTopics;
                 }
-                let mut rng = rand::rng();
+                let mut rng = self.rng();
                 self.topics
                     .choose(&mut rng)
                     .expect("topics is non-empty")
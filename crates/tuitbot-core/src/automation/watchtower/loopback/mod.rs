@@ -12,8 +12,11 @@ pub mod sync;
 #[cfg(test)]
 mod tests;
 
+use std::collections::{HashMap, VecDeque};
 use std::io;
 use std::path::Path;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
 use serde::{Deserialize, Serialize};
 
@@ -67,6 +70,62 @@ pub enum LoopBackResult {
     NodeNotFound,
     /// The source file does not exist on disk.
     FileNotFound,
+    /// The source already had its hourly quota of loop-back writes.
+    Throttled,
+}
+
+/// Per-source cap on loop-back writes in any rolling hour.
+///
+/// Shared by every loop-back write of a process so a burst of approvals
+/// cannot rewrite the same notes (and trigger re-ingests) in a storm.
+#[derive(Debug, Default)]
+pub struct LoopBackThrottle {
+    /// Writes allowed per source per hour; 0 means unlimited.
+    max_per_hour: u32,
+    writes: Mutex<HashMap<i64, VecDeque<Instant>>>,
+}
+
+impl LoopBackThrottle {
+    const WINDOW: Duration = Duration::from_secs(3600);
+
+    /// Create a throttle allowing `max_per_hour` writes per source (0 = unlimited).
+    pub fn new(max_per_hour: u32) -> Self {
+        Self {
+            max_per_hour,
+            writes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Whether `source_id` may write at `now`.
+    fn has_capacity(&self, source_id: i64, now: Instant) -> bool {
+        if self.max_per_hour == 0 {
+            return true;
+        }
+        let mut writes = match self.writes.lock() {
+            Ok(w) => w,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        let recent = writes.entry(source_id).or_default();
+        while recent
+            .front()
+            .is_some_and(|t| now.saturating_duration_since(*t) >= Self::WINDOW)
+        {
+            recent.pop_front();
+        }
+        recent.len() < self.max_per_hour as usize
+    }
+
+    /// Count a completed write for `source_id` at `now`.
+    fn record(&self, source_id: i64, now: Instant) {
+        if self.max_per_hour == 0 {
+            return;
+        }
+        let mut writes = match self.writes.lock() {
+            Ok(w) => w,
+            Err(poisoned) => poisoned.into_inner(),
+        };
+        writes.entry(source_id).or_default().push_back(now);
+    }
 }
 
 /// Execute provenance-driven loop-back: look up the source note for a content
@@ -74,8 +133,10 @@ pub enum LoopBackResult {
 ///
 /// Returns `LoopBackResult` indicating the outcome. DB lookup failures are
 /// logged and mapped to result variants rather than propagated as errors.
+/// Writes beyond the source's hourly quota in `throttle` are skipped.
 pub async fn execute_loopback(
     pool: &DbPool,
+    throttle: &LoopBackThrottle,
     node_id: i64,
     tweet_id: &str,
     url: &str,
@@ -137,8 +198,21 @@ pub async fn execute_loopback(
         synced_at: None,
     };
 
+    if !throttle.has_capacity(node.source_id, Instant::now()) {
+        tracing::warn!(
+            node_id,
+            source_id = node.source_id,
+            path = %full_path.display(),
+            "Loopback write skipped: hourly write cap reached for source"
+        );
+        return LoopBackResult::Throttled;
+    }
+
     match write_metadata_to_file(&full_path, &entry) {
-        Ok(true) => LoopBackResult::Written,
+        Ok(true) => {
+            throttle.record(node.source_id, Instant::now());
+            LoopBackResult::Written
+        }
         Ok(false) => LoopBackResult::AlreadyPresent,
         Err(e) => {
             tracing::warn!(
@@ -158,6 +232,7 @@ pub async fn execute_loopback(
 /// `child_tweet_ids` and `thread_url` populated.
 pub async fn execute_loopback_thread(
     pool: &DbPool,
+    throttle: &LoopBackThrottle,
     node_id: i64,
     root_tweet_id: &str,
     url: &str,
@@ -223,8 +298,21 @@ pub async fn execute_loopback_thread(
         synced_at: None,
     };
 
+    if !throttle.has_capacity(node.source_id, Instant::now()) {
+        tracing::warn!(
+            node_id,
+            source_id = node.source_id,
+            path = %full_path.display(),
+            "Loopback write skipped: hourly write cap reached for source"
+        );
+        return LoopBackResult::Throttled;
+    }
+
     match write_metadata_to_file(&full_path, &entry) {
-        Ok(true) => LoopBackResult::Written,
+        Ok(true) => {
+            throttle.record(node.source_id, Instant::now());
+            LoopBackResult::Written
+        }
         Ok(false) => LoopBackResult::AlreadyPresent,
         Err(e) => {
             tracing::warn!(
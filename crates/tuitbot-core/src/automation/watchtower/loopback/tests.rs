@@ -968,3 +968,64 @@ fn update_analytics_no_frontmatter_returns_entry_not_found() {
         update_entry_analytics(&path, "tweet_999", &analytics, &default_percentiles()).unwrap();
     assert_eq!(result, UpdateResult::EntryNotFound);
 }
+
+// ---------------------------------------------------------------------------
+// Hourly write cap
+// ---------------------------------------------------------------------------
+
+#[test]
+fn throttle_caps_writes_per_source_in_rolling_hour() {
+    let throttle = LoopBackThrottle::new(2);
+    let t0 = Instant::now();
+
+    assert!(throttle.has_capacity(1, t0));
+    throttle.record(1, t0);
+    throttle.record(1, t0 + Duration::from_secs(600));
+    assert!(!throttle.has_capacity(1, t0 + Duration::from_secs(1200)));
+    // Other sources have their own budget.
+    assert!(throttle.has_capacity(2, t0 + Duration::from_secs(1200)));
+    // The first write leaves the window after an hour.
+    assert!(throttle.has_capacity(1, t0 + Duration::from_secs(3600)));
+}
+
+#[test]
+fn throttle_zero_is_unlimited() {
+    let throttle = LoopBackThrottle::new(0);
+    let now = Instant::now();
+    for _ in 0..100 {
+        throttle.record(1, now);
+    }
+    assert!(throttle.has_capacity(1, now));
+}
+
+#[tokio::test]
+async fn execute_loopback_skips_writes_beyond_hourly_cap() {
+    use crate::automation::watchtower::ingest_file;
+    use crate::storage::init_test_db;
+    use crate::storage::watchtower as store;
+
+    let pool = init_test_db().await.expect("init db");
+    let dir = tempfile::tempdir().unwrap();
+    let config = serde_json::json!({ "path": dir.path().to_str().unwrap() }).to_string();
+    let src_id = store::insert_source_context(&pool, "local_fs", &config)
+        .await
+        .unwrap();
+    for name in ["a.md", "b.md"] {
+        fs::write(dir.path().join(name), "A note.\n").unwrap();
+        ingest_file(&pool, src_id, dir.path(), name, false)
+            .await
+            .unwrap();
+    }
+    let nodes = store::get_nodes_for_source(&pool, src_id, None)
+        .await
+        .unwrap();
+
+    let throttle = LoopBackThrottle::new(1);
+    let first = execute_loopback(&pool, &throttle, nodes[0].id, "111", "u1", "tweet").await;
+    assert_eq!(first, LoopBackResult::Written);
+
+    let second = execute_loopback(&pool, &throttle, nodes[1].id, "222", "u2", "tweet").await;
+    assert_eq!(second, LoopBackResult::Throttled);
+    let untouched = fs::read_to_string(dir.path().join(&nodes[1].relative_path)).unwrap();
+    assert!(parse_tuitbot_metadata(&untouched).is_empty());
+}
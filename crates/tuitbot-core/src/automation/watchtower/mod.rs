@@ -2,7 +2,7 @@
 //!
 //! Watches configured local directories for `.md` and `.txt` changes via
 //! the `notify` crate with debouncing, and polls remote content sources
-//! (e.g. Google Drive) on a configurable interval.  Both local filesystem
+//! (e.g. Google Drive, Notion) on a configurable interval.  Both local filesystem
 //! events and remote polls funnel through `ingest_content()`, ensuring
 //! identical state transitions.
 
@@ -70,6 +70,23 @@ pub struct IngestSummary {
     pub errors: Vec<String>,
 }
 
+impl IngestSummary {
+    /// Fold one file's ingest result into the summary.
+    fn record(&mut self, rel_path: &str, result: Result<store::UpsertResult, WatchtowerError>) {
+        match result {
+            Ok(store::UpsertResult::Inserted | store::UpsertResult::Updated) => {
+                self.ingested += 1;
+            }
+            Ok(store::UpsertResult::Skipped) => {
+                self.skipped += 1;
+            }
+            Err(e) => {
+                self.errors.push(format!("{rel_path}: {e}"));
+            }
+        }
+    }
+}
+
 /// Parsed front-matter from a markdown file.
 #[derive(Debug, Default)]
 pub struct ParsedFrontMatter {
@@ -155,6 +172,64 @@ pub fn matches_patterns(path: &Path, patterns: &[String]) -> bool {
     false
 }
 
+// ---------------------------------------------------------------------------
+// File limits
+// ---------------------------------------------------------------------------
+
+/// Size and type limits checked before a source file is read.
+#[derive(Debug, Clone)]
+pub struct FileLimits {
+    /// Largest file ingested, in bytes (0 = unlimited).
+    pub max_bytes: u64,
+    /// Allowed extensions, lowercase and without the dot.
+    pub allowed_extensions: Vec<String>,
+}
+
+impl FileLimits {
+    /// Limits from `content_sources.max_file_bytes` and `allowed_extensions`.
+    pub fn from_config(config: &ContentSourcesConfig) -> Self {
+        Self {
+            max_bytes: config.max_file_bytes,
+            allowed_extensions: config
+                .allowed_extensions
+                .iter()
+                .map(|ext| ext.trim().trim_start_matches('.').to_ascii_lowercase())
+                .collect(),
+        }
+    }
+
+    /// Why a local file of `len` bytes must be skipped, or `None` to ingest it.
+    pub fn rejection(&self, path: &Path, len: u64) -> Option<String> {
+        let ext = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(str::to_ascii_lowercase)
+            .unwrap_or_default();
+        if !self.allowed_extensions.contains(&ext) {
+            return Some(format!(
+                "extension '{ext}' is not in content_sources.allowed_extensions"
+            ));
+        }
+        self.size_rejection(len)
+    }
+
+    /// Why content of `len` bytes must be skipped for its size, if it must.
+    fn size_rejection(&self, len: u64) -> Option<String> {
+        (self.max_bytes > 0 && len > self.max_bytes).then(|| {
+            format!(
+                "{len} bytes exceeds content_sources.max_file_bytes ({})",
+                self.max_bytes
+            )
+        })
+    }
+}
+
+impl Default for FileLimits {
+    fn default() -> Self {
+        Self::from_config(&ContentSourcesConfig::default())
+    }
+}
+
 /// Convert a relative path into a stable slash-delimited string across platforms.
 fn relative_path_string(path: &Path) -> String {
     path.iter()
@@ -216,14 +291,43 @@ pub async fn ingest_content(
 /// Ingest a single file from the local filesystem into the Watchtower pipeline.
 ///
 /// Convenience wrapper that reads the file then delegates to `ingest_content`.
+/// Applies the default [`FileLimits`].
 pub async fn ingest_file(
     pool: &DbPool,
     source_id: i64,
     base_path: &Path,
     relative_path: &str,
     force: bool,
+) -> Result<store::UpsertResult, WatchtowerError> {
+    ingest_file_with_limits(
+        pool,
+        source_id,
+        base_path,
+        relative_path,
+        force,
+        &FileLimits::default(),
+    )
+    .await
+}
+
+/// Ingest a single file if it passes `limits`.
+///
+/// Oversize files and files with a disallowed extension are not read: the
+/// reason is logged and the file counts as skipped.
+pub async fn ingest_file_with_limits(
+    pool: &DbPool,
+    source_id: i64,
+    base_path: &Path,
+    relative_path: &str,
+    force: bool,
+    limits: &FileLimits,
 ) -> Result<store::UpsertResult, WatchtowerError> {
     let full_path = base_path.join(relative_path);
+    let len = tokio::fs::metadata(&full_path).await?.len();
+    if let Some(reason) = limits.rejection(&full_path, len) {
+        tracing::info!(path = relative_path, reason = %reason, "Watchtower skipped file");
+        return Ok(store::UpsertResult::Skipped);
+    }
     let content = tokio::fs::read_to_string(&full_path).await?;
     ingest_content(pool, source_id, relative_path, &content, force).await
 }
@@ -235,23 +339,75 @@ pub async fn ingest_files(
     base_path: &Path,
     paths: &[String],
     force: bool,
+    limits: &FileLimits,
 ) -> IngestSummary {
     let mut summary = IngestSummary::default();
 
     for rel_path in paths {
-        match ingest_file(pool, source_id, base_path, rel_path, force).await {
-            Ok(store::UpsertResult::Inserted | store::UpsertResult::Updated) => {
-                summary.ingested += 1;
-            }
-            Ok(store::UpsertResult::Skipped) => {
-                summary.skipped += 1;
-            }
-            Err(e) => {
-                summary.errors.push(format!("{rel_path}: {e}"));
-            }
+        let result =
+            ingest_file_with_limits(pool, source_id, base_path, rel_path, force, limits).await;
+        summary.record(rel_path, result);
+    }
+
+    summary
+}
+
+/// Ingest multiple files with up to `concurrency` files in flight at once.
+///
+/// Produces the same summary as [`ingest_files`]: counts are identical and
+/// errors are listed in input order regardless of completion order. A
+/// concurrency of 0 or 1 runs sequentially. Concurrent upserts rely on the
+/// pool's busy timeout to wait out SQLite write locks.
+pub async fn ingest_files_concurrent(
+    pool: &DbPool,
+    source_id: i64,
+    base_path: &Path,
+    paths: &[String],
+    force: bool,
+    limits: &FileLimits,
+    concurrency: usize,
+) -> IngestSummary {
+    if concurrency <= 1 {
+        return ingest_files(pool, source_id, base_path, paths, force, limits).await;
+    }
+
+    let mut results: Vec<Option<Result<store::UpsertResult, WatchtowerError>>> =
+        (0..paths.len()).map(|_| None).collect();
+    let mut task_failures = Vec::new();
+    let mut pending = paths.iter().cloned().enumerate();
+    let mut tasks = tokio::task::JoinSet::new();
+
+    loop {
+        while tasks.len() < concurrency {
+            let Some((index, rel_path)) = pending.next() else {
+                break;
+            };
+            let pool = pool.clone();
+            let base_path = base_path.to_path_buf();
+            let limits = limits.clone();
+            tasks.spawn(async move {
+                let result = ingest_file_with_limits(
+                    &pool, source_id, &base_path, &rel_path, force, &limits,
+                )
+                .await;
+                (index, result)
+            });
+        }
+
+        match tasks.join_next().await {
+            Some(Ok((index, result))) => results[index] = Some(result),
+            Some(Err(e)) => task_failures.push(format!("ingest task failed: {e}")),
+            None => break,
         }
     }
 
+    let mut summary = IngestSummary::default();
+    for (rel_path, result) in paths.iter().zip(results) {
+        if let Some(result) = result {
+            summary.record(rel_path, result);
+        }
+    }
+    summary.errors.extend(task_failures);
     summary
 }
 
@@ -317,12 +473,16 @@ pub struct WatchtowerLoop {
 
 impl WatchtowerLoop {
     /// Create a new WatchtowerLoop.
+    ///
+    /// The cooldown for paths written by loop-back comes from
+    /// `config.loopback.cooldown_seconds`.
     pub fn new(
         pool: DbPool,
         config: ContentSourcesConfig,
         connector_config: ConnectorConfig,
         data_dir: PathBuf,
     ) -> Self {
+        let cooldown_ttl = Duration::from_secs(config.loopback.cooldown_seconds);
         Self {
             pool,
             config,
@@ -330,7 +490,7 @@ impl WatchtowerLoop {
             data_dir,
             debounce_duration: Duration::from_secs(2),
             fallback_scan_interval: Duration::from_secs(300), // 5 minutes
-            cooldown_ttl: Duration::from_secs(5),
+            cooldown_ttl,
         }
     }
 
@@ -338,7 +498,7 @@ impl WatchtowerLoop {
     ///
     /// Registers both local filesystem and remote sources, then runs:
     /// - `notify` watcher + fallback polling for local sources
-    /// - interval-based polling for remote sources (e.g. Google Drive)
+    /// - interval-based polling for remote sources (Google Drive, Notion)
     pub async fn run(&self, cancel: CancellationToken) {
         // Split config into local (watchable) and remote (pollable) sources.
         // Uses `is_enabled()` which respects both `enabled` and legacy `watch`.
@@ -356,7 +516,14 @@ impl WatchtowerLoop {
             .filter(|s| s.source_type == "google_drive" && s.is_enabled() && s.folder_id.is_some())
             .collect();
 
-        if local_sources.is_empty() && remote_sources.is_empty() {
+        let notion_sources: Vec<_> = self
+            .config
+            .sources
+            .iter()
+            .filter(|s| s.source_type == "notion" && s.is_enabled() && s.database_id.is_some())
+            .collect();
+
+        if local_sources.is_empty() && remote_sources.is_empty() && notion_sources.is_empty() {
             tracing::info!("Watchtower: no watch sources configured, exiting");
             return;
         }
@@ -442,6 +609,45 @@ impl WatchtowerLoop {
             }
         }
 
+        for src in &notion_sources {
+            let database_id = src.database_id.as_deref().unwrap();
+            let token = match crate::source::notion::NotionProvider::token_from_env(
+                src.api_token_env.as_deref(),
+            ) {
+                Ok(token) => token,
+                Err(e) => {
+                    tracing::warn!(database_id, error = %e, "Skipping Notion source");
+                    continue;
+                }
+            };
+            let config_json = serde_json::json!({
+                "database_id": database_id,
+                "api_token_env": src.api_token_env,
+            })
+            .to_string();
+
+            match store::ensure_notion_source(&self.pool, database_id, &config_json).await {
+                Ok(source_id) => {
+                    let interval = Duration::from_secs(src.poll_interval_seconds.unwrap_or(300));
+                    let provider =
+                        crate::source::notion::NotionProvider::new(database_id.to_string(), token);
+                    remote_map.push((
+                        source_id,
+                        Box::new(provider),
+                        src.file_patterns.clone(),
+                        interval,
+                    ));
+                }
+                Err(e) => {
+                    tracing::error!(
+                        database_id,
+                        error = %e,
+                        "Failed to register Notion source"
+                    );
+                }
+            }
+        }
+
         if source_map.is_empty() && remote_map.is_empty() {
             tracing::warn!("Watchtower: no sources registered, exiting");
             return;
@@ -648,7 +854,12 @@ impl WatchtowerLoop {
                     Err(_) => return,
                 };
 
-                match ingest_file(&self.pool, *source_id, base_path, &rel, false).await {
+                let limits = FileLimits::from_config(&self.config);
+                match ingest_file_with_limits(
+                    &self.pool, *source_id, base_path, &rel, false, &limits,
+                )
+                .await
+                {
                     Ok(result) => {
                         tracing::debug!(
                             path = %rel,
@@ -679,7 +890,16 @@ impl WatchtowerLoop {
         let mut rel_paths = Vec::new();
         Self::walk_directory(base_path, base_path, patterns, &mut rel_paths)?;
 
-        let summary = ingest_files(&self.pool, source_id, base_path, &rel_paths, false).await;
+        let summary = ingest_files_concurrent(
+            &self.pool,
+            source_id,
+            base_path,
+            &rel_paths,
+            false,
+            &FileLimits::from_config(&self.config),
+            self.config.scan_concurrency as usize,
+        )
+        .await;
 
         tracing::debug!(
             path = %base_path.display(),
@@ -730,6 +950,7 @@ impl WatchtowerLoop {
 
     /// Poll all remote sources for changes, ingest new/updated content.
     async fn poll_remote_sources(&self, remote_sources: &[RemoteSource]) {
+        let limits = FileLimits::from_config(&self.config);
         for (source_id, provider, patterns, _interval) in remote_sources {
             let _ = store::update_source_status(&self.pool, *source_id, "syncing", None).await;
 
@@ -749,6 +970,15 @@ impl WatchtowerLoop {
                     for file in &files {
                         match provider.read_content(&file.provider_id).await {
                             Ok(content) => {
+                                if let Some(reason) = limits.size_rejection(content.len() as u64) {
+                                    tracing::info!(
+                                        provider_id = %file.provider_id,
+                                        reason = %reason,
+                                        "Watchtower skipped remote content"
+                                    );
+                                    skipped += 1;
+                                    continue;
+                                }
                                 match ingest_content(
                                     &self.pool,
                                     *source_id,
@@ -894,19 +1124,31 @@ impl WatchtowerLoop {
     /// Perform a one-shot full rescan of a single local source.
     ///
     /// Used by the reindex API. Sets status to `"syncing"` before the scan
-    /// and `"active"` (or `"error"`) afterward.
+    /// and `"active"` (or `"error"`) afterward. Up to `concurrency` files
+    /// are ingested at once; files failing `limits` are skipped.
     pub async fn reindex_local_source(
         pool: &DbPool,
         source_id: i64,
         base_path: &Path,
         patterns: &[String],
+        limits: &FileLimits,
+        concurrency: usize,
     ) -> Result<IngestSummary, WatchtowerError> {
         store::update_source_status(pool, source_id, "syncing", None).await?;
 
         let mut rel_paths = Vec::new();
         Self::walk_directory(base_path, base_path, patterns, &mut rel_paths)?;
 
-        let summary = ingest_files(pool, source_id, base_path, &rel_paths, true).await;
+        let summary = ingest_files_concurrent(
+            pool,
+            source_id,
+            base_path,
+            &rel_paths,
+            true,
+            limits,
+            concurrency,
+        )
+        .await;
 
         let cursor = chrono::Utc::now().to_rfc3339();
         let _ = store::update_sync_cursor(pool, source_id, &cursor).await;
@@ -218,7 +218,15 @@ async fn batch_ingest_summary() {
 
     // First batch: 2 new files.
     let paths = vec!["a.md".to_string(), "b.md".to_string()];
-    let summary = ingest_files(&pool, source_id, dir.path(), &paths, false).await;
+    let summary = ingest_files(
+        &pool,
+        source_id,
+        dir.path(),
+        &paths,
+        false,
+        &FileLimits::default(),
+    )
+    .await;
     assert_eq!(summary.ingested, 2);
     assert_eq!(summary.skipped, 0);
 
@@ -228,11 +236,156 @@ async fn batch_ingest_summary() {
         "b.md".to_string(),
         "missing.md".to_string(),
     ];
-    let summary2 = ingest_files(&pool, source_id, dir.path(), &paths2, false).await;
+    let summary2 = ingest_files(
+        &pool,
+        source_id,
+        dir.path(),
+        &paths2,
+        false,
+        &FileLimits::default(),
+    )
+    .await;
     assert_eq!(summary2.skipped, 2);
     assert_eq!(summary2.errors.len(), 1);
 }
 
+#[tokio::test]
+async fn concurrent_ingest_matches_sequential_summary() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut paths = Vec::new();
+    for i in 0..40 {
+        let name = format!("note-{i:02}.md");
+        std::fs::write(dir.path().join(&name), format!("Note {i}.\n")).unwrap();
+        paths.push(name);
+    }
+    paths.insert(7, "missing-a.md".to_string());
+    paths.push("missing-b.md".to_string());
+
+    let seq_pool = init_test_db().await.expect("init db");
+    let seq_source = store::insert_source_context(&seq_pool, "local_fs", "{}")
+        .await
+        .unwrap();
+    let sequential = ingest_files(
+        &seq_pool,
+        seq_source,
+        dir.path(),
+        &paths,
+        false,
+        &FileLimits::default(),
+    )
+    .await;
+
+    let par_pool = init_test_db().await.expect("init db");
+    let par_source = store::insert_source_context(&par_pool, "local_fs", "{}")
+        .await
+        .unwrap();
+    let concurrent = ingest_files_concurrent(
+        &par_pool,
+        par_source,
+        dir.path(),
+        &paths,
+        false,
+        &FileLimits::default(),
+        4,
+    )
+    .await;
+
+    assert_eq!(concurrent.ingested, 40);
+    assert_eq!(concurrent.ingested, sequential.ingested);
+    assert_eq!(concurrent.skipped, sequential.skipped);
+    assert_eq!(concurrent.errors.len(), 2);
+    // Errors are reported in input order, like the sequential path.
+    assert!(concurrent.errors[0].starts_with("missing-a.md"));
+    assert!(concurrent.errors[1].starts_with("missing-b.md"));
+
+    // A second concurrent pass sees every file as unchanged.
+    let rerun = ingest_files_concurrent(
+        &par_pool,
+        par_source,
+        dir.path(),
+        &paths,
+        false,
+        &FileLimits::default(),
+        4,
+    )
+    .await;
+    assert_eq!(rerun.ingested, 0);
+    assert_eq!(rerun.skipped, 40);
+}
+
+// ---------------------------------------------------------------------------
+// File limits
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn file_within_limits_is_ingested() {
+    let pool = init_test_db().await.expect("init db");
+    let dir = tempfile::tempdir().unwrap();
+    let source_id = store::insert_source_context(&pool, "local_fs", "{}")
+        .await
+        .unwrap();
+    std::fs::write(dir.path().join("note.md"), "A normal note.\n").unwrap();
+
+    let limits = FileLimits {
+        max_bytes: 64,
+        ..FileLimits::default()
+    };
+    let result = ingest_file_with_limits(&pool, source_id, dir.path(), "note.md", false, &limits)
+        .await
+        .unwrap();
+
+    assert_eq!(result, store::UpsertResult::Inserted);
+}
+
+#[tokio::test]
+async fn oversize_and_disallowed_files_are_skipped() {
+    let pool = init_test_db().await.expect("init db");
+    let dir = tempfile::tempdir().unwrap();
+    let source_id = store::insert_source_context(&pool, "local_fs", "{}")
+        .await
+        .unwrap();
+    std::fs::write(dir.path().join("small.md"), "Small.\n").unwrap();
+    std::fs::write(dir.path().join("huge.md"), "x".repeat(65)).unwrap();
+    std::fs::write(dir.path().join("data.csv"), "a,b\n").unwrap();
+
+    let limits = FileLimits {
+        max_bytes: 64,
+        ..FileLimits::default()
+    };
+    let paths = vec![
+        "small.md".to_string(),
+        "huge.md".to_string(),
+        "data.csv".to_string(),
+    ];
+    let summary = ingest_files(&pool, source_id, dir.path(), &paths, false, &limits).await;
+
+    assert_eq!(summary.ingested, 1);
+    assert_eq!(summary.skipped, 2);
+    assert!(summary.errors.is_empty());
+    let nodes = store::get_nodes_for_source(&pool, source_id, None)
+        .await
+        .unwrap();
+    assert_eq!(nodes.len(), 1);
+    assert_eq!(nodes[0].relative_path, "small.md");
+}
+
+#[test]
+fn file_limits_normalize_configured_extensions() {
+    let config = ContentSourcesConfig {
+        max_file_bytes: 0,
+        allowed_extensions: vec![".MD".to_string(), " txt ".to_string()],
+        ..Default::default()
+    };
+    let limits = FileLimits::from_config(&config);
+
+    assert_eq!(limits.rejection(Path::new("Note.md"), u64::MAX), None);
+    assert_eq!(limits.rejection(Path::new("notes.TXT"), 10), None);
+    assert!(limits
+        .rejection(Path::new("Makefile"), 10)
+        .unwrap()
+        .contains("allowed_extensions"));
+}
+
 // ---------------------------------------------------------------------------
 // Cooldown
 // ---------------------------------------------------------------------------
@@ -263,6 +416,52 @@ fn cooldown_cleanup_removes_old() {
     assert!(cd.entries.is_empty());
 }
 
+#[tokio::test]
+async fn configured_cooldown_suppresses_reingest_of_own_write() {
+    let pool = init_test_db().await.expect("init db");
+    let dir = tempfile::tempdir().unwrap();
+    let source_config = serde_json::json!({ "path": dir.path().to_str().unwrap() }).to_string();
+    let src_id = store::insert_source_context(&pool, "local_fs", &source_config)
+        .await
+        .unwrap();
+    let note = dir.path().join("note.md");
+    std::fs::write(&note, "Original.\n").unwrap();
+    ingest_file(&pool, src_id, dir.path(), "note.md", false)
+        .await
+        .unwrap();
+
+    let mut config = ContentSourcesConfig::default();
+    config.loopback.cooldown_seconds = 60;
+    let watchtower = WatchtowerLoop::new(
+        pool.clone(),
+        config,
+        Default::default(),
+        std::env::temp_dir(),
+    );
+    assert_eq!(watchtower.cooldown_ttl, Duration::from_secs(60));
+
+    let source_map = vec![(src_id, dir.path().to_path_buf(), vec!["*.md".to_string()])];
+
+    // A write marked by us is ignored while cooling down.
+    let cooldown = Mutex::new(CooldownSet::new(watchtower.cooldown_ttl));
+    cooldown.lock().unwrap().mark(note.clone());
+    std::fs::write(&note, "Rewritten by loop-back.\n").unwrap();
+    watchtower.handle_event(&note, &source_map, &cooldown).await;
+    let nodes = store::get_nodes_for_source(&pool, src_id, None)
+        .await
+        .unwrap();
+    assert_eq!(nodes[0].body_text, "Original.\n");
+
+    // Once the cooldown has expired the change is ingested.
+    let cooldown = Mutex::new(CooldownSet::new(Duration::ZERO));
+    cooldown.lock().unwrap().mark(note.clone());
+    watchtower.handle_event(&note, &source_map, &cooldown).await;
+    let nodes = store::get_nodes_for_source(&pool, src_id, None)
+        .await
+        .unwrap();
+    assert_eq!(nodes[0].body_text, "Rewritten by loop-back.\n");
+}
+
 // ---------------------------------------------------------------------------
 // Directory walking
 // ---------------------------------------------------------------------------
@@ -342,6 +541,7 @@ async fn watcher_respects_cancellation() {
     let pool = init_test_db().await.expect("init db");
     let config = ContentSourcesConfig {
         sources: Vec::new(), // No sources = immediate exit.
+        ..Default::default()
     };
 
     let watchtower = WatchtowerLoop::new(pool, config, Default::default(), std::env::temp_dir());
@@ -372,6 +572,8 @@ async fn watcher_cancels_with_sources() {
             folder_id: None,
             service_account_key: None,
             connection_id: None,
+            database_id: None,
+            api_token_env: None,
             watch: true,
             file_patterns: vec!["*.md".to_string()],
             loop_back_enabled: false,
@@ -380,6 +582,7 @@ async fn watcher_cancels_with_sources() {
             enabled: None,
             change_detection: "auto".to_string(),
         }],
+        ..Default::default()
     };
 
     let watchtower = WatchtowerLoop::new(pool, config, Default::default(), std::env::temp_dir());
@@ -419,6 +622,8 @@ async fn watchtower_skips_source_without_auth() {
             folder_id: Some("folder_no_auth".to_string()),
             service_account_key: None,
             connection_id: None,
+            database_id: None,
+            api_token_env: None,
             watch: true,
             file_patterns: vec!["*.md".to_string()],
             loop_back_enabled: false,
@@ -427,6 +632,7 @@ async fn watchtower_skips_source_without_auth() {
             enabled: None,
             change_detection: "auto".to_string(),
         }],
+        ..Default::default()
     };
 
     let watchtower = WatchtowerLoop::new(
@@ -544,6 +750,8 @@ async fn watchtower_mixed_legacy_and_connection_sources() {
                 folder_id: None,
                 service_account_key: None,
                 connection_id: None,
+                database_id: None,
+                api_token_env: None,
                 watch: true,
                 file_patterns: vec!["*.md".to_string()],
                 loop_back_enabled: false,
@@ -558,6 +766,8 @@ async fn watchtower_mixed_legacy_and_connection_sources() {
                 folder_id: Some("folder_mixed".to_string()),
                 service_account_key: None,
                 connection_id: None, // No auth = skipped
+                database_id: None,
+                api_token_env: None,
                 watch: true,
                 file_patterns: vec!["*.md".to_string()],
                 loop_back_enabled: false,
@@ -567,6 +777,7 @@ async fn watchtower_mixed_legacy_and_connection_sources() {
                 change_detection: "auto".to_string(),
             },
         ],
+        ..Default::default()
     };
 
     // Write a test file for the local source.
@@ -3,6 +3,8 @@
 //! These defaults match the values specified in the CLI interface contract.
 //! Users only need to supply credentials and business profile.
 
+use std::collections::BTreeMap;
+
 use super::{
     AuthConfig, IntervalsConfig, LimitsConfig, McpPolicyConfig, ScoringConfig, StorageConfig,
 };
@@ -13,6 +15,7 @@ impl Default for AuthConfig {
             mode: "manual".to_string(),
             callback_host: "127.0.0.1".to_string(),
             callback_port: 8080,
+            token_storage: "file".to_string(),
         }
     }
 }
@@ -27,6 +30,8 @@ impl Default for ScoringConfig {
             engagement_rate_max: 15.0,
             reply_count_max: 15.0,
             content_type_max: 10.0,
+            recency_curve: super::types::RecencyCurve::default(),
+            recency_half_life_minutes: super::types::default_recency_half_life_minutes(),
         }
     }
 }
@@ -40,6 +45,10 @@ impl Default for LimitsConfig {
             min_action_delay_seconds: 45,
             max_action_delay_seconds: 180,
             max_replies_per_author_per_day: 1,
+            max_replies_per_conversation_per_day: 2,
+            max_likes_per_day: 20,
+            max_retweets_per_day: 5,
+            discovery_likes: false,
             banned_phrases: vec![
                 "check out".to_string(),
                 "you should try".to_string(),
@@ -47,6 +56,7 @@ impl Default for LimitsConfig {
                 "link in bio".to_string(),
             ],
             product_mention_ratio: 0.2,
+            ramp: None,
         }
     }
 }
@@ -56,6 +66,7 @@ impl Default for IntervalsConfig {
         Self {
             mentions_check_seconds: 300,
             discovery_search_seconds: 900,
+            discovery_max_pages: 1,
             content_post_window_seconds: 10800,
             thread_interval_seconds: 604800,
         }
@@ -67,6 +78,10 @@ impl Default for StorageConfig {
         Self {
             db_path: "~/.tuitbot/tuitbot.db".to_string(),
             retention_days: 90,
+            critical_retention_days: super::types::default_critical_retention_days(),
+            busy_timeout_ms: super::types::default_busy_timeout_ms(),
+            journal_mode: super::types::default_journal_mode(),
+            synchronous: super::types::default_synchronous(),
         }
     }
 }
@@ -87,6 +102,7 @@ impl Default for McpPolicyConfig {
             template: None,
             rules: Vec::new(),
             rate_limits: Vec::new(),
+            tool_limits: BTreeMap::new(),
         }
     }
 }
@@ -136,6 +152,10 @@ mod tests {
         assert_eq!(config.min_action_delay_seconds, 45);
         assert_eq!(config.max_action_delay_seconds, 180);
         assert_eq!(config.max_replies_per_author_per_day, 1);
+        assert_eq!(config.max_replies_per_conversation_per_day, 2);
+        assert_eq!(config.max_likes_per_day, 20);
+        assert_eq!(config.max_retweets_per_day, 5);
+        assert!(!config.discovery_likes);
         assert_eq!(config.banned_phrases.len(), 4);
         assert!(config.banned_phrases.contains(&"check out".to_string()));
         assert!(config
@@ -179,6 +199,7 @@ mod tests {
         let config = StorageConfig::default();
         assert_eq!(config.db_path, "~/.tuitbot/tuitbot.db");
         assert_eq!(config.retention_days, 90);
+        assert_eq!(config.critical_retention_days, 365);
     }
 
     #[test]
@@ -1,6 +1,6 @@
 //! Environment variable overrides for configuration.
 
-use super::{Config, DeploymentMode, OperatingMode};
+use super::{Config, DeploymentMode, LlmBackoff, OperatingMode, RecencyCurve};
 use crate::error::ConfigError;
 use std::env;
 
@@ -8,7 +8,9 @@ impl Config {
     /// Apply environment variable overrides to the configuration.
     ///
     /// Environment variables use the `TUITBOT_` prefix with double underscores
-    /// separating nested keys (e.g., `TUITBOT_LLM__API_KEY`).
+    /// separating nested keys (e.g., `TUITBOT_LLM__API_KEY`). List fields take
+    /// comma-separated values; prefix the value with `+`
+    /// to append to the list from the config file instead of replacing it.
     pub(super) fn apply_env_overrides(&mut self) -> Result<(), ConfigError> {
         // Operating mode
         if let Ok(val) = env::var("TUITBOT_MODE") {
@@ -59,6 +61,17 @@ impl Config {
             self.x_api.scraper_allow_mutations =
                 parse_env_bool("TUITBOT_X_API__SCRAPER_ALLOW_MUTATIONS", &val)?;
         }
+        if let Ok(val) = env::var("TUITBOT_X_API__HTTP__CONNECT_TIMEOUT_MS") {
+            self.x_api.http.connect_timeout_ms =
+                parse_env_u64("TUITBOT_X_API__HTTP__CONNECT_TIMEOUT_MS", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_X_API__HTTP__REQUEST_TIMEOUT_MS") {
+            self.x_api.http.request_timeout_ms =
+                parse_env_u64("TUITBOT_X_API__HTTP__REQUEST_TIMEOUT_MS", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_X_API__CACHE_TTL_SECONDS") {
+            self.x_api.cache_ttl_seconds = parse_env_u64("TUITBOT_X_API__CACHE_TTL_SECONDS", &val)?;
+        }
 
         // Auth
         if let Ok(val) = env::var("TUITBOT_AUTH__MODE") {
@@ -70,6 +83,9 @@ impl Config {
         if let Ok(val) = env::var("TUITBOT_AUTH__CALLBACK_PORT") {
             self.auth.callback_port = parse_env_u16("TUITBOT_AUTH__CALLBACK_PORT", &val)?;
         }
+        if let Ok(val) = env::var("TUITBOT_AUTH__TOKEN_STORAGE") {
+            self.auth.token_storage = val;
+        }
 
         // Business
         if let Ok(val) = env::var("TUITBOT_BUSINESS__PRODUCT_NAME") {
@@ -85,13 +101,13 @@ impl Config {
             self.business.target_audience = val;
         }
         if let Ok(val) = env::var("TUITBOT_BUSINESS__PRODUCT_KEYWORDS") {
-            self.business.product_keywords = split_csv(&val);
+            apply_csv(&mut self.business.product_keywords, &val);
         }
         if let Ok(val) = env::var("TUITBOT_BUSINESS__COMPETITOR_KEYWORDS") {
-            self.business.competitor_keywords = split_csv(&val);
+            apply_csv(&mut self.business.competitor_keywords, &val);
         }
         if let Ok(val) = env::var("TUITBOT_BUSINESS__INDUSTRY_TOPICS") {
-            self.business.industry_topics = split_csv(&val);
+            apply_csv(&mut self.business.industry_topics, &val);
         }
         if let Ok(val) = env::var("TUITBOT_BUSINESS__BRAND_VOICE") {
             self.business.brand_voice = Some(val);
@@ -115,6 +131,25 @@ impl Config {
             self.scoring.content_type_max =
                 parse_env_f32("TUITBOT_SCORING__CONTENT_TYPE_MAX", &val)?;
         }
+        if let Ok(val) = env::var("TUITBOT_SCORING__RECENCY_HALF_LIFE_MINUTES") {
+            self.scoring.recency_half_life_minutes =
+                parse_env_u32("TUITBOT_SCORING__RECENCY_HALF_LIFE_MINUTES", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_SCORING__RECENCY_CURVE") {
+            self.scoring.recency_curve = match val.to_lowercase().as_str() {
+                "brackets" => RecencyCurve::Brackets,
+                "linear" => RecencyCurve::Linear,
+                "exponential" => RecencyCurve::Exponential,
+                other => {
+                    return Err(ConfigError::InvalidValue {
+                        field: "scoring.recency_curve".to_string(),
+                        message: format!(
+                            "invalid curve '{other}', expected 'brackets', 'linear' or 'exponential'"
+                        ),
+                    });
+                }
+            };
+        }
 
         // Limits
         if let Ok(val) = env::var("TUITBOT_LIMITS__MAX_REPLIES_PER_DAY") {
@@ -141,8 +176,23 @@ impl Config {
             self.limits.max_replies_per_author_per_day =
                 parse_env_u32("TUITBOT_LIMITS__MAX_REPLIES_PER_AUTHOR_PER_DAY", &val)?;
         }
+        if let Ok(val) = env::var("TUITBOT_LIMITS__MAX_REPLIES_PER_CONVERSATION_PER_DAY") {
+            self.limits.max_replies_per_conversation_per_day =
+                parse_env_u32("TUITBOT_LIMITS__MAX_REPLIES_PER_CONVERSATION_PER_DAY", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_LIMITS__MAX_LIKES_PER_DAY") {
+            self.limits.max_likes_per_day =
+                parse_env_u32("TUITBOT_LIMITS__MAX_LIKES_PER_DAY", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_LIMITS__MAX_RETWEETS_PER_DAY") {
+            self.limits.max_retweets_per_day =
+                parse_env_u32("TUITBOT_LIMITS__MAX_RETWEETS_PER_DAY", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_LIMITS__DISCOVERY_LIKES") {
+            self.limits.discovery_likes = parse_env_bool("TUITBOT_LIMITS__DISCOVERY_LIKES", &val)?;
+        }
         if let Ok(val) = env::var("TUITBOT_LIMITS__BANNED_PHRASES") {
-            self.limits.banned_phrases = split_csv(&val);
+            apply_csv(&mut self.limits.banned_phrases, &val);
         }
         if let Ok(val) = env::var("TUITBOT_LIMITS__PRODUCT_MENTION_RATIO") {
             self.limits.product_mention_ratio =
@@ -158,6 +208,10 @@ impl Config {
             self.intervals.discovery_search_seconds =
                 parse_env_u64("TUITBOT_INTERVALS__DISCOVERY_SEARCH_SECONDS", &val)?;
         }
+        if let Ok(val) = env::var("TUITBOT_INTERVALS__DISCOVERY_MAX_PAGES") {
+            self.intervals.discovery_max_pages =
+                parse_env_u32("TUITBOT_INTERVALS__DISCOVERY_MAX_PAGES", &val)?;
+        }
         if let Ok(val) = env::var("TUITBOT_INTERVALS__CONTENT_POST_WINDOW_SECONDS") {
             self.intervals.content_post_window_seconds =
                 parse_env_u64("TUITBOT_INTERVALS__CONTENT_POST_WINDOW_SECONDS", &val)?;
@@ -169,12 +223,16 @@ impl Config {
 
         // Targets
         if let Ok(val) = env::var("TUITBOT_TARGETS__ACCOUNTS") {
-            self.targets.accounts = split_csv(&val);
+            apply_csv(&mut self.targets.accounts, &val);
         }
         if let Ok(val) = env::var("TUITBOT_TARGETS__MAX_TARGET_REPLIES_PER_DAY") {
             self.targets.max_target_replies_per_day =
                 parse_env_u32("TUITBOT_TARGETS__MAX_TARGET_REPLIES_PER_DAY", &val)?;
         }
+        if let Ok(val) = env::var("TUITBOT_TARGETS__RECENCY_BONUS_MAX") {
+            self.targets.recency_bonus_max =
+                parse_env_f32("TUITBOT_TARGETS__RECENCY_BONUS_MAX", &val)?;
+        }
 
         // LLM
         if let Ok(val) = env::var("TUITBOT_LLM__PROVIDER") {
@@ -189,6 +247,35 @@ impl Config {
         if let Ok(val) = env::var("TUITBOT_LLM__BASE_URL") {
             self.llm.base_url = Some(val);
         }
+        if let Ok(val) = env::var("TUITBOT_LLM__RETRY__MAX_ATTEMPTS") {
+            self.llm.retry.max_attempts = parse_env_u32("TUITBOT_LLM__RETRY__MAX_ATTEMPTS", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_LLM__RETRY__BASE_DELAY_MS") {
+            self.llm.retry.base_delay_ms =
+                parse_env_u64("TUITBOT_LLM__RETRY__BASE_DELAY_MS", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_LLM__RETRY__BACKOFF") {
+            match val.to_lowercase().as_str() {
+                "exponential" => self.llm.retry.backoff = LlmBackoff::Exponential,
+                "fixed" => self.llm.retry.backoff = LlmBackoff::Fixed,
+                other => {
+                    return Err(ConfigError::InvalidValue {
+                        field: "llm.retry.backoff".to_string(),
+                        message: format!(
+                            "invalid backoff '{other}', expected 'exponential' or 'fixed'"
+                        ),
+                    });
+                }
+            }
+        }
+        if let Ok(val) = env::var("TUITBOT_LLM__HTTP__CONNECT_TIMEOUT_MS") {
+            self.llm.http.connect_timeout_ms =
+                parse_env_u64("TUITBOT_LLM__HTTP__CONNECT_TIMEOUT_MS", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_LLM__HTTP__REQUEST_TIMEOUT_MS") {
+            self.llm.http.request_timeout_ms =
+                parse_env_u64("TUITBOT_LLM__HTTP__REQUEST_TIMEOUT_MS", &val)?;
+        }
 
         // Storage
         if let Ok(val) = env::var("TUITBOT_STORAGE__DB_PATH") {
@@ -197,12 +284,77 @@ impl Config {
         if let Ok(val) = env::var("TUITBOT_STORAGE__RETENTION_DAYS") {
             self.storage.retention_days = parse_env_u32("TUITBOT_STORAGE__RETENTION_DAYS", &val)?;
         }
+        if let Ok(val) = env::var("TUITBOT_STORAGE__CRITICAL_RETENTION_DAYS") {
+            self.storage.critical_retention_days =
+                parse_env_u32("TUITBOT_STORAGE__CRITICAL_RETENTION_DAYS", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_STORAGE__BUSY_TIMEOUT_MS") {
+            self.storage.busy_timeout_ms = parse_env_u64("TUITBOT_STORAGE__BUSY_TIMEOUT_MS", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_STORAGE__JOURNAL_MODE") {
+            self.storage.journal_mode = val;
+        }
+        if let Ok(val) = env::var("TUITBOT_STORAGE__SYNCHRONOUS") {
+            self.storage.synchronous = val;
+        }
+
+        // Content
+        if let Ok(val) = env::var("TUITBOT_CONTENT__AUTO_ROTATE") {
+            self.content.auto_rotate = parse_env_bool("TUITBOT_CONTENT__AUTO_ROTATE", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_CONTENT__RAG_SANITIZE") {
+            self.content.rag_sanitize = parse_env_bool("TUITBOT_CONTENT__RAG_SANITIZE", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_CONTENT__RAG_REQUIRED") {
+            self.content.rag_required = parse_env_bool("TUITBOT_CONTENT__RAG_REQUIRED", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_CONTENT__MIN_TOPIC_GAP_HOURS") {
+            self.content.min_topic_gap_hours =
+                parse_env_u64("TUITBOT_CONTENT__MIN_TOPIC_GAP_HOURS", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_CONTENT__RANDOM_SEED") {
+            self.content.random_seed = Some(parse_env_u64("TUITBOT_CONTENT__RANDOM_SEED", &val)?);
+        }
+        if let Ok(val) = env::var("TUITBOT_CONTENT__INCLUDE_CONVERSATION_CONTEXT") {
+            self.content.include_conversation_context =
+                parse_env_bool("TUITBOT_CONTENT__INCLUDE_CONVERSATION_CONTEXT", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_CONTENT__MAX_CONVERSATION_TWEETS") {
+            self.content.max_conversation_tweets =
+                parse_env_u32("TUITBOT_CONTENT__MAX_CONVERSATION_TWEETS", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_CONTENT__HASHTAG_POLICY") {
+            self.content.hashtag_policy = val;
+        }
+        if let Ok(val) = env::var("TUITBOT_CONTENT__EMOJI_POLICY") {
+            self.content.emoji_policy = val;
+        }
+        if let Ok(val) = env::var("TUITBOT_CONTENT__MAX_EMOJIS") {
+            self.content.max_emojis = parse_env_u32("TUITBOT_CONTENT__MAX_EMOJIS", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_CONTENT__CREATIVITY") {
+            self.content.creativity = parse_env_f32("TUITBOT_CONTENT__CREATIVITY", &val)?;
+        }
+
+        // Analytics
+        if let Ok(val) = env::var("TUITBOT_ANALYTICS__MIN_ENGAGEMENT_SAMPLE") {
+            self.analytics.min_engagement_sample =
+                parse_env_u32("TUITBOT_ANALYTICS__MIN_ENGAGEMENT_SAMPLE", &val)?;
+        }
 
         // Logging
         if let Ok(val) = env::var("TUITBOT_LOGGING__STATUS_INTERVAL_SECONDS") {
             self.logging.status_interval_seconds =
                 parse_env_u64("TUITBOT_LOGGING__STATUS_INTERVAL_SECONDS", &val)?;
         }
+        if let Ok(val) = env::var("TUITBOT_LOGGING__DUMP_PROMPT_ON_ERROR") {
+            self.logging.dump_prompt_on_error =
+                parse_env_bool("TUITBOT_LOGGING__DUMP_PROMPT_ON_ERROR", &val)?;
+        }
+        if let Ok(val) = env::var("TUITBOT_DUMP_PROMPT_ON_ERROR") {
+            self.logging.dump_prompt_on_error =
+                parse_env_bool("TUITBOT_DUMP_PROMPT_ON_ERROR", &val)?;
+        }
 
         // Schedule
         if let Ok(val) = env::var("TUITBOT_SCHEDULE__TIMEZONE") {
@@ -217,10 +369,10 @@ impl Config {
                 parse_env_u8("TUITBOT_SCHEDULE__ACTIVE_HOURS_END", &val)?;
         }
         if let Ok(val) = env::var("TUITBOT_SCHEDULE__ACTIVE_DAYS") {
-            self.schedule.active_days = split_csv(&val);
+            apply_csv(&mut self.schedule.active_days, &val);
         }
         if let Ok(val) = env::var("TUITBOT_SCHEDULE__PREFERRED_TIMES") {
-            self.schedule.preferred_times = split_csv(&val);
+            apply_csv(&mut self.schedule.preferred_times, &val);
         }
         if let Ok(val) = env::var("TUITBOT_SCHEDULE__THREAD_PREFERRED_DAY") {
             let val = val.trim().to_string();
@@ -240,10 +392,10 @@ impl Config {
                 parse_env_bool("TUITBOT_MCP_POLICY__ENFORCE_FOR_MUTATIONS", &val)?;
         }
         if let Ok(val) = env::var("TUITBOT_MCP_POLICY__REQUIRE_APPROVAL_FOR") {
-            self.mcp_policy.require_approval_for = split_csv(&val);
+            apply_csv(&mut self.mcp_policy.require_approval_for, &val);
         }
         if let Ok(val) = env::var("TUITBOT_MCP_POLICY__BLOCKED_TOOLS") {
-            self.mcp_policy.blocked_tools = split_csv(&val);
+            apply_csv(&mut self.mcp_policy.blocked_tools, &val);
         }
         if let Ok(val) = env::var("TUITBOT_MCP_POLICY__DRY_RUN_MUTATIONS") {
             self.mcp_policy.dry_run_mutations =
@@ -295,6 +447,23 @@ pub(super) fn split_csv(s: &str) -> Vec<String> {
         .collect()
 }
 
+/// Apply a comma-separated list override.
+///
+/// A value starting with `+` appends its entries to the existing list
+/// (skipping entries already present); any other value replaces the list.
+fn apply_csv(list: &mut Vec<String>, val: &str) {
+    match val.trim_start().strip_prefix('+') {
+        Some(rest) => {
+            for item in split_csv(rest) {
+                if !list.contains(&item) {
+                    list.push(item);
+                }
+            }
+        }
+        None => *list = split_csv(val),
+    }
+}
+
 /// Parse an environment variable value as `u16`.
 pub(super) fn parse_env_u16(var_name: &str, val: &str) -> Result<u16, ConfigError> {
     val.parse::<u16>().map_err(|_| ConfigError::InvalidValue {
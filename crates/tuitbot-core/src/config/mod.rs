@@ -12,6 +12,8 @@ mod defaults;
 mod enrichment;
 mod env_overrides;
 pub mod merge;
+mod schema;
+mod style_lint;
 mod types;
 mod types_policy;
 mod validation;
@@ -27,15 +29,23 @@ pub use merge::{
     effective_config, merge_overrides, split_patch_by_scope, validate_override_keys,
     EffectiveConfigResult, ACCOUNT_SCOPED_KEYS,
 };
+pub use schema::{settings_schema, SettingsField, GENERAL_SECTION};
+pub use style_lint::ConfigWarning;
 pub use types::{
     AuthConfig, BusinessProfile, ConnectorConfig, ContentSourceEntry, ContentSourcesConfig,
-    DeploymentCapabilities, DeploymentMode, EmbeddingConfig, GoogleDriveConnectorConfig,
-    IntervalsConfig, LimitsConfig, LlmConfig, LoggingConfig, ScoringConfig, ServerConfig,
-    StorageConfig, TargetsConfig, XApiConfig,
+    CorsConfig, DeploymentCapabilities, DeploymentMode, EmbeddingConfig,
+    GoogleDriveConnectorConfig, HttpTimeoutConfig, IntervalsConfig, LimitRampConfig, LimitsConfig,
+    LlmBackoff, LlmConfig, LlmProviderConfig, LlmRetryConfig, LoggingConfig, LoopbackConfig,
+    RecencyCurve, ScoringConfig, ServerConfig, StorageConfig, TargetsConfig, TopicSource,
+    XApiConfig, PILLAR_ANGLES,
+};
+pub use types_policy::{
+    AnalyticsConfig, CircuitBreakerConfig, ContentConfig, LoopBreakerConfig, McpPolicyConfig,
+    ScheduleConfig,
 };
-pub use types_policy::{CircuitBreakerConfig, McpPolicyConfig, ScheduleConfig};
 
 use crate::error::ConfigError;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use std::env;
 use std::path::PathBuf;
@@ -54,7 +64,7 @@ fn default_max_batch_approve() -> usize {
 /// - **Composer**: User-controlled posting with on-demand AI intelligence.
 ///   In composer mode, `approval_mode` is implicitly `true` and autonomous
 ///   posting loops (content, threads, discovery replies) are disabled.
-#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "lowercase")]
 pub enum OperatingMode {
     /// Full autonomous operation.
@@ -74,7 +84,7 @@ impl std::fmt::Display for OperatingMode {
 }
 
 /// Top-level configuration for the Tuitbot agent.
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
 pub struct Config {
     /// Operating mode: "autopilot" (default) or "composer".
     #[serde(default)]
@@ -148,6 +158,14 @@ pub struct Config {
     #[serde(default)]
     pub content_sources: ContentSourcesConfig,
 
+    /// Content generation preferences (archetype/format rotation).
+    #[serde(default)]
+    pub content: ContentConfig,
+
+    /// Minimum-sample thresholds for analytics and strategy.
+    #[serde(default)]
+    pub analytics: AnalyticsConfig,
+
     /// Deployment mode: desktop (default), self_host, or cloud.
     /// Controls which source types and features are available.
     #[serde(default)]
@@ -203,6 +221,39 @@ impl Config {
         Ok(config)
     }
 
+    /// Return a copy with every secret (API keys, client secrets, custom LLM
+    /// header values) replaced by a placeholder, safe to print or log.
+    pub fn redacted(&self) -> Config {
+        const REDACTED: &str = "***REDACTED***";
+
+        fn redact(secret: &mut Option<String>) {
+            if secret.is_some() {
+                *secret = Some(REDACTED.to_string());
+            }
+        }
+
+        // Custom headers usually carry proxy credentials; keep the names only.
+        fn redact_headers(headers: &mut std::collections::BTreeMap<String, String>) {
+            for value in headers.values_mut() {
+                *value = REDACTED.to_string();
+            }
+        }
+
+        let mut config = self.clone();
+        redact(&mut config.llm.api_key);
+        redact_headers(&mut config.llm.headers);
+        for fallback in &mut config.llm.fallbacks {
+            redact(&mut fallback.api_key);
+            redact_headers(&mut fallback.headers);
+        }
+        redact(&mut config.x_api.client_secret);
+        redact(&mut config.connectors.google_drive.client_secret);
+        if let Some(embedding) = config.embedding.as_mut() {
+            redact(&mut embedding.api_key);
+        }
+        config
+    }
+
     /// Returns `true` if approval mode is effectively enabled.
     ///
     /// In composer mode, approval mode is implicitly enabled for
@@ -0,0 +1,261 @@
+//! Machine-readable schema of the configuration, for rendering settings forms.
+//!
+//! The schema is generated from the `JsonSchema` derives on [`Config`] and its
+//! section types, so field names, doc comments, serde defaults, and the
+//! `#[schemars(...)]` constraints declared next to each field are the single
+//! source of truth. [`settings_schema`] flattens it into one entry per leaf
+//! field, keyed by its dotted TOML path.
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+
+use super::Config;
+
+/// Section name for top-level scalar fields such as `mode` or `approval_mode`.
+pub const GENERAL_SECTION: &str = "general";
+
+/// One leaf configuration field.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SettingsField {
+    /// Dotted path, e.g. `"scoring.threshold"`.
+    pub path: String,
+    /// Top-level section the field belongs to, e.g. `"scoring"`.
+    pub section: String,
+    /// JSON type: `string`, `integer`, `number`, `boolean`, `array`, or `object`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Whether the field may be left unset (`null`).
+    pub nullable: bool,
+    /// Value used when the field is absent from the config file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    /// Doc comment of the field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Inclusive lower bound for numeric fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub minimum: Option<Value>,
+    /// Inclusive upper bound for numeric fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maximum: Option<Value>,
+    /// Allowed values, when the field is restricted to a fixed set.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub options: Vec<Value>,
+    /// Element type for `array` fields.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub items: Option<String>,
+}
+
+/// Flatten the [`Config`] schema into one [`SettingsField`] per leaf field.
+pub fn settings_schema() -> Vec<SettingsField> {
+    let root = schemars::schema_for!(Config).to_value();
+    let defs = root
+        .get("$defs")
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default();
+
+    let mut fields = Vec::new();
+    collect_fields(&defs, &root, None, "", &mut fields);
+    fields
+}
+
+fn collect_fields(
+    defs: &Map<String, Value>,
+    schema: &Value,
+    parent_default: Option<&Value>,
+    prefix: &str,
+    out: &mut Vec<SettingsField>,
+) {
+    let Some(properties) = resolve(defs, schema)
+        .get("properties")
+        .and_then(Value::as_object)
+    else {
+        return;
+    };
+
+    for (name, property) in properties {
+        let path = if prefix.is_empty() {
+            name.clone()
+        } else {
+            format!("{prefix}.{name}")
+        };
+        let (target, nullable) = unwrap_nullable(defs, property);
+        let default = property
+            .get("default")
+            .or_else(|| parent_default.and_then(|d| d.get(name)))
+            .cloned();
+
+        if target.get("properties").is_some() {
+            collect_fields(defs, target, default.as_ref(), &path, out);
+            continue;
+        }
+
+        let section = match path.split_once('.') {
+            Some((section, _)) => section.to_string(),
+            None => GENERAL_SECTION.to_string(),
+        };
+        out.push(SettingsField {
+            section,
+            kind: json_type(target).unwrap_or_else(|| "string".to_string()),
+            nullable,
+            default,
+            description: description(property, target),
+            minimum: target.get("minimum").cloned(),
+            maximum: target.get("maximum").cloned(),
+            options: options(target),
+            items: target
+                .get("items")
+                .map(|items| resolve(defs, items))
+                .and_then(json_type),
+            path,
+        });
+    }
+}
+
+/// Follow a `$ref` into `$defs`, if present.
+fn resolve<'a>(defs: &'a Map<String, Value>, schema: &'a Value) -> &'a Value {
+    schema
+        .get("$ref")
+        .and_then(Value::as_str)
+        .and_then(|r| r.strip_prefix("#/$defs/"))
+        .and_then(|name| defs.get(name))
+        .unwrap_or(schema)
+}
+
+/// Strip `null` from an `Option<T>` schema, returning `T` and whether null was allowed.
+fn unwrap_nullable<'a>(defs: &'a Map<String, Value>, schema: &'a Value) -> (&'a Value, bool) {
+    if let Some(variants) = schema.get("anyOf").and_then(Value::as_array) {
+        let non_null: Vec<&Value> = variants
+            .iter()
+            .filter(|v| v.get("type").and_then(Value::as_str) != Some("null"))
+            .collect();
+        if non_null.len() == 1 && non_null.len() < variants.len() {
+            return (resolve(defs, non_null[0]), true);
+        }
+    }
+    let nullable = schema
+        .get("type")
+        .and_then(Value::as_array)
+        .is_some_and(|types| types.iter().any(|t| t == "null"));
+    (resolve(defs, schema), nullable)
+}
+
+/// The non-null JSON type of a schema, inferring `string` for string enums.
+fn json_type(schema: &Value) -> Option<String> {
+    match schema.get("type") {
+        Some(Value::String(t)) => Some(t.clone()),
+        Some(Value::Array(types)) => types
+            .iter()
+            .filter_map(Value::as_str)
+            .find(|t| *t != "null")
+            .map(String::from),
+        _ => options(schema)
+            .first()
+            .and_then(|v| v.is_string().then(|| "string".to_string())),
+    }
+}
+
+/// Allowed values from `enum`, or from `oneOf` variants that are each a `const`.
+fn options(schema: &Value) -> Vec<Value> {
+    if let Some(values) = schema.get("enum").and_then(Value::as_array) {
+        return values.clone();
+    }
+    schema
+        .get("oneOf")
+        .and_then(Value::as_array)
+        .and_then(|variants| {
+            variants
+                .iter()
+                .map(|v| v.get("const").cloned())
+                .collect::<Option<Vec<_>>>()
+        })
+        .unwrap_or_default()
+}
+
+fn description(property: &Value, target: &Value) -> Option<String> {
+    property
+        .get("description")
+        .or_else(|| target.get("description"))
+        .and_then(Value::as_str)
+        .map(String::from)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(path: &str) -> SettingsField {
+        settings_schema()
+            .into_iter()
+            .find(|f| f.path == path)
+            .unwrap_or_else(|| panic!("{path} missing from schema"))
+    }
+
+    #[test]
+    fn scoring_threshold_has_default_and_range() {
+        let threshold = field("scoring.threshold");
+        assert_eq!(threshold.section, "scoring");
+        assert_eq!(threshold.kind, "integer");
+        assert!(!threshold.nullable);
+        assert_eq!(threshold.default, Some(Value::from(60)));
+        assert_eq!(threshold.minimum, Some(Value::from(0)));
+        assert_eq!(threshold.maximum, Some(Value::from(100)));
+        assert!(threshold.description.unwrap().contains("Minimum score"));
+    }
+
+    #[test]
+    fn nested_sections_flatten_to_dotted_paths() {
+        let timeout = field("x_api.http.request_timeout_ms");
+        assert_eq!(timeout.section, "x_api");
+        assert_eq!(timeout.default, Some(Value::from(60_000)));
+        assert_eq!(timeout.minimum, Some(Value::from(1)));
+
+        assert!(settings_schema().iter().all(|f| f.path != "x_api.http"));
+    }
+
+    #[test]
+    fn top_level_scalars_use_general_section() {
+        let approval = field("approval_mode");
+        assert_eq!(approval.section, GENERAL_SECTION);
+        assert_eq!(approval.kind, "boolean");
+        assert_eq!(approval.default, Some(Value::from(true)));
+    }
+
+    #[test]
+    fn enums_and_options_list_allowed_values() {
+        let mode = field("mode");
+        assert_eq!(mode.kind, "string");
+        assert_eq!(
+            mode.options,
+            vec![Value::from("autopilot"), Value::from("composer")]
+        );
+        assert_eq!(mode.default, Some(Value::from("autopilot")));
+
+        let auth_mode = field("auth.mode");
+        assert_eq!(
+            auth_mode.options,
+            vec![Value::from("manual"), Value::from("local_callback")]
+        );
+    }
+
+    #[test]
+    fn optional_and_array_fields() {
+        let url = field("business.product_url");
+        assert!(url.nullable);
+        assert_eq!(url.kind, "string");
+
+        let keywords = field("business.product_keywords");
+        assert_eq!(keywords.kind, "array");
+        assert_eq!(keywords.items.as_deref(), Some("string"));
+    }
+
+    #[test]
+    fn every_field_is_unique() {
+        let fields = settings_schema();
+        let mut paths: Vec<_> = fields.iter().map(|f| f.path.as_str()).collect();
+        paths.sort_unstable();
+        paths.dedup();
+        assert_eq!(paths.len(), fields.len());
+    }
+}
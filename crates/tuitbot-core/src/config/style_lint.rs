@@ -0,0 +1,207 @@
+//! Non-fatal linting of free-text style fields.
+//!
+//! `business.brand_voice`, `business.reply_style`, and
+//! `business.content_style` are pasted into every generation prompt. When
+//! they ask for something the generator never produces (hashtags, heavy
+//! emoji, long-form posts) the model gets contradictory instructions and
+//! the output is stripped or rejected anyway. These checks flag the
+//! contradiction at config time without blocking startup.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+use serde::Serialize;
+
+use super::Config;
+use crate::content::length::MAX_TWEET_CHARS;
+
+/// A configuration problem worth reporting that does not block startup.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ConfigWarning {
+    /// Dotted path of the field the warning is about.
+    pub field: String,
+    /// What conflicts and why.
+    pub message: String,
+}
+
+impl std::fmt::Display for ConfigWarning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// Words that turn a clause into a prohibition ("no hashtags", "avoid emoji",
+/// "hashtag-free").
+fn negation() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?i)\b(no|not|don'?t|never|avoid|without|skip|zero|minimal)\b|\w-free\b")
+            .expect("valid negation pattern")
+    })
+}
+
+fn hashtag_directive() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)\bhashtags?\b").expect("valid hashtag pattern"))
+}
+
+fn heavy_emoji() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?i)\b(lots of|plenty of|many|tons of|loads of|heavy|every|each|always)\b.*\bemojis?\b|\bemoji[- ]heavy\b",
+        )
+        .expect("valid emoji pattern")
+    })
+}
+
+fn any_emoji() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)\bemojis?\b").expect("valid emoji pattern"))
+}
+
+fn long_form() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(r"(?i)\b(long[- ]form|lengthy|essays?|multiple paragraphs|long posts?)\b")
+            .expect("valid long-form pattern")
+    })
+}
+
+fn minimum_length() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| {
+        Regex::new(
+            r"(?i)\b(at least|minimum of|min\.?|more than|over)\s+(\d+)\s*(words?|characters?|chars?)\b",
+        )
+        .expect("valid minimum length pattern")
+    })
+}
+
+/// Rough upper bound on how many words fit in one tweet.
+const MAX_TWEET_WORDS: usize = 50;
+
+impl Config {
+    /// Check style and voice fields for directives that contradict the
+    /// generator's hard rules. Returns warnings only; never fails.
+    pub fn style_warnings(&self) -> Vec<ConfigWarning> {
+        let fields = [
+            ("business.brand_voice", &self.business.brand_voice),
+            ("business.reply_style", &self.business.reply_style),
+            ("business.content_style", &self.business.content_style),
+        ];
+
+        let mut warnings = Vec::new();
+        for (field, value) in fields {
+            let Some(text) = value.as_deref().filter(|v| !v.trim().is_empty()) else {
+                continue;
+            };
+            for message in self.style_conflicts(text) {
+                warnings.push(ConfigWarning {
+                    field: field.to_string(),
+                    message,
+                });
+            }
+        }
+        warnings
+    }
+
+    /// Conflicts found in one style string, at most one per rule.
+    fn style_conflicts(&self, text: &str) -> Vec<String> {
+        let clauses: Vec<&str> = text
+            .split(['.', ';', ',', '\n', '!', '?'])
+            .filter(|c| !negation().is_match(c))
+            .collect();
+        let any = |re: &Regex| clauses.iter().any(|c| re.is_match(c));
+
+        let mut conflicts = Vec::new();
+
+        if self.content.hashtag_policy != "allow" && any(hashtag_directive()) {
+            conflicts.push(format!(
+                "asks for hashtags, but generation prompts forbid them and \
+                 content.hashtag_policy = \"{}\" removes or rejects them",
+                self.content.hashtag_policy
+            ));
+        }
+
+        if self.content.emoji_policy != "allow" {
+            if self.content.max_emojis == 0 && any(any_emoji()) {
+                conflicts.push(format!(
+                    "asks for emoji, but content.max_emojis = 0 and \
+                     content.emoji_policy = \"{}\"",
+                    self.content.emoji_policy
+                ));
+            } else if any(heavy_emoji()) {
+                conflicts.push(format!(
+                    "asks for heavy emoji use, but generated tweets are capped at {} \
+                     (content.max_emojis)",
+                    self.content.max_emojis
+                ));
+            }
+        }
+
+        let too_long = clauses.iter().any(|c| {
+            minimum_length().captures_iter(c).any(|caps| {
+                let n: usize = caps[2].parse().unwrap_or(0);
+                let unit = caps[3].to_ascii_lowercase();
+                if unit.starts_with("word") {
+                    n > MAX_TWEET_WORDS
+                } else {
+                    n > MAX_TWEET_CHARS
+                }
+            })
+        });
+        if too_long || any(long_form()) {
+            conflicts.push(format!(
+                "asks for long-form writing, but each tweet is limited to \
+                 {MAX_TWEET_CHARS} characters"
+            ));
+        }
+
+        conflicts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_with_style(style: &str) -> Config {
+        let mut config = Config::default();
+        config.business.content_style = Some(style.to_string());
+        config
+    }
+
+    #[test]
+    fn conflicting_style_produces_warnings() {
+        let config = config_with_style(
+            "Always end with 3 hashtags. Use lots of emojis! Long-form essays of at least 500 words",
+        );
+        let warnings = config.style_warnings();
+
+        assert_eq!(warnings.len(), 3, "got: {warnings:?}");
+        assert!(warnings.iter().all(|w| w.field == "business.content_style"));
+        assert!(warnings[0].message.contains("hashtags"));
+        assert!(warnings[1].message.contains("emoji"));
+        assert!(warnings[2].message.contains("280 characters"));
+    }
+
+    #[test]
+    fn clean_style_produces_no_warnings() {
+        let mut config =
+            config_with_style("Punchy and practical. No hashtags, never more than one emoji.");
+        config.business.brand_voice = Some("Friendly founder, under 40 words".to_string());
+        config.business.reply_style = Some("Ask a follow-up question".to_string());
+
+        assert!(config.style_warnings().is_empty());
+    }
+
+    #[test]
+    fn allow_policies_silence_matching_rules() {
+        let mut config = config_with_style("Add hashtags and lots of emoji");
+        config.content.hashtag_policy = "allow".to_string();
+        config.content.emoji_policy = "allow".to_string();
+
+        assert!(config.style_warnings().is_empty());
+    }
+}
@@ -79,6 +79,41 @@ fn env_var_override_csv() {
     });
 }
 
+#[test]
+fn env_var_override_csv_plus_prefix_appends() {
+    with_locked_env(|| {
+        let _keywords =
+            ScopedEnvVar::set("TUITBOT_BUSINESS__PRODUCT_KEYWORDS", "+newterm, another");
+        let _phrases = ScopedEnvVar::set("TUITBOT_LIMITS__BANNED_PHRASES", "+buy now,free money");
+        let mut config = Config::default();
+        config.business.product_keywords = vec!["rust".to_string(), "another".to_string()];
+        config.limits.banned_phrases = vec!["spam".to_string()];
+        config.apply_env_overrides().expect("env override");
+        assert_eq!(
+            config.business.product_keywords,
+            vec!["rust", "another", "newterm"]
+        );
+        assert_eq!(
+            config.limits.banned_phrases,
+            vec!["spam", "buy now", "free money"]
+        );
+    });
+}
+
+#[test]
+fn env_var_override_csv_without_prefix_replaces() {
+    with_locked_env(|| {
+        let _keywords = ScopedEnvVar::set("TUITBOT_BUSINESS__PRODUCT_KEYWORDS", "newterm");
+        let _phrases = ScopedEnvVar::set("TUITBOT_LIMITS__BANNED_PHRASES", "buy now");
+        let mut config = Config::default();
+        config.business.product_keywords = vec!["rust".to_string()];
+        config.limits.banned_phrases = vec!["spam".to_string()];
+        config.apply_env_overrides().expect("env override");
+        assert_eq!(config.business.product_keywords, vec!["newterm"]);
+        assert_eq!(config.limits.banned_phrases, vec!["buy now"]);
+    });
+}
+
 #[test]
 fn env_var_invalid_numeric_returns_error() {
     let result = parse_env_u32("TUITBOT_SCORING__THRESHOLD", "not_a_number");
@@ -136,6 +171,86 @@ fn validate_threshold_boundary_values() {
     assert!(config.validate().is_ok());
 }
 
+#[test]
+fn validate_recency_half_life_must_be_positive() {
+    let mut config = valid_test_config();
+    config.scoring.recency_half_life_minutes = 0;
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "scoring.recency_half_life_minutes"
+    )));
+
+    config.scoring.recency_half_life_minutes = 1;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn validate_discovery_max_pages_range() {
+    let mut config = valid_test_config();
+    for pages in [0, 11] {
+        config.intervals.discovery_max_pages = pages;
+        let errors = config.validate().unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ConfigError::InvalidValue { field, .. } if field == "intervals.discovery_max_pages"
+        )));
+    }
+
+    config.intervals.discovery_max_pages = 10;
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn validate_scan_concurrency_must_be_positive() {
+    let mut config = valid_test_config();
+    assert_eq!(config.content_sources.scan_concurrency, 4);
+
+    config.content_sources.scan_concurrency = 0;
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "content_sources.scan_concurrency"
+    )));
+}
+
+#[test]
+fn validate_allowed_extensions_must_not_be_empty() {
+    let mut config = valid_test_config();
+    assert_eq!(config.content_sources.max_file_bytes, 1024 * 1024);
+    assert_eq!(config.content_sources.allowed_extensions, vec!["md", "txt"]);
+
+    config.content_sources.allowed_extensions = Vec::new();
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "content_sources.allowed_extensions"
+    )));
+
+    config.content_sources.allowed_extensions = vec!["md".to_string(), ".".to_string()];
+    assert!(config.validate().is_err());
+
+    config.content_sources.allowed_extensions = vec![".md".to_string()];
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn validate_cors_origins() {
+    let mut config = valid_test_config();
+    config.server.cors.allowed_origins = vec![
+        "http://192.168.1.20:3001".to_string(),
+        "tauri://localhost".to_string(),
+    ];
+    assert!(config.validate().is_ok());
+
+    config.server.cors.allowed_origins = vec!["http://example.com/app".to_string()];
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "server.cors.allowed_origins"
+    )));
+}
+
 #[test]
 fn validate_returns_multiple_errors() {
     let mut config = Config::default();
@@ -384,3 +499,48 @@ thread_preferred_time = "10:00"
     );
     assert_eq!(config.schedule.thread_preferred_time, "10:00");
 }
+
+#[test]
+fn redacted_masks_secrets_only() {
+    let mut config = Config::default();
+    config.llm.api_key = Some("sk-live-1234567890".to_string());
+    config.x_api.client_secret = Some("x-secret".to_string());
+    config.x_api.client_id = "client-id".to_string();
+    config
+        .llm
+        .headers
+        .insert("X-Proxy-Token".to_string(), "proxy-secret".to_string());
+    config.llm.fallbacks = vec![LlmProviderConfig {
+        provider: "groq".to_string(),
+        api_key: Some("gsk-fallback".to_string()),
+        headers: [("Authorization".to_string(), "Bearer fb".to_string())].into(),
+        ..LlmProviderConfig::default()
+    }];
+    config.embedding = Some(EmbeddingConfig {
+        api_key: Some("sk-embed-abcdef".to_string()),
+        ..EmbeddingConfig::default()
+    });
+
+    let redacted = config.redacted();
+    assert_eq!(redacted.llm.api_key.as_deref(), Some("***REDACTED***"));
+    assert_eq!(
+        redacted.x_api.client_secret.as_deref(),
+        Some("***REDACTED***")
+    );
+    assert_eq!(
+        redacted.llm.fallbacks[0].api_key.as_deref(),
+        Some("***REDACTED***")
+    );
+    assert_eq!(redacted.llm.headers["X-Proxy-Token"], "***REDACTED***");
+    assert_eq!(
+        redacted.llm.fallbacks[0].headers["Authorization"],
+        "***REDACTED***"
+    );
+    assert!(redacted.connectors.google_drive.client_secret.is_none());
+    assert_eq!(
+        redacted.embedding.and_then(|e| e.api_key).as_deref(),
+        Some("***REDACTED***")
+    );
+    assert_eq!(redacted.x_api.client_id, "client-id");
+    assert_eq!(config.llm.api_key.as_deref(), Some("sk-live-1234567890"));
+}
@@ -155,6 +155,8 @@ fn validate_local_fs_source_rejected_in_cloud_mode() {
         folder_id: None,
         service_account_key: None,
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec!["*.md".to_string()],
         loop_back_enabled: true,
@@ -182,6 +184,8 @@ fn validate_google_drive_source_allowed_in_cloud_mode() {
         folder_id: Some("abc123".to_string()),
         service_account_key: Some("/keys/sa.json".to_string()),
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec!["*.md".to_string()],
         loop_back_enabled: false,
@@ -203,6 +207,8 @@ fn validate_local_fs_source_allowed_in_desktop_mode() {
         folder_id: None,
         service_account_key: None,
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec!["*.md".to_string()],
         loop_back_enabled: true,
@@ -241,6 +247,13 @@ fn deployment_mode_display() {
     assert_eq!(DeploymentMode::Cloud.to_string(), "cloud");
 }
 
+#[test]
+fn deployment_mode_default_bind_host() {
+    assert_eq!(DeploymentMode::Desktop.default_bind_host(), "127.0.0.1");
+    assert_eq!(DeploymentMode::SelfHost.default_bind_host(), "0.0.0.0");
+    assert_eq!(DeploymentMode::Cloud.default_bind_host(), "0.0.0.0");
+}
+
 // --- Preferred source default tests ---
 
 #[test]
@@ -131,6 +131,43 @@ fn env_override_llm_fields() {
     });
 }
 
+#[test]
+fn env_override_llm_retry_fields() {
+    with_locked_env(|| {
+        let _a = ScopedEnvVar::set("TUITBOT_LLM__RETRY__MAX_ATTEMPTS", "5");
+        let _d = ScopedEnvVar::set("TUITBOT_LLM__RETRY__BASE_DELAY_MS", "250");
+        let _b = ScopedEnvVar::set("TUITBOT_LLM__RETRY__BACKOFF", "fixed");
+        let mut config = Config::default();
+        config.apply_env_overrides().expect("env override");
+        assert_eq!(config.llm.retry.max_attempts, 5);
+        assert_eq!(config.llm.retry.base_delay_ms, 250);
+        assert_eq!(config.llm.retry.backoff, LlmBackoff::Fixed);
+    });
+}
+
+#[test]
+fn env_override_llm_retry_rejects_unknown_backoff() {
+    with_locked_env(|| {
+        let _b = ScopedEnvVar::set("TUITBOT_LLM__RETRY__BACKOFF", "linear");
+        let mut config = Config::default();
+        assert!(config.apply_env_overrides().is_err());
+    });
+}
+
+#[test]
+fn env_override_http_timeouts() {
+    with_locked_env(|| {
+        let _a = ScopedEnvVar::set("TUITBOT_X_API__HTTP__REQUEST_TIMEOUT_MS", "5000");
+        let _b = ScopedEnvVar::set("TUITBOT_LLM__HTTP__CONNECT_TIMEOUT_MS", "2000");
+        let mut config = Config::default();
+        config.apply_env_overrides().expect("env override");
+        assert_eq!(config.x_api.http.request_timeout_ms, 5000);
+        assert_eq!(config.x_api.http.connect_timeout_ms, 10_000);
+        assert_eq!(config.llm.http.connect_timeout_ms, 2000);
+        assert_eq!(config.llm.http.request_timeout_ms, 60_000);
+    });
+}
+
 #[test]
 fn env_override_limits_fields() {
     with_locked_env(|| {
@@ -162,10 +199,12 @@ fn env_override_intervals_fields() {
         let _b = ScopedEnvVar::set("TUITBOT_INTERVALS__DISCOVERY_SEARCH_SECONDS", "1800");
         let _c = ScopedEnvVar::set("TUITBOT_INTERVALS__CONTENT_POST_WINDOW_SECONDS", "7200");
         let _d = ScopedEnvVar::set("TUITBOT_INTERVALS__THREAD_INTERVAL_SECONDS", "86400");
+        let _e = ScopedEnvVar::set("TUITBOT_INTERVALS__DISCOVERY_MAX_PAGES", "3");
         let mut config = Config::default();
         config.apply_env_overrides().expect("env override");
         assert_eq!(config.intervals.mentions_check_seconds, 600);
         assert_eq!(config.intervals.discovery_search_seconds, 1800);
+        assert_eq!(config.intervals.discovery_max_pages, 3);
         assert_eq!(config.intervals.content_post_window_seconds, 7200);
         assert_eq!(config.intervals.thread_interval_seconds, 86400);
     });
@@ -221,10 +260,12 @@ fn env_override_targets_fields() {
     with_locked_env(|| {
         let _a = ScopedEnvVar::set("TUITBOT_TARGETS__ACCOUNTS", "alice,bob,charlie");
         let _b = ScopedEnvVar::set("TUITBOT_TARGETS__MAX_TARGET_REPLIES_PER_DAY", "5");
+        let _c = ScopedEnvVar::set("TUITBOT_TARGETS__RECENCY_BONUS_MAX", "12.5");
         let mut config = Config::default();
         config.apply_env_overrides().expect("env override");
         assert_eq!(config.targets.accounts, vec!["alice", "bob", "charlie"]);
         assert_eq!(config.targets.max_target_replies_per_day, 5);
+        assert!((config.targets.recency_bonus_max - 12.5).abs() < f32::EPSILON);
     });
 }
 
@@ -288,6 +329,19 @@ fn env_override_logging_fields() {
     });
 }
 
+#[test]
+fn env_override_dump_prompt_on_error() {
+    with_locked_env(|| {
+        let mut config = Config::default();
+        config.apply_env_overrides().expect("env override");
+        assert!(!config.logging.dump_prompt_on_error);
+
+        let _s = ScopedEnvVar::set("TUITBOT_DUMP_PROMPT_ON_ERROR", "true");
+        config.apply_env_overrides().expect("env override");
+        assert!(config.logging.dump_prompt_on_error);
+    });
+}
+
 #[test]
 fn env_no_overrides_is_noop() {
     with_locked_env(|| {
@@ -317,7 +371,7 @@ fn config_default_serde_roundtrip() {
 #[test]
 fn server_config_default() {
     let sc = super::types::ServerConfig::default();
-    assert_eq!(sc.host, "127.0.0.1");
+    assert_eq!(sc.host, None);
     assert_eq!(sc.port, 3001);
 }
 
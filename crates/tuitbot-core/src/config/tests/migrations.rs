@@ -59,6 +59,8 @@ fn legacy_local_fs_config_unaffected_by_deployment_mode() {
         folder_id: None,
         service_account_key: None,
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec!["*.md".to_string()],
         loop_back_enabled: true,
@@ -85,6 +87,8 @@ fn legacy_sa_key_only_config_still_valid() {
         folder_id: Some("abc123".to_string()),
         service_account_key: Some("/keys/sa.json".to_string()),
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec!["*.md".to_string()],
         loop_back_enabled: false,
@@ -126,6 +130,8 @@ fn connection_id_without_sa_key_valid() {
         folder_id: Some("folder_xyz".to_string()),
         service_account_key: None,
         connection_id: Some(7),
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec!["*.md".to_string()],
         loop_back_enabled: false,
@@ -148,6 +154,8 @@ fn google_drive_source_no_auth_warns() {
         folder_id: Some("folder_abc".to_string()),
         service_account_key: None,
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec!["*.md".to_string()],
         loop_back_enabled: false,
@@ -172,6 +180,8 @@ fn source_is_enabled_defaults_to_watch() {
         folder_id: None,
         service_account_key: None,
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec![],
         loop_back_enabled: false,
@@ -198,6 +208,8 @@ fn source_enabled_overrides_watch() {
         folder_id: None,
         service_account_key: None,
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec![],
         loop_back_enabled: false,
@@ -246,6 +258,8 @@ fn source_change_detection_poll() {
         folder_id: None,
         service_account_key: None,
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec![],
         loop_back_enabled: false,
@@ -266,6 +280,8 @@ fn source_change_detection_none_means_scan_only() {
         folder_id: None,
         service_account_key: None,
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec![],
         loop_back_enabled: false,
@@ -290,6 +306,8 @@ fn validate_invalid_change_detection() {
         folder_id: None,
         service_account_key: None,
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec!["*.md".to_string()],
         loop_back_enabled: false,
@@ -319,6 +337,8 @@ fn validate_poll_interval_too_low() {
         folder_id: None,
         service_account_key: None,
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec!["*.md".to_string()],
         loop_back_enabled: false,
@@ -348,6 +368,8 @@ fn validate_enabled_local_fs_without_path() {
         folder_id: None,
         service_account_key: None,
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec!["*.md".to_string()],
         loop_back_enabled: false,
@@ -373,6 +395,8 @@ fn validate_disabled_source_skips_path_check() {
         folder_id: None,
         service_account_key: None,
         connection_id: None,
+        database_id: None,
+        api_token_env: None,
         watch: true,
         file_patterns: vec!["*.md".to_string()],
         loop_back_enabled: false,
@@ -181,6 +181,21 @@ fn validate_missing_industry_topics() {
     ));
 }
 
+#[test]
+fn validate_content_pillars_satisfy_industry_topics() {
+    let mut config = Config::default();
+    config.business.product_name = "Test".to_string();
+    config.business.product_keywords = vec!["test".to_string()];
+    config.business.product_description = "A product".to_string();
+    config.business.content_pillars = vec!["DevOps".to_string()];
+    config.llm.provider = "ollama".to_string();
+    config.x_api.client_id = "test-id".to_string();
+    let errors = config.validate().err().unwrap_or_default();
+    assert!(!errors.iter().any(
+        |e| matches!(e, ConfigError::MissingField { field } if field == "business.industry_topics")
+    ));
+}
+
 #[test]
 fn validate_max_tweets_per_day_zero() {
     let mut config = valid_test_config();
@@ -201,6 +216,16 @@ fn validate_max_threads_per_week_zero() {
     ));
 }
 
+#[test]
+fn validate_min_engagement_sample_zero() {
+    let mut config = valid_test_config();
+    config.analytics.min_engagement_sample = 0;
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(
+        |e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "analytics.min_engagement_sample")
+    ));
+}
+
 #[test]
 fn validate_active_hours_start_over_23() {
     let mut config = valid_test_config();
@@ -278,6 +303,55 @@ fn validate_valid_auth_modes() {
     }
 }
 
+#[test]
+fn validate_token_storage() {
+    let mut config = valid_test_config();
+    config.auth.token_storage = "keychain".to_string();
+    assert_eq!(config.validate().is_ok(), cfg!(feature = "keychain"));
+
+    config.auth.token_storage = "vault".to_string();
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(
+        |e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "auth.token_storage")
+    ));
+}
+
+#[test]
+fn validate_limit_ramp() {
+    let mut config = valid_test_config();
+    config.limits.ramp = Some(LimitRampConfig {
+        start: 0.25,
+        target: 1.0,
+        days: 14,
+    });
+    assert!(config.validate().is_ok());
+
+    config.limits.ramp = Some(LimitRampConfig {
+        start: 0.0,
+        target: 0.1,
+        days: 0,
+    });
+    let errors = config.validate().unwrap_err();
+    for expected in ["limits.ramp.start", "limits.ramp.days"] {
+        assert!(
+            errors
+                .iter()
+                .any(|e| matches!(e, ConfigError::InvalidValue { field, .. } if field == expected)),
+            "{expected}"
+        );
+    }
+
+    config.limits.ramp = Some(LimitRampConfig {
+        start: 0.5,
+        target: 0.25,
+        days: 7,
+    });
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(
+        |e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "limits.ramp.target")
+    ));
+}
+
 #[test]
 fn validate_mcp_policy_blocked_and_approval_overlap() {
     let mut config = valid_test_config();
@@ -338,6 +412,113 @@ fn validate_ollama_no_api_key_needed() {
     assert!(config.validate().is_ok());
 }
 
+#[test]
+fn validate_creativity_and_temperature_ranges() {
+    let mut config = valid_test_config();
+    config.content.creativity = 1.5;
+    config.content.thread_temperature = Some(2.5);
+    config.content.reply_temperature = Some(0.2);
+    let errors = config.validate().unwrap_err();
+    let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+    assert!(errors.iter().any(
+        |e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "content.creativity")
+    ));
+    assert!(errors.iter().any(|e| matches!(
+        e,
+        ConfigError::InvalidValue { field, .. } if field == "content.thread_temperature"
+    )));
+    assert!(
+        !messages.iter().any(|m| m.contains("reply_temperature")),
+        "{messages:?}"
+    );
+
+    config.content.creativity = 0.0;
+    config.content.thread_temperature = Some(2.0);
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn validate_framework_weights() {
+    let mut config = valid_test_config();
+    config.content.framework_weights = [
+        ("contrarian_take".to_string(), 20.0),
+        ("list".to_string(), 0.0),
+        ("ask_question".to_string(), -1.0),
+        ("educational".to_string(), 50.0),
+        ("mistakes".to_string(), 0.0),
+    ]
+    .into();
+
+    let errors = config.validate().unwrap_err();
+    let messages: Vec<String> = errors
+        .iter()
+        .filter_map(|e| match e {
+            ConfigError::InvalidValue { field, message } => Some(format!("{field}: {message}")),
+            _ => None,
+        })
+        .collect();
+    assert!(messages
+        .iter()
+        .any(|m| m.starts_with("content.framework_weights.ask_question: must be a non-negative")));
+    assert!(messages
+        .iter()
+        .any(|m| m == "content.framework_weights.educational: unknown framework name"));
+    assert!(messages
+        .iter()
+        .any(|m| m
+            == "content.framework_weights: at least one structure must have a positive weight"));
+    assert_eq!(messages.len(), 3, "{messages:?}");
+
+    config.content.framework_weights = [
+        ("contrarian_take".to_string(), 20.0),
+        ("list".to_string(), 0.0),
+        ("ask_question".to_string(), 1.5),
+    ]
+    .into();
+    assert!(config.validate().is_ok());
+}
+
+#[test]
+fn validate_llm_fallbacks_and_headers() {
+    let mut config = valid_test_config();
+    config.llm.provider = "ollama".to_string();
+    config.llm.fallbacks = vec![
+        crate::config::LlmProviderConfig {
+            provider: "openai".to_string(),
+            base_url: Some("https://proxy.internal/v1".to_string()),
+            headers: [("X-Proxy-Auth".to_string(), "token".to_string())].into(),
+            ..Default::default()
+        },
+        crate::config::LlmProviderConfig {
+            provider: "gemini".to_string(),
+            ..Default::default()
+        },
+    ];
+    config.llm.headers = [("bad header".to_string(), "v".to_string())].into();
+
+    let errors = config.validate().unwrap_err();
+    let fields: Vec<String> = errors
+        .iter()
+        .filter_map(|e| match e {
+            ConfigError::MissingField { field } | ConfigError::InvalidValue { field, .. } => {
+                Some(field.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    assert!(fields
+        .iter()
+        .any(|f| f.starts_with("llm.fallbacks[0].api_key")));
+    assert!(fields.contains(&"llm.fallbacks[1].provider".to_string()));
+    assert!(fields.contains(&"llm.headers.bad header".to_string()));
+    assert!(!fields.iter().any(|f| f.contains("X-Proxy-Auth")));
+
+    config.llm.fallbacks.truncate(1);
+    config.llm.fallbacks[0].api_key = Some("sk-test".to_string());
+    config.llm.headers.clear();
+    assert!(config.validate().is_ok());
+}
+
 #[test]
 fn validate_empty_description() {
     let mut config = valid_test_config();
@@ -391,3 +572,17 @@ fn validate_effective_slots_override_exceeds_max() {
         |e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "schedule.preferred_times")
     ));
 }
+
+#[test]
+fn validate_redact_patterns_must_compile() {
+    let mut config = valid_test_config();
+    config.logging.redact_patterns = vec![r"acme_[0-9a-f]{16}".to_string(), "(".to_string()];
+    let errors = config.validate().unwrap_err();
+    assert_eq!(
+        errors
+            .iter()
+            .filter(|e| matches!(e, ConfigError::InvalidValue { field, .. } if field == "logging.redact_patterns"))
+            .count(),
+        1
+    );
+}
@@ -1,5 +1,6 @@
 //! Core server, auth, business profile, scoring, storage, and deployment configuration types.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // ---------------------------------------------------------------------------
@@ -7,7 +8,7 @@ use serde::{Deserialize, Serialize};
 // ---------------------------------------------------------------------------
 
 /// X API credentials.
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct XApiConfig {
     /// OAuth 2.0 client ID.
     #[serde(default)]
@@ -25,6 +26,67 @@ pub struct XApiConfig {
     /// Only meaningful when `provider_backend = "scraper"`. Default: `false`.
     #[serde(default)]
     pub scraper_allow_mutations: bool,
+
+    /// HTTP timeouts for X API requests (`[x_api.http]`).
+    #[serde(default)]
+    pub http: HttpTimeoutConfig,
+
+    /// How long `get_me` and user-by-username lookups are cached, in
+    /// seconds (0 = no caching).
+    #[serde(default = "default_x_api_cache_ttl_seconds")]
+    pub cache_ttl_seconds: u64,
+}
+
+impl Default for XApiConfig {
+    fn default() -> Self {
+        Self {
+            client_id: String::new(),
+            client_secret: None,
+            provider_backend: String::new(),
+            scraper_allow_mutations: false,
+            http: HttpTimeoutConfig::default(),
+            cache_ttl_seconds: default_x_api_cache_ttl_seconds(),
+        }
+    }
+}
+
+fn default_x_api_cache_ttl_seconds() -> u64 {
+    300
+}
+
+/// Connect and request timeouts for an outbound HTTP client.
+///
+/// Used by `[x_api.http]` and `[llm.http]`. A request that exceeds either
+/// limit fails with a timeout error, which the retry layers treat as transient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct HttpTimeoutConfig {
+    /// Maximum time to establish a connection, in milliseconds.
+    #[serde(default = "default_connect_timeout_ms")]
+    #[schemars(range(min = 1))]
+    pub connect_timeout_ms: u64,
+
+    /// Maximum time for a whole request, including reading the response body,
+    /// in milliseconds.
+    #[serde(default = "default_request_timeout_ms")]
+    #[schemars(range(min = 1))]
+    pub request_timeout_ms: u64,
+}
+
+impl Default for HttpTimeoutConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout_ms: default_connect_timeout_ms(),
+            request_timeout_ms: default_request_timeout_ms(),
+        }
+    }
+}
+
+fn default_connect_timeout_ms() -> u64 {
+    10_000
+}
+
+fn default_request_timeout_ms() -> u64 {
+    60_000
 }
 
 // ---------------------------------------------------------------------------
@@ -32,10 +94,11 @@ pub struct XApiConfig {
 // ---------------------------------------------------------------------------
 
 /// Authentication mode and callback settings.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct AuthConfig {
     /// Auth mode: "manual" or "local_callback".
     #[serde(default = "default_auth_mode")]
+    #[schemars(extend("enum" = ["manual", "local_callback"]))]
     pub mode: String,
 
     /// Host for local callback server.
@@ -45,6 +108,12 @@ pub struct AuthConfig {
     /// Port for local callback server.
     #[serde(default = "default_callback_port")]
     pub callback_port: u16,
+
+    /// Where OAuth tokens are kept: "file" (`~/.tuitbot/tokens.json`) or
+    /// "keychain" (the OS credential store).
+    #[serde(default = "default_token_storage")]
+    #[schemars(extend("enum" = ["file", "keychain"]))]
+    pub token_storage: String,
 }
 
 // ---------------------------------------------------------------------------
@@ -65,7 +134,7 @@ pub struct AuthConfig {
 /// **Enrichment fields** (shape voice/persona — unlocked via progressive setup):
 /// - `brand_voice`, `reply_style`, `content_style`,
 ///   `persona_opinions`, `persona_experiences`, `content_pillars`
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
 pub struct BusinessProfile {
     // -- Quickstart fields --
     /// Name of the user's product.
@@ -151,6 +220,34 @@ impl BusinessProfile {
         }
     }
 
+    /// Returns the topics the content and thread loops post about, and
+    /// which field they came from.
+    ///
+    /// Uses `industry_topics` when set. Otherwise each of the
+    /// `content_pillars` is used as-is plus a few concrete angles on it
+    /// (see [`PILLAR_ANGLES`]). With neither set, falls back to
+    /// `product_keywords` like [`Self::effective_industry_topics`].
+    pub fn content_topics(&self) -> (Vec<String>, TopicSource) {
+        if !self.industry_topics.is_empty() {
+            return (self.industry_topics.clone(), TopicSource::IndustryTopics);
+        }
+        if !self.content_pillars.is_empty() {
+            let topics = self
+                .content_pillars
+                .iter()
+                .flat_map(|pillar| {
+                    std::iter::once(pillar.clone()).chain(
+                        PILLAR_ANGLES
+                            .iter()
+                            .map(move |angle| angle.replace("{pillar}", pillar)),
+                    )
+                })
+                .collect();
+            return (topics, TopicSource::ContentPillars);
+        }
+        (self.product_keywords.clone(), TopicSource::ProductKeywords)
+    }
+
     /// Returns the merged keyword set used for draft-context retrieval.
     ///
     /// Combines `product_keywords`, `competitor_keywords`, and the
@@ -179,15 +276,42 @@ impl BusinessProfile {
     }
 }
 
+/// Templates expanding a content pillar into concrete post angles when
+/// pillars stand in for `industry_topics`.
+pub const PILLAR_ANGLES: &[&str] = &[
+    "common mistakes with {pillar}",
+    "lessons learned from {pillar}",
+    "practical {pillar} tips",
+];
+
+/// Which [`BusinessProfile`] field supplied the content loop topics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TopicSource {
+    IndustryTopics,
+    ContentPillars,
+    ProductKeywords,
+}
+
+impl std::fmt::Display for TopicSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TopicSource::IndustryTopics => write!(f, "industry_topics"),
+            TopicSource::ContentPillars => write!(f, "content_pillars"),
+            TopicSource::ProductKeywords => write!(f, "product_keywords"),
+        }
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Scoring
 // ---------------------------------------------------------------------------
 
 /// Scoring engine weights and threshold.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ScoringConfig {
     /// Minimum score (0-100) to trigger a reply.
     #[serde(default = "default_threshold")]
+    #[schemars(range(max = 100))]
     pub threshold: u32,
 
     /// Maximum points for keyword relevance.
@@ -213,22 +337,40 @@ pub struct ScoringConfig {
     /// Maximum points for content type signal (text-only originals score highest).
     #[serde(default = "default_content_type_max")]
     pub content_type_max: f32,
-}
 
-// ---------------------------------------------------------------------------
-// Storage
-// ---------------------------------------------------------------------------
+    /// Shape of the recency decay curve.
+    #[serde(default)]
+    pub recency_curve: RecencyCurve,
 
-/// Data storage configuration.
-#[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct StorageConfig {
-    /// Path to the SQLite database file.
-    #[serde(default = "default_db_path")]
-    pub db_path: String,
+    /// Tweet age in minutes at which the recency signal drops to half of
+    /// `recency_max`. Lower values favor very fresh tweets. Must be > 0.
+    #[serde(default = "default_recency_half_life_minutes")]
+    #[schemars(range(min = 1))]
+    pub recency_half_life_minutes: u32,
+}
 
-    /// Number of days to retain data.
-    #[serde(default = "default_retention_days")]
-    pub retention_days: u32,
+/// How the recency signal decays with tweet age.
+#[derive(Debug, Clone, Copy, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RecencyCurve {
+    /// Stepped brackets (full score while fresh, zero past six half-lives),
+    /// stretched so the score is half at the half-life.
+    #[default]
+    Brackets,
+    /// Straight line from full score to zero at twice the half-life.
+    Linear,
+    /// Halves every half-life; never quite reaches zero.
+    Exponential,
+}
+
+impl std::fmt::Display for RecencyCurve {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RecencyCurve::Brackets => write!(f, "brackets"),
+            RecencyCurve::Linear => write!(f, "linear"),
+            RecencyCurve::Exponential => write!(f, "exponential"),
+        }
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -236,36 +378,87 @@ pub struct StorageConfig {
 // ---------------------------------------------------------------------------
 
 /// Server binding configuration for LAN access.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ServerConfig {
     /// Host address to bind to. Use "0.0.0.0" for LAN access.
-    #[serde(default = "default_server_host")]
-    pub host: String,
+    ///
+    /// When unset, the host follows `deployment_mode`
+    /// (see [`DeploymentMode::default_bind_host`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub host: Option<String>,
 
     /// Port to listen on.
     #[serde(default = "default_server_port")]
     pub port: u16,
+
+    /// Cross-origin request policy for the HTTP API.
+    #[serde(default)]
+    pub cors: CorsConfig,
 }
 
 impl Default for ServerConfig {
     fn default() -> Self {
         Self {
-            host: default_server_host(),
+            host: None,
             port: default_server_port(),
+            cors: CorsConfig::default(),
         }
     }
 }
 
+/// Cross-origin (CORS) policy for the HTTP API (`[server.cors]`).
+///
+/// When `allowed_origins` is non-empty, only those origins (plus the
+/// server's own origin) may make cross-origin requests, regardless of
+/// `permissive`. With no origins listed, `permissive = true` allows any
+/// origin and `permissive = false` allows same-origin requests only.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct CorsConfig {
+    /// Allow requests from any origin when no `allowed_origins` are listed.
+    #[serde(default = "default_cors_permissive")]
+    pub permissive: bool,
+
+    /// Origins allowed to call the API, e.g. `"http://192.168.1.20:5173"`.
+    #[serde(default)]
+    pub allowed_origins: Vec<String>,
+}
+
+impl Default for CorsConfig {
+    fn default() -> Self {
+        Self {
+            permissive: default_cors_permissive(),
+            allowed_origins: Vec::new(),
+        }
+    }
+}
+
+impl CorsConfig {
+    /// Whether requests from arbitrary origins are allowed.
+    pub fn is_permissive(&self) -> bool {
+        self.permissive && self.allowed_origins.is_empty()
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Logging
 // ---------------------------------------------------------------------------
 
 /// Logging and observability settings.
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
 pub struct LoggingConfig {
     /// Seconds between periodic status summaries (0 = disabled).
     #[serde(default)]
     pub status_interval_seconds: u64,
+
+    /// On a content generation failure, log the full prompt and the last raw
+    /// model response at debug level (secrets redacted). Off by default.
+    #[serde(default)]
+    pub dump_prompt_on_error: bool,
+
+    /// Extra regexes masked in logs and diagnostics, on top of the built-in
+    /// credential shapes.
+    #[serde(default)]
+    pub redact_patterns: Vec<String>,
 }
 
 // ---------------------------------------------------------------------------
@@ -277,7 +470,7 @@ pub struct LoggingConfig {
 /// - **Desktop**: Native Tauri app. Full local filesystem access + native file picker.
 /// - **SelfHost**: Docker/VPS browser UI. Local filesystem access (server-side paths).
 /// - **Cloud**: Managed cloud service. No local filesystem access.
-#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum DeploymentMode {
     #[default]
@@ -325,6 +518,17 @@ pub struct DeploymentCapabilities {
 }
 
 impl DeploymentMode {
+    /// Host the server binds to when neither `--host` nor `server.host` is set.
+    ///
+    /// Desktop stays on loopback; self-hosted and cloud deployments are
+    /// reached over the network, so they listen on all interfaces.
+    pub fn default_bind_host(&self) -> &'static str {
+        match self {
+            DeploymentMode::Desktop => "127.0.0.1",
+            DeploymentMode::SelfHost | DeploymentMode::Cloud => "0.0.0.0",
+        }
+    }
+
     /// Returns the set of capabilities for this deployment mode.
     pub fn capabilities(&self) -> DeploymentCapabilities {
         match self {
@@ -367,6 +571,8 @@ impl DeploymentMode {
         match source_type {
             "local_fs" => caps.local_folder,
             "google_drive" => caps.google_drive,
+            // Notion is API-only, so it works wherever the server can reach the internet.
+            "notion" => true,
             "manual" => caps.inline_ingest,
             _ => false,
         }
@@ -405,7 +611,7 @@ impl DeploymentMode {
 /// These are *application credentials* (e.g. GCP OAuth client ID/secret),
 /// not user credentials. They define which OAuth application the linking
 /// flow uses. User credentials are stored in the `connections` table.
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
 pub struct ConnectorConfig {
     /// Google Drive connector settings.
     #[serde(default)]
@@ -417,7 +623,7 @@ pub struct ConnectorConfig {
 /// Self-hosted operators configure these once in `config.toml` or via
 /// environment variables. Desktop installs can bundle embedded defaults
 /// via env vars in the Tauri sidecar.
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
 pub struct GoogleDriveConnectorConfig {
     /// GCP OAuth client ID for user-account Drive linking.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -448,6 +654,10 @@ fn default_callback_port() -> u16 {
     8080
 }
 
+fn default_token_storage() -> String {
+    "file".to_string()
+}
+
 fn default_threshold() -> u32 {
     60
 }
@@ -476,18 +686,14 @@ fn default_content_type_max() -> f32 {
     10.0
 }
 
-fn default_db_path() -> String {
-    "~/.tuitbot/tuitbot.db".to_string()
-}
-
-fn default_retention_days() -> u32 {
-    90
-}
-
-fn default_server_host() -> String {
-    "127.0.0.1".to_string()
+pub(crate) fn default_recency_half_life_minutes() -> u32 {
+    60
 }
 
 fn default_server_port() -> u16 {
     3001
 }
+
+fn default_cors_permissive() -> bool {
+    true
+}
@@ -1,13 +1,18 @@
 //! LLM and embedding provider configuration types.
 
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use super::core_types::HttpTimeoutConfig;
+
 // ---------------------------------------------------------------------------
 // LLM
 // ---------------------------------------------------------------------------
 
 /// LLM provider configuration.
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct LlmConfig {
     /// LLM provider name: "openai", "anthropic", "ollama", or "groq".
     #[serde(default)]
@@ -24,6 +29,146 @@ pub struct LlmConfig {
     /// Override URL for custom endpoints.
     #[serde(default)]
     pub base_url: Option<String>,
+
+    /// Extra HTTP headers sent with every request (e.g. a proxy auth header).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+
+    /// Providers tried in order when the primary one fails with a transient
+    /// error (`[[llm.fallbacks]]`). Each carries its own endpoint and headers.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fallbacks: Vec<LlmProviderConfig>,
+
+    /// Retry policy for transient provider failures.
+    #[serde(default)]
+    pub retry: LlmRetryConfig,
+
+    /// HTTP timeouts for provider requests (`[llm.http]`).
+    #[serde(default)]
+    pub http: HttpTimeoutConfig,
+
+    /// Largest `max_tokens` a single request may ask for (0 = no cap).
+    /// Larger requests are clamped.
+    #[serde(default = "default_max_tokens_cap")]
+    pub max_tokens_cap: u32,
+
+    /// Total input and output tokens a provider may use before further
+    /// generations are refused (0 = unlimited).
+    #[serde(default)]
+    pub session_token_budget: u64,
+}
+
+impl Default for LlmConfig {
+    fn default() -> Self {
+        Self {
+            provider: String::new(),
+            api_key: None,
+            model: String::new(),
+            base_url: None,
+            headers: BTreeMap::new(),
+            fallbacks: Vec::new(),
+            retry: LlmRetryConfig::default(),
+            http: HttpTimeoutConfig::default(),
+            max_tokens_cap: default_max_tokens_cap(),
+            session_token_budget: 0,
+        }
+    }
+}
+
+impl LlmConfig {
+    /// The primary provider as a chain entry.
+    pub fn primary(&self) -> LlmProviderConfig {
+        LlmProviderConfig {
+            provider: self.provider.clone(),
+            api_key: self.api_key.clone(),
+            model: self.model.clone(),
+            base_url: self.base_url.clone(),
+            headers: self.headers.clone(),
+        }
+    }
+
+    /// The primary provider followed by every fallback, in the order tried.
+    pub fn provider_chain(&self) -> Vec<LlmProviderConfig> {
+        std::iter::once(self.primary())
+            .chain(self.fallbacks.iter().cloned())
+            .collect()
+    }
+}
+
+/// One provider in the LLM chain (`[[llm.fallbacks]]`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct LlmProviderConfig {
+    /// LLM provider name: "openai", "anthropic", "ollama", or "groq".
+    #[serde(default)]
+    pub provider: String,
+
+    /// API key for this provider (not needed for ollama).
+    #[serde(default)]
+    pub api_key: Option<String>,
+
+    /// Provider-specific model name.
+    #[serde(default)]
+    pub model: String,
+
+    /// Override URL, e.g. a corporate proxy or an Azure OpenAI deployment.
+    #[serde(default)]
+    pub base_url: Option<String>,
+
+    /// Extra HTTP headers sent with every request to this provider.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub headers: BTreeMap<String, String>,
+}
+
+/// Retry policy for LLM calls (`[llm.retry]`).
+///
+/// Only transient failures (timeouts, 5xx, rate limits) are retried; see
+/// [`LlmError::is_transient`](crate::error::LlmError::is_transient).
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct LlmRetryConfig {
+    /// Maximum attempts per LLM call, including the first.
+    #[serde(default = "default_retry_max_attempts")]
+    pub max_attempts: u32,
+
+    /// Delay before the first retry in milliseconds.
+    #[serde(default = "default_retry_base_delay_ms")]
+    pub base_delay_ms: u64,
+
+    /// How the delay grows between retries.
+    #[serde(default)]
+    pub backoff: LlmBackoff,
+}
+
+impl Default for LlmRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: default_retry_max_attempts(),
+            base_delay_ms: default_retry_base_delay_ms(),
+            backoff: LlmBackoff::default(),
+        }
+    }
+}
+
+/// Backoff strategy between LLM retries.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum LlmBackoff {
+    /// Double the delay after each failed attempt.
+    #[default]
+    Exponential,
+    /// Wait `base_delay_ms` before every retry.
+    Fixed,
+}
+
+fn default_max_tokens_cap() -> u32 {
+    4096
+}
+
+fn default_retry_max_attempts() -> u32 {
+    3
+}
+
+fn default_retry_base_delay_ms() -> u64 {
+    500
 }
 
 // ---------------------------------------------------------------------------
@@ -31,7 +176,7 @@ pub struct LlmConfig {
 // ---------------------------------------------------------------------------
 
 /// Embedding provider configuration for semantic search indexing.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct EmbeddingConfig {
     /// Embedding provider: "ollama" (default) or "openai".
     #[serde(default = "default_embedding_provider")]
@@ -1,24 +1,33 @@
 //! Configuration section structs and their serde default functions.
 //!
 //! Split into submodules by domain:
-//! - `core_types`: X API, auth, business profile, scoring, storage, server, logging, deployment
+//! - `core_types`: X API, auth, business profile, scoring, server, logging, deployment
 //! - `policy_types`: rate limits, intervals, targets, content sources
 //! - `llm_types`: LLM and embedding provider config
+//! - `storage_types`: SQLite path, retention, and connection pragmas
 
 mod core_types;
 mod llm_types;
 mod policy_types;
+mod storage_types;
 
 #[cfg(test)]
 mod tests;
 
+pub(crate) use core_types::default_recency_half_life_minutes;
 pub use core_types::{
-    AuthConfig, BusinessProfile, ConnectorConfig, DeploymentCapabilities, DeploymentMode,
-    GoogleDriveConnectorConfig, LoggingConfig, ScoringConfig, ServerConfig, StorageConfig,
-    XApiConfig,
+    AuthConfig, BusinessProfile, ConnectorConfig, CorsConfig, DeploymentCapabilities,
+    DeploymentMode, GoogleDriveConnectorConfig, HttpTimeoutConfig, LoggingConfig, RecencyCurve,
+    ScoringConfig, ServerConfig, TopicSource, XApiConfig, PILLAR_ANGLES,
 };
-pub use llm_types::{EmbeddingConfig, LlmConfig};
+pub use llm_types::{EmbeddingConfig, LlmBackoff, LlmConfig, LlmProviderConfig, LlmRetryConfig};
 pub use policy_types::{
-    ContentSourceEntry, ContentSourcesConfig, IntervalsConfig, LimitsConfig, TargetsConfig,
-    CHANGE_DETECTION_AUTO, CHANGE_DETECTION_NONE, CHANGE_DETECTION_POLL, MIN_POLL_INTERVAL_SECONDS,
+    ContentSourceEntry, ContentSourcesConfig, IntervalsConfig, LimitRampConfig, LimitsConfig,
+    LoopbackConfig, TargetsConfig, CHANGE_DETECTION_AUTO, CHANGE_DETECTION_NONE,
+    CHANGE_DETECTION_POLL, MIN_POLL_INTERVAL_SECONDS,
+};
+pub use storage_types::StorageConfig;
+pub(crate) use storage_types::{
+    default_busy_timeout_ms, default_critical_retention_days, default_journal_mode,
+    default_synchronous,
 };
@@ -1,5 +1,6 @@
 //! Rate limit, interval, target, and content source configuration types.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 // ---------------------------------------------------------------------------
@@ -7,18 +8,21 @@ use serde::{Deserialize, Serialize};
 // ---------------------------------------------------------------------------
 
 /// Safety limits for API actions.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct LimitsConfig {
     /// Maximum replies per day.
     #[serde(default = "default_max_replies_per_day")]
+    #[schemars(range(min = 1))]
     pub max_replies_per_day: u32,
 
     /// Maximum original tweets per day.
     #[serde(default = "default_max_tweets_per_day")]
+    #[schemars(range(min = 1))]
     pub max_tweets_per_day: u32,
 
     /// Maximum threads per week.
     #[serde(default = "default_max_threads_per_week")]
+    #[schemars(range(min = 1))]
     pub max_threads_per_week: u32,
 
     /// Minimum delay between actions in seconds.
@@ -33,6 +37,23 @@ pub struct LimitsConfig {
     #[serde(default = "default_max_replies_per_author_per_day")]
     pub max_replies_per_author_per_day: u32,
 
+    /// Maximum replies into the same conversation thread per day.
+    #[serde(default = "default_max_replies_per_conversation_per_day")]
+    pub max_replies_per_conversation_per_day: u32,
+
+    /// Maximum likes per day, across MCP tools and the discovery loop.
+    #[serde(default = "default_max_likes_per_day")]
+    pub max_likes_per_day: u32,
+
+    /// Maximum retweets per day.
+    #[serde(default = "default_max_retweets_per_day")]
+    pub max_retweets_per_day: u32,
+
+    /// Like tweets the discovery loop finds above the scoring threshold,
+    /// within `max_likes_per_day`.
+    #[serde(default)]
+    pub discovery_likes: bool,
+
     /// Phrases that should never appear in generated replies.
     #[serde(default = "default_banned_phrases")]
     pub banned_phrases: Vec<String>,
@@ -40,6 +61,36 @@ pub struct LimitsConfig {
     /// Fraction of replies that may mention the product (0.0 - 1.0).
     #[serde(default = "default_product_mention_ratio")]
     pub product_mention_ratio: f32,
+
+    /// Soft-launch ramp for the reply, tweet, and thread limits. Unset means
+    /// the full limits apply from day one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ramp: Option<LimitRampConfig>,
+}
+
+/// Gradual increase of the posting limits for a new account.
+///
+/// `start` and `target` are fractions of each configured limit. On the day
+/// of the account's first recorded activity the limits are scaled by `start`;
+/// the factor then grows linearly to `target`, reached after `days` days.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, JsonSchema)]
+pub struct LimitRampConfig {
+    /// Fraction of each limit allowed on day zero (0.0 - 1.0].
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub start: f64,
+
+    /// Fraction of each limit allowed once the ramp is over, usually 1.0.
+    #[serde(default = "default_ramp_target")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub target: f64,
+
+    /// Days from first activity until `target` is reached.
+    #[schemars(range(min = 1))]
+    pub days: u32,
+}
+
+fn default_ramp_target() -> f64 {
+    1.0
 }
 
 // ---------------------------------------------------------------------------
@@ -47,7 +98,7 @@ pub struct LimitsConfig {
 // ---------------------------------------------------------------------------
 
 /// Automation interval settings.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct IntervalsConfig {
     /// Seconds between mention checks.
     #[serde(default = "default_mentions_check_seconds")]
@@ -57,6 +108,11 @@ pub struct IntervalsConfig {
     #[serde(default = "default_discovery_search_seconds")]
     pub discovery_search_seconds: u64,
 
+    /// Search result pages fetched per keyword in one discovery run.
+    /// Each page costs one search request against the search rate limit.
+    #[serde(default = "default_discovery_max_pages")]
+    pub discovery_max_pages: u32,
+
     /// Seconds for content post window.
     #[serde(default = "default_content_post_window_seconds")]
     pub content_post_window_seconds: u64,
@@ -71,7 +127,7 @@ pub struct IntervalsConfig {
 // ---------------------------------------------------------------------------
 
 /// Target account monitoring configuration.
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
 pub struct TargetsConfig {
     /// Target account usernames to monitor (without @).
     #[serde(default)]
@@ -80,6 +136,11 @@ pub struct TargetsConfig {
     /// Maximum target account replies per day (separate from general limit).
     #[serde(default = "default_max_target_replies_per_day")]
     pub max_target_replies_per_day: u32,
+
+    /// Extra score points for a brand-new tweet from a target account.
+    /// Halves every `scoring.recency_half_life_minutes`. 0 disables it.
+    #[serde(default = "default_target_recency_bonus_max")]
+    pub recency_bonus_max: f32,
 }
 
 // ---------------------------------------------------------------------------
@@ -87,17 +148,70 @@ pub struct TargetsConfig {
 // ---------------------------------------------------------------------------
 
 /// Content source configuration for the Watchtower.
-#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ContentSourcesConfig {
     /// Configured content sources.
     #[serde(default)]
     pub sources: Vec<ContentSourceEntry>,
+
+    /// Throttling for metadata written back to source files.
+    #[serde(default)]
+    pub loopback: LoopbackConfig,
+
+    /// Maximum files ingested concurrently during a directory scan.
+    #[serde(default = "default_scan_concurrency")]
+    pub scan_concurrency: u32,
+
+    /// Largest source file ingested, in bytes (0 = unlimited). Larger files
+    /// are skipped.
+    #[serde(default = "default_max_file_bytes")]
+    pub max_file_bytes: u64,
+
+    /// File extensions local sources may ingest, without the dot. Files
+    /// with other extensions are skipped even if a file pattern matches.
+    #[serde(default = "default_allowed_extensions")]
+    pub allowed_extensions: Vec<String>,
+}
+
+impl Default for ContentSourcesConfig {
+    fn default() -> Self {
+        Self {
+            sources: Vec::new(),
+            loopback: LoopbackConfig::default(),
+            scan_concurrency: default_scan_concurrency(),
+            max_file_bytes: default_max_file_bytes(),
+            allowed_extensions: default_allowed_extensions(),
+        }
+    }
+}
+
+/// Loop-back write throttling (`[content_sources.loopback]`).
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct LoopbackConfig {
+    /// Seconds a path written by Tuitbot is ignored by the watcher, so the
+    /// write does not trigger a re-ingest.
+    #[serde(default = "default_loopback_cooldown_seconds")]
+    pub cooldown_seconds: u64,
+
+    /// Maximum loop-back writes per source in any rolling hour (0 = unlimited).
+    /// Writes beyond the cap are skipped.
+    #[serde(default = "default_loop_back_max_writes_per_hour")]
+    pub loop_back_max_writes_per_hour: u32,
+}
+
+impl Default for LoopbackConfig {
+    fn default() -> Self {
+        Self {
+            cooldown_seconds: default_loopback_cooldown_seconds(),
+            loop_back_max_writes_per_hour: default_loop_back_max_writes_per_hour(),
+        }
+    }
 }
 
 /// A single content source entry.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ContentSourceEntry {
-    /// Source type: `"local_fs"` or `"google_drive"`.
+    /// Source type: `"local_fs"`, `"google_drive"`, or `"notion"`.
     #[serde(default = "default_source_type")]
     pub source_type: String,
 
@@ -120,6 +234,15 @@ pub struct ContentSourceEntry {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub connection_id: Option<i64>,
 
+    /// Notion database ID (for notion sources).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub database_id: Option<String>,
+
+    /// Name of the environment variable holding the Notion integration
+    /// token (for notion sources). Defaults to `NOTION_TOKEN`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub api_token_env: Option<String>,
+
     /// Whether to watch for changes in real-time.
     ///
     /// **Deprecated:** Use `enabled` and `change_detection` instead.
@@ -138,7 +261,7 @@ pub struct ContentSourceEntry {
     /// How changes are detected for this source.
     ///
     /// - `"auto"` (default) — local_fs: notify watcher + fallback poll;
-    ///   google_drive: interval poll.
+    ///   google_drive and notion: interval poll.
     /// - `"poll"` — poll only (useful when notify is unreliable, e.g. NFS).
     /// - `"none"` — initial scan only, no ongoing monitoring.
     #[serde(default = "default_change_detection")]
@@ -235,6 +358,10 @@ fn default_discovery_search_seconds() -> u64 {
     900
 }
 
+fn default_discovery_max_pages() -> u32 {
+    1
+}
+
 fn default_content_post_window_seconds() -> u64 {
     10800
 }
@@ -247,6 +374,18 @@ fn default_max_replies_per_author_per_day() -> u32 {
     1
 }
 
+fn default_max_replies_per_conversation_per_day() -> u32 {
+    2
+}
+
+fn default_max_likes_per_day() -> u32 {
+    20
+}
+
+fn default_max_retweets_per_day() -> u32 {
+    5
+}
+
 fn default_banned_phrases() -> Vec<String> {
     vec![
         "check out".to_string(),
@@ -264,6 +403,10 @@ fn default_max_target_replies_per_day() -> u32 {
     3
 }
 
+fn default_target_recency_bonus_max() -> f32 {
+    10.0
+}
+
 fn default_source_type() -> String {
     "local_fs".to_string()
 }
@@ -283,3 +426,23 @@ fn default_file_patterns() -> Vec<String> {
 fn default_loop_back() -> bool {
     true
 }
+
+fn default_scan_concurrency() -> u32 {
+    4
+}
+
+fn default_max_file_bytes() -> u64 {
+    1024 * 1024
+}
+
+fn default_allowed_extensions() -> Vec<String> {
+    vec!["md".to_string(), "txt".to_string()]
+}
+
+fn default_loopback_cooldown_seconds() -> u64 {
+    5
+}
+
+fn default_loop_back_max_writes_per_hour() -> u32 {
+    60
+}
@@ -0,0 +1,58 @@
+//! Storage (SQLite) configuration types.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Data storage configuration.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct StorageConfig {
+    /// Path to the SQLite database file.
+    #[serde(default = "default_db_path")]
+    pub db_path: String,
+
+    /// Number of days to retain data.
+    #[serde(default = "default_retention_days")]
+    pub retention_days: u32,
+
+    /// Days to retain rows needed for deduplication (sent replies).
+    /// Never shorter than `retention_days`.
+    #[serde(default = "default_critical_retention_days")]
+    pub critical_retention_days: u32,
+
+    /// Milliseconds a connection waits for a lock before failing with
+    /// "database is locked".
+    #[serde(default = "default_busy_timeout_ms")]
+    pub busy_timeout_ms: u64,
+
+    /// SQLite journal mode: "wal", "delete", "truncate", "persist", "memory", or "off".
+    #[serde(default = "default_journal_mode")]
+    pub journal_mode: String,
+
+    /// SQLite synchronous level: "off", "normal", "full", or "extra".
+    #[serde(default = "default_synchronous")]
+    pub synchronous: String,
+}
+
+fn default_db_path() -> String {
+    "~/.tuitbot/tuitbot.db".to_string()
+}
+
+fn default_retention_days() -> u32 {
+    90
+}
+
+pub(crate) fn default_critical_retention_days() -> u32 {
+    365
+}
+
+pub(crate) fn default_busy_timeout_ms() -> u64 {
+    5000
+}
+
+pub(crate) fn default_journal_mode() -> String {
+    "wal".to_string()
+}
+
+pub(crate) fn default_synchronous() -> String {
+    "normal".to_string()
+}
@@ -20,9 +20,12 @@ fn x_api_config_serde_roundtrip() {
         client_secret: Some("secret".into()),
         provider_backend: "x_api".into(),
         scraper_allow_mutations: true,
+        http: Default::default(),
+        cache_ttl_seconds: 60,
     };
     let json = serde_json::to_string(&cfg).unwrap();
     let back: XApiConfig = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.cache_ttl_seconds, 60);
     assert_eq!(back.client_id, "my-client-id");
     assert_eq!(back.client_secret.as_deref(), Some("secret"));
     assert_eq!(back.provider_backend, "x_api");
@@ -45,12 +48,14 @@ fn auth_config_serde_roundtrip() {
         mode: "local_callback".into(),
         callback_host: "0.0.0.0".into(),
         callback_port: 9090,
+        token_storage: "keychain".into(),
     };
     let json = serde_json::to_string(&cfg).unwrap();
     let back: AuthConfig = serde_json::from_str(&json).unwrap();
     assert_eq!(back.mode, "local_callback");
     assert_eq!(back.callback_host, "0.0.0.0");
     assert_eq!(back.callback_port, 9090);
+    assert_eq!(back.token_storage, "keychain");
 }
 
 #[test]
@@ -59,6 +64,7 @@ fn auth_config_deserialize_defaults() {
     assert_eq!(cfg.mode, "manual");
     assert_eq!(cfg.callback_host, "127.0.0.1");
     assert_eq!(cfg.callback_port, 8080);
+    assert_eq!(cfg.token_storage, "file");
 }
 
 // --- BusinessProfile ---
@@ -113,6 +119,49 @@ fn business_profile_effective_industry_topics_empty_falls_back() {
     assert_eq!(bp.effective_industry_topics(), &["fallback"]);
 }
 
+#[test]
+fn content_topics_prefer_industry_topics_over_pillars() {
+    let bp = BusinessProfile {
+        product_keywords: vec!["kw".into()],
+        industry_topics: vec!["topic".into()],
+        content_pillars: vec!["pillar".into()],
+        ..Default::default()
+    };
+    assert_eq!(
+        bp.content_topics(),
+        (vec!["topic".to_string()], TopicSource::IndustryTopics)
+    );
+}
+
+#[test]
+fn content_topics_fall_back_to_expanded_pillars() {
+    let bp = BusinessProfile {
+        product_keywords: vec!["kw".into()],
+        content_pillars: vec!["DevOps".into(), "Testing".into()],
+        ..Default::default()
+    };
+    let (topics, source) = bp.content_topics();
+    assert_eq!(source, TopicSource::ContentPillars);
+    assert_eq!(topics.len(), 2 * (1 + PILLAR_ANGLES.len()));
+    assert_eq!(topics[0], "DevOps");
+    assert_eq!(topics[1], "common mistakes with DevOps");
+    assert!(topics.contains(&"practical Testing tips".to_string()));
+    assert!(!topics.contains(&"kw".to_string()));
+}
+
+#[test]
+fn content_topics_without_topics_or_pillars_use_keywords() {
+    let bp = BusinessProfile {
+        product_keywords: vec!["kw".into()],
+        ..Default::default()
+    };
+    assert_eq!(
+        bp.content_topics(),
+        (vec!["kw".to_string()], TopicSource::ProductKeywords)
+    );
+    assert_eq!(BusinessProfile::default().content_topics().0.len(), 0);
+}
+
 #[test]
 fn business_profile_draft_context_keywords() {
     let bp = BusinessProfile {
@@ -258,11 +307,25 @@ fn scoring_config_serde_roundtrip() {
         engagement_rate_max: 20.0,
         reply_count_max: 10.0,
         content_type_max: 5.0,
+        recency_curve: RecencyCurve::Exponential,
+        recency_half_life_minutes: 15,
     };
     let json = serde_json::to_string(&cfg).unwrap();
     let back: ScoringConfig = serde_json::from_str(&json).unwrap();
     assert_eq!(back.threshold, 80);
     assert!((back.keyword_relevance_max - 30.0).abs() < 0.001);
+    assert_eq!(back.recency_curve, RecencyCurve::Exponential);
+    assert_eq!(back.recency_half_life_minutes, 15);
+}
+
+#[test]
+fn scoring_config_recency_defaults() {
+    let cfg: ScoringConfig = serde_json::from_str("{}").unwrap();
+    assert_eq!(cfg.recency_curve, RecencyCurve::Brackets);
+    assert_eq!(cfg.recency_half_life_minutes, 60);
+
+    let cfg: ScoringConfig = serde_json::from_str(r#"{"recency_curve":"linear"}"#).unwrap();
+    assert_eq!(cfg.recency_curve, RecencyCurve::Linear);
 }
 
 // --- LimitsConfig ---
@@ -276,6 +339,10 @@ fn limits_config_deserialize_defaults() {
     assert_eq!(cfg.min_action_delay_seconds, 45);
     assert_eq!(cfg.max_action_delay_seconds, 180);
     assert_eq!(cfg.max_replies_per_author_per_day, 1);
+    assert_eq!(cfg.max_replies_per_conversation_per_day, 2);
+    assert_eq!(cfg.max_likes_per_day, 20);
+    assert_eq!(cfg.max_retweets_per_day, 5);
+    assert!(!cfg.discovery_likes);
     assert!(!cfg.banned_phrases.is_empty());
     assert!((cfg.product_mention_ratio - 0.2).abs() < 0.001);
 }
@@ -289,8 +356,13 @@ fn limits_config_serde_roundtrip() {
         min_action_delay_seconds: 60,
         max_action_delay_seconds: 300,
         max_replies_per_author_per_day: 2,
+        max_replies_per_conversation_per_day: 2,
+        max_likes_per_day: 20,
+        max_retweets_per_day: 5,
+        discovery_likes: false,
         banned_phrases: vec!["spam".into()],
         product_mention_ratio: 0.3,
+        ramp: None,
     };
     let json = serde_json::to_string(&cfg).unwrap();
     let back: LimitsConfig = serde_json::from_str(&json).unwrap();
@@ -299,6 +371,18 @@ fn limits_config_serde_roundtrip() {
     assert_eq!(back.banned_phrases, vec!["spam"]);
 }
 
+#[test]
+fn limits_config_ramp_is_optional_and_target_defaults_to_full() {
+    let cfg: LimitsConfig = toml::from_str("").unwrap();
+    assert!(cfg.ramp.is_none());
+
+    let cfg: LimitsConfig = toml::from_str("[ramp]\nstart = 0.25\ndays = 14").unwrap();
+    let ramp = cfg.ramp.expect("ramp");
+    assert!((ramp.start - 0.25).abs() < f64::EPSILON);
+    assert!((ramp.target - 1.0).abs() < f64::EPSILON);
+    assert_eq!(ramp.days, 14);
+}
+
 // --- IntervalsConfig ---
 
 #[test]
@@ -306,6 +390,7 @@ fn intervals_config_deserialize_defaults() {
     let cfg: IntervalsConfig = serde_json::from_str("{}").unwrap();
     assert_eq!(cfg.mentions_check_seconds, 300);
     assert_eq!(cfg.discovery_search_seconds, 900);
+    assert_eq!(cfg.discovery_max_pages, 1);
     assert_eq!(cfg.content_post_window_seconds, 10800);
     assert_eq!(cfg.thread_interval_seconds, 604800);
 }
@@ -315,6 +400,7 @@ fn intervals_config_serde_roundtrip() {
     let cfg = IntervalsConfig {
         mentions_check_seconds: 120,
         discovery_search_seconds: 600,
+        discovery_max_pages: 1,
         content_post_window_seconds: 7200,
         thread_interval_seconds: 86400,
     };
@@ -338,11 +424,13 @@ fn targets_config_serde_roundtrip() {
     let cfg = TargetsConfig {
         accounts: vec!["user1".into(), "user2".into()],
         max_target_replies_per_day: 5,
+        recency_bonus_max: 4.0,
     };
     let json = serde_json::to_string(&cfg).unwrap();
     let back: TargetsConfig = serde_json::from_str(&json).unwrap();
     assert_eq!(back.accounts.len(), 2);
     assert_eq!(back.max_target_replies_per_day, 5);
+    assert!((back.recency_bonus_max - 4.0).abs() < f32::EPSILON);
 }
 
 #[test]
@@ -350,6 +438,7 @@ fn targets_config_deserialize_defaults() {
     let cfg: TargetsConfig = serde_json::from_str("{}").unwrap();
     assert!(cfg.accounts.is_empty());
     assert_eq!(cfg.max_target_replies_per_day, 3);
+    assert!((cfg.recency_bonus_max - 10.0).abs() < f32::EPSILON);
 }
 
 // --- LlmConfig ---
@@ -361,6 +450,20 @@ fn llm_config_default() {
     assert!(cfg.api_key.is_none());
     assert!(cfg.model.is_empty());
     assert!(cfg.base_url.is_none());
+    assert_eq!(cfg.max_tokens_cap, 4096);
+    assert_eq!(cfg.session_token_budget, 0);
+}
+
+#[test]
+fn llm_budget_fields_default_when_omitted() {
+    let cfg: LlmConfig = toml::from_str("provider = \"openai\"").unwrap();
+    assert_eq!(cfg.max_tokens_cap, 4096);
+    assert_eq!(cfg.session_token_budget, 0);
+
+    let cfg: LlmConfig =
+        toml::from_str("max_tokens_cap = 1024\nsession_token_budget = 200000").unwrap();
+    assert_eq!(cfg.max_tokens_cap, 1024);
+    assert_eq!(cfg.session_token_budget, 200_000);
 }
 
 #[test]
@@ -370,6 +473,7 @@ fn llm_config_serde_roundtrip() {
         api_key: Some("sk-test".into()),
         model: "claude-3-5-sonnet".into(),
         base_url: Some("https://api.anthropic.com".into()),
+        ..Default::default()
     };
     let json = serde_json::to_string(&cfg).unwrap();
     let back: LlmConfig = serde_json::from_str(&json).unwrap();
@@ -386,6 +490,9 @@ fn storage_config_deserialize_defaults() {
     let cfg: StorageConfig = serde_json::from_str("{}").unwrap();
     assert_eq!(cfg.db_path, "~/.tuitbot/tuitbot.db");
     assert_eq!(cfg.retention_days, 90);
+    assert_eq!(cfg.busy_timeout_ms, 5000);
+    assert_eq!(cfg.journal_mode, "wal");
+    assert_eq!(cfg.synchronous, "normal");
 }
 
 #[test]
@@ -393,11 +500,18 @@ fn storage_config_serde_roundtrip() {
     let cfg = StorageConfig {
         db_path: "/custom/path.db".into(),
         retention_days: 30,
+        critical_retention_days: 180,
+        busy_timeout_ms: 2500,
+        journal_mode: "delete".into(),
+        synchronous: "full".into(),
     };
     let json = serde_json::to_string(&cfg).unwrap();
     let back: StorageConfig = serde_json::from_str(&json).unwrap();
     assert_eq!(back.db_path, "/custom/path.db");
     assert_eq!(back.retention_days, 30);
+    assert_eq!(back.busy_timeout_ms, 2500);
+    assert_eq!(back.journal_mode, "delete");
+    assert_eq!(back.synchronous, "full");
 }
 
 // --- ServerConfig ---
@@ -405,38 +519,67 @@ fn storage_config_serde_roundtrip() {
 #[test]
 fn server_config_default() {
     let cfg = ServerConfig::default();
-    assert_eq!(cfg.host, "127.0.0.1");
+    assert_eq!(cfg.host, None);
     assert_eq!(cfg.port, 3001);
 }
 
 #[test]
 fn server_config_serde_roundtrip() {
     let cfg = ServerConfig {
-        host: "0.0.0.0".into(),
+        host: Some("0.0.0.0".into()),
         port: 8080,
+        cors: CorsConfig::default(),
     };
     let json = serde_json::to_string(&cfg).unwrap();
     let back: ServerConfig = serde_json::from_str(&json).unwrap();
-    assert_eq!(back.host, "0.0.0.0");
+    assert_eq!(back.host.as_deref(), Some("0.0.0.0"));
     assert_eq!(back.port, 8080);
 }
 
+#[test]
+fn cors_config_defaults_to_permissive() {
+    let cfg = ServerConfig::default();
+    assert!(cfg.cors.permissive);
+    assert!(cfg.cors.allowed_origins.is_empty());
+    assert!(cfg.cors.is_permissive());
+}
+
+#[test]
+fn cors_config_origins_override_permissive() {
+    let cfg: ServerConfig = toml::from_str(
+        r#"
+        host = "0.0.0.0"
+        [cors]
+        allowed_origins = ["http://192.168.1.20:5173"]
+        "#,
+    )
+    .unwrap();
+    assert!(cfg.cors.permissive);
+    assert!(!cfg.cors.is_permissive());
+}
+
 // --- LoggingConfig ---
 
 #[test]
 fn logging_config_default() {
     let cfg = LoggingConfig::default();
     assert_eq!(cfg.status_interval_seconds, 0);
+    assert!(!cfg.dump_prompt_on_error);
+    assert!(cfg.redact_patterns.is_empty());
 }
 
 #[test]
 fn logging_config_serde_roundtrip() {
     let cfg = LoggingConfig {
         status_interval_seconds: 60,
+        dump_prompt_on_error: true,
+        redact_patterns: vec![r"acme_[0-9a-f]{16}".to_string()],
     };
     let json = serde_json::to_string(&cfg).unwrap();
     let back: LoggingConfig = serde_json::from_str(&json).unwrap();
     assert_eq!(back.status_interval_seconds, 60);
+    assert!(back.dump_prompt_on_error);
+    assert_eq!(back.redact_patterns, cfg.redact_patterns);
 }
 
 // --- ContentSourcesConfig ---
@@ -456,6 +599,8 @@ fn content_sources_config_serde_roundtrip() {
             folder_id: None,
             service_account_key: None,
             connection_id: None,
+            database_id: None,
+            api_token_env: None,
             watch: true,
             enabled: Some(true),
             change_detection: "auto".into(),
@@ -464,6 +609,7 @@ fn content_sources_config_serde_roundtrip() {
             analytics_sync_enabled: false,
             poll_interval_seconds: None,
         }],
+        ..Default::default()
     };
     let json = serde_json::to_string(&cfg).unwrap();
     let back: ContentSourcesConfig = serde_json::from_str(&json).unwrap();
@@ -618,6 +764,7 @@ fn deployment_mode_allows_source_type() {
 
     assert!(!DeploymentMode::Cloud.allows_source_type("local_fs"));
     assert!(DeploymentMode::Cloud.allows_source_type("google_drive"));
+    assert!(DeploymentMode::Cloud.allows_source_type("notion"));
 }
 
 // --- DeploymentCapabilities ---
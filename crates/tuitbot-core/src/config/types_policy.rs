@@ -1,14 +1,15 @@
-//! Schedule, MCP policy, and circuit breaker configuration types.
+//! Schedule, MCP policy, circuit breaker, content, and analytics configuration types.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 // ---------------------------------------------------------------------------
 // Schedule
 // ---------------------------------------------------------------------------
 
 /// Active hours schedule configuration.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct ScheduleConfig {
     /// IANA timezone name (e.g. "America/New_York", "UTC").
     #[serde(default = "default_timezone")]
@@ -16,10 +17,12 @@ pub struct ScheduleConfig {
 
     /// Hour of day (0-23) when active posting window starts.
     #[serde(default = "default_active_hours_start")]
+    #[schemars(range(max = 23))]
     pub active_hours_start: u8,
 
     /// Hour of day (0-23) when active posting window ends.
     #[serde(default = "default_active_hours_end")]
+    #[schemars(range(max = 23))]
     pub active_hours_end: u8,
 
     /// Days of the week when posting is active (e.g. ["Mon", "Tue", ...]).
@@ -97,7 +100,7 @@ fn default_thread_preferred_time() -> String {
 ///
 /// v2 fields (`template`, `rules`, `rate_limits`) are additive — existing
 /// v1 configs deserialize without changes.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct McpPolicyConfig {
     // --- v1 fields (unchanged) ---
     /// Master switch: when false, all mutations are allowed without checks.
@@ -132,6 +135,12 @@ pub struct McpPolicyConfig {
     /// Per-dimension rate limits (beyond the global `max_mutations_per_hour`).
     #[serde(default)]
     pub rate_limits: Vec<crate::mcp_policy::types::PolicyRateLimit>,
+
+    /// Per-tool hourly caps, keyed by tool name. A listed tool is limited
+    /// by its own cap instead of `max_mutations_per_hour`; unlisted tools
+    /// fall back to the global cap.
+    #[serde(default)]
+    pub tool_limits: BTreeMap<String, u32>,
 }
 
 fn default_true() -> bool {
@@ -156,7 +165,7 @@ fn default_max_mutations_per_hour() -> u32 {
 // ---------------------------------------------------------------------------
 
 /// Circuit breaker configuration for X API rate-limit protection.
-#[derive(Debug, Clone, Deserialize, Serialize)]
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 pub struct CircuitBreakerConfig {
     /// Number of errors within the window to trip the breaker.
     #[serde(default = "default_cb_error_threshold")]
@@ -169,6 +178,10 @@ pub struct CircuitBreakerConfig {
     /// How long (seconds) to stay Open before allowing a probe mutation.
     #[serde(default = "default_cb_cooldown_seconds")]
     pub cooldown_seconds: u64,
+
+    /// Per-loop error budgets (`[circuit_breaker.loops]`).
+    #[serde(default)]
+    pub loops: LoopBreakerConfig,
 }
 
 impl Default for CircuitBreakerConfig {
@@ -177,6 +190,7 @@ impl Default for CircuitBreakerConfig {
             error_threshold: default_cb_error_threshold(),
             window_seconds: default_cb_window_seconds(),
             cooldown_seconds: default_cb_cooldown_seconds(),
+            loops: LoopBreakerConfig::default(),
         }
     }
 }
@@ -191,6 +205,205 @@ fn default_cb_cooldown_seconds() -> u64 {
     600
 }
 
+/// Error budget for each automation loop.
+///
+/// After `failure_threshold` consecutive failures within `window_seconds`,
+/// the loop is paused for `cooldown_seconds`. Each time it fails again
+/// straight after a pause the cooldown doubles, up to `max_cooldown_seconds`.
+/// A single success resets the budget.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct LoopBreakerConfig {
+    /// Consecutive failures that pause a loop.
+    #[serde(default = "default_loop_failure_threshold")]
+    pub failure_threshold: u32,
+
+    /// Failures older than this many seconds no longer count.
+    #[serde(default = "default_loop_window_seconds")]
+    pub window_seconds: u64,
+
+    /// Pause after the first trip, in seconds.
+    #[serde(default = "default_loop_cooldown_seconds")]
+    pub cooldown_seconds: u64,
+
+    /// Upper bound on the doubled pause, in seconds.
+    #[serde(default = "default_loop_max_cooldown_seconds")]
+    pub max_cooldown_seconds: u64,
+}
+
+impl Default for LoopBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: default_loop_failure_threshold(),
+            window_seconds: default_loop_window_seconds(),
+            cooldown_seconds: default_loop_cooldown_seconds(),
+            max_cooldown_seconds: default_loop_max_cooldown_seconds(),
+        }
+    }
+}
+
+fn default_loop_failure_threshold() -> u32 {
+    5
+}
+fn default_loop_window_seconds() -> u64 {
+    6 * 3600
+}
+fn default_loop_cooldown_seconds() -> u64 {
+    1800
+}
+fn default_loop_max_cooldown_seconds() -> u64 {
+    24 * 3600
+}
+
+// ---------------------------------------------------------------------------
+// Content
+// ---------------------------------------------------------------------------
+
+/// Content generation preferences.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct ContentConfig {
+    /// When true, callers that don't pick an archetype, format, or thread
+    /// structure get the least-recently-used one from `content::rotation`.
+    #[serde(default)]
+    pub auto_rotate: bool,
+
+    /// When true, RAG context from content sources is scrubbed of known
+    /// prompt-injection phrases and fenced off as untrusted reference
+    /// material before it is added to a prompt.
+    #[serde(default = "default_true")]
+    pub rag_sanitize: bool,
+
+    /// When true, the content loop fails a tweet when no RAG context can be
+    /// retrieved from configured content sources. When false, it logs a
+    /// warning and generates without context.
+    #[serde(default)]
+    pub rag_required: bool,
+
+    /// Minimum hours between two original tweets on the same topic.
+    /// 0 disables the gap and keeps epsilon-greedy topic selection.
+    #[serde(default)]
+    pub min_topic_gap_hours: u64,
+
+    /// Seed for framework rotation and pacing jitter. When set, the same
+    /// seed reproduces the same archetype picks and delays across runs.
+    #[serde(default)]
+    pub random_seed: Option<u64>,
+
+    /// When true, discovery replies to tweets that are themselves replies
+    /// fetch the parent tweets and show them to the LLM as conversation context.
+    #[serde(default)]
+    pub include_conversation_context: bool,
+
+    /// Maximum ancestor tweets fetched per reply when
+    /// `include_conversation_context` is on. Each costs one tweet lookup.
+    #[serde(default = "default_max_conversation_tweets")]
+    #[schemars(range(min = 1, max = 10))]
+    pub max_conversation_tweets: u32,
+
+    /// What to do when generated text contains hashtags: "strip" removes
+    /// them, "allow" keeps them, "reject" fails the generation.
+    #[serde(default = "default_compliance_policy")]
+    #[schemars(extend("enum" = ["strip", "allow", "reject"]))]
+    pub hashtag_policy: String,
+
+    /// What to do when generated text has more than `max_emojis` emoji:
+    /// "strip" drops the extras, "allow" keeps them, "reject" fails the generation.
+    #[serde(default = "default_compliance_policy")]
+    #[schemars(extend("enum" = ["strip", "allow", "reject"]))]
+    pub emoji_policy: String,
+
+    /// Emoji allowed per generated tweet before `emoji_policy` applies.
+    #[serde(default = "default_max_emojis")]
+    pub max_emojis: u32,
+
+    /// Relative weights per framework name (`ask_question = 3`,
+    /// `contrarian_take = 1`, ...) used by framework selection and
+    /// `auto_rotate`. Once any framework of a kind is listed, unlisted or
+    /// zero-weighted frameworks of that kind are never picked.
+    #[serde(default)]
+    pub framework_weights: BTreeMap<String, f64>,
+
+    /// Generation creativity from 0.0 (conservative) to 1.0 (adventurous),
+    /// mapped to a temperature per content type. Replies always run cooler
+    /// than tweets, and tweets cooler than threads.
+    #[serde(default = "default_creativity")]
+    #[schemars(range(min = 0.0, max = 1.0))]
+    pub creativity: f32,
+
+    /// Temperature for replies and quote tweets; overrides `creativity`.
+    #[serde(default)]
+    pub reply_temperature: Option<f32>,
+
+    /// Temperature for original tweets and draft improvement; overrides `creativity`.
+    #[serde(default)]
+    pub tweet_temperature: Option<f32>,
+
+    /// Temperature for threads; overrides `creativity`.
+    #[serde(default)]
+    pub thread_temperature: Option<f32>,
+}
+
+impl Default for ContentConfig {
+    fn default() -> Self {
+        Self {
+            auto_rotate: false,
+            rag_sanitize: true,
+            rag_required: false,
+            min_topic_gap_hours: 0,
+            random_seed: None,
+            include_conversation_context: false,
+            max_conversation_tweets: default_max_conversation_tweets(),
+            hashtag_policy: default_compliance_policy(),
+            emoji_policy: default_compliance_policy(),
+            max_emojis: default_max_emojis(),
+            framework_weights: BTreeMap::new(),
+            creativity: default_creativity(),
+            reply_temperature: None,
+            tweet_temperature: None,
+            thread_temperature: None,
+        }
+    }
+}
+
+fn default_max_conversation_tweets() -> u32 {
+    3
+}
+fn default_compliance_policy() -> String {
+    "strip".to_string()
+}
+fn default_max_emojis() -> u32 {
+    3
+}
+fn default_creativity() -> f32 {
+    0.5
+}
+
+// ---------------------------------------------------------------------------
+// Analytics
+// ---------------------------------------------------------------------------
+
+/// Thresholds for learning from posted content.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
+pub struct AnalyticsConfig {
+    /// Posts an aggregation bucket (topic, posting hour) needs before it
+    /// counts as signal. Smaller buckets are marked `low_confidence` and
+    /// left out of strategy recommendations and optimal-time suggestions.
+    #[serde(default = "default_min_engagement_sample")]
+    #[schemars(range(min = 1))]
+    pub min_engagement_sample: u32,
+}
+
+impl Default for AnalyticsConfig {
+    fn default() -> Self {
+        Self {
+            min_engagement_sample: default_min_engagement_sample(),
+        }
+    }
+}
+
+fn default_min_engagement_sample() -> u32 {
+    3
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -260,6 +473,7 @@ mod tests {
             error_threshold: 10,
             window_seconds: 600,
             cooldown_seconds: 1200,
+            loops: Default::default(),
         };
         let json = serde_json::to_string(&cb).unwrap();
         let back: CircuitBreakerConfig = serde_json::from_str(&json).unwrap();
@@ -277,6 +491,32 @@ mod tests {
         assert_eq!(cb.cooldown_seconds, 600);
     }
 
+    // --- ContentConfig ---
+
+    #[test]
+    fn content_config_defaults_to_no_rotation() {
+        let cc: ContentConfig = serde_json::from_str("{}").unwrap();
+        assert!(!cc.auto_rotate);
+    }
+
+    #[test]
+    fn content_config_defaults_to_mid_creativity_without_overrides() {
+        let cc: ContentConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(cc.creativity, 0.5);
+        assert!(cc.reply_temperature.is_none());
+        assert!(cc.tweet_temperature.is_none());
+        assert!(cc.thread_temperature.is_none());
+    }
+
+    // --- AnalyticsConfig ---
+
+    #[test]
+    fn analytics_config_defaults_to_three_posts() {
+        let ac: AnalyticsConfig = serde_json::from_str("{}").unwrap();
+        assert_eq!(ac.min_engagement_sample, 3);
+        assert_eq!(AnalyticsConfig::default().min_engagement_sample, 3);
+    }
+
     // --- McpPolicyConfig ---
 
     #[test]
@@ -304,6 +544,7 @@ mod tests {
             template: Some(crate::mcp_policy::types::PolicyTemplateName::SafeDefault),
             rules: vec![],
             rate_limits: vec![],
+            tool_limits: BTreeMap::new(),
         };
         let json = serde_json::to_string(&pc).unwrap();
         let back: McpPolicyConfig = serde_json::from_str(&json).unwrap();
@@ -391,6 +632,7 @@ mod tests {
             error_threshold: 1,
             window_seconds: 60,
             cooldown_seconds: 30,
+            loops: Default::default(),
         };
         assert_eq!(cb.error_threshold, 1);
         assert_eq!(cb.window_seconds, 60);
@@ -412,6 +654,7 @@ mod tests {
             error_threshold: 1000,
             window_seconds: 86400,
             cooldown_seconds: 3600,
+            loops: Default::default(),
         };
         let json = serde_json::to_string(&cb).unwrap();
         let back: CircuitBreakerConfig = serde_json::from_str(&json).unwrap();
@@ -438,6 +681,7 @@ mod tests {
             template: None,
             rules: vec![],
             rate_limits: vec![],
+            tool_limits: BTreeMap::new(),
         };
         let json = serde_json::to_string(&pc).unwrap();
         let back: McpPolicyConfig = serde_json::from_str(&json).unwrap();
@@ -490,6 +734,7 @@ mod tests {
                 max_count: 10,
                 period_seconds: 3600,
             }],
+            tool_limits: BTreeMap::from([("post_tweet".to_string(), 5)]),
         };
         let json = serde_json::to_string(&pc).unwrap();
         let back: McpPolicyConfig = serde_json::from_str(&json).unwrap();
@@ -497,6 +742,7 @@ mod tests {
         assert_eq!(back.rules[0].id, "test_rule");
         assert_eq!(back.rate_limits.len(), 1);
         assert_eq!(back.rate_limits[0].key, "test:hourly");
+        assert_eq!(back.tool_limits.get("post_tweet"), Some(&5));
     }
 
     #[test]
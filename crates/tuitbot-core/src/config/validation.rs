@@ -118,7 +118,8 @@ impl Config {
             });
         }
 
-        if self.business.industry_topics.is_empty() {
+        // Content pillars stand in for topics (see `BusinessProfile::content_topics`).
+        if self.business.industry_topics.is_empty() && self.business.content_pillars.is_empty() {
             errors.push(ConfigError::MissingField {
                 field: "business.industry_topics".to_string(),
             });
@@ -151,6 +152,46 @@ impl Config {
             }
         }
 
+        // Validate LLM fallback chain and extra headers
+        for (i, entry) in self.llm.fallbacks.iter().enumerate() {
+            let field = format!("llm.fallbacks[{i}]");
+            if !matches!(
+                entry.provider.as_str(),
+                "openai" | "anthropic" | "ollama" | "groq"
+            ) {
+                errors.push(ConfigError::InvalidValue {
+                    field: format!("{field}.provider"),
+                    message: "must be openai, anthropic, ollama, or groq".to_string(),
+                });
+            }
+            if matches!(entry.provider.as_str(), "openai" | "anthropic" | "groq")
+                && entry.api_key.as_deref().map_or(true, str::is_empty)
+            {
+                errors.push(ConfigError::MissingField {
+                    field: format!("{field}.api_key (required for {} provider)", entry.provider),
+                });
+            }
+        }
+        let header_sets = std::iter::once(("llm.headers".to_string(), &self.llm.headers)).chain(
+            self.llm
+                .fallbacks
+                .iter()
+                .enumerate()
+                .map(|(i, entry)| (format!("llm.fallbacks[{i}].headers"), &entry.headers)),
+        );
+        for (field, headers) in header_sets {
+            for (name, value) in headers {
+                if reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_err()
+                    || reqwest::header::HeaderValue::from_str(value).is_err()
+                {
+                    errors.push(ConfigError::InvalidValue {
+                        field: format!("{field}.{name}"),
+                        message: "must be a valid HTTP header name and value".to_string(),
+                    });
+                }
+            }
+        }
+
         // Validate auth mode
         if !self.auth.mode.is_empty() {
             match self.auth.mode.as_str() {
@@ -164,6 +205,41 @@ impl Config {
             }
         }
 
+        if !matches!(self.auth.token_storage.as_str(), "file" | "keychain") {
+            errors.push(ConfigError::InvalidValue {
+                field: "auth.token_storage".to_string(),
+                message: format!(
+                    "must be file or keychain, got '{}'",
+                    self.auth.token_storage
+                ),
+            });
+        } else if self.auth.token_storage == "keychain" && !cfg!(feature = "keychain") {
+            errors.push(ConfigError::InvalidValue {
+                field: "auth.token_storage".to_string(),
+                message: "keychain storage is not available in this build; \
+                          reinstall with `--features keychain` or use file"
+                    .to_string(),
+            });
+        }
+
+        // Validate HTTP timeouts
+        for (section, http) in [
+            ("x_api.http", &self.x_api.http),
+            ("llm.http", &self.llm.http),
+        ] {
+            for (key, value) in [
+                ("connect_timeout_ms", http.connect_timeout_ms),
+                ("request_timeout_ms", http.request_timeout_ms),
+            ] {
+                if value == 0 {
+                    errors.push(ConfigError::InvalidValue {
+                        field: format!("{section}.{key}"),
+                        message: "must be greater than 0".to_string(),
+                    });
+                }
+            }
+        }
+
         // Validate scoring threshold
         if self.scoring.threshold > 100 {
             errors.push(ConfigError::InvalidValue {
@@ -172,6 +248,118 @@ impl Config {
             });
         }
 
+        if self.scoring.recency_half_life_minutes == 0 {
+            errors.push(ConfigError::InvalidValue {
+                field: "scoring.recency_half_life_minutes".to_string(),
+                message: "must be greater than 0".to_string(),
+            });
+        }
+
+        if !(0.0..=100.0).contains(&self.targets.recency_bonus_max) {
+            errors.push(ConfigError::InvalidValue {
+                field: "targets.recency_bonus_max".to_string(),
+                message: "must be between 0 and 100".to_string(),
+            });
+        }
+
+        if !(1..=10).contains(&self.intervals.discovery_max_pages) {
+            errors.push(ConfigError::InvalidValue {
+                field: "intervals.discovery_max_pages".to_string(),
+                message: "must be between 1 and 10".to_string(),
+            });
+        }
+
+        if !(1..=10).contains(&self.content.max_conversation_tweets) {
+            errors.push(ConfigError::InvalidValue {
+                field: "content.max_conversation_tweets".to_string(),
+                message: "must be between 1 and 10".to_string(),
+            });
+        }
+
+        if self.analytics.min_engagement_sample == 0 {
+            errors.push(ConfigError::InvalidValue {
+                field: "analytics.min_engagement_sample".to_string(),
+                message: "must be at least 1".to_string(),
+            });
+        }
+
+        for (field, value) in [
+            ("content.hashtag_policy", &self.content.hashtag_policy),
+            ("content.emoji_policy", &self.content.emoji_policy),
+        ] {
+            if crate::content::CompliancePolicy::parse(value).is_none() {
+                errors.push(ConfigError::InvalidValue {
+                    field: field.to_string(),
+                    message: format!(
+                        "must be one of {}, got '{value}'",
+                        crate::content::CompliancePolicy::VALUES.join(", ")
+                    ),
+                });
+            }
+        }
+
+        if !(0.0..=1.0).contains(&self.content.creativity) {
+            errors.push(ConfigError::InvalidValue {
+                field: "content.creativity".to_string(),
+                message: format!(
+                    "must be between 0.0 and 1.0, got {}",
+                    self.content.creativity
+                ),
+            });
+        }
+        for (field, value) in [
+            ("content.reply_temperature", self.content.reply_temperature),
+            ("content.tweet_temperature", self.content.tweet_temperature),
+            (
+                "content.thread_temperature",
+                self.content.thread_temperature,
+            ),
+        ] {
+            if let Some(t) = value {
+                if !(0.0..=crate::content::creativity::MAX_TEMPERATURE).contains(&t) {
+                    errors.push(ConfigError::InvalidValue {
+                        field: field.to_string(),
+                        message: format!(
+                            "must be between 0.0 and {}, got {t}",
+                            crate::content::creativity::MAX_TEMPERATURE
+                        ),
+                    });
+                }
+            }
+        }
+
+        let mut weighted_kinds: Vec<(&str, bool)> = Vec::new();
+        for (name, &weight) in &self.content.framework_weights {
+            let field = format!("content.framework_weights.{name}");
+            let Some(kind) = crate::content::frameworks::framework_kind(name) else {
+                errors.push(ConfigError::InvalidValue {
+                    field,
+                    message: "unknown framework name".to_string(),
+                });
+                continue;
+            };
+            if !weight.is_finite() || weight < 0.0 {
+                errors.push(ConfigError::InvalidValue {
+                    field,
+                    message: format!("must be a non-negative number, got {weight}"),
+                });
+                continue;
+            }
+            match weighted_kinds.iter_mut().find(|(k, _)| *k == kind) {
+                Some((_, any_positive)) => *any_positive |= weight > 0.0,
+                None => weighted_kinds.push((kind, weight > 0.0)),
+            }
+        }
+        for (kind, _) in weighted_kinds
+            .iter()
+            .filter(|(_, any_positive)| !any_positive)
+        {
+            errors.push(ConfigError::InvalidValue {
+                field: "content.framework_weights".to_string(),
+                message: format!("at least one {kind} must have a positive weight"),
+            });
+        }
+
         // Validate limits
         if self.limits.max_replies_per_day == 0 {
             errors.push(ConfigError::InvalidValue {
@@ -201,6 +389,45 @@ impl Config {
             });
         }
 
+        if let Some(ramp) = &self.limits.ramp {
+            if !(ramp.start > 0.0 && ramp.start <= 1.0) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "limits.ramp.start".to_string(),
+                    message: "must be greater than 0 and at most 1.0".to_string(),
+                });
+            }
+            if !(ramp.target >= ramp.start && ramp.target <= 1.0) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "limits.ramp.target".to_string(),
+                    message: "must be between limits.ramp.start and 1.0".to_string(),
+                });
+            }
+            if ramp.days == 0 {
+                errors.push(ConfigError::InvalidValue {
+                    field: "limits.ramp.days".to_string(),
+                    message: "must be greater than 0".to_string(),
+                });
+            }
+        }
+
+        // Validate CORS origins (scheme://host[:port], no path)
+        for origin in &self.server.cors.allowed_origins {
+            let valid = origin.split_once("://").is_some_and(|(scheme, rest)| {
+                !scheme.is_empty()
+                    && !rest.is_empty()
+                    && !rest.contains('/')
+                    && !origin.contains(char::is_whitespace)
+            });
+            if !valid {
+                errors.push(ConfigError::InvalidValue {
+                    field: "server.cors.allowed_origins".to_string(),
+                    message: format!(
+                        "'{origin}' is not a valid origin (expected scheme://host[:port], e.g. http://192.168.1.20:3001)"
+                    ),
+                });
+            }
+        }
+
         // Validate schedule
         if self.schedule.active_hours_start > 23 {
             errors.push(ConfigError::InvalidValue {
@@ -397,6 +624,42 @@ impl Config {
             }
         }
 
+        // Validate storage pragmas
+        if let Err(e) = crate::storage::ConnectionPragmas::from_config(&self.storage) {
+            errors.push(e);
+        }
+
+        for pattern in &self.logging.redact_patterns {
+            if let Err(e) = regex::Regex::new(pattern) {
+                errors.push(ConfigError::InvalidValue {
+                    field: "logging.redact_patterns".to_string(),
+                    message: format!("'{pattern}' is not a valid regex: {e}"),
+                });
+            }
+        }
+
+        if self.content_sources.scan_concurrency == 0 {
+            errors.push(ConfigError::InvalidValue {
+                field: "content_sources.scan_concurrency".to_string(),
+                message: "must be greater than 0".to_string(),
+            });
+        }
+        if self.content_sources.allowed_extensions.is_empty() {
+            errors.push(ConfigError::InvalidValue {
+                field: "content_sources.allowed_extensions".to_string(),
+                message: "must list at least one extension".to_string(),
+            });
+        }
+        for ext in &self.content_sources.allowed_extensions {
+            if ext.trim_start_matches('.').trim().is_empty() {
+                errors.push(ConfigError::InvalidValue {
+                    field: "content_sources.allowed_extensions".to_string(),
+                    message: "extensions must not be empty".to_string(),
+                });
+                break;
+            }
+        }
+
         // Validate content sources against deployment capabilities
         for (i, source) in self.content_sources.sources.iter().enumerate() {
             if !self.deployment_mode.allows_source_type(&source.source_type) {
@@ -461,6 +724,16 @@ impl Config {
                         ),
                     });
                 }
+                if source.source_type == "notion"
+                    && source.database_id.as_ref().map_or(true, |d| d.is_empty())
+                {
+                    errors.push(ConfigError::MissingField {
+                        field: format!(
+                            "content_sources.sources[{}].database_id (required for enabled notion source)",
+                            i
+                        ),
+                    });
+                }
             }
 
             // Warn if both connection_id and service_account_key are set.
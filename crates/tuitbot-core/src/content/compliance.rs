@@ -0,0 +1,262 @@
+//! Deterministic post-generation compliance pass.
+//!
+//! Prompts ask the model not to use hashtags and to go easy on emoji, but
+//! models do not always listen. [`enforce`] runs over every generated tweet
+//! and either cleans the text or rejects it, according to
+//! `content.hashtag_policy`, `content.emoji_policy`, and `content.max_emojis`.
+
+use std::sync::OnceLock;
+
+use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::length::{emoji_count, is_emoji};
+use crate::config::ContentConfig;
+
+/// What to do with output that breaks a rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompliancePolicy {
+    /// Remove the offending hashtags or emoji.
+    Strip,
+    /// Leave the text as generated.
+    Allow,
+    /// Fail the generation.
+    Reject,
+}
+
+impl CompliancePolicy {
+    /// Valid config values, in declaration order.
+    pub const VALUES: [&'static str; 3] = ["strip", "allow", "reject"];
+
+    /// Parse `strip`, `allow`, or `reject`.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "strip" => Some(Self::Strip),
+            "allow" => Some(Self::Allow),
+            "reject" => Some(Self::Reject),
+            _ => None,
+        }
+    }
+}
+
+/// Compliance rules applied to generated text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ComplianceRules {
+    pub hashtags: CompliancePolicy,
+    pub emoji: CompliancePolicy,
+    /// Emoji allowed per tweet before `emoji` applies.
+    pub max_emojis: usize,
+}
+
+impl Default for ComplianceRules {
+    fn default() -> Self {
+        Self {
+            hashtags: CompliancePolicy::Strip,
+            emoji: CompliancePolicy::Strip,
+            max_emojis: 3,
+        }
+    }
+}
+
+impl ComplianceRules {
+    /// Build rules from `[content]`. Unknown policy values fall back to `strip`;
+    /// config validation reports them.
+    pub fn from_config(content: &ContentConfig) -> Self {
+        Self {
+            hashtags: CompliancePolicy::parse(&content.hashtag_policy)
+                .unwrap_or(CompliancePolicy::Strip),
+            emoji: CompliancePolicy::parse(&content.emoji_policy)
+                .unwrap_or(CompliancePolicy::Strip),
+            max_emojis: content.max_emojis as usize,
+        }
+    }
+}
+
+fn hashtag_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    // A `#` not preceded by a word character, `&` (HTML entities) or `/`
+    // (URL fragments), followed by a tag that starts with a letter.
+    RE.get_or_init(|| {
+        Regex::new(r"(^|[^\w&/#])#([\p{L}_][\p{L}\p{N}_]*)").expect("valid hashtag pattern")
+    })
+}
+
+/// Count the hashtags in `text`.
+pub fn hashtag_count(text: &str) -> usize {
+    hashtag_regex().find_iter(text).count()
+}
+
+/// Apply `rules` to generated text.
+///
+/// Returns the cleaned text, which is empty if it held nothing but
+/// hashtags, or a description of the violation when a `reject` policy is hit.
+pub fn enforce(text: &str, rules: &ComplianceRules) -> Result<String, String> {
+    let mut out = text.to_string();
+
+    let hashtags = hashtag_count(&out);
+    if hashtags > 0 {
+        match rules.hashtags {
+            CompliancePolicy::Allow => {}
+            CompliancePolicy::Reject => {
+                return Err(format!("output contains {hashtags} hashtag(s)"));
+            }
+            CompliancePolicy::Strip => out = strip_hashtags(&out),
+        }
+    }
+
+    let emojis = emoji_count(&out);
+    if emojis > rules.max_emojis {
+        match rules.emoji {
+            CompliancePolicy::Allow => {}
+            CompliancePolicy::Reject => {
+                return Err(format!(
+                    "output contains {emojis} emoji (max {})",
+                    rules.max_emojis
+                ));
+            }
+            CompliancePolicy::Strip => out = strip_excess_emoji(&out, rules.max_emojis),
+        }
+    }
+
+    Ok(out)
+}
+
+/// Drop trailing hashtag blocks entirely and unwrap inline hashtags
+/// (`#rust` becomes `rust`) so sentences still read.
+fn strip_hashtags(text: &str) -> String {
+    let lines: Vec<String> = text
+        .lines()
+        .map(|line| {
+            let mut words: Vec<&str> = line.split(' ').collect();
+            while words
+                .last()
+                .is_some_and(|w| w.is_empty() || is_bare_hashtag(w))
+            {
+                words.pop();
+            }
+            let line = words.join(" ");
+            hashtag_regex().replace_all(&line, "$1$2").into_owned()
+        })
+        .collect();
+    tidy(&lines.join("\n"))
+}
+
+/// Whether `word` is a hashtag with at most trailing punctuation.
+fn is_bare_hashtag(word: &str) -> bool {
+    let trimmed = word.trim_end_matches(|c: char| c.is_ascii_punctuation() && c != '_');
+    hashtag_regex()
+        .find(trimmed)
+        .is_some_and(|m| m.start() == 0 && m.end() == trimmed.len())
+}
+
+/// Keep the first `max` emoji and remove the rest.
+fn strip_excess_emoji(text: &str, max: usize) -> String {
+    let mut kept = 0;
+    let out: String = text
+        .graphemes(true)
+        .filter(|g| {
+            if !is_emoji(g) {
+                return true;
+            }
+            kept += 1;
+            kept <= max
+        })
+        .collect();
+    tidy(&out)
+}
+
+/// Collapse runs of spaces left by removals, trim each line, and drop
+/// blank lines at either end.
+fn tidy(text: &str) -> String {
+    let lines: Vec<String> = text
+        .lines()
+        .map(|line| {
+            line.split(' ')
+                .filter(|w| !w.is_empty())
+                .collect::<Vec<_>>()
+                .join(" ")
+        })
+        .collect();
+    lines.join("\n").trim().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rules(
+        hashtags: CompliancePolicy,
+        emoji: CompliancePolicy,
+        max_emojis: usize,
+    ) -> ComplianceRules {
+        ComplianceRules {
+            hashtags,
+            emoji,
+            max_emojis,
+        }
+    }
+
+    #[test]
+    fn strip_removes_trailing_tags_and_unwraps_inline_ones() {
+        let r = rules(CompliancePolicy::Strip, CompliancePolicy::Allow, 3);
+        let out = enforce("Shipping #rust in prod is calm. #dev #buildinpublic", &r).unwrap();
+        assert_eq!(out, "Shipping rust in prod is calm.");
+    }
+
+    #[test]
+    fn strip_leaves_non_hashtags_alone() {
+        let r = ComplianceRules::default();
+        let text = "C# and F# are fine, issue #42 too: https://example.com/page#intro";
+        assert_eq!(hashtag_count(text), 0);
+        assert_eq!(enforce(text, &r).unwrap(), text);
+    }
+
+    #[test]
+    fn strip_drops_hashtag_only_lines() {
+        let r = ComplianceRules::default();
+        let out = enforce("Ship small, ship often.\n\n#devops #ci", &r).unwrap();
+        assert_eq!(out, "Ship small, ship often.");
+    }
+
+    #[test]
+    fn allow_keeps_hashtags_and_emoji() {
+        let r = rules(CompliancePolicy::Allow, CompliancePolicy::Allow, 1);
+        let text = "Launch day 🚀🔥🎉 #startup";
+        assert_eq!(enforce(text, &r).unwrap(), text);
+    }
+
+    #[test]
+    fn reject_fails_on_hashtags() {
+        let r = rules(CompliancePolicy::Reject, CompliancePolicy::Allow, 3);
+        let err = enforce("Great thread #growth", &r).unwrap_err();
+        assert!(err.contains("1 hashtag"));
+        assert!(enforce("No tags here.", &r).is_ok());
+    }
+
+    #[test]
+    fn strip_caps_emoji() {
+        let r = rules(CompliancePolicy::Allow, CompliancePolicy::Strip, 2);
+        let out = enforce("Ship it 🚀 today 🔥 and celebrate 🎉 with the team 👏🏽", &r).unwrap();
+        assert_eq!(out, "Ship it 🚀 today 🔥 and celebrate with the team");
+    }
+
+    #[test]
+    fn reject_fails_on_excess_emoji_only() {
+        let r = rules(CompliancePolicy::Allow, CompliancePolicy::Reject, 1);
+        assert!(enforce("One is fine 👍", &r).is_ok());
+        let err = enforce("Two is not 👍👍", &r).unwrap_err();
+        assert!(err.contains("2 emoji (max 1)"));
+    }
+
+    #[test]
+    fn stripping_everything_leaves_empty_text() {
+        let r = ComplianceRules::default();
+        assert_eq!(enforce("#mondaymotivation #grind", &r).unwrap(), "");
+    }
+
+    #[test]
+    fn zero_cap_strips_all_emoji() {
+        let r = rules(CompliancePolicy::Strip, CompliancePolicy::Strip, 0);
+        assert_eq!(enforce("Done ✅", &r).unwrap(), "Done");
+    }
+}
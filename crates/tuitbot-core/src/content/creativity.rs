@@ -0,0 +1,129 @@
+//! Per-content-type sampling temperatures from one creativity knob.
+//!
+//! `content.creativity` (0.0–1.0) moves every content type along its own
+//! temperature range. The ranges are staggered so that at any setting
+//! replies run cooler than tweets and tweets cooler than threads. An
+//! explicit `content.reply_temperature`, `tweet_temperature`, or
+//! `thread_temperature` replaces the derived value for that type.
+
+use crate::config::ContentConfig;
+
+/// Highest temperature accepted for an explicit per-type override.
+pub const MAX_TEMPERATURE: f32 = 2.0;
+
+/// Creativity used when none is configured.
+pub const DEFAULT_CREATIVITY: f32 = 0.5;
+
+/// Temperature range (creativity 0.0 → 1.0) for replies and quote tweets.
+const REPLY_RANGE: (f32, f32) = (0.4, 1.0);
+
+/// Temperature range for original tweets.
+const TWEET_RANGE: (f32, f32) = (0.5, 1.1);
+
+/// Temperature range for threads.
+const THREAD_RANGE: (f32, f32) = (0.6, 1.2);
+
+/// Sampling temperature for each kind of generated content.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Temperatures {
+    pub reply: f32,
+    pub tweet: f32,
+    pub thread: f32,
+}
+
+impl Default for Temperatures {
+    fn default() -> Self {
+        Self::from_creativity(DEFAULT_CREATIVITY)
+    }
+}
+
+impl Temperatures {
+    /// Derive temperatures from a creativity level, clamped to 0.0–1.0.
+    pub fn from_creativity(creativity: f32) -> Self {
+        let c = if creativity.is_nan() {
+            DEFAULT_CREATIVITY
+        } else {
+            creativity.clamp(0.0, 1.0)
+        };
+        let lerp = |(lo, hi): (f32, f32)| lo + (hi - lo) * c;
+        Self {
+            reply: lerp(REPLY_RANGE),
+            tweet: lerp(TWEET_RANGE),
+            thread: lerp(THREAD_RANGE),
+        }
+    }
+
+    /// Build temperatures from `[content]`: `creativity`, then any explicit
+    /// per-type temperature on top.
+    pub fn from_config(content: &ContentConfig) -> Self {
+        let derived = Self::from_creativity(content.creativity);
+        Self {
+            reply: content.reply_temperature.unwrap_or(derived.reply),
+            tweet: content.tweet_temperature.unwrap_or(derived.tweet),
+            thread: content.thread_temperature.unwrap_or(derived.thread),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn temperatures_rise_monotonically_with_creativity() {
+        let mut previous = Temperatures::from_creativity(0.0);
+        for step in 1..=10 {
+            let t = Temperatures::from_creativity(step as f32 / 10.0);
+            assert!(t.reply > previous.reply, "reply at step {step}");
+            assert!(t.tweet > previous.tweet, "tweet at step {step}");
+            assert!(t.thread > previous.thread, "thread at step {step}");
+            previous = t;
+        }
+    }
+
+    #[test]
+    fn replies_cooler_than_tweets_cooler_than_threads() {
+        for step in 0..=10 {
+            let t = Temperatures::from_creativity(step as f32 / 10.0);
+            assert!(t.reply < t.tweet && t.tweet < t.thread, "{t:?}");
+        }
+    }
+
+    #[test]
+    fn creativity_outside_range_is_clamped() {
+        assert_eq!(
+            Temperatures::from_creativity(-1.0),
+            Temperatures::from_creativity(0.0)
+        );
+        assert_eq!(
+            Temperatures::from_creativity(3.0),
+            Temperatures::from_creativity(1.0)
+        );
+        assert_eq!(
+            Temperatures::from_creativity(f32::NAN),
+            Temperatures::default()
+        );
+    }
+
+    #[test]
+    fn explicit_temperatures_override_creativity() {
+        let content = ContentConfig {
+            creativity: 1.0,
+            reply_temperature: Some(0.2),
+            ..ContentConfig::default()
+        };
+        let t = Temperatures::from_config(&content);
+        let derived = Temperatures::from_creativity(1.0);
+        assert_eq!(t.reply, 0.2);
+        assert_eq!(t.tweet, derived.tweet);
+        assert_eq!(t.thread, derived.thread);
+    }
+
+    #[test]
+    fn default_config_matches_default_creativity() {
+        assert_eq!(
+            Temperatures::from_config(&ContentConfig::default()),
+            Temperatures::default()
+        );
+    }
+}
@@ -4,9 +4,74 @@
 //! for threads. Each variant includes prompt fragment guidance so the
 //! LLM produces distinctly different content depending on the chosen
 //! framework.
+//!
+//! [`FrameworkWeights`] (`[content.framework_weights]`) biases selection
+//! toward a configured mix. [`performance_report`] closes the loop: posted
+//! items are linked to the framework that produced them and compared by
+//! measured engagement.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
 
 use rand::seq::IndexedRandom;
 
+use crate::config::ContentConfig;
+use crate::error::StorageError;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::content_frameworks::{self, FrameworkPerformance};
+use crate::storage::DbPool;
+
+/// Storage `kind` for [`ReplyArchetype`] variants.
+pub const KIND_ARCHETYPE: &str = "archetype";
+/// Storage `kind` for [`TweetFormat`] variants.
+pub const KIND_FORMAT: &str = "format";
+/// Storage `kind` for [`ThreadStructure`] variants.
+pub const KIND_STRUCTURE: &str = "structure";
+
+/// Framework kind used for an approval-queue `action_type`: replies use
+/// archetypes, threads use structures, everything else uses tweet formats.
+pub fn kind_for_action(action_type: &str) -> &'static str {
+    match action_type {
+        "reply" => KIND_ARCHETYPE,
+        "thread" => KIND_STRUCTURE,
+        _ => KIND_FORMAT,
+    }
+}
+
+/// The framework a generated text was shaped by. Travels with the text from
+/// the generating adapter to the post (or approval-queue row) that uses it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FrameworkPick {
+    /// `archetype`, `format`, or `structure`.
+    pub kind: &'static str,
+    /// Framework name, e.g. `ask_question` or `list`.
+    pub framework: String,
+}
+
+impl FrameworkPick {
+    /// A reply shaped by `archetype`.
+    pub fn archetype(archetype: ReplyArchetype) -> Self {
+        Self::new(KIND_ARCHETYPE, archetype)
+    }
+
+    /// A tweet shaped by `format`.
+    pub fn format(format: TweetFormat) -> Self {
+        Self::new(KIND_FORMAT, format)
+    }
+
+    /// A thread shaped by `structure`.
+    pub fn structure(structure: ThreadStructure) -> Self {
+        Self::new(KIND_STRUCTURE, structure)
+    }
+
+    fn new(kind: &'static str, framework: impl Display) -> Self {
+        Self {
+            kind,
+            framework: framework.to_string(),
+        }
+    }
+}
+
 // ============================================================================
 // Reply archetypes
 // ============================================================================
@@ -28,6 +93,15 @@ pub enum ReplyArchetype {
 }
 
 impl ReplyArchetype {
+    /// All available archetypes.
+    pub const ALL: &'static [Self] = &[
+        Self::AgreeAndExpand,
+        Self::RespectfulDisagree,
+        Self::AddData,
+        Self::AskQuestion,
+        Self::ShareExperience,
+    ];
+
     /// Weighted selection — prefer archetypes that start conversations.
     pub fn select(rng: &mut impl rand::Rng) -> Self {
         // Weights: AgreeAndExpand 30, AskQuestion 25, ShareExperience 20,
@@ -51,6 +125,15 @@ impl ReplyArchetype {
         Self::AgreeAndExpand
     }
 
+    /// Like [`Self::select`], but drawn from `weights` when any archetype
+    /// is weighted.
+    pub fn select_weighted(weights: &FrameworkWeights, rng: &mut impl rand::Rng) -> Self {
+        match weights.weighted(Self::ALL) {
+            Some(choices) => pick_weighted(&choices, rng),
+            None => Self::select(rng),
+        }
+    }
+
     /// Prompt fragment injected into the system prompt.
     pub fn prompt_fragment(self) -> &'static str {
         match self {
@@ -116,7 +199,7 @@ pub enum TweetFormat {
 
 impl TweetFormat {
     /// All available formats.
-    const ALL: &'static [Self] = &[
+    pub const ALL: &'static [Self] = &[
         Self::List,
         Self::ContrarianTake,
         Self::MostPeopleThinkX,
@@ -141,6 +224,29 @@ impl TweetFormat {
         }
     }
 
+    /// Like [`Self::select`], but drawn from `weights` when any format is
+    /// weighted. Recent formats are still skipped while a weighted
+    /// alternative remains.
+    pub fn select_weighted(
+        recent: &[Self],
+        weights: &FrameworkWeights,
+        rng: &mut impl rand::Rng,
+    ) -> Self {
+        let Some(choices) = weights.weighted(Self::ALL) else {
+            return Self::select(recent, rng);
+        };
+        let fresh: Vec<(Self, f64)> = choices
+            .iter()
+            .copied()
+            .filter(|(f, _)| !recent.contains(f))
+            .collect();
+        if fresh.is_empty() {
+            pick_weighted(&choices, rng)
+        } else {
+            pick_weighted(&fresh, rng)
+        }
+    }
+
     /// Prompt fragment injected into the system prompt.
     pub fn prompt_fragment(self) -> &'static str {
         match self {
@@ -208,7 +314,7 @@ pub enum ThreadStructure {
 
 impl ThreadStructure {
     /// All available structures.
-    const ALL: &'static [Self] = &[
+    pub const ALL: &'static [Self] = &[
         Self::Transformation,
         Self::Framework,
         Self::Mistakes,
@@ -220,6 +326,15 @@ impl ThreadStructure {
         *Self::ALL.choose(rng).expect("ALL is non-empty")
     }
 
+    /// Like [`Self::select`], but drawn from `weights` when any structure
+    /// is weighted.
+    pub fn select_weighted(weights: &FrameworkWeights, rng: &mut impl rand::Rng) -> Self {
+        match weights.weighted(Self::ALL) {
+            Some(choices) => pick_weighted(&choices, rng),
+            None => Self::select(rng),
+        }
+    }
+
     /// Prompt fragment injected into the system prompt.
     pub fn prompt_fragment(self) -> &'static str {
         match self {
@@ -257,9 +372,130 @@ impl std::fmt::Display for ThreadStructure {
     }
 }
 
+// ============================================================================
+// Weights
+// ============================================================================
+
+/// Relative selection weights from `[content.framework_weights]`, keyed by
+/// framework name (`ask_question`, `contrarian_take`, `mistakes`, ...).
+///
+/// Weights apply per kind. A kind with no entries keeps its default
+/// selection; once any archetype (or format, or structure) is listed, only
+/// the listed ones with a positive weight are picked.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FrameworkWeights(BTreeMap<String, f64>);
+
+impl FrameworkWeights {
+    /// Wrap a name → weight map.
+    pub fn new(weights: BTreeMap<String, f64>) -> Self {
+        Self(weights)
+    }
+
+    /// Build weights from `[content]`.
+    pub fn from_config(content: &ContentConfig) -> Self {
+        Self::new(content.framework_weights.clone())
+    }
+
+    /// Every positively weighted variant of `all` with its weight, or `None`
+    /// when this kind has no usable weights.
+    pub fn weighted<T: Copy + Display>(&self, all: &[T]) -> Option<Vec<(T, f64)>> {
+        let choices: Vec<(T, f64)> = all
+            .iter()
+            .filter_map(|v| {
+                let weight = *self.0.get(&v.to_string())?;
+                (weight > 0.0).then_some((*v, weight))
+            })
+            .collect();
+        (!choices.is_empty()).then_some(choices)
+    }
+}
+
+/// Storage kind of the framework named `name`, or `None` if no archetype,
+/// format, or structure has that name.
+pub fn framework_kind(name: &str) -> Option<&'static str> {
+    fn named<T: Display>(all: &[T], name: &str) -> bool {
+        all.iter().any(|v| v.to_string() == name)
+    }
+    if named(ReplyArchetype::ALL, name) {
+        Some(KIND_ARCHETYPE)
+    } else if named(TweetFormat::ALL, name) {
+        Some(KIND_FORMAT)
+    } else if named(ThreadStructure::ALL, name) {
+        Some(KIND_STRUCTURE)
+    } else {
+        None
+    }
+}
+
+fn pick_weighted<T: Copy>(choices: &[(T, f64)], rng: &mut impl rand::Rng) -> T {
+    choices
+        .choose_weighted(rng, |(_, weight)| *weight)
+        .map(|(v, _)| *v)
+        .expect("choices are non-empty with positive weights")
+}
+
+// ============================================================================
+// Performance tracking
+// ============================================================================
+
+/// Link the posted `tweet_id` to `pick` for a specific account. Failures are
+/// logged only.
+///
+/// Items only queued for approval (`queued:<id>`) are skipped: the approval
+/// queue row keeps their framework and the approval poster links them once
+/// they are posted.
+pub async fn link_posted_for(
+    pool: &DbPool,
+    account_id: &str,
+    tweet_id: &str,
+    pick: &FrameworkPick,
+) {
+    if tweet_id.starts_with("queued:") {
+        return;
+    }
+    if let Err(e) = content_frameworks::record_framework_for(
+        pool,
+        account_id,
+        tweet_id,
+        pick.kind,
+        &pick.framework,
+    )
+    .await
+    {
+        tracing::warn!(tweet_id, error = %e, "Failed to record content framework");
+    }
+}
+
+/// Link the posted `tweet_id` to `pick`.
+pub async fn link_posted(pool: &DbPool, tweet_id: &str, pick: &FrameworkPick) {
+    link_posted_for(pool, DEFAULT_ACCOUNT_ID, tweet_id, pick).await
+}
+
+/// Per-framework average engagement and sample counts for a specific account.
+pub async fn performance_report_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Vec<FrameworkPerformance>, StorageError> {
+    content_frameworks::get_framework_performance_for(pool, account_id).await
+}
+
+/// Per-framework average engagement and sample counts.
+pub async fn performance_report(pool: &DbPool) -> Result<Vec<FrameworkPerformance>, StorageError> {
+    performance_report_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn kind_for_action_maps_action_types() {
+        assert_eq!(kind_for_action("reply"), KIND_ARCHETYPE);
+        assert_eq!(kind_for_action("thread"), KIND_STRUCTURE);
+        assert_eq!(kind_for_action("tweet"), KIND_FORMAT);
+    }
 
     #[test]
     fn reply_archetype_select_returns_valid() {
@@ -582,4 +818,117 @@ mod tests {
         let frag = TweetFormat::MostPeopleThinkX.prompt_fragment();
         assert!(frag.contains("Most people"));
     }
+
+    // -----------------------------------------------------------------------
+    // Weighted selection
+    // -----------------------------------------------------------------------
+
+    fn weights(entries: &[(&str, f64)]) -> FrameworkWeights {
+        FrameworkWeights::new(
+            entries
+                .iter()
+                .map(|(name, weight)| (name.to_string(), *weight))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn weighted_archetype_distribution_tracks_weights() {
+        let weights = weights(&[
+            ("add_data", 50.0),
+            ("respectful_disagree", 20.0),
+            ("ask_question", 30.0),
+            ("share_experience", 0.0),
+        ]);
+        let mut rng = StdRng::seed_from_u64(42);
+        let draws = 20_000u32;
+        let mut counts = std::collections::HashMap::new();
+        for _ in 0..draws {
+            *counts
+                .entry(ReplyArchetype::select_weighted(&weights, &mut rng).to_string())
+                .or_insert(0u32) += 1;
+        }
+
+        let share = |a: ReplyArchetype| {
+            f64::from(counts.get(&a.to_string()).copied().unwrap_or(0)) / f64::from(draws)
+        };
+        assert!((share(ReplyArchetype::AddData) - 0.5).abs() < 0.02);
+        assert!((share(ReplyArchetype::RespectfulDisagree) - 0.2).abs() < 0.02);
+        assert!((share(ReplyArchetype::AskQuestion) - 0.3).abs() < 0.02);
+        assert!(
+            !counts.contains_key("share_experience"),
+            "weight 0 excludes"
+        );
+        assert!(
+            !counts.contains_key("agree_and_expand"),
+            "unlisted excludes"
+        );
+    }
+
+    #[test]
+    fn weighted_format_and_structure_only_pick_weighted() {
+        let weights = weights(&[("tip", 3.0), ("list", 1.0), ("mistakes", 1.0)]);
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut tips = 0;
+        for _ in 0..4_000 {
+            let format = TweetFormat::select_weighted(&[], &weights, &mut rng);
+            assert!(matches!(format, TweetFormat::Tip | TweetFormat::List));
+            tips += u32::from(format == TweetFormat::Tip);
+            assert_eq!(
+                ThreadStructure::select_weighted(&weights, &mut rng),
+                ThreadStructure::Mistakes
+            );
+        }
+        assert!((f64::from(tips) / 4_000.0 - 0.75).abs() < 0.03);
+
+        // A recent format is skipped while another weighted one remains.
+        for _ in 0..50 {
+            assert_eq!(
+                TweetFormat::select_weighted(&[TweetFormat::Tip], &weights, &mut rng),
+                TweetFormat::List
+            );
+        }
+    }
+
+    #[test]
+    fn unweighted_kinds_keep_default_selection() {
+        let weights = weights(&[("tip", 1.0)]);
+        assert!(weights.weighted(ReplyArchetype::ALL).is_none());
+        assert!(FrameworkWeights::default()
+            .weighted(TweetFormat::ALL)
+            .is_none());
+
+        let mut rng = StdRng::seed_from_u64(1);
+        let seen: std::collections::HashSet<_> = (0..500)
+            .map(|_| ThreadStructure::select_weighted(&weights, &mut rng).to_string())
+            .collect();
+        assert_eq!(seen.len(), ThreadStructure::ALL.len());
+    }
+
+    #[test]
+    fn framework_kind_resolves_names() {
+        assert_eq!(framework_kind("ask_question"), Some(KIND_ARCHETYPE));
+        assert_eq!(framework_kind("contrarian_take"), Some(KIND_FORMAT));
+        assert_eq!(framework_kind("mistakes"), Some(KIND_STRUCTURE));
+        assert_eq!(framework_kind("educational"), None);
+    }
+
+    #[tokio::test]
+    async fn link_posted_skips_queued_items() {
+        let pool = crate::storage::init_test_db().await.expect("init db");
+        let pick = FrameworkPick::format(TweetFormat::List);
+
+        link_posted(&pool, "t1", &pick).await;
+        link_posted(&pool, "queued:7", &pick).await;
+
+        let linked: Vec<(String, String, String)> =
+            sqlx::query_as("SELECT tweet_id, kind, framework FROM content_frameworks")
+                .fetch_all(&pool)
+                .await
+                .unwrap();
+        assert_eq!(
+            linked,
+            vec![("t1".to_string(), KIND_FORMAT.to_string(), pick.framework)]
+        );
+    }
 }
@@ -5,17 +5,37 @@
 
 pub(crate) mod angles;
 pub(crate) mod parser;
+pub mod rag_guard;
 
 #[cfg(test)]
 mod tests;
 
-use crate::config::BusinessProfile;
-use crate::content::frameworks::{ReplyArchetype, ThreadStructure, TweetFormat};
-use crate::content::length::{truncate_at_sentence, validate_tweet_length, MAX_TWEET_CHARS};
+use std::sync::Mutex;
+
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+
+use crate::config::{BusinessProfile, LlmRetryConfig};
+use crate::content::compliance::{self, ComplianceRules};
+use crate::content::creativity::Temperatures;
+use crate::content::frameworks::{FrameworkWeights, ReplyArchetype, ThreadStructure, TweetFormat};
+use crate::content::length::{smart_truncate, validate_tweet_length, MAX_TWEET_CHARS};
 use crate::error::LlmError;
+use crate::llm::retry::RetryingProvider;
 use crate::llm::{GenerationParams, LlmProvider, TokenUsage};
+use crate::safety::redact::scrub;
 
 use parser::{parse_hooks_response, parse_thread};
+pub use rag_guard::sanitize_rag_context;
+
+/// One earlier tweet in the conversation a reply is being written into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversationTweet {
+    /// Author's username, without the `@`.
+    pub author: String,
+    /// Tweet text.
+    pub text: String,
+}
 
 /// Output from a single-text generation (reply or tweet).
 #[derive(Debug, Clone)]
@@ -69,19 +89,92 @@ pub struct ThreadGenerationOutput {
     pub provider: String,
 }
 
-/// Maximum retries for thread generation.
-const MAX_THREAD_RETRIES: u32 = 2;
-
 /// Content generator that combines an LLM provider with business context.
 pub struct ContentGenerator {
-    provider: Box<dyn LlmProvider>,
+    provider: RetryingProvider,
     business: BusinessProfile,
+    rag_sanitize: bool,
+    /// Hashtag and emoji rules enforced on every generated tweet.
+    compliance: ComplianceRules,
+    /// Sampling temperature per content type (`content.creativity`).
+    temperatures: Temperatures,
+    /// Source of framework and hook-style randomness; seeded for reproducible runs.
+    rng: Mutex<StdRng>,
+    /// Bias for archetype, format, and structure picks (`content.framework_weights`).
+    framework_weights: FrameworkWeights,
+    /// Log the prompt and raw response when a generation fails.
+    dump_prompt_on_error: bool,
 }
 
 impl ContentGenerator {
     /// Create a new content generator.
+    ///
+    /// RAG context sanitization, the default `[llm.retry]` policy, the
+    /// default compliance rules (strip hashtags, cap emoji at 3), and the
+    /// default creativity's temperatures are enabled by default.
     pub fn new(provider: Box<dyn LlmProvider>, business: BusinessProfile) -> Self {
-        Self { provider, business }
+        Self {
+            provider: RetryingProvider::new(provider, LlmRetryConfig::default()),
+            business,
+            rag_sanitize: true,
+            compliance: ComplianceRules::default(),
+            temperatures: Temperatures::default(),
+            rng: Mutex::new(StdRng::from_rng(&mut rand::rng())),
+            framework_weights: FrameworkWeights::default(),
+            dump_prompt_on_error: false,
+        }
+    }
+
+    /// Seed the generator's RNG (`content.random_seed`) so framework picks
+    /// and hook styles repeat across runs.
+    pub fn with_random_seed(mut self, seed: Option<u64>) -> Self {
+        if let Some(seed) = seed {
+            self.rng = Mutex::new(StdRng::seed_from_u64(seed));
+        }
+        self
+    }
+
+    /// Bias framework picks toward `content.framework_weights`.
+    pub fn with_framework_weights(mut self, weights: FrameworkWeights) -> Self {
+        self.framework_weights = weights;
+        self
+    }
+
+    /// Enable or disable prompt-injection guarding of RAG context
+    /// (`content.rag_sanitize`).
+    pub fn with_rag_sanitize(mut self, enabled: bool) -> Self {
+        self.rag_sanitize = enabled;
+        self
+    }
+
+    /// Set the hashtag and emoji rules applied to generated output
+    /// (`content.hashtag_policy`, `content.emoji_policy`, `content.max_emojis`).
+    pub fn with_compliance(mut self, rules: ComplianceRules) -> Self {
+        self.compliance = rules;
+        self
+    }
+
+    /// Set the per-type sampling temperatures (`content.creativity` and the
+    /// `content.*_temperature` overrides).
+    pub fn with_temperatures(mut self, temperatures: Temperatures) -> Self {
+        self.temperatures = temperatures;
+        self
+    }
+
+    /// Set the retry policy for transient LLM failures (`llm.retry`). Its
+    /// `max_attempts` also bounds attempts at a well-formed thread or a
+    /// tweet that fits in 280 characters.
+    pub fn with_retry(mut self, retry: LlmRetryConfig) -> Self {
+        self.provider.set_retry(retry);
+        self
+    }
+
+    /// On `GenerationFailed`, log the full prompt and the last raw model
+    /// response at debug level with secrets redacted
+    /// (`logging.dump_prompt_on_error`).
+    pub fn with_dump_prompt_on_error(mut self, enabled: bool) -> Self {
+        self.dump_prompt_on_error = enabled;
+        self
     }
 
     /// Returns a reference to the business profile.
@@ -89,6 +182,33 @@ impl ContentGenerator {
         &self.business
     }
 
+    /// Returns the framework weights (`content.framework_weights`).
+    pub fn framework_weights(&self) -> &FrameworkWeights {
+        &self.framework_weights
+    }
+
+    /// Pick a reply archetype with the generator's RNG and framework weights.
+    pub fn pick_archetype(&self) -> ReplyArchetype {
+        ReplyArchetype::select_weighted(&self.framework_weights, &mut *self.lock_rng())
+    }
+
+    /// Pick a tweet format not in `recent` with the generator's RNG and
+    /// framework weights.
+    pub fn pick_format(&self, recent: &[TweetFormat]) -> TweetFormat {
+        TweetFormat::select_weighted(recent, &self.framework_weights, &mut *self.lock_rng())
+    }
+
+    /// Pick a thread structure with the generator's RNG and framework weights.
+    pub fn pick_structure(&self) -> ThreadStructure {
+        ThreadStructure::select_weighted(&self.framework_weights, &mut *self.lock_rng())
+    }
+
+    fn lock_rng(&self) -> std::sync::MutexGuard<'_, StdRng> {
+        self.rng
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
     // -----------------------------------------------------------------
     // Reply generation
     // -----------------------------------------------------------------
@@ -100,7 +220,7 @@ impl ContentGenerator {
         tweet_author: &str,
         mention_product: bool,
     ) -> Result<GenerationOutput, LlmError> {
-        self.generate_reply_inner(tweet_text, tweet_author, mention_product, None, None)
+        self.generate_reply_inner(tweet_text, tweet_author, mention_product, None, None, &[])
             .await
     }
 
@@ -112,11 +232,22 @@ impl ContentGenerator {
         mention_product: bool,
         archetype: Option<ReplyArchetype>,
     ) -> Result<GenerationOutput, LlmError> {
-        self.generate_reply_inner(tweet_text, tweet_author, mention_product, archetype, None)
-            .await
+        self.generate_reply_inner(
+            tweet_text,
+            tweet_author,
+            mention_product,
+            archetype,
+            None,
+            &[],
+        )
+        .await
     }
 
     /// Generate a reply with optional RAG context injected into the prompt.
+    ///
+    /// `conversation` holds the tweets the target replies to, oldest first;
+    /// when non-empty they are shown to the model as a delimited block ahead
+    /// of the tweet being answered.
     pub async fn generate_reply_with_context(
         &self,
         tweet_text: &str,
@@ -124,6 +255,7 @@ impl ContentGenerator {
         mention_product: bool,
         archetype: Option<ReplyArchetype>,
         rag_context: Option<&str>,
+        conversation: &[ConversationTweet],
     ) -> Result<GenerationOutput, LlmError> {
         self.generate_reply_inner(
             tweet_text,
@@ -131,11 +263,12 @@ impl ContentGenerator {
             mention_product,
             archetype,
             rag_context,
+            conversation,
         )
         .await
     }
 
-    /// Internal reply generation with optional archetype and RAG context.
+    /// Internal reply generation with optional archetype, RAG and conversation context.
     async fn generate_reply_inner(
         &self,
         tweet_text: &str,
@@ -143,12 +276,14 @@ impl ContentGenerator {
         mention_product: bool,
         archetype: Option<ReplyArchetype>,
         rag_context: Option<&str>,
+        conversation: &[ConversationTweet],
     ) -> Result<GenerationOutput, LlmError> {
         tracing::debug!(
             author = %tweet_author,
             archetype = ?archetype,
             mention_product = mention_product,
             has_rag_context = rag_context.is_some(),
+            conversation_len = conversation.len(),
             "Generating reply",
         );
 
@@ -162,7 +297,8 @@ impl ContentGenerator {
             None => String::new(),
         };
         let persona_section = self.format_persona_context();
-        let rag_section = Self::format_rag_section(rag_context);
+        let rag_section = self.rag_section(rag_context);
+        let conversation_section = Self::format_conversation_section(conversation);
         let audience_section = self.format_audience_section();
 
         let system = if mention_product {
@@ -175,7 +311,8 @@ impl ContentGenerator {
                  {reply_section}\
                  {archetype_section}\
                  {persona_section}\
-                 {rag_section}\n\n\
+                 {rag_section}\
+                 {conversation_section}\n\n\
                  Rules:\n\
                  - Write a reply to the tweet below.\n\
                  - Maximum 3 sentences.\n\
@@ -195,7 +332,8 @@ impl ContentGenerator {
                  {reply_section}\
                  {archetype_section}\
                  {persona_section}\
-                 {rag_section}\n\n\
+                 {rag_section}\
+                 {conversation_section}\n\n\
                  Rules:\n\
                  - Write a reply to the tweet below.\n\
                  - Maximum 3 sentences.\n\
@@ -209,7 +347,7 @@ impl ContentGenerator {
         let user_message = format!("Tweet by @{tweet_author}: {tweet_text}");
         let params = GenerationParams {
             max_tokens: 200,
-            temperature: 0.7,
+            temperature: self.temperatures.reply,
             ..Default::default()
         };
 
@@ -268,7 +406,7 @@ impl ContentGenerator {
             None => String::new(),
         };
         let persona_section = self.format_persona_context();
-        let rag_section = Self::format_rag_section(rag_context);
+        let rag_section = self.rag_section(rag_context);
         let audience_section = self.format_audience_section();
 
         let system = format!(
@@ -292,7 +430,7 @@ impl ContentGenerator {
         let user_message = format!("Write a tweet about: {topic}");
         let params = GenerationParams {
             max_tokens: 150,
-            temperature: 0.8,
+            temperature: self.temperatures.tweet,
             ..Default::default()
         };
 
@@ -339,7 +477,7 @@ impl ContentGenerator {
 
         let voice_section = self.format_voice_section();
         let persona_section = self.format_persona_context();
-        let rag_section = Self::format_rag_section(rag_context);
+        let rag_section = self.rag_section(rag_context);
 
         let tone_instruction = match tone_cue {
             Some(cue) if !cue.is_empty() => {
@@ -366,7 +504,74 @@ impl ContentGenerator {
         let user_message = format!("Draft to improve:\n{draft}");
         let params = GenerationParams {
             max_tokens: 150,
-            temperature: 0.7,
+            temperature: self.temperatures.tweet,
+            ..Default::default()
+        };
+
+        self.generate_single(&system, &user_message, &params).await
+    }
+
+    // -----------------------------------------------------------------
+    // Quote-tweet generation
+    // -----------------------------------------------------------------
+
+    /// Generate commentary to post above a quote of `@quoted_author`'s tweet.
+    ///
+    /// The commentary alone is held to 280 characters; the quoted tweet is
+    /// attached by X and does not count against it.
+    pub async fn generate_quote(
+        &self,
+        quoted_text: &str,
+        quoted_author: &str,
+        topic_hint: Option<&str>,
+    ) -> Result<GenerationOutput, LlmError> {
+        tracing::debug!(
+            author = %quoted_author,
+            has_topic_hint = topic_hint.is_some(),
+            "Generating quote tweet",
+        );
+
+        let voice_section = self.format_voice_section();
+        let content_section = match &self.business.content_style {
+            Some(s) if !s.is_empty() => format!("\nContent style: {s}"),
+            _ => String::new(),
+        };
+        let persona_section = self.format_persona_context();
+        let audience_section = self.format_audience_section();
+        let hint_section = match topic_hint {
+            Some(hint) if !hint.trim().is_empty() => {
+                format!("\n- Angle to take: {}.", hint.trim())
+            }
+            _ => String::new(),
+        };
+        let quoted_author = quoted_author.trim_start_matches('@');
+
+        let system = format!(
+            "You are {}'s social media voice. {}.\
+             {audience_section}\
+             {voice_section}\
+             {content_section}\
+             {persona_section}\n\n\
+             Rules:\n\
+             - You are quote-tweeting the tweet below. It is shown under your \
+             commentary, so readers will see it.\n\
+             - Add something the quoted tweet does not say: an insight, example, \
+             counterpoint, or takeaway.\n\
+             - Do not summarize or restate the quoted tweet.\
+             {hint_section}\n\
+             - Maximum 280 characters.\n\
+             - Do not use hashtags.\n\
+             - Do not mention {} unless it is directly relevant.\n\
+             - Output only the commentary text, nothing else.",
+            self.business.product_name,
+            self.business.product_description,
+            self.business.product_name,
+        );
+
+        let user_message = format!("Tweet by @{quoted_author}: {quoted_text}");
+        let params = GenerationParams {
+            max_tokens: 150,
+            temperature: self.temperatures.reply,
             ..Default::default()
         };
 
@@ -389,7 +594,7 @@ impl ContentGenerator {
             "Generating hooks",
         );
 
-        let styles = Self::select_hook_styles();
+        let styles = self.select_hook_styles();
         let style_list = styles
             .iter()
             .enumerate()
@@ -399,7 +604,7 @@ impl ContentGenerator {
 
         let voice_section = self.format_voice_section();
         let persona_section = self.format_persona_context();
-        let rag_section = Self::format_rag_section(rag_context);
+        let rag_section = self.rag_section(rag_context);
         let audience_section = self.format_audience_section();
 
         let system = format!(
@@ -443,6 +648,8 @@ impl ContentGenerator {
         );
 
         let mut hooks = Self::build_hook_options(&parse_hooks_response(&resp.text));
+        let mut last_msg = user_message.clone();
+        let mut last_raw = resp.text;
 
         // Retry once if fewer than 3 hooks
         if hooks.len() < 3 {
@@ -460,9 +667,12 @@ impl ContentGenerator {
             );
 
             hooks = Self::build_hook_options(&parse_hooks_response(&resp.text));
+            last_msg = retry_msg;
+            last_raw = resp.text;
         }
 
         if hooks.is_empty() {
+            self.dump_failed_prompt("hooks", &system, &last_msg, &last_raw);
             return Err(LlmError::GenerationFailed(
                 "No valid hooks could be generated".to_string(),
             ));
@@ -481,7 +691,7 @@ impl ContentGenerator {
 
     /// Select 5 TweetFormat styles for hook generation.
     /// Always includes Question and ContrarianTake, plus 3 from the rest.
-    fn select_hook_styles() -> Vec<TweetFormat> {
+    fn select_hook_styles(&self) -> Vec<TweetFormat> {
         use rand::seq::SliceRandom;
 
         let mut styles = vec![TweetFormat::Question, TweetFormat::ContrarianTake];
@@ -492,9 +702,8 @@ impl ContentGenerator {
             TweetFormat::BeforeAfter,
             TweetFormat::Tip,
         ];
-        let mut rng = rand::rng();
         let mut pool = remaining.to_vec();
-        pool.shuffle(&mut rng);
+        pool.shuffle(&mut *self.lock_rng());
         styles.extend(pool.into_iter().take(3));
         styles
     }
@@ -534,7 +743,7 @@ impl ContentGenerator {
         selection_context: Option<&str>,
     ) -> Result<crate::content::angles::AngleMiningOutput, LlmError> {
         angles::generate_mined_angles(
-            &*self.provider,
+            &self.provider,
             &self.business,
             topic,
             neighbors,
@@ -597,6 +806,7 @@ impl ContentGenerator {
                 raw_response = %resp.text,
                 "Highlight extraction produced no results after parsing"
             );
+            self.dump_failed_prompt("highlights", &system, &user_message, &resp.text);
             return Err(LlmError::GenerationFailed(
                 "No highlights could be extracted from the provided context".to_string(),
             ));
@@ -676,7 +886,7 @@ impl ContentGenerator {
             None => String::new(),
         };
         let persona_section = self.format_persona_context();
-        let rag_section = Self::format_rag_section(rag_context);
+        let rag_section = self.rag_section(rag_context);
         let audience_section = self.format_audience_section();
 
         let (hook_rule, tweet_count_rule) = match opening_hook {
@@ -715,13 +925,15 @@ impl ContentGenerator {
         let user_message = format!("Write a thread about: {topic}");
         let params = GenerationParams {
             max_tokens: 1500,
-            temperature: 0.7,
+            temperature: self.temperatures.thread,
             ..Default::default()
         };
 
         let mut usage = TokenUsage::default();
         let provider_name = self.provider.name().to_string();
         let mut model = String::new();
+        let mut last_msg = String::new();
+        let mut last_raw = String::new();
 
         // When we have an opening hook, we expect 4-7 generated tweets (prepend hook for 5-8 total).
         let (min_gen, max_gen) = if opening_hook.is_some() {
@@ -730,7 +942,9 @@ impl ContentGenerator {
             (5, 8)
         };
 
-        for attempt in 0..=MAX_THREAD_RETRIES {
+        // `llm.retry.max_attempts` also bounds regenerating a malformed thread.
+        let max_attempts = self.provider.retry().max_attempts.max(1);
+        for attempt in 0..max_attempts {
             let msg = if attempt == 0 {
                 user_message.clone()
             } else {
@@ -743,7 +957,15 @@ impl ContentGenerator {
             let resp = self.provider.complete(&system, &msg, &params).await?;
             usage.accumulate(&resp.usage);
             model.clone_from(&resp.model);
-            let mut tweets = parse_thread(&resp.text);
+            let mut tweets = parse_thread(&resp.text)
+                .iter()
+                .map(|t| self.enforce_compliance(t))
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| {
+                    self.dump_failed_prompt("thread", &system, &msg, &resp.text);
+                    e
+                })?;
+            tweets.retain(|t| !t.is_empty());
 
             // If hook provided, prepend it to form the complete thread.
             if let Some(hook) = opening_hook {
@@ -763,8 +985,12 @@ impl ContentGenerator {
                     provider: provider_name,
                 });
             }
+
+            last_msg = msg;
+            last_raw = resp.text;
         }
 
+        self.dump_failed_prompt("thread", &system, &last_msg, &last_raw);
         Err(LlmError::GenerationFailed(
             "Failed to generate valid thread after retries".to_string(),
         ))
@@ -781,49 +1007,83 @@ impl ContentGenerator {
         user_message: &str,
         params: &GenerationParams,
     ) -> Result<GenerationOutput, LlmError> {
-        let resp = self.provider.complete(system, user_message, params).await?;
-        let mut usage = resp.usage.clone();
         let provider_name = self.provider.name().to_string();
-        let model = resp.model.clone();
-        let text = resp.text.trim().to_string();
+        let mut usage = TokenUsage::default();
+        let mut model = String::new();
+        let mut text = String::new();
+
+        // `llm.retry.max_attempts` also bounds regenerating an over-length tweet.
+        let max_attempts = self.provider.retry().max_attempts.max(1);
+        for attempt in 0..max_attempts {
+            let msg = if attempt == 0 {
+                user_message.to_string()
+            } else {
+                // Retry with stricter instruction
+                format!(
+                    "{user_message}\n\nImportant: Your response MUST be under 280 characters. Be more concise."
+                )
+            };
 
-        tracing::debug!(chars = text.len(), "Generated content");
+            let resp = self.provider.complete(system, &msg, params).await?;
+            usage.accumulate(&resp.usage);
+            model.clone_from(&resp.model);
+            text = self.enforce_compliance(resp.text.trim()).map_err(|e| {
+                self.dump_failed_prompt("single", system, &msg, &resp.text);
+                e
+            })?;
+            if text.is_empty() && !resp.text.trim().is_empty() {
+                self.dump_failed_prompt("single", system, &msg, &resp.text);
+                return Err(LlmError::GenerationFailed(
+                    "generated content was empty after removing hashtags and emoji".to_string(),
+                ));
+            }
 
-        if validate_tweet_length(&text, MAX_TWEET_CHARS) {
-            return Ok(GenerationOutput {
-                text,
-                usage,
-                model,
-                provider: provider_name,
-            });
-        }
+            tracing::debug!(attempt, chars = text.len(), "Generated content");
 
-        // Retry with stricter instruction
-        let retry_msg = format!(
-            "{user_message}\n\nImportant: Your response MUST be under 280 characters. Be more concise."
-        );
-        let resp = self.provider.complete(system, &retry_msg, params).await?;
-        usage.accumulate(&resp.usage);
-        let text = resp.text.trim().to_string();
-
-        if validate_tweet_length(&text, MAX_TWEET_CHARS) {
-            return Ok(GenerationOutput {
-                text,
-                usage,
-                model,
-                provider: provider_name,
-            });
+            if validate_tweet_length(&text, MAX_TWEET_CHARS) {
+                return Ok(GenerationOutput {
+                    text,
+                    usage,
+                    model,
+                    provider: provider_name,
+                });
+            }
         }
 
-        // Last resort: truncate at sentence boundary
+        // Last resort: truncate the body, keeping trailing hashtags/mentions/URLs
         Ok(GenerationOutput {
-            text: truncate_at_sentence(&text, MAX_TWEET_CHARS),
+            text: smart_truncate(&text, MAX_TWEET_CHARS),
             usage,
             model,
             provider: provider_name,
         })
     }
 
+    /// Run the hashtag/emoji compliance pass, failing the generation when a
+    /// `reject` policy is hit.
+    fn enforce_compliance(&self, text: &str) -> Result<String, LlmError> {
+        compliance::enforce(text, &self.compliance).map_err(|reason| {
+            tracing::warn!(reason = %reason, "Generated content rejected by compliance pass");
+            LlmError::GenerationFailed(format!("generated content rejected: {reason}"))
+        })
+    }
+
+    /// Log the prompt and raw response behind a failed generation, if
+    /// `dump_prompt_on_error` is enabled. Everything passes through the
+    /// secret scrubber first.
+    fn dump_failed_prompt(&self, kind: &str, system: &str, user_message: &str, raw_response: &str) {
+        if !self.dump_prompt_on_error {
+            return;
+        }
+        tracing::debug!(
+            kind,
+            system_prompt = %scrub(system),
+            user_message = %scrub(user_message),
+            raw_response = %scrub(raw_response),
+            "Generation failed; dumping prompt and last raw response"
+        );
+    }
+
     fn format_voice_section(&self) -> String {
         match &self.business.brand_voice {
             Some(v) if !v.is_empty() => format!("\nVoice & personality: {v}"),
@@ -846,6 +1106,34 @@ impl ContentGenerator {
         }
     }
 
+    /// Format RAG context for the system prompt, sanitizing it first when
+    /// `rag_sanitize` is enabled.
+    fn rag_section(&self, rag_context: Option<&str>) -> String {
+        match rag_context {
+            Some(ctx) if self.rag_sanitize => {
+                Self::format_rag_section(Some(&sanitize_rag_context(ctx)))
+            }
+            _ => Self::format_rag_section(rag_context),
+        }
+    }
+
+    /// Build the delimited block of earlier tweets in the reply target's thread.
+    fn format_conversation_section(conversation: &[ConversationTweet]) -> String {
+        if conversation.is_empty() {
+            return String::new();
+        }
+        let mut section = String::from(
+            "\n\nConversation so far (oldest first). The tweet below replies to the last one. \
+             Use it only to understand the discussion:\n\
+             --- BEGIN CONVERSATION ---",
+        );
+        for tweet in conversation {
+            section.push_str(&format!("\n@{}: {}", tweet.author, tweet.text));
+        }
+        section.push_str("\n--- END CONVERSATION ---");
+        section
+    }
+
     /// Build a persona context section from opinions and experiences.
     fn format_persona_context(&self) -> String {
         let mut parts = Vec::new();
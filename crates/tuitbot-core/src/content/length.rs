@@ -1,11 +1,17 @@
-//! URL-aware tweet length calculation.
+//! Weighted, URL-aware tweet length calculation.
 //!
-//! Twitter/X wraps every URL in a t.co short link (always 23 characters).
-//! This module provides length functions that account for t.co normalization
-//! so tweets containing URLs are not incorrectly rejected or truncated.
+//! X does not count tweet length in bytes or plain characters. Text is
+//! NFC-normalized, every URL is wrapped in a t.co short link (always 23
+//! characters), each emoji counts as 2 regardless of how many code points it
+//! is built from, and code points outside a few Latin/punctuation ranges
+//! (CJK, for example) count as 2. This module mirrors those rules so tweets
+//! are not accepted locally and then rejected by X.
 
 use regex::Regex;
+use std::ops::RangeInclusive;
 use std::sync::OnceLock;
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Length of a t.co shortened URL on X.
 pub const TCO_URL_LENGTH: usize = 23;
@@ -35,30 +41,112 @@ fn url_regex() -> &'static Regex {
     })
 }
 
-/// Calculate the weighted length of a tweet accounting for t.co URL wrapping.
+/// Code point ranges X counts as 1; everything else counts as 2.
 ///
-/// Every URL (protocol or bare domain) is counted as [`TCO_URL_LENGTH`] (23)
-/// characters regardless of its actual length.
-pub fn tweet_weighted_len(text: &str) -> usize {
-    let re = url_regex();
-    let mut length = text.len();
+/// Taken from the twitter-text v3 configuration: Latin through Georgian,
+/// general punctuation spaces, dashes and quotes, and primes.
+const SINGLE_WEIGHT_RANGES: [RangeInclusive<u32>; 4] = [
+    0x0000..=0x10FF,
+    0x2000..=0x200D,
+    0x2010..=0x201F,
+    0x2032..=0x2037,
+];
+
+/// Weight of a code point outside an emoji sequence.
+fn code_point_weight(c: char) -> usize {
+    let cp = c as u32;
+    if SINGLE_WEIGHT_RANGES.iter().any(|r| r.contains(&cp)) {
+        1
+    } else {
+        2
+    }
+}
 
-    for m in re.find_iter(text) {
-        let url_len = m.as_str().len();
-        // Replace actual URL length with t.co length
-        length = length - url_len + TCO_URL_LENGTH;
+/// Whether a grapheme cluster is rendered as an emoji.
+///
+/// Covers pictographs, flags (regional indicator pairs), keycaps, and text
+/// symbols forced to emoji presentation with VS16.
+pub(crate) fn is_emoji(grapheme: &str) -> bool {
+    grapheme.chars().any(|c| {
+        matches!(
+            c as u32,
+            0x1F000..=0x1FAFF // pictographs, emoticons, flags, symbols
+                | 0x2600..=0x27BF // misc symbols and dingbats
+                | 0x2300..=0x23FF // misc technical (⌚, ⏰, ...)
+                | 0x2B00..=0x2BFF // arrows and stars (⭐, ⬆, ...)
+                | 0xFE0F // emoji presentation selector
+                | 0x20E3 // combining enclosing keycap
+        )
+    })
+}
+
+/// Weighted length of text that contains no URLs.
+fn weighted_text_len(text: &str) -> usize {
+    text.graphemes(true)
+        .map(|g| {
+            if is_emoji(g) {
+                2
+            } else {
+                g.chars().map(code_point_weight).sum()
+            }
+        })
+        .sum()
+}
+
+/// Sum `segment_len` over the non-URL parts of `text`, counting each URL as
+/// [`TCO_URL_LENGTH`].
+fn url_aware_len(text: &str, segment_len: impl Fn(&str) -> usize) -> usize {
+    let mut length = 0;
+    let mut last = 0;
+    for m in url_regex().find_iter(text) {
+        length += segment_len(&text[last..m.start()]) + TCO_URL_LENGTH;
+        last = m.end();
     }
+    length + segment_len(&text[last..])
+}
 
-    length
+/// Calculate the length of a tweet the way X counts it.
+///
+/// The text is NFC-normalized first, so a base letter plus a combining
+/// accent that composes counts once. Then:
+/// - every URL (protocol or bare domain) counts as [`TCO_URL_LENGTH`] (23);
+/// - every emoji counts as 2, including ZWJ sequences, skin-tone variants,
+///   flags, and keycaps;
+/// - other code points count as 1 in the Latin and common punctuation
+///   ranges and 2 elsewhere (CJK, for example).
+pub fn weighted_len(text: &str) -> usize {
+    let normalized: String = text.nfc().collect();
+    url_aware_len(&normalized, weighted_text_len)
 }
 
-/// Check if text is within the tweet character limit, accounting for t.co URLs.
+/// Count the emoji in `text`, treating each emoji sequence as one.
+pub fn emoji_count(text: &str) -> usize {
+    text.graphemes(true).filter(|g| is_emoji(g)).count()
+}
+
+/// Count Unicode code points, with URLs counted as [`TCO_URL_LENGTH`].
+///
+/// No emoji or CJK weighting is applied, so this under-counts relative to X
+/// for non-Latin text. Prefer [`weighted_len`] when checking limits.
+pub fn char_len(text: &str) -> usize {
+    url_aware_len(text, |s| s.chars().count())
+}
+
+/// Calculate the weighted length of a tweet.
+///
+/// Equivalent to [`weighted_len`]; kept for existing callers.
+pub fn tweet_weighted_len(text: &str) -> usize {
+    weighted_len(text)
+}
+
+/// Check if text is within the tweet character limit using X's weighted
+/// counting (see [`weighted_len`]).
 ///
 /// Media attachments (images, GIFs, videos) do **not** affect the character
 /// count — X attaches them via `media_ids` outside the tweet text, so this
 /// function only considers the text content.
 pub fn validate_tweet_length(text: &str, max_chars: usize) -> bool {
-    tweet_weighted_len(text) <= max_chars
+    weighted_len(text) <= max_chars
 }
 
 /// Truncate text at the last sentence boundary that fits within the limit.
@@ -71,12 +159,13 @@ pub fn truncate_at_sentence(text: &str, max_chars: usize) -> String {
         return text.to_string();
     }
 
-    // For truncation we need a byte-level cutoff. If the text has no URLs,
-    // max_chars is exact. With URLs it's conservative but safe — we work
+    // For truncation we need a byte-level cutoff. For ASCII text without URLs
+    // max_chars is exact. Otherwise it's conservative but safe — we work
     // backwards from byte positions and recheck the weighted length.
 
-    // Start from the raw byte limit (may be generous if URLs are present)
-    let byte_limit = text.len().min(max_chars);
+    // Start from the raw byte limit (may be generous if URLs are present),
+    // kept on a char boundary so multi-byte text can be sliced.
+    let byte_limit = floor_char_boundary(text, text.len().min(max_chars));
     let search_area = &text[..byte_limit];
 
     // Find the last sentence-ending punctuation
@@ -96,7 +185,7 @@ pub fn truncate_at_sentence(text: &str, max_chars: usize) -> String {
 
     // No valid sentence boundary; hard truncate with ellipsis.
     // Walk backwards to find a position that fits.
-    let truncate_at = byte_limit.saturating_sub(3);
+    let truncate_at = floor_char_boundary(text, byte_limit.saturating_sub(3));
     let word_end = text[..truncate_at].rfind(' ').unwrap_or(truncate_at);
     let candidate = format!("{}...", &text[..word_end]);
 
@@ -121,6 +210,84 @@ pub fn truncate_at_sentence(text: &str, max_chars: usize) -> String {
     "...".to_string()
 }
 
+/// Truncate text to the limit while keeping its trailing hashtags,
+/// mentions, and URLs.
+///
+/// The run of `#tag`, `@handle`, and URL tokens at the end of `text` is
+/// split off, the body is cut at a sentence boundary to leave room for it,
+/// and the tail is re-appended. Without such a tail, or when the tail
+/// leaves no room for the body, this behaves like [`truncate_at_sentence`].
+pub fn smart_truncate(text: &str, max_chars: usize) -> String {
+    if weighted_len(text) <= max_chars {
+        return text.to_string();
+    }
+
+    let (body, tail) = split_trailing_tags(text);
+    if body.is_empty() || tail.is_empty() {
+        return truncate_at_sentence(text, max_chars);
+    }
+
+    // One extra character for the space that rejoins body and tail.
+    let reserved = weighted_len(tail) + 1;
+    if reserved >= max_chars {
+        return truncate_at_sentence(text, max_chars);
+    }
+
+    let truncated_body = truncate_at_sentence(body, max_chars - reserved);
+    let candidate = format!("{truncated_body} {tail}");
+    if truncated_body == "..." || weighted_len(&candidate) > max_chars {
+        return truncate_at_sentence(text, max_chars);
+    }
+    candidate
+}
+
+/// Split `text` into its body and the run of trailing hashtag, mention,
+/// and URL tokens. The tail is empty when the text ends in plain words.
+fn split_trailing_tags(text: &str) -> (&str, &str) {
+    let trimmed = text.trim_end();
+    let mut tail_start = trimmed.len();
+
+    loop {
+        let before = trimmed[..tail_start].trim_end();
+        let token_start = before
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        let token = &before[token_start..];
+        if token.is_empty() || !is_trailing_tag(token) {
+            break;
+        }
+        tail_start = token_start;
+        if token_start == 0 {
+            break;
+        }
+    }
+
+    (trimmed[..tail_start].trim_end(), &trimmed[tail_start..])
+}
+
+/// Whether a whitespace-free token is a hashtag, mention, or URL.
+fn is_trailing_tag(token: &str) -> bool {
+    if let Some(rest) = token.strip_prefix('#').or_else(|| token.strip_prefix('@')) {
+        return rest
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_alphanumeric() || c == '_');
+    }
+    url_regex()
+        .find(token)
+        .is_some_and(|m| m.start() == 0 && m.end() == token.len())
+}
+
+/// Largest char boundary in `text` at or below `index`.
+fn floor_char_boundary(text: &str, mut index: usize) -> usize {
+    while !text.is_char_boundary(index) {
+        index -= 1;
+    }
+    index
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -252,10 +419,136 @@ mod tests {
         assert!(validate_tweet_length(&text_280, MAX_TWEET_CHARS));
     }
 
+    #[test]
+    fn emoji_count_as_two() {
+        assert_eq!(weighted_len("🚀"), 2);
+        assert_eq!(weighted_len("ship it 🚀🔥"), 12);
+        // ZWJ family, skin tone, flag, and keycap sequences are one emoji each.
+        assert_eq!(weighted_len("👨‍👩‍👧‍👦"), 2);
+        assert_eq!(weighted_len("👍🏽"), 2);
+        assert_eq!(weighted_len("🇯🇵"), 2);
+        assert_eq!(weighted_len("1️⃣"), 2);
+        assert_eq!(weighted_len("❤️"), 2);
+    }
+
+    #[test]
+    fn cjk_counts_as_two() {
+        assert_eq!(weighted_len("日本語"), 6);
+        assert_eq!(weighted_len("한국어"), 6);
+        // 140 CJK characters fill a tweet.
+        let text = "字".repeat(140);
+        assert!(validate_tweet_length(&text, MAX_TWEET_CHARS));
+        let text = "字".repeat(141);
+        assert!(!validate_tweet_length(&text, MAX_TWEET_CHARS));
+        assert_eq!(char_len(&text), 141);
+    }
+
+    #[test]
+    fn latin_accents_and_punctuation_count_as_one() {
+        assert_eq!(weighted_len("café — “quoted”"), 15);
+        assert_eq!(weighted_len("naïve résumé"), 12);
+    }
+
+    #[test]
+    fn combining_characters_are_normalized() {
+        // "e" + COMBINING ACUTE ACCENT composes to "é".
+        assert_eq!(weighted_len("cafe\u{301}"), 4);
+        assert_eq!(char_len("cafe\u{301}"), 5);
+        // No precomposed form: both code points are in the single-weight range.
+        assert_eq!(weighted_len("q\u{307}"), 2);
+    }
+
+    #[test]
+    fn urls_weighted_alongside_emoji_and_cjk() {
+        let text = "新機能 https://example.com/release 🎉";
+        // 3 CJK (6) + space + URL (23) + space + emoji (2)
+        assert_eq!(weighted_len(text), 33);
+        assert_eq!(char_len(text), 3 + 1 + 23 + 1 + 1);
+    }
+
+    #[test]
+    fn char_len_ignores_weighting() {
+        assert_eq!(char_len("🚀 日本"), 4);
+        assert_eq!(char_len("see example.com"), 4 + TCO_URL_LENGTH);
+    }
+
+    #[test]
+    fn validate_rejects_emoji_heavy_text_under_char_count() {
+        // 150 chars but 300 weighted.
+        let text = "🔥".repeat(150);
+        assert_eq!(char_len(&text), 150);
+        assert!(!validate_tweet_length(&text, MAX_TWEET_CHARS));
+    }
+
+    #[test]
+    fn truncate_multibyte_text_fits_weighted_limit() {
+        let text = "日本語のテキスト。".repeat(40);
+        let result = truncate_at_sentence(&text, MAX_TWEET_CHARS);
+        assert!(weighted_len(&result) <= MAX_TWEET_CHARS);
+        assert!(result.ends_with('。') || result.ends_with("..."));
+    }
+
     #[test]
     fn not_a_url_without_known_tld() {
         // ".rs" is not in the TLD allowlist, so this shouldn't be treated as a URL
         let text = "Check out foo.rs for Rust crates";
         assert_eq!(tweet_weighted_len(text), text.len());
     }
+
+    // ── smart_truncate ──────────────────────────────────────────────
+
+    fn long_body() -> String {
+        "Shipping small changes every day compounds faster than big launches. ".repeat(6)
+    }
+
+    #[test]
+    fn smart_truncate_keeps_trailing_hashtags() {
+        let text = format!("{}#buildinpublic #rust", long_body());
+        let result = smart_truncate(&text, MAX_TWEET_CHARS);
+        assert!(weighted_len(&result) <= MAX_TWEET_CHARS);
+        assert!(result.ends_with(". #buildinpublic #rust"), "got: {result}");
+        assert!(result.starts_with("Shipping small changes"));
+    }
+
+    #[test]
+    fn smart_truncate_keeps_trailing_mentions_and_urls() {
+        let text = format!("{}@tuitbot https://example.com/post", long_body());
+        let result = smart_truncate(&text, MAX_TWEET_CHARS);
+        assert!(weighted_len(&result) <= MAX_TWEET_CHARS);
+        assert!(
+            result.ends_with(" @tuitbot https://example.com/post"),
+            "got: {result}"
+        );
+    }
+
+    #[test]
+    fn smart_truncate_without_tags_matches_sentence_truncation() {
+        let text = long_body();
+        assert_eq!(
+            smart_truncate(&text, MAX_TWEET_CHARS),
+            truncate_at_sentence(&text, MAX_TWEET_CHARS)
+        );
+    }
+
+    #[test]
+    fn smart_truncate_falls_back_when_tail_does_not_fit() {
+        let text = format!("{}{}", long_body(), "#tag ".repeat(60));
+        assert_eq!(
+            smart_truncate(&text, MAX_TWEET_CHARS),
+            truncate_at_sentence(&text, MAX_TWEET_CHARS)
+        );
+    }
+
+    #[test]
+    fn smart_truncate_short_text_unchanged() {
+        assert_eq!(smart_truncate("Hello #rust", 280), "Hello #rust");
+    }
+
+    #[test]
+    fn split_trailing_tags_stops_at_plain_word() {
+        let (body, tail) = split_trailing_tags("Use #rust daily. #dev @me ");
+        assert_eq!(body, "Use #rust daily.");
+        assert_eq!(tail, "#dev @me");
+        assert_eq!(split_trailing_tags("No tags here").1, "");
+    }
 }
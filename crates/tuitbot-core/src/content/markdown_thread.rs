@@ -0,0 +1,236 @@
+//! Split a hand-written markdown draft into thread tweets.
+//!
+//! A new tweet starts at every `---` line and at every ATX heading
+//! (`# ...` through `###### ...`); the heading text, without its `#`
+//! markers, becomes the tweet's first line. Lines inside fenced code
+//! blocks never split. No LLM is involved: the text is posted as written.
+
+use super::length::{tweet_weighted_len, MAX_TWEET_CHARS};
+
+/// One tweet cut from a markdown file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownPart {
+    /// Tweet text, trimmed.
+    pub text: String,
+    /// 1-based line where the tweet's text starts.
+    pub line: usize,
+}
+
+/// A tweet that exceeds [`MAX_TWEET_CHARS`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OverLengthPart {
+    /// 1-based position of the tweet in the thread.
+    pub index: usize,
+    /// 1-based line where the tweet's text starts.
+    pub line: usize,
+    /// Weighted length as X counts it.
+    pub length: usize,
+}
+
+/// Why a markdown file cannot be posted as a thread.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum MarkdownThreadError {
+    #[error("markdown contains no tweet text")]
+    Empty,
+    #[error("markdown produced a single tweet; split it with `---` or headings")]
+    SingleTweet,
+    #[error("{}", format_over_length(.0))]
+    TooLong(Vec<OverLengthPart>),
+}
+
+fn format_over_length(parts: &[OverLengthPart]) -> String {
+    let mut msg = format!(
+        "{} tweet(s) exceed {MAX_TWEET_CHARS} characters:",
+        parts.len()
+    );
+    for part in parts {
+        msg.push_str(&format!(
+            "\n  tweet {} (line {}): {} characters",
+            part.index, part.line, part.length
+        ));
+    }
+    msg
+}
+
+/// Split `markdown` into tweets on `---` lines and headings.
+///
+/// Empty sections are dropped. Lengths are not checked; see
+/// [`parse_markdown_thread`].
+pub fn split_markdown_thread(markdown: &str) -> Vec<MarkdownPart> {
+    let mut parts = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    let mut start_line = 0;
+    let mut in_fence = false;
+
+    for (i, raw) in markdown.lines().enumerate() {
+        let line_no = i + 1;
+        let trimmed = raw.trim();
+
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_fence = !in_fence;
+        } else if !in_fence {
+            if trimmed == "---" {
+                flush(&mut parts, &mut current, start_line);
+                continue;
+            }
+            if let Some(heading) = heading_text(trimmed) {
+                flush(&mut parts, &mut current, start_line);
+                start_line = line_no;
+                current.push(heading);
+                continue;
+            }
+        }
+
+        if current.iter().all(|l| l.trim().is_empty()) && !trimmed.is_empty() {
+            current.clear();
+            start_line = line_no;
+        }
+        current.push(raw.trim_end());
+    }
+    flush(&mut parts, &mut current, start_line);
+    parts
+}
+
+/// Split `markdown` into tweets and check every one fits in a tweet.
+///
+/// Fails with every over-length tweet and the line it starts on, so a
+/// draft can be fixed in one pass.
+pub fn parse_markdown_thread(markdown: &str) -> Result<Vec<MarkdownPart>, MarkdownThreadError> {
+    let parts = split_markdown_thread(markdown);
+    match parts.len() {
+        0 => return Err(MarkdownThreadError::Empty),
+        1 => return Err(MarkdownThreadError::SingleTweet),
+        _ => {}
+    }
+
+    let too_long: Vec<OverLengthPart> = parts
+        .iter()
+        .enumerate()
+        .filter_map(|(i, part)| {
+            let length = tweet_weighted_len(&part.text);
+            (length > MAX_TWEET_CHARS).then_some(OverLengthPart {
+                index: i + 1,
+                line: part.line,
+                length,
+            })
+        })
+        .collect();
+    if !too_long.is_empty() {
+        return Err(MarkdownThreadError::TooLong(too_long));
+    }
+
+    Ok(parts)
+}
+
+/// The text of an ATX heading line (`## Title` → `Title`), if it is one.
+fn heading_text(line: &str) -> Option<&str> {
+    let hashes = line.len() - line.trim_start_matches('#').len();
+    if !(1..=6).contains(&hashes) {
+        return None;
+    }
+    let rest = &line[hashes..];
+    if rest.is_empty() {
+        return Some("");
+    }
+    rest.starts_with(' ')
+        .then(|| rest.trim().trim_end_matches('#').trim_end())
+}
+
+fn flush(parts: &mut Vec<MarkdownPart>, current: &mut Vec<&str>, start_line: usize) {
+    let text = current.join("\n").trim().to_string();
+    current.clear();
+    if !text.is_empty() {
+        parts.push(MarkdownPart {
+            text,
+            line: start_line,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DRAFT: &str = "\
+# Why we rewrote the scheduler
+
+It was dropping posts under load.
+
+---
+
+The old loop polled every minute.
+Missed windows piled up.
+
+## What changed
+
+Posts are now claimed with a lease.
+
+```
+--- not a split
+# not a heading
+```
+";
+
+    #[test]
+    fn splits_on_rules_and_headings_with_line_numbers() {
+        let parts = split_markdown_thread(DRAFT);
+        assert_eq!(parts.len(), 3);
+
+        assert_eq!(
+            parts[0].text,
+            "Why we rewrote the scheduler\n\nIt was dropping posts under load."
+        );
+        assert_eq!(parts[0].line, 1);
+
+        assert_eq!(
+            parts[1].text,
+            "The old loop polled every minute.\nMissed windows piled up."
+        );
+        assert_eq!(parts[1].line, 7);
+
+        assert!(parts[2].text.starts_with("What changed\n\nPosts are now"));
+        assert!(parts[2].text.contains("--- not a split\n# not a heading"));
+        assert_eq!(parts[2].line, 10);
+    }
+
+    #[test]
+    fn valid_draft_parses() {
+        let parts = parse_markdown_thread(DRAFT).expect("valid thread");
+        assert_eq!(parts.len(), 3);
+    }
+
+    #[test]
+    fn over_length_section_is_reported_with_its_line() {
+        let long = "word ".repeat(70);
+        let draft = format!("First tweet.\n---\n\n{long}\n---\nThird tweet.\n");
+
+        let err = parse_markdown_thread(&draft).unwrap_err();
+        let MarkdownThreadError::TooLong(parts) = &err else {
+            panic!("expected TooLong, got {err:?}");
+        };
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].index, 2);
+        assert_eq!(parts[0].line, 4);
+        assert!(parts[0].length > MAX_TWEET_CHARS);
+        assert!(err.to_string().contains("tweet 2 (line 4)"));
+    }
+
+    #[test]
+    fn single_or_empty_draft_is_rejected() {
+        assert_eq!(
+            parse_markdown_thread("\n\n---\n"),
+            Err(MarkdownThreadError::Empty)
+        );
+        assert_eq!(
+            parse_markdown_thread("Just one tweet."),
+            Err(MarkdownThreadError::SingleTweet)
+        );
+    }
+
+    #[test]
+    fn hashtags_are_not_headings() {
+        assert_eq!(heading_text("#rustlang is great"), None);
+        assert_eq!(heading_text("### Title ###"), Some("Title"));
+        assert_eq!(heading_text("####### seven"), None);
+    }
+}
@@ -3,22 +3,46 @@
 //! Uses an LLM provider to produce content that matches the user's
 //! business profile and adheres to X's format constraints.
 //! The `frameworks` module provides reply archetypes, tweet formats,
-//! and thread structures that shape LLM prompts for varied output.
+//! and thread structures that shape LLM prompts for varied output;
+//! `rotation` balances their usage when no explicit choice is made, and
+//! `topic_selector` spaces out tweets on the same topic. `quality` runs
+//! LLM-free heuristic checks over drafts, and `compliance` strips or rejects
+//! hashtags and excess emoji in generated output. `markdown_thread` splits a
+//! hand-written markdown draft into thread tweets without the LLM. `creativity`
+//! maps `content.creativity` to per-type sampling temperatures. `scheduling`
+//! suggests preferred posting times from the account's history.
 
 pub mod angles;
+pub mod compliance;
+pub mod creativity;
 pub mod evidence;
 pub mod frameworks;
 pub mod generator;
 pub mod length;
+pub mod markdown_thread;
+pub mod quality;
+pub mod rotation;
+pub mod scheduling;
 pub mod thread;
+pub mod topic_selector;
 
 pub use angles::{AngleMiningOutput, AngleType, EvidenceItem, EvidenceType, MinedAngle};
-pub use frameworks::{ReplyArchetype, ThreadStructure, TweetFormat};
-pub use generator::{ContentGenerator, GenerationOutput, ThreadGenerationOutput};
+pub use compliance::{CompliancePolicy, ComplianceRules};
+pub use creativity::Temperatures;
+pub use frameworks::{
+    FrameworkPick, FrameworkWeights, ReplyArchetype, ThreadStructure, TweetFormat,
+};
+pub use generator::{
+    ContentGenerator, ConversationTweet, GenerationOutput, ThreadGenerationOutput,
+};
 pub use length::{
-    truncate_at_sentence, tweet_weighted_len, validate_tweet_length, MAX_TWEET_CHARS,
-    TCO_URL_LENGTH,
+    char_len, emoji_count, smart_truncate, truncate_at_sentence, tweet_weighted_len,
+    validate_tweet_length, weighted_len, MAX_TWEET_CHARS, TCO_URL_LENGTH,
+};
+pub use markdown_thread::{
+    parse_markdown_thread, split_markdown_thread, MarkdownPart, MarkdownThreadError, OverLengthPart,
 };
+pub use quality::{assess_tweet, CheckStatus, QualityCheck, QualityReport};
 pub use thread::{
     deserialize_blocks_from_content, serialize_blocks_for_storage, validate_thread_blocks,
     ThreadBlock, ThreadBlockError, ThreadBlocksPayload, MAX_MEDIA_PER_BLOCK,
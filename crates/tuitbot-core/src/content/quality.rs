@@ -0,0 +1,254 @@
+//! Heuristic quality checks for original tweet drafts.
+//!
+//! Everything here is rule-based and runs without an LLM, so it is cheap
+//! enough to call on every keystroke. Each check reports pass, warn, or
+//! fail with a short message a writer can act on.
+
+use serde::Serialize;
+
+use super::length::{emoji_count, weighted_len, MAX_TWEET_CHARS};
+use crate::safety::contains_banned_phrase;
+
+/// Tweets shorter than this (weighted) get a length warning.
+pub const MIN_TWEET_CHARS: usize = 40;
+
+/// More hashtags than this is a warning; twice as many is a failure.
+pub const MAX_HASHTAGS: usize = 2;
+
+/// More emoji than this is a warning; twice as many is a failure.
+pub const MAX_EMOJIS: usize = 3;
+
+/// Phrases that invite a reply or other engagement.
+const CALL_TO_ACTION_PHRASES: &[&str] = &[
+    "what do you think",
+    "let me know",
+    "reply",
+    "comment",
+    "share",
+    "retweet",
+    "repost",
+    "follow",
+    "bookmark",
+    "sign up",
+    "subscribe",
+    "join",
+    "dm me",
+    "try it",
+    "thoughts",
+];
+
+/// Outcome of a single check, ordered from best to worst.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+/// One named heuristic check and its result.
+#[derive(Debug, Clone, Serialize)]
+pub struct QualityCheck {
+    /// Stable check identifier (`length`, `banned_phrases`, ...).
+    pub name: &'static str,
+    pub status: CheckStatus,
+    /// Human-readable explanation of the result.
+    pub message: String,
+}
+
+/// Structured quality report for a tweet.
+#[derive(Debug, Clone, Serialize)]
+pub struct QualityReport {
+    /// X-weighted length of the text.
+    pub weighted_len: usize,
+    /// Whether the text fits in [`MAX_TWEET_CHARS`].
+    pub within_limit: bool,
+    /// Worst status across all checks.
+    pub status: CheckStatus,
+    pub checks: Vec<QualityCheck>,
+}
+
+/// Run every heuristic check over a single tweet.
+pub fn assess_tweet(text: &str, banned_phrases: &[String]) -> QualityReport {
+    let len = weighted_len(text);
+    let checks = vec![
+        check_length(len),
+        check_banned_phrases(text, banned_phrases),
+        check_hashtags(text),
+        check_emojis(text),
+        check_call_to_action(text),
+    ];
+    let status = checks
+        .iter()
+        .map(|c| c.status)
+        .max()
+        .unwrap_or(CheckStatus::Pass);
+
+    QualityReport {
+        weighted_len: len,
+        within_limit: len <= MAX_TWEET_CHARS,
+        status,
+        checks,
+    }
+}
+
+fn check(name: &'static str, status: CheckStatus, message: impl Into<String>) -> QualityCheck {
+    QualityCheck {
+        name,
+        status,
+        message: message.into(),
+    }
+}
+
+/// Status for a count with a warning threshold and a failure at twice that.
+fn count_status(count: usize, max: usize) -> CheckStatus {
+    if count > max * 2 {
+        CheckStatus::Fail
+    } else if count > max {
+        CheckStatus::Warn
+    } else {
+        CheckStatus::Pass
+    }
+}
+
+fn check_length(len: usize) -> QualityCheck {
+    if len > MAX_TWEET_CHARS {
+        check(
+            "length",
+            CheckStatus::Fail,
+            format!("{len} characters; X allows {MAX_TWEET_CHARS}"),
+        )
+    } else if len < MIN_TWEET_CHARS {
+        check(
+            "length",
+            CheckStatus::Warn,
+            format!("{len} characters; tweets under {MIN_TWEET_CHARS} rarely land"),
+        )
+    } else {
+        check("length", CheckStatus::Pass, format!("{len} characters"))
+    }
+}
+
+fn check_banned_phrases(text: &str, banned_phrases: &[String]) -> QualityCheck {
+    match contains_banned_phrase(text, banned_phrases) {
+        Some(phrase) => check(
+            "banned_phrases",
+            CheckStatus::Fail,
+            format!("contains banned phrase \"{phrase}\""),
+        ),
+        None => check("banned_phrases", CheckStatus::Pass, "no banned phrases"),
+    }
+}
+
+/// Count `#tag` tokens, ignoring a bare `#` or `#123`.
+fn hashtag_count(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|word| {
+            word.strip_prefix('#')
+                .and_then(|tag| tag.chars().next())
+                .is_some_and(char::is_alphabetic)
+        })
+        .count()
+}
+
+fn check_hashtags(text: &str) -> QualityCheck {
+    let count = hashtag_count(text);
+    let status = count_status(count, MAX_HASHTAGS);
+    let message = match status {
+        CheckStatus::Pass => format!("{count} hashtags"),
+        _ => format!("{count} hashtags; keep it to {MAX_HASHTAGS} or fewer"),
+    };
+    check("hashtags", status, message)
+}
+
+fn check_emojis(text: &str) -> QualityCheck {
+    let count = emoji_count(text);
+    let status = count_status(count, MAX_EMOJIS);
+    let message = match status {
+        CheckStatus::Pass => format!("{count} emoji"),
+        _ => format!("{count} emoji; keep it to {MAX_EMOJIS} or fewer"),
+    };
+    check("emojis", status, message)
+}
+
+fn check_call_to_action(text: &str) -> QualityCheck {
+    let lower = text.to_lowercase();
+    let has_cta = lower.contains('?') || CALL_TO_ACTION_PHRASES.iter().any(|p| lower.contains(p));
+    if has_cta {
+        check("call_to_action", CheckStatus::Pass, "invites a response")
+    } else {
+        check(
+            "call_to_action",
+            CheckStatus::Warn,
+            "no question or call to action; consider inviting replies",
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status_of(report: &QualityReport, name: &str) -> CheckStatus {
+        report
+            .checks
+            .iter()
+            .find(|c| c.name == name)
+            .map(|c| c.status)
+            .expect("check present")
+    }
+
+    #[test]
+    fn clean_tweet_passes_every_check() {
+        let report = assess_tweet(
+            "Shipping small changes daily beats one big launch. What has worked for your team?",
+            &["check out".to_string()],
+        );
+        assert_eq!(report.status, CheckStatus::Pass);
+        assert!(report.within_limit);
+    }
+
+    #[test]
+    fn over_limit_fails_length() {
+        let report = assess_tweet(&"a".repeat(281), &[]);
+        assert_eq!(status_of(&report, "length"), CheckStatus::Fail);
+        assert!(!report.within_limit);
+        assert_eq!(report.status, CheckStatus::Fail);
+    }
+
+    #[test]
+    fn short_tweet_warns_on_length() {
+        let report = assess_tweet("Thoughts?", &[]);
+        assert_eq!(status_of(&report, "length"), CheckStatus::Warn);
+    }
+
+    #[test]
+    fn banned_phrase_fails() {
+        let report = assess_tweet(
+            "You should Check Out this tool for your next project, what do you think?",
+            &["check out".to_string()],
+        );
+        assert_eq!(status_of(&report, "banned_phrases"), CheckStatus::Fail);
+    }
+
+    #[test]
+    fn hashtag_thresholds() {
+        assert_eq!(hashtag_count("#rust #dev issue #1 and a # sign"), 2);
+        let warn = assess_tweet("#a #b #c", &[]);
+        assert_eq!(status_of(&warn, "hashtags"), CheckStatus::Warn);
+        let fail = assess_tweet("#a #b #c #d #e", &[]);
+        assert_eq!(status_of(&fail, "hashtags"), CheckStatus::Fail);
+    }
+
+    #[test]
+    fn emoji_overuse_warns() {
+        let report = assess_tweet("Launch day \u{1F680}\u{1F680}\u{1F389}\u{1F389}", &[]);
+        assert_eq!(status_of(&report, "emojis"), CheckStatus::Warn);
+    }
+
+    #[test]
+    fn missing_call_to_action_warns() {
+        let report = assess_tweet("Shipping small changes daily beats one big launch.", &[]);
+        assert_eq!(status_of(&report, "call_to_action"), CheckStatus::Warn);
+    }
+}
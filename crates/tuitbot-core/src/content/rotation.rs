@@ -0,0 +1,291 @@
+//! Usage-balancing rotation for reply archetypes, tweet formats, and
+//! thread structures.
+//!
+//! When `content.auto_rotate` is enabled, callers that don't request a
+//! specific framework ask the rotator instead. It picks the variant that
+//! was used least recently (never-used variants first, in declaration
+//! order) and records the pick, so consecutive generations cycle through
+//! every variant before repeating.
+//!
+//! When `[content.framework_weights]` weights a kind, the rotator instead
+//! picks the weighted variant furthest below its share of past picks, so
+//! the mix converges on the configured weights without random streaks.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+
+use super::frameworks::{
+    FrameworkWeights, ReplyArchetype, ThreadStructure, TweetFormat, KIND_ARCHETYPE, KIND_FORMAT,
+    KIND_STRUCTURE,
+};
+use crate::error::StorageError;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::{content_rotation, DbPool};
+
+/// Select and record the next reply archetype for a specific account.
+pub async fn next_archetype_for(
+    pool: &DbPool,
+    account_id: &str,
+    weights: &FrameworkWeights,
+) -> Result<ReplyArchetype, StorageError> {
+    next_for(
+        pool,
+        account_id,
+        KIND_ARCHETYPE,
+        ReplyArchetype::ALL,
+        weights,
+    )
+    .await
+}
+
+/// Select and record the next reply archetype.
+pub async fn next_archetype(
+    pool: &DbPool,
+    weights: &FrameworkWeights,
+) -> Result<ReplyArchetype, StorageError> {
+    next_archetype_for(pool, DEFAULT_ACCOUNT_ID, weights).await
+}
+
+/// Select and record the next tweet format for a specific account.
+pub async fn next_format_for(
+    pool: &DbPool,
+    account_id: &str,
+    weights: &FrameworkWeights,
+) -> Result<TweetFormat, StorageError> {
+    next_for(pool, account_id, KIND_FORMAT, TweetFormat::ALL, weights).await
+}
+
+/// Select and record the next tweet format.
+pub async fn next_format(
+    pool: &DbPool,
+    weights: &FrameworkWeights,
+) -> Result<TweetFormat, StorageError> {
+    next_format_for(pool, DEFAULT_ACCOUNT_ID, weights).await
+}
+
+/// Select and record the next thread structure for a specific account.
+pub async fn next_structure_for(
+    pool: &DbPool,
+    account_id: &str,
+    weights: &FrameworkWeights,
+) -> Result<ThreadStructure, StorageError> {
+    next_for(
+        pool,
+        account_id,
+        KIND_STRUCTURE,
+        ThreadStructure::ALL,
+        weights,
+    )
+    .await
+}
+
+/// Select and record the next thread structure.
+pub async fn next_structure(
+    pool: &DbPool,
+    weights: &FrameworkWeights,
+) -> Result<ThreadStructure, StorageError> {
+    next_structure_for(pool, DEFAULT_ACCOUNT_ID, weights).await
+}
+
+async fn next_for<T: Copy + Display>(
+    pool: &DbPool,
+    account_id: &str,
+    kind: &str,
+    all: &[T],
+    weights: &FrameworkWeights,
+) -> Result<T, StorageError> {
+    let history = content_rotation::last_used_for(pool, account_id, kind).await?;
+    let pick = match weights.weighted(all) {
+        Some(choices) => {
+            let counts = content_rotation::use_counts_for(pool, account_id, kind).await?;
+            most_underused(&choices, &counts, &history)
+        }
+        None => least_recently_used(all, &history),
+    };
+    content_rotation::record_use_for(pool, account_id, kind, &pick.to_string()).await?;
+    Ok(pick)
+}
+
+/// Pick the variant furthest below its weighted share: the lowest
+/// `(uses + 1) / weight`, with ties going to the least recently used.
+fn most_underused<T: Copy + Display>(
+    choices: &[(T, f64)],
+    counts: &HashMap<String, i64>,
+    history: &HashMap<String, i64>,
+) -> T {
+    let key = |v: &T, weight: f64| {
+        let name = v.to_string();
+        let uses = counts.get(&name).copied().unwrap_or(0);
+        let last = history.get(&name).copied().unwrap_or(i64::MIN);
+        ((uses + 1) as f64 / weight, last)
+    };
+    choices
+        .iter()
+        .map(|(v, weight)| (*v, key(v, *weight)))
+        .min_by(|(_, (a, a_last)), (_, (b, b_last))| a.total_cmp(b).then(a_last.cmp(b_last)))
+        .map(|(v, _)| v)
+        .expect("choices is non-empty")
+}
+
+/// Pick the first never-used variant, otherwise the one whose latest use is oldest.
+fn least_recently_used<T: Copy + Display>(all: &[T], history: &HashMap<String, i64>) -> T {
+    *all.iter()
+        .min_by_key(|v| history.get(&v.to_string()).copied().unwrap_or(i64::MIN))
+        .expect("variant list is non-empty")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn fresh_db_starts_in_declaration_order() {
+        let pool = init_test_db().await.expect("init db");
+        let none = FrameworkWeights::default();
+        assert_eq!(
+            next_archetype(&pool, &none).await.unwrap(),
+            ReplyArchetype::ALL[0]
+        );
+        assert_eq!(
+            next_format(&pool, &none).await.unwrap(),
+            TweetFormat::ALL[0]
+        );
+        assert_eq!(
+            next_structure(&pool, &none).await.unwrap(),
+            ThreadStructure::ALL[0]
+        );
+    }
+
+    #[tokio::test]
+    async fn archetypes_cycle_through_all_before_repeating() {
+        let pool = init_test_db().await.expect("init db");
+        let none = FrameworkWeights::default();
+        let n = ReplyArchetype::ALL.len();
+
+        let mut picks = Vec::new();
+        for _ in 0..n * 2 {
+            picks.push(next_archetype(&pool, &none).await.unwrap());
+        }
+
+        for variant in ReplyArchetype::ALL {
+            assert!(
+                picks[..n].contains(variant),
+                "{variant} missing from first cycle"
+            );
+        }
+        assert_eq!(picks[..n], picks[n..]);
+    }
+
+    #[tokio::test]
+    async fn formats_and_structures_cycle_through_all() {
+        let pool = init_test_db().await.expect("init db");
+        let none = FrameworkWeights::default();
+
+        let mut formats = Vec::new();
+        for _ in 0..TweetFormat::ALL.len() {
+            formats.push(next_format(&pool, &none).await.unwrap());
+        }
+        assert_eq!(formats, TweetFormat::ALL);
+
+        let mut structures = Vec::new();
+        for _ in 0..ThreadStructure::ALL.len() {
+            structures.push(next_structure(&pool, &none).await.unwrap());
+        }
+        assert_eq!(structures, ThreadStructure::ALL);
+    }
+
+    #[tokio::test]
+    async fn history_is_tracked_per_account() {
+        let pool = init_test_db().await.expect("init db");
+        let none = FrameworkWeights::default();
+        next_archetype_for(&pool, "acct-a", &none).await.unwrap();
+        assert_eq!(
+            next_archetype_for(&pool, "acct-b", &none).await.unwrap(),
+            ReplyArchetype::ALL[0]
+        );
+        assert_eq!(
+            next_archetype_for(&pool, "acct-a", &none).await.unwrap(),
+            ReplyArchetype::ALL[1]
+        );
+    }
+
+    #[tokio::test]
+    async fn weighted_rotation_follows_weights_and_skips_zero() {
+        let pool = init_test_db().await.expect("init db");
+        let weights = FrameworkWeights::new(
+            [
+                ("list".to_string(), 3.0),
+                ("tip".to_string(), 1.0),
+                ("question".to_string(), 0.0),
+            ]
+            .into(),
+        );
+
+        let mut picks = Vec::new();
+        for _ in 0..8 {
+            picks.push(next_format(&pool, &weights).await.unwrap());
+        }
+        assert_eq!(
+            picks,
+            [
+                TweetFormat::List,
+                TweetFormat::List,
+                TweetFormat::Tip,
+                TweetFormat::List,
+                TweetFormat::List,
+                TweetFormat::List,
+                TweetFormat::Tip,
+                TweetFormat::List,
+            ]
+        );
+
+        // Unweighted kinds still rotate through every variant.
+        assert_eq!(
+            next_archetype(&pool, &weights).await.unwrap(),
+            ReplyArchetype::ALL[0]
+        );
+    }
+
+    #[test]
+    fn most_underused_converges_on_weights() {
+        let choices = [
+            (ThreadStructure::Framework, 50.0),
+            (ThreadStructure::Mistakes, 20.0),
+            (ThreadStructure::Analysis, 30.0),
+        ];
+        let mut counts = HashMap::new();
+        let mut history = HashMap::new();
+        for seq in 0..1_000 {
+            let pick = most_underused(&choices, &counts, &history).to_string();
+            *counts.entry(pick.clone()).or_insert(0) += 1;
+            history.insert(pick, seq);
+        }
+
+        for (structure, weight) in choices {
+            let uses = counts[&structure.to_string()];
+            assert!(
+                (uses as f64 - weight * 10.0).abs() <= 1.0,
+                "{structure}: {uses} uses for weight {weight}"
+            );
+        }
+    }
+
+    #[test]
+    fn least_recently_used_prefers_oldest_pick() {
+        let history = HashMap::from([
+            ("list".to_string(), 5),
+            ("contrarian_take".to_string(), 2),
+            ("most_people_think_x".to_string(), 7),
+        ]);
+        let pick = least_recently_used(
+            &[
+                TweetFormat::List,
+                TweetFormat::ContrarianTake,
+                TweetFormat::MostPeopleThinkX,
+            ],
+            &history,
+        );
+        assert_eq!(pick, TweetFormat::ContrarianTake);
+    }
+}
@@ -0,0 +1,150 @@
+//! Suggested `schedule.preferred_times` from the account's posting history.
+//!
+//! Per-weekday best hours come from
+//! [`get_optimal_times_by_weekday_for`](crate::storage::analytics::get_optimal_times_by_weekday_for);
+//! this module turns them into base times plus per-day overrides. With too
+//! little history the research-backed [`AUTO_PREFERRED_TIMES`] are suggested
+//! instead.
+
+use std::collections::BTreeMap;
+
+use chrono_tz::Tz;
+use serde::Serialize;
+
+use crate::automation::schedule::AUTO_PREFERRED_TIMES;
+use crate::error::StorageError;
+use crate::storage::analytics::{get_optimal_times_by_weekday_for, WeekdayOptimalHours};
+use crate::storage::DbPool;
+
+/// Hours suggested per weekday.
+pub const OPTIMAL_HOURS_PER_DAY: usize = 3;
+
+/// Below this many sent posts, history is too sparse to trust.
+const MIN_HISTORY_POSTS: i64 = 10;
+
+/// Suggested schedule settings, expressed in the schedule timezone.
+#[derive(Debug, Clone, Serialize)]
+pub struct PreferredTimesSuggestion {
+    /// `"history"` when computed from the account's posts, `"default"` otherwise.
+    pub source: &'static str,
+    /// Suggested `schedule.preferred_times` (HH:MM, local time).
+    pub preferred_times: Vec<String>,
+    /// Suggested `schedule.preferred_times_override`, keyed by day (Mon-Sun).
+    pub preferred_times_override: BTreeMap<String, Vec<String>>,
+}
+
+/// Suggest preferred posting times for a specific account.
+///
+/// Hours with fewer than `min_sample` posts are ignored.
+pub async fn suggest_preferred_times_for(
+    pool: &DbPool,
+    account_id: &str,
+    tz: Tz,
+    min_sample: u32,
+) -> Result<PreferredTimesSuggestion, StorageError> {
+    let by_day =
+        get_optimal_times_by_weekday_for(pool, account_id, tz, OPTIMAL_HOURS_PER_DAY, min_sample)
+            .await?;
+    Ok(suggest_preferred_times(&by_day))
+}
+
+/// Turn per-weekday history into `preferred_times` suggestions, falling back
+/// to the defaults when there is too little history.
+pub fn suggest_preferred_times(by_day: &[WeekdayOptimalHours]) -> PreferredTimesSuggestion {
+    let total_posts: i64 = by_day.iter().map(|d| d.post_count).sum();
+    if total_posts < MIN_HISTORY_POSTS {
+        return PreferredTimesSuggestion {
+            source: "default",
+            preferred_times: AUTO_PREFERRED_TIMES.iter().map(|t| t.to_string()).collect(),
+            preferred_times_override: BTreeMap::new(),
+        };
+    }
+
+    let overrides = by_day
+        .iter()
+        .map(|d| {
+            let hours = d.hours.iter().map(|h| h.hour).collect();
+            (d.weekday.clone(), format_hours(hours))
+        })
+        .collect();
+
+    // Base times: best hours across all days, weighted by post count.
+    let mut totals: BTreeMap<i64, (f64, i64)> = BTreeMap::new();
+    for h in by_day.iter().flat_map(|d| &d.hours) {
+        let entry = totals.entry(h.hour).or_default();
+        entry.0 += h.avg_engagement * h.post_count as f64;
+        entry.1 += h.post_count;
+    }
+    let mut ranked: Vec<(i64, f64)> = totals
+        .into_iter()
+        .map(|(hour, (sum, count))| (hour, sum / count as f64))
+        .collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+    let base = ranked
+        .into_iter()
+        .take(OPTIMAL_HOURS_PER_DAY)
+        .map(|(hour, _)| hour)
+        .collect();
+
+    PreferredTimesSuggestion {
+        source: "history",
+        preferred_times: format_hours(base),
+        preferred_times_override: overrides,
+    }
+}
+
+fn format_hours(mut hours: Vec<i64>) -> Vec<String> {
+    hours.sort_unstable();
+    hours.iter().map(|h| format!("{h:02}:00")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::analytics::HourlyPerformance;
+
+    fn hour(hour: i64, avg_engagement: f64, post_count: i64) -> HourlyPerformance {
+        HourlyPerformance {
+            hour,
+            avg_engagement,
+            post_count,
+            low_confidence: false,
+        }
+    }
+
+    fn day(weekday: &str, hours: Vec<HourlyPerformance>) -> WeekdayOptimalHours {
+        let post_count = hours.iter().map(|h| h.post_count).sum();
+        WeekdayOptimalHours {
+            weekday: weekday.to_string(),
+            hours,
+            post_count,
+        }
+    }
+
+    #[test]
+    fn sparse_history_falls_back_to_defaults() {
+        let suggestion = suggest_preferred_times(&[day("Mon", vec![hour(9, 50.0, 3)])]);
+        assert_eq!(suggestion.source, "default");
+        assert_eq!(suggestion.preferred_times, AUTO_PREFERRED_TIMES);
+        assert!(suggestion.preferred_times_override.is_empty());
+    }
+
+    #[test]
+    fn history_ranks_hours_weighted_by_post_count() {
+        let by_day = vec![
+            day("Mon", vec![hour(9, 80.0, 4), hour(14, 40.0, 2)]),
+            day(
+                "Tue",
+                vec![hour(9, 20.0, 4), hour(18, 70.0, 3), hour(7, 10.0, 2)],
+            ),
+        ];
+        let suggestion = suggest_preferred_times(&by_day);
+        assert_eq!(suggestion.source, "history");
+        // 09 averages 50 over 8 posts, 18 has 70, 14 has 40, 07 has 10.
+        assert_eq!(suggestion.preferred_times, ["09:00", "14:00", "18:00"]);
+        assert_eq!(
+            suggestion.preferred_times_override["Tue"],
+            ["07:00", "09:00", "18:00"]
+        );
+    }
+}
@@ -0,0 +1,174 @@
+//! Topic selection with a minimum gap between tweets on the same topic.
+//!
+//! The topic of every posted original tweet is stored in `original_tweets`.
+//! With `content.min_topic_gap_hours` set, the content loop asks this
+//! module for its next topic: topics used within the gap are skipped and
+//! the least-recently-used eligible topic wins (never-used topics first,
+//! in configured order). If every topic is inside the gap, the oldest is
+//! picked anyway so the loop never stalls.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+
+use crate::error::StorageError;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::{threads, DbPool};
+
+/// Select the next topic for a specific account, or `None` when `topics` is empty.
+pub async fn next_topic_for(
+    pool: &DbPool,
+    account_id: &str,
+    topics: &[String],
+    gap: Duration,
+) -> Result<Option<String>, StorageError> {
+    let last_used: HashMap<String, DateTime<Utc>> =
+        threads::get_topic_last_used_for(pool, account_id)
+            .await?
+            .into_iter()
+            .filter_map(|(topic, at)| parse_timestamp(&at).map(|at| (topic, at)))
+            .collect();
+    Ok(select_topic(topics, &last_used, gap, Utc::now()))
+}
+
+/// Select the next topic, or `None` when `topics` is empty.
+pub async fn next_topic(
+    pool: &DbPool,
+    topics: &[String],
+    gap: Duration,
+) -> Result<Option<String>, StorageError> {
+    next_topic_for(pool, DEFAULT_ACCOUNT_ID, topics, gap).await
+}
+
+/// Pick the least-recently-used topic last used more than `gap` before `now`.
+///
+/// Falls back to the least-recently-used topic overall when none is eligible.
+pub fn select_topic(
+    topics: &[String],
+    last_used: &HashMap<String, DateTime<Utc>>,
+    gap: Duration,
+    now: DateTime<Utc>,
+) -> Option<String> {
+    let oldest = topics.iter().min_by_key(|t| {
+        last_used
+            .get(*t)
+            .copied()
+            .unwrap_or(DateTime::<Utc>::MIN_UTC)
+    })?;
+
+    if let Some(&used_at) = last_used.get(oldest) {
+        if now - used_at < gap {
+            tracing::info!(
+                topic = %oldest,
+                last_used = %used_at,
+                min_gap_hours = gap.num_hours(),
+                "Every topic was used within the minimum gap; picking the oldest anyway"
+            );
+        }
+    }
+    Some(oldest.clone())
+}
+
+fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .map(|n| n.and_utc())
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+    use crate::storage::threads::{insert_original_tweet, OriginalTweet};
+
+    fn topics(names: &[&str]) -> Vec<String> {
+        names.iter().map(|s| s.to_string()).collect()
+    }
+
+    fn hours_ago(now: DateTime<Utc>, h: i64) -> DateTime<Utc> {
+        now - Duration::hours(h)
+    }
+
+    #[test]
+    fn never_used_topics_come_first_in_order() {
+        let now = Utc::now();
+        let used = HashMap::from([("rust".to_string(), hours_ago(now, 100))]);
+        let pick = select_topic(
+            &topics(&["rust", "go", "zig"]),
+            &used,
+            Duration::hours(6),
+            now,
+        );
+        assert_eq!(pick.as_deref(), Some("go"));
+    }
+
+    #[test]
+    fn topics_inside_gap_are_skipped() {
+        let now = Utc::now();
+        let used = HashMap::from([
+            ("rust".to_string(), hours_ago(now, 1)),
+            ("go".to_string(), hours_ago(now, 30)),
+            ("zig".to_string(), hours_ago(now, 10)),
+        ]);
+        let pick = select_topic(
+            &topics(&["rust", "go", "zig"]),
+            &used,
+            Duration::hours(6),
+            now,
+        );
+        assert_eq!(pick.as_deref(), Some("go"));
+    }
+
+    #[test]
+    fn all_inside_gap_picks_oldest_anyway() {
+        let now = Utc::now();
+        let used = HashMap::from([
+            ("rust".to_string(), hours_ago(now, 1)),
+            ("go".to_string(), hours_ago(now, 3)),
+        ]);
+        let pick = select_topic(&topics(&["rust", "go"]), &used, Duration::hours(24), now);
+        assert_eq!(pick.as_deref(), Some("go"));
+    }
+
+    #[test]
+    fn empty_topics_returns_none() {
+        assert!(select_topic(&[], &HashMap::new(), Duration::hours(1), Utc::now()).is_none());
+    }
+
+    #[tokio::test]
+    async fn next_topic_uses_seeded_history() {
+        let pool = init_test_db().await.expect("init db");
+        let now = Utc::now();
+        for (topic, h) in [("rust", 2), ("go", 48), ("rust", 72), ("zig", 5)] {
+            let tweet = OriginalTweet {
+                id: 0,
+                tweet_id: None,
+                content: format!("about {topic}"),
+                topic: Some(topic.to_string()),
+                llm_provider: None,
+                created_at: hours_ago(now, h).to_rfc3339(),
+                status: "sent".to_string(),
+                error_message: None,
+            };
+            insert_original_tweet(&pool, &tweet).await.expect("seed");
+        }
+
+        let all = topics(&["rust", "go", "zig"]);
+        let pick = next_topic(&pool, &all, Duration::hours(12)).await.unwrap();
+        assert_eq!(pick.as_deref(), Some("go"));
+
+        // Everything is inside a 3-day gap: the oldest ("go") still wins.
+        let pick = next_topic(&pool, &all, Duration::hours(72)).await.unwrap();
+        assert_eq!(pick.as_deref(), Some("go"));
+
+        let pick = next_topic(&pool, &topics(&["rust", "zig"]), Duration::hours(1))
+            .await
+            .unwrap();
+        assert_eq!(pick.as_deref(), Some("zig"));
+    }
+}
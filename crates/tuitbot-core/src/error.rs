@@ -84,6 +84,14 @@ pub enum XApiError {
         source: reqwest::Error,
     },
 
+    /// Request to X API exceeded the configured `[x_api.http]` timeout.
+    #[error("X API request timed out: {source}")]
+    Timeout {
+        /// The underlying HTTP client error.
+        #[source]
+        source: reqwest::Error,
+    },
+
     /// Any other X API error response.
     #[error("X API error (HTTP {status}): {message}")]
     ApiError {
@@ -130,6 +138,15 @@ pub enum XApiError {
 }
 
 impl XApiError {
+    /// Classify a transport-level reqwest failure as `Timeout` or `Network`.
+    pub fn from_transport(source: reqwest::Error) -> Self {
+        if source.is_timeout() {
+            XApiError::Timeout { source }
+        } else {
+            XApiError::Network { source }
+        }
+    }
+
     /// Returns `true` for transient errors where a retry may succeed.
     ///
     /// Non-retryable: auth failures (401/403), scope issues, permanent
@@ -140,6 +157,7 @@ impl XApiError {
             // Transient: retry after a delay.
             XApiError::RateLimited { .. } => true,
             XApiError::Network { .. } => true,
+            XApiError::Timeout { .. } => true,
             XApiError::ScraperTransportUnavailable { .. } => true,
             // Retryable server errors (5xx); non-retryable client errors (4xx).
             XApiError::ApiError { status, .. } => *status >= 500,
@@ -161,7 +179,11 @@ impl XApiError {
 pub enum LlmError {
     /// HTTP request to the LLM endpoint failed.
     #[error("LLM HTTP request failed: {0}")]
-    Request(#[from] reqwest::Error),
+    Request(reqwest::Error),
+
+    /// Request to the LLM endpoint exceeded the configured `[llm.http]` timeout.
+    #[error("LLM request timed out: {0}")]
+    Timeout(reqwest::Error),
 
     /// LLM API returned an error response.
     #[error("LLM API error (status {status}): {message}")]
@@ -190,6 +212,60 @@ pub enum LlmError {
     /// Content generation failed after retries.
     #[error("content generation failed: {0}")]
     GenerationFailed(String),
+
+    /// The provider is reachable but does not offer the configured model.
+    #[error("model '{model}' is not available from this provider")]
+    ModelUnavailable {
+        /// The configured model name.
+        model: String,
+    },
+
+    /// `llm.session_token_budget` is used up; no further generations are made.
+    #[error(
+        "LLM session token budget exhausted ({used} of {budget} tokens used); \
+         raise llm.session_token_budget or restart to continue"
+    )]
+    BudgetExhausted {
+        /// Tokens used so far this session.
+        used: u64,
+        /// The configured budget.
+        budget: u64,
+    },
+}
+
+impl From<reqwest::Error> for LlmError {
+    fn from(e: reqwest::Error) -> Self {
+        if e.is_timeout() {
+            LlmError::Timeout(e)
+        } else {
+            LlmError::Request(e)
+        }
+    }
+}
+
+impl LlmError {
+    /// Returns `true` for transient errors where retrying the same request
+    /// may succeed.
+    ///
+    /// Transient: timeouts and connection failures, server errors (5xx),
+    /// request timeouts (408), and rate limits. Not transient: other client
+    /// errors such as invalid keys or content-policy rejections (4xx),
+    /// unparseable responses, and missing configuration.
+    pub fn is_transient(&self) -> bool {
+        match self {
+            LlmError::Request(e) => {
+                e.is_timeout() || e.is_connect() || e.status().is_some_and(|s| s.is_server_error())
+            }
+            LlmError::Api { status, .. } => *status >= 500 || *status == 408 || *status == 429,
+            LlmError::Timeout(_) => true,
+            LlmError::RateLimited { .. } => true,
+            LlmError::Parse(_) => false,
+            LlmError::NotConfigured => false,
+            LlmError::GenerationFailed(_) => false,
+            LlmError::ModelUnavailable { .. } => false,
+            LlmError::BudgetExhausted { .. } => false,
+        }
+    }
 }
 
 /// Errors from SQLite storage operations.
@@ -219,6 +295,18 @@ pub enum StorageError {
         source: sqlx::Error,
     },
 
+    /// The database was migrated by a newer binary than this one.
+    #[error(
+        "database schema version {db_version} is newer than this binary supports \
+         ({binary_version}); upgrade tuitbot or restore a backup taken before the upgrade"
+    )]
+    SchemaTooNew {
+        /// Highest migration version recorded in the database.
+        db_version: i64,
+        /// Highest migration version shipped with this binary.
+        binary_version: i64,
+    },
+
     /// An approval item has already been reviewed and cannot be re-reviewed.
     #[error("item {id} has already been reviewed (current status: {current_status})")]
     AlreadyReviewed {
@@ -335,6 +423,28 @@ mod tests {
         assert_eq!(err.to_string(), "LLM rate limited, retry after 30 seconds");
     }
 
+    #[test]
+    fn llm_error_is_transient_classification() {
+        let api = |status| LlmError::Api {
+            status,
+            message: String::new(),
+        };
+        assert!(api(500).is_transient());
+        assert!(api(503).is_transient());
+        assert!(api(429).is_transient());
+        assert!(api(408).is_transient());
+        assert!(LlmError::RateLimited {
+            retry_after_secs: 5
+        }
+        .is_transient());
+
+        assert!(!api(400).is_transient());
+        assert!(!api(401).is_transient());
+        assert!(!LlmError::Parse("bad json".to_string()).is_transient());
+        assert!(!LlmError::NotConfigured.is_transient());
+        assert!(!LlmError::GenerationFailed("too long".to_string()).is_transient());
+    }
+
     #[test]
     fn llm_error_parse_failure_message() {
         let err = LlmError::Parse("unexpected JSON structure".to_string());
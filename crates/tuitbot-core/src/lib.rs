@@ -12,6 +12,7 @@ pub mod llm;
 pub mod mcp_policy;
 pub mod mutation_gateway;
 pub mod net;
+pub mod route_manifest;
 pub mod safety;
 pub mod scheduling;
 pub mod scoring;
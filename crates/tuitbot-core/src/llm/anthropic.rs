@@ -3,7 +3,10 @@
 //! Uses the Anthropic Messages API which has a distinct request format,
 //! authentication mechanism, and response structure from OpenAI-compatible endpoints.
 
+use std::collections::BTreeMap;
+
 use super::{GenerationParams, LlmProvider, LlmResponse, TokenUsage};
+use crate::config::HttpTimeoutConfig;
 use crate::error::LlmError;
 use serde::{Deserialize, Serialize};
 
@@ -19,28 +22,43 @@ pub struct AnthropicProvider {
     base_url: String,
     api_key: String,
     model: String,
+    headers: reqwest::header::HeaderMap,
 }
 
 impl AnthropicProvider {
     /// Create a new Anthropic provider with the default base URL.
     pub fn new(api_key: String, model: String) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: crate::net::http_client(&HttpTimeoutConfig::default()),
             base_url: ANTHROPIC_BASE_URL.to_string(),
             api_key,
             model,
+            headers: reqwest::header::HeaderMap::new(),
         }
     }
 
     /// Create a new Anthropic provider with a custom base URL (for testing).
     pub fn with_base_url(api_key: String, model: String, base_url: String) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: crate::net::http_client(&HttpTimeoutConfig::default()),
             base_url,
             api_key,
             model,
+            headers: reqwest::header::HeaderMap::new(),
         }
     }
+
+    /// Apply connect and request timeouts from `[llm.http]`.
+    pub fn with_timeouts(mut self, timeouts: &HttpTimeoutConfig) -> Self {
+        self.client = crate::net::http_client(timeouts);
+        self
+    }
+
+    /// Send these extra headers with every request (e.g. proxy auth).
+    pub fn with_headers(mut self, headers: &BTreeMap<String, String>) -> Self {
+        self.headers = crate::net::header_map(headers);
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -85,35 +103,13 @@ impl LlmProvider for AnthropicProvider {
             .header("x-api-key", &self.api_key)
             .header("anthropic-version", ANTHROPIC_VERSION)
             .header("content-type", "application/json")
+            .headers(self.headers.clone())
             .json(&request)
             .send()
             .await?;
 
         if !response.status().is_success() {
-            let status = response.status().as_u16();
-
-            if status == 429 || status == 529 {
-                let retry_after = if status == 529 {
-                    30 // Anthropic "overloaded" default
-                } else {
-                    response
-                        .headers()
-                        .get("retry-after")
-                        .and_then(|v| v.to_str().ok())
-                        .and_then(|v| v.parse::<u64>().ok())
-                        .unwrap_or(60)
-                };
-                return Err(LlmError::RateLimited {
-                    retry_after_secs: retry_after,
-                });
-            }
-
-            let body = response.text().await.unwrap_or_default();
-            let message = serde_json::from_str::<AnthropicErrorResponse>(&body)
-                .map(|e| e.error.message)
-                .unwrap_or(body);
-
-            return Err(LlmError::Api { status, message });
+            return Err(api_error(response).await);
         }
 
         let body: AnthropicResponse = response
@@ -148,20 +144,58 @@ impl LlmProvider for AnthropicProvider {
         })
     }
 
+    /// Retrieve the configured model from the Models API. Costs no tokens
+    /// and fails with `ModelUnavailable` when the model (or alias) is unknown.
     async fn health_check(&self) -> Result<(), LlmError> {
-        self.complete(
-            "You are a test assistant.",
-            "Say OK",
-            &GenerationParams {
-                max_tokens: 10,
-                ..Default::default()
-            },
-        )
-        .await?;
+        let response = self
+            .client
+            .get(format!("{}/models/{}", self.base_url, self.model))
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_VERSION)
+            .headers(self.headers.clone())
+            .send()
+            .await?;
+
+        if response.status().as_u16() == 404 {
+            return Err(LlmError::ModelUnavailable {
+                model: self.model.clone(),
+            });
+        }
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
+        }
         Ok(())
     }
 }
 
+/// Map a non-success Anthropic response to an `LlmError`.
+async fn api_error(response: reqwest::Response) -> LlmError {
+    let status = response.status().as_u16();
+
+    if status == 429 || status == 529 {
+        let retry_after = if status == 529 {
+            30 // Anthropic "overloaded" default
+        } else {
+            response
+                .headers()
+                .get("retry-after")
+                .and_then(|v| v.to_str().ok())
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(60)
+        };
+        return LlmError::RateLimited {
+            retry_after_secs: retry_after,
+        };
+    }
+
+    let body = response.text().await.unwrap_or_default();
+    let message = serde_json::from_str::<AnthropicErrorResponse>(&body)
+        .map(|e| e.error.message)
+        .unwrap_or(body);
+
+    LlmError::Api { status, message }
+}
+
 // --- Internal Serde types ---
 
 #[derive(Serialize)]
@@ -366,6 +400,77 @@ mod tests {
         assert_eq!(resp.text, "OK");
     }
 
+    #[tokio::test]
+    async fn health_check_retrieves_model() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/models/claude-sonnet-4-5"))
+            .and(header("x-api-key", "test-key"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"id": "claude-sonnet-4-5", "type": "model"})),
+            )
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let provider = AnthropicProvider::with_base_url(
+            "test-key".into(),
+            "claude-sonnet-4-5".into(),
+            server.uri(),
+        );
+
+        provider.health_check().await.expect("healthy");
+    }
+
+    #[tokio::test]
+    async fn health_check_unknown_model_is_unavailable() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/models/claude-nope"))
+            .respond_with(ResponseTemplate::new(404).set_body_json(serde_json::json!({
+                "type": "error",
+                "error": {"type": "not_found_error", "message": "model: claude-nope"}
+            })))
+            .mount(&server)
+            .await;
+
+        let provider =
+            AnthropicProvider::with_base_url("key".into(), "claude-nope".into(), server.uri());
+
+        let err = provider.health_check().await.unwrap_err();
+        assert!(
+            matches!(err, LlmError::ModelUnavailable { ref model } if model == "claude-nope"),
+            "got {err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn health_check_bad_key_maps_to_api_error() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/models/model"))
+            .respond_with(ResponseTemplate::new(401).set_body_json(serde_json::json!({
+                "type": "error",
+                "error": {"type": "authentication_error", "message": "invalid x-api-key"}
+            })))
+            .mount(&server)
+            .await;
+
+        let provider = AnthropicProvider::with_base_url("bad".into(), "model".into(), server.uri());
+
+        match provider.health_check().await.unwrap_err() {
+            LlmError::Api { status, message } => {
+                assert_eq!(status, 401);
+                assert_eq!(message, "invalid x-api-key");
+            }
+            other => panic!("expected Api error, got: {other}"),
+        }
+    }
+
     #[test]
     fn provider_name() {
         let provider = AnthropicProvider::new("key".into(), "model".into());
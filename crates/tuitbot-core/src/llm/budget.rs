@@ -0,0 +1,205 @@
+//! Token budget guard.
+//!
+//! `BudgetedProvider` clamps every request's `max_tokens` to
+//! `llm.max_tokens_cap` and refuses further generations once the tokens
+//! used through it reach `llm.session_token_budget`. A session is the
+//! provider's lifetime: one `tuitbot run`, or one cached generator per
+//! account in the server.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use super::{GenerationParams, LlmProvider, LlmResponse};
+use crate::error::LlmError;
+
+/// An `LlmProvider` that enforces a per-call and per-session token budget.
+pub struct BudgetedProvider {
+    inner: Box<dyn LlmProvider>,
+    max_tokens_cap: u32,
+    session_budget: u64,
+    used: AtomicU64,
+}
+
+impl BudgetedProvider {
+    /// Wrap `inner`. A `max_tokens_cap` or `session_budget` of 0 disables
+    /// that limit.
+    pub fn new(inner: Box<dyn LlmProvider>, max_tokens_cap: u32, session_budget: u64) -> Self {
+        Self {
+            inner,
+            max_tokens_cap,
+            session_budget,
+            used: AtomicU64::new(0),
+        }
+    }
+
+    /// Input and output tokens used through this provider so far.
+    pub fn tokens_used(&self) -> u64 {
+        self.used.load(Ordering::Relaxed)
+    }
+}
+
+#[async_trait::async_trait]
+impl LlmProvider for BudgetedProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn complete(
+        &self,
+        system: &str,
+        user_message: &str,
+        params: &GenerationParams,
+    ) -> Result<LlmResponse, LlmError> {
+        let used = self.tokens_used();
+        if self.session_budget > 0 && used >= self.session_budget {
+            return Err(LlmError::BudgetExhausted {
+                used,
+                budget: self.session_budget,
+            });
+        }
+
+        let clamped;
+        let params = if self.max_tokens_cap > 0 && params.max_tokens > self.max_tokens_cap {
+            tracing::warn!(
+                requested = params.max_tokens,
+                cap = self.max_tokens_cap,
+                "Clamping LLM max_tokens to llm.max_tokens_cap"
+            );
+            clamped = GenerationParams {
+                max_tokens: self.max_tokens_cap,
+                ..params.clone()
+            };
+            &clamped
+        } else {
+            params
+        };
+
+        let resp = self.inner.complete(system, user_message, params).await?;
+        let tokens = u64::from(resp.usage.input_tokens) + u64::from(resp.usage.output_tokens);
+        self.used.fetch_add(tokens, Ordering::Relaxed);
+        Ok(resp)
+    }
+
+    /// Health checks are not counted against the budget.
+    async fn health_check(&self) -> Result<(), LlmError> {
+        self.inner.health_check().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::llm::TokenUsage;
+    use std::sync::atomic::AtomicU32;
+    use std::sync::Arc;
+
+    /// Records the `max_tokens` it was asked for and reports fixed usage.
+    struct Recorder {
+        requested: Arc<AtomicU32>,
+        calls: Arc<AtomicU32>,
+    }
+
+    #[async_trait::async_trait]
+    impl LlmProvider for Recorder {
+        fn name(&self) -> &str {
+            "recorder"
+        }
+
+        async fn complete(
+            &self,
+            _system: &str,
+            _user_message: &str,
+            params: &GenerationParams,
+        ) -> Result<LlmResponse, LlmError> {
+            self.requested.store(params.max_tokens, Ordering::SeqCst);
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            Ok(LlmResponse {
+                text: "ok".to_string(),
+                usage: TokenUsage {
+                    input_tokens: 60,
+                    output_tokens: 40,
+                },
+                model: "recorder-1".to_string(),
+            })
+        }
+    }
+
+    fn budgeted(cap: u32, budget: u64) -> (BudgetedProvider, Arc<AtomicU32>, Arc<AtomicU32>) {
+        let requested = Arc::new(AtomicU32::new(0));
+        let calls = Arc::new(AtomicU32::new(0));
+        let inner = Recorder {
+            requested: requested.clone(),
+            calls: calls.clone(),
+        };
+        (
+            BudgetedProvider::new(Box::new(inner), cap, budget),
+            requested,
+            calls,
+        )
+    }
+
+    fn params(max_tokens: u32) -> GenerationParams {
+        GenerationParams {
+            max_tokens,
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn clamps_max_tokens_above_cap() {
+        let (provider, requested, _) = budgeted(1024, 0);
+
+        provider
+            .complete("sys", "msg", &params(50_000))
+            .await
+            .unwrap();
+        assert_eq!(requested.load(Ordering::SeqCst), 1024);
+
+        provider.complete("sys", "msg", &params(512)).await.unwrap();
+        assert_eq!(requested.load(Ordering::SeqCst), 512);
+    }
+
+    #[tokio::test]
+    async fn zero_cap_passes_max_tokens_through() {
+        let (provider, requested, _) = budgeted(0, 0);
+        provider
+            .complete("sys", "msg", &params(50_000))
+            .await
+            .unwrap();
+        assert_eq!(requested.load(Ordering::SeqCst), 50_000);
+    }
+
+    #[tokio::test]
+    async fn refuses_generations_once_session_budget_is_used() {
+        // Each call uses 100 tokens. The budget is checked before a call,
+        // so the call that crosses it completes and the next is refused.
+        let (provider, _, calls) = budgeted(0, 250);
+
+        provider.complete("sys", "msg", &params(10)).await.unwrap();
+        provider.complete("sys", "msg", &params(10)).await.unwrap();
+        provider.complete("sys", "msg", &params(10)).await.unwrap();
+        assert_eq!(provider.tokens_used(), 300);
+
+        let err = provider
+            .complete("sys", "msg", &params(10))
+            .await
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            LlmError::BudgetExhausted {
+                used: 300,
+                budget: 250
+            }
+        ));
+        assert!(!err.is_transient());
+        assert!(err.to_string().contains("llm.session_token_budget"));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn health_check_is_not_counted() {
+        let (provider, _, _) = budgeted(0, 1);
+        provider.health_check().await.unwrap();
+        assert_eq!(provider.tokens_used(), 0);
+        provider.complete("sys", "msg", &params(10)).await.unwrap();
+    }
+}
@@ -4,16 +4,49 @@
 //! abstracting away provider-specific construction details.
 
 use super::anthropic::AnthropicProvider;
+use super::budget::BudgetedProvider;
+use super::fallback::FallbackProvider;
 use super::openai_compat::OpenAiCompatProvider;
-use super::LlmProvider;
-use crate::config::LlmConfig;
+use super::{GenerationParams, LlmProvider, LlmResponse};
+use crate::config::{HttpTimeoutConfig, LlmConfig, LlmProviderConfig};
 use crate::error::LlmError;
 
 /// Create an LLM provider from configuration.
 ///
 /// Returns `Box<dyn LlmProvider>` so callers are decoupled from the concrete type.
-/// Logs the constructed provider at info level (without the API key).
+/// When `[[llm.fallbacks]]` are configured the primary provider and the
+/// fallbacks are chained in a [`FallbackProvider`], each with its own
+/// endpoint and headers. The whole chain is wrapped in a
+/// [`BudgetedProvider`] enforcing `llm.max_tokens_cap` and
+/// `llm.session_token_budget`. Logs every constructed provider at info
+/// level (without the API key).
 pub fn create_provider(config: &LlmConfig) -> Result<Box<dyn LlmProvider>, LlmError> {
+    let primary = create_single(&config.primary(), &config.http)?;
+    let chain: Box<dyn LlmProvider> = if config.fallbacks.is_empty() {
+        primary
+    } else {
+        let mut providers = vec![primary];
+        for entry in &config.fallbacks {
+            providers.push(create_single(entry, &config.http)?);
+        }
+        Box::new(FallbackProvider::new(providers))
+    };
+
+    if config.max_tokens_cap == 0 && config.session_token_budget == 0 {
+        return Ok(chain);
+    }
+    Ok(Box::new(BudgetedProvider::new(
+        chain,
+        config.max_tokens_cap,
+        config.session_token_budget,
+    )))
+}
+
+/// Create one provider from a chain entry.
+fn create_single(
+    config: &LlmProviderConfig,
+    http: &HttpTimeoutConfig,
+) -> Result<Box<dyn LlmProvider>, LlmError> {
     match config.provider.as_str() {
         "openai" => {
             let api_key = config
@@ -38,12 +71,11 @@ pub fn create_provider(config: &LlmConfig) -> Result<Box<dyn LlmProvider>, LlmEr
 
             tracing::info!(provider = "openai", model = %model, base_url = %base_url, "Creating LLM provider");
 
-            Ok(Box::new(OpenAiCompatProvider::new(
-                base_url,
-                api_key,
-                model,
-                "openai".to_string(),
-            )))
+            Ok(Box::new(
+                OpenAiCompatProvider::new(base_url, api_key, model, "openai".to_string())
+                    .with_timeouts(http)
+                    .with_headers(&config.headers),
+            ))
         }
         "ollama" => {
             let base_url = config
@@ -61,12 +93,16 @@ pub fn create_provider(config: &LlmConfig) -> Result<Box<dyn LlmProvider>, LlmEr
 
             tracing::info!(provider = "ollama", model = %model, base_url = %base_url, "Creating LLM provider");
 
-            Ok(Box::new(OpenAiCompatProvider::new(
-                base_url,
-                "ollama".to_string(),
-                model,
-                "ollama".to_string(),
-            )))
+            Ok(Box::new(
+                OpenAiCompatProvider::new(
+                    base_url,
+                    "ollama".to_string(),
+                    model,
+                    "ollama".to_string(),
+                )
+                .with_timeouts(http)
+                .with_headers(&config.headers),
+            ))
         }
         "anthropic" => {
             let api_key = config
@@ -85,13 +121,17 @@ pub fn create_provider(config: &LlmConfig) -> Result<Box<dyn LlmProvider>, LlmEr
             tracing::info!(provider = "anthropic", model = %model, "Creating LLM provider");
 
             if let Some(base_url) = config.base_url.as_deref().filter(|u| !u.is_empty()) {
-                Ok(Box::new(AnthropicProvider::with_base_url(
-                    api_key,
-                    model,
-                    base_url.to_string(),
-                )))
+                Ok(Box::new(
+                    AnthropicProvider::with_base_url(api_key, model, base_url.to_string())
+                        .with_timeouts(http)
+                        .with_headers(&config.headers),
+                ))
             } else {
-                Ok(Box::new(AnthropicProvider::new(api_key, model)))
+                Ok(Box::new(
+                    AnthropicProvider::new(api_key, model)
+                        .with_timeouts(http)
+                        .with_headers(&config.headers),
+                ))
             }
         }
         "groq" => {
@@ -117,18 +157,43 @@ pub fn create_provider(config: &LlmConfig) -> Result<Box<dyn LlmProvider>, LlmEr
 
             tracing::info!(provider = "groq", model = %model, base_url = %base_url, "Creating LLM provider");
 
-            Ok(Box::new(OpenAiCompatProvider::new(
-                base_url,
-                api_key,
-                model,
-                "groq".to_string(),
-            )))
+            Ok(Box::new(
+                OpenAiCompatProvider::new(base_url, api_key, model, "groq".to_string())
+                    .with_timeouts(http)
+                    .with_headers(&config.headers),
+            ))
         }
         "" => Err(LlmError::NotConfigured),
         _other => Err(LlmError::NotConfigured),
     }
 }
 
+/// Stand-in provider for runs that must not call an LLM (e.g. `tuitbot tick --no-llm`).
+///
+/// Every request fails with [`LlmError::NotConfigured`], so a loop that reaches
+/// it by mistake fails loudly instead of generating content.
+pub struct DisabledProvider;
+
+#[async_trait::async_trait]
+impl LlmProvider for DisabledProvider {
+    fn name(&self) -> &str {
+        "disabled"
+    }
+
+    async fn complete(
+        &self,
+        _system: &str,
+        _user_message: &str,
+        _params: &GenerationParams,
+    ) -> Result<LlmResponse, LlmError> {
+        Err(LlmError::NotConfigured)
+    }
+
+    async fn health_check(&self) -> Result<(), LlmError> {
+        Err(LlmError::NotConfigured)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -141,6 +206,7 @@ mod tests {
             api_key: Some("sk-test".to_string()),
             model: "gpt-4o".to_string(),
             base_url: None,
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "openai");
@@ -153,6 +219,7 @@ mod tests {
             api_key: None,
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         assert!(matches!(
             create_provider(&config),
@@ -167,6 +234,7 @@ mod tests {
             api_key: None,
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "ollama");
@@ -179,6 +247,7 @@ mod tests {
             api_key: Some("sk-ant-test".to_string()),
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "anthropic");
@@ -191,6 +260,7 @@ mod tests {
             api_key: None,
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         assert!(matches!(
             create_provider(&config),
@@ -205,6 +275,7 @@ mod tests {
             api_key: None,
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         assert!(matches!(
             create_provider(&config),
@@ -228,6 +299,7 @@ mod tests {
             api_key: Some("key".to_string()),
             model: String::new(),
             base_url: Some("https://custom.api.com/v1".to_string()),
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "openai");
@@ -240,6 +312,7 @@ mod tests {
             api_key: None,
             model: "custom-model".to_string(),
             base_url: Some("http://remote:11434/v1".to_string()),
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "ollama");
@@ -252,6 +325,7 @@ mod tests {
             api_key: Some("sk-ant-test".to_string()),
             model: "claude-sonnet-4-5-20250514".to_string(),
             base_url: Some("https://custom.anthropic.com".to_string()),
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "anthropic");
@@ -264,6 +338,7 @@ mod tests {
             api_key: Some(String::new()),
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         assert!(matches!(
             create_provider(&config),
@@ -278,6 +353,7 @@ mod tests {
             api_key: Some(String::new()),
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         assert!(matches!(
             create_provider(&config),
@@ -292,6 +368,7 @@ mod tests {
             api_key: Some("sk-test".to_string()),
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         // Should succeed with default model gpt-4o-mini
         let provider = create_provider(&config).expect("create");
@@ -305,6 +382,7 @@ mod tests {
             api_key: None,
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "ollama");
@@ -317,6 +395,7 @@ mod tests {
             api_key: Some("sk-ant-key".to_string()),
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "anthropic");
@@ -329,6 +408,7 @@ mod tests {
             api_key: Some("sk-test".to_string()),
             model: "gpt-4o".to_string(),
             base_url: Some(String::new()),
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "openai");
@@ -341,6 +421,7 @@ mod tests {
             api_key: Some("gsk_test".to_string()),
             model: "llama-3.3-70b-versatile".to_string(),
             base_url: None,
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "groq");
@@ -353,6 +434,7 @@ mod tests {
             api_key: None,
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         assert!(matches!(
             create_provider(&config),
@@ -367,11 +449,23 @@ mod tests {
             api_key: Some("gsk_test".to_string()),
             model: String::new(),
             base_url: None,
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "groq");
     }
 
+    #[tokio::test]
+    async fn disabled_provider_refuses_requests() {
+        let provider = DisabledProvider;
+        assert_eq!(provider.name(), "disabled");
+        let err = provider
+            .complete("system", "user", &GenerationParams::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LlmError::NotConfigured));
+    }
+
     #[test]
     fn create_anthropic_empty_base_url_uses_default() {
         let config = LlmConfig {
@@ -379,6 +473,7 @@ mod tests {
             api_key: Some("sk-ant-test".to_string()),
             model: "claude-sonnet-4-5-20250514".to_string(),
             base_url: Some(String::new()),
+            ..Default::default()
         };
         let provider = create_provider(&config).expect("create");
         assert_eq!(provider.name(), "anthropic");
@@ -0,0 +1,247 @@
+//! Provider failover chain.
+//!
+//! `FallbackProvider` tries the primary provider and then each
+//! `[[llm.fallbacks]]` entry in order, moving on only when a provider fails
+//! with a transient error (timeouts, 5xx, rate limits). Permanent errors such
+//! as an invalid key are returned from the provider that raised them.
+
+use super::{GenerationParams, LlmProvider, LlmResponse};
+use crate::error::LlmError;
+
+/// An `LlmProvider` that fails over across an ordered list of providers.
+pub struct FallbackProvider {
+    providers: Vec<Box<dyn LlmProvider>>,
+}
+
+impl FallbackProvider {
+    /// Chain `providers`, tried first to last. Must not be empty.
+    pub fn new(providers: Vec<Box<dyn LlmProvider>>) -> Self {
+        debug_assert!(!providers.is_empty(), "fallback chain needs a provider");
+        Self { providers }
+    }
+}
+
+#[async_trait::async_trait]
+impl LlmProvider for FallbackProvider {
+    fn name(&self) -> &str {
+        self.providers.first().map_or("fallback", |p| p.name())
+    }
+
+    async fn complete(
+        &self,
+        system: &str,
+        user_message: &str,
+        params: &GenerationParams,
+    ) -> Result<LlmResponse, LlmError> {
+        let mut last_err = LlmError::NotConfigured;
+        for (i, provider) in self.providers.iter().enumerate() {
+            match provider.complete(system, user_message, params).await {
+                Ok(resp) => return Ok(resp),
+                Err(e) if !e.is_transient() => return Err(e),
+                Err(e) => {
+                    if let Some(next) = self.providers.get(i + 1) {
+                        tracing::warn!(
+                            provider = provider.name(),
+                            next = next.name(),
+                            error = %e,
+                            "LLM provider failed; falling back"
+                        );
+                    }
+                    last_err = e;
+                }
+            }
+        }
+        Err(last_err)
+    }
+
+    /// Healthy while any provider in the chain is reachable, since that is
+    /// enough for `complete` to succeed. Unreachable providers are logged so a
+    /// broken fallback is still visible; the last error is returned when none
+    /// respond.
+    async fn health_check(&self) -> Result<(), LlmError> {
+        let mut last_err = LlmError::NotConfigured;
+        for provider in &self.providers {
+            match provider.health_check().await {
+                Ok(()) => return Ok(()),
+                Err(e) => {
+                    tracing::warn!(
+                        provider = provider.name(),
+                        error = %e,
+                        "LLM provider health check failed"
+                    );
+                    last_err = e;
+                }
+            }
+        }
+        Err(last_err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{LlmConfig, LlmProviderConfig};
+    use crate::error::LlmError;
+    use crate::llm::factory::create_provider;
+    use crate::llm::GenerationParams;
+
+    fn chain_entry(provider: &str, base_url: &str, token: &str) -> LlmProviderConfig {
+        LlmProviderConfig {
+            provider: provider.to_string(),
+            api_key: Some("key".to_string()),
+            model: "m".to_string(),
+            base_url: Some(base_url.to_string()),
+            headers: [("x-proxy-token".to_string(), token.to_string())].into(),
+        }
+    }
+
+    #[tokio::test]
+    async fn fallback_chain_sends_each_provider_to_its_own_endpoint() {
+        use wiremock::matchers::{header, method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let openai = MockServer::start().await;
+        let anthropic = MockServer::start().await;
+        let groq = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(header("x-proxy-token", "primary"))
+            .respond_with(ResponseTemplate::new(503))
+            .expect(1)
+            .mount(&openai)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/messages"))
+            .and(header("x-proxy-token", "second"))
+            .respond_with(ResponseTemplate::new(502))
+            .expect(1)
+            .mount(&anthropic)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .and(header("x-proxy-token", "third"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "choices": [{"message": {"content": "from groq"}}],
+                "model": "m"
+            })))
+            .expect(1)
+            .mount(&groq)
+            .await;
+
+        let primary = chain_entry("openai", &openai.uri(), "primary");
+        let config = LlmConfig {
+            provider: primary.provider,
+            api_key: primary.api_key,
+            model: primary.model,
+            base_url: primary.base_url,
+            headers: primary.headers,
+            fallbacks: vec![
+                chain_entry("anthropic", &anthropic.uri(), "second"),
+                chain_entry("groq", &groq.uri(), "third"),
+            ],
+            ..Default::default()
+        };
+
+        let provider = create_provider(&config).expect("create");
+        assert_eq!(provider.name(), "openai");
+        let resp = provider
+            .complete("system", "user", &GenerationParams::default())
+            .await
+            .expect("third provider answers");
+        assert_eq!(resp.text, "from groq");
+    }
+
+    #[tokio::test]
+    async fn fallback_chain_stops_on_permanent_error() {
+        use wiremock::matchers::method;
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let primary = MockServer::start().await;
+        let fallback = MockServer::start().await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(401))
+            .mount(&primary)
+            .await;
+        Mock::given(method("POST"))
+            .respond_with(ResponseTemplate::new(200))
+            .expect(0)
+            .mount(&fallback)
+            .await;
+
+        let config = LlmConfig {
+            provider: "openai".to_string(),
+            api_key: Some("bad".to_string()),
+            base_url: Some(primary.uri()),
+            fallbacks: vec![chain_entry("openai", &fallback.uri(), "unused")],
+            ..Default::default()
+        };
+        let err = create_provider(&config)
+            .expect("create")
+            .complete("system", "user", &GenerationParams::default())
+            .await
+            .unwrap_err();
+        assert!(matches!(err, LlmError::Api { status: 401, .. }));
+    }
+
+    #[tokio::test]
+    async fn fallback_chain_is_healthy_while_any_provider_is() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let primary = MockServer::start().await;
+        let fallback = MockServer::start().await;
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .respond_with(ResponseTemplate::new(503))
+            .mount(&primary)
+            .await;
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .respond_with(
+                ResponseTemplate::new(200)
+                    .set_body_json(serde_json::json!({"data": [{"id": "m"}]})),
+            )
+            .mount(&fallback)
+            .await;
+
+        let config = LlmConfig {
+            provider: "openai".to_string(),
+            api_key: Some("key".to_string()),
+            model: "m".to_string(),
+            base_url: Some(primary.uri()),
+            fallbacks: vec![chain_entry("openai", &fallback.uri(), "fb")],
+            ..Default::default()
+        };
+        let provider = create_provider(&config).expect("create");
+        provider
+            .health_check()
+            .await
+            .expect("fallback is reachable");
+
+        let down = LlmConfig {
+            fallbacks: vec![chain_entry("openai", &primary.uri(), "fb")],
+            ..config
+        };
+        assert!(create_provider(&down)
+            .expect("create")
+            .health_check()
+            .await
+            .is_err());
+    }
+
+    #[test]
+    fn fallback_without_api_key_is_not_configured() {
+        let config = LlmConfig {
+            provider: "ollama".to_string(),
+            fallbacks: vec![LlmProviderConfig {
+                provider: "openai".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        assert!(matches!(
+            create_provider(&config),
+            Err(LlmError::NotConfigured)
+        ));
+    }
+}
@@ -4,16 +4,22 @@
 //! with typed responses, token usage tracking, and health checking.
 
 pub mod anthropic;
+pub mod budget;
 pub mod embedding;
 pub mod embedding_factory;
 pub mod factory;
+pub mod fallback;
 pub mod ollama_embedding;
 pub mod openai_compat;
 pub mod openai_embedding;
 pub mod pricing;
+pub mod retry;
+pub mod session_usage;
 
 use crate::error::LlmError;
 
+pub use session_usage::{SessionUsage, SessionUsageTotals};
+
 /// Token usage information from an LLM completion.
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct TokenUsage {
@@ -83,7 +89,23 @@ pub trait LlmProvider: Send + Sync {
     ) -> Result<LlmResponse, LlmError>;
 
     /// Check if the provider is reachable and configured correctly.
-    async fn health_check(&self) -> Result<(), LlmError>;
+    ///
+    /// The default sends a tiny completion. Providers override it with a
+    /// cheaper call (such as listing models) that also confirms the
+    /// configured model exists, failing with [`LlmError::ModelUnavailable`]
+    /// when it does not.
+    async fn health_check(&self) -> Result<(), LlmError> {
+        self.complete(
+            "You are a test assistant.",
+            "Say OK",
+            &GenerationParams {
+                max_tokens: 10,
+                ..Default::default()
+            },
+        )
+        .await?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -137,6 +159,69 @@ mod tests {
         assert_eq!(total.output_tokens, 17);
     }
 
+    /// Provider that only implements `complete`, exercising the default
+    /// `health_check`.
+    struct CompleteOnly {
+        fail: bool,
+        max_tokens: std::sync::Mutex<Option<u32>>,
+    }
+
+    #[async_trait::async_trait]
+    impl LlmProvider for CompleteOnly {
+        fn name(&self) -> &str {
+            "complete-only"
+        }
+
+        async fn complete(
+            &self,
+            _system: &str,
+            _user_message: &str,
+            params: &GenerationParams,
+        ) -> Result<LlmResponse, LlmError> {
+            *self.max_tokens.lock().unwrap() = Some(params.max_tokens);
+            if self.fail {
+                return Err(LlmError::Api {
+                    status: 401,
+                    message: "invalid key".into(),
+                });
+            }
+            Ok(LlmResponse {
+                text: "OK".into(),
+                usage: TokenUsage::default(),
+                model: "mock".into(),
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn default_health_check_sends_tiny_completion() {
+        let provider = CompleteOnly {
+            fail: false,
+            max_tokens: Default::default(),
+        };
+        provider.health_check().await.expect("healthy");
+        assert_eq!(*provider.max_tokens.lock().unwrap(), Some(10));
+    }
+
+    #[tokio::test]
+    async fn default_health_check_propagates_failure() {
+        let provider = CompleteOnly {
+            fail: true,
+            max_tokens: Default::default(),
+        };
+        let err = provider.health_check().await.unwrap_err();
+        assert!(matches!(err, LlmError::Api { status: 401, .. }));
+    }
+
+    #[test]
+    fn model_unavailable_is_not_transient() {
+        let err = LlmError::ModelUnavailable {
+            model: "gpt-9".into(),
+        };
+        assert!(!err.is_transient());
+        assert!(err.to_string().contains("gpt-9"));
+    }
+
     #[test]
     fn generation_params_default() {
         let params = GenerationParams::default();
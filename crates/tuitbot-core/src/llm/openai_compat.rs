@@ -3,7 +3,10 @@
 //! Works with both OpenAI (cloud) and Ollama (local) since they share
 //! the same chat completions request/response format.
 
+use std::collections::BTreeMap;
+
 use super::{GenerationParams, LlmProvider, LlmResponse, TokenUsage};
+use crate::config::HttpTimeoutConfig;
 use crate::error::LlmError;
 use serde::{Deserialize, Serialize};
 
@@ -16,19 +19,33 @@ pub struct OpenAiCompatProvider {
     api_key: String,
     model: String,
     provider_name: String,
+    headers: reqwest::header::HeaderMap,
 }
 
 impl OpenAiCompatProvider {
     /// Create a new OpenAI-compatible provider.
     pub fn new(base_url: String, api_key: String, model: String, provider_name: String) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: crate::net::http_client(&HttpTimeoutConfig::default()),
             base_url,
             api_key,
             model,
             provider_name,
+            headers: reqwest::header::HeaderMap::new(),
         }
     }
+
+    /// Apply connect and request timeouts from `[llm.http]`.
+    pub fn with_timeouts(mut self, timeouts: &HttpTimeoutConfig) -> Self {
+        self.client = crate::net::http_client(timeouts);
+        self
+    }
+
+    /// Send these extra headers with every request (e.g. proxy auth).
+    pub fn with_headers(mut self, headers: &BTreeMap<String, String>) -> Self {
+        self.headers = crate::net::header_map(headers);
+        self
+    }
 }
 
 #[async_trait::async_trait]
@@ -72,30 +89,13 @@ impl LlmProvider for OpenAiCompatProvider {
             .client
             .post(format!("{}/chat/completions", self.base_url))
             .bearer_auth(&self.api_key)
+            .headers(self.headers.clone())
             .json(&request)
             .send()
             .await?;
 
         if !response.status().is_success() {
-            let status = response.status().as_u16();
-
-            if status == 429 {
-                let retry_after = response
-                    .headers()
-                    .get("retry-after")
-                    .and_then(|v| v.to_str().ok())
-                    .and_then(|v| v.parse::<u64>().ok())
-                    .unwrap_or(60);
-                return Err(LlmError::RateLimited {
-                    retry_after_secs: retry_after,
-                });
-            }
-
-            let body = response.text().await.unwrap_or_default();
-            return Err(LlmError::Api {
-                status,
-                message: body,
-            });
+            return Err(api_error(response).await);
         }
 
         let body: ChatCompletionResponse = response
@@ -129,20 +129,85 @@ impl LlmProvider for OpenAiCompatProvider {
         })
     }
 
+    /// List models via `GET /models` and confirm the configured one is
+    /// offered. Endpoints without a models route (404/405) fall back to a
+    /// tiny completion.
     async fn health_check(&self) -> Result<(), LlmError> {
-        self.complete(
-            "You are a test assistant.",
-            "Say OK",
-            &GenerationParams {
-                max_tokens: 10,
-                ..Default::default()
-            },
-        )
-        .await?;
-        Ok(())
+        let response = self
+            .client
+            .get(format!("{}/models", self.base_url))
+            .bearer_auth(&self.api_key)
+            .headers(self.headers.clone())
+            .send()
+            .await?;
+
+        let status = response.status().as_u16();
+        if status == 404 || status == 405 {
+            self.complete(
+                "You are a test assistant.",
+                "Say OK",
+                &GenerationParams {
+                    max_tokens: 10,
+                    ..Default::default()
+                },
+            )
+            .await?;
+            return Ok(());
+        }
+        if !response.status().is_success() {
+            return Err(api_error(response).await);
+        }
+
+        let list: ModelList = response
+            .json()
+            .await
+            .map_err(|e| LlmError::Parse(format!("failed to parse model list: {e}")))?;
+
+        if list.data.iter().any(|m| model_matches(&m.id, &self.model)) {
+            Ok(())
+        } else {
+            Err(LlmError::ModelUnavailable {
+                model: self.model.clone(),
+            })
+        }
     }
 }
 
+/// Map a non-success response to an `LlmError`.
+async fn api_error(response: reqwest::Response) -> LlmError {
+    let status = response.status().as_u16();
+
+    if status == 429 {
+        let retry_after = response
+            .headers()
+            .get("retry-after")
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.parse::<u64>().ok())
+            .unwrap_or(60);
+        return LlmError::RateLimited {
+            retry_after_secs: retry_after,
+        };
+    }
+
+    let body = response.text().await.unwrap_or_default();
+    LlmError::Api {
+        status,
+        message: body,
+    }
+}
+
+/// Whether a listed model id satisfies the configured model.
+///
+/// Ollama lists tagged ids (`llama3.1:latest`), so an untagged configured
+/// model matches any tag of the same name.
+fn model_matches(listed: &str, configured: &str) -> bool {
+    listed == configured
+        || (!configured.contains(':')
+            && listed
+                .split_once(':')
+                .is_some_and(|(name, _)| name == configured))
+}
+
 // --- Internal Serde types ---
 
 #[derive(Serialize)]
@@ -180,6 +245,17 @@ struct ChoiceMessage {
     content: String,
 }
 
+#[derive(Deserialize)]
+struct ModelList {
+    #[serde(default)]
+    data: Vec<ModelEntry>,
+}
+
+#[derive(Deserialize)]
+struct ModelEntry {
+    id: String,
+}
+
 #[derive(Deserialize)]
 struct Usage {
     #[serde(default)]
@@ -384,4 +460,145 @@ mod tests {
         );
         assert_eq!(provider.name(), "ollama");
     }
+
+    #[tokio::test]
+    async fn slow_response_times_out_at_configured_limit() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_delay(std::time::Duration::from_secs(5)))
+            .mount(&server)
+            .await;
+
+        let provider = OpenAiCompatProvider::new(
+            server.uri(),
+            "test-key".into(),
+            "gpt-4o-mini".into(),
+            "openai".into(),
+        )
+        .with_timeouts(&HttpTimeoutConfig {
+            connect_timeout_ms: 1_000,
+            request_timeout_ms: 200,
+        });
+
+        let started = std::time::Instant::now();
+        let err = provider
+            .complete("system", "hello", &GenerationParams::default())
+            .await
+            .unwrap_err();
+
+        assert!(matches!(err, LlmError::Timeout(_)), "got {err:?}");
+        assert!(err.is_transient());
+        assert!(started.elapsed() < std::time::Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn health_check_finds_model_in_list() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .and(header("authorization", "Bearer test-key"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"id": "gpt-4o"}, {"id": "gpt-4o-mini"}]
+            })))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(500))
+            .expect(0)
+            .mount(&server)
+            .await;
+
+        let provider = OpenAiCompatProvider::new(
+            server.uri(),
+            "test-key".into(),
+            "gpt-4o-mini".into(),
+            "openai".into(),
+        );
+
+        provider.health_check().await.expect("healthy");
+    }
+
+    #[tokio::test]
+    async fn health_check_missing_model_is_unavailable() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "data": [{"id": "gpt-4o"}]
+            })))
+            .mount(&server)
+            .await;
+
+        let provider =
+            OpenAiCompatProvider::new(server.uri(), "key".into(), "gpt-9".into(), "openai".into());
+
+        let err = provider.health_check().await.unwrap_err();
+        assert!(
+            matches!(err, LlmError::ModelUnavailable { ref model } if model == "gpt-9"),
+            "got {err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn health_check_bad_key_maps_to_api_error() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .respond_with(ResponseTemplate::new(401).set_body_string("invalid api key"))
+            .mount(&server)
+            .await;
+
+        let provider =
+            OpenAiCompatProvider::new(server.uri(), "bad".into(), "model".into(), "openai".into());
+
+        let err = provider.health_check().await.unwrap_err();
+        assert!(
+            matches!(err, LlmError::Api { status: 401, .. }),
+            "got {err:?}"
+        );
+    }
+
+    #[tokio::test]
+    async fn health_check_falls_back_to_completion_without_models_route() {
+        let server = MockServer::start().await;
+
+        Mock::given(method("GET"))
+            .and(path("/models"))
+            .respond_with(ResponseTemplate::new(404))
+            .mount(&server)
+            .await;
+        Mock::given(method("POST"))
+            .and(path("/chat/completions"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "choices": [{"message": {"content": "OK"}}],
+                "model": "custom"
+            })))
+            .expect(1)
+            .mount(&server)
+            .await;
+
+        let provider =
+            OpenAiCompatProvider::new(server.uri(), "key".into(), "custom".into(), "custom".into());
+
+        provider
+            .health_check()
+            .await
+            .expect("healthy via completion");
+    }
+
+    #[test]
+    fn model_matches_ollama_tags() {
+        assert!(model_matches("llama3.1:latest", "llama3.1"));
+        assert!(model_matches("llama3.1:8b", "llama3.1:8b"));
+        assert!(!model_matches("llama3.1:8b", "llama3.1:70b"));
+        assert!(!model_matches("llama3.10:latest", "llama3.1"));
+        assert!(model_matches("gpt-4o-mini", "gpt-4o-mini"));
+        assert!(!model_matches("gpt-4o-mini", "gpt-4o"));
+    }
 }
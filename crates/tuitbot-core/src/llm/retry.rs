@@ -0,0 +1,250 @@
+//! Retry helper for transient LLM errors.
+//!
+//! Provides `complete_with_retry` — wraps a single `LlmProvider::complete`
+//! call and retries it per `[llm.retry]` when the error is transient
+//! (timeouts, 5xx, rate limits). Permanent errors such as invalid keys or
+//! content-policy rejections are returned on the first failure.
+//!
+//! `RetryingProvider` applies the same policy to every call made through a
+//! provider, which is how `ContentGenerator` retries all of its generation
+//! methods.
+
+use std::time::Duration;
+
+use super::{GenerationParams, LlmProvider, LlmResponse};
+use crate::config::{LlmBackoff, LlmRetryConfig};
+use crate::error::LlmError;
+
+/// Upper bound on a single delay, including provider `retry_after` hints.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Call `provider.complete`, retrying transient failures.
+///
+/// Makes at most `retry.max_attempts` calls (at least one). Returns the last
+/// error unchanged once attempts are exhausted.
+pub async fn complete_with_retry(
+    provider: &dyn LlmProvider,
+    retry: &LlmRetryConfig,
+    system: &str,
+    user_message: &str,
+    params: &GenerationParams,
+) -> Result<LlmResponse, LlmError> {
+    let max_attempts = retry.max_attempts.max(1);
+    let mut attempt = 0u32;
+    loop {
+        match provider.complete(system, user_message, params).await {
+            Ok(resp) => return Ok(resp),
+            Err(e) if !e.is_transient() => return Err(e),
+            Err(e) => {
+                attempt += 1;
+                if attempt >= max_attempts {
+                    return Err(e);
+                }
+
+                let delay = retry_delay(retry, attempt, &e);
+                tracing::debug!(
+                    provider = provider.name(),
+                    attempt,
+                    delay_ms = delay.as_millis() as u64,
+                    error = %e,
+                    "Transient LLM error — backing off before retry"
+                );
+                tokio::time::sleep(delay).await;
+            }
+        }
+    }
+}
+
+/// An `LlmProvider` that retries transient failures of the wrapped provider.
+pub struct RetryingProvider {
+    inner: Box<dyn LlmProvider>,
+    retry: LlmRetryConfig,
+}
+
+impl RetryingProvider {
+    /// Wrap `inner` with the given retry policy.
+    pub fn new(inner: Box<dyn LlmProvider>, retry: LlmRetryConfig) -> Self {
+        Self { inner, retry }
+    }
+
+    /// Replace the retry policy.
+    pub fn set_retry(&mut self, retry: LlmRetryConfig) {
+        self.retry = retry;
+    }
+
+    /// The current retry policy.
+    pub fn retry(&self) -> &LlmRetryConfig {
+        &self.retry
+    }
+}
+
+#[async_trait::async_trait]
+impl LlmProvider for RetryingProvider {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn complete(
+        &self,
+        system: &str,
+        user_message: &str,
+        params: &GenerationParams,
+    ) -> Result<LlmResponse, LlmError> {
+        complete_with_retry(&*self.inner, &self.retry, system, user_message, params).await
+    }
+
+    async fn health_check(&self) -> Result<(), LlmError> {
+        self.inner.health_check().await
+    }
+}
+
+/// Delay before retry number `attempt` (1-based).
+///
+/// Rate-limit responses wait at least as long as the provider asked.
+fn retry_delay(retry: &LlmRetryConfig, attempt: u32, error: &LlmError) -> Duration {
+    let base = Duration::from_millis(retry.base_delay_ms);
+    let backoff = match retry.backoff {
+        LlmBackoff::Exponential => base.saturating_mul(2u32.saturating_pow(attempt - 1)),
+        LlmBackoff::Fixed => base,
+    };
+    let delay = match error {
+        LlmError::RateLimited { retry_after_secs } => {
+            backoff.max(Duration::from_secs(*retry_after_secs))
+        }
+        _ => backoff,
+    };
+    delay.min(MAX_RETRY_DELAY)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::llm::TokenUsage;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::Mutex;
+
+    /// Provider that returns queued errors before succeeding.
+    struct FlakyProvider {
+        failures: Mutex<Vec<LlmError>>,
+        calls: AtomicU32,
+    }
+
+    impl FlakyProvider {
+        fn new(failures: Vec<LlmError>) -> Self {
+            Self {
+                failures: Mutex::new(failures),
+                calls: AtomicU32::new(0),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl LlmProvider for FlakyProvider {
+        fn name(&self) -> &str {
+            "flaky"
+        }
+
+        async fn complete(
+            &self,
+            _system: &str,
+            _user_message: &str,
+            _params: &GenerationParams,
+        ) -> Result<LlmResponse, LlmError> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let mut failures = self.failures.lock().unwrap();
+            if !failures.is_empty() {
+                return Err(failures.remove(0));
+            }
+            Ok(LlmResponse {
+                text: "ok".to_string(),
+                usage: TokenUsage::default(),
+                model: "flaky-1".to_string(),
+            })
+        }
+
+        async fn health_check(&self) -> Result<(), LlmError> {
+            Ok(())
+        }
+    }
+
+    fn fast_retry(max_attempts: u32) -> LlmRetryConfig {
+        LlmRetryConfig {
+            max_attempts,
+            base_delay_ms: 1,
+            backoff: LlmBackoff::Exponential,
+        }
+    }
+
+    fn server_error() -> LlmError {
+        LlmError::Api {
+            status: 503,
+            message: "overloaded".to_string(),
+        }
+    }
+
+    async fn call(
+        provider: &FlakyProvider,
+        retry: &LlmRetryConfig,
+    ) -> Result<LlmResponse, LlmError> {
+        complete_with_retry(provider, retry, "sys", "msg", &GenerationParams::default()).await
+    }
+
+    #[tokio::test]
+    async fn retries_transient_errors_then_succeeds() {
+        let provider = FlakyProvider::new(vec![server_error(), server_error()]);
+        let resp = call(&provider, &fast_retry(3)).await.expect("succeeds");
+        assert_eq!(resp.text, "ok");
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let provider = FlakyProvider::new(vec![server_error(), server_error(), server_error()]);
+        let err = call(&provider, &fast_retry(2)).await.unwrap_err();
+        assert!(matches!(err, LlmError::Api { status: 503, .. }));
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_permanent_errors() {
+        let provider = FlakyProvider::new(vec![LlmError::Api {
+            status: 400,
+            message: "content policy violation".to_string(),
+        }]);
+        let err = call(&provider, &fast_retry(3)).await.unwrap_err();
+        assert!(matches!(err, LlmError::Api { status: 400, .. }));
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn zero_max_attempts_still_calls_once() {
+        let provider = FlakyProvider::new(vec![]);
+        call(&provider, &fast_retry(0)).await.expect("succeeds");
+        assert_eq!(provider.calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn delay_grows_exponentially_or_stays_fixed() {
+        let mut retry = LlmRetryConfig {
+            max_attempts: 5,
+            base_delay_ms: 100,
+            backoff: LlmBackoff::Exponential,
+        };
+        let err = server_error();
+        assert_eq!(retry_delay(&retry, 1, &err), Duration::from_millis(100));
+        assert_eq!(retry_delay(&retry, 3, &err), Duration::from_millis(400));
+
+        retry.backoff = LlmBackoff::Fixed;
+        assert_eq!(retry_delay(&retry, 3, &err), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn rate_limit_delay_honors_retry_after_with_cap() {
+        let retry = fast_retry(3);
+        let limited = |secs| LlmError::RateLimited {
+            retry_after_secs: secs,
+        };
+        assert_eq!(retry_delay(&retry, 1, &limited(2)), Duration::from_secs(2));
+        assert_eq!(retry_delay(&retry, 1, &limited(600)), MAX_RETRY_DELAY);
+    }
+}
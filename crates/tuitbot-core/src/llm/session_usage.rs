@@ -0,0 +1,126 @@
+//! Session-level token usage accumulator.
+//!
+//! Collects token counts and estimated cost from every generation made during
+//! a long-running session (e.g. `tuitbot run`) so they can be reported in the
+//! periodic status summary and once more at shutdown.
+
+use std::sync::Mutex;
+
+use super::pricing;
+use super::TokenUsage;
+
+/// Cumulative totals for a session.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SessionUsageTotals {
+    /// Number of generations recorded.
+    pub generations: u64,
+    /// Total input/prompt tokens.
+    pub input_tokens: u64,
+    /// Total output/completion tokens.
+    pub output_tokens: u64,
+    /// Estimated cost in USD across priced generations.
+    pub estimated_cost: f64,
+    /// Number of generations whose provider/model has known pricing.
+    pub priced_generations: u64,
+}
+
+impl SessionUsageTotals {
+    /// Format a human-readable summary line.
+    ///
+    /// The cost is only shown when at least one generation had pricing
+    /// (local providers like Ollama have none).
+    pub fn format_summary(&self) -> String {
+        let mut line = format!(
+            "LLM usage this session: {} generations, {} input tokens, {} output tokens",
+            self.generations, self.input_tokens, self.output_tokens,
+        );
+        if self.priced_generations > 0 {
+            line.push_str(&format!(", est. cost ${:.4}", self.estimated_cost));
+        }
+        line.push('.');
+        line
+    }
+}
+
+/// Thread-safe accumulator shared by the LLM adapters of a runtime.
+#[derive(Debug, Default)]
+pub struct SessionUsage {
+    totals: Mutex<SessionUsageTotals>,
+}
+
+impl SessionUsage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add one generation's usage to the session totals.
+    pub fn record(&self, provider: &str, model: &str, usage: &TokenUsage) {
+        let pricing = pricing::lookup(provider, model);
+        let priced = pricing.input_per_million > 0.0 || pricing.output_per_million > 0.0;
+
+        let mut totals = self.totals.lock().unwrap_or_else(|e| e.into_inner());
+        totals.generations += 1;
+        totals.input_tokens += u64::from(usage.input_tokens);
+        totals.output_tokens += u64::from(usage.output_tokens);
+        if priced {
+            totals.priced_generations += 1;
+            totals.estimated_cost += pricing.compute_cost(usage.input_tokens, usage.output_tokens);
+        }
+    }
+
+    /// Current cumulative totals.
+    pub fn snapshot(&self) -> SessionUsageTotals {
+        self.totals
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(input_tokens: u32, output_tokens: u32) -> TokenUsage {
+        TokenUsage {
+            input_tokens,
+            output_tokens,
+        }
+    }
+
+    #[test]
+    fn accumulates_tokens_and_cost() {
+        let session = SessionUsage::new();
+        session.record("openai", "gpt-4o-mini", &usage(1_000_000, 0));
+        session.record("openai", "gpt-4o-mini", &usage(0, 1_000_000));
+        session.record("ollama", "llama3", &usage(500, 200));
+
+        let totals = session.snapshot();
+        assert_eq!(totals.generations, 3);
+        assert_eq!(totals.input_tokens, 1_000_500);
+        assert_eq!(totals.output_tokens, 1_000_200);
+        assert_eq!(totals.priced_generations, 2);
+        assert!((totals.estimated_cost - 0.75).abs() < 1e-9);
+    }
+
+    #[test]
+    fn summary_omits_cost_without_pricing() {
+        let session = SessionUsage::new();
+        session.record("ollama", "llama3", &usage(120, 40));
+
+        let summary = session.snapshot().format_summary();
+        assert_eq!(
+            summary,
+            "LLM usage this session: 1 generations, 120 input tokens, 40 output tokens."
+        );
+    }
+
+    #[test]
+    fn summary_includes_cost_when_priced() {
+        let session = SessionUsage::new();
+        session.record("openai", "gpt-4o", &usage(1000, 1000));
+
+        let summary = session.snapshot().format_summary();
+        assert!(summary.ends_with("est. cost $0.0125."), "{summary}");
+    }
+}
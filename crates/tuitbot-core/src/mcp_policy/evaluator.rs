@@ -5,13 +5,15 @@
 //! 2. Build effective rule set via `build_effective_rules()`
 //! 3. Walk rules by priority: first match → mapped PolicyDecision
 //! 4. Check per-dimension rate limits from `config.rate_limits`
-//! 5. Check legacy global rate limit (`mcp_mutation`)
+//! 5. Check the tool's own cap if listed in `config.tool_limits`,
+//!    otherwise the legacy global rate limit (`mcp_mutation`)
 //! 6. Default → Allow
 
 use serde::Serialize;
 
 use crate::config::{McpPolicyConfig, OperatingMode};
 use crate::error::StorageError;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
 use crate::storage::rate_limits;
 use crate::storage::DbPool;
 
@@ -48,6 +50,19 @@ pub enum PolicyDenialReason {
     HardRule,
     /// A user-defined rule denied the request.
     UserRule,
+    /// The tool's own hourly cap from `mcp_policy.tool_limits` has been exceeded.
+    ToolRateLimited {
+        tool: String,
+        /// ISO-8601 UTC timestamp when the tool's counter resets.
+        resets_at: Option<String>,
+    },
+    /// The daily cap for an engagement action (`limits.max_likes_per_day`,
+    /// `limits.max_retweets_per_day`) has been reached.
+    DailyCapReached {
+        action: String,
+        /// ISO-8601 UTC timestamp when the action's counter resets.
+        resets_at: Option<String>,
+    },
 }
 
 impl std::fmt::Display for PolicyDenialReason {
@@ -57,6 +72,14 @@ impl std::fmt::Display for PolicyDenialReason {
             PolicyDenialReason::RateLimited => write!(f, "rate_limited"),
             PolicyDenialReason::HardRule => write!(f, "hard_rule"),
             PolicyDenialReason::UserRule => write!(f, "user_rule"),
+            PolicyDenialReason::ToolRateLimited { tool, resets_at } => match resets_at {
+                Some(at) => write!(f, "tool_rate_limited: {tool} (resets at {at})"),
+                None => write!(f, "tool_rate_limited: {tool}"),
+            },
+            PolicyDenialReason::DailyCapReached { action, resets_at } => match resets_at {
+                Some(at) => write!(f, "daily_cap_reached: {action} (resets at {at})"),
+                None => write!(f, "daily_cap_reached: {action}"),
+            },
         }
     }
 }
@@ -76,12 +99,24 @@ pub struct PolicyAuditRecord {
 pub struct McpPolicyEvaluator;
 
 impl McpPolicyEvaluator {
-    /// Evaluate whether a tool invocation should proceed.
+    /// Evaluate whether a tool invocation should proceed for the default account.
+    pub async fn evaluate(
+        pool: &DbPool,
+        config: &McpPolicyConfig,
+        mode: &OperatingMode,
+        tool_name: &str,
+    ) -> Result<PolicyDecision, StorageError> {
+        Self::evaluate_for(pool, DEFAULT_ACCOUNT_ID, config, mode, tool_name).await
+    }
+
+    /// Evaluate whether a tool invocation should proceed, checking the rate
+    /// limits of `account_id`.
     ///
     /// Accepts `params_json` for future keyword/author matching;
     /// currently unused but threaded through for v2 extensibility.
-    pub async fn evaluate(
+    pub async fn evaluate_for(
         pool: &DbPool,
+        account_id: &str,
         config: &McpPolicyConfig,
         mode: &OperatingMode,
         tool_name: &str,
@@ -128,8 +163,9 @@ impl McpPolicyEvaluator {
         }
 
         // 4. Check per-dimension rate limits
-        if let Some(exceeded_key) = rate_limits::check_policy_rate_limits(
+        if let Some(exceeded_key) = rate_limits::check_policy_rate_limits_for(
             pool,
+            account_id,
             tool_name,
             &ctx.category.to_string(),
             &config.rate_limits,
@@ -142,8 +178,26 @@ impl McpPolicyEvaluator {
             });
         }
 
-        // 5. Legacy global rate limit
-        let allowed = rate_limits::check_rate_limit(pool, "mcp_mutation").await?;
+        // 5. Per-tool cap for listed tools; everything else uses the global cap
+        if config.tool_limits.contains_key(tool_name) {
+            let key = rate_limits::mcp_tool_limit_key(tool_name);
+            if !rate_limits::check_rate_limit_for(pool, account_id, &key).await? {
+                let resets_at = rate_limits::get_rate_limit_for(pool, account_id, &key)
+                    .await?
+                    .and_then(|rl| rl.resets_at())
+                    .map(|t| t.format("%Y-%m-%dT%H:%M:%SZ").to_string());
+                return Ok(PolicyDecision::Deny {
+                    reason: PolicyDenialReason::ToolRateLimited {
+                        tool: tool_name.to_string(),
+                        resets_at,
+                    },
+                    rule_id: Some(key),
+                });
+            }
+            return Ok(PolicyDecision::Allow);
+        }
+
+        let allowed = rate_limits::check_rate_limit_for(pool, account_id, "mcp_mutation").await?;
         if !allowed {
             return Ok(PolicyDecision::Deny {
                 reason: PolicyDenialReason::RateLimited,
@@ -155,11 +209,21 @@ impl McpPolicyEvaluator {
         Ok(PolicyDecision::Allow)
     }
 
-    /// Log a policy decision to the action log.
+    /// Log a policy decision to the default account's action log.
     pub async fn log_decision(
         pool: &DbPool,
         tool_name: &str,
         decision: &PolicyDecision,
+    ) -> Result<(), StorageError> {
+        Self::log_decision_for(pool, DEFAULT_ACCOUNT_ID, tool_name, decision).await
+    }
+
+    /// Log a policy decision to `account_id`'s action log.
+    pub async fn log_decision_for(
+        pool: &DbPool,
+        account_id: &str,
+        tool_name: &str,
+        decision: &PolicyDecision,
     ) -> Result<(), StorageError> {
         let (status, reason_str, rule_id) = match decision {
             PolicyDecision::Allow => ("allowed", None, None),
@@ -186,8 +250,9 @@ impl McpPolicyEvaluator {
 
         let metadata = serde_json::to_string(&audit).ok();
 
-        crate::storage::action_log::log_action(
+        crate::storage::action_log::log_action_for(
             pool,
+            account_id,
             "mcp_policy",
             status,
             Some(tool_name),
@@ -196,21 +261,78 @@ impl McpPolicyEvaluator {
         .await
     }
 
-    /// Record a successful mutation against rate limit counters.
+    /// Record a successful mutation against the default account's counters.
+    pub async fn record_mutation(
+        pool: &DbPool,
+        tool_name: &str,
+        rate_limit_configs: &[super::types::PolicyRateLimit],
+    ) -> Result<(), StorageError> {
+        Self::record_mutation_for(pool, DEFAULT_ACCOUNT_ID, tool_name, rate_limit_configs).await
+    }
+
+    /// Record a successful mutation against `account_id`'s rate limit counters.
     ///
-    /// Increments both the legacy global counter and any applicable
+    /// Increments the legacy global counter, the tool's own counter (a no-op
+    /// unless the tool is listed in `tool_limits`), and any applicable
     /// per-dimension counters.
-    pub async fn record_mutation(
+    pub async fn record_mutation_for(
         pool: &DbPool,
+        account_id: &str,
         tool_name: &str,
         rate_limit_configs: &[super::types::PolicyRateLimit],
     ) -> Result<(), StorageError> {
         // Legacy global counter
-        rate_limits::increment_rate_limit(pool, "mcp_mutation").await?;
+        rate_limits::increment_rate_limit_for(pool, account_id, "mcp_mutation").await?;
+
+        // Per-tool counter (row only exists for tools in `tool_limits`)
+        let key = rate_limits::mcp_tool_limit_key(tool_name);
+        rate_limits::increment_rate_limit_for(pool, account_id, &key).await?;
 
         // Per-dimension counters
         let category = tool_category(tool_name).to_string();
-        rate_limits::record_policy_rate_limits(pool, tool_name, &category, rate_limit_configs).await
+        rate_limits::record_policy_rate_limits_for(
+            pool,
+            account_id,
+            tool_name,
+            &category,
+            rate_limit_configs,
+        )
+        .await
+    }
+
+    /// Effective per-tool caps from `tool_limits` with the default account's usage.
+    pub async fn tool_limits_status(pool: &DbPool, config: &McpPolicyConfig) -> serde_json::Value {
+        Self::tool_limits_status_for(pool, DEFAULT_ACCOUNT_ID, config).await
+    }
+
+    /// Effective per-tool caps from `tool_limits` with `account_id`'s usage.
+    ///
+    /// Tools not listed there are governed by `max_mutations_per_hour`.
+    pub async fn tool_limits_status_for(
+        pool: &DbPool,
+        account_id: &str,
+        config: &McpPolicyConfig,
+    ) -> serde_json::Value {
+        let mut out = serde_json::Map::new();
+        for (tool, max) in &config.tool_limits {
+            let key = rate_limits::mcp_tool_limit_key(tool);
+            let row = rate_limits::get_rate_limit_for(pool, account_id, &key)
+                .await
+                .ok()
+                .flatten();
+            out.insert(
+                tool.clone(),
+                serde_json::json!({
+                    "max_per_hour": max,
+                    "used": row.as_ref().map_or(0, |rl| rl.request_count),
+                    "resets_at": row
+                        .as_ref()
+                        .and_then(|rl| rl.resets_at())
+                        .map(|t| t.format("%Y-%m-%dT%H:%M:%SZ").to_string()),
+                }),
+            );
+        }
+        serde_json::Value::Object(out)
     }
 }
 
@@ -240,6 +362,27 @@ mod tests {
         assert_eq!(PolicyDenialReason::UserRule.to_string(), "user_rule");
     }
 
+    #[test]
+    fn denial_reason_display_tool_rate_limited() {
+        let reason = PolicyDenialReason::ToolRateLimited {
+            tool: "post_tweet".to_string(),
+            resets_at: Some("2026-03-01T13:00:00Z".to_string()),
+        };
+        assert_eq!(
+            reason.to_string(),
+            "tool_rate_limited: post_tweet (resets at 2026-03-01T13:00:00Z)"
+        );
+    }
+
+    #[test]
+    fn denial_reason_display_daily_cap_reached() {
+        let reason = PolicyDenialReason::DailyCapReached {
+            action: "like".to_string(),
+            resets_at: None,
+        };
+        assert_eq!(reason.to_string(), "daily_cap_reached: like");
+    }
+
     // ── PolicyDecision equality / patterns ───────────────────────────────
 
     #[test]
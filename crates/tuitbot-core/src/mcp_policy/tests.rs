@@ -228,6 +228,37 @@ async fn record_mutation_increments_counter() {
     assert_eq!(mcp.request_count, 1);
 }
 
+#[tokio::test]
+async fn mutation_budget_is_per_account() {
+    let pool = storage::init_test_db().await.expect("init db");
+    for account in ["acct-a", "acct-b"] {
+        rate_limits::init_mcp_rate_limit_for(&pool, account, 1)
+            .await
+            .expect("init rate limit");
+    }
+    let config = no_approval_policy();
+
+    McpPolicyEvaluator::record_mutation_for(&pool, "acct-a", "post_tweet", &[])
+        .await
+        .expect("record");
+
+    let mode = OperatingMode::Autopilot;
+    let a = McpPolicyEvaluator::evaluate_for(&pool, "acct-a", &config, &mode, "post_tweet")
+        .await
+        .expect("evaluate a");
+    let b = McpPolicyEvaluator::evaluate_for(&pool, "acct-b", &config, &mode, "post_tweet")
+        .await
+        .expect("evaluate b");
+    assert!(matches!(
+        a,
+        PolicyDecision::Deny {
+            reason: PolicyDenialReason::RateLimited,
+            ..
+        }
+    ));
+    assert_eq!(b, PolicyDecision::Allow);
+}
+
 #[tokio::test]
 async fn blocked_takes_priority_over_dry_run() {
     let pool = storage::init_test_db().await.expect("init db");
@@ -807,3 +838,120 @@ async fn record_mutation_increments_per_dimension_counters() {
         .expect("per-tool row");
     assert_eq!(per_tool.request_count, 1);
 }
+
+// =========================================================================
+// Per-tool limit (`tool_limits`) tests
+// =========================================================================
+
+fn with_tool_limits(limits: &[(&str, u32)]) -> McpPolicyConfig {
+    McpPolicyConfig {
+        tool_limits: limits.iter().map(|(t, n)| (t.to_string(), *n)).collect(),
+        ..no_approval_policy()
+    }
+}
+
+#[tokio::test]
+async fn tool_limit_exhausted_denies_only_that_tool() {
+    let pool = storage::init_test_db().await.expect("init db");
+    rate_limits::init_mcp_rate_limit(&pool, 100)
+        .await
+        .expect("init rate limit");
+    let config = with_tool_limits(&[("post_tweet", 2), ("like_tweet", 5)]);
+    rate_limits::init_mcp_tool_limits(&pool, &config.tool_limits)
+        .await
+        .expect("init tool limits");
+
+    for _ in 0..2 {
+        let decision =
+            McpPolicyEvaluator::evaluate(&pool, &config, &OperatingMode::Autopilot, "post_tweet")
+                .await
+                .expect("evaluate");
+        assert_eq!(decision, PolicyDecision::Allow);
+        McpPolicyEvaluator::record_mutation(&pool, "post_tweet", &config.rate_limits)
+            .await
+            .expect("record");
+    }
+
+    let decision =
+        McpPolicyEvaluator::evaluate(&pool, &config, &OperatingMode::Autopilot, "post_tweet")
+            .await
+            .expect("evaluate");
+    match decision {
+        PolicyDecision::Deny {
+            reason: PolicyDenialReason::ToolRateLimited { tool, resets_at },
+            rule_id,
+        } => {
+            assert_eq!(tool, "post_tweet");
+            assert!(resets_at.is_some());
+            assert_eq!(rule_id.as_deref(), Some("mcp_tool:post_tweet"));
+        }
+        other => panic!("expected tool rate limit denial, got {other:?}"),
+    }
+
+    // like_tweet has its own budget, and unlisted tools use the global cap.
+    for tool in ["like_tweet", "follow_user"] {
+        let decision =
+            McpPolicyEvaluator::evaluate(&pool, &config, &OperatingMode::Autopilot, tool)
+                .await
+                .expect("evaluate");
+        assert_eq!(decision, PolicyDecision::Allow, "{tool} should be allowed");
+    }
+}
+
+#[tokio::test]
+async fn listed_tool_uses_own_cap_instead_of_global() {
+    let pool = storage::init_test_db().await.expect("init db");
+    rate_limits::init_mcp_rate_limit(&pool, 1)
+        .await
+        .expect("init rate limit");
+    let config = with_tool_limits(&[("like_tweet", 10)]);
+    rate_limits::init_mcp_tool_limits(&pool, &config.tool_limits)
+        .await
+        .expect("init tool limits");
+
+    rate_limits::increment_rate_limit(&pool, "mcp_mutation")
+        .await
+        .expect("inc");
+
+    let listed =
+        McpPolicyEvaluator::evaluate(&pool, &config, &OperatingMode::Autopilot, "like_tweet")
+            .await
+            .expect("evaluate");
+    assert_eq!(listed, PolicyDecision::Allow);
+
+    let unlisted =
+        McpPolicyEvaluator::evaluate(&pool, &config, &OperatingMode::Autopilot, "follow_user")
+            .await
+            .expect("evaluate");
+    assert!(matches!(
+        unlisted,
+        PolicyDecision::Deny {
+            reason: PolicyDenialReason::RateLimited,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn init_tool_limits_updates_max_and_keeps_count() {
+    let pool = storage::init_test_db().await.expect("init db");
+    let mut config = with_tool_limits(&[("post_tweet", 3)]);
+    rate_limits::init_mcp_tool_limits(&pool, &config.tool_limits)
+        .await
+        .expect("init");
+    McpPolicyEvaluator::record_mutation(&pool, "post_tweet", &[])
+        .await
+        .expect("record");
+
+    config.tool_limits.insert("post_tweet".to_string(), 7);
+    rate_limits::init_mcp_tool_limits(&pool, &config.tool_limits)
+        .await
+        .expect("re-init");
+
+    let row = rate_limits::get_rate_limit(&pool, "mcp_tool:post_tweet")
+        .await
+        .expect("get")
+        .expect("row");
+    assert_eq!(row.max_requests, 7);
+    assert_eq!(row.request_count, 1);
+}
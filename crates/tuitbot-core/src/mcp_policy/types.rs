@@ -3,12 +3,13 @@
 //! Defines multi-dimensional rules, per-dimension rate limits, template names,
 //! and enriched audit records for the v2 policy engine.
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
 use crate::config::OperatingMode;
 
 /// Tool category for grouping related MCP tools.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ToolCategory {
     Read,
@@ -74,7 +75,7 @@ pub fn tool_category(name: &str) -> ToolCategory {
 }
 
 /// A policy rule with multi-dimensional conditions.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PolicyRule {
     /// Unique identifier for this rule.
     pub id: String,
@@ -93,7 +94,7 @@ pub struct PolicyRule {
 /// Conditions for a policy rule. AND across dimensions, OR within each dimension.
 ///
 /// Empty vectors mean "match any" for that dimension.
-#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
 pub struct RuleConditions {
     /// Tool names to match (OR). Empty = match all tools.
     #[serde(default)]
@@ -110,7 +111,7 @@ pub struct RuleConditions {
 }
 
 /// A time-based schedule window for rule conditions.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct ScheduleWindow {
     /// Start hour (0-23).
     pub start_hour: u8,
@@ -129,7 +130,7 @@ fn default_timezone() -> String {
 }
 
 /// Action to take when a policy rule matches.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "type", rename_all = "snake_case")]
 pub enum PolicyAction {
     /// Allow the mutation to proceed.
@@ -143,7 +144,7 @@ pub enum PolicyAction {
 }
 
 /// Per-dimension rate limit configuration.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
 pub struct PolicyRateLimit {
     /// Composite key, e.g. "mcp:like_tweet:hourly".
     pub key: String,
@@ -158,7 +159,7 @@ pub struct PolicyRateLimit {
 }
 
 /// Dimension for per-dimension rate limiting.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum RateLimitDimension {
     /// Rate limit per individual tool.
@@ -172,7 +173,7 @@ pub enum RateLimitDimension {
 }
 
 /// Named policy template.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum PolicyTemplateName {
     SafeDefault,
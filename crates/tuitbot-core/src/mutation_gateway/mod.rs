@@ -5,9 +5,12 @@
 //!
 //! The gateway enforces a strict sequence:
 //! 1. **Policy evaluation** — block rules, rate limits, approval routing, dry-run
-//! 2. **Idempotency** — DB-backed dedup within a 5-minute window
-//! 3. **Audit record** — pending entry before execution
-//! 4. **Post-execution recording** — rate-limit increment + audit completion
+//! 2. **Engagement caps** — daily like/retweet limits per account, enforced
+//!    even when `mcp_policy.enforce_for_mutations` is off. The slot is taken
+//!    atomically here and given back if the mutation does not happen.
+//! 3. **Idempotency** — DB-backed dedup within a 5-minute window
+//! 4. **Audit record** — pending entry before execution
+//! 5. **Post-execution recording** — policy rate-limit increment + audit completion
 //!
 //! This single path replaces the scattered policy/idempotency/audit logic
 //! that was previously duplicated across MCP tool handlers.
@@ -19,8 +22,8 @@ use crate::config::{McpPolicyConfig, OperatingMode};
 use crate::error::StorageError;
 use crate::mcp_policy::types::PolicyRateLimit;
 use crate::mcp_policy::{McpPolicyEvaluator, PolicyDecision, PolicyDenialReason};
-use crate::storage::mutation_audit;
 use crate::storage::DbPool;
+use crate::storage::{mutation_audit, rate_limits};
 
 /// DB-backed idempotency window in seconds (5 minutes).
 const IDEMPOTENCY_WINDOW_SECS: u32 = 300;
@@ -34,6 +37,8 @@ pub struct MutationGateway;
 /// Input parameters for a gateway evaluation.
 pub struct MutationRequest<'a> {
     pub pool: &'a DbPool,
+    /// Account whose daily engagement caps the mutation counts against.
+    pub account_id: &'a str,
     pub policy_config: &'a McpPolicyConfig,
     pub mode: &'a OperatingMode,
     pub tool_name: &'a str,
@@ -89,6 +94,8 @@ pub struct MutationTicket {
     pub audit_id: i64,
     pub correlation_id: String,
     pub tool_name: String,
+    /// Account holding the engagement cap slot taken for this mutation.
+    pub account_id: String,
 }
 
 impl MutationGateway {
@@ -96,19 +103,31 @@ impl MutationGateway {
     ///
     /// Sequence:
     /// 1. Policy evaluation (block rules, rate limits, approval routing)
-    /// 2. DB-backed idempotency check (5-minute window)
-    /// 3. Pending audit record creation
+    /// 2. Daily like/retweet caps
+    /// 3. DB-backed idempotency check (5-minute window)
+    /// 4. Pending audit record creation
     ///
     /// Returns a [`GatewayDecision`] indicating whether the mutation may
     /// proceed, was denied, routed to approval, or is a duplicate.
     pub async fn evaluate(req: &MutationRequest<'_>) -> Result<GatewayDecision, StorageError> {
         // ── Step 1: Policy evaluation ──────────────────────────────────
-        let decision =
-            McpPolicyEvaluator::evaluate(req.pool, req.policy_config, req.mode, req.tool_name)
-                .await?;
+        let decision = McpPolicyEvaluator::evaluate_for(
+            req.pool,
+            req.account_id,
+            req.policy_config,
+            req.mode,
+            req.tool_name,
+        )
+        .await?;
 
         // Log the policy decision (best-effort).
-        let _ = McpPolicyEvaluator::log_decision(req.pool, req.tool_name, &decision).await;
+        let _ = McpPolicyEvaluator::log_decision_for(
+            req.pool,
+            req.account_id,
+            req.tool_name,
+            &decision,
+        )
+        .await;
 
         match decision {
             PolicyDecision::Deny { reason, rule_id } => {
@@ -146,62 +165,52 @@ impl MutationGateway {
             PolicyDecision::DryRun { rule_id } => {
                 return Ok(GatewayDecision::DryRun { rule_id });
             }
-            PolicyDecision::Allow => { /* continue to idempotency check */ }
+            PolicyDecision::Allow => { /* continue to engagement caps */ }
         }
 
-        // ── Step 2: DB-backed idempotency (5-minute window) ────────────
-        let params_hash = mutation_audit::compute_params_hash(req.tool_name, req.params_json);
-        let params_summary = mutation_audit::truncate_summary(req.params_json, 500);
-
-        if let Some(existing) = mutation_audit::find_recent_duplicate(
-            req.pool,
-            req.tool_name,
-            &params_hash,
-            IDEMPOTENCY_WINDOW_SECS,
-        )
-        .await?
-        {
-            // Record the duplicate attempt in audit trail.
-            let dup_corr = generate_correlation_id();
-            let dup_id = mutation_audit::insert_pending(
-                req.pool,
-                &dup_corr,
-                None,
-                req.tool_name,
-                &params_hash,
-                &params_summary,
-            )
-            .await?;
-            let _ =
-                mutation_audit::mark_duplicate(req.pool, dup_id, &existing.correlation_id).await;
-
-            return Ok(GatewayDecision::Duplicate(DuplicateInfo {
-                original_correlation_id: existing.correlation_id,
-                cached_result: existing.result_summary,
-                audit_id: dup_id,
-            }));
+        // ── Step 2: Daily engagement caps ──────────────────────────────
+        // Check and take the slot in one transaction so concurrent calls
+        // cannot both pass the last slot.
+        let engagement = engagement_action(req.tool_name);
+        if let Some(action) = engagement {
+            if !rate_limits::check_and_increment_rate_limit_for(req.pool, req.account_id, action)
+                .await?
+            {
+                let resets_at = rate_limits::get_rate_limit_for(req.pool, req.account_id, action)
+                    .await?
+                    .and_then(|rl| rl.resets_at())
+                    .map(|t| t.format("%Y-%m-%dT%H:%M:%SZ").to_string());
+                return Ok(GatewayDecision::Denied(GatewayDenial {
+                    reason: PolicyDenialReason::DailyCapReached {
+                        action: action.to_string(),
+                        resets_at,
+                    },
+                    rule_id: Some(action.to_string()),
+                }));
+            }
         }
 
-        // ── Step 3: Create pending audit record ────────────────────────
-        let correlation_id = generate_correlation_id();
-        let audit_id = mutation_audit::insert_pending(
-            req.pool,
-            &correlation_id,
-            None,
-            req.tool_name,
-            &params_hash,
-            &params_summary,
-        )
-        .await?;
-
-        Ok(GatewayDecision::Proceed(MutationTicket {
-            audit_id,
-            correlation_id,
-            tool_name: req.tool_name.to_string(),
-        }))
+        // ── Steps 3-4: Idempotency and pending audit record ────────────
+        // Only `Proceed` keeps the engagement slot; a duplicate or a storage
+        // error gives it back.
+        let decision = dedupe_and_audit(req).await;
+        if let Some(action) = engagement {
+            if !matches!(decision, Ok(GatewayDecision::Proceed(_))) {
+                let released =
+                    rate_limits::release_rate_limit_for(req.pool, req.account_id, action).await;
+                if decision.is_ok() {
+                    released?;
+                } else if let Err(e) = released {
+                    tracing::warn!(error = %e, action, "Failed to give back engagement slot");
+                }
+            }
+        }
+        decision
     }
 
-    /// Record a successful mutation: complete audit + increment rate counters.
+    /// Record a successful mutation: complete audit + increment policy rate
+    /// counters. The daily like/retweet slot was already taken in
+    /// [`MutationGateway::evaluate`].
     pub async fn complete_success(
         pool: &DbPool,
         ticket: &MutationTicket,
@@ -220,19 +229,90 @@ impl MutationGateway {
         )
         .await?;
 
-        McpPolicyEvaluator::record_mutation(pool, &ticket.tool_name, rate_limit_configs).await?;
-
-        Ok(())
+        McpPolicyEvaluator::record_mutation_for(
+            pool,
+            &ticket.account_id,
+            &ticket.tool_name,
+            rate_limit_configs,
+        )
+        .await
     }
 
-    /// Record a failed mutation in the audit trail.
+    /// Record a failed mutation in the audit trail and give back its daily
+    /// like/retweet slot.
     pub async fn complete_failure(
         pool: &DbPool,
         ticket: &MutationTicket,
         error_message: &str,
         elapsed_ms: u64,
     ) -> Result<(), StorageError> {
-        mutation_audit::complete_failure(pool, ticket.audit_id, error_message, elapsed_ms).await
+        mutation_audit::complete_failure(pool, ticket.audit_id, error_message, elapsed_ms).await?;
+        if let Some(action) = engagement_action(&ticket.tool_name) {
+            rate_limits::release_rate_limit_for(pool, &ticket.account_id, action).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Steps 3-4 of [`MutationGateway::evaluate`]: the idempotency check and
+/// the pending audit record.
+async fn dedupe_and_audit(req: &MutationRequest<'_>) -> Result<GatewayDecision, StorageError> {
+    let params_hash = mutation_audit::compute_params_hash(req.tool_name, req.params_json);
+    let params_summary = mutation_audit::truncate_summary(req.params_json, 500);
+
+    if let Some(existing) = mutation_audit::find_recent_duplicate(
+        req.pool,
+        req.tool_name,
+        &params_hash,
+        IDEMPOTENCY_WINDOW_SECS,
+    )
+    .await?
+    {
+        // Record the duplicate attempt in audit trail.
+        let dup_corr = generate_correlation_id();
+        let dup_id = mutation_audit::insert_pending(
+            req.pool,
+            &dup_corr,
+            None,
+            req.tool_name,
+            &params_hash,
+            &params_summary,
+        )
+        .await?;
+        let _ = mutation_audit::mark_duplicate(req.pool, dup_id, &existing.correlation_id).await;
+
+        return Ok(GatewayDecision::Duplicate(DuplicateInfo {
+            original_correlation_id: existing.correlation_id,
+            cached_result: existing.result_summary,
+            audit_id: dup_id,
+        }));
+    }
+
+    let correlation_id = generate_correlation_id();
+    let audit_id = mutation_audit::insert_pending(
+        req.pool,
+        &correlation_id,
+        None,
+        req.tool_name,
+        &params_hash,
+        &params_summary,
+    )
+    .await?;
+
+    Ok(GatewayDecision::Proceed(MutationTicket {
+        audit_id,
+        correlation_id,
+        tool_name: req.tool_name.to_string(),
+        account_id: req.account_id.to_string(),
+    }))
+}
+
+/// Rate limit action type for tools counted against a daily engagement cap.
+fn engagement_action(tool_name: &str) -> Option<&'static str> {
+    match tool_name {
+        "like_tweet" => Some("like"),
+        "retweet" => Some("retweet"),
+        _ => None,
     }
 }
 
@@ -1,14 +1,15 @@
 //! Tests for the unified mutation gateway.
 //!
 //! Covers: allowed, blocked (tool blocked, hard rule), rate-limited,
-//! approval routing, dry-run, idempotency (duplicate), and post-execution
-//! recording.
+//! daily engagement caps, approval routing, dry-run, idempotency
+//! (duplicate), and post-execution recording.
 
 use super::*;
-use crate::config::{McpPolicyConfig, OperatingMode};
+use crate::config::{IntervalsConfig, LimitsConfig, McpPolicyConfig, OperatingMode};
 use crate::mcp_policy::types::{
     PolicyAction, PolicyRateLimit, PolicyRule, RateLimitDimension, RuleConditions,
 };
+use crate::storage::accounts::{self, DEFAULT_ACCOUNT_ID};
 use crate::storage::{init_test_db, rate_limits};
 
 fn default_policy_config() -> McpPolicyConfig {
@@ -21,6 +22,7 @@ fn default_policy_config() -> McpPolicyConfig {
         template: None,
         rules: vec![],
         rate_limits: vec![],
+        tool_limits: Default::default(),
     }
 }
 
@@ -33,6 +35,7 @@ fn make_request<'a>(
 ) -> MutationRequest<'a> {
     MutationRequest {
         pool,
+        account_id: DEFAULT_ACCOUNT_ID,
         policy_config: config,
         mode,
         tool_name,
@@ -444,6 +447,198 @@ async fn gateway_routes_delete_to_approval() {
     }
 }
 
+// ── Daily engagement caps ──────────────────────────────────────────────
+
+#[tokio::test]
+async fn gateway_counts_likes_and_denies_at_daily_cap() {
+    let pool = init_test_db().await.expect("init db");
+    let limits = LimitsConfig {
+        max_likes_per_day: 2,
+        ..Default::default()
+    };
+    rate_limits::init_rate_limits(&pool, &limits, &IntervalsConfig::default())
+        .await
+        .expect("init rl");
+    // The cap applies even with MCP policy enforcement switched off.
+    let mut config = default_policy_config();
+    config.enforce_for_mutations = false;
+    let mode = OperatingMode::Autopilot;
+
+    for tweet_id in ["1", "2"] {
+        let params = format!(r#"{{"tweet_id":"{tweet_id}"}}"#);
+        let req = make_request(&pool, &config, &mode, "like_tweet", &params);
+        let ticket = match MutationGateway::evaluate(&req).await.expect("eval") {
+            GatewayDecision::Proceed(t) => t,
+            other => panic!("expected Proceed, got {other:?}"),
+        };
+        MutationGateway::complete_success(&pool, &ticket, "{}", None, 5, &config.rate_limits)
+            .await
+            .expect("complete");
+    }
+
+    let req = make_request(&pool, &config, &mode, "like_tweet", r#"{"tweet_id":"3"}"#);
+    match MutationGateway::evaluate(&req).await.expect("eval") {
+        GatewayDecision::Denied(denial) => {
+            assert!(matches!(
+                denial.reason,
+                PolicyDenialReason::DailyCapReached { ref action, resets_at: Some(_) }
+                    if action == "like"
+            ));
+        }
+        other => panic!("expected Denied(DailyCapReached), got {other:?}"),
+    }
+
+    // Retweets have their own counter.
+    let req = make_request(&pool, &config, &mode, "retweet", r#"{"tweet_id":"3"}"#);
+    assert!(matches!(
+        MutationGateway::evaluate(&req).await.expect("eval"),
+        GatewayDecision::Proceed(_)
+    ));
+}
+
+#[tokio::test]
+async fn gateway_zero_retweet_cap_blocks_retweets() {
+    let pool = init_test_db().await.expect("init db");
+    let limits = LimitsConfig {
+        max_retweets_per_day: 0,
+        ..Default::default()
+    };
+    rate_limits::init_rate_limits(&pool, &limits, &IntervalsConfig::default())
+        .await
+        .expect("init rl");
+    let mut config = default_policy_config();
+    config.enforce_for_mutations = false;
+    let mode = OperatingMode::Autopilot;
+
+    let req = make_request(&pool, &config, &mode, "retweet", r#"{"tweet_id":"1"}"#);
+    assert!(matches!(
+        MutationGateway::evaluate(&req).await.expect("eval"),
+        GatewayDecision::Denied(GatewayDenial {
+            reason: PolicyDenialReason::DailyCapReached { .. },
+            ..
+        })
+    ));
+
+    // Undoing a retweet is not capped.
+    let req = make_request(&pool, &config, &mode, "unretweet", r#"{"tweet_id":"1"}"#);
+    assert!(matches!(
+        MutationGateway::evaluate(&req).await.expect("eval"),
+        GatewayDecision::Proceed(_)
+    ));
+}
+
+/// Pool with a daily like cap of 1 and policy enforcement off.
+async fn one_like_per_day() -> (DbPool, McpPolicyConfig) {
+    let pool = init_test_db().await.expect("init db");
+    let limits = LimitsConfig {
+        max_likes_per_day: 1,
+        ..Default::default()
+    };
+    rate_limits::init_rate_limits(&pool, &limits, &IntervalsConfig::default())
+        .await
+        .expect("init rl");
+    let mut config = default_policy_config();
+    config.enforce_for_mutations = false;
+    (pool, config)
+}
+
+#[tokio::test]
+async fn gateway_concurrent_likes_take_the_last_slot_once() {
+    let (pool, config) = one_like_per_day().await;
+    let mode = OperatingMode::Autopilot;
+
+    let first = make_request(&pool, &config, &mode, "like_tweet", r#"{"tweet_id":"1"}"#);
+    let second = make_request(&pool, &config, &mode, "like_tweet", r#"{"tweet_id":"2"}"#);
+    let (a, b) = tokio::join!(
+        MutationGateway::evaluate(&first),
+        MutationGateway::evaluate(&second)
+    );
+
+    let proceeded = [a.expect("eval"), b.expect("eval")]
+        .iter()
+        .filter(|d| matches!(d, GatewayDecision::Proceed(_)))
+        .count();
+    assert_eq!(proceeded, 1);
+}
+
+#[tokio::test]
+async fn gateway_failed_like_gives_back_its_slot() {
+    let (pool, config) = one_like_per_day().await;
+    let mode = OperatingMode::Autopilot;
+
+    let req = make_request(&pool, &config, &mode, "like_tweet", r#"{"tweet_id":"1"}"#);
+    let ticket = match MutationGateway::evaluate(&req).await.expect("eval") {
+        GatewayDecision::Proceed(t) => t,
+        other => panic!("expected Proceed, got {other:?}"),
+    };
+    MutationGateway::complete_failure(&pool, &ticket, "X API error", 5)
+        .await
+        .expect("complete");
+
+    let req = make_request(&pool, &config, &mode, "like_tweet", r#"{"tweet_id":"2"}"#);
+    assert!(matches!(
+        MutationGateway::evaluate(&req).await.expect("eval"),
+        GatewayDecision::Proceed(_)
+    ));
+}
+
+#[tokio::test]
+async fn gateway_audit_failure_gives_back_the_like_slot() {
+    let (pool, config) = one_like_per_day().await;
+    let mode = OperatingMode::Autopilot;
+    sqlx::query("DROP TABLE mutation_audit")
+        .execute(&pool)
+        .await
+        .expect("drop");
+
+    let req = make_request(&pool, &config, &mode, "like_tweet", r#"{"tweet_id":"1"}"#);
+    assert!(MutationGateway::evaluate(&req).await.is_err());
+
+    let like = rate_limits::get_rate_limit_for(&pool, DEFAULT_ACCOUNT_ID, "like")
+        .await
+        .expect("read")
+        .expect("like limit");
+    assert_eq!(like.request_count, 0);
+}
+
+#[tokio::test]
+async fn gateway_daily_caps_are_per_account() {
+    let (pool, config) = one_like_per_day().await;
+    let mode = OperatingMode::Autopilot;
+    let other = uuid::Uuid::new_v4().to_string();
+    accounts::create_account(&pool, &other, "Other")
+        .await
+        .expect("create");
+    let limits = LimitsConfig {
+        max_likes_per_day: 1,
+        ..Default::default()
+    };
+    rate_limits::init_rate_limits_for(&pool, &other, &limits, &IntervalsConfig::default())
+        .await
+        .expect("init rl");
+
+    let mut req = make_request(&pool, &config, &mode, "like_tweet", r#"{"tweet_id":"1"}"#);
+    req.account_id = &other;
+    let ticket = match MutationGateway::evaluate(&req).await.expect("eval") {
+        GatewayDecision::Proceed(t) => t,
+        other => panic!("expected Proceed, got {other:?}"),
+    };
+    assert_eq!(ticket.account_id, other);
+    let mut req = make_request(&pool, &config, &mode, "like_tweet", r#"{"tweet_id":"2"}"#);
+    req.account_id = &other;
+    assert!(matches!(
+        MutationGateway::evaluate(&req).await.expect("eval"),
+        GatewayDecision::Denied(_)
+    ));
+
+    // The default account still has its own slot.
+    let req = make_request(&pool, &config, &mode, "like_tweet", r#"{"tweet_id":"3"}"#);
+    assert!(matches!(
+        MutationGateway::evaluate(&req).await.expect("eval"),
+        GatewayDecision::Proceed(_)
+    ));
+}
+
 // ── Correlation ID format ──────────────────────────────────────────────
 
 #[test]
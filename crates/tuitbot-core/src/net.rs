@@ -1,8 +1,47 @@
 //! Network utility helpers.
 
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+
+use crate::config::HttpTimeoutConfig;
+
 /// Detect a local non-loopback IPv4 address for LAN display.
 pub fn local_ip() -> Option<String> {
     let socket = std::net::UdpSocket::bind("0.0.0.0:0").ok()?;
     socket.connect("8.8.8.8:80").ok()?;
     Some(socket.local_addr().ok()?.ip().to_string())
 }
+
+/// Build a reqwest client that enforces the given connect and request timeouts.
+pub fn http_client(timeouts: &HttpTimeoutConfig) -> reqwest::Client {
+    reqwest::Client::builder()
+        .connect_timeout(Duration::from_millis(timeouts.connect_timeout_ms))
+        .timeout(Duration::from_millis(timeouts.request_timeout_ms))
+        .build()
+        .unwrap_or_else(|e| {
+            tracing::warn!(error = %e, "Failed to build HTTP client with timeouts; using defaults");
+            reqwest::Client::new()
+        })
+}
+
+/// Convert configured `name = value` headers into a `HeaderMap`.
+///
+/// Entries that are not valid HTTP header names or values are logged and
+/// skipped; config validation reports them up front.
+pub fn header_map(headers: &BTreeMap<String, String>) -> HeaderMap {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        match (
+            HeaderName::from_bytes(name.as_bytes()),
+            HeaderValue::from_str(value),
+        ) {
+            (Ok(name), Ok(value)) => {
+                map.insert(name, value);
+            }
+            _ => tracing::warn!(header = %name, "Skipping invalid HTTP header"),
+        }
+    }
+    map
+}
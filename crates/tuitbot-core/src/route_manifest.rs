@@ -0,0 +1,250 @@
+//! Machine-readable listing of the dashboard server's HTTP API routes.
+//!
+//! The table lives in core so tools that only describe the API, such as
+//! `tuitbot export-manifest`, do not have to link the server. The server's
+//! tests check that the routes it registers match this table exactly.
+
+use serde::Serialize;
+
+/// Prefix the API router is nested under.
+pub const API_PREFIX: &str = "/api";
+
+/// Every API path, relative to [`API_PREFIX`], with the upper-case methods
+/// it accepts, in registration order.
+pub const API_ROUTES: &[(&str, &[&str])] = &[
+    ("/health", &["GET"]),
+    ("/health/detailed", &["GET"]),
+    // Auth
+    ("/auth/login", &["POST"]),
+    ("/auth/logout", &["POST"]),
+    ("/auth/status", &["GET"]),
+    // Analytics
+    ("/analytics/summary", &["GET"]),
+    ("/analytics/followers", &["GET"]),
+    ("/analytics/followers/growth", &["GET"]),
+    ("/analytics/performance", &["GET"]),
+    ("/analytics/topics", &["GET"]),
+    ("/analytics/topics/performance", &["GET"]),
+    ("/analytics/recent-performance", &["GET"]),
+    ("/analytics/engagement-rate", &["GET"]),
+    ("/analytics/reach", &["GET"]),
+    ("/analytics/follower-growth", &["GET"]),
+    ("/analytics/best-times", &["GET"]),
+    ("/analytics/heatmap", &["GET"]),
+    ("/analytics/content-breakdown", &["GET"]),
+    ("/analytics/frameworks", &["GET"]),
+    // Approval
+    ("/approval/export", &["GET"]),
+    ("/approval", &["GET"]),
+    ("/approval/stats", &["GET"]),
+    ("/approval/approve-all", &["POST"]),
+    ("/approval/bulk/approve", &["POST"]),
+    ("/approval/bulk/reject", &["POST"]),
+    ("/approval/{id}/history", &["GET"]),
+    ("/approval/{id}/preview", &["GET"]),
+    ("/approval/{id}", &["PATCH"]),
+    ("/approval/{id}/approve", &["POST"]),
+    ("/approval/{id}/reject", &["POST"]),
+    // Activity
+    ("/activity/export", &["GET"]),
+    ("/activity", &["GET"]),
+    ("/activity/stream", &["GET"]),
+    ("/activity/rate-limits", &["GET"]),
+    // Replies
+    ("/replies", &["GET"]),
+    // Content
+    ("/content/tweets", &["GET", "POST"]),
+    ("/content/threads", &["GET", "POST"]),
+    ("/content/calendar", &["GET"]),
+    ("/content/calendar.ics", &["GET"]),
+    ("/content/schedule", &["GET"]),
+    ("/content/preview-schedule", &["GET"]),
+    ("/content/compose", &["POST"]),
+    ("/content/scheduled/{id}", &["PATCH", "DELETE"]),
+    // Draft Studio — Tags (literal paths before parameterized)
+    ("/tags", &["GET", "POST"]),
+    // Draft Studio (new canonical paths)
+    ("/drafts", &["GET", "POST"]),
+    ("/drafts/{id}", &["GET", "PATCH", "DELETE"]),
+    ("/drafts/{id}/meta", &["PATCH"]),
+    ("/drafts/{id}/schedule", &["POST"]),
+    ("/drafts/{id}/reschedule", &["PATCH"]),
+    ("/drafts/{id}/unschedule", &["POST"]),
+    ("/drafts/{id}/archive", &["POST"]),
+    ("/drafts/{id}/restore", &["POST"]),
+    ("/drafts/{id}/duplicate", &["POST"]),
+    ("/drafts/{id}/revisions", &["GET", "POST"]),
+    ("/drafts/{id}/revisions/{rev_id}/restore", &["POST"]),
+    ("/drafts/{id}/activity", &["GET"]),
+    ("/drafts/{id}/provenance", &["GET"]),
+    ("/drafts/{id}/tags", &["GET"]),
+    ("/drafts/{id}/tags/{tag_id}", &["POST", "DELETE"]),
+    // Legacy drafts (backward compat)
+    ("/content/drafts", &["GET", "POST"]),
+    ("/content/drafts/{id}", &["PATCH", "DELETE"]),
+    ("/content/drafts/{id}/schedule", &["POST"]),
+    ("/content/drafts/{id}/publish", &["POST"]),
+    ("/content/drafts/{id}/provenance", &["GET"]),
+    ("/content/drafts/{id}/score", &["GET"]),
+    // Ingest
+    ("/ingest", &["POST"]),
+    // Sources
+    ("/sources/status", &["GET"]),
+    ("/sources/{id}/reindex", &["POST"]),
+    // Targets
+    ("/targets", &["GET", "POST"]),
+    ("/targets/{username}/timeline", &["GET"]),
+    ("/targets/{username}/stats", &["GET"]),
+    ("/targets/{username}/score-preview", &["GET"]),
+    ("/targets/{username}", &["DELETE"]),
+    // Strategy
+    ("/strategy/current", &["GET"]),
+    ("/strategy/history", &["GET"]),
+    ("/strategy/history/{id}/inputs", &["GET"]),
+    ("/strategy/refresh", &["POST"]),
+    ("/strategy/inputs", &["GET"]),
+    ("/strategy/recommendations", &["GET"]),
+    // Costs — LLM
+    ("/costs/summary", &["GET"]),
+    ("/costs/daily", &["GET"]),
+    ("/costs/by-model", &["GET"]),
+    ("/costs/by-type", &["GET"]),
+    // Costs — X API
+    ("/costs/x-api/summary", &["GET"]),
+    ("/costs/x-api/daily", &["GET"]),
+    ("/costs/x-api/by-endpoint", &["GET"]),
+    // AI Assist
+    ("/assist/tweet", &["POST"]),
+    ("/assist/reply", &["POST"]),
+    ("/assist/thread", &["POST"]),
+    ("/assist/improve", &["POST"]),
+    ("/assist/quote", &["POST"]),
+    ("/assist/highlights", &["POST"]),
+    ("/assist/hooks", &["POST"]),
+    ("/assist/angles", &["POST"]),
+    ("/assist/topics", &["GET"]),
+    ("/assist/optimal-times", &["GET"]),
+    ("/assist/mode", &["GET"]),
+    // Vault
+    ("/vault/evidence", &["GET"]),
+    ("/vault/index-status", &["GET"]),
+    ("/vault/sources", &["GET"]),
+    ("/vault/notes", &["GET"]),
+    ("/vault/notes/{id}/neighbors", &["GET"]),
+    ("/vault/notes/{id}", &["GET"]),
+    ("/vault/search", &["GET"]),
+    ("/vault/resolve-refs", &["POST"]),
+    ("/vault/send-selection", &["POST"]),
+    ("/vault/selection/{session_id}", &["GET"]),
+    // Discovery feed
+    ("/discovery/feed", &["GET"]),
+    ("/discovery/keywords", &["GET"]),
+    ("/discovery/keywords/suggestions", &["GET"]),
+    ("/discovery/{tweet_id}/compose-reply", &["POST"]),
+    ("/discovery/{tweet_id}/queue-reply", &["POST"]),
+    // Media
+    ("/media/upload", &["POST"]),
+    ("/media/file", &["GET"]),
+    // LAN settings
+    ("/settings/lan", &["GET", "PATCH"]),
+    ("/settings/lan/reset-passphrase", &["POST"]),
+    // Settings
+    ("/settings/status", &["GET"]),
+    ("/settings/init", &["POST"]),
+    ("/settings/validate", &["POST"]),
+    ("/settings/defaults", &["GET"]),
+    ("/settings/schema", &["GET"]),
+    ("/settings/test-llm", &["POST"]),
+    ("/settings/factory-reset", &["POST"]),
+    ("/settings/scraper-session", &["GET", "POST", "DELETE"]),
+    ("/settings", &["GET", "PATCH"]),
+    // Connectors
+    ("/connectors/google-drive/link", &["POST"]),
+    ("/connectors/google-drive/callback", &["GET"]),
+    ("/connectors/google-drive/status", &["GET"]),
+    ("/connectors/google-drive/{id}", &["DELETE"]),
+    // MCP governance
+    ("/mcp/policy", &["GET", "PATCH"]),
+    ("/mcp/policy/templates", &["GET"]),
+    ("/mcp/policy/templates/{name}", &["POST"]),
+    ("/mcp/telemetry/summary", &["GET"]),
+    ("/mcp/telemetry/metrics", &["GET"]),
+    ("/mcp/telemetry/errors", &["GET"]),
+    ("/mcp/telemetry/recent", &["GET"]),
+    ("/mcp/telemetry/export", &["GET"]),
+    // Runtime
+    ("/runtime/status", &["GET"]),
+    ("/runtime/start", &["POST"]),
+    ("/runtime/stop", &["POST"]),
+    ("/runtime/logs", &["GET"]),
+    // Onboarding OAuth (pre-account, auth-exempt)
+    ("/onboarding/x-auth/start", &["POST"]),
+    ("/onboarding/x-auth/callback", &["POST"]),
+    ("/onboarding/x-auth/status", &["GET"]),
+    ("/onboarding/analyze-profile", &["POST"]),
+    // Accounts
+    ("/accounts", &["GET", "POST"]),
+    ("/accounts/{id}/roles", &["GET", "POST", "DELETE"]),
+    ("/accounts/{id}/sync-profile", &["POST"]),
+    ("/accounts/{id}/switch", &["POST"]),
+    // X credential linking (before catch-all /accounts/{id})
+    ("/accounts/{id}/x-auth/start", &["POST"]),
+    ("/accounts/{id}/x-auth/callback", &["POST"]),
+    ("/accounts/{id}/x-auth/status", &["GET"]),
+    ("/accounts/{id}/x-auth/tokens", &["DELETE"]),
+    ("/accounts/{id}", &["GET", "PATCH", "DELETE"]),
+    // Telemetry
+    ("/telemetry/events", &["POST"]),
+    // WebSocket
+    ("/ws", &["GET"]),
+];
+
+/// One method + path pair served by the API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RouteEntry {
+    /// Upper-case HTTP method, e.g. `GET`.
+    pub method: String,
+    /// Full path including the `/api` prefix; path parameters use `{name}`.
+    pub path: String,
+}
+
+/// List every API route with the methods it accepts, in registration order.
+pub fn api_routes() -> Vec<RouteEntry> {
+    API_ROUTES
+        .iter()
+        .flat_map(|(path, methods)| {
+            methods.iter().map(move |method| RouteEntry {
+                method: method.to_string(),
+                path: format!("{API_PREFIX}{path}"),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn has(routes: &[RouteEntry], method: &str, path: &str) -> bool {
+        routes.iter().any(|r| r.method == method && r.path == path)
+    }
+
+    #[test]
+    fn lists_known_routes_with_methods() {
+        let routes = api_routes();
+        assert!(has(&routes, "GET", "/api/health"));
+        assert!(has(&routes, "POST", "/api/auth/login"));
+        assert!(has(&routes, "GET", "/api/accounts"));
+        assert!(has(&routes, "POST", "/api/accounts"));
+        assert!(has(&routes, "PATCH", "/api/accounts/{id}"));
+        assert!(has(&routes, "DELETE", "/api/accounts/{id}"));
+        assert!(has(&routes, "GET", "/api/ws"));
+    }
+
+    #[test]
+    fn every_route_has_a_method() {
+        for (path, methods) in API_ROUTES {
+            assert!(!methods.is_empty(), "{path} lists no methods");
+        }
+    }
+}
@@ -2,10 +2,17 @@
 //!
 //! Provides exact-match deduplication (never reply to the same tweet twice)
 //! and phrasing similarity detection (reject replies too similar to recent ones).
+//!
+//! Similarity runs on normalized text: URLs and @mentions are dropped and
+//! case, punctuation, and whitespace are folded, so replies that differ only
+//! in who they tag or where they link still count as duplicates. Stored
+//! replies keep their original text.
 
 use crate::error::StorageError;
 use crate::storage::DbPool;
+use regex::Regex;
 use std::collections::HashSet;
+use std::sync::OnceLock;
 
 /// Checks for duplicate and similar replies.
 pub struct DedupChecker {
@@ -27,8 +34,9 @@ impl DedupChecker {
 
     /// Check if a proposed reply is too similar to recent replies.
     ///
-    /// Compares against the last `limit` replies using Jaccard word similarity.
-    /// Returns `true` if any recent reply has >= 0.8 similarity or is an exact match.
+    /// Compares against the last `limit` replies using Jaccard word similarity
+    /// over [`normalize`]d text. Returns `true` if any recent reply has >= 0.8
+    /// similarity or is an exact match before or after normalization.
     /// Replies shorter than 5 words skip the similarity check (too short for meaningful comparison).
     pub async fn is_phrasing_similar(
         &self,
@@ -40,7 +48,8 @@ impl DedupChecker {
         }
 
         let recent = crate::storage::replies::get_recent_reply_contents(&self.pool, limit).await?;
-        let new_tokens = tokenize(new_reply);
+        let new_normalized = normalize(new_reply);
+        let new_tokens = tokenize(&new_normalized);
 
         for recent_reply in &recent {
             // Exact match check
@@ -48,12 +57,19 @@ impl DedupChecker {
                 return Ok(true);
             }
 
+            // Same words once links and mentions are gone. A reply that was
+            // nothing but links normalizes to "" and is not compared.
+            let recent_normalized = normalize(recent_reply);
+            if !new_normalized.is_empty() && new_normalized == recent_normalized {
+                return Ok(true);
+            }
+
             // Skip similarity check for very short replies
             if new_tokens.len() < 5 {
                 continue;
             }
 
-            let recent_tokens = tokenize(recent_reply);
+            let recent_tokens = tokenize(&recent_normalized);
             if jaccard_similarity(&new_tokens, &recent_tokens) >= 0.8 {
                 return Ok(true);
             }
@@ -68,6 +84,31 @@ impl DedupChecker {
     }
 }
 
+fn url_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    RE.get_or_init(|| Regex::new(r"(?i)\b(?:https?://|www\.)\S+").expect("valid url pattern"))
+}
+
+fn mention_regex() -> &'static Regex {
+    static RE: OnceLock<Regex> = OnceLock::new();
+    // An `@` not preceded by a word character, so emails are left alone.
+    RE.get_or_init(|| Regex::new(r"(^|[^\w@])@\w+").expect("valid mention pattern"))
+}
+
+/// Normalize reply text for similarity: drop URLs and @mentions, lowercase,
+/// trim punctuation from each word, and collapse whitespace.
+fn normalize(text: &str) -> String {
+    let without_urls = url_regex().replace_all(text, " ");
+    let without_mentions = mention_regex().replace_all(&without_urls, "$1 ");
+    without_mentions
+        .to_lowercase()
+        .split_whitespace()
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|w| !w.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
 /// Tokenize text into a set of lowercase alphanumeric words.
 fn tokenize(text: &str) -> HashSet<String> {
     text.to_lowercase()
@@ -107,6 +148,7 @@ mod tests {
             created_at: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
             status: "sent".to_string(),
             error_message: None,
+            conversation_id: None,
         }
     }
 
@@ -134,6 +176,17 @@ mod tests {
         assert!(tokens.is_empty());
     }
 
+    #[test]
+    fn normalize_drops_urls_mentions_and_whitespace() {
+        assert_eq!(
+            normalize("Great   tool @alice_dev, see https://example.com/a?b=1 !"),
+            "great tool see"
+        );
+        assert_eq!(normalize("via www.example.com and @bob"), "via and");
+        assert_eq!(normalize("mail me@example.com"), "mail me@example.com");
+        assert_eq!(normalize("https://only.link"), "");
+    }
+
     #[test]
     fn jaccard_identical_sets() {
         let a: HashSet<String> = ["hello", "world"].iter().map(|s| s.to_string()).collect();
@@ -227,6 +280,62 @@ mod tests {
             .expect("check"));
     }
 
+    #[tokio::test]
+    async fn is_phrasing_similar_ignores_url_and_mention_changes() {
+        let pool = init_test_db().await.expect("init db");
+        let checker = DedupChecker::new(pool.clone());
+
+        insert_reply(&pool, &sample_reply("t1", "great tool @a https://x.com/1"))
+            .await
+            .expect("insert");
+        insert_reply(
+            &pool,
+            &sample_reply(
+                "t2",
+                "We cut our deploy time in half with @acme, details at https://acme.dev/blog",
+            ),
+        )
+        .await
+        .expect("insert");
+
+        // Short reply: only the mention and link changed.
+        assert!(checker
+            .is_phrasing_similar("great tool @b https://y.io/2", 20)
+            .await
+            .expect("check"));
+        // Long reply: same words, different handle and link.
+        assert!(checker
+            .is_phrasing_similar(
+                "We cut our deploy time in half with @other — details at https://t.co/xyz",
+                20,
+            )
+            .await
+            .expect("check"));
+    }
+
+    #[tokio::test]
+    async fn is_phrasing_similar_keeps_genuine_differences() {
+        let pool = init_test_db().await.expect("init db");
+        let checker = DedupChecker::new(pool.clone());
+
+        insert_reply(&pool, &sample_reply("t1", "great tool @a https://x.com/1"))
+            .await
+            .expect("insert");
+        insert_reply(&pool, &sample_reply("t2", "https://x.com/only-a-link"))
+            .await
+            .expect("insert");
+
+        assert!(!checker
+            .is_phrasing_similar("terrible tool @a https://x.com/1", 20)
+            .await
+            .expect("check"));
+        // Link-only replies normalize to nothing and are not matched.
+        assert!(!checker
+            .is_phrasing_similar("https://y.com/another-link", 20)
+            .await
+            .expect("check"));
+    }
+
     #[tokio::test]
     async fn is_phrasing_similar_empty_string() {
         let pool = init_test_db().await.expect("init db");
@@ -0,0 +1,142 @@
+//! Collects [`DenialReason`]s raised while automation loops run.
+//!
+//! The safety adapters record every denial into a shared [`DenialLog`];
+//! callers such as `tuitbot tick` drain it after each loop and report the
+//! grouped [`DenialSummary`] list, so "why didn't it post?" has an answer
+//! beyond a skip count.
+
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+
+use super::DenialReason;
+
+/// Upper bound on buffered denials, so a long-running process that never
+/// drains the log doesn't grow it without limit.
+const MAX_BUFFERED_DENIALS: usize = 10_000;
+
+/// Shared, cloneable sink for safety denials.
+#[derive(Debug, Clone, Default)]
+pub struct DenialLog {
+    entries: Arc<Mutex<Vec<DenialReason>>>,
+}
+
+impl DenialLog {
+    /// Create an empty log.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record one denial. Dropped once the buffer is full.
+    pub fn record(&self, reason: DenialReason) {
+        let mut entries = self
+            .entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner());
+        if entries.len() < MAX_BUFFERED_DENIALS {
+            entries.push(reason);
+        }
+    }
+
+    /// Remove and return everything recorded so far.
+    pub fn take(&self) -> Vec<DenialReason> {
+        std::mem::take(
+            &mut *self
+                .entries
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner()),
+        )
+    }
+}
+
+/// Denials of one kind, aggregated.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct DenialSummary {
+    /// Stable reason identifier (see [`DenialReason::kind`]).
+    pub reason: &'static str,
+    /// How many times this reason was hit.
+    pub count: usize,
+    /// Whether the reason held back an intended action
+    /// (see [`DenialReason::is_blocking`]).
+    pub blocking: bool,
+    /// Distinct human-readable messages for this reason, `; `-separated.
+    pub detail: String,
+}
+
+/// Group denials by kind, in order of first occurrence.
+pub fn summarize(reasons: &[DenialReason]) -> Vec<DenialSummary> {
+    let mut summaries: Vec<(DenialSummary, Vec<String>)> = Vec::new();
+    for reason in reasons {
+        let message = reason.to_string();
+        match summaries
+            .iter_mut()
+            .find(|(s, _)| s.reason == reason.kind())
+        {
+            Some((summary, details)) => {
+                summary.count += 1;
+                if !details.contains(&message) {
+                    details.push(message);
+                }
+            }
+            None => summaries.push((
+                DenialSummary {
+                    reason: reason.kind(),
+                    count: 1,
+                    blocking: reason.is_blocking(),
+                    detail: String::new(),
+                },
+                vec![message],
+            )),
+        }
+    }
+    summaries
+        .into_iter()
+        .map(|(mut summary, details)| {
+            summary.detail = details.join("; ");
+            summary
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn log_take_drains() {
+        let log = DenialLog::new();
+        log.clone().record(DenialReason::SelfReply);
+        assert_eq!(log.take(), vec![DenialReason::SelfReply]);
+        assert!(log.take().is_empty());
+    }
+
+    #[test]
+    fn summarize_groups_by_kind_and_dedups_details() {
+        let rate_limited = DenialReason::RateLimited {
+            action_type: "reply".to_string(),
+            current: 20,
+            max: 20,
+        };
+        let reasons = vec![
+            rate_limited.clone(),
+            DenialReason::BannedPhrase {
+                phrase: "check out".to_string(),
+            },
+            rate_limited,
+            DenialReason::BannedPhrase {
+                phrase: "link in bio".to_string(),
+            },
+        ];
+
+        let summary = summarize(&reasons);
+        assert_eq!(summary.len(), 2);
+        assert_eq!(summary[0].reason, "rate_limited");
+        assert_eq!(summary[0].count, 2);
+        assert!(summary[0].blocking);
+        assert_eq!(summary[0].detail, "Rate limited: reply (20/20)");
+        assert_eq!(summary[1].reason, "banned_phrase");
+        assert_eq!(summary[1].count, 2);
+        assert!(summary[1].detail.contains("check out"));
+        assert!(summary[1].detail.contains("link in bio"));
+    }
+}
@@ -5,64 +5,84 @@
 //! to prevent API abuse and duplicate content.
 
 pub mod dedup;
+pub mod denials;
 pub mod qa;
 pub mod redact;
 
 use crate::error::StorageError;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
 use crate::storage::rate_limits;
-use crate::storage::{author_interactions, DbPool};
+use crate::storage::{author_interactions, replies, DbPool};
 
 pub use dedup::DedupChecker;
+pub use denials::{DenialLog, DenialSummary};
 
 /// Wraps rate limit database operations with a clean API.
+///
+/// Counters are scoped to one account so accounts managed by the same
+/// server never share a budget.
 pub struct RateLimiter {
     pool: DbPool,
+    account_id: String,
 }
 
 impl RateLimiter {
-    /// Create a new rate limiter backed by the given database pool.
+    /// Create a new rate limiter for the default account.
     pub fn new(pool: DbPool) -> Self {
-        Self { pool }
+        Self::new_for(pool, DEFAULT_ACCOUNT_ID)
+    }
+
+    /// Create a new rate limiter scoped to `account_id`.
+    pub fn new_for(pool: DbPool, account_id: impl Into<String>) -> Self {
+        Self {
+            pool,
+            account_id: account_id.into(),
+        }
+    }
+
+    /// The account whose counters this limiter reads and updates.
+    pub fn account_id(&self) -> &str {
+        &self.account_id
     }
 
     /// Check if a reply action is allowed under the current rate limit.
     pub async fn can_reply(&self) -> Result<bool, StorageError> {
-        rate_limits::check_rate_limit(&self.pool, "reply").await
+        rate_limits::check_rate_limit_for(&self.pool, &self.account_id, "reply").await
     }
 
     /// Check if a tweet action is allowed under the current rate limit.
     pub async fn can_tweet(&self) -> Result<bool, StorageError> {
-        rate_limits::check_rate_limit(&self.pool, "tweet").await
+        rate_limits::check_rate_limit_for(&self.pool, &self.account_id, "tweet").await
     }
 
     /// Check if a thread action is allowed under the current rate limit.
     pub async fn can_thread(&self) -> Result<bool, StorageError> {
-        rate_limits::check_rate_limit(&self.pool, "thread").await
+        rate_limits::check_rate_limit_for(&self.pool, &self.account_id, "thread").await
     }
 
     /// Check if a search action is allowed under the current rate limit.
     pub async fn can_search(&self) -> Result<bool, StorageError> {
-        rate_limits::check_rate_limit(&self.pool, "search").await
+        rate_limits::check_rate_limit_for(&self.pool, &self.account_id, "search").await
     }
 
     /// Record a successful reply action (increments counter).
     pub async fn record_reply(&self) -> Result<(), StorageError> {
-        rate_limits::increment_rate_limit(&self.pool, "reply").await
+        rate_limits::increment_rate_limit_for(&self.pool, &self.account_id, "reply").await
     }
 
     /// Record a successful tweet action (increments counter).
     pub async fn record_tweet(&self) -> Result<(), StorageError> {
-        rate_limits::increment_rate_limit(&self.pool, "tweet").await
+        rate_limits::increment_rate_limit_for(&self.pool, &self.account_id, "tweet").await
     }
 
     /// Record a successful thread action (increments counter).
     pub async fn record_thread(&self) -> Result<(), StorageError> {
-        rate_limits::increment_rate_limit(&self.pool, "thread").await
+        rate_limits::increment_rate_limit_for(&self.pool, &self.account_id, "thread").await
     }
 
     /// Record a successful search action (increments counter).
     pub async fn record_search(&self) -> Result<(), StorageError> {
-        rate_limits::increment_rate_limit(&self.pool, "search").await
+        rate_limits::increment_rate_limit_for(&self.pool, &self.account_id, "search").await
     }
 
     /// Atomically check and claim a rate limit slot.
@@ -71,7 +91,33 @@ impl RateLimiter {
     /// `Ok(false)` if the rate limit is reached.
     /// Preferred over separate check + record for posting actions.
     pub async fn acquire_posting_permit(&self, action_type: &str) -> Result<bool, StorageError> {
-        rate_limits::check_and_increment_rate_limit(&self.pool, action_type).await
+        rate_limits::check_and_increment_rate_limit_for(&self.pool, &self.account_id, action_type)
+            .await
+    }
+
+    /// Actions of `action_type` still allowed in the current window.
+    ///
+    /// Read-only: an expired window counts as a full budget without being
+    /// reset. Returns `0` for untracked action types.
+    pub async fn remaining(&self, action_type: &str) -> Result<i64, StorageError> {
+        let Some(limit) =
+            rate_limits::get_rate_limit_for(&self.pool, &self.account_id, action_type).await?
+        else {
+            return Ok(0);
+        };
+        let expired = limit.resets_at().is_some_and(|at| at <= chrono::Utc::now());
+        let used = if expired { 0 } else { limit.request_count };
+        Ok((limit.max_requests - used).max(0))
+    }
+
+    /// Current `(count, max)` for an action type, or `(0, 0)` if untracked.
+    async fn usage(&self, action_type: &str) -> Result<(i64, i64), StorageError> {
+        Ok(
+            rate_limits::get_rate_limit_for(&self.pool, &self.account_id, action_type)
+                .await?
+                .map(|l| (l.request_count, l.max_requests))
+                .unwrap_or((0, 0)),
+        )
     }
 }
 
@@ -101,8 +147,41 @@ pub enum DenialReason {
     },
     /// Already reached the per-author daily reply limit.
     AuthorLimitReached,
+    /// Already reached the per-conversation daily reply limit.
+    ConversationLimitReached,
     /// Replying to own tweet.
     SelfReply,
+    /// The author's tweets are protected, so a reply would reach no one
+    /// outside their approved followers.
+    ProtectedAuthor,
+}
+
+impl DenialReason {
+    /// Stable snake_case identifier for this kind of denial.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::RateLimited { .. } => "rate_limited",
+            Self::AlreadyReplied { .. } => "already_replied",
+            Self::SimilarPhrasing => "similar_phrasing",
+            Self::BannedPhrase { .. } => "banned_phrase",
+            Self::AuthorLimitReached => "author_limit_reached",
+            Self::ConversationLimitReached => "conversation_limit_reached",
+            Self::SelfReply => "self_reply",
+            Self::ProtectedAuthor => "protected_author",
+        }
+    }
+
+    /// Whether this denial stopped an action the bot wanted to take.
+    ///
+    /// Dedup, self-reply, and protected-author denials mean there was
+    /// nothing worth acting on; limits and content rules mean work was held
+    /// back.
+    pub fn is_blocking(&self) -> bool {
+        !matches!(
+            self,
+            Self::AlreadyReplied { .. } | Self::SelfReply | Self::ProtectedAuthor
+        )
+    }
 }
 
 impl std::fmt::Display for DenialReason {
@@ -125,9 +204,15 @@ impl std::fmt::Display for DenialReason {
             Self::AuthorLimitReached => {
                 write!(f, "Already reached daily reply limit for this author")
             }
+            Self::ConversationLimitReached => {
+                write!(f, "Already reached daily reply limit for this conversation")
+            }
             Self::SelfReply => {
                 write!(f, "Cannot reply to own tweets")
             }
+            Self::ProtectedAuthor => {
+                write!(f, "Author's tweets are protected")
+            }
         }
     }
 }
@@ -150,6 +235,15 @@ pub fn is_self_reply(tweet_author_id: &str, own_user_id: &str) -> bool {
     !tweet_author_id.is_empty() && !own_user_id.is_empty() && tweet_author_id == own_user_id
 }
 
+/// Check if the X API marked the tweet author as protected.
+///
+/// An unknown flag (`None`) is treated as not protected so a response
+/// without user fields never blocks a reply. X API v2 has no suspended flag
+/// on user objects; suspended authors' tweets are simply not returned.
+pub fn is_protected_author(author_protected: Option<bool>) -> bool {
+    author_protected == Some(true)
+}
+
 /// Combined safety guard for all automation loops.
 ///
 /// Provides pre-flight checks that combine rate limiting with deduplication.
@@ -161,10 +255,16 @@ pub struct SafetyGuard {
 }
 
 impl SafetyGuard {
-    /// Create a new safety guard backed by the given database pool.
+    /// Create a new safety guard for the default account.
     pub fn new(pool: DbPool) -> Self {
+        Self::new_for(pool, DEFAULT_ACCOUNT_ID)
+    }
+
+    /// Create a new safety guard whose rate limits and per-author /
+    /// per-conversation daily limits are scoped to `account_id`.
+    pub fn new_for(pool: DbPool, account_id: impl Into<String>) -> Self {
         Self {
-            rate_limiter: RateLimiter::new(pool.clone()),
+            rate_limiter: RateLimiter::new_for(pool.clone(), account_id),
             dedup_checker: DedupChecker::new(pool.clone()),
             pool,
         }
@@ -182,11 +282,7 @@ impl SafetyGuard {
     ) -> Result<Result<(), DenialReason>, StorageError> {
         // Check rate limit
         if !self.rate_limiter.can_reply().await? {
-            let limits = rate_limits::get_all_rate_limits(&self.rate_limiter.pool).await?;
-            let reply_limit = limits.iter().find(|l| l.action_type == "reply");
-            let (current, max) = reply_limit
-                .map(|l| (l.request_count, l.max_requests))
-                .unwrap_or((0, 0));
+            let (current, max) = self.rate_limiter.usage("reply").await?;
 
             tracing::debug!(
                 action = "reply",
@@ -230,11 +326,7 @@ impl SafetyGuard {
     /// Only checks rate limits (no dedup for original tweets).
     pub async fn can_post_tweet(&self) -> Result<Result<(), DenialReason>, StorageError> {
         if !self.rate_limiter.can_tweet().await? {
-            let limits = rate_limits::get_all_rate_limits(&self.rate_limiter.pool).await?;
-            let tweet_limit = limits.iter().find(|l| l.action_type == "tweet");
-            let (current, max) = tweet_limit
-                .map(|l| (l.request_count, l.max_requests))
-                .unwrap_or((0, 0));
+            let (current, max) = self.rate_limiter.usage("tweet").await?;
 
             tracing::debug!(
                 action = "tweet",
@@ -258,11 +350,7 @@ impl SafetyGuard {
     /// Only checks rate limits (no dedup for threads).
     pub async fn can_post_thread(&self) -> Result<Result<(), DenialReason>, StorageError> {
         if !self.rate_limiter.can_thread().await? {
-            let limits = rate_limits::get_all_rate_limits(&self.rate_limiter.pool).await?;
-            let thread_limit = limits.iter().find(|l| l.action_type == "thread");
-            let (current, max) = thread_limit
-                .map(|l| (l.request_count, l.max_requests))
-                .unwrap_or((0, 0));
+            let (current, max) = self.rate_limiter.usage("thread").await?;
 
             tracing::debug!(
                 action = "thread",
@@ -287,8 +375,12 @@ impl SafetyGuard {
         author_id: &str,
         max_per_day: u32,
     ) -> Result<Result<(), DenialReason>, StorageError> {
-        let count =
-            author_interactions::get_author_reply_count_today(&self.pool, author_id).await?;
+        let count = author_interactions::get_author_reply_count_today_for(
+            &self.pool,
+            self.rate_limiter.account_id(),
+            author_id,
+        )
+        .await?;
         if count >= max_per_day as i64 {
             tracing::debug!(
                 author_id,
@@ -301,6 +393,36 @@ impl SafetyGuard {
         Ok(Ok(()))
     }
 
+    /// Check if replying into this conversation is permitted (per-conversation
+    /// daily limit).
+    ///
+    /// Tweets without a known conversation ID are never blocked.
+    pub async fn check_conversation_limit(
+        &self,
+        conversation_id: Option<&str>,
+        max_per_day: u32,
+    ) -> Result<Result<(), DenialReason>, StorageError> {
+        let Some(conversation_id) = conversation_id.filter(|id| !id.is_empty()) else {
+            return Ok(Ok(()));
+        };
+        let count = replies::count_conversation_replies_today_for(
+            &self.pool,
+            self.rate_limiter.account_id(),
+            conversation_id,
+        )
+        .await?;
+        if count >= max_per_day as i64 {
+            tracing::debug!(
+                conversation_id,
+                count,
+                max = max_per_day,
+                "Action denied: conversation daily limit reached"
+            );
+            return Ok(Err(DenialReason::ConversationLimitReached));
+        }
+        Ok(Ok(()))
+    }
+
     /// Check if a generated reply contains a banned phrase.
     pub fn check_banned_phrases(reply_text: &str, banned: &[String]) -> Result<(), DenialReason> {
         if let Some(phrase) = contains_banned_phrase(reply_text, banned) {
@@ -310,14 +432,40 @@ impl SafetyGuard {
         Ok(())
     }
 
+    /// Check that the tweet author is not the authenticated user.
+    pub fn check_self_reply(tweet_author_id: &str, own_user_id: &str) -> Result<(), DenialReason> {
+        if is_self_reply(tweet_author_id, own_user_id) {
+            tracing::debug!(tweet_author_id, "Action denied: self reply");
+            return Err(DenialReason::SelfReply);
+        }
+        Ok(())
+    }
+
+    /// Check that the tweet author's account is not protected.
+    pub fn check_protected_author(
+        tweet_author_id: &str,
+        author_protected: Option<bool>,
+    ) -> Result<(), DenialReason> {
+        if is_protected_author(author_protected) {
+            tracing::debug!(tweet_author_id, "Action denied: protected author");
+            return Err(DenialReason::ProtectedAuthor);
+        }
+        Ok(())
+    }
+
     /// Record a reply for an author interaction.
     pub async fn record_author_interaction(
         &self,
         author_id: &str,
         author_username: &str,
     ) -> Result<(), StorageError> {
-        author_interactions::increment_author_interaction(&self.pool, author_id, author_username)
-            .await
+        author_interactions::increment_author_interaction_for(
+            &self.pool,
+            self.rate_limiter.account_id(),
+            author_id,
+            author_username,
+        )
+        .await
     }
 
     /// Record a successful reply action.
@@ -361,8 +509,13 @@ mod tests {
             min_action_delay_seconds: 30,
             max_action_delay_seconds: 120,
             max_replies_per_author_per_day: 1,
+            max_replies_per_conversation_per_day: 2,
+            max_likes_per_day: 20,
+            max_retweets_per_day: 5,
+            discovery_likes: false,
             banned_phrases: vec!["check out".to_string(), "you should try".to_string()],
             product_mention_ratio: 0.2,
+            ramp: None,
         }
     }
 
@@ -370,6 +523,7 @@ mod tests {
         IntervalsConfig {
             mentions_check_seconds: 300,
             discovery_search_seconds: 600,
+            discovery_max_pages: 1,
             content_post_window_seconds: 14400,
             thread_interval_seconds: 604800,
         }
@@ -395,6 +549,7 @@ mod tests {
             created_at: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
             status: "sent".to_string(),
             error_message: None,
+            conversation_id: None,
         }
     }
 
@@ -414,6 +569,26 @@ mod tests {
         assert!(!limiter.can_reply().await.expect("check"));
     }
 
+    #[tokio::test]
+    async fn rate_limiter_remaining_counts_down_to_zero() {
+        let pool = init_test_db().await.expect("init db");
+        rate_limits::init_rate_limits(&pool, &test_limits(), &test_intervals())
+            .await
+            .expect("init");
+
+        let limiter = RateLimiter::new(pool);
+
+        assert_eq!(limiter.remaining("reply").await.expect("remaining"), 3);
+        limiter.record_reply().await.expect("record");
+        assert_eq!(limiter.remaining("reply").await.expect("remaining"), 2);
+        limiter.record_reply().await.expect("record");
+        limiter.record_reply().await.expect("record");
+        assert_eq!(limiter.remaining("reply").await.expect("remaining"), 0);
+        limiter.record_reply().await.expect("record");
+        assert_eq!(limiter.remaining("reply").await.expect("remaining"), 0);
+        assert_eq!(limiter.remaining("unknown").await.expect("remaining"), 0);
+    }
+
     #[tokio::test]
     async fn rate_limiter_acquire_posting_permit() {
         let pool = init_test_db().await.expect("init db");
@@ -428,6 +603,67 @@ mod tests {
         assert!(!limiter.acquire_posting_permit("tweet").await.expect("3"));
     }
 
+    #[tokio::test]
+    async fn safety_guard_rate_limits_are_independent_per_account() {
+        let pool = init_test_db().await.expect("init db");
+        for account in ["acct-a", "acct-b"] {
+            crate::storage::accounts::create_account(&pool, account, account)
+                .await
+                .expect("create account");
+            rate_limits::init_rate_limits_for(&pool, account, &test_limits(), &test_intervals())
+                .await
+                .expect("init rate limits");
+        }
+        let guard_a = SafetyGuard::new_for(pool.clone(), "acct-a");
+        let guard_b = SafetyGuard::new_for(pool.clone(), "acct-b");
+
+        for _ in 0..3 {
+            guard_a.record_reply().await.expect("record");
+        }
+
+        assert_eq!(
+            guard_a.can_reply_to("t1", None).await.expect("check"),
+            Err(DenialReason::RateLimited {
+                action_type: "reply".to_string(),
+                current: 3,
+                max: 3,
+            })
+        );
+        assert_eq!(
+            guard_b.can_reply_to("t1", None).await.expect("check"),
+            Ok(())
+        );
+
+        // Nothing was charged to the default account.
+        let default_limits = rate_limits::get_all_rate_limits(&pool).await.expect("get");
+        assert!(default_limits.iter().all(|l| l.request_count == 0));
+    }
+
+    #[test]
+    fn check_self_reply_denies_own_tweet() {
+        assert_eq!(
+            SafetyGuard::check_self_reply("me_123", "me_123"),
+            Err(DenialReason::SelfReply)
+        );
+        assert_eq!(SafetyGuard::check_self_reply("other", "me_123"), Ok(()));
+        assert_eq!(SafetyGuard::check_self_reply("me_123", ""), Ok(()));
+    }
+
+    #[test]
+    fn check_protected_author_denies_only_when_flag_is_true() {
+        assert_eq!(
+            SafetyGuard::check_protected_author("u1", Some(true)),
+            Err(DenialReason::ProtectedAuthor)
+        );
+        assert_eq!(
+            SafetyGuard::check_protected_author("u1", Some(false)),
+            Ok(())
+        );
+        assert_eq!(SafetyGuard::check_protected_author("u1", None), Ok(()));
+        assert_eq!(DenialReason::ProtectedAuthor.kind(), "protected_author");
+        assert!(!DenialReason::ProtectedAuthor.is_blocking());
+    }
+
     #[tokio::test]
     async fn safety_guard_allows_new_reply() {
         let (_pool, guard) = setup_guard().await;
@@ -591,6 +827,12 @@ mod tests {
             "Already reached daily reply limit for this author"
         );
 
+        let conversation = DenialReason::ConversationLimitReached;
+        assert_eq!(
+            conversation.to_string(),
+            "Already reached daily reply limit for this conversation"
+        );
+
         let self_reply = DenialReason::SelfReply;
         assert_eq!(self_reply.to_string(), "Cannot reply to own tweets");
     }
@@ -711,6 +953,7 @@ mod tests {
                 phrase: "buy now".to_string(),
             },
             DenialReason::AuthorLimitReached,
+            DenialReason::ConversationLimitReached,
             DenialReason::SelfReply,
         ];
         for variant in &variants {
@@ -728,6 +971,20 @@ mod tests {
         assert_ne!(DenialReason::SelfReply, DenialReason::SimilarPhrasing);
     }
 
+    #[test]
+    fn denial_reason_blocking() {
+        assert!(DenialReason::SimilarPhrasing.is_blocking());
+        assert!(DenialReason::BannedPhrase {
+            phrase: "x".to_string()
+        }
+        .is_blocking());
+        assert!(!DenialReason::SelfReply.is_blocking());
+        assert!(!DenialReason::AlreadyReplied {
+            tweet_id: "1".to_string()
+        }
+        .is_blocking());
+    }
+
     #[tokio::test]
     async fn safety_guard_exposes_rate_limiter_and_dedup() {
         let (_pool, guard) = setup_guard().await;
@@ -892,4 +1149,52 @@ mod tests {
         let result = guard.check_author_limit("a2", 1).await.expect("check");
         assert!(result.is_ok());
     }
+
+    fn reply_in_conversation(target_id: &str, conversation_id: &str) -> ReplySent {
+        ReplySent {
+            conversation_id: Some(conversation_id.to_string()),
+            ..sample_reply(target_id, "A reply in this thread")
+        }
+    }
+
+    #[tokio::test]
+    async fn safety_guard_conversation_limit_blocks_shared_thread() {
+        let (pool, guard) = setup_guard().await;
+        for target in ["t1", "t2"] {
+            insert_reply(&pool, &reply_in_conversation(target, "conv_1"))
+                .await
+                .expect("insert");
+        }
+        insert_reply(&pool, &reply_in_conversation("t3", "conv_2"))
+            .await
+            .expect("insert");
+
+        let result = guard
+            .check_conversation_limit(Some("conv_1"), 2)
+            .await
+            .expect("check");
+        assert_eq!(result, Err(DenialReason::ConversationLimitReached));
+
+        let result = guard
+            .check_conversation_limit(Some("conv_2"), 2)
+            .await
+            .expect("check");
+        assert!(result.is_ok());
+    }
+
+    #[tokio::test]
+    async fn safety_guard_conversation_limit_ignores_unknown_conversation() {
+        let (pool, guard) = setup_guard().await;
+        insert_reply(&pool, &sample_reply("t1", "No thread info"))
+            .await
+            .expect("insert");
+
+        for conversation_id in [None, Some("")] {
+            let result = guard
+                .check_conversation_limit(conversation_id, 1)
+                .await
+                .expect("check");
+            assert!(result.is_ok());
+        }
+    }
 }
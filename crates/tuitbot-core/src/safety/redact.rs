@@ -1,7 +1,7 @@
 //! Secret redaction and masking helpers for logs and user-facing diagnostics.
 
 use std::fmt;
-use std::sync::OnceLock;
+use std::sync::{OnceLock, RwLock};
 
 use regex::{Captures, Regex};
 
@@ -33,6 +33,91 @@ pub fn redact_secrets(input: &str) -> String {
         .into_owned()
 }
 
+/// Built-in credential shapes masked by [`scrub`] wherever they appear.
+const DEFAULT_TOKEN_PATTERNS: &[&str] = &[
+    // OpenAI/Anthropic-style API keys (`sk-...`, `sk-proj-...`, `sk-ant-...`).
+    r"\bsk-[A-Za-z0-9_\-]{8,}",
+    // Google OAuth client secrets.
+    r"\bGOCSPX-[A-Za-z0-9_\-]{8,}",
+];
+
+/// A set of token patterns masked in addition to [`redact_secrets`].
+///
+/// Starts with [`DEFAULT_TOKEN_PATTERNS`]; callers can add their own shapes
+/// with [`Scrubber::with_pattern`] or globally with [`register_pattern`].
+#[derive(Debug, Clone)]
+pub struct Scrubber {
+    patterns: Vec<Regex>,
+}
+
+impl Default for Scrubber {
+    fn default() -> Self {
+        Self {
+            patterns: DEFAULT_TOKEN_PATTERNS
+                .iter()
+                .map(|p| Regex::new(p).expect("default token pattern must compile"))
+                .collect(),
+        }
+    }
+}
+
+impl Scrubber {
+    /// Add a pattern; every match is replaced with the redaction marker.
+    pub fn with_pattern(mut self, pattern: &str) -> Result<Self, regex::Error> {
+        self.patterns.push(Regex::new(pattern)?);
+        Ok(self)
+    }
+
+    /// Mask bearer tokens, secret key-value pairs, and every token pattern.
+    pub fn scrub(&self, text: &str) -> String {
+        let mut out = redact_secrets(text);
+        for re in &self.patterns {
+            if re.is_match(&out) {
+                out = re.replace_all(&out, REDACTED).into_owned();
+            }
+        }
+        out
+    }
+}
+
+fn global_scrubber() -> &'static RwLock<Scrubber> {
+    static SCRUBBER: OnceLock<RwLock<Scrubber>> = OnceLock::new();
+    SCRUBBER.get_or_init(|| RwLock::new(Scrubber::default()))
+}
+
+/// Add a pattern to the process-wide set used by [`scrub`].
+pub fn register_pattern(pattern: &str) -> Result<(), regex::Error> {
+    let re = Regex::new(pattern)?;
+    global_scrubber()
+        .write()
+        .unwrap_or_else(|e| e.into_inner())
+        .patterns
+        .push(re);
+    Ok(())
+}
+
+/// Add every pattern from `logging.redact_patterns` to the process-wide set.
+///
+/// Config validation rejects invalid patterns, so an error here means the
+/// config was not validated.
+pub fn register_patterns(patterns: &[String]) -> Result<(), regex::Error> {
+    for pattern in patterns {
+        register_pattern(pattern)?;
+    }
+    Ok(())
+}
+
+/// Mask anything that looks like a credential before text is printed or logged.
+///
+/// Covers bearer tokens, `access_token=`-style pairs, `sk-...` API keys,
+/// `GOCSPX-...` client secrets, and any pattern added via [`register_pattern`].
+pub fn scrub(text: &str) -> String {
+    global_scrubber()
+        .read()
+        .unwrap_or_else(|e| e.into_inner())
+        .scrub(text)
+}
+
 /// Wrapper that redacts secrets from anything implementing `Display`.
 pub struct Redacted<T: fmt::Display>(pub T);
 
@@ -103,6 +188,59 @@ mod tests {
         assert_eq!(wrapped.to_string(), "client_secret=***REDACTED***");
     }
 
+    #[test]
+    fn scrub_masks_api_keys_and_keeps_context() {
+        let input = "LLM request failed (key sk-proj-Ab3dEf6hIj9kLmN0pQ): 401 Unauthorized";
+        assert_eq!(
+            scrub(input),
+            "LLM request failed (key ***REDACTED***): 401 Unauthorized"
+        );
+
+        let input = "invalid_client: GOCSPX-1a2B3c4D5e6F7g8H9i0J for client 123.apps";
+        assert_eq!(
+            scrub(input),
+            "invalid_client: ***REDACTED*** for client 123.apps"
+        );
+    }
+
+    #[test]
+    fn scrub_masks_bearer_and_kv_secrets() {
+        let input = "GET /2/users/me -> Bearer AAAAAAAAAAAAAAAAAAAAAMLheAAA%2Fabc, refresh_token=r-77 expired";
+        let out = scrub(input);
+        assert_eq!(
+            out,
+            "GET /2/users/me -> Bearer ***REDACTED***, refresh_token=***REDACTED*** expired"
+        );
+    }
+
+    #[test]
+    fn scrub_leaves_lookalikes_alone() {
+        for input in ["task-runner failed", "risk-free", "sk-short", "GOCSPX-"] {
+            assert_eq!(scrub(input), input);
+        }
+    }
+
+    #[test]
+    fn scrubber_accepts_custom_patterns() {
+        let scrubber = Scrubber::default()
+            .with_pattern(r"\bxai-[A-Za-z0-9]{10,}")
+            .unwrap();
+        assert_eq!(
+            scrubber.scrub("using xai-ABCDEFGHIJ12 and sk-abcdefgh12"),
+            "using ***REDACTED*** and ***REDACTED***"
+        );
+        assert!(Scrubber::default().with_pattern("(unclosed").is_err());
+    }
+
+    #[test]
+    fn registered_patterns_apply_to_scrub() {
+        register_pattern(r"\btbtest_[0-9a-f]{12}\b").unwrap();
+        assert_eq!(
+            scrub("webhook secret tbtest_0123456789ab rejected"),
+            "webhook secret ***REDACTED*** rejected"
+        );
+    }
+
     #[test]
     fn mask_secret_long_short_and_empty() {
         assert_eq!(mask_secret("sk-1234567890abcdef"), "sk-1...cdef");
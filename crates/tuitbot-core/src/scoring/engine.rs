@@ -1,8 +1,11 @@
 //! Scoring engine — combines all signals into a unified tweet score.
 
+use std::collections::HashSet;
+
 use chrono::{DateTime, Utc};
 
-use crate::config::ScoringConfig;
+use crate::config::{RecencyCurve, ScoringConfig, TargetsConfig};
+use crate::storage::DbPool;
 
 use super::signals;
 use super::{TweetData, TweetScore};
@@ -11,6 +14,9 @@ use super::{TweetData, TweetScore};
 pub struct ScoringEngine {
     pub(super) config: ScoringConfig,
     pub(super) keywords: Vec<String>,
+    /// Lowercased target usernames that earn the freshness bonus.
+    targets: HashSet<String>,
+    target_recency_max: f32,
 }
 
 impl ScoringEngine {
@@ -19,7 +25,26 @@ impl ScoringEngine {
     /// Keywords should be the combined list of `product_keywords` and
     /// `competitor_keywords` from the business profile.
     pub fn new(config: ScoringConfig, keywords: Vec<String>) -> Self {
-        Self { config, keywords }
+        Self {
+            config,
+            keywords,
+            targets: HashSet::new(),
+            target_recency_max: 0.0,
+        }
+    }
+
+    /// Give fresh tweets from `targets.accounts` a bonus of up to
+    /// `targets.recency_bonus_max` points, so replies to them land early
+    /// in the conversation. The bonus halves every
+    /// `recency_half_life_minutes`.
+    pub fn with_targets(mut self, targets: &TargetsConfig) -> Self {
+        self.targets = targets
+            .accounts
+            .iter()
+            .map(|a| a.trim_start_matches('@').to_lowercase())
+            .collect();
+        self.target_recency_max = targets.recency_bonus_max;
+        self
     }
 
     /// Score a tweet using all six signals.
@@ -29,10 +54,35 @@ impl ScoringEngine {
         self.score_tweet_at(tweet, Utc::now())
     }
 
-    /// Score a tweet using all six signals with a specific time reference.
+    /// Score a tweet using all six signals with a specific time reference,
+    /// plus the target freshness bonus when the author is a target.
     ///
     /// Accepts `now` for deterministic testing.
     pub fn score_tweet_at(&self, tweet: &TweetData, now: DateTime<Utc>) -> TweetScore {
+        self.score_tweet_at_with_reputation(tweet, now, 0.0)
+    }
+
+    /// Score a tweet, folding in the author's historical reply performance.
+    ///
+    /// Looks up the reputation adjustment for `author_id` in `pool`; use
+    /// [`score_tweet`](Self::score_tweet) for offline scoring.
+    pub async fn score_tweet_with_pool(
+        &self,
+        pool: &DbPool,
+        author_id: &str,
+        tweet: &TweetData,
+    ) -> TweetScore {
+        let reputation = super::reputation::author_reputation(pool, author_id).await;
+        self.score_tweet_at_with_reputation(tweet, Utc::now(), reputation)
+    }
+
+    /// Score a tweet with a precomputed author reputation adjustment.
+    pub fn score_tweet_at_with_reputation(
+        &self,
+        tweet: &TweetData,
+        now: DateTime<Utc>,
+        author_reputation: f32,
+    ) -> TweetScore {
         let keyword_relevance = signals::keyword_relevance(
             &tweet.text,
             &self.keywords,
@@ -44,7 +94,13 @@ impl ScoringEngine {
             self.config.follower_count_max,
         );
 
-        let recency = signals::recency_score_at(&tweet.created_at, self.config.recency_max, now);
+        let recency = signals::recency_score_at(
+            &tweet.created_at,
+            self.config.recency_max,
+            self.config.recency_curve,
+            self.config.recency_half_life_minutes,
+            now,
+        );
 
         let engagement = signals::engagement_rate(
             tweet.likes,
@@ -62,9 +118,17 @@ impl ScoringEngine {
             self.config.content_type_max,
         );
 
-        let total =
-            (keyword_relevance + follower + recency + engagement + reply_count + content_type)
-                .clamp(0.0, 100.0);
+        let target_recency = self.target_recency(tweet, now);
+
+        let total = (keyword_relevance
+            + follower
+            + recency
+            + engagement
+            + reply_count
+            + content_type
+            + author_reputation
+            + target_recency)
+            .clamp(0.0, 100.0);
         let meets_threshold = total >= self.config.threshold as f32;
 
         tracing::debug!(
@@ -76,6 +140,8 @@ impl ScoringEngine {
             engagement = format!("{:.0}", engagement),
             reply = format!("{:.0}", reply_count),
             content = format!("{:.0}", content_type),
+            reputation = format!("{:+.1}", author_reputation),
+            target = format!("{:.0}", target_recency),
             meets = meets_threshold,
             "Scored tweet",
         );
@@ -88,10 +154,27 @@ impl ScoringEngine {
             engagement,
             reply_count,
             content_type,
+            author_reputation,
+            target_recency,
             meets_threshold,
         }
     }
 
+    /// Exponentially decaying bonus for tweets authored by a target account.
+    fn target_recency(&self, tweet: &TweetData, now: DateTime<Utc>) -> f32 {
+        let author = tweet.author_username.trim_start_matches('@').to_lowercase();
+        if self.target_recency_max <= 0.0 || !self.targets.contains(&author) {
+            return 0.0;
+        }
+        signals::recency_score_at(
+            &tweet.created_at,
+            self.target_recency_max,
+            RecencyCurve::Exponential,
+            self.config.recency_half_life_minutes,
+            now,
+        )
+    }
+
     /// Return the configured keywords.
     pub fn keywords(&self) -> &[String] {
         &self.keywords
@@ -4,20 +4,25 @@
 //! recency, engagement rate, reply count, content type) into a total score
 //! (0-100) with a configurable threshold for the REPLY/SKIP verdict.
 //!
-//! All scoring is purely heuristic — no LLM calls.
+//! All scoring is purely heuristic — no LLM calls. When a database is
+//! available, an author reputation adjustment derived from past reply
+//! performance can be folded in (see [`reputation`]).
 
+pub mod reputation;
 pub mod signals;
 
 mod engine;
 mod weights;
 
 pub use engine::ScoringEngine;
+pub use reputation::{author_reputation, author_reputation_for};
 pub use weights::{
     find_matched_keywords, format_follower_count, format_tweet_age, format_tweet_age_at,
     truncate_text,
 };
 
 use crate::config::ScoringConfig;
+use serde::Serialize;
 
 /// Input data for scoring a tweet.
 ///
@@ -64,11 +69,98 @@ pub struct TweetScore {
     pub reply_count: f32,
     /// Content type signal score (text-only = max).
     pub content_type: f32,
+    /// Author reputation adjustment (0 without history or a database).
+    pub author_reputation: f32,
+    /// Freshness bonus for tweets from target accounts (0 for everyone else).
+    pub target_recency: f32,
     /// Whether the total score meets the configured threshold.
     pub meets_threshold: bool,
 }
 
+/// The signal that most held a skipped tweet below the threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// Few or none of the configured keywords appear in the text.
+    NoKeywordMatch,
+    /// The author's audience is outside the targeted follower range.
+    OffTargetAudience,
+    /// The tweet is too old to be worth joining.
+    TooOld,
+    /// Likes, retweets and replies are low for the author's audience.
+    LowEngagement,
+    /// The conversation already has many replies.
+    CrowdedConversation,
+    /// The tweet carries media or quotes another tweet.
+    MediaOrQuote,
+    /// Past replies to this author performed poorly.
+    PoorAuthorHistory,
+}
+
+impl std::fmt::Display for SkipReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SkipReason::NoKeywordMatch => "no keyword match",
+            SkipReason::OffTargetAudience => "off-target audience",
+            SkipReason::TooOld => "too old",
+            SkipReason::LowEngagement => "low engagement",
+            SkipReason::CrowdedConversation => "crowded conversation",
+            SkipReason::MediaOrQuote => "media/quote",
+            SkipReason::PoorAuthorHistory => "poor author history",
+        })
+    }
+}
+
 impl TweetScore {
+    /// The dominant reason a tweet was skipped, or `None` if it meets the threshold.
+    ///
+    /// Each signal's deficit is the points it fell short of its configured
+    /// max; the signal that gave up the most points is the reason. A negative
+    /// author reputation counts as a deficit of its own. Ties go to the
+    /// signal listed first in [`SkipReason`].
+    pub fn skip_reason(&self, config: &ScoringConfig) -> Option<SkipReason> {
+        if self.meets_threshold {
+            return None;
+        }
+
+        let deficits = [
+            (
+                SkipReason::NoKeywordMatch,
+                config.keyword_relevance_max - self.keyword_relevance,
+            ),
+            (
+                SkipReason::OffTargetAudience,
+                config.follower_count_max - self.follower,
+            ),
+            (SkipReason::TooOld, config.recency_max - self.recency),
+            (
+                SkipReason::LowEngagement,
+                config.engagement_rate_max - self.engagement,
+            ),
+            (
+                SkipReason::CrowdedConversation,
+                config.reply_count_max - self.reply_count,
+            ),
+            (
+                SkipReason::MediaOrQuote,
+                config.content_type_max - self.content_type,
+            ),
+            (SkipReason::PoorAuthorHistory, -self.author_reputation),
+        ];
+
+        deficits
+            .into_iter()
+            .filter(|(_, deficit)| *deficit > 0.0)
+            .fold(
+                None,
+                |best: Option<(SkipReason, f32)>, (reason, deficit)| match best {
+                    Some((_, top)) if top >= deficit => best,
+                    _ => Some((reason, deficit)),
+                },
+            )
+            .map(|(reason, _)| reason)
+    }
+
     /// Format a human-readable breakdown of the score.
     ///
     /// Shows the total score, per-signal breakdown with context,
@@ -92,14 +184,32 @@ impl TweetScore {
         let followers_for_rate = tweet.author_followers.max(1) as f64;
         let rate_pct = (total_engagement as f64 / followers_for_rate) * 100.0;
 
-        let verdict = if self.meets_threshold {
-            "REPLY"
-        } else {
-            "SKIP"
+        let verdict = match self.skip_reason(config) {
+            _ if self.meets_threshold => "REPLY".to_string(),
+            Some(reason) => format!("SKIP ({reason})"),
+            None => "SKIP".to_string(),
         };
 
         let reply_count_display = tweet.replies;
 
+        let reputation_line = if self.author_reputation != 0.0 {
+            format!(
+                "\x20 Author history:     {:+.0}  (past reply performance)\n",
+                self.author_reputation
+            )
+        } else {
+            String::new()
+        };
+
+        let target_line = if self.target_recency > 0.0 {
+            format!(
+                "\x20 Target freshness:   {:+.0}  (target account)\n",
+                self.target_recency
+            )
+        } else {
+            String::new()
+        };
+
         format!(
             "Tweet: \"{}\" by @{} ({} followers)\n\
              Score: {:.0}/100\n\
@@ -109,6 +219,8 @@ impl TweetScore {
              \x20 Engagement rate:    {:.0}/{}  ({:.1}% engagement vs 1.5% baseline)\n\
              \x20 Reply count:        {:.0}/{}  ({} existing replies)\n\
              \x20 Content type:       {:.0}/{}  ({})\n\
+             {}\
+             {}\
              Verdict: {} (threshold: {})",
             truncated,
             tweet.author_username,
@@ -136,6 +248,8 @@ impl TweetScore {
             } else {
                 "text-only"
             },
+            reputation_line,
+            target_line,
             verdict,
             config.threshold,
         )
@@ -146,7 +260,7 @@ impl std::fmt::Display for TweetScore {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "Score: {:.0}/100 [kw:{:.0} fol:{:.0} rec:{:.0} eng:{:.0} rep:{:.0} ct:{:.0}] {}",
+            "Score: {:.0}/100 [kw:{:.0} fol:{:.0} rec:{:.0} eng:{:.0} rep:{:.0} ct:{:.0}{}{}] {}",
             self.total,
             self.keyword_relevance,
             self.follower,
@@ -154,6 +268,16 @@ impl std::fmt::Display for TweetScore {
             self.engagement,
             self.reply_count,
             self.content_type,
+            if self.author_reputation != 0.0 {
+                format!(" auth:{:+.0}", self.author_reputation)
+            } else {
+                String::new()
+            },
+            if self.target_recency > 0.0 {
+                format!(" tgt:{:+.0}", self.target_recency)
+            } else {
+                String::new()
+            },
             if self.meets_threshold {
                 "REPLY"
             } else {
@@ -0,0 +1,65 @@
+//! Author reputation adjustment.
+//!
+//! Authors whose threads have historically ignored our replies get a small
+//! penalty; authors whose threads reliably engage get a small bonus. The
+//! adjustment compares an author's mean reply performance against the
+//! account-wide mean and is zero until enough replies have been measured.
+
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::author_reputation;
+use crate::storage::DbPool;
+
+/// Measured replies required before an author's history affects scoring.
+pub const REPUTATION_MIN_SAMPLES: i64 = 3;
+
+/// Largest penalty or bonus (in score points) reputation can apply.
+pub const REPUTATION_MAX_ADJUSTMENT: f32 = 5.0;
+
+/// Compute the score adjustment for an author's reply history.
+///
+/// Linear in the ratio of the author's mean performance to `baseline`:
+/// an author performing at baseline scores 0, one whose replies never
+/// perform scores `-REPUTATION_MAX_ADJUSTMENT`, and the bonus is capped
+/// at `+REPUTATION_MAX_ADJUSTMENT`.
+pub fn reputation_adjustment(avg_performance: f64, replies_measured: i64, baseline: f64) -> f32 {
+    if replies_measured < REPUTATION_MIN_SAMPLES || baseline <= 0.0 {
+        return 0.0;
+    }
+    let ratio = (avg_performance / baseline) as f32;
+    ((ratio - 1.0) * REPUTATION_MAX_ADJUSTMENT)
+        .clamp(-REPUTATION_MAX_ADJUSTMENT, REPUTATION_MAX_ADJUSTMENT)
+}
+
+/// Look up the reputation adjustment for an author for a specific account.
+///
+/// Storage errors are logged and treated as "no history" so scoring never
+/// fails because of reputation lookups.
+pub async fn author_reputation_for(pool: &DbPool, account_id: &str, author_id: &str) -> f32 {
+    let lookup = async {
+        let Some(rep) =
+            author_reputation::get_author_reputation_for(pool, account_id, author_id).await?
+        else {
+            return Ok(0.0);
+        };
+        let baseline =
+            author_reputation::get_reply_performance_baseline_for(pool, account_id).await?;
+        Ok::<_, crate::error::StorageError>(reputation_adjustment(
+            rep.avg_performance,
+            rep.replies_measured,
+            baseline.unwrap_or(0.0),
+        ))
+    };
+
+    match lookup.await {
+        Ok(adjustment) => adjustment,
+        Err(e) => {
+            tracing::warn!(author_id, error = %e, "Author reputation lookup failed");
+            0.0
+        }
+    }
+}
+
+/// Look up the reputation adjustment for an author.
+pub async fn author_reputation(pool: &DbPool, author_id: &str) -> f32 {
+    author_reputation_for(pool, DEFAULT_ACCOUNT_ID, author_id).await
+}
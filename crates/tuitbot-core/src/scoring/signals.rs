@@ -3,8 +3,12 @@
 //! All functions are pure: same inputs always produce the same outputs.
 //! Each signal evaluates one dimension of a tweet's reply-worthiness.
 
+use crate::config::RecencyCurve;
 use chrono::{DateTime, Utc};
 
+/// Half-life the bracket curve was designed around, in minutes.
+const BRACKETS_BASE_HALF_LIFE_MINUTES: f64 = 60.0;
+
 /// Compute keyword relevance score for a tweet.
 ///
 /// Matches the tweet text (case-insensitive) against the provided keywords.
@@ -60,16 +64,29 @@ pub fn follower_score(follower_count: u64, max_score: f32) -> f32 {
 
 /// Compute recency score based on tweet age.
 ///
-/// Uses time brackets with linear interpolation:
-/// - 0-5 minutes: 100% of max_score
-/// - 5-30 minutes: 80-100% (interpolated)
-/// - 30-60 minutes: 50-80% (interpolated)
-/// - 1-6 hours: 25-50% (interpolated)
-/// - 6+ hours: 0-25% (interpolated, reaching 0 at 12 hours)
+/// `curve` sets the decay shape and `half_life_minutes` the age at which
+/// the score is half of `max_score`:
+/// - `Brackets`: time brackets with linear interpolation. At the default
+///   60-minute half-life:
+///   - 0-5 minutes: 100% of max_score
+///   - 5-30 minutes: 80-100% (interpolated)
+///   - 30-60 minutes: 50-80% (interpolated)
+///   - 1-6 hours: 25-50% (interpolated)
+///   - 6+ hours: 0%
+///
+///   Other half-lives stretch or shrink every bracket proportionally.
+/// - `Linear`: 100% at age 0, falling to 0% at twice the half-life.
+/// - `Exponential`: `0.5^(age / half_life)`.
 ///
-/// Accepts a `now` parameter for testability.
-/// Returns 0.0 if the timestamp fails to parse.
-pub fn recency_score_at(tweet_created_at: &str, max_score: f32, now: DateTime<Utc>) -> f32 {
+/// Accepts a `now` parameter for testability. A zero half-life is treated
+/// as one minute. Returns 0.0 if the timestamp fails to parse.
+pub fn recency_score_at(
+    tweet_created_at: &str,
+    max_score: f32,
+    curve: RecencyCurve,
+    half_life_minutes: u32,
+    now: DateTime<Utc>,
+) -> f32 {
     let created_at = match tweet_created_at.parse::<DateTime<Utc>>() {
         Ok(dt) => dt,
         Err(_) => {
@@ -82,8 +99,22 @@ pub fn recency_score_at(tweet_created_at: &str, max_score: f32, now: DateTime<Ut
     };
 
     let age_minutes = (now - created_at).num_minutes().max(0) as f64;
+    let half_life = f64::from(half_life_minutes.max(1));
+
+    let fraction = match curve {
+        RecencyCurve::Brackets => {
+            bracket_fraction(age_minutes * BRACKETS_BASE_HALF_LIFE_MINUTES / half_life)
+        }
+        RecencyCurve::Linear => (1.0 - age_minutes / (2.0 * half_life)).max(0.0),
+        RecencyCurve::Exponential => 0.5f64.powf(age_minutes / half_life),
+    };
 
-    let fraction = if age_minutes <= 5.0 {
+    (fraction as f32 * max_score).clamp(0.0, max_score)
+}
+
+/// Bracket curve fraction for an age on the 60-minute half-life scale.
+fn bracket_fraction(age_minutes: f64) -> f64 {
+    if age_minutes <= 5.0 {
         // 0-5 min: 100%
         1.0
     } else if age_minutes <= 30.0 {
@@ -101,14 +132,19 @@ pub fn recency_score_at(tweet_created_at: &str, max_score: f32, now: DateTime<Ut
     } else {
         // 6+ hours: 0%
         0.0
-    };
-
-    (fraction as f32 * max_score).clamp(0.0, max_score)
+    }
 }
 
-/// Convenience wrapper for `recency_score_at` using the current time.
+/// Convenience wrapper for `recency_score_at` using the current time and
+/// the default bracket curve.
 pub fn recency_score(tweet_created_at: &str, max_score: f32) -> f32 {
-    recency_score_at(tweet_created_at, max_score, Utc::now())
+    recency_score_at(
+        tweet_created_at,
+        max_score,
+        RecencyCurve::Brackets,
+        BRACKETS_BASE_HALF_LIFE_MINUTES as u32,
+        Utc::now(),
+    )
 }
 
 /// Compute reply count score — fewer existing replies = higher score.
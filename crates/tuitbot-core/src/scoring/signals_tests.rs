@@ -1,6 +1,7 @@
 //! Tests for scoring signal functions.
 
 use super::signals::*;
+use crate::config::RecencyCurve;
 use chrono::{Duration, Utc};
 
 // --- keyword_relevance tests ---
@@ -103,7 +104,7 @@ fn follower_million_clamped() {
 fn recency_1_minute_ago() {
     let now = Utc::now();
     let created = (now - Duration::minutes(1)).to_rfc3339();
-    let score = recency_score_at(&created, 15.0, now);
+    let score = recency_score_at(&created, 15.0, RecencyCurve::Brackets, 60, now);
     // 1 min: within 0-5 bracket, should be 100%
     assert!((score - 15.0).abs() < 0.5);
 }
@@ -112,7 +113,7 @@ fn recency_1_minute_ago() {
 fn recency_15_minutes_ago() {
     let now = Utc::now();
     let created = (now - Duration::minutes(15)).to_rfc3339();
-    let score = recency_score_at(&created, 15.0, now);
+    let score = recency_score_at(&created, 15.0, RecencyCurve::Brackets, 60, now);
     // 15 min: in 5-30 bracket, interpolated ~92%
     assert!(score > 12.0 && score < 15.0);
 }
@@ -121,7 +122,7 @@ fn recency_15_minutes_ago() {
 fn recency_45_minutes_ago() {
     let now = Utc::now();
     let created = (now - Duration::minutes(45)).to_rfc3339();
-    let score = recency_score_at(&created, 15.0, now);
+    let score = recency_score_at(&created, 15.0, RecencyCurve::Brackets, 60, now);
     // 45 min: in 30-60 bracket, interpolated ~65%
     assert!(score > 7.0 && score < 12.0);
 }
@@ -130,7 +131,7 @@ fn recency_45_minutes_ago() {
 fn recency_3_hours_ago() {
     let now = Utc::now();
     let created = (now - Duration::hours(3)).to_rfc3339();
-    let score = recency_score_at(&created, 15.0, now);
+    let score = recency_score_at(&created, 15.0, RecencyCurve::Brackets, 60, now);
     // 3 hours: in 1-6 bracket, interpolated ~38%
     assert!(score > 3.0 && score < 8.0);
 }
@@ -139,7 +140,7 @@ fn recency_3_hours_ago() {
 fn recency_12_hours_ago() {
     let now = Utc::now();
     let created = (now - Duration::hours(12)).to_rfc3339();
-    let score = recency_score_at(&created, 15.0, now);
+    let score = recency_score_at(&created, 15.0, RecencyCurve::Brackets, 60, now);
     // 12 hours: beyond 6 hour bracket, should be 0
     assert!((score - 0.0).abs() < 0.01);
 }
@@ -147,7 +148,7 @@ fn recency_12_hours_ago() {
 #[test]
 fn recency_invalid_timestamp() {
     let now = Utc::now();
-    let score = recency_score_at("not-a-timestamp", 15.0, now);
+    let score = recency_score_at("not-a-timestamp", 15.0, RecencyCurve::Brackets, 60, now);
     assert_eq!(score, 0.0);
 }
 
@@ -334,7 +335,7 @@ fn follower_score_10() {
 fn recency_exactly_5_minutes() {
     let now = Utc::now();
     let created = (now - Duration::minutes(5)).to_rfc3339();
-    let score = recency_score_at(&created, 15.0, now);
+    let score = recency_score_at(&created, 15.0, RecencyCurve::Brackets, 60, now);
     // At 5 min boundary: still in 0-5 bracket = 100%
     assert!((score - 15.0).abs() < 0.5);
 }
@@ -343,7 +344,7 @@ fn recency_exactly_5_minutes() {
 fn recency_exactly_30_minutes() {
     let now = Utc::now();
     let created = (now - Duration::minutes(30)).to_rfc3339();
-    let score = recency_score_at(&created, 15.0, now);
+    let score = recency_score_at(&created, 15.0, RecencyCurve::Brackets, 60, now);
     // At 30 min: 5-30 bracket, t=1.0 => 80%
     let expected = 0.8 * 15.0;
     assert!((score - expected).abs() < 0.5);
@@ -353,7 +354,7 @@ fn recency_exactly_30_minutes() {
 fn recency_exactly_60_minutes() {
     let now = Utc::now();
     let created = (now - Duration::minutes(60)).to_rfc3339();
-    let score = recency_score_at(&created, 15.0, now);
+    let score = recency_score_at(&created, 15.0, RecencyCurve::Brackets, 60, now);
     // At 60 min: 30-60 bracket, t=1.0 => 50%
     let expected = 0.5 * 15.0;
     assert!((score - expected).abs() < 0.5);
@@ -363,12 +364,82 @@ fn recency_exactly_60_minutes() {
 fn recency_exactly_6_hours() {
     let now = Utc::now();
     let created = (now - Duration::hours(6)).to_rfc3339();
-    let score = recency_score_at(&created, 15.0, now);
+    let score = recency_score_at(&created, 15.0, RecencyCurve::Brackets, 60, now);
     // At 360 min: 1-6 hour bracket, t=1.0 => 25%
     let expected = 0.25 * 15.0;
     assert!((score - expected).abs() < 0.5);
 }
 
+// --- recency curve / half-life ---
+
+fn score_at_age(curve: RecencyCurve, half_life: u32, age_minutes: i64) -> f32 {
+    let now = Utc::now();
+    let created = (now - Duration::minutes(age_minutes)).to_rfc3339();
+    recency_score_at(&created, 10.0, curve, half_life, now)
+}
+
+#[test]
+fn recency_shorter_half_life_scores_same_age_lower() {
+    for curve in [
+        RecencyCurve::Brackets,
+        RecencyCurve::Linear,
+        RecencyCurve::Exponential,
+    ] {
+        let breaking = score_at_age(curve, 15, 30);
+        let default = score_at_age(curve, 60, 30);
+        let evergreen = score_at_age(curve, 240, 30);
+        assert!(breaking < default, "{curve:?}: {breaking} !< {default}");
+        assert!(default < evergreen, "{curve:?}: {default} !< {evergreen}");
+    }
+}
+
+#[test]
+fn recency_half_score_at_half_life() {
+    for curve in [
+        RecencyCurve::Brackets,
+        RecencyCurve::Linear,
+        RecencyCurve::Exponential,
+    ] {
+        for half_life in [15, 60, 240] {
+            let score = score_at_age(curve, half_life, i64::from(half_life));
+            assert!(
+                (score - 5.0).abs() < 0.01,
+                "{curve:?} at {half_life}m: {score}"
+            );
+        }
+    }
+}
+
+#[test]
+fn recency_brackets_scale_with_half_life() {
+    // 30 minutes at a 30-minute half-life lands where 60 minutes does at 60.
+    let scaled = score_at_age(RecencyCurve::Brackets, 30, 30);
+    let base = score_at_age(RecencyCurve::Brackets, 60, 60);
+    assert!((scaled - base).abs() < 0.01);
+    // The default half-life keeps the original brackets (12h -> 0).
+    assert_eq!(score_at_age(RecencyCurve::Brackets, 60, 12 * 60), 0.0);
+}
+
+#[test]
+fn recency_linear_reaches_zero_at_twice_half_life() {
+    assert_eq!(score_at_age(RecencyCurve::Linear, 30, 60), 0.0);
+    assert!((score_at_age(RecencyCurve::Linear, 30, 15) - 7.5).abs() < 0.01);
+}
+
+#[test]
+fn recency_exponential_halves_each_half_life() {
+    let one = score_at_age(RecencyCurve::Exponential, 20, 20);
+    let two = score_at_age(RecencyCurve::Exponential, 20, 40);
+    assert!((one / two - 2.0).abs() < 0.01);
+    assert!(score_at_age(RecencyCurve::Exponential, 20, 600) > 0.0);
+}
+
+#[test]
+fn recency_zero_half_life_does_not_panic() {
+    let score = score_at_age(RecencyCurve::Exponential, 0, 5);
+    assert!(score.is_finite());
+}
+
 #[test]
 fn recency_score_convenience_wrapper() {
     let now = Utc::now();
@@ -141,6 +141,8 @@ fn display_impl_all_zero_scores() {
         engagement: 0.0,
         reply_count: 0.0,
         content_type: 0.0,
+        author_reputation: 0.0,
+        target_recency: 0.0,
         meets_threshold: false,
     };
     let display = format!("{score}");
@@ -158,6 +160,8 @@ fn display_impl_max_scores() {
         engagement: 15.0,
         reply_count: 15.0,
         content_type: 10.0,
+        author_reputation: 0.0,
+        target_recency: 0.0,
         meets_threshold: true,
     };
     let display = format!("{score}");
@@ -184,6 +188,8 @@ fn tweet_score_debug_format() {
         engagement: 7.0,
         reply_count: 7.0,
         content_type: 5.0,
+        author_reputation: 0.0,
+        target_recency: 0.0,
         meets_threshold: false,
     };
     let debug = format!("{:?}", score);
@@ -205,6 +211,8 @@ fn format_breakdown_text_only_tweet() {
         engagement: 10.0,
         reply_count: 10.0,
         content_type: 5.0,
+        author_reputation: 0.0,
+        target_recency: 0.0,
         meets_threshold: true,
     };
 
@@ -230,6 +238,8 @@ fn format_breakdown_zero_follower_tweet() {
         engagement: 5.0,
         reply_count: 5.0,
         content_type: 5.0,
+        author_reputation: 0.0,
+        target_recency: 0.0,
         meets_threshold: false,
     };
 
@@ -32,6 +32,7 @@ fn score_total_clamped_to_100() {
         engagement_rate_max: 80.0,
         reply_count_max: 80.0,
         content_type_max: 80.0,
+        ..default_scoring_config()
     };
     let keywords = vec!["rust".to_string()];
     let engine = ScoringEngine::new(config, keywords);
@@ -258,6 +259,8 @@ fn format_breakdown_contains_verdict() {
         engagement: 10.0,
         reply_count: 15.0,
         content_type: 10.0,
+        author_reputation: 0.0,
+        target_recency: 0.0,
         meets_threshold: true,
     };
 
@@ -282,6 +285,8 @@ fn format_breakdown_skip_verdict() {
         engagement: 7.0,
         reply_count: 5.0,
         content_type: 5.0,
+        author_reputation: 0.0,
+        target_recency: 0.0,
         meets_threshold: false,
     };
 
@@ -400,6 +405,8 @@ fn display_impl() {
         engagement: 10.0,
         reply_count: 15.0,
         content_type: 10.0,
+        author_reputation: 0.0,
+        target_recency: 0.0,
         meets_threshold: true,
     };
     let display = format!("{score}");
@@ -419,6 +426,8 @@ fn display_impl_skip() {
         engagement: 5.0,
         reply_count: 5.0,
         content_type: 5.0,
+        author_reputation: 0.0,
+        target_recency: 0.0,
         meets_threshold: false,
     };
     let display = format!("{score}");
@@ -464,3 +473,128 @@ fn score_empty_text_no_keyword_match() {
     let score = engine.score_tweet_at(&tweet, now);
     assert_eq!(score.keyword_relevance, 0.0);
 }
+
+// --- Target freshness bonus ---
+
+fn target_engine(accounts: &[&str]) -> ScoringEngine {
+    let targets = crate::config::TargetsConfig {
+        accounts: accounts.iter().map(|a| a.to_string()).collect(),
+        max_target_replies_per_day: 3,
+        recency_bonus_max: 10.0,
+    };
+    ScoringEngine::new(default_scoring_config(), vec!["rust".to_string()]).with_targets(&targets)
+}
+
+#[test]
+fn target_author_scores_higher_than_non_target() {
+    let engine = target_engine(&["@DevUser"]);
+    let now = Utc::now();
+    let target_tweet = test_tweet(now);
+    let mut other_tweet = test_tweet(now);
+    other_tweet.author_username = "someoneelse".to_string();
+
+    let target = engine.score_tweet_at(&target_tweet, now);
+    let other = engine.score_tweet_at(&other_tweet, now);
+
+    assert!(target.target_recency > 0.0);
+    assert_eq!(other.target_recency, 0.0);
+    assert!(target.total > other.total);
+    assert!((target.total - other.total - target.target_recency).abs() < 0.01);
+}
+
+#[test]
+fn target_bonus_decays_exponentially() {
+    let engine = target_engine(&["devuser"]);
+    let now = Utc::now();
+    let mut tweet = test_tweet(now);
+
+    tweet.created_at = now.to_rfc3339();
+    let fresh = engine.score_tweet_at(&tweet, now).target_recency;
+    tweet.created_at = (now - Duration::minutes(60)).to_rfc3339();
+    let one_half_life = engine.score_tweet_at(&tweet, now).target_recency;
+    tweet.created_at = (now - Duration::minutes(120)).to_rfc3339();
+    let two_half_lives = engine.score_tweet_at(&tweet, now).target_recency;
+
+    assert!((fresh - 10.0).abs() < 0.01);
+    assert!((one_half_life - 5.0).abs() < 0.01);
+    assert!((two_half_lives - 2.5).abs() < 0.01);
+}
+
+#[test]
+fn no_target_bonus_without_targets() {
+    let engine = ScoringEngine::new(default_scoring_config(), vec!["rust".to_string()]);
+    let now = Utc::now();
+    let score = engine.score_tweet_at(&test_tweet(now), now);
+    assert_eq!(score.target_recency, 0.0);
+}
+
+// --- Skip reason tests ---
+
+#[test]
+fn skip_reason_points_at_stale_tweet() {
+    let config = ScoringConfig {
+        threshold: 90,
+        recency_max: 40.0,
+        ..default_scoring_config()
+    };
+    let engine = ScoringEngine::new(config.clone(), vec!["rust".to_string(), "cli".to_string()]);
+    let now = Utc::now();
+    let tweet = TweetData {
+        created_at: (now - Duration::hours(7)).to_rfc3339(),
+        ..test_tweet(now)
+    };
+
+    let score = engine.score_tweet_at(&tweet, now);
+    assert!(!score.meets_threshold);
+    assert_eq!(score.recency, 0.0);
+    assert_eq!(score.skip_reason(&config), Some(SkipReason::TooOld));
+}
+
+#[test]
+fn skip_reason_points_at_missing_keywords() {
+    let config = ScoringConfig {
+        threshold: 90,
+        ..default_scoring_config()
+    };
+    let engine = ScoringEngine::new(config.clone(), vec!["python".to_string()]);
+    let now = Utc::now();
+    let tweet = test_tweet(now);
+
+    let score = engine.score_tweet_at(&tweet, now);
+    assert!(!score.meets_threshold);
+    assert_eq!(score.keyword_relevance, 0.0);
+    assert_eq!(score.skip_reason(&config), Some(SkipReason::NoKeywordMatch));
+}
+
+#[test]
+fn skip_reason_is_none_when_tweet_meets_threshold() {
+    let config = ScoringConfig {
+        threshold: 10,
+        ..default_scoring_config()
+    };
+    let engine = ScoringEngine::new(config.clone(), vec!["rust".to_string()]);
+    let now = Utc::now();
+
+    let score = engine.score_tweet_at(&test_tweet(now), now);
+    assert!(score.meets_threshold);
+    assert_eq!(score.skip_reason(&config), None);
+}
+
+#[test]
+fn skip_reason_shows_in_breakdown_verdict() {
+    let config = ScoringConfig {
+        threshold: 90,
+        ..default_scoring_config()
+    };
+    let engine = ScoringEngine::new(config.clone(), vec!["python".to_string()]);
+    let now = Utc::now();
+    let tweet = test_tweet(now);
+
+    let score = engine.score_tweet_at(&tweet, now);
+    let breakdown = score.format_breakdown(&config, &tweet, &[]);
+    assert!(breakdown.contains("Verdict: SKIP (no keyword match)"));
+    assert_eq!(
+        serde_json::to_value(SkipReason::NoKeywordMatch).unwrap(),
+        "no_keyword_match"
+    );
+}
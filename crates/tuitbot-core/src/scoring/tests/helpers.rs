@@ -17,6 +17,8 @@ fn format_breakdown_media_tweet() {
         engagement: 7.0,
         reply_count: 10.0,
         content_type: 0.0,
+        author_reputation: 0.0,
+        target_recency: 0.0,
         meets_threshold: false,
     };
 
@@ -39,6 +41,8 @@ fn format_breakdown_no_keywords_matched() {
         engagement: 15.0,
         reply_count: 10.0,
         content_type: 10.0,
+        author_reputation: 0.0,
+        target_recency: 0.0,
         meets_threshold: true,
     };
 
@@ -117,6 +121,8 @@ fn tweet_score_clone() {
         engagement: 10.0,
         reply_count: 5.0,
         content_type: 5.0,
+        author_reputation: 0.0,
+        target_recency: 0.0,
         meets_threshold: false,
     };
     let cloned = score.clone();
@@ -162,6 +168,8 @@ fn format_breakdown_multiple_keywords() {
         engagement: 10.0,
         reply_count: 10.0,
         content_type: 10.0,
+        author_reputation: 0.0,
+        target_recency: 0.0,
         meets_threshold: true,
     };
 
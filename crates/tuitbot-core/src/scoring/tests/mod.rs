@@ -1,7 +1,7 @@
 //! Scoring module tests.
 
 use super::*;
-use crate::config::ScoringConfig;
+use crate::config::{RecencyCurve, ScoringConfig};
 use chrono::{Duration, Utc};
 
 fn default_scoring_config() -> ScoringConfig {
@@ -13,6 +13,8 @@ fn default_scoring_config() -> ScoringConfig {
         engagement_rate_max: 15.0,
         reply_count_max: 15.0,
         content_type_max: 10.0,
+        recency_curve: RecencyCurve::Brackets,
+        recency_half_life_minutes: 60,
     }
 }
 
@@ -33,3 +35,4 @@ fn test_tweet(now: chrono::DateTime<Utc>) -> TweetData {
 mod edge_cases;
 mod engine;
 mod helpers;
+mod reputation;
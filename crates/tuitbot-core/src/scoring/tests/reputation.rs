@@ -0,0 +1,81 @@
+//! Author reputation adjustment tests.
+
+use super::*;
+use crate::scoring::reputation::{
+    reputation_adjustment, REPUTATION_MAX_ADJUSTMENT, REPUTATION_MIN_SAMPLES,
+};
+use crate::storage::author_reputation::seed_author_history;
+use crate::storage::init_test_db;
+
+#[test]
+fn adjustment_zero_without_enough_samples() {
+    assert_eq!(
+        reputation_adjustment(0.0, REPUTATION_MIN_SAMPLES - 1, 10.0),
+        0.0
+    );
+    assert_eq!(reputation_adjustment(5.0, 10, 0.0), 0.0);
+}
+
+#[test]
+fn adjustment_zero_at_baseline() {
+    assert!(reputation_adjustment(10.0, 5, 10.0).abs() < f32::EPSILON);
+}
+
+#[test]
+fn adjustment_penalizes_and_rewards_within_bounds() {
+    assert_eq!(
+        reputation_adjustment(0.0, 5, 10.0),
+        -REPUTATION_MAX_ADJUSTMENT
+    );
+    assert_eq!(
+        reputation_adjustment(100.0, 5, 10.0),
+        REPUTATION_MAX_ADJUSTMENT
+    );
+    let mild = reputation_adjustment(15.0, 5, 10.0);
+    assert!(mild > 0.0 && mild < REPUTATION_MAX_ADJUSTMENT);
+}
+
+#[tokio::test]
+async fn seeded_histories_rank_authors() {
+    let pool = init_test_db().await.expect("init db");
+    seed_author_history(&pool, "dud", &[0.0, 0.0, 0.0]).await;
+    seed_author_history(&pool, "star", &[30.0, 30.0, 30.0]).await;
+    seed_author_history(&pool, "new", &[30.0]).await;
+
+    let dud = author_reputation(&pool, "dud").await;
+    let star = author_reputation(&pool, "star").await;
+    assert_eq!(dud, -REPUTATION_MAX_ADJUSTMENT);
+    assert!(star > 0.0, "star = {star}");
+    assert_eq!(author_reputation(&pool, "new").await, 0.0);
+    assert_eq!(author_reputation(&pool, "unknown").await, 0.0);
+}
+
+#[tokio::test]
+async fn pool_scoring_folds_in_reputation() {
+    let pool = init_test_db().await.expect("init db");
+    seed_author_history(&pool, "dud", &[0.0, 0.0, 0.0]).await;
+    seed_author_history(&pool, "star", &[30.0, 30.0, 30.0]).await;
+
+    let engine = ScoringEngine::new(default_scoring_config(), vec!["rust".to_string()]);
+    let now = Utc::now();
+    let tweet = test_tweet(now);
+
+    let offline = engine.score_tweet(&tweet);
+    let penalized = engine.score_tweet_with_pool(&pool, "dud", &tweet).await;
+    assert_eq!(penalized.author_reputation, -REPUTATION_MAX_ADJUSTMENT);
+    assert!((offline.total - penalized.total - REPUTATION_MAX_ADJUSTMENT).abs() < 0.5);
+    assert!(penalized.to_string().contains("auth:-5"));
+    assert_eq!(offline.author_reputation, 0.0);
+}
+
+#[test]
+fn precomputed_reputation_is_clamped_into_total() {
+    let engine = ScoringEngine::new(default_scoring_config(), vec![]);
+    let now = Utc::now();
+    let tweet = test_tweet(now);
+
+    let base = engine.score_tweet_at(&tweet, now);
+    let boosted = engine.score_tweet_at_with_reputation(&tweet, now, 3.0);
+    assert!((boosted.total - (base.total + 3.0).min(100.0)).abs() < 0.01);
+    assert_eq!(boosted.author_reputation, 3.0);
+}
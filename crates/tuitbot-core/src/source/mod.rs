@@ -1,13 +1,14 @@
 //! Content source provider abstraction.
 //!
 //! Defines the `ContentSourceProvider` trait that both local filesystem and
-//! remote (e.g. Google Drive) sources implement. The trait covers scanning
+//! remote (e.g. Google Drive, Notion) sources implement. The trait covers scanning
 //! for changed files and reading content — the Watchtower orchestrates
 //! watching vs polling based on source type.
 
 pub mod connector;
 pub mod google_drive;
 pub mod local_fs;
+pub mod notion;
 
 #[cfg(test)]
 mod tests;
@@ -44,7 +45,8 @@ pub enum SourceError {
 /// Metadata about a file discovered by a provider scan.
 #[derive(Debug, Clone)]
 pub struct SourceFile {
-    /// Stable identifier (relative path for local, `gdrive://<id>/<name>` for Drive).
+    /// Stable identifier (relative path for local, `gdrive://<id>/<name>` for Drive,
+    /// `notion://<page_id>` for Notion).
     pub provider_id: String,
     /// Human-readable display name.
     pub display_name: String,
@@ -65,7 +67,7 @@ pub struct SourceFile {
 /// into the shared Watchtower ingest pipeline.
 #[async_trait]
 pub trait ContentSourceProvider: Send + Sync {
-    /// Returns the source type identifier (e.g. `"local_fs"`, `"google_drive"`).
+    /// Returns the source type identifier (e.g. `"local_fs"`, `"google_drive"`, `"notion"`).
     fn source_type(&self) -> &str;
 
     /// Scan for files that changed since `since_cursor`.
@@ -0,0 +1,205 @@
+//! Notion block to markdown conversion.
+//!
+//! Covers the text-bearing block types creators actually draft with
+//! (paragraphs, headings, lists, to-dos, quotes, callouts, code, dividers).
+//! Media, embeds, tables, and child pages are skipped.
+
+use serde_json::Value;
+
+/// A block together with its fetched children.
+#[derive(Debug, Clone)]
+pub(super) struct NotionBlock {
+    pub raw: Value,
+    pub children: Vec<NotionBlock>,
+}
+
+/// Render a page's blocks as markdown.
+pub(super) fn blocks_to_markdown(blocks: &[NotionBlock]) -> String {
+    let mut out = String::new();
+    render(blocks, 0, &mut out);
+    let trimmed = out.trim_end();
+    if trimmed.is_empty() {
+        String::new()
+    } else {
+        format!("{trimmed}\n")
+    }
+}
+
+/// Join the `plain_text` of a rich text array, applying inline formatting.
+pub(super) fn rich_text_to_markdown(rich_text: &Value) -> String {
+    let Some(spans) = rich_text.as_array() else {
+        return String::new();
+    };
+    spans.iter().map(span_to_markdown).collect()
+}
+
+/// Join the unformatted `plain_text` of a rich text array.
+pub(super) fn plain_text(rich_text: &Value) -> String {
+    rich_text
+        .as_array()
+        .map(|spans| {
+            spans
+                .iter()
+                .filter_map(|s| s["plain_text"].as_str())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn span_to_markdown(span: &Value) -> String {
+    let text = span["plain_text"].as_str().unwrap_or("");
+    if text.trim().is_empty() {
+        return text.to_string();
+    }
+
+    let annotations = &span["annotations"];
+    let mut out = text.to_string();
+    if annotations["code"].as_bool() == Some(true) {
+        out = format!("`{out}`");
+    }
+    if annotations["bold"].as_bool() == Some(true) {
+        out = format!("**{out}**");
+    }
+    if annotations["italic"].as_bool() == Some(true) {
+        out = format!("_{out}_");
+    }
+    if annotations["strikethrough"].as_bool() == Some(true) {
+        out = format!("~~{out}~~");
+    }
+    if let Some(href) = span["href"].as_str() {
+        out = format!("[{out}]({href})");
+    }
+    out
+}
+
+fn render(blocks: &[NotionBlock], depth: usize, out: &mut String) {
+    let indent = "  ".repeat(depth);
+    let mut number = 0;
+    let mut in_list = false;
+
+    for block in blocks {
+        let kind = block.raw["type"].as_str().unwrap_or("");
+        let data = &block.raw[kind];
+        let text = rich_text_to_markdown(&data["rich_text"]);
+
+        let list_item = match kind {
+            "bulleted_list_item" | "toggle" => Some(format!("- {text}")),
+            "numbered_list_item" => Some(format!("{}. {text}", number + 1)),
+            "to_do" => {
+                let mark = if data["checked"].as_bool() == Some(true) {
+                    "x"
+                } else {
+                    " "
+                };
+                Some(format!("- [{mark}] {text}"))
+            }
+            _ => None,
+        };
+        number = if kind == "numbered_list_item" {
+            number + 1
+        } else {
+            0
+        };
+
+        if let Some(item) = list_item {
+            out.push_str(&format!("{indent}{item}\n"));
+            render(&block.children, depth + 1, out);
+            in_list = true;
+            continue;
+        }
+
+        let rendered = match kind {
+            "paragraph" => text,
+            "heading_1" => format!("# {text}"),
+            "heading_2" => format!("## {text}"),
+            "heading_3" => format!("### {text}"),
+            "quote" | "callout" => text
+                .lines()
+                .map(|line| format!("> {line}"))
+                .collect::<Vec<_>>()
+                .join("\n"),
+            "code" => {
+                let language = data["language"].as_str().unwrap_or("");
+                let language = if language == "plain text" {
+                    ""
+                } else {
+                    language
+                };
+                format!("```{language}\n{}\n```", plain_text(&data["rich_text"]))
+            }
+            "divider" => "---".to_string(),
+            _ => continue,
+        };
+
+        if in_list {
+            out.push('\n');
+            in_list = false;
+        }
+        if !rendered.is_empty() {
+            for line in rendered.lines() {
+                out.push_str(&format!("{indent}{line}\n"));
+            }
+            out.push('\n');
+        }
+        render(&block.children, depth + 1, out);
+    }
+
+    // Nested lists continue straight into the parent's next item.
+    if in_list && depth == 0 {
+        out.push('\n');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn block(kind: &str, text: &str) -> NotionBlock {
+        NotionBlock {
+            raw: json!({
+                "type": kind,
+                kind: { "rich_text": [{ "plain_text": text, "annotations": {} }] }
+            }),
+            children: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn headings_paragraphs_and_lists() {
+        let mut parent = block("bulleted_list_item", "parent");
+        parent.children.push(block("bulleted_list_item", "child"));
+        let blocks = vec![
+            block("heading_1", "Title"),
+            block("paragraph", "Intro."),
+            parent,
+            block("numbered_list_item", "one"),
+            block("numbered_list_item", "two"),
+            block("divider", ""),
+        ];
+
+        assert_eq!(
+            blocks_to_markdown(&blocks),
+            "# Title\n\nIntro.\n\n- parent\n  - child\n1. one\n2. two\n\n---\n"
+        );
+    }
+
+    #[test]
+    fn inline_annotations_and_links() {
+        let rich = json!([
+            { "plain_text": "bold", "annotations": { "bold": true } },
+            { "plain_text": " and ", "annotations": {} },
+            { "plain_text": "link", "annotations": {}, "href": "https://example.com" }
+        ]);
+        assert_eq!(
+            rich_text_to_markdown(&rich),
+            "**bold** and [link](https://example.com)"
+        );
+    }
+
+    #[test]
+    fn unsupported_blocks_are_skipped() {
+        let blocks = vec![block("image", ""), block("paragraph", "kept")];
+        assert_eq!(blocks_to_markdown(&blocks), "kept\n");
+    }
+}
@@ -832,6 +832,7 @@ async fn e2e_provenance_driven_loopback_writes_to_source_note() {
     // Step 3: Execute loopback.
     let result = loopback::execute_loopback(
         &pool,
+        &loopback::LoopBackThrottle::default(),
         node_id,
         "tweet_lb_001",
         "https://x.com/i/status/tweet_lb_001",
@@ -849,6 +850,7 @@ async fn e2e_provenance_driven_loopback_writes_to_source_note() {
     // Step 5: Idempotent — second call returns AlreadyPresent.
     let result2 = loopback::execute_loopback(
         &pool,
+        &loopback::LoopBackThrottle::default(),
         node_id,
         "tweet_lb_001",
         "https://x.com/i/status/tweet_lb_001",
@@ -898,6 +900,7 @@ async fn e2e_loopback_skips_non_local_sources() {
     // Loopback should return SourceNotWritable.
     let result = loopback::execute_loopback(
         &pool,
+        &loopback::LoopBackThrottle::default(),
         node_id,
         "tweet_skip",
         "https://x.com/i/status/tweet_skip",
@@ -945,6 +948,7 @@ async fn e2e_loopback_multiple_nodes_from_same_post() {
     for node in &nodes {
         let result = loopback::execute_loopback(
             &pool,
+            &loopback::LoopBackThrottle::default(),
             node.id,
             "tweet_multi",
             "https://x.com/i/status/tweet_multi",
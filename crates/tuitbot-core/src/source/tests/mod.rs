@@ -1,4 +1,5 @@
 //! Tests for content source providers.
 
 mod integration;
+mod notion;
 mod unit;
@@ -0,0 +1,178 @@
+//! NotionProvider tests against recorded Notion API responses.
+
+use serde_json::json;
+use wiremock::matchers::{body_partial_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+use crate::source::notion::NotionProvider;
+use crate::source::{ContentSourceProvider, SourceError};
+
+const DATABASE_ID: &str = "d9824bdc-8445-4327-be8b-5b47500af6ce";
+const PAGE_ID: &str = "59833787-2cf9-4fdf-8782-e53db20768a5";
+
+fn fixture(name: &str) -> serde_json::Value {
+    let raw = match name {
+        "database_query" => include_str!("fixtures/notion/database_query.json"),
+        "database_query_page2" => include_str!("fixtures/notion/database_query_page2.json"),
+        "page" => include_str!("fixtures/notion/page.json"),
+        "page_blocks" => include_str!("fixtures/notion/page_blocks.json"),
+        "list_item_children" => include_str!("fixtures/notion/list_item_children.json"),
+        _ => panic!("unknown fixture {name}"),
+    };
+    serde_json::from_str(raw).unwrap()
+}
+
+fn provider(server: &MockServer) -> NotionProvider {
+    NotionProvider::with_base_url(
+        DATABASE_ID.to_string(),
+        "secret_test".to_string(),
+        server.uri(),
+    )
+}
+
+#[tokio::test]
+async fn scan_pages_through_results_and_skips_archived() {
+    let server = MockServer::start().await;
+    let query_path = format!("/databases/{DATABASE_ID}/query");
+    Mock::given(method("POST"))
+        .and(path(query_path.as_str()))
+        .and(body_partial_json(
+            json!({ "start_cursor": "a1b2c3d4-0000-4000-8000-000000000003" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("database_query_page2")))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(query_path.as_str()))
+        .and(header("authorization", "Bearer secret_test"))
+        .and(header("notion-version", "2022-06-28"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("database_query")))
+        .mount(&server)
+        .await;
+
+    let files = provider(&server).scan_for_changes(None, &[]).await.unwrap();
+
+    assert_eq!(files.len(), 2, "archived page is skipped");
+    assert_eq!(files[0].provider_id, format!("notion://{PAGE_ID}"));
+    assert_eq!(files[0].display_name, "Why founders should reply more");
+    assert_eq!(files[0].modified_at, "2026-03-01T10:15:00.000Z");
+    // Untitled pages fall back to their ID.
+    assert_eq!(
+        files[1].display_name,
+        "a1b2c3d4-0000-4000-8000-000000000003"
+    );
+}
+
+#[tokio::test]
+async fn scan_filters_by_last_edited_time_cursor() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .and(body_partial_json(json!({
+            "filter": {
+                "timestamp": "last_edited_time",
+                "last_edited_time": { "on_or_after": "2026-03-01T10:00:00+00:00" }
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("database_query_page2")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let files = provider(&server)
+        .scan_for_changes(Some("2026-03-01T10:00:30+00:00"), &["*.md".to_string()])
+        .await
+        .unwrap();
+
+    assert_eq!(files.len(), 1, "file patterns do not filter Notion pages");
+}
+
+#[tokio::test]
+async fn read_content_renders_title_and_nested_blocks() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/pages/{PAGE_ID}").as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("page")))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/blocks/{PAGE_ID}/children").as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("page_blocks")))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(
+            "/blocks/b0000000-0000-4000-8000-000000000003/children",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("list_item_children")))
+        .mount(&server)
+        .await;
+
+    let content = provider(&server)
+        .read_content(&format!("notion://{PAGE_ID}"))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        content,
+        "---\ntitle: \"Why founders should reply more\"\n---\n\
+         ## The short version\n\n\
+         Replies are **distribution**.\n\n\
+         - Show up in threads you already read\n  \
+         - [x] Ten replies a day\n"
+    );
+}
+
+#[tokio::test]
+async fn read_content_ingests_with_page_title() {
+    let server = MockServer::start().await;
+    Mock::given(method("GET"))
+        .and(path(format!("/pages/{PAGE_ID}").as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("page")))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(format!("/blocks/{PAGE_ID}/children").as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(fixture("list_item_children")))
+        .mount(&server)
+        .await;
+
+    let pool = crate::storage::init_test_db().await.unwrap();
+    let source_id = crate::storage::watchtower::ensure_notion_source(&pool, DATABASE_ID, "{}")
+        .await
+        .unwrap();
+    let provider_id = format!("notion://{PAGE_ID}");
+    let content = provider(&server).read_content(&provider_id).await.unwrap();
+    crate::automation::watchtower::ingest_content(&pool, source_id, &provider_id, &content, false)
+        .await
+        .unwrap();
+
+    let nodes = crate::storage::watchtower::get_nodes_for_source(&pool, source_id, None)
+        .await
+        .unwrap();
+    assert_eq!(nodes.len(), 1);
+    assert_eq!(
+        nodes[0].title.as_deref(),
+        Some("Why founders should reply more")
+    );
+    assert_eq!(nodes[0].body_text, "- [x] Ten replies a day\n");
+}
+
+#[tokio::test]
+async fn unauthorized_token_is_auth_error() {
+    let server = MockServer::start().await;
+    Mock::given(method("POST"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "object": "error",
+            "status": 401,
+            "code": "unauthorized",
+            "message": "API token is invalid."
+        })))
+        .mount(&server)
+        .await;
+
+    let err = provider(&server)
+        .scan_for_changes(None, &[])
+        .await
+        .unwrap_err();
+    assert!(matches!(err, SourceError::Auth(_)), "got: {err}");
+}
@@ -92,7 +92,8 @@ impl TierCapabilities {
 // Stored Tokens
 // ============================================================================
 
-/// OAuth tokens persisted to disk at `~/.tuitbot/tokens.json`.
+/// OAuth tokens persisted by a [`super::TokenStore`], by default at
+/// `~/.tuitbot/tokens.json`.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StoredTokens {
     /// OAuth 2.0 access token.
@@ -111,6 +112,30 @@ pub struct StoredTokens {
     pub scopes: Vec<String>,
 }
 
+impl From<&crate::x_api::auth::Tokens> for StoredTokens {
+    fn from(tokens: &crate::x_api::auth::Tokens) -> Self {
+        Self {
+            access_token: tokens.access_token.clone(),
+            refresh_token: Some(tokens.refresh_token.clone()).filter(|t| !t.is_empty()),
+            expires_at: Some(tokens.expires_at),
+            scopes: tokens.scopes.clone(),
+        }
+    }
+}
+
+impl From<StoredTokens> for crate::x_api::auth::Tokens {
+    /// Tokens without an expiry are treated as already expired, so the
+    /// token manager refreshes them on first use.
+    fn from(stored: StoredTokens) -> Self {
+        Self {
+            access_token: stored.access_token,
+            refresh_token: stored.refresh_token.unwrap_or_default(),
+            expires_at: stored.expires_at.unwrap_or_else(chrono::Utc::now),
+            scopes: stored.scopes,
+        }
+    }
+}
+
 impl StoredTokens {
     /// Check if the token has expired.
     pub fn is_expired(&self) -> bool {
@@ -158,6 +183,40 @@ impl StoredTokens {
     }
 }
 
+// ============================================================================
+// Pending Auth State
+// ============================================================================
+
+/// How long a persisted auth session stays valid, in minutes.
+pub const AUTH_STATE_TTL_MINUTES: i64 = 10;
+
+/// PKCE verifier and CSRF state for an in-progress `tuitbot auth` session,
+/// persisted to `~/.tuitbot/auth_state.json` so the callback can be
+/// validated by a later invocation.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingAuthState {
+    /// PKCE code verifier (sent during token exchange).
+    pub verifier: String,
+    /// CSRF state parameter sent in the authorization URL.
+    pub state: String,
+    /// Redirect URI the authorization URL was built with.
+    pub redirect_uri: String,
+    /// When the session was started.
+    pub created_at: chrono::DateTime<chrono::Utc>,
+}
+
+impl PendingAuthState {
+    /// Whether the session is older than [`AUTH_STATE_TTL_MINUTES`] at `now`.
+    pub fn is_expired_at(&self, now: chrono::DateTime<chrono::Utc>) -> bool {
+        now - self.created_at >= chrono::TimeDelta::minutes(AUTH_STATE_TTL_MINUTES)
+    }
+
+    /// Whether a callback's `state` parameter belongs to this session.
+    pub fn matches(&self, returned_state: &str) -> bool {
+        !returned_state.is_empty() && returned_state == self.state
+    }
+}
+
 // ============================================================================
 // Startup Error
 // ============================================================================
@@ -2,7 +2,8 @@
 
 use std::path::PathBuf;
 
-use super::config::{StartupError, StoredTokens};
+use super::config::{PendingAuthState, StartupError, StoredTokens};
+use super::token_store::{FileTokenStore, TokenStore};
 
 // ============================================================================
 // Token File I/O
@@ -21,17 +22,12 @@ pub fn token_file_path() -> PathBuf {
 }
 
 /// Load OAuth tokens from the default file path.
+///
+/// Ignores `auth.token_storage`; use [`super::load_tokens`] to honor it.
 pub fn load_tokens_from_file() -> Result<StoredTokens, StartupError> {
-    let path = token_file_path();
-    let contents = std::fs::read_to_string(&path).map_err(|e| {
-        if e.kind() == std::io::ErrorKind::NotFound {
-            StartupError::AuthRequired
-        } else {
-            StartupError::Io(e)
-        }
-    })?;
-    serde_json::from_str(&contents)
-        .map_err(|e| StartupError::Other(format!("failed to parse tokens file: {e}")))
+    FileTokenStore::new(token_file_path())
+        .load()?
+        .ok_or(StartupError::AuthRequired)
 }
 
 /// Save OAuth tokens to the default file path with secure permissions.
@@ -39,25 +35,76 @@ pub fn load_tokens_from_file() -> Result<StoredTokens, StartupError> {
 /// Creates the `~/.tuitbot/` directory if it does not exist.
 /// On Unix, sets file permissions to 0600 (owner read/write only).
 pub fn save_tokens_to_file(tokens: &StoredTokens) -> Result<(), StartupError> {
-    let dir = data_dir();
-    std::fs::create_dir_all(&dir)?;
+    FileTokenStore::new(token_file_path()).save(tokens)
+}
+
+// ============================================================================
+// Auth State File I/O
+// ============================================================================
+
+/// Path to the pending auth session file (`~/.tuitbot/auth_state.json`).
+pub fn auth_state_file_path() -> PathBuf {
+    data_dir().join("auth_state.json")
+}
+
+/// Persist a pending auth session with secure permissions.
+///
+/// Creates the parent directory if needed. On Unix, the file is 0600
+/// because it holds the PKCE verifier.
+pub fn save_auth_state(
+    path: &std::path::Path,
+    auth_state: &PendingAuthState,
+) -> Result<(), StartupError> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
 
-    let path = token_file_path();
-    let json = serde_json::to_string_pretty(tokens)
-        .map_err(|e| StartupError::Other(format!("failed to serialize tokens: {e}")))?;
-    std::fs::write(&path, json)?;
+    let json = serde_json::to_string_pretty(auth_state)
+        .map_err(|e| StartupError::Other(format!("failed to serialize auth state: {e}")))?;
+    std::fs::write(path, json)?;
 
-    // Set file permissions to 0600 on Unix (owner read/write only).
     #[cfg(unix)]
     {
         use std::os::unix::fs::PermissionsExt;
         let perms = std::fs::Permissions::from_mode(0o600);
-        std::fs::set_permissions(&path, perms)?;
+        std::fs::set_permissions(path, perms)?;
     }
 
     Ok(())
 }
 
+/// Load a pending auth session that is still valid at `now`.
+///
+/// Returns `None` when no session is stored. An expired session is
+/// deleted and also reported as `None`.
+pub fn load_auth_state(
+    path: &std::path::Path,
+    now: chrono::DateTime<chrono::Utc>,
+) -> Result<Option<PendingAuthState>, StartupError> {
+    let contents = match std::fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(StartupError::Io(e)),
+    };
+    let auth_state: PendingAuthState = serde_json::from_str(&contents)
+        .map_err(|e| StartupError::Other(format!("failed to parse auth state file: {e}")))?;
+
+    if auth_state.is_expired_at(now) {
+        clear_auth_state(path)?;
+        return Ok(None);
+    }
+    Ok(Some(auth_state))
+}
+
+/// Delete the pending auth session file. A missing file is not an error.
+pub fn clear_auth_state(path: &std::path::Path) -> Result<(), StartupError> {
+    match std::fs::remove_file(path) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) => Err(StartupError::Io(e)),
+    }
+}
+
 // ============================================================================
 // Path Helpers
 // ============================================================================
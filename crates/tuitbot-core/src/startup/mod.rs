@@ -6,25 +6,34 @@
 //! CLI commands.
 //!
 //! ## Module layout
-//! - `config`   — ApiTier, TierCapabilities, StoredTokens, StartupError
-//! - `db`       — token file I/O, path helpers (data_dir, expand_tilde, validate_db_path)
+//! - `config`   — ApiTier, TierCapabilities, StoredTokens, PendingAuthState, StartupError
+//! - `db`       — token and auth state file I/O, path helpers (data_dir, expand_tilde, validate_db_path)
 //! - `services` — PKCE, OAuth URL building, token exchange, credential verification, banner
+//! - `token_store` — TokenStore trait with file and OS keychain stores (`auth.token_storage`)
 
 pub mod config;
 pub mod db;
 pub mod services;
+pub mod token_store;
 
 #[cfg(test)]
 mod tests;
 
 // Re-export the entire public API so callers use `startup::*` unchanged.
-pub use config::{ApiTier, StartupError, StoredTokens, TierCapabilities};
+pub use config::{
+    ApiTier, PendingAuthState, StartupError, StoredTokens, TierCapabilities, AUTH_STATE_TTL_MINUTES,
+};
 pub use db::{
-    data_dir, expand_tilde, load_tokens_from_file, resolve_db_path, save_tokens_to_file,
-    token_file_path, validate_db_path,
+    auth_state_file_path, clear_auth_state, data_dir, expand_tilde, load_auth_state,
+    load_tokens_from_file, resolve_db_path, save_auth_state, save_tokens_to_file, token_file_path,
+    validate_db_path,
 };
 pub use services::{
     build_auth_url, build_redirect_uri, exchange_auth_code, extract_auth_code,
     extract_callback_state, format_startup_banner, generate_pkce, verify_credentials,
     PkceChallenge, X_AUTH_URL, X_TOKEN_URL, X_USERS_ME_URL,
 };
+pub use token_store::{
+    account_token_store, load_tokens, save_tokens, token_store, token_store_at, FileTokenStore,
+    KeychainTokenStore, MigratingTokenStore, TokenStore,
+};
@@ -3,7 +3,8 @@
 use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
 use sha2::{Digest, Sha256};
 
-use crate::startup::config::{ApiTier, StartupError, TierCapabilities};
+use crate::startup::config::{ApiTier, PendingAuthState, StartupError, TierCapabilities};
+use crate::startup::db::{clear_auth_state, load_auth_state, save_auth_state};
 use crate::startup::services::{
     build_auth_url, build_redirect_uri, extract_auth_code, format_startup_banner, generate_pkce,
     url_encode, X_AUTH_URL,
@@ -199,3 +200,88 @@ fn url_encode_special_chars() {
 fn url_encode_empty() {
     assert_eq!(url_encode(""), "");
 }
+
+// ============================================================================
+// Pending auth state
+// ============================================================================
+
+fn pending_auth_state(created_at: chrono::DateTime<chrono::Utc>) -> PendingAuthState {
+    PendingAuthState {
+        verifier: "verifier-abc".to_string(),
+        state: "state-xyz".to_string(),
+        redirect_uri: "http://127.0.0.1:8080/callback".to_string(),
+        created_at,
+    }
+}
+
+#[test]
+fn auth_state_persist_and_load_roundtrip() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("nested").join("auth_state.json");
+    let now = chrono::Utc::now();
+    let saved = pending_auth_state(now);
+
+    save_auth_state(&path, &saved).expect("save");
+    let loaded = load_auth_state(&path, now).expect("load");
+
+    assert_eq!(loaded, Some(saved));
+}
+
+#[cfg(unix)]
+#[test]
+fn auth_state_file_is_owner_only() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("auth_state.json");
+    save_auth_state(&path, &pending_auth_state(chrono::Utc::now())).expect("save");
+
+    let mode = std::fs::metadata(&path)
+        .expect("metadata")
+        .permissions()
+        .mode();
+    assert_eq!(mode & 0o777, 0o600);
+}
+
+#[test]
+fn auth_state_missing_file_loads_none() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("auth_state.json");
+    assert!(load_auth_state(&path, chrono::Utc::now())
+        .expect("load")
+        .is_none());
+}
+
+#[test]
+fn auth_state_expires_after_ttl_and_is_deleted() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("auth_state.json");
+    let started = chrono::Utc::now();
+    save_auth_state(&path, &pending_auth_state(started)).expect("save");
+
+    let just_before = started + chrono::TimeDelta::minutes(9);
+    assert!(load_auth_state(&path, just_before).expect("load").is_some());
+
+    let after = started + chrono::TimeDelta::minutes(10);
+    assert!(load_auth_state(&path, after).expect("load").is_none());
+    assert!(!path.exists(), "expired state should be removed");
+}
+
+#[test]
+fn auth_state_matches_only_its_own_state() {
+    let auth_state = pending_auth_state(chrono::Utc::now());
+    assert!(auth_state.matches("state-xyz"));
+    assert!(!auth_state.matches("other"));
+    assert!(!auth_state.matches(""));
+}
+
+#[test]
+fn clear_auth_state_is_idempotent() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("auth_state.json");
+    save_auth_state(&path, &pending_auth_state(chrono::Utc::now())).expect("save");
+
+    clear_auth_state(&path).expect("clear");
+    assert!(!path.exists());
+    clear_auth_state(&path).expect("clear missing file");
+}
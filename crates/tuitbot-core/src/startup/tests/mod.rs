@@ -2,10 +2,12 @@
 //!
 //! - `tier`   — ApiTier and TierCapabilities
 //! - `tokens` — StoredTokens, file I/O, scope analysis
-//! - `auth`   — StartupError, PKCE, URL building, banner
+//! - `auth`   — StartupError, PKCE, URL building, banner, pending auth state
 //! - `paths`  — Path helpers, validate_db_path, resolve_db_path, callback state
+//! - `token_store` — TokenStore file store and keychain migration against a fake store
 
 mod auth;
 mod paths;
 mod tier;
+mod token_store;
 mod tokens;
@@ -0,0 +1,203 @@
+//! Tests for the TokenStore trait: file store and migration between stores.
+
+use std::sync::{Arc, Mutex};
+
+use crate::startup::config::{StartupError, StoredTokens};
+use crate::startup::token_store::{
+    account_token_store, FileTokenStore, MigratingTokenStore, TokenStore,
+};
+use crate::storage::accounts::{account_token_path, DEFAULT_ACCOUNT_ID};
+
+/// In-memory stand-in for the OS keychain. Clones share the same slot.
+#[derive(Clone, Default)]
+struct MemoryTokenStore {
+    tokens: Arc<Mutex<Option<StoredTokens>>>,
+}
+
+impl MemoryTokenStore {
+    fn holding(access_token: &str) -> Self {
+        Self {
+            tokens: Arc::new(Mutex::new(Some(sample(access_token)))),
+        }
+    }
+
+    fn access_token(&self) -> Option<String> {
+        self.tokens
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(|t| t.access_token.clone())
+    }
+}
+
+impl TokenStore for MemoryTokenStore {
+    fn load(&self) -> Result<Option<StoredTokens>, StartupError> {
+        Ok(self.tokens.lock().unwrap().clone())
+    }
+
+    fn save(&self, tokens: &StoredTokens) -> Result<(), StartupError> {
+        *self.tokens.lock().unwrap() = Some(tokens.clone());
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), StartupError> {
+        *self.tokens.lock().unwrap() = None;
+        Ok(())
+    }
+
+    fn location(&self) -> String {
+        "memory".to_string()
+    }
+}
+
+fn sample(access_token: &str) -> StoredTokens {
+    StoredTokens {
+        access_token: access_token.to_string(),
+        refresh_token: Some("refresh".to_string()),
+        expires_at: Some(chrono::Utc::now() + chrono::TimeDelta::hours(2)),
+        scopes: vec!["tweet.read".to_string()],
+    }
+}
+
+#[test]
+fn file_store_round_trips_and_deletes() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let store = FileTokenStore::new(dir.path().join("nested").join("tokens.json"));
+
+    assert!(store.load().unwrap().is_none());
+    store.save(&sample("file-token")).unwrap();
+
+    let loaded = store.load().unwrap().expect("tokens saved");
+    assert_eq!(loaded.access_token, "file-token");
+    assert_eq!(loaded.refresh_token.as_deref(), Some("refresh"));
+    assert_eq!(loaded.scopes, vec!["tweet.read".to_string()]);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let meta = std::fs::metadata(dir.path().join("nested").join("tokens.json")).unwrap();
+        assert_eq!(meta.permissions().mode() & 0o777, 0o600);
+    }
+
+    store.delete().unwrap();
+    assert!(store.load().unwrap().is_none());
+    store.delete().expect("deleting nothing is fine");
+}
+
+#[test]
+fn file_store_reports_corrupt_file() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("tokens.json");
+    std::fs::write(&path, "not json").unwrap();
+
+    let err = FileTokenStore::new(path).load().unwrap_err();
+    assert!(err.to_string().contains("failed to parse tokens file"));
+}
+
+#[test]
+fn migrating_store_moves_legacy_tokens_on_first_load() {
+    let (primary, legacy) = (
+        MemoryTokenStore::default(),
+        MemoryTokenStore::holding("old"),
+    );
+    let store = MigratingTokenStore::new(primary.clone(), legacy.clone());
+
+    let loaded = store.load().unwrap().expect("legacy tokens migrated");
+    assert_eq!(loaded.access_token, "old");
+    assert_eq!(primary.access_token().as_deref(), Some("old"));
+    assert!(legacy.access_token().is_none());
+
+    // Later loads come from the primary store.
+    assert_eq!(store.load().unwrap().unwrap().access_token, "old");
+}
+
+#[test]
+fn migrating_store_prefers_legacy_tokens_written_later() {
+    let primary = MemoryTokenStore::holding("keychain");
+    let store = MigratingTokenStore::new(
+        primary.clone(),
+        MemoryTokenStore::holding("reauthenticated"),
+    );
+
+    assert_eq!(
+        store.load().unwrap().unwrap().access_token,
+        "reauthenticated"
+    );
+    assert_eq!(primary.access_token().as_deref(), Some("reauthenticated"));
+}
+
+#[test]
+fn migrating_store_saves_and_deletes_primary_only() {
+    let (primary, legacy) = (MemoryTokenStore::default(), MemoryTokenStore::default());
+    let store = MigratingTokenStore::new(primary.clone(), legacy.clone());
+    assert!(store.load().unwrap().is_none());
+
+    store.save(&sample("new")).unwrap();
+    assert_eq!(primary.access_token().as_deref(), Some("new"));
+    assert!(legacy.access_token().is_none());
+    assert_eq!(store.location(), "memory");
+
+    store.delete().unwrap();
+    assert!(store.load().unwrap().is_none());
+}
+
+#[test]
+fn stored_tokens_from_refreshed_tokens() {
+    let refreshed = crate::x_api::auth::Tokens {
+        access_token: "access".to_string(),
+        refresh_token: String::new(),
+        expires_at: chrono::Utc::now(),
+        scopes: vec!["tweet.write".to_string()],
+    };
+    let stored = StoredTokens::from(&refreshed);
+    assert_eq!(stored.access_token, "access");
+    assert_eq!(stored.refresh_token, None);
+    assert_eq!(stored.expires_at, Some(refreshed.expires_at));
+    assert_eq!(stored.scopes, refreshed.scopes);
+}
+
+#[test]
+fn account_token_store_uses_account_file() {
+    let dir = tempfile::tempdir().unwrap();
+    let auth = crate::config::AuthConfig::default();
+
+    let default = account_token_store(&auth, dir.path(), DEFAULT_ACCOUNT_ID);
+    assert_eq!(
+        default.location(),
+        dir.path().join("tokens.json").display().to_string()
+    );
+
+    let other = account_token_store(&auth, dir.path(), "acct-2");
+    other.save(&sample("second")).unwrap();
+    assert_eq!(
+        other.location(),
+        account_token_path(dir.path(), "acct-2")
+            .display()
+            .to_string()
+    );
+    assert!(default.load().unwrap().is_none());
+    assert_eq!(other.load().unwrap().unwrap().access_token, "second");
+}
+
+#[test]
+fn tokens_from_stored_without_expiry_are_due_for_refresh() {
+    let before = chrono::Utc::now();
+    let tokens = crate::x_api::auth::Tokens::from(StoredTokens {
+        access_token: "access".to_string(),
+        refresh_token: None,
+        expires_at: None,
+        scopes: vec![],
+    });
+    assert_eq!(tokens.refresh_token, "");
+    assert!(tokens.expires_at >= before);
+    assert!(tokens.expires_at <= chrono::Utc::now());
+}
+
+#[cfg(not(feature = "keychain"))]
+#[test]
+fn keychain_store_without_feature_names_the_feature() {
+    let store = crate::startup::token_store::KeychainTokenStore::new("tuitbot", "test");
+    let err = store.load().unwrap_err().to_string();
+    assert!(err.contains("`keychain` feature"), "{err}");
+    assert!(store.save(&sample("a")).is_err());
+}
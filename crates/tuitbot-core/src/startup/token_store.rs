@@ -0,0 +1,292 @@
+//! Pluggable OAuth token storage, selected by `auth.token_storage`.
+//!
+//! `file` (the default) keeps tokens in `~/.tuitbot/tokens.json`, readable
+//! only by the owner on Unix. `keychain` keeps them in the OS credential
+//! store (macOS Keychain, Windows Credential Manager, Secret Service on
+//! Linux). The first time the keychain store is used, an existing tokens
+//! file is moved into it and the plaintext file is deleted. The keychain
+//! store needs the `keychain` cargo feature; without it every operation
+//! fails with an error saying so.
+
+use std::path::{Path, PathBuf};
+
+use super::config::{StartupError, StoredTokens};
+use super::db::token_file_path;
+use crate::config::AuthConfig;
+use crate::storage::accounts::{account_token_path, DEFAULT_ACCOUNT_ID};
+
+/// Keychain service name Tuitbot credentials are stored under.
+pub const KEYCHAIN_SERVICE: &str = "tuitbot";
+
+/// Keychain account name for the X API OAuth tokens.
+pub const KEYCHAIN_ACCOUNT: &str = "x-oauth-tokens";
+
+/// Somewhere OAuth tokens can be loaded from and saved to.
+pub trait TokenStore: Send + Sync {
+    /// Load the stored tokens, or `None` when nothing is stored.
+    fn load(&self) -> Result<Option<StoredTokens>, StartupError>;
+
+    /// Replace the stored tokens.
+    fn save(&self, tokens: &StoredTokens) -> Result<(), StartupError>;
+
+    /// Remove the stored tokens. Removing nothing is not an error.
+    fn delete(&self) -> Result<(), StartupError>;
+
+    /// Where the tokens live, for messages ("/home/me/.tuitbot/tokens.json").
+    fn location(&self) -> String;
+}
+
+/// Build the store configured by `auth.token_storage`.
+///
+/// Unknown values fall back to the file store; config validation reports them.
+pub fn token_store(auth: &AuthConfig) -> Box<dyn TokenStore> {
+    token_store_at(auth, token_file_path(), KEYCHAIN_ACCOUNT)
+}
+
+/// Build the configured store for one account's tokens under `data_dir`.
+///
+/// The default account uses the same file and keychain entry as
+/// [`token_store`], so the CLI and the server see the same tokens.
+pub fn account_token_store(
+    auth: &AuthConfig,
+    data_dir: &Path,
+    account_id: &str,
+) -> Box<dyn TokenStore> {
+    let path = account_token_path(data_dir, account_id);
+    if account_id == DEFAULT_ACCOUNT_ID {
+        token_store_at(auth, path, KEYCHAIN_ACCOUNT)
+    } else {
+        token_store_at(auth, path, &format!("{KEYCHAIN_ACCOUNT}:{account_id}"))
+    }
+}
+
+/// Build the configured store for tokens kept in the file at `path`, or
+/// under the `keychain_account` entry when `auth.token_storage = "keychain"`.
+pub fn token_store_at(
+    auth: &AuthConfig,
+    path: PathBuf,
+    keychain_account: &str,
+) -> Box<dyn TokenStore> {
+    let file = FileTokenStore::new(path);
+    match auth.token_storage.as_str() {
+        "keychain" => Box::new(MigratingTokenStore::new(
+            KeychainTokenStore::new(KEYCHAIN_SERVICE, keychain_account),
+            file,
+        )),
+        _ => Box::new(file),
+    }
+}
+
+/// Load tokens from the configured store.
+///
+/// Returns [`StartupError::AuthRequired`] when no tokens are stored.
+pub fn load_tokens(auth: &AuthConfig) -> Result<StoredTokens, StartupError> {
+    token_store(auth).load()?.ok_or(StartupError::AuthRequired)
+}
+
+/// Save tokens to the configured store.
+pub fn save_tokens(auth: &AuthConfig, tokens: &StoredTokens) -> Result<(), StartupError> {
+    token_store(auth).save(tokens)
+}
+
+// ============================================================================
+// File store
+// ============================================================================
+
+/// Tokens as pretty-printed JSON in a file, mode 0600 on Unix.
+#[derive(Debug, Clone)]
+pub struct FileTokenStore {
+    path: PathBuf,
+}
+
+impl FileTokenStore {
+    /// Store tokens at `path`. Parent directories are created on save.
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+}
+
+impl TokenStore for FileTokenStore {
+    fn load(&self) -> Result<Option<StoredTokens>, StartupError> {
+        let contents = match std::fs::read_to_string(&self.path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(StartupError::Io(e)),
+        };
+        serde_json::from_str(&contents)
+            .map(Some)
+            .map_err(|e| StartupError::Other(format!("failed to parse tokens file: {e}")))
+    }
+
+    fn save(&self, tokens: &StoredTokens) -> Result<(), StartupError> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string_pretty(tokens)
+            .map_err(|e| StartupError::Other(format!("failed to serialize tokens: {e}")))?;
+        std::fs::write(&self.path, json)?;
+
+        // Set file permissions to 0600 on Unix (owner read/write only).
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let perms = std::fs::Permissions::from_mode(0o600);
+            std::fs::set_permissions(&self.path, perms)?;
+        }
+
+        Ok(())
+    }
+
+    fn delete(&self) -> Result<(), StartupError> {
+        match std::fs::remove_file(&self.path) {
+            Ok(()) => Ok(()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+            Err(e) => Err(StartupError::Io(e)),
+        }
+    }
+
+    fn location(&self) -> String {
+        self.path.display().to_string()
+    }
+}
+
+// ============================================================================
+// Keychain store
+// ============================================================================
+
+/// Tokens as a JSON secret in the OS credential store.
+#[derive(Debug, Clone)]
+pub struct KeychainTokenStore {
+    service: String,
+    account: String,
+}
+
+impl KeychainTokenStore {
+    /// Store tokens under the `service`/`account` keychain entry.
+    pub fn new(service: &str, account: &str) -> Self {
+        Self {
+            service: service.to_string(),
+            account: account.to_string(),
+        }
+    }
+
+    #[cfg(feature = "keychain")]
+    fn entry(&self) -> Result<keyring::Entry, StartupError> {
+        keyring::Entry::new(&self.service, &self.account).map_err(keychain_error)
+    }
+}
+
+#[cfg(feature = "keychain")]
+impl TokenStore for KeychainTokenStore {
+    fn load(&self) -> Result<Option<StoredTokens>, StartupError> {
+        let secret = match self.entry()?.get_password() {
+            Ok(secret) => secret,
+            Err(keyring::Error::NoEntry) => return Ok(None),
+            Err(e) => return Err(keychain_error(e)),
+        };
+        serde_json::from_str(&secret)
+            .map(Some)
+            .map_err(|e| StartupError::Other(format!("failed to parse keychain tokens: {e}")))
+    }
+
+    fn save(&self, tokens: &StoredTokens) -> Result<(), StartupError> {
+        let json = serde_json::to_string(tokens)
+            .map_err(|e| StartupError::Other(format!("failed to serialize tokens: {e}")))?;
+        self.entry()?.set_password(&json).map_err(keychain_error)
+    }
+
+    fn delete(&self) -> Result<(), StartupError> {
+        match self.entry()?.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(keychain_error(e)),
+        }
+    }
+
+    fn location(&self) -> String {
+        format!("OS keychain ({}/{})", self.service, self.account)
+    }
+}
+
+#[cfg(feature = "keychain")]
+fn keychain_error(e: keyring::Error) -> StartupError {
+    StartupError::Other(format!("keychain error: {e}"))
+}
+
+#[cfg(not(feature = "keychain"))]
+impl TokenStore for KeychainTokenStore {
+    fn load(&self) -> Result<Option<StoredTokens>, StartupError> {
+        Err(keychain_unavailable())
+    }
+
+    fn save(&self, _tokens: &StoredTokens) -> Result<(), StartupError> {
+        Err(keychain_unavailable())
+    }
+
+    fn delete(&self) -> Result<(), StartupError> {
+        Err(keychain_unavailable())
+    }
+
+    fn location(&self) -> String {
+        format!("OS keychain ({}/{})", self.service, self.account)
+    }
+}
+
+#[cfg(not(feature = "keychain"))]
+fn keychain_unavailable() -> StartupError {
+    StartupError::Other(
+        "auth.token_storage = \"keychain\" needs a build with the `keychain` feature; \
+         reinstall with `--features keychain` or set token_storage = \"file\""
+            .to_string(),
+    )
+}
+
+// ============================================================================
+// Migration
+// ============================================================================
+
+/// A store that first moves tokens out of a legacy store.
+///
+/// Whenever the legacy store holds tokens (for example a `tokens.json` left
+/// from before `auth.token_storage = "keychain"`, or written by an older
+/// binary), `load` copies them into the primary store and deletes them from
+/// the legacy one. Saves and deletes go to the primary store only.
+pub struct MigratingTokenStore<P, L> {
+    primary: P,
+    legacy: L,
+}
+
+impl<P: TokenStore, L: TokenStore> MigratingTokenStore<P, L> {
+    /// Keep tokens in `primary`, importing any found in `legacy`.
+    pub fn new(primary: P, legacy: L) -> Self {
+        Self { primary, legacy }
+    }
+}
+
+impl<P: TokenStore, L: TokenStore> TokenStore for MigratingTokenStore<P, L> {
+    fn load(&self) -> Result<Option<StoredTokens>, StartupError> {
+        let Some(tokens) = self.legacy.load()? else {
+            return self.primary.load();
+        };
+        self.primary.save(&tokens)?;
+        self.legacy.delete()?;
+        tracing::info!(
+            from = %self.legacy.location(),
+            to = %self.primary.location(),
+            "Moved stored OAuth tokens"
+        );
+        Ok(Some(tokens))
+    }
+
+    fn save(&self, tokens: &StoredTokens) -> Result<(), StartupError> {
+        self.primary.save(tokens)
+    }
+
+    fn delete(&self) -> Result<(), StartupError> {
+        self.primary.delete()
+    }
+
+    fn location(&self) -> String {
+        self.primary.location()
+    }
+}
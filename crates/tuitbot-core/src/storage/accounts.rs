@@ -374,6 +374,100 @@ pub fn write_active_account_id(dir: &Path, account_id: &str) -> Result<(), std::
     Ok(())
 }
 
+/// Pointer to the account that requests without an explicit account ID
+/// act on.
+///
+/// Shared by the HTTP server and the MCP server so a client can switch
+/// accounts once instead of tagging every call. [`ActiveAccount::load`]
+/// backs it with the `active_account` sentinel that `tuitbot accounts
+/// switch` writes, so the choice survives restarts and is shared with the
+/// CLI. The `Default` value starts on the default account and is not
+/// persisted.
+#[derive(Debug)]
+pub struct ActiveAccount {
+    id: tokio::sync::RwLock<String>,
+    /// Directory holding the sentinel switches are written to.
+    dir: Option<PathBuf>,
+}
+
+impl Default for ActiveAccount {
+    fn default() -> Self {
+        Self {
+            id: tokio::sync::RwLock::new(DEFAULT_ACCOUNT_ID.to_string()),
+            dir: None,
+        }
+    }
+}
+
+impl ActiveAccount {
+    /// Start on the account recorded in `dir`'s sentinel and persist later
+    /// switches there. Falls back to the default account if the recorded
+    /// account no longer exists or is archived.
+    pub async fn load(pool: &DbPool, dir: &Path) -> Result<Self, StorageError> {
+        let mut id = read_active_account_id(dir);
+        if id != DEFAULT_ACCOUNT_ID && !account_exists(pool, &id).await? {
+            tracing::warn!(account_id = %id, "Recorded active account not found; using default");
+            id = DEFAULT_ACCOUNT_ID.to_string();
+        }
+        let active = Self {
+            id: tokio::sync::RwLock::new(id.clone()),
+            dir: Some(dir.to_path_buf()),
+        };
+        active.persist(&id);
+        Ok(active)
+    }
+
+    /// Write `account_id` to the sentinel, if this pointer is persisted.
+    fn persist(&self, account_id: &str) {
+        if let Some(dir) = &self.dir {
+            if let Err(e) = write_active_account_id(dir, account_id) {
+                tracing::warn!(error = %e, "Failed to persist active account");
+            }
+        }
+    }
+
+    /// The currently active account ID.
+    ///
+    /// A persisted pointer re-reads the sentinel on every call, so a switch
+    /// made by another process (e.g. `tuitbot accounts switch`) takes effect
+    /// without a restart.
+    pub async fn get(&self) -> String {
+        let Some(dir) = &self.dir else {
+            return self.id.read().await.clone();
+        };
+        let recorded = read_active_account_id(dir);
+        let mut id = self.id.write().await;
+        if *id != recorded {
+            *id = recorded;
+        }
+        id.clone()
+    }
+
+    /// Make `account_id` the active account.
+    ///
+    /// Returns `false` and leaves the active account unchanged if the
+    /// account does not exist or is not active.
+    pub async fn switch(&self, pool: &DbPool, account_id: &str) -> Result<bool, StorageError> {
+        if !account_exists(pool, account_id).await? {
+            return Ok(false);
+        }
+        let mut id = self.id.write().await;
+        *id = account_id.to_string();
+        self.persist(account_id);
+        Ok(true)
+    }
+
+    /// Fall back to the default account if `account_id` is the active one.
+    /// Called after an account is deleted.
+    pub async fn clear_if(&self, account_id: &str) {
+        let mut id = self.id.write().await;
+        if *id == account_id {
+            *id = DEFAULT_ACCOUNT_ID.to_string();
+            self.persist(DEFAULT_ACCOUNT_ID);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -754,4 +848,51 @@ mod tests {
         let active = read_active_account_id(&tmpdir);
         assert_eq!(active, DEFAULT_ACCOUNT_ID);
     }
+
+    #[tokio::test]
+    async fn active_account_switches_only_to_existing_accounts() {
+        let pool = init_test_db().await.expect("init db");
+        let id = uuid::Uuid::new_v4().to_string();
+        create_account(&pool, &id, "Second").await.expect("create");
+
+        let active = ActiveAccount::default();
+        assert_eq!(active.get().await, DEFAULT_ACCOUNT_ID);
+
+        assert!(!active.switch(&pool, "nonexistent").await.expect("switch"));
+        assert_eq!(active.get().await, DEFAULT_ACCOUNT_ID);
+
+        assert!(active.switch(&pool, &id).await.expect("switch"));
+        assert_eq!(active.get().await, id);
+
+        active.clear_if("other").await;
+        assert_eq!(active.get().await, id);
+        active.clear_if(&id).await;
+        assert_eq!(active.get().await, DEFAULT_ACCOUNT_ID);
+    }
+
+    #[tokio::test]
+    async fn loaded_active_account_persists_switches() {
+        let pool = init_test_db().await.expect("init db");
+        let tmpdir = std::env::temp_dir().join(format!("tuitbot_test_{}", uuid::Uuid::new_v4()));
+        let id = uuid::Uuid::new_v4().to_string();
+        create_account(&pool, &id, "Second").await.expect("create");
+
+        let active = ActiveAccount::load(&pool, &tmpdir).await.expect("load");
+        assert!(active.switch(&pool, &id).await.expect("switch"));
+        assert_eq!(read_active_account_id(&tmpdir), id);
+
+        let reloaded = ActiveAccount::load(&pool, &tmpdir).await.expect("reload");
+        assert_eq!(reloaded.get().await, id);
+
+        write_active_account_id(&tmpdir, "gone").expect("write");
+        let fallback = ActiveAccount::load(&pool, &tmpdir).await.expect("load");
+        assert_eq!(fallback.get().await, DEFAULT_ACCOUNT_ID);
+        assert_eq!(read_active_account_id(&tmpdir), DEFAULT_ACCOUNT_ID);
+
+        // A switch written by another process is picked up without reloading.
+        write_active_account_id(&tmpdir, &id).expect("write");
+        assert_eq!(fallback.get().await, id);
+
+        let _ = std::fs::remove_dir_all(&tmpdir);
+    }
 }
@@ -4,6 +4,7 @@
 //! status, and optional metadata in JSON format.
 
 use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::pagination::{Page, PageCursor, BEFORE_CURSOR_SQL};
 use super::DbPool;
 use crate::error::StorageError;
 use std::collections::HashMap;
@@ -25,6 +26,15 @@ pub struct ActionLogEntry {
     pub created_at: String,
 }
 
+/// An action log entry together with the account that logged it.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct AccountActionLogEntry {
+    /// Owning account.
+    pub account_id: String,
+    #[sqlx(flatten)]
+    pub entry: ActionLogEntry,
+}
+
 /// Insert a new action log entry for a specific account.
 ///
 /// The `metadata` parameter is a pre-serialized JSON string; the caller
@@ -119,6 +129,31 @@ pub async fn get_actions_since(
     get_actions_since_for(pool, DEFAULT_ACCOUNT_ID, since, action_type).await
 }
 
+/// Fetch entries of `action_type` logged after `after_id`, across all
+/// accounts, ordered by ID ascending.
+pub async fn get_actions_after_id(
+    pool: &DbPool,
+    after_id: i64,
+    action_type: &str,
+) -> Result<Vec<AccountActionLogEntry>, StorageError> {
+    sqlx::query_as::<_, AccountActionLogEntry>(
+        "SELECT * FROM action_log WHERE id > ? AND action_type = ? ORDER BY id ASC",
+    )
+    .bind(after_id)
+    .bind(action_type)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Highest action log ID, or 0 when the log is empty.
+pub async fn get_max_action_id(pool: &DbPool) -> Result<i64, StorageError> {
+    sqlx::query_scalar("SELECT COALESCE(MAX(id), 0) FROM action_log")
+        .fetch_one(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })
+}
+
 /// Get counts of each action type since a given timestamp for a specific account.
 ///
 /// Returns a HashMap mapping action types to their counts.
@@ -193,7 +228,7 @@ pub async fn get_actions_paginated_for(
     if status.is_some() {
         sql.push_str(" AND status = ?");
     }
-    sql.push_str(" ORDER BY created_at DESC LIMIT ? OFFSET ?");
+    sql.push_str(" ORDER BY created_at DESC, id DESC LIMIT ? OFFSET ?");
 
     let mut query = sqlx::query_as::<_, ActionLogEntry>(&sql);
     query = query.bind(account_id);
@@ -224,6 +259,53 @@ pub async fn get_actions_paginated(
     get_actions_paginated_for(pool, DEFAULT_ACCOUNT_ID, limit, offset, action_type, status).await
 }
 
+/// Fetch one keyset page of action log entries for a specific account,
+/// newest first, starting after `cursor` (or at the newest entry).
+///
+/// Entries logged between two page fetches are newer than every cursor
+/// already handed out, so they never shift later pages.
+pub async fn get_actions_page_for(
+    pool: &DbPool,
+    account_id: &str,
+    limit: u32,
+    cursor: Option<&PageCursor>,
+    action_type: Option<&str>,
+    status: Option<&str>,
+) -> Result<Page<ActionLogEntry>, StorageError> {
+    let mut sql = String::from("SELECT * FROM action_log WHERE account_id = ?");
+    if action_type.is_some() {
+        sql.push_str(" AND action_type = ?");
+    }
+    if status.is_some() {
+        sql.push_str(" AND status = ?");
+    }
+    if cursor.is_some() {
+        sql.push_str(" AND ");
+        sql.push_str(BEFORE_CURSOR_SQL);
+    }
+    sql.push_str(" ORDER BY created_at DESC, id DESC LIMIT ?");
+
+    let mut query = sqlx::query_as::<_, ActionLogEntry>(&sql).bind(account_id);
+    if let Some(at) = action_type {
+        query = query.bind(at);
+    }
+    if let Some(st) = status {
+        query = query.bind(st);
+    }
+    if let Some(c) = cursor {
+        query = query.bind(&c.created_at).bind(&c.created_at).bind(c.id);
+    }
+    let rows = query
+        .bind(limit.saturating_add(1))
+        .fetch_all(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(Page::from_rows(rows, limit, |e| {
+        PageCursor::new(&e.created_at, e.id)
+    }))
+}
+
 /// Get total count of action log entries for a specific account with optional
 /// type and status filters.
 pub async fn get_actions_count_for(
@@ -270,6 +352,51 @@ mod tests {
     use super::*;
     use crate::storage::init_test_db;
 
+    async fn log_at(pool: &DbPool, action_type: &str, created_at: &str) {
+        sqlx::query(
+            "INSERT INTO action_log (account_id, action_type, status, created_at) \
+             VALUES (?, ?, 'success', ?)",
+        )
+        .bind(DEFAULT_ACCOUNT_ID)
+        .bind(action_type)
+        .bind(created_at)
+        .execute(pool)
+        .await
+        .expect("insert");
+    }
+
+    #[tokio::test]
+    async fn keyset_pages_neither_overlap_nor_skip_when_rows_arrive() {
+        let pool = init_test_db().await.expect("init db");
+        // Several entries share a second, so only the id tie-break orders them.
+        for i in 0..5 {
+            log_at(&pool, &format!("a{i}"), "2026-03-02 10:00:00").await;
+        }
+        log_at(&pool, "a5", "2026-03-02 10:00:01").await;
+
+        let first = get_actions_page_for(&pool, DEFAULT_ACCOUNT_ID, 2, None, None, None)
+            .await
+            .expect("page 1");
+        let types: Vec<&str> = first.items.iter().map(|e| e.action_type.as_str()).collect();
+        assert_eq!(types, ["a5", "a4"]);
+
+        // New activity between fetches must not push rows into the next page.
+        log_at(&pool, "late", "2026-03-02 10:00:00").await;
+        log_at(&pool, "later", "2026-03-02 11:00:00").await;
+
+        let mut seen: Vec<String> = first.items.into_iter().map(|e| e.action_type).collect();
+        let mut cursor = first.next_cursor;
+        while let Some(c) = cursor {
+            let c = PageCursor::decode(&c).expect("valid cursor");
+            let page = get_actions_page_for(&pool, DEFAULT_ACCOUNT_ID, 2, Some(&c), None, None)
+                .await
+                .expect("page");
+            seen.extend(page.items.into_iter().map(|e| e.action_type));
+            cursor = page.next_cursor;
+        }
+        assert_eq!(seen, ["a5", "a4", "a3", "a2", "a1", "a0"]);
+    }
+
     #[tokio::test]
     async fn log_and_retrieve_action() {
         let pool = init_test_db().await.expect("init db");
@@ -781,4 +908,27 @@ mod tests {
             .expect("recent r limited");
         assert_eq!(recent_r1.len(), 1);
     }
+
+    #[tokio::test]
+    async fn get_actions_after_id_spans_accounts() {
+        let pool = init_test_db().await.expect("init db");
+
+        log_action_for(&pool, "acct_a", "post_activity", "posted", None, None)
+            .await
+            .expect("log a");
+        let cursor = get_max_action_id(&pool).await.expect("max id");
+        log_action_for(&pool, "acct_b", "post_activity", "denied", None, None)
+            .await
+            .expect("log b");
+        log_action_for(&pool, "acct_a", "reply", "success", None, None)
+            .await
+            .expect("log reply");
+
+        let rows = get_actions_after_id(&pool, cursor, "post_activity")
+            .await
+            .expect("after id");
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].account_id, "acct_b");
+        assert_eq!(rows[0].entry.status, "denied");
+    }
 }
@@ -2,6 +2,7 @@
 
 use super::super::accounts::DEFAULT_ACCOUNT_ID;
 use super::super::DbPool;
+use super::is_low_confidence;
 use crate::error::StorageError;
 use serde::{Deserialize, Serialize};
 
@@ -14,12 +15,19 @@ pub struct BestTimeSlot {
     pub avg_engagement: f64,
     pub confidence_score: f64, // 0-100, higher = more historical data
     pub sample_size: i64,
+    /// True when `sample_size` is below the minimum sample; not a recommendation.
+    #[serde(default)]
+    pub low_confidence: bool,
 }
 
-/// Get ranked best-time-to-post slots for a specific account (sorted by avg_engagement DESC).
+/// Get ranked best-time-to-post slots for a specific account.
+///
+/// Slots with fewer than `min_sample` posts are flagged `low_confidence` and
+/// ranked after every other slot; within each group, by avg_engagement DESC.
 pub async fn get_best_times_for(
     pool: &DbPool,
     account_id: &str,
+    min_sample: u32,
 ) -> Result<Vec<BestTimeSlot>, StorageError> {
     let day_names = [
         "Sunday",
@@ -42,7 +50,7 @@ pub async fn get_best_times_for(
     .await
     .map_err(|e| StorageError::Query { source: e })?;
 
-    Ok(rows
+    let mut slots: Vec<BestTimeSlot> = rows
         .into_iter()
         .map(
             |(hour, day, avg_engagement, confidence, sample_size)| BestTimeSlot {
@@ -52,14 +60,21 @@ pub async fn get_best_times_for(
                 avg_engagement,
                 confidence_score: confidence,
                 sample_size,
+                low_confidence: is_low_confidence(sample_size, min_sample),
             },
         )
-        .collect())
+        .collect();
+    // Stable sort keeps the engagement order within each group.
+    slots.sort_by_key(|s| s.low_confidence);
+    Ok(slots)
 }
 
 /// Get ranked best-time-to-post slots (default account).
-pub async fn get_best_times(pool: &DbPool) -> Result<Vec<BestTimeSlot>, StorageError> {
-    get_best_times_for(pool, DEFAULT_ACCOUNT_ID).await
+pub async fn get_best_times(
+    pool: &DbPool,
+    min_sample: u32,
+) -> Result<Vec<BestTimeSlot>, StorageError> {
+    get_best_times_for(pool, DEFAULT_ACCOUNT_ID, min_sample).await
 }
 
 /// Compute and update best-times aggregations for a specific account.
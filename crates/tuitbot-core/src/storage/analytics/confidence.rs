@@ -0,0 +1,26 @@
+//! Minimum-sample gate shared by engagement aggregations.
+//!
+//! A topic or posting hour backed by one or two posts says more about luck
+//! than about the audience. Aggregations that feed strategy recommendations
+//! and optimal-time suggestions flag such buckets `low_confidence` through
+//! [`is_low_confidence`], and consumers leave flagged buckets out of anything
+//! they recommend. The threshold is `analytics.min_engagement_sample`.
+
+/// Whether an aggregate over `sample_size` data points is too sparse to act on.
+pub fn is_low_confidence(sample_size: i64, min_sample: u32) -> bool {
+    sample_size < i64::from(min_sample)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn below_threshold_is_low_confidence() {
+        assert!(is_low_confidence(0, 3));
+        assert!(is_low_confidence(2, 3));
+        assert!(!is_low_confidence(3, 3));
+        assert!(!is_low_confidence(10, 3));
+        assert!(!is_low_confidence(1, 1));
+    }
+}
@@ -0,0 +1,234 @@
+//! Follower growth rate-of-change derived from daily snapshots.
+//!
+//! Snapshots are taken at most once a day and days can be missed (the bot
+//! was not running). The series is laid out on every calendar day between
+//! the first and last snapshot; missing days are linearly interpolated and
+//! flagged so charts can draw them differently.
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+use super::super::accounts::DEFAULT_ACCOUNT_ID;
+use super::super::DbPool;
+use crate::error::StorageError;
+
+/// Extra history loaded before the requested window so 7-day and 30-day
+/// changes are available from its first day.
+const LOOKBACK_DAYS: u32 = 30;
+
+/// One calendar day of the follower growth series.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FollowerGrowthPoint {
+    pub date: String,
+    pub follower_count: i64,
+    /// True when no snapshot exists for this day and the count is interpolated.
+    pub interpolated: bool,
+    /// Net change since the previous day.
+    pub daily_change: Option<i64>,
+    /// Net change since seven days earlier.
+    pub weekly_change: Option<i64>,
+    /// Mean daily change over the last seven days.
+    pub moving_avg_7d: Option<f64>,
+}
+
+/// Headline numbers for the growth chart.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FollowerGrowthSummary {
+    /// Latest recorded follower count.
+    pub current: Option<i64>,
+    pub change_7d: Option<i64>,
+    pub change_30d: Option<i64>,
+    /// Days in `series` filled by interpolation.
+    pub missing_days: u32,
+}
+
+/// Follower growth series plus summary.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FollowerGrowthReport {
+    pub series: Vec<FollowerGrowthPoint>,
+    pub summary: FollowerGrowthSummary,
+}
+
+/// Get the follower growth report for a specific account over the past `days` days.
+pub async fn get_follower_growth_report_for(
+    pool: &DbPool,
+    account_id: &str,
+    days: u32,
+) -> Result<FollowerGrowthReport, StorageError> {
+    let rows = sqlx::query_as::<_, (String, i64)>(
+        "SELECT snapshot_date, follower_count \
+         FROM follower_snapshots \
+         WHERE account_id = ? \
+         AND snapshot_date GLOB '[0-9][0-9][0-9][0-9]-[0-9][0-9]-[0-9][0-9]' \
+         AND snapshot_date >= date('now', '-' || ? || ' days') \
+         ORDER BY snapshot_date ASC",
+    )
+    .bind(account_id)
+    .bind(i64::from(days) + i64::from(LOOKBACK_DAYS))
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let snapshots: Vec<(NaiveDate, i64)> = rows
+        .into_iter()
+        .filter_map(|(date, count)| {
+            NaiveDate::parse_from_str(&date, "%Y-%m-%d")
+                .ok()
+                .map(|d| (d, count))
+        })
+        .collect();
+
+    Ok(build_follower_growth_report(&snapshots, days))
+}
+
+/// Get the follower growth report (default account).
+pub async fn get_follower_growth_report(
+    pool: &DbPool,
+    days: u32,
+) -> Result<FollowerGrowthReport, StorageError> {
+    get_follower_growth_report_for(pool, DEFAULT_ACCOUNT_ID, days).await
+}
+
+/// Build the report from ascending `(date, follower_count)` snapshots.
+///
+/// The returned series covers at most the last `days` days up to the latest
+/// snapshot; earlier snapshots only feed the changes and averages.
+pub fn build_follower_growth_report(
+    snapshots: &[(NaiveDate, i64)],
+    days: u32,
+) -> FollowerGrowthReport {
+    let filled = fill_gaps(snapshots);
+    let Some(&(last_date, current, _)) = filled.last() else {
+        return FollowerGrowthReport::default();
+    };
+
+    let change = |back: usize| -> Option<i64> {
+        filled
+            .len()
+            .checked_sub(back + 1)
+            .map(|i| current - filled[i].1)
+    };
+
+    let first_shown = last_date - Duration::days(i64::from(days.max(1)) - 1);
+    let series: Vec<FollowerGrowthPoint> = filled
+        .iter()
+        .enumerate()
+        .filter(|(_, (date, _, _))| *date >= first_shown)
+        .map(|(i, &(date, count, interpolated))| {
+            let prev = |back: usize| i.checked_sub(back).map(|j| filled[j].1);
+            let moving_avg_7d = prev(7).map(|week_ago| (count - week_ago) as f64 / 7.0);
+            FollowerGrowthPoint {
+                date: date.format("%Y-%m-%d").to_string(),
+                follower_count: count,
+                interpolated,
+                daily_change: prev(1).map(|p| count - p),
+                weekly_change: prev(7).map(|p| count - p),
+                moving_avg_7d,
+            }
+        })
+        .collect();
+
+    let missing_days = series.iter().filter(|p| p.interpolated).count() as u32;
+    FollowerGrowthReport {
+        series,
+        summary: FollowerGrowthSummary {
+            current: Some(current),
+            change_7d: change(7),
+            change_30d: change(30),
+            missing_days,
+        },
+    }
+}
+
+/// One entry per calendar day from the first to the last snapshot, with
+/// missing days linearly interpolated (rounded) and flagged.
+fn fill_gaps(snapshots: &[(NaiveDate, i64)]) -> Vec<(NaiveDate, i64, bool)> {
+    let mut filled = Vec::new();
+    for pair in snapshots.windows(2) {
+        let ((from, a), (to, b)) = (pair[0], pair[1]);
+        let span = (to - from).num_days();
+        filled.push((from, a, false));
+        for step in 1..span {
+            let value = a as f64 + (b - a) as f64 * step as f64 / span as f64;
+            filled.push((from + Duration::days(step), value.round() as i64, true));
+        }
+    }
+    if let Some(&(date, count)) = snapshots.last() {
+        filled.push((date, count, false));
+    }
+    filled
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    fn day(n: i64) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, 1).unwrap() + Duration::days(n)
+    }
+
+    #[test]
+    fn empty_snapshots_give_empty_report() {
+        let report = build_follower_growth_report(&[], 30);
+        assert!(report.series.is_empty());
+        assert_eq!(report.summary.current, None);
+    }
+
+    #[test]
+    fn gap_is_interpolated_and_flagged() {
+        // Days 2 and 3 are missing.
+        let snapshots = [(day(0), 100), (day(1), 110), (day(4), 140), (day(5), 142)];
+        let report = build_follower_growth_report(&snapshots, 30);
+
+        let counts: Vec<i64> = report.series.iter().map(|p| p.follower_count).collect();
+        assert_eq!(counts, vec![100, 110, 120, 130, 140, 142]);
+        let flags: Vec<bool> = report.series.iter().map(|p| p.interpolated).collect();
+        assert_eq!(flags, vec![false, false, true, true, false, false]);
+        assert_eq!(report.series[0].daily_change, None);
+        assert_eq!(report.series[5].daily_change, Some(2));
+        assert_eq!(report.summary.missing_days, 2);
+        assert_eq!(report.summary.current, Some(142));
+        assert_eq!(report.summary.change_7d, None);
+    }
+
+    #[test]
+    fn weekly_change_and_moving_average() {
+        let snapshots: Vec<(NaiveDate, i64)> = (0..10).map(|d| (day(d), 100 + 3 * d)).collect();
+        let report = build_follower_growth_report(&snapshots, 3);
+
+        assert_eq!(report.series.len(), 3);
+        let last = report.series.last().unwrap();
+        assert_eq!(last.date, "2026-03-10");
+        assert_eq!(last.weekly_change, Some(21));
+        assert_eq!(last.moving_avg_7d, Some(3.0));
+        assert_eq!(report.summary.change_7d, Some(21));
+        assert_eq!(report.summary.change_30d, None);
+    }
+
+    #[tokio::test]
+    async fn report_reads_seeded_snapshots_with_gap() {
+        let pool = init_test_db().await.expect("init db");
+        let today = chrono::Utc::now().date_naive();
+        for (ago, count) in [(9, 500), (8, 510), (3, 560), (2, 570), (1, 575), (0, 580)] {
+            sqlx::query(
+                "INSERT INTO follower_snapshots (account_id, snapshot_date, follower_count) \
+                 VALUES (?, ?, ?)",
+            )
+            .bind(DEFAULT_ACCOUNT_ID)
+            .bind((today - Duration::days(ago)).format("%Y-%m-%d").to_string())
+            .bind(count)
+            .execute(&pool)
+            .await
+            .expect("seed");
+        }
+
+        let report = get_follower_growth_report(&pool, 30).await.expect("report");
+
+        assert_eq!(report.series.len(), 10);
+        assert_eq!(report.summary.missing_days, 4);
+        assert_eq!(report.summary.current, Some(580));
+        // Day 7 ago is interpolated between 510 (8 ago) and 560 (3 ago).
+        assert_eq!(report.summary.change_7d, Some(580 - 520));
+    }
+}
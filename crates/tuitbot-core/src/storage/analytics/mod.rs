@@ -6,12 +6,17 @@
 
 mod ancestors;
 mod best_times;
+mod confidence;
 mod content_scores;
 mod engagement;
+mod follower_growth;
+mod optimal_times;
 mod performance_items;
+mod period_stats;
 mod reply_performance;
 mod snapshots;
 mod summary;
+mod topic_performance;
 mod tweet_performance;
 
 #[cfg(test)]
@@ -19,10 +24,15 @@ mod tests;
 
 pub use ancestors::*;
 pub use best_times::*;
+pub use confidence::*;
 pub use content_scores::*;
 pub use engagement::*;
+pub use follower_growth::*;
+pub use optimal_times::*;
 pub use performance_items::*;
+pub use period_stats::*;
 pub use reply_performance::*;
 pub use snapshots::*;
 pub use summary::*;
+pub use topic_performance::*;
 pub use tweet_performance::*;
@@ -0,0 +1,129 @@
+//! Per-weekday optimal posting hours from the account's own history,
+//! bucketed in a local timezone.
+
+use std::collections::{BTreeMap, HashMap};
+
+use chrono::{DateTime, Datelike, NaiveDateTime, Timelike, Utc, Weekday};
+use chrono_tz::Tz;
+use serde::Serialize;
+
+use super::super::accounts::DEFAULT_ACCOUNT_ID;
+use super::super::DbPool;
+use super::{is_low_confidence, HourlyPerformance};
+use crate::error::StorageError;
+
+/// Top posting hours for one weekday, in local time.
+#[derive(Debug, Clone, Serialize)]
+pub struct WeekdayOptimalHours {
+    /// Day abbreviation (Mon-Sun), matching `schedule.preferred_times_override` keys.
+    pub weekday: String,
+    /// Best local hours for this day, highest average engagement first.
+    /// Hours below the minimum sample are left out.
+    pub hours: Vec<HourlyPerformance>,
+    /// Number of posts sent on this weekday in hours that met the minimum sample.
+    pub post_count: i64,
+}
+
+const WEEKDAYS: [Weekday; 7] = [
+    Weekday::Mon,
+    Weekday::Tue,
+    Weekday::Wed,
+    Weekday::Thu,
+    Weekday::Fri,
+    Weekday::Sat,
+    Weekday::Sun,
+];
+
+/// Get the top `per_day` local hours per weekday for a specific account.
+///
+/// Sent original tweets are bucketed by their posting time converted to `tz`
+/// and ranked by average performance score. Hours with fewer than
+/// `min_sample` posts are too sparse to recommend and are dropped; weekdays
+/// left without any hour are omitted.
+pub async fn get_optimal_times_by_weekday_for(
+    pool: &DbPool,
+    account_id: &str,
+    tz: Tz,
+    per_day: usize,
+    min_sample: u32,
+) -> Result<Vec<WeekdayOptimalHours>, StorageError> {
+    let rows: Vec<(String, f64)> = sqlx::query_as(
+        "SELECT ot.created_at, COALESCE(tp.performance_score, 0.0)
+         FROM original_tweets ot
+         LEFT JOIN tweet_performance tp ON tp.tweet_id = ot.tweet_id
+         WHERE ot.account_id = ? AND ot.status = 'sent' AND ot.tweet_id IS NOT NULL",
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    // (weekday index, local hour) -> (score sum, count)
+    let mut buckets: BTreeMap<(u32, u32), (f64, i64)> = BTreeMap::new();
+    for (created_at, score) in rows {
+        let Some(utc) = parse_timestamp(&created_at) else {
+            continue;
+        };
+        let local = utc.with_timezone(&tz);
+        let entry = buckets
+            .entry((local.weekday().num_days_from_monday(), local.hour()))
+            .or_default();
+        entry.0 += score;
+        entry.1 += 1;
+    }
+
+    let mut by_day: HashMap<u32, Vec<HourlyPerformance>> = HashMap::new();
+    for ((day, hour), (sum, count)) in buckets {
+        if is_low_confidence(count, min_sample) {
+            continue;
+        }
+        by_day.entry(day).or_default().push(HourlyPerformance {
+            hour: i64::from(hour),
+            avg_engagement: sum / count as f64,
+            post_count: count,
+            low_confidence: false,
+        });
+    }
+
+    Ok(WEEKDAYS
+        .iter()
+        .filter_map(|wd| {
+            let mut hours = by_day.remove(&wd.num_days_from_monday())?;
+            let post_count = hours.iter().map(|h| h.post_count).sum();
+            hours.sort_by(|a, b| {
+                b.avg_engagement
+                    .total_cmp(&a.avg_engagement)
+                    .then(b.post_count.cmp(&a.post_count))
+                    .then(a.hour.cmp(&b.hour))
+            });
+            hours.truncate(per_day);
+            Some(WeekdayOptimalHours {
+                weekday: wd.to_string(),
+                hours,
+                post_count,
+            })
+        })
+        .collect())
+}
+
+/// Get the top `per_day` local hours per weekday.
+pub async fn get_optimal_times_by_weekday(
+    pool: &DbPool,
+    tz: Tz,
+    per_day: usize,
+    min_sample: u32,
+) -> Result<Vec<WeekdayOptimalHours>, StorageError> {
+    get_optimal_times_by_weekday_for(pool, DEFAULT_ACCOUNT_ID, tz, per_day, min_sample).await
+}
+
+/// Parse RFC 3339 or SQLite `datetime('now')` timestamps as UTC.
+fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .map(|naive| naive.and_utc())
+        })
+}
@@ -1,5 +1,6 @@
 use super::super::accounts::DEFAULT_ACCOUNT_ID;
 use super::super::DbPool;
+use super::is_low_confidence;
 use crate::error::StorageError;
 
 /// A recent content item with performance metrics.
@@ -155,12 +156,17 @@ pub struct HourlyPerformance {
     pub avg_engagement: f64,
     /// Number of posts in this hour.
     pub post_count: i64,
+    /// True when `post_count` is below the minimum sample; not a recommendation.
+    pub low_confidence: bool,
 }
 
 /// Get optimal posting times based on historical performance for a specific account.
+///
+/// Hours with fewer than `min_sample` posts are flagged `low_confidence`.
 pub async fn get_optimal_posting_times_for(
     pool: &DbPool,
     account_id: &str,
+    min_sample: u32,
 ) -> Result<Vec<HourlyPerformance>, StorageError> {
     let rows: Vec<(i64, f64, i64)> = sqlx::query_as(
         "SELECT
@@ -184,6 +190,7 @@ pub async fn get_optimal_posting_times_for(
             hour,
             avg_engagement,
             post_count,
+            low_confidence: is_low_confidence(post_count, min_sample),
         })
         .collect())
 }
@@ -191,6 +198,7 @@ pub async fn get_optimal_posting_times_for(
 /// Get optimal posting times based on historical performance.
 pub async fn get_optimal_posting_times(
     pool: &DbPool,
+    min_sample: u32,
 ) -> Result<Vec<HourlyPerformance>, StorageError> {
-    get_optimal_posting_times_for(pool, DEFAULT_ACCOUNT_ID).await
+    get_optimal_posting_times_for(pool, DEFAULT_ACCOUNT_ID, min_sample).await
 }
@@ -0,0 +1,372 @@
+//! Period-over-period activity and engagement comparison.
+//!
+//! Metrics are attributed to the period in which content was posted, so a
+//! reply sent last week and measured today still counts toward last week.
+//! Periods are rolling windows ending at the given instant: the current
+//! period is `[now - len, now)` and the prior one `[now - 2*len, now - len)`.
+
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use serde::Serialize;
+
+use super::super::accounts::DEFAULT_ACCOUNT_ID;
+use super::super::DbPool;
+use crate::error::StorageError;
+
+/// Timestamp format of `replies_sent.created_at` and `original_tweets.created_at`.
+const TS_FORMAT: &str = "%Y-%m-%dT%H:%M:%SZ";
+
+/// Length of the periods being compared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ComparePeriod {
+    /// Day over day.
+    Dod,
+    /// Week over week.
+    Wow,
+    /// Month (30 days) over month.
+    Mom,
+}
+
+impl ComparePeriod {
+    /// Parse `dod`, `wow`, or `mom`.
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "dod" => Some(Self::Dod),
+            "wow" => Some(Self::Wow),
+            "mom" => Some(Self::Mom),
+            _ => None,
+        }
+    }
+
+    /// Length of one period.
+    pub fn length(self) -> Duration {
+        match self {
+            Self::Dod => Duration::days(1),
+            Self::Wow => Duration::days(7),
+            Self::Mom => Duration::days(30),
+        }
+    }
+}
+
+/// Metrics for one period.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PeriodStats {
+    /// Inclusive start, RFC 3339.
+    pub start: String,
+    /// Exclusive end, RFC 3339.
+    pub end: String,
+    pub replies_sent: i64,
+    pub tweets_posted: i64,
+    /// Average performance score of measured replies sent in the period.
+    pub avg_reply_score: Option<f64>,
+    /// Average performance score of measured tweets posted in the period.
+    pub avg_tweet_score: Option<f64>,
+    /// Followers gained over the period; `None` without a snapshot from
+    /// before the period to measure from.
+    pub follower_change: Option<i64>,
+}
+
+/// Percentage change of each metric from the prior period to the current
+/// one. `None` when either side is missing or the prior value is zero.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PeriodDeltas {
+    pub replies_sent: Option<f64>,
+    pub tweets_posted: Option<f64>,
+    pub avg_reply_score: Option<f64>,
+    pub avg_tweet_score: Option<f64>,
+    pub follower_change: Option<f64>,
+}
+
+/// Current period, prior period, and the change between them.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct PeriodComparison {
+    pub period: ComparePeriod,
+    pub current: PeriodStats,
+    /// `None` when recorded history does not reach back to the prior
+    /// period's start, so its metrics would be misleadingly low.
+    pub previous: Option<PeriodStats>,
+    pub deltas: PeriodDeltas,
+}
+
+/// Compare the period ending at `now` with the one before it, for a specific account.
+pub async fn compare_periods_for(
+    pool: &DbPool,
+    account_id: &str,
+    period: ComparePeriod,
+    now: DateTime<Utc>,
+) -> Result<PeriodComparison, StorageError> {
+    let length = period.length();
+    let current_start = now - length;
+    let previous_start = current_start - length;
+
+    let current = get_period_stats_for(pool, account_id, current_start, now).await?;
+    let previous = match earliest_activity_for(pool, account_id).await? {
+        Some(first) if first <= previous_start.date_naive() => {
+            Some(get_period_stats_for(pool, account_id, previous_start, current_start).await?)
+        }
+        _ => None,
+    };
+    let deltas = previous
+        .as_ref()
+        .map(|prev| compute_deltas(&current, prev))
+        .unwrap_or_default();
+
+    Ok(PeriodComparison {
+        period,
+        current,
+        previous,
+        deltas,
+    })
+}
+
+/// Compare the period ending at `now` with the one before it (default account).
+pub async fn compare_periods(
+    pool: &DbPool,
+    period: ComparePeriod,
+    now: DateTime<Utc>,
+) -> Result<PeriodComparison, StorageError> {
+    compare_periods_for(pool, DEFAULT_ACCOUNT_ID, period, now).await
+}
+
+/// Get metrics for content posted in `[start, end)` for a specific account.
+pub async fn get_period_stats_for(
+    pool: &DbPool,
+    account_id: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<PeriodStats, StorageError> {
+    let start_ts = start.format(TS_FORMAT).to_string();
+    let end_ts = end.format(TS_FORMAT).to_string();
+
+    let (replies_sent, avg_reply_score): (i64, Option<f64>) = sqlx::query_as(
+        "SELECT COUNT(*), AVG(rp.performance_score) \
+         FROM replies_sent rs \
+         LEFT JOIN reply_performance rp \
+           ON rp.reply_id = rs.reply_tweet_id AND rp.account_id = rs.account_id \
+         WHERE rs.account_id = ? AND rs.status = 'sent' \
+           AND rs.created_at >= ? AND rs.created_at < ?",
+    )
+    .bind(account_id)
+    .bind(&start_ts)
+    .bind(&end_ts)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let (tweets_posted, avg_tweet_score): (i64, Option<f64>) = sqlx::query_as(
+        "SELECT COUNT(*), AVG(tp.performance_score) \
+         FROM original_tweets ot \
+         LEFT JOIN tweet_performance tp \
+           ON tp.tweet_id = ot.tweet_id AND tp.account_id = ot.account_id \
+         WHERE ot.account_id = ? AND ot.status = 'sent' \
+           AND ot.created_at >= ? AND ot.created_at < ?",
+    )
+    .bind(account_id)
+    .bind(&start_ts)
+    .bind(&end_ts)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let follower_change = match (
+        latest_follower_count_before(pool, account_id, start.date_naive()).await?,
+        latest_follower_count_before(pool, account_id, end.date_naive()).await?,
+    ) {
+        (Some(before), Some(after)) => Some(after - before),
+        _ => None,
+    };
+
+    Ok(PeriodStats {
+        start: start.to_rfc3339(),
+        end: end.to_rfc3339(),
+        replies_sent,
+        tweets_posted,
+        avg_reply_score,
+        avg_tweet_score,
+        follower_change,
+    })
+}
+
+/// Follower count from the latest snapshot dated on or before `date`.
+async fn latest_follower_count_before(
+    pool: &DbPool,
+    account_id: &str,
+    date: NaiveDate,
+) -> Result<Option<i64>, StorageError> {
+    let row: Option<(i64,)> = sqlx::query_as(
+        "SELECT follower_count FROM follower_snapshots \
+         WHERE account_id = ? AND snapshot_date <= ? \
+         ORDER BY snapshot_date DESC LIMIT 1",
+    )
+    .bind(account_id)
+    .bind(date.format("%Y-%m-%d").to_string())
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(row.map(|r| r.0))
+}
+
+/// Date of the earliest reply, tweet, or follower snapshot on record.
+async fn earliest_activity_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Option<NaiveDate>, StorageError> {
+    let row: (Option<String>,) = sqlx::query_as(
+        "SELECT MIN(d) FROM ( \
+           SELECT MIN(substr(created_at, 1, 10)) AS d FROM replies_sent WHERE account_id = ?1 \
+           UNION ALL \
+           SELECT MIN(substr(created_at, 1, 10)) FROM original_tweets WHERE account_id = ?1 \
+           UNION ALL \
+           SELECT MIN(snapshot_date) FROM follower_snapshots WHERE account_id = ?1 \
+         )",
+    )
+    .bind(account_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(row
+        .0
+        .and_then(|d| NaiveDate::parse_from_str(&d, "%Y-%m-%d").ok()))
+}
+
+/// Percentage change of every metric from `previous` to `current`.
+pub fn compute_deltas(current: &PeriodStats, previous: &PeriodStats) -> PeriodDeltas {
+    let count = |cur: i64, prev: i64| percent_change(cur as f64, prev as f64);
+    let opt = |cur: Option<f64>, prev: Option<f64>| match (cur, prev) {
+        (Some(c), Some(p)) => percent_change(c, p),
+        _ => None,
+    };
+    PeriodDeltas {
+        replies_sent: count(current.replies_sent, previous.replies_sent),
+        tweets_posted: count(current.tweets_posted, previous.tweets_posted),
+        avg_reply_score: opt(current.avg_reply_score, previous.avg_reply_score),
+        avg_tweet_score: opt(current.avg_tweet_score, previous.avg_tweet_score),
+        follower_change: opt(
+            current.follower_change.map(|c| c as f64),
+            previous.follower_change.map(|p| p as f64),
+        ),
+    }
+}
+
+/// `(current - previous) / |previous|` as a percentage; `None` when previous is zero.
+fn percent_change(current: f64, previous: f64) -> Option<f64> {
+    (previous != 0.0).then(|| (current - previous) / previous.abs() * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+    use chrono::TimeZone;
+
+    fn now() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 15, 12, 0, 0).unwrap()
+    }
+
+    async fn seed_reply(pool: &DbPool, id: &str, created_at: &str, score: Option<f64>) {
+        sqlx::query(
+            "INSERT INTO replies_sent (target_tweet_id, reply_tweet_id, reply_content, created_at) \
+             VALUES ('t', ?, 'reply', ?)",
+        )
+        .bind(id)
+        .bind(created_at)
+        .execute(pool)
+        .await
+        .unwrap();
+        if let Some(score) = score {
+            super::super::upsert_reply_performance(pool, id, 1, 1, 100, score)
+                .await
+                .unwrap();
+        }
+    }
+
+    async fn seed_tweet(pool: &DbPool, id: &str, created_at: &str) {
+        sqlx::query(
+            "INSERT INTO original_tweets (tweet_id, content, created_at) VALUES (?, 'tweet', ?)",
+        )
+        .bind(id)
+        .bind(created_at)
+        .execute(pool)
+        .await
+        .unwrap();
+    }
+
+    async fn seed_snapshot(pool: &DbPool, date: &str, followers: i64) {
+        sqlx::query("INSERT INTO follower_snapshots (snapshot_date, follower_count) VALUES (?, ?)")
+            .bind(date)
+            .bind(followers)
+            .execute(pool)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn week_over_week_deltas_over_two_periods() {
+        let pool = init_test_db().await.unwrap();
+
+        // Prior week: 2026-03-01T12:00 .. 2026-03-08T12:00
+        seed_reply(&pool, "r1", "2026-03-02T10:00:00Z", Some(40.0)).await;
+        seed_reply(&pool, "r2", "2026-03-05T10:00:00Z", Some(60.0)).await;
+        seed_tweet(&pool, "tw1", "2026-03-03T10:00:00Z").await;
+        seed_snapshot(&pool, "2026-03-01", 100).await;
+        seed_snapshot(&pool, "2026-03-08", 110).await;
+
+        // Current week: 2026-03-08T12:00 .. 2026-03-15T12:00
+        seed_reply(&pool, "r3", "2026-03-09T10:00:00Z", Some(75.0)).await;
+        seed_reply(&pool, "r4", "2026-03-10T10:00:00Z", None).await;
+        seed_reply(&pool, "r5", "2026-03-12T10:00:00Z", Some(75.0)).await;
+        seed_snapshot(&pool, "2026-03-15", 125).await;
+
+        // Outside both periods.
+        seed_reply(&pool, "r0", "2026-02-20T10:00:00Z", Some(10.0)).await;
+
+        let cmp = compare_periods(&pool, ComparePeriod::Wow, now())
+            .await
+            .unwrap();
+        let prev = cmp.previous.as_ref().expect("prior week available");
+
+        assert_eq!(cmp.current.replies_sent, 3);
+        assert_eq!(prev.replies_sent, 2);
+        assert_eq!(cmp.current.avg_reply_score, Some(75.0));
+        assert_eq!(prev.avg_reply_score, Some(50.0));
+        assert_eq!(cmp.current.tweets_posted, 0);
+        assert_eq!(prev.tweets_posted, 1);
+        assert_eq!(cmp.current.follower_change, Some(15));
+        assert_eq!(prev.follower_change, Some(10));
+
+        assert_eq!(cmp.deltas.replies_sent, Some(50.0));
+        assert_eq!(cmp.deltas.avg_reply_score, Some(50.0));
+        assert_eq!(cmp.deltas.tweets_posted, Some(-100.0));
+        assert_eq!(cmp.deltas.follower_change, Some(50.0));
+        assert_eq!(cmp.deltas.avg_tweet_score, None);
+    }
+
+    #[tokio::test]
+    async fn prior_period_unavailable_without_history() {
+        let pool = init_test_db().await.unwrap();
+        seed_reply(&pool, "r1", "2026-03-14T10:00:00Z", Some(30.0)).await;
+
+        let cmp = compare_periods(&pool, ComparePeriod::Wow, now())
+            .await
+            .unwrap();
+
+        assert_eq!(cmp.current.replies_sent, 1);
+        assert!(cmp.previous.is_none());
+        assert_eq!(cmp.deltas, PeriodDeltas::default());
+    }
+
+    #[test]
+    fn percent_change_handles_zero_and_negative_base() {
+        assert_eq!(percent_change(5.0, 0.0), None);
+        assert_eq!(percent_change(-5.0, -10.0), Some(50.0));
+        assert_eq!(percent_change(0.0, 4.0), Some(-100.0));
+    }
+
+    #[test]
+    fn compare_period_parse_and_length() {
+        assert_eq!(ComparePeriod::parse("wow"), Some(ComparePeriod::Wow));
+        assert_eq!(ComparePeriod::parse("yoy"), None);
+        assert_eq!(ComparePeriod::Mom.length(), Duration::days(30));
+    }
+}
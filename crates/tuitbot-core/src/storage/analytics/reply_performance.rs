@@ -31,7 +31,8 @@ pub async fn upsert_reply_performance_for(
     .execute(pool)
     .await
     .map_err(|e| StorageError::Query { source: e })?;
-    Ok(())
+
+    super::super::author_reputation::record_reply_outcome_for(pool, account_id, reply_id).await
 }
 
 /// Store or update reply performance metrics.
@@ -1,6 +1,6 @@
 use super::*;
 use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
-use crate::storage::init_test_db;
+use crate::storage::{init_test_db, DbPool};
 
 #[tokio::test]
 async fn upsert_and_get_follower_snapshot() {
@@ -223,6 +223,7 @@ async fn recent_performance_items_with_data() {
         created_at: "2026-02-23T12:00:00Z".to_string(),
         status: "sent".to_string(),
         error_message: None,
+        conversation_id: None,
     };
     crate::storage::replies::insert_reply(&pool, &reply)
         .await
@@ -568,7 +569,7 @@ async fn avg_tweet_engagement_with_data() {
 #[tokio::test]
 async fn optimal_posting_times_empty() {
     let pool = init_test_db().await.expect("init db");
-    let times = get_optimal_posting_times(&pool).await.expect("get");
+    let times = get_optimal_posting_times(&pool, 1).await.expect("get");
     assert!(times.is_empty());
 }
 
@@ -605,13 +606,130 @@ async fn optimal_posting_times_with_data() {
         .await
         .expect("perf afternoon");
 
-    let times = get_optimal_posting_times(&pool).await.expect("get");
+    let times = get_optimal_posting_times(&pool, 1).await.expect("get");
     assert_eq!(times.len(), 2);
     // Best hour should be first (ordered by avg_engagement DESC)
     assert!(times[0].avg_engagement >= times[1].avg_engagement);
     assert_eq!(times[0].hour, 9);
 }
 
+#[tokio::test]
+async fn optimal_times_by_weekday_buckets_in_local_timezone() {
+    let pool = init_test_db().await.expect("init db");
+
+    // 2026-03-02 is a Monday; New York is UTC-5 before the March DST switch.
+    let seeds = [
+        ("tw-a", "2026-03-02T14:00:00Z", 90.0), // Mon 09:00 local
+        ("tw-b", "2026-03-02T17:00:00Z", 60.0), // Mon 12:00 local
+        ("tw-c", "2026-03-03T03:00:00Z", 75.0), // Mon 22:00 local (Tue in UTC)
+        ("tw-d", "2026-03-02T20:00:00Z", 10.0), // Mon 15:00 local
+        ("tw-e", "2026-03-04T15:00:00Z", 50.0), // Wed 10:00 local
+    ];
+    for (id, at, score) in seeds {
+        sqlx::query(
+            "INSERT INTO original_tweets \
+             (account_id, tweet_id, content, topic, status, created_at) \
+             VALUES (?, ?, 'tweet', 'rust', 'sent', ?)",
+        )
+        .bind(DEFAULT_ACCOUNT_ID)
+        .bind(id)
+        .bind(at)
+        .execute(&pool)
+        .await
+        .expect("insert tweet");
+        upsert_tweet_performance(&pool, id, 1, 1, 1, 100, score)
+            .await
+            .expect("perf");
+    }
+
+    let days = get_optimal_times_by_weekday(&pool, chrono_tz::America::New_York, 3, 1)
+        .await
+        .expect("get");
+    assert_eq!(days.len(), 2);
+
+    assert_eq!(days[0].weekday, "Mon");
+    assert_eq!(days[0].post_count, 4);
+    let hours: Vec<i64> = days[0].hours.iter().map(|h| h.hour).collect();
+    assert_eq!(hours, vec![9, 22, 12]);
+
+    assert_eq!(days[1].weekday, "Wed");
+    assert_eq!(days[1].hours[0].hour, 10);
+}
+
+/// Three mediocre posts at Mon 09:00 UTC and one viral post at Mon 12:00.
+async fn seed_sparse_and_dense_hours(pool: &DbPool) {
+    let seeds = [
+        ("dense-1", "2026-03-02T09:00:00Z", 40.0),
+        ("dense-2", "2026-03-09T09:10:00Z", 50.0),
+        ("dense-3", "2026-03-16T09:20:00Z", 60.0),
+        ("sparse-1", "2026-03-02T12:00:00Z", 500.0),
+    ];
+    for (id, at, score) in seeds {
+        sqlx::query(
+            "INSERT INTO original_tweets \
+             (account_id, tweet_id, content, topic, status, created_at) \
+             VALUES (?, ?, 'tweet', 'rust', 'sent', ?)",
+        )
+        .bind(DEFAULT_ACCOUNT_ID)
+        .bind(id)
+        .bind(at)
+        .execute(pool)
+        .await
+        .expect("insert tweet");
+        upsert_tweet_performance(pool, id, 1, 1, 1, 100, score)
+            .await
+            .expect("perf");
+    }
+}
+
+#[tokio::test]
+async fn optimal_times_exclude_hours_below_min_sample() {
+    let pool = init_test_db().await.expect("init db");
+    seed_sparse_and_dense_hours(&pool).await;
+
+    let days = get_optimal_times_by_weekday(&pool, chrono_tz::UTC, 3, 3)
+        .await
+        .expect("get");
+    assert_eq!(days.len(), 1);
+    let hours: Vec<i64> = days[0].hours.iter().map(|h| h.hour).collect();
+    assert_eq!(hours, vec![9], "single viral post must not be recommended");
+    assert_eq!(days[0].post_count, 3);
+
+    let times = get_optimal_posting_times(&pool, 3).await.expect("get");
+    let flags: Vec<(i64, bool)> = times.iter().map(|t| (t.hour, t.low_confidence)).collect();
+    assert_eq!(flags, vec![(12, true), (9, false)]);
+}
+
+#[tokio::test]
+async fn optimal_times_keep_all_hours_at_or_above_min_sample() {
+    let pool = init_test_db().await.expect("init db");
+    seed_sparse_and_dense_hours(&pool).await;
+
+    let days = get_optimal_times_by_weekday(&pool, chrono_tz::UTC, 3, 1)
+        .await
+        .expect("get");
+    let hours: Vec<i64> = days[0].hours.iter().map(|h| h.hour).collect();
+    assert_eq!(hours, vec![12, 9]);
+
+    let times = get_optimal_posting_times(&pool, 1).await.expect("get");
+    assert!(times.iter().all(|t| !t.low_confidence));
+
+    // Below the threshold everywhere: nothing to recommend.
+    let days = get_optimal_times_by_weekday(&pool, chrono_tz::UTC, 3, 4)
+        .await
+        .expect("get");
+    assert!(days.is_empty());
+}
+
+#[tokio::test]
+async fn optimal_times_by_weekday_empty() {
+    let pool = init_test_db().await.expect("init db");
+    let days = get_optimal_times_by_weekday(&pool, chrono_tz::UTC, 3, 1)
+        .await
+        .expect("get");
+    assert!(days.is_empty());
+}
+
 #[tokio::test]
 async fn content_score_incremental_mean() {
     let pool = init_test_db().await.expect("init db");
@@ -782,6 +900,7 @@ async fn recent_performance_items_with_tweets_and_replies() {
         created_at: "2026-03-01T12:00:00Z".to_string(),
         status: "sent".to_string(),
         error_message: None,
+        conversation_id: None,
     };
     crate::storage::replies::insert_reply(&pool, &reply)
         .await
@@ -1082,10 +1201,14 @@ async fn aggregate_best_times_populates_table() {
 
     aggregate_best_times_for(&pool, acct).await.expect("agg");
 
-    let slots = get_best_times(&pool).await.expect("get");
+    let slots = get_best_times(&pool, 1).await.expect("get");
     assert_eq!(slots.len(), 2);
     // Both should have confidence based on sample_size = 1
     assert!(slots.iter().all(|s| s.sample_size == 1));
+    assert!(slots.iter().all(|s| !s.low_confidence));
+
+    let slots = get_best_times(&pool, 2).await.expect("get");
+    assert!(slots.iter().all(|s| s.low_confidence));
 }
 
 #[tokio::test]
@@ -1115,3 +1238,64 @@ async fn aggregate_reach_creates_snapshot() {
     assert_eq!(reach[0].total_reach, 500);
     assert_eq!(reach[0].post_count, 1);
 }
+
+async fn seed_topic_post(pool: &DbPool, tweet_id: &str, topic: &str, likes: i64, score: f64) {
+    sqlx::query(
+        "INSERT INTO original_tweets (account_id, tweet_id, content, topic, status, created_at) \
+         VALUES (?, ?, 'tweet', ?, 'sent', '2026-03-02T14:00:00Z')",
+    )
+    .bind(DEFAULT_ACCOUNT_ID)
+    .bind(tweet_id)
+    .bind(topic)
+    .execute(pool)
+    .await
+    .expect("insert tweet");
+    upsert_tweet_performance(pool, tweet_id, likes, 2, 1, 1000, score)
+        .await
+        .expect("perf");
+}
+
+#[tokio::test]
+async fn topic_outcomes_rank_by_average_and_flag_sparse_topics() {
+    let pool = init_test_db().await.expect("init db");
+
+    seed_topic_post(&pool, "r1", "rust", 10, 60.0).await;
+    seed_topic_post(&pool, "r2", "rust", 20, 80.0).await;
+    seed_topic_post(&pool, "r3", "rust", 30, 70.0).await;
+    seed_topic_post(&pool, "g1", "go", 4, 30.0).await;
+    seed_topic_post(&pool, "g2", "go", 6, 50.0).await;
+    seed_topic_post(&pool, "g3", "go", 8, 40.0).await;
+    // A single viral post must not outrank the well-sampled topics.
+    seed_topic_post(&pool, "z1", "zig", 500, 400.0).await;
+    // Untagged and unmeasured posts are left out.
+    seed_topic_post(&pool, "u1", "", 100, 100.0).await;
+    sqlx::query(
+        "INSERT INTO original_tweets (account_id, tweet_id, content, topic, status, created_at) \
+         VALUES (?, 'unmeasured', 'tweet', 'rust', 'sent', '2026-03-02T14:00:00Z')",
+    )
+    .bind(DEFAULT_ACCOUNT_ID)
+    .execute(&pool)
+    .await
+    .expect("insert tweet");
+
+    let outcomes = get_topic_outcomes(&pool, 3, 10).await.expect("outcomes");
+    let topics: Vec<&str> = outcomes.iter().map(|o| o.topic.as_str()).collect();
+    assert_eq!(topics, ["rust", "go", "zig"]);
+
+    let rust = &outcomes[0];
+    assert_eq!(rust.post_count, 3);
+    assert!((rust.avg_performance - 70.0).abs() < 1e-9);
+    assert!((rust.avg_likes - 20.0).abs() < 1e-9);
+    assert!((rust.avg_impressions - 1000.0).abs() < 1e-9);
+    assert!(!rust.low_confidence);
+
+    assert!((outcomes[1].avg_performance - 40.0).abs() < 1e-9);
+    assert!(!outcomes[1].low_confidence);
+
+    assert_eq!(outcomes[2].post_count, 1);
+    assert!(outcomes[2].low_confidence);
+
+    let top = get_topic_outcomes(&pool, 3, 1).await.expect("outcomes");
+    assert_eq!(top.len(), 1);
+    assert_eq!(top[0].topic, "rust");
+}
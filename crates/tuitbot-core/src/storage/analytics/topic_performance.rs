@@ -0,0 +1,81 @@
+//! Per-topic outcomes: posted original tweets joined to their measured engagement.
+
+use super::super::accounts::DEFAULT_ACCOUNT_ID;
+use super::super::DbPool;
+use super::is_low_confidence;
+use crate::error::StorageError;
+use serde::{Deserialize, Serialize};
+
+/// Average engagement of the measured posts tagged with one topic.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TopicOutcome {
+    pub topic: String,
+    /// Posts with performance measurements.
+    pub post_count: i64,
+    pub avg_likes: f64,
+    pub avg_retweets: f64,
+    pub avg_replies: f64,
+    pub avg_impressions: f64,
+    pub avg_performance: f64,
+    /// True when `post_count` is below the minimum sample; ranked last.
+    #[serde(default)]
+    pub low_confidence: bool,
+}
+
+/// Rank topics by the average performance score of their measured posts
+/// for a specific account.
+///
+/// Only original tweets (including thread roots) posted with a topic count.
+/// Topics with fewer than `min_sample` measured posts are flagged
+/// `low_confidence` and ranked after every other topic; within each group,
+/// by avg_performance DESC.
+pub async fn get_topic_outcomes_for(
+    pool: &DbPool,
+    account_id: &str,
+    min_sample: u32,
+    limit: u32,
+) -> Result<Vec<TopicOutcome>, StorageError> {
+    let rows: Vec<(String, i64, f64, f64, f64, f64, f64)> = sqlx::query_as(
+        "SELECT ot.topic, COUNT(*) as post_count, \
+                AVG(tp.likes_received), AVG(tp.retweets_received), \
+                AVG(tp.replies_received), AVG(tp.impressions), \
+                AVG(tp.performance_score) as avg_performance \
+         FROM original_tweets ot \
+         JOIN tweet_performance tp ON tp.tweet_id = ot.tweet_id \
+         WHERE ot.account_id = ? AND ot.status = 'sent' \
+           AND ot.topic IS NOT NULL AND ot.topic != '' \
+         GROUP BY ot.topic \
+         ORDER BY avg_performance DESC",
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let mut outcomes: Vec<TopicOutcome> = rows
+        .into_iter()
+        .map(|r| TopicOutcome {
+            topic: r.0,
+            post_count: r.1,
+            avg_likes: r.2,
+            avg_retweets: r.3,
+            avg_replies: r.4,
+            avg_impressions: r.5,
+            avg_performance: r.6,
+            low_confidence: is_low_confidence(r.1, min_sample),
+        })
+        .collect();
+    // Stable sort keeps the performance order within each group.
+    outcomes.sort_by_key(|o| o.low_confidence);
+    outcomes.truncate(limit as usize);
+    Ok(outcomes)
+}
+
+/// Rank topics by the average performance score of their measured posts.
+pub async fn get_topic_outcomes(
+    pool: &DbPool,
+    min_sample: u32,
+    limit: u32,
+) -> Result<Vec<TopicOutcome>, StorageError> {
+    get_topic_outcomes_for(pool, DEFAULT_ACCOUNT_ID, min_sample, limit).await
+}
@@ -3,6 +3,7 @@
 use super::{ApprovalItem, ApprovalRow, ApprovalStats, ReviewAction};
 use crate::error::StorageError;
 use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::pagination::{Page, PageCursor, AFTER_CURSOR_SQL};
 use crate::storage::provenance::ProvenanceRef;
 use crate::storage::DbPool;
 
@@ -519,6 +520,57 @@ pub async fn get_filtered_for(
     action_type: Option<&str>,
     reviewed_by: Option<&str>,
     since: Option<&str>,
+) -> Result<Vec<ApprovalItem>, StorageError> {
+    query_filtered(
+        pool,
+        account_id,
+        statuses,
+        action_type,
+        reviewed_by,
+        since,
+        None,
+    )
+    .await
+}
+
+/// Get one keyset page of filtered approval items for a specific account,
+/// oldest first, starting after `cursor` (or at the oldest item).
+#[allow(clippy::too_many_arguments)]
+pub async fn get_filtered_page_for(
+    pool: &DbPool,
+    account_id: &str,
+    statuses: &[&str],
+    action_type: Option<&str>,
+    reviewed_by: Option<&str>,
+    since: Option<&str>,
+    limit: u32,
+    cursor: Option<&PageCursor>,
+) -> Result<Page<ApprovalItem>, StorageError> {
+    let rows = query_filtered(
+        pool,
+        account_id,
+        statuses,
+        action_type,
+        reviewed_by,
+        since,
+        Some((limit.saturating_add(1), cursor)),
+    )
+    .await?;
+    Ok(Page::from_rows(rows, limit, |item| {
+        PageCursor::new(&item.created_at, item.id)
+    }))
+}
+
+/// Filtered approval items in `created_at, id` order, optionally limited to
+/// `page.0` rows after the `page.1` cursor.
+async fn query_filtered(
+    pool: &DbPool,
+    account_id: &str,
+    statuses: &[&str],
+    action_type: Option<&str>,
+    reviewed_by: Option<&str>,
+    since: Option<&str>,
+    page: Option<(u32, Option<&PageCursor>)>,
 ) -> Result<Vec<ApprovalItem>, StorageError> {
     if statuses.is_empty() {
         return Ok(Vec::new());
@@ -540,7 +592,15 @@ pub async fn get_filtered_for(
     if since.is_some() {
         sql.push_str(" AND created_at >= ?");
     }
-    sql.push_str(" ORDER BY created_at ASC");
+    let cursor = page.and_then(|(_, cursor)| cursor);
+    if cursor.is_some() {
+        sql.push_str(" AND ");
+        sql.push_str(AFTER_CURSOR_SQL);
+    }
+    sql.push_str(" ORDER BY created_at ASC, id ASC");
+    if page.is_some() {
+        sql.push_str(" LIMIT ?");
+    }
 
     let mut q = sqlx::query_as::<_, ApprovalRow>(&sql);
     q = q.bind(account_id);
@@ -556,6 +616,12 @@ pub async fn get_filtered_for(
     if let Some(s) = since {
         q = q.bind(s);
     }
+    if let Some(c) = cursor {
+        q = q.bind(&c.created_at).bind(&c.created_at).bind(c.id);
+    }
+    if let Some((limit, _)) = page {
+        q = q.bind(limit);
+    }
 
     let rows = q
         .fetch_all(pool)
@@ -404,6 +404,58 @@ async fn get_filtered_by_action_type() {
     assert!(items.iter().all(|i| i.action_type == "tweet"));
 }
 
+#[tokio::test]
+async fn filtered_pages_cover_every_item_once_while_items_arrive() {
+    use crate::storage::pagination::PageCursor;
+
+    let pool = init_test_db().await.expect("init db");
+    for text in ["A", "B", "C"] {
+        enqueue(&pool, "tweet", "", "", text, "General", "", 0.0, "[]")
+            .await
+            .expect("enqueue");
+    }
+
+    let first = get_filtered_page_for(
+        &pool,
+        DEFAULT_ACCOUNT_ID,
+        &["pending"],
+        None,
+        None,
+        None,
+        2,
+        None,
+    )
+    .await
+    .expect("page 1");
+    // Queued in the same second as A-C, so only the id orders it after them.
+    enqueue(&pool, "tweet", "", "", "D", "General", "", 0.0, "[]")
+        .await
+        .expect("enqueue");
+
+    let cursor = PageCursor::decode(first.next_cursor.as_deref().unwrap()).unwrap();
+    let second = get_filtered_page_for(
+        &pool,
+        DEFAULT_ACCOUNT_ID,
+        &["pending"],
+        None,
+        None,
+        None,
+        2,
+        Some(&cursor),
+    )
+    .await
+    .expect("page 2");
+    assert!(second.next_cursor.is_none());
+
+    let texts: Vec<&str> = first
+        .items
+        .iter()
+        .chain(&second.items)
+        .map(|i| i.generated_content.as_str())
+        .collect();
+    assert_eq!(texts, ["A", "B", "C", "D"]);
+}
+
 #[tokio::test]
 async fn get_filtered_by_reviewer() {
     let pool = init_test_db().await.expect("init db");
@@ -0,0 +1,258 @@
+//! Per-author reply outcomes for reputation-weighted scoring.
+//!
+//! Each row summarizes how our replies to one author have performed.
+//! Aggregates are recomputed from `replies_sent`, `discovered_tweets`, and
+//! `reply_performance` rather than incremented, so re-measuring a reply
+//! never double-counts.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Historical reply outcomes for a single author.
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow)]
+pub struct AuthorReputation {
+    /// X user ID of the author.
+    pub author_id: String,
+    /// Author's username at the time of the last refresh.
+    pub author_username: String,
+    /// Replies we have sent to this author's tweets.
+    pub replies_sent: i64,
+    /// Replies with measured performance.
+    pub replies_measured: i64,
+    /// Mean `performance_score` across measured replies.
+    pub avg_performance: f64,
+    /// When the row was last recomputed.
+    pub updated_at: String,
+}
+
+/// Recompute the reputation row for an author for a specific account.
+pub async fn refresh_author_reputation_for(
+    pool: &DbPool,
+    account_id: &str,
+    author_id: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "INSERT INTO author_reputation \
+         (account_id, author_id, author_username, replies_sent, replies_measured, avg_performance, updated_at) \
+         SELECT rs.account_id, dt.author_id, MAX(dt.author_username), COUNT(*), COUNT(rp.reply_id), \
+                COALESCE(AVG(rp.performance_score), 0.0), datetime('now') \
+         FROM replies_sent rs \
+         JOIN discovered_tweets dt ON dt.id = rs.target_tweet_id \
+         LEFT JOIN reply_performance rp ON rp.reply_id = rs.reply_tweet_id \
+         WHERE rs.account_id = ? AND rs.status = 'sent' AND dt.author_id = ? \
+         GROUP BY rs.account_id, dt.author_id \
+         ON CONFLICT(account_id, author_id) DO UPDATE SET \
+         author_username = excluded.author_username, \
+         replies_sent = excluded.replies_sent, \
+         replies_measured = excluded.replies_measured, \
+         avg_performance = excluded.avg_performance, \
+         updated_at = excluded.updated_at",
+    )
+    .bind(account_id)
+    .bind(author_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(())
+}
+
+/// Recompute the reputation row for an author.
+pub async fn refresh_author_reputation(pool: &DbPool, author_id: &str) -> Result<(), StorageError> {
+    refresh_author_reputation_for(pool, DEFAULT_ACCOUNT_ID, author_id).await
+}
+
+/// Refresh the reputation of the author a measured reply was sent to.
+///
+/// No-op when the reply's target tweet was never discovered (e.g. manual
+/// replies), since the author is unknown.
+pub async fn record_reply_outcome_for(
+    pool: &DbPool,
+    account_id: &str,
+    reply_id: &str,
+) -> Result<(), StorageError> {
+    let author: Option<(String,)> = sqlx::query_as(
+        "SELECT dt.author_id FROM replies_sent rs \
+         JOIN discovered_tweets dt ON dt.id = rs.target_tweet_id \
+         WHERE rs.account_id = ? AND rs.reply_tweet_id = ? \
+         LIMIT 1",
+    )
+    .bind(account_id)
+    .bind(reply_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    match author {
+        Some((author_id,)) => refresh_author_reputation_for(pool, account_id, &author_id).await,
+        None => Ok(()),
+    }
+}
+
+/// Get the reputation row for an author for a specific account.
+pub async fn get_author_reputation_for(
+    pool: &DbPool,
+    account_id: &str,
+    author_id: &str,
+) -> Result<Option<AuthorReputation>, StorageError> {
+    sqlx::query_as::<_, AuthorReputation>(
+        "SELECT author_id, author_username, replies_sent, replies_measured, avg_performance, updated_at \
+         FROM author_reputation WHERE account_id = ? AND author_id = ?",
+    )
+    .bind(account_id)
+    .bind(author_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Get the reputation row for an author.
+pub async fn get_author_reputation(
+    pool: &DbPool,
+    author_id: &str,
+) -> Result<Option<AuthorReputation>, StorageError> {
+    get_author_reputation_for(pool, DEFAULT_ACCOUNT_ID, author_id).await
+}
+
+/// Mean reply `performance_score` across all authors for a specific account.
+///
+/// Returns `None` when no replies have been measured yet.
+pub async fn get_reply_performance_baseline_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Option<f64>, StorageError> {
+    let row: (Option<f64>,) =
+        sqlx::query_as("SELECT AVG(performance_score) FROM reply_performance WHERE account_id = ?")
+            .bind(account_id)
+            .fetch_one(pool)
+            .await
+            .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(row.0)
+}
+
+/// Seed a discovered tweet by `author_id` and a sent reply to it, returning
+/// the reply ID.
+#[cfg(test)]
+pub(crate) async fn seed_reply(pool: &DbPool, author_id: &str, n: usize) -> String {
+    let tweet_id = format!("{author_id}-tweet-{n}");
+    let reply_id = format!("{author_id}-reply-{n}");
+    crate::storage::tweets::insert_discovered_tweet(
+        pool,
+        &crate::storage::tweets::DiscoveredTweet {
+            id: tweet_id.clone(),
+            author_id: author_id.to_string(),
+            author_username: format!("user_{author_id}"),
+            content: "hello".to_string(),
+            like_count: 0,
+            retweet_count: 0,
+            reply_count: 0,
+            impression_count: None,
+            relevance_score: None,
+            matched_keyword: None,
+            discovered_at: "2026-03-01T00:00:00Z".to_string(),
+            replied_to: 1,
+        },
+    )
+    .await
+    .expect("insert tweet");
+    crate::storage::replies::insert_reply(
+        pool,
+        &crate::storage::replies::ReplySent {
+            id: 0,
+            target_tweet_id: tweet_id,
+            reply_tweet_id: Some(reply_id.clone()),
+            reply_content: "reply".to_string(),
+            llm_provider: None,
+            llm_model: None,
+            created_at: "2026-03-01T00:00:00Z".to_string(),
+            status: "sent".to_string(),
+            error_message: None,
+            conversation_id: None,
+        },
+    )
+    .await
+    .expect("insert reply");
+    reply_id
+}
+
+/// Seed one measured reply per score to tweets by `author_id`.
+#[cfg(test)]
+pub(crate) async fn seed_author_history(pool: &DbPool, author_id: &str, scores: &[f64]) {
+    for (i, score) in scores.iter().enumerate() {
+        let reply_id = seed_reply(pool, author_id, i).await;
+        crate::storage::analytics::upsert_reply_performance(pool, &reply_id, 0, 0, 100, *score)
+            .await
+            .expect("upsert performance");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::analytics::upsert_reply_performance;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn unknown_author_has_no_reputation() {
+        let pool = init_test_db().await.expect("init db");
+        let rep = get_author_reputation(&pool, "nobody").await.expect("get");
+        assert!(rep.is_none());
+    }
+
+    #[tokio::test]
+    async fn measuring_replies_updates_reputation() {
+        let pool = init_test_db().await.expect("init db");
+        let r1 = seed_reply(&pool, "a1", 1).await;
+        let r2 = seed_reply(&pool, "a1", 2).await;
+        seed_reply(&pool, "a1", 3).await;
+
+        upsert_reply_performance(&pool, &r1, 1, 0, 100, 30.0)
+            .await
+            .expect("upsert");
+        upsert_reply_performance(&pool, &r2, 0, 0, 100, 10.0)
+            .await
+            .expect("upsert");
+
+        let rep = get_author_reputation(&pool, "a1")
+            .await
+            .expect("get")
+            .expect("row");
+        assert_eq!(rep.author_username, "user_a1");
+        assert_eq!(rep.replies_sent, 3);
+        assert_eq!(rep.replies_measured, 2);
+        assert!((rep.avg_performance - 20.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn remeasuring_does_not_double_count() {
+        let pool = init_test_db().await.expect("init db");
+        let r1 = seed_reply(&pool, "a1", 1).await;
+
+        for score in [5.0, 15.0] {
+            upsert_reply_performance(&pool, &r1, 0, 0, 100, score)
+                .await
+                .expect("upsert");
+        }
+
+        let rep = get_author_reputation(&pool, "a1")
+            .await
+            .expect("get")
+            .expect("row");
+        assert_eq!(rep.replies_measured, 1);
+        assert!((rep.avg_performance - 15.0).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn outcome_for_unknown_reply_is_noop() {
+        let pool = init_test_db().await.expect("init db");
+        record_reply_outcome_for(&pool, DEFAULT_ACCOUNT_ID, "missing")
+            .await
+            .expect("noop");
+        let baseline = get_reply_performance_baseline_for(&pool, DEFAULT_ACCOUNT_ID)
+            .await
+            .expect("baseline");
+        assert!(baseline.is_none());
+    }
+}
@@ -42,6 +42,8 @@ pub struct ValidationResult {
     pub valid: bool,
     /// Tables found in the backup.
     pub tables: Vec<String>,
+    /// Newest migration recorded in the backup (`None` when it could not be opened).
+    pub schema_version: Option<i64>,
     /// Human-readable messages about the validation.
     pub messages: Vec<String>,
 }
@@ -103,12 +105,17 @@ async fn create_backup_with_prefix(
     })
 }
 
-/// Validate a backup file by opening it read-only and checking expected tables.
+/// Validate a backup file by opening it read-only and checking expected
+/// tables, integrity, and schema version.
+///
+/// A backup migrated by a newer tuitbot than this binary is invalid: it
+/// would be refused on first open after restoring.
 pub async fn validate_backup(backup_path: &Path) -> Result<ValidationResult, StorageError> {
     if !backup_path.exists() {
         return Ok(ValidationResult {
             valid: false,
             tables: vec![],
+            schema_version: None,
             messages: vec![format!("File not found: {}", backup_path.display())],
         });
     }
@@ -121,6 +128,7 @@ pub async fn validate_backup(backup_path: &Path) -> Result<ValidationResult, Sto
             return Ok(ValidationResult {
                 valid: false,
                 tables: vec![],
+                schema_version: None,
                 messages: vec![format!(
                     "File is too small to be a SQLite database: {}",
                     backup_path.display()
@@ -131,6 +139,7 @@ pub async fn validate_backup(backup_path: &Path) -> Result<ValidationResult, Sto
             return Ok(ValidationResult {
                 valid: false,
                 tables: vec![],
+                schema_version: None,
                 messages: vec![format!(
                     "File is not a valid SQLite database: {}",
                     backup_path.display()
@@ -141,6 +150,7 @@ pub async fn validate_backup(backup_path: &Path) -> Result<ValidationResult, Sto
             return Ok(ValidationResult {
                 valid: false,
                 tables: vec![],
+                schema_version: None,
                 messages: vec![format!(
                     "Cannot read backup file {}: {}",
                     backup_path.display(),
@@ -190,7 +200,7 @@ pub async fn validate_backup(backup_path: &Path) -> Result<ValidationResult, Sto
         }
     }
 
-    let valid = missing.is_empty() && !tables.is_empty();
+    let mut valid = missing.is_empty() && !tables.is_empty();
 
     if valid {
         messages.push(format!("Valid backup with {} tables", tables.len()));
@@ -200,6 +210,35 @@ pub async fn validate_backup(backup_path: &Path) -> Result<ValidationResult, Sto
         messages.push(format!("Missing expected tables: {}", missing.join(", ")));
     }
 
+    // Schema version.
+    let has_migrations: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations')",
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    let schema_version: i64 = if has_migrations {
+        sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) FROM _sqlx_migrations")
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| StorageError::Query { source: e })?
+    } else {
+        0
+    };
+    let latest = super::migrations::latest_version();
+    if schema_version > latest {
+        valid = false;
+        messages.push(format!(
+            "Schema version {schema_version} is newer than this binary supports ({latest})"
+        ));
+    } else if schema_version < latest {
+        messages.push(format!(
+            "Schema version {schema_version} (pending migrations apply on first open)"
+        ));
+    } else {
+        messages.push(format!("Schema version {schema_version} (current)"));
+    }
+
     // Integrity check.
     let integrity: String = sqlx::query_scalar("PRAGMA integrity_check")
         .fetch_one(&pool)
@@ -211,6 +250,7 @@ pub async fn validate_backup(backup_path: &Path) -> Result<ValidationResult, Sto
         return Ok(ValidationResult {
             valid: false,
             tables,
+            schema_version: Some(schema_version),
             messages,
         });
     }
@@ -220,6 +260,7 @@ pub async fn validate_backup(backup_path: &Path) -> Result<ValidationResult, Sto
     Ok(ValidationResult {
         valid,
         tables,
+        schema_version: Some(schema_version),
         messages,
     })
 }
@@ -310,6 +351,7 @@ pub fn list_backups(backup_dir: &Path) -> Vec<BackupInfo> {
         let timestamp = name
             .strip_prefix("tuitbot_")
             .and_then(|s| s.strip_suffix(".db"))
+            .map(|s| s.trim_end_matches(".inc"))
             .map(|s| s.to_string());
 
         backups.push(BackupInfo {
@@ -344,6 +386,8 @@ pub fn prune_backups(backup_dir: &Path, keep: usize) -> Result<u32, StorageError
             );
         } else {
             deleted += 1;
+            // Incremental chains keep a manifest sidecar next to each file.
+            let _ = std::fs::remove_file(super::incremental_backup::manifest_path(&backup.path));
         }
     }
 
@@ -781,5 +825,64 @@ mod tests {
             "should report valid: {:?}",
             validation.messages
         );
+        assert_eq!(
+            validation.schema_version,
+            Some(crate::storage::migrations::latest_version())
+        );
+    }
+
+    #[tokio::test]
+    async fn validate_backup_detects_corruption() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let (pool, _db_path) = file_test_db(dir.path()).await;
+        let backup_dir = dir.path().join("backups");
+        let result = create_backup(&pool, &backup_dir).await.expect("backup");
+        pool.close().await;
+
+        // Keep the header page intact and scribble over everything after it.
+        let mut bytes = std::fs::read(&result.path).expect("read backup");
+        let page_size = u16::from_be_bytes([bytes[16], bytes[17]]) as usize;
+        for b in bytes.iter_mut().skip(page_size) {
+            *b = 0xA5;
+        }
+        std::fs::write(&result.path, &bytes).expect("corrupt backup");
+
+        // Corruption surfaces either as a failed check or a query error.
+        let valid = validate_backup(&result.path)
+            .await
+            .map(|v| v.valid)
+            .unwrap_or(false);
+        assert!(!valid, "corrupted backup must not validate");
+    }
+
+    #[tokio::test]
+    async fn validate_backup_rejects_newer_schema() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let (pool, _db_path) = file_test_db(dir.path()).await;
+        let backup_dir = dir.path().join("backups");
+        let result = create_backup(&pool, &backup_dir).await.expect("backup");
+        pool.close().await;
+
+        let future = crate::storage::migrations::latest_version() + 1;
+        let backup = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect_with(SqliteConnectOptions::new().filename(&result.path))
+            .await
+            .expect("open backup");
+        sqlx::query(
+            "INSERT INTO _sqlx_migrations \
+             (version, description, success, checksum, execution_time) \
+             VALUES (?, 'future', 1, x'00', 0)",
+        )
+        .bind(future)
+        .execute(&backup)
+        .await
+        .expect("insert future migration");
+        backup.close().await;
+
+        let validation = validate_backup(&result.path).await.expect("validate");
+        assert!(!validation.valid);
+        assert_eq!(validation.schema_version, Some(future));
+        assert!(validation.messages.iter().any(|m| m.contains("newer")));
     }
 }
@@ -0,0 +1,191 @@
+//! Links posted items to the content framework that produced them.
+//!
+//! One row per posted tweet/reply/thread root. Engagement is not stored
+//! here: the analytics loop already measures posted items into
+//! `tweet_performance` and `reply_performance`, and the report joins
+//! against those tables by tweet ID.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Record that `tweet_id` was generated with `framework` of `kind`
+/// (`archetype`, `format`, or `structure`) for a specific account.
+///
+/// Re-recording the same tweet is a no-op.
+pub async fn record_framework_for(
+    pool: &DbPool,
+    account_id: &str,
+    tweet_id: &str,
+    kind: &str,
+    framework: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "INSERT OR IGNORE INTO content_frameworks (account_id, tweet_id, kind, framework) \
+         VALUES (?, ?, ?, ?)",
+    )
+    .bind(account_id)
+    .bind(tweet_id)
+    .bind(kind)
+    .bind(framework)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Record that `tweet_id` was generated with `framework` of `kind`.
+pub async fn record_framework(
+    pool: &DbPool,
+    tweet_id: &str,
+    kind: &str,
+    framework: &str,
+) -> Result<(), StorageError> {
+    record_framework_for(pool, DEFAULT_ACCOUNT_ID, tweet_id, kind, framework).await
+}
+
+/// Aggregated engagement for one framework.
+#[derive(Debug, Clone, PartialEq, sqlx::FromRow, serde::Serialize)]
+pub struct FrameworkPerformance {
+    /// `archetype`, `format`, or `structure`.
+    pub kind: String,
+    /// Framework name, e.g. `ask_question` or `contrarian_take`.
+    pub framework: String,
+    /// Posted items generated with this framework.
+    pub posted: i64,
+    /// Posted items that have engagement measurements so far.
+    pub measured: i64,
+    /// Average performance score over measured items (0 when none).
+    pub avg_performance: f64,
+    pub avg_likes: f64,
+    pub avg_replies: f64,
+    pub avg_impressions: f64,
+}
+
+/// Per-framework engagement for a specific account, best average first
+/// within each kind.
+pub async fn get_framework_performance_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Vec<FrameworkPerformance>, StorageError> {
+    sqlx::query_as::<_, FrameworkPerformance>(
+        "SELECT f.kind, f.framework, \
+         COUNT(*) AS posted, \
+         COUNT(p.performance_score) AS measured, \
+         COALESCE(AVG(p.performance_score), 0.0) AS avg_performance, \
+         COALESCE(AVG(p.likes_received), 0.0) AS avg_likes, \
+         COALESCE(AVG(p.replies_received), 0.0) AS avg_replies, \
+         COALESCE(AVG(p.impressions), 0.0) AS avg_impressions \
+         FROM content_frameworks f \
+         LEFT JOIN ( \
+             SELECT account_id, tweet_id AS id, performance_score, likes_received, \
+                    replies_received, impressions FROM tweet_performance \
+             UNION ALL \
+             SELECT account_id, reply_id AS id, performance_score, likes_received, \
+                    replies_received, impressions FROM reply_performance \
+         ) p ON p.id = f.tweet_id AND p.account_id = f.account_id \
+         WHERE f.account_id = ? \
+         GROUP BY f.kind, f.framework \
+         ORDER BY f.kind, avg_performance DESC, f.framework",
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Per-framework engagement, best average first within each kind.
+pub async fn get_framework_performance(
+    pool: &DbPool,
+) -> Result<Vec<FrameworkPerformance>, StorageError> {
+    get_framework_performance_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::analytics::{upsert_reply_performance, upsert_tweet_performance};
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn report_averages_measured_items_per_framework() {
+        let pool = init_test_db().await.expect("init db");
+
+        record_framework(&pool, "t1", "format", "list")
+            .await
+            .unwrap();
+        record_framework(&pool, "t2", "format", "list")
+            .await
+            .unwrap();
+        record_framework(&pool, "t3", "format", "question")
+            .await
+            .unwrap();
+        record_framework(&pool, "r1", "archetype", "ask_question")
+            .await
+            .unwrap();
+        // Duplicate record is ignored.
+        record_framework(&pool, "t1", "format", "tip")
+            .await
+            .unwrap();
+
+        upsert_tweet_performance(&pool, "t1", 10, 1, 2, 100, 40.0)
+            .await
+            .unwrap();
+        upsert_tweet_performance(&pool, "t2", 20, 3, 4, 300, 60.0)
+            .await
+            .unwrap();
+        upsert_tweet_performance(&pool, "t3", 50, 5, 6, 500, 90.0)
+            .await
+            .unwrap();
+        upsert_reply_performance(&pool, "r1", 4, 1, 80, 30.0)
+            .await
+            .unwrap();
+
+        let report = get_framework_performance(&pool).await.unwrap();
+        assert_eq!(report.len(), 3);
+
+        assert_eq!(report[0].kind, "archetype");
+        assert_eq!(report[0].framework, "ask_question");
+        assert_eq!(report[0].avg_likes, 4.0);
+
+        assert_eq!(report[1].framework, "question");
+        assert_eq!(report[1].avg_performance, 90.0);
+
+        let list = &report[2];
+        assert_eq!(list.framework, "list");
+        assert_eq!((list.posted, list.measured), (2, 2));
+        assert_eq!(list.avg_performance, 50.0);
+        assert_eq!(list.avg_likes, 15.0);
+        assert_eq!(list.avg_impressions, 200.0);
+    }
+
+    #[tokio::test]
+    async fn unmeasured_items_count_as_posted_only() {
+        let pool = init_test_db().await.expect("init db");
+        record_framework(&pool, "t1", "structure", "mistakes")
+            .await
+            .unwrap();
+
+        let report = get_framework_performance(&pool).await.unwrap();
+        assert_eq!(report[0].posted, 1);
+        assert_eq!(report[0].measured, 0);
+        assert_eq!(report[0].avg_performance, 0.0);
+    }
+
+    #[tokio::test]
+    async fn report_is_scoped_to_account() {
+        let pool = init_test_db().await.expect("init db");
+        record_framework_for(&pool, "other", "t1", "format", "tip")
+            .await
+            .unwrap();
+
+        assert!(get_framework_performance(&pool).await.unwrap().is_empty());
+        assert_eq!(
+            get_framework_performance_for(&pool, "other")
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+    }
+}
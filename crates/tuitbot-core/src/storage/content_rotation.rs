@@ -0,0 +1,82 @@
+//! Selection history for archetype/format/structure rotation.
+//!
+//! Rows are append-only; the rotator only needs the most recent use of each
+//! variant, so ordering is by the autoincrement `id` rather than `used_at`
+//! (which has one-second resolution).
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+use std::collections::HashMap;
+
+/// Record that `variant` of `kind` was selected for a specific account.
+pub async fn record_use_for(
+    pool: &DbPool,
+    account_id: &str,
+    kind: &str,
+    variant: &str,
+) -> Result<(), StorageError> {
+    sqlx::query("INSERT INTO content_rotation (account_id, kind, variant) VALUES (?, ?, ?)")
+        .bind(account_id)
+        .bind(kind)
+        .bind(variant)
+        .execute(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Record that `variant` of `kind` was selected.
+pub async fn record_use(pool: &DbPool, kind: &str, variant: &str) -> Result<(), StorageError> {
+    record_use_for(pool, DEFAULT_ACCOUNT_ID, kind, variant).await
+}
+
+/// Map each previously used variant of `kind` to the sequence number of its
+/// latest use for a specific account. Higher means more recent.
+pub async fn last_used_for(
+    pool: &DbPool,
+    account_id: &str,
+    kind: &str,
+) -> Result<HashMap<String, i64>, StorageError> {
+    let rows: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT variant, MAX(id) FROM content_rotation \
+         WHERE account_id = ? AND kind = ? \
+         GROUP BY variant",
+    )
+    .bind(account_id)
+    .bind(kind)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(rows.into_iter().collect())
+}
+
+/// Map each previously used variant of `kind` to the sequence number of its latest use.
+pub async fn last_used(pool: &DbPool, kind: &str) -> Result<HashMap<String, i64>, StorageError> {
+    last_used_for(pool, DEFAULT_ACCOUNT_ID, kind).await
+}
+
+/// Map each previously used variant of `kind` to how many times it was
+/// selected for a specific account.
+pub async fn use_counts_for(
+    pool: &DbPool,
+    account_id: &str,
+    kind: &str,
+) -> Result<HashMap<String, i64>, StorageError> {
+    let rows: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT variant, COUNT(*) FROM content_rotation \
+         WHERE account_id = ? AND kind = ? \
+         GROUP BY variant",
+    )
+    .bind(account_id)
+    .bind(kind)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(rows.into_iter().collect())
+}
+
+/// Map each previously used variant of `kind` to how many times it was selected.
+pub async fn use_counts(pool: &DbPool, kind: &str) -> Result<HashMap<String, i64>, StorageError> {
+    use_counts_for(pool, DEFAULT_ACCOUNT_ID, kind).await
+}
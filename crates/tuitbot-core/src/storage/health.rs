@@ -1,8 +1,15 @@
 //! Database health check for the deep health endpoint.
 
 use super::DbPool;
+use crate::error::StorageError;
 use serde::Serialize;
-use std::time::Instant;
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Upper bound on the `SELECT 1` probe so a locked or hung database
+/// reports unreachable instead of stalling the health endpoint.
+pub const PROBE_TIMEOUT: Duration = Duration::from_secs(2);
 
 /// Database health check result.
 #[derive(Debug, Clone, Serialize)]
@@ -13,25 +20,63 @@ pub struct DbHealth {
     pub latency_ms: u64,
     /// Whether WAL journal mode is active.
     pub wal_mode: bool,
+    /// Whether the probe query hit [`PROBE_TIMEOUT`].
+    pub timed_out: bool,
+    /// Connection pool occupancy at the time of the check.
+    pub pool: PoolStats,
+}
+
+/// Snapshot of sqlx connection pool occupancy.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct PoolStats {
+    /// Connections checked out and in use.
+    pub active: u32,
+    /// Open connections waiting in the pool.
+    pub idle: u32,
+    /// Open connections (active + idle).
+    pub size: u32,
+    /// Configured maximum pool size.
+    pub max_size: u32,
+}
+
+/// Read the current pool occupancy without touching the database.
+pub fn pool_stats(pool: &DbPool) -> PoolStats {
+    let size = pool.size();
+    let idle = u32::try_from(pool.num_idle()).unwrap_or(u32::MAX).min(size);
+    PoolStats {
+        active: size - idle,
+        idle,
+        size,
+        max_size: pool.options().get_max_connections(),
+    }
 }
 
 /// Check database health by running a probe query and inspecting journal mode.
 pub async fn check_db_health(pool: &DbPool) -> DbHealth {
+    // Sample occupancy before the probe borrows a connection.
+    let pool_stats = pool_stats(pool);
     let start = Instant::now();
 
-    let reachable = sqlx::query_scalar::<_, i32>("SELECT 1")
-        .fetch_one(pool)
-        .await
-        .is_ok();
+    let probe = tokio::time::timeout(
+        PROBE_TIMEOUT,
+        sqlx::query_scalar::<_, i32>("SELECT 1").fetch_one(pool),
+    )
+    .await;
+    let timed_out = probe.is_err();
+    let reachable = matches!(probe, Ok(Ok(_)));
 
     let latency_ms = start.elapsed().as_millis() as u64;
 
     let wal_mode = if reachable {
-        sqlx::query_scalar::<_, String>("PRAGMA journal_mode")
-            .fetch_one(pool)
-            .await
-            .map(|mode| mode.eq_ignore_ascii_case("wal"))
-            .unwrap_or(false)
+        tokio::time::timeout(
+            PROBE_TIMEOUT,
+            sqlx::query_scalar::<_, String>("PRAGMA journal_mode").fetch_one(pool),
+        )
+        .await
+        .ok()
+        .and_then(Result::ok)
+        .map(|mode| mode.eq_ignore_ascii_case("wal"))
+        .unwrap_or(false)
     } else {
         false
     };
@@ -40,9 +85,46 @@ pub async fn check_db_health(pool: &DbPool) -> DbHealth {
         reachable,
         latency_ms,
         wal_mode,
+        timed_out,
+        pool: pool_stats,
     }
 }
 
+/// Open an existing database file read-only and return the latest applied
+/// migration version (0 when no migrations have run).
+///
+/// Unlike [`init_db`](super::init_db) this never creates the file, runs
+/// migrations, or changes pragmas, so it is safe for diagnostics.
+pub async fn read_schema_version(db_path: &Path) -> Result<i64, StorageError> {
+    let options = SqliteConnectOptions::new()
+        .filename(db_path)
+        .read_only(true)
+        .create_if_missing(false);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .acquire_timeout(PROBE_TIMEOUT)
+        .connect_with(options)
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+
+    let has_migrations: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = '_sqlx_migrations')",
+    )
+    .fetch_one(&pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    let version = if has_migrations {
+        sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) FROM _sqlx_migrations")
+            .fetch_one(&pool)
+            .await
+            .map_err(|e| StorageError::Query { source: e })?
+    } else {
+        0
+    };
+    pool.close().await;
+    Ok(version)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -53,9 +135,21 @@ mod tests {
         let pool = init_test_db().await.expect("init test db");
         let health = check_db_health(&pool).await;
         assert!(health.reachable);
+        assert!(!health.timed_out);
         // In-memory SQLite doesn't report WAL mode via PRAGMA journal_mode.
     }
 
+    #[tokio::test]
+    async fn pool_stats_reflect_checked_out_connections() {
+        let pool = init_test_db().await.expect("init test db");
+        let conn = pool.acquire().await.expect("acquire");
+        let stats = pool_stats(&pool);
+        assert_eq!(stats.max_size, 1);
+        assert_eq!(stats.active, 1);
+        assert_eq!(stats.idle, 0);
+        drop(conn);
+    }
+
     #[tokio::test]
     async fn check_healthy_file_db_wal() {
         let dir = tempfile::tempdir().expect("create temp dir");
@@ -68,4 +162,21 @@ mod tests {
         assert!(health.wal_mode);
         pool.close().await;
     }
+
+    #[tokio::test]
+    async fn read_schema_version_reports_latest_migration_without_creating() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let missing = dir.path().join("missing.db");
+        assert!(read_schema_version(&missing).await.is_err());
+        assert!(!missing.exists());
+
+        let db_path = dir.path().join("schema.db");
+        let pool = crate::storage::init_db(&db_path.to_string_lossy())
+            .await
+            .expect("init file db");
+        pool.close().await;
+
+        let version = read_schema_version(&db_path).await.expect("read version");
+        assert!(version >= 20260328000100);
+    }
 }
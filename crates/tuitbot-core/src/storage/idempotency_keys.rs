@@ -0,0 +1,152 @@
+//! Persisted MCP idempotency keys.
+//!
+//! Backs the MCP server's `IdempotencyStore` so a mutation retried after a
+//! restart is still recognised. Each row is keyed by the `(tool, params)`
+//! fingerprint and carries the original call's result once it succeeds.
+//! Rows older than the caller's TTL are treated as absent and pruned.
+
+use super::DbPool;
+use crate::error::StorageError;
+
+/// A recorded idempotency key.
+#[derive(Debug, Clone, PartialEq, Eq, sqlx::FromRow)]
+pub struct IdempotencyKeyRow {
+    pub key: String,
+    pub tool_name: String,
+    /// Result JSON of the original call, once it completed successfully.
+    pub response: Option<String>,
+    pub created_at: String,
+}
+
+/// Find `key` if it was recorded within the last `ttl_seconds`.
+pub async fn find_live_key(
+    pool: &DbPool,
+    key: &str,
+    ttl_seconds: u64,
+) -> Result<Option<IdempotencyKeyRow>, StorageError> {
+    sqlx::query_as::<_, IdempotencyKeyRow>(
+        "SELECT * FROM mcp_idempotency_keys
+         WHERE key = ?
+           AND created_at >= strftime('%Y-%m-%dT%H:%M:%fZ', 'now', '-' || ? || ' seconds')",
+    )
+    .bind(key)
+    .bind(ttl_seconds as i64)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Record `key` as seen now, unless a row for it already exists.
+///
+/// Returns `false` when the key was already recorded, so concurrent callers
+/// racing on the same key see exactly one `true`. Expired rows still count:
+/// callers prune them with [`prune_expired_keys`] first so a stale key does
+/// not block a fresh call.
+pub async fn try_insert_key(
+    pool: &DbPool,
+    key: &str,
+    tool_name: &str,
+) -> Result<bool, StorageError> {
+    let result = sqlx::query(
+        "INSERT INTO mcp_idempotency_keys (key, tool_name, response, created_at)
+         VALUES (?, ?, NULL, strftime('%Y-%m-%dT%H:%M:%fZ', 'now'))
+         ON CONFLICT(key) DO NOTHING",
+    )
+    .bind(key)
+    .bind(tool_name)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.rows_affected() == 1)
+}
+
+/// Attach the result of the original call to `key`.
+pub async fn set_response(pool: &DbPool, key: &str, response: &str) -> Result<(), StorageError> {
+    sqlx::query("UPDATE mcp_idempotency_keys SET response = ? WHERE key = ?")
+        .bind(response)
+        .bind(key)
+        .execute(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Delete keys older than `ttl_seconds`. Returns the number of rows removed.
+pub async fn prune_expired_keys(pool: &DbPool, ttl_seconds: u64) -> Result<u64, StorageError> {
+    let result = sqlx::query(
+        "DELETE FROM mcp_idempotency_keys
+         WHERE created_at < strftime('%Y-%m-%dT%H:%M:%fZ', 'now', '-' || ? || ' seconds')",
+    )
+    .bind(ttl_seconds as i64)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn insert_find_and_set_response() {
+        let pool = init_test_db().await.expect("init db");
+
+        assert!(find_live_key(&pool, "k1", 30).await.unwrap().is_none());
+
+        assert!(try_insert_key(&pool, "k1", "post_tweet").await.unwrap());
+        let row = find_live_key(&pool, "k1", 30).await.unwrap().unwrap();
+        assert_eq!(row.tool_name, "post_tweet");
+        assert!(row.response.is_none());
+
+        set_response(&pool, "k1", r#"{"id":"tw1"}"#).await.unwrap();
+        let row = find_live_key(&pool, "k1", 30).await.unwrap().unwrap();
+        assert_eq!(row.response.as_deref(), Some(r#"{"id":"tw1"}"#));
+    }
+
+    #[tokio::test]
+    async fn expired_keys_are_ignored_and_pruned() {
+        let pool = init_test_db().await.expect("init db");
+        try_insert_key(&pool, "old", "like_tweet").await.unwrap();
+        sqlx::query(
+            "UPDATE mcp_idempotency_keys
+             SET created_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now', '-60 seconds')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+        try_insert_key(&pool, "new", "like_tweet").await.unwrap();
+
+        assert!(find_live_key(&pool, "old", 30).await.unwrap().is_none());
+        assert_eq!(prune_expired_keys(&pool, 30).await.unwrap(), 1);
+        assert!(find_live_key(&pool, "new", 30).await.unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn duplicate_insert_is_refused_and_keeps_response() {
+        let pool = init_test_db().await.expect("init db");
+        assert!(try_insert_key(&pool, "k", "retweet").await.unwrap());
+        set_response(&pool, "k", "{}").await.unwrap();
+        assert!(!try_insert_key(&pool, "k", "retweet").await.unwrap());
+
+        let row = find_live_key(&pool, "k", 30).await.unwrap().unwrap();
+        assert_eq!(row.response.as_deref(), Some("{}"));
+    }
+
+    #[tokio::test]
+    async fn expired_key_can_be_reinserted_after_prune() {
+        let pool = init_test_db().await.expect("init db");
+        try_insert_key(&pool, "k", "retweet").await.unwrap();
+        sqlx::query(
+            "UPDATE mcp_idempotency_keys
+             SET created_at = strftime('%Y-%m-%dT%H:%M:%fZ', 'now', '-60 seconds')",
+        )
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        prune_expired_keys(&pool, 30).await.unwrap();
+        assert!(try_insert_key(&pool, "k", "retweet").await.unwrap());
+    }
+}
@@ -0,0 +1,264 @@
+//! Change-log internals: starting a chain, writing and applying increments.
+
+use std::path::{Path, PathBuf};
+
+use sqlx::{Row, SqliteConnection};
+
+use super::helpers::{
+    backup_error, change_seq, file_checksum, file_name, now_iso, open_pool, quote_ident,
+    quote_literal, schema_version, tracked_tables, write_manifest,
+};
+use super::{enable_change_tracking, BackupKind, BackupManifest, IncrementalBackupResult};
+use super::{DbPool, DELETED_ROWS};
+use crate::error::StorageError;
+use crate::storage::backup::create_backup;
+
+pub(super) async fn start_chain(
+    pool: &DbPool,
+    backup_dir: &Path,
+    reason: Option<String>,
+) -> Result<IncrementalBackupResult, StorageError> {
+    // Triggers must exist before the snapshot so no change is missed.
+    enable_change_tracking(pool).await?;
+    let backup = create_backup(pool, backup_dir).await?;
+
+    let (to_seq, schema_version) = {
+        let snapshot = open_pool(&backup.path, true).await?;
+        let mut conn = snapshot
+            .acquire()
+            .await
+            .map_err(|e| StorageError::Connection { source: e })?;
+        let seq = change_seq(&mut conn).await?;
+        drop(conn);
+        let version = schema_version(&snapshot).await?;
+        snapshot.close().await;
+        (seq, version)
+    };
+
+    // Changes up to the base are captured by it; drop them from the log.
+    prune_change_log(pool, to_seq).await?;
+
+    let checksum = file_checksum(&backup.path)?;
+    let manifest = BackupManifest {
+        kind: BackupKind::Full,
+        file: file_name(&backup.path),
+        checksum: checksum.clone(),
+        base_file: file_name(&backup.path),
+        base_checksum: checksum,
+        schema_version,
+        from_seq: 0,
+        to_seq,
+        created_at: now_iso(),
+    };
+    write_manifest(&backup.path, &manifest)?;
+
+    Ok(IncrementalBackupResult {
+        backup,
+        manifest,
+        new_chain_reason: reason,
+    })
+}
+
+/// Write rows changed after `from_seq` into a new SQLite file at `inc_path`.
+///
+/// Returns the last change sequence number included.
+pub(super) async fn write_increment(
+    pool: &DbPool,
+    inc_path: &Path,
+    from_seq: i64,
+) -> Result<i64, StorageError> {
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+
+    sqlx::query("ATTACH DATABASE ? AS inc")
+        .bind(inc_path.to_string_lossy().into_owned())
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    let result = copy_changes(&mut conn, from_seq).await;
+
+    let _ = sqlx::query("DETACH DATABASE inc").execute(&mut *conn).await;
+    result
+}
+
+async fn copy_changes(conn: &mut SqliteConnection, from_seq: i64) -> Result<i64, StorageError> {
+    let q = |e: sqlx::Error| StorageError::Query { source: e };
+
+    sqlx::query("BEGIN").execute(&mut *conn).await.map_err(q)?;
+    let result = async {
+        let to_seq = change_seq(conn).await?;
+
+        sqlx::query(&format!(
+            "CREATE TABLE inc.{DELETED_ROWS} (table_name TEXT NOT NULL, row_id INTEGER NOT NULL)"
+        ))
+        .execute(&mut *conn)
+        .await
+        .map_err(q)?;
+
+        let tables: Vec<String> = sqlx::query_scalar(
+            "SELECT DISTINCT table_name FROM main.row_changes WHERE seq > ? AND seq <= ?",
+        )
+        .bind(from_seq)
+        .bind(to_seq)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(q)?;
+
+        for table in tables {
+            let ident = quote_ident(&table);
+            let changed = format!(
+                "SELECT row_id FROM main.row_changes \
+                 WHERE table_name = {} AND seq > {from_seq} AND seq <= {to_seq}",
+                quote_literal(&table)
+            );
+            sqlx::query(&format!(
+                "CREATE TABLE inc.{ident} AS \
+                 SELECT rowid AS __rowid, * FROM main.{ident} WHERE rowid IN ({changed})"
+            ))
+            .execute(&mut *conn)
+            .await
+            .map_err(q)?;
+            sqlx::query(&format!(
+                "INSERT INTO inc.{DELETED_ROWS} (table_name, row_id) \
+                 SELECT DISTINCT {}, row_id FROM ({changed}) \
+                 WHERE row_id NOT IN (SELECT rowid FROM main.{ident})",
+                quote_literal(&table)
+            ))
+            .execute(&mut *conn)
+            .await
+            .map_err(q)?;
+        }
+        Ok(to_seq)
+    }
+    .await;
+
+    match result {
+        Ok(seq) => {
+            sqlx::query("COMMIT").execute(&mut *conn).await.map_err(q)?;
+            Ok(seq)
+        }
+        Err(e) => {
+            let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+            Err(e)
+        }
+    }
+}
+
+/// Apply increments, in order, to the database file at `db_path`.
+pub(super) async fn apply_increments(
+    db_path: &Path,
+    increments: &[PathBuf],
+) -> Result<(), StorageError> {
+    let pool = open_pool(db_path, false).await?;
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+    let q = |e: sqlx::Error| StorageError::Query { source: e };
+
+    for inc in increments {
+        sqlx::query("ATTACH DATABASE ? AS inc")
+            .bind(inc.to_string_lossy().into_owned())
+            .execute(&mut *conn)
+            .await
+            .map_err(q)?;
+
+        let result = apply_one(&mut conn).await;
+        let _ = sqlx::query("DETACH DATABASE inc").execute(&mut *conn).await;
+        result?;
+    }
+
+    // The replayed writes went through the tracking triggers; they describe
+    // the restore itself, not new changes, so start the log fresh.
+    sqlx::query("DELETE FROM row_changes")
+        .execute(&mut *conn)
+        .await
+        .map_err(q)?;
+    sqlx::query("UPDATE sqlite_sequence SET seq = 0 WHERE name = 'row_changes'")
+        .execute(&mut *conn)
+        .await
+        .map_err(q)?;
+
+    drop(conn);
+    pool.close().await;
+    Ok(())
+}
+
+async fn apply_one(conn: &mut SqliteConnection) -> Result<(), StorageError> {
+    let q = |e: sqlx::Error| StorageError::Query { source: e };
+    sqlx::query("BEGIN").execute(&mut *conn).await.map_err(q)?;
+
+    let result = async {
+        let deleted_tables: Vec<String> = sqlx::query_scalar(&format!(
+            "SELECT DISTINCT table_name FROM inc.{DELETED_ROWS}"
+        ))
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(q)?;
+        for table in deleted_tables {
+            sqlx::query(&format!(
+                "DELETE FROM main.{} WHERE rowid IN \
+                 (SELECT row_id FROM inc.{DELETED_ROWS} WHERE table_name = {})",
+                quote_ident(&table),
+                quote_literal(&table)
+            ))
+            .execute(&mut *conn)
+            .await
+            .map_err(q)?;
+        }
+
+        for table in tracked_tables(conn, "inc").await? {
+            if table == DELETED_ROWS {
+                continue;
+            }
+            let columns: Vec<String> =
+                sqlx::query(&format!("PRAGMA main.table_info({})", quote_ident(&table)))
+                    .fetch_all(&mut *conn)
+                    .await
+                    .map_err(q)?
+                    .iter()
+                    .map(|r| quote_ident(&r.get::<String, _>("name")))
+                    .collect();
+            if columns.is_empty() {
+                return Err(backup_error(format!(
+                    "increment contains table {table} which does not exist in the base"
+                )));
+            }
+            let cols = columns.join(", ");
+            let ident = quote_ident(&table);
+            sqlx::query(&format!(
+                "INSERT OR REPLACE INTO main.{ident} (rowid, {cols}) \
+                 SELECT __rowid, {cols} FROM inc.{ident}"
+            ))
+            .execute(&mut *conn)
+            .await
+            .map_err(q)?;
+        }
+        Ok(())
+    }
+    .await;
+
+    match result {
+        Ok(()) => {
+            sqlx::query("COMMIT").execute(&mut *conn).await.map_err(q)?;
+            Ok(())
+        }
+        Err(e) => {
+            let _ = sqlx::query("ROLLBACK").execute(&mut *conn).await;
+            Err(e)
+        }
+    }
+}
+
+/// Drop logged changes up to `to_seq` once a backup holds them.
+pub(super) async fn prune_change_log(pool: &DbPool, to_seq: i64) -> Result<(), StorageError> {
+    sqlx::query("DELETE FROM row_changes WHERE seq <= ?")
+        .bind(to_seq)
+        .execute(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
@@ -0,0 +1,138 @@
+//! Manifest, schema, and SQL quoting helpers for incremental backups.
+
+use std::path::Path;
+
+use chrono::Utc;
+use sha2::{Digest, Sha256};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePoolOptions};
+use sqlx::SqliteConnection;
+
+use super::{manifest_path, BackupKind, BackupManifest};
+use crate::error::StorageError;
+use crate::storage::DbPool;
+
+/// Newest backup of the newest chain in `backup_dir`.
+pub(super) fn chain_head(backup_dir: &Path) -> Result<Option<BackupManifest>, StorageError> {
+    let manifests = read_manifests(backup_dir);
+    let Some(base) = manifests
+        .iter()
+        .filter(|m| m.kind == BackupKind::Full)
+        .max_by(|a, b| a.created_at.cmp(&b.created_at))
+    else {
+        return Ok(None);
+    };
+    Ok(manifests
+        .iter()
+        .filter(|m| m.base_checksum == base.checksum)
+        .max_by_key(|m| m.to_seq)
+        .cloned())
+}
+
+pub(super) fn read_manifests(dir: &Path) -> Vec<BackupManifest> {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|n| n.ends_with(".manifest.json"))
+        })
+        .filter_map(|p| {
+            let contents = std::fs::read_to_string(&p).ok()?;
+            match serde_json::from_str(&contents) {
+                Ok(m) => Some(m),
+                Err(e) => {
+                    tracing::warn!(path = %p.display(), error = %e, "Skipping unreadable backup manifest");
+                    None
+                }
+            }
+        })
+        .collect()
+}
+
+pub(super) fn write_manifest(
+    backup_path: &Path,
+    manifest: &BackupManifest,
+) -> Result<(), StorageError> {
+    let json = serde_json::to_string_pretty(manifest)
+        .map_err(|e| backup_error(format!("failed to serialize manifest: {e}")))?;
+    std::fs::write(manifest_path(backup_path), json)
+        .map_err(|e| backup_error(format!("failed to write manifest: {e}")))
+}
+
+pub(super) async fn tracked_tables(
+    conn: &mut SqliteConnection,
+    schema: &str,
+) -> Result<Vec<String>, StorageError> {
+    sqlx::query_scalar(&format!(
+        "SELECT name FROM {schema}.sqlite_master WHERE type = 'table' \
+         AND name NOT LIKE 'sqlite_%' \
+         AND name NOT IN ('_sqlx_migrations', 'row_changes') \
+         ORDER BY name"
+    ))
+    .fetch_all(conn)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Highest change sequence number ever assigned (survives log pruning).
+pub(super) async fn change_seq(conn: &mut SqliteConnection) -> Result<i64, StorageError> {
+    sqlx::query_scalar(
+        "SELECT COALESCE((SELECT seq FROM sqlite_sequence WHERE name = 'row_changes'), 0)",
+    )
+    .fetch_one(conn)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+pub(super) async fn schema_version(pool: &DbPool) -> Result<i64, StorageError> {
+    sqlx::query_scalar("SELECT COALESCE(MAX(version), 0) FROM _sqlx_migrations")
+        .fetch_one(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })
+}
+
+pub(super) async fn open_pool(path: &Path, read_only: bool) -> Result<DbPool, StorageError> {
+    let options = SqliteConnectOptions::new()
+        .filename(path)
+        .read_only(read_only)
+        .foreign_keys(false);
+    SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await
+        .map_err(|e| StorageError::Connection { source: e })
+}
+
+pub(super) fn file_checksum(path: &Path) -> Result<String, StorageError> {
+    let bytes = std::fs::read(path)
+        .map_err(|e| backup_error(format!("failed to read {}: {e}", path.display())))?;
+    Ok(hex::encode(Sha256::digest(&bytes)))
+}
+
+pub(super) fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+pub(super) fn now_iso() -> String {
+    Utc::now().format("%Y-%m-%dT%H:%M:%S%.3fZ").to_string()
+}
+
+pub(super) fn quote_ident(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+pub(super) fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+pub(super) fn backup_error(msg: String) -> StorageError {
+    StorageError::Connection {
+        source: sqlx::Error::Configuration(msg.into()),
+    }
+}
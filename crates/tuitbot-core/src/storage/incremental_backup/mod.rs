@@ -0,0 +1,324 @@
+//! Incremental backups layered on a full base backup.
+//!
+//! A chain starts with a full `VACUUM INTO` backup. From that point on,
+//! triggers record every insert, update, and delete in `row_changes`, and
+//! each incremental backup is a small SQLite file holding only the rows that
+//! changed since the previous backup in the chain, plus the row ids that were
+//! deleted. Every backup in a chain has a JSON manifest sidecar
+//! (`<backup>.manifest.json`) recording its checksum and the checksum of the
+//! base it builds on, so an increment is never applied to a different base.
+//! Once a backup is written, the changes it covers are pruned from
+//! `row_changes`, so the log only holds what the next increment needs.
+
+mod changes;
+mod helpers;
+#[cfg(test)]
+mod tests;
+
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use super::backup::{restore_from_backup, BackupResult};
+use super::DbPool;
+use crate::error::StorageError;
+use changes::{apply_increments, prune_change_log, start_chain, write_increment};
+use helpers::{
+    backup_error, chain_head, change_seq, file_checksum, file_name, now_iso, quote_ident,
+    quote_literal, read_manifests, schema_version, tracked_tables, write_manifest,
+};
+
+/// Table inside an increment file listing rows deleted since the parent backup.
+const DELETED_ROWS: &str = "__deleted_rows";
+
+/// Whether a backup is a full base or an increment on top of one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackupKind {
+    Full,
+    Incremental,
+}
+
+/// Manifest written next to every backup in an incremental chain.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub kind: BackupKind,
+    /// File name of the backup this manifest describes.
+    pub file: String,
+    /// SHA-256 of the backup file.
+    pub checksum: String,
+    /// File name of the full backup the chain starts from.
+    pub base_file: String,
+    /// SHA-256 of the base backup file.
+    pub base_checksum: String,
+    /// Latest applied migration version when the backup was taken.
+    pub schema_version: i64,
+    /// Changes with a sequence number above this are included (0 for a base).
+    pub from_seq: i64,
+    /// Last change sequence number reflected in this backup.
+    pub to_seq: i64,
+    /// ISO-8601 UTC timestamp when the backup was taken.
+    pub created_at: String,
+}
+
+/// Result of `create_incremental_backup`.
+#[derive(Debug, Clone)]
+pub struct IncrementalBackupResult {
+    pub backup: BackupResult,
+    pub manifest: BackupManifest,
+    /// Why a new full base was taken instead of an increment, if it was.
+    pub new_chain_reason: Option<String>,
+}
+
+/// Path of the manifest sidecar for a backup file.
+pub fn manifest_path(backup_path: &Path) -> PathBuf {
+    let name = backup_path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    backup_path.with_file_name(format!("{name}.manifest.json"))
+}
+
+/// Read the manifest for a backup file, if it has one.
+pub fn read_manifest(backup_path: &Path) -> Result<Option<BackupManifest>, StorageError> {
+    let path = manifest_path(backup_path);
+    let contents = match std::fs::read_to_string(&path) {
+        Ok(c) => c,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(backup_error(format!(
+                "failed to read manifest {}: {e}",
+                path.display()
+            )))
+        }
+    };
+    serde_json::from_str(&contents)
+        .map(Some)
+        .map_err(|e| backup_error(format!("invalid manifest {}: {e}", path.display())))
+}
+
+/// Install change-tracking triggers on every user table (idempotent).
+pub async fn enable_change_tracking(pool: &DbPool) -> Result<(), StorageError> {
+    let mut conn = pool
+        .acquire()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+    for table in tracked_tables(&mut conn, "main").await? {
+        let ident = quote_ident(&table);
+        let lit = quote_literal(&table);
+        let triggers = [
+            format!(
+                "CREATE TRIGGER IF NOT EXISTS {} AFTER INSERT ON {ident} BEGIN \
+                 INSERT INTO row_changes (table_name, row_id, op) VALUES ({lit}, NEW.rowid, 'upsert'); \
+                 END",
+                quote_ident(&format!("row_changes_{table}_ins"))
+            ),
+            format!(
+                "CREATE TRIGGER IF NOT EXISTS {} AFTER UPDATE ON {ident} BEGIN \
+                 INSERT INTO row_changes (table_name, row_id, op) \
+                 SELECT {lit}, OLD.rowid, 'delete' WHERE OLD.rowid IS NOT NEW.rowid; \
+                 INSERT INTO row_changes (table_name, row_id, op) VALUES ({lit}, NEW.rowid, 'upsert'); \
+                 END",
+                quote_ident(&format!("row_changes_{table}_upd"))
+            ),
+            format!(
+                "CREATE TRIGGER IF NOT EXISTS {} AFTER DELETE ON {ident} BEGIN \
+                 INSERT INTO row_changes (table_name, row_id, op) VALUES ({lit}, OLD.rowid, 'delete'); \
+                 END",
+                quote_ident(&format!("row_changes_{table}_del"))
+            ),
+        ];
+        for sql in &triggers {
+            sqlx::query(sql)
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| StorageError::Query { source: e })?;
+        }
+    }
+    Ok(())
+}
+
+/// Take an incremental backup into `backup_dir`.
+///
+/// Builds on the latest backup in the directory's newest chain. Starts a new
+/// chain with a full base backup when there is none, when the schema has been
+/// migrated since the base, or when the change log is behind the chain (e.g.
+/// after a restore).
+pub async fn create_incremental_backup(
+    pool: &DbPool,
+    backup_dir: &Path,
+) -> Result<IncrementalBackupResult, StorageError> {
+    let Some(head) = chain_head(backup_dir)? else {
+        return start_chain(pool, backup_dir, None).await;
+    };
+
+    let base_path = backup_dir.join(&head.base_file);
+    if !base_path.exists() {
+        return start_chain(
+            pool,
+            backup_dir,
+            Some(format!("base backup {} is missing", head.base_file)),
+        )
+        .await;
+    }
+    if file_checksum(&base_path)? != head.base_checksum {
+        return Err(backup_error(format!(
+            "base backup {} does not match its manifest checksum; \
+             refusing to extend the chain",
+            head.base_file
+        )));
+    }
+    if schema_version(pool).await? != head.schema_version {
+        return start_chain(
+            pool,
+            backup_dir,
+            Some("database schema changed since the base backup".to_string()),
+        )
+        .await;
+    }
+    let current_seq = {
+        let mut conn = pool
+            .acquire()
+            .await
+            .map_err(|e| StorageError::Connection { source: e })?;
+        change_seq(&mut conn).await?
+    };
+    if current_seq < head.to_seq {
+        return start_chain(
+            pool,
+            backup_dir,
+            Some("change log is behind the last backup (database restored?)".to_string()),
+        )
+        .await;
+    }
+
+    enable_change_tracking(pool).await?;
+
+    let start = std::time::Instant::now();
+    let timestamp = Utc::now().format("%Y%m%d_%H%M%S_%3f");
+    let inc_path = backup_dir.join(format!("tuitbot_{timestamp}.inc.db"));
+    let to_seq = write_increment(pool, &inc_path, head.to_seq).await?;
+
+    let manifest = BackupManifest {
+        kind: BackupKind::Incremental,
+        file: file_name(&inc_path),
+        checksum: file_checksum(&inc_path)?,
+        base_file: head.base_file.clone(),
+        base_checksum: head.base_checksum.clone(),
+        schema_version: head.schema_version,
+        from_seq: head.to_seq,
+        to_seq,
+        created_at: now_iso(),
+    };
+    write_manifest(&inc_path, &manifest)?;
+    prune_change_log(pool, to_seq).await?;
+
+    let size_bytes = std::fs::metadata(&inc_path).map(|m| m.len()).unwrap_or(0);
+    Ok(IncrementalBackupResult {
+        backup: BackupResult {
+            path: inc_path,
+            size_bytes,
+            duration_ms: start.elapsed().as_millis() as u64,
+        },
+        manifest,
+        new_chain_reason: None,
+    })
+}
+
+/// Resolve the files needed to restore `backup_path`: the base first, then
+/// every increment up to and including `backup_path`, in order.
+///
+/// Fails if the base checksum does not match, an increment is missing, or an
+/// increment file has been modified.
+pub fn resolve_chain(backup_path: &Path) -> Result<Vec<PathBuf>, StorageError> {
+    let manifest = read_manifest(backup_path)?
+        .ok_or_else(|| backup_error(format!("no manifest found for {}", backup_path.display())))?;
+    let dir = backup_path.parent().unwrap_or_else(|| Path::new("."));
+
+    let base_path = dir.join(&manifest.base_file);
+    if !base_path.exists() {
+        return Err(backup_error(format!(
+            "base backup {} not found next to {}",
+            manifest.base_file,
+            backup_path.display()
+        )));
+    }
+    if file_checksum(&base_path)? != manifest.base_checksum {
+        return Err(backup_error(format!(
+            "base backup {} does not match the checksum in the manifest; \
+             this increment was taken against a different base",
+            manifest.base_file
+        )));
+    }
+    if manifest.kind == BackupKind::Full {
+        return Ok(vec![base_path]);
+    }
+
+    let base_manifest = read_manifest(&base_path)?.ok_or_else(|| {
+        backup_error(format!("no manifest found for base {}", manifest.base_file))
+    })?;
+
+    let mut increments: Vec<BackupManifest> = read_manifests(dir)
+        .into_iter()
+        .filter(|m| {
+            m.kind == BackupKind::Incremental
+                && m.base_checksum == manifest.base_checksum
+                && m.to_seq <= manifest.to_seq
+        })
+        .collect();
+    increments.sort_by_key(|m| m.from_seq);
+
+    let mut chain = vec![base_path];
+    let mut expected_from = base_manifest.to_seq;
+    for inc in increments {
+        if inc.from_seq != expected_from {
+            continue;
+        }
+        let path = dir.join(&inc.file);
+        if file_checksum(&path)? != inc.checksum {
+            return Err(backup_error(format!(
+                "increment {} does not match its manifest checksum",
+                inc.file
+            )));
+        }
+        expected_from = inc.to_seq;
+        chain.push(path);
+    }
+    if expected_from != manifest.to_seq {
+        return Err(backup_error(format!(
+            "incremental chain is incomplete: no increment covers changes after #{expected_from}"
+        )));
+    }
+    Ok(chain)
+}
+
+/// Restore an incremental chain ending at `backup_path` to `target_path`.
+///
+/// Applies the base plus each increment in order to a temporary copy, then
+/// swaps it in via `restore_from_backup` (which validates it and keeps a
+/// safety copy of the current database).
+pub async fn restore_incremental(
+    backup_path: &Path,
+    target_path: &Path,
+) -> Result<(), StorageError> {
+    let chain = resolve_chain(backup_path)?;
+    let parent = target_path.parent().unwrap_or_else(|| Path::new("."));
+    let temp_path = parent.join(format!(
+        ".tuitbot_incremental_{}.db",
+        Utc::now().timestamp_millis()
+    ));
+
+    let result = async {
+        std::fs::copy(&chain[0], &temp_path)
+            .map_err(|e| backup_error(format!("failed to copy base backup: {e}")))?;
+        apply_increments(&temp_path, &chain[1..]).await?;
+        restore_from_backup(&temp_path, target_path).await
+    }
+    .await;
+
+    let _ = std::fs::remove_file(&temp_path);
+    let _ = std::fs::remove_file(temp_path.with_extension("db-wal"));
+    let _ = std::fs::remove_file(temp_path.with_extension("db-shm"));
+    result
+}
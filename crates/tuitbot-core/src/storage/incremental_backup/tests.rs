@@ -0,0 +1,177 @@
+use super::*;
+use crate::storage::backup::create_backup;
+use crate::storage::init_db;
+
+async fn file_test_db(dir: &Path) -> (DbPool, PathBuf) {
+    let db_path = dir.join("live.db");
+    let pool = init_db(&db_path.to_string_lossy())
+        .await
+        .expect("init file db");
+    (pool, db_path)
+}
+
+async fn log_action(pool: &DbPool, message: &str) {
+    sqlx::query(
+        "INSERT INTO action_log (action_type, status, message) VALUES ('test', 'success', ?)",
+    )
+    .bind(message)
+    .execute(pool)
+    .await
+    .expect("insert");
+}
+
+/// Count rows that differ between `main` and the attached `restored` db.
+async fn diff_rows(pool: &DbPool, restored: &Path) -> i64 {
+    let mut conn = pool.acquire().await.unwrap();
+    sqlx::query("ATTACH DATABASE ? AS restored")
+        .bind(restored.to_string_lossy().into_owned())
+        .execute(&mut *conn)
+        .await
+        .unwrap();
+    let mut diff = 0;
+    for table in tracked_tables(&mut conn, "main").await.unwrap() {
+        let t = quote_ident(&table);
+        let n: i64 = sqlx::query_scalar(&format!(
+            "SELECT (SELECT COUNT(*) FROM (SELECT * FROM main.{t} EXCEPT SELECT * FROM restored.{t})) \
+                  + (SELECT COUNT(*) FROM (SELECT * FROM restored.{t} EXCEPT SELECT * FROM main.{t}))"
+        ))
+        .fetch_one(&mut *conn)
+        .await
+        .unwrap();
+        diff += n;
+    }
+    sqlx::query("DETACH DATABASE restored")
+        .execute(&mut *conn)
+        .await
+        .unwrap();
+    diff
+}
+
+#[tokio::test]
+async fn full_then_incremental_roundtrip() {
+    let dir = tempfile::tempdir().unwrap();
+    let (pool, _) = file_test_db(dir.path()).await;
+    let backup_dir = dir.path().join("backups");
+
+    log_action(&pool, "before base").await;
+    log_action(&pool, "to be edited").await;
+    log_action(&pool, "to be deleted").await;
+
+    let base = create_incremental_backup(&pool, &backup_dir).await.unwrap();
+    assert_eq!(base.manifest.kind, BackupKind::Full);
+    assert!(base.new_chain_reason.is_none());
+
+    // Mutate: insert, update, delete.
+    log_action(&pool, "after base").await;
+    sqlx::query("UPDATE action_log SET status = 'edited' WHERE message = 'to be edited'")
+        .execute(&pool)
+        .await
+        .unwrap();
+    sqlx::query("DELETE FROM action_log WHERE message = 'to be deleted'")
+        .execute(&pool)
+        .await
+        .unwrap();
+
+    let inc1 = create_incremental_backup(&pool, &backup_dir).await.unwrap();
+    assert_eq!(inc1.manifest.kind, BackupKind::Incremental);
+    assert_eq!(inc1.manifest.from_seq, base.manifest.to_seq);
+    assert_eq!(inc1.manifest.base_checksum, base.manifest.checksum);
+    assert!(inc1.backup.size_bytes < base.backup.size_bytes);
+
+    sqlx::query("INSERT INTO cursors (key, value) VALUES ('since_id', '42')")
+        .execute(&pool)
+        .await
+        .unwrap();
+    let inc2 = create_incremental_backup(&pool, &backup_dir).await.unwrap();
+    assert_eq!(inc2.manifest.from_seq, inc1.manifest.to_seq);
+
+    let chain = resolve_chain(&inc2.backup.path).unwrap();
+    assert_eq!(chain.len(), 3);
+
+    let target = dir.path().join("restored.db");
+    restore_incremental(&inc2.backup.path, &target)
+        .await
+        .unwrap();
+
+    assert_eq!(diff_rows(&pool, &target).await, 0);
+    pool.close().await;
+}
+
+#[tokio::test]
+async fn increment_rejects_mismatched_base() {
+    let dir = tempfile::tempdir().unwrap();
+    let (pool, _) = file_test_db(dir.path()).await;
+    let backup_dir = dir.path().join("backups");
+
+    let base = create_incremental_backup(&pool, &backup_dir).await.unwrap();
+    log_action(&pool, "after base").await;
+    let inc = create_incremental_backup(&pool, &backup_dir).await.unwrap();
+
+    // Swap the base file for a different database.
+    log_action(&pool, "diverged").await;
+    let other = create_backup(&pool, &dir.path().join("other"))
+        .await
+        .unwrap();
+    std::fs::copy(&other.path, &base.backup.path).unwrap();
+
+    let err = resolve_chain(&inc.backup.path).unwrap_err().to_string();
+    assert!(err.contains("different base"), "{err}");
+    let target = dir.path().join("restored.db");
+    assert!(restore_incremental(&inc.backup.path, &target)
+        .await
+        .is_err());
+    assert!(!target.exists());
+    pool.close().await;
+}
+
+#[tokio::test]
+async fn missing_increment_breaks_chain() {
+    let dir = tempfile::tempdir().unwrap();
+    let (pool, _) = file_test_db(dir.path()).await;
+    let backup_dir = dir.path().join("backups");
+
+    create_incremental_backup(&pool, &backup_dir).await.unwrap();
+    log_action(&pool, "one").await;
+    let inc1 = create_incremental_backup(&pool, &backup_dir).await.unwrap();
+    log_action(&pool, "two").await;
+    let inc2 = create_incremental_backup(&pool, &backup_dir).await.unwrap();
+
+    std::fs::remove_file(manifest_path(&inc1.backup.path)).unwrap();
+    let err = resolve_chain(&inc2.backup.path).unwrap_err().to_string();
+    assert!(err.contains("incomplete"), "{err}");
+    pool.close().await;
+}
+
+#[tokio::test]
+async fn backups_prune_the_changes_they_cover() {
+    let dir = tempfile::tempdir().unwrap();
+    let (pool, _) = file_test_db(dir.path()).await;
+    let backup_dir = dir.path().join("backups");
+    let logged = |pool: DbPool| async move {
+        sqlx::query_scalar::<_, i64>("SELECT COUNT(*) FROM row_changes")
+            .fetch_one(&pool)
+            .await
+            .unwrap()
+    };
+
+    create_incremental_backup(&pool, &backup_dir).await.unwrap();
+    log_action(&pool, "one").await;
+    log_action(&pool, "two").await;
+    assert_eq!(logged(pool.clone()).await, 2);
+
+    let inc1 = create_incremental_backup(&pool, &backup_dir).await.unwrap();
+    assert_eq!(logged(pool.clone()).await, 0);
+
+    // The next increment still picks up only what changed since.
+    log_action(&pool, "three").await;
+    let inc2 = create_incremental_backup(&pool, &backup_dir).await.unwrap();
+    assert_eq!(inc2.manifest.from_seq, inc1.manifest.to_seq);
+    assert_eq!(logged(pool.clone()).await, 0);
+
+    let target = dir.path().join("restored.db");
+    restore_incremental(&inc2.backup.path, &target)
+        .await
+        .unwrap();
+    assert_eq!(diff_rows(&pool, &target).await, 0);
+    pool.close().await;
+}
@@ -0,0 +1,120 @@
+//! Persisted per-loop error budgets.
+//!
+//! One row per account and loop name. The transition rules live in
+//! `automation::loop_breaker`; this module only loads and stores state so
+//! it survives across `tuitbot tick` invocations.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Error-budget state for one loop.
+#[derive(Debug, Clone, Default, PartialEq, Eq, sqlx::FromRow, serde::Serialize)]
+pub struct LoopBreakerRow {
+    pub account_id: String,
+    pub loop_name: String,
+    /// Failures since the last success within the current window.
+    pub consecutive_failures: i64,
+    /// RFC 3339 time of the first failure in the current window.
+    pub window_started_at: Option<String>,
+    /// RFC 3339 time until which the loop is paused.
+    pub open_until: Option<String>,
+    /// Times the breaker has opened since the last success.
+    pub trips: i64,
+    pub last_error: Option<String>,
+    pub updated_at: String,
+}
+
+/// Load the state of `loop_name` for a specific account, if any was recorded.
+pub async fn get_loop_breaker_for(
+    pool: &DbPool,
+    account_id: &str,
+    loop_name: &str,
+) -> Result<Option<LoopBreakerRow>, StorageError> {
+    sqlx::query_as::<_, LoopBreakerRow>(
+        "SELECT * FROM loop_breakers WHERE account_id = ? AND loop_name = ?",
+    )
+    .bind(account_id)
+    .bind(loop_name)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Load the state of `loop_name`, if any was recorded.
+pub async fn get_loop_breaker(
+    pool: &DbPool,
+    loop_name: &str,
+) -> Result<Option<LoopBreakerRow>, StorageError> {
+    get_loop_breaker_for(pool, DEFAULT_ACCOUNT_ID, loop_name).await
+}
+
+/// Insert or replace the state of `row.loop_name` for `row.account_id`.
+pub async fn save_loop_breaker(pool: &DbPool, row: &LoopBreakerRow) -> Result<(), StorageError> {
+    sqlx::query(
+        "INSERT INTO loop_breakers \
+         (account_id, loop_name, consecutive_failures, window_started_at, open_until, \
+          trips, last_error, updated_at) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, datetime('now')) \
+         ON CONFLICT(account_id, loop_name) DO UPDATE SET \
+         consecutive_failures = excluded.consecutive_failures, \
+         window_started_at = excluded.window_started_at, \
+         open_until = excluded.open_until, \
+         trips = excluded.trips, \
+         last_error = excluded.last_error, \
+         updated_at = excluded.updated_at",
+    )
+    .bind(&row.account_id)
+    .bind(&row.loop_name)
+    .bind(row.consecutive_failures)
+    .bind(&row.window_started_at)
+    .bind(&row.open_until)
+    .bind(row.trips)
+    .bind(&row.last_error)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Every recorded loop state across all accounts, ordered by account and loop.
+pub async fn list_loop_breakers(pool: &DbPool) -> Result<Vec<LoopBreakerRow>, StorageError> {
+    sqlx::query_as::<_, LoopBreakerRow>(
+        "SELECT * FROM loop_breakers ORDER BY account_id, loop_name",
+    )
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    #[tokio::test]
+    async fn save_then_load_round_trips_and_upserts() {
+        let pool = init_test_db().await.expect("init db");
+        assert!(get_loop_breaker(&pool, "discovery")
+            .await
+            .unwrap()
+            .is_none());
+
+        let mut row = LoopBreakerRow {
+            account_id: DEFAULT_ACCOUNT_ID.to_string(),
+            loop_name: "discovery".to_string(),
+            consecutive_failures: 2,
+            window_started_at: Some("2026-03-29T10:00:00+00:00".to_string()),
+            last_error: Some("search failed".to_string()),
+            ..Default::default()
+        };
+        save_loop_breaker(&pool, &row).await.unwrap();
+        row.consecutive_failures = 3;
+        save_loop_breaker(&pool, &row).await.unwrap();
+
+        let loaded = get_loop_breaker(&pool, "discovery").await.unwrap().unwrap();
+        assert_eq!(loaded.consecutive_failures, 3);
+        assert_eq!(loaded.last_error.as_deref(), Some("search failed"));
+        assert_eq!(list_loop_breakers(&pool).await.unwrap().len(), 1);
+    }
+}
@@ -227,6 +227,85 @@ pub async fn get_error_breakdown(
     get_error_breakdown_for(pool, DEFAULT_ACCOUNT_ID, since).await
 }
 
+/// Failures sharing one signature (tool + error code).
+#[derive(Debug, Clone, sqlx::FromRow, Serialize)]
+pub struct ErrorGroup {
+    pub tool_name: String,
+    pub error_code: String,
+    pub count: i64,
+    pub first_seen: String,
+    pub last_seen: String,
+    /// Most recent `error_message` recorded in the group's metadata, if any.
+    pub sample_message: Option<String>,
+}
+
+/// One page of error groups plus the total number of groups.
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorGroupPage {
+    pub groups: Vec<ErrorGroup>,
+    pub total: i64,
+}
+
+/// Get failures since a timestamp grouped by signature for a specific
+/// account, most frequent first.
+pub async fn get_error_groups_for(
+    pool: &DbPool,
+    account_id: &str,
+    since: &str,
+    limit: u32,
+    offset: u32,
+) -> Result<ErrorGroupPage, StorageError> {
+    let groups = sqlx::query_as::<_, ErrorGroup>(
+        "SELECT t.tool_name, COALESCE(t.error_code, 'unknown') AS error_code, \
+         COUNT(*) AS count, MIN(t.created_at) AS first_seen, \
+         MAX(t.created_at) AS last_seen, \
+         (SELECT json_extract(m.metadata, '$.error_message') \
+          FROM mcp_telemetry m \
+          WHERE m.account_id = t.account_id AND m.tool_name = t.tool_name \
+          AND m.error_code IS t.error_code AND m.success = 0 \
+          AND m.created_at >= ? \
+          AND CASE WHEN json_valid(m.metadata) \
+              THEN json_extract(m.metadata, '$.error_message') END IS NOT NULL \
+          ORDER BY m.created_at DESC, m.id DESC LIMIT 1) AS sample_message \
+         FROM mcp_telemetry t \
+         WHERE t.created_at >= ? AND t.success = 0 AND t.account_id = ? \
+         GROUP BY t.tool_name, t.error_code \
+         ORDER BY count DESC, last_seen DESC \
+         LIMIT ? OFFSET ?",
+    )
+    .bind(since)
+    .bind(since)
+    .bind(account_id)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let (total,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM (SELECT 1 FROM mcp_telemetry \
+         WHERE created_at >= ? AND success = 0 AND account_id = ? \
+         GROUP BY tool_name, error_code)",
+    )
+    .bind(since)
+    .bind(account_id)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(ErrorGroupPage { groups, total })
+}
+
+/// Get failures since a timestamp grouped by signature, most frequent first.
+pub async fn get_error_groups(
+    pool: &DbPool,
+    since: &str,
+    limit: u32,
+    offset: u32,
+) -> Result<ErrorGroupPage, StorageError> {
+    get_error_groups_for(pool, DEFAULT_ACCOUNT_ID, since, limit, offset).await
+}
+
 /// Summary statistics across all tools.
 #[derive(Debug, Clone, Serialize)]
 pub struct TelemetrySummary {
@@ -326,6 +405,40 @@ pub async fn get_recent_entries(
     get_recent_entries_for(pool, DEFAULT_ACCOUNT_ID, limit).await
 }
 
+/// All-time invocation count for one tool, outcome, and error code.
+#[derive(Debug, Clone, sqlx::FromRow, Serialize)]
+pub struct OutcomeCount {
+    pub tool_name: String,
+    pub category: String,
+    pub success: bool,
+    /// Error code of failed calls; `None` for successes and uncoded failures.
+    pub error_code: Option<String>,
+    pub count: i64,
+}
+
+/// Get all-time invocation counts grouped by tool, outcome, and error code
+/// for a specific account. A single aggregate query, suitable for scraping.
+pub async fn get_outcome_counts_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Vec<OutcomeCount>, StorageError> {
+    sqlx::query_as::<_, OutcomeCount>(
+        "SELECT tool_name, category, success, error_code, COUNT(*) as count \
+         FROM mcp_telemetry WHERE account_id = ? \
+         GROUP BY tool_name, category, success, error_code \
+         ORDER BY tool_name, success DESC, error_code",
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Get all-time invocation counts grouped by tool, outcome, and error code.
+pub async fn get_outcome_counts(pool: &DbPool) -> Result<Vec<OutcomeCount>, StorageError> {
+    get_outcome_counts_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
 /// Compute a percentile from sorted latency values.
 fn percentile(sorted: &[(i64,)], pct: u32) -> i64 {
     if sorted.is_empty() {
@@ -366,6 +479,24 @@ mod tests {
         .expect("log telemetry");
     }
 
+    async fn log_failure(pool: &DbPool, tool: &str, code: &str, message: Option<&str>) {
+        let metadata = message.map(|m| serde_json::json!({ "error_message": m }).to_string());
+        log_telemetry(
+            pool,
+            &TelemetryParams {
+                tool_name: tool,
+                category: "mutation",
+                latency_ms: 10,
+                success: false,
+                error_code: Some(code),
+                policy_decision: None,
+                metadata: metadata.as_deref(),
+            },
+        )
+        .await
+        .expect("log telemetry");
+    }
+
     #[tokio::test]
     async fn log_and_retrieve_telemetry() {
         let pool = init_test_db().await.expect("init db");
@@ -475,6 +606,84 @@ mod tests {
         assert_eq!(summary.overall_success_rate, 0.0);
     }
 
+    #[tokio::test]
+    async fn error_groups_key_on_tool_and_code_with_sample_and_paging() {
+        let pool = init_test_db().await.expect("init db");
+
+        log_failure(&pool, "x_post_tweet", "x_rate_limited", Some("429 from X")).await;
+        log_failure(&pool, "x_post_tweet", "x_rate_limited", None).await;
+        log_failure(&pool, "x_post_tweet", "x_rate_limited", Some("429 again")).await;
+        log_failure(&pool, "x_post_tweet", "db_error", Some("disk full")).await;
+        log_failure(&pool, "x_reply_to_tweet", "x_rate_limited", None).await;
+        log_failure(&pool, "x_reply_to_tweet", "x_rate_limited", None).await;
+        log(&pool, "x_post_tweet", "mutation", 10, true, None, None).await;
+
+        let page = get_error_groups(&pool, "2000-01-01T00:00:00Z", 10, 0)
+            .await
+            .expect("groups");
+        assert_eq!(page.total, 3);
+        assert_eq!(page.groups.len(), 3);
+
+        let first = &page.groups[0];
+        assert_eq!(
+            (first.tool_name.as_str(), first.error_code.as_str()),
+            ("x_post_tweet", "x_rate_limited")
+        );
+        assert_eq!(first.count, 3);
+        assert!(first.first_seen <= first.last_seen);
+        assert_eq!(first.sample_message.as_deref(), Some("429 again"));
+
+        let second = &page.groups[1];
+        assert_eq!(second.tool_name, "x_reply_to_tweet");
+        assert_eq!(second.count, 2);
+        assert_eq!(second.sample_message, None);
+
+        let third = &page.groups[2];
+        assert_eq!(third.error_code, "db_error");
+        assert_eq!(third.count, 1);
+        assert_eq!(third.sample_message.as_deref(), Some("disk full"));
+
+        let paged = get_error_groups(&pool, "2000-01-01T00:00:00Z", 1, 1)
+            .await
+            .expect("page 2");
+        assert_eq!(paged.total, 3);
+        assert_eq!(paged.groups.len(), 1);
+        assert_eq!(paged.groups[0].tool_name, "x_reply_to_tweet");
+    }
+
+    #[tokio::test]
+    async fn outcome_counts_group_by_tool_and_error() {
+        let pool = init_test_db().await.expect("init db");
+
+        log(&pool, "x_post_tweet", "mutation", 10, true, None, None).await;
+        log(&pool, "x_post_tweet", "mutation", 12, true, None, None).await;
+        log(
+            &pool,
+            "x_post_tweet",
+            "mutation",
+            1,
+            false,
+            Some("policy_denied_rate_limited"),
+            Some("deny"),
+        )
+        .await;
+        log(&pool, "get_stats", "analytics", 5, false, None, None).await;
+
+        let counts = get_outcome_counts(&pool).await.expect("counts");
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[0].tool_name, "get_stats");
+        assert!(!counts[0].success);
+        assert_eq!(counts[0].error_code, None);
+        assert_eq!(counts[1].tool_name, "x_post_tweet");
+        assert!(counts[1].success);
+        assert_eq!(counts[1].count, 2);
+        assert_eq!(
+            counts[2].error_code.as_deref(),
+            Some("policy_denied_rate_limited")
+        );
+        assert_eq!(counts[2].count, 1);
+    }
+
     #[tokio::test]
     async fn percentile_calculation() {
         let pool = init_test_db().await.expect("init db");
@@ -0,0 +1,183 @@
+//! Schema migration runner.
+//!
+//! Migrations are the ordered SQL files in `crates/tuitbot-core/migrations`,
+//! embedded at compile time. The schema version of a database is the
+//! highest migration version recorded in SQLx's `_sqlx_migrations` table
+//! (0 for a fresh file). Running is idempotent: already-applied migrations
+//! are skipped, and each newly applied one is logged.
+//!
+//! A database migrated by a newer binary is refused with
+//! [`StorageError::SchemaTooNew`] instead of being opened with a schema this
+//! binary does not understand.
+
+use serde::Serialize;
+use sqlx::migrate::Migrator;
+
+use super::DbPool;
+use crate::error::StorageError;
+
+/// Every migration shipped with this binary.
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// A migration applied by [`run_pending`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AppliedMigration {
+    pub version: i64,
+    pub description: String,
+}
+
+/// Outcome of a migration run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MigrationReport {
+    /// Schema version before the run.
+    pub from_version: i64,
+    /// Schema version after the run.
+    pub to_version: i64,
+    /// Migrations applied by this run, oldest first.
+    pub applied: Vec<AppliedMigration>,
+}
+
+/// Highest migration version this binary knows about.
+pub fn latest_version() -> i64 {
+    MIGRATOR.iter().map(|m| m.version).max().unwrap_or(0)
+}
+
+/// Current schema version of the database (0 when never migrated).
+pub async fn current_version(pool: &DbPool) -> Result<i64, StorageError> {
+    Ok(applied_versions(pool).await?.into_iter().max().unwrap_or(0))
+}
+
+/// Apply every pending migration in order.
+///
+/// Fails with [`StorageError::SchemaTooNew`] when the database has a
+/// migration newer than any this binary ships.
+pub async fn run_pending(pool: &DbPool) -> Result<MigrationReport, StorageError> {
+    let applied = applied_versions(pool).await?;
+    let from_version = applied.iter().copied().max().unwrap_or(0);
+    let binary_version = latest_version();
+    if from_version > binary_version {
+        return Err(StorageError::SchemaTooNew {
+            db_version: from_version,
+            binary_version,
+        });
+    }
+
+    let pending: Vec<AppliedMigration> = MIGRATOR
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration() && !applied.contains(&m.version))
+        .map(|m| AppliedMigration {
+            version: m.version,
+            description: m.description.to_string(),
+        })
+        .collect();
+
+    MIGRATOR
+        .run(pool)
+        .await
+        .map_err(|e| StorageError::Migration { source: e })?;
+
+    for m in &pending {
+        tracing::info!(
+            version = m.version,
+            description = %m.description,
+            "Applied database migration"
+        );
+    }
+
+    Ok(MigrationReport {
+        from_version,
+        to_version: current_version(pool).await?,
+        applied: pending,
+    })
+}
+
+/// Versions recorded as successfully applied; empty before the first run.
+async fn applied_versions(pool: &DbPool) -> Result<Vec<i64>, StorageError> {
+    let has_table: bool = sqlx::query_scalar(
+        "SELECT EXISTS(SELECT 1 FROM sqlite_master \
+         WHERE type = 'table' AND name = '_sqlx_migrations')",
+    )
+    .fetch_one(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    if !has_table {
+        return Ok(Vec::new());
+    }
+
+    sqlx::query_scalar("SELECT version FROM _sqlx_migrations WHERE success = 1")
+        .fetch_all(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    /// An empty in-memory database with no migrations applied (version 0).
+    async fn v0_db() -> DbPool {
+        SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .expect("connect")
+    }
+
+    #[tokio::test]
+    async fn migrates_v0_db_to_latest() {
+        let pool = v0_db().await;
+        assert_eq!(current_version(&pool).await.unwrap(), 0);
+
+        let report = run_pending(&pool).await.expect("migrate");
+
+        assert_eq!(report.from_version, 0);
+        assert_eq!(report.to_version, latest_version());
+        assert_eq!(report.applied.len(), MIGRATOR.iter().count());
+        assert!(report
+            .applied
+            .windows(2)
+            .all(|w| w[0].version < w[1].version));
+    }
+
+    #[tokio::test]
+    async fn rerun_is_a_noop() {
+        let pool = v0_db().await;
+        run_pending(&pool).await.expect("first run");
+
+        let report = run_pending(&pool).await.expect("second run");
+
+        assert!(report.applied.is_empty());
+        assert_eq!(report.from_version, latest_version());
+        assert_eq!(report.to_version, latest_version());
+    }
+
+    #[tokio::test]
+    async fn newer_database_is_refused() {
+        let pool = v0_db().await;
+        run_pending(&pool).await.expect("migrate");
+        let future = latest_version() + 1;
+        sqlx::query(
+            "INSERT INTO _sqlx_migrations \
+             (version, description, success, checksum, execution_time) \
+             VALUES (?, 'from the future', 1, x'00', 0)",
+        )
+        .bind(future)
+        .execute(&pool)
+        .await
+        .unwrap();
+
+        let err = run_pending(&pool).await.unwrap_err();
+
+        match err {
+            StorageError::SchemaTooNew {
+                db_version,
+                binary_version,
+            } => {
+                assert_eq!(db_version, future);
+                assert_eq!(binary_version, latest_version());
+            }
+            other => panic!("expected SchemaTooNew, got {other}"),
+        }
+    }
+}
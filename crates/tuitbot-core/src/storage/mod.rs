@@ -8,29 +8,40 @@ pub mod action_log;
 pub mod analytics;
 pub mod approval_queue;
 pub mod author_interactions;
+pub mod author_reputation;
 pub mod backup;
-pub mod cleanup;
+pub mod content_frameworks;
+pub mod content_rotation;
 pub mod cursors;
 pub mod health;
+pub mod idempotency_keys;
+pub mod incremental_backup;
 pub mod llm_usage;
+pub mod loop_breakers;
 pub mod mcp_telemetry;
 pub mod media;
+pub mod migrations;
 pub mod mutation_audit;
+pub mod pagination;
 pub mod provenance;
 pub mod rate_limits;
 pub mod replies;
 pub mod reset;
+pub mod retention;
 pub mod scheduled_content;
 pub mod strategy;
 pub mod target_accounts;
+pub mod thread_progress;
 pub mod threads;
 pub mod tweets;
 pub mod vault_selections;
 pub mod watchtower;
 pub mod x_api_usage;
 
-use crate::error::StorageError;
+use crate::config::StorageConfig;
+use crate::error::{ConfigError, StorageError};
 use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqlitePoolOptions, SqliteSynchronous};
+use sqlx::Executor;
 use std::str::FromStr;
 use std::time::Duration;
 
@@ -41,8 +52,45 @@ pub type DbPool = sqlx::SqlitePool;
 ///
 /// Creates the database file and parent directories if they don't exist,
 /// configures WAL mode for concurrent read/write performance, runs embedded
-/// migrations, and returns a connection pool.
+/// migrations, and returns a connection pool. Uses the default `[storage]`
+/// pragmas; see [`init_db_with_config`] to honor user overrides.
 pub async fn init_db(db_path: &str) -> Result<DbPool, StorageError> {
+    init_db_with_config(db_path, &StorageConfig::default()).await
+}
+
+/// Initialize the SQLite database using the pragma settings from `[storage]`.
+///
+/// `journal_mode`, `synchronous`, and `busy_timeout` are applied through an
+/// `after_connect` hook so every pooled connection gets them, not just the
+/// first one opened. Pending migrations are applied before returning.
+pub async fn init_db_with_config(
+    db_path: &str,
+    storage: &StorageConfig,
+) -> Result<DbPool, StorageError> {
+    let pool = connect(db_path, storage).await?;
+    migrations::run_pending(&pool).await?;
+    Ok(pool)
+}
+
+/// Open the database, apply pending migrations, close it, and report what
+/// changed. Backs `tuitbot migrate`.
+pub async fn migrate_db(
+    db_path: &str,
+    storage: &StorageConfig,
+) -> Result<migrations::MigrationReport, StorageError> {
+    let pool = connect(db_path, storage).await?;
+    let result = migrations::run_pending(&pool).await;
+    pool.close().await;
+    result
+}
+
+/// Create and open the database file without running migrations.
+async fn connect(db_path: &str, storage: &StorageConfig) -> Result<DbPool, StorageError> {
+    let pragmas =
+        ConnectionPragmas::from_config(storage).map_err(|e| StorageError::Connection {
+            source: sqlx::Error::Configuration(e.to_string().into()),
+        })?;
+
     let trimmed = db_path.trim();
     if trimmed.is_empty() {
         return Err(StorageError::Connection {
@@ -96,26 +144,95 @@ pub async fn init_db(db_path: &str) -> Result<DbPool, StorageError> {
     let connect_options = SqliteConnectOptions::from_str(&format!("sqlite:{expanded}"))
         .map_err(|e| StorageError::Connection { source: e })?
         .create_if_missing(true)
-        .journal_mode(SqliteJournalMode::Wal)
-        .synchronous(SqliteSynchronous::Normal)
-        .busy_timeout(Duration::from_secs(5))
         .optimize_on_close(true, None)
         .foreign_keys(true);
+    let connect_options = pragmas.connect_options(connect_options);
+    let init_sql = pragmas.statements();
 
-    let pool = SqlitePoolOptions::new()
+    SqlitePoolOptions::new()
         .max_connections(4)
         .min_connections(1)
         .idle_timeout(Duration::from_secs(300))
+        .after_connect(move |conn, _meta| {
+            let init_sql = init_sql.clone();
+            Box::pin(async move {
+                conn.execute(init_sql.as_str()).await?;
+                Ok(())
+            })
+        })
         .connect_with(connect_options)
         .await
-        .map_err(|e| StorageError::Connection { source: e })?;
+        .map_err(|e| StorageError::Connection { source: e })
+}
 
-    sqlx::migrate!("./migrations")
-        .run(&pool)
-        .await
-        .map_err(|e| StorageError::Migration { source: e })?;
+/// Connection-level pragmas resolved from [`StorageConfig`].
+///
+/// `Config::validate` goes through [`ConnectionPragmas::from_config`] too, so
+/// the allow-lists are checked in one place.
+#[derive(Debug, Clone)]
+pub(crate) struct ConnectionPragmas {
+    journal_mode: String,
+    synchronous: String,
+    busy_timeout_ms: u64,
+}
 
-    Ok(pool)
+impl ConnectionPragmas {
+    pub(crate) fn from_config(storage: &StorageConfig) -> Result<Self, ConfigError> {
+        let journal_mode = storage.journal_mode.trim().to_ascii_lowercase();
+        if !JOURNAL_MODES.contains(&journal_mode.as_str()) {
+            return Err(invalid_pragma(
+                "journal_mode",
+                JOURNAL_MODES,
+                &storage.journal_mode,
+            ));
+        }
+        let synchronous = storage.synchronous.trim().to_ascii_lowercase();
+        if !SYNCHRONOUS_LEVELS.contains(&synchronous.as_str()) {
+            return Err(invalid_pragma(
+                "synchronous",
+                SYNCHRONOUS_LEVELS,
+                &storage.synchronous,
+            ));
+        }
+        Ok(Self {
+            journal_mode,
+            synchronous,
+            busy_timeout_ms: storage.busy_timeout_ms,
+        })
+    }
+
+    fn connect_options(&self, options: SqliteConnectOptions) -> SqliteConnectOptions {
+        // Both values were validated against the allow-lists above.
+        let journal_mode =
+            SqliteJournalMode::from_str(&self.journal_mode).unwrap_or(SqliteJournalMode::Wal);
+        let synchronous =
+            SqliteSynchronous::from_str(&self.synchronous).unwrap_or(SqliteSynchronous::Normal);
+        options
+            .journal_mode(journal_mode)
+            .synchronous(synchronous)
+            .busy_timeout(Duration::from_millis(self.busy_timeout_ms))
+    }
+
+    /// PRAGMA statements re-applied on every new pooled connection.
+    fn statements(&self) -> String {
+        format!(
+            "PRAGMA busy_timeout = {}; PRAGMA journal_mode = {}; PRAGMA synchronous = {};",
+            self.busy_timeout_ms, self.journal_mode, self.synchronous,
+        )
+    }
+}
+
+/// Journal modes accepted in `storage.journal_mode`.
+pub const JOURNAL_MODES: &[&str] = &["wal", "delete", "truncate", "persist", "memory", "off"];
+
+/// Synchronous levels accepted in `storage.synchronous`.
+pub const SYNCHRONOUS_LEVELS: &[&str] = &["off", "normal", "full", "extra"];
+
+fn invalid_pragma(field: &str, allowed: &[&str], value: &str) -> ConfigError {
+    ConfigError::InvalidValue {
+        field: format!("storage.{field}"),
+        message: format!("must be one of {}, got '{value}'", allowed.join(", ")),
+    }
 }
 
 /// Initialize an in-memory SQLite database for testing.
@@ -135,10 +252,7 @@ pub async fn init_test_db() -> Result<DbPool, StorageError> {
         .await
         .map_err(|e| StorageError::Connection { source: e })?;
 
-    sqlx::migrate!("./migrations")
-        .run(&pool)
-        .await
-        .map_err(|e| StorageError::Migration { source: e })?;
+    migrations::run_pending(&pool).await?;
 
     Ok(pool)
 }
@@ -186,6 +300,9 @@ mod tests {
         assert!(table_names.contains(&"target_tweets"));
         assert!(table_names.contains(&"follower_snapshots"));
         assert!(table_names.contains(&"reply_performance"));
+        assert!(table_names.contains(&"author_reputation"));
+        assert!(table_names.contains(&"content_rotation"));
+        assert!(table_names.contains(&"content_frameworks"));
         assert!(table_names.contains(&"tweet_performance"));
         assert!(table_names.contains(&"content_scores"));
         assert!(table_names.contains(&"approval_queue"));
@@ -196,6 +313,7 @@ mod tests {
         assert!(table_names.contains(&"approval_edit_history"));
         assert!(table_names.contains(&"media_uploads"));
         assert!(table_names.contains(&"mutation_audit"));
+        assert!(table_names.contains(&"mcp_idempotency_keys"));
         assert!(table_names.contains(&"source_contexts"));
         assert!(table_names.contains(&"content_nodes"));
         assert!(table_names.contains(&"draft_seeds"));
@@ -316,4 +434,106 @@ mod tests {
         let pool2 = init_db(&db_path_str).await.expect("second init");
         pool2.close().await;
     }
+
+    #[tokio::test]
+    async fn init_db_with_config_rejects_unknown_journal_mode() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let db_path = dir.path().join("bad.db");
+        let storage = StorageConfig {
+            journal_mode: "sideways".to_string(),
+            ..StorageConfig::default()
+        };
+        let result = init_db_with_config(&db_path.to_string_lossy(), &storage).await;
+        let msg = result.unwrap_err().to_string();
+        assert!(msg.contains("storage.journal_mode"));
+    }
+
+    #[tokio::test]
+    async fn init_db_applies_pragmas_to_every_connection() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let db_path = dir.path().join("pragmas.db");
+        let storage = StorageConfig {
+            busy_timeout_ms: 7000,
+            ..StorageConfig::default()
+        };
+        let pool = init_db_with_config(&db_path.to_string_lossy(), &storage)
+            .await
+            .expect("init db");
+
+        // Hold two connections at once so the second is freshly opened.
+        let mut first = pool.acquire().await.expect("first conn");
+        let mut second = pool.acquire().await.expect("second conn");
+        for conn in [&mut first, &mut second] {
+            let timeout: i64 = sqlx::query_scalar("PRAGMA busy_timeout")
+                .fetch_one(&mut **conn)
+                .await
+                .expect("busy_timeout");
+            assert_eq!(timeout, 7000);
+            let mode: String = sqlx::query_scalar("PRAGMA journal_mode")
+                .fetch_one(&mut **conn)
+                .await
+                .expect("journal_mode");
+            assert_eq!(mode, "wal");
+            let sync: i64 = sqlx::query_scalar("PRAGMA synchronous")
+                .fetch_one(&mut **conn)
+                .await
+                .expect("synchronous");
+            assert_eq!(sync, 1, "NORMAL");
+        }
+        drop(first);
+        drop(second);
+        pool.close().await;
+    }
+
+    #[tokio::test]
+    async fn init_db_overlapping_writes_do_not_lock() {
+        let dir = tempfile::tempdir().expect("create temp dir");
+        let db_path = dir.path().join("concurrent.db");
+        let pool = init_db(&db_path.to_string_lossy()).await.expect("init db");
+
+        sqlx::query("CREATE TABLE concurrent_probe (id INTEGER PRIMARY KEY, writer INTEGER)")
+            .execute(&pool)
+            .await
+            .expect("create table");
+
+        let mut first = pool.acquire().await.expect("first conn");
+        let mut second = pool.acquire().await.expect("second conn");
+
+        // The first writer holds the write lock while the second attempts to write;
+        // busy_timeout makes the second wait instead of failing immediately.
+        sqlx::query("BEGIN IMMEDIATE")
+            .execute(&mut *first)
+            .await
+            .expect("begin");
+        sqlx::query("INSERT INTO concurrent_probe (writer) VALUES (1)")
+            .execute(&mut *first)
+            .await
+            .expect("first insert");
+
+        let second_write = tokio::spawn(async move {
+            sqlx::query("INSERT INTO concurrent_probe (writer) VALUES (2)")
+                .execute(&mut *second)
+                .await
+                .map(|_| ())
+        });
+
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        sqlx::query("COMMIT")
+            .execute(&mut *first)
+            .await
+            .expect("commit");
+        drop(first);
+
+        second_write
+            .await
+            .expect("join")
+            .expect("second insert should wait, not error");
+
+        let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM concurrent_probe")
+            .fetch_one(&pool)
+            .await
+            .expect("count");
+        assert_eq!(count, 2);
+        pool.close().await;
+    }
 }
@@ -0,0 +1,109 @@
+//! Keyset pagination over `(created_at, id)`.
+//!
+//! Listing endpoints page by the last row they returned instead of by
+//! offset, so rows inserted between two page fetches never shift a page:
+//! nothing is returned twice and nothing is skipped. `id` breaks ties
+//! between rows created in the same second.
+
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine as _};
+use serde::Serialize;
+
+/// Position just past the last row of a page.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PageCursor {
+    pub created_at: String,
+    pub id: i64,
+}
+
+impl PageCursor {
+    /// Cursor positioned at the row with this `created_at` and `id`.
+    pub fn new(created_at: &str, id: i64) -> Self {
+        Self {
+            created_at: created_at.to_string(),
+            id,
+        }
+    }
+
+    /// Opaque, URL-safe form handed to clients as `next_cursor`.
+    pub fn encode(&self) -> String {
+        URL_SAFE_NO_PAD.encode(format!("{}|{}", self.id, self.created_at))
+    }
+
+    /// Parse a cursor produced by [`PageCursor::encode`]; `None` if malformed.
+    pub fn decode(cursor: &str) -> Option<Self> {
+        let bytes = URL_SAFE_NO_PAD.decode(cursor).ok()?;
+        let text = String::from_utf8(bytes).ok()?;
+        let (id, created_at) = text.split_once('|')?;
+        Some(Self {
+            created_at: created_at.to_string(),
+            id: id.parse().ok()?,
+        })
+    }
+}
+
+/// One page of rows plus the cursor for the next page, if there is one.
+#[derive(Debug, Clone, Serialize)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub next_cursor: Option<String>,
+}
+
+impl<T> Page<T> {
+    /// Build a page from up to `limit + 1` fetched rows.
+    ///
+    /// The extra row only signals that another page exists; it is dropped
+    /// and the cursor points at the last row kept.
+    pub fn from_rows(mut rows: Vec<T>, limit: u32, key: impl Fn(&T) -> PageCursor) -> Self {
+        let limit = limit as usize;
+        let next_cursor = if rows.len() > limit {
+            rows.truncate(limit);
+            rows.last().map(|row| key(row).encode())
+        } else {
+            None
+        };
+        Self {
+            items: rows,
+            next_cursor,
+        }
+    }
+}
+
+/// `WHERE` clause selecting rows after `cursor` in `created_at DESC, id DESC` order.
+pub(crate) const BEFORE_CURSOR_SQL: &str = "(created_at < ? OR (created_at = ? AND id < ?))";
+
+/// `WHERE` clause selecting rows after `cursor` in `created_at ASC, id ASC` order.
+pub(crate) const AFTER_CURSOR_SQL: &str = "(created_at > ? OR (created_at = ? AND id > ?))";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cursor_round_trips() {
+        let cursor = PageCursor::new("2026-03-02 14:00:00", 42);
+        assert_eq!(PageCursor::decode(&cursor.encode()), Some(cursor));
+    }
+
+    #[test]
+    fn malformed_cursor_is_rejected() {
+        assert_eq!(PageCursor::decode("not base64!"), None);
+        assert_eq!(
+            PageCursor::decode(&URL_SAFE_NO_PAD.encode("no-separator")),
+            None
+        );
+        assert_eq!(PageCursor::decode(&URL_SAFE_NO_PAD.encode("x|2026")), None);
+    }
+
+    #[test]
+    fn next_cursor_only_when_rows_remain() {
+        let key = |n: &i64| PageCursor::new("t", *n);
+
+        let page = Page::from_rows(vec![1, 2, 3], 2, key);
+        assert_eq!(page.items, vec![1, 2]);
+        assert_eq!(page.next_cursor, Some(PageCursor::new("t", 2).encode()));
+
+        let page = Page::from_rows(vec![1, 2], 2, key);
+        assert_eq!(page.items, vec![1, 2]);
+        assert_eq!(page.next_cursor, None);
+    }
+}
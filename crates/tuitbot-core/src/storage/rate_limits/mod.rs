@@ -5,20 +5,24 @@
 //! uses transactions for atomicity.
 
 pub mod queries;
+pub mod ramp;
 pub mod tracker;
 
 pub use crate::mcp_policy::types::{PolicyRateLimit, RateLimitDimension};
 pub use queries::{
     check_policy_rate_limits, check_policy_rate_limits_for, get_all_rate_limits,
-    get_all_rate_limits_for, get_daily_usage, get_daily_usage_for, init_policy_rate_limits,
-    init_policy_rate_limits_for, record_policy_rate_limits, record_policy_rate_limits_for,
-    ActionUsage, DailyUsage,
+    get_all_rate_limits_for, get_daily_usage, get_daily_usage_for, get_rate_limit,
+    get_rate_limit_for, init_policy_rate_limits, init_policy_rate_limits_for,
+    record_policy_rate_limits, record_policy_rate_limits_for, ActionUsage, DailyUsage,
 };
+pub use ramp::{effective_limit, RAMPED_ACTIONS};
 pub use tracker::{
     check_and_increment_rate_limit, check_and_increment_rate_limit_for, check_rate_limit,
-    check_rate_limit_for, increment_rate_limit, increment_rate_limit_for,
+    check_rate_limit_for, increment_rate_limit, increment_rate_limit_for, release_rate_limit_for,
 };
 
+use std::collections::BTreeMap;
+
 use super::DbPool;
 use crate::config::{IntervalsConfig, LimitsConfig};
 use crate::error::StorageError;
@@ -28,7 +32,7 @@ use super::accounts::DEFAULT_ACCOUNT_ID;
 /// A rate limit entry tracking usage for a specific action type.
 #[derive(Debug, Clone, sqlx::FromRow, serde::Serialize)]
 pub struct RateLimit {
-    /// Action type: reply, tweet, thread, search, mention_check.
+    /// Action type: reply, tweet, thread, like, retweet, search, mention_check.
     pub action_type: String,
     /// Number of requests made in the current period.
     pub request_count: i64,
@@ -40,6 +44,19 @@ pub struct RateLimit {
     pub period_seconds: i64,
 }
 
+impl RateLimit {
+    /// When the current period ends and the counter resets.
+    ///
+    /// Returns `None` if `period_start` is not a valid timestamp.
+    pub fn resets_at(&self) -> Option<chrono::DateTime<chrono::Utc>> {
+        let start = self
+            .period_start
+            .parse::<chrono::DateTime<chrono::Utc>>()
+            .ok()?;
+        Some(start + chrono::Duration::seconds(self.period_seconds))
+    }
+}
+
 /// Initialize rate limit rows from configuration for a specific account.
 ///
 /// Uses `INSERT OR IGNORE` so existing counters are preserved across restarts.
@@ -57,6 +74,8 @@ pub async fn init_rate_limits_for(
         ("reply", i64::from(config.max_replies_per_day), 86400),
         ("tweet", i64::from(config.max_tweets_per_day), 86400),
         ("thread", i64::from(config.max_threads_per_week), 604800),
+        ("like", i64::from(config.max_likes_per_day), 86400),
+        ("retweet", i64::from(config.max_retweets_per_day), 86400),
         ("search", 300, 900),
         ("mention_check", 180, 900),
     ];
@@ -76,6 +95,28 @@ pub async fn init_rate_limits_for(
         .map_err(|e| StorageError::Query { source: e })?;
     }
 
+    // Store the soft-launch ramp (or clear it) on the posting limits. The
+    // anchor is the account's first activity, fixed once recorded.
+    let ramp = config.ramp.as_ref();
+    for action_type in RAMPED_ACTIONS {
+        sqlx::query(
+            "UPDATE rate_limits SET ramp_start = ?, ramp_target = ?, ramp_days = ?, \
+             ramp_anchor = COALESCE(ramp_anchor, \
+                 (SELECT MIN(created_at) FROM action_log WHERE account_id = ?), \
+                 strftime('%Y-%m-%dT%H:%M:%SZ', 'now')) \
+             WHERE account_id = ? AND action_type = ?",
+        )
+        .bind(ramp.map(|r| r.start))
+        .bind(ramp.map(|r| r.target))
+        .bind(ramp.map(|r| i64::from(r.days)))
+        .bind(account_id)
+        .bind(account_id)
+        .bind(action_type)
+        .execute(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    }
+
     Ok(())
 }
 
@@ -120,5 +161,47 @@ pub async fn init_mcp_rate_limit(pool: &DbPool, max_per_hour: u32) -> Result<(),
     init_mcp_rate_limit_for(pool, DEFAULT_ACCOUNT_ID, max_per_hour).await
 }
 
+/// Rate limit row key for a tool listed in `mcp_policy.tool_limits`.
+pub fn mcp_tool_limit_key(tool_name: &str) -> String {
+    format!("mcp_tool:{tool_name}")
+}
+
+/// Initialize per-tool MCP rate limit rows for a specific account.
+///
+/// Existing counters are preserved, but `max_requests` is updated so that
+/// edits to `mcp_policy.tool_limits` take effect without a reset.
+pub async fn init_mcp_tool_limits_for(
+    pool: &DbPool,
+    account_id: &str,
+    tool_limits: &BTreeMap<String, u32>,
+) -> Result<(), StorageError> {
+    for (tool_name, max_per_hour) in tool_limits {
+        sqlx::query(
+            "INSERT INTO rate_limits \
+             (account_id, action_type, request_count, period_start, max_requests, period_seconds) \
+             VALUES (?, ?, 0, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'), ?, 3600) \
+             ON CONFLICT(account_id, action_type) DO UPDATE SET max_requests = excluded.max_requests",
+        )
+        .bind(account_id)
+        .bind(mcp_tool_limit_key(tool_name))
+        .bind(i64::from(*max_per_hour))
+        .execute(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    }
+
+    Ok(())
+}
+
+/// Initialize per-tool MCP rate limit rows.
+///
+/// Existing counters are preserved, but `max_requests` is updated.
+pub async fn init_mcp_tool_limits(
+    pool: &DbPool,
+    tool_limits: &BTreeMap<String, u32>,
+) -> Result<(), StorageError> {
+    init_mcp_tool_limits_for(pool, DEFAULT_ACCOUNT_ID, tool_limits).await
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,6 +1,7 @@
 //! Rate limit queries and policy operations.
 
 use super::super::DbPool;
+use super::ramp::apply_ramp;
 use super::{RateLimit, DEFAULT_ACCOUNT_ID};
 use crate::error::StorageError;
 use crate::mcp_policy::types::PolicyRateLimit;
@@ -61,18 +62,23 @@ pub async fn get_daily_usage(pool: &DbPool) -> Result<DailyUsage, StorageError>
 /// Fetch all rate limit entries for a specific account, ordered by action type.
 ///
 /// Used for status reporting and debugging.
+/// `max_requests` is today's limit under `limits.ramp`, if configured.
 pub async fn get_all_rate_limits_for(
     pool: &DbPool,
     account_id: &str,
 ) -> Result<Vec<RateLimit>, StorageError> {
-    sqlx::query_as::<_, RateLimit>(
+    let mut limits = sqlx::query_as::<_, RateLimit>(
         "SELECT action_type, request_count, period_start, max_requests, period_seconds \
          FROM rate_limits WHERE account_id = ? ORDER BY action_type",
     )
     .bind(account_id)
     .fetch_all(pool)
     .await
-    .map_err(|e| StorageError::Query { source: e })
+    .map_err(|e| StorageError::Query { source: e })?;
+    for limit in &mut limits {
+        apply_ramp(pool, account_id, limit).await?;
+    }
+    Ok(limits)
 }
 
 /// Fetch all rate limit entries, ordered by action type.
@@ -82,6 +88,37 @@ pub async fn get_all_rate_limits(pool: &DbPool) -> Result<Vec<RateLimit>, Storag
     get_all_rate_limits_for(pool, DEFAULT_ACCOUNT_ID).await
 }
 
+/// Fetch a single rate limit entry by action type for a specific account.
+///
+/// `max_requests` is today's limit under `limits.ramp`, if configured.
+pub async fn get_rate_limit_for(
+    pool: &DbPool,
+    account_id: &str,
+    action_type: &str,
+) -> Result<Option<RateLimit>, StorageError> {
+    let mut limit = sqlx::query_as::<_, RateLimit>(
+        "SELECT action_type, request_count, period_start, max_requests, period_seconds \
+         FROM rate_limits WHERE account_id = ? AND action_type = ?",
+    )
+    .bind(account_id)
+    .bind(action_type)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    if let Some(limit) = &mut limit {
+        apply_ramp(pool, account_id, limit).await?;
+    }
+    Ok(limit)
+}
+
+/// Fetch a single rate limit entry by action type.
+pub async fn get_rate_limit(
+    pool: &DbPool,
+    action_type: &str,
+) -> Result<Option<RateLimit>, StorageError> {
+    get_rate_limit_for(pool, DEFAULT_ACCOUNT_ID, action_type).await
+}
+
 /// Initialize rate limit rows for v2 policy rate limits for a specific account.
 ///
 /// Uses `INSERT OR IGNORE` so existing counters are preserved.
@@ -0,0 +1,64 @@
+//! Soft-launch ramp for posting limits (`limits.ramp`).
+//!
+//! A new account starts with a fraction of its reply, tweet, and thread
+//! limits, growing linearly to the full limits over the ramp window. The
+//! ramp is stored on the rate limit rows at init, so every check sees the
+//! limit for the account's age without needing the config.
+
+use crate::config::LimitRampConfig;
+use crate::error::StorageError;
+
+use super::RateLimit;
+
+/// Action types the ramp applies to.
+pub const RAMPED_ACTIONS: [&str; 3] = ["reply", "tweet", "thread"];
+
+/// Today's limit for an action whose configured limit is `base`.
+///
+/// Without a ramp this is `base`. With one, `base` is scaled by a factor
+/// moving linearly from `ramp.start` on day zero to `ramp.target` on day
+/// `ramp.days` and after. Never below 1 or above `base`.
+pub fn effective_limit(base: i64, ramp: Option<&LimitRampConfig>, account_age_days: i64) -> i64 {
+    let Some(ramp) = ramp else {
+        return base;
+    };
+    let progress = if ramp.days == 0 {
+        1.0
+    } else {
+        (account_age_days.max(0) as f64 / f64::from(ramp.days)).min(1.0)
+    };
+    let factor = ramp.start + (ramp.target - ramp.start) * progress;
+    ((base as f64 * factor).round() as i64).max(1).min(base)
+}
+
+/// Replace `limit.max_requests` with today's ramped limit, if the row has a ramp.
+pub(super) async fn apply_ramp<'e, E>(
+    executor: E,
+    account_id: &str,
+    limit: &mut RateLimit,
+) -> Result<(), StorageError>
+where
+    E: sqlx::Executor<'e, Database = sqlx::Sqlite>,
+{
+    let row: Option<(f64, f64, i64, Option<i64>)> = sqlx::query_as(
+        "SELECT ramp_start, ramp_target, ramp_days, \
+                CAST(julianday('now') - julianday(ramp_anchor) AS INTEGER) \
+         FROM rate_limits \
+         WHERE account_id = ? AND action_type = ? AND ramp_days IS NOT NULL",
+    )
+    .bind(account_id)
+    .bind(&limit.action_type)
+    .fetch_optional(executor)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    if let Some((start, target, days, age)) = row {
+        let ramp = LimitRampConfig {
+            start,
+            target,
+            days: u32::try_from(days).unwrap_or(0),
+        };
+        limit.max_requests = effective_limit(limit.max_requests, Some(&ramp), age.unwrap_or(0));
+    }
+    Ok(())
+}
@@ -11,8 +11,13 @@ fn test_limits_config() -> crate::config::LimitsConfig {
         min_action_delay_seconds: 30,
         max_action_delay_seconds: 120,
         max_replies_per_author_per_day: 1,
+        max_replies_per_conversation_per_day: 2,
+        max_likes_per_day: 20,
+        max_retweets_per_day: 5,
+        discovery_likes: false,
         banned_phrases: vec![],
         product_mention_ratio: 0.2,
+        ramp: None,
     }
 }
 
@@ -20,6 +25,7 @@ fn test_intervals_config() -> crate::config::IntervalsConfig {
     crate::config::IntervalsConfig {
         mentions_check_seconds: 60,
         discovery_search_seconds: 300,
+        discovery_max_pages: 1,
         content_post_window_seconds: 600,
         thread_interval_seconds: 900,
     }
@@ -36,12 +42,17 @@ async fn init_rate_limits_inserts_rows() {
         .expect("init");
 
     let limits = get_all_rate_limits(&pool).await.expect("get");
-    assert_eq!(limits.len(), 5);
-    assert_eq!(limits[0].action_type, "mention_check");
-    assert_eq!(limits[0].max_requests, 180);
-    assert_eq!(limits[0].period_seconds, 900);
-    assert_eq!(limits[4].action_type, "tweet");
-    assert_eq!(limits[4].max_requests, 2);
+    assert_eq!(limits.len(), 7);
+    assert_eq!(limits[0].action_type, "like");
+    assert_eq!(limits[0].max_requests, 20);
+    assert_eq!(limits[0].period_seconds, 86400);
+    assert_eq!(limits[1].action_type, "mention_check");
+    assert_eq!(limits[1].max_requests, 180);
+    assert_eq!(limits[1].period_seconds, 900);
+    assert_eq!(limits[3].action_type, "retweet");
+    assert_eq!(limits[3].max_requests, 5);
+    assert_eq!(limits[6].action_type, "tweet");
+    assert_eq!(limits[6].max_requests, 2);
 }
 
 #[tokio::test]
@@ -347,3 +358,77 @@ async fn policy_rate_limits_record_only_matching() {
         .expect("like");
     assert_eq!(like.request_count, 1);
 }
+
+fn ramp(start: f64, target: f64, days: u32) -> crate::config::LimitRampConfig {
+    crate::config::LimitRampConfig {
+        start,
+        target,
+        days,
+    }
+}
+
+#[test]
+fn effective_limit_grows_from_start_to_target_over_the_ramp() {
+    let ramp = ramp(0.25, 1.0, 10);
+
+    let by_day: Vec<i64> = (0..=12)
+        .map(|day| effective_limit(20, Some(&ramp), day))
+        .collect();
+    assert_eq!(by_day[0], 5);
+    assert_eq!(by_day[5], 13);
+    assert_eq!(by_day[10], 20);
+    assert_eq!(by_day[12], 20, "stays at target after the window");
+    assert!(by_day.windows(2).all(|w| w[0] <= w[1]), "{by_day:?}");
+}
+
+#[test]
+fn effective_limit_without_ramp_is_the_base() {
+    assert_eq!(effective_limit(20, None, 0), 20);
+    assert_eq!(effective_limit(20, None, 365), 20);
+}
+
+#[test]
+fn effective_limit_never_drops_below_one() {
+    assert_eq!(effective_limit(1, Some(&ramp(0.1, 1.0, 30)), 0), 1);
+    assert_eq!(effective_limit(3, Some(&ramp(0.1, 0.5, 30)), 60), 2);
+}
+
+#[tokio::test]
+async fn check_rate_limit_uses_ramped_limit_for_account_age() {
+    let pool = init_test_db().await.expect("init db");
+    let mut config = test_limits_config();
+    config.max_replies_per_day = 10;
+    config.ramp = Some(ramp(0.2, 1.0, 10));
+
+    // First activity five days ago: replies ramp to 0.6 * 10 = 6 today.
+    sqlx::query(
+        "INSERT INTO action_log (account_id, action_type, status, created_at) \
+         VALUES (?, 'search', 'success', strftime('%Y-%m-%dT%H:%M:%SZ', 'now', '-5 days'))",
+    )
+    .bind(DEFAULT_ACCOUNT_ID)
+    .execute(&pool)
+    .await
+    .expect("seed activity");
+    init_rate_limits(&pool, &config, &test_intervals_config())
+        .await
+        .expect("init");
+
+    let reply = get_rate_limit(&pool, "reply").await.unwrap().unwrap();
+    assert_eq!(reply.max_requests, 6);
+    let search = get_rate_limit(&pool, "search").await.unwrap().unwrap();
+    assert_eq!(search.max_requests, 300, "the ramp only covers posting");
+
+    for _ in 0..6 {
+        assert!(check_and_increment_rate_limit(&pool, "reply")
+            .await
+            .unwrap());
+    }
+    assert!(!check_rate_limit(&pool, "reply").await.unwrap());
+
+    // Removing the ramp restores the configured limit.
+    config.ramp = None;
+    init_rate_limits(&pool, &config, &test_intervals_config())
+        .await
+        .expect("re-init");
+    assert!(check_rate_limit(&pool, "reply").await.unwrap());
+}
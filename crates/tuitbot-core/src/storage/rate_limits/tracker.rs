@@ -1,6 +1,7 @@
 //! Rate limit checking and increment operations.
 
 use super::super::DbPool;
+use super::ramp::apply_ramp;
 use super::{RateLimit, DEFAULT_ACCOUNT_ID};
 use crate::error::StorageError;
 use chrono::{DateTime, Utc};
@@ -12,6 +13,9 @@ use chrono::{DateTime, Utc};
 /// 2. Resets the counter if the period has expired.
 /// 3. Returns `true` if under the limit, `false` if at or over.
 ///
+/// The limit is today's [`effective_limit`](super::effective_limit) when
+/// `limits.ramp` is configured.
+///
 /// Does NOT increment the counter -- call `increment_rate_limit` after the action succeeds.
 pub async fn check_rate_limit_for(
     pool: &DbPool,
@@ -33,7 +37,7 @@ pub async fn check_rate_limit_for(
     .await
     .map_err(|e| StorageError::Query { source: e })?;
 
-    let limit = match row {
+    let mut limit = match row {
         Some(l) => l,
         None => {
             tx.commit()
@@ -42,6 +46,7 @@ pub async fn check_rate_limit_for(
             return Ok(true);
         }
     };
+    apply_ramp(&mut *tx, account_id, &mut limit).await?;
 
     let now = Utc::now();
     let period_start = limit.period_start.parse::<DateTime<Utc>>().unwrap_or(now);
@@ -103,7 +108,7 @@ pub async fn check_and_increment_rate_limit_for(
     .await
     .map_err(|e| StorageError::Query { source: e })?;
 
-    let limit = match row {
+    let mut limit = match row {
         Some(l) => l,
         None => {
             tx.commit()
@@ -112,6 +117,7 @@ pub async fn check_and_increment_rate_limit_for(
             return Ok(true);
         }
     };
+    apply_ramp(&mut *tx, account_id, &mut limit).await?;
 
     let now = Utc::now();
     let period_start = limit.period_start.parse::<DateTime<Utc>>().unwrap_or(now);
@@ -187,3 +193,22 @@ pub async fn increment_rate_limit_for(
 pub async fn increment_rate_limit(pool: &DbPool, action_type: &str) -> Result<(), StorageError> {
     increment_rate_limit_for(pool, DEFAULT_ACCOUNT_ID, action_type).await
 }
+
+/// Give back a slot taken by [`check_and_increment_rate_limit_for`] for an
+/// action that did not happen. Never drops the counter below zero.
+pub async fn release_rate_limit_for(
+    pool: &DbPool,
+    account_id: &str,
+    action_type: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE rate_limits SET request_count = request_count - 1 \
+         WHERE account_id = ? AND action_type = ? AND request_count > 0",
+    )
+    .bind(account_id)
+    .bind(action_type)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
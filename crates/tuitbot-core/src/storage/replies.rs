@@ -4,6 +4,7 @@
 //! count daily usage, and retrieve recent reply content.
 
 use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::pagination::{Page, PageCursor, BEFORE_CURSOR_SQL};
 use super::DbPool;
 use crate::error::StorageError;
 
@@ -28,6 +29,8 @@ pub struct ReplySent {
     pub status: String,
     /// Error details if failed.
     pub error_message: Option<String>,
+    /// Conversation (root tweet) ID of the replied-to thread, if known.
+    pub conversation_id: Option<String>,
 }
 
 /// Insert a new reply record for a specific account. Returns the auto-generated ID.
@@ -39,8 +42,8 @@ pub async fn insert_reply_for(
     let result = sqlx::query(
         "INSERT INTO replies_sent \
          (account_id, target_tweet_id, reply_tweet_id, reply_content, llm_provider, llm_model, \
-          created_at, status, error_message) \
-         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+          created_at, status, error_message, conversation_id) \
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
     )
     .bind(account_id)
     .bind(&reply.target_tweet_id)
@@ -51,6 +54,7 @@ pub async fn insert_reply_for(
     .bind(&reply.created_at)
     .bind(&reply.status)
     .bind(&reply.error_message)
+    .bind(&reply.conversation_id)
     .execute(pool)
     .await
     .map_err(|e| StorageError::Query { source: e })?;
@@ -151,6 +155,34 @@ pub async fn count_replies_today(pool: &DbPool) -> Result<i64, StorageError> {
     count_replies_today_for(pool, DEFAULT_ACCOUNT_ID).await
 }
 
+/// Count replies sent today (UTC) into a conversation for a specific account.
+pub async fn count_conversation_replies_today_for(
+    pool: &DbPool,
+    account_id: &str,
+    conversation_id: &str,
+) -> Result<i64, StorageError> {
+    let row: Option<(i64,)> = sqlx::query_as(
+        "SELECT COUNT(*) FROM replies_sent \
+         WHERE account_id = ? AND conversation_id = ? AND date(created_at) = date('now') \
+         GROUP BY conversation_id",
+    )
+    .bind(account_id)
+    .bind(conversation_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(row.map_or(0, |r| r.0))
+}
+
+/// Count replies sent today (UTC) into a conversation.
+pub async fn count_conversation_replies_today(
+    pool: &DbPool,
+    conversation_id: &str,
+) -> Result<i64, StorageError> {
+    count_conversation_replies_today_for(pool, DEFAULT_ACCOUNT_ID, conversation_id).await
+}
+
 /// Get replies within a date range for a specific account, ordered by creation time.
 pub async fn get_replies_in_range_for(
     pool: &DbPool,
@@ -188,7 +220,8 @@ pub async fn get_recent_replies_for(
     offset: u32,
 ) -> Result<Vec<ReplySent>, StorageError> {
     sqlx::query_as::<_, ReplySent>(
-        "SELECT * FROM replies_sent WHERE account_id = ? ORDER BY created_at DESC LIMIT ? OFFSET ?",
+        "SELECT * FROM replies_sent WHERE account_id = ? \
+         ORDER BY created_at DESC, id DESC LIMIT ? OFFSET ?",
     )
     .bind(account_id)
     .bind(limit)
@@ -198,6 +231,36 @@ pub async fn get_recent_replies_for(
     .map_err(|e| StorageError::Query { source: e })
 }
 
+/// Fetch one keyset page of replies for a specific account, newest first,
+/// starting after `cursor` (or at the newest reply).
+pub async fn get_replies_page_for(
+    pool: &DbPool,
+    account_id: &str,
+    limit: u32,
+    cursor: Option<&PageCursor>,
+) -> Result<Page<ReplySent>, StorageError> {
+    let mut sql = String::from("SELECT * FROM replies_sent WHERE account_id = ?");
+    if cursor.is_some() {
+        sql.push_str(" AND ");
+        sql.push_str(BEFORE_CURSOR_SQL);
+    }
+    sql.push_str(" ORDER BY created_at DESC, id DESC LIMIT ?");
+
+    let mut query = sqlx::query_as::<_, ReplySent>(&sql).bind(account_id);
+    if let Some(c) = cursor {
+        query = query.bind(&c.created_at).bind(&c.created_at).bind(c.id);
+    }
+    let rows = query
+        .bind(limit.saturating_add(1))
+        .fetch_all(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(Page::from_rows(rows, limit, |r| {
+        PageCursor::new(&r.created_at, r.id)
+    }))
+}
+
 /// Get the most recent replies, newest first, with pagination.
 pub async fn get_recent_replies(
     pool: &DbPool,
@@ -223,6 +286,7 @@ mod tests {
             created_at: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
             status: "sent".to_string(),
             error_message: None,
+            conversation_id: None,
         }
     }
 
@@ -238,6 +302,38 @@ mod tests {
         assert!(!has_replied_to(&pool, "tweet_xyz").await.expect("check"));
     }
 
+    #[tokio::test]
+    async fn reply_pages_are_stable_across_new_replies() {
+        let pool = init_test_db().await.expect("init db");
+        for i in 0..3 {
+            let mut reply = sample_reply(&format!("t{i}"));
+            reply.created_at = "2026-03-02T10:00:00Z".to_string();
+            insert_reply(&pool, &reply).await.expect("insert");
+        }
+
+        let first = get_replies_page_for(&pool, DEFAULT_ACCOUNT_ID, 2, None)
+            .await
+            .expect("page 1");
+        assert_eq!(first.items.len(), 2);
+        insert_reply(&pool, &sample_reply("new"))
+            .await
+            .expect("insert");
+
+        let cursor = PageCursor::decode(first.next_cursor.as_deref().unwrap()).unwrap();
+        let second = get_replies_page_for(&pool, DEFAULT_ACCOUNT_ID, 2, Some(&cursor))
+            .await
+            .expect("page 2");
+        assert!(second.next_cursor.is_none());
+
+        let targets: Vec<&str> = first
+            .items
+            .iter()
+            .chain(&second.items)
+            .map(|r| r.target_tweet_id.as_str())
+            .collect();
+        assert_eq!(targets, ["t2", "t1", "t0"]);
+    }
+
     #[tokio::test]
     async fn count_replies_today_works() {
         let pool = init_test_db().await.expect("init db");
@@ -248,6 +344,25 @@ mod tests {
         assert_eq!(count, 1);
     }
 
+    #[tokio::test]
+    async fn count_conversation_replies_today_groups_by_conversation() {
+        let pool = init_test_db().await.expect("init db");
+        for (target, conv) in [("t1", Some("conv_a")), ("t2", Some("conv_a")), ("t3", None)] {
+            let mut reply = sample_reply(target);
+            reply.conversation_id = conv.map(String::from);
+            insert_reply(&pool, &reply).await.expect("insert");
+        }
+
+        let count = count_conversation_replies_today(&pool, "conv_a")
+            .await
+            .expect("count");
+        assert_eq!(count, 2);
+        let count = count_conversation_replies_today(&pool, "conv_b")
+            .await
+            .expect("count");
+        assert_eq!(count, 0);
+    }
+
     #[tokio::test]
     async fn get_recent_contents() {
         let pool = init_test_db().await.expect("init db");
@@ -60,14 +60,20 @@ const TABLES_TO_CLEAR: &[&str] = &[
     "action_log",
     "cursors",
     "author_interactions",
+    "author_reputation",
+    "content_rotation",
+    "content_frameworks",
+    "loop_breakers",
     "media_uploads",
     "llm_usage",
     "x_api_usage",
     "mcp_telemetry",
     "mutation_audit",
+    "mcp_idempotency_keys",
     "source_contexts",
     "sessions",
     "accounts",
+    "row_changes",
 ];
 
 /// Clear all user data from the database within a single transaction.
@@ -144,7 +150,7 @@ mod tests {
 
         // Run factory reset.
         let stats = factory_reset(&pool).await.expect("factory reset");
-        assert_eq!(stats.tables_cleared, 44);
+        assert_eq!(stats.tables_cleared, 50);
         // Migration seeds 1 account + 2 account_roles = 3 rows, plus our 4 = 7.
         assert!(stats.rows_deleted >= 7);
 
@@ -195,7 +201,7 @@ mod tests {
             .unwrap();
 
         let stats = factory_reset(&pool).await.expect("factory reset");
-        assert_eq!(stats.tables_cleared, 44);
+        assert_eq!(stats.tables_cleared, 50);
         assert_eq!(stats.rows_deleted, 2);
     }
 
@@ -205,13 +211,13 @@ mod tests {
 
         // First reset clears migration-seeded rows.
         let stats1 = factory_reset(&pool).await.expect("first reset");
-        assert_eq!(stats1.tables_cleared, 44);
+        assert_eq!(stats1.tables_cleared, 50);
         // Migration seeds 1 account + 2 account_roles = 3 rows.
         assert_eq!(stats1.rows_deleted, 3);
 
         // Second reset on now-empty DB succeeds with 0 rows.
         let stats2 = factory_reset(&pool).await.expect("second reset");
-        assert_eq!(stats2.tables_cleared, 44);
+        assert_eq!(stats2.tables_cleared, 50);
         assert_eq!(stats2.rows_deleted, 0);
     }
 
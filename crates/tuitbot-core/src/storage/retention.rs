@@ -0,0 +1,276 @@
+//! Retention enforcement for `storage.retention_days`.
+//!
+//! Purges expired activity, scored-tweet and telemetry rows in a single
+//! transaction. Sent replies back reply deduplication, so they are kept for
+//! the longer `storage.critical_retention_days` window.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::DbPool;
+use crate::error::StorageError;
+use chrono::{DateTime, Utc};
+
+/// Default retention for critical (dedup) tables, in days.
+pub const DEFAULT_CRITICAL_RETENTION_DAYS: u32 = 365;
+
+/// Rows deleted by a purge, per table.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct PurgeStats {
+    /// `action_log` rows deleted.
+    pub action_log_deleted: u64,
+    /// `discovered_tweets` (scored tweets) rows deleted.
+    pub discovered_tweets_deleted: u64,
+    /// `mcp_telemetry` rows deleted.
+    pub mcp_telemetry_deleted: u64,
+    /// `replies_sent` rows deleted (critical retention window).
+    pub replies_deleted: u64,
+    /// Total rows deleted across all tables.
+    pub total_deleted: u64,
+}
+
+/// Purge rows older than the retention windows for a specific account.
+///
+/// - `action_log`, `discovered_tweets`, `mcp_telemetry`: `retention_days`.
+/// - `replies_sent`: `critical_retention_days`, never shorter than
+///   `retention_days`.
+///
+/// A `retention_days` of 0 keeps everything and deletes nothing. All
+/// deletes run in one transaction, so a failure leaves the tables untouched.
+pub async fn purge_expired_for(
+    pool: &DbPool,
+    account_id: &str,
+    retention_days: u32,
+    critical_retention_days: u32,
+) -> Result<PurgeStats, StorageError> {
+    purge_expired_at(
+        pool,
+        account_id,
+        retention_days,
+        critical_retention_days,
+        Utc::now(),
+    )
+    .await
+}
+
+/// Purge expired rows with the default critical retention window.
+pub async fn purge_expired(pool: &DbPool, retention_days: u32) -> Result<PurgeStats, StorageError> {
+    purge_expired_for(
+        pool,
+        DEFAULT_ACCOUNT_ID,
+        retention_days,
+        DEFAULT_CRITICAL_RETENTION_DAYS,
+    )
+    .await
+}
+
+async fn purge_expired_at(
+    pool: &DbPool,
+    account_id: &str,
+    retention_days: u32,
+    critical_retention_days: u32,
+    now: DateTime<Utc>,
+) -> Result<PurgeStats, StorageError> {
+    if retention_days == 0 {
+        return Ok(PurgeStats::default());
+    }
+
+    let cutoff = cutoff_at(now, retention_days);
+    let critical_cutoff = cutoff_at(now, critical_retention_days.max(retention_days));
+
+    let mut tx = pool
+        .begin()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+
+    let action_log_deleted =
+        delete_older_than(&mut tx, "action_log", "created_at", &cutoff, account_id).await?;
+    let discovered_tweets_deleted = delete_older_than(
+        &mut tx,
+        "discovered_tweets",
+        "discovered_at",
+        &cutoff,
+        account_id,
+    )
+    .await?;
+    let mcp_telemetry_deleted =
+        delete_older_than(&mut tx, "mcp_telemetry", "created_at", &cutoff, account_id).await?;
+    let replies_deleted = delete_older_than(
+        &mut tx,
+        "replies_sent",
+        "created_at",
+        &critical_cutoff,
+        account_id,
+    )
+    .await?;
+
+    tx.commit()
+        .await
+        .map_err(|e| StorageError::Connection { source: e })?;
+
+    let stats = PurgeStats {
+        action_log_deleted,
+        discovered_tweets_deleted,
+        mcp_telemetry_deleted,
+        replies_deleted,
+        total_deleted: action_log_deleted
+            + discovered_tweets_deleted
+            + mcp_telemetry_deleted
+            + replies_deleted,
+    };
+
+    tracing::info!(
+        action_log = stats.action_log_deleted,
+        discovered_tweets = stats.discovered_tweets_deleted,
+        mcp_telemetry = stats.mcp_telemetry_deleted,
+        replies = stats.replies_deleted,
+        total = stats.total_deleted,
+        "Retention purge completed"
+    );
+
+    Ok(stats)
+}
+
+fn cutoff_at(now: DateTime<Utc>, days: u32) -> String {
+    (now - chrono::Duration::days(i64::from(days)))
+        .format("%Y-%m-%dT%H:%M:%SZ")
+        .to_string()
+}
+
+/// Table and column names are compile-time constants from this module,
+/// never user input, so the `format!` is safe from injection.
+async fn delete_older_than(
+    tx: &mut sqlx::Transaction<'_, sqlx::Sqlite>,
+    table: &str,
+    column: &str,
+    cutoff: &str,
+    account_id: &str,
+) -> Result<u64, StorageError> {
+    let query = format!("DELETE FROM {table} WHERE {column} < ? AND account_id = ?");
+    let result = sqlx::query(&query)
+        .bind(cutoff)
+        .bind(account_id)
+        .execute(&mut **tx)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    Ok(result.rows_affected())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    const OLD: &str = "2020-01-01T00:00:00Z";
+    /// Older than 90 days but inside the 365-day critical window.
+    const MID: &str = "2026-06-01T00:00:00Z";
+
+    fn now() -> DateTime<Utc> {
+        "2026-10-01T00:00:00Z".parse().unwrap()
+    }
+
+    async fn seed(pool: &DbPool, ts: &str) {
+        sqlx::query(
+            "INSERT INTO action_log (action_type, status, created_at) \
+             VALUES ('search', 'success', ?)",
+        )
+        .bind(ts)
+        .execute(pool)
+        .await
+        .expect("insert action");
+        sqlx::query(
+            "INSERT INTO discovered_tweets \
+             (id, author_id, author_username, content, discovered_at) \
+             VALUES (?, 'u1', 'user1', 'content', ?)",
+        )
+        .bind(format!("t-{ts}"))
+        .bind(ts)
+        .execute(pool)
+        .await
+        .expect("insert tweet");
+        sqlx::query(
+            "INSERT INTO mcp_telemetry (tool_name, latency_ms, created_at) \
+             VALUES ('get_stats', 5, ?)",
+        )
+        .bind(ts)
+        .execute(pool)
+        .await
+        .expect("insert telemetry");
+        sqlx::query(
+            "INSERT INTO replies_sent (target_tweet_id, reply_content, created_at) \
+             VALUES (?, 'reply', ?)",
+        )
+        .bind(format!("t-{ts}"))
+        .bind(ts)
+        .execute(pool)
+        .await
+        .expect("insert reply");
+    }
+
+    async fn count(pool: &DbPool, table: &str) -> i64 {
+        let row: (i64,) = sqlx::query_as(&format!("SELECT COUNT(*) FROM {table}"))
+            .fetch_one(pool)
+            .await
+            .expect("count");
+        row.0
+    }
+
+    #[tokio::test]
+    async fn purges_only_expired_non_critical_rows() {
+        let pool = init_test_db().await.expect("init db");
+        seed(&pool, OLD).await;
+        seed(&pool, MID).await;
+        seed(&pool, "2026-09-30T00:00:00Z").await;
+
+        let stats = purge_expired_at(&pool, DEFAULT_ACCOUNT_ID, 90, 365, now())
+            .await
+            .expect("purge");
+
+        assert_eq!(stats.action_log_deleted, 2);
+        assert_eq!(stats.discovered_tweets_deleted, 2);
+        assert_eq!(stats.mcp_telemetry_deleted, 2);
+        // Only the row past the critical window goes.
+        assert_eq!(stats.replies_deleted, 1);
+        assert_eq!(stats.total_deleted, 7);
+
+        assert_eq!(count(&pool, "action_log").await, 1);
+        assert_eq!(count(&pool, "discovered_tweets").await, 1);
+        assert_eq!(count(&pool, "mcp_telemetry").await, 1);
+        assert_eq!(count(&pool, "replies_sent").await, 2);
+    }
+
+    #[tokio::test]
+    async fn critical_window_never_shorter_than_retention() {
+        let pool = init_test_db().await.expect("init db");
+        seed(&pool, MID).await;
+
+        let stats = purge_expired_at(&pool, DEFAULT_ACCOUNT_ID, 400, 30, now())
+            .await
+            .expect("purge");
+
+        assert_eq!(stats.total_deleted, 0);
+        assert_eq!(count(&pool, "replies_sent").await, 1);
+    }
+
+    #[tokio::test]
+    async fn zero_retention_keeps_everything() {
+        let pool = init_test_db().await.expect("init db");
+        seed(&pool, OLD).await;
+
+        let stats = purge_expired(&pool, 0).await.expect("purge");
+
+        assert_eq!(stats.total_deleted, 0);
+        assert_eq!(count(&pool, "action_log").await, 1);
+    }
+
+    #[tokio::test]
+    async fn purge_is_scoped_to_account() {
+        let pool = init_test_db().await.expect("init db");
+        seed(&pool, OLD).await;
+
+        let stats = purge_expired_for(&pool, "other-account", 90, 365)
+            .await
+            .expect("purge");
+
+        assert_eq!(stats.total_deleted, 0);
+        assert_eq!(count(&pool, "discovered_tweets").await, 1);
+    }
+}
@@ -152,6 +152,60 @@ pub async fn get_recent_reports(
     get_recent_reports_for(pool, DEFAULT_ACCOUNT_ID, limit).await
 }
 
+/// Store the serialized input snapshot for a report, identified by `week_start`, for a specific account.
+pub async fn set_report_inputs_for(
+    pool: &DbPool,
+    account_id: &str,
+    week_start: &str,
+    inputs_json: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE strategy_reports SET inputs_json = ? WHERE week_start = ? AND account_id = ?",
+    )
+    .bind(inputs_json)
+    .bind(week_start)
+    .bind(account_id)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Store the serialized input snapshot for a report, identified by `week_start`.
+pub async fn set_report_inputs(
+    pool: &DbPool,
+    week_start: &str,
+    inputs_json: &str,
+) -> Result<(), StorageError> {
+    set_report_inputs_for(pool, DEFAULT_ACCOUNT_ID, week_start, inputs_json).await
+}
+
+/// Get the serialized input snapshot for a report by id for a specific account.
+///
+/// Returns `None` if the report does not exist or has no snapshot.
+pub async fn get_report_inputs_for(
+    pool: &DbPool,
+    account_id: &str,
+    report_id: i64,
+) -> Result<Option<String>, StorageError> {
+    let row: Option<(Option<String>,)> =
+        sqlx::query_as("SELECT inputs_json FROM strategy_reports WHERE id = ? AND account_id = ?")
+            .bind(report_id)
+            .bind(account_id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| StorageError::Query { source: e })?;
+    Ok(row.and_then(|(json,)| json))
+}
+
+/// Get the serialized input snapshot for a report by id.
+pub async fn get_report_inputs(
+    pool: &DbPool,
+    report_id: i64,
+) -> Result<Option<String>, StorageError> {
+    get_report_inputs_for(pool, DEFAULT_ACCOUNT_ID, report_id).await
+}
+
 /// Delete a strategy report by `week_start` for a specific account.
 pub async fn delete_strategy_report_for(
     pool: &DbPool,
@@ -266,6 +320,33 @@ mod tests {
         assert_eq!(reports[1].week_start, "2026-02-17");
     }
 
+    #[tokio::test]
+    async fn report_inputs_roundtrip() {
+        let pool = init_test_db().await.expect("init db");
+        insert_strategy_report(&pool, &sample_report("2026-02-24", "2026-03-02"))
+            .await
+            .expect("insert");
+        let id = get_strategy_report(&pool, "2026-02-24")
+            .await
+            .expect("get")
+            .expect("should exist")
+            .id;
+
+        assert!(get_report_inputs(&pool, id).await.expect("get").is_none());
+
+        set_report_inputs(&pool, "2026-02-24", r#"{"k":1}"#)
+            .await
+            .expect("set");
+        assert_eq!(
+            get_report_inputs(&pool, id).await.expect("get").as_deref(),
+            Some(r#"{"k":1}"#)
+        );
+        assert!(get_report_inputs(&pool, id + 1)
+            .await
+            .expect("get")
+            .is_none());
+    }
+
     #[tokio::test]
     async fn delete_strategy_report_works() {
         let pool = init_test_db().await.expect("init db");
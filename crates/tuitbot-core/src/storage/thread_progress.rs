@@ -0,0 +1,253 @@
+//! Thread posting progress for resuming partially posted threads.
+//!
+//! A partially posted thread keeps one `thread_tweets` row per tweet: posted
+//! tweets carry their X tweet ID, and tweets still to post have an empty or
+//! NULL `tweet_id`. Resuming reads this progress back and fills in the gaps.
+
+use super::accounts::DEFAULT_ACCOUNT_ID;
+use super::threads::{insert_thread_for, insert_thread_tweets_for, Thread, ThreadTweet};
+use super::DbPool;
+use crate::error::StorageError;
+
+/// A thread and all of its tweets, ordered by position.
+#[derive(Debug, Clone)]
+pub struct ThreadProgress {
+    /// The thread row.
+    pub thread: Thread,
+    /// Thread tweets ordered by position, posted or not.
+    pub tweets: Vec<ThreadTweet>,
+}
+
+impl ThreadProgress {
+    /// Number of tweets posted before the first unposted one.
+    pub fn posted_count(&self) -> usize {
+        self.tweets.iter().take_while(|t| is_posted(t)).count()
+    }
+
+    /// The last tweet of the posted prefix, if any.
+    pub fn last_posted(&self) -> Option<&ThreadTweet> {
+        self.posted_count()
+            .checked_sub(1)
+            .and_then(|i| self.tweets.get(i))
+    }
+
+    /// Tweets still to post, in order.
+    pub fn remaining(&self) -> &[ThreadTweet] {
+        &self.tweets[self.posted_count()..]
+    }
+
+    /// X tweet IDs of the posted prefix, in order.
+    pub fn posted_ids(&self) -> Vec<String> {
+        self.tweets[..self.posted_count()]
+            .iter()
+            .filter_map(|t| t.tweet_id.clone())
+            .collect()
+    }
+}
+
+fn is_posted(tweet: &ThreadTweet) -> bool {
+    tweet.tweet_id.as_deref().is_some_and(|id| !id.is_empty())
+}
+
+/// Persist a thread that failed partway through for a specific account.
+///
+/// `posted_ids` are the tweets already on X (a prefix of `contents`); the
+/// remaining contents are stored without a tweet ID so the thread can be
+/// resumed later. Returns the new thread ID.
+pub async fn persist_partial_thread_for(
+    pool: &DbPool,
+    account_id: &str,
+    topic: &str,
+    posted_ids: &[String],
+    contents: &[String],
+) -> Result<i64, StorageError> {
+    let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string();
+    let thread = Thread {
+        id: 0,
+        topic: topic.to_string(),
+        tweet_count: posted_ids.len() as i64,
+        root_tweet_id: posted_ids.first().cloned(),
+        created_at: now.clone(),
+        status: "partial".to_string(),
+    };
+    let thread_id = insert_thread_for(pool, account_id, &thread).await?;
+
+    let tweets: Vec<ThreadTweet> = contents
+        .iter()
+        .enumerate()
+        .map(|(i, content)| ThreadTweet {
+            id: 0,
+            thread_id,
+            position: i as i64,
+            tweet_id: posted_ids.get(i).cloned(),
+            content: content.clone(),
+            created_at: now.clone(),
+        })
+        .collect();
+    insert_thread_tweets_for(pool, account_id, thread_id, &tweets).await?;
+
+    Ok(thread_id)
+}
+
+/// Persist a thread that failed partway through.
+pub async fn persist_partial_thread(
+    pool: &DbPool,
+    topic: &str,
+    posted_ids: &[String],
+    contents: &[String],
+) -> Result<i64, StorageError> {
+    persist_partial_thread_for(pool, DEFAULT_ACCOUNT_ID, topic, posted_ids, contents).await
+}
+
+/// Load a thread and its tweets for a specific account.
+pub async fn get_thread_progress_for(
+    pool: &DbPool,
+    account_id: &str,
+    thread_id: i64,
+) -> Result<Option<ThreadProgress>, StorageError> {
+    let thread = sqlx::query_as::<_, Thread>(
+        "SELECT id, topic, tweet_count, root_tweet_id, created_at, status \
+         FROM threads WHERE id = ? AND account_id = ?",
+    )
+    .bind(thread_id)
+    .bind(account_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    let Some(thread) = thread else {
+        return Ok(None);
+    };
+
+    let tweets = sqlx::query_as::<_, ThreadTweet>(
+        "SELECT id, thread_id, position, tweet_id, content, created_at \
+         FROM thread_tweets WHERE thread_id = ? ORDER BY position ASC",
+    )
+    .bind(thread_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(Some(ThreadProgress { thread, tweets }))
+}
+
+/// Load a thread and its tweets.
+pub async fn get_thread_progress(
+    pool: &DbPool,
+    thread_id: i64,
+) -> Result<Option<ThreadProgress>, StorageError> {
+    get_thread_progress_for(pool, DEFAULT_ACCOUNT_ID, thread_id).await
+}
+
+/// Record the X tweet ID for a thread tweet once it has been posted.
+pub async fn mark_thread_tweet_posted(
+    pool: &DbPool,
+    thread_id: i64,
+    position: i64,
+    tweet_id: &str,
+) -> Result<(), StorageError> {
+    sqlx::query(
+        "UPDATE thread_tweets SET tweet_id = ?, created_at = datetime('now') \
+         WHERE thread_id = ? AND position = ?",
+    )
+    .bind(tweet_id)
+    .bind(thread_id)
+    .bind(position)
+    .execute(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+/// Update a thread's status, posted tweet count, and root tweet ID.
+pub async fn update_thread_progress(
+    pool: &DbPool,
+    thread_id: i64,
+    status: &str,
+    posted_count: usize,
+    root_tweet_id: Option<&str>,
+) -> Result<(), StorageError> {
+    sqlx::query("UPDATE threads SET status = ?, tweet_count = ?, root_tweet_id = ? WHERE id = ?")
+        .bind(status)
+        .bind(posted_count as i64)
+        .bind(root_tweet_id)
+        .bind(thread_id)
+        .execute(pool)
+        .await
+        .map_err(|e| StorageError::Query { source: e })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::init_test_db;
+
+    fn contents(n: usize) -> Vec<String> {
+        (1..=n).map(|i| format!("tweet {i}")).collect()
+    }
+
+    #[tokio::test]
+    async fn partial_thread_round_trips() {
+        let pool = init_test_db().await.expect("init db");
+        let posted = vec!["x1".to_string(), "x2".to_string()];
+        let id = persist_partial_thread(&pool, "rust", &posted, &contents(5))
+            .await
+            .expect("persist");
+
+        let progress = get_thread_progress(&pool, id)
+            .await
+            .expect("get")
+            .expect("exists");
+        assert_eq!(progress.thread.status, "partial");
+        assert_eq!(progress.thread.root_tweet_id.as_deref(), Some("x1"));
+        assert_eq!(progress.posted_count(), 2);
+        assert_eq!(progress.posted_ids(), posted);
+        assert_eq!(progress.last_posted().unwrap().content, "tweet 2");
+        let remaining: Vec<&str> = progress
+            .remaining()
+            .iter()
+            .map(|t| t.content.as_str())
+            .collect();
+        assert_eq!(remaining, ["tweet 3", "tweet 4", "tweet 5"]);
+    }
+
+    #[tokio::test]
+    async fn marking_posted_advances_progress() {
+        let pool = init_test_db().await.expect("init db");
+        let id = persist_partial_thread(&pool, "", &["x1".to_string()], &contents(3))
+            .await
+            .expect("persist");
+
+        mark_thread_tweet_posted(&pool, id, 1, "x2")
+            .await
+            .expect("mark");
+        update_thread_progress(&pool, id, "partial", 2, Some("x1"))
+            .await
+            .expect("update");
+
+        let progress = get_thread_progress(&pool, id).await.unwrap().unwrap();
+        assert_eq!(progress.posted_count(), 2);
+        assert_eq!(progress.thread.tweet_count, 2);
+    }
+
+    #[tokio::test]
+    async fn empty_tweet_id_counts_as_unposted() {
+        let pool = init_test_db().await.expect("init db");
+        let id = persist_partial_thread(&pool, "", &[], &contents(2))
+            .await
+            .expect("persist");
+        mark_thread_tweet_posted(&pool, id, 0, "").await.unwrap();
+
+        let progress = get_thread_progress(&pool, id).await.unwrap().unwrap();
+        assert_eq!(progress.posted_count(), 0);
+        assert!(progress.last_posted().is_none());
+        assert_eq!(progress.remaining().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn unknown_thread_is_none() {
+        let pool = init_test_db().await.expect("init db");
+        assert!(get_thread_progress(&pool, 999).await.unwrap().is_none());
+    }
+}
@@ -314,6 +314,29 @@ pub async fn get_todays_tweet_times(pool: &DbPool) -> Result<Vec<String>, Storag
     get_todays_tweet_times_for(pool, DEFAULT_ACCOUNT_ID).await
 }
 
+/// Get each topic's most recent successfully posted original tweet time for a specific account.
+///
+/// Returns `(topic, created_at)` pairs; tweets without a topic are ignored.
+pub async fn get_topic_last_used_for(
+    pool: &DbPool,
+    account_id: &str,
+) -> Result<Vec<(String, String)>, StorageError> {
+    sqlx::query_as(
+        "SELECT topic, MAX(created_at) FROM original_tweets \
+         WHERE account_id = ? AND status = 'sent' AND topic IS NOT NULL \
+         GROUP BY topic",
+    )
+    .bind(account_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })
+}
+
+/// Get each topic's most recent successfully posted original tweet time.
+pub async fn get_topic_last_used(pool: &DbPool) -> Result<Vec<(String, String)>, StorageError> {
+    get_topic_last_used_for(pool, DEFAULT_ACCOUNT_ID).await
+}
+
 /// Count threads posted in the current ISO week (Monday-Sunday, UTC) for a specific account.
 pub async fn count_threads_this_week_for(
     pool: &DbPool,
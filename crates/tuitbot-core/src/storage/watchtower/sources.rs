@@ -247,6 +247,51 @@ pub async fn ensure_google_drive_source(
     ensure_google_drive_source_for(pool, DEFAULT_ACCOUNT_ID, folder_id, config_json).await
 }
 
+/// Find a source context by Notion database ID for a specific account.
+pub async fn find_source_by_database_id_for(
+    pool: &DbPool,
+    account_id: &str,
+    database_id: &str,
+) -> Result<Option<SourceContext>, StorageError> {
+    let row: Option<SourceContextRow> = sqlx::query_as(
+        "SELECT id, account_id, source_type, config_json, sync_cursor, \
+                    status, error_message, created_at, updated_at \
+             FROM source_contexts \
+             WHERE account_id = ? AND source_type = 'notion' AND status = 'active' \
+               AND config_json LIKE '%' || ? || '%' \
+             LIMIT 1",
+    )
+    .bind(account_id)
+    .bind(database_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| StorageError::Query { source: e })?;
+
+    Ok(row.map(SourceContext::from_row))
+}
+
+/// Ensure a "notion" source context exists for a specific account, returning its ID.
+pub async fn ensure_notion_source_for(
+    pool: &DbPool,
+    account_id: &str,
+    database_id: &str,
+    config_json: &str,
+) -> Result<i64, StorageError> {
+    if let Some(ctx) = find_source_by_database_id_for(pool, account_id, database_id).await? {
+        return Ok(ctx.id);
+    }
+    insert_source_context_for(pool, account_id, "notion", config_json).await
+}
+
+/// Ensure a "notion" source context exists for the given database ID, returning its ID.
+pub async fn ensure_notion_source(
+    pool: &DbPool,
+    database_id: &str,
+    config_json: &str,
+) -> Result<i64, StorageError> {
+    ensure_notion_source_for(pool, DEFAULT_ACCOUNT_ID, database_id, config_json).await
+}
+
 /// Get all source contexts for a specific account regardless of status.
 pub async fn get_all_source_contexts_for(
     pool: &DbPool,
@@ -109,6 +109,32 @@ async fn ensure_google_drive_source_different_folders_differ() {
     assert_ne!(id1, id2);
 }
 
+#[tokio::test]
+async fn ensure_notion_source_creates_and_dedup() {
+    let pool = init_test_db().await.expect("init db");
+
+    let config = r#"{"database_id":"db-123"}"#;
+    let id1 = ensure_notion_source(&pool, "db-123", config)
+        .await
+        .expect("first ensure");
+    let id2 = ensure_notion_source(&pool, "db-123", config)
+        .await
+        .expect("second ensure");
+    assert_eq!(id1, id2, "same database_id should return same source");
+
+    // A Drive folder with the same ID is a different source.
+    let drive = ensure_google_drive_source(&pool, "db-123", r#"{"folder_id":"db-123"}"#)
+        .await
+        .expect("drive");
+    assert_ne!(id1, drive);
+
+    let ctx = get_source_context(&pool, id1)
+        .await
+        .expect("get")
+        .expect("exists");
+    assert_eq!(ctx.source_type, "notion");
+}
+
 // ============================================================================
 // Sources — find_source_by_path
 // ============================================================================
@@ -0,0 +1,103 @@
+//! Strategy input snapshots — everything a weekly report's recommendations
+//! were derived from, captured when the report is computed.
+//!
+//! The snapshot is stored alongside the report row (`inputs_json`) so a past
+//! recommendation can be explained and reproduced even after the config,
+//! target list, or underlying metrics have changed.
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+use crate::config::{BusinessProfile, Config, ScoringConfig};
+use crate::error::StorageError;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::{strategy, target_accounts, DbPool};
+
+use super::recommendations::WeekMetrics;
+
+/// Inputs to a single strategy computation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategySnapshot {
+    /// ISO-8601 UTC timestamp when the report was computed.
+    pub captured_at: String,
+    /// Scoring weights and threshold in effect.
+    pub scoring: ScoringConfig,
+    /// Active business profile (pillars, keywords, topics, voice).
+    pub business: BusinessProfile,
+    /// Week metrics fed to the recommendation engine.
+    pub metrics: WeekMetrics,
+    /// `week_start` of the previous report used for week-over-week rules, if any.
+    pub previous_week_start: Option<String>,
+    /// Usernames of active target accounts.
+    pub target_accounts: Vec<String>,
+}
+
+impl StrategySnapshot {
+    /// Capture a snapshot for one account from the config and computed metrics.
+    pub async fn capture_for(
+        pool: &DbPool,
+        config: &Config,
+        account_id: &str,
+        metrics: &WeekMetrics,
+        previous_week_start: Option<String>,
+    ) -> Result<Self, StorageError> {
+        let targets = target_accounts::get_active_target_accounts_for(pool, account_id).await?;
+        Ok(Self {
+            captured_at: Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+            scoring: config.scoring.clone(),
+            business: config.business.clone(),
+            metrics: metrics.clone(),
+            previous_week_start,
+            target_accounts: targets.into_iter().map(|t| t.username).collect(),
+        })
+    }
+}
+
+/// Persist a snapshot on the report for `week_start` for a specific account.
+pub async fn save_snapshot_for(
+    pool: &DbPool,
+    account_id: &str,
+    week_start: &str,
+    snapshot: &StrategySnapshot,
+) -> Result<(), StorageError> {
+    let json = serde_json::to_string(snapshot).unwrap_or_else(|_| "{}".to_string());
+    strategy::set_report_inputs_for(pool, account_id, week_start, &json).await
+}
+
+/// Persist a snapshot on the report for `week_start`.
+pub async fn save_snapshot(
+    pool: &DbPool,
+    week_start: &str,
+    snapshot: &StrategySnapshot,
+) -> Result<(), StorageError> {
+    save_snapshot_for(pool, DEFAULT_ACCOUNT_ID, week_start, snapshot).await
+}
+
+/// Load the snapshot stored with report `report_id` for a specific account.
+///
+/// Returns `None` if the report does not exist, predates snapshots, or the
+/// stored JSON no longer matches the snapshot shape.
+pub async fn load_snapshot_for(
+    pool: &DbPool,
+    account_id: &str,
+    report_id: i64,
+) -> Result<Option<StrategySnapshot>, StorageError> {
+    let Some(json) = strategy::get_report_inputs_for(pool, account_id, report_id).await? else {
+        return Ok(None);
+    };
+    match serde_json::from_str(&json) {
+        Ok(snapshot) => Ok(Some(snapshot)),
+        Err(e) => {
+            tracing::warn!(report_id, error = %e, "Unreadable strategy input snapshot");
+            Ok(None)
+        }
+    }
+}
+
+/// Load the snapshot stored with report `report_id`.
+pub async fn load_snapshot(
+    pool: &DbPool,
+    report_id: i64,
+) -> Result<Option<StrategySnapshot>, StorageError> {
+    load_snapshot_for(pool, DEFAULT_ACCOUNT_ID, report_id).await
+}
@@ -0,0 +1,265 @@
+//! Heuristic keyword expansion from engaged discovery content.
+//!
+//! Tokenizes high-scoring discovered tweets, drops stopwords and terms the
+//! user already tracks, and ranks the remaining tokens by how often they
+//! appear alongside configured keywords. No LLM involved.
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::storage::tweets::DiscoveredTweet;
+use crate::toolkit::profile_inference::is_stopword;
+
+/// Fewest analyzed tweets needed before suggestions are attempted.
+pub const MIN_TWEETS: usize = 5;
+
+/// A token must appear in at least this many tweets to be suggested.
+const MIN_TWEET_FREQUENCY: usize = 2;
+
+/// A suggested keyword with the evidence behind it.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct KeywordSuggestion {
+    /// Lowercase suggested term.
+    pub keyword: String,
+    /// Number of analyzed tweets in which the term co-occurs with at least
+    /// one configured keyword. Primary ranking key.
+    pub co_occurrences: usize,
+    /// Number of analyzed tweets containing the term.
+    pub tweet_count: usize,
+    /// Configured keywords the term appeared alongside, sorted.
+    pub related_to: Vec<String>,
+}
+
+/// Result of a suggestion run.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct KeywordSuggestions {
+    pub suggestions: Vec<KeywordSuggestion>,
+    /// Number of tweets the analysis ran over.
+    pub analyzed_tweets: usize,
+    /// Explanation when no suggestions could be produced.
+    pub note: Option<String>,
+}
+
+#[derive(Default)]
+struct TokenStats {
+    tweet_count: usize,
+    co_occurrences: usize,
+    related_to: BTreeSet<String>,
+}
+
+/// Suggest up to `limit` keywords related to `existing`, mined from `tweets`.
+///
+/// Callers pass recent high-scoring tweets; only tokens that co-occur with a
+/// configured keyword in at least [`MIN_TWEET_FREQUENCY`] tweets qualify.
+/// Ranking: co-occurrences, then tweet frequency, then alphabetical.
+pub fn suggest(
+    existing: &[String],
+    tweets: &[DiscoveredTweet],
+    limit: usize,
+) -> KeywordSuggestions {
+    let existing: Vec<String> = existing
+        .iter()
+        .map(|k| k.trim().to_lowercase())
+        .filter(|k| !k.is_empty())
+        .collect();
+
+    if existing.is_empty() {
+        return empty(
+            tweets.len(),
+            "No keywords configured; add product or competitor keywords first.",
+        );
+    }
+    if tweets.len() < MIN_TWEETS {
+        return empty(
+            tweets.len(),
+            &format!(
+                "Not enough engaged tweets to analyze ({} found, need at least {MIN_TWEETS}).",
+                tweets.len()
+            ),
+        );
+    }
+
+    // Words that are already covered by a configured keyword (including the
+    // parts of multi-word keywords) are never suggested.
+    let covered: BTreeSet<&str> = existing
+        .iter()
+        .flat_map(|k| k.split_whitespace())
+        .chain(existing.iter().map(String::as_str))
+        .collect();
+
+    let mut stats: HashMap<String, TokenStats> = HashMap::new();
+    for tweet in tweets {
+        let text = tweet.content.to_lowercase();
+        let matched: Vec<&String> = existing.iter().filter(|k| text.contains(*k)).collect();
+
+        for token in tokens(&text) {
+            if covered.contains(token.as_str()) {
+                continue;
+            }
+            let entry = stats.entry(token).or_default();
+            entry.tweet_count += 1;
+            if !matched.is_empty() {
+                entry.co_occurrences += 1;
+                entry
+                    .related_to
+                    .extend(matched.iter().map(|k| k.to_string()));
+            }
+        }
+    }
+
+    let mut suggestions: Vec<KeywordSuggestion> = stats
+        .into_iter()
+        .filter(|(_, s)| s.co_occurrences > 0 && s.tweet_count >= MIN_TWEET_FREQUENCY)
+        .map(|(keyword, s)| KeywordSuggestion {
+            keyword,
+            co_occurrences: s.co_occurrences,
+            tweet_count: s.tweet_count,
+            related_to: s.related_to.into_iter().collect(),
+        })
+        .collect();
+    suggestions.sort_by(|a, b| {
+        b.co_occurrences
+            .cmp(&a.co_occurrences)
+            .then(b.tweet_count.cmp(&a.tweet_count))
+            .then(a.keyword.cmp(&b.keyword))
+    });
+    suggestions.truncate(limit);
+
+    let note = suggestions
+        .is_empty()
+        .then(|| "No recurring terms co-occur with your keywords yet.".to_string());
+
+    KeywordSuggestions {
+        suggestions,
+        analyzed_tweets: tweets.len(),
+        note,
+    }
+}
+
+fn empty(analyzed_tweets: usize, note: &str) -> KeywordSuggestions {
+    KeywordSuggestions {
+        suggestions: Vec::new(),
+        analyzed_tweets,
+        note: Some(note.to_string()),
+    }
+}
+
+/// Distinct candidate tokens in already-lowercased text: hashtags are kept
+/// without `#`; mentions, URLs, numbers, stopwords, and words shorter than
+/// four characters are dropped.
+fn tokens(text: &str) -> BTreeSet<String> {
+    text.split_whitespace()
+        .filter(|w| !w.starts_with('@') && !w.starts_with("http"))
+        .map(|w| w.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|w| {
+            w.chars().count() >= 4 && !w.chars().all(|c| c.is_ascii_digit()) && !is_stopword(w)
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tweet(id: &str, content: &str) -> DiscoveredTweet {
+        DiscoveredTweet {
+            id: id.to_string(),
+            author_id: "a1".to_string(),
+            author_username: "someone".to_string(),
+            content: content.to_string(),
+            like_count: 10,
+            retweet_count: 1,
+            reply_count: 1,
+            impression_count: None,
+            relevance_score: Some(80.0),
+            matched_keyword: None,
+            discovered_at: "2026-01-01T00:00:00Z".to_string(),
+            replied_to: 0,
+        }
+    }
+
+    fn corpus() -> Vec<DiscoveredTweet> {
+        vec![
+            tweet(
+                "1",
+                "Rust async runtimes: tokio is still the default choice",
+            ),
+            tweet(
+                "2",
+                "Moved our CLI to rust and tokio, compile times went up",
+            ),
+            tweet("3", "Borrow checker fights again. #rust #tokio"),
+            tweet(
+                "4",
+                "Writing a CLI in rust with clap made argument parsing easy",
+            ),
+            tweet("5", "Gardening update: tomatoes are finally ripe @neighbor"),
+            tweet(
+                "6",
+                "Tomatoes and basil from the gardening bed https://x.co/abc",
+            ),
+        ]
+    }
+
+    fn keywords() -> Vec<String> {
+        vec!["rust".to_string(), "cli".to_string()]
+    }
+
+    #[test]
+    fn ranks_by_co_occurrence_with_existing_keywords() {
+        let result = suggest(&keywords(), &corpus(), 10);
+
+        assert_eq!(result.analyzed_tweets, 6);
+        assert!(result.note.is_none());
+        let first = &result.suggestions[0];
+        assert_eq!(first.keyword, "tokio");
+        assert_eq!(first.co_occurrences, 3);
+        assert_eq!(first.related_to, vec!["cli", "rust"]);
+    }
+
+    #[test]
+    fn excludes_unrelated_existing_and_filtered_tokens() {
+        let result = suggest(&keywords(), &corpus(), 10);
+        let words: Vec<&str> = result
+            .suggestions
+            .iter()
+            .map(|s| s.keyword.as_str())
+            .collect();
+
+        // Frequent but never alongside a configured keyword.
+        assert!(!words.contains(&"tomatoes"));
+        assert!(!words.contains(&"gardening"));
+        // Already configured, stopwords, and single-occurrence terms.
+        assert!(!words.contains(&"rust"));
+        assert!(!words.contains(&"still"));
+        assert!(!words.contains(&"clap"));
+    }
+
+    #[test]
+    fn respects_limit() {
+        let result = suggest(&keywords(), &corpus(), 1);
+        assert_eq!(result.suggestions.len(), 1);
+    }
+
+    #[test]
+    fn insufficient_data_returns_empty_with_note() {
+        let result = suggest(&keywords(), &corpus()[..2], 10);
+        assert!(result.suggestions.is_empty());
+        assert_eq!(result.analyzed_tweets, 2);
+        assert!(result.note.unwrap().contains("Not enough engaged tweets"));
+    }
+
+    #[test]
+    fn no_configured_keywords_returns_note() {
+        let result = suggest(&[], &corpus(), 10);
+        assert!(result.suggestions.is_empty());
+        assert!(result.note.unwrap().contains("No keywords configured"));
+    }
+
+    #[test]
+    fn tokens_strip_hashtags_mentions_and_urls() {
+        let toks = tokens("#tokio rocks @dev https://example.com 2026 the async!");
+        let toks: Vec<&str> = toks.iter().map(String::as_str).collect();
+        assert_eq!(toks, vec!["async", "rocks", "tokio"]);
+    }
+}
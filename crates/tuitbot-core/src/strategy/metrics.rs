@@ -5,6 +5,7 @@
 //! with date bounds. No new data collection is needed.
 
 use crate::error::StorageError;
+use crate::storage::analytics::is_low_confidence;
 use crate::storage::DbPool;
 
 /// Action counts for a date range.
@@ -23,6 +24,10 @@ pub struct TopicPerformance {
     pub format: String,
     pub avg_score: f64,
     pub post_count: i64,
+    /// True when `post_count` is below `analytics.min_engagement_sample`.
+    /// Shown in reports but never turned into a recommendation.
+    #[serde(default)]
+    pub low_confidence: bool,
 }
 
 /// A top-performing content item.
@@ -152,11 +157,14 @@ pub async fn reply_acceptance_rate(
 }
 
 /// Top topics by average performance score in a date range.
+///
+/// Topics with fewer than `min_sample` posts are kept but flagged `low_confidence`.
 pub async fn top_topics_in_range(
     pool: &DbPool,
     start: &str,
     end: &str,
     limit: u32,
+    min_sample: u32,
 ) -> Result<Vec<TopicPerformance>, StorageError> {
     let rows: Vec<(String, String, f64, i64)> = sqlx::query_as(
         "SELECT ot.topic, COALESCE(ot.topic, '') as format, \
@@ -183,16 +191,20 @@ pub async fn top_topics_in_range(
             format: r.1,
             avg_score: r.2,
             post_count: r.3,
+            low_confidence: is_low_confidence(r.3, min_sample),
         })
         .collect())
 }
 
-/// Bottom topics by average performance score in a date range (minimum 3 posts).
+/// Bottom topics by average performance score in a date range.
+///
+/// Only topics with at least `min_sample` posts qualify.
 pub async fn bottom_topics_in_range(
     pool: &DbPool,
     start: &str,
     end: &str,
     limit: u32,
+    min_sample: u32,
 ) -> Result<Vec<TopicPerformance>, StorageError> {
     let rows: Vec<(String, String, f64, i64)> = sqlx::query_as(
         "SELECT ot.topic, COALESCE(ot.topic, '') as format, \
@@ -201,12 +213,13 @@ pub async fn bottom_topics_in_range(
          JOIN original_tweets ot ON ot.tweet_id = tp.tweet_id \
          WHERE ot.created_at >= ? AND ot.created_at < ? AND ot.topic IS NOT NULL \
          GROUP BY ot.topic \
-         HAVING post_count >= 3 \
+         HAVING post_count >= ? \
          ORDER BY avg_score ASC \
          LIMIT ?",
     )
     .bind(start)
     .bind(end)
+    .bind(min_sample)
     .bind(limit)
     .fetch_all(pool)
     .await
@@ -219,6 +232,7 @@ pub async fn bottom_topics_in_range(
             format: r.1,
             avg_score: r.2,
             post_count: r.3,
+            low_confidence: is_low_confidence(r.3, min_sample),
         })
         .collect())
 }
@@ -422,12 +436,15 @@ pub async fn reply_acceptance_rate_for(
 }
 
 /// Top topics by performance score in a date range, scoped to one account.
+///
+/// Topics with fewer than `min_sample` posts are kept but flagged `low_confidence`.
 pub async fn top_topics_in_range_for(
     pool: &DbPool,
     account_id: &str,
     start: &str,
     end: &str,
     limit: u32,
+    min_sample: u32,
 ) -> Result<Vec<TopicPerformance>, StorageError> {
     let rows: Vec<(String, String, f64, i64)> = sqlx::query_as(
         "SELECT ot.topic, COALESCE(ot.topic, '') as format, \
@@ -456,17 +473,21 @@ pub async fn top_topics_in_range_for(
             format: r.1,
             avg_score: r.2,
             post_count: r.3,
+            low_confidence: is_low_confidence(r.3, min_sample),
         })
         .collect())
 }
 
 /// Bottom topics by performance score in a date range, scoped to one account.
+///
+/// Only topics with at least `min_sample` posts qualify.
 pub async fn bottom_topics_in_range_for(
     pool: &DbPool,
     account_id: &str,
     start: &str,
     end: &str,
     limit: u32,
+    min_sample: u32,
 ) -> Result<Vec<TopicPerformance>, StorageError> {
     let rows: Vec<(String, String, f64, i64)> = sqlx::query_as(
         "SELECT ot.topic, COALESCE(ot.topic, '') as format, \
@@ -476,13 +497,14 @@ pub async fn bottom_topics_in_range_for(
          WHERE ot.created_at >= ? AND ot.created_at < ? AND ot.topic IS NOT NULL \
          AND ot.account_id = ? \
          GROUP BY ot.topic \
-         HAVING post_count >= 3 \
+         HAVING post_count >= ? \
          ORDER BY avg_score ASC \
          LIMIT ?",
     )
     .bind(start)
     .bind(end)
     .bind(account_id)
+    .bind(min_sample)
     .bind(limit)
     .fetch_all(pool)
     .await
@@ -495,6 +517,7 @@ pub async fn bottom_topics_in_range_for(
             format: r.1,
             avg_score: r.2,
             post_count: r.3,
+            low_confidence: is_low_confidence(r.3, min_sample),
         })
         .collect())
 }
@@ -616,12 +639,76 @@ mod tests {
     #[tokio::test]
     async fn top_topics_empty() {
         let pool = init_test_db().await.expect("init db");
-        let topics = top_topics_in_range(&pool, "2026-01-01T00:00:00Z", "2026-12-31T23:59:59Z", 5)
-            .await
-            .expect("topics");
+        let topics =
+            top_topics_in_range(&pool, "2026-01-01T00:00:00Z", "2026-12-31T23:59:59Z", 5, 3)
+                .await
+                .expect("topics");
         assert!(topics.is_empty());
     }
 
+    /// Three "rust" posts and one high-scoring "go" post in March 2026.
+    async fn seed_topics(pool: &DbPool) {
+        let seeds = [
+            ("t-1", "rust", 10.0),
+            ("t-2", "rust", 20.0),
+            ("t-3", "rust", 30.0),
+            ("t-4", "go", 90.0),
+        ];
+        for (id, topic, score) in seeds {
+            sqlx::query(
+                "INSERT INTO original_tweets (tweet_id, content, topic, status, created_at) \
+                 VALUES (?, 'tweet', ?, 'sent', '2026-03-02T10:00:00Z')",
+            )
+            .bind(id)
+            .bind(topic)
+            .execute(pool)
+            .await
+            .expect("insert tweet");
+            crate::storage::analytics::upsert_tweet_performance(pool, id, 1, 1, 1, 100, score)
+                .await
+                .expect("perf");
+        }
+    }
+
+    #[tokio::test]
+    async fn topics_below_min_sample_are_low_confidence() {
+        let pool = init_test_db().await.expect("init db");
+        seed_topics(&pool).await;
+        let (start, end) = ("2026-03-01T00:00:00Z", "2026-04-01T00:00:00Z");
+
+        let top = top_topics_in_range(&pool, start, end, 5, 3)
+            .await
+            .expect("top");
+        let flags: Vec<(&str, bool)> = top
+            .iter()
+            .map(|t| (t.topic.as_str(), t.low_confidence))
+            .collect();
+        assert_eq!(flags, vec![("go", true), ("rust", false)]);
+
+        let bottom = bottom_topics_in_range(&pool, start, end, 5, 3)
+            .await
+            .expect("bottom");
+        assert_eq!(bottom.len(), 1);
+        assert_eq!(bottom[0].topic, "rust");
+    }
+
+    #[tokio::test]
+    async fn topics_at_min_sample_are_signal() {
+        let pool = init_test_db().await.expect("init db");
+        seed_topics(&pool).await;
+        let (start, end) = ("2026-03-01T00:00:00Z", "2026-04-01T00:00:00Z");
+
+        let top = top_topics_in_range(&pool, start, end, 5, 1)
+            .await
+            .expect("top");
+        assert!(top.iter().all(|t| !t.low_confidence));
+
+        let bottom = bottom_topics_in_range(&pool, start, end, 5, 1)
+            .await
+            .expect("bottom");
+        assert_eq!(bottom.len(), 2);
+    }
+
     #[tokio::test]
     async fn top_content_empty() {
         let pool = init_test_db().await.expect("init db");
@@ -1,5 +1,9 @@
-//! Strategy layer — weekly report engine with metrics, recommendations, and report computation.
+//! Strategy layer — weekly report engine with metrics, recommendations, report computation,
+//! input snapshots, discovery keyword suggestions, and actionable next steps.
 
+pub mod inputs;
+pub mod keyword_suggestions;
 pub mod metrics;
+pub mod next_steps;
 pub mod recommendations;
 pub mod report;
@@ -0,0 +1,61 @@
+//! Keyword step: track terms that recur in the best discovered tweets.
+
+use serde_json::json;
+
+use super::{NextStep, NextStepInputs, NextStepKind};
+use crate::strategy::keyword_suggestions;
+
+/// Most keywords suggested in one step.
+const MAX_NEW_KEYWORDS: usize = 3;
+
+pub(super) fn keyword_step(inputs: &NextStepInputs<'_>) -> Option<NextStep> {
+    let business = &inputs.config.business;
+    let existing: Vec<String> = business
+        .product_keywords
+        .iter()
+        .chain(business.competitor_keywords.iter())
+        .cloned()
+        .collect();
+    let found = keyword_suggestions::suggest(&existing, inputs.engaged_tweets, MAX_NEW_KEYWORDS);
+    if found.suggestions.is_empty() {
+        return None;
+    }
+
+    let new: Vec<String> = found
+        .suggestions
+        .iter()
+        .map(|s| s.keyword.clone())
+        .collect();
+    let evidence: Vec<String> = found
+        .suggestions
+        .iter()
+        .map(|s| {
+            format!(
+                "\"{}\" ({} tweets alongside {})",
+                s.keyword,
+                s.co_occurrences,
+                s.related_to.join(", ")
+            )
+        })
+        .collect();
+    let mut keywords = business.product_keywords.clone();
+    keywords.extend(new.iter().cloned());
+
+    Some(NextStep {
+        kind: NextStepKind::AddKeywords,
+        title: format!("Track {}", quoted_list(&new)),
+        rationale: format!(
+            "These terms keep showing up in your best discovered tweets: {}.",
+            evidence.join("; ")
+        ),
+        apply: json!({ "business": { "product_keywords": keywords } }),
+    })
+}
+
+fn quoted_list(items: &[String]) -> String {
+    items
+        .iter()
+        .map(|i| format!("\"{i}\""))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,60 @@
+//! Limits step: raise or lower daily caps that were saturated this week.
+
+use serde_json::json;
+
+use super::{NextStep, NextStepInputs, NextStepKind};
+
+/// Share of weekly capacity at which a limit counts as saturated.
+const SATURATED_SHARE: f64 = 0.9;
+
+pub(super) fn limits_step(inputs: &NextStepInputs<'_>) -> Option<NextStep> {
+    let m = inputs.metrics;
+    let limits = &inputs.config.limits;
+    let saturated =
+        |sent: i64, capacity: i64| capacity > 0 && sent as f64 >= capacity as f64 * SATURATED_SHARE;
+
+    let mut patch = serde_json::Map::new();
+    let mut reasons = Vec::new();
+
+    if saturated(m.replies_sent, m.max_replies_per_week) && m.replies_sent > 0 {
+        let current = limits.max_replies_per_day;
+        if m.reply_acceptance_rate >= 0.20 {
+            let raised = current + (current / 5).max(1);
+            patch.insert("max_replies_per_day".to_string(), json!(raised));
+            reasons.push(format!(
+                "replies hit the daily cap with {:.0}% getting a response, so raise it \
+                 from {current} to {raised}",
+                m.reply_acceptance_rate * 100.0
+            ));
+        } else if m.reply_acceptance_rate < 0.10 && current > 1 {
+            let lowered = (current - (current / 4).max(1)).max(1);
+            patch.insert("max_replies_per_day".to_string(), json!(lowered));
+            reasons.push(format!(
+                "replies hit the daily cap but only {:.0}% got a response, so lower it \
+                 from {current} to {lowered} and favour quality",
+                m.reply_acceptance_rate * 100.0
+            ));
+        }
+    }
+
+    if saturated(m.tweets_posted, m.max_tweets_per_week) && m.follower_delta > 0 {
+        let current = limits.max_tweets_per_day;
+        let raised = current + 1;
+        patch.insert("max_tweets_per_day".to_string(), json!(raised));
+        reasons.push(format!(
+            "tweets hit the daily cap while followers grew by {}, so raise it from \
+             {current} to {raised}",
+            m.follower_delta
+        ));
+    }
+
+    if patch.is_empty() {
+        return None;
+    }
+    Some(NextStep {
+        kind: NextStepKind::AdjustLimits,
+        title: "Adjust posting limits".to_string(),
+        rationale: format!("This week {}.", reasons.join("; ")),
+        apply: json!({ "limits": patch }),
+    })
+}
@@ -0,0 +1,152 @@
+//! Actionable next steps derived from the strategy inputs.
+//!
+//! Where the weekly report says how things went, these say what to change:
+//! keywords to add, posting hours to adopt, targets to add or drop, and
+//! limits to adjust. Each step carries the `PATCH /api/settings` body that
+//! applies it, so the dashboard can offer it as a one-click action.
+//!
+//! Each step lives in its own submodule; [`gather_inputs_for`] loads the
+//! data they are derived from.
+
+mod keywords;
+mod limits;
+mod posting_times;
+mod targets;
+
+#[cfg(test)]
+mod tests;
+
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde_json::Value;
+
+use super::recommendations::WeekMetrics;
+use crate::config::Config;
+use crate::content::scheduling::OPTIMAL_HOURS_PER_DAY;
+use crate::error::StorageError;
+use crate::storage::analytics::{get_optimal_times_by_weekday_for, WeekdayOptimalHours};
+use crate::storage::target_accounts::{get_active_target_accounts_for, TargetAccount};
+use crate::storage::tweets::{get_discovery_feed_for, DiscoveredTweet};
+use crate::storage::DbPool;
+
+/// Minimum relevance score for a discovered tweet to count as engaged content.
+const ENGAGED_TWEET_MIN_SCORE: f64 = 70.0;
+
+/// How many recent engaged tweets keyword and target steps are mined from.
+const ENGAGED_TWEET_SAMPLE: u32 = 200;
+
+/// What a step changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NextStepKind {
+    AddKeywords,
+    AdoptPostingTimes,
+    AddTargets,
+    DropTargets,
+    AdjustLimits,
+}
+
+/// A concrete change with the reason for it and the patch that applies it.
+#[derive(Debug, Clone, PartialEq, serde::Serialize)]
+pub struct NextStep {
+    pub kind: NextStepKind,
+    /// Short headline.
+    pub title: String,
+    /// Why the change is suggested, citing the numbers behind it.
+    pub rationale: String,
+    /// Body for `PATCH /api/settings` that applies the step.
+    pub apply: Value,
+}
+
+/// Everything the next steps are derived from.
+pub struct NextStepInputs<'a> {
+    /// Effective config the steps are measured against and patch.
+    pub config: &'a Config,
+    /// Current week's metrics.
+    pub metrics: &'a WeekMetrics,
+    /// Recent high-scoring discovered tweets.
+    pub engaged_tweets: &'a [DiscoveredTweet],
+    /// Best local posting hours per weekday.
+    pub optimal_times: &'a [WeekdayOptimalHours],
+    /// Active target accounts with their reply history.
+    pub targets: &'a [TargetAccount],
+    /// Reference time for staleness checks.
+    pub now: DateTime<Utc>,
+}
+
+/// Derive next steps from `inputs`, in a fixed order: keywords, posting
+/// times, target additions, target removals, limits.
+pub fn recommendations(inputs: &NextStepInputs<'_>) -> Vec<NextStep> {
+    [
+        keywords::keyword_step(inputs),
+        posting_times::posting_times_step(inputs),
+        targets::add_targets_step(inputs),
+        targets::drop_targets_step(inputs),
+        limits::limits_step(inputs),
+    ]
+    .into_iter()
+    .flatten()
+    .collect()
+}
+
+/// Owned data behind [`NextStepInputs`], loaded for one account.
+pub struct GatheredInputs {
+    pub metrics: WeekMetrics,
+    pub engaged_tweets: Vec<DiscoveredTweet>,
+    pub optimal_times: Vec<WeekdayOptimalHours>,
+    pub targets: Vec<TargetAccount>,
+    pub now: DateTime<Utc>,
+}
+
+impl GatheredInputs {
+    /// Borrow the gathered data as step inputs measured against `config`.
+    pub fn inputs<'a>(&'a self, config: &'a Config) -> NextStepInputs<'a> {
+        NextStepInputs {
+            config,
+            metrics: &self.metrics,
+            engaged_tweets: &self.engaged_tweets,
+            optimal_times: &self.optimal_times,
+            targets: &self.targets,
+            now: self.now,
+        }
+    }
+}
+
+/// Load everything the next steps for `account_id` are derived from: this
+/// week's metrics, recent engaged tweets, per-weekday best hours in the
+/// schedule timezone, and active targets.
+pub async fn gather_inputs_for(
+    pool: &DbPool,
+    account_id: &str,
+    config: &Config,
+) -> Result<GatheredInputs, StorageError> {
+    let now = Utc::now();
+    let (_, snapshot) =
+        super::report::compute_report_with_inputs_for(pool, config, account_id, now.date_naive())
+            .await?;
+    let engaged_tweets = get_discovery_feed_for(
+        pool,
+        account_id,
+        ENGAGED_TWEET_MIN_SCORE,
+        ENGAGED_TWEET_SAMPLE,
+    )
+    .await?;
+    let tz: Tz = config.schedule.timezone.parse().unwrap_or(chrono_tz::UTC);
+    let optimal_times = get_optimal_times_by_weekday_for(
+        pool,
+        account_id,
+        tz,
+        OPTIMAL_HOURS_PER_DAY,
+        config.analytics.min_engagement_sample,
+    )
+    .await?;
+    let targets = get_active_target_accounts_for(pool, account_id).await?;
+
+    Ok(GatheredInputs {
+        metrics: snapshot.metrics,
+        engaged_tweets,
+        optimal_times,
+        targets,
+        now,
+    })
+}
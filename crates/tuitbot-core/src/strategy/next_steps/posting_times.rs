@@ -0,0 +1,51 @@
+//! Posting-times step: adopt the account's best-performing hours per weekday.
+
+use std::collections::BTreeMap;
+
+use serde_json::json;
+
+use super::{NextStep, NextStepInputs, NextStepKind};
+
+/// Fewest sent posts in the per-weekday history before hours are suggested.
+const MIN_TIME_HISTORY_POSTS: i64 = 10;
+
+pub(super) fn posting_times_step(inputs: &NextStepInputs<'_>) -> Option<NextStep> {
+    let total_posts: i64 = inputs.optimal_times.iter().map(|d| d.post_count).sum();
+    if total_posts < MIN_TIME_HISTORY_POSTS {
+        return None;
+    }
+
+    let schedule = &inputs.config.schedule;
+    let mut changes: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for day in inputs.optimal_times {
+        let mut hours: Vec<i64> = day.hours.iter().map(|h| h.hour).collect();
+        if hours.is_empty() {
+            continue;
+        }
+        hours.sort_unstable();
+        let suggested: Vec<String> = hours.iter().map(|h| format!("{h:02}:00")).collect();
+        let current = schedule
+            .preferred_times_override
+            .get(&day.weekday)
+            .unwrap_or(&schedule.preferred_times);
+        if *current != suggested {
+            changes.insert(day.weekday.clone(), suggested);
+        }
+    }
+    if changes.is_empty() {
+        return None;
+    }
+
+    let days: Vec<String> = changes.keys().cloned().collect();
+    Some(NextStep {
+        kind: NextStepKind::AdoptPostingTimes,
+        title: "Post at your best-performing hours".to_string(),
+        rationale: format!(
+            "Across {total_posts} posts, your highest-engagement hours ({}) differ from \
+             the times configured for {}.",
+            schedule.timezone,
+            days.join(", ")
+        ),
+        apply: json!({ "schedule": { "preferred_times_override": changes } }),
+    })
+}
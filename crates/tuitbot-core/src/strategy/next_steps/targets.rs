@@ -0,0 +1,113 @@
+//! Target steps: monitor frequent high-scoring authors and drop stale targets.
+
+use std::collections::{BTreeSet, HashMap};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde_json::json;
+
+use super::{NextStep, NextStepInputs, NextStepKind};
+
+/// An author needs this many engaged tweets to be suggested as a target.
+const MIN_TARGET_TWEETS: usize = 3;
+
+/// Most targets suggested in one step.
+const MAX_NEW_TARGETS: usize = 3;
+
+/// A target with no reply for this many days is suggested for removal.
+const STALE_TARGET_DAYS: i64 = 30;
+
+pub(super) fn add_targets_step(inputs: &NextStepInputs<'_>) -> Option<NextStep> {
+    let configured: BTreeSet<String> = inputs
+        .config
+        .targets
+        .accounts
+        .iter()
+        .map(|a| a.trim_start_matches('@').to_lowercase())
+        .collect();
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    for tweet in inputs.engaged_tweets {
+        let author = tweet.author_username.trim_start_matches('@');
+        if author.is_empty() || configured.contains(&author.to_lowercase()) {
+            continue;
+        }
+        *counts.entry(author.to_string()).or_default() += 1;
+    }
+
+    let mut candidates: Vec<(String, usize)> = counts
+        .into_iter()
+        .filter(|(_, n)| *n >= MIN_TARGET_TWEETS)
+        .collect();
+    if candidates.is_empty() {
+        return None;
+    }
+    candidates.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    candidates.truncate(MAX_NEW_TARGETS);
+
+    let evidence: Vec<String> = candidates
+        .iter()
+        .map(|(name, n)| format!("@{name} ({n})"))
+        .collect();
+    let mut accounts = inputs.config.targets.accounts.clone();
+    accounts.extend(candidates.into_iter().map(|(name, _)| name));
+
+    Some(NextStep {
+        kind: NextStepKind::AddTargets,
+        title: format!("Monitor {}", evidence.join(", ")),
+        rationale: "These authors wrote several of your highest-scoring discovered tweets \
+                    (count in parentheses). Monitoring them catches their next one early."
+            .to_string(),
+        apply: json!({ "targets": { "accounts": accounts } }),
+    })
+}
+
+pub(super) fn drop_targets_step(inputs: &NextStepInputs<'_>) -> Option<NextStep> {
+    let stale: BTreeSet<String> = inputs
+        .targets
+        .iter()
+        .filter(|t| {
+            t.last_reply_at
+                .as_deref()
+                .and_then(parse_timestamp)
+                .is_some_and(|last| (inputs.now - last).num_days() >= STALE_TARGET_DAYS)
+        })
+        .map(|t| t.username.to_lowercase())
+        .collect();
+
+    let (dropped, kept): (Vec<String>, Vec<String>) = inputs
+        .config
+        .targets
+        .accounts
+        .iter()
+        .cloned()
+        .partition(|a| stale.contains(&a.trim_start_matches('@').to_lowercase()));
+    if dropped.is_empty() {
+        return None;
+    }
+
+    let names: Vec<String> = dropped
+        .iter()
+        .map(|a| format!("@{}", a.trim_start_matches('@')))
+        .collect();
+    Some(NextStep {
+        kind: NextStepKind::DropTargets,
+        title: format!("Stop monitoring {}", names.join(", ")),
+        rationale: format!(
+            "No reply to these targets in {STALE_TARGET_DAYS}+ days: their tweets no longer \
+             pass scoring, so monitoring them only spends API calls."
+        ),
+        apply: json!({ "targets": { "accounts": kept } }),
+    })
+}
+
+/// Parse RFC 3339 or SQLite `datetime('now')` timestamps as UTC.
+fn parse_timestamp(s: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|dt| dt.with_timezone(&Utc))
+        .ok()
+        .or_else(|| {
+            NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+                .ok()
+                .map(|naive| naive.and_utc())
+        })
+}
@@ -0,0 +1,215 @@
+//! Tests for the next-step derivations.
+
+use chrono::TimeZone;
+use serde_json::json;
+
+use super::*;
+use crate::storage::analytics::HourlyPerformance;
+
+fn now() -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2026, 3, 20, 12, 0, 0).unwrap()
+}
+
+fn config() -> Config {
+    let mut config = Config::default();
+    config.business.product_keywords = vec!["rust".to_string(), "cli".to_string()];
+    config.targets.accounts = vec!["alice".to_string(), "bob".to_string()];
+    config.schedule.timezone = "UTC".to_string();
+    config.schedule.preferred_times = vec!["09:00".to_string()];
+    config.limits.max_replies_per_day = 10;
+    config.limits.max_tweets_per_day = 3;
+    config
+}
+
+fn metrics() -> WeekMetrics {
+    WeekMetrics {
+        replies_sent: 70,
+        tweets_posted: 21,
+        threads_posted: 1,
+        target_replies: 5,
+        follower_delta: 40,
+        avg_reply_score: 60.0,
+        avg_tweet_score: 70.0,
+        reply_acceptance_rate: 0.35,
+        top_topics: vec![],
+        bottom_topics: vec![],
+        distinct_topic_count: 5,
+        max_replies_per_week: 70,
+        max_tweets_per_week: 21,
+    }
+}
+
+fn tweet(id: &str, author: &str, content: &str) -> DiscoveredTweet {
+    DiscoveredTweet {
+        id: id.to_string(),
+        author_id: format!("id-{author}"),
+        author_username: author.to_string(),
+        content: content.to_string(),
+        like_count: 10,
+        retweet_count: 1,
+        reply_count: 1,
+        impression_count: None,
+        relevance_score: Some(85.0),
+        matched_keyword: None,
+        discovered_at: "2026-03-19T00:00:00Z".to_string(),
+        replied_to: 0,
+    }
+}
+
+fn engaged_tweets() -> Vec<DiscoveredTweet> {
+    vec![
+        tweet("1", "carol", "Rust async runtimes: tokio is the default"),
+        tweet("2", "carol", "Moved our CLI to rust and tokio"),
+        tweet("3", "carol", "Borrow checker fights again #rust #tokio"),
+        tweet("4", "alice", "Writing a CLI in rust with clap"),
+        tweet("5", "dave", "Shipping a rust CLI this week"),
+    ]
+}
+
+fn optimal_times() -> Vec<WeekdayOptimalHours> {
+    let hour = |hour: i64| HourlyPerformance {
+        hour,
+        avg_engagement: 50.0,
+        post_count: 6,
+        low_confidence: false,
+    };
+    vec![
+        WeekdayOptimalHours {
+            weekday: "Mon".to_string(),
+            hours: vec![hour(14), hour(8)],
+            post_count: 12,
+        },
+        WeekdayOptimalHours {
+            weekday: "Tue".to_string(),
+            hours: vec![hour(9)],
+            post_count: 6,
+        },
+    ]
+}
+
+fn target(username: &str, last_reply_at: Option<&str>) -> TargetAccount {
+    TargetAccount {
+        account_id: format!("id-{username}"),
+        username: username.to_string(),
+        followed_at: None,
+        first_engagement_at: None,
+        total_replies_sent: 4,
+        last_reply_at: last_reply_at.map(str::to_string),
+        status: "active".to_string(),
+    }
+}
+
+fn steps_for(config: &Config, metrics: &WeekMetrics) -> Vec<NextStep> {
+    let tweets = engaged_tweets();
+    let times = optimal_times();
+    let targets = [
+        target("alice", Some("2026-01-02 10:00:00")),
+        target("bob", Some("2026-03-18 10:00:00")),
+    ];
+    recommendations(&NextStepInputs {
+        config,
+        metrics,
+        engaged_tweets: &tweets,
+        optimal_times: &times,
+        targets: &targets,
+        now: now(),
+    })
+}
+
+fn step(steps: &[NextStep], kind: NextStepKind) -> &NextStep {
+    steps
+        .iter()
+        .find(|s| s.kind == kind)
+        .unwrap_or_else(|| panic!("no {kind:?} step in {steps:?}"))
+}
+
+#[test]
+fn known_scenario_yields_every_kind_in_order() {
+    let steps = steps_for(&config(), &metrics());
+    let kinds: Vec<NextStepKind> = steps.iter().map(|s| s.kind).collect();
+    assert_eq!(
+        kinds,
+        vec![
+            NextStepKind::AddKeywords,
+            NextStepKind::AdoptPostingTimes,
+            NextStepKind::AddTargets,
+            NextStepKind::DropTargets,
+            NextStepKind::AdjustLimits,
+        ]
+    );
+    assert!(steps.iter().all(|s| !s.rationale.is_empty()));
+}
+
+#[test]
+fn keyword_step_appends_to_product_keywords() {
+    let steps = steps_for(&config(), &metrics());
+    let s = step(&steps, NextStepKind::AddKeywords);
+    assert_eq!(
+        s.apply,
+        json!({ "business": { "product_keywords": ["rust", "cli", "tokio"] } })
+    );
+    assert!(s.rationale.contains("\"tokio\" (3 tweets"));
+}
+
+#[test]
+fn posting_times_step_patches_only_differing_days() {
+    let steps = steps_for(&config(), &metrics());
+    let s = step(&steps, NextStepKind::AdoptPostingTimes);
+    // Tuesday's best hour already matches the base preferred_times.
+    assert_eq!(
+        s.apply,
+        json!({ "schedule": { "preferred_times_override": { "Mon": ["08:00", "14:00"] } } })
+    );
+}
+
+#[test]
+fn target_steps_add_frequent_authors_and_drop_stale_targets() {
+    let steps = steps_for(&config(), &metrics());
+
+    let add = step(&steps, NextStepKind::AddTargets);
+    assert_eq!(
+        add.apply,
+        json!({ "targets": { "accounts": ["alice", "bob", "carol"] } })
+    );
+
+    let drop = step(&steps, NextStepKind::DropTargets);
+    assert_eq!(drop.apply, json!({ "targets": { "accounts": ["bob"] } }));
+    assert!(drop.title.contains("@alice"));
+}
+
+#[test]
+fn limits_step_raises_saturated_limits_that_perform() {
+    let steps = steps_for(&config(), &metrics());
+    let s = step(&steps, NextStepKind::AdjustLimits);
+    assert_eq!(
+        s.apply,
+        json!({ "limits": { "max_replies_per_day": 12, "max_tweets_per_day": 4 } })
+    );
+}
+
+#[test]
+fn limits_step_lowers_saturated_replies_that_fall_flat() {
+    let mut m = metrics();
+    m.reply_acceptance_rate = 0.05;
+    m.follower_delta = 0;
+    let steps = steps_for(&config(), &m);
+    let s = step(&steps, NextStepKind::AdjustLimits);
+    assert_eq!(s.apply, json!({ "limits": { "max_replies_per_day": 8 } }));
+}
+
+#[test]
+fn no_steps_without_evidence() {
+    let config = config();
+    let mut m = metrics();
+    m.replies_sent = 20;
+    m.tweets_posted = 5;
+    let steps = recommendations(&NextStepInputs {
+        config: &config,
+        metrics: &m,
+        engaged_tweets: &[],
+        optimal_times: &[],
+        targets: &[],
+        now: now(),
+    });
+    assert!(steps.is_empty(), "got: {steps:?}");
+}
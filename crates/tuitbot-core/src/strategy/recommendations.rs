@@ -18,7 +18,7 @@ pub struct Recommendation {
 }
 
 /// Intermediate metrics struct passed to the rule engine.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct WeekMetrics {
     pub replies_sent: i64,
     pub tweets_posted: i64,
@@ -46,9 +46,9 @@ pub fn generate(
     // Compute overall average score across reply and tweet scores
     let overall_avg = overall_average(metrics);
 
-    // Rule 1: Promote Winners — topic avg > 1.5x overall, posts >= 3
+    // Rule 1: Promote Winners — topic avg > 1.5x overall, enough posts to trust
     for topic in &metrics.top_topics {
-        if topic.post_count >= 3 && overall_avg > 0.0 && topic.avg_score > overall_avg * 1.5 {
+        if !topic.low_confidence && overall_avg > 0.0 && topic.avg_score > overall_avg * 1.5 {
             recs.push(Recommendation {
                 category: "promote".to_string(),
                 priority: "high".to_string(),
@@ -63,9 +63,9 @@ pub fn generate(
         }
     }
 
-    // Rule 2: Kill Losers — topic avg < 0.5x overall, posts >= 3
+    // Rule 2: Kill Losers — topic avg < 0.5x overall, enough posts to trust
     for topic in &metrics.bottom_topics {
-        if topic.post_count >= 3 && overall_avg > 0.0 && topic.avg_score < overall_avg * 0.5 {
+        if !topic.low_confidence && overall_avg > 0.0 && topic.avg_score < overall_avg * 0.5 {
             recs.push(Recommendation {
                 category: "kill".to_string(),
                 priority: "medium".to_string(),
@@ -241,6 +241,7 @@ mod tests {
             format: String::new(),
             avg_score: 120.0, // > 1.5 * 65 (overall avg)
             post_count: 5,
+            low_confidence: false,
         }];
         let recs = generate(&metrics, None);
         assert!(recs.iter().any(|r| r.category == "promote"));
@@ -254,11 +255,33 @@ mod tests {
             format: String::new(),
             avg_score: 10.0, // < 0.5 * 65 (overall avg)
             post_count: 5,
+            low_confidence: false,
         }];
         let recs = generate(&metrics, None);
         assert!(recs.iter().any(|r| r.category == "kill"));
     }
 
+    #[test]
+    fn low_confidence_topics_are_not_recommended() {
+        let mut metrics = base_metrics();
+        let sparse = |topic: &str, avg_score: f64| TopicPerformance {
+            topic: topic.to_string(),
+            format: String::new(),
+            avg_score,
+            post_count: 1,
+            low_confidence: true,
+        };
+        metrics.top_topics = vec![sparse("viral-once", 500.0)];
+        metrics.bottom_topics = vec![sparse("flopped-once", 1.0)];
+        let recs = generate(&metrics, None);
+        assert!(
+            !recs
+                .iter()
+                .any(|r| r.category == "promote" || r.category == "kill"),
+            "got: {recs:?}"
+        );
+    }
+
     #[test]
     fn follower_stall_alert() {
         let mut metrics = base_metrics();
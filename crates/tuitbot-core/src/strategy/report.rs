@@ -8,6 +8,7 @@ use crate::error::StorageError;
 use crate::storage::strategy::StrategyReportRow;
 use crate::storage::DbPool;
 
+use super::inputs::StrategySnapshot;
 use super::metrics;
 use super::recommendations::{self, WeekMetrics};
 
@@ -35,8 +36,9 @@ pub async fn compute_report(
     let avg_reply_score = metrics::avg_reply_score_in_range(pool, &start, &end).await?;
     let avg_tweet_score = metrics::avg_tweet_score_in_range(pool, &start, &end).await?;
     let acceptance_rate = metrics::reply_acceptance_rate(pool, &start, &end).await?;
-    let top_topics = metrics::top_topics_in_range(pool, &start, &end, 5).await?;
-    let bottom_topics = metrics::bottom_topics_in_range(pool, &start, &end, 5).await?;
+    let min_sample = config.analytics.min_engagement_sample;
+    let top_topics = metrics::top_topics_in_range(pool, &start, &end, 5, min_sample).await?;
+    let bottom_topics = metrics::bottom_topics_in_range(pool, &start, &end, 5, min_sample).await?;
     let top_content = metrics::top_content_in_range(pool, &start, &end, 5).await?;
     let distinct_topic_count = metrics::distinct_topic_count(pool, &start, &end).await?;
 
@@ -140,6 +142,19 @@ pub async fn compute_report_for(
     account_id: &str,
     week_of: NaiveDate,
 ) -> Result<StrategyReportRow, StorageError> {
+    compute_report_with_inputs_for(pool, config, account_id, week_of)
+        .await
+        .map(|(report, _)| report)
+}
+
+/// Compute a strategy report for one account together with a snapshot of
+/// the inputs its recommendations were derived from.
+pub async fn compute_report_with_inputs_for(
+    pool: &DbPool,
+    config: &Config,
+    account_id: &str,
+    week_of: NaiveDate,
+) -> Result<(StrategyReportRow, StrategySnapshot), StorageError> {
     let (monday, sunday) = week_bounds(week_of);
     let start = format!("{monday}T00:00:00Z");
     let end_date = sunday.succ_opt().unwrap_or(sunday);
@@ -160,9 +175,11 @@ pub async fn compute_report_for(
         metrics::avg_tweet_score_in_range_for(pool, account_id, &start, &end).await?;
     let acceptance_rate =
         metrics::reply_acceptance_rate_for(pool, account_id, &start, &end).await?;
-    let top_topics = metrics::top_topics_in_range_for(pool, account_id, &start, &end, 5).await?;
+    let min_sample = config.analytics.min_engagement_sample;
+    let top_topics =
+        metrics::top_topics_in_range_for(pool, account_id, &start, &end, 5, min_sample).await?;
     let bottom_topics =
-        metrics::bottom_topics_in_range_for(pool, account_id, &start, &end, 5).await?;
+        metrics::bottom_topics_in_range_for(pool, account_id, &start, &end, 5, min_sample).await?;
     let top_content = metrics::top_content_in_range_for(pool, account_id, &start, &end, 5).await?;
     let distinct_topic_count =
         metrics::distinct_topic_count_for(pool, account_id, &start, &end).await?;
@@ -198,6 +215,14 @@ pub async fn compute_report_for(
         max_tweets_per_week: i64::from(config.limits.max_tweets_per_day) * 7,
     };
     let recs = recommendations::generate(&week_metrics, previous.as_ref());
+    let snapshot = StrategySnapshot::capture_for(
+        pool,
+        config,
+        account_id,
+        &week_metrics,
+        previous.map(|p| p.week_start),
+    )
+    .await?;
 
     let top_topics_json = serde_json::to_string(&top_topics).unwrap_or_else(|_| "[]".to_string());
     let bottom_topics_json =
@@ -205,7 +230,7 @@ pub async fn compute_report_for(
     let top_content_json = serde_json::to_string(&top_content).unwrap_or_else(|_| "[]".to_string());
     let recommendations_json = serde_json::to_string(&recs).unwrap_or_else(|_| "[]".to_string());
 
-    Ok(StrategyReportRow {
+    let report = StrategyReportRow {
         id: 0,
         week_start: monday.to_string(),
         week_end: sunday.to_string(),
@@ -225,10 +250,13 @@ pub async fn compute_report_for(
         top_content_json,
         recommendations_json,
         created_at: String::new(),
-    })
+    };
+    Ok((report, snapshot))
 }
 
 /// Get the current week's report for one account, computing it if missing.
+///
+/// The input snapshot is stored with the report (see [`super::inputs`]).
 pub async fn get_or_compute_current_for(
     pool: &DbPool,
     config: &Config,
@@ -237,8 +265,10 @@ pub async fn get_or_compute_current_for(
     let today = Utc::now().date_naive();
     let (monday, _sunday) = week_bounds(today);
 
-    let report = compute_report_for(pool, config, account_id, today).await?;
+    let (report, snapshot) =
+        compute_report_with_inputs_for(pool, config, account_id, today).await?;
     crate::storage::strategy::insert_strategy_report_for(pool, account_id, &report).await?;
+    super::inputs::save_snapshot_for(pool, account_id, &report.week_start, &snapshot).await?;
 
     crate::storage::strategy::get_strategy_report_for(pool, account_id, &monday.to_string())
         .await
@@ -308,4 +338,46 @@ mod tests {
         assert_eq!(report.replies_sent, 0);
         assert_eq!(report.follower_delta, 0);
     }
+
+    #[tokio::test]
+    async fn computed_report_snapshot_roundtrips() {
+        let pool = crate::storage::init_test_db().await.expect("init db");
+        crate::storage::target_accounts::upsert_target_account(&pool, "u1", "alice")
+            .await
+            .expect("target");
+        let mut config = Config::default();
+        config.business.product_keywords = vec!["rust".to_string()];
+        config.scoring.threshold = 55;
+
+        let report = get_or_compute_current_for(
+            &pool,
+            &config,
+            crate::storage::accounts::DEFAULT_ACCOUNT_ID,
+        )
+        .await
+        .expect("compute");
+
+        let snapshot = super::super::inputs::load_snapshot(&pool, report.id)
+            .await
+            .expect("load")
+            .expect("snapshot stored");
+        assert_eq!(snapshot.scoring.threshold, 55);
+        assert_eq!(snapshot.business.product_keywords, vec!["rust"]);
+        assert_eq!(snapshot.target_accounts, vec!["alice"]);
+        assert_eq!(snapshot.metrics.replies_sent, report.replies_sent);
+        assert_eq!(
+            snapshot.metrics.max_replies_per_week,
+            i64::from(config.limits.max_replies_per_day) * 7
+        );
+
+        let reserialized = serde_json::to_string(&snapshot).expect("serialize");
+        let stored = crate::storage::strategy::get_report_inputs(&pool, report.id)
+            .await
+            .expect("get")
+            .expect("json");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&reserialized).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&stored).unwrap()
+        );
+    }
 }
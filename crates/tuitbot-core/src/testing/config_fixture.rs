@@ -31,6 +31,7 @@ impl ConfigFixture {
             client_secret: None,
             provider_backend: "local".to_string(),
             scraper_allow_mutations: false,
+            ..Default::default()
         };
         cfg.business = BusinessProfile {
             product_name: "TuitBot Test".to_string(),
@@ -95,6 +96,7 @@ impl ConfigFixture {
             client_secret: None,
             provider_backend: String::new(),
             scraper_allow_mutations: false,
+            ..Default::default()
         };
         cfg
     }
@@ -210,6 +210,7 @@ impl XApiClient for MockXClient {
                 following_count: 200,
                 tweet_count: 500,
             },
+            protected: None,
         })
     }
 
@@ -270,6 +271,7 @@ fn default_user() -> User {
             following_count: 100,
             tweet_count: 200,
         },
+        protected: None,
     }
 }
 
@@ -344,6 +346,7 @@ mod tests {
                 following_count: 1,
                 tweet_count: 1,
             },
+            protected: None,
         });
         let me = client.get_me().await.unwrap();
         assert_eq!(me.username, "custom_user");
@@ -115,6 +115,7 @@ impl TweetFactory {
                 bookmark_count: 0,
             },
             conversation_id,
+            referenced_tweets: vec![],
         }
     }
 
@@ -106,6 +106,7 @@ mod tests {
                 created_at: "2026-02-24T12:00:00Z".to_string(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             })
         }
 
@@ -119,6 +120,7 @@ mod tests {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             })
         }
 
@@ -150,6 +152,7 @@ mod tests {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             })
         }
 
@@ -200,6 +203,7 @@ mod tests {
                 ..Default::default()
             },
             conversation_id: None,
+            referenced_tweets: vec![],
         }
     }
 
@@ -217,6 +221,7 @@ mod tests {
                 following_count: 100,
                 tweet_count: 500,
             },
+            protected: None,
         }
     }
 
@@ -28,6 +28,26 @@ pub async fn unlike_tweet(
     Ok(client.unlike_tweet(user_id, tweet_id).await?)
 }
 
+/// Fail when `tweet_id` was posted by `user_id`.
+///
+/// Likes and retweets of the account's own tweets are refused: they add
+/// nothing for anyone else and look like self-promotion.
+pub async fn ensure_not_own_tweet(
+    client: &dyn XApiClient,
+    user_id: &str,
+    tweet_id: &str,
+) -> Result<(), ToolkitError> {
+    super::validate_id(user_id, "user_id")?;
+    super::validate_id(tweet_id, "tweet_id")?;
+    let tweet = client.get_tweet(tweet_id).await?;
+    if tweet.author_id == user_id {
+        return Err(ToolkitError::InvalidInput {
+            message: format!("tweet {tweet_id} was posted by the authenticated user"),
+        });
+    }
+    Ok(())
+}
+
 /// Follow a user.
 pub async fn follow_user(
     client: &dyn XApiClient,
@@ -127,8 +147,17 @@ mod tests {
         async fn reply_to_tweet(&self, _: &str, _: &str) -> Result<PostedTweet, XApiError> {
             unimplemented!()
         }
-        async fn get_tweet(&self, _: &str) -> Result<Tweet, XApiError> {
-            unimplemented!()
+        /// Tweet "own" is authored by "u1"; every other tweet by "u2".
+        async fn get_tweet(&self, id: &str) -> Result<Tweet, XApiError> {
+            Ok(Tweet {
+                id: id.to_string(),
+                text: "hello".to_string(),
+                author_id: if id == "own" { "u1" } else { "u2" }.to_string(),
+                created_at: String::new(),
+                public_metrics: PublicMetrics::default(),
+                conversation_id: None,
+                referenced_tweets: vec![],
+            })
         }
         async fn get_me(&self) -> Result<User, XApiError> {
             unimplemented!()
@@ -187,6 +216,19 @@ mod tests {
         assert!(matches!(e, ToolkitError::InvalidInput { .. }));
     }
 
+    #[tokio::test]
+    async fn ensure_not_own_tweet_allows_other_authors() {
+        ensure_not_own_tweet(&MockClient, "u1", "t1").await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn ensure_not_own_tweet_rejects_own_tweet() {
+        let e = ensure_not_own_tweet(&MockClient, "u1", "own")
+            .await
+            .unwrap_err();
+        assert!(matches!(e, ToolkitError::InvalidInput { .. }));
+    }
+
     #[tokio::test]
     async fn follow_user_success() {
         assert!(follow_user(&MockClient, "u1", "u2").await.unwrap());
@@ -302,7 +302,7 @@ fn infer_product_keywords(bio: &str, tweets: &[Tweet]) -> InferredField<Vec<Stri
 }
 
 /// Common English stopwords that should not be treated as keywords.
-fn is_stopword(w: &str) -> bool {
+pub(crate) fn is_stopword(w: &str) -> bool {
     matches!(
         w,
         "that"
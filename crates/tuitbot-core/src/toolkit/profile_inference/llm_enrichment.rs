@@ -267,6 +267,7 @@ mod tests {
                 following_count: 200,
                 tweet_count: 500,
             },
+            protected: None,
         }
     }
 
@@ -352,6 +353,7 @@ mod tests {
             created_at: "2026-01-01T00:00:00Z".to_string(),
             public_metrics: Default::default(),
             conversation_id: None,
+            referenced_tweets: vec![],
         });
         let (_, user_msg) = build_llm_prompt(&input);
         assert!(user_msg.contains("shipped a new feature"));
@@ -525,6 +527,7 @@ mod tests {
                 created_at: String::new(),
                 public_metrics: Default::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             });
         }
         let merged = merge_llm_into_heuristics(base, llm, &input);
@@ -22,6 +22,8 @@ use crate::x_api::types::{Tweet, User};
 pub use heuristics::extract_heuristics;
 pub use llm_enrichment::enrich_with_llm;
 
+pub(crate) use heuristics::is_stopword;
+
 /// How confident the inference is.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -17,6 +17,7 @@ fn make_user(name: &str, username: &str, bio: Option<&str>, url: Option<&str>) -
             following_count: 200,
             tweet_count: 1000,
         },
+        protected: None,
     }
 }
 
@@ -28,6 +29,7 @@ fn make_tweet(text: &str) -> Tweet {
         created_at: "2026-03-01T12:00:00Z".into(),
         public_metrics: PublicMetrics::default(),
         conversation_id: None,
+        referenced_tweets: vec![],
     }
 }
 
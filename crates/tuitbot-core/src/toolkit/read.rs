@@ -215,6 +215,7 @@ mod tests {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             })
         }
         async fn get_me(&self) -> Result<User, XApiError> {
@@ -243,6 +244,7 @@ mod tests {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         }
     }
 
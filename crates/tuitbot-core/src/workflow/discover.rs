@@ -13,7 +13,7 @@ use crate::storage::DbPool;
 use crate::toolkit;
 use crate::x_api::XApiClient;
 
-use super::{ScoreBreakdown, ScoredCandidate, WorkflowError};
+use super::{recommended_action, ScoreBreakdown, ScoredCandidate, WorkflowError};
 
 /// Input for the discover step.
 #[derive(Debug, Clone)]
@@ -47,6 +47,34 @@ pub async fn execute(
     x_client: &dyn XApiClient,
     config: &Config,
     input: DiscoverInput,
+) -> Result<DiscoverOutput, WorkflowError> {
+    let mut output = search_and_score(db, x_client, config, &input, true).await?;
+    let threshold = output.threshold;
+    output
+        .candidates
+        .retain(|c| (c.score_total as f64) >= threshold);
+    Ok(output)
+}
+
+/// Search and score without persisting to `discovered_tweets`.
+///
+/// Unlike [`execute`], candidates below the threshold are kept (with a
+/// `"skip"` recommendation) so callers can see why they would be ignored.
+pub async fn preview(
+    db: &DbPool,
+    x_client: &dyn XApiClient,
+    config: &Config,
+    input: DiscoverInput,
+) -> Result<DiscoverOutput, WorkflowError> {
+    search_and_score(db, x_client, config, &input, false).await
+}
+
+async fn search_and_score(
+    db: &DbPool,
+    x_client: &dyn XApiClient,
+    config: &Config,
+    input: &DiscoverInput,
+    persist: bool,
 ) -> Result<DiscoverOutput, WorkflowError> {
     // Build query from input or product keywords
     let search_query = match &input.query {
@@ -99,7 +127,8 @@ pub async fn execute(
         .chain(config.business.effective_industry_topics().iter())
         .cloned()
         .collect();
-    let engine = ScoringEngine::new(config.scoring.clone(), keywords.clone());
+    let engine =
+        ScoringEngine::new(config.scoring.clone(), keywords.clone()).with_targets(&config.targets);
 
     let mut candidates = Vec::new();
 
@@ -120,39 +149,36 @@ pub async fn execute(
             is_quote_tweet: false,
         };
 
-        let score = engine.score_tweet(&tweet_data);
+        let score = engine
+            .score_tweet_with_pool(db, &tweet.author_id, &tweet_data)
+            .await;
         let matched = find_matched_keywords(&tweet.text, &keywords);
 
         // Persist to DB (best-effort)
-        let discovered = DiscoveredTweet {
-            id: tweet.id.clone(),
-            author_id: tweet.author_id.clone(),
-            author_username: author_username.to_string(),
-            content: tweet.text.clone(),
-            like_count: tweet.public_metrics.like_count as i64,
-            retweet_count: tweet.public_metrics.retweet_count as i64,
-            reply_count: tweet.public_metrics.reply_count as i64,
-            impression_count: Some(tweet.public_metrics.impression_count as i64),
-            relevance_score: Some(score.total as f64),
-            matched_keyword: matched.first().cloned(),
-            discovered_at: tweet.created_at.clone(),
-            replied_to: 0,
-        };
-        let _ = storage::tweets::insert_discovered_tweet(db, &discovered).await;
+        if persist {
+            let discovered = DiscoveredTweet {
+                id: tweet.id.clone(),
+                author_id: tweet.author_id.clone(),
+                author_username: author_username.to_string(),
+                content: tweet.text.clone(),
+                like_count: tweet.public_metrics.like_count as i64,
+                retweet_count: tweet.public_metrics.retweet_count as i64,
+                reply_count: tweet.public_metrics.reply_count as i64,
+                impression_count: Some(tweet.public_metrics.impression_count as i64),
+                relevance_score: Some(score.total as f64),
+                matched_keyword: matched.first().cloned(),
+                discovered_at: tweet.created_at.clone(),
+                replied_to: 0,
+            };
+            let _ = storage::tweets::insert_discovered_tweet(db, &discovered).await;
+        }
 
         // Check if already replied
         let already_replied = storage::replies::has_replied_to(db, &tweet.id)
             .await
             .unwrap_or(false);
 
-        // Determine recommended action
-        let recommended_action = if (score.total as f64) >= threshold + 15.0 {
-            "strong_reply"
-        } else if (score.total as f64) >= threshold {
-            "consider"
-        } else {
-            "skip"
-        };
+        let recommended_action = recommended_action(score.total, threshold);
 
         candidates.push(ScoredCandidate {
             tweet_id: tweet.id.clone(),
@@ -175,8 +201,7 @@ pub async fn execute(
         });
     }
 
-    // Filter by threshold, sort desc, take limit
-    candidates.retain(|c| (c.score_total as f64) >= threshold);
+    // Sort desc, take limit
     candidates.sort_by(|a, b| {
         b.score_total
             .partial_cmp(&a.score_total)
@@ -5,7 +5,7 @@
 
 use std::sync::Arc;
 
-use crate::content::frameworks::ReplyArchetype;
+use crate::content::frameworks::{FrameworkWeights, ReplyArchetype};
 use crate::context::retrieval::VaultCitation;
 use crate::context::winning_dna;
 use crate::llm::LlmProvider;
@@ -47,7 +47,11 @@ pub async fn execute(
     let archetype_override: Option<ReplyArchetype> =
         input.archetype.as_deref().and_then(parse_archetype);
 
-    let gen = make_content_gen(llm, &config.business);
+    let gen = make_content_gen(llm, &config.business)
+        .with_rag_sanitize(config.content.rag_sanitize)
+        .with_random_seed(config.content.random_seed)
+        .with_framework_weights(FrameworkWeights::from_config(&config.content))
+        .with_retry(config.llm.retry.clone());
     let dedup = DedupChecker::new(db.clone());
     let banned = &config.limits.banned_phrases;
 
@@ -111,6 +115,7 @@ pub async fn execute(
                 input.mention_product,
                 archetype_override,
                 rag_prompt,
+                &[],
             )
             .await;
 
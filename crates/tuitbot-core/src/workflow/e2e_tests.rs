@@ -115,6 +115,7 @@ mod tests {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             })
         }
 
@@ -208,6 +209,7 @@ mod tests {
                 ..Default::default()
             },
             conversation_id: None,
+            referenced_tweets: vec![],
         }
     }
 
@@ -225,6 +227,7 @@ mod tests {
                 following_count: 100,
                 tweet_count: 500,
             },
+            protected: None,
         }
     }
 
@@ -14,7 +14,10 @@ pub mod draft;
 pub mod orchestrate;
 pub mod publish;
 pub mod queue;
+pub mod target_preview;
 pub mod thread_plan;
+pub mod thread_post;
+pub mod thread_resume;
 
 #[cfg(test)]
 mod e2e_tests;
@@ -125,6 +128,21 @@ pub struct ScoreBreakdown {
     pub content_type: f32,
 }
 
+/// Recommended action for a candidate given its score and the reply threshold.
+///
+/// `"strong_reply"` at 15+ points above threshold, `"consider"` at or above
+/// it, `"skip"` below.
+pub(crate) fn recommended_action(score_total: f32, threshold: f64) -> &'static str {
+    let score = score_total as f64;
+    if score >= threshold + 15.0 {
+        "strong_reply"
+    } else if score >= threshold {
+        "consider"
+    } else {
+        "skip"
+    }
+}
+
 /// Result of drafting a reply for a single candidate.
 #[derive(Debug, Clone, Serialize)]
 #[serde(tag = "status")]
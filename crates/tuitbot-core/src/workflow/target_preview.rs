@@ -0,0 +1,153 @@
+//! Target preview step: score a target account's recent tweets.
+//!
+//! Shows which of a prospective target's tweets would trigger replies under
+//! the current scoring config. Read-only: nothing is persisted to
+//! `discovered_tweets`, so previewing never feeds the discovery queue.
+
+use crate::config::Config;
+use crate::scoring::{find_matched_keywords, reputation, ScoringEngine, TweetData};
+use crate::storage;
+use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+use crate::storage::DbPool;
+use crate::toolkit;
+use crate::x_api::XApiClient;
+
+use super::{recommended_action, ScoreBreakdown, ScoredCandidate, WorkflowError};
+
+/// Tweets scored when no limit is given.
+pub const DEFAULT_PREVIEW_TWEETS: u32 = 20;
+
+/// Upper bound on tweets scored per preview.
+pub const MAX_PREVIEW_TWEETS: u32 = 50;
+
+/// Smallest page the X user-timeline endpoint accepts.
+const MIN_PREVIEW_TWEETS: u32 = 5;
+
+/// Output from the target preview step.
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct TargetPreviewOutput {
+    /// Target's X user ID.
+    pub user_id: String,
+    /// Target's username as returned by X.
+    pub username: String,
+    /// Target's follower count.
+    pub followers: u64,
+    /// Score threshold used for the verdicts.
+    pub threshold: f64,
+    /// Number of tweets at or above the threshold.
+    pub would_reply: usize,
+    /// Scored tweets, highest score first.
+    pub candidates: Vec<ScoredCandidate>,
+}
+
+/// Score the recent timeline of `username` for a specific account.
+///
+/// `limit` is clamped to `5..=MAX_PREVIEW_TWEETS`.
+pub async fn execute_for(
+    db: &DbPool,
+    account_id: &str,
+    x_client: &dyn XApiClient,
+    config: &Config,
+    username: &str,
+    limit: Option<u32>,
+) -> Result<TargetPreviewOutput, WorkflowError> {
+    let username = username.trim().trim_start_matches('@');
+    if username.is_empty() {
+        return Err(WorkflowError::InvalidInput(
+            "username is required".to_string(),
+        ));
+    }
+
+    let max_results = limit
+        .unwrap_or(DEFAULT_PREVIEW_TWEETS)
+        .clamp(MIN_PREVIEW_TWEETS, MAX_PREVIEW_TWEETS);
+    let threshold = config.scoring.threshold as f64;
+
+    let user = toolkit::read::get_user_by_username(x_client, username).await?;
+    let timeline = toolkit::read::get_user_tweets(x_client, &user.id, max_results, None).await?;
+
+    let keywords: Vec<String> = config
+        .business
+        .product_keywords
+        .iter()
+        .chain(config.business.competitor_keywords.iter())
+        .chain(config.business.effective_industry_topics().iter())
+        .cloned()
+        .collect();
+    let engine = ScoringEngine::new(config.scoring.clone(), keywords.clone());
+    let author_reputation = reputation::author_reputation_for(db, account_id, &user.id).await;
+    let followers = user.public_metrics.followers_count;
+
+    let mut candidates = Vec::with_capacity(timeline.data.len());
+    for tweet in timeline.data.iter().take(max_results as usize) {
+        let tweet_data = TweetData {
+            text: tweet.text.clone(),
+            created_at: tweet.created_at.clone(),
+            likes: tweet.public_metrics.like_count,
+            retweets: tweet.public_metrics.retweet_count,
+            replies: tweet.public_metrics.reply_count,
+            author_username: user.username.clone(),
+            author_followers: followers,
+            has_media: false,
+            is_quote_tweet: false,
+        };
+        let score = engine.score_tweet_at_with_reputation(
+            &tweet_data,
+            chrono::Utc::now(),
+            author_reputation,
+        );
+        let already_replied = storage::replies::has_replied_to_for(db, account_id, &tweet.id)
+            .await
+            .unwrap_or(false);
+
+        candidates.push(ScoredCandidate {
+            tweet_id: tweet.id.clone(),
+            author_username: user.username.clone(),
+            author_followers: followers,
+            text: tweet.text.clone(),
+            created_at: tweet.created_at.clone(),
+            score_total: score.total,
+            score_breakdown: ScoreBreakdown {
+                keyword_relevance: score.keyword_relevance,
+                follower: score.follower,
+                recency: score.recency,
+                engagement: score.engagement,
+                reply_count: score.reply_count,
+                content_type: score.content_type,
+            },
+            matched_keywords: find_matched_keywords(&tweet.text, &keywords),
+            recommended_action: recommended_action(score.total, threshold).to_string(),
+            already_replied,
+        });
+    }
+
+    candidates.sort_by(|a, b| {
+        b.score_total
+            .partial_cmp(&a.score_total)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    let would_reply = candidates
+        .iter()
+        .filter(|c| (c.score_total as f64) >= threshold)
+        .count();
+
+    Ok(TargetPreviewOutput {
+        user_id: user.id,
+        username: user.username,
+        followers,
+        threshold,
+        would_reply,
+        candidates,
+    })
+}
+
+/// Score the recent timeline of `username`.
+pub async fn execute(
+    db: &DbPool,
+    x_client: &dyn XApiClient,
+    config: &Config,
+    username: &str,
+    limit: Option<u32>,
+) -> Result<TargetPreviewOutput, WorkflowError> {
+    execute_for(db, DEFAULT_ACCOUNT_ID, x_client, config, username, limit).await
+}
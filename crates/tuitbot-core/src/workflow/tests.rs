@@ -104,6 +104,7 @@ impl XApiClient for MockXApiClient {
             created_at: "2026-02-24T00:00:00Z".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            referenced_tweets: vec![],
         })
     }
 
@@ -117,6 +118,7 @@ impl XApiClient for MockXApiClient {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -148,6 +150,7 @@ impl XApiClient for MockXApiClient {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -249,6 +252,7 @@ fn sample_tweet(id: &str, text: &str, author_id: &str) -> Tweet {
             bookmark_count: 0,
         },
         conversation_id: None,
+        referenced_tweets: vec![],
     }
 }
 
@@ -266,6 +270,7 @@ fn sample_user(id: &str, username: &str, followers: u64) -> User {
             following_count: 100,
             tweet_count: 500,
         },
+        protected: None,
     }
 }
 
@@ -401,6 +406,45 @@ mod discover_tests {
 
         assert!(matches!(err, WorkflowError::InvalidInput(_)));
     }
+
+    #[tokio::test]
+    async fn preview_keeps_low_scores_and_skips_persistence() {
+        let db = storage::init_test_db().await.unwrap();
+        let tweets = vec![
+            sample_tweet("t1", "Learning rust async programming today", "a1"),
+            sample_tweet("t2", "Just had coffee", "a2"),
+        ];
+        let users = vec![
+            sample_user("a1", "rustdev", 5000),
+            sample_user("a2", "coffeelover", 200),
+        ];
+        let client = MockXApiClient::with_results(tweets, users);
+        let config = test_config();
+
+        let output = discover::preview(
+            &db,
+            &client,
+            &config,
+            DiscoverInput {
+                query: Some("rust".to_string()),
+                min_score: Some(1000.0),
+                limit: Some(10),
+                since_id: None,
+            },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(output.candidates.len(), 2);
+        assert!(output
+            .candidates
+            .iter()
+            .all(|c| c.recommended_action == "skip"));
+        assert!(storage::tweets::get_tweet_by_id(&db, "t1")
+            .await
+            .unwrap()
+            .is_none());
+    }
 }
 
 // ── Draft step tests ─────────────────────────────────────────────────
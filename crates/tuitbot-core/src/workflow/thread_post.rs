@@ -0,0 +1,341 @@
+//! Post step: publish a ready-made thread as a reply chain.
+//!
+//! The root tweet is posted standalone and every following tweet replies to
+//! the one before it. [`post_reply_chain`] is shared with the approval
+//! poster; [`execute`] adds the weekly thread limit and storage records for
+//! callers posting a thread directly, such as `tuitbot thread --from-markdown`.
+
+use crate::safety::{DenialReason, SafetyGuard};
+use crate::storage::{thread_progress, threads, DbPool};
+use crate::toolkit::{self, ToolkitError};
+use crate::x_api::XApiClient;
+
+use super::WorkflowError;
+
+/// A reply chain that stopped partway through.
+#[derive(Debug)]
+pub struct ChainFailure {
+    /// IDs of the tweets posted before the failure, root first.
+    pub posted_ids: Vec<String>,
+    /// Zero-based index of the tweet that failed.
+    pub failed_index: usize,
+    /// Why the tweet at `failed_index` could not be posted.
+    pub error: ToolkitError,
+}
+
+/// Outcome of posting a thread with [`execute`].
+#[derive(Debug)]
+pub enum PostThreadOutcome {
+    /// Every tweet was posted and the thread recorded as sent.
+    Posted {
+        thread_id: i64,
+        /// IDs of every tweet in the thread, root first.
+        tweet_ids: Vec<String>,
+    },
+    /// Some tweets were posted before one failed. Progress is saved under
+    /// `thread_id` so the thread can be resumed.
+    PartialFailure {
+        thread_id: i64,
+        posted_ids: Vec<String>,
+        failed_index: usize,
+        total: usize,
+        error: String,
+    },
+    /// The weekly thread limit is reached; nothing was posted.
+    RateLimited { reason: DenialReason },
+}
+
+/// Post `tweets` as a reply chain, attaching `media_ids` to the root tweet.
+///
+/// Returns the posted tweet IDs, root first.
+pub async fn post_reply_chain(
+    x_client: &dyn XApiClient,
+    tweets: &[String],
+    media_ids: &[String],
+) -> Result<Vec<String>, ChainFailure> {
+    let mut posted_ids: Vec<String> = Vec::with_capacity(tweets.len());
+    for (i, text) in tweets.iter().enumerate() {
+        let result = match posted_ids.last() {
+            Some(prev) => toolkit::write::reply_to_tweet(x_client, text, prev, None).await,
+            None if media_ids.is_empty() => toolkit::write::post_tweet(x_client, text, None).await,
+            None => toolkit::write::post_tweet(x_client, text, Some(media_ids)).await,
+        };
+        match result {
+            Ok(posted) => posted_ids.push(posted.id),
+            Err(error) => {
+                return Err(ChainFailure {
+                    posted_ids,
+                    failed_index: i,
+                    error,
+                })
+            }
+        }
+    }
+    Ok(posted_ids)
+}
+
+/// Post a thread for `account_id` and record it.
+///
+/// Checks the weekly thread limit first. On success the thread is stored as
+/// sent and counted against the limit. A failure on the root tweet is
+/// returned as an error; a later failure is saved as a partial thread.
+pub async fn execute(
+    db: &DbPool,
+    x_client: &dyn XApiClient,
+    account_id: &str,
+    topic: &str,
+    tweets: &[String],
+) -> Result<PostThreadOutcome, WorkflowError> {
+    if tweets.is_empty() {
+        return Err(WorkflowError::InvalidInput(
+            "thread must contain at least one tweet".to_string(),
+        ));
+    }
+
+    let guard = SafetyGuard::new_for(db.clone(), account_id);
+    if let Err(reason) = guard.can_post_thread().await? {
+        return Ok(PostThreadOutcome::RateLimited { reason });
+    }
+
+    let tweet_ids = match post_reply_chain(x_client, tweets, &[]).await {
+        Ok(ids) => ids,
+        Err(failure) if failure.posted_ids.is_empty() => return Err(failure.error.into()),
+        Err(failure) => {
+            let thread_id = thread_progress::persist_partial_thread_for(
+                db,
+                account_id,
+                topic,
+                &failure.posted_ids,
+                tweets,
+            )
+            .await?;
+            return Ok(PostThreadOutcome::PartialFailure {
+                thread_id,
+                posted_ids: failure.posted_ids,
+                failed_index: failure.failed_index,
+                total: tweets.len(),
+                error: failure.error.to_string(),
+            });
+        }
+    };
+
+    let (thread_id, _) =
+        threads::persist_thread_records(db, account_id, topic, &tweet_ids, tweets, "sent").await?;
+    guard.record_thread().await?;
+
+    Ok(PostThreadOutcome::Posted {
+        thread_id,
+        tweet_ids,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::error::XApiError;
+    use crate::storage::accounts::DEFAULT_ACCOUNT_ID;
+    use crate::storage::{init_test_db, rate_limits};
+    use crate::x_api::types::*;
+
+    /// Posts succeed until `fail_at` calls have been made.
+    struct ChainClient {
+        calls: AtomicUsize,
+        fail_at: Option<usize>,
+        replies: Mutex<Vec<(String, String)>>,
+    }
+
+    impl ChainClient {
+        fn new(fail_at: Option<usize>) -> Self {
+            Self {
+                calls: AtomicUsize::new(0),
+                fail_at,
+                replies: Mutex::new(vec![]),
+            }
+        }
+
+        fn next(&self, text: &str) -> Result<PostedTweet, XApiError> {
+            let n = self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.fail_at == Some(n) {
+                return Err(XApiError::ApiError {
+                    status: 503,
+                    message: "service unavailable".to_string(),
+                });
+            }
+            Ok(PostedTweet {
+                id: format!("t{n}"),
+                text: text.to_string(),
+            })
+        }
+    }
+
+    fn empty_search() -> SearchResponse {
+        SearchResponse {
+            data: vec![],
+            includes: None,
+            meta: SearchMeta {
+                newest_id: None,
+                oldest_id: None,
+                result_count: 0,
+                next_token: None,
+            },
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl XApiClient for ChainClient {
+        async fn search_tweets(
+            &self,
+            _: &str,
+            _: u32,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            Ok(empty_search())
+        }
+
+        async fn get_mentions(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<MentionResponse, XApiError> {
+            Ok(empty_search())
+        }
+
+        async fn post_tweet(&self, text: &str) -> Result<PostedTweet, XApiError> {
+            self.next(text)
+        }
+
+        async fn reply_to_tweet(
+            &self,
+            text: &str,
+            in_reply_to_id: &str,
+        ) -> Result<PostedTweet, XApiError> {
+            let posted = self.next(text)?;
+            self.replies
+                .lock()
+                .unwrap()
+                .push((in_reply_to_id.to_string(), posted.id.clone()));
+            Ok(posted)
+        }
+
+        async fn get_tweet(&self, _: &str) -> Result<Tweet, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+
+        async fn get_me(&self) -> Result<User, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+
+        async fn get_user_tweets(
+            &self,
+            _: &str,
+            _: u32,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            Ok(empty_search())
+        }
+
+        async fn get_user_by_username(&self, _: &str) -> Result<User, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+    }
+
+    fn thread_of(n: usize) -> Vec<String> {
+        (1..=n).map(|i| format!("Tweet {i}/{n}")).collect()
+    }
+
+    async fn init_thread_limit(db: &DbPool, max_per_week: u32) {
+        let limits = crate::config::LimitsConfig {
+            max_threads_per_week: max_per_week,
+            ..Default::default()
+        };
+        rate_limits::init_rate_limits(db, &limits, &Default::default())
+            .await
+            .expect("init limits");
+    }
+
+    #[tokio::test]
+    async fn posts_chain_and_counts_thread() {
+        let db = init_test_db().await.expect("init db");
+        init_thread_limit(&db, 2).await;
+        let client = ChainClient::new(None);
+
+        let outcome = execute(&db, &client, DEFAULT_ACCOUNT_ID, "rust", &thread_of(3))
+            .await
+            .expect("post");
+
+        let PostThreadOutcome::Posted { tweet_ids, .. } = outcome else {
+            panic!("expected posted, got {outcome:?}");
+        };
+        assert_eq!(tweet_ids, ["t0", "t1", "t2"]);
+        let replies = client.replies.lock().unwrap().clone();
+        assert_eq!(
+            replies,
+            [
+                ("t0".to_string(), "t1".to_string()),
+                ("t1".to_string(), "t2".to_string())
+            ]
+        );
+        let limit = rate_limits::get_rate_limit(&db, "thread")
+            .await
+            .expect("read")
+            .expect("row");
+        assert_eq!(limit.request_count, 1);
+    }
+
+    #[tokio::test]
+    async fn later_failure_saves_partial_thread() {
+        let db = init_test_db().await.expect("init db");
+        let client = ChainClient::new(Some(2));
+
+        let outcome = execute(&db, &client, DEFAULT_ACCOUNT_ID, "rust", &thread_of(4))
+            .await
+            .expect("post");
+
+        let PostThreadOutcome::PartialFailure {
+            thread_id,
+            posted_ids,
+            failed_index,
+            ..
+        } = outcome
+        else {
+            panic!("expected partial failure, got {outcome:?}");
+        };
+        assert_eq!(posted_ids, ["t0", "t1"]);
+        assert_eq!(failed_index, 2);
+        let progress = thread_progress::get_thread_progress(&db, thread_id)
+            .await
+            .expect("read")
+            .expect("thread");
+        assert_eq!(progress.remaining().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn root_failure_is_an_error() {
+        let db = init_test_db().await.expect("init db");
+        let client = ChainClient::new(Some(0));
+
+        let err = execute(&db, &client, DEFAULT_ACCOUNT_ID, "rust", &thread_of(2))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, WorkflowError::Toolkit(ToolkitError::XApi(_))));
+    }
+
+    #[tokio::test]
+    async fn thread_limit_blocks_posting() {
+        let db = init_test_db().await.expect("init db");
+        init_thread_limit(&db, 0).await;
+        let client = ChainClient::new(None);
+
+        let outcome = execute(&db, &client, DEFAULT_ACCOUNT_ID, "rust", &thread_of(2))
+            .await
+            .expect("post");
+
+        assert!(matches!(outcome, PostThreadOutcome::RateLimited { .. }));
+        assert_eq!(client.calls.load(Ordering::SeqCst), 0);
+    }
+}
@@ -0,0 +1,366 @@
+//! Resume step: finish posting a thread that failed partway through.
+//!
+//! Reads the persisted thread progress, verifies the last posted tweet still
+//! exists, then posts only the remaining tweets as a reply chain off it.
+//! Progress is written back after every tweet, so a resume that fails again
+//! can itself be resumed.
+
+use crate::error::XApiError;
+use crate::storage::thread_progress;
+use crate::storage::DbPool;
+use crate::toolkit::{self, ToolkitError};
+use crate::x_api::XApiClient;
+
+use super::WorkflowError;
+
+/// Output from a successful resume.
+#[derive(Debug, Clone)]
+pub struct ResumeThreadOutput {
+    /// The resumed thread's ID.
+    pub thread_id: i64,
+    /// Position of the first tweet posted by this resume (0-indexed).
+    pub resumed_from: usize,
+    /// IDs of the tweets posted by this resume.
+    pub new_tweet_ids: Vec<String>,
+    /// IDs of every tweet in the thread, root first.
+    pub thread_tweet_ids: Vec<String>,
+}
+
+/// Resume a partially posted thread.
+///
+/// Fails with `InvalidInput` if the thread does not exist, is already fully
+/// posted, or its last posted tweet has been deleted (posting the rest would
+/// orphan them). A failure while posting returns
+/// `ToolkitError::ThreadPartialFailure` with progress saved for another resume.
+pub async fn execute(
+    db: &DbPool,
+    x_client: &dyn XApiClient,
+    thread_id: i64,
+) -> Result<ResumeThreadOutput, WorkflowError> {
+    let progress = thread_progress::get_thread_progress(db, thread_id)
+        .await?
+        .ok_or_else(|| WorkflowError::InvalidInput(format!("thread {thread_id} not found")))?;
+
+    let remaining = progress.remaining().to_vec();
+    if remaining.is_empty() {
+        return Err(WorkflowError::InvalidInput(format!(
+            "thread {thread_id} has no unposted tweets; nothing to resume"
+        )));
+    }
+
+    let mut posted_ids = progress.posted_ids();
+    let resumed_from = posted_ids.len();
+    let total = progress.tweets.len();
+
+    if let Some(last) = posted_ids.last() {
+        match toolkit::read::get_tweet(x_client, last).await {
+            Ok(_) => {}
+            Err(ToolkitError::XApi(XApiError::ApiError { status: 404, .. })) => {
+                return Err(WorkflowError::InvalidInput(format!(
+                    "cannot resume thread {thread_id}: last posted tweet {last} \
+                     (position {resumed_from}) was deleted; posting the remaining \
+                     {} tweet(s) would orphan them",
+                    remaining.len()
+                )));
+            }
+            Err(e) => return Err(e.into()),
+        }
+    }
+
+    let mut new_tweet_ids = Vec::with_capacity(remaining.len());
+    for tweet in &remaining {
+        let result = match posted_ids.last() {
+            Some(prev) => {
+                toolkit::write::reply_to_tweet(x_client, &tweet.content, prev, None).await
+            }
+            None => toolkit::write::post_tweet(x_client, &tweet.content, None).await,
+        };
+
+        match result {
+            Ok(posted) => {
+                thread_progress::mark_thread_tweet_posted(
+                    db,
+                    thread_id,
+                    tweet.position,
+                    &posted.id,
+                )
+                .await?;
+                posted_ids.push(posted.id.clone());
+                new_tweet_ids.push(posted.id);
+            }
+            Err(e) => {
+                thread_progress::update_thread_progress(
+                    db,
+                    thread_id,
+                    "partial",
+                    posted_ids.len(),
+                    posted_ids.first().map(String::as_str),
+                )
+                .await?;
+                let source = match e {
+                    ToolkitError::XApi(x) => x,
+                    other => XApiError::ApiError {
+                        status: 0,
+                        message: other.to_string(),
+                    },
+                };
+                return Err(ToolkitError::ThreadPartialFailure {
+                    failed_index: posted_ids.len(),
+                    posted: posted_ids.len(),
+                    posted_ids,
+                    total,
+                    source: Box::new(source),
+                }
+                .into());
+            }
+        }
+    }
+
+    thread_progress::update_thread_progress(
+        db,
+        thread_id,
+        "sent",
+        posted_ids.len(),
+        posted_ids.first().map(String::as_str),
+    )
+    .await?;
+
+    Ok(ResumeThreadOutput {
+        thread_id,
+        resumed_from,
+        new_tweet_ids,
+        thread_tweet_ids: posted_ids,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::storage::init_test_db;
+    use crate::x_api::types::*;
+
+    /// Posts succeed until `fail_at` calls have been made; `deleted` tweets 404.
+    struct FlakyClient {
+        calls: AtomicUsize,
+        fail_at: Option<usize>,
+        deleted: Vec<String>,
+        replies: Mutex<Vec<(String, String)>>,
+    }
+
+    impl FlakyClient {
+        fn new(fail_at: Option<usize>) -> Self {
+            Self {
+                calls: AtomicUsize::new(0),
+                fail_at,
+                deleted: vec![],
+                replies: Mutex::new(vec![]),
+            }
+        }
+
+        fn next(&self, text: &str) -> Result<PostedTweet, XApiError> {
+            let n = self.calls.fetch_add(1, Ordering::SeqCst);
+            if self.fail_at == Some(n) {
+                return Err(XApiError::ApiError {
+                    status: 503,
+                    message: "service unavailable".to_string(),
+                });
+            }
+            Ok(PostedTweet {
+                id: format!("new_{n}"),
+                text: text.to_string(),
+            })
+        }
+    }
+
+    fn empty_search() -> SearchResponse {
+        SearchResponse {
+            data: vec![],
+            includes: None,
+            meta: SearchMeta {
+                newest_id: None,
+                oldest_id: None,
+                result_count: 0,
+                next_token: None,
+            },
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl XApiClient for FlakyClient {
+        async fn search_tweets(
+            &self,
+            _: &str,
+            _: u32,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            Ok(empty_search())
+        }
+
+        async fn get_mentions(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<MentionResponse, XApiError> {
+            Ok(empty_search())
+        }
+
+        async fn post_tweet(&self, text: &str) -> Result<PostedTweet, XApiError> {
+            self.next(text)
+        }
+
+        async fn reply_to_tweet(
+            &self,
+            text: &str,
+            in_reply_to_id: &str,
+        ) -> Result<PostedTweet, XApiError> {
+            let posted = self.next(text)?;
+            self.replies
+                .lock()
+                .unwrap()
+                .push((in_reply_to_id.to_string(), posted.id.clone()));
+            Ok(posted)
+        }
+
+        async fn get_tweet(&self, tweet_id: &str) -> Result<Tweet, XApiError> {
+            if self.deleted.iter().any(|d| d == tweet_id) {
+                return Err(XApiError::ApiError {
+                    status: 404,
+                    message: "Not Found".to_string(),
+                });
+            }
+            Ok(Tweet {
+                id: tweet_id.to_string(),
+                text: String::new(),
+                author_id: "me".to_string(),
+                created_at: String::new(),
+                public_metrics: PublicMetrics::default(),
+                conversation_id: None,
+                referenced_tweets: vec![],
+            })
+        }
+
+        async fn get_me(&self) -> Result<User, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+
+        async fn get_user_tweets(
+            &self,
+            _: &str,
+            _: u32,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            Ok(empty_search())
+        }
+
+        async fn get_user_by_username(&self, _: &str) -> Result<User, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+    }
+
+    fn thread_of(n: usize) -> Vec<String> {
+        (1..=n).map(|i| format!("Tweet {i}/{n}")).collect()
+    }
+
+    /// Post a 7-tweet thread that fails on tweet 4 and persist its progress.
+    async fn failed_thread(db: &DbPool) -> i64 {
+        let tweets = thread_of(7);
+        let client = FlakyClient::new(Some(3));
+        let err = toolkit::write::post_thread(&client, &tweets, None)
+            .await
+            .unwrap_err();
+        let ToolkitError::ThreadPartialFailure { posted_ids, .. } = err else {
+            panic!("expected partial failure, got {err:?}");
+        };
+        assert_eq!(posted_ids.len(), 3);
+        thread_progress::persist_partial_thread(db, "resume", &posted_ids, &tweets)
+            .await
+            .expect("persist")
+    }
+
+    #[tokio::test]
+    async fn resume_posts_only_remaining_tweets() {
+        let db = init_test_db().await.expect("init db");
+        let thread_id = failed_thread(&db).await;
+
+        let client = FlakyClient::new(None);
+        let out = execute(&db, &client, thread_id).await.expect("resume");
+
+        assert_eq!(out.resumed_from, 3);
+        assert_eq!(out.new_tweet_ids.len(), 4);
+        assert_eq!(out.thread_tweet_ids.len(), 7);
+        // The first resumed tweet replies to the last tweet posted before the failure.
+        let replies = client.replies.lock().unwrap().clone();
+        assert_eq!(replies[0].0, "new_2");
+        assert_eq!(replies[1].0, out.new_tweet_ids[0]);
+
+        let progress = thread_progress::get_thread_progress(&db, thread_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(progress.thread.status, "sent");
+        assert_eq!(progress.posted_count(), 7);
+        assert_eq!(progress.thread.tweet_count, 7);
+    }
+
+    #[tokio::test]
+    async fn failed_resume_saves_progress_for_next_attempt() {
+        let db = init_test_db().await.expect("init db");
+        let thread_id = failed_thread(&db).await;
+
+        let client = FlakyClient::new(Some(1));
+        let err = execute(&db, &client, thread_id).await.unwrap_err();
+        assert!(matches!(
+            err,
+            WorkflowError::Toolkit(ToolkitError::ThreadPartialFailure {
+                posted: 4,
+                total: 7,
+                ..
+            })
+        ));
+
+        let out = execute(&db, &FlakyClient::new(None), thread_id)
+            .await
+            .expect("second resume");
+        assert_eq!(out.resumed_from, 4);
+        assert_eq!(out.new_tweet_ids.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn resume_aborts_when_last_tweet_deleted() {
+        let db = init_test_db().await.expect("init db");
+        let thread_id = failed_thread(&db).await;
+
+        let mut client = FlakyClient::new(None);
+        client.deleted = vec!["new_2".to_string()];
+        let err = execute(&db, &client, thread_id).await.unwrap_err();
+
+        let msg = err.to_string();
+        assert!(msg.contains("was deleted"), "{msg}");
+        assert_eq!(client.calls.load(Ordering::SeqCst), 0, "nothing posted");
+    }
+
+    #[tokio::test]
+    async fn resume_rejects_unknown_and_complete_threads() {
+        let db = init_test_db().await.expect("init db");
+        let err = execute(&db, &FlakyClient::new(None), 42).await.unwrap_err();
+        assert!(err.to_string().contains("not found"));
+
+        let done = thread_progress::persist_partial_thread(
+            &db,
+            "",
+            &["a".to_string(), "b".to_string()],
+            &thread_of(2),
+        )
+        .await
+        .unwrap();
+        let err = execute(&db, &FlakyClient::new(None), done)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("nothing to resume"));
+    }
+}
@@ -6,6 +6,7 @@ use chrono::Utc;
 use tokio::sync::RwLock;
 
 use crate::error::XApiError;
+use crate::startup::{StoredTokens, TokenStore};
 
 use super::{save_tokens, TokenRefreshResponse, Tokens, REFRESH_WINDOW_SECS, TOKEN_URL};
 
@@ -15,6 +16,8 @@ pub struct TokenManager {
     client_id: String,
     http_client: reqwest::Client,
     token_path: std::path::PathBuf,
+    /// Where refreshed tokens are persisted instead of `token_path`, if set.
+    store: Option<Box<dyn TokenStore>>,
     /// Serializes refresh attempts so only one runs at a time.
     /// X API refresh tokens are single-use, so concurrent refreshes
     /// would invalidate the token used by the second caller.
@@ -29,10 +32,18 @@ impl TokenManager {
             client_id,
             http_client: reqwest::Client::new(),
             token_path,
+            store: None,
             refresh_lock: tokio::sync::Mutex::new(()),
         }
     }
 
+    /// Persist refreshed tokens to `store` (`auth.token_storage`) instead
+    /// of the token file.
+    pub fn with_store(mut self, store: Box<dyn TokenStore>) -> Self {
+        self.store = Some(store);
+        self
+    }
+
     /// Get the current access token, refreshing if needed.
     pub async fn get_access_token(&self) -> Result<String, XApiError> {
         self.refresh_if_needed().await?;
@@ -103,7 +114,7 @@ impl TokenManager {
             .form(&params)
             .send()
             .await
-            .map_err(|e| XApiError::Network { source: e })?;
+            .map_err(XApiError::from_transport)?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -116,10 +127,8 @@ impl TokenManager {
             return Err(XApiError::AuthExpired);
         }
 
-        let body: TokenRefreshResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let body: TokenRefreshResponse =
+            response.json().await.map_err(XApiError::from_transport)?;
 
         let new_tokens = Tokens {
             access_token: body.access_token,
@@ -143,8 +152,14 @@ impl TokenManager {
             *tokens = new_tokens.clone();
         }
 
-        // Persist to disk
-        save_tokens(&new_tokens, &self.token_path).map_err(|e| {
+        // Persist to the configured store, or the token file
+        let saved = match &self.store {
+            Some(store) => store
+                .save(&StoredTokens::from(&new_tokens))
+                .map_err(|e| e.to_string()),
+            None => save_tokens(&new_tokens, &self.token_path),
+        };
+        saved.map_err(|e| {
             tracing::error!(error = %e, "Failed to save refreshed tokens");
             XApiError::ApiError {
                 status: 0,
@@ -0,0 +1,71 @@
+//! In-memory TTL cache for user lookups.
+//!
+//! `get_me` runs on every startup and `get_user_by_username` is repeated
+//! across loops, so both are cached for `x_api.cache_ttl_seconds` to save
+//! quota. The cache is cleared whenever the access token is replaced.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+
+use crate::x_api::types::User;
+
+/// Cache key for the authenticated user.
+pub(crate) const ME_KEY: &str = "me";
+
+/// Cache key for a username lookup. Usernames are case-insensitive on X.
+pub(crate) fn username_key(username: &str) -> String {
+    format!("username:{}", username.to_ascii_lowercase())
+}
+
+/// Users keyed by lookup, each expiring `ttl` after insertion.
+pub(crate) struct UserCache {
+    ttl: Duration,
+    entries: Mutex<HashMap<String, (Instant, User)>>,
+}
+
+impl UserCache {
+    /// Create a cache. A zero TTL disables caching.
+    pub(crate) fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Return a cached user that has not expired.
+    pub(crate) fn get(&self, key: &str) -> Option<User> {
+        if self.ttl.is_zero() {
+            return None;
+        }
+        let mut entries = self.lock_entries();
+        match entries.get(key) {
+            Some((stored_at, user)) if stored_at.elapsed() < self.ttl => Some(user.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    /// Store a user under `key`.
+    pub(crate) fn insert(&self, key: String, user: &User) {
+        if self.ttl.is_zero() {
+            return;
+        }
+        self.lock_entries()
+            .insert(key, (Instant::now(), user.clone()));
+    }
+
+    /// Drop every cached user.
+    pub(crate) fn clear(&self) {
+        self.lock_entries().clear();
+    }
+
+    fn lock_entries(&self) -> MutexGuard<'_, HashMap<String, (Instant, User)>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
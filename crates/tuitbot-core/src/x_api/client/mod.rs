@@ -4,19 +4,23 @@
 //! using reqwest for HTTP requests with proper error mapping and
 //! rate limit header parsing.
 
+mod cache;
 mod trait_impl;
 
 #[cfg(test)]
 mod tests;
 
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::RwLock;
 
+use crate::config::HttpTimeoutConfig;
 use crate::error::XApiError;
 use crate::safety::redact::redact_secrets;
 use crate::storage::{self, DbPool};
 
 use super::types::{RateLimitInfo, XApiErrorResponse};
+use cache::UserCache;
 
 /// Default X API v2 base URL.
 const DEFAULT_BASE_URL: &str = "https://api.x.com/2";
@@ -24,38 +28,47 @@ const DEFAULT_BASE_URL: &str = "https://api.x.com/2";
 /// Default X API v1.1 media upload base URL.
 const DEFAULT_UPLOAD_BASE_URL: &str = "https://upload.twitter.com/1.1";
 
+/// Default TTL for cached user lookups, matching `x_api.cache_ttl_seconds`.
+const DEFAULT_USER_CACHE_TTL: Duration = Duration::from_secs(300);
+
 /// Standard tweet fields requested on every query.
-pub(crate) const TWEET_FIELDS: &str = "public_metrics,created_at,author_id,conversation_id";
+pub(crate) const TWEET_FIELDS: &str =
+    "public_metrics,created_at,author_id,conversation_id,referenced_tweets";
 
 /// Standard expansions requested on every query.
 pub(crate) const EXPANSIONS: &str = "author_id";
 
 /// Standard user fields requested on every query.
 pub(crate) const USER_FIELDS: &str =
-    "username,name,public_metrics,profile_image_url,description,location,url";
+    "username,name,public_metrics,profile_image_url,description,location,url,protected";
 
 /// HTTP client for the X API v2.
 ///
 /// Uses reqwest with Bearer token authentication. The access token
 /// is stored behind an `Arc<RwLock>` so the token manager can
-/// update it transparently after a refresh.
+/// update it transparently after a refresh. Requests are bounded by
+/// the default `[x_api.http]` timeouts unless overridden with
+/// [`with_timeouts`](Self::with_timeouts). `get_me` and username lookups
+/// are cached; see [`with_cache_ttl`](Self::with_cache_ttl).
 pub struct XApiHttpClient {
     pub(crate) client: reqwest::Client,
     pub(crate) base_url: String,
     pub(crate) upload_base_url: String,
     pub(crate) access_token: Arc<RwLock<String>>,
     pool: Arc<RwLock<Option<DbPool>>>,
+    pub(crate) user_cache: UserCache,
 }
 
 impl XApiHttpClient {
     /// Create a new X API HTTP client with the given access token.
     pub fn new(access_token: String) -> Self {
         Self {
-            client: reqwest::Client::new(),
+            client: crate::net::http_client(&HttpTimeoutConfig::default()),
             base_url: DEFAULT_BASE_URL.to_string(),
             upload_base_url: DEFAULT_UPLOAD_BASE_URL.to_string(),
             access_token: Arc::new(RwLock::new(access_token)),
             pool: Arc::new(RwLock::new(None)),
+            user_cache: UserCache::new(DEFAULT_USER_CACHE_TTL),
         }
     }
 
@@ -63,14 +76,27 @@ impl XApiHttpClient {
     pub fn with_base_url(access_token: String, base_url: String) -> Self {
         let upload_base_url = base_url.clone();
         Self {
-            client: reqwest::Client::new(),
+            client: crate::net::http_client(&HttpTimeoutConfig::default()),
             base_url,
             upload_base_url,
             access_token: Arc::new(RwLock::new(access_token)),
             pool: Arc::new(RwLock::new(None)),
+            user_cache: UserCache::new(DEFAULT_USER_CACHE_TTL),
         }
     }
 
+    /// Apply connect and request timeouts from `[x_api.http]`.
+    pub fn with_timeouts(mut self, timeouts: &HttpTimeoutConfig) -> Self {
+        self.client = crate::net::http_client(timeouts);
+        self
+    }
+
+    /// Cache `get_me` and username lookups for `ttl` (zero disables caching).
+    pub fn with_cache_ttl(mut self, ttl: Duration) -> Self {
+        self.user_cache = UserCache::new(ttl);
+        self
+    }
+
     /// Set the database pool for usage tracking.
     ///
     /// Called after DB initialization to enable fire-and-forget recording
@@ -86,9 +112,13 @@ impl XApiHttpClient {
     }
 
     /// Update the access token (used by token manager after refresh).
+    ///
+    /// Clears cached user lookups, since the token may belong to a
+    /// different account.
     pub async fn set_access_token(&self, token: String) {
         let mut lock = self.access_token.write().await;
         *lock = token;
+        self.user_cache.clear();
     }
 
     /// Parse rate limit headers from an X API response.
@@ -191,7 +221,7 @@ impl XApiHttpClient {
             .query(query)
             .send()
             .await
-            .map_err(|e| XApiError::Network { source: e })?;
+            .map_err(XApiError::from_transport)?;
 
         let status_code = response.status().as_u16();
         let rate_info = Self::parse_rate_limit_headers(response.headers());
@@ -222,7 +252,7 @@ impl XApiHttpClient {
             .bearer_auth(&*token)
             .send()
             .await
-            .map_err(|e| XApiError::Network { source: e })?;
+            .map_err(XApiError::from_transport)?;
 
         let status_code = response.status().as_u16();
         let rate_info = Self::parse_rate_limit_headers(response.headers());
@@ -258,7 +288,7 @@ impl XApiHttpClient {
             .json(body)
             .send()
             .await
-            .map_err(|e| XApiError::Network { source: e })?;
+            .map_err(XApiError::from_transport)?;
 
         let status_code = response.status().as_u16();
         let rate_info = Self::parse_rate_limit_headers(response.headers());
@@ -113,6 +113,96 @@ async fn get_me_success() {
     assert_eq!(user.public_metrics.followers_count, 100);
 }
 
+fn user_body(id: &str, username: &str) -> serde_json::Value {
+    serde_json::json!({
+        "data": {"id": id, "username": username, "name": username}
+    })
+}
+
+#[tokio::test]
+async fn get_me_cached_within_ttl() {
+    let server = MockServer::start().await;
+    let client = setup_client(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/users/me"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(user_body("u1", "testuser")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let first = client.get_me().await.expect("first get me");
+    let second = client.get_me().await.expect("second get me");
+    assert_eq!(first.id, second.id);
+    // `expect(1)` is verified when the mock server drops.
+}
+
+#[tokio::test]
+async fn get_user_by_username_cached_case_insensitively() {
+    let server = MockServer::start().await;
+    let client = setup_client(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/users/by/username/SomeUser"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(user_body("u2", "SomeUser")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    client
+        .get_user_by_username("SomeUser")
+        .await
+        .expect("lookup");
+    let cached = client
+        .get_user_by_username("someuser")
+        .await
+        .expect("cached lookup");
+    assert_eq!(cached.id, "u2");
+}
+
+#[tokio::test]
+async fn get_me_not_cached_with_zero_ttl() {
+    let server = MockServer::start().await;
+    let client = setup_client(&server)
+        .await
+        .with_cache_ttl(std::time::Duration::ZERO);
+
+    Mock::given(method("GET"))
+        .and(path("/users/me"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(user_body("u1", "testuser")))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    client.get_me().await.expect("first get me");
+    client.get_me().await.expect("second get me");
+}
+
+#[tokio::test]
+async fn set_access_token_invalidates_user_cache() {
+    let server = MockServer::start().await;
+    let client = setup_client(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/users/me"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(user_body("u1", "first")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/users/me"))
+        .and(header("Authorization", "Bearer other-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(user_body("u9", "second")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    assert_eq!(client.get_me().await.expect("get me").id, "u1");
+    client.set_access_token("other-token".to_string()).await;
+    assert_eq!(client.get_me().await.expect("get me").id, "u9");
+}
+
 #[tokio::test]
 async fn error_429_maps_to_rate_limited() {
     let server = MockServer::start().await;
@@ -213,6 +303,37 @@ async fn error_500_maps_to_api_error() {
     }
 }
 
+#[tokio::test]
+async fn slow_response_times_out_at_configured_limit() {
+    let server = MockServer::start().await;
+    let client = setup_client(&server)
+        .await
+        .with_timeouts(&HttpTimeoutConfig {
+            connect_timeout_ms: 1_000,
+            request_timeout_ms: 200,
+        });
+
+    Mock::given(method("GET"))
+        .and(path("/users/me"))
+        .respond_with(ResponseTemplate::new(200).set_delay(std::time::Duration::from_secs(5)))
+        .mount(&server)
+        .await;
+
+    let started = std::time::Instant::now();
+    let result = client.get_me().await;
+    let elapsed = started.elapsed();
+
+    match result {
+        Err(err @ XApiError::Timeout { .. }) => assert!(err.is_retryable()),
+        other => panic!("expected Timeout, got: {other:?}"),
+    }
+    assert!(elapsed >= std::time::Duration::from_millis(200));
+    assert!(
+        elapsed < std::time::Duration::from_secs(2),
+        "took {elapsed:?}"
+    );
+}
+
 #[tokio::test]
 async fn error_messages_are_redacted() {
     let server = MockServer::start().await;
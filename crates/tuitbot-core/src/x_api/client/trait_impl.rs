@@ -12,7 +12,7 @@ use crate::x_api::types::{
 };
 use crate::x_api::XApiClient;
 
-use super::{XApiHttpClient, EXPANSIONS, TWEET_FIELDS, USER_FIELDS};
+use super::{cache, XApiHttpClient, EXPANSIONS, TWEET_FIELDS, USER_FIELDS};
 
 #[async_trait::async_trait]
 impl XApiClient for XApiHttpClient {
@@ -46,10 +46,7 @@ impl XApiClient for XApiHttpClient {
         }
 
         let response = self.get("/tweets/search/recent", &params).await?;
-        let resp: SearchResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: SearchResponse = response.json().await.map_err(XApiError::from_transport)?;
         tracing::debug!(
             query = %query,
             results = resp.data.len(),
@@ -87,7 +84,7 @@ impl XApiClient for XApiHttpClient {
         response
             .json::<MentionResponse>()
             .await
-            .map_err(|e| XApiError::Network { source: e })
+            .map_err(XApiError::from_transport)
     }
 
     async fn post_tweet(&self, text: &str) -> Result<PostedTweet, XApiError> {
@@ -100,10 +97,7 @@ impl XApiClient for XApiHttpClient {
         };
 
         let response = self.post_json("/tweets", &body).await?;
-        let resp: PostTweetResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: PostTweetResponse = response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data)
     }
 
@@ -123,10 +117,7 @@ impl XApiClient for XApiHttpClient {
         };
 
         let response = self.post_json("/tweets", &body).await?;
-        let resp: PostTweetResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: PostTweetResponse = response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data)
     }
 
@@ -161,10 +152,7 @@ impl XApiClient for XApiHttpClient {
         };
 
         let response = self.post_json("/tweets", &body).await?;
-        let resp: PostTweetResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: PostTweetResponse = response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data)
     }
 
@@ -187,10 +175,7 @@ impl XApiClient for XApiHttpClient {
         };
 
         let response = self.post_json("/tweets", &body).await?;
-        let resp: PostTweetResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: PostTweetResponse = response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data)
     }
 
@@ -203,21 +188,21 @@ impl XApiClient for XApiHttpClient {
         ];
 
         let response = self.get(&path, &params).await?;
-        let resp: SingleTweetResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: SingleTweetResponse = response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data)
     }
 
     async fn get_me(&self) -> Result<User, XApiError> {
+        if let Some(user) = self.user_cache.get(cache::ME_KEY) {
+            return Ok(user);
+        }
+
         let params = [("user.fields", USER_FIELDS)];
 
         let response = self.get("/users/me", &params).await?;
-        let resp: UserResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: UserResponse = response.json().await.map_err(XApiError::from_transport)?;
+        self.user_cache
+            .insert(cache::ME_KEY.to_string(), &resp.data);
         Ok(resp.data)
     }
 
@@ -246,18 +231,21 @@ impl XApiClient for XApiHttpClient {
         response
             .json::<SearchResponse>()
             .await
-            .map_err(|e| XApiError::Network { source: e })
+            .map_err(XApiError::from_transport)
     }
 
     async fn get_user_by_username(&self, username: &str) -> Result<User, XApiError> {
+        let key = cache::username_key(username);
+        if let Some(user) = self.user_cache.get(&key) {
+            return Ok(user);
+        }
+
         let path = format!("/users/by/username/{username}");
         let params = [("user.fields", USER_FIELDS)];
 
         let response = self.get(&path, &params).await?;
-        let resp: UserResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: UserResponse = response.json().await.map_err(XApiError::from_transport)?;
+        self.user_cache.insert(key, &resp.data);
         Ok(resp.data)
     }
 
@@ -275,10 +263,7 @@ impl XApiClient for XApiHttpClient {
         };
 
         let response = self.post_json("/tweets", &body).await?;
-        let resp: PostTweetResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: PostTweetResponse = response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data)
     }
 
@@ -290,10 +275,8 @@ impl XApiClient for XApiHttpClient {
         };
 
         let response = self.post_json(&path, &body).await?;
-        let resp: ActionResultResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: ActionResultResponse =
+            response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data.result)
     }
 
@@ -305,10 +288,8 @@ impl XApiClient for XApiHttpClient {
         };
 
         let response = self.post_json(&path, &body).await?;
-        let resp: ActionResultResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: ActionResultResponse =
+            response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data.result)
     }
 
@@ -317,10 +298,8 @@ impl XApiClient for XApiHttpClient {
         let path = format!("/users/{user_id}/following/{target_user_id}");
 
         let response = self.delete(&path).await?;
-        let resp: ActionResultResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: ActionResultResponse =
+            response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data.result)
     }
 
@@ -332,10 +311,8 @@ impl XApiClient for XApiHttpClient {
         };
 
         let response = self.post_json(&path, &body).await?;
-        let resp: ActionResultResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: ActionResultResponse =
+            response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data.result)
     }
 
@@ -344,10 +321,8 @@ impl XApiClient for XApiHttpClient {
         let path = format!("/users/{user_id}/retweets/{tweet_id}");
 
         let response = self.delete(&path).await?;
-        let resp: ActionResultResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: ActionResultResponse =
+            response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data.result)
     }
 
@@ -356,10 +331,7 @@ impl XApiClient for XApiHttpClient {
         let path = format!("/tweets/{tweet_id}");
 
         let response = self.delete(&path).await?;
-        let resp: DeleteTweetResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: DeleteTweetResponse = response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data.deleted)
     }
 
@@ -389,7 +361,7 @@ impl XApiClient for XApiHttpClient {
         response
             .json::<SearchResponse>()
             .await
-            .map_err(|e| XApiError::Network { source: e })
+            .map_err(XApiError::from_transport)
     }
 
     async fn unlike_tweet(&self, user_id: &str, tweet_id: &str) -> Result<bool, XApiError> {
@@ -397,10 +369,8 @@ impl XApiClient for XApiHttpClient {
         let path = format!("/users/{user_id}/likes/{tweet_id}");
 
         let response = self.delete(&path).await?;
-        let resp: ActionResultResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: ActionResultResponse =
+            response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data.result)
     }
 
@@ -428,7 +398,7 @@ impl XApiClient for XApiHttpClient {
         response
             .json::<UsersResponse>()
             .await
-            .map_err(|e| XApiError::Network { source: e })
+            .map_err(XApiError::from_transport)
     }
 
     async fn get_following(
@@ -455,7 +425,7 @@ impl XApiClient for XApiHttpClient {
         response
             .json::<UsersResponse>()
             .await
-            .map_err(|e| XApiError::Network { source: e })
+            .map_err(XApiError::from_transport)
     }
 
     async fn get_user_by_id(&self, user_id: &str) -> Result<User, XApiError> {
@@ -464,10 +434,7 @@ impl XApiClient for XApiHttpClient {
         let params = [("user.fields", USER_FIELDS)];
 
         let response = self.get(&path, &params).await?;
-        let resp: UserResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: UserResponse = response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data)
     }
 
@@ -497,7 +464,7 @@ impl XApiClient for XApiHttpClient {
         response
             .json::<SearchResponse>()
             .await
-            .map_err(|e| XApiError::Network { source: e })
+            .map_err(XApiError::from_transport)
     }
 
     async fn get_bookmarks(
@@ -526,7 +493,7 @@ impl XApiClient for XApiHttpClient {
         response
             .json::<SearchResponse>()
             .await
-            .map_err(|e| XApiError::Network { source: e })
+            .map_err(XApiError::from_transport)
     }
 
     async fn bookmark_tweet(&self, user_id: &str, tweet_id: &str) -> Result<bool, XApiError> {
@@ -537,10 +504,8 @@ impl XApiClient for XApiHttpClient {
         };
 
         let response = self.post_json(&path, &body).await?;
-        let resp: ActionResultResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: ActionResultResponse =
+            response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data.result)
     }
 
@@ -549,10 +514,8 @@ impl XApiClient for XApiHttpClient {
         let path = format!("/users/{user_id}/bookmarks/{tweet_id}");
 
         let response = self.delete(&path).await?;
-        let resp: ActionResultResponse = response
-            .json()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let resp: ActionResultResponse =
+            response.json().await.map_err(XApiError::from_transport)?;
         Ok(resp.data.result)
     }
 
@@ -565,7 +528,7 @@ impl XApiClient for XApiHttpClient {
         response
             .json::<UsersResponse>()
             .await
-            .map_err(|e| XApiError::Network { source: e })
+            .map_err(XApiError::from_transport)
     }
 
     async fn get_tweet_liking_users(
@@ -592,7 +555,7 @@ impl XApiClient for XApiHttpClient {
         response
             .json::<UsersResponse>()
             .await
-            .map_err(|e| XApiError::Network { source: e })
+            .map_err(XApiError::from_transport)
     }
 
     async fn raw_request(
@@ -633,10 +596,7 @@ impl XApiClient for XApiHttpClient {
             }
         }
 
-        let response = builder
-            .send()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let response = builder.send().await.map_err(XApiError::from_transport)?;
 
         let status = response.status().as_u16();
         let rate_limit = Self::parse_rate_limit_headers(response.headers());
@@ -661,10 +621,7 @@ impl XApiClient for XApiHttpClient {
             self.record_usage(parsed.path(), method, status);
         }
 
-        let response_body = response
-            .text()
-            .await
-            .map_err(|e| XApiError::Network { source: e })?;
+        let response_body = response.text().await.map_err(XApiError::from_transport)?;
 
         Ok(RawApiResponse {
             status,
@@ -376,44 +376,7 @@ impl CookieTransport {
                     message: format!("failed to parse verify_credentials JSON: {e}"),
                 })?;
 
-        let id = body
-            .get("id_str")
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
-            .to_string();
-        let username = body
-            .get("screen_name")
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
-            .to_string();
-        let name = body
-            .get("name")
-            .and_then(|v| v.as_str())
-            .unwrap_or_default()
-            .to_string();
-        let profile_image_url = body
-            .get("profile_image_url_https")
-            .and_then(|v| v.as_str())
-            .map(|u| u.replace("_normal.", "_400x400."))
-            .filter(|u| !u.is_empty());
-
-        if id.is_empty() {
-            return Err(XApiError::ApiError {
-                status: 0,
-                message: "verify_credentials returned no user ID".to_string(),
-            });
-        }
-
-        Ok(User {
-            id,
-            username,
-            name,
-            profile_image_url,
-            description: None,
-            location: None,
-            url: None,
-            public_metrics: Default::default(),
-        })
+        self::response::parse_viewer(&body)
     }
 }
 
@@ -428,3 +391,5 @@ pub(crate) use resolve::{
 
 #[cfg(test)]
 mod tests;
+#[cfg(test)]
+mod tests_fixtures;
@@ -11,6 +11,13 @@ use super::features;
 use super::response;
 use super::CookieTransport;
 
+/// Path from the response root to the SearchTimeline `instructions` parent.
+pub(super) const SEARCH_TIMELINE_PATH: &[&str] =
+    &["data", "search_by_raw_query", "search_timeline", "timeline"];
+
+/// Path from the response root to the HomeLatestTimeline `instructions` parent.
+pub(super) const HOME_TIMELINE_PATH: &[&str] = &["data", "home", "home_timeline_urt"];
+
 impl CookieTransport {
     /// Search tweets via the SearchTimeline GraphQL endpoint.
     pub async fn search_timeline(
@@ -33,10 +40,7 @@ impl CookieTransport {
             .graphql_get("SearchTimeline", &variables, &features::read_features())
             .await?;
 
-        let (tweets, next_cursor) = response::parse_timeline(
-            &body,
-            &["data", "search_by_raw_query", "search_timeline", "timeline"],
-        );
+        let (tweets, next_cursor) = response::parse_timeline(&body, SEARCH_TIMELINE_PATH);
 
         Ok(build_search_response(tweets, next_cursor))
     }
@@ -267,8 +271,7 @@ impl CookieTransport {
             .graphql_get("HomeLatestTimeline", &variables, &features::read_features())
             .await?;
 
-        let (tweets, next_cursor) =
-            response::parse_timeline(&body, &["data", "home", "home_timeline_urt"]);
+        let (tweets, next_cursor) = response::parse_timeline(&body, HOME_TIMELINE_PATH);
 
         Ok(build_search_response(tweets, next_cursor))
     }
@@ -345,6 +348,7 @@ mod tests {
             created_at: "2026-01-01T00:00:00Z".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            referenced_tweets: vec![],
         }
     }
 
@@ -358,6 +362,7 @@ mod tests {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         }
     }
 
@@ -5,7 +5,7 @@
 //! and changes frequently.
 
 use crate::error::XApiError;
-use crate::x_api::types::{PostedTweet, PublicMetrics, Tweet, User, UserMetrics};
+use crate::x_api::types::{PostedTweet, PublicMetrics, ReferencedTweet, Tweet, User, UserMetrics};
 
 /// Parse a single tweet from a GraphQL `tweet_results.result` node.
 ///
@@ -54,6 +54,17 @@ pub fn parse_tweet(result: &serde_json::Value) -> Option<Tweet> {
         .and_then(|v| v.as_str())
         .map(|s| s.to_string());
 
+    let referenced_tweets = legacy
+        .get("in_reply_to_status_id_str")
+        .and_then(|v| v.as_str())
+        .map(|id| {
+            vec![ReferencedTweet {
+                kind: "replied_to".to_string(),
+                id: id.to_string(),
+            }]
+        })
+        .unwrap_or_default();
+
     let public_metrics = PublicMetrics {
         retweet_count: legacy
             .get("retweet_count")
@@ -90,6 +101,7 @@ pub fn parse_tweet(result: &serde_json::Value) -> Option<Tweet> {
         created_at: created_at.to_string(),
         public_metrics,
         conversation_id,
+        referenced_tweets,
     })
 }
 
@@ -160,6 +172,7 @@ pub fn parse_user(result: &serde_json::Value) -> Option<User> {
         location,
         url,
         public_metrics,
+        protected: legacy.get("protected").and_then(|v| v.as_bool()),
     })
 }
 
@@ -345,6 +358,49 @@ pub fn check_graphql_errors(body: &serde_json::Value) -> Result<(), XApiError> {
     Ok(())
 }
 
+/// Parse the authenticated viewer from a `verify_credentials.json` response.
+pub fn parse_viewer(body: &serde_json::Value) -> Result<User, XApiError> {
+    let id = body
+        .get("id_str")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let username = body
+        .get("screen_name")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let name = body
+        .get("name")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default()
+        .to_string();
+    let profile_image_url = body
+        .get("profile_image_url_https")
+        .and_then(|v| v.as_str())
+        .map(|u| u.replace("_normal.", "_400x400."))
+        .filter(|u| !u.is_empty());
+
+    if id.is_empty() {
+        return Err(XApiError::ApiError {
+            status: 0,
+            message: "verify_credentials returned no user ID".to_string(),
+        });
+    }
+
+    Ok(User {
+        id,
+        username,
+        name,
+        profile_image_url,
+        description: None,
+        location: None,
+        url: None,
+        public_metrics: Default::default(),
+        protected: None,
+    })
+}
+
 // --- Helper functions ---
 
 /// Navigate a JSON value by a path of keys to reach an array.
@@ -587,6 +587,7 @@ fn build_search_response_with_tweets() {
             created_at: "2026-01-01".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            referenced_tweets: vec![],
         },
         Tweet {
             id: "99".to_string(),
@@ -595,6 +596,7 @@ fn build_search_response_with_tweets() {
             created_at: "2026-01-01".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            referenced_tweets: vec![],
         },
     ];
 
@@ -631,6 +633,7 @@ fn build_users_response_with_users() {
         location: None,
         url: None,
         public_metrics: Default::default(),
+        protected: None,
     }];
 
     let resp = build_users_response(users, Some("next".to_string()));
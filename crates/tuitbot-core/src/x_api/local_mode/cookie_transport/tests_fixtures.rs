@@ -0,0 +1,91 @@
+//! Read-path parsing over recorded GraphQL / REST responses.
+//!
+//! Fixtures in `fixtures/` are trimmed captures of real responses (IDs and
+//! text replaced). They exercise the same paths and parsers the transport
+//! uses, so a format change on X's side shows up here first.
+
+use super::queries::{build_search_response, HOME_TIMELINE_PATH, SEARCH_TIMELINE_PATH};
+use super::response;
+
+fn fixture(json: &str) -> serde_json::Value {
+    serde_json::from_str(json).expect("fixture is valid JSON")
+}
+
+#[test]
+fn search_timeline_fixture() {
+    let body = fixture(include_str!("fixtures/search_timeline.json"));
+    let (tweets, cursor) = response::parse_timeline(&body, SEARCH_TIMELINE_PATH);
+    let resp = build_search_response(tweets, cursor);
+
+    // Plain tweet, visibility-wrapped tweet, and module item; tombstone dropped.
+    let ids: Vec<&str> = resp.data.iter().map(|t| t.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        vec![
+            "1900000000000000001",
+            "1900000000000000002",
+            "1900000000000000004"
+        ]
+    );
+    assert_eq!(resp.meta.result_count, 3);
+    assert_eq!(
+        resp.meta.next_token.as_deref(),
+        Some("DAADDAABCgABGmBottomCursor")
+    );
+
+    let first = &resp.data[0];
+    assert_eq!(first.author_id, "2244994945");
+    assert_eq!(first.created_at, "Tue Mar 03 14:02:11 +0000 2026");
+    assert_eq!(first.public_metrics.like_count, 42);
+    assert_eq!(first.public_metrics.retweet_count, 7);
+    assert_eq!(first.public_metrics.impression_count, 1532);
+    assert_eq!(first.public_metrics.bookmark_count, 5);
+
+    // No `user_id_str`: author falls back to core.user_results.
+    assert_eq!(resp.data[1].author_id, "783214");
+    assert_eq!(
+        resp.data[1].conversation_id.as_deref(),
+        Some("1899999999999999990")
+    );
+}
+
+#[test]
+fn home_latest_timeline_fixture() {
+    let body = fixture(include_str!("fixtures/home_latest_timeline.json"));
+    let (tweets, cursor) = response::parse_timeline(&body, HOME_TIMELINE_PATH);
+    let resp = build_search_response(tweets, cursor);
+
+    assert_eq!(resp.data.len(), 1);
+    assert_eq!(resp.data[0].id, "1900000000000000201");
+    assert_eq!(resp.data[0].author_id, "55667788");
+    assert_eq!(resp.data[0].public_metrics.impression_count, 88);
+    assert_eq!(resp.meta.next_token.as_deref(), Some("HBaAwLHomeBottom"));
+}
+
+#[test]
+fn home_fixture_is_empty_under_search_path() {
+    let body = fixture(include_str!("fixtures/home_latest_timeline.json"));
+    let (tweets, cursor) = response::parse_timeline(&body, SEARCH_TIMELINE_PATH);
+    assert!(tweets.is_empty());
+    assert!(cursor.is_none());
+}
+
+#[test]
+fn verify_credentials_fixture() {
+    let body = fixture(include_str!("fixtures/verify_credentials.json"));
+    let user = response::parse_viewer(&body).expect("viewer");
+
+    assert_eq!(user.id, "1444555666777888999");
+    assert_eq!(user.username, "tuitbot_dev");
+    assert_eq!(user.name, "Tuitbot Dev");
+    assert_eq!(
+        user.profile_image_url.as_deref(),
+        Some("https://pbs.twimg.com/profile_images/1444555/avatar_400x400.jpg")
+    );
+}
+
+#[test]
+fn verify_credentials_without_id_is_error() {
+    let body = serde_json::json!({ "screen_name": "ghost" });
+    assert!(response::parse_viewer(&body).is_err());
+}
@@ -93,7 +93,7 @@ async fn simple_upload(
         .multipart(form)
         .send()
         .await
-        .map_err(|e| XApiError::Network { source: e })?;
+        .map_err(XApiError::from_transport)?;
 
     if !response.status().is_success() {
         let status = response.status().as_u16();
@@ -140,7 +140,7 @@ async fn chunked_upload(
         .multipart(init_form)
         .send()
         .await
-        .map_err(|e| XApiError::Network { source: e })?;
+        .map_err(XApiError::from_transport)?;
 
     if !response.status().is_success() {
         let status = response.status().as_u16();
@@ -176,7 +176,7 @@ async fn chunked_upload(
             .multipart(append_form)
             .send()
             .await
-            .map_err(|e| XApiError::Network { source: e })?;
+            .map_err(XApiError::from_transport)?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
@@ -198,7 +198,7 @@ async fn chunked_upload(
         .multipart(finalize_form)
         .send()
         .await
-        .map_err(|e| XApiError::Network { source: e })?;
+        .map_err(XApiError::from_transport)?;
 
     if !response.status().is_success() {
         let status = response.status().as_u16();
@@ -263,7 +263,7 @@ async fn poll_processing_status(
             .query(&[("command", "STATUS"), ("media_id", media_id)])
             .send()
             .await
-            .map_err(|e| XApiError::Network { source: e })?;
+            .map_err(XApiError::from_transport)?;
 
         if !response.status().is_success() {
             let status = response.status().as_u16();
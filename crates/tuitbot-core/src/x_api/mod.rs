@@ -426,6 +426,7 @@ mod tests {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             })
         }
         async fn get_user_tweets(
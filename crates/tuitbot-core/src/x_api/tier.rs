@@ -88,7 +88,7 @@ pub async fn detect_tier(client: &dyn XApiClient) -> Result<ApiTier, XApiError>
             // Do not infer tier from auth failure
             Err(XApiError::AuthExpired)
         }
-        Err(XApiError::Network { .. }) => {
+        Err(XApiError::Network { .. } | XApiError::Timeout { .. }) => {
             tracing::warn!("Network error during tier detection, defaulting to Free tier");
             let tier = ApiTier::Free;
             log_tier_detection(&tier);
@@ -10,8 +10,8 @@ mod user_types;
 
 pub use tweet_types::{
     DeleteTweetData, DeleteTweetResponse, Includes, MentionResponse, PostTweetRequest,
-    PostTweetResponse, PostedTweet, PublicMetrics, ReplyTo, SearchMeta, SearchResponse,
-    SingleTweetResponse, Tweet,
+    PostTweetResponse, PostedTweet, PublicMetrics, ReferencedTweet, ReplyTo, SearchMeta,
+    SearchResponse, SingleTweetResponse, Tweet,
 };
 
 pub use user_types::{
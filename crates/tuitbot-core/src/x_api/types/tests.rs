@@ -20,6 +20,18 @@ fn deserialize_tweet_missing_optional() {
     assert!(tweet.conversation_id.is_none());
 }
 
+#[test]
+fn deserialize_tweet_referenced_tweets() {
+    let json = r#"{"id":"3","text":"Agreed","author_id":"456","referenced_tweets":[{"type":"quoted","id":"1"},{"type":"replied_to","id":"2"}]}"#;
+    let tweet: Tweet = serde_json::from_str(json).unwrap();
+    assert_eq!(tweet.referenced_tweets.len(), 2);
+    assert_eq!(tweet.replied_to_id(), Some("2"));
+
+    let original: Tweet =
+        serde_json::from_str(r#"{"id":"1","text":"Hi","author_id":"456"}"#).unwrap();
+    assert_eq!(original.replied_to_id(), None);
+}
+
 #[test]
 fn deserialize_search_response() {
     let json = r#"{"data":[{"id":"1","text":"Tweet 1","author_id":"a1"}],"includes":{"users":[{"id":"a1","username":"user1","name":"User One"}]},"meta":{"newest_id":"1","oldest_id":"1","result_count":1,"next_token":"abc123"}}"#;
@@ -202,6 +214,7 @@ fn includes_serde_roundtrip() {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         }],
     };
     let json = serde_json::to_string(&inc).unwrap();
@@ -224,6 +237,7 @@ fn user_serde_roundtrip() {
             following_count: 5,
             tweet_count: 30,
         },
+        protected: None,
     };
     let json = serde_json::to_string(&user).unwrap();
     let back: User = serde_json::from_str(&json).unwrap();
@@ -438,6 +452,7 @@ fn user_response_serde_roundtrip() {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         },
     };
     let json = serde_json::to_string(&resp).unwrap();
@@ -20,6 +20,29 @@ pub struct Tweet {
     /// Conversation thread ID (matches the root tweet's ID).
     #[serde(default)]
     pub conversation_id: Option<String>,
+    /// Tweets this one replies to, quotes, or retweets.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub referenced_tweets: Vec<ReferencedTweet>,
+}
+
+impl Tweet {
+    /// ID of the tweet this one replies to, if it is a reply.
+    pub fn replied_to_id(&self) -> Option<&str> {
+        self.referenced_tweets
+            .iter()
+            .find(|r| r.kind == "replied_to")
+            .map(|r| r.id.as_str())
+    }
+}
+
+/// A reference from one tweet to another (`referenced_tweets` field).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReferencedTweet {
+    /// `"replied_to"`, `"quoted"`, or `"retweeted"`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// ID of the referenced tweet.
+    pub id: String,
 }
 
 /// Public engagement metrics for a tweet.
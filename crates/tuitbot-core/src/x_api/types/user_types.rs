@@ -26,6 +26,10 @@ pub struct User {
     /// User engagement metrics.
     #[serde(default)]
     pub public_metrics: UserMetrics,
+    /// Whether the account's tweets are protected (followers only).
+    /// `None` when the response did not include the field.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protected: Option<bool>,
 }
 
 /// Public metrics for a user profile.
@@ -30,6 +30,9 @@ pub enum ProviderError {
     NotConfigured { message: String },
     /// Upstream server error (HTTP 5xx). Transient — may succeed on retry.
     ServerError { status: u16, message: String },
+    /// The requested resource does not exist or is no longer available
+    /// (deleted, protected, or suspended author).
+    NotFound { message: String },
     /// Catch-all for other provider errors.
     Other { message: String },
 }
@@ -49,6 +52,7 @@ impl fmt::Display for ProviderError {
             Self::ServerError { status, message } => {
                 write!(f, "server error ({status}): {message}")
             }
+            Self::NotFound { message } => write!(f, "not found: {message}"),
             Self::Other { message } => write!(f, "provider error: {message}"),
         }
     }
@@ -67,6 +71,7 @@ impl ProviderError {
             Self::Network { .. } => ErrorCode::XNetworkError,
             Self::NotConfigured { .. } => ErrorCode::XNotConfigured,
             Self::ServerError { .. } => ErrorCode::XApiError,
+            Self::NotFound { .. } => ErrorCode::NotFound,
             Self::Other { .. } => ErrorCode::XApiError,
         }
     }
@@ -93,6 +98,7 @@ impl ProviderError {
             Self::ServerError { status, message } => {
                 format!("X API server error ({status}): {message}")
             }
+            Self::NotFound { message } => format!("Not found on X: {message}"),
             Self::Other { message } => message.clone(),
         }
     }
@@ -149,6 +155,16 @@ mod tests {
         assert!(!err.error_code().is_retryable());
     }
 
+    #[test]
+    fn not_found_maps_correctly() {
+        let err = ProviderError::NotFound {
+            message: "tweet 1 was deleted".to_string(),
+        };
+        assert_eq!(err.error_code(), ErrorCode::NotFound);
+        assert!(!err.error_code().is_retryable());
+        assert!(err.error_message().contains("tweet 1 was deleted"));
+    }
+
     #[test]
     fn provider_error_to_response_produces_valid_json() {
         let err = ProviderError::Forbidden {
@@ -25,6 +25,7 @@ impl SocialReadProvider for MockProvider {
             created_at: "2026-02-25T00:00:00Z".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            referenced_tweets: vec![],
         })
     }
 
@@ -38,6 +39,7 @@ impl SocialReadProvider for MockProvider {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -56,6 +58,7 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -81,6 +84,7 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -106,6 +110,7 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -131,6 +136,7 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -152,6 +158,7 @@ impl SocialReadProvider for MockProvider {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -171,6 +178,7 @@ impl SocialReadProvider for MockProvider {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             }],
             meta: types::UsersMeta {
                 result_count: 1,
@@ -195,6 +203,7 @@ impl SocialReadProvider for MockProvider {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             }],
             meta: types::UsersMeta {
                 result_count: 1,
@@ -213,6 +222,7 @@ impl SocialReadProvider for MockProvider {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -230,6 +240,7 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -255,6 +266,7 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -281,6 +293,7 @@ impl SocialReadProvider for MockProvider {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             })
             .collect::<Vec<_>>();
         let count = users.len() as u32;
@@ -309,6 +322,7 @@ impl SocialReadProvider for MockProvider {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             }],
             meta: types::UsersMeta {
                 result_count: 1,
@@ -46,6 +46,8 @@ pub use tools::manifest::{generate_profile_manifest, ProfileManifest};
 ///
 /// Dispatches to the appropriate server implementation based on profile.
 pub async fn run_server(config: Config, profile: Profile) -> anyhow::Result<()> {
+    tuitbot_core::safety::redact::register_patterns(&config.logging.redact_patterns)
+        .map_err(|e| anyhow::anyhow!("invalid logging.redact_patterns: {e}"))?;
     match profile {
         Profile::Readonly => run_readonly_server(config).await,
         Profile::ApiReadonly => run_api_readonly_server(config).await,
@@ -61,11 +63,22 @@ pub async fn run_server(config: Config, profile: Profile) -> anyhow::Result<()>
 /// Initialize shared state for write / admin profiles: DB, LLM, X client.
 async fn init_write_state(config: Config) -> anyhow::Result<Arc<AppState>> {
     // Initialize database
-    let pool = storage::init_db(&config.storage.db_path).await?;
+    let pool = storage::init_db_with_config(&config.storage.db_path, &config.storage).await?;
 
     // Initialize MCP mutation rate limit
     storage::rate_limits::init_mcp_rate_limit(&pool, config.mcp_policy.max_mutations_per_hour)
         .await?;
+    storage::rate_limits::init_mcp_tool_limits(&pool, &config.mcp_policy.tool_limits).await?;
+    // Action limits, including the daily like/retweet caps the gateway enforces
+    storage::rate_limits::init_rate_limits(&pool, &config.limits, &config.intervals).await?;
+
+    // Persist idempotency keys so a retry after a restart is still caught
+    let idempotency = Arc::new(IdempotencyStore::persistent(pool.clone()));
+
+    // Account-scoped tools start on the account last switched to, shared
+    // with the dashboard server and `tuitbot accounts switch`.
+    let active_account =
+        storage::accounts::ActiveAccount::load(&pool, &startup::data_dir()).await?;
 
     // Try to create LLM provider (optional — content tools won't work without it)
     let llm_provider = match llm::factory::create_provider(&config.llm) {
@@ -127,7 +140,8 @@ async fn init_write_state(config: Config) -> anyhow::Result<Arc<AppState>> {
             x_client,
             authenticated_user_id,
             granted_scopes: vec![],
-            idempotency: Arc::new(IdempotencyStore::new()),
+            idempotency,
+            active_account,
         }));
     }
 
@@ -136,10 +150,14 @@ async fn init_write_state(config: Config) -> anyhow::Result<Arc<AppState>> {
         Option<Box<dyn XApiClient>>,
         Option<String>,
         Vec<String>,
-    ) = match startup::load_tokens_from_file() {
+    ) = match startup::load_tokens(&config.auth) {
         Ok(tokens) if !tokens.is_expired() => {
             let scopes = tokens.scopes.clone();
-            let client = XApiHttpClient::new(tokens.access_token);
+            let client = XApiHttpClient::new(tokens.access_token)
+                .with_timeouts(&config.x_api.http)
+                .with_cache_ttl(std::time::Duration::from_secs(
+                    config.x_api.cache_ttl_seconds,
+                ));
             client.set_pool(pool.clone()).await;
             match client.get_me().await {
                 Ok(user) => {
@@ -180,7 +198,8 @@ async fn init_write_state(config: Config) -> anyhow::Result<Arc<AppState>> {
         x_client,
         authenticated_user_id,
         granted_scopes,
-        idempotency: Arc::new(IdempotencyStore::new()),
+        idempotency,
+        active_account,
     }))
 }
 
@@ -285,9 +304,13 @@ async fn init_readonly_state(
 
     // ── Official X API backend ──────────────────────────────────────
     let (x_client, authenticated_user_id, x_available): (Box<dyn XApiClient>, String, bool) =
-        match startup::load_tokens_from_file() {
+        match startup::load_tokens(&config.auth) {
             Ok(tokens) if !tokens.is_expired() => {
-                let client = XApiHttpClient::new(tokens.access_token);
+                let client = XApiHttpClient::new(tokens.access_token)
+                    .with_timeouts(&config.x_api.http)
+                    .with_cache_ttl(std::time::Duration::from_secs(
+                        config.x_api.cache_ttl_seconds,
+                    ));
                 match client.get_me().await {
                     Ok(user) => {
                         tracing::info!(
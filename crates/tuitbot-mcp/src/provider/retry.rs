@@ -342,6 +342,7 @@ mod tests {
                     created_at: String::new(),
                     public_metrics: PublicMetrics::default(),
                     conversation_id: None,
+                    referenced_tweets: vec![],
                 })
             }
         }
@@ -473,6 +474,7 @@ mod tests {
                         created_at: String::new(),
                         public_metrics: PublicMetrics::default(),
                         conversation_id: None,
+                        referenced_tweets: vec![],
                     })
                 }
             }
@@ -179,6 +179,9 @@ pub(crate) fn map_x_error(e: &XApiError) -> ProviderError {
         XApiError::Network { source } => ProviderError::Network {
             message: source.to_string(),
         },
+        XApiError::Timeout { .. } => ProviderError::Network {
+            message: e.to_string(),
+        },
         XApiError::ApiError { status, message } if *status >= 500 => ProviderError::ServerError {
             status: *status,
             message: message.clone(),
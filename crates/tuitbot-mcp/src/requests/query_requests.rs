@@ -1,5 +1,5 @@
 //! Query/read-only request types: analytics, mutation audit, discovery,
-//! scoring, approval, context intelligence, and telemetry.
+//! scoring, approval, accounts, context intelligence, and telemetry.
 
 use schemars::JsonSchema;
 use serde::Deserialize;
@@ -101,6 +101,14 @@ pub struct ApprovalIdRequest {
     pub id: i64,
 }
 
+// --- Accounts ---
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct SetActiveAccountRequest {
+    /// ID of the account that account-scoped tools should act on
+    pub account_id: String,
+}
+
 // --- Direct X API (queries) ---
 
 #[derive(Debug, Deserialize, JsonSchema)]
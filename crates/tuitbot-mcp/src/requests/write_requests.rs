@@ -21,6 +21,16 @@ pub struct TopicRequest {
     pub topic: Option<String>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct GenerateQuoteRequest {
+    /// The text of the tweet being quoted
+    pub quoted_text: String,
+    /// Username of the quoted tweet's author
+    pub quoted_author: String,
+    /// Optional angle or topic to steer the commentary
+    pub topic_hint: Option<String>,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct ComposeTweetRequest {
     /// The text content of the tweet or thread (JSON array for thread).
@@ -125,6 +135,12 @@ pub struct PostThreadMcpRequest {
     pub media_ids: Option<Vec<Vec<String>>>,
 }
 
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ResumeThreadMcpRequest {
+    /// ID of the partially posted thread (returned as `thread_id` by a failed `x_post_thread`).
+    pub thread_id: i64,
+}
+
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct UploadMediaMcpRequest {
     /// Local file path of the media to upload.
@@ -295,6 +311,12 @@ mod tests {
         assert!(req.media_ids.is_some());
     }
 
+    #[test]
+    fn resume_thread_mcp_request_deser() {
+        let req: ResumeThreadMcpRequest = serde_json::from_str(r#"{"thread_id": 7}"#).unwrap();
+        assert_eq!(req.thread_id, 7);
+    }
+
     #[test]
     fn upload_media_mcp_request_deser() {
         let json = r#"{"file_path": "/tmp/image.jpg", "alt_text": "photo", "dry_run": true}"#;
@@ -92,6 +92,15 @@ impl AdminMcpServer {
                 .await;
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
+    /// Resume a partially posted thread, posting only the tweets that failed. Aborts if the last posted tweet was deleted.
+    #[tool]
+    async fn x_resume_thread(
+        &self,
+        Parameters(req): Parameters<ResumeThreadMcpRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = workflow::x_actions::resume_thread(&self.state, req.thread_id).await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
     /// Upload a media file (image/gif/video) for attaching to tweets. Set dry_run=true to validate without uploading.
     #[tool]
     async fn x_upload_media(
@@ -98,6 +98,7 @@ pub(super) async fn make_state() -> crate::state::SharedState {
         authenticated_user_id: Some("u1".to_string()),
         granted_scopes: vec![],
         idempotency: Arc::new(crate::tools::idempotency::IdempotencyStore::new()),
+        active_account: Default::default(),
     })
 }
 
@@ -60,6 +60,57 @@ async fn admin_get_rate_limits() {
     assert!(!result.is_empty());
 }
 
+#[tokio::test]
+async fn admin_set_active_account_routes_rate_limits() {
+    use tuitbot_core::config::{IntervalsConfig, LimitsConfig};
+    use tuitbot_core::storage::{accounts, rate_limits};
+
+    let state = make_state().await;
+    let id = "acct-second".to_string();
+    accounts::create_account(&state.pool, &id, "Second")
+        .await
+        .unwrap();
+    let limits = LimitsConfig {
+        max_replies_per_day: 7,
+        ..Default::default()
+    };
+    rate_limits::init_rate_limits_for(&state.pool, &id, &limits, &IntervalsConfig::default())
+        .await
+        .unwrap();
+
+    let result = workflow::accounts::set_active_account(
+        &state.pool,
+        &state.active_account,
+        "nonexistent",
+        &state.config,
+    )
+    .await;
+    let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+    assert_eq!(parsed["error"]["code"], "not_found");
+
+    let result = workflow::accounts::set_active_account(
+        &state.pool,
+        &state.active_account,
+        &id,
+        &state.config,
+    )
+    .await;
+    let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+    assert_eq!(parsed["data"]["active_account_id"], id.as_str());
+
+    let active = state.active_account.get().await;
+    let result =
+        workflow::rate_limits::get_rate_limits_for(&state.pool, &active, &state.config).await;
+    let parsed: serde_json::Value = serde_json::from_str(&result).unwrap();
+    let reply = parsed["data"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|l| l["action_type"] == "reply")
+        .expect("reply limit for switched account");
+    assert_eq!(reply["max_requests"], 7);
+}
+
 #[tokio::test]
 async fn admin_get_recent_replies() {
     let state = make_state().await;
@@ -106,8 +106,13 @@ impl AdminMcpServer {
     /// Get current rate limit status for all action types.
     #[tool]
     async fn get_rate_limits(&self) -> Result<CallToolResult, rmcp::ErrorData> {
-        let result =
-            workflow::rate_limits::get_rate_limits(&self.state.pool, &self.state.config).await;
+        let account_id = self.state.active_account.get().await;
+        let result = workflow::rate_limits::get_rate_limits_for(
+            &self.state.pool,
+            &account_id,
+            &self.state.config,
+        )
+        .await;
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
     /// Get replies sent within a time window.
@@ -169,6 +174,15 @@ impl AdminMcpServer {
         let result = tools::scoring::score_tweet(&self.state.config, &input);
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
+    /// Fetch a tweet by ID and score it for reply-worthiness. Returns the score breakdown, matched keywords and a reply/skip verdict.
+    #[tool]
+    async fn score_tweet_by_id(
+        &self,
+        Parameters(req): Parameters<TweetIdRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = workflow::x_actions::score_tweet_by_id(&self.state, &req.tweet_id).await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
     /// List all pending approval queue items (posts waiting for human review).
     #[tool]
     async fn list_pending_approvals(&self) -> Result<CallToolResult, rmcp::ErrorData> {
@@ -229,6 +243,21 @@ impl AdminMcpServer {
         let result = tools::config::validate_config(&self.state.config);
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
+    /// Switch the account that account-scoped tools such as get_rate_limits act on (admin only).
+    #[tool]
+    async fn set_active_account(
+        &self,
+        Parameters(req): Parameters<SetActiveAccountRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = workflow::accounts::set_active_account(
+            &self.state.pool,
+            &self.state.active_account,
+            &req.account_id,
+            &self.state.config,
+        )
+        .await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
     /// Check system health: database connectivity and LLM provider status.
     #[tool]
     async fn health_check(&self) -> Result<CallToolResult, rmcp::ErrorData> {
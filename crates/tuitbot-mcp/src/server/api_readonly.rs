@@ -1,4 +1,4 @@
-//! API read-only MCP server (20 tools, no mutations).
+//! API read-only MCP server (21 tools, no mutations).
 //!
 //! Provides the full X API read surface plus utility and meta tools
 //! for AI agents that need broad read access without mutation capability.
@@ -18,7 +18,7 @@ use crate::state::SharedReadonlyState;
 use crate::tools::response::{ToolMeta, ToolResponse};
 use crate::tools::scoring;
 
-/// API read-only MCP server (20 tools).
+/// API read-only MCP server (21 tools).
 #[derive(Clone)]
 pub struct ApiReadonlyMcpServer {
     state: SharedReadonlyState,
@@ -298,7 +298,7 @@ impl ApiReadonlyMcpServer {
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
-    // ── Utils (3) ───────────────────────────────────────────────────
+    // ── Utils (4) ───────────────────────────────────────────────────
 
     /// Get current Tuitbot configuration (secrets are redacted).
     #[tool]
@@ -333,6 +333,21 @@ impl ApiReadonlyMcpServer {
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
+    /// Fetch a tweet by ID and score it for reply-worthiness. Returns the score breakdown, matched keywords and a reply/skip verdict.
+    #[tool]
+    async fn score_tweet_by_id(
+        &self,
+        Parameters(req): Parameters<TweetIdRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = scoring::score_tweet_by_id(
+            self.state.x_client.as_ref(),
+            &self.state.config,
+            &req.tweet_id,
+        )
+        .await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
     // ── Meta (3) ────────────────────────────────────────────────────
 
     /// Get API read-only profile capabilities: profile name, tool families, authenticated user.
@@ -1,4 +1,4 @@
-//! Minimal read-only MCP server (10 tools, no mutations).
+//! Minimal read-only MCP server (11 tools, no mutations).
 //!
 //! Provides the smallest useful tool surface for AI agents that only need
 //! to read from X API plus a few pure-function utilities.
@@ -15,7 +15,7 @@ use crate::requests::*;
 use crate::state::SharedReadonlyState;
 use crate::tools::scoring;
 
-/// Minimal read-only MCP server (10 tools).
+/// Minimal read-only MCP server (11 tools).
 #[derive(Clone)]
 pub struct ReadonlyMcpServer {
     state: SharedReadonlyState,
@@ -162,7 +162,7 @@ impl ReadonlyMcpServer {
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
-    // ── Utils (2) ───────────────────────────────────────────────────
+    // ── Utils (3) ───────────────────────────────────────────────────
 
     /// Get current Tuitbot configuration (secrets are redacted).
     #[tool]
@@ -190,6 +190,21 @@ impl ReadonlyMcpServer {
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
+    /// Fetch a tweet by ID and score it for reply-worthiness. Returns the score breakdown, matched keywords and a reply/skip verdict.
+    #[tool]
+    async fn score_tweet_by_id(
+        &self,
+        Parameters(req): Parameters<TweetIdRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = scoring::score_tweet_by_id(
+            self.state.x_client.as_ref(),
+            &self.state.config,
+            &req.tweet_id,
+        )
+        .await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
     // ── Health (1) ──────────────────────────────────────────────────
 
     /// Check readonly profile health by verifying X client connectivity via get_me.
@@ -58,7 +58,7 @@ fn toolkit_error_to_envelope(err: ToolkitError) -> CallToolResult {
             XApiError::Forbidden { .. } => {
                 ToolResponse::error(ErrorCode::XForbidden, err.to_string())
             }
-            XApiError::Network { .. } => {
+            XApiError::Network { .. } | XApiError::Timeout { .. } => {
                 ToolResponse::error(ErrorCode::XNetworkError, err.to_string())
             }
             _ => ToolResponse::error(ErrorCode::XApiError, err.to_string()),
@@ -144,6 +144,29 @@ impl WriteMcpServer {
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
 
+    /// Generate quote-tweet commentary that adds a take on the quoted tweet (max 280 chars). Requires LLM provider.
+    #[tool]
+    async fn generate_quote(
+        &self,
+        Parameters(req): Parameters<GenerateQuoteRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        if self.state.llm_provider.is_none() {
+            return Ok(CallToolResult::success(vec![Content::text(
+                ToolResponse::llm_not_configured().to_json(),
+            )]));
+        }
+        let result = workflow::content::generate_quote(
+            &self.state,
+            &self.state.config.business,
+            &req.quoted_text,
+            &req.quoted_author,
+            req.topic_hint.as_deref(),
+            &self.state.config,
+        )
+        .await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
+
     /// Get current capabilities, tier info, scope analysis, endpoint group availability, rate-limit remaining, and actionable guidance.
     #[tool]
     async fn get_capabilities(&self) -> Result<CallToolResult, rmcp::ErrorData> {
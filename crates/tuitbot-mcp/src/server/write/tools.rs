@@ -106,8 +106,13 @@ impl WriteMcpServer {
     /// Get current rate limit status for all action types.
     #[tool]
     async fn get_rate_limits(&self) -> Result<CallToolResult, rmcp::ErrorData> {
-        let result =
-            workflow::rate_limits::get_rate_limits(&self.state.pool, &self.state.config).await;
+        let account_id = self.state.active_account.get().await;
+        let result = workflow::rate_limits::get_rate_limits_for(
+            &self.state.pool,
+            &account_id,
+            &self.state.config,
+        )
+        .await;
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
     /// Get replies sent within a time window.
@@ -169,6 +174,15 @@ impl WriteMcpServer {
         let result = tools::scoring::score_tweet(&self.state.config, &input);
         Ok(CallToolResult::success(vec![Content::text(result)]))
     }
+    /// Fetch a tweet by ID and score it for reply-worthiness. Returns the score breakdown, matched keywords and a reply/skip verdict.
+    #[tool]
+    async fn score_tweet_by_id(
+        &self,
+        Parameters(req): Parameters<TweetIdRequest>,
+    ) -> Result<CallToolResult, rmcp::ErrorData> {
+        let result = workflow::x_actions::score_tweet_by_id(&self.state, &req.tweet_id).await;
+        Ok(CallToolResult::success(vec![Content::text(result)]))
+    }
     /// List all pending approval queue items (posts waiting for human review).
     #[tool]
     async fn list_pending_approvals(&self) -> Result<CallToolResult, rmcp::ErrorData> {
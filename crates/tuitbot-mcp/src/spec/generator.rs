@@ -71,7 +71,7 @@ fn endpoint_to_tool_entry(ep: &EndpointDef) -> ToolEntry {
         requires_x_client: true,
         requires_llm: false,
         requires_db,
-        requires_scopes: ep.scopes.iter().map(|s| (*s).to_string()).collect(),
+        required_scopes: ep.scopes.iter().map(|s| (*s).to_string()).collect(),
         requires_user_auth: true,
         requires_elevated_access: is_admin_only,
         profiles: ep.profiles.to_vec(),
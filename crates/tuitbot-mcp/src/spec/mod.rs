@@ -40,4 +40,4 @@ pub use params::{EndpointDef, HttpMethod, ParamDef, ParamType};
 pub const X_API_SPEC_VERSION: &str = "1.3.0";
 
 /// Schema version for the manifest format.
-pub const MCP_SCHEMA_VERSION: &str = "1.2";
+pub const MCP_SCHEMA_VERSION: &str = "1.3";
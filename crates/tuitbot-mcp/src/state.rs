@@ -14,6 +14,7 @@ use std::sync::Arc;
 
 use tuitbot_core::config::Config;
 use tuitbot_core::llm::LlmProvider;
+use tuitbot_core::storage::accounts::ActiveAccount;
 use tuitbot_core::storage::DbPool;
 use tuitbot_core::x_api::XApiClient;
 
@@ -90,6 +91,9 @@ pub struct AppState {
     pub granted_scopes: Vec<String>,
     /// Idempotency guard for mutation dedup.
     pub idempotency: Arc<IdempotencyStore>,
+    /// Account that account-scoped tools act on (switched by the admin-only
+    /// `set_active_account` tool).
+    pub active_account: ActiveAccount,
 }
 
 /// Thread-safe reference to shared full-profile state.
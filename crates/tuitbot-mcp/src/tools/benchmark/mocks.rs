@@ -21,6 +21,7 @@ impl SocialReadProvider for BenchMockProvider {
             created_at: "2026-02-25T00:00:00Z".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            referenced_tweets: vec![],
         })
     }
     async fn get_user_by_username(&self, u: &str) -> Result<User, ProviderError> {
@@ -33,6 +34,7 @@ impl SocialReadProvider for BenchMockProvider {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
     async fn search_tweets(
@@ -77,6 +79,7 @@ impl SocialReadProvider for BenchMockProvider {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
     async fn get_me(&self) -> Result<User, ProviderError> {
@@ -89,6 +92,7 @@ impl SocialReadProvider for BenchMockProvider {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 }
@@ -66,12 +66,18 @@ pub(super) async fn setup_db() -> DbPool {
         min_action_delay_seconds: 30,
         max_action_delay_seconds: 120,
         max_replies_per_author_per_day: 1,
+        max_replies_per_conversation_per_day: 2,
+        max_likes_per_day: 20,
+        max_retweets_per_day: 5,
+        discovery_likes: false,
         banned_phrases: vec![],
         product_mention_ratio: 0.2,
+        ramp: None,
     };
     let intervals = IntervalsConfig {
         mentions_check_seconds: 300,
         discovery_search_seconds: 600,
+        discovery_max_pages: 1,
         content_post_window_seconds: 14400,
         thread_interval_seconds: 604800,
     };
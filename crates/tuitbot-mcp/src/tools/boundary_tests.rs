@@ -41,6 +41,7 @@ mod tests {
             "x_quote_tweet",
             "x_delete_tweet",
             "x_post_thread",
+            "x_resume_thread",
             "x_like_tweet",
             "x_unlike_tweet",
             "x_follow_user",
@@ -94,9 +95,9 @@ mod tests {
         ]
     }
 
-    /// Tools that are admin-only (universal request tools).
+    /// Tools that are admin-only (universal request tools and account switching).
     fn admin_only_tools() -> &'static [&'static str] {
-        &["x_get", "x_post", "x_put", "x_delete"]
+        &["x_get", "x_post", "x_put", "x_delete", "set_active_account"]
     }
 
     /// Extract `#[tool]`-annotated function names from server source code.
@@ -285,7 +286,7 @@ mod tests {
             .iter()
             .filter(|t| t.profiles.contains(&Profile::Readonly))
             .count();
-        assert_eq!(count, 14, "Readonly has {count} tools (expected 14)");
+        assert_eq!(count, 15, "Readonly has {count} tools (expected 15)");
     }
 
     #[test]
@@ -296,7 +297,7 @@ mod tests {
             .iter()
             .filter(|t| t.profiles.contains(&Profile::ApiReadonly))
             .count();
-        assert_eq!(count, 45, "ApiReadonly has {count} tools (expected 45)");
+        assert_eq!(count, 46, "ApiReadonly has {count} tools (expected 46)");
     }
 
     #[test]
@@ -307,8 +308,8 @@ mod tests {
             .iter()
             .filter(|t| t.profiles.contains(&Profile::Write))
             .count();
-        // 69 curated write + 44 generated - 4 admin-only = 113
-        assert_eq!(count, 113, "Write has {count} tools (expected 113)");
+        // 72 curated write + 44 generated - 4 admin-only = 116
+        assert_eq!(count, 116, "Write has {count} tools (expected 116)");
     }
 
     #[test]
@@ -319,8 +320,8 @@ mod tests {
             .iter()
             .filter(|t| t.profiles.contains(&Profile::Admin))
             .count();
-        // 73 curated + 44 generated + 16 ads + 7 compliance/stream = 140 (superset of write)
-        assert_eq!(count, 140, "Admin has {count} tools (expected 140)");
+        // 77 curated + 44 generated + 16 ads + 7 compliance/stream = 144 (superset of write)
+        assert_eq!(count, 144, "Admin has {count} tools (expected 144)");
     }
 
     // ── Mutation safety ─────────────────────────────────────────────
@@ -552,8 +553,8 @@ mod tests {
         let fn_names = extract_tool_fn_names(source);
         assert_eq!(
             fn_names.len(),
-            10,
-            "readonly.rs has {} tools (expected 10): {:?}",
+            11,
+            "readonly.rs has {} tools (expected 11): {:?}",
             fn_names.len(),
             fn_names
         );
@@ -565,8 +566,8 @@ mod tests {
         let fn_names = extract_tool_fn_names(source);
         assert_eq!(
             fn_names.len(),
-            20,
-            "api_readonly.rs has {} tools (expected 20): {:?}",
+            21,
+            "api_readonly.rs has {} tools (expected 21): {:?}",
             fn_names.len(),
             fn_names
         );
@@ -576,11 +577,11 @@ mod tests {
     fn write_server_tool_count() {
         let source = write_server_source();
         let fn_names = extract_tool_fn_names(source);
-        // 73 curated - 4 admin-only universal request tools = 69
+        // 76 curated - 4 admin-only universal request tools = 72
         assert_eq!(
             fn_names.len(),
-            69,
-            "write/ has {} tools (expected 69): {:?}",
+            72,
+            "write/ has {} tools (expected 72): {:?}",
             fn_names.len(),
             fn_names
         );
@@ -590,11 +591,11 @@ mod tests {
     fn admin_server_tool_count() {
         let source = admin_server_source();
         let fn_names = extract_tool_fn_names(source);
-        // All 73 curated tools including universal request tools
+        // All 76 curated tools including universal request tools
         assert_eq!(
             fn_names.len(),
-            73,
-            "admin/ has {} tools (expected 73): {:?}",
+            76,
+            "admin/ has {} tools (expected 76): {:?}",
             fn_names.len(),
             fn_names
         );
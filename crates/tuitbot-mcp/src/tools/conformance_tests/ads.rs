@@ -257,7 +257,7 @@ mod tests {
         for tool_name in ADS_READS {
             let t = find_tool(&manifest.tools, tool_name);
             assert!(
-                t.requires_scopes.contains(&"ads.read".to_string()),
+                t.required_scopes.contains(&"ads.read".to_string()),
                 "Ads read '{tool_name}' missing ads.read scope"
             );
         }
@@ -269,7 +269,7 @@ mod tests {
         for tool_name in ADS_MUTATIONS {
             let t = find_tool(&manifest.tools, tool_name);
             assert!(
-                t.requires_scopes.contains(&"ads.write".to_string()),
+                t.required_scopes.contains(&"ads.write".to_string()),
                 "Ads mutation '{tool_name}' missing ads.write scope"
             );
         }
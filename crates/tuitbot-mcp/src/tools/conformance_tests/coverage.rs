@@ -297,7 +297,7 @@ fn generate_report() -> CoverageReport {
             requires_db: tool.requires_db,
             requires_user_auth: tool.requires_user_auth,
             requires_elevated_access: tool.requires_elevated_access,
-            scopes: tool.requires_scopes.clone(),
+            scopes: tool.required_scopes.clone(),
             profiles: tool
                 .profiles
                 .iter()
@@ -698,10 +698,10 @@ async fn generate_coverage_report() {
     // Profile delta assertions
     for p in &report.profiles {
         match p.profile.as_str() {
-            "readonly" => assert_eq!(p.delta, 0, "Readonly delta should be 0"),
-            "api_readonly" => assert_eq!(p.delta, 5, "ApiReadonly delta should be +5"),
-            "write" => assert_eq!(p.delta, 9, "Write delta should be +9"),
-            "admin" => assert_eq!(p.delta, 32, "Admin delta should be +32"),
+            "readonly" => assert_eq!(p.delta, 1, "Readonly delta should be +1"),
+            "api_readonly" => assert_eq!(p.delta, 6, "ApiReadonly delta should be +6"),
+            "write" => assert_eq!(p.delta, 12, "Write delta should be +12"),
+            "admin" => assert_eq!(p.delta, 35, "Admin delta should be +35"),
             _ => {}
         }
     }
@@ -197,7 +197,7 @@ mod tests {
         for tool_name in DM_READS {
             let t = find_tool(&manifest.tools, tool_name);
             assert!(
-                t.requires_scopes.contains(&"dm.read".to_string()),
+                t.required_scopes.contains(&"dm.read".to_string()),
                 "DM read '{tool_name}' missing dm.read scope"
             );
         }
@@ -209,7 +209,7 @@ mod tests {
         for tool_name in DM_MUTATIONS {
             let t = find_tool(&manifest.tools, tool_name);
             assert!(
-                t.requires_scopes.contains(&"dm.write".to_string()),
+                t.required_scopes.contains(&"dm.write".to_string()),
                 "DM mutation '{tool_name}' missing dm.write scope"
             );
         }
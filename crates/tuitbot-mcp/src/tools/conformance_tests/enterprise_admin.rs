@@ -252,7 +252,7 @@ mod tests {
         for tool_name in &compliance_job_tools {
             let t = find_tool(&manifest.tools, tool_name);
             assert!(
-                t.requires_scopes.contains(&"compliance.write".to_string()),
+                t.required_scopes.contains(&"compliance.write".to_string()),
                 "Compliance job tool '{tool_name}' missing compliance.write scope"
             );
         }
@@ -263,7 +263,7 @@ mod tests {
         let manifest = generate_manifest();
         let t = find_tool(&manifest.tools, "x_v2_usage_tweets");
         assert!(
-            t.requires_scopes.contains(&"usage.read".to_string()),
+            t.required_scopes.contains(&"usage.read".to_string()),
             "x_v2_usage_tweets missing usage.read scope"
         );
     }
@@ -279,7 +279,7 @@ mod tests {
         for tool_name in &stream_tools {
             let t = find_tool(&manifest.tools, tool_name);
             assert!(
-                t.requires_scopes.contains(&"tweet.read".to_string()),
+                t.required_scopes.contains(&"tweet.read".to_string()),
                 "Stream rule tool '{tool_name}' missing tweet.read scope"
             );
         }
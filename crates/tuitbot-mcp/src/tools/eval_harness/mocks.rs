@@ -98,6 +98,7 @@ impl XApiClient for MockXApiClient {
             created_at: "2026-02-24T00:00:00Z".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            referenced_tweets: vec![],
         })
     }
 
@@ -111,6 +112,7 @@ impl XApiClient for MockXApiClient {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -142,6 +144,7 @@ impl XApiClient for MockXApiClient {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -260,6 +263,7 @@ pub async fn make_test_state(
         authenticated_user_id: Some("u1".to_string()),
         granted_scopes: vec![],
         idempotency: Arc::new(crate::tools::idempotency::IdempotencyStore::new()),
+        active_account: Default::default(),
     })
 }
 
@@ -278,6 +282,7 @@ pub fn sample_tweet(id: &str, text: &str, author_id: &str) -> Tweet {
             bookmark_count: 0,
         },
         conversation_id: None,
+        referenced_tweets: vec![],
     }
 }
 
@@ -295,6 +300,7 @@ pub fn sample_user(id: &str, username: &str, followers: u64) -> User {
             following_count: 100,
             tweet_count: 500,
         },
+        protected: None,
     }
 }
 
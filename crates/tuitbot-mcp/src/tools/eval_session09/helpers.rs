@@ -44,6 +44,7 @@ pub async fn make_test_state(
         authenticated_user_id: Some("u1".to_string()),
         granted_scopes: vec![],
         idempotency: Arc::new(IdempotencyStore::new()),
+        active_account: Default::default(),
     })
 }
 
@@ -20,7 +20,8 @@ pub async fn run_scenario_e() -> ScenarioResult {
     let params = r#"{"candidate_id":"t1","text":"Great point!"}"#;
     let first = state
         .idempotency
-        .check_and_record("propose_and_queue_replies", params);
+        .check_and_record("propose_and_queue_replies", params)
+        .await;
     steps.push(StepResult {
         tool_name: "idempotency_check_first".to_string(),
         latency_ms: 0,
@@ -52,7 +53,8 @@ pub async fn run_scenario_e() -> ScenarioResult {
     // Step 3: IdempotencyStore same params within 30s -> duplicate error
     let duplicate = state
         .idempotency
-        .check_and_record("propose_and_queue_replies", params);
+        .check_and_record("propose_and_queue_replies", params)
+        .await;
     let dup_valid = duplicate
         .as_ref()
         .map(|j| validate_schema(j))
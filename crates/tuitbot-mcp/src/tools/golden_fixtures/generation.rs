@@ -30,6 +30,7 @@ impl SocialReadProvider for GoldenMockProvider {
                 bookmark_count: 0,
             },
             conversation_id: None,
+            referenced_tweets: vec![],
         })
     }
 
@@ -47,6 +48,7 @@ impl SocialReadProvider for GoldenMockProvider {
                 following_count: 50,
                 tweet_count: 200,
             },
+            protected: None,
         })
     }
 
@@ -65,6 +67,7 @@ impl SocialReadProvider for GoldenMockProvider {
                 created_at: "2026-02-25T00:00:00Z".to_string(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: Some(Includes {
                 users: vec![User {
@@ -76,6 +79,7 @@ impl SocialReadProvider for GoldenMockProvider {
                     location: None,
                     url: None,
                     public_metrics: UserMetrics::default(),
+                    protected: None,
                 }],
             }),
             meta: SearchMeta {
@@ -119,6 +123,7 @@ impl SocialReadProvider for GoldenMockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -158,6 +163,7 @@ impl SocialReadProvider for GoldenMockProvider {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -177,6 +183,7 @@ impl SocialReadProvider for GoldenMockProvider {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             }],
             meta: UsersMeta {
                 result_count: 1,
@@ -210,6 +217,7 @@ impl SocialReadProvider for GoldenMockProvider {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -263,6 +271,7 @@ impl SocialReadProvider for GoldenMockProvider {
                     location: None,
                     url: None,
                     public_metrics: UserMetrics::default(),
+                    protected: None,
                 })
                 .collect(),
             meta: UsersMeta {
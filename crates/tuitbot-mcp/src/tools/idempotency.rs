@@ -1,80 +1,188 @@
-//! In-memory idempotency guard for mutation tools.
+//! Idempotency guard for mutation tools.
 //!
-//! [`IdempotencyStore`] hashes `(tool_name, params)` and rejects duplicate
-//! mutation calls within a configurable time window (default 30 seconds).
-//! Protects against agent retry storms sending the same mutation twice.
+//! [`IdempotencyStore`] fingerprints `(tool_name, params)` and rejects
+//! duplicate mutation calls within a configurable time window (default 30
+//! seconds). Protects against agent retry storms sending the same mutation
+//! twice. Once the original call succeeds its result is cached, and a
+//! duplicate gets that result back instead of an error.
+//!
+//! By default keys live in memory only. Write profiles use
+//! [`IdempotencyStore::persistent`], which also stores them in the
+//! `mcp_idempotency_keys` table so an agent retrying after a server crash or
+//! restart still hits the guard. Expired rows are pruned as keys are checked.
 //!
 //! DB-backed idempotency (5-minute window) and audit recording are handled
 //! by the unified `MutationGateway` in `tuitbot-core::mutation_gateway`.
 
-use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
-use std::hash::{Hash, Hasher};
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
+use tuitbot_core::storage::mutation_audit::compute_params_hash;
+use tuitbot_core::storage::{idempotency_keys, DbPool};
+
+/// A fingerprint seen within the window.
+struct Entry {
+    first_seen: Instant,
+    /// Result JSON of the original call, once it succeeded.
+    result: Option<String>,
+}
+
 /// Thread-safe idempotency store using fingerprint hashing.
 pub struct IdempotencyStore {
-    entries: Mutex<HashMap<u64, Instant>>,
+    entries: Mutex<HashMap<String, Entry>>,
+    /// Correlation ID of each in-flight mutation → its fingerprint.
+    in_flight: Mutex<HashMap<String, String>>,
     window: Duration,
+    pool: Option<DbPool>,
 }
 
 impl IdempotencyStore {
-    /// Create a new store with the default 30-second dedup window.
+    /// Create an in-memory store with the default 30-second dedup window.
     pub fn new() -> Self {
+        Self::with_window(Duration::from_secs(30))
+    }
+
+    /// Create a store that also persists keys to the database, so they
+    /// survive restarts.
+    pub fn persistent(pool: DbPool) -> Self {
         Self {
-            entries: Mutex::new(HashMap::new()),
-            window: Duration::from_secs(30),
+            pool: Some(pool),
+            ..Self::new()
         }
     }
 
     /// Create a store with a custom dedup window (for testing).
-    #[cfg(test)]
-    pub fn with_window(window: Duration) -> Self {
+    pub(crate) fn with_window(window: Duration) -> Self {
         Self {
             entries: Mutex::new(HashMap::new()),
+            in_flight: Mutex::new(HashMap::new()),
             window,
+            pool: None,
         }
     }
 
     /// Check if this `(tool_name, params)` combination is a duplicate.
     ///
-    /// Returns `Some(error_json)` if the call is a duplicate within the
-    /// dedup window, `None` if the call should proceed.
-    /// Records the fingerprint on first occurrence.
-    pub fn check_and_record(&self, tool_name: &str, params_json: &str) -> Option<String> {
-        let fingerprint = Self::compute_fingerprint(tool_name, params_json);
+    /// Returns `Some(response_json)` if the call is a duplicate within the
+    /// dedup window: the cached result when the original call succeeded,
+    /// otherwise an error. Returns `None` if the call should proceed.
+    ///
+    /// The fingerprint is reserved in memory before any await, so of two
+    /// concurrent identical calls exactly one proceeds. Persistent stores
+    /// then claim the key in the database, where an existing row (another
+    /// process, or a call from before a restart) also makes this a duplicate.
+    pub async fn check_and_record(&self, tool_name: &str, params_json: &str) -> Option<String> {
+        let key = compute_params_hash(tool_name, params_json);
         let now = Instant::now();
 
-        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner());
-
-        // Evict expired entries.
-        entries.retain(|_, ts| now.duration_since(*ts) < self.window);
-
-        if let Some(first_seen) = entries.get(&fingerprint) {
-            let age_ms = now.duration_since(*first_seen).as_millis();
-            return Some(
-                crate::contract::ToolResponse::error(
-                    crate::contract::ErrorCode::ValidationError,
-                    format!(
-                        "Duplicate {tool_name} call detected ({age_ms}ms ago). \
-                         Wait {}s before retrying identical mutations.",
-                        self.window.as_secs()
-                    ),
-                )
-                .to_json(),
+        {
+            let mut entries = self.lock_entries();
+
+            // Evict expired entries.
+            entries.retain(|_, e| now.duration_since(e.first_seen) < self.window);
+
+            if let Some(entry) = entries.get(&key) {
+                let age_ms = now.duration_since(entry.first_seen).as_millis();
+                return Some(self.duplicate_response(tool_name, age_ms, entry.result.as_deref()));
+            }
+
+            entries.insert(
+                key.clone(),
+                Entry {
+                    first_seen: now,
+                    result: None,
+                },
             );
         }
 
-        entries.insert(fingerprint, now);
-        None
+        let pool = self.pool.as_ref()?;
+        let ttl = self.window.as_secs().max(1);
+        if let Err(e) = idempotency_keys::prune_expired_keys(pool, ttl).await {
+            tracing::warn!(error = %e, "Failed to prune idempotency keys");
+        }
+        match idempotency_keys::try_insert_key(pool, &key, tool_name).await {
+            Ok(true) => None,
+            Ok(false) => {
+                let response = match idempotency_keys::find_live_key(pool, &key, ttl).await {
+                    Ok(row) => row.and_then(|row| row.response),
+                    Err(e) => {
+                        tracing::warn!(error = %e, "Idempotency key lookup failed");
+                        None
+                    }
+                };
+                if let Some(entry) = self.lock_entries().get_mut(&key) {
+                    entry.result = response.clone();
+                }
+                Some(self.duplicate_response(tool_name, 0, response.as_deref()))
+            }
+            Err(e) => {
+                tracing::warn!(error = %e, "Failed to persist idempotency key");
+                None
+            }
+        }
+    }
+
+    /// Associate a proceeding call with its audit correlation ID, so its
+    /// result can be cached by [`Self::record_result`].
+    pub fn track(&self, tool_name: &str, params_json: &str, correlation_id: &str) {
+        self.lock_in_flight().insert(
+            correlation_id.to_string(),
+            compute_params_hash(tool_name, params_json),
+        );
+    }
+
+    /// Cache the result of a successful call for replay to duplicates.
+    pub async fn record_result(&self, correlation_id: &str, result_json: &str) {
+        let Some(key) = self.lock_in_flight().remove(correlation_id) else {
+            return;
+        };
+        if let Some(entry) = self.lock_entries().get_mut(&key) {
+            entry.result = Some(result_json.to_string());
+        }
+        if let Some(pool) = &self.pool {
+            if let Err(e) = idempotency_keys::set_response(pool, &key, result_json).await {
+                tracing::warn!(error = %e, "Failed to persist idempotent result");
+            }
+        }
+    }
+
+    /// Stop tracking a call that failed. Duplicates stay blocked until the
+    /// window expires.
+    pub fn forget(&self, correlation_id: &str) {
+        self.lock_in_flight().remove(correlation_id);
+    }
+
+    fn duplicate_response(&self, tool_name: &str, age_ms: u128, result: Option<&str>) -> String {
+        match result {
+            Some(cached) => crate::contract::ToolResponse::success(serde_json::json!({
+                "duplicate": true,
+                "cached_result": serde_json::from_str::<serde_json::Value>(cached)
+                    .unwrap_or_else(|_| serde_json::Value::String(cached.to_string())),
+                "message": format!(
+                    "Identical {tool_name} was already executed successfully. \
+                     Returning cached result."
+                ),
+            }))
+            .to_json(),
+            None => crate::contract::ToolResponse::error(
+                crate::contract::ErrorCode::ValidationError,
+                format!(
+                    "Duplicate {tool_name} call detected ({age_ms}ms ago). \
+                     Wait {}s before retrying identical mutations.",
+                    self.window.as_secs()
+                ),
+            )
+            .to_json(),
+        }
+    }
+
+    fn lock_entries(&self) -> std::sync::MutexGuard<'_, HashMap<String, Entry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
     }
 
-    fn compute_fingerprint(tool_name: &str, params_json: &str) -> u64 {
-        let mut hasher = DefaultHasher::new();
-        tool_name.hash(&mut hasher);
-        params_json.hash(&mut hasher);
-        hasher.finish()
+    fn lock_in_flight(&self) -> std::sync::MutexGuard<'_, HashMap<String, String>> {
+        self.in_flight.lock().unwrap_or_else(|e| e.into_inner())
     }
 }
 
@@ -82,49 +190,171 @@ impl IdempotencyStore {
 mod tests {
     use super::*;
 
-    #[test]
-    fn blocks_duplicate_within_window() {
+    #[tokio::test]
+    async fn blocks_duplicate_within_window() {
         let store = IdempotencyStore::new();
-        let first = store.check_and_record("post_tweet", r#"{"text":"hi"}"#);
+        let first = store
+            .check_and_record("post_tweet", r#"{"text":"hi"}"#)
+            .await;
         assert!(first.is_none(), "first call should succeed");
 
-        let second = store.check_and_record("post_tweet", r#"{"text":"hi"}"#);
+        let second = store
+            .check_and_record("post_tweet", r#"{"text":"hi"}"#)
+            .await;
         assert!(second.is_some(), "duplicate should be blocked");
         let err_json = second.unwrap();
         assert!(err_json.contains("Duplicate"));
         assert!(err_json.contains("validation_error"));
     }
 
-    #[test]
-    fn allows_different_params() {
+    #[tokio::test]
+    async fn allows_different_params() {
         let store = IdempotencyStore::new();
-        let first = store.check_and_record("post_tweet", r#"{"text":"hello"}"#);
+        let first = store
+            .check_and_record("post_tweet", r#"{"text":"hello"}"#)
+            .await;
         assert!(first.is_none());
 
-        let second = store.check_and_record("post_tweet", r#"{"text":"world"}"#);
+        let second = store
+            .check_and_record("post_tweet", r#"{"text":"world"}"#)
+            .await;
         assert!(second.is_none(), "different params should be allowed");
     }
 
-    #[test]
-    fn allows_after_eviction() {
+    #[tokio::test]
+    async fn allows_after_eviction() {
         let store = IdempotencyStore::with_window(Duration::from_millis(1));
-        let first = store.check_and_record("post_tweet", r#"{"text":"hi"}"#);
+        let first = store
+            .check_and_record("post_tweet", r#"{"text":"hi"}"#)
+            .await;
         assert!(first.is_none());
 
         // Wait for the window to expire.
         std::thread::sleep(Duration::from_millis(5));
 
-        let second = store.check_and_record("post_tweet", r#"{"text":"hi"}"#);
+        let second = store
+            .check_and_record("post_tweet", r#"{"text":"hi"}"#)
+            .await;
         assert!(second.is_none(), "should be allowed after window expires");
     }
 
-    #[test]
-    fn different_tools_same_params_allowed() {
+    #[tokio::test]
+    async fn different_tools_same_params_allowed() {
         let store = IdempotencyStore::new();
-        let first = store.check_and_record("like_tweet", r#"{"tweet_id":"123"}"#);
+        let first = store
+            .check_and_record("like_tweet", r#"{"tweet_id":"123"}"#)
+            .await;
         assert!(first.is_none());
 
-        let second = store.check_and_record("retweet", r#"{"tweet_id":"123"}"#);
+        let second = store
+            .check_and_record("retweet", r#"{"tweet_id":"123"}"#)
+            .await;
         assert!(second.is_none(), "different tools should be independent");
     }
+
+    #[tokio::test]
+    async fn duplicate_after_success_returns_cached_result() {
+        let store = IdempotencyStore::new();
+        let params = r#"{"text":"hi"}"#;
+        assert!(store.check_and_record("post_tweet", params).await.is_none());
+        store.track("post_tweet", params, "corr-1");
+        store.record_result("corr-1", r#"{"tweet_id":"tw1"}"#).await;
+
+        let replay = store.check_and_record("post_tweet", params).await.unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&replay).unwrap();
+        assert_eq!(parsed["success"], true);
+        assert_eq!(parsed["data"]["duplicate"], true);
+        assert_eq!(parsed["data"]["cached_result"]["tweet_id"], "tw1");
+    }
+
+    #[tokio::test]
+    async fn concurrent_duplicates_let_exactly_one_through() {
+        let store = std::sync::Arc::new(IdempotencyStore::new());
+        let calls: Vec<_> = (0..8)
+            .map(|_| {
+                let store = store.clone();
+                tokio::spawn(async move {
+                    store
+                        .check_and_record("post_tweet", r#"{"text":"hi"}"#)
+                        .await
+                })
+            })
+            .collect();
+
+        let mut proceeded = 0;
+        for call in calls {
+            if call.await.unwrap().is_none() {
+                proceeded += 1;
+            }
+        }
+        assert_eq!(proceeded, 1);
+    }
+
+    #[tokio::test]
+    async fn persistent_concurrent_duplicates_across_stores_let_exactly_one_through() {
+        let pool = tuitbot_core::storage::init_test_db().await.unwrap();
+        // Two stores over one database stand in for two server processes.
+        let a = IdempotencyStore::persistent(pool.clone());
+        let b = IdempotencyStore::persistent(pool);
+        let params = r#"{"tweet_id":"123"}"#;
+
+        let (first, second) = tokio::join!(
+            a.check_and_record("like_tweet", params),
+            b.check_and_record("like_tweet", params),
+        );
+
+        assert_eq!(
+            [first.is_none(), second.is_none()]
+                .iter()
+                .filter(|p| **p)
+                .count(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn persistent_key_survives_restart() {
+        let pool = tuitbot_core::storage::init_test_db().await.unwrap();
+        let params = r#"{"text":"hi"}"#;
+
+        let before_crash = IdempotencyStore::persistent(pool.clone());
+        assert!(before_crash
+            .check_and_record("post_tweet", params)
+            .await
+            .is_none());
+        before_crash.track("post_tweet", params, "corr-1");
+        before_crash
+            .record_result("corr-1", r#"{"tweet_id":"tw1"}"#)
+            .await;
+        drop(before_crash);
+
+        // A fresh store over the same database stands in for a restarted server.
+        let after_restart = IdempotencyStore::persistent(pool);
+        let replay = after_restart
+            .check_and_record("post_tweet", params)
+            .await
+            .expect("replayed key is recognised after restart");
+        let parsed: serde_json::Value = serde_json::from_str(&replay).unwrap();
+        assert_eq!(parsed["data"]["cached_result"]["tweet_id"], "tw1");
+    }
+
+    #[tokio::test]
+    async fn persistent_key_without_result_still_blocks_after_restart() {
+        let pool = tuitbot_core::storage::init_test_db().await.unwrap();
+        let params = r#"{"tweet_id":"123"}"#;
+
+        let before_crash = IdempotencyStore::persistent(pool.clone());
+        assert!(before_crash
+            .check_and_record("like_tweet", params)
+            .await
+            .is_none());
+        drop(before_crash);
+
+        let after_restart = IdempotencyStore::persistent(pool);
+        let dup = after_restart
+            .check_and_record("like_tweet", params)
+            .await
+            .unwrap();
+        assert!(dup.contains("Duplicate"));
+    }
 }
@@ -16,7 +16,6 @@ pub(super) const ALL_SIX: &[Profile] = &[
     Profile::UtilityWrite,
 ];
 /// All four original profiles (no utility profiles).
-#[allow(dead_code)]
 pub(super) const ALL_FOUR: &[Profile] = &[
     Profile::Readonly,
     Profile::ApiReadonly,
@@ -55,6 +54,18 @@ pub(super) const X_READ_ERR: &[ErrorCode] = &[
     ErrorCode::XApiError,
 ];
 
+/// X API read errors plus input validation and missing resources.
+pub(super) const X_READ_SCORE_ERR: &[ErrorCode] = &[
+    ErrorCode::XNotConfigured,
+    ErrorCode::XRateLimited,
+    ErrorCode::XAuthExpired,
+    ErrorCode::XForbidden,
+    ErrorCode::XNetworkError,
+    ErrorCode::XApiError,
+    ErrorCode::InvalidInput,
+    ErrorCode::NotFound,
+];
+
 /// X API read errors + no-user-id.
 pub(super) const X_READ_USER_ERR: &[ErrorCode] = &[
     ErrorCode::XNotConfigured,
@@ -156,7 +167,7 @@ pub(super) fn tool(
         requires_x_client,
         requires_llm,
         requires_db,
-        requires_scopes: vec![],
+        required_scopes: vec![],
         requires_user_auth: false,
         requires_elevated_access: false,
         profiles: profiles.to_vec(),
@@ -186,7 +197,7 @@ pub(super) fn x_tool(
         requires_x_client: true,
         requires_llm: false,
         requires_db,
-        requires_scopes: scopes.iter().map(|s| (*s).to_string()).collect(),
+        required_scopes: scopes.iter().map(|s| (*s).to_string()).collect(),
         requires_user_auth,
         requires_elevated_access,
         profiles: profiles.to_vec(),
@@ -3,7 +3,7 @@
 
 use crate::contract::error_code::ErrorCode;
 
-use super::builders::{tool, ALL_SIX, DB_ERR, LLM_ERR, WRITE_UP, WRITE_UP_AND_API_RO};
+use super::builders::{tool, ADMIN_ONLY, ALL_SIX, DB_ERR, LLM_ERR, WRITE_UP, WRITE_UP_AND_API_RO};
 use super::types::{Lane, Profile, ToolCategory, ToolEntry};
 
 /// Workflow-only curated tools (non-X-API).
@@ -248,6 +248,17 @@ pub(super) fn workflow_tools() -> Vec<ToolEntry> {
             WRITE_UP,
             LLM_ERR,
         ),
+        tool(
+            "generate_quote",
+            ToolCategory::Content,
+            Lane::Workflow,
+            false,
+            false,
+            true,
+            true,
+            WRITE_UP,
+            LLM_ERR,
+        ),
         tool(
             "generate_thread",
             ToolCategory::Content,
@@ -288,6 +299,17 @@ pub(super) fn workflow_tools() -> Vec<ToolEntry> {
             ],
             &[],
         ),
+        tool(
+            "set_active_account",
+            ToolCategory::Config,
+            Lane::Workflow,
+            false,
+            false,
+            false,
+            true,
+            ADMIN_ONLY,
+            &[ErrorCode::DbError, ErrorCode::NotFound],
+        ),
         // ── Capabilities & Health ────────────────────────────────────
         tool(
             "get_capabilities",
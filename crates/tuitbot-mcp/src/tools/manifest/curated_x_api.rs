@@ -4,9 +4,9 @@
 use crate::contract::error_code::ErrorCode;
 
 use super::builders::{
-    x_tool, ADMIN_ONLY, ALL_SIX, API_RO, WRITE_UP_AND_API_RO_AND_UTIL_WRITE,
-    WRITE_UP_AND_UTIL_WRITE, X_ENGAGE_ERR, X_READ_ERR, X_READ_USER_ERR, X_REQUEST_MUTATION_ERR,
-    X_REQUEST_READ_ERR, X_WRITE_ERR,
+    x_tool, ADMIN_ONLY, ALL_FOUR, ALL_SIX, API_RO, WRITE_UP, WRITE_UP_AND_API_RO_AND_UTIL_WRITE,
+    WRITE_UP_AND_UTIL_WRITE, X_ENGAGE_ERR, X_READ_ERR, X_READ_SCORE_ERR, X_READ_USER_ERR,
+    X_REQUEST_MUTATION_ERR, X_REQUEST_READ_ERR, X_WRITE_ERR,
 };
 use super::types::{Lane, ToolCategory, ToolEntry};
 
@@ -171,6 +171,19 @@ pub(super) fn x_api_tools() -> Vec<ToolEntry> {
             WRITE_UP_AND_API_RO_AND_UTIL_WRITE,
             X_READ_ERR,
         ),
+        // ── X API Scoring (fetch + score, non-utility profiles) ─────
+        x_tool(
+            "score_tweet_by_id",
+            ToolCategory::Scoring,
+            Lane::Shared,
+            false,
+            false,
+            &["tweet.read", "users.read"],
+            true,
+            false,
+            ALL_FOUR,
+            X_READ_SCORE_ERR,
+        ),
         // ── X API Read (api-readonly only) ──────────────────────────
         x_tool(
             "x_get_me",
@@ -261,6 +274,34 @@ pub(super) fn x_api_tools() -> Vec<ToolEntry> {
                 ErrorCode::PolicyError,
             ],
         ),
+        x_tool(
+            "x_resume_thread",
+            ToolCategory::Write,
+            Lane::Workflow,
+            true,
+            true,
+            &["tweet.read", "tweet.write", "users.read"],
+            true,
+            false,
+            WRITE_UP,
+            &[
+                ErrorCode::XNotConfigured,
+                ErrorCode::XRateLimited,
+                ErrorCode::XAuthExpired,
+                ErrorCode::XForbidden,
+                ErrorCode::XNetworkError,
+                ErrorCode::XApiError,
+                ErrorCode::InvalidInput,
+                ErrorCode::ThreadPartialFailure,
+                ErrorCode::DbError,
+                ErrorCode::ScraperMutationBlocked,
+                ErrorCode::PolicyDeniedBlocked,
+                ErrorCode::PolicyDeniedRateLimited,
+                ErrorCode::PolicyDeniedHardRule,
+                ErrorCode::PolicyDeniedUserRule,
+                ErrorCode::PolicyError,
+            ],
+        ),
         // ── X API Engage (shared: workflow in write/admin, toolkit in utility) ──
         x_tool(
             "x_like_tweet",
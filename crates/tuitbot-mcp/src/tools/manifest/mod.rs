@@ -14,18 +14,21 @@
 //! | `builders`          | `tool()`, `x_tool()`, profile/error constants             |
 //! | `curated_workflow`  | Non-X curated tool entries (analytics, config, composite) |
 //! | `curated_x_api`     | X API curated tool entries (reads, writes, engages)       |
+//! | `scopes`            | Central tool → OAuth scope mapping and lookups            |
 
 mod builders;
 mod curated_composite;
 mod curated_workflow;
 mod curated_x_api;
 mod profile;
+mod scopes;
 mod types;
 
 #[cfg(test)]
 mod tests;
 
 pub use profile::generate_profile_manifest;
+pub use scopes::missing_scopes;
 pub use types::{Lane, Profile, ProfileManifest, ToolCategory, ToolEntry, ToolManifest};
 
 /// Build the complete tool manifest from the source-of-truth lookup table.
@@ -52,5 +55,6 @@ fn all_curated_tools() -> Vec<ToolEntry> {
     let mut tools = curated_workflow::workflow_tools();
     tools.extend(curated_composite::composite_tools());
     tools.extend(curated_x_api::x_api_tools());
+    scopes::apply_workflow_scopes(&mut tools);
     tools
 }
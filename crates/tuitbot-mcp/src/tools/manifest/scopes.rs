@@ -0,0 +1,79 @@
+//! Central tool → OAuth scope mapping.
+//!
+//! X API tools declare their scopes inline (`x_tool()` and the spec pack).
+//! Workflow and composite tools that reach X indirectly are mapped here so
+//! every manifest entry carries an accurate `required_scopes` list. Tools
+//! that only touch the DB or LLM list none.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use super::types::ToolEntry;
+
+/// Scopes for workflow/composite tools that call X through the core layer.
+///
+/// Universal request tools (`x_get`, `x_post`, ...) are intentionally absent:
+/// their scopes depend on the target endpoint.
+const WORKFLOW_TOOL_SCOPES: &[(&str, &[&str])] = &[
+    ("approve_all", &["tweet.read", "tweet.write", "users.read"]),
+    ("approve_item", &["tweet.read", "tweet.write", "users.read"]),
+    (
+        "compose_tweet",
+        &["tweet.read", "tweet.write", "users.read"],
+    ),
+    ("find_reply_opportunities", &["tweet.read", "users.read"]),
+    ("get_author_context", &["tweet.read", "users.read"]),
+    (
+        "propose_and_queue_replies",
+        &["tweet.read", "tweet.write", "users.read"],
+    ),
+    ("recommend_engagement_action", &["tweet.read", "users.read"]),
+];
+
+/// Fill `required_scopes` on curated entries from [`WORKFLOW_TOOL_SCOPES`].
+pub(super) fn apply_workflow_scopes(tools: &mut [ToolEntry]) {
+    for tool in tools.iter_mut().filter(|t| t.required_scopes.is_empty()) {
+        if let Some((_, scopes)) = WORKFLOW_TOOL_SCOPES.iter().find(|(n, _)| *n == tool.name) {
+            tool.required_scopes = scopes.iter().map(|s| (*s).to_string()).collect();
+        }
+    }
+}
+
+/// Lazily-built index of tool name → required scopes over the full manifest.
+fn scope_index() -> &'static HashMap<String, Vec<String>> {
+    static INDEX: OnceLock<HashMap<String, Vec<String>>> = OnceLock::new();
+    INDEX.get_or_init(|| {
+        super::generate_manifest()
+            .tools
+            .into_iter()
+            .map(|t| (t.name, t.required_scopes))
+            .collect()
+    })
+}
+
+/// Scopes required by `tool_name`.
+///
+/// Accepts both manifest names (`x_post_tweet`) and the short gateway names
+/// used by workflow mutations (`post_tweet`). Unknown tools require none.
+pub fn required_scopes(tool_name: &str) -> &'static [String] {
+    let index = scope_index();
+    index
+        .get(tool_name)
+        .or_else(|| index.get(&format!("x_{tool_name}")))
+        .map_or(&[], Vec::as_slice)
+}
+
+/// Scopes required by `tool_name` that are absent from `granted`.
+///
+/// An empty `granted` list means scopes are unknown (e.g. scraper backend or
+/// tokens saved before scope tracking) and yields no missing scopes.
+pub fn missing_scopes(tool_name: &str, granted: &[String]) -> Vec<String> {
+    if granted.is_empty() {
+        return vec![];
+    }
+    required_scopes(tool_name)
+        .iter()
+        .filter(|s| !granted.contains(s))
+        .cloned()
+        .collect()
+}
@@ -224,4 +224,59 @@ fn manifest_snapshot() {
 }
 
 use crate::contract::error_code::ErrorCode;
-use crate::tools::manifest::{generate_manifest, generate_profile_manifest, Lane};
+use crate::tools::manifest::scopes::required_scopes;
+use crate::tools::manifest::{generate_manifest, generate_profile_manifest, missing_scopes, Lane};
+
+// ── Required scopes mapping ──────────────────────────────────────────
+
+#[test]
+fn db_only_tools_list_no_scopes() {
+    let manifest = generate_manifest();
+    for t in manifest.tools.iter().filter(|t| !t.requires_x_client) {
+        assert!(
+            t.required_scopes.is_empty(),
+            "DB/LLM-only tool {} should list no scopes",
+            t.name
+        );
+    }
+}
+
+#[test]
+fn workflow_x_tools_have_mapped_scopes() {
+    let manifest = generate_manifest();
+    for name in ["approve_item", "compose_tweet", "find_reply_opportunities"] {
+        let t = manifest.tools.iter().find(|t| t.name == name).unwrap();
+        assert!(!t.required_scopes.is_empty(), "{name} should list scopes");
+    }
+}
+
+#[test]
+fn required_scopes_accepts_gateway_names() {
+    assert_eq!(
+        required_scopes("post_tweet"),
+        required_scopes("x_post_tweet")
+    );
+    assert!(required_scopes("post_tweet").contains(&"tweet.write".to_string()));
+    assert!(required_scopes("get_stats").is_empty());
+    assert!(required_scopes("no_such_tool").is_empty());
+}
+
+#[test]
+fn missing_scopes_reports_absent_grants() {
+    let granted = vec!["tweet.read".to_string(), "users.read".to_string()];
+    assert_eq!(
+        missing_scopes("x_like_tweet", &granted),
+        vec!["like.read", "like.write"]
+    );
+    assert!(missing_scopes("x_like_tweet", &[]).is_empty());
+    assert!(missing_scopes("x_search_tweets", &granted).is_empty());
+}
+
+#[test]
+fn profile_manifest_serializes_required_scopes() {
+    let manifest = generate_profile_manifest(crate::state::Profile::Write);
+    let json = serde_json::to_value(&manifest).unwrap();
+    for tool in json["tools"].as_array().unwrap() {
+        assert!(tool["required_scopes"].is_array(), "{}", tool["name"]);
+    }
+}
@@ -30,9 +30,9 @@ pub struct ToolEntry {
     pub requires_llm: bool,
     /// Whether the tool requires database access.
     pub requires_db: bool,
-    /// OAuth scopes required by this tool (empty for non-X tools).
-    #[serde(default, skip_serializing_if = "Vec::is_empty")]
-    pub requires_scopes: Vec<String>,
+    /// OAuth scopes required by this tool (empty for DB/LLM-only tools).
+    #[serde(default, alias = "requires_scopes")]
+    pub required_scopes: Vec<String>,
     /// Whether the tool requires OAuth user-context authentication.
     #[serde(default, skip_serializing_if = "is_false")]
     pub requires_user_auth: bool,
@@ -70,7 +70,7 @@ pub fn guidance_for(tool_name: &str, result_data: &Value) -> RollbackGuidance {
             undo_params: None,
             note: Some("Deleted tweets cannot be restored.".into()),
         },
-        "x_post_thread" => {
+        "x_post_thread" | "x_resume_thread" => {
             let ids = result_data
                 .get("thread_tweet_ids")
                 .and_then(|v| v.as_array())
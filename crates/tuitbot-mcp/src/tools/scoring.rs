@@ -1,13 +1,17 @@
-//! Scoring tool: score a tweet for reply-worthiness.
+//! Scoring tools: score a tweet for reply-worthiness.
 
 use std::time::Instant;
 
 use serde::Serialize;
 
 use tuitbot_core::config::Config;
-use tuitbot_core::scoring::{ScoringEngine, TweetData};
+use tuitbot_core::error::XApiError;
+use tuitbot_core::scoring::{ScoringEngine, SkipReason, TweetData};
+use tuitbot_core::x_api::XApiClient;
 
-use super::response::{ToolMeta, ToolResponse};
+use super::response::{ErrorCode, ToolMeta, ToolResponse};
+use crate::contract::error::{provider_error_to_response, ProviderError};
+use crate::provider::x_api::map_x_error;
 
 #[derive(Serialize)]
 struct ScoreOut {
@@ -19,6 +23,8 @@ struct ScoreOut {
     reply_count: f32,
     content_type: f32,
     meets_threshold: bool,
+    /// Signal that most held a skipped tweet below the threshold.
+    skip_reason: Option<SkipReason>,
 }
 
 /// Input for scoring a tweet.
@@ -36,16 +42,7 @@ pub struct ScoreTweetInput<'a> {
 pub fn score_tweet(config: &Config, input: &ScoreTweetInput<'_>) -> String {
     let start = Instant::now();
 
-    let keywords: Vec<String> = config
-        .business
-        .product_keywords
-        .iter()
-        .chain(config.business.competitor_keywords.iter())
-        .chain(config.business.effective_industry_topics().iter())
-        .cloned()
-        .collect();
-
-    let engine = ScoringEngine::new(config.scoring.clone(), keywords);
+    let engine = ScoringEngine::new(config.scoring.clone(), scoring_keywords(config));
 
     let tweet_data = TweetData {
         text: input.text.to_string(),
@@ -70,6 +67,101 @@ pub fn score_tweet(config: &Config, input: &ScoreTweetInput<'_>) -> String {
         reply_count: score.reply_count,
         content_type: score.content_type,
         meets_threshold: score.meets_threshold,
+        skip_reason: score.skip_reason(&config.scoring),
+    };
+
+    let elapsed = start.elapsed().as_millis() as u64;
+    ToolResponse::success(out)
+        .with_meta(ToolMeta::new(elapsed))
+        .to_json()
+}
+
+#[derive(Serialize)]
+struct ScoreByIdOut {
+    tweet_id: String,
+    author_username: Option<String>,
+    score: ScoreOut,
+    matched_keywords: Vec<String>,
+    /// `"reply"` when the score meets the threshold, otherwise `"skip"`.
+    verdict: &'static str,
+}
+
+/// Fetch a tweet by ID and score it with the 6-signal scoring engine.
+///
+/// The author profile is looked up best-effort for the follower signal;
+/// a tweet the X API reports missing (HTTP 404) returns a `not_found` error.
+pub async fn score_tweet_by_id(client: &dyn XApiClient, config: &Config, tweet_id: &str) -> String {
+    let start = Instant::now();
+
+    if !is_valid_tweet_id(tweet_id) {
+        let elapsed = start.elapsed().as_millis() as u64;
+        return ToolResponse::error(
+            ErrorCode::InvalidInput,
+            format!("tweet_id must be 1-19 digits, got {tweet_id:?}"),
+        )
+        .with_meta(ToolMeta::new(elapsed))
+        .to_json();
+    }
+
+    let tweet = match client.get_tweet(tweet_id).await {
+        Ok(tweet) => tweet,
+        Err(e) => return provider_error_to_response(&tweet_lookup_error(&e), start),
+    };
+    let author = client.get_user_by_id(&tweet.author_id).await.ok();
+
+    let keywords = scoring_keywords(config);
+    let text_lower = tweet.text.to_lowercase();
+    // Industry topics fall back to the product keywords, so skip repeats.
+    let mut matched_keywords: Vec<String> = Vec::new();
+    for keyword in &keywords {
+        if !keyword.is_empty()
+            && text_lower.contains(&keyword.to_lowercase())
+            && !matched_keywords.contains(keyword)
+        {
+            matched_keywords.push(keyword.clone());
+        }
+    }
+
+    let engine = ScoringEngine::new(config.scoring.clone(), keywords);
+    let tweet_data = TweetData {
+        text: tweet.text.clone(),
+        created_at: tweet.created_at.clone(),
+        likes: tweet.public_metrics.like_count,
+        retweets: tweet.public_metrics.retweet_count,
+        replies: tweet.public_metrics.reply_count,
+        author_username: author
+            .as_ref()
+            .map(|u| u.username.clone())
+            .unwrap_or_default(),
+        author_followers: author
+            .as_ref()
+            .map(|u| u.public_metrics.followers_count)
+            .unwrap_or(0),
+        has_media: false,
+        is_quote_tweet: false,
+    };
+    let score = engine.score_tweet(&tweet_data);
+
+    let out = ScoreByIdOut {
+        tweet_id: tweet.id,
+        author_username: author.map(|u| u.username),
+        verdict: if score.meets_threshold {
+            "reply"
+        } else {
+            "skip"
+        },
+        matched_keywords,
+        score: ScoreOut {
+            total: score.total,
+            keyword_relevance: score.keyword_relevance,
+            follower: score.follower,
+            recency: score.recency,
+            engagement: score.engagement,
+            reply_count: score.reply_count,
+            content_type: score.content_type,
+            meets_threshold: score.meets_threshold,
+            skip_reason: score.skip_reason(&config.scoring),
+        },
     };
 
     let elapsed = start.elapsed().as_millis() as u64;
@@ -77,3 +169,170 @@ pub fn score_tweet(config: &Config, input: &ScoreTweetInput<'_>) -> String {
         .with_meta(ToolMeta::new(elapsed))
         .to_json()
 }
+
+/// Product, competitor and industry keywords, as used by the discovery loop.
+fn scoring_keywords(config: &Config) -> Vec<String> {
+    config
+        .business
+        .product_keywords
+        .iter()
+        .chain(config.business.competitor_keywords.iter())
+        .chain(config.business.effective_industry_topics().iter())
+        .cloned()
+        .collect()
+}
+
+/// Map a failed tweet lookup: an X API 404 is `not_found` for this tool,
+/// anything else keeps the provider's usual mapping.
+fn tweet_lookup_error(e: &XApiError) -> ProviderError {
+    match e {
+        XApiError::ApiError {
+            status: 404,
+            message,
+        } => ProviderError::NotFound {
+            message: message.clone(),
+        },
+        other => map_x_error(other),
+    }
+}
+
+/// X tweet IDs are unsigned 64-bit snowflakes: at most 19 decimal digits.
+fn is_valid_tweet_id(id: &str) -> bool {
+    !id.is_empty() && id.len() <= 19 && id.bytes().all(|b| b.is_ascii_digit())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+    use tuitbot_core::x_api::types::*;
+
+    /// X client serving one live tweet (`"100"`); every other ID is deleted.
+    struct MockX;
+
+    #[async_trait::async_trait]
+    impl XApiClient for MockX {
+        async fn search_tweets(
+            &self,
+            _: &str,
+            _: u32,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+        async fn get_mentions(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<MentionResponse, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+        async fn post_tweet(&self, _: &str) -> Result<PostedTweet, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+        async fn reply_to_tweet(&self, _: &str, _: &str) -> Result<PostedTweet, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+        async fn get_tweet(&self, id: &str) -> Result<Tweet, XApiError> {
+            if id != "100" {
+                return Err(XApiError::ApiError {
+                    status: 404,
+                    message: format!("Could not find tweet with id: [{id}]."),
+                });
+            }
+            Ok(Tweet {
+                id: id.to_string(),
+                text: "Any good Rust CLI tools for scheduling posts?".to_string(),
+                author_id: "u1".to_string(),
+                created_at: chrono::Utc::now().to_rfc3339(),
+                public_metrics: PublicMetrics {
+                    like_count: 12,
+                    reply_count: 2,
+                    ..Default::default()
+                },
+                conversation_id: None,
+                referenced_tweets: vec![],
+            })
+        }
+        async fn get_me(&self) -> Result<User, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+        async fn get_user_tweets(
+            &self,
+            _: &str,
+            _: u32,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+        async fn get_user_by_username(&self, _: &str) -> Result<User, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+        async fn get_user_by_id(&self, id: &str) -> Result<User, XApiError> {
+            Ok(User {
+                id: id.to_string(),
+                username: "alice".to_string(),
+                name: "Alice".to_string(),
+                profile_image_url: None,
+                description: None,
+                location: None,
+                url: None,
+                public_metrics: UserMetrics {
+                    followers_count: 5_000,
+                    ..Default::default()
+                },
+                protected: None,
+            })
+        }
+    }
+
+    fn config() -> Config {
+        let mut config = Config::default();
+        config.business.product_keywords = vec!["rust".to_string(), "golang".to_string()];
+        config.business.competitor_keywords = vec!["Scheduling".to_string()];
+        config
+    }
+
+    async fn call(tweet_id: &str) -> Value {
+        let json = score_tweet_by_id(&MockX, &config(), tweet_id).await;
+        serde_json::from_str(&json).expect("valid JSON")
+    }
+
+    #[tokio::test]
+    async fn scores_fetched_tweet() {
+        let v = call("100").await;
+        assert_eq!(v["success"], true);
+        let data = &v["data"];
+        assert_eq!(data["tweet_id"], "100");
+        assert_eq!(data["author_username"], "alice");
+        assert_eq!(
+            data["matched_keywords"],
+            serde_json::json!(["rust", "Scheduling"])
+        );
+        assert!(data["score"]["total"].as_f64().unwrap() > 0.0);
+        let expected = if data["score"]["meets_threshold"] == true {
+            "reply"
+        } else {
+            "skip"
+        };
+        assert_eq!(data["verdict"], expected);
+    }
+
+    #[tokio::test]
+    async fn deleted_tweet_is_not_found() {
+        let v = call("200").await;
+        assert_eq!(v["success"], false);
+        assert_eq!(v["error"]["code"], "not_found");
+        assert_eq!(v["error"]["retryable"], false);
+    }
+
+    #[tokio::test]
+    async fn malformed_id_is_rejected() {
+        for id in ["", "abc", "12a4", "12345678901234567890"] {
+            let v = call(id).await;
+            assert_eq!(v["error"]["code"], "invalid_input", "id {id:?}");
+        }
+    }
+}
@@ -88,6 +88,7 @@ impl SocialReadProvider for MockProvider {
             created_at: "2026-02-25T00:00:00Z".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            referenced_tweets: vec![],
         })
     }
 
@@ -101,6 +102,7 @@ impl SocialReadProvider for MockProvider {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -119,6 +121,7 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -162,6 +165,7 @@ impl SocialReadProvider for MockProvider {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -201,6 +205,7 @@ impl SocialReadProvider for MockProvider {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -220,6 +225,7 @@ impl SocialReadProvider for MockProvider {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             }],
             meta: UsersMeta {
                 result_count: 1,
@@ -253,6 +259,7 @@ impl SocialReadProvider for MockProvider {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -304,6 +311,7 @@ impl SocialReadProvider for MockProvider {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             })
             .collect::<Vec<_>>();
         let count = users.len() as u32;
@@ -0,0 +1,41 @@
+//! Account tools: set_active_account.
+
+use std::time::Instant;
+
+use tuitbot_core::config::Config;
+use tuitbot_core::storage::accounts::ActiveAccount;
+use tuitbot_core::storage::DbPool;
+
+use crate::tools::response::{ErrorCode, ToolMeta, ToolResponse};
+
+/// Make `account_id` the account that account-scoped tools act on.
+///
+/// Fails with `not_found` and keeps the current account when the ID is
+/// unknown or archived.
+pub async fn set_active_account(
+    pool: &DbPool,
+    active: &ActiveAccount,
+    account_id: &str,
+    config: &Config,
+) -> String {
+    let start = Instant::now();
+    let result = active.switch(pool, account_id).await;
+    let elapsed = start.elapsed().as_millis() as u64;
+    let meta = ToolMeta::new(elapsed)
+        .with_workflow(config.mode.to_string(), config.effective_approval_mode());
+
+    match result {
+        Ok(true) => ToolResponse::success(serde_json::json!({ "active_account_id": account_id }))
+            .with_meta(meta)
+            .to_json(),
+        Ok(false) => ToolResponse::error(
+            ErrorCode::NotFound,
+            format!("Account not found: {account_id}"),
+        )
+        .with_meta(meta)
+        .to_json(),
+        Err(e) => ToolResponse::db_error(format!("Error switching account: {e}"))
+            .with_meta(meta)
+            .to_json(),
+    }
+}
@@ -155,9 +155,10 @@ pub async fn get_analytics_summary(pool: &DbPool, config: &Config) -> String {
     let content_breakdown = storage::analytics::get_content_breakdown(pool)
         .await
         .unwrap_or_default();
-    let best_times = storage::analytics::get_best_times(pool)
-        .await
-        .unwrap_or_default();
+    let best_times =
+        storage::analytics::get_best_times(pool, config.analytics.min_engagement_sample)
+            .await
+            .unwrap_or_default();
 
     #[derive(Serialize)]
     struct SummaryOut {
@@ -219,6 +220,7 @@ pub async fn get_analytics_summary(pool: &DbPool, config: &Config) -> String {
         top_topics: topics_to_out(summary.top_topics),
         best_times: best_times
             .into_iter()
+            .filter(|s| !s.low_confidence)
             .take(10)
             .map(|s| BestTimeOut {
                 day_of_week: s.day_of_week,
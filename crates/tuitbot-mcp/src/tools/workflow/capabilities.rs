@@ -556,12 +556,18 @@ mod tests {
             min_action_delay_seconds: 30,
             max_action_delay_seconds: 120,
             max_replies_per_author_per_day: 1,
+            max_replies_per_conversation_per_day: 2,
+            max_likes_per_day: 20,
+            max_retweets_per_day: 5,
+            discovery_likes: false,
             banned_phrases: vec![],
             product_mention_ratio: 0.2,
+            ramp: None,
         };
         let intervals = tuitbot_core::config::IntervalsConfig {
             mentions_check_seconds: 300,
             discovery_search_seconds: 600,
+            discovery_max_pages: 1,
             content_post_window_seconds: 14400,
             thread_interval_seconds: 604800,
         };
@@ -629,7 +635,7 @@ mod tests {
         let rate_limits = parsed["data"]["rate_limits"]
             .as_array()
             .expect("rate_limits array");
-        assert_eq!(rate_limits.len(), 5);
+        assert_eq!(rate_limits.len(), 7);
         assert_eq!(parsed["data"]["recommended_max_actions"]["replies"], 5);
         assert_eq!(parsed["data"]["recommended_max_actions"]["tweets"], 6);
         assert_eq!(parsed["data"]["recommended_max_actions"]["threads"], 1);
@@ -76,18 +76,18 @@ pub async fn execute(
         }
         Err(e) => {
             let code = workflow_error_to_code(&e);
-            crate::tools::workflow::telemetry::record(
+            let message = e.to_string();
+            crate::tools::workflow::telemetry::record_failure(
                 &state.pool,
                 "find_reply_opportunities",
                 "composite",
                 elapsed,
-                false,
-                Some(code.as_str()),
-                None,
+                code.as_str(),
+                &message,
                 None,
             )
             .await;
-            ToolResponse::error(code, e.to_string())
+            ToolResponse::error(code, message)
                 .with_meta(ToolMeta::new(elapsed))
                 .to_json()
         }
@@ -1,10 +1,10 @@
-//! Content generation tools: generate reply, tweet, thread.
+//! Content generation tools: generate reply, tweet, thread, quote.
 
 use std::sync::Arc;
 use std::time::Instant;
 
 use tuitbot_core::config::{BusinessProfile, Config};
-use tuitbot_core::content::ContentGenerator;
+use tuitbot_core::content::{ContentGenerator, Temperatures};
 use tuitbot_core::llm::{GenerationParams, LlmProvider, LlmResponse};
 use tuitbot_core::LlmError;
 
@@ -63,7 +63,8 @@ pub async fn generate_reply(
     let provider = Box::new(ArcProvider {
         state: Arc::clone(state),
     });
-    let gen = ContentGenerator::new(provider, business.clone());
+    let gen = ContentGenerator::new(provider, business.clone())
+        .with_temperatures(Temperatures::from_config(&config.content));
 
     match gen
         .generate_reply(tweet_text, tweet_author, mention_product)
@@ -102,7 +103,8 @@ pub async fn generate_tweet(
     let provider = Box::new(ArcProvider {
         state: Arc::clone(state),
     });
-    let gen = ContentGenerator::new(provider, business.clone());
+    let gen = ContentGenerator::new(provider, business.clone())
+        .with_temperatures(Temperatures::from_config(&config.content));
 
     match gen.generate_tweet(topic).await {
         Ok(output) => {
@@ -138,7 +140,8 @@ pub async fn generate_thread(
     let provider = Box::new(ArcProvider {
         state: Arc::clone(state),
     });
-    let gen = ContentGenerator::new(provider, business.clone());
+    let gen = ContentGenerator::new(provider, business.clone())
+        .with_temperatures(Temperatures::from_config(&config.content));
 
     match gen.generate_thread(topic).await {
         Ok(output) => {
@@ -163,6 +166,48 @@ pub async fn generate_thread(
     }
 }
 
+/// Generate quote-tweet commentary via LLM.
+pub async fn generate_quote(
+    state: &Arc<AppState>,
+    business: &BusinessProfile,
+    quoted_text: &str,
+    quoted_author: &str,
+    topic_hint: Option<&str>,
+    config: &Config,
+) -> String {
+    let start = Instant::now();
+    let provider = Box::new(ArcProvider {
+        state: Arc::clone(state),
+    });
+    let gen = ContentGenerator::new(provider, business.clone())
+        .with_temperatures(Temperatures::from_config(&config.content));
+
+    match gen
+        .generate_quote(quoted_text, quoted_author, topic_hint)
+        .await
+    {
+        Ok(output) => {
+            let elapsed = start.elapsed().as_millis() as u64;
+            let meta = ToolMeta::new(elapsed)
+                .with_workflow(config.mode.to_string(), config.effective_approval_mode());
+            ToolResponse::success(serde_json::json!({
+                "quote": output.text,
+                "char_count": output.text.len(),
+            }))
+            .with_meta(meta)
+            .to_json()
+        }
+        Err(e) => {
+            let elapsed = start.elapsed().as_millis() as u64;
+            let meta = ToolMeta::new(elapsed)
+                .with_workflow(config.mode.to_string(), config.effective_approval_mode());
+            ToolResponse::error(ErrorCode::LlmError, format!("Error generating quote: {e}"))
+                .with_meta(meta)
+                .to_json()
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -235,6 +280,7 @@ mod tests {
             authenticated_user_id: Some("u1".to_string()),
             granted_scopes: vec![],
             idempotency: Arc::new(IdempotencyStore::new()),
+            active_account: Default::default(),
         })
     }
 
@@ -316,6 +362,22 @@ mod tests {
         assert!(result.contains("error") || result.contains("Error"));
     }
 
+    #[tokio::test]
+    async fn generate_quote_without_llm_returns_error_json() {
+        let state = make_state().await;
+        let result = generate_quote(
+            &state,
+            &state.config.business,
+            "Rust compile times are getting better",
+            "rustacean",
+            None,
+            &state.config,
+        )
+        .await;
+        assert!(!result.is_empty());
+        assert!(result.contains("error") || result.contains("Error"));
+    }
+
     #[test]
     fn tool_response_llm_not_configured() {
         let result = ToolResponse::llm_not_configured().to_json();
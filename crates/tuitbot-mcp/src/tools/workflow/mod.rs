@@ -3,6 +3,7 @@
 //! These tools require the full workflow stack (DB, LLM, policy gate)
 //! and are NOT available in the API profile.
 
+pub mod accounts;
 pub mod actions;
 pub mod analytics;
 pub mod approval;
@@ -6,6 +6,7 @@ use serde::Serialize;
 
 use tuitbot_core::config::Config;
 use tuitbot_core::storage;
+use tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID;
 use tuitbot_core::storage::DbPool;
 
 use crate::tools::response::{ToolMeta, ToolResponse};
@@ -20,11 +21,16 @@ struct RateLimitOut {
     remaining: i64,
 }
 
-/// Get current rate limit status for all action types.
+/// Get current rate limit status for all action types of the default account.
 pub async fn get_rate_limits(pool: &DbPool, config: &Config) -> String {
+    get_rate_limits_for(pool, DEFAULT_ACCOUNT_ID, config).await
+}
+
+/// Get current rate limit status for all action types of one account.
+pub async fn get_rate_limits_for(pool: &DbPool, account_id: &str, config: &Config) -> String {
     let start = Instant::now();
 
-    match storage::rate_limits::get_all_rate_limits(pool).await {
+    match storage::rate_limits::get_all_rate_limits_for(pool, account_id).await {
         Ok(limits) => {
             let out: Vec<RateLimitOut> = limits
                 .into_iter()
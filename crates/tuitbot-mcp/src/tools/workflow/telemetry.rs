@@ -103,3 +103,32 @@ pub async fn record(
     )
     .await;
 }
+
+/// Record a failed MCP tool invocation along with its error message.
+///
+/// The message is stored as `error_message` in the entry's metadata so the
+/// grouped error view can show a sample. Best-effort, like [`record`].
+pub async fn record_failure(
+    pool: &DbPool,
+    tool_name: &str,
+    category: &str,
+    latency_ms: u64,
+    error_code: &str,
+    error_message: &str,
+    policy_decision: Option<&str>,
+) {
+    let metadata_json = serde_json::json!({ "error_message": error_message }).to_string();
+    let _ = storage::mcp_telemetry::log_telemetry(
+        pool,
+        &storage::mcp_telemetry::TelemetryParams {
+            tool_name,
+            category,
+            latency_ms,
+            success: false,
+            error_code: Some(error_code),
+            policy_decision,
+            metadata: Some(&metadata_json),
+        },
+    )
+    .await;
+}
@@ -16,7 +16,7 @@ use crate::tools::workflow::policy_gate::{
     complete_gateway_failure, complete_gateway_success, run_gateway, GatewayResult,
 };
 
-/// Like a tweet.
+/// Like a tweet. The account's own tweets are refused.
 pub async fn like_tweet(state: &SharedState, tweet_id: &str) -> String {
     let start = Instant::now();
     if let Some(err) = super::scraper_mutation_guard(state, start) {
@@ -49,6 +49,15 @@ pub async fn like_tweet(state: &SharedState, tweet_id: &str) -> String {
         }
     };
 
+    if let Err(ref e) =
+        tuitbot_core::toolkit::engage::ensure_not_own_tweet(client.as_ref(), user_id, tweet_id)
+            .await
+    {
+        let msg = e.to_string();
+        let meta = complete_gateway_failure(state, &ticket, &msg, start).await;
+        return super::format_toolkit_error_with_meta(e, meta);
+    }
+
     match tuitbot_core::toolkit::engage::like_tweet(client.as_ref(), user_id, tweet_id).await {
         Ok(liked) => {
             #[derive(Serialize)]
@@ -187,7 +196,7 @@ pub async fn unfollow_user(state: &SharedState, target_user_id: &str) -> String
     }
 }
 
-/// Retweet a tweet.
+/// Retweet a tweet. The account's own tweets are refused.
 pub async fn retweet(state: &SharedState, tweet_id: &str) -> String {
     let start = Instant::now();
     if let Some(err) = super::scraper_mutation_guard(state, start) {
@@ -220,6 +229,15 @@ pub async fn retweet(state: &SharedState, tweet_id: &str) -> String {
         }
     };
 
+    if let Err(ref e) =
+        tuitbot_core::toolkit::engage::ensure_not_own_tweet(client.as_ref(), user_id, tweet_id)
+            .await
+    {
+        let msg = e.to_string();
+        let meta = complete_gateway_failure(state, &ticket, &msg, start).await;
+        return super::format_toolkit_error_with_meta(e, meta);
+    }
+
     match tuitbot_core::toolkit::engage::retweet(client.as_ref(), user_id, tweet_id).await {
         Ok(retweeted) => {
             #[derive(Serialize)]
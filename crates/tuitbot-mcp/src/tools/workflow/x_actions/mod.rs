@@ -1,10 +1,12 @@
 //! Direct X API tool implementations.
 //!
-//! Split into submodules by concern: read, write, engage, media, validate.
+//! Split into submodules by concern: read, write, engage, media, validate,
+//! thread resumption.
 
 mod engage;
 mod media;
 mod read;
+mod thread_resume;
 mod validate;
 mod write;
 pub mod x_request;
@@ -79,8 +81,10 @@ pub use media::upload_media;
 pub use read::{
     get_bookmarks, get_followers, get_following, get_home_timeline, get_liked_tweets,
     get_tweet_by_id, get_tweet_liking_users, get_user_by_id, get_user_by_username,
-    get_user_mentions, get_user_tweets, get_users_by_ids, get_x_usage, search_tweets,
+    get_user_mentions, get_user_tweets, get_users_by_ids, get_x_usage, score_tweet_by_id,
+    search_tweets,
 };
+pub use thread_resume::resume_thread;
 pub use write::{
     delete_tweet, post_thread, post_thread_dry_run, post_tweet, post_tweet_dry_run, quote_tweet,
     reply_to_tweet,
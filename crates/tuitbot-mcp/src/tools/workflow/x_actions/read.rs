@@ -45,6 +45,16 @@ pub async fn get_tweet_by_id(state: &SharedState, tweet_id: &str) -> String {
     }
 }
 
+/// Fetch a tweet by ID and score it — delegates to the scoring tool.
+pub async fn score_tweet_by_id(state: &SharedState, tweet_id: &str) -> String {
+    let start = Instant::now();
+    let client = match state.x_client.as_ref() {
+        Some(c) => c.as_ref(),
+        None => return not_configured_response(start),
+    };
+    crate::tools::scoring::score_tweet_by_id(client, &state.config, tweet_id).await
+}
+
 /// Look up a user by username — delegates to toolkit.
 pub async fn get_user_by_username(state: &SharedState, username: &str) -> String {
     let start = Instant::now();
@@ -49,6 +49,20 @@ async fn unretweet_success() {
     assert_eq!(parsed["data"]["retweeted"], false);
 }
 
+#[tokio::test]
+async fn like_and_retweet_refuse_own_tweet() {
+    // MockXApiClient reports every tweet as authored by "a1".
+    let state = make_state(Some(Box::new(MockXApiClient)), Some("a1".into())).await;
+    for result in [like_tweet(&state, "t1").await, retweet(&state, "t1").await] {
+        let parsed: serde_json::Value = serde_json::from_str(&result).expect("valid JSON");
+        assert_eq!(parsed["success"], false);
+        assert!(parsed["error"]["message"]
+            .as_str()
+            .unwrap()
+            .contains("authenticated user"));
+    }
+}
+
 // ── Policy gate tests ───────────────────────────────────────────────
 
 #[tokio::test]
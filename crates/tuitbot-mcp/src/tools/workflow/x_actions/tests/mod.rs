@@ -41,6 +41,7 @@ impl XApiClient for MockXApiClient {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -96,6 +97,7 @@ impl XApiClient for MockXApiClient {
             created_at: "2026-02-24T00:00:00Z".to_string(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            referenced_tweets: vec![],
         })
     }
 
@@ -109,6 +111,7 @@ impl XApiClient for MockXApiClient {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -140,6 +143,7 @@ impl XApiClient for MockXApiClient {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -196,6 +200,7 @@ impl XApiClient for MockXApiClient {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -235,6 +240,7 @@ impl XApiClient for MockXApiClient {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             }],
             meta: UsersMeta {
                 result_count: 1,
@@ -259,6 +265,7 @@ impl XApiClient for MockXApiClient {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             }],
             meta: UsersMeta {
                 result_count: 1,
@@ -277,6 +284,7 @@ impl XApiClient for MockXApiClient {
             location: None,
             url: None,
             public_metrics: UserMetrics::default(),
+            protected: None,
         })
     }
 
@@ -294,6 +302,7 @@ impl XApiClient for MockXApiClient {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -319,6 +328,7 @@ impl XApiClient for MockXApiClient {
                 created_at: String::new(),
                 public_metrics: PublicMetrics::default(),
                 conversation_id: None,
+                referenced_tweets: vec![],
             }],
             includes: None,
             meta: SearchMeta {
@@ -342,6 +352,7 @@ impl XApiClient for MockXApiClient {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             })
             .collect::<Vec<_>>();
         let count = users.len() as u32;
@@ -370,6 +381,7 @@ impl XApiClient for MockXApiClient {
                 location: None,
                 url: None,
                 public_metrics: UserMetrics::default(),
+                protected: None,
             }],
             meta: UsersMeta {
                 result_count: 1,
@@ -463,6 +475,7 @@ pub(crate) async fn make_state(
         authenticated_user_id: user_id,
         granted_scopes: vec![],
         idempotency: Arc::new(IdempotencyStore::new()),
+        active_account: Default::default(),
     })
 }
 
@@ -486,6 +499,7 @@ async fn make_state_with_config(
         authenticated_user_id: user_id,
         granted_scopes: vec![],
         idempotency: Arc::new(IdempotencyStore::new()),
+        active_account: Default::default(),
     })
 }
 
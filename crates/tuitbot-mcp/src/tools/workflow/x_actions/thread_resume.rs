@@ -0,0 +1,98 @@
+//! Resume a partially posted thread.
+//!
+//! Delegates to `tuitbot_core::workflow::thread_resume`, which reads the saved
+//! progress and posts only the remaining tweets. Governance goes through the
+//! same gateway as `post_thread`.
+
+use std::time::Instant;
+
+use serde::Serialize;
+use tuitbot_core::toolkit::ToolkitError;
+use tuitbot_core::workflow::{thread_resume, WorkflowError};
+
+use crate::state::SharedState;
+
+use super::not_configured_response;
+use crate::tools::response::{ErrorCode, ToolResponse};
+use crate::tools::workflow::policy_gate::{
+    complete_gateway_failure, complete_gateway_success, run_gateway, GatewayResult,
+};
+
+/// Post the remaining tweets of a thread that failed partway through.
+pub async fn resume_thread(state: &SharedState, thread_id: i64) -> String {
+    let start = Instant::now();
+
+    if let Some(err) = super::scraper_mutation_guard(state, start) {
+        return err;
+    }
+
+    let params = serde_json::json!({"thread_id": thread_id}).to_string();
+    let ticket = match run_gateway(state, "resume_thread", &params, start).await {
+        GatewayResult::Proceed(t) => t,
+        GatewayResult::EarlyReturn(r) => return r,
+    };
+
+    let client = match state.x_client.as_ref() {
+        Some(c) => c,
+        None => {
+            let _ = complete_gateway_failure(state, &ticket, "X API client not configured", start)
+                .await;
+            return not_configured_response(start);
+        }
+    };
+
+    match thread_resume::execute(&state.pool, client.as_ref(), thread_id).await {
+        Ok(out) => {
+            #[derive(Serialize)]
+            struct ResumeResult {
+                thread_id: i64,
+                resumed_from: usize,
+                new_tweet_ids: Vec<String>,
+                thread_tweet_ids: Vec<String>,
+            }
+            let result = ResumeResult {
+                thread_id: out.thread_id,
+                resumed_from: out.resumed_from,
+                new_tweet_ids: out.new_tweet_ids,
+                thread_tweet_ids: out.thread_tweet_ids,
+            };
+            let result_data = serde_json::to_value(&result).unwrap_or_default();
+            let meta = complete_gateway_success(state, &ticket, &result_data, start).await;
+            ToolResponse::success(result).with_meta(meta).to_json()
+        }
+        Err(WorkflowError::Toolkit(ToolkitError::ThreadPartialFailure {
+            ref posted_ids,
+            failed_index,
+            total,
+            ..
+        })) => {
+            let msg = format!(
+                "Resume failed at tweet {failed_index}. Posted {}/{total} tweets; \
+                 call x_resume_thread again to continue.",
+                posted_ids.len(),
+            );
+            let meta = complete_gateway_failure(state, &ticket, &msg, start).await;
+            let mut resp =
+                ToolResponse::error(ErrorCode::ThreadPartialFailure, msg).with_meta(meta);
+            resp.data = serde_json::json!({
+                "thread_id": thread_id,
+                "posted_tweet_ids": posted_ids,
+                "failed_at_index": failed_index,
+            });
+            resp.to_json()
+        }
+        Err(WorkflowError::Toolkit(ref e)) => {
+            let meta = complete_gateway_failure(state, &ticket, &e.to_string(), start).await;
+            super::format_toolkit_error_with_meta(e, meta)
+        }
+        Err(e) => {
+            let code = match e {
+                WorkflowError::Database(_) | WorkflowError::Storage(_) => ErrorCode::DbError,
+                _ => ErrorCode::InvalidInput,
+            };
+            let msg = e.to_string();
+            let meta = complete_gateway_failure(state, &ticket, &msg, start).await;
+            ToolResponse::error(code, msg).with_meta(meta).to_json()
+        }
+    }
+}
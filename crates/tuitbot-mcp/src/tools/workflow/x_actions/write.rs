@@ -272,6 +272,21 @@ pub async fn post_thread(
                 tweets.len(),
             );
             let meta = complete_gateway_failure(state, &ticket, &error_msg, start).await;
+            // Save progress so the rest can be posted with x_resume_thread.
+            let thread_id = match tuitbot_core::storage::thread_progress::persist_partial_thread(
+                &state.pool,
+                "",
+                posted_ids,
+                tweets,
+            )
+            .await
+            {
+                Ok(id) => Some(id),
+                Err(e) => {
+                    tracing::warn!(error = %e, "Failed to save partial thread progress");
+                    None
+                }
+            };
             let mut resp = ToolResponse::error(
                 ErrorCode::ThreadPartialFailure,
                 format!(
@@ -284,6 +299,7 @@ pub async fn post_thread(
             resp.data = serde_json::json!({
                 "posted_tweet_ids": posted_ids,
                 "failed_at_index": failed_index,
+                "thread_id": thread_id,
             });
             resp.to_json()
         }
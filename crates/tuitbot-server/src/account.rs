@@ -1,7 +1,8 @@
 //! Account context extraction and role-based access control.
 //!
 //! Resolves the `X-Account-Id` header into an `AccountContext` with the
-//! caller's role. Missing header defaults to the backward-compatible
+//! caller's role. Missing header falls back to the active account set via
+//! `POST /api/accounts/{id}/switch`, which starts as the backward-compatible
 //! default account.
 
 use std::sync::Arc;
@@ -48,6 +49,21 @@ impl Role {
     pub fn can_mutate(self) -> bool {
         matches!(self, Role::Admin)
     }
+
+    /// Whether this role grants everything `required` does.
+    ///
+    /// Tiers nest: admin ⊇ approver ⊇ viewer.
+    pub fn satisfies(self, required: Role) -> bool {
+        self.rank() >= required.rank()
+    }
+
+    fn rank(self) -> u8 {
+        match self {
+            Role::Viewer => 0,
+            Role::Approver => 1,
+            Role::Admin => 2,
+        }
+    }
 }
 
 impl std::fmt::Display for Role {
@@ -90,22 +106,27 @@ impl FromRequestParts<Arc<AppState>> for AccountContext {
 
     /// Extract account context from the `X-Account-Id` header.
     ///
-    /// - Missing header → default account with admin role (backward compat).
+    /// - Missing header → active account (default account unless switched).
     /// - Present header → validates account exists and resolves role.
     fn from_request_parts(
         parts: &mut Parts,
         state: &Arc<AppState>,
     ) -> impl std::future::Future<Output = Result<Self, Self::Rejection>> + Send {
-        let account_id = parts
+        let header_id = parts
             .headers
             .get("x-account-id")
             .and_then(|v| v.to_str().ok())
-            .unwrap_or(DEFAULT_ACCOUNT_ID)
-            .to_string();
+            .map(String::from);
 
-        let db = state.db.clone();
+        let state = state.clone();
 
         async move {
+            let account_id = match header_id {
+                Some(id) => id,
+                None => state.active_account.get().await,
+            };
+            let db = &state.db;
+
             // Default account always grants admin.
             if account_id == DEFAULT_ACCOUNT_ID {
                 return Ok(AccountContext {
@@ -115,7 +136,7 @@ impl FromRequestParts<Arc<AppState>> for AccountContext {
             }
 
             // Validate account exists and is active.
-            let exists = accounts::account_exists(&db, &account_id)
+            let exists = accounts::account_exists(db, &account_id)
                 .await
                 .map_err(|e| AccountError {
                     status: StatusCode::INTERNAL_SERVER_ERROR,
@@ -130,7 +151,7 @@ impl FromRequestParts<Arc<AppState>> for AccountContext {
             }
 
             // Resolve role — default actor is "dashboard" for HTTP requests.
-            let role_str = accounts::get_role(&db, &account_id, "dashboard")
+            let role_str = accounts::get_role(db, &account_id, "dashboard")
                 .await
                 .map_err(|e| AccountError {
                     status: StatusCode::INTERNAL_SERVER_ERROR,
@@ -148,28 +169,32 @@ impl FromRequestParts<Arc<AppState>> for AccountContext {
     }
 }
 
+/// Reject the request with 403 unless the caller's role satisfies `required`.
+///
+/// Read routes need only [`Role::Viewer`], which every resolved caller has.
+pub fn require_role(ctx: &AccountContext, required: Role) -> Result<(), AccountError> {
+    if ctx.role.satisfies(required) {
+        return Ok(());
+    }
+    let message = match required {
+        Role::Admin => "admin role required",
+        Role::Approver => "approver or admin role required",
+        Role::Viewer => "viewer role required",
+    };
+    Err(AccountError {
+        status: StatusCode::FORBIDDEN,
+        message: message.to_string(),
+    })
+}
+
 /// Helper to reject requests that require approval permissions.
 pub fn require_approve(ctx: &AccountContext) -> Result<(), AccountError> {
-    if ctx.role.can_approve() {
-        Ok(())
-    } else {
-        Err(AccountError {
-            status: StatusCode::FORBIDDEN,
-            message: "approver or admin role required".to_string(),
-        })
-    }
+    require_role(ctx, Role::Approver)
 }
 
 /// Helper to reject requests that require mutation permissions.
 pub fn require_mutate(ctx: &AccountContext) -> Result<(), AccountError> {
-    if ctx.role.can_mutate() {
-        Ok(())
-    } else {
-        Err(AccountError {
-            status: StatusCode::FORBIDDEN,
-            message: "admin role required".to_string(),
-        })
-    }
+    require_role(ctx, Role::Admin)
 }
 
 #[cfg(test)]
@@ -201,6 +226,15 @@ mod tests {
         assert!(!Role::Viewer.can_mutate());
     }
 
+    #[test]
+    fn role_satisfies_nested_tiers() {
+        assert!(Role::Admin.satisfies(Role::Approver));
+        assert!(Role::Approver.satisfies(Role::Viewer));
+        assert!(Role::Viewer.satisfies(Role::Viewer));
+        assert!(!Role::Viewer.satisfies(Role::Approver));
+        assert!(!Role::Approver.satisfies(Role::Admin));
+    }
+
     // --- Role Display ---
 
     #[test]
@@ -272,6 +306,19 @@ mod tests {
         assert!(err.message.contains("approver"));
     }
 
+    // --- require_role ---
+
+    #[test]
+    fn require_role_viewer_always_ok() {
+        for role in [Role::Admin, Role::Approver, Role::Viewer] {
+            let ctx = AccountContext {
+                account_id: "test".into(),
+                role,
+            };
+            assert!(require_role(&ctx, Role::Viewer).is_ok());
+        }
+    }
+
     // --- require_mutate ---
 
     #[test]
@@ -0,0 +1,64 @@
+//! Relays posting activity recorded by out-of-process runtimes.
+//!
+//! Runtimes started from the CLI log each posting outcome through
+//! [`LogActivitySink`](tuitbot_core::automation::LogActivitySink). This task
+//! polls those action log rows and republishes them as `WsEvent::Action`, so
+//! `/api/activity/stream` covers loop posts as well as compose posts.
+
+use std::time::Duration;
+
+use tokio::sync::broadcast;
+use tuitbot_core::automation::{ActivityEvent, ActivitySink, ACTIVITY_ACTION_TYPE};
+use tuitbot_core::storage::{action_log, DbPool};
+
+use crate::ws::{AccountWsEvent, WsActivitySink};
+
+/// How often the action log is polled for new activity rows.
+const POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Forward activity rows logged after startup, for the life of the process.
+pub async fn run_activity_relay(pool: DbPool, event_tx: broadcast::Sender<AccountWsEvent>) {
+    // Rows from before startup are history, not live activity.
+    let mut cursor = match action_log::get_max_action_id(&pool).await {
+        Ok(id) => id,
+        Err(e) => {
+            tracing::warn!(error = %e, "Activity relay disabled: cannot read action log");
+            return;
+        }
+    };
+
+    loop {
+        tokio::time::sleep(POLL_INTERVAL).await;
+        cursor = relay_new_activity(&pool, &event_tx, cursor).await;
+    }
+}
+
+/// Publish activity rows logged after `cursor` and return the new cursor.
+pub async fn relay_new_activity(
+    pool: &DbPool,
+    event_tx: &broadcast::Sender<AccountWsEvent>,
+    cursor: i64,
+) -> i64 {
+    let rows = match action_log::get_actions_after_id(pool, cursor, ACTIVITY_ACTION_TYPE).await {
+        Ok(rows) => rows,
+        Err(e) => {
+            tracing::warn!(error = %e, "Failed to poll activity rows");
+            return cursor;
+        }
+    };
+
+    let mut cursor = cursor;
+    for row in rows {
+        cursor = row.entry.id;
+        let event = row
+            .entry
+            .metadata
+            .as_deref()
+            .and_then(|m| serde_json::from_str::<ActivityEvent>(m).ok());
+        match event {
+            Some(event) => WsActivitySink::new(row.account_id, event_tx.clone()).emit(event),
+            None => tracing::debug!(id = row.entry.id, "Skipping unparseable activity row"),
+        }
+    }
+    cursor
+}
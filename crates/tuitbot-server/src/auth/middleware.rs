@@ -44,6 +44,9 @@ const AUTH_EXEMPT_PATHS: &[&str] = &[
     "/api/settings/test-llm",
     "/ws",
     "/api/ws",
+    // Activity stream is a WebSocket upgrade with the same query/cookie auth as /ws.
+    "/activity/stream",
+    "/api/activity/stream",
     "/auth/login",
     "/api/auth/login",
     "/auth/status",
@@ -0,0 +1,106 @@
+//! Bind-address resolution for the HTTP server.
+//!
+//! The host comes from `--host`, then `server.host`, then the
+//! `deployment_mode` default: loopback for desktop, all interfaces for
+//! self-hosted and cloud deployments. Cloud instances refuse to listen on
+//! all interfaces until a web login passphrase exists, since there is no
+//! operator at a terminal to read an auto-generated one.
+
+use tuitbot_core::config::{Config, DeploymentMode};
+
+/// Resolve the host to bind to.
+///
+/// `cli_host` wins when given, then `server.host` from the config, then
+/// the deployment mode default. Without a loadable config the desktop
+/// default applies.
+pub fn resolve_bind_host(cli_host: Option<&str>, config: Option<&Config>) -> String {
+    if let Some(host) = cli_host {
+        return host.to_string();
+    }
+    if let Some(host) = config.and_then(|c| c.server.host.as_deref()) {
+        return host.to_string();
+    }
+    config
+        .map(|c| c.deployment_mode.default_bind_host())
+        .unwrap_or(DeploymentMode::Desktop.default_bind_host())
+        .to_string()
+}
+
+/// Whether `host` listens on every interface rather than loopback only.
+pub fn is_lan_host(host: &str) -> bool {
+    matches!(host, "0.0.0.0" | "::" | "[::]")
+}
+
+/// Refuse to expose a cloud deployment without a configured passphrase.
+pub fn check_passphrase_guard(
+    host: &str,
+    mode: &DeploymentMode,
+    passphrase_configured: bool,
+) -> anyhow::Result<()> {
+    if *mode == DeploymentMode::Cloud && is_lan_host(host) && !passphrase_configured {
+        anyhow::bail!(
+            "refusing to bind {host} in cloud mode without a web login passphrase; \
+             run `tuitbot-server --reset-passphrase` first or bind to 127.0.0.1"
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_for(mode: DeploymentMode) -> Config {
+        Config {
+            deployment_mode: mode,
+            ..Config::default()
+        }
+    }
+
+    #[test]
+    fn desktop_defaults_to_loopback() {
+        let config = config_for(DeploymentMode::Desktop);
+        assert_eq!(resolve_bind_host(None, Some(&config)), "127.0.0.1");
+        assert_eq!(resolve_bind_host(None, None), "127.0.0.1");
+    }
+
+    #[test]
+    fn self_host_and_cloud_default_to_all_interfaces() {
+        for mode in [DeploymentMode::SelfHost, DeploymentMode::Cloud] {
+            let config = config_for(mode);
+            assert_eq!(resolve_bind_host(None, Some(&config)), "0.0.0.0");
+        }
+    }
+
+    #[test]
+    fn config_host_overrides_mode_default() {
+        let mut config = config_for(DeploymentMode::Cloud);
+        config.server.host = Some("127.0.0.1".to_string());
+        assert_eq!(resolve_bind_host(None, Some(&config)), "127.0.0.1");
+    }
+
+    #[test]
+    fn cli_host_overrides_everything() {
+        let mut config = config_for(DeploymentMode::SelfHost);
+        config.server.host = Some("0.0.0.0".to_string());
+        assert_eq!(
+            resolve_bind_host(Some("127.0.0.1"), Some(&config)),
+            "127.0.0.1"
+        );
+        assert_eq!(resolve_bind_host(Some("0.0.0.0"), None), "0.0.0.0");
+    }
+
+    #[test]
+    fn cloud_on_lan_requires_passphrase() {
+        let err = check_passphrase_guard("0.0.0.0", &DeploymentMode::Cloud, false).unwrap_err();
+        assert!(err.to_string().contains("cloud mode"));
+        assert!(check_passphrase_guard("0.0.0.0", &DeploymentMode::Cloud, true).is_ok());
+    }
+
+    #[test]
+    fn passphrase_guard_ignores_loopback_and_other_modes() {
+        assert!(check_passphrase_guard("127.0.0.1", &DeploymentMode::Cloud, false).is_ok());
+        assert!(check_passphrase_guard("0.0.0.0", &DeploymentMode::SelfHost, false).is_ok());
+        assert!(check_passphrase_guard("0.0.0.0", &DeploymentMode::Desktop, false).is_ok());
+    }
+}
@@ -0,0 +1,101 @@
+//! Cross-origin request policy built from `[server.cors]`.
+//!
+//! The permissive default keeps existing setups working. When origins are
+//! listed (or `permissive = false`), only those origins and the server's own
+//! origin may call the API; requests carrying any other `Origin` header are
+//! rejected with 403 before reaching a handler, since CORS headers alone
+//! only stop browsers from reading responses, not from sending requests.
+
+use std::sync::Arc;
+
+use axum::extract::Request;
+use axum::http::{header, HeaderName, HeaderValue, Method, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::IntoResponse;
+use axum::Router;
+use serde_json::json;
+use tower_http::cors::{AllowOrigin, CorsLayer};
+use tuitbot_core::config::CorsConfig;
+
+/// Apply the CORS policy from `config` to `router`.
+pub fn apply<S>(router: Router<S>, config: &CorsConfig) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    if config.is_permissive() {
+        return router.layer(CorsLayer::permissive());
+    }
+
+    let allowed: Arc<Vec<HeaderValue>> = Arc::new(
+        config
+            .allowed_origins
+            .iter()
+            .filter_map(
+                |origin| match HeaderValue::from_str(origin.trim_end_matches('/')) {
+                    Ok(value) => Some(value),
+                    Err(_) => {
+                        tracing::warn!(origin = %origin, "Ignoring invalid CORS origin");
+                        None
+                    }
+                },
+            )
+            .collect(),
+    );
+
+    let cors = CorsLayer::new()
+        .allow_origin(AllowOrigin::list(allowed.iter().cloned()))
+        .allow_methods([
+            Method::GET,
+            Method::POST,
+            Method::PUT,
+            Method::PATCH,
+            Method::DELETE,
+            Method::OPTIONS,
+        ])
+        .allow_headers([
+            header::AUTHORIZATION,
+            header::CONTENT_TYPE,
+            HeaderName::from_static("x-csrf-token"),
+            HeaderName::from_static("x-account-id"),
+        ])
+        .expose_headers([crate::routes::pagination::NEXT_CURSOR_HEADER])
+        .allow_credentials(true);
+
+    router
+        .layer(cors)
+        .layer(middleware::from_fn(move |req: Request, next: Next| {
+            let allowed = allowed.clone();
+            async move {
+                if origin_allowed(&req, &allowed) {
+                    next.run(req).await
+                } else {
+                    (
+                        StatusCode::FORBIDDEN,
+                        axum::Json(json!({"error": "origin not allowed"})),
+                    )
+                        .into_response()
+                }
+            }
+        }))
+}
+
+/// Requests without an `Origin` header (curl, same-origin GETs) and requests
+/// from the server's own origin are always allowed.
+fn origin_allowed(req: &Request, allowed: &[HeaderValue]) -> bool {
+    let Some(origin) = req.headers().get(header::ORIGIN) else {
+        return true;
+    };
+    if allowed.contains(origin) {
+        return true;
+    }
+    let host = req
+        .headers()
+        .get(header::HOST)
+        .and_then(|h| h.to_str().ok());
+    let authority = origin
+        .to_str()
+        .ok()
+        .and_then(|o| o.split_once("://"))
+        .map(|(_, rest)| rest);
+    matches!((host, authority), (Some(h), Some(a)) if h.eq_ignore_ascii_case(a))
+}
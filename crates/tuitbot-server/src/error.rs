@@ -20,6 +20,8 @@ pub enum ApiError {
     Internal(String),
     /// Forbidden — insufficient role/permissions.
     Forbidden(String),
+    /// A required upstream service (e.g. the X API client) is unavailable.
+    ServiceUnavailable(String),
 }
 
 impl From<tuitbot_core::error::StorageError> for ApiError {
@@ -60,6 +62,7 @@ impl IntoResponse for ApiError {
                 (StatusCode::INTERNAL_SERVER_ERROR, msg)
             }
             Self::Forbidden(msg) => (StatusCode::FORBIDDEN, msg),
+            Self::ServiceUnavailable(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg),
         };
 
         let body = axum::Json(json!({ "error": message }));
@@ -117,6 +120,14 @@ mod tests {
         assert_eq!(body["error"], "no access");
     }
 
+    #[tokio::test]
+    async fn service_unavailable_returns_503() {
+        let (status, body) =
+            error_response(ApiError::ServiceUnavailable("X client down".into())).await;
+        assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(body["error"], "X client down");
+    }
+
     #[tokio::test]
     async fn storage_error_returns_500() {
         let storage_err = tuitbot_core::error::StorageError::Query {
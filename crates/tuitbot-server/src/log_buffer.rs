@@ -0,0 +1,203 @@
+//! In-memory tail of recent log output for the dashboard.
+//!
+//! A [`LogBufferLayer`] installed next to the stdout formatter copies every
+//! event into a bounded ring buffer shared through `AppState`, so
+//! `GET /api/runtime/logs` can show what the server and its loops are doing.
+//! Lines are scrubbed of credentials before they are stored.
+
+use std::collections::VecDeque;
+use std::fmt::{self, Write as _};
+use std::sync::{Arc, Mutex};
+
+use serde::Serialize;
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::Layer;
+use tuitbot_core::safety::redact::scrub;
+
+/// Lines kept by [`LogBuffer::default`].
+pub const DEFAULT_CAPACITY: usize = 1000;
+
+/// One captured log event.
+#[derive(Debug, Clone, Serialize)]
+pub struct LogLine {
+    /// RFC 3339 time the event was recorded.
+    pub timestamp: String,
+    /// Upper-case level, e.g. `WARN`.
+    pub level: String,
+    /// Module path the event came from.
+    pub target: String,
+    /// The message followed by any other fields as `key=value`.
+    pub message: String,
+    #[serde(skip)]
+    severity: Level,
+}
+
+/// Bounded, shared buffer of the most recent log lines.
+///
+/// Cloning shares the same buffer. When full, the oldest line is dropped.
+#[derive(Debug, Clone)]
+pub struct LogBuffer {
+    lines: Arc<Mutex<VecDeque<LogLine>>>,
+    capacity: usize,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::with_capacity(DEFAULT_CAPACITY)
+    }
+}
+
+impl LogBuffer {
+    /// Create a buffer holding at most `capacity` lines (at least one).
+    pub fn with_capacity(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            lines: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+        }
+    }
+
+    /// Maximum number of lines kept.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Append a line with credentials masked, evicting the oldest when full.
+    pub fn push(&self, level: Level, target: &str, message: String) {
+        let message = scrub(&message);
+        let mut lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        if lines.len() == self.capacity {
+            lines.pop_front();
+        }
+        lines.push_back(LogLine {
+            timestamp: chrono::Utc::now().to_rfc3339(),
+            level: level.to_string(),
+            target: target.to_string(),
+            message,
+            severity: level,
+        });
+    }
+
+    /// The last `n` lines at `min_level` or more severe, oldest first.
+    pub fn tail(&self, n: usize, min_level: Level) -> Vec<LogLine> {
+        let lines = self.lines.lock().unwrap_or_else(|e| e.into_inner());
+        // `Level` orders by verbosity: ERROR < WARN < ... < TRACE.
+        let mut tail: Vec<LogLine> = lines
+            .iter()
+            .rev()
+            .filter(|line| line.severity <= min_level)
+            .take(n)
+            .cloned()
+            .collect();
+        tail.reverse();
+        tail
+    }
+
+    /// A tracing layer that records every event into this buffer.
+    pub fn layer(&self) -> LogBufferLayer {
+        LogBufferLayer {
+            buffer: self.clone(),
+        }
+    }
+}
+
+/// Tracing layer feeding a [`LogBuffer`].
+pub struct LogBufferLayer {
+    buffer: LogBuffer,
+}
+
+impl<S: Subscriber> Layer<S> for LogBufferLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        let meta = event.metadata();
+        self.buffer
+            .push(*meta.level(), meta.target(), visitor.finish());
+    }
+}
+
+/// Collects the `message` field and formats the rest as `key=value`.
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: String,
+}
+
+impl MessageVisitor {
+    fn finish(self) -> String {
+        if self.message.is_empty() {
+            self.fields.trim_start().to_string()
+        } else {
+            self.message + &self.fields
+        }
+    }
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_string();
+        } else {
+            let _ = write!(self.fields, " {}={value}", field.name());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        } else {
+            let _ = write!(self.fields, " {}={value:?}", field.name());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt;
+
+    #[test]
+    fn tail_returns_recent_events_in_order_with_level_filter() {
+        let buffer = LogBuffer::with_capacity(3);
+        let subscriber = tracing_subscriber::registry().with(buffer.layer());
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("dropped when the buffer wraps");
+            tracing::warn!(keyword = "rust", "search rate limited");
+            tracing::debug!("polling");
+            tracing::error!(tweet_id = 42, "reply failed");
+        });
+
+        let all = buffer.tail(10, Level::TRACE);
+        let messages: Vec<&str> = all.iter().map(|l| l.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "search rate limited keyword=rust",
+                "polling",
+                "reply failed tweet_id=42"
+            ]
+        );
+
+        let warnings = buffer.tail(10, Level::WARN);
+        let levels: Vec<&str> = warnings.iter().map(|l| l.level.as_str()).collect();
+        assert_eq!(levels, ["WARN", "ERROR"]);
+
+        let last = buffer.tail(1, Level::TRACE);
+        assert_eq!(last[0].message, "reply failed tweet_id=42");
+    }
+
+    #[test]
+    fn push_masks_credentials() {
+        let buffer = LogBuffer::with_capacity(2);
+        buffer.push(
+            Level::WARN,
+            "tuitbot_core",
+            "refresh failed: Authorization: Bearer abc123secret".into(),
+        );
+        let line = &buffer.tail(1, Level::TRACE)[0];
+        assert!(!line.message.contains("abc123secret"), "{}", line.message);
+    }
+}
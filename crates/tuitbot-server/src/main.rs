@@ -9,10 +9,12 @@ use std::sync::Arc;
 use anyhow::Result;
 use clap::Parser;
 use tokio::sync::Mutex;
+use tracing_subscriber::layer::SubscriberExt;
+use tracing_subscriber::util::SubscriberInitExt;
 use tracing_subscriber::EnvFilter;
 use tuitbot_core::auth::passphrase;
-use tuitbot_core::config::Config;
-use tuitbot_core::content::ContentGenerator;
+use tuitbot_core::config::{Config, DeploymentMode};
+use tuitbot_core::content::{ComplianceRules, ContentGenerator, FrameworkWeights, Temperatures};
 use tuitbot_core::context::semantic_index::SemanticIndex;
 use tuitbot_core::llm::embedding_factory::create_embedding_provider;
 use tuitbot_core::llm::factory::create_provider;
@@ -23,7 +25,10 @@ use tuitbot_core::x_api::scraper_health::new_scraper_health;
 use tokio_util::sync::CancellationToken;
 use tuitbot_core::automation::WatchtowerLoop;
 use tuitbot_core::net::local_ip;
+use tuitbot_server::activity_relay;
 use tuitbot_server::auth;
+use tuitbot_server::bind;
+use tuitbot_server::log_buffer::LogBuffer;
 use tuitbot_server::state::AppState;
 use tuitbot_server::ws::AccountWsEvent;
 
@@ -36,8 +41,11 @@ struct Cli {
     port: u16,
 
     /// Host address to bind to. Use 0.0.0.0 for LAN access.
-    #[arg(long, default_value = "127.0.0.1")]
-    host: String,
+    ///
+    /// Defaults to `server.host`, then to the deployment mode default
+    /// (127.0.0.1 for desktop, 0.0.0.0 for self_host and cloud).
+    #[arg(long)]
+    host: Option<String>,
 
     /// Path to the tuitbot configuration file.
     #[arg(long, default_value = "~/.tuitbot/config.toml")]
@@ -50,9 +58,13 @@ struct Cli {
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // Initialize tracing (respects RUST_LOG env var).
-    tracing_subscriber::fmt()
-        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()))
+    // Initialize tracing (respects RUST_LOG env var). Events also go to an
+    // in-memory buffer that the dashboard tails via `/api/runtime/logs`.
+    let log_buffer = LogBuffer::default();
+    tracing_subscriber::registry()
+        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| "info".into()))
+        .with(tracing_subscriber::fmt::layer())
+        .with(log_buffer.layer())
         .init();
 
     let cli = Cli::parse();
@@ -72,14 +84,44 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
+    // Load config for server settings and content generator.
+    let loaded_config = Config::load(Some(&cli.config)).ok();
+    if let Some(config) = &loaded_config {
+        tuitbot_core::safety::redact::register_patterns(&config.logging.redact_patterns)
+            .map_err(|e| anyhow::anyhow!("invalid logging.redact_patterns: {e}"))?;
+    }
+
+    // Extract deployment mode from config (defaults to Desktop).
+    let deployment_mode = loaded_config
+        .as_ref()
+        .map(|c| c.deployment_mode.clone())
+        .unwrap_or_default();
+
+    // Determine effective bind host/port: CLI flags override config values,
+    // and an unset host falls back to the deployment mode default.
+    let bind_host = bind::resolve_bind_host(cli.host.as_deref(), loaded_config.as_ref());
+    let bind_port = if cli.port != 3001 {
+        cli.port
+    } else {
+        loaded_config
+            .as_ref()
+            .map(|c| c.server.port)
+            .unwrap_or(cli.port)
+    };
+
     tracing::info!(
         db = %db_path.display(),
-        host = %cli.host,
-        port = cli.port,
+        host = %bind_host,
+        port = bind_port,
+        deployment_mode = %deployment_mode,
         "starting tuitbot server"
     );
 
-    let pool = storage::init_db(&db_path.to_string_lossy()).await?;
+    let storage_config = loaded_config
+        .as_ref()
+        .map(|c| c.storage.clone())
+        .unwrap_or_default();
+    let pool = storage::init_db_with_config(&db_path.to_string_lossy(), &storage_config).await?;
 
     // Ensure default account exists (may be missing after factory reset).
     storage::accounts::ensure_default_account(&pool).await?;
@@ -89,32 +131,39 @@ async fn main() -> Result<()> {
     tracing::info!(token_path = %db_dir.join("api_token").display(), "API token ready");
 
     // Handle passphrase for web/LAN auth.
-    let passphrase_hash = if cli.host == "0.0.0.0" {
-        // LAN mode: auto-generate passphrase if none exists (backward compatible).
-        match passphrase::ensure_passphrase(db_dir)? {
-            Some(new_passphrase) => {
-                println!("\n  Web login passphrase: {new_passphrase}");
-                println!("  (save this — it won't be shown again)\n");
-            }
-            None => {
-                tracing::info!("Passphrase already configured");
-            }
-        }
-        passphrase::load_passphrase_hash(db_dir)?
-    } else {
-        // Localhost mode: load existing hash if present, skip generation.
-        // The browser claim flow will handle passphrase creation.
-        match passphrase::load_passphrase_hash(db_dir)? {
-            Some(hash) => {
-                tracing::info!("Passphrase loaded from disk");
-                Some(hash)
+    let passphrase_hash =
+        if bind::is_lan_host(&bind_host) && deployment_mode == DeploymentMode::Cloud {
+            // Cloud on all interfaces: never auto-generate, since nobody is
+            // watching stdout. Refuse to start until a passphrase exists.
+            let hash = passphrase::load_passphrase_hash(db_dir)?;
+            bind::check_passphrase_guard(&bind_host, &deployment_mode, hash.is_some())?;
+            hash
+        } else if bind::is_lan_host(&bind_host) {
+            // LAN mode: auto-generate passphrase if none exists (backward compatible).
+            match passphrase::ensure_passphrase(db_dir)? {
+                Some(new_passphrase) => {
+                    println!("\n  Web login passphrase: {new_passphrase}");
+                    println!("  (save this — it won't be shown again)\n");
+                }
+                None => {
+                    tracing::info!("Passphrase already configured");
+                }
             }
-            None => {
-                tracing::info!("No passphrase configured — awaiting browser claim");
-                None
+            passphrase::load_passphrase_hash(db_dir)?
+        } else {
+            // Localhost mode: load existing hash if present, skip generation.
+            // The browser claim flow will handle passphrase creation.
+            match passphrase::load_passphrase_hash(db_dir)? {
+                Some(hash) => {
+                    tracing::info!("Passphrase loaded from disk");
+                    Some(hash)
+                }
+                None => {
+                    tracing::info!("No passphrase configured — awaiting browser claim");
+                    None
+                }
             }
-        }
-    };
+        };
 
     // Record the initial mtime so login can detect out-of-band resets.
     let passphrase_hash_mtime = passphrase::passphrase_hash_mtime(db_dir);
@@ -122,35 +171,29 @@ async fn main() -> Result<()> {
     // Create the broadcast channel for WebSocket events.
     let (event_tx, _) = tokio::sync::broadcast::channel::<AccountWsEvent>(256);
 
-    let data_dir = db_dir.to_path_buf();
-
-    // Load config for server settings and content generator.
-    let loaded_config = Config::load(Some(&cli.config)).ok();
+    // Forward posting activity logged by CLI runtimes to the activity stream.
+    tokio::spawn(activity_relay::run_activity_relay(
+        pool.clone(),
+        event_tx.clone(),
+    ));
 
-    // Determine effective bind host/port: CLI flags override config values.
-    let bind_host = if cli.host != "127.0.0.1" {
-        cli.host.clone()
-    } else {
-        loaded_config
-            .as_ref()
-            .map(|c| c.server.host.clone())
-            .unwrap_or_else(|| cli.host.clone())
-    };
-    let bind_port = if cli.port != 3001 {
-        cli.port
-    } else {
-        loaded_config
-            .as_ref()
-            .map(|c| c.server.port)
-            .unwrap_or(cli.port)
-    };
+    let data_dir = db_dir.to_path_buf();
 
     // Try to initialize content generator from config (optional — AI assist endpoints need it).
     let content_generator = match Config::load(Some(&cli.config)) {
         Ok(config) => match create_provider(&config.llm) {
             Ok(provider) => {
                 tracing::info!("LLM provider initialized for AI assist endpoints");
-                Some(Arc::new(ContentGenerator::new(provider, config.business)))
+                Some(Arc::new(
+                    ContentGenerator::new(provider, config.business)
+                        .with_rag_sanitize(config.content.rag_sanitize)
+                        .with_random_seed(config.content.random_seed)
+                        .with_framework_weights(FrameworkWeights::from_config(&config.content))
+                        .with_compliance(ComplianceRules::from_config(&config.content))
+                        .with_temperatures(Temperatures::from_config(&config.content))
+                        .with_retry(config.llm.retry.clone())
+                        .with_dump_prompt_on_error(config.logging.dump_prompt_on_error),
+                ))
             }
             Err(e) => {
                 tracing::info!(error = %e, "LLM provider not configured — AI assist endpoints disabled");
@@ -180,12 +223,6 @@ async fn main() -> Result<()> {
         .map(|c| c.connectors.clone())
         .unwrap_or_default();
 
-    // Extract deployment mode from config (defaults to Desktop).
-    let deployment_mode = loaded_config
-        .as_ref()
-        .map(|c| c.deployment_mode.clone())
-        .unwrap_or_default();
-
     // Conditionally start the Watchtower filesystem watcher.
     // Uses `is_enabled()` which respects both `enabled` and legacy `watch`.
     let watchtower_cancel = {
@@ -258,6 +295,10 @@ async fn main() -> Result<()> {
         None => (None, None),
     };
 
+    // Requests without `X-Account-Id` act on the account last switched to,
+    // shared with `tuitbot accounts switch`.
+    let active_account = storage::accounts::ActiveAccount::load(&pool, &data_dir).await?;
+
     let state = Arc::new(AppState {
         db: pool,
         config_path,
@@ -283,7 +324,7 @@ async fn main() -> Result<()> {
         watchtower_cancel: tokio::sync::RwLock::new(watchtower_cancel),
         content_sources: tokio::sync::RwLock::new(content_sources),
         connector_config,
-        deployment_mode,
+        deployment_mode: deployment_mode.clone(),
         pending_oauth: Mutex::new(HashMap::new()),
         token_managers: Mutex::new(HashMap::new()),
         x_client_id: loaded_config
@@ -292,13 +333,33 @@ async fn main() -> Result<()> {
             .unwrap_or_default(),
         semantic_index,
         embedding_provider,
+        active_account,
+        log_buffer,
     });
 
-    let router = tuitbot_server::build_router(state.clone());
-
-    // Warn about network exposure when binding to 0.0.0.0.
-    if bind_host == "0.0.0.0" {
-        tracing::warn!("Binding to 0.0.0.0 — server accessible from LAN");
+    let cors = loaded_config
+        .as_ref()
+        .map(|c| c.server.cors.clone())
+        .unwrap_or_default();
+    let router = tuitbot_server::build_router_with_cors(state.clone(), &cors);
+
+    // Warn loudly about network exposure when binding to all interfaces.
+    if bind::is_lan_host(&bind_host) {
+        eprintln!();
+        eprintln!("  WARNING: listening on {bind_host} ({deployment_mode} mode).");
+        eprintln!("  The API and dashboard are reachable by every device on this network.");
+        eprintln!("  Pass --host 127.0.0.1 to keep the server local.");
+        eprintln!();
+        tracing::warn!(
+            host = %bind_host,
+            deployment_mode = %deployment_mode,
+            "Binding to all interfaces — server accessible from LAN"
+        );
+        if cors.is_permissive() {
+            tracing::warn!(
+                "CORS is permissive; set [server.cors] allowed_origins to restrict which sites can call the API"
+            );
+        }
         if let Some(ip) = local_ip() {
             println!("  Dashboard: http://{}:{}", ip, bind_port);
         }
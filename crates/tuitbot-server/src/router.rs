@@ -0,0 +1,262 @@
+//! The API route table.
+//!
+//! Routes are declared once through `api_routes!`, which registers them on
+//! the router and records each path with the method helpers chained on its
+//! handler. Tests check that record against
+//! [`tuitbot_core::route_manifest::API_ROUTES`], so the published listing
+//! matches what is served.
+
+use std::sync::Arc;
+
+use axum::extract::DefaultBodyLimit;
+use axum::routing::{delete, get, patch, post};
+use axum::Router;
+
+use crate::state::AppState;
+use crate::{auth, routes, ws};
+
+/// Declare the API routes as `"path" => method_router,` entries.
+macro_rules! api_routes {
+    ($($path:literal => $method:ident($($args:tt)*) $(.$chain:ident($($chain_args:tt)*))*,)*) => {
+        /// Every route path with the names chained on its handler (`get`,
+        /// `post`, ..., and any `layer`), in registration order.
+        #[cfg(test)]
+        const API_ROUTES: &[(&str, &[&str])] =
+            &[$(($path, &[stringify!($method) $(, stringify!($chain))*])),*];
+
+        /// Router serving every API route, before `/api` nesting and auth.
+        pub(crate) fn api_router() -> Router<Arc<AppState>> {
+            Router::new()$(.route($path, $method($($args)*)$(.$chain($($chain_args)*))*))*
+        }
+    };
+}
+
+api_routes! {
+    "/health" => get(routes::health::health),
+    "/health/detailed" => get(routes::health::health_detailed),
+    // Auth
+    "/auth/login" => post(auth::routes::login),
+    "/auth/logout" => post(auth::routes::logout),
+    "/auth/status" => get(auth::routes::status),
+    // Analytics
+    "/analytics/summary" => get(routes::analytics::summary),
+    "/analytics/followers" => get(routes::analytics::followers),
+    "/analytics/followers/growth" => get(routes::analytics::followers_growth),
+    "/analytics/performance" => get(routes::analytics::performance),
+    "/analytics/topics" => get(routes::analytics::topics),
+    "/analytics/topics/performance" => get(routes::analytics::topic_performance),
+    "/analytics/recent-performance" => get(routes::analytics::recent_performance),
+    "/analytics/engagement-rate" => get(routes::analytics::engagement_rate),
+    "/analytics/reach" => get(routes::analytics::reach),
+    "/analytics/follower-growth" => get(routes::analytics::follower_growth),
+    "/analytics/best-times" => get(routes::analytics::best_times),
+    "/analytics/heatmap" => get(routes::analytics::heatmap),
+    "/analytics/content-breakdown" => get(routes::analytics::content_breakdown),
+    "/analytics/frameworks" => get(routes::analytics::frameworks),
+    // Approval
+    "/approval/export" => get(routes::approval::export_items),
+    "/approval" => get(routes::approval::list_items),
+    "/approval/stats" => get(routes::approval::stats),
+    "/approval/approve-all" => post(routes::approval::approve_all),
+    "/approval/bulk/approve" => post(routes::approval::bulk_approve),
+    "/approval/bulk/reject" => post(routes::approval::bulk_reject),
+    "/approval/{id}/history" => get(routes::approval::get_edit_history),
+    "/approval/{id}/preview" => get(routes::approval::preview_item),
+    "/approval/{id}" => patch(routes::approval::edit_item),
+    "/approval/{id}/approve" => post(routes::approval::approve_item),
+    "/approval/{id}/reject" => post(routes::approval::reject_item),
+    // Activity
+    "/activity/export" => get(routes::activity::export_activity),
+    "/activity" => get(routes::activity::list_activity),
+    "/activity/stream" => get(ws::activity_stream_handler),
+    "/activity/rate-limits" => get(routes::activity::rate_limit_usage),
+    // Replies
+    "/replies" => get(routes::replies::list_replies),
+    // Content
+    "/content/tweets" => get(routes::content::list_tweets).post(routes::content::compose_tweet),
+    "/content/threads" => get(routes::content::list_threads).post(routes::content::compose_thread),
+    "/content/calendar" => get(routes::content::calendar),
+    "/content/calendar.ics" => get(routes::content::calendar_ics),
+    "/content/schedule" => get(routes::content::schedule),
+    "/content/preview-schedule" => get(routes::content::preview_schedule),
+    "/content/compose" => post(routes::content::compose),
+    "/content/scheduled/{id}" => patch(routes::content::edit_scheduled)
+        .delete(routes::content::cancel_scheduled),
+    // Draft Studio — Tags (literal paths before parameterized)
+    "/tags" => get(routes::content::list_account_tags).post(routes::content::create_account_tag),
+    // Draft Studio (new canonical paths)
+    "/drafts" => get(routes::content::list_studio_drafts)
+        .post(routes::content::create_studio_draft),
+    "/drafts/{id}" => get(routes::content::get_studio_draft)
+        .patch(routes::content::autosave_draft)
+        .delete(routes::content::delete_draft),
+    "/drafts/{id}/meta" => patch(routes::content::patch_draft_meta),
+    "/drafts/{id}/schedule" => post(routes::content::schedule_studio_draft),
+    "/drafts/{id}/reschedule" => patch(routes::content::reschedule_studio_draft),
+    "/drafts/{id}/unschedule" => post(routes::content::unschedule_studio_draft),
+    "/drafts/{id}/archive" => post(routes::content::archive_studio_draft),
+    "/drafts/{id}/restore" => post(routes::content::restore_studio_draft),
+    "/drafts/{id}/duplicate" => post(routes::content::duplicate_studio_draft),
+    "/drafts/{id}/revisions" => get(routes::content::list_draft_revisions)
+        .post(routes::content::create_draft_revision),
+    "/drafts/{id}/revisions/{rev_id}/restore" => post(routes::content::restore_from_revision),
+    "/drafts/{id}/activity" => get(routes::content::list_draft_activity),
+    "/drafts/{id}/provenance" => get(routes::content::get_draft_provenance),
+    "/drafts/{id}/tags" => get(routes::content::list_draft_tags),
+    "/drafts/{id}/tags/{tag_id}" => post(routes::content::assign_draft_tag)
+        .delete(routes::content::unassign_draft_tag),
+    // Legacy drafts (backward compat)
+    "/content/drafts" => get(routes::content::list_drafts).post(routes::content::create_draft),
+    "/content/drafts/{id}" => patch(routes::content::edit_draft)
+        .delete(routes::content::delete_draft),
+    "/content/drafts/{id}/schedule" => post(routes::content::schedule_draft),
+    "/content/drafts/{id}/publish" => post(routes::content::publish_draft),
+    "/content/drafts/{id}/provenance" => get(routes::content::get_draft_provenance),
+    "/content/drafts/{id}/score" => get(routes::content::score_draft),
+    // Ingest
+    "/ingest" => post(routes::ingest::ingest),
+    // Sources
+    "/sources/status" => get(routes::sources::source_status),
+    "/sources/{id}/reindex" => post(routes::sources::reindex_source),
+    // Targets
+    "/targets" => get(routes::targets::list_targets).post(routes::targets::add_target),
+    "/targets/{username}/timeline" => get(routes::targets::target_timeline),
+    "/targets/{username}/stats" => get(routes::targets::target_stats),
+    "/targets/{username}/score-preview" => get(routes::targets::target_score_preview),
+    "/targets/{username}" => delete(routes::targets::remove_target),
+    // Strategy
+    "/strategy/current" => get(routes::strategy::current),
+    "/strategy/history" => get(routes::strategy::history),
+    "/strategy/history/{id}/inputs" => get(routes::strategy::history_inputs),
+    "/strategy/refresh" => post(routes::strategy::refresh),
+    "/strategy/inputs" => get(routes::strategy::inputs),
+    "/strategy/recommendations" => get(routes::strategy::recommendations),
+    // Costs — LLM
+    "/costs/summary" => get(routes::costs::summary),
+    "/costs/daily" => get(routes::costs::daily),
+    "/costs/by-model" => get(routes::costs::by_model),
+    "/costs/by-type" => get(routes::costs::by_type),
+    // Costs — X API
+    "/costs/x-api/summary" => get(routes::costs::x_api_summary),
+    "/costs/x-api/daily" => get(routes::costs::x_api_daily),
+    "/costs/x-api/by-endpoint" => get(routes::costs::x_api_by_endpoint),
+    // AI Assist
+    "/assist/tweet" => post(routes::assist::assist_tweet),
+    "/assist/reply" => post(routes::assist::assist_reply),
+    "/assist/thread" => post(routes::assist::assist_thread),
+    "/assist/improve" => post(routes::assist::assist_improve),
+    "/assist/quote" => post(routes::assist::assist_quote),
+    "/assist/highlights" => post(routes::assist::assist_highlights),
+    "/assist/hooks" => post(routes::assist::hooks::assist_hooks),
+    "/assist/angles" => post(routes::assist::angles::assist_angles),
+    "/assist/topics" => get(routes::assist::assist_topics),
+    "/assist/optimal-times" => get(routes::assist::assist_optimal_times),
+    "/assist/mode" => get(routes::assist::get_mode),
+    // Vault
+    "/vault/evidence" => get(routes::vault::evidence::search_evidence),
+    "/vault/index-status" => get(routes::vault::index_status::get_index_status),
+    "/vault/sources" => get(routes::vault::vault_sources),
+    "/vault/notes" => get(routes::vault::search_notes),
+    "/vault/notes/{id}/neighbors" => get(routes::vault::note_neighbors),
+    "/vault/notes/{id}" => get(routes::vault::note_detail),
+    "/vault/search" => get(routes::vault::search_fragments),
+    "/vault/resolve-refs" => post(routes::vault::resolve_refs),
+    "/vault/send-selection" => post(routes::vault::selections::send_selection),
+    "/vault/selection/{session_id}" => get(routes::vault::selections::get_selection),
+    // Discovery feed
+    "/discovery/feed" => get(routes::discovery::feed),
+    "/discovery/keywords" => get(routes::discovery::keywords),
+    "/discovery/keywords/suggestions" => get(routes::discovery::keyword_suggestions),
+    "/discovery/{tweet_id}/compose-reply" => post(routes::discovery::compose_reply),
+    "/discovery/{tweet_id}/queue-reply" => post(routes::discovery::queue_reply),
+    // Media — raise body limit for uploads (default 2MB is too small for images/video).
+    "/media/upload" => post(routes::media::upload)
+        .layer(DefaultBodyLimit::max(520 * 1024 * 1024)),
+    "/media/file" => get(routes::media::serve_file),
+    // LAN settings
+    "/settings/lan" => get(routes::lan::get_status).patch(routes::lan::toggle_lan),
+    "/settings/lan/reset-passphrase" => post(routes::lan::reset_passphrase),
+    // Settings
+    "/settings/status" => get(routes::settings::config_status),
+    "/settings/init" => post(routes::settings::init_settings),
+    "/settings/validate" => post(routes::settings::validate_settings),
+    "/settings/defaults" => get(routes::settings::get_defaults),
+    "/settings/schema" => get(routes::settings::get_schema),
+    "/settings/test-llm" => post(routes::settings::test_llm),
+    "/settings/factory-reset" => post(routes::settings::factory_reset),
+    "/settings/scraper-session" => get(routes::scraper_session::get_scraper_session)
+        .post(routes::scraper_session::import_scraper_session)
+        .delete(routes::scraper_session::delete_scraper_session),
+    "/settings" => get(routes::settings::get_settings).patch(routes::settings::patch_settings),
+    // Connectors
+    "/connectors/google-drive/link" => post(routes::connectors::link_google_drive),
+    "/connectors/google-drive/callback" => get(routes::connectors::callback_google_drive),
+    "/connectors/google-drive/status" => get(routes::connectors::status_google_drive),
+    "/connectors/google-drive/{id}" => delete(routes::connectors::disconnect_google_drive),
+    // MCP governance
+    "/mcp/policy" => get(routes::mcp::get_policy).patch(routes::mcp::patch_policy),
+    "/mcp/policy/templates" => get(routes::mcp::list_templates),
+    "/mcp/policy/templates/{name}" => post(routes::mcp::apply_template),
+    "/mcp/telemetry/summary" => get(routes::mcp::telemetry_summary),
+    "/mcp/telemetry/metrics" => get(routes::mcp::telemetry_metrics),
+    "/mcp/telemetry/errors" => get(routes::mcp::telemetry_errors),
+    "/mcp/telemetry/recent" => get(routes::mcp::telemetry_recent),
+    "/mcp/telemetry/export" => get(routes::mcp::telemetry_export),
+    // Runtime
+    "/runtime/status" => get(routes::runtime::status),
+    "/runtime/start" => post(routes::runtime::start),
+    "/runtime/stop" => post(routes::runtime::stop),
+    "/runtime/logs" => get(routes::runtime::logs),
+    // Onboarding OAuth (pre-account, auth-exempt)
+    "/onboarding/x-auth/start" => post(routes::onboarding::start_onboarding_auth),
+    "/onboarding/x-auth/callback" => post(routes::onboarding::complete_onboarding_auth),
+    "/onboarding/x-auth/status" => get(routes::onboarding::onboarding_auth_status),
+    "/onboarding/analyze-profile" => post(routes::onboarding::analyze_profile),
+    // Accounts
+    "/accounts" => get(routes::accounts::list_accounts).post(routes::accounts::create_account),
+    "/accounts/{id}/roles" => get(routes::accounts::list_roles)
+        .post(routes::accounts::set_role)
+        .delete(routes::accounts::remove_role),
+    "/accounts/{id}/sync-profile" => post(routes::accounts::sync_profile),
+    "/accounts/{id}/switch" => post(routes::accounts::switch_account),
+    // X credential linking (before catch-all /accounts/{id})
+    "/accounts/{id}/x-auth/start" => post(routes::x_auth::start_link),
+    "/accounts/{id}/x-auth/callback" => post(routes::x_auth::complete_link),
+    "/accounts/{id}/x-auth/status" => get(routes::x_auth::link_status),
+    "/accounts/{id}/x-auth/tokens" => delete(routes::x_auth::unlink),
+    "/accounts/{id}" => get(routes::accounts::get_account)
+        .patch(routes::accounts::update_account)
+        .delete(routes::accounts::delete_account),
+    // Telemetry
+    "/telemetry/events" => post(routes::telemetry::ingest_events),
+    // WebSocket
+    "/ws" => get(ws::ws_handler),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::API_ROUTES;
+
+    /// Method helpers from `axum::routing` that register a handler.
+    const METHODS: &[&str] = &["get", "post", "put", "patch", "delete"];
+
+    #[test]
+    fn registered_routes_match_published_manifest() {
+        let registered: Vec<(&str, Vec<String>)> = API_ROUTES
+            .iter()
+            .map(|(path, calls)| {
+                let methods = calls
+                    .iter()
+                    .filter(|call| METHODS.contains(call))
+                    .map(|method| method.to_ascii_uppercase())
+                    .collect();
+                (*path, methods)
+            })
+            .collect();
+        let published: Vec<(&str, Vec<String>)> = tuitbot_core::route_manifest::API_ROUTES
+            .iter()
+            .map(|(path, methods)| (*path, methods.iter().map(|m| m.to_string()).collect()))
+            .collect();
+        assert_eq!(registered, published);
+    }
+}
@@ -224,9 +224,32 @@ pub async fn delete_account(
     accounts::delete_account(&state.db, &id)
         .await
         .map_err(|_| ApiError::BadRequest("cannot delete this account".to_string()))?;
+    state.active_account.clear_if(&id).await;
     Ok(Json(json!({"status": "archived"})))
 }
 
+/// `POST /api/accounts/{id}/switch` — make `id` the active account (admin only).
+///
+/// This changes process-global state: every client that omits the
+/// `X-Account-Id` header falls back to the active account, so the switch
+/// affects all of them, not just the caller. It is also written to the
+/// `active_account` sentinel, so it survives restarts and is shared with
+/// `tuitbot accounts switch`. Headerless requests are still authorized by
+/// the role held on the switched-to account, so switching never widens
+/// access. A caller without admin on the active account can still switch
+/// by sending `X-Account-Id` for an account they administer.
+pub async fn switch_account(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, ApiError> {
+    require_mutate(&ctx)?;
+    if !state.active_account.switch(&state.db, &id).await? {
+        return Err(ApiError::NotFound(format!("account not found: {id}")));
+    }
+    Ok(Json(json!({"active_account_id": id})))
+}
+
 // ---- Role management ----
 
 /// `GET /api/accounts/{id}/roles` — list roles for an account.
@@ -6,10 +6,13 @@ use axum::extract::{Query, State};
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
+use tuitbot_core::safety::RateLimiter;
+use tuitbot_core::storage::pagination::{Page, PageCursor};
 use tuitbot_core::storage::{action_log, rate_limits};
 
 use crate::account::AccountContext;
 use crate::error::ApiError;
+use crate::routes::pagination::parse_cursor;
 use crate::state::AppState;
 
 /// Query parameters for the activity endpoint.
@@ -18,9 +21,12 @@ pub struct ActivityQuery {
     /// Maximum number of actions to return (default: 50).
     #[serde(default = "default_limit")]
     pub limit: u32,
-    /// Offset for pagination (default: 0).
+    /// Offset for pagination (default: 0). Ignored when `cursor` is set.
     #[serde(default)]
     pub offset: u32,
+    /// `next_cursor` from the previous page; pages stay stable as new
+    /// actions are logged, unlike `offset`.
+    pub cursor: Option<String>,
     /// Filter by action type. Use "all" or omit for no filter.
     #[serde(rename = "type")]
     pub action_type: Option<String>,
@@ -32,7 +38,10 @@ fn default_limit() -> u32 {
     50
 }
 
-/// `GET /api/activity` — paginated, filterable action log.
+/// `GET /api/activity` — paginated, filterable action log, newest first.
+///
+/// Pass the returned `next_cursor` as `cursor` to fetch the next page; it is
+/// `null` on the last page.
 pub async fn list_activity(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
@@ -44,26 +53,41 @@ pub async fn list_activity(
             .as_deref()
             .and_then(|t| if t == "all" { None } else { Some(t) });
     let status_filter = params.status.as_deref();
-
-    let actions = action_log::get_actions_paginated_for(
-        &state.db,
-        &ctx.account_id,
-        params.limit,
-        params.offset,
-        type_filter,
-        status_filter,
-    )
-    .await?;
+    let cursor = parse_cursor(params.cursor.as_deref())?;
+
+    let page = if cursor.is_some() || params.offset == 0 {
+        action_log::get_actions_page_for(
+            &state.db,
+            &ctx.account_id,
+            params.limit,
+            cursor.as_ref(),
+            type_filter,
+            status_filter,
+        )
+        .await?
+    } else {
+        let rows = action_log::get_actions_paginated_for(
+            &state.db,
+            &ctx.account_id,
+            params.limit.saturating_add(1),
+            params.offset,
+            type_filter,
+            status_filter,
+        )
+        .await?;
+        Page::from_rows(rows, params.limit, |e| PageCursor::new(&e.created_at, e.id))
+    };
 
     let total =
         action_log::get_actions_count_for(&state.db, &ctx.account_id, type_filter, status_filter)
             .await?;
 
     Ok(Json(json!({
-        "actions": actions,
+        "actions": page.items,
         "total": total,
         "limit": params.limit,
         "offset": params.offset,
+        "next_cursor": page.next_cursor,
     })))
 }
 
@@ -161,11 +185,21 @@ fn escape_csv(value: &str) -> String {
     }
 }
 
-/// `GET /api/activity/rate-limits` — current daily rate limit usage.
+/// `GET /api/activity/rate-limits` — current daily rate limit usage and remaining budget.
 pub async fn rate_limit_usage(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
 ) -> Result<Json<Value>, ApiError> {
     let usage = rate_limits::get_daily_usage_for(&state.db, &ctx.account_id).await?;
-    Ok(Json(json!(usage)))
+    let limiter = RateLimiter::new_for(state.db.clone(), ctx.account_id);
+
+    let mut body = json!(usage);
+    for (key, action_type) in [
+        ("replies", "reply"),
+        ("tweets", "tweet"),
+        ("threads", "thread"),
+    ] {
+        body[key]["remaining"] = json!(limiter.remaining(action_type).await?);
+    }
+    Ok(Json(body))
 }
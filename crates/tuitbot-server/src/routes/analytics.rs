@@ -6,6 +6,7 @@ use axum::extract::{Query, State};
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
+use tuitbot_core::content::frameworks;
 use tuitbot_core::storage::analytics;
 
 use crate::account::AccountContext;
@@ -59,6 +60,20 @@ pub async fn followers(
     Ok(Json(json!(snapshots)))
 }
 
+/// `GET /api/analytics/followers/growth` — daily/weekly net follower change,
+/// 7-day moving average, and a current/7d/30d summary. Days without a
+/// snapshot are interpolated and flagged.
+pub async fn followers_growth(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Query(params): Query<FollowerGrowthQuery>,
+) -> Result<Json<Value>, ApiError> {
+    let report =
+        analytics::get_follower_growth_report_for(&state.db, &ctx.account_id, params.window)
+            .await?;
+    Ok(Json(json!(report)))
+}
+
 /// `GET /api/analytics/performance` — reply and tweet performance summaries.
 pub async fn performance(
     State(state): State<Arc<AppState>>,
@@ -87,6 +102,25 @@ pub async fn topics(
     Ok(Json(json!(scores)))
 }
 
+/// `GET /api/analytics/topics/performance` — topics ranked by the average
+/// engagement of their posted tweets. Topics with fewer measured posts than
+/// `analytics.min_engagement_sample` are flagged `low_confidence` and listed last.
+pub async fn topic_performance(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Query(params): Query<TopicsQuery>,
+) -> Result<Json<Value>, ApiError> {
+    let config = crate::routes::content::read_effective_config(&state, &ctx.account_id).await?;
+    let outcomes = analytics::get_topic_outcomes_for(
+        &state.db,
+        &ctx.account_id,
+        config.analytics.min_engagement_sample,
+        params.limit,
+    )
+    .await?;
+    Ok(Json(json!(outcomes)))
+}
+
 /// `GET /api/analytics/summary` — combined analytics dashboard summary.
 pub async fn summary(
     State(state): State<Arc<AppState>>,
@@ -181,7 +215,13 @@ pub async fn best_times(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
 ) -> Result<Json<Value>, ApiError> {
-    let slots = analytics::get_best_times_for(&state.db, &ctx.account_id).await?;
+    let config = crate::routes::content::read_effective_config(&state, &ctx.account_id).await?;
+    let slots = analytics::get_best_times_for(
+        &state.db,
+        &ctx.account_id,
+        config.analytics.min_engagement_sample,
+    )
+    .await?;
     Ok(Json(json!(slots)))
 }
 
@@ -202,3 +242,13 @@ pub async fn content_breakdown(
     let breakdown = analytics::get_content_breakdown_for(&state.db, &ctx.account_id).await?;
     Ok(Json(json!(breakdown)))
 }
+
+/// `GET /api/analytics/frameworks` — average engagement per content
+/// framework (reply archetype, tweet format, thread structure).
+pub async fn frameworks(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+) -> Result<Json<Value>, ApiError> {
+    let report = frameworks::performance_report_for(&state.db, &ctx.account_id).await?;
+    Ok(Json(json!(report)))
+}
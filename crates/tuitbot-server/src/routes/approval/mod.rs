@@ -1,7 +1,7 @@
 //! Approval queue route handlers.
 //!
 //! Split by concern:
-//! - mod.rs: shared types + list/stats (read-only endpoints)
+//! - mod.rs: shared types + list/stats/preview (read-only endpoints)
 //! - handlers.rs: edit/approve/reject/approve_all (write endpoints)
 //! - bulk_handlers.rs: bulk approve/reject (batch write endpoints)
 //! - export.rs: CSV/JSON export, edit history, internal helpers
@@ -16,14 +16,20 @@ pub use handlers::{approve_all, approve_item, edit_item, reject_item};
 
 use std::sync::Arc;
 
-use axum::extract::{Query, State};
+use axum::extract::{Path, Query, State};
+use axum::response::{IntoResponse, Response};
 use axum::Json;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use tuitbot_core::storage::approval_queue;
+use tuitbot_core::automation::approval_poster::parse_thread_content;
+use tuitbot_core::content::length::{weighted_len, MAX_TWEET_CHARS};
+use tuitbot_core::safety::contains_banned_phrase;
+use tuitbot_core::storage::approval_queue::{self, ApprovalItem};
 
 use crate::account::AccountContext;
 use crate::error::ApiError;
+use crate::routes::content::read_effective_config;
+use crate::routes::pagination::{parse_cursor, with_next_cursor};
 use crate::state::AppState;
 
 /// Query parameters for listing approval items.
@@ -42,22 +48,32 @@ pub struct ApprovalQuery {
     /// Override the account to filter by (defaults to X-Account-Id header).
     /// Must match the authenticated account; ignored if it differs.
     pub account_id: Option<String>,
+    /// Page size. Without `limit` or `cursor`, every matching item is returned.
+    pub limit: Option<u32>,
+    /// `X-Next-Cursor` header value from the previous page.
+    pub cursor: Option<String>,
 }
 
 fn default_status() -> String {
     "pending".to_string()
 }
 
+/// Page size when only `cursor` is given.
+const DEFAULT_PAGE_LIMIT: u32 = 50;
+
 /// `GET /api/approval` — list approval items with optional status/type/reviewer/date filters.
 ///
 /// Accepts an optional `account_id` query param to scope results. The param is
 /// validated against the authenticated account — if it differs, the header
 /// account takes precedence (prevents cross-account data leakage).
+///
+/// With `limit` or `cursor`, returns one page, oldest first, and the cursor
+/// for the next page in the `X-Next-Cursor` header.
 pub async fn list_items(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
     Query(params): Query<ApprovalQuery>,
-) -> Result<Json<Value>, ApiError> {
+) -> Result<Response, ApiError> {
     let statuses: Vec<&str> = params.status.split(',').map(|s| s.trim()).collect();
     let action_type = params.action_type.as_deref();
     let reviewed_by = params.reviewed_by.as_deref();
@@ -71,16 +87,32 @@ pub async fn list_items(
         None => &ctx.account_id,
     };
 
-    let items = approval_queue::get_filtered_for(
+    if params.limit.is_none() && params.cursor.is_none() {
+        let items = approval_queue::get_filtered_for(
+            &state.db,
+            effective_account_id,
+            &statuses,
+            action_type,
+            reviewed_by,
+            since,
+        )
+        .await?;
+        return Ok(Json(json!(items)).into_response());
+    }
+
+    let cursor = parse_cursor(params.cursor.as_deref())?;
+    let page = approval_queue::get_filtered_page_for(
         &state.db,
         effective_account_id,
         &statuses,
         action_type,
         reviewed_by,
         since,
+        params.limit.unwrap_or(DEFAULT_PAGE_LIMIT),
+        cursor.as_ref(),
     )
     .await?;
-    Ok(Json(json!(items)))
+    Ok(with_next_cursor(json!(page.items), page.next_cursor))
 }
 
 /// `GET /api/approval/stats` — counts by status.
@@ -92,5 +124,96 @@ pub async fn stats(
     Ok(Json(json!(stats)))
 }
 
+/// One tweet of a thread preview.
+#[derive(Debug, Serialize)]
+pub struct ThreadPartPreview {
+    pub text: String,
+    pub weighted_len: usize,
+    pub within_limit: bool,
+}
+
+/// An approval item rendered as it will be posted.
+#[derive(Debug, Serialize)]
+pub struct ApprovalPreview {
+    pub id: i64,
+    pub action_type: String,
+    pub status: String,
+    pub content: String,
+    pub media_paths: Vec<String>,
+    /// X-weighted length; for threads, the longest part.
+    pub weighted_len: usize,
+    /// Whether every tweet fits in [`MAX_TWEET_CHARS`].
+    pub within_limit: bool,
+    /// First banned phrase found in any tweet, if one is.
+    pub banned_phrase_hit: Option<String>,
+    /// Thread tweets in posting order; `None` for single tweets and replies.
+    pub thread_parts: Option<Vec<ThreadPartPreview>>,
+}
+
+/// Build the preview for an item. Threads whose content cannot be parsed
+/// are previewed as a single tweet of the raw content.
+pub(crate) fn build_preview(item: &ApprovalItem, banned_phrases: &[String]) -> ApprovalPreview {
+    let media_paths: Vec<String> = serde_json::from_str(&item.media_paths).unwrap_or_default();
+
+    let parts = (item.action_type == "thread")
+        .then(|| parse_thread_content(&item.generated_content).ok())
+        .flatten();
+    let texts: Vec<&str> = match &parts {
+        Some(parts) => parts.iter().map(String::as_str).collect(),
+        None => vec![item.generated_content.as_str()],
+    };
+
+    let max_weighted_len = texts.iter().map(|t| weighted_len(t)).max().unwrap_or(0);
+    let banned_phrase_hit = texts
+        .iter()
+        .find_map(|t| contains_banned_phrase(t, banned_phrases));
+
+    let thread_parts = parts.as_ref().map(|parts| {
+        parts
+            .iter()
+            .map(|text| {
+                let len = weighted_len(text);
+                ThreadPartPreview {
+                    text: text.clone(),
+                    weighted_len: len,
+                    within_limit: len <= MAX_TWEET_CHARS,
+                }
+            })
+            .collect()
+    });
+
+    ApprovalPreview {
+        id: item.id,
+        action_type: item.action_type.clone(),
+        status: item.status.clone(),
+        content: item.generated_content.clone(),
+        media_paths,
+        weighted_len: max_weighted_len,
+        within_limit: max_weighted_len <= MAX_TWEET_CHARS,
+        banned_phrase_hit,
+        thread_parts,
+    }
+}
+
+/// `GET /api/approval/{id}/preview` — render an item as it will be posted.
+///
+/// Read-only: returns the content plus its weighted length, whether it fits
+/// the tweet limit, the first banned phrase hit, and thread parts.
+pub async fn preview_item(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+) -> Result<Json<Value>, ApiError> {
+    let item = approval_queue::get_by_id_for(&state.db, &ctx.account_id, id)
+        .await?
+        .ok_or_else(|| ApiError::NotFound(format!("approval item {id} not found")))?;
+    let config = read_effective_config(&state, &ctx.account_id).await?;
+
+    Ok(Json(json!(build_preview(
+        &item,
+        &config.limits.banned_phrases
+    ))))
+}
+
 #[cfg(test)]
 mod tests;
@@ -6,6 +6,7 @@
 pub mod angles;
 pub mod hooks;
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use axum::extract::State;
@@ -13,7 +14,8 @@ use axum::http::StatusCode;
 use axum::Json;
 use serde::{Deserialize, Serialize};
 
-use tuitbot_core::content::ContentGenerator;
+use chrono_tz::Tz;
+use tuitbot_core::content::{rotation, scheduling, ContentGenerator, FrameworkWeights};
 use tuitbot_core::context::retrieval::VaultCitation;
 use tuitbot_core::storage;
 
@@ -36,6 +38,17 @@ async fn get_generator(
         .map_err(ApiError::BadRequest)
 }
 
+/// The account's `content.framework_weights`, or `None` when
+/// `content.auto_rotate` is disabled.
+async fn rotation_weights(state: &AppState, account_id: &str) -> Option<FrameworkWeights> {
+    state
+        .load_effective_config(account_id)
+        .await
+        .ok()
+        .filter(|c| c.content.auto_rotate)
+        .map(|c| FrameworkWeights::from_config(&c.content))
+}
+
 // ---------------------------------------------------------------------------
 // POST /api/assist/tweet
 // ---------------------------------------------------------------------------
@@ -53,6 +66,10 @@ pub struct AssistTweetResponse {
     pub topic: String,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub vault_citations: Vec<VaultCitation>,
+    /// Framework rotated into the prompt, when `content.auto_rotate` is on.
+    /// Pass it back as compose's `framework` to credit the post to it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub framework: Option<String>,
 }
 
 pub async fn assist_tweet(
@@ -70,8 +87,15 @@ pub async fn assist_tweet(
         .map(|c| c.vault_citations.clone())
         .unwrap_or_default();
 
+    let format = match rotation_weights(&state, &ctx.account_id).await {
+        Some(weights) => {
+            Some(rotation::next_format_for(&state.db, &ctx.account_id, &weights).await?)
+        }
+        None => None,
+    };
+
     let output = gen
-        .generate_tweet_with_context(&body.topic, None, prompt_block)
+        .generate_tweet_with_context(&body.topic, format, prompt_block)
         .await
         .map_err(|e| ApiError::Internal(e.to_string()))?;
 
@@ -79,6 +103,7 @@ pub async fn assist_tweet(
         content: output.text,
         topic: body.topic,
         vault_citations: citations,
+        framework: format.map(|f| f.to_string()),
     }))
 }
 
@@ -101,6 +126,10 @@ pub struct AssistReplyResponse {
     pub content: String,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub vault_citations: Vec<VaultCitation>,
+    /// Framework rotated into the prompt, when `content.auto_rotate` is on.
+    /// Pass it back as compose's `framework` to credit the post to it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub framework: Option<String>,
 }
 
 pub async fn assist_reply(
@@ -118,13 +147,21 @@ pub async fn assist_reply(
         .map(|c| c.vault_citations.clone())
         .unwrap_or_default();
 
+    let archetype = match rotation_weights(&state, &ctx.account_id).await {
+        Some(weights) => {
+            Some(rotation::next_archetype_for(&state.db, &ctx.account_id, &weights).await?)
+        }
+        None => None,
+    };
+
     let output = gen
         .generate_reply_with_context(
             &body.tweet_text,
             &body.tweet_author,
             body.mention_product,
-            None,
+            archetype,
             prompt_block,
+            &[],
         )
         .await
         .map_err(|e| ApiError::Internal(e.to_string()))?;
@@ -132,6 +169,7 @@ pub async fn assist_reply(
     Ok(Json(AssistReplyResponse {
         content: output.text,
         vault_citations: citations,
+        framework: archetype.map(|a| a.to_string()),
     }))
 }
 
@@ -154,6 +192,10 @@ pub struct AssistThreadResponse {
     pub topic: String,
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub vault_citations: Vec<VaultCitation>,
+    /// Framework rotated into the prompt, when `content.auto_rotate` is on.
+    /// Pass it back as compose's `framework` to credit the post to it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub framework: Option<String>,
 }
 
 pub async fn assist_thread(
@@ -171,11 +213,18 @@ pub async fn assist_thread(
         .map(|c| c.vault_citations.clone())
         .unwrap_or_default();
 
+    let structure = match rotation_weights(&state, &ctx.account_id).await {
+        Some(weights) => {
+            Some(rotation::next_structure_for(&state.db, &ctx.account_id, &weights).await?)
+        }
+        None => None,
+    };
+
     let output = if let Some(ref hook) = body.opening_hook {
-        gen.generate_thread_with_hook(&body.topic, hook, None, prompt_block)
+        gen.generate_thread_with_hook(&body.topic, hook, structure, prompt_block)
             .await
     } else {
-        gen.generate_thread_with_context(&body.topic, None, prompt_block)
+        gen.generate_thread_with_context(&body.topic, structure, prompt_block)
             .await
     }
     .map_err(|e| ApiError::Internal(e.to_string()))?;
@@ -184,6 +233,7 @@ pub async fn assist_thread(
         tweets: output.tweets,
         topic: body.topic,
         vault_citations: citations,
+        framework: structure.map(|s| s.to_string()),
     }))
 }
 
@@ -233,6 +283,47 @@ pub async fn assist_improve(
     }))
 }
 
+// ---------------------------------------------------------------------------
+// POST /api/assist/quote
+// ---------------------------------------------------------------------------
+
+#[derive(Deserialize)]
+pub struct AssistQuoteRequest {
+    pub quoted_text: String,
+    pub quoted_author: String,
+    #[serde(default)]
+    pub topic_hint: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct AssistQuoteResponse {
+    pub content: String,
+}
+
+pub async fn assist_quote(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Json(body): Json<AssistQuoteRequest>,
+) -> Result<Json<AssistQuoteResponse>, ApiError> {
+    if body.quoted_text.trim().is_empty() {
+        return Err(ApiError::BadRequest("quoted_text is required".to_string()));
+    }
+    let gen = get_generator(&state, &ctx.account_id).await?;
+
+    let output = gen
+        .generate_quote(
+            &body.quoted_text,
+            &body.quoted_author,
+            body.topic_hint.as_deref(),
+        )
+        .await
+        .map_err(|e| ApiError::Internal(e.to_string()))?;
+
+    Ok(Json(AssistQuoteResponse {
+        content: output.text,
+    }))
+}
+
 // ---------------------------------------------------------------------------
 // POST /api/assist/highlights
 // ---------------------------------------------------------------------------
@@ -319,7 +410,16 @@ pub async fn assist_topics(
 
 #[derive(Serialize)]
 pub struct OptimalTimesResponse {
+    /// Hour-of-day performance (UTC) across all history.
     pub times: Vec<OptimalTime>,
+    /// Schedule timezone the suggestions below are expressed in.
+    pub timezone: String,
+    /// `"history"` when computed from the account's posts, `"default"` otherwise.
+    pub source: &'static str,
+    /// Suggested `schedule.preferred_times` (HH:MM, local time).
+    pub preferred_times: Vec<String>,
+    /// Suggested `schedule.preferred_times_override`, keyed by day (Mon-Sun).
+    pub preferred_times_override: BTreeMap<String, Vec<String>>,
 }
 
 #[derive(Serialize)]
@@ -327,14 +427,24 @@ pub struct OptimalTime {
     pub hour: u32,
     pub avg_engagement: f64,
     pub post_count: i64,
+    /// Fewer posts than `analytics.min_engagement_sample`; not used for suggestions.
+    pub low_confidence: bool,
 }
 
 pub async fn assist_optimal_times(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
 ) -> Result<Json<OptimalTimesResponse>, ApiError> {
+    let config = crate::routes::content::read_effective_config(&state, &ctx.account_id).await?;
+    let tz: Tz = config.schedule.timezone.parse().unwrap_or(chrono_tz::UTC);
+
+    let min_sample = config.analytics.min_engagement_sample;
+
     let rows =
-        storage::analytics::get_optimal_posting_times_for(&state.db, &ctx.account_id).await?;
+        storage::analytics::get_optimal_posting_times_for(&state.db, &ctx.account_id, min_sample)
+            .await?;
+    let suggestion =
+        scheduling::suggest_preferred_times_for(&state.db, &ctx.account_id, tz, min_sample).await?;
 
     let times = rows
         .into_iter()
@@ -342,10 +452,17 @@ pub async fn assist_optimal_times(
             hour: r.hour as u32,
             avg_engagement: r.avg_engagement,
             post_count: r.post_count,
+            low_confidence: r.low_confidence,
         })
         .collect();
 
-    Ok(Json(OptimalTimesResponse { times }))
+    Ok(Json(OptimalTimesResponse {
+        times,
+        timezone: tz.name().to_string(),
+        source: suggestion.source,
+        preferred_times: suggestion.preferred_times,
+        preferred_times_override: suggestion.preferred_times_override,
+    }))
 }
 
 // ---------------------------------------------------------------------------
@@ -419,6 +536,8 @@ mod tests {
             x_client_id: String::new(),
             semantic_index: None,
             embedding_provider: None,
+            active_account: Default::default(),
+            log_buffer: Default::default(),
         }
     }
 
@@ -531,8 +650,10 @@ mod tests {
         let resp = AssistReplyResponse {
             content: "Great point!".to_string(),
             vault_citations: vec![],
+            framework: None,
         };
         let json = serde_json::to_string(&resp).expect("serialize");
         assert!(!json.contains("vault_citations"));
+        assert!(!json.contains("framework"));
     }
 }
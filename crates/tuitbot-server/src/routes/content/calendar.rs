@@ -3,9 +3,14 @@
 use std::sync::Arc;
 
 use axum::extract::{Query, State};
+use axum::http::header;
+use axum::response::IntoResponse;
 use axum::Json;
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
+use tuitbot_core::automation::schedule::{build_schedule_preview, render_ics, ActiveSchedule};
+use tuitbot_core::storage::scheduled_content::ScheduledContent;
 use tuitbot_core::storage::{approval_queue, replies, scheduled_content, threads};
 
 use crate::account::AccountContext;
@@ -171,3 +176,92 @@ pub async fn schedule(
         "thread_time": config.schedule.thread_preferred_time,
     })))
 }
+
+/// Days covered by the schedule preview, today included.
+const PREVIEW_DAYS: u32 = 7;
+
+/// Upper bound on `days` for the ICS export.
+const MAX_ICS_DAYS: u32 = 60;
+
+/// `GET /api/content/preview-schedule` — concrete tweet and thread slots for
+/// the next 7 days, cross-referenced with scheduled content.
+pub async fn preview_schedule(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+) -> Result<Json<Value>, ApiError> {
+    let config = read_effective_config(&state, &ctx.account_id).await?;
+    let now = Utc::now();
+    let (schedule, scheduled) =
+        load_schedule(&state, &ctx.account_id, &config.schedule, now, PREVIEW_DAYS).await?;
+
+    Ok(Json(json!({
+        "timezone": config.schedule.timezone,
+        "tweet_mode": if schedule.has_preferred_times() { "slots" } else { "interval" },
+        "thread_mode": if schedule.has_thread_preferred_schedule() { "slots" } else { "interval" },
+        "days": build_schedule_preview(&schedule, now, PREVIEW_DAYS, &scheduled),
+    })))
+}
+
+/// Query parameters for the ICS export.
+#[derive(Deserialize)]
+pub struct CalendarIcsQuery {
+    /// Days to export, today included (default 7, max 60).
+    pub days: Option<u32>,
+}
+
+/// `GET /api/content/calendar.ics?days=N` — the next N days of posting slots
+/// and scheduled content as an iCalendar feed.
+pub async fn calendar_ics(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Query(params): Query<CalendarIcsQuery>,
+) -> Result<impl IntoResponse, ApiError> {
+    let days = params.days.unwrap_or(PREVIEW_DAYS);
+    if days == 0 || days > MAX_ICS_DAYS {
+        return Err(ApiError::BadRequest(format!(
+            "days must be between 1 and {MAX_ICS_DAYS}"
+        )));
+    }
+
+    let config = read_effective_config(&state, &ctx.account_id).await?;
+    let now = Utc::now();
+    let (schedule, scheduled) =
+        load_schedule(&state, &ctx.account_id, &config.schedule, now, days).await?;
+    let preview = build_schedule_preview(&schedule, now, days, &scheduled);
+
+    Ok((
+        [
+            (header::CONTENT_TYPE, "text/calendar; charset=utf-8"),
+            (
+                header::CONTENT_DISPOSITION,
+                "inline; filename=\"tuitbot-schedule.ics\"",
+            ),
+        ],
+        render_ics(&preview, &scheduled, now),
+    ))
+}
+
+/// Resolve the account's schedule and load the scheduled content covering
+/// the next `days` days.
+async fn load_schedule(
+    state: &AppState,
+    account_id: &str,
+    config: &tuitbot_core::config::ScheduleConfig,
+    now: DateTime<Utc>,
+    days: u32,
+) -> Result<(ActiveSchedule, Vec<ScheduledContent>), ApiError> {
+    let schedule = ActiveSchedule::from_config(config).ok_or_else(|| {
+        ApiError::BadRequest(format!("invalid schedule timezone '{}'", config.timezone))
+    })?;
+
+    let to = now + Duration::days(i64::from(days) + 1);
+    let scheduled = scheduled_content::get_in_range_for(
+        &state.db,
+        account_id,
+        &now.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+        &to.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+    )
+    .await?;
+
+    Ok((schedule, scheduled))
+}
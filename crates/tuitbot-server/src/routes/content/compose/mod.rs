@@ -6,9 +6,9 @@ use axum::extract::State;
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
-use tuitbot_core::content::ThreadBlock;
-use tuitbot_core::storage::approval_queue;
+use tuitbot_core::content::{frameworks, ThreadBlock};
 use tuitbot_core::storage::provenance::ProvenanceRef;
+use tuitbot_core::storage::{approval_queue, content_frameworks};
 
 use crate::account::{require_mutate, AccountContext};
 use crate::error::ApiError;
@@ -218,6 +218,10 @@ pub struct ComposeRequest {
     /// Optional hook style tag (e.g. "contrarian_take") for source enrichment.
     #[serde(default)]
     pub hook_style: Option<String>,
+    /// Optional content framework the assist endpoints generated this with
+    /// (their response's `framework`), for the framework performance report.
+    #[serde(default)]
+    pub framework: Option<String>,
 }
 
 /// `POST /api/content/compose` — compose manual content (tweet or thread).
@@ -271,6 +275,31 @@ fn build_provenance_input(
     })
 }
 
+/// Link a composed tweet (or thread root) posted as `tweet_id` to the
+/// request's framework, if it named one. Failures are logged only.
+async fn record_compose_framework(
+    state: &AppState,
+    ctx: &AccountContext,
+    body: &ComposeRequest,
+    tweet_id: &str,
+) {
+    let Some(framework) = body.framework.as_deref().filter(|f| !f.is_empty()) else {
+        return;
+    };
+    let kind = frameworks::kind_for_action(&body.content_type);
+    if let Err(e) = content_frameworks::record_framework_for(
+        &state.db,
+        &ctx.account_id,
+        tweet_id,
+        kind,
+        framework,
+    )
+    .await
+    {
+        tracing::warn!(tweet_id, error = %e, "Failed to record content framework");
+    }
+}
+
 pub(crate) mod transforms;
 
 #[cfg(test)]
@@ -2,20 +2,21 @@
 
 use axum::Json;
 use serde_json::{json, Value};
+use tuitbot_core::automation::{ActivityEvent, ActivityKind, ActivitySink, ActivityStatus};
 use tuitbot_core::content::{
     serialize_blocks_for_storage, tweet_weighted_len, validate_thread_blocks, ThreadBlock,
     MAX_TWEET_CHARS,
 };
 use tuitbot_core::storage::{action_log, approval_queue, provenance, scheduled_content, threads};
-use tuitbot_core::x_api::{XApiClient, XApiHttpClient};
 
 use crate::account::AccountContext;
 use crate::error::ApiError;
 use crate::state::AppState;
-use crate::ws::{AccountWsEvent, WsEvent};
+use crate::ws::{AccountWsEvent, WsActivitySink, WsEvent};
 
 use super::super::read_approval_mode;
-use super::{build_provenance_input, ComposeRequest, ThreadBlockRequest};
+use super::{build_provenance_input, record_compose_framework, ComposeRequest, ThreadBlockRequest};
+use crate::routes::x_client::build_x_client;
 
 pub(super) async fn compose_tweet_flow(
     state: &AppState,
@@ -120,7 +121,7 @@ pub(super) async fn compose_thread_blocks_flow(
             "",
             &content,
             "",
-            "",
+            body.framework.as_deref().unwrap_or(""),
             0.0,
             &media_json,
             None,
@@ -229,7 +230,7 @@ pub(super) async fn compose_thread_blocks_flow(
             })));
         }
 
-        try_post_thread_now(state, ctx, &core_blocks, body.provenance.as_deref()).await
+        try_post_thread_now(state, ctx, &core_blocks, body).await
     }
 }
 
@@ -268,7 +269,7 @@ async fn persist_content(
             "",
             content,
             "",
-            "",
+            body.framework.as_deref().unwrap_or(""),
             0.0,
             &media_json,
             None,
@@ -375,62 +376,7 @@ async fn persist_content(
             })));
         }
 
-        try_post_now(state, ctx, &body.content_type, content).await
-    }
-}
-
-/// Build an X API client for the given account based on the configured backend.
-///
-/// Returns `Box<dyn XApiClient>` so callers can use either scraper or OAuth
-/// without duplicating the construction logic.
-async fn build_x_client(
-    state: &AppState,
-    ctx: &AccountContext,
-) -> Result<Box<dyn XApiClient>, ApiError> {
-    let config = super::super::read_effective_config(state, &ctx.account_id).await?;
-
-    match config.x_api.provider_backend.as_str() {
-        "scraper" => {
-            let account_data =
-                tuitbot_core::storage::accounts::account_data_dir(&state.data_dir, &ctx.account_id);
-            // Use the shared health handle from AppState so each request's outcome
-            // aggregates into the tracker that /health reads, rather than being discarded.
-            let client = if let Some(ref health) = state.scraper_health {
-                tuitbot_core::x_api::LocalModeXClient::with_session_and_health(
-                    config.x_api.scraper_allow_mutations,
-                    &account_data,
-                    health.clone(),
-                )
-                .await
-            } else {
-                tuitbot_core::x_api::LocalModeXClient::with_session(
-                    config.x_api.scraper_allow_mutations,
-                    &account_data,
-                )
-                .await
-            };
-            Ok(Box::new(client))
-        }
-        "x_api" => {
-            let token_path = tuitbot_core::storage::accounts::account_token_path(
-                &state.data_dir,
-                &ctx.account_id,
-            );
-            let access_token = state
-                .get_x_access_token(&token_path, &ctx.account_id)
-                .await
-                .map_err(|e| {
-                    ApiError::BadRequest(format!(
-                        "X API authentication failed — re-link your account in Settings. ({e})"
-                    ))
-                })?;
-            Ok(Box::new(XApiHttpClient::new(access_token)))
-        }
-        _ => Err(ApiError::BadRequest(
-            "Direct posting requires X API credentials or a browser session. \
-             Configure in Settings → X API."
-                .to_string(),
-        )),
+        try_post_now(state, ctx, body, content).await
     }
 }
 
@@ -438,18 +384,20 @@ async fn build_x_client(
 async fn try_post_now(
     state: &AppState,
     ctx: &AccountContext,
-    content_type: &str,
+    body: &ComposeRequest,
     content: &str,
 ) -> Result<Json<Value>, ApiError> {
+    let content_type = body.content_type.as_str();
     let client = build_x_client(state, ctx).await?;
+    let activity = WsActivitySink::new(&ctx.account_id, state.event_tx.clone());
 
-    let posted = client
-        .post_tweet(content)
-        .await
-        .map_err(|e| ApiError::Internal(format!("Failed to post tweet: {e}")))?;
+    let result = client.post_tweet(content).await.map(|p| p.id);
+    activity.emit(ActivityEvent::from_result(ActivityKind::Tweet, "", &result));
+    let tweet_id = result.map_err(|e| ApiError::Internal(format!("Failed to post tweet: {e}")))?;
+    record_compose_framework(state, ctx, body, &tweet_id).await;
 
     let metadata = json!({
-        "tweet_id": posted.id,
+        "tweet_id": tweet_id,
         "content_type": content_type,
         "source": "compose",
     });
@@ -458,14 +406,14 @@ async fn try_post_now(
         &ctx.account_id,
         "tweet_posted",
         "success",
-        Some(&format!("Posted tweet {}", posted.id)),
+        Some(&format!("Posted tweet {tweet_id}")),
         Some(&metadata.to_string()),
     )
     .await;
 
     Ok(Json(json!({
         "status": "posted",
-        "tweet_id": posted.id,
+        "tweet_id": tweet_id,
     })))
 }
 
@@ -475,9 +423,10 @@ async fn try_post_thread_now(
     state: &AppState,
     ctx: &AccountContext,
     blocks: &[ThreadBlock],
-    body_provenance: Option<&[provenance::ProvenanceRef]>,
+    body: &ComposeRequest,
 ) -> Result<Json<Value>, ApiError> {
     let client = build_x_client(state, ctx).await?;
+    let activity = WsActivitySink::new(&ctx.account_id, state.event_tx.clone());
 
     let mut sorted: Vec<&ThreadBlock> = blocks.iter().collect();
     sorted.sort_by_key(|b| b.order);
@@ -494,6 +443,13 @@ async fn try_post_thread_now(
         match posted {
             Ok(p) => tweet_ids.push(p.id),
             Err(e) => {
+                let target = tweet_ids.last().cloned().unwrap_or_default();
+                activity.emit(ActivityEvent {
+                    kind: ActivityKind::Thread,
+                    target,
+                    status: ActivityStatus::from(&e),
+                });
+
                 // Persist partial thread records for whatever was posted.
                 if !tweet_ids.is_empty() {
                     let partial_contents: Vec<String> = sorted
@@ -544,6 +500,16 @@ async fn try_post_thread_now(
         }
     }
 
+    activity.emit(ActivityEvent {
+        kind: ActivityKind::Thread,
+        target: tweet_ids.first().cloned().unwrap_or_default(),
+        status: ActivityStatus::Posted,
+    });
+
+    if let Some(root_id) = tweet_ids.first() {
+        record_compose_framework(state, ctx, body, root_id).await;
+    }
+
     // Persist thread records: threads + thread_tweets + original_tweets rows.
     let tweet_contents: Vec<String> = sorted.iter().map(|b| b.text.clone()).collect();
 
@@ -558,7 +524,7 @@ async fn try_post_thread_now(
     .await
     {
         // Propagate provenance to both original_tweet and thread entities.
-        if let Some(refs) = body_provenance {
+        if let Some(refs) = body.provenance.as_deref() {
             if !refs.is_empty() {
                 let _ = provenance::insert_links_for(
                     &state.db,
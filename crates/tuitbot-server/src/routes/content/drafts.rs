@@ -7,8 +7,8 @@ use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
 use tuitbot_core::content::{
-    serialize_blocks_for_storage, tweet_weighted_len, validate_thread_blocks, ThreadBlock,
-    MAX_TWEET_CHARS,
+    assess_tweet, serialize_blocks_for_storage, tweet_weighted_len, validate_thread_blocks,
+    QualityReport, ThreadBlock, MAX_TWEET_CHARS,
 };
 use tuitbot_core::storage::provenance::ProvenanceRef;
 use tuitbot_core::storage::{approval_queue, provenance, scheduled_content};
@@ -303,6 +303,34 @@ pub async fn publish_draft(
     ))
 }
 
+/// `GET /api/content/drafts/{id}/score` — heuristic quality report for a draft.
+///
+/// Only original-tweet drafts can be scored. Runs length, banned-phrase,
+/// hashtag, emoji, and call-to-action checks without calling an LLM.
+pub async fn score_draft(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+) -> Result<Json<QualityReport>, ApiError> {
+    let item = scheduled_content::get_by_id_for(&state.db, &ctx.account_id, id)
+        .await
+        .map_err(ApiError::Storage)?
+        .ok_or_else(|| ApiError::NotFound(format!("Draft {id} not found")))?;
+
+    if item.content_type != "tweet" {
+        return Err(ApiError::BadRequest(format!(
+            "only tweet drafts can be scored, got '{}'",
+            item.content_type
+        )));
+    }
+
+    let config = super::read_effective_config(&state, &ctx.account_id).await?;
+    Ok(Json(assess_tweet(
+        &item.content,
+        &config.limits.banned_phrases,
+    )))
+}
+
 /// `GET /api/drafts/:id/provenance` — retrieve provenance links for a draft.
 pub async fn get_draft_provenance(
     State(state): State<Arc<AppState>>,
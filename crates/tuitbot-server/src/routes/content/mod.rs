@@ -15,7 +15,7 @@ use crate::error::ApiError;
 use crate::state::AppState;
 
 // Re-export all handlers so route registration in lib.rs stays unchanged.
-pub use calendar::{calendar, schedule};
+pub use calendar::{calendar, calendar_ics, preview_schedule, schedule};
 pub use compose::{compose, compose_thread, compose_tweet};
 pub use draft_studio::{
     archive_studio_draft, autosave_draft, create_draft_revision, create_studio_draft,
@@ -28,13 +28,13 @@ pub use draft_tags::{
 };
 pub use drafts::{
     create_draft, delete_draft, edit_draft, get_draft_provenance, list_drafts, publish_draft,
-    schedule_draft,
+    schedule_draft, score_draft,
 };
 pub use list::{list_threads, list_tweets};
 pub use scheduled::{cancel_scheduled, edit_scheduled};
 
 // Re-export types used by route registration (if any).
-pub use calendar::{CalendarItem, CalendarQuery};
+pub use calendar::{CalendarIcsQuery, CalendarItem, CalendarQuery};
 pub use compose::{ComposeRequest, ComposeThreadRequest, ComposeTweetRequest, ThreadBlockRequest};
 pub use drafts::{CreateDraftRequest, EditDraftRequest, ScheduleDraftRequest};
 pub use list::{ThreadsQuery, TweetsQuery};
@@ -11,6 +11,7 @@ use tuitbot_core::context::retrieval::VaultCitation;
 use tuitbot_core::storage::approval_queue::{self, ProvenanceInput};
 use tuitbot_core::storage::provenance::ProvenanceRef;
 use tuitbot_core::storage::{self};
+use tuitbot_core::strategy::keyword_suggestions::{self, KeywordSuggestions};
 
 use crate::account::{require_mutate, AccountContext};
 use crate::error::ApiError;
@@ -45,6 +46,25 @@ pub struct FeedQuery {
     pub limit: u32,
 }
 
+impl FeedQuery {
+    /// Scores are 0–100, so thresholds outside that range are rejected.
+    fn validate(&self) -> Result<(), String> {
+        let in_range = |v: f64| (0.0..=100.0).contains(&v);
+        if !in_range(self.min_score) {
+            return Err(format!(
+                "min_score must be between 0 and 100, got {}",
+                self.min_score
+            ));
+        }
+        if let Some(max) = self.max_score {
+            if !in_range(max) {
+                return Err(format!("max_score must be between 0 and 100, got {max}"));
+            }
+        }
+        Ok(())
+    }
+}
+
 fn default_min_score() -> f64 {
     50.0
 }
@@ -71,6 +91,8 @@ pub async fn feed(
     ctx: AccountContext,
     Query(q): Query<FeedQuery>,
 ) -> Result<Json<Vec<DiscoveryTweet>>, ApiError> {
+    q.validate().map_err(ApiError::BadRequest)?;
+
     let rows = storage::tweets::get_discovery_feed_filtered_for(
         &state.db,
         &ctx.account_id,
@@ -112,6 +134,70 @@ pub async fn keywords(
     Ok(Json(kws))
 }
 
+// ---------------------------------------------------------------------------
+// GET /api/discovery/keywords/suggestions
+// ---------------------------------------------------------------------------
+
+#[derive(Deserialize)]
+pub struct KeywordSuggestionsQuery {
+    /// Minimum relevance score for a tweet to count as engaged content.
+    #[serde(default = "default_suggestion_min_score")]
+    pub min_score: f64,
+    /// Maximum number of suggestions to return.
+    #[serde(default = "default_suggestion_limit")]
+    pub limit: usize,
+}
+
+fn default_suggestion_min_score() -> f64 {
+    70.0
+}
+fn default_suggestion_limit() -> usize {
+    10
+}
+
+/// How many recent high-scoring tweets the analysis samples.
+const SUGGESTION_SAMPLE_SIZE: u32 = 200;
+
+/// Suggest additional discovery keywords from recent high-scoring tweets,
+/// ranked by co-occurrence with the configured product and competitor
+/// keywords.
+pub async fn keyword_suggestions(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Query(q): Query<KeywordSuggestionsQuery>,
+) -> Result<Json<KeywordSuggestions>, ApiError> {
+    if !(0.0..=100.0).contains(&q.min_score) {
+        return Err(ApiError::BadRequest(format!(
+            "min_score must be between 0 and 100, got {}",
+            q.min_score
+        )));
+    }
+
+    let config = state
+        .load_effective_config(&ctx.account_id)
+        .await
+        .map_err(ApiError::BadRequest)?;
+    let existing: Vec<String> = config
+        .business
+        .product_keywords
+        .iter()
+        .chain(config.business.competitor_keywords.iter())
+        .cloned()
+        .collect();
+
+    let tweets = storage::tweets::get_discovery_feed_for(
+        &state.db,
+        &ctx.account_id,
+        q.min_score,
+        SUGGESTION_SAMPLE_SIZE,
+    )
+    .await?;
+
+    Ok(Json(keyword_suggestions::suggest(
+        &existing, &tweets, q.limit,
+    )))
+}
+
 // ---------------------------------------------------------------------------
 // POST /api/discovery/{tweet_id}/compose-reply
 // ---------------------------------------------------------------------------
@@ -163,6 +249,7 @@ pub async fn compose_reply(
             body.mention_product,
             None,
             prompt_block,
+            &[],
         )
         .await
         .map_err(|e| ApiError::Internal(e.to_string()))?;
@@ -298,6 +385,19 @@ mod tests {
         assert_eq!(q.limit, 10);
     }
 
+    #[test]
+    fn feed_query_validate_rejects_out_of_range() {
+        let q: FeedQuery = serde_json::from_str(r#"{"min_score":101.0}"#).expect("deser");
+        assert!(q.validate().unwrap_err().contains("min_score"));
+        let q: FeedQuery = serde_json::from_str(r#"{"min_score":-1.0}"#).expect("deser");
+        assert!(q.validate().is_err());
+        let q: FeedQuery = serde_json::from_str(r#"{"max_score":150.0}"#).expect("deser");
+        assert!(q.validate().unwrap_err().contains("max_score"));
+        let q: FeedQuery =
+            serde_json::from_str(r#"{"min_score":0.0,"max_score":100.0}"#).expect("deser");
+        assert!(q.validate().is_ok());
+    }
+
     // --- DiscoveryTweet serialization ---
 
     #[test]
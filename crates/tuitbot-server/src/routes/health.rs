@@ -54,7 +54,28 @@ pub async fn health_detailed(State(state): State<Arc<AppState>>) -> Json<Value>
         None
     };
 
-    // Overall status — degraded if scraper is down or CB is open.
+    // Per-loop error budgets (persisted by `tuitbot tick`).
+    let now = chrono::Utc::now();
+    let loop_breakers: Vec<Value> =
+        tuitbot_core::storage::loop_breakers::list_loop_breakers(&state.db)
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|row| {
+                let loop_state = tuitbot_core::automation::loop_breaker::state(&row, now);
+                json!({
+                    "account_id": row.account_id,
+                    "loop_name": row.loop_name,
+                    "state": loop_state.to_string(),
+                    "consecutive_failures": row.consecutive_failures,
+                    "open_until": row.open_until,
+                    "last_error": row.last_error,
+                })
+            })
+            .collect();
+    let loops_paused = loop_breakers.iter().any(|b| b["state"] == "open");
+
+    // Overall status — degraded if scraper is down, CB is open, or a loop is paused.
     let scraper_down = scraper
         .as_ref()
         .and_then(|s| s.get("state"))
@@ -64,7 +85,7 @@ pub async fn health_detailed(State(state): State<Arc<AppState>>) -> Json<Value>
 
     let overall = if !db_health.reachable {
         "unhealthy"
-    } else if !db_health.wal_mode || cb_state == "open" || scraper_down {
+    } else if !db_health.wal_mode || cb_state == "open" || scraper_down || loops_paused {
         "degraded"
     } else {
         "healthy"
@@ -76,6 +97,13 @@ pub async fn health_detailed(State(state): State<Arc<AppState>>) -> Json<Value>
             "reachable": db_health.reachable,
             "latency_ms": db_health.latency_ms,
             "wal_mode": db_health.wal_mode,
+            "timed_out": db_health.timed_out,
+            "pool": {
+                "active": db_health.pool.active,
+                "idle": db_health.pool.idle,
+                "size": db_health.pool.size,
+                "max_size": db_health.pool.max_size,
+            },
         },
         "runtime": {
             "healthy": runtime_running,
@@ -88,6 +116,10 @@ pub async fn health_detailed(State(state): State<Arc<AppState>>) -> Json<Value>
             "error_count": cb_error_count,
             "cooldown_remaining_seconds": cb_cooldown,
         },
+        "loop_breakers": {
+            "healthy": !loops_paused,
+            "loops": loop_breakers,
+        },
     });
 
     if let Some(s) = scraper {
@@ -118,9 +118,9 @@ pub async fn ingest(
     // Process file hints through the shared ingest pipeline.
     if !body.file_hints.is_empty() {
         // Extract needed data from config under a short-lived read guard.
-        let local_source_info = {
+        let (local_source_info, limits) = {
             let content_sources = state.content_sources.read().await;
-            content_sources
+            let info = content_sources
                 .sources
                 .iter()
                 .find(|s| s.source_type == "local_fs" && s.path.is_some())
@@ -133,7 +133,8 @@ pub async fn ingest(
                     })
                     .to_string();
                     (path_str, config_json)
-                })
+                });
+            (info, wt::FileLimits::from_config(&content_sources))
         };
 
         if let Some((path_str, config_json)) = local_source_info {
@@ -149,6 +150,7 @@ pub async fn ingest(
                 &base_path,
                 &body.file_hints,
                 body.force,
+                &limits,
             )
             .await;
             ingested += summary.ingested;
@@ -15,6 +15,7 @@ use tuitbot_core::auth::passphrase;
 use tuitbot_core::config::Config;
 use tuitbot_core::net::local_ip;
 
+use crate::account::{require_mutate, AccountContext};
 use crate::state::AppState;
 
 #[derive(Serialize)]
@@ -40,7 +41,13 @@ pub async fn get_status(State(state): State<Arc<AppState>>) -> impl IntoResponse
 }
 
 /// `POST /api/settings/lan/reset-passphrase` — generate a new passphrase.
-pub async fn reset_passphrase(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+pub async fn reset_passphrase(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+) -> impl IntoResponse {
+    if let Err(e) = require_mutate(&ctx) {
+        return e.into_response();
+    }
     match passphrase::reset_passphrase(&state.data_dir) {
         Ok(new_passphrase) => {
             // Update the in-memory hash.
@@ -78,8 +85,12 @@ pub struct ToggleLanRequest {
 /// `PATCH /api/settings/lan` — toggle LAN mode by updating config.toml.
 pub async fn toggle_lan(
     State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
     axum::Json(body): axum::Json<ToggleLanRequest>,
 ) -> impl IntoResponse {
+    if let Err(e) = require_mutate(&ctx) {
+        return e.into_response();
+    }
     // Validate host value.
     if body.host != "0.0.0.0" && body.host != "127.0.0.1" {
         return (
@@ -102,7 +113,7 @@ pub async fn toggle_lan(
         }
     };
 
-    config.server.host = body.host;
+    config.server.host = Some(body.host);
 
     let toml_str = match toml::to_string_pretty(&config) {
         Ok(s) => s,
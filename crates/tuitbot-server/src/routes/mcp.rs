@@ -1,17 +1,21 @@
 //! MCP governance and telemetry endpoints.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::{SystemTime, UNIX_EPOCH};
 
 use axum::extract::{Path, Query, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response};
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
 use tuitbot_core::config::Config;
-use tuitbot_core::mcp_policy::templates;
 use tuitbot_core::mcp_policy::types::PolicyTemplateName;
+use tuitbot_core::mcp_policy::{templates, McpPolicyEvaluator};
 use tuitbot_core::storage::{mcp_telemetry, rate_limits};
 
+use crate::account::{require_mutate, AccountContext};
 use crate::error::ApiError;
 use crate::state::AppState;
 
@@ -41,6 +45,19 @@ fn default_limit() -> u32 {
     50
 }
 
+#[derive(Deserialize)]
+pub struct ErrorGroupsQuery {
+    /// Lookback window in hours (default: 24).
+    #[serde(default = "default_hours")]
+    pub hours: u32,
+    /// Maximum number of groups to return (default: 50).
+    #[serde(default = "default_limit")]
+    pub limit: u32,
+    /// Offset for pagination (default: 0).
+    #[serde(default)]
+    pub offset: u32,
+}
+
 // ---------------------------------------------------------------------------
 // Policy endpoints
 // ---------------------------------------------------------------------------
@@ -76,6 +93,7 @@ pub async fn get_policy(State(state): State<Arc<AppState>>) -> Result<Json<Value
         "template": config.mcp_policy.template,
         "rules": config.mcp_policy.rules,
         "rate_limits": config.mcp_policy.rate_limits,
+        "tool_limits": McpPolicyEvaluator::tool_limits_status(&state.db, &config.mcp_policy).await,
     })))
 }
 
@@ -84,8 +102,10 @@ pub async fn get_policy(State(state): State<Arc<AppState>>) -> Result<Json<Value
 /// Accepts partial JSON with `mcp_policy` fields and merges into config.
 pub async fn patch_policy(
     State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
     Json(patch): Json<Value>,
 ) -> Result<Json<Value>, ApiError> {
+    require_mutate(&ctx)?;
     if !patch.is_object() {
         return Err(ApiError::BadRequest(
             "request body must be a JSON object".to_string(),
@@ -124,6 +144,12 @@ pub async fn patch_policy(
         ))
     })?;
 
+    if let Err(e) =
+        rate_limits::init_mcp_tool_limits(&state.db, &config.mcp_policy.tool_limits).await
+    {
+        tracing::warn!("Failed to initialize MCP tool limits: {e}");
+    }
+
     Ok(Json(json!({
         "enforce_for_mutations": config.mcp_policy.enforce_for_mutations,
         "require_approval_for": config.mcp_policy.require_approval_for,
@@ -133,6 +159,7 @@ pub async fn patch_policy(
         "template": config.mcp_policy.template,
         "rules": config.mcp_policy.rules,
         "rate_limits": config.mcp_policy.rate_limits,
+        "tool_limits": config.mcp_policy.tool_limits,
     })))
 }
 
@@ -149,8 +176,10 @@ pub async fn list_templates() -> Json<Value> {
 /// `POST /api/mcp/policy/templates/{name}` — apply a template.
 pub async fn apply_template(
     State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
     Path(name): Path<String>,
 ) -> Result<Json<Value>, ApiError> {
+    require_mutate(&ctx)?;
     let template_name: PolicyTemplateName =
         name.parse().map_err(|e: String| ApiError::BadRequest(e))?;
 
@@ -238,14 +267,21 @@ pub async fn telemetry_metrics(
     Ok(Json(json!(metrics)))
 }
 
-/// `GET /api/mcp/telemetry/errors` — error breakdown over a time window.
+/// `GET /api/mcp/telemetry/errors` — failures over a time window, grouped
+/// by signature (tool + error code), most frequent first.
 pub async fn telemetry_errors(
     State(state): State<Arc<AppState>>,
-    Query(params): Query<TimeWindowQuery>,
+    Query(params): Query<ErrorGroupsQuery>,
 ) -> Result<Json<Value>, ApiError> {
     let since = since_timestamp(params.hours);
-    let errors = mcp_telemetry::get_error_breakdown(&state.db, &since).await?;
-    Ok(Json(json!(errors)))
+    let page =
+        mcp_telemetry::get_error_groups(&state.db, &since, params.limit, params.offset).await?;
+    Ok(Json(json!({
+        "groups": page.groups,
+        "total": page.total,
+        "limit": params.limit,
+        "offset": params.offset,
+    })))
 }
 
 /// `GET /api/mcp/telemetry/recent` — recent tool executions.
@@ -257,6 +293,108 @@ pub async fn telemetry_recent(
     Ok(Json(json!(entries)))
 }
 
+/// `GET /api/mcp/telemetry/export` — all-time telemetry counters in the
+/// Prometheus text exposition format (version 0.0.4).
+///
+/// Telemetry rows do not record the MCP profile that served the call, so
+/// series are labelled by `tool` and `category` (e.g. `mutation`) instead.
+pub async fn telemetry_export(State(state): State<Arc<AppState>>) -> Result<Response, ApiError> {
+    let counts = mcp_telemetry::get_outcome_counts(&state.db).await?;
+    Ok((
+        [(
+            header::CONTENT_TYPE,
+            "text/plain; version=0.0.4; charset=utf-8",
+        )],
+        render_prometheus(&counts),
+    )
+        .into_response())
+}
+
+/// Error code recorded when a mutation is denied by the MCP rate limiter.
+const RATE_LIMITED_ERROR_CODE: &str = "policy_denied_rate_limited";
+
+/// Render outcome counts as Prometheus counters.
+///
+/// HELP/TYPE lines are always emitted so an empty database still yields a
+/// well-formed scrape.
+fn render_prometheus(counts: &[mcp_telemetry::OutcomeCount]) -> String {
+    let mut invocations: BTreeMap<(&str, &str, &str), i64> = BTreeMap::new();
+    let mut errors: BTreeMap<(&str, &str, &str), i64> = BTreeMap::new();
+    let mut rate_limited: BTreeMap<&str, i64> = BTreeMap::new();
+
+    for c in counts {
+        let outcome = if c.success { "success" } else { "error" };
+        *invocations
+            .entry((c.tool_name.as_str(), c.category.as_str(), outcome))
+            .or_default() += c.count;
+        if c.success {
+            continue;
+        }
+        let code = c.error_code.as_deref().unwrap_or("unknown");
+        *errors
+            .entry((c.tool_name.as_str(), c.category.as_str(), code))
+            .or_default() += c.count;
+        if code == RATE_LIMITED_ERROR_CODE {
+            *rate_limited.entry(c.tool_name.as_str()).or_default() += c.count;
+        }
+    }
+
+    let mut out = String::new();
+    metric_header(
+        &mut out,
+        "tuitbot_mcp_tool_invocations_total",
+        "MCP tool invocations by outcome.",
+    );
+    for ((tool, category, outcome), n) in &invocations {
+        out.push_str(&format!(
+            "tuitbot_mcp_tool_invocations_total{{tool=\"{}\",category=\"{}\",outcome=\"{}\"}} {n}\n",
+            escape_label(tool),
+            escape_label(category),
+            outcome,
+        ));
+    }
+
+    metric_header(
+        &mut out,
+        "tuitbot_mcp_tool_errors_total",
+        "Failed MCP tool invocations by error code.",
+    );
+    for ((tool, category, code), n) in &errors {
+        out.push_str(&format!(
+            "tuitbot_mcp_tool_errors_total{{tool=\"{}\",category=\"{}\",error_code=\"{}\"}} {n}\n",
+            escape_label(tool),
+            escape_label(category),
+            escape_label(code),
+        ));
+    }
+
+    metric_header(
+        &mut out,
+        "tuitbot_mcp_mutation_rate_limited_total",
+        "MCP mutations denied by the policy rate limiter.",
+    );
+    for (tool, n) in &rate_limited {
+        out.push_str(&format!(
+            "tuitbot_mcp_mutation_rate_limited_total{{tool=\"{}\"}} {n}\n",
+            escape_label(tool),
+        ));
+    }
+
+    out
+}
+
+fn metric_header(out: &mut String, name: &str, help: &str) {
+    out.push_str(&format!("# HELP {name} {help}\n# TYPE {name} counter\n"));
+}
+
+/// Escape a Prometheus label value (backslash, double quote, newline).
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
@@ -484,6 +622,21 @@ mod tests {
         assert!(json_to_toml(&json).is_err());
     }
 
+    // --- Prometheus export ---
+
+    #[test]
+    fn escape_label_handles_quotes_backslashes_and_newlines() {
+        assert_eq!(escape_label("a\"b\\c\nd"), "a\\\"b\\\\c\\nd");
+    }
+
+    #[test]
+    fn render_prometheus_empty_has_headers_only() {
+        let text = render_prometheus(&[]);
+        assert_eq!(text.lines().count(), 6);
+        assert!(text.contains("# TYPE tuitbot_mcp_tool_invocations_total counter"));
+        assert!(text.contains("# TYPE tuitbot_mcp_mutation_rate_limited_total counter"));
+    }
+
     // --- TimeWindowQuery defaults ---
 
     #[test]
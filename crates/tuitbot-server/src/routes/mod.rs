@@ -1,6 +1,8 @@
 //! Route modules for the tuitbot API server.
 
+pub(crate) mod pagination;
 pub(crate) mod rag_helpers;
+pub(crate) mod x_client;
 
 pub mod accounts;
 pub mod activity;
@@ -2,8 +2,9 @@
 //!
 //! These endpoints let users authenticate with X during onboarding,
 //! before any account or config exists. Tokens are stored temporarily
-//! at `{data_dir}/onboarding_tokens.json` and migrated to the default
-//! account's token path when `POST /api/settings/init` completes.
+//! at `{data_dir}/onboarding_tokens.json` (or the OS keychain when
+//! `auth.token_storage = "keychain"`) and moved to the default account's
+//! token store when `POST /api/settings/init` completes.
 //!
 //! - `POST /api/onboarding/x-auth/start`       — start OAuth PKCE flow
 //! - `POST /api/onboarding/x-auth/callback`     — exchange code for tokens
@@ -18,7 +19,10 @@ use axum::extract::State;
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
-use tuitbot_core::startup::{build_auth_url, build_redirect_uri, generate_pkce};
+use tuitbot_core::config::AuthConfig;
+use tuitbot_core::startup::{
+    build_auth_url, build_redirect_uri, generate_pkce, token_store_at, StoredTokens, TokenStore,
+};
 use tuitbot_core::x_api::auth;
 use tuitbot_core::x_api::client::XApiHttpClient;
 use tuitbot_core::x_api::XApiClient;
@@ -32,11 +36,33 @@ const ONBOARDING_ACCOUNT_ID: &str = "__onboarding__";
 /// Maximum age for a pending OAuth state entry before it expires.
 const OAUTH_STATE_TTL: Duration = Duration::from_secs(600);
 
+/// Keychain account name for the temporary onboarding tokens.
+const ONBOARDING_KEYCHAIN_ACCOUNT: &str = "x-oauth-onboarding";
+
 /// Return the path for temporary onboarding tokens.
 pub fn onboarding_token_path(data_dir: &Path) -> PathBuf {
     data_dir.join("onboarding_tokens.json")
 }
 
+/// Store for the temporary onboarding tokens, honoring `auth.token_storage`.
+pub fn onboarding_token_store(data_dir: &Path, auth: &AuthConfig) -> Box<dyn TokenStore> {
+    token_store_at(
+        auth,
+        onboarding_token_path(data_dir),
+        ONBOARDING_KEYCHAIN_ACCOUNT,
+    )
+}
+
+/// Load unexpired onboarding tokens, if any.
+fn load_onboarding_tokens(state: &AppState) -> Result<Option<auth::Tokens>, String> {
+    let store = onboarding_token_store(&state.data_dir, &state.auth_config());
+    match store.load() {
+        Ok(Some(stored)) => Ok(Some(auth::Tokens::from(stored))),
+        Ok(None) => Ok(None),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
 /// Serialize the X user profile fields we care about during onboarding.
 fn user_to_json(user: &tuitbot_core::x_api::types::User) -> Value {
     json!({
@@ -125,7 +151,7 @@ pub struct OnboardingCallbackRequest {
 /// `POST /api/onboarding/x-auth/callback` — exchange code for tokens.
 ///
 /// Validates the state parameter against the `__onboarding__` sentinel,
-/// exchanges the code for tokens, saves them to `onboarding_tokens.json`,
+/// exchanges the code for tokens, saves them to the onboarding token store,
 /// then calls `get_me()` to fetch the authenticated user's profile.
 pub async fn complete_onboarding_auth(
     State(state): State<Arc<AppState>>,
@@ -177,9 +203,9 @@ pub async fn complete_onboarding_auth(
         scopes: stored_tokens.scopes,
     };
 
-    // Save to temporary onboarding path.
-    let token_path = onboarding_token_path(&state.data_dir);
-    auth::save_tokens(&tokens, &token_path)
+    // Save to the temporary onboarding store.
+    onboarding_token_store(&state.data_dir, &config.auth)
+        .save(&StoredTokens::from(&tokens))
         .map_err(|e| ApiError::Internal(format!("failed to save onboarding tokens: {e}")))?;
 
     // Fetch user identity using the new access token.
@@ -197,19 +223,13 @@ pub async fn complete_onboarding_auth(
 
 /// `GET /api/onboarding/x-auth/status` — check onboarding auth status.
 ///
-/// Returns whether `onboarding_tokens.json` exists with valid tokens,
-/// and if so, fetches the authenticated user's profile.
+/// Returns whether valid onboarding tokens are stored, and if so,
+/// fetches the authenticated user's profile.
 pub async fn onboarding_auth_status(
     State(state): State<Arc<AppState>>,
 ) -> Result<Json<Value>, ApiError> {
-    let token_path = onboarding_token_path(&state.data_dir);
-
-    if !token_path.exists() {
-        return Ok(Json(json!({ "connected": false })));
-    }
-
     // Load tokens and check validity.
-    let tokens = match auth::load_tokens(&token_path) {
+    let tokens = match load_onboarding_tokens(&state) {
         Ok(Some(t)) if t.expires_at > chrono::Utc::now() => t,
         _ => {
             return Ok(Json(json!({ "connected": false })));
@@ -259,16 +279,14 @@ pub async fn analyze_profile(
     };
 
     // 1. Load onboarding tokens.
-    let token_path = onboarding_token_path(&state.data_dir);
-    if !token_path.exists() {
-        return Ok(Json(json!({
-            "status": "x_api_error",
-            "error": "Not connected. Complete X sign-in first."
-        })));
-    }
-
-    let tokens = match auth::load_tokens(&token_path) {
+    let tokens = match load_onboarding_tokens(&state) {
         Ok(Some(t)) if t.expires_at > chrono::Utc::now() => t,
+        Ok(None) => {
+            return Ok(Json(json!({
+                "status": "x_api_error",
+                "error": "Not connected. Complete X sign-in first."
+            })));
+        }
         Ok(Some(_)) => {
             return Ok(Json(json!({
                 "status": "x_api_error",
@@ -326,6 +344,7 @@ pub async fn analyze_profile(
             api_key: llm_input.api_key,
             model: llm_input.model,
             base_url: llm_input.base_url,
+            ..Default::default()
         };
 
         match create_provider(&llm_config) {
@@ -380,6 +399,7 @@ mod tests {
             location: Some("NYC".into()),
             url: Some("https://example.com".into()),
             public_metrics: Default::default(),
+            protected: None,
         };
         let json = user_to_json(&user);
         assert_eq!(json["username"], "test");
@@ -399,6 +419,7 @@ mod tests {
             location: None,
             url: None,
             public_metrics: Default::default(),
+            protected: None,
         };
         let json = user_to_json(&user);
         assert_eq!(json["id"], "456");
@@ -0,0 +1,33 @@
+//! Shared keyset-pagination helpers for list endpoints.
+//!
+//! `/api/activity` returns `next_cursor` in its JSON body. `/api/approval`
+//! and `/api/replies` return bare arrays, so their next cursor travels in the
+//! `X-Next-Cursor` response header. The header is absent on the last page.
+
+use axum::http::{HeaderName, HeaderValue};
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use serde_json::Value;
+use tuitbot_core::storage::pagination::PageCursor;
+
+use crate::error::ApiError;
+
+/// Response header carrying the cursor for the next page.
+pub(crate) const NEXT_CURSOR_HEADER: HeaderName = HeaderName::from_static("x-next-cursor");
+
+/// Parse the `cursor` query parameter, rejecting malformed values.
+pub(crate) fn parse_cursor(raw: Option<&str>) -> Result<Option<PageCursor>, ApiError> {
+    raw.map(|c| {
+        PageCursor::decode(c).ok_or_else(|| ApiError::BadRequest("invalid cursor".to_string()))
+    })
+    .transpose()
+}
+
+/// A JSON body with `next_cursor` in the `X-Next-Cursor` header when set.
+pub(crate) fn with_next_cursor(body: Value, next_cursor: Option<String>) -> Response {
+    let mut response = Json(body).into_response();
+    if let Some(value) = next_cursor.and_then(|c| HeaderValue::from_str(&c).ok()) {
+        response.headers_mut().insert(NEXT_CURSOR_HEADER, value);
+    }
+    response
+}
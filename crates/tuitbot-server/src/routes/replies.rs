@@ -3,13 +3,15 @@
 use std::sync::Arc;
 
 use axum::extract::{Query, State};
-use axum::Json;
+use axum::response::Response;
 use serde::Deserialize;
-use serde_json::{json, Value};
+use serde_json::json;
+use tuitbot_core::storage::pagination::{Page, PageCursor};
 use tuitbot_core::storage::replies;
 
 use crate::account::AccountContext;
 use crate::error::ApiError;
+use crate::routes::pagination::{parse_cursor, with_next_cursor};
 use crate::state::AppState;
 
 /// Query parameters for the replies endpoint.
@@ -18,23 +20,38 @@ pub struct RepliesQuery {
     /// Maximum number of replies to return (default: 50).
     #[serde(default = "default_limit")]
     pub limit: u32,
-    /// Offset for pagination (default: 0).
+    /// Offset for pagination (default: 0). Ignored when `cursor` is set.
     #[serde(default)]
     pub offset: u32,
+    /// `X-Next-Cursor` header value from the previous page.
+    pub cursor: Option<String>,
 }
 
 fn default_limit() -> u32 {
     50
 }
 
-/// `GET /api/replies` — recent replies sent.
+/// `GET /api/replies` — recent replies sent, newest first.
+///
+/// The cursor for the next page, if any, is in the `X-Next-Cursor` header.
 pub async fn list_replies(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
     Query(params): Query<RepliesQuery>,
-) -> Result<Json<Value>, ApiError> {
-    let replies =
-        replies::get_recent_replies_for(&state.db, &ctx.account_id, params.limit, params.offset)
-            .await?;
-    Ok(Json(json!(replies)))
+) -> Result<Response, ApiError> {
+    let cursor = parse_cursor(params.cursor.as_deref())?;
+    let page = if cursor.is_some() || params.offset == 0 {
+        replies::get_replies_page_for(&state.db, &ctx.account_id, params.limit, cursor.as_ref())
+            .await?
+    } else {
+        let rows = replies::get_recent_replies_for(
+            &state.db,
+            &ctx.account_id,
+            params.limit.saturating_add(1),
+            params.offset,
+        )
+        .await?;
+        Page::from_rows(rows, params.limit, |r| PageCursor::new(&r.created_at, r.id))
+    };
+    Ok(with_next_cursor(json!(page.items), page.next_cursor))
 }
@@ -2,8 +2,9 @@
 
 use std::sync::Arc;
 
-use axum::extract::State;
+use axum::extract::{Query, State};
 use axum::Json;
+use serde::Deserialize;
 use serde_json::{json, Value};
 use tuitbot_core::automation::Runtime;
 
@@ -108,3 +109,40 @@ pub async fn stop(
         None => Err(ApiError::Conflict("runtime is not running".to_string())),
     }
 }
+
+/// Query parameters for `GET /api/runtime/logs`.
+#[derive(Deserialize)]
+pub struct LogsQuery {
+    /// Number of lines to return (default 100, capped at the buffer size).
+    pub lines: Option<usize>,
+    /// Minimum level: `error`, `warn`, `info`, `debug`, or `trace` (default).
+    pub level: Option<String>,
+}
+
+/// `GET /api/runtime/logs` — tail recent server log output.
+///
+/// Lines come from the in-memory buffer filled by the tracing subscriber,
+/// oldest first. The buffer is process-wide, so it covers every account;
+/// only admins may read it.
+pub async fn logs(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Query(params): Query<LogsQuery>,
+) -> Result<Json<Value>, ApiError> {
+    require_mutate(&ctx)?;
+    let min_level = match params.level.as_deref() {
+        None => tracing::Level::TRACE,
+        Some(level) => level.parse().map_err(|_| {
+            ApiError::BadRequest(format!(
+                "invalid level '{level}': expected error, warn, info, debug, or trace"
+            ))
+        })?,
+    };
+    let capacity = state.log_buffer.capacity();
+    let lines = params.lines.unwrap_or(100).min(capacity);
+
+    Ok(Json(json!({
+        "lines": state.log_buffer.tail(lines, min_level),
+        "capacity": capacity,
+    })))
+}
@@ -10,7 +10,7 @@ use tuitbot_core::config::merge_overrides;
 use tuitbot_core::storage::accounts::{self, account_scraper_session_path, DEFAULT_ACCOUNT_ID};
 use tuitbot_core::x_api::ScraperSession;
 
-use crate::account::AccountContext;
+use crate::account::{require_mutate, AccountContext};
 use crate::error::ApiError;
 use crate::routes::settings::merge_patch_and_parse;
 use crate::state::AppState;
@@ -54,6 +54,7 @@ pub async fn import_scraper_session(
     ctx: AccountContext,
     Json(body): Json<ImportSessionRequest>,
 ) -> Result<Json<Value>, ApiError> {
+    require_mutate(&ctx)?;
     if body.auth_token.trim().is_empty() || body.ct0.trim().is_empty() {
         return Err(ApiError::BadRequest(
             "auth_token and ct0 are required".to_string(),
@@ -151,6 +152,7 @@ pub async fn delete_scraper_session(
     State(state): State<Arc<AppState>>,
     ctx: AccountContext,
 ) -> Result<Json<Value>, ApiError> {
+    require_mutate(&ctx)?;
     let session_path = account_scraper_session_path(&state.data_dir, &ctx.account_id);
     let deleted = ScraperSession::delete(&session_path)
         .map_err(|e| ApiError::Internal(format!("failed to delete session: {e}")))?;
@@ -14,8 +14,9 @@ use tuitbot_core::auth::{passphrase, session};
 use tuitbot_core::config::{effective_config, merge_overrides, split_patch_by_scope, Config};
 use tuitbot_core::storage::accounts::{self, DEFAULT_ACCOUNT_ID};
 
-use crate::account::AccountContext;
+use crate::account::{require_mutate, AccountContext};
 use crate::error::ApiError;
+use crate::routes::onboarding::onboarding_token_store;
 use crate::state::AppState;
 
 use super::validation::{
@@ -173,18 +174,21 @@ pub async fn init_settings(
             std::fs::set_permissions(&state.config_path, std::fs::Permissions::from_mode(0o600));
     }
 
-    // Migrate onboarding tokens to default account token path if they exist.
-    let onboarding_path = state.data_dir.join("onboarding_tokens.json");
-    if onboarding_path.exists() {
-        let target = accounts::account_token_path(&state.data_dir, DEFAULT_ACCOUNT_ID);
-        if let Some(parent) = target.parent() {
-            let _ = std::fs::create_dir_all(parent);
-        }
-        if let Err(e) = std::fs::rename(&onboarding_path, &target) {
-            tracing::warn!("Failed to migrate onboarding tokens: {e}");
-        } else {
-            tracing::info!("Migrated onboarding tokens to default account");
+    // Move onboarding tokens into the default account's configured store.
+    let onboarding = onboarding_token_store(&state.data_dir, &config.auth);
+    match onboarding.load() {
+        Ok(Some(tokens)) => {
+            let target = state.token_store(DEFAULT_ACCOUNT_ID);
+            match target.save(&tokens).and_then(|()| onboarding.delete()) {
+                Ok(()) => tracing::info!(
+                    to = %target.location(),
+                    "Migrated onboarding tokens to default account"
+                ),
+                Err(e) => tracing::warn!("Failed to migrate onboarding tokens: {e}"),
+            }
         }
+        Ok(None) => {}
+        Err(e) => tracing::warn!("Failed to load onboarding tokens: {e}"),
     }
 
     // Populate the default account's X profile if provided.
@@ -316,6 +320,7 @@ pub async fn patch_settings(
     ctx: AccountContext,
     Json(patch): Json<Value>,
 ) -> Result<Json<Value>, ApiError> {
+    require_mutate(&ctx)?;
     if !patch.is_object() {
         return Err(ApiError::BadRequest(
             "request body must be a JSON object".to_string(),
@@ -13,7 +13,7 @@ mod tests;
 
 // Re-export public API so the router can reference `settings::*` unchanged.
 pub use handlers::{config_status, get_settings, init_settings, patch_settings, validate_settings};
-pub use validation::{factory_reset, get_defaults, merge_patch_and_parse, test_llm};
+pub use validation::{factory_reset, get_defaults, get_schema, merge_patch_and_parse, test_llm};
 
 use serde::{Deserialize, Serialize};
 use tuitbot_core::error::ConfigError;
@@ -105,6 +105,10 @@ pub(super) struct TestResult {
     pub error: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latency_ms: Option<u64>,
+    /// Whether the configured model is offered; omitted when the check
+    /// failed before model availability could be determined.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_available: Option<bool>,
 }
 
 // ---------------------------------------------------------------------------
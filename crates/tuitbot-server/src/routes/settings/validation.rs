@@ -177,8 +177,10 @@ use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::Json;
 use tuitbot_core::config::LlmConfig;
+use tuitbot_core::error::LlmError;
 use tuitbot_core::llm::factory::create_provider;
 
+use crate::account::{require_mutate, AccountContext};
 use crate::state::AppState;
 
 use super::{TestLlmRequest, TestResult};
@@ -192,6 +194,14 @@ pub async fn get_defaults() -> Result<Json<serde_json::Value>, crate::error::Api
     Ok(Json(json))
 }
 
+/// `GET /api/settings/schema` — describe every config field for form rendering.
+///
+/// Generated from the config types, so paths, defaults, and constraints
+/// always match what the server actually parses and validates.
+pub async fn get_schema() -> Json<serde_json::Value> {
+    Json(serde_json::json!({ "fields": tuitbot_core::config::settings_schema() }))
+}
+
 /// `POST /api/settings/test-llm` — test LLM provider connectivity.
 pub async fn test_llm(
     Json(body): Json<TestLlmRequest>,
@@ -201,6 +211,7 @@ pub async fn test_llm(
         api_key: body.api_key,
         model: body.model,
         base_url: body.base_url,
+        ..Default::default()
     };
 
     let provider = match create_provider(&llm_config) {
@@ -211,6 +222,7 @@ pub async fn test_llm(
                     success: false,
                     error: Some(e.to_string()),
                     latency_ms: None,
+                    model_available: None,
                 })
                 .unwrap(),
             ));
@@ -226,12 +238,14 @@ pub async fn test_llm(
                 success: true,
                 error: None,
                 latency_ms: Some(latency_ms(&start)),
+                model_available: Some(true),
             })
             .unwrap(),
         )),
         Err(e) => Ok(Json(
             serde_json::to_value(TestResult {
                 success: false,
+                model_available: matches!(e, LlmError::ModelUnavailable { .. }).then_some(false),
                 error: Some(e.to_string()),
                 latency_ms: Some(latency_ms(&start)),
             })
@@ -251,8 +265,10 @@ use super::{FactoryResetCleared, FactoryResetRequest, FactoryResetResponse, FACT
 /// session cookie.
 pub async fn factory_reset(
     State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
     Json(body): Json<FactoryResetRequest>,
 ) -> Result<impl IntoResponse, crate::error::ApiError> {
+    require_mutate(&ctx)?;
     if body.confirmation != FACTORY_RESET_PHRASE {
         return Err(crate::error::ApiError::BadRequest(
             "incorrect confirmation phrase".to_string(),
@@ -9,7 +9,7 @@ use std::sync::Arc;
 use axum::extract::{Path, State};
 use axum::Json;
 use serde::Serialize;
-use tuitbot_core::automation::WatchtowerLoop;
+use tuitbot_core::automation::{FileLimits, WatchtowerLoop};
 use tuitbot_core::storage::watchtower as store;
 
 use crate::error::ApiError;
@@ -119,10 +119,27 @@ pub async fn reindex_source(
         })
         .unwrap_or_else(|| vec!["*.md".to_string(), "*.txt".to_string()]);
 
+    let (concurrency, limits) = {
+        let content_sources = state.content_sources.read().await;
+        (
+            content_sources.scan_concurrency as usize,
+            FileLimits::from_config(&content_sources),
+        )
+    };
+
     // Spawn the reindex in a background task.
     let pool = state.db.clone();
     tokio::spawn(async move {
-        match WatchtowerLoop::reindex_local_source(&pool, source_id, &base_path, &patterns).await {
+        match WatchtowerLoop::reindex_local_source(
+            &pool,
+            source_id,
+            &base_path,
+            &patterns,
+            &limits,
+            concurrency,
+        )
+        .await
+        {
             Ok(summary) => {
                 tracing::info!(
                     source_id,
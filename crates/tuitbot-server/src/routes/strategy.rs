@@ -1,13 +1,14 @@
-//! Strategy endpoints — weekly reports, history, and strategy inputs.
+//! Strategy endpoints — weekly reports, history, strategy inputs, and next steps.
 
 use std::sync::Arc;
 
-use axum::extract::{Query, State};
+use axum::extract::{Path, Query, State};
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
 use tuitbot_core::config::Config;
 use tuitbot_core::storage::strategy;
+use tuitbot_core::strategy::next_steps::{self, NextStep};
 
 use crate::account::{require_mutate, AccountContext};
 use crate::error::ApiError;
@@ -51,6 +52,23 @@ pub async fn history(
     Ok(Json(json!(items)))
 }
 
+/// `GET /api/strategy/history/{id}/inputs` — input snapshot a report was computed from.
+pub async fn history_inputs(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(id): Path<i64>,
+) -> Result<Json<Value>, ApiError> {
+    let snapshot =
+        tuitbot_core::strategy::inputs::load_snapshot_for(&state.db, &ctx.account_id, id)
+            .await?
+            .ok_or_else(|| {
+                ApiError::NotFound(format!(
+                    "no input snapshot recorded for strategy report {id}"
+                ))
+            })?;
+    Ok(Json(json!(snapshot)))
+}
+
 /// `POST /api/strategy/refresh` — force recompute the current week's report for the requesting account.
 pub async fn refresh(
     State(state): State<Arc<AppState>>,
@@ -87,6 +105,20 @@ pub async fn inputs(
     })))
 }
 
+/// `GET /api/strategy/recommendations` — actionable next steps, each with the
+/// `PATCH /api/settings` body that applies it.
+pub async fn recommendations(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+) -> Result<Json<Vec<NextStep>>, ApiError> {
+    let config = state
+        .load_effective_config(&ctx.account_id)
+        .await
+        .map_err(ApiError::BadRequest)?;
+    let gathered = next_steps::gather_inputs_for(&state.db, &ctx.account_id, &config).await?;
+    Ok(Json(next_steps::recommendations(&gathered.inputs(&config))))
+}
+
 // ---------------------------------------------------------------------------
 // Helpers
 // ---------------------------------------------------------------------------
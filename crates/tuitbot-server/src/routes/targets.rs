@@ -6,10 +6,17 @@ use axum::extract::{Path, Query, State};
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
-use tuitbot_core::storage::target_accounts;
+use tuitbot_core::config::Config;
+use tuitbot_core::error::XApiError;
+use tuitbot_core::storage::{target_accounts, DbPool};
+use tuitbot_core::toolkit::ToolkitError;
+use tuitbot_core::workflow::{target_preview, WorkflowError};
+use tuitbot_core::x_api::XApiClient;
 
 use crate::account::{require_mutate, AccountContext};
 use crate::error::ApiError;
+use crate::routes::content::read_effective_config;
+use crate::routes::x_client::build_x_client;
 use crate::state::AppState;
 
 /// `GET /api/targets` — list target accounts with enriched data.
@@ -123,3 +130,259 @@ pub async fn target_stats(
         ))),
     }
 }
+
+/// Query parameters for the score-preview endpoint.
+#[derive(Deserialize)]
+pub struct ScorePreviewQuery {
+    /// Number of recent tweets to score (default 20, capped at 50).
+    pub limit: Option<u32>,
+}
+
+/// `GET /api/targets/:username/score-preview` — score a target's recent
+/// tweets under the current scoring config.
+pub async fn target_score_preview(
+    State(state): State<Arc<AppState>>,
+    ctx: AccountContext,
+    Path(username): Path<String>,
+    Query(params): Query<ScorePreviewQuery>,
+) -> Result<Json<Value>, ApiError> {
+    let config = read_effective_config(&state, &ctx.account_id).await?;
+    let client = build_x_client(&state, &ctx).await.map_err(|e| match e {
+        ApiError::BadRequest(msg) => {
+            ApiError::ServiceUnavailable(format!("X client unavailable: {msg}"))
+        }
+        other => other,
+    })?;
+
+    score_preview(
+        &state.db,
+        &ctx.account_id,
+        client.as_ref(),
+        &config,
+        &username,
+        params.limit,
+    )
+    .await
+}
+
+async fn score_preview(
+    db: &DbPool,
+    account_id: &str,
+    client: &dyn XApiClient,
+    config: &Config,
+    username: &str,
+    limit: Option<u32>,
+) -> Result<Json<Value>, ApiError> {
+    let preview = target_preview::execute_for(db, account_id, client, config, username, limit)
+        .await
+        .map_err(|e| match e {
+            WorkflowError::InvalidInput(msg) => ApiError::BadRequest(msg),
+            WorkflowError::Storage(e) => ApiError::Storage(e),
+            WorkflowError::Toolkit(ToolkitError::XApi(XApiError::ApiError {
+                status: 404, ..
+            })) => ApiError::NotFound(format!("X user @{username} not found")),
+            WorkflowError::Toolkit(ToolkitError::XApi(e)) => {
+                ApiError::ServiceUnavailable(format!("X API request failed: {e}"))
+            }
+            other => ApiError::Internal(other.to_string()),
+        })?;
+    Ok(Json(json!(preview)))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    use super::*;
+    use tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID;
+    use tuitbot_core::storage::init_test_db;
+    use tuitbot_core::x_api::types::*;
+
+    /// Serves a fixed timeline for `@known`; every other username is a 404.
+    struct MockTimelineClient {
+        tweets: Vec<Tweet>,
+        requested_max: AtomicU32,
+    }
+
+    impl MockTimelineClient {
+        fn new(texts: &[&str]) -> Self {
+            let now = chrono::Utc::now().to_rfc3339();
+            let tweets = texts
+                .iter()
+                .enumerate()
+                .map(|(i, text)| Tweet {
+                    id: format!("t{i}"),
+                    text: text.to_string(),
+                    author_id: "u_known".to_string(),
+                    created_at: now.clone(),
+                    public_metrics: PublicMetrics {
+                        like_count: 20,
+                        retweet_count: 5,
+                        reply_count: 2,
+                        ..Default::default()
+                    },
+                    conversation_id: None,
+                    referenced_tweets: vec![],
+                })
+                .collect();
+            Self {
+                tweets,
+                requested_max: AtomicU32::new(0),
+            }
+        }
+    }
+
+    fn search_response(data: Vec<Tweet>) -> SearchResponse {
+        SearchResponse {
+            meta: SearchMeta {
+                newest_id: None,
+                oldest_id: None,
+                result_count: data.len() as u32,
+                next_token: None,
+            },
+            data,
+            includes: None,
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl XApiClient for MockTimelineClient {
+        async fn search_tweets(
+            &self,
+            _: &str,
+            _: u32,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            Ok(search_response(vec![]))
+        }
+
+        async fn get_mentions(
+            &self,
+            _: &str,
+            _: Option<&str>,
+            _: Option<&str>,
+        ) -> Result<MentionResponse, XApiError> {
+            Ok(search_response(vec![]))
+        }
+
+        async fn post_tweet(&self, _: &str) -> Result<PostedTweet, XApiError> {
+            unreachable!("preview never posts")
+        }
+
+        async fn reply_to_tweet(&self, _: &str, _: &str) -> Result<PostedTweet, XApiError> {
+            unreachable!("preview never posts")
+        }
+
+        async fn get_tweet(&self, _: &str) -> Result<Tweet, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+
+        async fn get_me(&self) -> Result<User, XApiError> {
+            Err(XApiError::AuthExpired)
+        }
+
+        async fn get_user_tweets(
+            &self,
+            _: &str,
+            max_results: u32,
+            _: Option<&str>,
+        ) -> Result<SearchResponse, XApiError> {
+            self.requested_max.store(max_results, Ordering::SeqCst);
+            let page = self.tweets.iter().take(max_results as usize).cloned();
+            Ok(search_response(page.collect()))
+        }
+
+        async fn get_user_by_username(&self, username: &str) -> Result<User, XApiError> {
+            if username != "known" {
+                return Err(XApiError::ApiError {
+                    status: 404,
+                    message: "Not Found".to_string(),
+                });
+            }
+            Ok(User {
+                id: "u_known".to_string(),
+                username: "known".to_string(),
+                name: "Known".to_string(),
+                profile_image_url: None,
+                description: None,
+                location: None,
+                url: None,
+                public_metrics: UserMetrics {
+                    followers_count: 5_000,
+                    ..Default::default()
+                },
+                protected: None,
+            })
+        }
+    }
+
+    fn config() -> Config {
+        let mut config = Config::default();
+        config.business.product_keywords = vec!["rust".to_string(), "async".to_string()];
+        config
+    }
+
+    #[tokio::test]
+    async fn preview_scores_timeline_with_verdicts() {
+        let db = init_test_db().await.unwrap();
+        let client = MockTimelineClient::new(&[
+            "Lunch was great today",
+            "Anyone have tips for async Rust error handling?",
+        ]);
+
+        let Ok(Json(body)) =
+            score_preview(&db, DEFAULT_ACCOUNT_ID, &client, &config(), "@known", None).await
+        else {
+            panic!("preview failed");
+        };
+
+        assert_eq!(body["username"], "known");
+        let candidates = body["candidates"].as_array().unwrap();
+        assert_eq!(candidates.len(), 2);
+        // Highest score first: the on-topic tweet outranks small talk.
+        assert_eq!(candidates[0]["tweet_id"], "t1");
+        assert!(candidates[0]["score_total"].as_f64() > candidates[1]["score_total"].as_f64());
+        for c in candidates {
+            let verdict = c["recommended_action"].as_str().unwrap();
+            assert!(["strong_reply", "consider", "skip"].contains(&verdict));
+        }
+        assert_eq!(candidates[1]["recommended_action"], "skip");
+    }
+
+    #[tokio::test]
+    async fn preview_caps_scored_tweets() {
+        let db = init_test_db().await.unwrap();
+        let texts: Vec<String> = (0..80).map(|i| format!("rust tweet {i}")).collect();
+        let refs: Vec<&str> = texts.iter().map(String::as_str).collect();
+        let client = MockTimelineClient::new(&refs);
+
+        let Ok(Json(body)) = score_preview(
+            &db,
+            DEFAULT_ACCOUNT_ID,
+            &client,
+            &config(),
+            "known",
+            Some(500),
+        )
+        .await
+        else {
+            panic!("preview failed");
+        };
+
+        let max = target_preview::MAX_PREVIEW_TWEETS;
+        assert_eq!(client.requested_max.load(Ordering::SeqCst), max);
+        assert_eq!(body["candidates"].as_array().unwrap().len(), max as usize);
+    }
+
+    #[tokio::test]
+    async fn preview_unknown_user_is_not_found() {
+        let db = init_test_db().await.unwrap();
+        let client = MockTimelineClient::new(&[]);
+
+        let err = score_preview(&db, DEFAULT_ACCOUNT_ID, &client, &config(), "ghost", None)
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApiError::NotFound(msg) if msg.contains("@ghost")));
+    }
+}
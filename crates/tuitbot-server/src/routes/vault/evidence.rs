@@ -272,6 +272,8 @@ mod tests {
             x_client_id: String::new(),
             semantic_index: None,
             embedding_provider: None,
+            active_account: Default::default(),
+            log_buffer: Default::default(),
         })
     }
 
@@ -443,6 +445,8 @@ mod tests {
             x_client_id: String::new(),
             semantic_index: None,
             embedding_provider: None,
+            active_account: Default::default(),
+            log_buffer: Default::default(),
         });
         let app = test_router(state);
 
@@ -150,6 +150,8 @@ mod tests {
             x_client_id: String::new(),
             semantic_index: None,
             embedding_provider: None,
+            active_account: Default::default(),
+            log_buffer: Default::default(),
         })
     }
 
@@ -276,6 +278,8 @@ mod tests {
             x_client_id: String::new(),
             semantic_index: Some(index),
             embedding_provider: Some(Arc::new(MockEmbeddingProvider)),
+            active_account: Default::default(),
+            log_buffer: Default::default(),
         })
     }
 
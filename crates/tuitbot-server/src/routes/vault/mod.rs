@@ -473,6 +473,8 @@ mod tests {
             x_client_id: String::new(),
             semantic_index: None,
             embedding_provider: None,
+            active_account: Default::default(),
+            log_buffer: Default::default(),
         })
     }
 
@@ -748,6 +750,8 @@ mod tests {
             x_client_id: String::new(),
             semantic_index: None,
             embedding_provider: None,
+            active_account: Default::default(),
+            log_buffer: Default::default(),
         })
     }
 
@@ -15,8 +15,8 @@ use axum::extract::{Path, State};
 use axum::Json;
 use serde::Deserialize;
 use serde_json::{json, Value};
-use tuitbot_core::startup::{build_auth_url, build_redirect_uri, generate_pkce};
-use tuitbot_core::storage::accounts::{self, account_scraper_session_path, account_token_path};
+use tuitbot_core::startup::{build_auth_url, build_redirect_uri, generate_pkce, StoredTokens};
+use tuitbot_core::storage::accounts::{self, account_scraper_session_path};
 use tuitbot_core::x_api::auth;
 
 use crate::account::{require_mutate, AccountContext};
@@ -153,9 +153,10 @@ pub async fn complete_link(
         scopes: stored_tokens.scopes,
     };
 
-    // Save to account-specific token path.
-    let token_path = account_token_path(&state.data_dir, &id);
-    auth::save_tokens(&tokens, &token_path)
+    // Save to the account's configured token store.
+    let store = state.token_store(&id);
+    store
+        .save(&StoredTokens::from(&tokens))
         .map_err(|e| ApiError::Internal(format!("failed to save tokens: {e}")))?;
 
     // Evict any existing TokenManager for this account (force reload on next use).
@@ -166,13 +167,13 @@ pub async fn complete_link(
 
     Ok(Json(json!({
         "status": "linked",
-        "token_path": token_path.display().to_string(),
+        "token_path": store.location(),
     })))
 }
 
 /// `DELETE /api/accounts/{id}/x-auth/tokens` — unlink OAuth tokens.
 ///
-/// Deletes the token file for the specified account and evicts any cached
+/// Deletes the stored tokens for the specified account and evicts any cached
 /// `TokenManager`, effectively disconnecting the OAuth credential.
 pub async fn unlink(
     State(state): State<Arc<AppState>>,
@@ -186,15 +187,11 @@ pub async fn unlink(
         .await?
         .ok_or_else(|| ApiError::NotFound(format!("account not found: {id}")))?;
 
-    let token_path = account_token_path(&state.data_dir, &id);
-
-    let deleted = if token_path.exists() {
-        std::fs::remove_file(&token_path)
-            .map_err(|e| ApiError::Internal(format!("failed to delete tokens: {e}")))?;
-        true
-    } else {
-        false
-    };
+    let store = state.token_store(&id);
+    let deleted = matches!(store.load(), Ok(Some(_)));
+    store
+        .delete()
+        .map_err(|e| ApiError::Internal(format!("failed to delete tokens: {e}")))?;
 
     // Evict cached TokenManager regardless.
     {
@@ -224,18 +221,14 @@ pub async fn link_status(
         .ok_or_else(|| ApiError::NotFound(format!("account not found: {id}")))?;
 
     // Check OAuth tokens.
-    let token_path = account_token_path(&state.data_dir, &id);
-    let (oauth_linked, oauth_expired, oauth_expires_at) = if token_path.exists() {
-        match auth::load_tokens(&token_path) {
-            Ok(Some(tokens)) => {
-                let expired = tokens.expires_at < chrono::Utc::now();
-                let expires_at = tokens.expires_at.to_rfc3339();
-                (true, expired, Some(expires_at))
-            }
-            _ => (false, false, None),
+    let (oauth_linked, oauth_expired, oauth_expires_at) = match state.token_store(&id).load() {
+        Ok(Some(stored)) => {
+            let tokens = auth::Tokens::from(stored);
+            let expired = tokens.expires_at < chrono::Utc::now();
+            let expires_at = tokens.expires_at.to_rfc3339();
+            (true, expired, Some(expires_at))
         }
-    } else {
-        (false, false, None)
+        _ => (false, false, None),
     };
 
     // Check scraper session.
@@ -0,0 +1,70 @@
+//! Shared X API client construction for route handlers.
+
+use tuitbot_core::x_api::{XApiClient, XApiHttpClient};
+
+use crate::account::AccountContext;
+use crate::error::ApiError;
+use crate::state::AppState;
+
+use super::content::read_effective_config;
+
+/// Build an X API client for the given account based on the configured backend.
+///
+/// Returns `Box<dyn XApiClient>` so callers can use either scraper or OAuth
+/// without duplicating the construction logic.
+pub(crate) async fn build_x_client(
+    state: &AppState,
+    ctx: &AccountContext,
+) -> Result<Box<dyn XApiClient>, ApiError> {
+    let config = read_effective_config(state, &ctx.account_id).await?;
+
+    match config.x_api.provider_backend.as_str() {
+        "scraper" => {
+            let account_data =
+                tuitbot_core::storage::accounts::account_data_dir(&state.data_dir, &ctx.account_id);
+            // Use the shared health handle from AppState so each request's outcome
+            // aggregates into the tracker that /health reads, rather than being discarded.
+            let client = if let Some(ref health) = state.scraper_health {
+                tuitbot_core::x_api::LocalModeXClient::with_session_and_health(
+                    config.x_api.scraper_allow_mutations,
+                    &account_data,
+                    health.clone(),
+                )
+                .await
+            } else {
+                tuitbot_core::x_api::LocalModeXClient::with_session(
+                    config.x_api.scraper_allow_mutations,
+                    &account_data,
+                )
+                .await
+            };
+            Ok(Box::new(client))
+        }
+        "x_api" => {
+            let token_path = tuitbot_core::storage::accounts::account_token_path(
+                &state.data_dir,
+                &ctx.account_id,
+            );
+            let access_token = state
+                .get_x_access_token(&token_path, &ctx.account_id)
+                .await
+                .map_err(|e| {
+                    ApiError::BadRequest(format!(
+                        "X API authentication failed — re-link your account in Settings. ({e})"
+                    ))
+                })?;
+            Ok(Box::new(
+                XApiHttpClient::new(access_token)
+                    .with_timeouts(&config.x_api.http)
+                    .with_cache_ttl(std::time::Duration::from_secs(
+                        config.x_api.cache_ttl_seconds,
+                    )),
+            ))
+        }
+        _ => Err(ApiError::BadRequest(
+            "Direct posting requires X API credentials or a browser session. \
+             Configure in Settings → X API."
+                .to_string(),
+        )),
+    }
+}
@@ -12,20 +12,21 @@ use tuitbot_core::automation::circuit_breaker::CircuitBreaker;
 use tuitbot_core::automation::Runtime;
 use tuitbot_core::automation::WatchtowerLoop;
 use tuitbot_core::config::{
-    effective_config, Config, ConnectorConfig, ContentSourcesConfig, DeploymentMode,
+    effective_config, AuthConfig, Config, ConnectorConfig, ContentSourcesConfig, DeploymentMode,
 };
-use tuitbot_core::content::ContentGenerator;
+use tuitbot_core::content::{ComplianceRules, ContentGenerator, FrameworkWeights, Temperatures};
 use tuitbot_core::context::semantic_index::SemanticIndex;
 use tuitbot_core::llm::embedding::EmbeddingProvider;
 use tuitbot_core::llm::factory::create_provider;
-use tuitbot_core::storage::accounts::{self, DEFAULT_ACCOUNT_ID};
+use tuitbot_core::startup::{account_token_store, TokenStore};
+use tuitbot_core::storage::accounts::{self, ActiveAccount, DEFAULT_ACCOUNT_ID};
 use tuitbot_core::storage::DbPool;
 use tuitbot_core::x_api::auth::TokenManager;
 use tuitbot_core::x_api::ScraperHealth;
 
 use tuitbot_core::error::XApiError;
-use tuitbot_core::x_api::auth;
 
+use crate::log_buffer::LogBuffer;
 use crate::ws::AccountWsEvent;
 
 /// Pending OAuth PKCE state for connector link flows.
@@ -88,12 +89,17 @@ pub struct AppState {
     pub semantic_index: Option<Arc<RwLock<SemanticIndex>>>,
     /// Embedding provider for semantic indexing (None if not configured).
     pub embedding_provider: Option<Arc<dyn EmbeddingProvider>>,
+    /// Account used by requests that omit the `X-Account-Id` header.
+    pub active_account: ActiveAccount,
+    /// Recent log lines served by `GET /api/runtime/logs`.
+    pub log_buffer: LogBuffer,
 }
 
 impl AppState {
     /// Get a fresh X API access token for the given account.
     ///
-    /// Lazily creates a `TokenManager` on first use (loading tokens from disk),
+    /// Lazily creates a `TokenManager` on first use (loading tokens from the
+    /// configured store),
     /// then returns a token that is automatically refreshed before expiry.
     pub async fn get_x_access_token(
         &self,
@@ -108,14 +114,24 @@ impl AppState {
             }
         }
 
-        // Load tokens from disk and create a new manager.
-        let tokens = auth::load_tokens(token_path)?.ok_or(XApiError::AuthExpired)?;
+        // Load tokens from the configured store and create a new manager.
+        let store = self.token_store(account_id);
+        let tokens = store
+            .load()
+            .map_err(|e| XApiError::ApiError {
+                status: 0,
+                message: format!("Failed to load tokens: {e}"),
+            })?
+            .ok_or(XApiError::AuthExpired)?;
 
-        let tm = Arc::new(TokenManager::new(
-            tokens,
-            self.x_client_id.clone(),
-            token_path.to_path_buf(),
-        ));
+        let tm = Arc::new(
+            TokenManager::new(
+                tokens.into(),
+                self.x_client_id.clone(),
+                token_path.to_path_buf(),
+            )
+            .with_store(store),
+        );
 
         let access_token = tm.get_access_token().await?;
 
@@ -127,6 +143,19 @@ impl AppState {
         Ok(access_token)
     }
 
+    /// The `[auth]` section of config.toml, or defaults when it is missing.
+    pub fn auth_config(&self) -> AuthConfig {
+        let contents = std::fs::read_to_string(&self.config_path).unwrap_or_default();
+        toml::from_str::<Config>(&contents)
+            .map(|config| config.auth)
+            .unwrap_or_default()
+    }
+
+    /// Token store for an account, honoring `auth.token_storage`.
+    pub fn token_store(&self, account_id: &str) -> Box<dyn TokenStore> {
+        account_token_store(&self.auth_config(), &self.data_dir, account_id)
+    }
+
     /// Load the effective config for a given account.
     ///
     /// Default account: reads config.toml directly (backward compat).
@@ -169,7 +198,16 @@ impl AppState {
         let provider =
             create_provider(&config.llm).map_err(|e| format!("LLM not configured: {e}"))?;
 
-        let gen = Arc::new(ContentGenerator::new(provider, config.business));
+        let gen = Arc::new(
+            ContentGenerator::new(provider, config.business)
+                .with_rag_sanitize(config.content.rag_sanitize)
+                .with_random_seed(config.content.random_seed)
+                .with_framework_weights(FrameworkWeights::from_config(&config.content))
+                .with_compliance(ComplianceRules::from_config(&config.content))
+                .with_temperatures(Temperatures::from_config(&config.content))
+                .with_retry(config.llm.retry.clone())
+                .with_dump_prompt_on_error(config.logging.dump_prompt_on_error),
+        );
 
         self.content_generators
             .lock()
@@ -1,7 +1,8 @@
 //! WebSocket hub for real-time event streaming.
 //!
 //! Provides a `/api/ws` endpoint that streams server events to dashboard clients
-//! via a `tokio::sync::broadcast` channel.
+//! via a `tokio::sync::broadcast` channel, plus `/api/activity/stream`, which
+//! forwards only [`WsEvent::Action`] posting events for live activity feeds.
 //!
 //! Supports two authentication methods:
 //! - Query parameter: `?token=<api_token>` (Tauri/API clients)
@@ -15,7 +16,9 @@ use axum::http::{HeaderMap, StatusCode};
 use axum::response::{IntoResponse, Response};
 use serde::{Deserialize, Serialize};
 use serde_json::json;
+use tokio::sync::broadcast;
 use tuitbot_core::auth::session;
+use tuitbot_core::automation::{ActivityEvent, ActivitySink};
 
 use crate::state::AppState;
 
@@ -90,12 +93,47 @@ pub enum WsEvent {
         cooldown_remaining_seconds: u64,
         timestamp: String,
     },
+    /// A reply/tweet/thread was posted or denied (live activity feed).
+    Action {
+        kind: String,
+        target: String,
+        status: String,
+    },
     /// A Ghostwriter selection was received from the Obsidian plugin.
     SelectionReceived { session_id: String },
     /// An error occurred.
     Error { message: String },
 }
 
+/// [`ActivitySink`] that publishes posting events on the WebSocket broadcast.
+pub struct WsActivitySink {
+    account_id: String,
+    event_tx: broadcast::Sender<AccountWsEvent>,
+}
+
+impl WsActivitySink {
+    pub fn new(account_id: impl Into<String>, event_tx: broadcast::Sender<AccountWsEvent>) -> Self {
+        Self {
+            account_id: account_id.into(),
+            event_tx,
+        }
+    }
+}
+
+impl ActivitySink for WsActivitySink {
+    fn emit(&self, event: ActivityEvent) {
+        // No subscribers is not an error.
+        let _ = self.event_tx.send(AccountWsEvent {
+            account_id: self.account_id.clone(),
+            event: WsEvent::Action {
+                kind: event.kind.to_string(),
+                target: event.target,
+                status: event.status.to_string(),
+            },
+        });
+    }
+}
+
 /// Query parameters for WebSocket authentication.
 #[derive(Deserialize)]
 pub struct WsQuery {
@@ -123,20 +161,48 @@ pub async fn ws_handler(
     headers: HeaderMap,
     Query(params): Query<WsQuery>,
 ) -> Response {
+    if !is_authorized(&state, &headers, &params).await {
+        return unauthorized();
+    }
+    ws.on_upgrade(move |socket| handle_ws(socket, state, |_| true))
+}
+
+/// `GET /api/activity/stream` — WebSocket upgrade streaming only posting
+/// activity (`Action`) events. Same auth as `/api/ws`.
+pub async fn activity_stream_handler(
+    ws: WebSocketUpgrade,
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    Query(params): Query<WsQuery>,
+) -> Response {
+    if !is_authorized(&state, &headers, &params).await {
+        return unauthorized();
+    }
+    ws.on_upgrade(move |socket| {
+        handle_ws(socket, state, |e| matches!(e.event, WsEvent::Action { .. }))
+    })
+}
+
+/// Check the query token (Tauri/API clients) or session cookie (web/LAN).
+async fn is_authorized(state: &AppState, headers: &HeaderMap, params: &WsQuery) -> bool {
     // Strategy 1: Bearer token via query parameter
     if let Some(ref token) = params.token {
         if token == &state.api_token {
-            return ws.on_upgrade(move |socket| handle_ws(socket, state));
+            return true;
         }
     }
 
     // Strategy 2: Session cookie
-    if let Some(session_token) = extract_session_cookie(&headers) {
+    if let Some(session_token) = extract_session_cookie(headers) {
         if let Ok(Some(_)) = session::validate_session(&state.db, &session_token).await {
-            return ws.on_upgrade(move |socket| handle_ws(socket, state));
+            return true;
         }
     }
 
+    false
+}
+
+fn unauthorized() -> Response {
     (
         StatusCode::UNAUTHORIZED,
         axum::Json(json!({"error": "unauthorized"})),
@@ -146,12 +212,18 @@ pub async fn ws_handler(
 
 /// Handle a single WebSocket connection.
 ///
-/// Subscribes to the broadcast channel and forwards events as JSON text frames.
-async fn handle_ws(mut socket: WebSocket, state: Arc<AppState>) {
+/// Subscribes to the broadcast channel and forwards events accepted by
+/// `filter` as JSON text frames.
+async fn handle_ws(
+    mut socket: WebSocket,
+    state: Arc<AppState>,
+    filter: impl Fn(&AccountWsEvent) -> bool,
+) {
     let mut rx = state.event_tx.subscribe();
 
     loop {
         match rx.recv().await {
+            Ok(event) if !filter(&event) => {}
             Ok(event) => {
                 let json = match serde_json::to_string(&event) {
                     Ok(j) => j,
@@ -457,6 +529,11 @@ mod tests {
                 cooldown_remaining_seconds: 0,
                 timestamp: "ts".into(),
             },
+            WsEvent::Action {
+                kind: "reply".into(),
+                target: "t".into(),
+                status: "posted".into(),
+            },
             WsEvent::SelectionReceived {
                 session_id: "sess-1".into(),
             },
@@ -0,0 +1,135 @@
+//! Integration test for the live activity stream.
+//!
+//! Runs the core posting queue with a [`WsActivitySink`] and asserts that a
+//! subscriber on the WebSocket broadcast channel receives a `WsEvent::Action`
+//! after a simulated post, and that activity logged by an out-of-process
+//! runtime is relayed the same way.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::sync::{broadcast, oneshot};
+use tokio_util::sync::CancellationToken;
+use tuitbot_core::automation::{
+    create_posting_queue, run_posting_queue_with_activity, ActivityEvent, ActivityKind,
+    ActivitySink, ActivityStatus, LoopError, PostAction, PostExecutor, ACTIVITY_ACTION_TYPE,
+};
+use tuitbot_core::storage::{self, action_log};
+
+use tuitbot_server::activity_relay::relay_new_activity;
+use tuitbot_server::ws::{AccountWsEvent, WsActivitySink, WsEvent};
+
+struct MockExecutor;
+
+#[async_trait::async_trait]
+impl PostExecutor for MockExecutor {
+    async fn execute_reply(
+        &self,
+        _tweet_id: &str,
+        _content: &str,
+        _media_ids: &[String],
+    ) -> Result<String, LoopError> {
+        Ok("reply-1".to_string())
+    }
+
+    async fn execute_tweet(
+        &self,
+        _content: &str,
+        _media_ids: &[String],
+    ) -> Result<String, LoopError> {
+        Ok("tweet-1".to_string())
+    }
+}
+
+#[tokio::test]
+async fn simulated_post_emits_action_event() {
+    let (event_tx, mut event_rx) = broadcast::channel::<AccountWsEvent>(16);
+    let sink: Arc<dyn ActivitySink> = Arc::new(WsActivitySink::new("acct-1", event_tx));
+
+    let (tx, rx) = create_posting_queue();
+    let cancel = CancellationToken::new();
+    let handle = tokio::spawn(run_posting_queue_with_activity(
+        rx,
+        Arc::new(MockExecutor),
+        None,
+        Duration::ZERO,
+        Duration::ZERO,
+        None,
+        Some(sink),
+        None,
+        cancel.clone(),
+    ));
+
+    let (result_tx, result_rx) = oneshot::channel();
+    tx.send(PostAction::Reply {
+        tweet_id: "target-42".to_string(),
+        content: "Nice thread!".to_string(),
+        media_ids: vec![],
+        framework: None,
+        result_tx: Some(result_tx),
+    })
+    .await
+    .expect("send");
+    result_rx.await.expect("recv").expect("posted");
+
+    let received = tokio::time::timeout(Duration::from_secs(1), event_rx.recv())
+        .await
+        .expect("event within timeout")
+        .expect("event");
+    assert_eq!(received.account_id, "acct-1");
+    match received.event {
+        WsEvent::Action {
+            kind,
+            target,
+            status,
+        } => {
+            assert_eq!(kind, "reply");
+            assert_eq!(target, "target-42");
+            assert_eq!(status, "posted");
+        }
+        other => panic!("expected Action event, got {other:?}"),
+    }
+
+    cancel.cancel();
+    handle.await.expect("join");
+}
+
+#[tokio::test]
+async fn logged_runtime_activity_is_relayed() {
+    let pool = storage::init_test_db().await.expect("init test db");
+    let (event_tx, mut event_rx) = broadcast::channel::<AccountWsEvent>(16);
+
+    let event = ActivityEvent {
+        kind: ActivityKind::Tweet,
+        target: "tweet-7".to_string(),
+        status: ActivityStatus::Denied,
+    };
+    let metadata = serde_json::to_string(&event).expect("serialize");
+    action_log::log_action_for(
+        &pool,
+        "acct-2",
+        ACTIVITY_ACTION_TYPE,
+        "denied",
+        None,
+        Some(&metadata),
+    )
+    .await
+    .expect("log activity");
+
+    let cursor = relay_new_activity(&pool, &event_tx, 0).await;
+    assert!(cursor > 0);
+
+    let received = event_rx.try_recv().expect("relayed event");
+    assert_eq!(received.account_id, "acct-2");
+    match received.event {
+        WsEvent::Action { target, status, .. } => {
+            assert_eq!(target, "tweet-7");
+            assert_eq!(status, "denied");
+        }
+        other => panic!("expected Action event, got {other:?}"),
+    }
+
+    // Already-relayed rows are not sent twice.
+    relay_new_activity(&pool, &event_tx, cursor).await;
+    assert!(event_rx.try_recv().is_err());
+}
@@ -64,6 +64,23 @@ async fn analytics_followers_returns_array() {
     assert!(body.is_array());
 }
 
+#[tokio::test]
+async fn analytics_followers_growth_returns_series_and_summary() {
+    let dir = tempfile::tempdir().unwrap();
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+    tuitbot_core::storage::analytics::upsert_follower_snapshot(&pool, 1200, 10, 50)
+        .await
+        .unwrap();
+
+    let (status, body) = get_json(router, "/api/analytics/followers/growth?window=7").await;
+    assert_eq!(status, StatusCode::OK);
+    let series = body["series"].as_array().expect("series array");
+    assert_eq!(series.len(), 1);
+    assert_eq!(series[0]["interpolated"], false);
+    assert_eq!(body["summary"]["current"], 1200);
+    assert!(body["summary"]["change_7d"].is_null());
+}
+
 #[tokio::test]
 async fn analytics_performance_returns_object() {
     let router = test_router().await;
@@ -80,6 +97,61 @@ async fn analytics_topics_returns_array() {
     assert!(body.is_array());
 }
 
+#[tokio::test]
+async fn analytics_topic_performance_ranks_topics_and_flags_sparse_ones() {
+    let dir = tempfile::tempdir().unwrap();
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+
+    // rust: 3 posts averaging 70, go: 3 posts averaging 40, zig: 1 viral post.
+    let posts = [
+        ("r1", "rust", 10, 60.0),
+        ("r2", "rust", 20, 80.0),
+        ("r3", "rust", 30, 70.0),
+        ("g1", "go", 4, 30.0),
+        ("g2", "go", 6, 50.0),
+        ("g3", "go", 8, 40.0),
+        ("z1", "zig", 500, 400.0),
+    ];
+    for (id, topic, likes, score) in posts {
+        sqlx::query(
+            "INSERT INTO original_tweets \
+             (account_id, tweet_id, content, topic, status, created_at) \
+             VALUES (?, ?, 'tweet', ?, 'sent', '2026-03-02T14:00:00Z')",
+        )
+        .bind(tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID)
+        .bind(id)
+        .bind(topic)
+        .execute(&pool)
+        .await
+        .expect("insert tweet");
+        storage::analytics::upsert_tweet_performance(&pool, id, likes, 2, 1, 1000, score)
+            .await
+            .expect("perf");
+    }
+
+    let (status, body) = get_json(router.clone(), "/api/analytics/topics/performance").await;
+    assert_eq!(status, StatusCode::OK);
+    let topics: Vec<&str> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["topic"].as_str().unwrap())
+        .collect();
+    assert_eq!(topics, ["rust", "go", "zig"]);
+    assert_eq!(body[0]["post_count"], 3);
+    assert_eq!(body[0]["avg_performance"], 70.0);
+    assert_eq!(body[0]["avg_likes"], 20.0);
+    assert_eq!(body[0]["low_confidence"], false);
+    assert_eq!(body[1]["avg_performance"], 40.0);
+    assert_eq!(body[2]["post_count"], 1);
+    assert_eq!(body[2]["low_confidence"], true);
+
+    let (status, body) = get_json(router, "/api/analytics/topics/performance?limit=1").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 1);
+    assert_eq!(body[0]["topic"], "rust");
+}
+
 // ============================================================
 // Approval mutations
 // ============================================================
@@ -149,6 +221,8 @@ async fn runtime_start_and_stop() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -247,6 +321,8 @@ async fn post_ingest_idempotent() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -359,6 +435,8 @@ async fn config_status_capabilities_match_cloud_mode() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -429,6 +507,8 @@ product_keywords = ["base"]
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     };
 
     // Default account: base config
@@ -539,3 +619,126 @@ async fn analytics_topics_honours_limit_param() {
     assert!(body.is_array());
     assert!(body.as_array().unwrap().len() <= 5);
 }
+
+#[tokio::test]
+async fn analytics_frameworks_reports_recorded_frameworks() {
+    let dir = tempfile::tempdir().unwrap();
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+
+    tuitbot_core::storage::content_frameworks::record_framework(&pool, "t1", "format", "list")
+        .await
+        .unwrap();
+    tuitbot_core::storage::analytics::upsert_tweet_performance(&pool, "t1", 10, 1, 2, 100, 40.0)
+        .await
+        .unwrap();
+
+    let (status, body) = get_json(router, "/api/analytics/frameworks").await;
+    assert_eq!(status, StatusCode::OK);
+    let rows = body.as_array().expect("array");
+    assert_eq!(rows.len(), 1);
+    assert_eq!(rows[0]["kind"], "format");
+    assert_eq!(rows[0]["framework"], "list");
+    assert_eq!(rows[0]["measured"], 1);
+    assert_eq!(rows[0]["avg_performance"], 40.0);
+}
+
+// ============================================================
+// Assist: optimal posting times
+// ============================================================
+
+#[tokio::test]
+async fn assist_optimal_times_falls_back_to_defaults_without_history() {
+    let dir = tempfile::tempdir().unwrap();
+    let (router, _pool) = test_router_with_dir(dir.path()).await;
+
+    let (status, body) = get_json(router, "/api/assist/optimal-times").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["source"], "default");
+    assert_eq!(body["timezone"], "UTC");
+    assert_eq!(
+        body["preferred_times"],
+        serde_json::json!(["09:15", "12:30", "17:00"])
+    );
+    assert!(body["preferred_times_override"]
+        .as_object()
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn assist_optimal_times_computed_from_history_in_schedule_timezone() {
+    let dir = tempfile::tempdir().unwrap();
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+    std::fs::write(
+        dir.path().join("config.toml"),
+        r#"
+[x_api]
+provider_backend = "scraper"
+client_id = "test-client-id"
+
+[business]
+product_name = "TestProduct"
+product_keywords = ["test"]
+
+[schedule]
+timezone = "America/New_York"
+"#,
+    )
+    .unwrap();
+
+    // Mondays in early March 2026 (UTC-5): 14:00Z = 09:00 local, 23:00Z = 18:00 local,
+    // 17:00Z = 12:00 local (low engagement).
+    let mut n = 0;
+    for day in ["2026-03-02", "2026-02-23", "2026-02-16", "2026-02-09"] {
+        for (time, score) in [("14:00:00", 90.0), ("23:00:00", 70.0), ("17:00:00", 20.0)] {
+            n += 1;
+            let id = format!("tw-{n}");
+            sqlx::query(
+                "INSERT INTO original_tweets \
+                 (account_id, tweet_id, content, topic, status, created_at) \
+                 VALUES (?, ?, 'tweet', 'rust', 'sent', ?)",
+            )
+            .bind(tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID)
+            .bind(&id)
+            .bind(format!("{day}T{time}Z"))
+            .execute(&pool)
+            .await
+            .expect("insert tweet");
+            storage::analytics::upsert_tweet_performance(&pool, &id, 1, 1, 1, 100, score)
+                .await
+                .expect("perf");
+        }
+    }
+
+    // One viral post at 20:00Z (15:00 local) is below analytics.min_engagement_sample.
+    sqlx::query(
+        "INSERT INTO original_tweets \
+         (account_id, tweet_id, content, topic, status, created_at) \
+         VALUES (?, 'tw-viral', 'tweet', 'rust', 'sent', '2026-03-02T20:00:00Z')",
+    )
+    .bind(tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID)
+    .execute(&pool)
+    .await
+    .expect("insert tweet");
+    storage::analytics::upsert_tweet_performance(&pool, "tw-viral", 1, 1, 1, 100, 500.0)
+        .await
+        .expect("perf");
+
+    let (status, body) = get_json(router, "/api/assist/optimal-times").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["source"], "history");
+    assert_eq!(body["timezone"], "America/New_York");
+    assert_eq!(
+        body["preferred_times_override"]["Mon"],
+        serde_json::json!(["09:00", "12:00", "18:00"])
+    );
+    assert_eq!(
+        body["preferred_times"],
+        serde_json::json!(["09:00", "12:00", "18:00"])
+    );
+    // UTC hour breakdown is still reported, with the sparse hour flagged.
+    assert_eq!(body["times"][0]["hour"], 20);
+    assert_eq!(body["times"][0]["low_confidence"], true);
+    assert_eq!(body["times"][1]["hour"], 14);
+    assert_eq!(body["times"][1]["low_confidence"], false);
+}
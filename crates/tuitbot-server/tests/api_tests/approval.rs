@@ -57,6 +57,8 @@ async fn approval_stats_returns_counts() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -111,6 +113,8 @@ async fn approval_list_with_status_filter() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -175,6 +179,8 @@ async fn approval_edit_content() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -237,6 +243,8 @@ async fn approval_edit_empty_content() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -673,6 +681,83 @@ async fn approval_edit_history_records_edits() {
     assert_eq!(arr[0]["field"], "generated_content");
 }
 
+#[tokio::test]
+async fn approval_preview_reports_length_limit() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+
+    let at_limit = "a".repeat(280);
+    let over_limit = "a".repeat(281);
+    let fits = tuitbot_core::storage::approval_queue::enqueue(
+        &pool, "tweet", "", "", &at_limit, "General", "", 0.0, "[]",
+    )
+    .await
+    .expect("enqueue");
+    let too_long = tuitbot_core::storage::approval_queue::enqueue(
+        &pool,
+        "tweet",
+        "",
+        "",
+        &over_limit,
+        "General",
+        "",
+        0.0,
+        "[]",
+    )
+    .await
+    .expect("enqueue");
+
+    let (status, body) = get_json(router.clone(), &format!("/api/approval/{fits}/preview")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["weighted_len"], 280);
+    assert_eq!(body["within_limit"], true);
+    assert!(body["banned_phrase_hit"].is_null());
+    assert!(body["thread_parts"].is_null());
+
+    let (status, body) = get_json(router, &format!("/api/approval/{too_long}/preview")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["weighted_len"], 281);
+    assert_eq!(body["within_limit"], false);
+
+    // Preview is read-only.
+    let item = tuitbot_core::storage::approval_queue::get_by_id(&pool, too_long)
+        .await
+        .expect("get")
+        .expect("exists");
+    assert_eq!(item.status, "pending");
+    assert_eq!(item.generated_content, over_limit);
+}
+
+#[tokio::test]
+async fn approval_preview_flags_banned_phrase_in_thread() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+
+    let content = serde_json::json!(["First tweet", "Check out my product"]).to_string();
+    let id = tuitbot_core::storage::approval_queue::enqueue(
+        &pool, "thread", "", "", &content, "General", "", 0.0, "[]",
+    )
+    .await
+    .expect("enqueue");
+
+    let (status, body) = get_json(router, &format!("/api/approval/{id}/preview")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["banned_phrase_hit"], "check out");
+    let parts = body["thread_parts"].as_array().expect("thread parts");
+    assert_eq!(parts.len(), 2);
+    assert_eq!(parts[1]["text"], "Check out my product");
+    assert_eq!(parts[1]["within_limit"], true);
+    assert_eq!(body["weighted_len"], 20);
+}
+
+#[tokio::test]
+async fn approval_preview_not_found() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, _pool) = test_router_with_dir(dir.path()).await;
+    let (status, _) = get_json(router, "/api/approval/99999/preview").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
 // ---------------------------------------------------------------------------
 // Helpers for idempotency / auth tests
 // ---------------------------------------------------------------------------
@@ -98,6 +98,8 @@ async fn add_and_list_target() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -148,6 +150,8 @@ async fn add_duplicate_target_fails() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -199,6 +203,8 @@ async fn remove_target_works() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -229,6 +235,25 @@ async fn remove_nonexistent_target_fails() {
     assert_eq!(status, StatusCode::NOT_FOUND);
 }
 
+#[tokio::test]
+async fn target_score_preview_without_x_client_is_503() {
+    let dir = tempfile::tempdir().unwrap();
+    let (router, _) = test_router_with_dir(dir.path()).await;
+    // Official backend with no stored tokens: no X client can be built.
+    std::fs::write(
+        dir.path().join("config.toml"),
+        "[x_api]\nprovider_backend = \"x_api\"\nclient_id = \"test\"\n",
+    )
+    .unwrap();
+
+    let (status, body) = get_json(router, "/api/targets/someone/score-preview").await;
+    assert_eq!(status, StatusCode::SERVICE_UNAVAILABLE);
+    assert!(body["error"]
+        .as_str()
+        .unwrap()
+        .contains("X client unavailable"));
+}
+
 // ============================================================
 // Runtime
 // ============================================================
@@ -268,6 +293,8 @@ async fn settings_get_returns_json() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -310,6 +337,8 @@ async fn settings_patch_round_trips() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -50,6 +50,8 @@ loop_back_enabled = false
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -116,6 +118,8 @@ loop_back_enabled = false
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -207,6 +211,8 @@ loop_back_enabled = false
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -295,6 +301,8 @@ loop_back_enabled = false
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -346,6 +354,8 @@ async fn settings_init_with_connection_id() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -458,6 +468,8 @@ api_key = "test-key-not-real"
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
 
     // Cache starts empty
@@ -7,6 +7,7 @@
 //!   DELETE /api/content/drafts/{id}           — delete
 //!   POST   /api/content/drafts/{id}/publish   — publish (error: no X creds)
 //!   POST   /api/content/drafts/{id}/schedule  — schedule (happy path)
+//!   GET    /api/content/drafts/{id}/score     — heuristic quality report
 
 use super::*;
 
@@ -364,3 +365,63 @@ async fn content_drafts_schedule_requires_auth() {
     let resp = tower::ServiceExt::oneshot(router, req).await.unwrap();
     assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
 }
+
+// ---------------------------------------------------------------------------
+// GET /api/content/drafts/{id}/score — quality report
+// ---------------------------------------------------------------------------
+
+fn check_status<'a>(body: &'a serde_json::Value, name: &str) -> &'a serde_json::Value {
+    &body["checks"]
+        .as_array()
+        .expect("checks array")
+        .iter()
+        .find(|c| c["name"] == name)
+        .unwrap_or_else(|| panic!("missing check {name}: {body}"))["status"]
+}
+
+#[tokio::test]
+async fn content_drafts_score_flags_hashtags_and_length() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+
+    // Over the limit and stuffed with hashtags; inserted directly since the
+    // create endpoint rejects over-length tweets.
+    let content = format!("{} #rust #dev #ai #tools #growth", "a".repeat(260));
+    let id =
+        tuitbot_core::storage::scheduled_content::insert_draft(&pool, "tweet", &content, "manual")
+            .await
+            .expect("insert draft");
+
+    let (status, body) = get_json(router, &format!("/api/content/drafts/{id}/score")).await;
+    assert_eq!(status, StatusCode::OK, "score: {body}");
+    assert_eq!(body["within_limit"], false);
+    assert_eq!(body["status"], "fail");
+    assert_eq!(check_status(&body, "length"), "fail");
+    assert_eq!(check_status(&body, "hashtags"), "fail");
+    assert_eq!(check_status(&body, "banned_phrases"), "pass");
+}
+
+#[tokio::test]
+async fn content_drafts_score_rejects_threads() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+    let id = tuitbot_core::storage::scheduled_content::insert_draft(
+        &pool,
+        "thread",
+        r#"["one","two"]"#,
+        "manual",
+    )
+    .await
+    .expect("insert draft");
+
+    let (status, _) = get_json(router, &format!("/api/content/drafts/{id}/score")).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn content_drafts_score_not_found() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, _pool) = test_router_with_dir(dir.path()).await;
+    let (status, _) = get_json(router, "/api/content/drafts/99999/score").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,140 @@
+//! `[server.cors]` policy: permissive default vs. explicit allowed origins.
+
+use tuitbot_core::config::CorsConfig;
+
+use super::*;
+
+const ALLOWED: &str = "http://192.168.1.20:5173";
+
+fn restricted() -> CorsConfig {
+    CorsConfig {
+        permissive: true,
+        allowed_origins: vec![ALLOWED.to_string()],
+    }
+}
+
+fn get_with_origin(origin: Option<&str>, host: &str) -> Request<Body> {
+    let mut builder = Request::builder()
+        .uri("/api/health")
+        .header("Host", host)
+        .header("Authorization", format!("Bearer {TEST_TOKEN}"));
+    if let Some(origin) = origin {
+        builder = builder.header("Origin", origin);
+    }
+    builder.body(Body::empty()).unwrap()
+}
+
+#[tokio::test]
+async fn allowed_origin_passes_with_cors_headers() {
+    let router = test_router_with_cors(&restricted()).await;
+    let resp = router
+        .oneshot(get_with_origin(Some(ALLOWED), "192.168.1.10:3001"))
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers()
+            .get("access-control-allow-origin")
+            .and_then(|v| v.to_str().ok()),
+        Some(ALLOWED)
+    );
+}
+
+#[tokio::test]
+async fn disallowed_origin_is_rejected() {
+    let router = test_router_with_cors(&restricted()).await;
+    let resp = router
+        .oneshot(get_with_origin(
+            Some("http://evil.example"),
+            "192.168.1.10:3001",
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    assert!(resp.headers().get("access-control-allow-origin").is_none());
+}
+
+#[tokio::test]
+async fn disallowed_preflight_is_rejected() {
+    let router = test_router_with_cors(&restricted()).await;
+    let req = Request::builder()
+        .method("OPTIONS")
+        .uri("/api/approval")
+        .header("Host", "192.168.1.10:3001")
+        .header("Origin", "http://evil.example")
+        .header("Access-Control-Request-Method", "POST")
+        .body(Body::empty())
+        .unwrap();
+
+    let resp = router.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn same_origin_and_originless_requests_pass() {
+    let router = test_router_with_cors(&restricted()).await;
+    let resp = router
+        .clone()
+        .oneshot(get_with_origin(
+            Some("http://192.168.1.10:3001"),
+            "192.168.1.10:3001",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+
+    let resp = router
+        .oneshot(get_with_origin(None, "192.168.1.10:3001"))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn permissive_default_allows_any_origin() {
+    let router = test_router().await;
+    let resp = router
+        .oneshot(get_with_origin(
+            Some("http://evil.example"),
+            "localhost:3001",
+        ))
+        .await
+        .unwrap();
+
+    assert_eq!(resp.status(), StatusCode::OK);
+    assert_eq!(
+        resp.headers()
+            .get("access-control-allow-origin")
+            .and_then(|v| v.to_str().ok()),
+        Some("*")
+    );
+}
+
+#[tokio::test]
+async fn permissive_false_without_origins_allows_same_origin_only() {
+    let router = test_router_with_cors(&CorsConfig {
+        permissive: false,
+        allowed_origins: Vec::new(),
+    })
+    .await;
+    let resp = router
+        .clone()
+        .oneshot(get_with_origin(
+            Some("http://localhost:5173"),
+            "localhost:3001",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+    let resp = router
+        .oneshot(get_with_origin(
+            Some("http://localhost:3001"),
+            "localhost:3001",
+        ))
+        .await
+        .unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+}
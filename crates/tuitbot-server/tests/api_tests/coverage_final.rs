@@ -136,6 +136,54 @@ async fn schedule_list_empty() {
     assert_eq!(status, StatusCode::OK, "schedule: {body}");
 }
 
+#[tokio::test]
+async fn preview_schedule_returns_seven_days() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, _pool) = test_router_with_dir(dir.path()).await;
+
+    let (status, body) = get_json(router, "/api/content/preview-schedule").await;
+    assert_eq!(status, StatusCode::OK, "preview: {body}");
+    let days = body["days"].as_array().expect("days array");
+    assert_eq!(days.len(), 7);
+    assert!(days[0]["date"].is_string());
+    assert!(days[0]["slots"].is_array());
+    assert!(body["tweet_mode"].is_string());
+}
+
+#[tokio::test]
+async fn calendar_ics_returns_feed() {
+    use axum::body::Body;
+    use axum::http::{header, Request};
+    use http_body_util::BodyExt;
+    use tower::ServiceExt;
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, _pool) = test_router_with_dir(dir.path()).await;
+
+    let req = Request::builder()
+        .uri("/api/content/calendar.ics?days=14")
+        .header("Authorization", format!("Bearer {TEST_TOKEN}"))
+        .body(Body::empty())
+        .expect("build request");
+    let response = router.clone().oneshot(req).await.expect("send request");
+    assert_eq!(response.status(), StatusCode::OK);
+    assert!(response.headers()[header::CONTENT_TYPE]
+        .to_str()
+        .unwrap()
+        .starts_with("text/calendar"));
+    let body = response.into_body().collect().await.expect("read body");
+    let ics = String::from_utf8(body.to_bytes().to_vec()).expect("utf-8");
+    assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+    assert!(ics.ends_with("END:VCALENDAR\r\n"));
+    assert_eq!(
+        ics.matches("BEGIN:VEVENT").count(),
+        ics.matches("END:VEVENT").count()
+    );
+
+    let (status, _body) = get_json(router, "/api/content/calendar.ics?days=0").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn calendar_returns_ok() {
     let dir = tempfile::tempdir().expect("tempdir");
@@ -335,6 +383,7 @@ async fn replies_list_with_seeded_data() {
             created_at: chrono::Utc::now().format("%Y-%m-%dT%H:%M:%SZ").to_string(),
             status: "sent".to_string(),
             error_message: None,
+            conversation_id: None,
         };
         tuitbot_core::storage::replies::insert_reply(&pool, &reply)
             .await
@@ -53,6 +53,19 @@ async fn health_detailed_with_auth_returns_ok() {
     );
 }
 
+#[tokio::test]
+async fn health_detailed_reports_pool_metrics() {
+    let router = test_router().await;
+    let (status, body) = get_json(router, "/api/health/detailed").await;
+    assert_eq!(status, StatusCode::OK);
+    let db = &body["checks"]["database"];
+    assert!(db["latency_ms"].is_u64(), "latency_ms: {db}");
+    for field in ["active", "idle", "size", "max_size"] {
+        assert!(db["pool"][field].is_u64(), "pool.{field}: {db}");
+    }
+    assert!(db["pool"]["max_size"].as_u64().unwrap() >= 1);
+}
+
 // ============================================================
 // Tag routes: /api/tags
 // ============================================================
@@ -122,6 +135,8 @@ async fn list_tags_after_create_shows_new_tag() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -291,6 +306,13 @@ async fn strategy_history_with_limit_param() {
     assert!(body.is_array());
 }
 
+#[tokio::test]
+async fn strategy_history_inputs_unknown_report_is_404() {
+    let router = test_router().await;
+    let (status, _body) = get_json(router, "/api/strategy/history/9999/inputs").await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
 #[tokio::test]
 async fn strategy_inputs_returns_object() {
     let router = test_router().await;
@@ -42,6 +42,8 @@ async fn test_router_with_config(
         x_client_id: "test-client-id".to_string(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
 
     (tuitbot_server::build_router(state), pool)
@@ -275,6 +277,30 @@ async fn compose_unified_tweet_with_approval_mode() {
     assert_eq!(body["status"], "queued_for_approval");
 }
 
+#[tokio::test]
+async fn compose_unified_keeps_the_framework_for_approval() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, pool) = test_router_with_config(dir.path(), APPROVAL_ON_CONFIG).await;
+
+    let (status, body) = post_json(
+        router,
+        "/api/content/compose",
+        json!({
+            "content_type": "tweet",
+            "content": "Framework tweet",
+            "framework": "list"
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "queued: {body}");
+    let id = body["id"].as_i64().expect("id");
+    let item = storage::approval_queue::get_by_id(&pool, id)
+        .await
+        .unwrap()
+        .expect("queued item");
+    assert_eq!(item.archetype, "list");
+}
+
 #[tokio::test]
 async fn compose_unified_tweet_without_approval() {
     let dir = tempfile::tempdir().expect("tempdir");
@@ -3,6 +3,7 @@
 //! Covers:
 //!   GET  /api/discovery/feed
 //!   GET  /api/discovery/keywords
+//!   GET  /api/discovery/keywords/suggestions
 //!   POST /api/discovery/{tweet_id}/queue-reply  (happy path + error cases)
 //!   POST /api/discovery/{tweet_id}/compose-reply (no LLM → error)
 
@@ -87,6 +88,8 @@ async fn discovery_feed_each_item_has_required_fields() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
 
     // Insert a discovered tweet directly.
@@ -130,6 +133,66 @@ async fn discovery_feed_each_item_has_required_fields() {
     }
 }
 
+fn discovered(id: &str, score: f64) -> tuitbot_core::storage::tweets::DiscoveredTweet {
+    tuitbot_core::storage::tweets::DiscoveredTweet {
+        id: id.to_string(),
+        author_id: format!("author_{id}"),
+        author_username: format!("user_{id}"),
+        content: format!("Tweet {id} about rust"),
+        like_count: 0,
+        retweet_count: 0,
+        reply_count: 0,
+        impression_count: None,
+        relevance_score: Some(score),
+        matched_keyword: Some("rust".to_string()),
+        discovered_at: "2026-03-14T00:00:00Z".to_string(),
+        replied_to: 0,
+    }
+}
+
+#[tokio::test]
+async fn discovery_feed_min_score_filters_low_scores() {
+    let dir = tempfile::tempdir().unwrap();
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+    for (id, score) in [("high", 88.0), ("mid", 62.0), ("low", 25.0)] {
+        tuitbot_core::storage::tweets::insert_discovered_tweet(&pool, &discovered(id, score))
+            .await
+            .expect("insert tweet");
+    }
+
+    let (status, body) = get_json(router.clone(), "/api/discovery/feed?min_score=60").await;
+    assert_eq!(status, StatusCode::OK);
+    let ids: Vec<&str> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|t| t["id"].as_str().unwrap())
+        .collect();
+    assert!(ids.contains(&"high") && ids.contains(&"mid"));
+    assert!(!ids.contains(&"low"));
+    for item in body.as_array().unwrap() {
+        assert!(item["relevance_score"].as_f64().unwrap() >= 60.0);
+    }
+
+    // Default threshold (50) still applies when the param is omitted.
+    let (_, body) = get_json(router.clone(), "/api/discovery/feed").await;
+    assert_eq!(body.as_array().unwrap().len(), 2);
+
+    let (_, body) = get_json(router, "/api/discovery/feed?min_score=0").await;
+    assert_eq!(body.as_array().unwrap().len(), 3);
+}
+
+#[tokio::test]
+async fn discovery_feed_rejects_out_of_range_min_score() {
+    let router = test_router().await;
+    let (status, body) = get_json(router.clone(), "/api/discovery/feed?min_score=101").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert!(body["error"].as_str().unwrap().contains("min_score"));
+
+    let (status, _) = get_json(router, "/api/discovery/feed?min_score=-5").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
 // ---------------------------------------------------------------------------
 // GET /api/discovery/keywords
 // ---------------------------------------------------------------------------
@@ -153,6 +216,62 @@ async fn discovery_keywords_requires_auth() {
     assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
 }
 
+// ---------------------------------------------------------------------------
+// GET /api/discovery/keywords/suggestions
+// ---------------------------------------------------------------------------
+
+#[tokio::test]
+async fn keyword_suggestions_rank_terms_from_engaged_tweets() {
+    let dir = tempfile::tempdir().unwrap();
+    // The test config tracks the product keyword "test".
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+    let seeded = [
+        ("s1", 90.0, "Flaky test suites need better fixtures"),
+        ("s2", 85.0, "Snapshot fixtures make every test readable"),
+        ("s3", 80.0, "Property test generators beat fixtures"),
+        ("s4", 75.0, "Mutation test tools find gaps"),
+        ("s5", 72.0, "Unrelated post about coffee"),
+        ("s6", 20.0, "Low score test mocking mocking mocking"),
+        ("s7", 20.0, "Another low score test about mocking"),
+    ];
+    for (id, score, content) in seeded {
+        let mut tweet = discovered(id, score);
+        tweet.content = content.to_string();
+        tuitbot_core::storage::tweets::insert_discovered_tweet(&pool, &tweet)
+            .await
+            .expect("insert tweet");
+    }
+
+    let (status, body) = get_json(router, "/api/discovery/keywords/suggestions").await;
+    assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert_eq!(body["analyzed_tweets"], 5);
+    assert!(body["note"].is_null(), "body: {body}");
+    let suggestions = body["suggestions"].as_array().unwrap();
+    assert_eq!(suggestions[0]["keyword"], "fixtures");
+    assert_eq!(suggestions[0]["co_occurrences"], 3);
+    assert_eq!(suggestions[0]["related_to"], serde_json::json!(["test"]));
+    // Terms only present in low-scoring tweets are not analyzed.
+    assert!(suggestions.iter().all(|s| s["keyword"] != "mocking"));
+}
+
+#[tokio::test]
+async fn keyword_suggestions_with_no_data_return_note() {
+    let dir = tempfile::tempdir().unwrap();
+    let (router, _pool) = test_router_with_dir(dir.path()).await;
+    let (status, body) = get_json(router, "/api/discovery/keywords/suggestions").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["suggestions"], serde_json::json!([]));
+    assert!(body["note"].as_str().unwrap().contains("Not enough"));
+}
+
+#[tokio::test]
+async fn keyword_suggestions_rejects_out_of_range_min_score() {
+    let router = test_router().await;
+    let (status, _body) =
+        get_json(router, "/api/discovery/keywords/suggestions?min_score=150").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
+
 // ---------------------------------------------------------------------------
 // POST /api/discovery/{tweet_id}/queue-reply — error cases
 // ---------------------------------------------------------------------------
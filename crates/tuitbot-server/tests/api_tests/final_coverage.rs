@@ -779,6 +779,8 @@ async fn build_test_state(
         x_client_id: "test-client-id".to_string(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     })
 }
 
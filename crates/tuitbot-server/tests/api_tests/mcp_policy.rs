@@ -7,8 +7,9 @@
 //!   - POST /api/mcp/policy/templates/{name} — apply template
 //!   - GET  /api/mcp/telemetry/summary   — aggregate stats
 //!   - GET  /api/mcp/telemetry/metrics   — per-tool metrics
-//!   - GET  /api/mcp/telemetry/errors    — error breakdown
+//!   - GET  /api/mcp/telemetry/errors    — grouped error signatures
 //!   - GET  /api/mcp/telemetry/recent    — recent executions
+//!   - GET  /api/mcp/telemetry/export    — Prometheus text exposition
 
 use super::*;
 
@@ -142,6 +143,10 @@ async fn mcp_telemetry_errors_returns_ok() {
     let router = test_router().await;
     let (status, body) = get_json(router, "/api/mcp/telemetry/errors").await;
     assert_eq!(status, StatusCode::OK, "body: {body}");
+    assert!(body["groups"].is_array(), "expected groups, got: {body}");
+    assert_eq!(body["total"], 0);
+    assert_eq!(body["limit"], 50);
+    assert_eq!(body["offset"], 0);
 }
 
 #[tokio::test]
@@ -172,3 +177,105 @@ async fn mcp_telemetry_requires_auth() {
     let resp = router.oneshot(req).await.expect("send");
     assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
 }
+
+// ── /api/mcp/telemetry/export ────────────────────────────────────────────────
+
+/// Check every line against the Prometheus text format: `# HELP`/`# TYPE`
+/// comments, or `name{label="value",...} number` samples whose metric was
+/// declared by a preceding TYPE line.
+fn assert_valid_prometheus_text(text: &str) {
+    let mut declared = std::collections::HashSet::new();
+    for line in text.lines() {
+        if let Some(rest) = line.strip_prefix("# TYPE ") {
+            let (name, kind) = rest.split_once(' ').expect("TYPE has name and kind");
+            assert_eq!(kind, "counter", "line: {line}");
+            declared.insert(name.to_string());
+            continue;
+        }
+        if line.starts_with("# HELP ") {
+            continue;
+        }
+        let (series, value) = line.rsplit_once(' ').expect("sample has a value");
+        value
+            .parse::<f64>()
+            .unwrap_or_else(|_| panic!("bad value in: {line}"));
+        let (name, labels) = series.split_once('{').expect("sample has labels");
+        assert!(declared.contains(name), "undeclared metric: {line}");
+        assert!(
+            name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'),
+            "bad metric name: {line}"
+        );
+        let labels = labels.strip_suffix('}').expect("labels close");
+        for pair in labels.split("\",") {
+            let (key, val) = pair.split_once("=\"").expect("label is key=\"value\"");
+            assert!(!key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_'));
+            assert!(!val.trim_end_matches('"').contains('\n'));
+        }
+    }
+}
+
+#[tokio::test]
+async fn mcp_telemetry_export_renders_prometheus_text() {
+    use tuitbot_core::storage::mcp_telemetry::{log_telemetry, TelemetryParams};
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+    for (success, error_code) in [
+        (true, None),
+        (true, None),
+        (false, Some("policy_denied_rate_limited")),
+    ] {
+        log_telemetry(
+            &pool,
+            &TelemetryParams {
+                tool_name: "x_post_tweet",
+                category: "mutation",
+                latency_ms: 10,
+                success,
+                error_code,
+                policy_decision: None,
+                metadata: None,
+            },
+        )
+        .await
+        .expect("log telemetry");
+    }
+
+    let req = Request::builder()
+        .uri("/api/mcp/telemetry/export")
+        .header("Authorization", format!("Bearer {TEST_TOKEN}"))
+        .body(Body::empty())
+        .expect("build");
+    let resp = router.oneshot(req).await.expect("send");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let content_type = resp.headers()["content-type"].to_str().unwrap().to_string();
+    assert!(content_type.starts_with("text/plain; version=0.0.4"));
+    let body = resp.into_body().collect().await.expect("body").to_bytes();
+    let text = String::from_utf8(body.to_vec()).expect("utf-8");
+
+    assert_valid_prometheus_text(&text);
+    for line in [
+        "tuitbot_mcp_tool_invocations_total{tool=\"x_post_tweet\",category=\"mutation\",outcome=\"success\"} 2",
+        "tuitbot_mcp_tool_invocations_total{tool=\"x_post_tweet\",category=\"mutation\",outcome=\"error\"} 1",
+        "tuitbot_mcp_tool_errors_total{tool=\"x_post_tweet\",category=\"mutation\",error_code=\"policy_denied_rate_limited\"} 1",
+        "tuitbot_mcp_mutation_rate_limited_total{tool=\"x_post_tweet\"} 1",
+    ] {
+        assert!(text.lines().any(|l| l == line), "missing {line} in:\n{text}");
+    }
+}
+
+#[tokio::test]
+async fn mcp_telemetry_export_empty_db_has_only_headers() {
+    let router = test_router().await;
+    let req = Request::builder()
+        .uri("/api/mcp/telemetry/export")
+        .header("Authorization", format!("Bearer {TEST_TOKEN}"))
+        .body(Body::empty())
+        .expect("build");
+    let resp = router.oneshot(req).await.expect("send");
+    assert_eq!(resp.status(), StatusCode::OK);
+    let body = resp.into_body().collect().await.expect("body").to_bytes();
+    let text = String::from_utf8(body.to_vec()).expect("utf-8");
+    assert_valid_prometheus_text(&text);
+    assert!(text.lines().all(|l| l.starts_with('#')), "{text}");
+}
@@ -22,6 +22,7 @@ mod compose;
 mod connectors_coverage; // Connector routes (link, status, disconnect) coverage
 mod content;
 mod content_drafts; // Task 3.4: legacy /api/content/drafts CRUD + publish + schedule
+mod cors;
 mod coverage_final; // Deep integration tests for all routes — final 75% push
 mod coverage_gaps;
 mod coverage_sprint1; // Sprint-1 coverage push: compose, connectors, settings, factory reset
@@ -31,6 +32,8 @@ mod discovery_feed; // Task 3.4: discovery feed + queue-reply routes
 mod draft_studio_coverage; // Additional draft studio error-path coverage
 mod final_coverage; // Final coverage push: drafts CRUD, scheduled, strategy, vault, sources, x-auth, LAN, state
 mod mcp_policy; // Task 3.8: /api/mcp/* policy + telemetry coverage
+mod pagination; // Keyset cursors on /activity, /approval and /replies
+mod role_enforcement; // Viewer/approver/admin role checks on read, approve, and settings routes
 mod route_coverage_extra; // Extra compose, onboarding, media, ingest, assist, draft-studio coverage
 mod settings_accounts; // Settings, accounts, activity, connectors, vault, content, and misc route coverage
 mod settings_init_workflow; // Settings init → get → patch → validate workflow coverage
@@ -42,10 +45,20 @@ pub const TEST_TOKEN: &str = "test-token-abc123";
 
 /// Create the test router backed by an in-memory SQLite database.
 pub async fn test_router() -> axum::Router {
+    tuitbot_server::build_router(test_state().await)
+}
+
+/// Create the test router with a specific `[server.cors]` policy.
+pub async fn test_router_with_cors(cors: &tuitbot_core::config::CorsConfig) -> axum::Router {
+    tuitbot_server::build_router_with_cors(test_state().await, cors)
+}
+
+/// App state backed by an in-memory SQLite database.
+async fn test_state() -> Arc<AppState> {
     let pool = storage::init_test_db().await.expect("init test db");
     let (event_tx, _) = tokio::sync::broadcast::channel::<AccountWsEvent>(256);
 
-    let state = Arc::new(AppState {
+    Arc::new(AppState {
         db: pool,
         config_path: std::path::PathBuf::from("/tmp/test-config.toml"),
         data_dir: std::path::PathBuf::from("/tmp"),
@@ -70,9 +83,9 @@ pub async fn test_router() -> axum::Router {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
-    });
-
-    tuitbot_server::build_router(state)
+        active_account: Default::default(),
+        log_buffer: Default::default(),
+    })
 }
 
 /// Helper: send a GET request with auth and parse JSON from the response.
@@ -201,6 +214,8 @@ product_keywords = ["test"]
         x_client_id: "test-client-id".to_string(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
 
     (tuitbot_server::build_router(state), pool)
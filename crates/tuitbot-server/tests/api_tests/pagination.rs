@@ -0,0 +1,169 @@
+//! Keyset cursors on `/api/activity`, `/api/approval` and `/api/replies`.
+
+use super::*;
+
+/// GET `path` and return the status, JSON body and `X-Next-Cursor` header.
+async fn get_page(
+    router: axum::Router,
+    path: &str,
+) -> (StatusCode, serde_json::Value, Option<String>) {
+    let req = Request::builder()
+        .uri(path)
+        .header("Authorization", format!("Bearer {TEST_TOKEN}"))
+        .body(Body::empty())
+        .expect("build request");
+
+    let response = router.oneshot(req).await.expect("send request");
+    let status = response.status();
+    let next = response
+        .headers()
+        .get("x-next-cursor")
+        .map(|v| v.to_str().unwrap().to_string());
+    let body = response.into_body().collect().await.expect("read body");
+    let json = serde_json::from_slice(&body.to_bytes()).expect("parse JSON");
+    (status, json, next)
+}
+
+async fn log_at(pool: &storage::DbPool, action_type: &str, created_at: &str) {
+    sqlx::query(
+        "INSERT INTO action_log (account_id, action_type, status, created_at) \
+         VALUES (?, ?, 'success', ?)",
+    )
+    .bind(storage::accounts::DEFAULT_ACCOUNT_ID)
+    .bind(action_type)
+    .bind(created_at)
+    .execute(pool)
+    .await
+    .expect("insert action");
+}
+
+#[tokio::test]
+async fn activity_cursor_pages_do_not_overlap_or_skip_as_actions_arrive() {
+    let dir = tempfile::tempdir().unwrap();
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+    for i in 0..5 {
+        log_at(&pool, &format!("a{i}"), "2026-03-02 10:00:00").await;
+    }
+
+    let (status, body) = get_json(router.clone(), "/api/activity?limit=2").await;
+    assert_eq!(status, StatusCode::OK);
+    let mut seen: Vec<String> = body["actions"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|a| a["action_type"].as_str().unwrap().to_string())
+        .collect();
+    let mut cursor = body["next_cursor"].as_str().map(str::to_string);
+
+    // New actions between fetches would shift an offset-based page.
+    log_at(&pool, "new1", "2026-03-02 10:00:00").await;
+    log_at(&pool, "new2", "2026-03-02 12:00:00").await;
+
+    while let Some(c) = cursor {
+        let (status, body) =
+            get_json(router.clone(), &format!("/api/activity?limit=2&cursor={c}")).await;
+        assert_eq!(status, StatusCode::OK);
+        seen.extend(
+            body["actions"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|a| a["action_type"].as_str().unwrap().to_string()),
+        );
+        cursor = body["next_cursor"].as_str().map(str::to_string);
+    }
+    assert_eq!(seen, ["a4", "a3", "a2", "a1", "a0"]);
+}
+
+#[tokio::test]
+async fn approval_pages_follow_the_next_cursor_header() {
+    let dir = tempfile::tempdir().unwrap();
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+    for text in ["A", "B", "C"] {
+        storage::approval_queue::enqueue(&pool, "tweet", "", "", text, "", "", 0.0, "[]")
+            .await
+            .expect("enqueue");
+    }
+
+    let (status, body, next) = get_page(router.clone(), "/api/approval?limit=2").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body.as_array().unwrap().len(), 2);
+    let next = next.expect("more items");
+
+    storage::approval_queue::enqueue(&pool, "tweet", "", "", "D", "", "", 0.0, "[]")
+        .await
+        .expect("enqueue");
+
+    let (status, page2, next) = get_page(
+        router.clone(),
+        &format!("/api/approval?limit=2&cursor={next}"),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(next.is_none(), "C and D fill the last page");
+
+    let texts: Vec<&str> = body
+        .as_array()
+        .unwrap()
+        .iter()
+        .chain(page2.as_array().unwrap())
+        .map(|i| i["generated_content"].as_str().unwrap())
+        .collect();
+    assert_eq!(texts, ["A", "B", "C", "D"]);
+
+    // Without limit or cursor the whole queue is still returned.
+    let (_, all, next) = get_page(router, "/api/approval").await;
+    assert_eq!(all.as_array().unwrap().len(), 4);
+    assert!(next.is_none());
+}
+
+#[tokio::test]
+async fn replies_pages_follow_the_next_cursor_header() {
+    let dir = tempfile::tempdir().unwrap();
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+    for i in 0..3 {
+        let reply = storage::replies::ReplySent {
+            id: 0,
+            target_tweet_id: format!("t{i}"),
+            reply_tweet_id: Some(format!("r{i}")),
+            reply_content: "reply".to_string(),
+            llm_provider: None,
+            llm_model: None,
+            created_at: "2026-03-02T10:00:00Z".to_string(),
+            status: "sent".to_string(),
+            error_message: None,
+            conversation_id: None,
+        };
+        storage::replies::insert_reply(&pool, &reply)
+            .await
+            .expect("insert");
+    }
+
+    let (status, page1, next) = get_page(router.clone(), "/api/replies?limit=2").await;
+    assert_eq!(status, StatusCode::OK);
+    let next = next.expect("more replies");
+    let (_, page2, next) = get_page(router, &format!("/api/replies?limit=2&cursor={next}")).await;
+    assert!(next.is_none());
+
+    let targets: Vec<&str> = page1
+        .as_array()
+        .unwrap()
+        .iter()
+        .chain(page2.as_array().unwrap())
+        .map(|r| r["target_tweet_id"].as_str().unwrap())
+        .collect();
+    assert_eq!(targets, ["t2", "t1", "t0"]);
+}
+
+#[tokio::test]
+async fn malformed_cursor_is_rejected() {
+    let router = test_router().await;
+    for path in [
+        "/api/activity?cursor=bogus!",
+        "/api/approval?cursor=bogus!",
+        "/api/replies?cursor=bogus!",
+    ] {
+        let (status, _) = get_json(router.clone(), path).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST, "{path}");
+    }
+}
@@ -0,0 +1,126 @@
+//! Role checks: viewers read, approvers also approve, admins also mutate.
+
+use super::*;
+use tuitbot_core::storage::{accounts, approval_queue};
+
+/// Router plus a non-default account with X tokens and one pending item.
+async fn account_with_pending_item(role: &str) -> (axum::Router, String, i64, tempfile::TempDir) {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+    let account_id = create_test_account(&pool, "team").await;
+    accounts::set_role(&pool, &account_id, "dashboard", role)
+        .await
+        .expect("set role");
+
+    let token_path = accounts::account_token_path(dir.path(), &account_id);
+    let tokens = tuitbot_core::x_api::auth::Tokens {
+        access_token: "test_access".to_string(),
+        refresh_token: "test_refresh".to_string(),
+        expires_at: chrono::Utc::now() + chrono::TimeDelta::hours(2),
+        scopes: vec!["tweet.read".to_string(), "tweet.write".to_string()],
+    };
+    tuitbot_core::x_api::auth::save_tokens(&tokens, &token_path).expect("write tokens");
+
+    let id = approval_queue::enqueue_for(
+        &pool,
+        &account_id,
+        "tweet",
+        "",
+        "",
+        "Queued tweet",
+        "General",
+        "",
+        0.0,
+        "[]",
+    )
+    .await
+    .expect("enqueue");
+
+    (router, account_id, id, dir)
+}
+
+async fn patch_settings_as(router: axum::Router, account_id: &str) -> StatusCode {
+    let req = Request::builder()
+        .method("PATCH")
+        .uri("/api/settings")
+        .header("Authorization", format!("Bearer {TEST_TOKEN}"))
+        .header("Content-Type", "application/json")
+        .header("X-Account-Id", account_id)
+        .body(Body::from(
+            serde_json::to_vec(&serde_json::json!({"limits": {"max_replies_per_day": 4}})).unwrap(),
+        ))
+        .expect("build request");
+    router.oneshot(req).await.expect("send request").status()
+}
+
+#[tokio::test]
+async fn viewer_can_read_but_not_approve_or_change_settings() {
+    let (router, account_id, id, _dir) = account_with_pending_item("viewer").await;
+
+    let (status, body) = get_json_for(router.clone(), "/api/approval", &account_id).await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body.as_array().unwrap().len(), 1);
+
+    let (status, body) = post_json_for(
+        router.clone(),
+        &format!("/api/approval/{id}/approve"),
+        &account_id,
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(body["error"].as_str().unwrap().contains("approver"));
+
+    assert_eq!(
+        patch_settings_as(router, &account_id).await,
+        StatusCode::FORBIDDEN
+    );
+}
+
+#[tokio::test]
+async fn approver_can_read_and_approve_but_not_change_settings() {
+    let (router, account_id, id, _dir) = account_with_pending_item("approver").await;
+
+    let (status, _) = get_json_for(router.clone(), "/api/approval", &account_id).await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = post_json_for(
+        router.clone(),
+        &format!("/api/approval/{id}/approve"),
+        &account_id,
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+    assert_eq!(body["status"], "approved");
+
+    assert_eq!(
+        patch_settings_as(router, &account_id).await,
+        StatusCode::FORBIDDEN
+    );
+}
+
+#[tokio::test]
+async fn viewer_cannot_change_mcp_policy() {
+    let (router, account_id, _, _dir) = account_with_pending_item("viewer").await;
+
+    let (status, _) = post_json_for(
+        router,
+        "/api/mcp/policy/templates/safe_default",
+        &account_id,
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn only_admin_can_read_runtime_logs() {
+    let (router, account_id, _, _dir) = account_with_pending_item("approver").await;
+
+    let (status, _) = get_json_for(router.clone(), "/api/runtime/logs", &account_id).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = get_json(router, "/api/runtime/logs").await;
+    assert_eq!(status, StatusCode::OK, "{body}");
+}
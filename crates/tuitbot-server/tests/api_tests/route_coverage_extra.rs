@@ -547,6 +547,8 @@ async fn ingest_duplicate_inline_node_skipped() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -657,6 +659,22 @@ async fn assist_improve_no_llm_returns_error() {
     );
 }
 
+#[tokio::test]
+async fn assist_quote_no_llm_returns_error() {
+    let router = test_router().await;
+    let (status, _body) = post_json(
+        router,
+        "/api/assist/quote",
+        serde_json::json!({ "quoted_text": "Rust is fast", "quoted_author": "dev" }),
+    )
+    .await;
+    let code = status.as_u16();
+    assert!(
+        code == 400 || code == 500,
+        "assist quote without LLM: {code}"
+    );
+}
+
 #[tokio::test]
 async fn assist_topics_no_llm_returns_error() {
     let router = test_router().await;
@@ -769,6 +787,8 @@ async fn drafts_create_and_get() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -112,6 +112,8 @@ async fn init_settings_creates_config() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -154,6 +156,30 @@ async fn settings_defaults_returns_ok() {
     assert!(body.is_object(), "expected object: {body}");
 }
 
+#[tokio::test]
+async fn settings_schema_describes_known_fields() {
+    let router = test_router().await;
+    let (status, body) = get_json(router, "/api/settings/schema").await;
+    assert_eq!(status, StatusCode::OK, "schema: {body}");
+
+    let fields = body["fields"].as_array().expect("fields array");
+    let threshold = fields
+        .iter()
+        .find(|f| f["path"] == "scoring.threshold")
+        .expect("scoring.threshold in schema");
+    assert_eq!(threshold["section"], "scoring");
+    assert_eq!(threshold["type"], "integer");
+    assert_eq!(threshold["default"], 60);
+    assert_eq!(threshold["minimum"], 0);
+    assert_eq!(threshold["maximum"], 100);
+
+    let hours = fields
+        .iter()
+        .find(|f| f["path"] == "schedule.active_hours_start")
+        .expect("schedule.active_hours_start in schema");
+    assert_eq!(hours["maximum"], 23);
+}
+
 #[tokio::test]
 async fn settings_factory_reset_wrong_confirmation() {
     let router = test_router().await;
@@ -229,6 +255,8 @@ async fn create_and_get_account() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -322,6 +350,9 @@ async fn activity_rate_limits() {
     let (status, body) = get_json(router, "/api/activity/rate-limits").await;
     let code = status.as_u16();
     assert!(code == 200 || code == 400, "got {code}: {body}");
+    if code == 200 {
+        assert!(body["replies"]["remaining"].is_i64(), "got: {body}");
+    }
 }
 
 // ============================================================
@@ -666,6 +697,8 @@ async fn onboarding_start_auth_no_client_id() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None, // empty = no client_id
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -772,6 +805,132 @@ async fn create_and_delete_account() {
     assert_eq!(acc.status, "archived", "account should be archived");
 }
 
+#[tokio::test]
+async fn switch_account_routes_headerless_requests() {
+    use tuitbot_core::config::{IntervalsConfig, LimitsConfig};
+    use tuitbot_core::storage::rate_limits;
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+    let account_id = create_test_account(&pool, "switched").await;
+    let limits = LimitsConfig {
+        max_replies_per_day: 7,
+        ..Default::default()
+    };
+    rate_limits::init_rate_limits_for(&pool, &account_id, &limits, &IntervalsConfig::default())
+        .await
+        .expect("init limits");
+    rate_limits::increment_rate_limit_for(&pool, &account_id, "reply")
+        .await
+        .expect("increment");
+
+    let (status, body) = post_json(
+        router.clone(),
+        "/api/accounts/nonexistent/switch",
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND, "unknown: {body}");
+
+    let (status, body) = post_json(
+        router.clone(),
+        &format!("/api/accounts/{account_id}/switch"),
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "switch: {body}");
+    assert_eq!(body["active_account_id"], account_id.as_str());
+
+    let (status, body) = get_json(router.clone(), "/api/activity/rate-limits").await;
+    assert_eq!(status, StatusCode::OK, "rate limits: {body}");
+    assert_eq!(body["replies"]["max"], 7);
+    assert_eq!(body["replies"]["used"], 1);
+    assert_eq!(body["replies"]["remaining"], 6);
+
+    // Deleting the active account falls back to the default account.
+    let (status, _) = delete_json_for(
+        router.clone(),
+        &format!("/api/accounts/{account_id}"),
+        tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, body) = get_json(router, "/api/activity/rate-limits").await;
+    assert_eq!(status, StatusCode::OK, "after delete: {body}");
+    assert_ne!(body["replies"]["max"], 7);
+}
+
+#[tokio::test]
+async fn switch_account_requires_admin() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+    let account_id = create_test_account(&pool, "viewer-only").await;
+    tuitbot_core::storage::accounts::set_role(&pool, &account_id, "dashboard", "viewer")
+        .await
+        .expect("set role");
+
+    let (status, body) = post_json_for(
+        router.clone(),
+        &format!("/api/accounts/{account_id}/switch"),
+        &account_id,
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "viewer: {body}");
+
+    // Still on the default account: admin-only reads keep working.
+    let (status, body) = get_json(router, "/api/accounts").await;
+    assert_eq!(status, StatusCode::OK, "headerless: {body}");
+}
+
+#[tokio::test]
+async fn switched_account_role_governs_headerless_requests() {
+    use tuitbot_core::storage::accounts::DEFAULT_ACCOUNT_ID;
+
+    let dir = tempfile::tempdir().expect("tempdir");
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+    let account_id = create_test_account(&pool, "viewer-target").await;
+    tuitbot_core::storage::accounts::set_role(&pool, &account_id, "dashboard", "viewer")
+        .await
+        .expect("set role");
+
+    // An admin on the default account switches to an account where the
+    // dashboard is only a viewer.
+    let (status, body) = post_json(
+        router.clone(),
+        &format!("/api/accounts/{account_id}/switch"),
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "switch: {body}");
+
+    // Headerless requests now carry the viewer role: reads work, admin
+    // routes and switching back are refused.
+    let (status, body) = get_json(router.clone(), "/api/activity/rate-limits").await;
+    assert_eq!(status, StatusCode::OK, "viewer read: {body}");
+    let (status, body) = get_json(router.clone(), "/api/accounts").await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "viewer admin read: {body}");
+    let (status, body) = post_json(
+        router.clone(),
+        &format!("/api/accounts/{DEFAULT_ACCOUNT_ID}/switch"),
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN, "viewer switch back: {body}");
+
+    // Naming the default account explicitly still grants admin.
+    let (status, body) = post_json_for(
+        router.clone(),
+        &format!("/api/accounts/{DEFAULT_ACCOUNT_ID}/switch"),
+        DEFAULT_ACCOUNT_ID,
+        serde_json::json!({}),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK, "admin switch back: {body}");
+    let (status, body) = get_json(router, "/api/accounts").await;
+    assert_eq!(status, StatusCode::OK, "admin again: {body}");
+}
+
 #[tokio::test]
 async fn update_account_label() {
     let dir = tempfile::tempdir().expect("tempdir");
@@ -1468,3 +1627,49 @@ async fn settings_get_and_patch_roundtrip() {
         assert_eq!(status, StatusCode::OK, "re-read: {body}");
     }
 }
+
+// ============================================================
+// Runtime logs
+// ============================================================
+
+#[tokio::test]
+async fn runtime_logs_tail_buffer_with_level_filter() {
+    use tracing::Level;
+
+    let state = test_state().await;
+    state
+        .log_buffer
+        .push(Level::INFO, "tuitbot_core", "discovery loop started".into());
+    state
+        .log_buffer
+        .push(Level::WARN, "tuitbot_core", "search rate limited".into());
+    state
+        .log_buffer
+        .push(Level::ERROR, "tuitbot_core", "reply failed".into());
+    let router = tuitbot_server::build_router(state);
+
+    let (status, body) = get_json(router.clone(), "/api/runtime/logs").await;
+    assert_eq!(status, StatusCode::OK, "logs: {body}");
+    let messages: Vec<&str> = body["lines"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|l| l["message"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            "discovery loop started",
+            "search rate limited",
+            "reply failed"
+        ]
+    );
+
+    let (_, body) = get_json(router.clone(), "/api/runtime/logs?level=warn&lines=1").await;
+    let lines = body["lines"].as_array().unwrap();
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0]["level"], "ERROR");
+
+    let (status, _) = get_json(router, "/api/runtime/logs?level=loud").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -37,6 +37,8 @@ async fn init_then_get_returns_created_config() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
 
     // Step 1: Init settings.
@@ -153,6 +155,8 @@ async fn init_settings_rejects_non_object_body() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -1,8 +1,8 @@
 //! Account isolation tests for strategy routes.
 //!
-//! Verifies that GET /api/strategy/current, POST /api/strategy/refresh, and
-//! GET /api/strategy/history scope data to the X-Account-Id header:
-//! account A cannot see account B's strategy reports.
+//! Verifies that GET /api/strategy/current, POST /api/strategy/refresh,
+//! GET /api/strategy/history, and GET /api/strategy/recommendations scope
+//! data to the X-Account-Id header: account A cannot see account B's strategy reports.
 
 use super::*;
 
@@ -182,3 +182,23 @@ async fn strategy_inputs_still_works_after_route_changes() {
         "has target_accounts: {body}"
     );
 }
+
+// ── recommendations route ─────────────────────────────────────────────────────
+
+#[tokio::test]
+async fn strategy_recommendations_empty_without_history_and_not_persisted() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    write_test_config(dir.path()).await;
+    let (router, pool) = test_router_with_dir(dir.path()).await;
+
+    let acct = create_test_account(&pool, "recs").await;
+
+    let (status, body) = get_json_for(router.clone(), "/api/strategy/recommendations", &acct).await;
+    assert_eq!(status, StatusCode::OK, "recommendations: {body}");
+    let steps = body.as_array().expect("array response");
+    assert!(steps.is_empty(), "no evidence, no steps: {body}");
+
+    // Computing next steps must not store a weekly report.
+    let (_, history) = get_json_for(router.clone(), "/api/strategy/history?limit=10", &acct).await;
+    assert!(history.as_array().expect("array").is_empty(), "{history}");
+}
@@ -361,6 +361,8 @@ async fn build_test_router_with_generator(
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
 
     let router = tuitbot_server::build_router(state);
@@ -405,6 +407,8 @@ async fn build_test_router_no_generator() -> (axum::Router, tempfile::TempDir) {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
 
     let router = tuitbot_server::build_router(state);
@@ -44,6 +44,8 @@ async fn test_router() -> axum::Router {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
 
     tuitbot_server::build_router(state)
@@ -63,6 +63,8 @@ async fn test_state_with_dir(dir: &std::path::Path) -> Arc<AppState> {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     })
 }
 
@@ -220,7 +222,7 @@ async fn factory_reset_success() {
     assert_eq!(json["status"], "reset_complete");
 
     let cleared = &json["cleared"];
-    assert_eq!(cleared["tables_cleared"], 44);
+    assert_eq!(cleared["tables_cleared"], 50);
     // Migration seeds 1 account + 2 account_roles = at least 3 rows.
     assert!(cleared["rows_deleted"].as_u64().unwrap() >= 3);
     assert_eq!(cleared["config_deleted"], true);
@@ -64,6 +64,8 @@ async fn test_router_with_dir(dir: &std::path::Path) -> axum::Router {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
 
     tuitbot_server::build_router(state)
@@ -292,6 +294,8 @@ async fn init_with_claim_produces_valid_session() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -380,6 +384,8 @@ async fn login_detects_out_of_band_passphrase_reset() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
@@ -449,6 +455,8 @@ async fn login_detects_new_passphrase_file() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
     let router = tuitbot_server::build_router(state);
 
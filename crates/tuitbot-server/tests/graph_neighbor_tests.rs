@@ -59,6 +59,8 @@ async fn build_test_state() -> (Arc<AppState>, tempfile::TempDir) {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
 
     (state, dir)
@@ -318,6 +320,8 @@ async fn neighbors_cloud_mode_omits_relative_path() {
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
 
     let (main_id, _) = seed_graph_data(&state.db).await;
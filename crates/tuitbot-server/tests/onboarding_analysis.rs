@@ -23,6 +23,7 @@ fn make_user(bio: Option<&str>, url: Option<&str>) -> User {
             following_count: 300,
             tweet_count: 5000,
         },
+        protected: None,
     }
 }
 
@@ -35,6 +36,7 @@ fn make_tweets(count: usize) -> Vec<Tweet> {
             created_at: "2026-03-01T00:00:00Z".into(),
             public_metrics: PublicMetrics::default(),
             conversation_id: None,
+            referenced_tweets: vec![],
         })
         .collect()
 }
@@ -62,6 +62,8 @@ async fn test_router_with_pool(dir: &std::path::Path) -> (axum::Router, DbPool)
         x_client_id: String::new(),
         semantic_index: None,
         embedding_provider: None,
+        active_account: Default::default(),
+        log_buffer: Default::default(),
     });
 
     (tuitbot_server::build_router(state), pool)
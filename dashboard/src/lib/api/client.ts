@@ -2,6 +2,7 @@ import { request, uploadFile, BASE_URL, getAuthMode } from './http';
 import type {
 	HealthResponse,
 	RuntimeStatus,
+	RuntimeLogs,
 	Account,
 	AnalyticsSummary,
 	FollowerSnapshot,
@@ -11,18 +12,21 @@ import type {
 	RateLimitUsage,
 	CalendarItem,
 	ScheduleConfig,
+	SchedulePreview,
 	ComposeRequest,
 	ScheduledContentItem,
 	ThreadBlock,
 	TuitbotConfig,
 	ConfigStatus,
 	SettingsValidationResult,
+	SettingsSchema,
 	SettingsTestResult,
 	TargetAccount,
 	TargetTimelineItem,
 	TargetStats,
 	StrategyReport,
 	StrategyInputs,
+	StrategyNextStep,
 	CostSummary,
 	DailyCostSummary,
 	ModelCostBreakdown,
@@ -39,7 +43,7 @@ import type {
 	McpPolicyTemplate,
 	McpTelemetrySummary,
 	McpToolMetrics,
-	McpErrorBreakdown,
+	McpErrorGroupPage,
 	McpTelemetryEntry,
 	McpAvailableTool,
 	LinkResponse,
@@ -73,7 +77,14 @@ export const api = {
 	runtime: {
 		status: () => request<RuntimeStatus>('/api/runtime/status'),
 		start: () => request<{ status: string }>('/api/runtime/start', { method: 'POST' }),
-		stop: () => request<{ status: string }>('/api/runtime/stop', { method: 'POST' })
+		stop: () => request<{ status: string }>('/api/runtime/stop', { method: 'POST' }),
+		logs: (lines?: number, level?: string) => {
+			const query = new URLSearchParams();
+			if (lines !== undefined) query.set('lines', String(lines));
+			if (level) query.set('level', level);
+			const qs = query.toString();
+			return request<RuntimeLogs>(`/api/runtime/logs${qs ? `?${qs}` : ''}`);
+		}
 	},
 
 	auth: {
@@ -130,6 +141,10 @@ export const api = {
 			request<{ status: string }>(`/api/accounts/${id}`, { method: 'DELETE' }),
 		syncProfile: (id: string) =>
 			request<Account>(`/api/accounts/${id}/sync-profile`, { method: 'POST' }),
+		switch: (id: string) =>
+			request<{ active_account_id: string }>(`/api/accounts/${id}/switch`, {
+				method: 'POST'
+			}),
 		authStatus: (id: string) =>
 			request<AccountAuthStatus>(`/api/accounts/${id}/x-auth/status`),
 		startAuth: (id: string) =>
@@ -210,6 +225,7 @@ export const api = {
 		calendar: (from: string, to: string) =>
 			request<CalendarItem[]>(`/api/content/calendar?from=${from}&to=${to}`),
 		schedule: () => request<ScheduleConfig>('/api/content/schedule'),
+		previewSchedule: () => request<SchedulePreview>('/api/content/preview-schedule'),
 		compose: (data: ComposeRequest) =>
 			request<{ status: string; id: number }>('/api/content/compose', {
 				method: 'POST',
@@ -284,6 +300,7 @@ export const api = {
 				body: JSON.stringify(data)
 			}),
 		defaults: () => request<TuitbotConfig>('/api/settings/defaults'),
+		schema: () => request<SettingsSchema>('/api/settings/schema'),
 		testLlm: (data: {
 			provider: string;
 			api_key?: string | null;
@@ -347,7 +364,8 @@ export const api = {
 		history: (limit: number = 12) =>
 			request<StrategyReport[]>(`/api/strategy/history?limit=${limit}`),
 		refresh: () => request<StrategyReport>('/api/strategy/refresh', { method: 'POST' }),
-		inputs: () => request<StrategyInputs>('/api/strategy/inputs')
+		inputs: () => request<StrategyInputs>('/api/strategy/inputs'),
+		recommendations: () => request<StrategyNextStep[]>('/api/strategy/recommendations')
 	},
 
 	costs: {
@@ -436,7 +454,12 @@ export const api = {
 
 	assist: {
 		tweet: (topic: string, selectedNodeIds?: number[]) =>
-			request<{ content: string; topic: string; vault_citations?: VaultCitation[] }>(
+			request<{
+				content: string;
+				topic: string;
+				vault_citations?: VaultCitation[];
+				framework?: string;
+			}>(
 				'/api/assist/tweet',
 				{
 					method: 'POST',
@@ -447,7 +470,7 @@ export const api = {
 				}
 			),
 		reply: (tweetText: string, tweetAuthor: string, mentionProduct: boolean = false) =>
-			request<{ content: string }>('/api/assist/reply', {
+			request<{ content: string; framework?: string }>('/api/assist/reply', {
 				method: 'POST',
 				body: JSON.stringify({
 					tweet_text: tweetText,
@@ -456,7 +479,12 @@ export const api = {
 				})
 			}),
 		thread: (topic: string, selectedNodeIds?: number[], openingHook?: string) =>
-			request<{ tweets: string[]; topic: string; vault_citations?: VaultCitation[] }>(
+			request<{
+				tweets: string[];
+				topic: string;
+				vault_citations?: VaultCitation[];
+				framework?: string;
+			}>(
 				'/api/assist/thread',
 				{
 					method: 'POST',
@@ -479,11 +507,29 @@ export const api = {
 					})
 				}
 			),
+		quote: (quotedText: string, quotedAuthor: string, topicHint?: string) =>
+			request<{ content: string }>('/api/assist/quote', {
+				method: 'POST',
+				body: JSON.stringify({
+					quoted_text: quotedText,
+					quoted_author: quotedAuthor,
+					...(topicHint && { topic_hint: topicHint })
+				})
+			}),
 		topics: () =>
 			request<{ topics: Array<{ topic: string; score: number }> }>('/api/assist/topics'),
 		optimalTimes: () =>
 			request<{
-				times: Array<{ hour: number; avg_engagement: number; post_count: number }>;
+				times: Array<{
+					hour: number;
+					avg_engagement: number;
+					post_count: number;
+					low_confidence: boolean;
+				}>;
+				timezone: string;
+				source: 'history' | 'default';
+				preferred_times: string[];
+				preferred_times_override: Record<string, string[]>;
 			}>('/api/assist/optimal-times'),
 		highlights: (selectedNodeIds: number[]) =>
 			request<{ highlights: string[]; vault_citations?: VaultCitation[] }>(
@@ -687,8 +733,10 @@ export const api = {
 			request<McpTelemetrySummary>(`/api/mcp/telemetry/summary?hours=${hours}`),
 		telemetryMetrics: (hours: number = 24) =>
 			request<McpToolMetrics[]>(`/api/mcp/telemetry/metrics?hours=${hours}`),
-		telemetryErrors: (hours: number = 24) =>
-			request<McpErrorBreakdown[]>(`/api/mcp/telemetry/errors?hours=${hours}`),
+		telemetryErrors: (hours: number = 24, limit: number = 50, offset: number = 0) =>
+			request<McpErrorGroupPage>(
+				`/api/mcp/telemetry/errors?hours=${hours}&limit=${limit}&offset=${offset}`
+			),
 		telemetryRecent: (limit: number = 50) =>
 			request<McpTelemetryEntry[]>(`/api/mcp/telemetry/recent?limit=${limit}`),
 		// Discovery: list all available MCP tools with their parameter hints.
@@ -742,6 +790,21 @@ export const api = {
 			>(`/api/discovery/feed?${query.toString()}`);
 		},
 		keywords: () => request<string[]>('/api/discovery/keywords'),
+		keywordSuggestions: (params: { minScore?: number; limit?: number } = {}) => {
+			const query = new URLSearchParams();
+			if (params.minScore !== undefined) query.set('min_score', params.minScore.toString());
+			if (params.limit !== undefined) query.set('limit', params.limit.toString());
+			return request<{
+				suggestions: Array<{
+					keyword: string;
+					co_occurrences: number;
+					tweet_count: number;
+					related_to: string[];
+				}>;
+				analyzed_tweets: number;
+				note: string | null;
+			}>(`/api/discovery/keywords/suggestions?${query.toString()}`);
+		},
 		composeReply: (tweetId: string, mentionProduct: boolean = false) =>
 			request<{ content: string; tweet_id: string }>(
 				`/api/discovery/${tweetId}/compose-reply`,
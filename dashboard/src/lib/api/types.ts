@@ -69,6 +69,19 @@ export interface RuntimeStatus {
 	capability_tier: CapabilityTier;
 }
 
+/** One captured server log line from `GET /api/runtime/logs`. */
+export interface RuntimeLogLine {
+	timestamp: string;
+	level: string;
+	target: string;
+	message: string;
+}
+
+export interface RuntimeLogs {
+	lines: RuntimeLogLine[];
+	capacity: number;
+}
+
 // --- Health & Analytics types ---
 
 /** Runtime health status returned by `GET /api/health`. */
@@ -152,11 +165,14 @@ export interface ActivityResponse {
 	total: number;
 	limit: number;
 	offset: number;
+	next_cursor: string | null;
 }
 
 export interface ActionUsage {
 	used: number;
 	max: number;
+	/** Actions left in the current window (a full budget once it has expired). */
+	remaining: number;
 }
 
 export interface RateLimitUsage {
@@ -241,6 +257,29 @@ export interface ScheduleConfig {
 	thread_time: string;
 }
 
+export interface SchedulePreviewSlot {
+	at: string;
+	local_time: string;
+	kind: 'tweet' | 'thread';
+	filled: boolean;
+	scheduled_content_id?: number;
+}
+
+export interface SchedulePreviewDay {
+	date: string;
+	weekday: string;
+	active: boolean;
+	slots: SchedulePreviewSlot[];
+	unslotted_scheduled: number[];
+}
+
+export interface SchedulePreview {
+	timezone: string;
+	tweet_mode: 'slots' | 'interval';
+	thread_mode: 'slots' | 'interval';
+	days: SchedulePreviewDay[];
+}
+
 export interface ThreadBlock {
 	id: string;
 	text: string;
@@ -261,6 +300,7 @@ export interface ComposeRequest {
 	blocks?: ThreadBlock[];
 	provenance?: ProvenanceRef[];
 	hook_style?: string;
+	framework?: string;
 }
 
 // Thread content parsing utilities moved to $lib/utils/parseThreadContent.ts
@@ -402,6 +442,7 @@ export interface TuitbotConfig {
 		client_secret: string | null;
 		provider_backend: string;
 		scraper_allow_mutations: boolean;
+		cache_ttl_seconds?: number;
 	};
 	auth: {
 		mode: string;
@@ -431,6 +472,8 @@ export interface TuitbotConfig {
 		engagement_rate_max: number;
 		reply_count_max: number;
 		content_type_max: number;
+		recency_curve: 'brackets' | 'linear' | 'exponential';
+		recency_half_life_minutes: number;
 	};
 	limits: {
 		max_replies_per_day: number;
@@ -439,8 +482,13 @@ export interface TuitbotConfig {
 		min_action_delay_seconds: number;
 		max_action_delay_seconds: number;
 		max_replies_per_author_per_day: number;
+		max_replies_per_conversation_per_day: number;
+		max_likes_per_day: number;
+		max_retweets_per_day: number;
+		discovery_likes: boolean;
 		banned_phrases: string[];
 		product_mention_ratio: number;
+		ramp?: { start: number; target: number; days: number };
 	};
 	intervals: {
 		mentions_check_seconds: number;
@@ -453,6 +501,8 @@ export interface TuitbotConfig {
 		api_key: string | null;
 		model: string;
 		base_url: string | null;
+		max_tokens_cap?: number;
+		session_token_budget?: number;
 	};
 	targets: {
 		accounts: string[];
@@ -463,6 +513,7 @@ export interface TuitbotConfig {
 	storage: {
 		db_path: string;
 		retention_days: number;
+		critical_retention_days: number;
 	};
 	logging: {
 		status_interval_seconds: number;
@@ -490,6 +541,13 @@ export interface TuitbotConfig {
 			analytics_sync_enabled: boolean;
 			poll_interval_seconds: number | null;
 		}>;
+		loopback?: {
+			cooldown_seconds: number;
+			loop_back_max_writes_per_hour: number;
+		};
+		scan_concurrency?: number;
+		max_file_bytes?: number;
+		allowed_extensions?: string[];
 	};
 	deployment_mode: DeploymentModeValue;
 	connectors?: {
@@ -522,10 +580,28 @@ export interface SettingsValidationResult {
 	errors: Array<{ field: string; message: string }>;
 }
 
+export interface SettingsSchemaField {
+	path: string;
+	section: string;
+	type: 'string' | 'integer' | 'number' | 'boolean' | 'array' | 'object';
+	nullable: boolean;
+	default?: unknown;
+	description?: string;
+	minimum?: number;
+	maximum?: number;
+	options?: unknown[];
+	items?: string;
+}
+
+export interface SettingsSchema {
+	fields: SettingsSchemaField[];
+}
+
 export interface SettingsTestResult {
 	success: boolean;
 	error?: string;
 	latency_ms?: number;
+	model_available?: boolean;
 }
 
 // --- Strategy types ---
@@ -581,6 +657,14 @@ export interface StrategyInputs {
 	target_accounts: string[];
 }
 
+export interface StrategyNextStep {
+	kind: 'add_keywords' | 'adopt_posting_times' | 'add_targets' | 'drop_targets' | 'adjust_limits';
+	title: string;
+	rationale: string;
+	/** Body for `PATCH /api/settings` that applies this step. */
+	apply: Record<string, unknown>;
+}
+
 // --- Cost types ---
 
 export interface CostSummary {
@@ -741,11 +825,20 @@ export interface McpToolMetrics {
 	max_latency_ms: number;
 }
 
-export interface McpErrorBreakdown {
+export interface McpErrorGroup {
 	tool_name: string;
 	error_code: string;
 	count: number;
-	latest_at: string;
+	first_seen: string;
+	last_seen: string;
+	sample_message: string | null;
+}
+
+export interface McpErrorGroupPage {
+	groups: McpErrorGroup[];
+	total: number;
+	limit: number;
+	offset: number;
 }
 
 export interface McpTelemetryEntry {
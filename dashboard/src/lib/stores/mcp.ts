@@ -4,7 +4,7 @@ import {
 	type McpPolicyStatus,
 	type McpTelemetrySummary,
 	type McpToolMetrics,
-	type McpErrorBreakdown,
+	type McpErrorGroup,
 	type McpTelemetryEntry,
 	type McpPolicyPatch,
 	type McpPolicyTemplate
@@ -15,7 +15,8 @@ import {
 export const policy = writable<McpPolicyStatus | null>(null);
 export const summary = writable<McpTelemetrySummary | null>(null);
 export const metrics = writable<McpToolMetrics[]>([]);
-export const errors = writable<McpErrorBreakdown[]>([]);
+export const errors = writable<McpErrorGroup[]>([]);
+export const errorGroupsTotal = writable(0);
 export const recentExecutions = writable<McpTelemetryEntry[]>([]);
 export const templates = writable<McpPolicyTemplate[]>([]);
 export const loading = writable(true);
@@ -39,7 +40,8 @@ export async function loadMcpData(hours: number = 24) {
 		policy.set(policyData);
 		summary.set(summaryData);
 		metrics.set(metricsData);
-		errors.set(errorsData);
+		errors.set(errorsData.groups);
+		errorGroupsTotal.set(errorsData.total);
 		recentExecutions.set(recentData);
 	} catch (e) {
 		error.set(e instanceof Error ? e.message : 'Failed to load MCP data');
@@ -22,6 +22,8 @@ export interface BuildComposeRequestOpts {
 	provenance?: ProvenanceRef[];
 	/** Hook style tag (e.g. "contrarian_take") for source enrichment. */
 	hookStyle?: string;
+	/** Content framework the AI draft was generated with. */
+	framework?: string;
 }
 
 /** Build a ComposeRequest from current editor state. */
@@ -61,6 +63,7 @@ export function buildComposeRequest(opts: BuildComposeRequestOpts): ComposeReque
 
 	if (opts.provenance && opts.provenance.length > 0) data.provenance = opts.provenance;
 	if (opts.hookStyle) data.hook_style = opts.hookStyle;
+	if (opts.framework) data.framework = opts.framework;
 
 	return data;
 }
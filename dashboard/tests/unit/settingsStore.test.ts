@@ -75,6 +75,10 @@ const makeConfig = (overrides: Partial<TuitbotConfig> = {}): TuitbotConfig =>
 			min_action_delay_seconds: 60,
 			max_action_delay_seconds: 300,
 			max_replies_per_author_per_day: 1,
+			max_replies_per_conversation_per_day: 2,
+			max_likes_per_day: 20,
+			max_retweets_per_day: 5,
+			discovery_likes: false,
 			banned_phrases: [],
 			product_mention_ratio: 0.2
 		},